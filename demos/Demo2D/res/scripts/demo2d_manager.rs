@@ -1325,6 +1325,8 @@ methods!({
             enable_propagation: true,
             direction_2d: AudioDirection::Omni,
             direction_3d: AudioDirection::Omni,
+            attenuation: AudioAttenuation::Quadratic,
+            doppler: 0.0,
         };
         for (i, speaker) in query!(
             ctx.run,
@@ -108,6 +108,8 @@ methods!({
             enable_propagation: true,
             direction_2d: AudioDirection::Omni,
             direction_3d: AudioDirection::Omni,
+            attenuation: AudioAttenuation::Quadratic,
+            doppler: 0.0,
         };
         let base = MidiNoteOptions {
             velocity: 78,
@@ -0,0 +1,19 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use perro_assets::{archive::PerroAssetsArchive, integrity::IntegrityManifest};
+
+// Full `.perro` open (plain or compressed container) + every entry read.
+fuzz_target!(|data: &[u8]| {
+    let _ = IntegrityManifest::compute(data, &[], 0);
+    let Ok(archive) = PerroAssetsArchive::open_from_owned_bytes(data.to_vec()) else {
+        return;
+    };
+    for path in archive.list_files() {
+        if let Ok(bytes) = archive.read_file(&path) {
+            let _ = bytes.len();
+        }
+        let _ = archive.get_file_slice(&path);
+        let _ = archive.stream_file(&path);
+    }
+});
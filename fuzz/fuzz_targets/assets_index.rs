@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use perro_assets::common::{read_header, read_index_entry};
+use std::io::{Cursor, Seek, SeekFrom};
+
+// Header + index walk exactly like archive open, without the data section.
+fuzz_target!(|data: &[u8]| {
+    let mut cursor = Cursor::new(data);
+    let Ok(header) = read_header(&mut cursor) else {
+        return;
+    };
+    if cursor.seek(SeekFrom::Start(header.index_offset)).is_err() {
+        return;
+    }
+    for _ in 0..header.file_count {
+        if read_index_entry(&mut cursor).is_err() {
+            return;
+        }
+    }
+});
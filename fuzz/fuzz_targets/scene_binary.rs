@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use perro_scene::decode_scene;
+
+// `.scnb` bytes from DLC archives: every input must decode or return `Err`.
+fuzz_target!(|data: &[u8]| {
+    let _ = decode_scene(data);
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use perro_scene::Parser;
+
+// `.scn` text from mods/DLC: every input must parse or return `Err`.
+fuzz_target!(|data: &[u8]| {
+    let Ok(src) = std::str::from_utf8(data) else {
+        return;
+    };
+    let _ = Parser::new(src).try_parse_scene();
+    let _ = Parser::new(src).try_parse_scene_doc();
+    let _ = Parser::new(src).try_parse_value_literal();
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use perro_assets::compression::decompress_zlib_limited;
+
+const LIMIT: usize = 1 << 20;
+
+// Bombs must stop at the limit; output never exceeds it.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(out) = decompress_zlib_limited(data, LIMIT) {
+        assert!(out.len() <= LIMIT);
+    }
+});
@@ -1,3 +1,5 @@
+use crate::scripts_ui_editor_console_rs as editor_console;
+use perro_api::prelude::LOG_SOURCE_ENV;
 use std::path::Path;
 
 pub fn create_project(parent_dir: &str, project_name: &str) -> Result<String, String> {
@@ -20,6 +22,40 @@ pub fn create_project(parent_dir: &str, project_name: &str) -> Result<String, St
     Ok(project_root.to_string_lossy().to_string())
 }
 
+/// Boots `scene_path` in its own `perro dev --scene` run, leaving the editor
+/// open. Uses the CLI that launched the editor (`PERRO_CLI`), else `perro`.
+pub fn play_scene(project_root: &str, scene_path: &str) -> Result<(), String> {
+    let cli = std::env::var_os("PERRO_CLI").unwrap_or_else(|| "perro".into());
+    let mut child = std::process::Command::new(cli)
+        .args(["dev", "--path", project_root, "--scene", scene_path])
+        .env("NO_COLOR", "1")
+        .env(LOG_SOURCE_ENV, "1")
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("failed to launch perro dev: {err}"))?;
+    // play output lands in the editor console; source suffix enables click-to-source
+    if let Some(stdout) = child.stdout.take() {
+        editor_console::spawn_console_reader(stdout);
+    }
+    if let Some(stderr) = child.stderr.take() {
+        editor_console::spawn_console_reader(stderr);
+    }
+    Ok(())
+}
+
+/// Re-renders the saved scene's cached thumbnail in the background via
+/// `perro thumbnail`. Fire and forget; a failed render keeps the old PNG.
+pub fn refresh_scene_thumbnail(project_root: &str, scene_path: &str) -> Result<(), String> {
+    let cli = std::env::var_os("PERRO_CLI").unwrap_or_else(|| "perro".into());
+    std::process::Command::new(cli)
+        .args(["thumbnail", "--path", project_root, "--scene", scene_path])
+        .stdout(std::process::Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|err| format!("failed to launch perro thumbnail: {err}"))
+}
+
 fn sanitize_project_dir_name(name: &str) -> String {
     let trimmed = name.trim();
     if trimmed.is_empty() {
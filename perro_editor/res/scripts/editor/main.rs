@@ -228,6 +228,7 @@ pub const MAX_FILES: usize = 12;
 pub const MAX_NODES: usize = 12;
 pub const MAX_TABS: usize = 4;
 pub const MAX_OUTPUT_MESSAGES: usize = 128;
+pub const OUTPUT_ROWS: usize = 8;
 pub const MAX_RECENT: usize = 5;
 pub const MAX_NODE_PICKER_ROWS: usize = 12;
 pub const MAX_INSPECTOR_PICKER_ROWS: usize = 12;
@@ -565,9 +566,12 @@ pub struct EditorState {
     pub log: String,
     pub output_messages: Vec<String>,
     pub output_levels: Vec<String>,
+    pub output_categories: Vec<String>,
+    pub output_sources: Vec<String>,
     pub output_repeats: Vec<u32>,
     pub output_seen_log: String,
     pub output_filter: String,
+    pub output_category: String,
     pub output_hide_info: bool,
     pub output_hide_warn: bool,
     pub output_hide_error: bool,
@@ -576,6 +580,7 @@ pub struct EditorState {
 lifecycle!({
     fn on_all_init(&self, ctx: &mut ScriptContext<'_, API>) {
         connect_editor_signals(ctx);
+        crate::scripts_ui_editor_console_rs::install_console_hook();
 
         let recent = load_recent_projects();
         let _ = with_state_mut!(ctx.run, EditorState, ctx.id, |state| {
@@ -604,6 +609,7 @@ lifecycle!({
         update_editor_cursor(ctx);
         update_editor_shortcuts(ctx);
         poll_project_diffs(ctx);
+        poll_editor_console(ctx);
         tick_script_schema_reload(ctx);
         tick_destructive_confirmation(ctx);
         update_anim_editor(ctx);
@@ -716,6 +722,7 @@ methods!({
             "output_info_button" => toggle_editor_output_level(ctx, "info"),
             "output_warn_button" => toggle_editor_output_level(ctx, "warn"),
             "output_error_button" => toggle_editor_output_level(ctx, "error"),
+            "output_category_button" => cycle_editor_output_category(ctx),
             "distraction_free_button" => toggle_distraction_free(ctx),
             "command_palette_button" => set_command_palette(ctx, true),
             "command_palette_filter_box" => update_command_palette_filter(ctx),
@@ -821,6 +828,8 @@ methods!({
                     click_anim_lane(ctx, idx);
                 } else if let Some(idx) = suffix_index(&name, "command_palette_row_") {
                     execute_command_palette_row(ctx, idx);
+                } else if let Some(idx) = suffix_index(&name, "output_row_") {
+                    open_editor_output_row_source(ctx, idx);
                 } else if let Some(idx) = suffix_index(&name, "manager_recent_") {
                     open_recent_project(ctx, idx);
                 } else if let Some(idx) = suffix_index(&name, "add_node_type_") {
@@ -1134,6 +1143,30 @@ mod destructive_confirmation_tests {
         assert_eq!(filtered_editor_output(&state), "No output");
     }
 
+    #[test]
+    fn output_rows_filter_by_category_and_keep_sources() {
+        let mut state = EditorState {
+            log: "play scene".to_string(),
+            ..EditorState::default()
+        };
+        capture_editor_output_state(&mut state);
+        state.output_levels.push("warn".to_string());
+        state.output_categories.push("ai".to_string());
+        state.output_messages.push("[ai] lost target".to_string());
+        state.output_sources.push("res://scripts/enemy.rs:42".to_string());
+        state.output_repeats.push(1);
+        assert_eq!(visible_editor_output(&state, OUTPUT_ROWS).len(), 2);
+
+        state.output_category = "ai".to_string();
+        let rows = visible_editor_output(&state, OUTPUT_ROWS);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].source, "res://scripts/enemy.rs:42");
+
+        state.output_category.clear();
+        state.output_filter = "enemy.rs".to_string();
+        assert_eq!(visible_editor_output(&state, OUTPUT_ROWS).len(), 1);
+    }
+
     #[test]
     fn snap_toggle_shift_inverts() {
         let mut state = EditorState::default();
@@ -1151,6 +1184,25 @@ mod destructive_confirmation_tests {
         assert_eq!(rows[0].id, "mode_3d");
         assert!(editor_commands("save").len() >= 2);
     }
+
+    #[test]
+    fn command_palette_lists_attach_script_templates() {
+        let rows = editor_commands("attach script");
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[1].id, "script_update");
+        let root = Path::new("missing_project");
+        assert_eq!(new_script_stem(root, "PlayerCamera"), "player_camera");
+        assert_eq!(new_script_stem(root, "hp bar!"), "hp_bar");
+        assert_eq!(new_script_stem(root, "__"), "script");
+    }
+
+    #[test]
+    fn command_palette_lists_play_current_scene() {
+        let rows = editor_commands("play scene");
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].id, "play_scene");
+        assert_eq!(rows[0].hint, "F6");
+    }
 }
 
 fn connect_editor_signals<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, API>) {
@@ -1190,6 +1242,15 @@ fn connect_editor_signals<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, A
             signal!("editor_output_info"),
             signal!("editor_output_warn"),
             signal!("editor_output_error"),
+            signal!("editor_output_category"),
+            signal!("editor_output_row_0"),
+            signal!("editor_output_row_1"),
+            signal!("editor_output_row_2"),
+            signal!("editor_output_row_3"),
+            signal!("editor_output_row_4"),
+            signal!("editor_output_row_5"),
+            signal!("editor_output_row_6"),
+            signal!("editor_output_row_7"),
             signal!("editor_distraction_free"),
             signal!("editor_command_palette"),
             signal!("editor_command_palette_filter"),
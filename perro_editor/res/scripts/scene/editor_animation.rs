@@ -1401,8 +1401,11 @@ mod node_rename_tests {
             parent: None,
             script: None,
             clear_script: false,
+            enabled: true,
             root_of: None,
             script_vars: Cow::Owned(script_vars),
+            meta: Cow::Owned(Vec::new()),
+            connections: Cow::Owned(Vec::new()),
         }
     }
 
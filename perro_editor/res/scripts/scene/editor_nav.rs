@@ -295,6 +295,10 @@ pub fn update_editor_shortcuts<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<
         prepare_rename_selection(ctx);
         return;
     }
+    if !ctrl && !alt && !shift && key_pressed!(ctx.ipt, KeyCode::F6) {
+        play_active_scene(ctx);
+        return;
+    }
     if ctrl && key_pressed!(ctx.ipt, KeyCode::KeyO) {
         if with_state!(ctx.run, EditorState, ctx.id, |state| state.sidebar_mode
             == "files").unwrap_or_default()
@@ -514,6 +518,33 @@ pub fn update_editor_shortcuts<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<
     }
 }
 
+/// F6: save dirty scenes, then run the active scene as the boot scene in a
+/// separate dev runner so sub-scenes play without touching `main_scene`.
+pub fn play_active_scene<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, API>) {
+    let (root, path) = with_state!(ctx.run, EditorState, ctx.id, |state| {
+        (
+            state.project_root.clone(),
+            state.open_paths.get(state.active_open).cloned(),
+        )
+    })
+    .unwrap_or_default();
+    let Some(path) = path.filter(|path| path.ends_with(".scn")) else {
+        set_log(ctx, "play fail\nno open scene");
+        refresh_all(ctx);
+        return;
+    };
+    if !save_all_scenes(ctx) {
+        set_log(ctx, "play blocked\nsave failed");
+        refresh_all(ctx);
+        return;
+    }
+    match editor_project::play_scene(&root, &path) {
+        Ok(()) => set_log(ctx, &format!("play scene\n{path}")),
+        Err(err) => set_log(ctx, &format!("play fail\n{path}\n{err}")),
+    }
+    refresh_all(ctx);
+}
+
 pub fn undo_active_scene<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, API>) {
     let changed = with_state_mut!(ctx.run, EditorState, ctx.id, undo_scene_doc).unwrap_or(false);
     if changed {
@@ -18,6 +18,7 @@ use crate::scripts_scene_editor_viewport_rs::*;
 use crate::scripts_ui_editor_ui_rs::*;
 use crate::scripts_ui_editor_view_rs as editor_view;
 use perro_api::prelude::*;
+use perro_api::project::{ScriptTemplate, create_script_from_template};
 use perro_api::scene::{
     SceneDoc, SceneFieldName, SceneKey, SceneNodeData, SceneNodeEntry, SceneValue, SceneValueKey,
 };
@@ -735,6 +736,96 @@ pub fn use_active_asset_on_selected_node<API: ScriptAPI + ?Sized>(
     refresh_all(ctx);
 }
 
+/// Generate `res://scripts/<node>.rs` from `template`, typed for the selected
+/// node, and attach it in the same undo-able doc edit.
+pub fn attach_new_script_to_selected_node<API: ScriptAPI + ?Sized>(
+    ctx: &mut ScriptContext<'_, API>,
+    template: ScriptTemplate,
+) {
+    let changed = with_state_mut!(ctx.run, EditorState, ctx.id, |state| {
+        let Some(key) = state.selected_key else {
+            state.log = "attach script fail\nselect node".to_string();
+            return false;
+        };
+        if state.doc_text.is_empty() || state.project_root.is_empty() {
+            state.log = "attach script fail\nno open scene".to_string();
+            return false;
+        }
+        let mut doc = cached_scene_doc(&state.doc_text);
+        let Some(node) = doc
+            .scene
+            .nodes
+            .to_mut()
+            .iter_mut()
+            .find(|node| node.key.as_u32() == key)
+        else {
+            state.log = "attach script fail\nmissing node".to_string();
+            return false;
+        };
+        if let Some(script) = node.script.as_ref() {
+            state.log = format!("attach script fail\nnode has {script}");
+            return false;
+        }
+        let node_type = node.data.type_name().to_string();
+        let root = Path::new(&state.project_root);
+        let stem = new_script_stem(root, node.name.as_deref().unwrap_or(node_type.as_str()));
+        let path = match create_script_from_template(root, &stem, template, &node_type) {
+            Ok(path) => path,
+            Err(err) => {
+                state.log = format!("attach script fail\n{err}");
+                return false;
+            }
+        };
+        node.script = Some(Cow::Owned(path.clone()));
+        node.clear_script = false;
+        doc.normalize_links();
+        set_state_scene_doc(state, &doc);
+        state.dirty = true;
+        if let Some(open) = state.open_paths.get(state.active_open).cloned()
+            && !state.dirty_scene_paths.iter().any(|item| item == &open)
+        {
+            state.dirty_scene_paths.push(open);
+        }
+        state.script_schema_reload_frames = 8;
+        state.log = format!("attach script\n{path}\n{} template", template.name());
+        true
+    })
+    .unwrap_or(false);
+    if changed {
+        refresh_project_assets(ctx);
+        rebuild_preview(ctx);
+    }
+    refresh_all(ctx);
+}
+
+/// `PlayerCamera` -> `player_camera`, suffixed `_2`, `_3`.. past existing
+/// files in `res/scripts`.
+pub fn new_script_stem(project_root: &Path, node_name: &str) -> String {
+    let mut base = String::new();
+    let mut prev_lower = false;
+    for c in node_name.chars() {
+        if c.is_ascii_uppercase() && prev_lower {
+            base.push('_');
+        }
+        prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        if c.is_ascii_alphanumeric() {
+            base.push(c.to_ascii_lowercase());
+        } else {
+            base.push('_');
+        }
+    }
+    let base = base.trim_matches('_');
+    let base = if base.is_empty() { "script" } else { base };
+    let dir = project_root.join("res").join("scripts");
+    let mut stem = base.to_string();
+    let mut n = 2;
+    while dir.join(format!("{stem}.rs")).exists() {
+        stem = format!("{base}_{n}");
+        n += 1;
+    }
+    stem
+}
+
 pub fn make_node_from_active_asset<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, API>) {
     let changed = with_state_mut!(ctx.run, EditorState, ctx.id, |state| {
         let asset_path = state.active_asset_path.clone();
@@ -799,8 +890,11 @@ pub fn make_node_from_active_asset<API: ScriptAPI + ?Sized>(ctx: &mut ScriptCont
             parent,
             script,
             clear_script: false,
+            enabled: true,
             root_of,
             script_vars: Cow::Owned(Vec::new()),
+            meta: Cow::Owned(Vec::new()),
+            connections: Cow::Owned(Vec::new()),
         });
         doc.normalize_links();
         set_state_scene_doc(state, &doc);
@@ -1013,8 +1107,11 @@ pub fn add_node<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, API>, node_
             parent,
             script,
             clear_script: false,
+            enabled: true,
             root_of,
             script_vars: Cow::Owned(Vec::new()),
+            meta: Cow::Owned(Vec::new()),
+            connections: Cow::Owned(Vec::new()),
         };
         doc.scene.key_names.to_mut().push(Cow::Owned(name.clone()));
         doc.scene.nodes.to_mut().push(node);
@@ -1492,6 +1589,10 @@ pub fn save_active_scene_to_disk<API: ScriptAPI + ?Sized>(
                     state.log = format!("save scene\n{path}");
                 }
             });
+            // autosaves skip it; each render boots a short dev runner window
+            if !quiet {
+                let _ = editor_project::refresh_scene_thumbnail(&root, &path);
+            }
             true
         }
         Err(err) => {
@@ -0,0 +1,213 @@
+use perro_api::prelude::*;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+
+/// One console line waiting to be folded into editor output history.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConsoleEntry {
+    pub level: String,
+    pub category: String,
+    pub message: String,
+    /// `res://path.rs:line` of the logging call, empty when unknown.
+    pub source: String,
+}
+
+static PENDING: OnceLock<Mutex<Vec<ConsoleEntry>>> = OnceLock::new();
+static HOOK: OnceLock<LogHookId> = OnceLock::new();
+/// Cap for lines queued between editor frames; a spammy play run drops oldest.
+const MAX_PENDING: usize = 512;
+
+fn push_entry(entry: ConsoleEntry) {
+    let pending = PENDING.get_or_init(|| Mutex::new(Vec::new()));
+    let Ok(mut pending) = pending.lock() else {
+        return;
+    };
+    if pending.len() >= MAX_PENDING {
+        pending.remove(0);
+    }
+    pending.push(entry);
+}
+
+pub fn drain_console_entries() -> Vec<ConsoleEntry> {
+    PENDING
+        .get()
+        .and_then(|pending| {
+            pending
+                .lock()
+                .ok()
+                .map(|mut pending| std::mem::take(&mut *pending))
+        })
+        .unwrap_or_default()
+}
+
+pub fn has_pending_console_entries() -> bool {
+    PENDING
+        .get()
+        .and_then(|pending| pending.lock().ok().map(|pending| !pending.is_empty()))
+        .unwrap_or(false)
+}
+
+/// Feed `log_*!` calls made inside the editor process into the console.
+pub fn install_console_hook() {
+    HOOK.get_or_init(|| {
+        add_log_hook(|record| {
+            push_entry(ConsoleEntry {
+                level: match record.level {
+                    LogLevel::Error => "error",
+                    LogLevel::Warn => "warn",
+                    LogLevel::Print | LogLevel::Info => "info",
+                }
+                .to_string(),
+                category: record.category().unwrap_or("log").to_string(),
+                message: record.message.clone(),
+                source: record
+                    .source()
+                    .map(|source| format!("{source}:{}", record.line))
+                    .unwrap_or_default(),
+            });
+        })
+    });
+}
+
+/// Read a play run's stdout/stderr line by line into the console.
+pub fn spawn_console_reader(stream: impl Read + Send + 'static) {
+    std::thread::spawn(move || {
+        for line in BufReader::new(stream).lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            if let Some(entry) = parse_console_line(&line) {
+                push_entry(entry);
+            }
+        }
+    });
+}
+
+/// Parse one line of `perro dev` output: `[LEVEL] message  @ res://x.rs:12`.
+/// Untagged lines (cargo, runner notes) fall back to a guess from the text.
+pub fn parse_console_line(line: &str) -> Option<ConsoleEntry> {
+    let line = strip_ansi(line);
+    let line = line.trim_end();
+    if line.trim().is_empty() {
+        return None;
+    }
+    let (level, rest) = if let Some(rest) = line.strip_prefix("[INFO] ") {
+        ("info", rest)
+    } else if let Some(rest) = line.strip_prefix("[WARN] ") {
+        ("warn", rest)
+    } else if let Some(rest) = line.strip_prefix("[ERROR] ") {
+        ("error", rest)
+    } else {
+        let lower = line.trim_start().to_ascii_lowercase();
+        let level = if lower.starts_with("error") || lower.contains("panicked at") {
+            "error"
+        } else if lower.starts_with("warning") {
+            "warn"
+        } else {
+            "info"
+        };
+        (level, line)
+    };
+    let (message, source) = match rest.rsplit_once(LOG_SOURCE_SEP) {
+        Some((message, source)) if source.starts_with("res://") || source.starts_with("dlc://") => {
+            (message, source)
+        }
+        _ => (rest, ""),
+    };
+    Some(ConsoleEntry {
+        level: level.to_string(),
+        category: log_category(message).unwrap_or("play").to_string(),
+        message: message.to_string(),
+        source: source.to_string(),
+    })
+}
+
+/// Matches the `PERRO_LOG_SOURCE` suffix written by `perro_modules::log`.
+const LOG_SOURCE_SEP: &str = "  @ ";
+
+fn strip_ansi(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // CSI: ESC [ ... final byte in @..~
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) && c != '[' {
+                    break;
+                }
+            }
+            continue;
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// `res://a.rs:12` -> (`<root>/res/a.rs`, 12). `dlc://name/a.rs` maps to
+/// `<root>/dlcs/name/a.rs`.
+pub fn source_file_and_line(root: &str, source: &str) -> Option<(String, u32)> {
+    let (path, line) = source.rsplit_once(':')?;
+    let line = line.parse::<u32>().ok()?;
+    let abs = if let Some(rel) = path.strip_prefix("res://") {
+        Path::new(root).join("res").join(rel)
+    } else if let Some(rel) = path.strip_prefix("dlc://") {
+        Path::new(root).join("dlcs").join(rel)
+    } else {
+        return None;
+    };
+    Some((abs.to_string_lossy().replace('\\', "/"), line))
+}
+
+/// Open `source` (`res://x.rs:line`) at that line via `perro open`, which
+/// honors `PERRO_EDITOR`.
+pub fn open_console_source(root: &str, source: &str) -> Result<String, String> {
+    let (file, _) = source_file_and_line(root, source)
+        .ok_or_else(|| format!("no source location in `{source}`"))?;
+    let cli = std::env::var_os("PERRO_CLI").unwrap_or_else(|| "perro".into());
+    std::process::Command::new(cli)
+        .args(["open", source, "--path", root])
+        .stdout(std::process::Stdio::null())
+        .spawn()
+        .map(|_| file)
+        .map_err(|err| format!("failed to launch perro open: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_console_line_reads_level_category_and_source() {
+        let entry = parse_console_line(
+            "\x1b[93m[WARN]\x1b[0m [ai] lost target  @ res://scripts/enemy.rs:42",
+        )
+        .expect("entry");
+        assert_eq!(entry.level, "warn");
+        assert_eq!(entry.category, "ai");
+        assert_eq!(entry.message, "[ai] lost target");
+        assert_eq!(entry.source, "res://scripts/enemy.rs:42");
+
+        let cargo = parse_console_line("error[E0425]: cannot find value `x`").expect("entry");
+        assert_eq!(cargo.level, "error");
+        assert_eq!(cargo.category, "play");
+        assert!(cargo.source.is_empty());
+        assert!(parse_console_line("   ").is_none());
+    }
+
+    #[test]
+    fn source_file_and_line_maps_res_and_dlc_paths() {
+        assert_eq!(
+            source_file_and_line("C:/game", "res://scripts/enemy.rs:42"),
+            Some(("C:/game/res/scripts/enemy.rs".to_string(), 42))
+        );
+        assert_eq!(
+            source_file_and_line("C:/game", "dlc://Expansion/boss.rs:7"),
+            Some(("C:/game/dlcs/Expansion/boss.rs".to_string(), 7))
+        );
+        assert_eq!(
+            source_file_and_line("C:/game", "res://scripts/enemy.rs"),
+            None
+        );
+    }
+}
@@ -4166,6 +4166,20 @@ fn scene_value_to_preview_variant(
             perro_api::variant::Variant::from(*z),
             perro_api::variant::Variant::from(*w),
         ]),
+        SceneValue::Color { .. } => perro_api::variant::Variant::Array(
+            value
+                .as_color()
+                .unwrap_or_default()
+                .into_iter()
+                .map(perro_api::variant::Variant::from)
+                .collect(),
+        ),
+        SceneValue::Rect { x, y, w, h } => perro_api::variant::Variant::Array(vec![
+            perro_api::variant::Variant::from(*x),
+            perro_api::variant::Variant::from(*y),
+            perro_api::variant::Variant::from(*w),
+            perro_api::variant::Variant::from(*h),
+        ]),
         SceneValue::Str(value) => perro_api::variant::Variant::from(value.to_string()),
         SceneValue::Hashed(value) => perro_api::variant::Variant::from(*value),
         SceneValue::Key(value) => {
@@ -4889,6 +4903,8 @@ pub fn scene_values_equal(a: &SceneValue, b: &SceneValue) -> bool {
         ) => ax == bx && ay == by && az == bz && aw == bw,
         (SceneValue::Str(a), SceneValue::Str(b)) => a.as_ref() == b.as_ref(),
         (SceneValue::Hashed(a), SceneValue::Hashed(b)) => a == b,
+        (SceneValue::Color { .. }, SceneValue::Color { .. })
+        | (SceneValue::Rect { .. }, SceneValue::Rect { .. }) => a == b,
         (SceneValue::Key(a), SceneValue::Key(b)) => a.as_ref() == b.as_ref(),
         (SceneValue::Array(a), SceneValue::Array(b)) => {
             a.len() == b.len()
@@ -6,7 +6,7 @@ use crate::scripts_assets_editor_file_watch_rs as editor_file_watch;
 use crate::scripts_assets_editor_files_rs as editor_files;
 use crate::scripts_editor_main_rs::{
     EditorState, FILE_WATCH_INTERVAL_FRAMES, MAX_FILES, MAX_INSPECTOR_PICKER_ROWS,
-    MAX_NODE_PICKER_ROWS, MAX_NODES, MAX_OUTPUT_MESSAGES, MAX_RECENT, MAX_TABS,
+    MAX_NODE_PICKER_ROWS, MAX_NODES, MAX_OUTPUT_MESSAGES, MAX_RECENT, MAX_TABS, OUTPUT_ROWS,
     RECENT_PROJECTS_PATH, cached_scene_doc,
     cached_scene_doc_shared, cached_scene_node,
 };
@@ -18,6 +18,7 @@ use crate::scripts_scene_editor_scene_deps_rs as editor_scene_deps;
 use crate::scripts_scene_editor_scene_rs as editor_scene;
 use crate::scripts_scene_editor_viewport_rs::*;
 use crate::scripts_ui_bitmask_rs::{ensure_inspector_bitmask_grid, update_inspector_bitmask_grid};
+use crate::scripts_ui_editor_console_rs as editor_console;
 use crate::scripts_ui_editor_inspector_values_rs::*;
 use crate::scripts_ui_editor_view_rs as editor_view;
 use crate::scripts_ui_theme_rs as theme;
@@ -27,6 +28,7 @@ use crate::scripts_ui_inspector_value_row_rs::{
     place_inspector_value_row,
 };
 use perro_api::prelude::*;
+use perro_api::project::ScriptTemplate;
 use perro_api::scene::{
     SceneDoc, SceneFieldName, SceneKey, SceneNodeData, SceneNodeEntry, SceneValue, SceneValueKey,
 };
@@ -57,22 +59,48 @@ pub fn classify_editor_log(text: &str) -> &'static str {
 }
 
 pub fn capture_editor_output_state(state: &mut EditorState) {
-    if state.log.trim().is_empty() || state.log == state.output_seen_log {
-        return;
+    if !state.log.trim().is_empty() && state.log != state.output_seen_log {
+        state.output_seen_log.clone_from(&state.log);
+        let log = state.log.clone();
+        push_editor_output(state, classify_editor_log(&log), "editor", &log, "");
+    }
+    for entry in editor_console::drain_console_entries() {
+        push_editor_output(
+            state,
+            &entry.level,
+            &entry.category,
+            &entry.message,
+            &entry.source,
+        );
     }
-    state.output_seen_log.clone_from(&state.log);
-    if state.output_messages.last() == Some(&state.log) {
-        if let Some(repeat) = state.output_repeats.last_mut() {
-            *repeat = repeat.saturating_add(1);
-        }
+}
+
+fn push_editor_output(
+    state: &mut EditorState,
+    level: &str,
+    category: &str,
+    message: &str,
+    source: &str,
+) {
+    let last = state.output_messages.len().checked_sub(1);
+    if let Some(last) = last
+        && state.output_messages[last] == message
+        && state.output_levels[last] == level
+        && state.output_sources[last] == source
+    {
+        state.output_repeats[last] = state.output_repeats[last].saturating_add(1);
         return;
     }
-    state.output_levels.push(classify_editor_log(&state.log).to_string());
-    state.output_messages.push(state.log.clone());
+    state.output_levels.push(level.to_string());
+    state.output_categories.push(category.to_string());
+    state.output_messages.push(message.to_string());
+    state.output_sources.push(source.to_string());
     state.output_repeats.push(1);
     if state.output_messages.len() > MAX_OUTPUT_MESSAGES {
         state.output_messages.remove(0);
         state.output_levels.remove(0);
+        state.output_categories.remove(0);
+        state.output_sources.remove(0);
         state.output_repeats.remove(0);
     }
 }
@@ -81,37 +109,87 @@ fn capture_editor_output<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, AP
     let _ = with_state_mut!(ctx.run, EditorState, ctx.id, capture_editor_output_state);
 }
 
-pub fn filtered_editor_output(state: &EditorState) -> String {
+/// One visible console row: display text plus `res://x.rs:line` when known.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OutputRow {
+    pub text: String,
+    pub source: String,
+}
+
+/// Newest `limit` history rows that pass the level, category and text filters.
+pub fn visible_editor_output(state: &EditorState, limit: usize) -> Vec<OutputRow> {
     let filter = state.output_filter.trim().to_ascii_lowercase();
-    let mut lines = state
-        .output_messages
-        .iter()
-        .zip(&state.output_levels)
-        .zip(&state.output_repeats)
-        .filter(|((text, level), _)| {
-            let visible = match level.as_str() {
+    let mut rows = (0..state.output_messages.len())
+        .filter(|&idx| {
+            let visible = match state.output_levels[idx].as_str() {
                 "error" => !state.output_hide_error,
                 "warn" => !state.output_hide_warn,
                 _ => !state.output_hide_info,
             };
-            visible && (filter.is_empty() || text.to_ascii_lowercase().contains(&filter))
+            let category = &state.output_categories[idx];
+            let matches = |field: &String| field.to_ascii_lowercase().contains(&filter);
+            visible
+                && (state.output_category.is_empty() || *category == state.output_category)
+                && (filter.is_empty()
+                    || matches(&state.output_messages[idx])
+                    || matches(category)
+                    || matches(&state.output_sources[idx]))
         })
-        .map(|((text, level), repeat)| {
-            let icon = match level.as_str() { "error" => "[x]", "warn" => "[!]", _ => "[i]" };
-            let text = text.replace('\n', "  ");
-            if *repeat > 1 { format!("{icon} {text}  x{repeat}") } else { format!("{icon} {text}") }
+        .map(|idx| {
+            let icon = match state.output_levels[idx].as_str() {
+                "error" => "[x]",
+                "warn" => "[!]",
+                _ => "[i]",
+            };
+            let text = state.output_messages[idx].replace('\n', "  ");
+            let repeat = state.output_repeats[idx];
+            OutputRow {
+                text: if repeat > 1 {
+                    format!("{icon} {text}  x{repeat}")
+                } else {
+                    format!("{icon} {text}")
+                },
+                source: state.output_sources[idx].clone(),
+            }
         })
         .collect::<Vec<_>>();
-    if lines.len() > 24 {
-        lines.drain(0..lines.len() - 24);
+    if rows.len() > limit {
+        rows.drain(0..rows.len() - limit);
     }
+    rows
+}
+
+pub fn filtered_editor_output(state: &EditorState) -> String {
+    let lines = visible_editor_output(state, 24)
+        .into_iter()
+        .map(|row| row.text)
+        .collect::<Vec<_>>();
     if lines.is_empty() { "No output".to_string() } else { lines.join("\n") }
 }
 
+fn apply_output_rows<API: ScriptAPI + ?Sized>(
+    ctx: &mut ScriptContext<'_, API>,
+    rows: &[OutputRow],
+) {
+    for idx in 0..OUTPUT_ROWS {
+        let row = rows.get(idx);
+        let text = match row {
+            Some(row) if row.source.is_empty() => row.text.clone(),
+            Some(row) => format!("{}  -> {}", row.text, row.source),
+            None if idx == 0 => "No output".to_string(),
+            None => String::new(),
+        };
+        set_ui_display(ctx, &format!("output_row_{idx}"), row.is_some() || idx == 0);
+        set_label(ctx, &format!("output_row_{idx}_label"), &text);
+    }
+}
+
 pub fn clear_editor_output<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, API>) {
     let _ = with_state_mut!(ctx.run, EditorState, ctx.id, |state| {
         state.output_messages.clear();
         state.output_levels.clear();
+        state.output_categories.clear();
+        state.output_sources.clear();
         state.output_repeats.clear();
         state.output_seen_log.clone_from(&state.log);
     });
@@ -139,6 +217,57 @@ pub fn toggle_editor_output_level<API: ScriptAPI + ?Sized>(
     refresh_all(ctx);
 }
 
+/// Step the category filter through "all" and each category seen in history.
+pub fn cycle_editor_output_category<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, API>) {
+    let _ = with_state_mut!(ctx.run, EditorState, ctx.id, |state| {
+        let mut categories = Vec::<&String>::new();
+        for category in &state.output_categories {
+            if !categories.contains(&category) {
+                categories.push(category);
+            }
+        }
+        let next = match categories
+            .iter()
+            .position(|category| **category == state.output_category)
+        {
+            _ if state.output_category.is_empty() => categories.first(),
+            Some(idx) => categories.get(idx + 1),
+            None => None,
+        };
+        state.output_category = next.map(|category| (*category).clone()).unwrap_or_default();
+    });
+    refresh_all(ctx);
+}
+
+pub fn open_editor_output_row_source<API: ScriptAPI + ?Sized>(
+    ctx: &mut ScriptContext<'_, API>,
+    idx: usize,
+) {
+    let Some((root, source)) = with_state!(ctx.run, EditorState, ctx.id, |state| {
+        visible_editor_output(state, OUTPUT_ROWS)
+            .get(idx)
+            .map(|row| (state.project_root.clone(), row.source.clone()))
+    })
+    .flatten() else {
+        return;
+    };
+    if source.is_empty() {
+        return;
+    }
+    match editor_console::open_console_source(&root, &source) {
+        Ok(file) => set_log(ctx, &format!("open source\n{file}")),
+        Err(err) => set_log(ctx, &format!("open source fail\n{err}")),
+    }
+    refresh_status(ctx);
+}
+
+/// Pull log lines queued by the log hook and play-run readers.
+pub fn poll_editor_console<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, API>) {
+    if editor_console::has_pending_console_entries() {
+        refresh_status(ctx);
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct EditorCommand {
     pub id: &'static str,
@@ -147,9 +276,10 @@ pub struct EditorCommand {
 }
 
 pub fn editor_commands(query: &str) -> Vec<EditorCommand> {
-    const COMMANDS: [EditorCommand; 12] = [
+    const COMMANDS: [EditorCommand; 16] = [
         EditorCommand { id: "save", label: "Save Scene", hint: "Ctrl+S" },
         EditorCommand { id: "save_all", label: "Save All Scenes", hint: "Ctrl+Shift+S" },
+        EditorCommand { id: "play_scene", label: "Play Current Scene", hint: "F6" },
         EditorCommand { id: "add", label: "Add Child Node", hint: "Ctrl+A" },
         EditorCommand { id: "mode_ui", label: "Switch Viewport to UI", hint: "3" },
         EditorCommand { id: "mode_2d", label: "Switch Viewport to 2D", hint: "1" },
@@ -160,6 +290,9 @@ pub fn editor_commands(query: &str) -> Vec<EditorCommand> {
         EditorCommand { id: "inspect_close", label: "Inspector Collapse All", hint: "" },
         EditorCommand { id: "refresh", label: "Refresh Project Assets", hint: "Ctrl+R" },
         EditorCommand { id: "frame", label: "Frame Selected", hint: "F" },
+        EditorCommand { id: "script_empty", label: "Attach New Script (Empty)", hint: "" },
+        EditorCommand { id: "script_update", label: "Attach New Script (Update)", hint: "" },
+        EditorCommand { id: "script_signal", label: "Attach New Script (Signal)", hint: "" },
     ];
     let tokens = query.split_whitespace().map(str::to_ascii_lowercase).collect::<Vec<_>>();
     COMMANDS
@@ -199,6 +332,7 @@ pub fn execute_command_palette_row<API: ScriptAPI + ?Sized>(
     match command.map(|item| item.id) {
         Some("save") => { save_active_scene(ctx); }
         Some("save_all") => { let _ = save_all_scenes(ctx); }
+        Some("play_scene") => { play_active_scene(ctx); }
         Some("add") => { open_add_node_popup(ctx); }
         Some("mode_ui") => { set_mode(ctx, "UI"); }
         Some("mode_2d") => { set_mode(ctx, "2D"); }
@@ -209,6 +343,10 @@ pub fn execute_command_palette_row<API: ScriptAPI + ?Sized>(
         Some("inspect_close") => { set_all_inspector_sections(ctx, true); }
         Some("refresh") => { refresh_project_assets(ctx); }
         Some("frame") => { frame_selected_node(ctx); }
+        Some(id) if id.starts_with("script_") => {
+            let template = ScriptTemplate::parse(&id["script_".len()..]).unwrap_or_default();
+            attach_new_script_to_selected_node(ctx, template);
+        }
         _ => {}
     }
 }
@@ -282,9 +420,14 @@ fn refresh_chrome_view<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, API>
         &format!("{}  {}", view.project_name, view.project_root),
     );
     set_label(ctx, "status_bar", &view.status);
-    set_label(ctx, "log_text", &view.log);
+    apply_output_rows(ctx, &view.output_rows);
     set_label(ctx, "bottom_log_label", &format!("Output  {}", view.output_count));
     set_text_box(ctx, "output_filter_box", &view.output_filter);
+    set_label(
+        ctx,
+        "output_category_label",
+        if view.output_category.is_empty() { "All" } else { view.output_category.as_str() },
+    );
     for (name, visible) in [
         ("output_info_button", view.output_info_visible),
         ("output_warn_button", view.output_warn_visible),
@@ -433,7 +576,7 @@ fn refresh_chrome_view<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, API>
     );
     set_ui_display(
         ctx,
-        "log_text",
+        "output_rows",
         view.bottom_dock_open && !view.anim_drawer_open,
     );
     set_ui_display(
@@ -1114,7 +1257,7 @@ fn refresh_status_view<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, API>
         &format!("{}  {}", view.project_name, view.project_root),
     );
     set_label(ctx, "status_bar", &view.status);
-    set_label(ctx, "log_text", &view.log);
+    apply_output_rows(ctx, &view.output_rows);
     set_label(ctx, "viewport_label", &view.viewport);
     apply_script_reload_popup(ctx, view.script_schema_reloading);
 }
@@ -1150,7 +1293,7 @@ fn inspector_row_component_input_type(row: &InspectorValueRow) -> UiTextInputTyp
 
 pub fn refresh_status<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, API>) {
     capture_editor_output(ctx);
-    let (project_name, project_root, status, log, viewport, script_reloading) =
+    let (project_name, project_root, status, output_rows, viewport, script_reloading) =
         with_state!(ctx.run, EditorState, ctx.id, |state| {
             (
                 if state.project_name.is_empty() {
@@ -1160,7 +1303,7 @@ pub fn refresh_status<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, API>)
                 },
                 state.project_root.clone(),
                 editor_status_text(state),
-                filtered_editor_output(state),
+                visible_editor_output(state, OUTPUT_ROWS),
                 format!("{} Viewport", state.viewport_mode),
                 state.script_schema_reload_frames > 0,
             )
@@ -1171,7 +1314,7 @@ pub fn refresh_status<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, API>)
         &format!("{project_name}  {project_root}"),
     );
     set_label(ctx, "status_bar", &status);
-    set_label(ctx, "log_text", &log);
+    apply_output_rows(ctx, &output_rows);
     set_label(ctx, "viewport_label", &viewport);
     apply_script_reload_popup(ctx, script_reloading);
 }
@@ -1567,8 +1710,9 @@ pub struct EditorView {
     glb_summary: String,
     viewport: String,
     status: String,
-    log: String,
+    output_rows: Vec<OutputRow>,
     output_filter: String,
+    output_category: String,
     output_info_visible: bool,
     output_warn_visible: bool,
     output_error_visible: bool,
@@ -1878,8 +2022,9 @@ impl EditorView {
             glb_summary,
             viewport,
             status,
-            log: filtered_editor_output(state),
+            output_rows: visible_editor_output(state, OUTPUT_ROWS),
             output_filter: state.output_filter.clone(),
+            output_category: state.output_category.clone(),
             output_info_visible: !state.output_hide_info,
             output_warn_visible: !state.output_hide_warn,
             output_error_visible: !state.output_hide_error,
@@ -2860,6 +3005,8 @@ pub fn scene_value_kind(value: &SceneValue) -> &'static str {
         SceneValue::UVec2 { .. } => "UVec2",
         SceneValue::UVec3 { .. } => "UVec3",
         SceneValue::UVec4 { .. } => "UVec4",
+        SceneValue::Color { .. } => "Color",
+        SceneValue::Rect { .. } => "Rect",
         SceneValue::Array(_) => "Array",
         SceneValue::Object(_) => "Object",
     }
@@ -2895,6 +3042,14 @@ pub fn scene_value_edit_text(value: &SceneValue) -> String {
         SceneValue::UVec2 { x, y } => format!("({x}, {y})"),
         SceneValue::UVec3 { x, y, z } => format!("({x}, {y}, {z})"),
         SceneValue::UVec4 { x, y, z, w } => format!("({x}, {y}, {z}, {w})"),
+        SceneValue::Color { r, g, b, a } => format!("#{r:02x}{g:02x}{b:02x}{a:02x}"),
+        SceneValue::Rect { x, y, w, h } => format!(
+            "rect({}, {}, {}, {})",
+            format_compact_f32(*x),
+            format_compact_f32(*y),
+            format_compact_f32(*w),
+            format_compact_f32(*h)
+        ),
         SceneValue::Array(values) => {
             let values = values
                 .iter()
@@ -4143,8 +4298,13 @@ pub fn inspector_var_component_row(name: &str) -> Option<usize> {
 pub fn set_log<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, API>, text: &str) {
     let _ = with_state_mut!(ctx.run, EditorState, ctx.id, |state| {
         state.log = text.to_string();
+        capture_editor_output_state(state);
     });
-    set_label(ctx, "log_text", text);
+    let rows = with_state!(ctx.run, EditorState, ctx.id, |state| {
+        visible_editor_output(state, OUTPUT_ROWS)
+    })
+    .unwrap_or_default();
+    apply_output_rows(ctx, &rows);
 }
 
 pub fn tick_script_schema_reload<API: ScriptAPI + ?Sized>(ctx: &mut ScriptContext<'_, API>) {
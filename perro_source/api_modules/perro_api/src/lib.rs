@@ -1,4 +1,6 @@
 pub mod builtin_assets;
+#[cfg(feature = "steamworks")]
+mod steam_backend;
 pub use perro_csv as csv;
 pub use perro_ids as ids;
 pub use perro_input_api as input;
@@ -15,6 +17,7 @@ pub use perro_scene as scene;
 pub use perro_scripting as scripting;
 #[cfg(feature = "steamworks")]
 pub mod steam {
+    pub use crate::steam_backend::{SteamAchievementBackend, SteamCloudSave};
     pub use perro_steamworks::account;
     pub use perro_steamworks::achievements;
     pub use perro_steamworks::apps;
@@ -0,0 +1,90 @@
+use perro_modules::achievements::{AchievementBackend, Change, Records};
+use perro_modules::cloud_save::{
+    CloudFile, CloudSave, MANIFEST_NAME, Timestamps, decode_timestamps, encode_timestamps,
+};
+use perro_steamworks::{achievements, cloud, stats};
+
+/// Routes `modules::achievements` to Steam. Achievement ids and stat names
+/// must match the ones set up in Steamworks; stats are `INT` stats.
+///
+/// ```rust,ignore
+/// modules::achievements::set_backend(steam::SteamAchievementBackend);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SteamAchievementBackend;
+
+impl AchievementBackend for SteamAchievementBackend {
+    fn load(&mut self) -> Records {
+        let mut records = Records::default();
+        let Ok(Some(names)) = stats::achievement_names() else {
+            return records;
+        };
+        records.unlocked = names
+            .into_iter()
+            .filter(|id| stats::achievement_unlocked(id).unwrap_or(false))
+            .collect();
+        records
+    }
+
+    fn load_stat(&mut self, name: &str) -> Option<i32> {
+        stats::get_i32(name).ok()
+    }
+
+    fn store(&mut self, change: Change<'_>, _records: &Records) -> Result<(), String> {
+        let result = match change {
+            Change::Unlock(id) => achievements::unlock(id),
+            Change::Stat { name, value } => stats::set_i32(name, value),
+        };
+        result.map_err(steam_err)
+    }
+}
+
+/// Steam Cloud as a `modules::cloud_save` store. Steam keeps no per-file
+/// timestamps, so they live in a `cloud_manifest.json` next to the saves.
+///
+/// ```rust,ignore
+/// let mut local = modules::cloud_save::LocalSaveDir::new("user://saves");
+/// let report = modules::cloud_save::sync(&mut local, &mut steam::SteamCloudSave)?;
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SteamCloudSave;
+
+impl SteamCloudSave {
+    fn manifest(&self) -> Result<Timestamps, String> {
+        if !cloud::is_file_present(MANIFEST_NAME).map_err(steam_err)? {
+            return Ok(Timestamps::new());
+        }
+        let bytes = cloud::get_file_bytes(MANIFEST_NAME).map_err(steam_err)?;
+        Ok(decode_timestamps(&bytes))
+    }
+}
+
+impl CloudSave for SteamCloudSave {
+    fn list(&mut self) -> Result<Vec<CloudFile>, String> {
+        let manifest = self.manifest()?;
+        let files = cloud::get_files().map_err(steam_err)?;
+        Ok(files
+            .into_iter()
+            .filter(|file| file.name != MANIFEST_NAME)
+            .map(|file| CloudFile {
+                modified: manifest.get(&file.name).copied().unwrap_or(0),
+                name: file.name,
+            })
+            .collect())
+    }
+
+    fn upload(&mut self, name: &str, bytes: &[u8], modified: u64) -> Result<(), String> {
+        let mut manifest = self.manifest()?;
+        cloud::write(name, bytes).map_err(steam_err)?;
+        manifest.insert(name.to_string(), modified);
+        cloud::write(MANIFEST_NAME, &encode_timestamps(&manifest)).map_err(steam_err)
+    }
+
+    fn download(&mut self, name: &str) -> Result<Vec<u8>, String> {
+        cloud::get_file_bytes(name).map_err(steam_err)
+    }
+}
+
+fn steam_err(err: perro_steamworks::SteamError) -> String {
+    format!("steam: {err}")
+}
@@ -116,3 +116,30 @@ fn steam_achievement_macros_accept_single_multi_slice_and_vec() {
     assert_eq!(steam_p2p_read!(1024), Err(steam::SteamError::Disabled));
     assert_eq!(steam_events!(), Ok(Vec::new()));
 }
+
+#[test]
+fn steam_achievement_backend_reports_disabled_store() {
+    use perro_api::modules::achievements::{AchievementBackend, Change, Records};
+
+    let mut backend = steam::SteamAchievementBackend;
+    assert_eq!(backend.load(), Records::default());
+    assert_eq!(backend.load_stat("kills"), None);
+    assert!(
+        backend
+            .store(Change::Unlock("ACH_ONE"), &Records::default())
+            .is_err()
+    );
+}
+
+#[test]
+fn steam_cloud_save_reports_disabled_store() {
+    use perro_api::modules::cloud_save::{CloudSave, sync};
+
+    let mut cloud = steam::SteamCloudSave;
+    assert!(cloud.list().is_err());
+    assert!(cloud.download("slot_1.json").is_err());
+    assert!(cloud.upload("slot_1.json", b"{}", 1).is_err());
+
+    let mut other = steam::SteamCloudSave;
+    assert!(sync(&mut cloud, &mut other).is_err());
+}
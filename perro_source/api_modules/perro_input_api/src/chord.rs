@@ -0,0 +1,190 @@
+use crate::{KeyCode, KeyboardState};
+
+/// Modifier set required by a [`KeyChord`]. Left and right keys both count.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct KeyModifiers(u8);
+
+impl KeyModifiers {
+    pub const NONE: Self = Self(0);
+    pub const CTRL: Self = Self(1 << 0);
+    pub const SHIFT: Self = Self(1 << 1);
+    pub const ALT: Self = Self(1 << 2);
+    pub const SUPER: Self = Self(1 << 3);
+
+    const SIDES: [(Self, KeyCode, KeyCode); 4] = [
+        (Self::CTRL, KeyCode::ControlLeft, KeyCode::ControlRight),
+        (Self::SHIFT, KeyCode::ShiftLeft, KeyCode::ShiftRight),
+        (Self::ALT, KeyCode::AltLeft, KeyCode::AltRight),
+        (Self::SUPER, KeyCode::SuperLeft, KeyCode::SuperRight),
+    ];
+
+    #[inline]
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits & 0b1111)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Parse one modifier name (`Ctrl`, `Shift`, `Alt`, `Super` + aliases).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "Ctrl" | "Control" => Some(Self::CTRL),
+            "Shift" => Some(Self::SHIFT),
+            "Alt" | "Option" => Some(Self::ALT),
+            "Super" | "Meta" | "Cmd" => Some(Self::SUPER),
+            _ => None,
+        }
+    }
+
+    /// Return the modifier a physical key provides, or `NONE`.
+    #[inline]
+    pub fn of_key(key: KeyCode) -> Self {
+        Self::SIDES
+            .into_iter()
+            .find(|(_, left, right)| *left == key || *right == key)
+            .map_or(Self::NONE, |(flag, _, _)| flag)
+    }
+
+    /// Return every modifier with a side currently held.
+    #[inline]
+    pub fn held_in(keyboard: &KeyboardState) -> Self {
+        Self::SIDES
+            .into_iter()
+            .filter(|(_, left, right)| keyboard.is_key_down(*left) || keyboard.is_key_down(*right))
+            .fold(Self::NONE, |held, (flag, _, _)| held | flag)
+    }
+
+    /// Return canonical modifier names in `Ctrl+Shift+Alt+Super` order.
+    pub fn names(self) -> impl Iterator<Item = &'static str> {
+        ["Ctrl", "Shift", "Alt", "Super"]
+            .into_iter()
+            .zip(Self::SIDES)
+            .filter(move |(_, (flag, _, _))| self.contains(*flag))
+            .map(|(name, _)| name)
+    }
+
+    /// Return physical keys that satisfy these modifiers, left then right.
+    pub fn keys(self) -> impl Iterator<Item = KeyCode> {
+        self.sides().flat_map(|(left, right)| [left, right])
+    }
+
+    /// Return `true` while every required modifier has a side held.
+    #[inline]
+    pub fn held(self, keyboard: &KeyboardState) -> bool {
+        self.sides()
+            .all(|(left, right)| keyboard.is_key_down(left) || keyboard.is_key_down(right))
+    }
+
+    #[inline]
+    fn sides(self) -> impl Iterator<Item = (KeyCode, KeyCode)> {
+        Self::SIDES
+            .into_iter()
+            .filter(move |(flag, _, _)| self.contains(*flag))
+            .map(|(_, left, right)| (left, right))
+    }
+
+    #[inline]
+    fn held_or_released(self, keyboard: &KeyboardState) -> bool {
+        self.sides().all(|(left, right)| {
+            [left, right]
+                .into_iter()
+                .any(|key| keyboard.is_key_down(key) || keyboard.is_key_released(key))
+        })
+    }
+
+    #[inline]
+    fn any_let_go(self, keyboard: &KeyboardState) -> bool {
+        self.sides().any(|(left, right)| {
+            !keyboard.is_key_down(left)
+                && !keyboard.is_key_down(right)
+                && (keyboard.is_key_released(left) || keyboard.is_key_released(right))
+        })
+    }
+}
+
+impl std::ops::BitOr for KeyModifiers {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+/// Key plus held modifiers, e.g. `Ctrl+KeyS`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub modifiers: KeyModifiers,
+    pub key: KeyCode,
+}
+
+impl KeyChord {
+    #[inline]
+    pub const fn new(modifiers: KeyModifiers, key: KeyCode) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Parse `Mod+Mod+Key`, e.g. `Ctrl+Shift+KeyZ`. The key comes last and
+    /// uses [`KeyCode::from_name`] names.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let mut parts = name.split('+').rev();
+        let key = KeyCode::from_name(parts.next()?)?;
+        let mut modifiers = KeyModifiers::NONE;
+        for part in parts {
+            modifiers = modifiers | KeyModifiers::from_name(part)?;
+        }
+        Some(Self { modifiers, key })
+    }
+
+    /// Return the `Mod+Key` name accepted by [`KeyChord::from_name`].
+    pub fn to_name(self) -> String {
+        let mut name = String::new();
+        for modifier in self.modifiers.names() {
+            name.push_str(modifier);
+            name.push('+');
+        }
+        name.push_str(self.key.as_name());
+        name
+    }
+
+    /// Return `true` while the key and every modifier are held.
+    #[inline]
+    pub fn down(self, keyboard: &KeyboardState) -> bool {
+        keyboard.is_key_down(self.key) && self.modifiers.held(keyboard)
+    }
+
+    /// Return `true` when the key goes down this frame w/ modifiers held.
+    /// Pressing a modifier after the key does not fire the chord.
+    #[inline]
+    pub fn pressed(self, keyboard: &KeyboardState) -> bool {
+        keyboard.is_key_pressed(self.key) && self.modifiers.held(keyboard)
+    }
+
+    /// Return `true` when a held chord breaks this frame, by letting go of
+    /// either the key or a modifier.
+    #[inline]
+    pub fn released(self, keyboard: &KeyboardState) -> bool {
+        let key_released = keyboard.is_key_released(self.key);
+        (keyboard.is_key_down(self.key) || key_released)
+            && self.modifiers.held_or_released(keyboard)
+            && (key_released || self.modifiers.any_let_go(keyboard))
+    }
+}
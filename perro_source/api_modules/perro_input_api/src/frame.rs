@@ -1,6 +1,6 @@
 use crate::{
     GamepadAxis, GamepadButton, InputSnapshot, JoyConButton, JoyConSide, KeyCode, MouseButton,
-    MouseMode, PlayerBinding,
+    MouseMode, PlayerBinding, TouchPhase, TouchPoint,
 };
 use perro_structs::SignedUnitVector2;
 use std::collections::VecDeque;
@@ -33,6 +33,10 @@ pub enum InputEvent {
         width: u32,
         height: u32,
     },
+    Touch {
+        phase: TouchPhase,
+        point: TouchPoint,
+    },
     GamepadButton {
         index: usize,
         button: GamepadButton,
@@ -143,6 +147,89 @@ impl InputFrame {
     }
 }
 
+/// Most events an [`InputEventLog`] holds between drains. Later events are
+/// counted as dropped until the runtime drains the log.
+pub const INPUT_EVENT_LOG_CAPACITY: usize = 1024;
+
+/// Raw device event w/ its arrival time, recorded for `on_input` scripts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimedInputEvent {
+    /// Seconds on the runtime input clock when the event arrived.
+    pub time: f64,
+    pub event: InputEvent,
+}
+
+/// Ordered, timestamped event record kept beside polled snapshot state.
+///
+/// Recording is off until the runtime enables it (a script opted into
+/// `on_input`), so games that only poll pay nothing. The runtime stamps
+/// [`Self::set_time`] before ingesting each device event.
+#[derive(Clone, Debug, Default)]
+pub struct InputEventLog {
+    events: Vec<TimedInputEvent>,
+    enabled: bool,
+    time: f64,
+    dropped_events: u64,
+}
+
+impl InputEventLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Turn recording on/off. Turning it off discards unread events.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if !enabled {
+            self.events.clear();
+            self.dropped_events = 0;
+        }
+        self.enabled = enabled;
+    }
+
+    /// Set the timestamp used for events recorded from now on.
+    #[inline]
+    pub fn set_time(&mut self, seconds: f64) {
+        self.time = seconds;
+    }
+
+    #[inline]
+    pub fn push(&mut self, event: InputEvent) {
+        if !self.enabled {
+            return;
+        }
+        if self.events.len() >= INPUT_EVENT_LOG_CAPACITY {
+            self.dropped_events = self.dropped_events.saturating_add(1);
+            return;
+        }
+        self.events.push(TimedInputEvent {
+            time: self.time,
+            event,
+        });
+    }
+
+    /// Move recorded events into `out` in arrival order. Returns how many
+    /// events were dropped since the last drain.
+    pub fn drain_into(&mut self, out: &mut Vec<TimedInputEvent>) -> u64 {
+        out.append(&mut self.events);
+        std::mem::take(&mut self.dropped_events)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
 pub struct InputRingBuffer {
     events: VecDeque<InputEvent>,
     capacity: usize,
@@ -204,6 +291,7 @@ fn apply_event(snapshot: &mut InputSnapshot, event: &InputEvent) {
         InputEvent::MousePosition { x, y } => snapshot.set_mouse_position(*x, *y),
         InputEvent::MouseMode(mode) => snapshot.set_mouse_mode_state(*mode),
         InputEvent::ViewportSize { width, height } => snapshot.set_viewport_size(*width, *height),
+        InputEvent::Touch { phase, point } => snapshot.set_touch(*phase, *point),
         InputEvent::GamepadButton {
             index,
             button,
@@ -273,6 +361,58 @@ mod tests {
         assert!(ring.is_empty());
     }
 
+    #[test]
+    fn event_log_records_timestamped_transitions_only_when_enabled() {
+        let mut snapshot = InputSnapshot::new();
+        snapshot.set_key_state(KeyCode::KeyA, true);
+        assert!(snapshot.event_log().is_empty());
+
+        snapshot.set_event_log_enabled(true);
+        snapshot.set_event_time(1.5);
+        snapshot.set_key_state(KeyCode::KeyA, true); // repeat, no transition
+        snapshot.set_key_state(KeyCode::KeyA, false);
+        snapshot.set_event_time(1.75);
+        snapshot.add_mouse_wheel(0.0, -1.0);
+        snapshot.set_mouse_position(4.0, 8.0);
+
+        let mut events = Vec::new();
+        assert_eq!(snapshot.drain_input_events_into(&mut events), 0);
+        assert_eq!(
+            events,
+            vec![
+                TimedInputEvent {
+                    time: 1.5,
+                    event: InputEvent::Key {
+                        key: KeyCode::KeyA,
+                        is_down: false,
+                    },
+                },
+                TimedInputEvent {
+                    time: 1.75,
+                    event: InputEvent::MouseWheel { dx: 0.0, dy: -1.0 },
+                },
+                TimedInputEvent {
+                    time: 1.75,
+                    event: InputEvent::MousePosition { x: 4.0, y: 8.0 },
+                },
+            ]
+        );
+        assert!(snapshot.event_log().is_empty());
+    }
+
+    #[test]
+    fn event_log_counts_overflow_as_dropped() {
+        let mut log = InputEventLog::new();
+        log.set_enabled(true);
+        for _ in 0..INPUT_EVENT_LOG_CAPACITY + 3 {
+            log.push(InputEvent::MouseDelta { dx: 1.0, dy: 0.0 });
+        }
+        let mut events = Vec::new();
+        assert_eq!(log.drain_into(&mut events), 3);
+        assert_eq!(events.len(), INPUT_EVENT_LOG_CAPACITY);
+        assert_eq!(log.drain_into(&mut events), 0);
+    }
+
     #[test]
     fn frame_preserves_pressed_released_and_delta_semantics() {
         let mut snapshot = InputSnapshot::new();
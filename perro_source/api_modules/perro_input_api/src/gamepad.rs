@@ -52,6 +52,55 @@ impl GamepadButton {
             _ => None,
         }
     }
+
+    #[inline]
+    pub const fn as_name(self) -> &'static str {
+        match self {
+            Self::Bottom => "Bottom",
+            Self::Right => "Right",
+            Self::Left => "Left",
+            Self::Top => "Top",
+            Self::DpadUp => "DpadUp",
+            Self::DpadDown => "DpadDown",
+            Self::DpadLeft => "DpadLeft",
+            Self::DpadRight => "DpadRight",
+            Self::Start => "Start",
+            Self::Select => "Select",
+            Self::Home => "Home",
+            Self::Capture => "Capture",
+            Self::L1 => "L1",
+            Self::R1 => "R1",
+            Self::L2 => "L2",
+            Self::R2 => "R2",
+            Self::L3 => "L3",
+            Self::R3 => "R3",
+        }
+    }
+
+    /// Return an Xbox-layout label for controls menus, e.g. `A`, `LB`.
+    #[inline]
+    pub const fn display_name(self) -> &'static str {
+        match self {
+            Self::Bottom => "A",
+            Self::Right => "B",
+            Self::Left => "X",
+            Self::Top => "Y",
+            Self::DpadUp => "D-Pad Up",
+            Self::DpadDown => "D-Pad Down",
+            Self::DpadLeft => "D-Pad Left",
+            Self::DpadRight => "D-Pad Right",
+            Self::Start => "Menu",
+            Self::Select => "View",
+            Self::Home => "Guide",
+            Self::Capture => "Share",
+            Self::L1 => "LB",
+            Self::R1 => "RB",
+            Self::L2 => "LT",
+            Self::R2 => "RT",
+            Self::L3 => "LS",
+            Self::R3 => "RS",
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -81,6 +130,7 @@ pub struct GamepadState {
     axes: [f32; GamepadAxis::COUNT],
     gyro: perro_structs::Vector3,
     accel: perro_structs::Vector3,
+    rumble_supported: bool,
 }
 
 impl GamepadState {
@@ -94,6 +144,7 @@ impl GamepadState {
             axes: [0.0; GamepadAxis::COUNT],
             gyro: perro_structs::Vector3::new(0.0, 0.0, 0.0),
             accel: perro_structs::Vector3::new(0.0, 0.0, 0.0),
+            rumble_supported: false,
         }
     }
 
@@ -136,6 +187,11 @@ impl GamepadState {
         self.accel = perro_structs::Vector3::new(x, y, z);
     }
 
+    #[inline(always)]
+    pub fn set_rumble_supported(&mut self, supported: bool) {
+        self.rumble_supported = supported;
+    }
+
     #[inline(always)]
     pub fn is_button_down(&self, button: GamepadButton) -> bool {
         self.test(&self.buttons_down, button)
@@ -182,6 +238,12 @@ impl GamepadState {
         self.accel
     }
 
+    /// Return `true` when the backend reported force feedback for this pad.
+    #[inline(always)]
+    pub fn supports_rumble(&self) -> bool {
+        self.rumble_supported
+    }
+
     #[inline(always)]
     fn test(&self, bits: &[u64; GamepadState::BUTTON_WORDS], button: GamepadButton) -> bool {
         let idx = button.as_index();
@@ -1,6 +1,6 @@
 use crate::{
-    GamepadButton, GamepadState, JoyConButton, JoyConState, KeyCode, KeyboardState, MouseButton,
-    MouseState,
+    GamepadButton, GamepadState, JoyConButton, JoyConState, KeyChord, KeyCode, KeyboardState,
+    MouseButton, MouseState,
 };
 
 pub const fn action_hash(name: &str) -> u64 {
@@ -15,6 +15,11 @@ pub struct InputMap {
     mouse_actions: Vec<Vec<usize>>,
     gamepad_actions: Vec<Vec<usize>>,
     joycon_actions: Vec<Vec<usize>>,
+    /// active context hashes, bottom -> top
+    contexts: Vec<u64>,
+    /// per action, per binding: `false` when the context is off or a
+    /// consuming action in a higher context owns the binding
+    live: Vec<Vec<bool>>,
 }
 
 impl Default for InputMap {
@@ -31,6 +36,7 @@ impl InputMap {
     pub fn from_actions(mut actions: Vec<InputAction>) -> Self {
         for action in &mut actions {
             action.name_hash = action_hash(&action.name);
+            action.context_hash = context_hash(&action.context);
         }
         let mut input_map = Self {
             actions,
@@ -39,6 +45,8 @@ impl InputMap {
             mouse_actions: vec![Vec::new(); MouseButton::COUNT],
             gamepad_actions: vec![Vec::new(); GamepadButton::COUNT],
             joycon_actions: vec![Vec::new(); JoyConButton::COUNT],
+            contexts: Vec::new(),
+            live: Vec::new(),
         };
         input_map.rebuild_indexes();
         input_map
@@ -93,6 +101,139 @@ impl InputMap {
         true
     }
 
+    /// Return other actions that already fire from `binding`'s button.
+    /// Chords conflict w/ plain keys and chords on the same key.
+    pub fn conflicts(&self, name_hash: u64, binding: InputBinding) -> Vec<&InputAction> {
+        let trigger = binding.trigger();
+        self.actions
+            .iter()
+            .filter(|action| action.name_hash != name_hash)
+            .filter(|action| {
+                action
+                    .bindings
+                    .iter()
+                    .any(|other| other.trigger() == trigger)
+            })
+            .collect()
+    }
+
+    // ---- Saved bindings ----
+
+    /// Serialize every action's bindings, one `name = ["device:Name", ...]`
+    /// line per action. Contexts and consume flags stay project-owned.
+    pub fn bindings_to_settings(&self) -> String {
+        let mut out = String::new();
+        for action in &self.actions {
+            let bindings = action
+                .bindings
+                .iter()
+                .map(|binding| format!("\"{}\"", binding.setting_name()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            out.push_str(&format!("\"{}\" = [{bindings}]\n", action.name));
+        }
+        out
+    }
+
+    /// Apply bindings saved by [`InputMap::bindings_to_settings`].
+    ///
+    /// Unknown actions and unparsable bindings are skipped, so a settings file
+    /// from an older build never breaks startup. Returns actions applied.
+    pub fn apply_settings(&mut self, settings: &str) -> usize {
+        let mut applied = 0;
+        for line in settings.lines() {
+            let Some((name, list)) = line.split_once('=') else {
+                continue;
+            };
+            let name = name.trim().trim_matches('"');
+            let Some(index) = self.action_index(action_hash(name)) else {
+                continue;
+            };
+            let list = list.trim().trim_start_matches('[').trim_end_matches(']');
+            let bindings: Vec<InputBinding> = list
+                .split(',')
+                .filter_map(|raw| InputBinding::from_setting_name(raw.trim().trim_matches('"')))
+                .collect();
+            if bindings.is_empty() && !list.trim().is_empty() {
+                continue;
+            }
+            self.actions[index].bindings = bindings;
+            applied += 1;
+        }
+        if applied > 0 {
+            self.rebuild_indexes();
+        }
+        applied
+    }
+
+    // ---- Contexts ----
+
+    /// Return active context hashes, bottom to top.
+    #[inline]
+    pub fn contexts(&self) -> &[u64] {
+        &self.contexts
+    }
+
+    #[inline]
+    pub fn is_context_active(&self, name: &str) -> bool {
+        self.is_context_active_hash(context_hash(name))
+    }
+
+    #[inline]
+    pub fn is_context_active_hash(&self, hash: u64) -> bool {
+        self.contexts.contains(&hash)
+    }
+
+    /// Push a context on top. A context already on the stack moves to the top.
+    pub fn push_context(&mut self, name: &str) {
+        self.push_context_hash(context_hash(name));
+    }
+
+    pub fn push_context_hash(&mut self, hash: u64) {
+        if hash == 0 {
+            return;
+        }
+        self.contexts.retain(|active| *active != hash);
+        self.contexts.push(hash);
+        self.rebuild_routing();
+    }
+
+    /// Remove a context wherever it sits in the stack.
+    pub fn pop_context(&mut self, name: &str) -> bool {
+        self.pop_context_hash(context_hash(name))
+    }
+
+    pub fn pop_context_hash(&mut self, hash: u64) -> bool {
+        let Some(slot) = self.contexts.iter().rposition(|active| *active == hash) else {
+            return false;
+        };
+        self.contexts.remove(slot);
+        self.rebuild_routing();
+        true
+    }
+
+    /// Return `true` when the action's context is active and at least one of
+    /// its bindings is not consumed by a higher context.
+    #[inline]
+    pub fn is_action_live(&self, index: usize) -> bool {
+        self.live
+            .get(index)
+            .is_some_and(|live| live.iter().any(|live| *live))
+    }
+
+    /// Return bindings that may fire for an action under the context stack.
+    #[inline]
+    pub fn live_bindings(&self, index: usize) -> impl Iterator<Item = InputBinding> + '_ {
+        self.actions
+            .get(index)
+            .map(|action| action.bindings.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .zip(self.live.get(index).map(Vec::as_slice).unwrap_or_default())
+            .filter(|(_, live)| **live)
+            .map(|(binding, _)| *binding)
+    }
+
     #[inline]
     pub fn actions_for_key(&self, key: KeyCode) -> &[usize] {
         &self.key_actions[key.as_index()]
@@ -158,10 +299,8 @@ impl InputMap {
         gamepads: &[GamepadState],
         joycons: &[JoyConState],
     ) -> bool {
-        self.action_by_hash(name_hash).is_some_and(|action| {
-            action
-                .bindings
-                .iter()
+        self.action_index(name_hash).is_some_and(|index| {
+            self.live_bindings(index)
                 .any(|binding| binding.down(keyboard, mouse, gamepads, joycons))
         })
     }
@@ -175,10 +314,8 @@ impl InputMap {
         gamepads: &[GamepadState],
         joycons: &[JoyConState],
     ) -> bool {
-        self.action_by_hash(name_hash).is_some_and(|action| {
-            action
-                .bindings
-                .iter()
+        self.action_index(name_hash).is_some_and(|index| {
+            self.live_bindings(index)
                 .any(|binding| binding.pressed(keyboard, mouse, gamepads, joycons))
         })
     }
@@ -192,10 +329,8 @@ impl InputMap {
         gamepads: &[GamepadState],
         joycons: &[JoyConState],
     ) -> bool {
-        self.action_by_hash(name_hash).is_some_and(|action| {
-            action
-                .bindings
-                .iter()
+        self.action_index(name_hash).is_some_and(|index| {
+            self.live_bindings(index)
                 .any(|binding| binding.released(keyboard, mouse, gamepads, joycons))
         })
     }
@@ -225,6 +360,12 @@ impl InputMap {
             for binding in &action.bindings {
                 match binding {
                     InputBinding::Key(key) => self.key_actions[key.as_index()].push(index),
+                    InputBinding::Chord(chord) => {
+                        self.key_actions[chord.key.as_index()].push(index);
+                        for key in chord.modifiers.keys() {
+                            self.key_actions[key.as_index()].push(index);
+                        }
+                    }
                     InputBinding::Mouse(button) => self.mouse_actions[*button as usize].push(index),
                     InputBinding::Gamepad(button) => {
                         self.gamepad_actions[button.as_index()].push(index)
@@ -235,6 +376,60 @@ impl InputMap {
                 }
             }
         }
+        for actions in &mut self.key_actions {
+            actions.dedup();
+        }
+        self.rebuild_routing();
+    }
+
+    /// Recompute live bindings from the context stack and consume flags.
+    fn rebuild_routing(&mut self) {
+        // rank 0 = global, 1.. = stack slot + 1, None = context off
+        let rank = |action: &InputAction| -> Option<usize> {
+            if action.context_hash == 0 {
+                return Some(0);
+            }
+            self.contexts
+                .iter()
+                .position(|active| *active == action.context_hash)
+                .map(|slot| slot + 1)
+        };
+        let ranks: Vec<Option<usize>> = self.actions.iter().map(rank).collect();
+        let mut claims: Vec<(usize, InputBinding)> = Vec::new();
+        for (action, rank) in self.actions.iter().zip(&ranks) {
+            if let Some(rank) = rank
+                && action.consume
+            {
+                claims.extend(action.bindings.iter().map(|binding| (*rank, *binding)));
+            }
+        }
+        self.live = self
+            .actions
+            .iter()
+            .zip(&ranks)
+            .map(|(action, rank)| {
+                action
+                    .bindings
+                    .iter()
+                    .map(|binding| {
+                        rank.is_some_and(|rank| {
+                            !claims.iter().any(|(claim_rank, claim)| {
+                                *claim_rank > rank && claim.trigger() == binding.trigger()
+                            })
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+    }
+}
+
+/// Hash an action context name. Empty names map to `0` (global).
+pub const fn context_hash(name: &str) -> u64 {
+    if name.is_empty() {
+        0
+    } else {
+        action_hash(name)
     }
 }
 
@@ -243,6 +438,13 @@ pub struct InputAction {
     pub name: String,
     pub name_hash: u64,
     pub bindings: Vec<InputBinding>,
+    /// Context that must be on the stack for this action to fire. Empty =
+    /// global: always on, below every pushed context.
+    pub context: String,
+    pub context_hash: u64,
+    /// Claim this action's bindings so actions in lower contexts sharing the
+    /// same button never fire while this action's context is active.
+    pub consume: bool,
 }
 
 impl InputAction {
@@ -252,19 +454,88 @@ impl InputAction {
             name_hash: action_hash(&name),
             name,
             bindings,
+            context: String::new(),
+            context_hash: 0,
+            consume: false,
         }
     }
+
+    pub fn with_context(mut self, context: impl Into<String>) -> Self {
+        self.context = context.into();
+        self.context_hash = context_hash(&self.context);
+        self
+    }
+
+    pub fn with_consume(mut self, consume: bool) -> Self {
+        self.consume = consume;
+        self
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum InputBinding {
     Key(KeyCode),
+    Chord(KeyChord),
     Mouse(MouseButton),
     Gamepad(GamepadButton),
     JoyCon(JoyConButton),
 }
 
 impl InputBinding {
+    /// Return a label for controls menus, e.g. `Ctrl+S`, `Left Mouse`, `A`.
+    pub fn display_name(self) -> String {
+        match self {
+            Self::Key(key) => key.display_name().to_string(),
+            Self::Chord(chord) => {
+                let mut name = String::new();
+                for modifier in chord.modifiers.names() {
+                    name.push_str(modifier);
+                    name.push('+');
+                }
+                name.push_str(chord.key.display_name());
+                name
+            }
+            Self::Mouse(button) => button.display_name().to_string(),
+            Self::Gamepad(button) => button.display_name().to_string(),
+            Self::JoyCon(button) => button.display_name().to_string(),
+        }
+    }
+
+    /// Return the stable `device:Name` form used by saved bindings, e.g.
+    /// `key:Ctrl+KeyS`, `mouse:Left`, `gamepad:Bottom`.
+    pub fn setting_name(self) -> String {
+        match self {
+            Self::Key(key) => format!("key:{}", key.as_name()),
+            Self::Chord(chord) => format!("key:{}", chord.to_name()),
+            Self::Mouse(button) => format!("mouse:{}", button.as_name()),
+            Self::Gamepad(button) => format!("gamepad:{}", button.as_name()),
+            Self::JoyCon(button) => format!("joycon:{}", button.as_name()),
+        }
+    }
+
+    /// Parse the form produced by [`InputBinding::setting_name`].
+    pub fn from_setting_name(raw: &str) -> Option<Self> {
+        let (device, name) = raw.trim().split_once(':')?;
+        match device {
+            "key" if name.contains('+') => KeyChord::from_name(name).map(Self::Chord),
+            "key" => KeyCode::from_name(name).map(Self::Key),
+            "mouse" => MouseButton::from_name(name).map(Self::Mouse),
+            "gamepad" => GamepadButton::from_name(name).map(Self::Gamepad),
+            "joycon" => JoyConButton::from_name(name).map(Self::JoyCon),
+            _ => None,
+        }
+    }
+
+    /// Return the binding w/o chord modifiers; consume claims match on this,
+    /// so consuming `Escape` also blocks `Shift+Escape` below.
+    #[inline]
+    fn trigger(self) -> Self {
+        match self {
+            Self::Chord(chord) => Self::Key(chord.key),
+            other => other,
+        }
+    }
+
     #[inline]
     fn down(
         self,
@@ -275,6 +546,7 @@ impl InputBinding {
     ) -> bool {
         match self {
             Self::Key(key) => keyboard.is_key_down(key),
+            Self::Chord(chord) => chord.down(keyboard),
             Self::Mouse(button) => mouse.is_button_down(button),
             Self::Gamepad(button) => gamepads.iter().any(|pad| pad.is_button_down(button)),
             Self::JoyCon(button) => joycons.iter().any(|joycon| joycon.is_button_down(button)),
@@ -291,6 +563,7 @@ impl InputBinding {
     ) -> bool {
         match self {
             Self::Key(key) => keyboard.is_key_pressed(key),
+            Self::Chord(chord) => chord.pressed(keyboard),
             Self::Mouse(button) => mouse.is_button_pressed(button),
             Self::Gamepad(button) => gamepads.iter().any(|pad| pad.is_button_pressed(button)),
             Self::JoyCon(button) => joycons
@@ -309,6 +582,7 @@ impl InputBinding {
     ) -> bool {
         match self {
             Self::Key(key) => keyboard.is_key_released(key),
+            Self::Chord(chord) => chord.released(keyboard),
             Self::Mouse(button) => mouse.is_button_released(button),
             Self::Gamepad(button) => gamepads.iter().any(|pad| pad.is_button_released(button)),
             Self::JoyCon(button) => joycons
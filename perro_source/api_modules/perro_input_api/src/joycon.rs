@@ -70,6 +70,41 @@ impl JoyConButton {
             _ => None,
         }
     }
+
+    #[inline]
+    pub const fn as_name(self) -> &'static str {
+        match self {
+            Self::Top => "Top",
+            Self::Bottom => "Bottom",
+            Self::Left => "Left",
+            Self::Right => "Right",
+            Self::Bumper => "Bumper",
+            Self::Trigger => "Trigger",
+            Self::Stick => "Stick",
+            Self::SL => "SL",
+            Self::SR => "SR",
+            Self::Start => "Start",
+            Self::Meta => "Meta",
+        }
+    }
+
+    /// Return a label naming both sides, e.g. `Up / X`, `L / R`.
+    #[inline]
+    pub const fn display_name(self) -> &'static str {
+        match self {
+            Self::Top => "Up / X",
+            Self::Bottom => "Down / B",
+            Self::Left => "Left / Y",
+            Self::Right => "Right / A",
+            Self::Bumper => "L / R",
+            Self::Trigger => "ZL / ZR",
+            Self::Stick => "Stick Press",
+            Self::SL => "SL",
+            Self::SR => "SR",
+            Self::Start => "- / +",
+            Self::Meta => "Capture / Home",
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -95,6 +130,7 @@ pub struct JoyConState {
     mouse_sensor: JoyConMouseSensor,
     gyro: perro_structs::Vector3,
     accel: perro_structs::Vector3,
+    rumble_supported: bool,
 }
 
 impl JoyConState {
@@ -115,6 +151,7 @@ impl JoyConState {
             mouse_sensor: JoyConMouseSensor::default(),
             gyro: perro_structs::Vector3::new(0.0, 0.0, 0.0),
             accel: perro_structs::Vector3::new(0.0, 0.0, 0.0),
+            rumble_supported: false,
         }
     }
 
@@ -145,6 +182,11 @@ impl JoyConState {
         self.connected = connected;
     }
 
+    #[inline(always)]
+    pub fn set_rumble_supported(&mut self, supported: bool) {
+        self.rumble_supported = supported;
+    }
+
     #[inline(always)]
     pub fn set_calibrated(&mut self, calibrated: bool) {
         self.calibrated = calibrated;
@@ -248,6 +290,12 @@ impl JoyConState {
         self.connected
     }
 
+    /// Return `true` when the connection exposes a rumble output.
+    #[inline(always)]
+    pub fn supports_rumble(&self) -> bool {
+        self.rumble_supported
+    }
+
     #[inline(always)]
     pub fn calibrated(&self) -> bool {
         self.calibrated
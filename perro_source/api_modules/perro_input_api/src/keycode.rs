@@ -422,6 +422,46 @@ impl KeyCode {
         KEY_NAMES[self.as_index()]
     }
 
+    /// Return a short label for controls menus, e.g. `S`, `1`, `Left Ctrl`.
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Self::Backquote => "`",
+            Self::Backslash => "\\",
+            Self::BracketLeft => "[",
+            Self::BracketRight => "]",
+            Self::Comma => ",",
+            Self::Equal => "=",
+            Self::Minus => "-",
+            Self::Period => ".",
+            Self::Quote => "'",
+            Self::Semicolon => ";",
+            Self::Slash => "/",
+            Self::AltLeft => "Left Alt",
+            Self::AltRight => "Right Alt",
+            Self::ControlLeft => "Left Ctrl",
+            Self::ControlRight => "Right Ctrl",
+            Self::ShiftLeft => "Left Shift",
+            Self::ShiftRight => "Right Shift",
+            Self::SuperLeft => "Left Super",
+            Self::SuperRight => "Right Super",
+            Self::CapsLock => "Caps Lock",
+            Self::PageDown => "Page Down",
+            Self::PageUp => "Page Up",
+            Self::ArrowDown => "Down",
+            Self::ArrowLeft => "Left",
+            Self::ArrowRight => "Right",
+            Self::ArrowUp => "Up",
+            Self::Escape => "Esc",
+            Self::PrintScreen => "Print Screen",
+            _ => {
+                let name = self.as_name();
+                name.strip_prefix("Key")
+                    .or_else(|| name.strip_prefix("Digit"))
+                    .unwrap_or(name)
+            }
+        }
+    }
+
     #[inline]
     pub fn from_index(index: usize) -> Option<Self> {
         if index < Self::COUNT {
@@ -6,6 +6,7 @@
 
 // ---- Device state modules ----
 
+mod chord;
 mod frame;
 mod gamepad;
 mod input_map;
@@ -16,17 +17,20 @@ mod mouse_button;
 mod player;
 mod snapshot;
 mod state;
+mod touch;
 mod types;
 mod window;
 
 // ---- Public device/state exports ----
 
+pub use chord::{KeyChord, KeyModifiers};
 pub use frame::*;
 pub use gamepad::{GamepadAxis, GamepadButton, GamepadState};
-pub use input_map::{InputAction, InputBinding, InputMap, action_hash};
+pub use input_map::{InputAction, InputBinding, InputMap, action_hash, context_hash};
 pub use joycon::{JoyConButton, JoyConMouseSensor, JoyConSide, JoyConState};
 pub use keycode::KeyCode;
 pub use mouse_button::MouseButton;
+use perro_ids::{NodeID, ScriptMemberID};
 use perro_structs::Vector2;
 pub use perro_structs::{SignedUnit, SignedUnitVector2};
 // macro expansions run in user script crates that only depend on perro_api,
@@ -37,6 +41,8 @@ pub use player::{PlayerBinding, PlayerModule, PlayerState};
 pub use snapshot::*;
 pub use state::*;
 use std::cell::RefCell;
+use std::time::Duration;
+pub use touch::{TouchPhase, TouchPoint, TouchState};
 pub use types::*;
 pub use window::*;
 
@@ -48,12 +54,15 @@ pub mod prelude {
     pub use crate::{
         ActionModule, GamepadAxis, GamepadButton, GamepadIndex, GamepadModule, GamepadState,
         InputAPI, InputAction, InputBinding, InputMap, InputSnapshot, InputWindow, JoyConButton,
-        JoyConIndex, JoyConModule, JoyConMouseSensor, JoyConSide, JoyConState, KeyCode, KeyModule,
-        KeyboardModule, KeyboardState, MouseButton, MouseMode, MouseModule, MouseState,
-        MouseStateModule, PlayerBinding, PlayerIndicatorSlot, PlayerModule, PlayerState,
-        RumbleIntensity, action_cancel_rebind, action_down, action_hash, action_is_rebinding,
-        action_pressed, action_rebind_result, action_released, action_start_rebind, gamepad_accel,
-        gamepad_down, gamepad_get, gamepad_gyro, gamepad_left_stick, gamepad_list, gamepad_pressed,
+        JoyConIndex, JoyConModule, JoyConMouseSensor, JoyConSide, JoyConState, KeyChord, KeyCode,
+        KeyModifiers, KeyModule, KeyboardModule, KeyboardState, MouseButton, MouseMode,
+        MouseModule, MouseState, MouseStateModule, PlayerBinding, PlayerIndicatorSlot,
+        PlayerModule, PlayerState, RebindCallback, RebindResult, RumbleDevice, RumbleIntensity,
+        TouchModule, TouchPhase, TouchPoint, TouchState, action_binding_names,
+        action_cancel_rebind, action_context_active, action_down, action_hash, action_is_rebinding,
+        action_pop_context, action_pressed, action_push_context, action_rebind_result,
+        action_released, action_reset_bindings, action_start_rebind, gamepad_accel, gamepad_down,
+        gamepad_get, gamepad_gyro, gamepad_left_stick, gamepad_list, gamepad_pressed,
         gamepad_released, gamepad_right_stick, gamepad_set_rumble, joycon_accel, joycon_calibrated,
         joycon_calibrating, joycon_calibration_bias, joycon_connected, joycon_down,
         joycon_ensure_calibration, joycon_get, joycon_gyro, joycon_list, joycon_mouse_sensor,
@@ -61,7 +70,8 @@ pub mod prelude {
         joycon_set_indicator, joycon_set_rumble, joycon_side, joycon_stick, key_down, key_pressed,
         key_released, mouse_capture, mouse_confine, mouse_confine_hidden, mouse_delta, mouse_down,
         mouse_hide, mouse_mode, mouse_position, mouse_pressed, mouse_released, mouse_set_mode,
-        mouse_show, mouse_wheel, player_bind, player_get, player_list, viewport_size,
+        mouse_show, mouse_wheel, player_bind, player_get, player_list, rumble, rumble_supported,
+        touch_count, touch_get, touch_list, viewport_size,
     };
     pub use perro_structs::{SignedUnit, SignedUnitVector2, Unit, UnitVector2, Vector2};
 }
@@ -104,8 +104,18 @@ macro_rules! action_released {
 }
 
 #[macro_export]
-/// Start native live rebinding for an action name.
+/// Start native live rebinding for an action name. Optional `script, method`
+/// args name a callback run when the rebind completes.
 macro_rules! action_start_rebind {
+    ($ipt:expr, $action:literal, $script:expr, $method:expr) => {{
+        const __PERRO_ACTION_HASH: u64 = $crate::action_hash($action);
+        $ipt.Actions()
+            .start_rebind_hash_with(__PERRO_ACTION_HASH, $script, $method)
+    }};
+    ($ipt:expr, $action:expr, $script:expr, $method:expr) => {
+        $ipt.Actions()
+            .start_rebind_hash_with($crate::action_hash($action), $script, $method)
+    };
     ($ipt:expr, $action:literal) => {{
         const __PERRO_ACTION_HASH: u64 = $crate::action_hash($action);
         $ipt.Actions().start_rebind_hash(__PERRO_ACTION_HASH)
@@ -134,6 +144,57 @@ macro_rules! action_rebind_result {
     ($ipt:expr) => {{ $ipt.Actions().rebind_result() }};
 }
 
+#[macro_export]
+/// Restore every action to the project's default bindings.
+macro_rules! action_reset_bindings {
+    ($ipt:expr) => {{ $ipt.Actions().reset_bindings() }};
+}
+
+#[macro_export]
+/// Return menu labels for an action's current bindings.
+macro_rules! action_binding_names {
+    ($ipt:expr, $action:expr) => {{ $ipt.Actions().binding_names($action) }};
+}
+
+#[macro_export]
+/// Queue pushing an action context (e.g. `"ui"`) on the context stack.
+macro_rules! action_push_context {
+    ($ipt:expr, $context:literal) => {{
+        const __PERRO_CONTEXT_HASH: u64 = $crate::context_hash($context);
+        $ipt.Actions().push_context_hash(__PERRO_CONTEXT_HASH)
+    }};
+    ($ipt:expr, $context:expr) => {
+        $ipt.Actions()
+            .push_context_hash($crate::context_hash($context))
+    };
+}
+
+#[macro_export]
+/// Queue removing an action context from the context stack.
+macro_rules! action_pop_context {
+    ($ipt:expr, $context:literal) => {{
+        const __PERRO_CONTEXT_HASH: u64 = $crate::context_hash($context);
+        $ipt.Actions().pop_context_hash(__PERRO_CONTEXT_HASH)
+    }};
+    ($ipt:expr, $context:expr) => {
+        $ipt.Actions()
+            .pop_context_hash($crate::context_hash($context))
+    };
+}
+
+#[macro_export]
+/// Check whether an action context is on the context stack.
+macro_rules! action_context_active {
+    ($ipt:expr, $context:literal) => {{
+        const __PERRO_CONTEXT_HASH: u64 = $crate::context_hash($context);
+        $ipt.Actions().context_active_hash(__PERRO_CONTEXT_HASH)
+    }};
+    ($ipt:expr, $context:expr) => {
+        $ipt.Actions()
+            .context_active_hash($crate::context_hash($context))
+    };
+}
+
 #[macro_export]
 /// Signature:
 /// - `mouse_down!(&InputWindow<_>, MouseButton) -> bool`
@@ -311,6 +372,23 @@ macro_rules! joycon_set_rumble {
     ($ipt:expr, $index:expr, $low:expr, $high:expr) => {{ $ipt.JoyCons().set_rumble($index, $low, $high) }};
 }
 
+#[macro_export]
+/// Signature:
+/// - `rumble!(&InputWindow<_>, impl Into<RumbleDevice>, f32, f32, f32) -> ()`
+///
+/// Usage:
+/// - `rumble!(ipt, RumbleDevice::Player(0), 0.4, 0.8, 0.25)`
+macro_rules! rumble {
+    ($ipt:expr, $device:expr, $low:expr, $high:expr, $duration:expr) => {{ $ipt.rumble($device, $low, $high, $duration) }};
+}
+
+#[macro_export]
+/// Signature:
+/// - `rumble_supported!(&InputWindow<_>, impl Into<RumbleDevice>) -> bool`
+macro_rules! rumble_supported {
+    ($ipt:expr, $device:expr) => {{ $ipt.supports_rumble($device) }};
+}
+
 #[macro_export]
 macro_rules! joycon_set_indicator {
     ($ipt:expr, $index:expr, $indicator:expr) => {{ $ipt.JoyCons().set_indicator($index, $indicator) }};
@@ -27,4 +27,27 @@ impl MouseButton {
             _ => None,
         }
     }
+
+    #[inline]
+    pub const fn as_name(self) -> &'static str {
+        match self {
+            Self::Left => "Left",
+            Self::Right => "Right",
+            Self::Middle => "Middle",
+            Self::Back => "Back",
+            Self::Forward => "Forward",
+        }
+    }
+
+    /// Return a short label for controls menus, e.g. `Left Mouse`.
+    #[inline]
+    pub const fn display_name(self) -> &'static str {
+        match self {
+            Self::Left => "Left Mouse",
+            Self::Right => "Right Mouse",
+            Self::Middle => "Middle Mouse",
+            Self::Back => "Mouse Back",
+            Self::Forward => "Mouse Forward",
+        }
+    }
 }
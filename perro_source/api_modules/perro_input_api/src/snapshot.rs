@@ -10,6 +10,9 @@ use super::*;
 pub struct InputSnapshot {
     keyboard: KeyboardState,
     mouse: MouseState,
+    touches: TouchState,
+    /// contact that drives emulated mouse input; first finger down wins
+    primary_touch: Option<u64>,
     gamepads: Vec<GamepadState>,
     joycons: Vec<JoyConState>,
     players: Vec<PlayerState>,
@@ -18,12 +21,20 @@ pub struct InputSnapshot {
     action_pressed: Vec<u64>,
     action_released: Vec<u64>,
     rebind_action: Option<u64>,
+    rebind_callback: Option<RebindCallback>,
+    /// modifier pressed alone during a rebind; binds on release
+    rebind_modifier: Option<KeyCode>,
     rebind_result: Option<RebindResult>,
+    completed_rebinds: Vec<(Option<RebindCallback>, RebindResult)>,
+    /// project bindings in settings form, for `ResetBindings`
+    default_bindings: String,
+    bindings_changed: bool,
     commands: RefCell<Vec<InputCommand>>,
     pending_mouse_mode: Option<MouseMode>,
     pending_gamepad_rumble: Vec<GamepadRumbleRequest>,
     pending_joycon_rumble: Vec<JoyConRumbleRequest>,
     pending_joycon_indicator: Vec<JoyConIndicatorRequest>,
+    event_log: InputEventLog,
 }
 
 impl InputSnapshot {
@@ -34,6 +45,8 @@ impl InputSnapshot {
         Self {
             keyboard: KeyboardState::new(),
             mouse: MouseState::new(),
+            touches: TouchState::new(),
+            primary_touch: None,
             gamepads: Vec::new(),
             joycons: Vec::new(),
             players: Vec::new(),
@@ -42,12 +55,18 @@ impl InputSnapshot {
             action_pressed: Vec::new(),
             action_released: Vec::new(),
             rebind_action: None,
+            rebind_callback: None,
+            rebind_modifier: None,
             rebind_result: None,
+            completed_rebinds: Vec::new(),
+            default_bindings: String::new(),
+            bindings_changed: false,
             commands: RefCell::new(Vec::new()),
             pending_mouse_mode: None,
             pending_gamepad_rumble: Vec::new(),
             pending_joycon_rumble: Vec::new(),
             pending_joycon_indicator: Vec::new(),
+            event_log: InputEventLog::new(),
         }
     }
 
@@ -60,6 +79,7 @@ impl InputSnapshot {
         self.apply_queued_commands();
         self.keyboard.begin_frame();
         self.mouse.begin_frame();
+        self.touches.begin_frame();
         for pad in &mut self.gamepads {
             pad.begin_frame();
         }
@@ -79,6 +99,8 @@ impl InputSnapshot {
     pub fn clear_keyboard_mouse_state(&mut self) {
         self.keyboard.clear();
         self.mouse.clear_buttons_and_motion();
+        self.touches.clear();
+        self.primary_touch = None;
         self.action_pressed.fill(0);
         self.action_released.fill(0);
         self.refresh_all_action_down();
@@ -89,9 +111,12 @@ impl InputSnapshot {
     /// Apply a key transition and refresh affected actions.
     #[inline]
     pub fn set_key_state(&mut self, key: KeyCode, is_down: bool) {
+        if self.keyboard.is_key_down(key) != is_down {
+            self.event_log.push(InputEvent::Key { key, is_down });
+        }
         self.keyboard.set_key_state(key, is_down);
-        if self.keyboard.is_key_pressed(key) {
-            self.capture_rebind(InputBinding::Key(key));
+        if self.rebind_action.is_some() {
+            self.capture_rebind_key(key, is_down);
         }
         self.refresh_key_actions(key);
     }
@@ -99,6 +124,10 @@ impl InputSnapshot {
     /// Add text input for this frame.
     #[inline]
     pub fn push_text_input(&mut self, text: impl Into<String>) {
+        let text = text.into();
+        if self.event_log.is_enabled() {
+            self.event_log.push(InputEvent::Text(text.clone()));
+        }
         self.keyboard.push_text_input(text);
     }
 
@@ -108,6 +137,24 @@ impl InputSnapshot {
         self.keyboard.text_inputs()
     }
 
+    /// Replace the in-progress IME composition.
+    #[inline]
+    pub fn set_ime_preedit(&mut self, text: impl Into<String>) {
+        self.keyboard.set_ime_preedit(text);
+    }
+
+    /// Return the in-progress IME composition, empty when not composing.
+    #[inline]
+    pub fn ime_preedit(&self) -> &str {
+        self.keyboard.ime_preedit()
+    }
+
+    /// Return `true` when the IME composition changed during this frame.
+    #[inline]
+    pub fn ime_preedit_changed(&self) -> bool {
+        self.keyboard.ime_preedit_changed()
+    }
+
     /// Return `true` while the key is held.
     #[inline]
     pub fn is_key_down(&self, key: KeyCode) -> bool {
@@ -131,6 +178,10 @@ impl InputSnapshot {
     /// Apply a mouse button transition and refresh affected actions.
     #[inline]
     pub fn set_mouse_button_state(&mut self, button: MouseButton, is_down: bool) {
+        if self.mouse.is_button_down(button) != is_down {
+            self.event_log
+                .push(InputEvent::MouseButton { button, is_down });
+        }
         self.mouse.set_button_state(button, is_down);
         if self.mouse.is_button_pressed(button) {
             self.capture_rebind(InputBinding::Mouse(button));
@@ -141,6 +192,7 @@ impl InputSnapshot {
     /// Add relative mouse movement in pixels for this frame.
     #[inline]
     pub fn add_mouse_delta(&mut self, dx: f32, dy: f32) {
+        self.event_log.push(InputEvent::MouseDelta { dx, dy });
         self.mouse.add_delta(dx, dy);
     }
 
@@ -153,15 +205,51 @@ impl InputSnapshot {
     /// Add mouse wheel movement for this frame.
     #[inline]
     pub fn add_mouse_wheel(&mut self, dx: f32, dy: f32) {
+        self.event_log.push(InputEvent::MouseWheel { dx, dy });
         self.mouse.add_wheel(dx, dy);
     }
 
     /// Set absolute mouse position in window pixels.
     #[inline]
     pub fn set_mouse_position(&mut self, x: f32, y: f32) {
+        self.event_log.push(InputEvent::MousePosition { x, y });
         self.mouse.set_position(x, y);
     }
 
+    // ---- Touch input ----
+
+    /// Apply a touch/pen contact update.
+    ///
+    /// The first contact down also drives the mouse (position + left button)
+    /// until it lifts, so mouse-driven UI controls work on touchscreens.
+    pub fn set_touch(&mut self, phase: TouchPhase, point: TouchPoint) {
+        self.event_log.push(InputEvent::Touch { phase, point });
+        self.touches.apply(phase, point);
+
+        if phase == TouchPhase::Started && self.primary_touch.is_none() {
+            self.primary_touch = Some(point.id);
+            self.set_mouse_position(point.position.x, point.position.y);
+            self.set_mouse_button_state(MouseButton::Left, true);
+            return;
+        }
+        if self.primary_touch != Some(point.id) {
+            return;
+        }
+        if phase != TouchPhase::Cancelled {
+            self.set_mouse_position(point.position.x, point.position.y);
+        }
+        if phase.is_end() {
+            self.primary_touch = None;
+            self.set_mouse_button_state(MouseButton::Left, false);
+        }
+    }
+
+    /// Return active touch contacts and this frame's start/end edges.
+    #[inline]
+    pub fn touches(&self) -> &TouchState {
+        &self.touches
+    }
+
     /// Set current mouse mode without queuing an output command.
     #[inline]
     pub fn set_mouse_mode_state(&mut self, mode: MouseMode) {
@@ -186,6 +274,38 @@ impl InputSnapshot {
         self.mouse.set_viewport_size(width, height);
     }
 
+    // ---- Event log ----
+
+    /// Record timestamped device events for `on_input` scripts.
+    #[inline]
+    pub fn set_event_log_enabled(&mut self, enabled: bool) {
+        self.event_log.set_enabled(enabled);
+    }
+
+    /// Return whether device events are being recorded.
+    #[inline]
+    pub fn event_log_enabled(&self) -> bool {
+        self.event_log.is_enabled()
+    }
+
+    /// Set the input clock time stamped on events recorded from now on.
+    #[inline]
+    pub fn set_event_time(&mut self, seconds: f64) {
+        self.event_log.set_time(seconds);
+    }
+
+    /// Return recorded events not yet drained.
+    #[inline]
+    pub fn event_log(&self) -> &InputEventLog {
+        &self.event_log
+    }
+
+    /// Move recorded events into `out`; returns events dropped on overflow.
+    #[inline]
+    pub fn drain_input_events_into(&mut self, out: &mut Vec<TimedInputEvent>) -> u64 {
+        self.event_log.drain_into(out)
+    }
+
     // ---- Read-only views ----
 
     /// Return all gamepad states.
@@ -213,13 +333,56 @@ impl InputSnapshot {
     }
 
     /// Replace the input map and rebuild all cached action state.
+    ///
+    /// The new map's bindings become the defaults `ResetBindings` restores.
     #[inline]
     pub fn set_input_map(&mut self, input_map: InputMap) {
+        self.default_bindings = input_map.bindings_to_settings();
         self.input_map = input_map;
         self.resize_action_bits();
         self.refresh_all_action_states();
     }
 
+    /// Apply saved player bindings on top of the current map. Defaults stay
+    /// untouched. Returns actions applied.
+    pub fn apply_binding_settings(&mut self, settings: &str) -> usize {
+        let applied = self.input_map.apply_settings(settings);
+        if applied > 0 {
+            self.refresh_all_action_states();
+        }
+        applied
+    }
+
+    /// Return `true` once after a rebind or reset changed bindings, so the
+    /// host can persist them.
+    #[inline]
+    pub fn take_bindings_changed(&mut self) -> bool {
+        std::mem::take(&mut self.bindings_changed)
+    }
+
+    /// Drain rebinds completed since the last call, w/ their callbacks.
+    #[inline]
+    pub fn take_completed_rebinds(&mut self) -> Vec<(Option<RebindCallback>, RebindResult)> {
+        std::mem::take(&mut self.completed_rebinds)
+    }
+
+    /// Push an action context immediately and refresh action state.
+    #[inline]
+    pub fn push_context(&mut self, name: &str) {
+        self.input_map.push_context(name);
+        self.refresh_all_action_states();
+    }
+
+    /// Remove an action context immediately and refresh action state.
+    #[inline]
+    pub fn pop_context(&mut self, name: &str) -> bool {
+        let popped = self.input_map.pop_context(name);
+        if popped {
+            self.refresh_all_action_states();
+        }
+        popped
+    }
+
     // ---- Mutable device slots ----
 
     /// Find the first Joy-Con with a matching side.
@@ -290,15 +453,38 @@ impl InputSnapshot {
                 InputCommand::BindPlayer { index, binding } => {
                     self.bind_player(index, binding);
                 }
-                InputCommand::StartRebind { action_hash } => {
+                InputCommand::StartRebind {
+                    action_hash,
+                    callback,
+                } => {
                     self.rebind_result = None;
+                    self.rebind_modifier = None;
                     self.rebind_action = self
                         .input_map
                         .action_by_hash(action_hash)
                         .map(|_| action_hash);
+                    self.rebind_callback = self.rebind_action.and(callback);
                 }
                 InputCommand::CancelRebind => {
                     self.rebind_action = None;
+                    self.rebind_callback = None;
+                    self.rebind_modifier = None;
+                }
+                InputCommand::ResetBindings => {
+                    let defaults = std::mem::take(&mut self.default_bindings);
+                    self.input_map.apply_settings(&defaults);
+                    self.default_bindings = defaults;
+                    self.bindings_changed = true;
+                    self.refresh_all_action_states();
+                }
+                InputCommand::PushContext { context_hash } => {
+                    self.input_map.push_context_hash(context_hash);
+                    self.refresh_all_action_states();
+                }
+                InputCommand::PopContext { context_hash } => {
+                    if self.input_map.pop_context_hash(context_hash) {
+                        self.refresh_all_action_states();
+                    }
                 }
                 InputCommand::RequestJoyConCalibration { index } => {
                     let state = self.joycon_mut(index);
@@ -309,12 +495,17 @@ impl InputSnapshot {
                     self.pending_mouse_mode = Some(mode);
                 }
                 InputCommand::SetGamepadRumble { index, rumble } => {
-                    self.pending_gamepad_rumble
-                        .push(GamepadRumbleRequest { index, rumble });
+                    self.queue_rumble(RumbleDevice::Gamepad(index), rumble, None);
                 }
                 InputCommand::SetJoyConRumble { index, rumble } => {
-                    self.pending_joycon_rumble
-                        .push(JoyConRumbleRequest { index, rumble });
+                    self.queue_rumble(RumbleDevice::JoyCon(index), rumble, None);
+                }
+                InputCommand::Rumble {
+                    device,
+                    rumble,
+                    duration,
+                } => {
+                    self.queue_rumble(device, rumble, duration);
                 }
                 InputCommand::SetJoyConIndicator { index, indicator } => {
                     self.pending_joycon_indicator
@@ -329,6 +520,13 @@ impl InputSnapshot {
     /// Apply a gamepad button transition and refresh affected actions.
     #[inline]
     pub fn set_gamepad_button_state(&mut self, index: usize, button: GamepadButton, is_down: bool) {
+        if self.gamepad_mut(index).is_button_down(button) != is_down {
+            self.event_log.push(InputEvent::GamepadButton {
+                index,
+                button,
+                is_down,
+            });
+        }
         self.gamepad_mut(index).set_button_state(button, is_down);
         if self.gamepads[index].is_button_pressed(button) {
             self.capture_rebind(InputBinding::Gamepad(button));
@@ -504,6 +702,54 @@ impl InputSnapshot {
         out
     }
 
+    /// Set whether the gamepad backend can drive force feedback.
+    #[inline]
+    pub fn set_gamepad_rumble_supported(&mut self, index: usize, supported: bool) {
+        self.gamepad_mut(index).set_rumble_supported(supported);
+    }
+
+    /// Set whether the Joy-Con connection exposes a rumble output.
+    #[inline]
+    pub fn set_joycon_rumble_supported(&mut self, index: usize, supported: bool) {
+        self.joycon_mut(index).set_rumble_supported(supported);
+    }
+
+    fn queue_rumble(
+        &mut self,
+        device: RumbleDevice,
+        rumble: RumbleIntensity,
+        duration: Option<Duration>,
+    ) {
+        let (gamepad, joycons) = match device {
+            RumbleDevice::Gamepad(index) => (Some(index), [None, None]),
+            RumbleDevice::JoyCon(index) => (None, [Some(index), None]),
+            RumbleDevice::Player(player) => {
+                match self.players.get(player).map(PlayerState::get_binding) {
+                    Some(PlayerBinding::Gamepad { index }) => (Some(index), [None, None]),
+                    Some(PlayerBinding::JoyConSingle { index }) => (None, [Some(index), None]),
+                    Some(PlayerBinding::JoyConPair { left, right }) => {
+                        (None, [Some(left), Some(right)])
+                    }
+                    _ => (None, [None, None]),
+                }
+            }
+        };
+        if let Some(index) = gamepad {
+            self.pending_gamepad_rumble.push(GamepadRumbleRequest {
+                index,
+                rumble,
+                duration,
+            });
+        }
+        for index in joycons.into_iter().flatten() {
+            self.pending_joycon_rumble.push(JoyConRumbleRequest {
+                index,
+                rumble,
+                duration,
+            });
+        }
+    }
+
     /// Drain pending gamepad rumble requests.
     #[inline]
     pub fn take_gamepad_rumble_requests(&mut self) -> Vec<GamepadRumbleRequest> {
@@ -562,20 +808,56 @@ impl InputSnapshot {
 
     // ---- Action cache maintenance ----
 
+    fn capture_rebind_key(&mut self, key: KeyCode, is_down: bool) {
+        if !KeyModifiers::of_key(key).is_empty() {
+            // bare modifier binds on release so Ctrl+key can still be chorded
+            if is_down {
+                if self.keyboard.is_key_pressed(key) {
+                    self.rebind_modifier = Some(key);
+                }
+            } else if self.rebind_modifier == Some(key) {
+                self.capture_rebind(InputBinding::Key(key));
+            }
+            return;
+        }
+        if !is_down || !self.keyboard.is_key_pressed(key) {
+            return;
+        }
+        let modifiers = KeyModifiers::held_in(&self.keyboard);
+        let binding = if modifiers.is_empty() {
+            InputBinding::Key(key)
+        } else {
+            InputBinding::Chord(KeyChord::new(modifiers, key))
+        };
+        self.capture_rebind(binding);
+    }
+
     fn capture_rebind(&mut self, binding: InputBinding) {
         let Some(action_hash) = self.rebind_action.take() else {
             return;
         };
+        let callback = self.rebind_callback.take();
+        self.rebind_modifier = None;
         let Some(action) = self.input_map.action_by_hash(action_hash) else {
             return;
         };
         let action = action.name.clone();
+        let conflicts = self
+            .input_map
+            .conflicts(action_hash, binding)
+            .into_iter()
+            .map(|action| action.name.clone())
+            .collect();
         self.input_map.set_bindings_hash(action_hash, vec![binding]);
-        self.rebind_result = Some(RebindResult {
+        let result = RebindResult {
             action,
             action_hash,
             binding,
-        });
+            conflicts,
+        };
+        self.completed_rebinds.push((callback, result.clone()));
+        self.rebind_result = Some(result);
+        self.bindings_changed = true;
         self.refresh_all_action_states();
     }
 
@@ -637,53 +919,56 @@ impl InputSnapshot {
     }
 
     fn compute_action_down(&self, action: usize) -> bool {
-        self.input_map.actions().get(action).is_some_and(|action| {
-            action.bindings.iter().any(|binding| match binding {
-                InputBinding::Key(key) => self.keyboard.is_key_down(*key),
-                InputBinding::Mouse(button) => self.mouse.is_button_down(*button),
+        self.input_map
+            .live_bindings(action)
+            .any(|binding| match binding {
+                InputBinding::Key(key) => self.keyboard.is_key_down(key),
+                InputBinding::Chord(chord) => chord.down(&self.keyboard),
+                InputBinding::Mouse(button) => self.mouse.is_button_down(button),
                 InputBinding::Gamepad(button) => {
-                    self.gamepads.iter().any(|pad| pad.is_button_down(*button))
+                    self.gamepads.iter().any(|pad| pad.is_button_down(button))
                 }
                 InputBinding::JoyCon(button) => self
                     .joycons
                     .iter()
-                    .any(|joycon| joycon.is_button_down(*button)),
+                    .any(|joycon| joycon.is_button_down(button)),
             })
-        })
     }
 
     fn compute_action_pressed(&self, action: usize) -> bool {
-        self.input_map.actions().get(action).is_some_and(|action| {
-            action.bindings.iter().any(|binding| match binding {
-                InputBinding::Key(key) => self.keyboard.is_key_pressed(*key),
-                InputBinding::Mouse(button) => self.mouse.is_button_pressed(*button),
+        self.input_map
+            .live_bindings(action)
+            .any(|binding| match binding {
+                InputBinding::Key(key) => self.keyboard.is_key_pressed(key),
+                InputBinding::Chord(chord) => chord.pressed(&self.keyboard),
+                InputBinding::Mouse(button) => self.mouse.is_button_pressed(button),
                 InputBinding::Gamepad(button) => self
                     .gamepads
                     .iter()
-                    .any(|pad| pad.is_button_pressed(*button)),
+                    .any(|pad| pad.is_button_pressed(button)),
                 InputBinding::JoyCon(button) => self
                     .joycons
                     .iter()
-                    .any(|joycon| joycon.is_button_pressed(*button)),
+                    .any(|joycon| joycon.is_button_pressed(button)),
             })
-        })
     }
 
     fn compute_action_released(&self, action: usize) -> bool {
-        self.input_map.actions().get(action).is_some_and(|action| {
-            action.bindings.iter().any(|binding| match binding {
-                InputBinding::Key(key) => self.keyboard.is_key_released(*key),
-                InputBinding::Mouse(button) => self.mouse.is_button_released(*button),
+        self.input_map
+            .live_bindings(action)
+            .any(|binding| match binding {
+                InputBinding::Key(key) => self.keyboard.is_key_released(key),
+                InputBinding::Chord(chord) => chord.released(&self.keyboard),
+                InputBinding::Mouse(button) => self.mouse.is_button_released(button),
                 InputBinding::Gamepad(button) => self
                     .gamepads
                     .iter()
-                    .any(|pad| pad.is_button_released(*button)),
+                    .any(|pad| pad.is_button_released(button)),
                 InputBinding::JoyCon(button) => self
                     .joycons
                     .iter()
-                    .any(|joycon| joycon.is_button_released(*button)),
+                    .any(|joycon| joycon.is_button_released(button)),
             })
-        })
     }
 }
 
@@ -721,6 +1006,16 @@ pub struct RebindResult {
     pub action: String,
     pub action_hash: u64,
     pub binding: InputBinding,
+    /// Other actions already bound to the captured button. The rebind still
+    /// applies; the options menu decides whether to warn, swap, or undo.
+    pub conflicts: Vec<String>,
+}
+
+/// Script method called when a live rebind completes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RebindCallback {
+    pub script: NodeID,
+    pub method: ScriptMemberID,
 }
 
 /// Input command queued by scripts and applied by the input backend.
@@ -732,9 +1027,18 @@ pub enum InputCommand {
         binding: PlayerBinding,
     },
     /// Listen for the next button press and replace an action's bindings.
-    StartRebind { action_hash: u64 },
+    StartRebind {
+        action_hash: u64,
+        callback: Option<RebindCallback>,
+    },
+    /// Restore the project's default bindings for every action.
+    ResetBindings,
     /// Stop an active live rebind.
     CancelRebind,
+    /// Push an action context on top of the context stack.
+    PushContext { context_hash: u64 },
+    /// Remove an action context from the context stack.
+    PopContext { context_hash: u64 },
     /// Request Joy-Con calibration for a slot.
     RequestJoyConCalibration { index: usize },
     /// Request mouse mode change.
@@ -749,6 +1053,12 @@ pub enum InputCommand {
         index: usize,
         rumble: RumbleIntensity,
     },
+    /// Request rumble on any device; `duration: None` holds until changed.
+    Rumble {
+        device: RumbleDevice,
+        rumble: RumbleIntensity,
+        duration: Option<Duration>,
+    },
     /// Request Joy-Con player indicator change.
     SetJoyConIndicator {
         index: usize,
@@ -765,6 +1075,8 @@ pub trait InputAPI {
     fn keyboard(&self) -> &KeyboardState;
     /// Return mouse state.
     fn mouse(&self) -> &MouseState;
+    /// Return touch/pen contact state.
+    fn touches(&self) -> &TouchState;
     /// Return gamepad states.
     fn gamepads(&self) -> &[GamepadState];
     /// Return Joy-Con states.
@@ -833,6 +1145,11 @@ impl InputAPI for InputSnapshot {
         &self.mouse
     }
 
+    #[inline]
+    fn touches(&self) -> &TouchState {
+        &self.touches
+    }
+
     #[inline]
     fn gamepads(&self) -> &[GamepadState] {
         self.gamepads()
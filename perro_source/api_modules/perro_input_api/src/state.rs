@@ -10,6 +10,8 @@ pub struct KeyboardState {
     pressed: Vec<u64>,
     released: Vec<u64>,
     text_inputs: Vec<String>,
+    ime_preedit: String,
+    ime_preedit_changed: bool,
 }
 
 impl KeyboardState {
@@ -23,6 +25,8 @@ impl KeyboardState {
             pressed: vec![0; words],
             released: vec![0; words],
             text_inputs: Vec::new(),
+            ime_preedit: String::new(),
+            ime_preedit_changed: false,
         }
     }
 
@@ -32,6 +36,7 @@ impl KeyboardState {
         self.pressed.fill(0);
         self.released.fill(0);
         self.text_inputs.clear();
+        self.ime_preedit_changed = false;
     }
 
     /// Release every held key and clear one-frame keyboard state.
@@ -41,6 +46,8 @@ impl KeyboardState {
         self.pressed.fill(0);
         self.released.fill(0);
         self.text_inputs.clear();
+        self.ime_preedit_changed = !self.ime_preedit.is_empty();
+        self.ime_preedit.clear();
     }
 
     // ---- Event input ----
@@ -99,6 +106,28 @@ impl KeyboardState {
         &self.text_inputs
     }
 
+    /// Replace the in-progress IME composition. Empty text ends composition.
+    #[inline]
+    pub fn set_ime_preedit(&mut self, text: impl Into<String>) {
+        let text = text.into();
+        if self.ime_preedit != text {
+            self.ime_preedit = text;
+            self.ime_preedit_changed = true;
+        }
+    }
+
+    /// Return the in-progress IME composition, empty when not composing.
+    #[inline]
+    pub fn ime_preedit(&self) -> &str {
+        &self.ime_preedit
+    }
+
+    /// Return `true` when the IME composition changed during the current frame.
+    #[inline]
+    pub fn ime_preedit_changed(&self) -> bool {
+        self.ime_preedit_changed
+    }
+
     // ---- Bit helpers ----
 
     #[inline]
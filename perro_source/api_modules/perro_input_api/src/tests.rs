@@ -1,12 +1,26 @@
 use crate::{
-    action_cancel_rebind, action_down, action_is_rebinding, action_pressed, action_rebind_result,
-    action_released, action_start_rebind, mouse_mode, mouse_set_mode,
+    action_binding_names, action_cancel_rebind, action_down, action_is_rebinding,
+    action_pop_context, action_pressed, action_push_context, action_rebind_result, action_released,
+    action_reset_bindings, action_start_rebind, mouse_mode, mouse_set_mode, touch_count, touch_get,
 };
 
 use super::{
     GamepadButton, InputAction, InputBinding, InputMap, InputSnapshot, InputWindow, JoyConButton,
-    KeyCode, MouseButton, MouseMode, action_hash,
+    KeyChord, KeyCode, KeyModifiers, MouseButton, MouseMode, PlayerBinding, RebindCallback,
+    RumbleDevice, TouchPhase, TouchPoint, action_hash,
 };
+use perro_ids::{NodeID, ScriptMemberID};
+use perro_structs::Vector2;
+use std::time::Duration;
+
+fn touch_point(id: u64, x: f32, y: f32) -> TouchPoint {
+    TouchPoint {
+        id,
+        position: Vector2::new(x, y),
+        pressure: 0.5,
+        altitude: None,
+    }
+}
 
 #[test]
 fn mouse_mode_defaults_visible() {
@@ -114,6 +128,22 @@ fn clear_keyboard_mouse_state_releases_stale_inputs() {
     assert_eq!(input.mouse_mode(), MouseMode::Captured);
 }
 
+#[test]
+fn ime_preedit_tracks_changes_per_frame() {
+    let mut input = InputSnapshot::new();
+    input.set_ime_preedit("ni");
+    assert_eq!(input.ime_preedit(), "ni");
+    assert!(input.ime_preedit_changed());
+
+    input.begin_frame();
+    input.set_ime_preedit("ni");
+    assert!(!input.ime_preedit_changed());
+
+    input.clear_keyboard_mouse_state();
+    assert_eq!(input.ime_preedit(), "");
+    assert!(input.ime_preedit_changed());
+}
+
 #[test]
 fn live_rebind_replaces_action_and_reports_result() {
     let mut input = InputSnapshot::new();
@@ -198,3 +228,314 @@ fn live_rebind_macros_queue_query_and_report() {
     input.apply_queued_commands();
     assert!(!action_is_rebinding!(InputWindow::new(&input)));
 }
+
+#[test]
+fn touch_tracks_contacts_and_frame_edges() {
+    let mut input = InputSnapshot::new();
+    input.set_touch(TouchPhase::Started, touch_point(1, 10.0, 20.0));
+    input.set_touch(TouchPhase::Started, touch_point(2, 30.0, 40.0));
+    input.set_touch(TouchPhase::Moved, touch_point(2, 35.0, 45.0));
+
+    let window = InputWindow::new(&input);
+    assert_eq!(touch_count!(&window), 2);
+    assert_eq!(
+        touch_get!(&window, 2).map(|point| point.position),
+        Some(Vector2::new(35.0, 45.0))
+    );
+    assert_eq!(window.Touches().started(), &[1, 2]);
+
+    input.begin_frame();
+    input.set_touch(TouchPhase::Ended, touch_point(1, 10.0, 20.0));
+    let window = InputWindow::new(&input);
+    assert_eq!(touch_count!(&window), 1);
+    assert!(window.Touches().started().is_empty());
+    assert_eq!(window.Touches().ended(), &[1]);
+}
+
+#[test]
+fn primary_touch_emulates_left_mouse() {
+    let mut input = InputSnapshot::new();
+    input.set_viewport_size(160, 160);
+    input.set_touch(TouchPhase::Started, touch_point(7, 10.0, 20.0));
+    input.set_touch(TouchPhase::Started, touch_point(8, 90.0, 90.0));
+
+    // mouse position is viewport-normalized, bottom-left origin
+    assert!(input.is_mouse_down(MouseButton::Left));
+    assert_eq!(input.mouse_position(), Vector2::new(0.0625, 0.875));
+
+    input.set_touch(TouchPhase::Moved, touch_point(8, 95.0, 95.0));
+    input.set_touch(TouchPhase::Moved, touch_point(7, 15.0, 25.0));
+    assert_eq!(input.mouse_position(), Vector2::new(0.09375, 0.84375));
+
+    input.set_touch(TouchPhase::Ended, touch_point(8, 95.0, 95.0));
+    assert!(input.is_mouse_down(MouseButton::Left));
+    input.set_touch(TouchPhase::Ended, touch_point(7, 15.0, 25.0));
+    assert!(!input.is_mouse_down(MouseButton::Left));
+}
+
+#[test]
+fn chord_needs_modifier_and_releases_when_broken() {
+    let mut input = InputSnapshot::new();
+    input.set_input_map(InputMap::from_actions(vec![InputAction::new(
+        "save",
+        vec![InputBinding::Chord(KeyChord::new(
+            KeyModifiers::CTRL,
+            KeyCode::KeyS,
+        ))],
+    )]));
+
+    input.set_key_state(KeyCode::KeyS, true);
+    assert!(!input.is_action_down_hash(action_hash("save")));
+    input.set_key_state(KeyCode::KeyS, false);
+
+    input.begin_frame();
+    input.set_key_state(KeyCode::ControlRight, true);
+    input.set_key_state(KeyCode::KeyS, true);
+    assert!(input.is_action_pressed_hash(action_hash("save")));
+    assert!(input.is_action_down_hash(action_hash("save")));
+
+    input.begin_frame();
+    input.set_key_state(KeyCode::ControlRight, false);
+    assert!(!input.is_action_down_hash(action_hash("save")));
+    assert!(input.is_action_released_hash(action_hash("save")));
+}
+
+#[test]
+fn chord_names_roundtrip() {
+    let chord = KeyChord::from_name("Shift+Ctrl+KeyZ").expect("chord");
+
+    assert_eq!(chord.modifiers, KeyModifiers::CTRL | KeyModifiers::SHIFT);
+    assert_eq!(chord.key, KeyCode::KeyZ);
+    assert_eq!(chord.to_name(), "Ctrl+Shift+KeyZ");
+    assert!(KeyChord::from_name("Hyper+KeyZ").is_none());
+}
+
+#[test]
+fn context_actions_fire_only_while_pushed() {
+    let mut input = InputSnapshot::new();
+    input.set_input_map(InputMap::from_actions(vec![
+        InputAction::new("jump", vec![InputBinding::Key(KeyCode::Space)]).with_context("gameplay"),
+    ]));
+
+    input.set_key_state(KeyCode::Space, true);
+    assert!(!input.is_action_down_hash(action_hash("jump")));
+
+    let window = InputWindow::new(&input);
+    action_push_context!(&window, "gameplay");
+    input.begin_frame();
+    assert!(input.is_action_down_hash(action_hash("jump")));
+
+    let window = InputWindow::new(&input);
+    action_pop_context!(&window, "gameplay");
+    input.begin_frame();
+    assert!(!input.is_action_down_hash(action_hash("jump")));
+}
+
+#[test]
+fn consuming_action_blocks_lower_contexts() {
+    let mut input = InputSnapshot::new();
+    input.set_input_map(InputMap::from_actions(vec![
+        InputAction::new("pause", vec![InputBinding::Key(KeyCode::Escape)]),
+        InputAction::new(
+            "fire",
+            vec![
+                InputBinding::Key(KeyCode::Enter),
+                InputBinding::Mouse(MouseButton::Left),
+            ],
+        )
+        .with_context("gameplay"),
+        InputAction::new("ui_back", vec![InputBinding::Key(KeyCode::Escape)])
+            .with_context("ui")
+            .with_consume(true),
+        InputAction::new("ui_accept", vec![InputBinding::Key(KeyCode::Enter)])
+            .with_context("ui")
+            .with_consume(true),
+    ]));
+    input.push_context("gameplay");
+    input.push_context("ui");
+
+    input.set_key_state(KeyCode::Escape, true);
+    input.set_key_state(KeyCode::Enter, true);
+    input.set_mouse_button_state(MouseButton::Left, true);
+
+    assert!(input.is_action_pressed_hash(action_hash("ui_back")));
+    assert!(input.is_action_pressed_hash(action_hash("ui_accept")));
+    assert!(!input.is_action_pressed_hash(action_hash("pause")));
+    assert!(input.is_action_pressed_hash(action_hash("fire")));
+
+    assert!(input.pop_context("ui"));
+    assert!(input.is_action_down_hash(action_hash("pause")));
+    assert!(!input.is_action_down_hash(action_hash("ui_back")));
+}
+
+#[test]
+fn live_rebind_captures_chord_while_modifier_held() {
+    let mut input = InputSnapshot::new();
+    input.set_input_map(InputMap::from_actions(vec![InputAction::new(
+        "save",
+        vec![InputBinding::Key(KeyCode::F5)],
+    )]));
+
+    InputWindow::new(&input).Actions().start_rebind("save");
+    input.apply_queued_commands();
+    input.set_key_state(KeyCode::ControlLeft, true);
+    assert!(input.is_rebinding());
+    input.set_key_state(KeyCode::KeyS, true);
+
+    let chord = InputBinding::Chord(KeyChord::new(KeyModifiers::CTRL, KeyCode::KeyS));
+    assert_eq!(
+        input.rebind_result().map(|result| result.binding),
+        Some(chord)
+    );
+    assert_eq!(chord.display_name(), "Ctrl+S");
+    assert!(input.is_action_down_hash(action_hash("save")));
+    assert!(input.take_bindings_changed());
+    assert!(!input.take_bindings_changed());
+}
+
+#[test]
+fn live_rebind_binds_bare_modifier_on_release() {
+    let mut input = InputSnapshot::new();
+    input.set_input_map(InputMap::from_actions(vec![InputAction::new(
+        "crouch",
+        vec![InputBinding::Key(KeyCode::KeyC)],
+    )]));
+
+    InputWindow::new(&input).Actions().start_rebind("crouch");
+    input.apply_queued_commands();
+    input.set_key_state(KeyCode::ShiftLeft, true);
+    assert!(input.is_rebinding());
+    input.set_key_state(KeyCode::ShiftLeft, false);
+
+    assert!(!input.is_rebinding());
+    assert_eq!(
+        input.rebind_result().map(|result| result.binding),
+        Some(InputBinding::Key(KeyCode::ShiftLeft))
+    );
+}
+
+#[test]
+fn live_rebind_reports_conflicts_and_callback() {
+    let mut input = InputSnapshot::new();
+    input.set_input_map(InputMap::from_actions(vec![
+        InputAction::new("jump", vec![InputBinding::Key(KeyCode::Space)]),
+        InputAction::new("fire", vec![InputBinding::Mouse(MouseButton::Left)]),
+        InputAction::new(
+            "quick_save",
+            vec![InputBinding::Chord(KeyChord::new(
+                KeyModifiers::CTRL,
+                KeyCode::Space,
+            ))],
+        ),
+    ]));
+    let window = InputWindow::new(&input);
+    assert_eq!(
+        window
+            .Actions()
+            .conflicts("fire", InputBinding::Key(KeyCode::Space)),
+        vec!["jump", "quick_save"]
+    );
+
+    let script = NodeID::from_parts(3, 0);
+    let method = ScriptMemberID::from_string("on_rebound");
+    InputWindow::new(&input)
+        .Actions()
+        .start_rebind_with("fire", script, method);
+    input.apply_queued_commands();
+    input.set_key_state(KeyCode::Space, true);
+
+    let completed = input.take_completed_rebinds();
+    assert_eq!(completed.len(), 1);
+    let (callback, result) = &completed[0];
+    assert_eq!(*callback, Some(RebindCallback { script, method }));
+    assert_eq!(result.action, "fire");
+    assert_eq!(result.conflicts, vec!["jump", "quick_save"]);
+    assert!(input.take_completed_rebinds().is_empty());
+}
+
+#[test]
+fn binding_settings_roundtrip_and_reset() {
+    let defaults = vec![
+        InputAction::new(
+            "jump",
+            vec![
+                InputBinding::Key(KeyCode::Space),
+                InputBinding::Gamepad(GamepadButton::Bottom),
+            ],
+        ),
+        InputAction::new("fire", vec![InputBinding::Mouse(MouseButton::Left)]),
+    ];
+    let mut input = InputSnapshot::new();
+    input.set_input_map(InputMap::from_actions(defaults.clone()));
+    let window = InputWindow::new(&input);
+    assert_eq!(action_binding_names!(&window, "jump"), vec!["Space", "A"]);
+    assert_eq!(action_binding_names!(&window, "fire"), vec!["Left Mouse"]);
+
+    let mut edited = InputMap::from_actions(defaults);
+    edited.set_bindings(
+        "jump",
+        vec![InputBinding::Chord(KeyChord::new(
+            KeyModifiers::SHIFT,
+            KeyCode::KeyW,
+        ))],
+    );
+    let saved = format!(
+        "{}\"removed\" = [\"key:KeyQ\"]\n",
+        edited.bindings_to_settings()
+    );
+
+    assert_eq!(input.apply_binding_settings(&saved), 2);
+    assert_eq!(
+        input.input_map().bindings_to_settings(),
+        edited.bindings_to_settings()
+    );
+    input.set_key_state(KeyCode::ShiftRight, true);
+    input.set_key_state(KeyCode::KeyW, true);
+    assert!(input.is_action_down_hash(action_hash("jump")));
+
+    action_reset_bindings!(&InputWindow::new(&input));
+    input.apply_queued_commands();
+    assert!(input.take_bindings_changed());
+    assert_eq!(
+        input
+            .input_map()
+            .action("jump")
+            .expect("test setup must succeed")
+            .bindings,
+        vec![
+            InputBinding::Key(KeyCode::Space),
+            InputBinding::Gamepad(GamepadButton::Bottom),
+        ]
+    );
+}
+
+#[test]
+fn rumble_routes_players_and_reports_support() {
+    let mut input = InputSnapshot::new();
+    input.set_gamepad_rumble_supported(0, true);
+    input.set_joycon_connected(1, true);
+    input.set_joycon_rumble_supported(1, true);
+    input.bind_player(0, PlayerBinding::JoyConPair { left: 0, right: 1 });
+    input.bind_player(1, PlayerBinding::Kbm);
+
+    let window = InputWindow::new(&input);
+    assert!(window.supports_rumble(RumbleDevice::Gamepad(0)));
+    assert!(!window.supports_rumble(RumbleDevice::JoyCon(0)));
+    assert!(window.supports_rumble(RumbleDevice::Player(0)));
+    assert!(!window.supports_rumble(RumbleDevice::Player(1)));
+
+    window.rumble(RumbleDevice::Player(0), 0.5, 0.25, 0.25);
+    window.rumble(RumbleDevice::Player(1), 1.0, 1.0, 0.2);
+    window.rumble(RumbleDevice::Gamepad(0), 1.0, 0.0, 0.0);
+    input.apply_queued_commands();
+
+    let joycons = input.take_joycon_rumble_requests();
+    assert_eq!(
+        joycons.iter().map(|req| req.index).collect::<Vec<_>>(),
+        vec![0, 1]
+    );
+    assert_eq!(joycons[0].duration, Some(Duration::from_millis(250)));
+    let gamepads = input.take_gamepad_rumble_requests();
+    assert_eq!(gamepads.len(), 1);
+    assert_eq!(gamepads[0].duration, None);
+}
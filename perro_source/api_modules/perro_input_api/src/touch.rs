@@ -0,0 +1,163 @@
+use perro_structs::Vector2;
+
+/// Stage of one touch or pen contact.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TouchPhase {
+    Started,
+    Moved,
+    Ended,
+    Cancelled,
+}
+
+impl TouchPhase {
+    #[inline]
+    pub const fn as_name(self) -> &'static str {
+        match self {
+            Self::Started => "started",
+            Self::Moved => "moved",
+            Self::Ended => "ended",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    /// Return whether the contact leaves the surface in this phase.
+    #[inline]
+    pub const fn is_end(self) -> bool {
+        matches!(self, Self::Ended | Self::Cancelled)
+    }
+}
+
+/// One finger or pen contact on a touchscreen or tablet.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TouchPoint {
+    /// OS contact id, stable from start to end of one contact.
+    pub id: u64,
+    /// Window pixels, y down (same space as raw mouse position).
+    pub position: Vector2,
+    /// Normalized pressure `0..=1`. `1.0` when the device reports none.
+    pub pressure: f32,
+    /// Pen altitude in radians: `0` flat on the surface, `PI / 2`
+    /// perpendicular. `None` for fingers and pens that do not report it.
+    pub altitude: Option<f32>,
+}
+
+/// Active touch contacts plus one-frame start/end edges.
+#[derive(Clone, Debug, Default)]
+pub struct TouchState {
+    active: Vec<TouchPoint>,
+    started: Vec<u64>,
+    ended: Vec<u64>,
+}
+
+impl TouchState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clear one-frame start/end edges. Active contacts persist.
+    #[inline]
+    pub fn begin_frame(&mut self) {
+        self.started.clear();
+        self.ended.clear();
+    }
+
+    /// Drop every contact, e.g. when the window loses focus.
+    pub fn clear(&mut self) {
+        self.active.clear();
+        self.started.clear();
+        self.ended.clear();
+    }
+
+    /// Apply one contact update.
+    pub fn apply(&mut self, phase: TouchPhase, point: TouchPoint) {
+        let slot = self.active.iter().position(|active| active.id == point.id);
+        if phase.is_end() {
+            if let Some(slot) = slot {
+                self.active.remove(slot);
+            }
+            self.ended.push(point.id);
+            return;
+        }
+        match slot {
+            Some(slot) => self.active[slot] = point,
+            None => {
+                self.active.push(point);
+                self.started.push(point.id);
+            }
+        }
+    }
+
+    /// Return active contacts in touch-down order.
+    #[inline]
+    pub fn active(&self) -> &[TouchPoint] {
+        &self.active
+    }
+
+    /// Return an active contact by id.
+    #[inline]
+    pub fn get(&self, id: u64) -> Option<&TouchPoint> {
+        self.active.iter().find(|point| point.id == id)
+    }
+
+    /// Return number of active contacts.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.active.len()
+    }
+
+    /// Return ids of contacts that started this frame.
+    #[inline]
+    pub fn started(&self) -> &[u64] {
+        &self.started
+    }
+
+    /// Return ids of contacts that ended or were cancelled this frame.
+    #[inline]
+    pub fn ended(&self) -> &[u64] {
+        &self.ended
+    }
+}
+
+#[macro_export]
+/// Signature:
+/// - `touch_list!(&InputWindow<_>) -> &[TouchPoint]`
+///
+/// Usage:
+/// - `touch_list!(ipt) -> &[TouchPoint]`
+///
+/// Returns active touch/pen contacts in touch-down order.
+macro_rules! touch_list {
+    ($ipt:expr) => {{
+        let touches = $ipt.Touches();
+        touches.all()
+    }};
+}
+
+#[macro_export]
+/// Signature:
+/// - `touch_get!(&InputWindow<_>, u64) -> Option<&TouchPoint>`
+///
+/// Usage:
+/// - `touch_get!(ipt, id) -> Option<&TouchPoint>`
+///
+/// Returns one active contact by OS contact id.
+macro_rules! touch_get {
+    ($ipt:expr, $id:expr) => {{
+        let touches = $ipt.Touches();
+        touches.get($id)
+    }};
+}
+
+#[macro_export]
+/// Signature:
+/// - `touch_count!(&InputWindow<_>) -> usize`
+///
+/// Usage:
+/// - `touch_count!(ipt) -> usize`
+///
+/// Returns how many contacts are on the surface.
+macro_rules! touch_count {
+    ($ipt:expr) => {
+        $ipt.Touches().count()
+    };
+}
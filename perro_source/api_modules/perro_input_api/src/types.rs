@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 /// Requested mouse cursor behavior.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum MouseMode {
@@ -86,6 +88,29 @@ impl RumbleIntensity {
     }
 }
 
+/// Rumble target: one device slot, or whatever a player slot is bound to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RumbleDevice {
+    /// Gamepad slot.
+    Gamepad(usize),
+    /// Joy-Con slot.
+    JoyCon(usize),
+    /// Player slot; a Joy-Con pair rumbles both sides, KBM is ignored.
+    Player(usize),
+}
+
+impl From<GamepadIndex> for RumbleDevice {
+    fn from(value: GamepadIndex) -> Self {
+        Self::Gamepad(value.0)
+    }
+}
+
+impl From<JoyConIndex> for RumbleDevice {
+    fn from(value: JoyConIndex) -> Self {
+        Self::JoyCon(value.0)
+    }
+}
+
 /// Joy-Con player indicator slot or mapped lamp pattern.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct PlayerIndicatorSlot(pub u8);
@@ -150,6 +175,8 @@ pub struct GamepadRumbleRequest {
     pub index: usize,
     /// Requested rumble intensity.
     pub rumble: RumbleIntensity,
+    /// Stop after this long; `None` holds until the next request.
+    pub duration: Option<Duration>,
 }
 
 /// Pending Joy-Con rumble command produced by script input APIs.
@@ -159,6 +186,8 @@ pub struct JoyConRumbleRequest {
     pub index: usize,
     /// Requested rumble intensity.
     pub rumble: RumbleIntensity,
+    /// Stop after this long; `None` holds until the next request.
+    pub duration: Option<Duration>,
 }
 
 /// Pending Joy-Con player indicator command produced by script input APIs.
@@ -44,6 +44,12 @@ impl<'ipt, IP: InputAPI + ?Sized> InputWindow<'ipt, IP> {
         MouseStateModule::new(self.ipt)
     }
 
+    /// Access touch/pen contacts.
+    #[inline]
+    pub fn Touches(&self) -> TouchModule<'_, IP> {
+        TouchModule::new(self.ipt)
+    }
+
     /// Access connected gamepad states.
     #[inline(always)]
     pub fn Gamepads(&self) -> GamepadModule<'_, IP> {
@@ -142,6 +148,66 @@ impl<'ipt, IP: InputAPI + ?Sized> InputWindow<'ipt, IP> {
         }
     }
 
+    /// Queue rumble on a gamepad, Joy-Con, or player slot for `duration`
+    /// seconds. Gamepads use force feedback; Joy-Cons use HD rumble.
+    /// `duration <= 0.0` holds until the next request for that device.
+    #[inline]
+    pub fn rumble(
+        &self,
+        device: impl Into<RumbleDevice>,
+        low_frequency: f32,
+        high_frequency: f32,
+        duration: f32,
+    ) {
+        if let Some(buffer) = self.ipt.command_buffer() {
+            buffer.borrow_mut().push(InputCommand::Rumble {
+                device: device.into(),
+                rumble: RumbleIntensity::new(low_frequency, high_frequency),
+                duration: Duration::try_from_secs_f32(duration)
+                    .ok()
+                    .filter(|duration| !duration.is_zero()),
+            });
+        }
+    }
+
+    /// Queue stopping rumble on a device.
+    #[inline]
+    pub fn stop_rumble(&self, device: impl Into<RumbleDevice>) {
+        self.rumble(device, 0.0, 0.0, 0.0);
+    }
+
+    /// Return `true` when the device (or a player's bound device) can rumble.
+    /// Disconnected and unknown slots return `false`.
+    #[inline]
+    pub fn supports_rumble(&self, device: impl Into<RumbleDevice>) -> bool {
+        let gamepad = |index: usize| {
+            self.ipt
+                .gamepads()
+                .get(index)
+                .is_some_and(GamepadState::supports_rumble)
+        };
+        let joycon = |index: usize| {
+            self.ipt
+                .joycons()
+                .get(index)
+                .is_some_and(|joycon| joycon.connected() && joycon.supports_rumble())
+        };
+        match device.into() {
+            RumbleDevice::Gamepad(index) => gamepad(index),
+            RumbleDevice::JoyCon(index) => joycon(index),
+            RumbleDevice::Player(player) => {
+                match self.ipt.players().get(player).map(PlayerState::get_binding) {
+                    Some(PlayerBinding::Gamepad { index }) => gamepad(index),
+                    Some(PlayerBinding::JoyConSingle { index }) => joycon(index),
+                    Some(PlayerBinding::JoyConPair { left, right }) => {
+                        joycon(left) || joycon(right)
+                    }
+                    _ => false,
+                }
+            }
+        }
+    }
+
     /// Queue Joy-Con indicator by slot or lamp bit pattern.
     #[inline]
     pub fn set_joycon_indicator(&self, index: usize, indicator: u8) {
@@ -217,6 +283,50 @@ impl<'ipt, IP: InputAPI + ?Sized> ActionModule<'ipt, IP> {
         self.ipt.action_released_hash(name_hash)
     }
 
+    /// Return `true` while the named context is on the context stack.
+    #[inline]
+    pub fn context_active(&self, name: &str) -> bool {
+        self.context_active_hash(context_hash(name))
+    }
+
+    /// Return `true` while the hashed context is on the context stack.
+    #[inline]
+    pub fn context_active_hash(&self, hash: u64) -> bool {
+        self.ipt.input_map().is_context_active_hash(hash)
+    }
+
+    /// Queue pushing an action context; it applies next input frame.
+    #[inline]
+    pub fn push_context(&self, name: &str) {
+        self.push_context_hash(context_hash(name));
+    }
+
+    /// Queue pushing a hashed action context.
+    #[inline]
+    pub fn push_context_hash(&self, context_hash: u64) {
+        if let Some(buffer) = self.ipt.command_buffer() {
+            buffer
+                .borrow_mut()
+                .push(InputCommand::PushContext { context_hash });
+        }
+    }
+
+    /// Queue removing an action context; it applies next input frame.
+    #[inline]
+    pub fn pop_context(&self, name: &str) {
+        self.pop_context_hash(context_hash(name));
+    }
+
+    /// Queue removing a hashed action context.
+    #[inline]
+    pub fn pop_context_hash(&self, context_hash: u64) {
+        if let Some(buffer) = self.ipt.command_buffer() {
+            buffer
+                .borrow_mut()
+                .push(InputCommand::PopContext { context_hash });
+        }
+    }
+
     /// Queue a live rebind for the named action.
     #[inline]
     pub fn start_rebind(&self, name: &str) {
@@ -226,13 +336,70 @@ impl<'ipt, IP: InputAPI + ?Sized> ActionModule<'ipt, IP> {
     /// Queue a live rebind for the hashed action.
     #[inline]
     pub fn start_rebind_hash(&self, action_hash: u64) {
+        self.queue_rebind(action_hash, None);
+    }
+
+    /// Queue a live rebind that calls `method` on `script` when it completes.
+    /// The method receives one object param: `action`, `binding`, `display`,
+    /// and `conflicts`.
+    #[inline]
+    pub fn start_rebind_with(&self, name: &str, script: NodeID, method: ScriptMemberID) {
+        self.start_rebind_hash_with(action_hash(name), script, method);
+    }
+
+    /// Hashed form of [`ActionModule::start_rebind_with`].
+    #[inline]
+    pub fn start_rebind_hash_with(&self, action_hash: u64, script: NodeID, method: ScriptMemberID) {
+        self.queue_rebind(action_hash, Some(RebindCallback { script, method }));
+    }
+
+    #[inline]
+    fn queue_rebind(&self, action_hash: u64, callback: Option<RebindCallback>) {
         if let Some(buffer) = self.ipt.command_buffer() {
-            buffer
-                .borrow_mut()
-                .push(InputCommand::StartRebind { action_hash });
+            buffer.borrow_mut().push(InputCommand::StartRebind {
+                action_hash,
+                callback,
+            });
         }
     }
 
+    /// Queue restoring every action to the project's default bindings.
+    #[inline]
+    pub fn reset_bindings(&self) {
+        if let Some(buffer) = self.ipt.command_buffer() {
+            buffer.borrow_mut().push(InputCommand::ResetBindings);
+        }
+    }
+
+    /// Return the named action's current bindings; empty for unknown actions.
+    #[inline]
+    pub fn bindings(&self, name: &str) -> &'ipt [InputBinding] {
+        self.ipt
+            .input_map()
+            .action(name)
+            .map_or(&[], |action| action.bindings.as_slice())
+    }
+
+    /// Return menu labels for the named action's bindings, e.g. `["Space", "A"]`.
+    #[inline]
+    pub fn binding_names(&self, name: &str) -> Vec<String> {
+        self.bindings(name)
+            .iter()
+            .map(|binding| binding.display_name())
+            .collect()
+    }
+
+    /// Return names of other actions already bound to `binding`'s button.
+    #[inline]
+    pub fn conflicts(&self, name: &str, binding: InputBinding) -> Vec<&'ipt str> {
+        self.ipt
+            .input_map()
+            .conflicts(action_hash(name), binding)
+            .into_iter()
+            .map(|action| action.name.as_str())
+            .collect()
+    }
+
     /// Queue cancellation of an active live rebind.
     #[inline]
     pub fn cancel_rebind(&self) {
@@ -486,6 +653,55 @@ impl<'ipt, IP: InputAPI + ?Sized> MouseStateModule<'ipt, IP> {
     }
 }
 
+/// Touchscreen and pen/tablet contact module.
+pub struct TouchModule<'ipt, IP: InputAPI + ?Sized> {
+    ipt: &'ipt IP,
+}
+
+impl<'ipt, IP: InputAPI + ?Sized> TouchModule<'ipt, IP> {
+    /// Create a touch module around an input API borrow.
+    #[inline]
+    pub fn new(ipt: &'ipt IP) -> Self {
+        Self { ipt }
+    }
+
+    /// Return full touch state.
+    #[inline]
+    pub fn state(&self) -> &'ipt TouchState {
+        self.ipt.touches()
+    }
+
+    /// Return active contacts in touch-down order.
+    #[inline]
+    pub fn all(&self) -> &'ipt [TouchPoint] {
+        self.ipt.touches().active()
+    }
+
+    /// Return one active contact by OS contact id.
+    #[inline]
+    pub fn get(&self, id: u64) -> Option<&'ipt TouchPoint> {
+        self.ipt.touches().get(id)
+    }
+
+    /// Return number of active contacts.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.ipt.touches().count()
+    }
+
+    /// Return ids of contacts that started this frame.
+    #[inline]
+    pub fn started(&self) -> &'ipt [u64] {
+        self.ipt.touches().started()
+    }
+
+    /// Return ids of contacts that ended or were cancelled this frame.
+    #[inline]
+    pub fn ended(&self) -> &'ipt [u64] {
+        self.ipt.touches().ended()
+    }
+}
+
 /// Gamepad state and command module.
 pub struct GamepadModule<'ipt, IP: InputAPI + ?Sized> {
     ipt: &'ipt IP,
@@ -520,6 +736,12 @@ impl<'ipt, IP: InputAPI + ?Sized> GamepadModule<'ipt, IP> {
             });
         }
     }
+
+    /// Return `true` when the gamepad slot reported force feedback.
+    #[inline(always)]
+    pub fn supports_rumble(&self, index: usize) -> bool {
+        self.get(index).is_some_and(GamepadState::supports_rumble)
+    }
 }
 
 /// Joy-Con state and command module.
@@ -557,6 +779,13 @@ impl<'ipt, IP: InputAPI + ?Sized> JoyConModule<'ipt, IP> {
         }
     }
 
+    /// Return `true` when the Joy-Con is connected w/ a rumble output.
+    #[inline(always)]
+    pub fn supports_rumble(&self, index: usize) -> bool {
+        self.get(index)
+            .is_some_and(|joycon| joycon.connected() && joycon.supports_rumble())
+    }
+
     /// Queue Joy-Con indicator by slot or lamp bit pattern.
     #[inline(always)]
     pub fn set_indicator(&self, index: usize, indicator: u8) {
@@ -0,0 +1,302 @@
+use crate::file;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Mutex, PoisonError};
+
+/// Where [`LocalFileBackend::default`] keeps unlocks and stats.
+pub const LOCAL_PATH: &str = "user://achievements.json";
+
+/// Unlocked achievement ids and stat values known to a backend.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Records {
+    pub unlocked: BTreeSet<String>,
+    pub stats: BTreeMap<String, i32>,
+}
+
+/// One write handed to [`AchievementBackend::store`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change<'a> {
+    Unlock(&'a str),
+    Stat { name: &'a str, value: i32 },
+}
+
+/// Store an [`Achievements`] service reads from and writes through.
+///
+/// Game code only talks to the service, so swapping the local file for a
+/// platform store (Steam, consoles) needs no script changes.
+pub trait AchievementBackend: Send {
+    /// Records saved by earlier runs. Called once when the backend is set.
+    fn load(&mut self) -> Records;
+
+    /// Stat missing from [`load`](Self::load), read on first use. Backends
+    /// that can't list their stats up front override this.
+    fn load_stat(&mut self, _name: &str) -> Option<i32> {
+        None
+    }
+
+    /// Persists `change`. `records` already includes it.
+    fn store(&mut self, change: Change<'_>, records: &Records) -> Result<(), String>;
+}
+
+/// Default backend: the whole record set as one JSON file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalFileBackend {
+    path: String,
+}
+
+impl LocalFileBackend {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl Default for LocalFileBackend {
+    fn default() -> Self {
+        Self::new(LOCAL_PATH)
+    }
+}
+
+impl AchievementBackend for LocalFileBackend {
+    fn load(&mut self) -> Records {
+        file::load_string(self.path.as_str())
+            .ok()
+            .and_then(|text| decode_records(&text))
+            .unwrap_or_default()
+    }
+
+    fn store(&mut self, _change: Change<'_>, records: &Records) -> Result<(), String> {
+        file::save_string(self.path.as_str(), &encode_records(records))
+            .map_err(|err| format!("failed to write `{}`: {err}", self.path))
+    }
+}
+
+pub fn encode_records(records: &Records) -> String {
+    let unlocked = records
+        .unlocked
+        .iter()
+        .map(|id| Value::String(id.clone()))
+        .collect();
+    let stats = records
+        .stats
+        .iter()
+        .map(|(name, value)| (name.clone(), Value::from(*value)))
+        .collect::<Map<_, _>>();
+    let mut root = Map::new();
+    root.insert("unlocked".to_string(), Value::Array(unlocked));
+    root.insert("stats".to_string(), Value::Object(stats));
+    serde_json::to_string_pretty(&Value::Object(root)).unwrap_or_default()
+}
+
+/// `None` when `text` is not an achievements file. Unknown keys and
+/// out-of-range stats are skipped.
+pub fn decode_records(text: &str) -> Option<Records> {
+    let Value::Object(root) = serde_json::from_str::<Value>(text).ok()? else {
+        return None;
+    };
+    let mut records = Records::default();
+    if let Some(Value::Array(ids)) = root.get("unlocked") {
+        records.unlocked = ids
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect();
+    }
+    if let Some(Value::Object(stats)) = root.get("stats") {
+        records.stats = stats
+            .iter()
+            .filter_map(|(name, value)| {
+                let value = i32::try_from(value.as_i64()?).ok()?;
+                Some((name.clone(), value))
+            })
+            .collect();
+    }
+    Some(records)
+}
+
+/// Achievement tied to a stat; unlocks once the stat reaches `target`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Goal {
+    stat: String,
+    target: i32,
+}
+
+/// Unlocks, stats, and stat goals over one [`AchievementBackend`].
+///
+/// Backend write errors are logged and the change is kept in memory, so a
+/// store going offline never loses progress for the session.
+pub struct Achievements {
+    backend: Box<dyn AchievementBackend>,
+    records: Records,
+    goals: BTreeMap<String, Goal>,
+}
+
+impl Default for Achievements {
+    fn default() -> Self {
+        Self::new(Box::new(LocalFileBackend::default()))
+    }
+}
+
+impl Achievements {
+    pub fn new(mut backend: Box<dyn AchievementBackend>) -> Self {
+        let records = backend.load();
+        Self {
+            backend,
+            records,
+            goals: BTreeMap::new(),
+        }
+    }
+
+    /// Swaps the store and reloads records from it. Goals are kept and
+    /// checked against the new stats.
+    pub fn set_backend(&mut self, mut backend: Box<dyn AchievementBackend>) {
+        self.records = backend.load();
+        self.backend = backend;
+        let ids = self.goals.keys().cloned().collect::<Vec<_>>();
+        for id in ids {
+            self.check_goal(&id);
+        }
+    }
+
+    pub fn records(&self) -> &Records {
+        &self.records
+    }
+
+    /// Ties `id` to `stat`. Unlocks right away when the stat is already at
+    /// `target`. Targets below 1 are raised to 1.
+    pub fn define(&mut self, id: &str, stat: &str, target: i32) {
+        self.goals.insert(
+            id.to_string(),
+            Goal {
+                stat: stat.to_string(),
+                target: target.max(1),
+            },
+        );
+        self.check_goal(id);
+    }
+
+    /// `true` only when `id` was not unlocked before.
+    pub fn unlock(&mut self, id: &str) -> bool {
+        if id.is_empty() || !self.records.unlocked.insert(id.to_string()) {
+            return false;
+        }
+        self.store(Change::Unlock(id));
+        true
+    }
+
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.records.unlocked.contains(id)
+    }
+
+    /// 0 for stats never set.
+    pub fn stat(&mut self, name: &str) -> i32 {
+        if let Some(value) = self.records.stats.get(name) {
+            return *value;
+        }
+        let value = self.backend.load_stat(name).unwrap_or(0);
+        self.records.stats.insert(name.to_string(), value);
+        value
+    }
+
+    /// Returns the stored value and unlocks goals it reaches.
+    pub fn set_stat(&mut self, name: &str, value: i32) -> i32 {
+        if name.is_empty() {
+            return 0;
+        }
+        if self.records.stats.get(name) != Some(&value) {
+            self.records.stats.insert(name.to_string(), value);
+            self.store(Change::Stat { name, value });
+        }
+        let ids = self
+            .goals
+            .iter()
+            .filter(|(_, goal)| goal.stat == name)
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<_>>();
+        for id in ids {
+            self.check_goal(&id);
+        }
+        value
+    }
+
+    /// Adds `by` (saturating) and returns the new value.
+    pub fn increment(&mut self, name: &str, by: i32) -> i32 {
+        let value = self.stat(name).saturating_add(by);
+        self.set_stat(name, value)
+    }
+
+    /// 0.0..=1.0. Unlocked ids are 1.0; ids without a goal are 0.0 until
+    /// unlocked.
+    pub fn progress(&mut self, id: &str) -> f32 {
+        if self.is_unlocked(id) {
+            return 1.0;
+        }
+        let Some(goal) = self.goals.get(id).cloned() else {
+            return 0.0;
+        };
+        (self.stat(&goal.stat) as f32 / goal.target as f32).clamp(0.0, 1.0)
+    }
+
+    fn check_goal(&mut self, id: &str) {
+        let Some(goal) = self.goals.get(id).cloned() else {
+            return;
+        };
+        if self.stat(&goal.stat) >= goal.target {
+            self.unlock(id);
+        }
+    }
+
+    fn store(&mut self, change: Change<'_>) {
+        if let Err(err) = self.backend.store(change, &self.records) {
+            crate::log::warn(format_args!("[achievements] {err}"));
+        }
+    }
+}
+
+static SERVICE: Mutex<Option<Achievements>> = Mutex::new(None);
+
+fn with_service<R>(f: impl FnOnce(&mut Achievements) -> R) -> R {
+    let mut guard = SERVICE.lock().unwrap_or_else(PoisonError::into_inner);
+    f(guard.get_or_insert_with(Achievements::default))
+}
+
+/// Replaces the game-wide store. Until called, [`LocalFileBackend::default`]
+/// is used.
+pub fn set_backend(backend: impl AchievementBackend + 'static) {
+    with_service(|service| service.set_backend(Box::new(backend)));
+}
+
+pub fn define(id: &str, stat: &str, target: i32) {
+    with_service(|service| service.define(id, stat, target));
+}
+
+pub fn unlock(id: &str) -> bool {
+    with_service(|service| service.unlock(id))
+}
+
+pub fn is_unlocked(id: &str) -> bool {
+    with_service(|service| service.is_unlocked(id))
+}
+
+pub fn stat(name: &str) -> i32 {
+    with_service(|service| service.stat(name))
+}
+
+pub fn set_stat(name: &str, value: i32) -> i32 {
+    with_service(|service| service.set_stat(name, value))
+}
+
+pub fn increment(name: &str, by: i32) -> i32 {
+    with_service(|service| service.increment(name, by))
+}
+
+pub fn progress(id: &str) -> f32 {
+    with_service(|service| service.progress(id))
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/achievements_tests.rs"]
+mod tests;
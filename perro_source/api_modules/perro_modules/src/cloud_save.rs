@@ -0,0 +1,314 @@
+use crate::file;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Remote backends keep file timestamps here, next to the saves.
+pub const MANIFEST_NAME: &str = "cloud_manifest.json";
+/// Local store file holding the timestamp of every file at its last sync.
+pub const SYNC_STATE_NAME: &str = "cloud_sync.json";
+
+/// Name -> last write time, Unix milliseconds.
+pub type Timestamps = BTreeMap<String, u64>;
+
+/// One save file in a [`CloudSave`] store.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CloudFile {
+    pub name: String,
+    /// Unix milliseconds.
+    pub modified: u64,
+}
+
+/// A flat store of save files: the local saves dir or a remote backend
+/// (Steam Cloud, WebDAV/HTTP).
+///
+/// [`sync`] copies between two stores; game code never talks to a platform
+/// SDK directly.
+pub trait CloudSave {
+    fn list(&mut self) -> Result<Vec<CloudFile>, String>;
+
+    /// Writes `bytes` and records `modified` as the file's timestamp.
+    fn upload(&mut self, name: &str, bytes: &[u8], modified: u64) -> Result<(), String>;
+
+    fn download(&mut self, name: &str) -> Result<Vec<u8>, String>;
+}
+
+/// Which side wins a file both stores changed since the last sync.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Resolution {
+    KeepLocal,
+    KeepRemote,
+    /// Leave both as-is and report it, e.g. to ask the player. Settle it
+    /// later w/ [`resolve_conflict`].
+    Defer,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Conflict {
+    pub name: String,
+    pub local: CloudFile,
+    pub remote: CloudFile,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    pub uploaded: Vec<String>,
+    pub downloaded: Vec<String>,
+    pub deferred: Vec<Conflict>,
+}
+
+/// Default resolver: the newer file wins, local on a tie.
+pub fn last_writer_wins(conflict: &Conflict) -> Resolution {
+    if conflict.local.modified >= conflict.remote.modified {
+        Resolution::KeepLocal
+    } else {
+        Resolution::KeepRemote
+    }
+}
+
+/// Save file names the sync copies: ASCII letters, digits, `_`, `-`, `.`,
+/// not starting w/ `.`, and not a reserved sync file.
+pub fn is_sync_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('.')
+        && !name.ends_with(".tmp")
+        && name != MANIFEST_NAME
+        && name != SYNC_STATE_NAME
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-' | b'.'))
+}
+
+pub fn encode_timestamps(timestamps: &Timestamps) -> Vec<u8> {
+    let map = timestamps
+        .iter()
+        .map(|(name, modified)| (name.clone(), Value::from(*modified)))
+        .collect::<Map<_, _>>();
+    serde_json::to_vec_pretty(&Value::Object(map)).unwrap_or_default()
+}
+
+/// Empty for missing or malformed data.
+pub fn decode_timestamps(bytes: &[u8]) -> Timestamps {
+    let Ok(Value::Object(map)) = serde_json::from_slice::<Value>(bytes) else {
+        return Timestamps::new();
+    };
+    map.into_iter()
+        .filter_map(|(name, modified)| Some((name, modified.as_u64()?)))
+        .collect()
+}
+
+pub fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_millis() as u64)
+}
+
+/// [`sync_with`] using [`last_writer_wins`].
+pub fn sync(local: &mut dyn CloudSave, remote: &mut dyn CloudSave) -> Result<SyncReport, String> {
+    sync_with(local, remote, last_writer_wins)
+}
+
+/// Brings `local` and `remote` up to date with each other.
+///
+/// A file changed on one side since the last sync is copied to the other.
+/// A file changed on both sides goes to `resolve`. Copies keep the source
+/// timestamp, so a synced file has the same timestamp in both stores.
+/// Deletes are not synced; a file missing on one side is copied back.
+///
+/// Sync state lives in `local` as [`SYNC_STATE_NAME`] and is saved even when
+/// a copy fails part way.
+pub fn sync_with(
+    local: &mut dyn CloudSave,
+    remote: &mut dyn CloudSave,
+    mut resolve: impl FnMut(&Conflict) -> Resolution,
+) -> Result<SyncReport, String> {
+    let mut state = load_state(local);
+    let mut report = SyncReport::default();
+    let result = sync_files(local, remote, &mut state, &mut report, &mut resolve);
+    save_state(local, &state)?;
+    result.map(|()| report)
+}
+
+/// Applies a choice for a conflict [`sync_with`] deferred.
+pub fn resolve_conflict(
+    local: &mut dyn CloudSave,
+    remote: &mut dyn CloudSave,
+    conflict: &Conflict,
+    resolution: Resolution,
+) -> Result<(), String> {
+    let mut state = load_state(local);
+    let mut report = SyncReport::default();
+    apply(local, remote, conflict, resolution, &mut state, &mut report)?;
+    save_state(local, &state)
+}
+
+fn sync_files(
+    local: &mut dyn CloudSave,
+    remote: &mut dyn CloudSave,
+    state: &mut Timestamps,
+    report: &mut SyncReport,
+    resolve: &mut dyn FnMut(&Conflict) -> Resolution,
+) -> Result<(), String> {
+    let locals = index_files(local.list()?);
+    let remotes = index_files(remote.list()?);
+    let names = locals.keys().chain(remotes.keys()).collect::<BTreeSet<_>>();
+    for name in names {
+        let base = state.get(name).copied();
+        let (local_file, remote_file) = match (locals.get(name), remotes.get(name)) {
+            (Some(local_file), Some(remote_file)) => (local_file, remote_file),
+            (Some(file), None) => {
+                copy(&mut *local, &mut *remote, file, state)?;
+                report.uploaded.push(file.name.clone());
+                continue;
+            }
+            (None, Some(file)) => {
+                copy(&mut *remote, &mut *local, file, state)?;
+                report.downloaded.push(file.name.clone());
+                continue;
+            }
+            (None, None) => continue,
+        };
+        if local_file.modified == remote_file.modified {
+            state.insert(name.clone(), local_file.modified);
+            continue;
+        }
+        let conflict = Conflict {
+            name: name.clone(),
+            local: local_file.clone(),
+            remote: remote_file.clone(),
+        };
+        let local_changed = base != Some(local_file.modified);
+        let remote_changed = base != Some(remote_file.modified);
+        let resolution = match (local_changed, remote_changed) {
+            (true, false) => Resolution::KeepLocal,
+            (false, true) => Resolution::KeepRemote,
+            _ => resolve(&conflict),
+        };
+        apply(
+            &mut *local,
+            &mut *remote,
+            &conflict,
+            resolution,
+            state,
+            report,
+        )?;
+    }
+    Ok(())
+}
+
+fn apply(
+    local: &mut dyn CloudSave,
+    remote: &mut dyn CloudSave,
+    conflict: &Conflict,
+    resolution: Resolution,
+    state: &mut Timestamps,
+    report: &mut SyncReport,
+) -> Result<(), String> {
+    match resolution {
+        Resolution::KeepLocal => {
+            copy(local, remote, &conflict.local, state)?;
+            report.uploaded.push(conflict.name.clone());
+        }
+        Resolution::KeepRemote => {
+            copy(remote, local, &conflict.remote, state)?;
+            report.downloaded.push(conflict.name.clone());
+        }
+        Resolution::Defer => report.deferred.push(conflict.clone()),
+    }
+    Ok(())
+}
+
+fn copy(
+    from: &mut dyn CloudSave,
+    to: &mut dyn CloudSave,
+    file: &CloudFile,
+    state: &mut Timestamps,
+) -> Result<(), String> {
+    let bytes = from.download(&file.name)?;
+    to.upload(&file.name, &bytes, file.modified)?;
+    state.insert(file.name.clone(), file.modified);
+    Ok(())
+}
+
+fn index_files(files: Vec<CloudFile>) -> BTreeMap<String, CloudFile> {
+    files
+        .into_iter()
+        .filter(|file| is_sync_name(&file.name))
+        .map(|file| (file.name.clone(), file))
+        .collect()
+}
+
+fn load_state(local: &mut dyn CloudSave) -> Timestamps {
+    local
+        .download(SYNC_STATE_NAME)
+        .map(|bytes| decode_timestamps(&bytes))
+        .unwrap_or_default()
+}
+
+fn save_state(local: &mut dyn CloudSave, state: &Timestamps) -> Result<(), String> {
+    local.upload(SYNC_STATE_NAME, &encode_timestamps(state), now_ms())
+}
+
+/// Local store: the files directly inside a disk dir such as
+/// `user://saves`. Subdirectories are skipped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalSaveDir {
+    dir: String,
+}
+
+impl LocalSaveDir {
+    pub fn new(dir: impl Into<String>) -> Self {
+        let dir: String = dir.into();
+        Self {
+            dir: dir.trim_end_matches('/').to_string(),
+        }
+    }
+
+    fn path(&self, name: &str) -> String {
+        format!("{}/{name}", self.dir)
+    }
+}
+
+impl CloudSave for LocalSaveDir {
+    fn list(&mut self) -> Result<Vec<CloudFile>, String> {
+        if !file::is_dir(self.dir.as_str()) {
+            return Ok(Vec::new());
+        }
+        let entries = file::read_dir(self.dir.as_str())
+            .map_err(|err| format!("failed to list `{}`: {err}", self.dir))?;
+        let mut files = Vec::new();
+        for entry in entries {
+            let entry = Path::new(&entry);
+            let Some(name) = entry.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if !entry.is_file() || !is_sync_name(name) {
+                continue;
+            }
+            let modified = file::modified_ms(self.path(name).as_str())
+                .map_err(|err| format!("failed to stat `{name}`: {err}"))?;
+            files.push(CloudFile {
+                name: name.to_string(),
+                modified,
+            });
+        }
+        Ok(files)
+    }
+
+    fn upload(&mut self, name: &str, bytes: &[u8], modified: u64) -> Result<(), String> {
+        let path = self.path(name);
+        file::save_bytes(path.as_str(), bytes)
+            .and_then(|()| file::set_modified_ms(path.as_str(), modified))
+            .map_err(|err| format!("failed to write `{path}`: {err}"))
+    }
+
+    fn download(&mut self, name: &str) -> Result<Vec<u8>, String> {
+        let path = self.path(name);
+        file::load_bytes(path.as_str()).map_err(|err| format!("failed to read `{path}`: {err}"))
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/cloud_save_tests.rs"]
+mod tests;
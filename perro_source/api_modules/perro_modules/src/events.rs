@@ -0,0 +1,115 @@
+//! Global publish/subscribe on string topics.
+//!
+//! Scripts subscribe a method to a topic and publish [`Variant`] payloads to
+//! it w/o holding a reference to each other. Publishing only queues the
+//! event; the runtime delivers queued events as script method calls at the
+//! start of the next frame, so a handler that publishes again never recurses
+//! into itself. Scripts reach the runtime's bus through the `event_*!` macros.
+
+use perro_ids::NodeID;
+use perro_variant::Variant;
+use std::fmt::Write;
+
+/// One script method listening on a topic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventSubscription {
+    pub topic: String,
+    pub script: NodeID,
+    pub method: String,
+}
+
+/// Topic subscriptions + events published since the last delivery.
+#[derive(Clone, Debug, Default)]
+pub struct EventBus {
+    subscriptions: Vec<EventSubscription>,
+    pending: Vec<(String, Variant)>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Listen for `topic` w/ `script`'s `method`. `false` when already
+    /// subscribed.
+    pub fn subscribe(&mut self, topic: &str, script: NodeID, method: &str) -> bool {
+        if self.position(topic, script, method).is_some() {
+            return false;
+        }
+        self.subscriptions.push(EventSubscription {
+            topic: topic.to_string(),
+            script,
+            method: method.to_string(),
+        });
+        true
+    }
+
+    pub fn unsubscribe(&mut self, topic: &str, script: NodeID, method: &str) -> bool {
+        let Some(index) = self.position(topic, script, method) else {
+            return false;
+        };
+        self.subscriptions.remove(index);
+        true
+    }
+
+    /// Drop every subscription `script` holds; returns how many.
+    pub fn unsubscribe_script(&mut self, script: NodeID) -> usize {
+        let before = self.subscriptions.len();
+        self.subscriptions.retain(|sub| sub.script != script);
+        before - self.subscriptions.len()
+    }
+
+    /// Queue `payload` for every subscriber of `topic`. Subscribers are
+    /// looked up at delivery, so ones added b4 then still receive it.
+    pub fn publish(&mut self, topic: &str, payload: Variant) {
+        self.pending.push((topic.to_string(), payload));
+    }
+
+    /// Queued events in publish order; the queue is left empty.
+    pub fn take_pending(&mut self) -> Vec<(String, Variant)> {
+        std::mem::take(&mut self.pending)
+    }
+
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn subscriptions(&self) -> &[EventSubscription] {
+        &self.subscriptions
+    }
+
+    /// Subscribers of `topic` in subscribe order.
+    pub fn subscribers(&self, topic: &str) -> Vec<(NodeID, String)> {
+        self.subscriptions
+            .iter()
+            .filter(|sub| sub.topic == topic)
+            .map(|sub| (sub.script, sub.method.clone()))
+            .collect()
+    }
+
+    /// One `topic -> script.method` line per subscription, sorted by topic.
+    pub fn debug_view(&self) -> String {
+        let mut subs = self.subscriptions.iter().collect::<Vec<_>>();
+        subs.sort_by(|a, b| a.topic.cmp(&b.topic));
+        let mut out = String::new();
+        for sub in subs {
+            let _ = writeln!(out, "{} -> {}.{}", sub.topic, sub.script, sub.method);
+        }
+        out
+    }
+
+    pub fn clear(&mut self) {
+        self.subscriptions.clear();
+        self.pending.clear();
+    }
+
+    fn position(&self, topic: &str, script: NodeID, method: &str) -> Option<usize> {
+        self.subscriptions
+            .iter()
+            .position(|sub| sub.script == script && sub.topic == topic && sub.method == method)
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/events_tests.rs"]
+mod tests;
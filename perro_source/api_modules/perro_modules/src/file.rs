@@ -1,6 +1,7 @@
 use std::{
     fs, io,
     path::{Path, PathBuf},
+    time::{Duration, UNIX_EPOCH},
 };
 
 use perro_io::{
@@ -46,7 +47,8 @@ pub fn exists<P: ResPathSource>(path: P) -> bool {
         | perro_io::ResolvedPath::PerroAssets(_)
         | perro_io::ResolvedPath::StaticBinary(_)
         | perro_io::ResolvedPath::DlcStaticBinary { .. }
-        | perro_io::ResolvedPath::DlcPerroAssets { .. } => load_asset(path).is_ok(),
+        | perro_io::ResolvedPath::DlcPerroAssets { .. }
+        | perro_io::ResolvedPath::MountedArchive { .. } => load_asset(path).is_ok(),
     }
 }
 
@@ -86,6 +88,22 @@ pub fn walk_dir<P: ResPathSource>(path: P) -> io::Result<Vec<String>> {
     Ok(out)
 }
 
+/// Last write time in Unix milliseconds. Disk paths only.
+pub fn modified_ms<P: ResPathSource>(path: P) -> io::Result<u64> {
+    let modified = fs::metadata(disk_path(path.as_res_path_str())?)?.modified()?;
+    Ok(modified
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_millis() as u64))
+}
+
+/// Sets the last write time. Disk paths only.
+pub fn set_modified_ms<P: ResPathSource>(path: P, ms: u64) -> io::Result<()> {
+    let path = path.as_res_path_str();
+    validate_write_path(path)?;
+    let file = fs::File::options().write(true).open(disk_path(path)?)?;
+    file.set_modified(UNIX_EPOCH + Duration::from_millis(ms))
+}
+
 pub fn pick_folder(title: &str) -> Option<String> {
     pick_folder_impl(title)
 }
@@ -111,6 +129,10 @@ pub fn resolve_path_string<P: ResPathSource>(path: P) -> String {
         perro_io::ResolvedPath::DlcPerroAssets { dlc, virtual_path } => {
             format!("perroassets+dlc://{dlc}/{virtual_path}")
         }
+        perro_io::ResolvedPath::MountedArchive {
+            mount,
+            virtual_path,
+        } => format!("perroassets+mount://{mount}/{virtual_path}"),
     }
 }
 
@@ -1,13 +1,22 @@
+pub mod achievements;
+pub mod cloud_save;
+pub mod events;
 pub mod file;
 pub mod json;
+pub mod localization;
 pub mod log;
 pub mod math;
 pub mod random;
+pub mod rollback;
 pub mod zip;
 
 pub mod prelude {
+    pub use crate::achievements as AchievementsMod;
+    pub use crate::cloud_save as CloudSaveMod;
+    pub use crate::events as EventsMod;
     pub use crate::file as FileMod;
     pub use crate::json as JSONMod;
+    pub use crate::localization as LocalizationMod;
     pub use crate::log as LogMod;
     pub use crate::log::{OptionWarnExt, ResultWarnExt};
     pub use crate::math as MathMod;
@@ -25,6 +34,7 @@ pub mod prelude {
         rand_range_u32, rand_u32, rand_u32_stream, rand_unit_vec2, rand_unit_vec3, rand01,
         rand01_stream, rand11, rand11_stream, shuffle,
     };
+    pub use crate::rollback as RollbackMod;
     pub use crate::zip as ZipMod;
     pub use crate::{log_error, log_info, log_print, log_warn};
 }
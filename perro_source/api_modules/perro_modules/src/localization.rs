@@ -0,0 +1,43 @@
+use perro_resource_api::sub_apis::{LocalizationAPI, LocalizationModule};
+
+/// Scene text `tr("KEY")` and `%loc: "KEY"` both parse to `%loc:KEY`.
+pub const MARKER_PREFIX: &str = "%loc:";
+
+pub fn marker(key: &str) -> String {
+    format!("{MARKER_PREFIX}{}", key.trim())
+}
+
+pub fn key_of(text: &str) -> Option<&str> {
+    let raw = text.strip_prefix(MARKER_PREFIX)?.trim();
+    let key = raw
+        .strip_prefix('"')
+        .and_then(|raw| raw.strip_suffix('"'))
+        .map_or(raw, str::trim);
+    (!key.is_empty()).then_some(key)
+}
+
+/// Active-locale string for `key`; the key itself when missing or empty.
+pub fn tr<'a, R>(loc: &LocalizationModule<'_, R>, key: &'a str) -> &'a str
+where
+    R: LocalizationAPI + ?Sized,
+{
+    loc.get(key)
+        .filter(|value| !value.is_empty())
+        .unwrap_or(key)
+}
+
+/// Resolves a `%loc:` marker like the scene loader does; other text is
+/// returned as-is.
+pub fn resolve<'a, R>(loc: &LocalizationModule<'_, R>, text: &'a str) -> &'a str
+where
+    R: LocalizationAPI + ?Sized,
+{
+    match key_of(text) {
+        Some(key) => tr(loc, key),
+        None => text,
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/localization_tests.rs"]
+mod tests;
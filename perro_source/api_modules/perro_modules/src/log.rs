@@ -3,9 +3,15 @@ use std::collections::HashSet;
 use std::fmt::Display;
 #[cfg(not(perro_no_console))]
 use std::io::{self, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
 #[cfg(not(perro_no_console))]
 use std::sync::{Mutex, OnceLock};
 
+/// Appends `  @ res://path.rs:line` to console lines that have a script source.
+/// The editor sets it on play runs so its console can jump to the source.
+pub const LOG_SOURCE_ENV: &str = "PERRO_LOG_SOURCE";
+
 #[cfg(not(perro_no_console))]
 type WarnSite = (&'static str, u32, u32);
 #[cfg(not(perro_no_console))]
@@ -13,6 +19,185 @@ type WarnedSites = Mutex<HashSet<WarnSite>>;
 #[cfg(not(perro_no_console))]
 static WARNED_SITES: OnceLock<WarnedSites> = OnceLock::new();
 
+type LogHook = Arc<dyn Fn(&LogRecord) + Send + Sync>;
+static LOG_HOOKS: RwLock<Vec<(u64, LogHook)>> = RwLock::new(Vec::new());
+static NEXT_LOG_HOOK: AtomicU64 = AtomicU64::new(1);
+
+/// Severity of one log line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Print,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Print => "print",
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// One log line as handed to hooks added with [`add_log_hook`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogRecord {
+    pub level: LogLevel,
+    pub message: String,
+    /// Call site file. Scripts are `include!`d from `res/`, so this points at
+    /// the script source and `line` matches it; see [`source_res_path`].
+    pub file: &'static str,
+    pub line: u32,
+}
+
+impl LogRecord {
+    /// Leading `[tag]` of the message: `"ai"` for `"[ai] lost target"`.
+    pub fn category(&self) -> Option<&str> {
+        log_category(&self.message)
+    }
+
+    /// `res://` or `dlc://` path of the call site, when it is a script.
+    pub fn source(&self) -> Option<String> {
+        source_res_path(self.file)
+    }
+}
+
+/// Handle returned by [`add_log_hook`]; pass to [`remove_log_hook`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LogHookId(u64);
+
+/// Calls `hook` for every `print`/`info`/`warn`/`error` in this process, after
+/// the console write. Hooks run on the logging thread; keep them short and do
+/// not log from inside one. No-op in `perro_no_console` builds.
+pub fn add_log_hook(hook: impl Fn(&LogRecord) + Send + Sync + 'static) -> LogHookId {
+    let id = NEXT_LOG_HOOK.fetch_add(1, Ordering::Relaxed);
+    let mut hooks = match LOG_HOOKS.write() {
+        Ok(hooks) => hooks,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    hooks.push((id, Arc::new(hook)));
+    LogHookId(id)
+}
+
+/// Returns false when `id` was already removed.
+pub fn remove_log_hook(id: LogHookId) -> bool {
+    let mut hooks = match LOG_HOOKS.write() {
+        Ok(hooks) => hooks,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    let before = hooks.len();
+    hooks.retain(|(hook_id, _)| *hook_id != id.0);
+    hooks.len() != before
+}
+
+/// Leading `[tag]` of a log message, trimmed. `None` when there is no tag.
+pub fn log_category(message: &str) -> Option<&str> {
+    let rest = message.strip_prefix('[')?;
+    let tag = rest[..rest.find(']')?].trim();
+    (!tag.is_empty()).then_some(tag)
+}
+
+/// Source map for log call sites: maps a compiled `file!()` path back to
+/// the `res://` (or `dlc://<name>/`) script it came from.
+///
+/// Generated script crates `include!` the original file, so the path ends in
+/// `.../res/<rel>` or `.../dlcs/<name>/<rel>` and line numbers match the source.
+pub fn source_res_path(file: &str) -> Option<String> {
+    let file = format!("/{}", file.replace('\\', "/"));
+    let res = file.rfind("/res/");
+    let dlc = file.rfind("/dlcs/");
+    match (res, dlc) {
+        (res, Some(dlc)) if res.is_none_or(|res| dlc > res) => {
+            let (name, rel) = file[dlc + "/dlcs/".len()..].split_once('/')?;
+            (!name.is_empty() && !rel.is_empty()).then(|| format!("dlc://{name}/{rel}"))
+        }
+        (Some(res), _) => {
+            let rel = &file[res + "/res/".len()..];
+            (!rel.is_empty()).then(|| format!("res://{rel}"))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(not(perro_no_console))]
+fn dispatch_hooks(
+    level: LogLevel,
+    message: String,
+    location: &'static std::panic::Location<'static>,
+) {
+    // clone out so a hook can add/remove hooks without deadlocking
+    let hooks: Vec<LogHook> = match LOG_HOOKS.read() {
+        Ok(hooks) => hooks.iter().map(|(_, hook)| Arc::clone(hook)).collect(),
+        Err(poisoned) => poisoned
+            .into_inner()
+            .iter()
+            .map(|(_, hook)| Arc::clone(hook))
+            .collect(),
+    };
+    if hooks.is_empty() {
+        return;
+    }
+    let record = LogRecord {
+        level,
+        message,
+        file: location.file(),
+        line: location.line(),
+    };
+    for hook in hooks {
+        hook(&record);
+    }
+}
+
+#[cfg(not(perro_no_console))]
+fn source_suffix(location: &'static std::panic::Location<'static>) -> String {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    let enabled = *ENABLED.get_or_init(|| std::env::var_os(LOG_SOURCE_ENV).is_some());
+    match source_res_path(location.file()) {
+        Some(source) if enabled => format!("  @ {source}:{}", location.line()),
+        _ => String::new(),
+    }
+}
+
+#[cfg(not(perro_no_console))]
+#[track_caller]
+fn emit(level: LogLevel, message: impl Display) {
+    emit_at(level, message, std::panic::Location::caller());
+}
+
+#[cfg(not(perro_no_console))]
+fn emit_at(
+    level: LogLevel,
+    message: impl Display,
+    location: &'static std::panic::Location<'static>,
+) {
+    let message = message.to_string();
+    let suffix = source_suffix(location);
+    let with_color = colors_enabled();
+    let _ = match level {
+        LogLevel::Print => writeln!(io::stdout(), "{message}{suffix}"),
+        LogLevel::Info => writeln!(
+            io::stdout().lock(),
+            "{}{suffix}",
+            format_info(&message, with_color)
+        ),
+        LogLevel::Warn => writeln!(
+            io::stderr().lock(),
+            "{}{suffix}",
+            format_warn(&message, with_color)
+        ),
+        LogLevel::Error => writeln!(
+            io::stderr().lock(),
+            "{}{suffix}",
+            format_error(&message, with_color)
+        ),
+    };
+    dispatch_hooks(level, message, location);
+}
+
 pub trait OptionWarnExt<T> {
     /// Logs `message` when this option is `None`, then returns it unchanged.
     fn warn_none<M: Display>(self, message: M) -> Option<T>;
@@ -23,6 +208,7 @@ pub trait OptionWarnExt<T> {
 }
 
 impl<T> OptionWarnExt<T> for Option<T> {
+    #[track_caller]
     fn warn_none<M: Display>(self, message: M) -> Option<T> {
         if self.is_none() {
             warn(message);
@@ -53,6 +239,7 @@ pub trait ResultWarnExt<T, E> {
 }
 
 impl<T, E> ResultWarnExt<T, E> for Result<T, E> {
+    #[track_caller]
     fn warn_err<M: Display>(self, context: M) -> Result<T, E>
     where
         E: Display,
@@ -87,7 +274,7 @@ fn warn_once(location: &'static std::panic::Location<'static>, message: impl Dis
     };
     if sites.insert((location.file(), location.line(), location.column())) {
         drop(sites);
-        warn(message);
+        emit_at(LogLevel::Warn, message, location);
     }
 }
 
@@ -107,41 +294,36 @@ const YELLOW: &str = "93";
 const RED: &str = "91";
 
 #[cfg(not(perro_no_console))]
+#[track_caller]
 pub fn print(message: impl Display) {
-    let _ = writeln!(io::stdout(), "{message}");
+    emit(LogLevel::Print, message);
 }
 
 #[cfg(perro_no_console)]
 pub fn print(_message: impl Display) {}
 
 #[cfg(not(perro_no_console))]
+#[track_caller]
 pub fn info(message: impl Display) {
-    let stdout = io::stdout();
-    let mut handle = stdout.lock();
-
-    let _ = writeln!(handle, "{}", format_info(message, colors_enabled()));
+    emit(LogLevel::Info, message);
 }
 
 #[cfg(perro_no_console)]
 pub fn info(_message: impl Display) {}
 
 #[cfg(not(perro_no_console))]
+#[track_caller]
 pub fn warn(message: impl Display) {
-    let stderr = io::stderr();
-    let mut handle = stderr.lock();
-
-    let _ = writeln!(handle, "{}", format_warn(message, colors_enabled()));
+    emit(LogLevel::Warn, message);
 }
 
 #[cfg(perro_no_console)]
 pub fn warn(_message: impl Display) {}
 
 #[cfg(not(perro_no_console))]
+#[track_caller]
 pub fn error(message: impl Display) {
-    let stderr = io::stderr();
-    let mut handle = stderr.lock();
-
-    let _ = writeln!(handle, "{}", format_error(message, colors_enabled()));
+    emit(LogLevel::Error, message);
 }
 
 #[cfg(perro_no_console)]
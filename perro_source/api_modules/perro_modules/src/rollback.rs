@@ -0,0 +1,179 @@
+//! Rollback netcode bookkeeping: a ring of recent tick snapshots plus
+//! confirmed + predicted inputs per player.
+//!
+//! Each fixed tick the simulation reads every player's input for that tick.
+//! Remote inputs that have not arrived yet are predicted by repeating the
+//! player's last confirmed input. When a late input disagrees w/ what the
+//! simulation used, the session asks for a rollback: restore the snapshot
+//! taken b4 that tick and resimulate up to the present. The runtime drives
+//! this from `fixed_update`; scripts reach it through the `rollback_*!`
+//! macros.
+
+use perro_ids::NodeID;
+use perro_variant::Variant;
+use std::collections::{BTreeMap, VecDeque};
+
+/// State at the end of one fixed tick.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TickSnapshot {
+    pub tick: u64,
+    /// Simulation seconds at the end of `tick`.
+    pub elapsed: f64,
+    /// Saved state of every tracked script.
+    pub states: Vec<(NodeID, Variant)>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct PlayerInputs {
+    confirmed: BTreeMap<u64, Variant>,
+    /// What the simulation read each tick, confirmed or predicted.
+    used: BTreeMap<u64, Variant>,
+}
+
+/// Snapshot ring + per-player input history for one rollback match.
+#[derive(Clone, Debug)]
+pub struct RollbackSession {
+    window: usize,
+    snapshots: VecDeque<TickSnapshot>,
+    inputs: Vec<PlayerInputs>,
+    rollback_to: Option<u64>,
+}
+
+impl RollbackSession {
+    /// Session for `players` that can roll back up to `window` ticks
+    /// (at least 1).
+    pub fn new(players: usize, window: usize) -> Self {
+        let window = window.max(1);
+        Self {
+            window,
+            snapshots: VecDeque::with_capacity(window + 1),
+            inputs: vec![PlayerInputs::default(); players],
+            rollback_to: None,
+        }
+    }
+
+    pub fn players(&self) -> usize {
+        self.inputs.len()
+    }
+
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Newest snapshot's tick: the last tick simulated.
+    pub fn current_tick(&self) -> Option<u64> {
+        self.snapshots.back().map(|snapshot| snapshot.tick)
+    }
+
+    /// Oldest snapshot's tick; ticks after it can still be rolled back.
+    pub fn oldest_tick(&self) -> Option<u64> {
+        self.snapshots.front().map(|snapshot| snapshot.tick)
+    }
+
+    pub fn snapshot(&self, tick: u64) -> Option<&TickSnapshot> {
+        self.snapshots.iter().find(|snapshot| snapshot.tick == tick)
+    }
+
+    /// Record the end-of-tick state. A resimulated tick replaces its old
+    /// snapshot; past `window` the oldest one is dropped.
+    pub fn push_snapshot(&mut self, snapshot: TickSnapshot) {
+        while self
+            .snapshots
+            .back()
+            .is_some_and(|last| last.tick >= snapshot.tick)
+        {
+            self.snapshots.pop_back();
+        }
+        self.snapshots.push_back(snapshot);
+        while self.snapshots.len() > self.window + 1 {
+            self.snapshots.pop_front();
+        }
+        self.prune_inputs();
+    }
+
+    /// Confirm `player`'s input for `tick`. Already simulated ticks that used
+    /// a different input (for `tick`, or predicted from it up to the next
+    /// confirmed one) schedule a rollback. `false` for an unknown player or a
+    /// tick too old to roll back.
+    pub fn set_input(&mut self, player: usize, tick: u64, input: Variant) -> bool {
+        let oldest = self.oldest_tick();
+        let Some(inputs) = self.inputs.get_mut(player) else {
+            return false;
+        };
+        if oldest.is_some_and(|oldest| tick <= oldest) {
+            return false;
+        }
+        let next_confirmed = inputs.confirmed.range(tick + 1..).next().map(|(t, _)| *t);
+        let mispredicted = inputs
+            .used
+            .range(tick..)
+            .take_while(|(t, _)| next_confirmed.is_none_or(|end| **t < end))
+            .find(|(_, used)| **used != input)
+            .map(|(t, _)| *t);
+        if let Some(at) = mispredicted {
+            self.rollback_to = Some(self.rollback_to.map_or(at, |prev| prev.min(at)));
+        }
+        inputs.confirmed.insert(tick, input);
+        true
+    }
+
+    /// `player`'s input for `tick`: the confirmed one, else their last
+    /// confirmed input b4 `tick`, else `Variant::Null`.
+    pub fn input(&mut self, player: usize, tick: u64) -> Variant {
+        let Some(inputs) = self.inputs.get_mut(player) else {
+            return Variant::Null;
+        };
+        let input = inputs
+            .confirmed
+            .range(..=tick)
+            .next_back()
+            .map(|(_, input)| input.clone())
+            .unwrap_or(Variant::Null);
+        inputs.used.insert(tick, input.clone());
+        input
+    }
+
+    pub fn is_confirmed(&self, player: usize, tick: u64) -> bool {
+        self.inputs
+            .get(player)
+            .is_some_and(|inputs| inputs.confirmed.contains_key(&tick))
+    }
+
+    /// First tick to resimulate, if a late input mispredicted.
+    pub fn pending_rollback(&self) -> Option<u64> {
+        self.rollback_to
+    }
+
+    /// Snapshot to restore for the pending rollback (the one b4 its first
+    /// tick), clearing it. Inputs read from that tick on are forgotten so the
+    /// resimulation records them again.
+    pub fn take_rollback(&mut self) -> Option<TickSnapshot> {
+        let tick = self.rollback_to.take()?;
+        let snapshot = self.snapshot(tick.checked_sub(1)?)?.clone();
+        for inputs in &mut self.inputs {
+            inputs.used.retain(|&used, _| used < tick);
+        }
+        Some(snapshot)
+    }
+
+    /// Drop inputs no rollback can reach, keeping each player's last
+    /// confirmed one for prediction.
+    fn prune_inputs(&mut self) {
+        let Some(oldest) = self.oldest_tick() else {
+            return;
+        };
+        for inputs in &mut self.inputs {
+            let kept = inputs.confirmed.split_off(&(oldest + 1));
+            let last = inputs.confirmed.pop_last();
+            inputs.confirmed = kept;
+            if let Some((tick, input)) = last {
+                inputs.confirmed.insert(tick, input);
+            }
+            inputs.used = inputs.used.split_off(&(oldest + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/rollback_tests.rs"]
+mod tests;
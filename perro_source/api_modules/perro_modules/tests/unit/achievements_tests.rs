@@ -0,0 +1,147 @@
+use std::sync::{Arc, Mutex};
+
+use super::{AchievementBackend, Achievements, Change, Records, decode_records, encode_records};
+
+#[derive(Clone, Default)]
+struct MemoryBackend {
+    saved: Records,
+    remote_stats: Vec<(&'static str, i32)>,
+    writes: Arc<Mutex<Vec<String>>>,
+    fail: bool,
+}
+
+impl AchievementBackend for MemoryBackend {
+    fn load(&mut self) -> Records {
+        self.saved.clone()
+    }
+
+    fn load_stat(&mut self, name: &str) -> Option<i32> {
+        self.remote_stats
+            .iter()
+            .find(|(stat, _)| *stat == name)
+            .map(|(_, value)| *value)
+    }
+
+    fn store(&mut self, change: Change<'_>, _records: &Records) -> Result<(), String> {
+        if self.fail {
+            return Err("offline".to_string());
+        }
+        let entry = match change {
+            Change::Unlock(id) => format!("unlock {id}"),
+            Change::Stat { name, value } => format!("stat {name}={value}"),
+        };
+        self.writes.lock().expect("writes lock").push(entry);
+        Ok(())
+    }
+}
+
+#[test]
+fn unlock_reports_only_first_unlock_and_writes_once() {
+    let backend = MemoryBackend::default();
+    let writes = backend.writes.clone();
+    let mut achievements = Achievements::new(Box::new(backend));
+
+    assert!(achievements.unlock("ACH_FIRST_BLOOD"));
+    assert!(!achievements.unlock("ACH_FIRST_BLOOD"));
+    assert!(!achievements.unlock(""));
+    assert!(achievements.is_unlocked("ACH_FIRST_BLOOD"));
+    assert_eq!(
+        *writes.lock().expect("writes lock"),
+        vec!["unlock ACH_FIRST_BLOOD"]
+    );
+}
+
+#[test]
+fn increment_unlocks_goal_when_target_reached() {
+    let backend = MemoryBackend::default();
+    let writes = backend.writes.clone();
+    let mut achievements = Achievements::new(Box::new(backend));
+    achievements.define("ACH_SLAYER", "kills", 10);
+
+    assert_eq!(achievements.increment("kills", 4), 4);
+    assert!((achievements.progress("ACH_SLAYER") - 0.4).abs() < 1.0e-6);
+    assert!(!achievements.is_unlocked("ACH_SLAYER"));
+
+    assert_eq!(achievements.increment("kills", 8), 12);
+    assert!(achievements.is_unlocked("ACH_SLAYER"));
+    assert_eq!(achievements.progress("ACH_SLAYER"), 1.0);
+    assert_eq!(
+        *writes.lock().expect("writes lock"),
+        vec!["stat kills=4", "stat kills=12", "unlock ACH_SLAYER"]
+    );
+}
+
+#[test]
+fn define_unlocks_when_saved_stat_already_meets_target() {
+    let mut saved = Records::default();
+    saved.stats.insert("wins".to_string(), 3);
+    let backend = MemoryBackend {
+        saved,
+        ..MemoryBackend::default()
+    };
+    let mut achievements = Achievements::new(Box::new(backend));
+
+    achievements.define("ACH_WINNER", "wins", 3);
+    assert!(achievements.is_unlocked("ACH_WINNER"));
+}
+
+#[test]
+fn stat_reads_missing_values_from_backend_once() {
+    let backend = MemoryBackend {
+        remote_stats: vec![("coins", 40)],
+        ..MemoryBackend::default()
+    };
+    let mut achievements = Achievements::new(Box::new(backend));
+
+    assert_eq!(achievements.stat("coins"), 40);
+    assert_eq!(achievements.increment("coins", 2), 42);
+    assert_eq!(achievements.stat("unknown"), 0);
+    assert_eq!(achievements.progress("ACH_UNDEFINED"), 0.0);
+}
+
+#[test]
+fn backend_errors_keep_progress_in_memory() {
+    let backend = MemoryBackend {
+        fail: true,
+        ..MemoryBackend::default()
+    };
+    let mut achievements = Achievements::new(Box::new(backend));
+
+    assert!(achievements.unlock("ACH_OFFLINE"));
+    assert_eq!(achievements.increment("deaths", 1), 1);
+    assert!(achievements.is_unlocked("ACH_OFFLINE"));
+}
+
+#[test]
+fn set_backend_reloads_records_and_rechecks_goals() {
+    let mut achievements = Achievements::new(Box::new(MemoryBackend::default()));
+    achievements.define("ACH_COLLECTOR", "gems", 5);
+    achievements.unlock("ACH_LOCAL_ONLY");
+
+    let mut saved = Records::default();
+    saved.stats.insert("gems".to_string(), 7);
+    achievements.set_backend(Box::new(MemoryBackend {
+        saved,
+        ..MemoryBackend::default()
+    }));
+
+    assert!(!achievements.is_unlocked("ACH_LOCAL_ONLY"));
+    assert!(achievements.is_unlocked("ACH_COLLECTOR"));
+}
+
+#[test]
+fn records_round_trip_through_json() {
+    let mut records = Records::default();
+    records.unlocked.insert("ACH_A".to_string());
+    records.stats.insert("kills".to_string(), -3);
+    records.stats.insert("wins".to_string(), 12);
+
+    let text = encode_records(&records);
+    assert_eq!(decode_records(&text), Some(records));
+    assert_eq!(decode_records("[1, 2]"), None);
+    assert_eq!(
+        decode_records(r#"{"unlocked":["ACH_B",4],"stats":{"big":9999999999,"ok":1}}"#)
+            .map(|records| (records.unlocked.len(), records.stats.len())),
+        Some((1, 1))
+    );
+}
@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+
+use super::{
+    CloudFile, CloudSave, Resolution, SYNC_STATE_NAME, Timestamps, decode_timestamps,
+    encode_timestamps, is_sync_name, resolve_conflict, sync, sync_with,
+};
+
+#[derive(Default)]
+struct MemoryStore {
+    files: BTreeMap<String, (Vec<u8>, u64)>,
+}
+
+impl MemoryStore {
+    fn with(files: &[(&str, &str, u64)]) -> Self {
+        let mut store = Self::default();
+        for (name, text, modified) in files {
+            store.put(name, text, *modified);
+        }
+        store
+    }
+
+    fn put(&mut self, name: &str, text: &str, modified: u64) {
+        self.files
+            .insert(name.to_string(), (text.as_bytes().to_vec(), modified));
+    }
+
+    fn text(&self, name: &str) -> Option<(&str, u64)> {
+        let (bytes, modified) = self.files.get(name)?;
+        Some((std::str::from_utf8(bytes).ok()?, *modified))
+    }
+}
+
+impl CloudSave for MemoryStore {
+    fn list(&mut self) -> Result<Vec<CloudFile>, String> {
+        Ok(self
+            .files
+            .iter()
+            .map(|(name, (_, modified))| CloudFile {
+                name: name.clone(),
+                modified: *modified,
+            })
+            .collect())
+    }
+
+    fn upload(&mut self, name: &str, bytes: &[u8], modified: u64) -> Result<(), String> {
+        self.files
+            .insert(name.to_string(), (bytes.to_vec(), modified));
+        Ok(())
+    }
+
+    fn download(&mut self, name: &str) -> Result<Vec<u8>, String> {
+        self.files
+            .get(name)
+            .map(|(bytes, _)| bytes.clone())
+            .ok_or_else(|| format!("missing `{name}`"))
+    }
+}
+
+#[test]
+fn first_sync_copies_missing_files_both_ways_with_timestamps() {
+    let mut local = MemoryStore::with(&[("slot_1.json", "local", 100)]);
+    let mut remote = MemoryStore::with(&[("slot_2.json", "remote", 200)]);
+
+    let report = sync(&mut local, &mut remote).expect("sync");
+    assert_eq!(report.uploaded, vec!["slot_1.json"]);
+    assert_eq!(report.downloaded, vec!["slot_2.json"]);
+    assert_eq!(remote.text("slot_1.json"), Some(("local", 100)));
+    assert_eq!(local.text("slot_2.json"), Some(("remote", 200)));
+    assert!(remote.text(SYNC_STATE_NAME).is_none());
+
+    let report = sync(&mut local, &mut remote).expect("second sync");
+    assert!(report.uploaded.is_empty() && report.downloaded.is_empty());
+}
+
+#[test]
+fn one_sided_change_copies_even_when_older() {
+    let mut local = MemoryStore::with(&[("slot_1.json", "v1", 500)]);
+    let mut remote = MemoryStore::default();
+    sync(&mut local, &mut remote).expect("sync");
+
+    // A restored backup has an older timestamp but is still the only change.
+    remote.put("slot_1.json", "backup", 300);
+    let report = sync(&mut local, &mut remote).expect("sync");
+    assert_eq!(report.downloaded, vec!["slot_1.json"]);
+    assert_eq!(local.text("slot_1.json"), Some(("backup", 300)));
+}
+
+#[test]
+fn conflict_defaults_to_last_writer_wins() {
+    let mut local = MemoryStore::with(&[("slot_1.json", "v1", 100)]);
+    let mut remote = MemoryStore::default();
+    sync(&mut local, &mut remote).expect("sync");
+
+    local.put("slot_1.json", "local edit", 150);
+    remote.put("slot_1.json", "remote edit", 180);
+    let report = sync(&mut local, &mut remote).expect("sync");
+    assert_eq!(report.downloaded, vec!["slot_1.json"]);
+    assert_eq!(local.text("slot_1.json"), Some(("remote edit", 180)));
+}
+
+#[test]
+fn deferred_conflict_is_reported_then_resolved_later() {
+    let mut local = MemoryStore::with(&[("slot_1.json", "local", 100)]);
+    let mut remote = MemoryStore::with(&[("slot_1.json", "remote", 200)]);
+
+    let mut asked = Vec::new();
+    let report = sync_with(&mut local, &mut remote, |conflict| {
+        asked.push(conflict.name.clone());
+        Resolution::Defer
+    })
+    .expect("sync");
+    assert_eq!(asked, vec!["slot_1.json"]);
+    assert_eq!(report.deferred.len(), 1);
+    assert_eq!(local.text("slot_1.json"), Some(("local", 100)));
+    assert_eq!(remote.text("slot_1.json"), Some(("remote", 200)));
+
+    resolve_conflict(
+        &mut local,
+        &mut remote,
+        &report.deferred[0],
+        Resolution::KeepLocal,
+    )
+    .expect("resolve");
+    assert_eq!(remote.text("slot_1.json"), Some(("local", 100)));
+
+    let report = sync(&mut local, &mut remote).expect("sync");
+    assert!(report.deferred.is_empty() && report.uploaded.is_empty());
+}
+
+#[test]
+fn sync_names_and_timestamps_round_trip() {
+    assert!(is_sync_name("slot_1.json"));
+    assert!(!is_sync_name(".hidden"));
+    assert!(!is_sync_name("saves/slot.json"));
+    assert!(!is_sync_name("slot.json.tmp"));
+    assert!(!is_sync_name(SYNC_STATE_NAME));
+
+    let mut timestamps = Timestamps::new();
+    timestamps.insert("slot_1.json".to_string(), 1_700_000_000_000);
+    assert_eq!(
+        decode_timestamps(&encode_timestamps(&timestamps)),
+        timestamps
+    );
+    assert!(decode_timestamps(b"not json").is_empty());
+}
@@ -0,0 +1,73 @@
+use perro_ids::NodeID;
+use perro_variant::Variant;
+
+use super::EventBus;
+
+#[test]
+fn subscribe_rejects_duplicates_and_unsubscribe_removes_one() {
+    let mut bus = EventBus::new();
+    let player = NodeID::new(3);
+
+    assert!(bus.subscribe("player_died", player, "on_player_died"));
+    assert!(!bus.subscribe("player_died", player, "on_player_died"));
+    assert!(bus.subscribe("player_died", player, "on_any_death"));
+    assert_eq!(bus.subscriptions().len(), 2);
+
+    assert!(bus.unsubscribe("player_died", player, "on_any_death"));
+    assert!(!bus.unsubscribe("player_died", player, "on_any_death"));
+    assert_eq!(
+        bus.subscribers("player_died"),
+        [(player, "on_player_died".to_string())]
+    );
+}
+
+#[test]
+fn publish_queues_until_taken() {
+    let mut bus = EventBus::new();
+    bus.publish("score", Variant::from(10_i32));
+    bus.publish("score", Variant::from(25_i32));
+    assert_eq!(bus.pending_len(), 2);
+
+    let pending = bus.take_pending();
+    assert_eq!(
+        pending,
+        [
+            ("score".to_string(), Variant::from(10_i32)),
+            ("score".to_string(), Variant::from(25_i32)),
+        ]
+    );
+    assert_eq!(bus.pending_len(), 0);
+}
+
+#[test]
+fn unsubscribe_script_drops_all_its_topics() {
+    let mut bus = EventBus::new();
+    let hud = NodeID::new(4);
+    let audio = NodeID::new(5);
+    bus.subscribe("score", hud, "on_score");
+    bus.subscribe("player_died", hud, "on_death");
+    bus.subscribe("player_died", audio, "play_sting");
+
+    assert_eq!(bus.unsubscribe_script(hud), 2);
+    assert_eq!(
+        bus.subscribers("player_died"),
+        [(audio, "play_sting".to_string())]
+    );
+    assert!(bus.subscribers("score").is_empty());
+}
+
+#[test]
+fn debug_view_lists_subscriptions_by_topic() {
+    let mut bus = EventBus::new();
+    bus.subscribe("score", NodeID::new(4), "on_score");
+    bus.subscribe("player_died", NodeID::new(5), "play_sting");
+
+    assert_eq!(
+        bus.debug_view(),
+        format!(
+            "player_died -> {}.play_sting\nscore -> {}.on_score\n",
+            NodeID::new(5),
+            NodeID::new(4)
+        )
+    );
+}
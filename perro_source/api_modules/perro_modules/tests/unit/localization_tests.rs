@@ -0,0 +1,62 @@
+use perro_resource_api::sub_apis::{Locale, LocalizationAPI, LocalizationModule};
+
+use super::{key_of, marker, resolve, tr};
+
+struct Table;
+
+impl LocalizationAPI for Table {
+    fn localization_set_locale(&self, _locale: Locale) -> bool {
+        false
+    }
+
+    fn localization_get_locale(&self) -> Locale {
+        Locale::EN
+    }
+
+    fn localization_get(&self, key: &str) -> Option<&'static str> {
+        match key {
+            "MENU_START" => Some("Start"),
+            "MENU_EMPTY" => Some(""),
+            _ => None,
+        }
+    }
+
+    fn localization_get_by_hash(&self, _key_hash: u64) -> Option<&'static str> {
+        None
+    }
+
+    fn localization_get_for_locale(&self, _locale: Locale, key: &str) -> Option<&'static str> {
+        self.localization_get(key)
+    }
+
+    fn localization_get_for_locale_by_hash(
+        &self,
+        _locale: Locale,
+        _key_hash: u64,
+    ) -> Option<&'static str> {
+        None
+    }
+}
+
+#[test]
+fn marker_round_trips_through_key_of() {
+    assert_eq!(marker("MENU_START"), "%loc:MENU_START");
+    assert_eq!(key_of(&marker("MENU_START")), Some("MENU_START"));
+    assert_eq!(key_of("%loc: \"MENU_START\""), Some("MENU_START"));
+    assert_eq!(key_of("%loc:"), None);
+    assert_eq!(key_of("Start"), None);
+}
+
+#[test]
+fn tr_and_resolve_fall_back_to_key() {
+    let table = Table;
+    let loc = LocalizationModule::new(&table);
+
+    assert_eq!(tr(&loc, "MENU_START"), "Start");
+    assert_eq!(tr(&loc, "MENU_EMPTY"), "MENU_EMPTY");
+    assert_eq!(tr(&loc, "MENU_MISSING"), "MENU_MISSING");
+
+    assert_eq!(resolve(&loc, "%loc:MENU_START"), "Start");
+    assert_eq!(resolve(&loc, "%loc:MENU_MISSING"), "MENU_MISSING");
+    assert_eq!(resolve(&loc, "plain text"), "plain text");
+}
@@ -1,4 +1,8 @@
-use super::{OptionWarnExt, ResultWarnExt, format_error, format_info, format_warn};
+use super::{
+    LogLevel, LogRecord, OptionWarnExt, ResultWarnExt, add_log_hook, format_error, format_info,
+    format_warn, log_category, remove_log_hook, source_res_path,
+};
+use std::sync::{Arc, Mutex};
 
 #[test]
 fn format_info_without_color() {
@@ -53,3 +57,52 @@ fn warn_extensions_keep_failure_flow() {
         Err("test error")
     );
 }
+
+#[test]
+fn log_category_reads_leading_tag() {
+    assert_eq!(log_category("[ai] lost target"), Some("ai"));
+    assert_eq!(log_category("[ perro ][movie] x"), Some("perro"));
+    assert_eq!(log_category("no tag"), None);
+    assert_eq!(log_category("[] empty"), None);
+}
+
+#[test]
+fn source_res_path_maps_included_scripts_back_to_res() {
+    assert_eq!(
+        source_res_path(".perro/scripts/src/../../../res/scripts/player.rs").as_deref(),
+        Some("res://scripts/player.rs")
+    );
+    assert_eq!(
+        source_res_path("C:\\games\\res\\game\\res\\enemy.rs").as_deref(),
+        Some("res://enemy.rs")
+    );
+    assert_eq!(
+        source_res_path("/games/demo/dlcs/Expansion/boss.rs").as_deref(),
+        Some("dlc://Expansion/boss.rs")
+    );
+    assert_eq!(source_res_path("perro_modules/src/log.rs"), None);
+}
+
+#[test]
+fn log_hook_receives_level_message_and_call_site() {
+    let seen: Arc<Mutex<Vec<LogRecord>>> = Arc::default();
+    let sink = Arc::clone(&seen);
+    let id = add_log_hook(move |record| {
+        if record.message.starts_with("[hook_test]") {
+            sink.lock().expect("hook sink").push(record.clone());
+        }
+    });
+    crate::log_warn!("[hook_test] careful {}", 3);
+    let line = line!() - 1;
+    assert!(remove_log_hook(id));
+    assert!(!remove_log_hook(id));
+    crate::log_warn!("[hook_test] after remove");
+
+    let seen = seen.lock().expect("hook sink");
+    assert_eq!(seen.len(), 1);
+    assert_eq!(seen[0].level, LogLevel::Warn);
+    assert_eq!(seen[0].message, "[hook_test] careful 3");
+    assert_eq!(seen[0].category(), Some("hook_test"));
+    assert!(seen[0].file.ends_with("log_tests.rs"));
+    assert_eq!(seen[0].line, line);
+}
@@ -0,0 +1,82 @@
+use perro_ids::NodeID;
+use perro_variant::Variant;
+
+use super::{RollbackSession, TickSnapshot};
+
+fn snapshot(tick: u64, hp: i32) -> TickSnapshot {
+    TickSnapshot {
+        tick,
+        elapsed: tick as f64 / 60.0,
+        states: vec![(NodeID::new(1), Variant::from(hp))],
+    }
+}
+
+#[test]
+fn ring_keeps_window_plus_one_snapshots_and_replaces_resimulated_ticks() {
+    let mut session = RollbackSession::new(2, 3);
+    for tick in 0..=6 {
+        session.push_snapshot(snapshot(tick, tick as i32));
+    }
+    assert_eq!(session.oldest_tick(), Some(3));
+    assert_eq!(session.current_tick(), Some(6));
+    assert!(session.snapshot(2).is_none());
+
+    // resimulating tick 5 drops the stale 5 + 6
+    session.push_snapshot(snapshot(5, 50));
+    assert_eq!(session.current_tick(), Some(5));
+    assert_eq!(session.snapshot(5), Some(&snapshot(5, 50)));
+    assert!(session.snapshot(6).is_none());
+}
+
+#[test]
+fn missing_inputs_repeat_the_last_confirmed_one() {
+    let mut session = RollbackSession::new(2, 8);
+    session.push_snapshot(snapshot(0, 0));
+    assert_eq!(session.input(1, 1), Variant::Null);
+
+    assert!(session.set_input(1, 2, Variant::from("left")));
+    assert!(session.is_confirmed(1, 2));
+    assert!(!session.is_confirmed(1, 3));
+    assert_eq!(session.input(1, 4), Variant::from("left"));
+    assert_eq!(session.input(7, 4), Variant::Null);
+    assert!(!session.set_input(7, 4, Variant::Null));
+}
+
+#[test]
+fn late_input_rolls_back_only_when_it_was_mispredicted() {
+    let mut session = RollbackSession::new(2, 8);
+    session.push_snapshot(snapshot(0, 0));
+    assert!(session.set_input(1, 1, Variant::from("idle")));
+    for tick in 1..=4 {
+        let _ = session.input(1, tick);
+        session.push_snapshot(snapshot(tick, tick as i32));
+    }
+
+    // tick 2 predicted "idle" correctly: nothing to redo
+    assert!(session.set_input(1, 2, Variant::from("idle")));
+    assert_eq!(session.pending_rollback(), None);
+
+    // tick 3 was "jump"; 3 + 4 ran on the "idle" prediction
+    assert!(session.set_input(1, 3, Variant::from("jump")));
+    assert_eq!(session.pending_rollback(), Some(3));
+    let restore = session.take_rollback().expect("snapshot b4 tick 3");
+    assert_eq!(restore.tick, 2);
+    assert_eq!(session.pending_rollback(), None);
+
+    // resimulation reads the corrected input + predicts from it
+    assert_eq!(session.input(1, 3), Variant::from("jump"));
+    assert_eq!(session.input(1, 4), Variant::from("jump"));
+}
+
+#[test]
+fn inputs_older_than_the_ring_are_rejected() {
+    let mut session = RollbackSession::new(1, 2);
+    for tick in 0..=5 {
+        let _ = session.input(0, tick);
+        session.push_snapshot(snapshot(tick, 0));
+    }
+    assert_eq!(session.oldest_tick(), Some(3));
+    assert!(!session.set_input(0, 3, Variant::from(1_i32)));
+    assert!(session.set_input(0, 4, Variant::from(1_i32)));
+    assert_eq!(session.pending_rollback(), Some(4));
+}
@@ -0,0 +1,134 @@
+//! `modules::cloud_save` store over plain HTTP `GET`/`PUT`, which covers
+//! WebDAV servers and simple object stores.
+//!
+//! Each save is `{base_url}/{name}`. Timestamps live in
+//! `{base_url}/cloud_manifest.json`; a missing manifest (404) is an empty store.
+
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use perro_modules::cloud_save::{
+    CloudFile, CloudSave, MANIFEST_NAME, Timestamps, decode_timestamps, encode_timestamps,
+};
+
+use super::http::{
+    HttpBody, HttpClient, HttpErrorKind, HttpEvent, HttpMethod, HttpRequest, HttpResponse,
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+const MAX_SAVE_BYTES: usize = 64 * 1024 * 1024;
+
+/// Blocking: every call waits for its requests. Run [`sync`] from a job, not
+/// from `on_update`.
+///
+/// [`sync`]: perro_modules::cloud_save::sync
+pub struct HttpCloudSave {
+    base_url: String,
+    headers: Vec<(String, String)>,
+    timeout: Duration,
+    http: HttpClient,
+}
+
+impl HttpCloudSave {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        let base_url: String = base_url.into();
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            headers: Vec::new(),
+            timeout: Duration::from_secs(30),
+            http: HttpClient::new(),
+        }
+    }
+
+    /// Sent w/ every request, e.g. `Authorization`.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn url(&self, name: &str) -> String {
+        format!("{}/{name}", self.base_url)
+    }
+
+    /// `Ok(None)` on 404.
+    fn send(&mut self, request: HttpRequest) -> Result<Option<HttpResponse>, String> {
+        let request = self
+            .headers
+            .iter()
+            .fold(request, |request, (name, value)| {
+                request.header(name.clone(), value.clone())
+            })
+            .timeout_ms(self.timeout.as_millis() as u64)
+            .max_response_bytes(MAX_SAVE_BYTES);
+        let id = self
+            .http
+            .try_request(request)
+            .map_err(|err| err.to_string())?;
+        // Worker enforces the request timeout; the grace covers queueing.
+        let deadline = Instant::now() + self.timeout + Duration::from_secs(5);
+        loop {
+            while let Some(event) = self.http.poll() {
+                match event {
+                    HttpEvent::Completed(response) if response.id == id => {
+                        return Ok(Some(response));
+                    }
+                    HttpEvent::Failed(err) if err.id == id => {
+                        if err.kind == HttpErrorKind::Status && err.message == "http status 404" {
+                            return Ok(None);
+                        }
+                        return Err(err.to_string());
+                    }
+                    _ => {}
+                }
+            }
+            if Instant::now() >= deadline {
+                return Err(format!("cloud request timed out: {}", self.base_url));
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    fn manifest(&mut self) -> Result<Timestamps, String> {
+        let response = self.send(HttpRequest::get(self.url(MANIFEST_NAME)))?;
+        Ok(response
+            .map(|response| decode_timestamps(response.bytes()))
+            .unwrap_or_default())
+    }
+
+    fn put(&mut self, name: &str, bytes: Vec<u8>) -> Result<(), String> {
+        let request = HttpRequest::new(HttpMethod::Put, self.url(name), HttpBody::Bytes(bytes));
+        self.send(request)?
+            .map(|_| ())
+            .ok_or_else(|| format!("cloud upload of `{name}` got 404"))
+    }
+}
+
+impl CloudSave for HttpCloudSave {
+    fn list(&mut self) -> Result<Vec<CloudFile>, String> {
+        Ok(self
+            .manifest()?
+            .into_iter()
+            .map(|(name, modified)| CloudFile { name, modified })
+            .collect())
+    }
+
+    fn upload(&mut self, name: &str, bytes: &[u8], modified: u64) -> Result<(), String> {
+        let mut manifest = self.manifest()?;
+        self.put(name, bytes.to_vec())?;
+        manifest.insert(name.to_string(), modified);
+        self.put(MANIFEST_NAME, encode_timestamps(&manifest))
+    }
+
+    fn download(&mut self, name: &str) -> Result<Vec<u8>, String> {
+        self.send(HttpRequest::get(self.url(name)))?
+            .map(|response| response.body)
+            .ok_or_else(|| format!("cloud file `{name}` not found"))
+    }
+}
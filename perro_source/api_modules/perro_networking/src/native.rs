@@ -21,6 +21,8 @@ use tungstenite::{
     stream::MaybeTlsStream,
 };
 
+#[path = "cloud_http.rs"]
+mod cloud_http;
 #[path = "error.rs"]
 mod error;
 #[path = "event.rs"]
@@ -42,12 +44,16 @@ mod world;
 
 #[path = "http.rs"]
 pub mod http;
+#[path = "telemetry.rs"]
+pub mod telemetry;
 
+pub use cloud_http::*;
 pub use error::*;
 pub use event::*;
 pub use http::*;
 pub use ids::*;
 pub use tcp::*;
+pub use telemetry::*;
 pub use udp::*;
 pub use websocket::*;
 pub use world::*;
@@ -0,0 +1,494 @@
+//! Opt-in anonymous session telemetry on top of `HttpClient`.
+//!
+//! Nothing runs unless the game constructs a `Telemetry`, and nothing is
+//! recorded or sent until the player grants consent (persisted on disk).
+//! Records carry a random per-session id, never a stable player id.
+//!
+//! Batches go out as JSON `POST`s; failed sends stay in a bounded on-disk
+//! queue and retry with exponential backoff, surviving restarts.
+
+use std::{
+    collections::VecDeque,
+    fs,
+    hash::{BuildHasher, Hasher, RandomState},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use serde_json::{Value, json};
+
+use super::http::{HttpClient, HttpConfig, HttpEvent, HttpID, HttpQueueConfig, HttpRequest};
+
+const CONSENT_FILE: &str = "consent";
+const QUEUE_FILE: &str = "queue.jsonl";
+const SESSION_MARKER_FILE: &str = "session.lock";
+const DEFAULT_STORAGE_PATH: &str = "user://telemetry";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TelemetryConfig {
+    pub endpoint: String,
+    /// `None` = `user://telemetry`.
+    pub storage_dir: Option<PathBuf>,
+    pub headers: Vec<(String, String)>,
+    pub batch_size: usize,
+    pub flush_interval: Duration,
+    /// Oldest records drop first once the queue is full.
+    pub max_queued: usize,
+    pub retry: TelemetryRetry,
+}
+
+impl TelemetryConfig {
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            storage_dir: None,
+            headers: Vec::new(),
+            batch_size: 20,
+            flush_interval: Duration::from_secs(60),
+            max_queued: 500,
+            retry: TelemetryRetry::default(),
+        }
+    }
+
+    pub fn storage_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.storage_dir = Some(dir.into());
+        self
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    pub fn flush_interval(mut self, flush_interval: Duration) -> Self {
+        self.flush_interval = flush_interval;
+        self
+    }
+
+    pub fn max_queued(mut self, max_queued: usize) -> Self {
+        self.max_queued = max_queued.max(1);
+        self
+    }
+
+    pub fn retry(mut self, retry: TelemetryRetry) -> Self {
+        self.retry = retry;
+        self
+    }
+}
+
+/// Exponential backoff: `base * 2^failures`, capped at `max`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TelemetryRetry {
+    pub base: Duration,
+    pub max: Duration,
+}
+
+impl Default for TelemetryRetry {
+    fn default() -> Self {
+        Self {
+            base: Duration::from_secs(5),
+            max: Duration::from_secs(600),
+        }
+    }
+}
+
+impl TelemetryRetry {
+    pub fn delay(&self, failures: u32) -> Duration {
+        self.base
+            .checked_mul(1u32 << failures.min(16))
+            .unwrap_or(self.max)
+            .min(self.max)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TelemetryConsent {
+    /// Player not asked yet; behaves like `Denied`.
+    Unknown,
+    Granted,
+    Denied,
+}
+
+impl TelemetryConsent {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Unknown => "unknown",
+            Self::Granted => "granted",
+            Self::Denied => "denied",
+        }
+    }
+
+    fn parse(raw: &str) -> Self {
+        match raw.trim() {
+            "granted" => Self::Granted,
+            "denied" => Self::Denied,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Coarse bucket so records can't fingerprint a machine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HardwareClass {
+    Low,
+    Mid,
+    High,
+}
+
+impl HardwareClass {
+    pub fn from_cpu_threads(threads: usize) -> Self {
+        match threads {
+            0..=4 => Self::Low,
+            5..=8 => Self::Mid,
+            _ => Self::High,
+        }
+    }
+
+    pub fn detect() -> Self {
+        Self::from_cpu_threads(std::thread::available_parallelism().map_or(1, usize::from))
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Mid => "mid",
+            Self::High => "high",
+        }
+    }
+}
+
+/// Bounded FIFO of JSON records; full queue drops the oldest.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TelemetryQueue {
+    records: VecDeque<Value>,
+    max: usize,
+}
+
+impl TelemetryQueue {
+    pub fn new(max: usize) -> Self {
+        Self {
+            records: VecDeque::new(),
+            max: max.max(1),
+        }
+    }
+
+    pub fn push(&mut self, record: Value) {
+        while self.records.len() >= self.max {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    fn front_batch(&self, size: usize) -> Vec<Value> {
+        self.records.iter().take(size).cloned().collect()
+    }
+
+    fn drop_front(&mut self, count: usize) {
+        let count = count.min(self.records.len());
+        self.records.drain(..count);
+    }
+
+    fn clear(&mut self) {
+        self.records.clear();
+    }
+
+    pub fn to_jsonl(&self) -> String {
+        let mut out = String::new();
+        for record in &self.records {
+            out.push_str(&record.to_string());
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Unparseable lines are skipped; a torn write loses one record, not all.
+    pub fn from_jsonl(text: &str, max: usize) -> Self {
+        let mut queue = Self::new(max);
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            if let Ok(record) = serde_json::from_str(line) {
+                queue.push(record);
+            }
+        }
+        queue
+    }
+}
+
+struct InFlight {
+    id: HttpID,
+    count: usize,
+}
+
+pub struct Telemetry {
+    config: TelemetryConfig,
+    dir: PathBuf,
+    consent: TelemetryConsent,
+    queue: TelemetryQueue,
+    // Records changed since the last queue write; written per batch, not per event.
+    queue_dirty: bool,
+    http: Option<HttpClient>,
+    in_flight: Option<InFlight>,
+    failures: u32,
+    next_flush: Instant,
+    session_id: String,
+    session_start: Option<Instant>,
+    previous_session_crashed: bool,
+}
+
+impl Telemetry {
+    pub fn new(config: TelemetryConfig) -> Self {
+        let dir = config
+            .storage_dir
+            .clone()
+            .unwrap_or_else(default_storage_dir);
+        let consent = fs::read_to_string(dir.join(CONSENT_FILE))
+            .map_or(TelemetryConsent::Unknown, |raw| {
+                TelemetryConsent::parse(&raw)
+            });
+        let queue = fs::read_to_string(dir.join(QUEUE_FILE))
+            .map(|text| TelemetryQueue::from_jsonl(&text, config.max_queued))
+            .unwrap_or_else(|_| TelemetryQueue::new(config.max_queued));
+        // Marker is only written while a consented session runs, so a leftover
+        // one means the last session never reached `end_session`.
+        let previous_session_crashed = dir.join(SESSION_MARKER_FILE).exists();
+        let next_flush = Instant::now() + config.flush_interval;
+        let mut telemetry = Self {
+            config,
+            dir,
+            consent,
+            queue,
+            queue_dirty: false,
+            http: None,
+            in_flight: None,
+            failures: 0,
+            next_flush,
+            session_id: random_session_id(),
+            session_start: None,
+            previous_session_crashed,
+        };
+        if consent == TelemetryConsent::Granted {
+            telemetry.start_session();
+        }
+        telemetry
+    }
+
+    pub fn consent(&self) -> TelemetryConsent {
+        self.consent
+    }
+
+    /// Persists the player's choice. Revoking drops everything still queued.
+    pub fn set_consent(&mut self, granted: bool) {
+        let consent = if granted {
+            TelemetryConsent::Granted
+        } else {
+            TelemetryConsent::Denied
+        };
+        let _ = fs::create_dir_all(&self.dir);
+        let _ = fs::write(self.dir.join(CONSENT_FILE), consent.as_str());
+        self.consent = consent;
+        if granted {
+            self.start_session();
+        } else {
+            self.queue.clear();
+            self.queue_dirty = false;
+            self.in_flight = None;
+            self.session_start = None;
+            let _ = fs::remove_file(self.dir.join(QUEUE_FILE));
+            let _ = fs::remove_file(self.dir.join(SESSION_MARKER_FILE));
+        }
+    }
+
+    pub fn record_resolution(&mut self, width: u32, height: u32) {
+        self.record("resolution", json!({ "width": width, "height": height }));
+    }
+
+    /// Custom developer event; `data` should stay free of personal info.
+    pub fn record_event(&mut self, name: &str, data: Value) {
+        self.record(name, data);
+    }
+
+    /// Call once per frame (or less): collects send results, flushes when due.
+    pub fn update(&mut self) {
+        self.poll_http();
+        let due = Instant::now() >= self.next_flush || self.queue.len() >= self.config.batch_size;
+        if due {
+            self.flush();
+        }
+    }
+
+    /// Records session length, tries one last send, and clears the crash
+    /// marker. Anything unsent stays queued for the next launch.
+    pub fn end_session(&mut self) {
+        let Some(start) = self.session_start.take() else {
+            return;
+        };
+        self.push(
+            "session_end",
+            json!({ "length_secs": start.elapsed().as_secs_f64() }),
+        );
+        self.next_flush = Instant::now();
+        self.flush();
+        self.persist_queue();
+        let _ = fs::remove_file(self.dir.join(SESSION_MARKER_FILE));
+    }
+
+    pub fn queued(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn previous_session_crashed(&self) -> bool {
+        self.previous_session_crashed
+    }
+
+    fn start_session(&mut self) {
+        if self.session_start.is_some() {
+            return;
+        }
+        self.session_start = Some(Instant::now());
+        let _ = fs::create_dir_all(&self.dir);
+        let _ = fs::write(self.dir.join(SESSION_MARKER_FILE), &self.session_id);
+        let hardware = HardwareClass::detect();
+        self.push(
+            "session_start",
+            json!({
+                "os": std::env::consts::OS,
+                "arch": std::env::consts::ARCH,
+                "hardware_class": hardware.as_str(),
+                "previous_session_crashed": self.previous_session_crashed,
+            }),
+        );
+    }
+
+    fn record(&mut self, name: &str, data: Value) {
+        if self.session_start.is_some() {
+            self.push(name, data);
+        }
+    }
+
+    fn push(&mut self, name: &str, data: Value) {
+        let t = self
+            .session_start
+            .map_or(0.0, |start| start.elapsed().as_secs_f64());
+        self.queue.push(json!({
+            "session": self.session_id,
+            "event": name,
+            "t": t,
+            "data": data,
+        }));
+        self.queue_dirty = true;
+    }
+
+    fn flush(&mut self) {
+        if self.consent != TelemetryConsent::Granted
+            || self.in_flight.is_some()
+            || self.queue.is_empty()
+            || Instant::now() < self.next_flush
+        {
+            return;
+        }
+        let batch = self.queue.front_batch(self.config.batch_size);
+        let count = batch.len();
+        let mut request = HttpRequest::post_bytes(
+            self.config.endpoint.clone(),
+            Value::Array(batch).to_string().into_bytes(),
+        )
+        .header("Content-Type", "application/json");
+        for (name, value) in &self.config.headers {
+            request = request.header(name.clone(), value.clone());
+        }
+        let http = self.http.get_or_insert_with(|| {
+            HttpClient::with_config_and_queue(
+                HttpConfig::default().timeout_ms(10_000),
+                HttpQueueConfig::default()
+                    .worker_count(1)
+                    .request_capacity(2),
+            )
+        });
+        let id = http.request(request);
+        self.in_flight = Some(InFlight { id, count });
+        self.persist_queue();
+    }
+
+    fn poll_http(&mut self) {
+        let Some(http) = self.http.as_mut() else {
+            return;
+        };
+        while let Some(event) = http.poll() {
+            let (id, delivered) = match &event {
+                HttpEvent::Completed(response) => (
+                    response.id,
+                    // 4xx (bar 408/429) won't improve on retry; drop the batch.
+                    response.ok() || !is_retryable_status(response.status),
+                ),
+                HttpEvent::Failed(error) => (error.id, false),
+            };
+            let Some(in_flight) = self.in_flight.take_if(|flight| flight.id == id) else {
+                continue;
+            };
+            if delivered {
+                self.queue.drop_front(in_flight.count);
+                self.queue_dirty = true;
+                self.failures = 0;
+                self.next_flush = Instant::now() + self.config.flush_interval;
+            } else {
+                self.next_flush = Instant::now() + self.config.retry.delay(self.failures);
+                self.failures = self.failures.saturating_add(1);
+            }
+        }
+        self.persist_queue();
+    }
+
+    fn persist_queue(&mut self) {
+        if !self.queue_dirty {
+            return;
+        }
+        self.queue_dirty = false;
+        let _ = fs::create_dir_all(&self.dir);
+        let _ = fs::write(self.dir.join(QUEUE_FILE), self.queue.to_jsonl());
+    }
+}
+
+impl Drop for Telemetry {
+    fn drop(&mut self) {
+        self.end_session();
+        self.persist_queue();
+    }
+}
+
+pub fn is_retryable_status(status: u16) -> bool {
+    status == 408 || status == 429 || status >= 500
+}
+
+fn default_storage_dir() -> PathBuf {
+    match perro_io::resolve_path(DEFAULT_STORAGE_PATH) {
+        perro_io::ResolvedPath::Disk(path) => path,
+        _ => std::env::temp_dir().join("perro_telemetry"),
+    }
+}
+
+fn random_session_id() -> String {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos()),
+    );
+    hasher.write_u32(std::process::id());
+    format!("{:016x}", hasher.finish())
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/telemetry_tests.rs"]
+mod tests;
@@ -14,6 +14,7 @@ use std::{
 use perro_ids::SignalID;
 use perro_variant::Variant;
 
+use crate::HttpCloudSave;
 use crate::http::{
     HttpClient, HttpConfig, HttpErrorKind, HttpEvent, HttpID, HttpProxy, HttpQueueConfig,
     HttpResponse, HttpSubmitErrorKind, HttpTLSMode,
@@ -336,6 +337,57 @@ fn http_worker_pool_runs_requests_concurrently_with_bounded_events() {
     assert_eq!(client.queue_config(), queue);
 }
 
+#[test]
+#[cfg_attr(
+    not(feature = "network-tests"),
+    ignore = "requires local socket access"
+)]
+fn http_cloud_save_puts_file_then_manifest_and_lists_from_manifest() {
+    use perro_modules::cloud_save::{CloudFile, CloudSave};
+
+    let lines = Arc::new(Mutex::new(Vec::new()));
+    let seen = lines.clone();
+    let mut manifest = Vec::new();
+    let server = TestServer::start_multi(4, move |request| {
+        seen.lock()
+            .expect("test setup must succeed")
+            .push(request.line.clone());
+        match request.line.split(' ').take(2).collect::<Vec<_>>()[..] {
+            ["GET", "/saves/cloud_manifest.json"] if manifest.is_empty() => response(404, &[], b""),
+            ["GET", "/saves/cloud_manifest.json"] => response(200, &[], &manifest),
+            ["PUT", "/saves/cloud_manifest.json"] => {
+                manifest = request.body;
+                response(200, &[], b"")
+            }
+            _ => response(200, &[], b""),
+        }
+    });
+    let mut cloud = HttpCloudSave::new(server.url("/saves/"));
+
+    cloud
+        .upload("slot_1.json", b"{}", 42)
+        .expect("upload must succeed");
+    let files = cloud.list().expect("list must succeed");
+    server.join();
+
+    assert_eq!(
+        files,
+        vec![CloudFile {
+            name: "slot_1.json".to_string(),
+            modified: 42,
+        }]
+    );
+    assert_eq!(
+        *lines.lock().expect("test setup must succeed"),
+        vec![
+            "GET /saves/cloud_manifest.json HTTP/1.1",
+            "PUT /saves/slot_1.json HTTP/1.1",
+            "PUT /saves/cloud_manifest.json HTTP/1.1",
+            "GET /saves/cloud_manifest.json HTTP/1.1",
+        ]
+    );
+}
+
 fn http_event_id(event: &HttpEvent) -> HttpID {
     match event {
         HttpEvent::Completed(response) => response.id,
@@ -399,6 +451,8 @@ impl TestServer {
 
 #[derive(Debug)]
 struct TestRequest {
+    /// `GET /path HTTP/1.1`.
+    line: String,
     headers: Vec<(String, String)>,
     body: Vec<u8>,
 }
@@ -420,6 +474,7 @@ fn read_request(stream: &mut TcpStream) -> TestRequest {
     }
 
     let header_text = String::from_utf8_lossy(&data[..header_end]);
+    let line = header_text.lines().next().unwrap_or_default().to_string();
     let headers = parse_headers(&header_text);
     let content_len = headers
         .iter()
@@ -433,6 +488,7 @@ fn read_request(stream: &mut TcpStream) -> TestRequest {
     }
 
     TestRequest {
+        line,
         headers,
         body: data[body_start..body_start + content_len].to_vec(),
     }
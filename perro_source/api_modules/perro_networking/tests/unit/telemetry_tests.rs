@@ -0,0 +1,155 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde_json::json;
+
+use super::{
+    HardwareClass, QUEUE_FILE, SESSION_MARKER_FILE, Telemetry, TelemetryConfig, TelemetryConsent,
+    TelemetryQueue, TelemetryRetry, is_retryable_status,
+};
+
+fn temp_dir(tag: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("perro_telemetry_{tag}_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn config(dir: &Path) -> TelemetryConfig {
+    // Discard port: the one send on drop fails fast and records stay queued.
+    TelemetryConfig::new("http://127.0.0.1:9/telemetry")
+        .storage_dir(dir)
+        .flush_interval(Duration::from_secs(3600))
+}
+
+#[test]
+fn queue_drops_oldest_when_full() {
+    let mut queue = TelemetryQueue::new(2);
+    queue.push(json!(1));
+    queue.push(json!(2));
+    queue.push(json!(3));
+    assert_eq!(queue.len(), 2);
+    assert_eq!(queue.front_batch(8), vec![json!(2), json!(3)]);
+}
+
+#[test]
+fn queue_jsonl_roundtrip_skips_torn_lines() {
+    let mut queue = TelemetryQueue::new(8);
+    queue.push(json!({"event": "a"}));
+    queue.push(json!({"event": "b"}));
+    let text = format!("{}{{\"event\": \"tor", queue.to_jsonl());
+    let loaded = TelemetryQueue::from_jsonl(&text, 8);
+    assert_eq!(loaded, queue);
+}
+
+#[test]
+fn retry_backoff_doubles_and_caps() {
+    let retry = TelemetryRetry {
+        base: Duration::from_secs(5),
+        max: Duration::from_secs(60),
+    };
+    assert_eq!(retry.delay(0), Duration::from_secs(5));
+    assert_eq!(retry.delay(1), Duration::from_secs(10));
+    assert_eq!(retry.delay(3), Duration::from_secs(40));
+    assert_eq!(retry.delay(4), Duration::from_secs(60));
+    assert_eq!(retry.delay(u32::MAX), Duration::from_secs(60));
+}
+
+#[test]
+fn only_server_and_throttle_statuses_retry() {
+    assert!(is_retryable_status(500));
+    assert!(is_retryable_status(503));
+    assert!(is_retryable_status(429));
+    assert!(is_retryable_status(408));
+    assert!(!is_retryable_status(400));
+    assert!(!is_retryable_status(404));
+}
+
+#[test]
+fn hardware_class_buckets_cpu_threads() {
+    assert_eq!(HardwareClass::from_cpu_threads(2), HardwareClass::Low);
+    assert_eq!(HardwareClass::from_cpu_threads(8), HardwareClass::Mid);
+    assert_eq!(HardwareClass::from_cpu_threads(16), HardwareClass::High);
+}
+
+#[test]
+fn nothing_recorded_without_consent() {
+    let dir = temp_dir("no_consent");
+    let mut telemetry = Telemetry::new(config(&dir));
+    assert_eq!(telemetry.consent(), TelemetryConsent::Unknown);
+    telemetry.record_resolution(1920, 1080);
+    telemetry.update();
+    assert_eq!(telemetry.queued(), 0);
+    drop(telemetry);
+    assert!(!dir.join(QUEUE_FILE).exists());
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn consent_persists_and_revoking_clears_queue() {
+    let dir = temp_dir("consent");
+    let mut telemetry = Telemetry::new(config(&dir));
+    telemetry.set_consent(true);
+    telemetry.record_resolution(1280, 720);
+    assert_eq!(telemetry.queued(), 2);
+    drop(telemetry);
+
+    let mut telemetry = Telemetry::new(config(&dir));
+    assert_eq!(telemetry.consent(), TelemetryConsent::Granted);
+    assert!(telemetry.queued() >= 3);
+    telemetry.set_consent(false);
+    assert_eq!(telemetry.queued(), 0);
+    drop(telemetry);
+
+    let telemetry = Telemetry::new(config(&dir));
+    assert_eq!(telemetry.consent(), TelemetryConsent::Denied);
+    assert_eq!(telemetry.queued(), 0);
+    drop(telemetry);
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn records_reach_disk_on_flush_or_drop_not_per_event() {
+    let dir = temp_dir("persist");
+    let mut telemetry = Telemetry::new(config(&dir));
+    telemetry.set_consent(true);
+    telemetry.record_resolution(1280, 720);
+    telemetry.record_event("level", json!({ "id": 3 }));
+    assert!(!dir.join(QUEUE_FILE).exists());
+    drop(telemetry);
+
+    let telemetry = Telemetry::new(config(&dir));
+    assert!(telemetry.queue.records.len() >= 4);
+    drop(telemetry);
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn leftover_session_marker_flags_previous_crash() {
+    let dir = temp_dir("crash");
+    let mut telemetry = Telemetry::new(config(&dir));
+    telemetry.set_consent(true);
+    assert!(dir.join(SESSION_MARKER_FILE).exists());
+    // Simulate a hard crash: no `end_session`, no drop.
+    std::mem::forget(telemetry);
+
+    let telemetry = Telemetry::new(config(&dir));
+    assert!(telemetry.previous_session_crashed());
+    let start = telemetry
+        .queue
+        .records
+        .iter()
+        .rev()
+        .find(|record| record["event"] == "session_start")
+        .cloned()
+        .expect("session_start queued");
+    assert_eq!(start["data"]["previous_session_crashed"], json!(true));
+    drop(telemetry);
+
+    let telemetry = Telemetry::new(config(&dir));
+    assert!(!telemetry.previous_session_crashed());
+    drop(telemetry);
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -1,9 +1,9 @@
 use crate::sub_apis::{
     AnimationAPI, AnimationModule, AnimationTreeAPI, AnimationTreeModule, AudioAPI, AudioModule,
-    CsvAPI, CsvModule, DisplayModule, Draw2DAPI, Draw2DModule, GlbModule, GltfAPI, IntoLocale,
-    Locale, LocalizationAPI, LocalizationModule, MaterialAPI, MaterialModule, MeshAPI, MeshModule,
-    MicAPI, MicModule, NavMeshAPI, NavMeshModule, PostProcessingAPI, SceneDocAPI, SceneDocModule,
-    SkeletonAPI, SkeletonModule, TextureAPI, TextureModule, VideoAPI, VideoModule,
+    CsvAPI, CsvModule, DataModule, DisplayModule, Draw2DAPI, Draw2DModule, GlbModule, GltfAPI,
+    IntoLocale, Locale, LocalizationAPI, LocalizationModule, MaterialAPI, MaterialModule, MeshAPI,
+    MeshModule, MicAPI, MicModule, NavMeshAPI, NavMeshModule, PostProcessingAPI, SceneDocAPI,
+    SceneDocModule, SkeletonAPI, SkeletonModule, TextureAPI, TextureModule, VideoAPI, VideoModule,
     VisualAccessibilityAPI, WebcamAPI, WebcamModule,
 };
 use crate::{LoadResult, ResPathSource};
@@ -131,6 +131,12 @@ impl<'res, R: ResourceAPI + ?Sized> ResourceWindow<'res, R> {
         CsvModule::new(self.api)
     }
 
+    /// Access named `res://data/` tables with keyed, typed row reads.
+    #[inline]
+    pub fn Data(&self) -> DataModule<'_, R> {
+        DataModule::new(self.api)
+    }
+
     /// Access mesh load, reserve, create, inspect, and write helpers.
     #[inline]
     pub fn Meshes(&self) -> MeshModule<'_, R> {
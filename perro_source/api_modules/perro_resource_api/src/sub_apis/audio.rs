@@ -36,11 +36,16 @@ pub trait AudioAPI {
     fn play_audio_2d(&self, bus_id: Option<AudioBusID>, audio: Audio2D<'_>) -> bool;
     fn play_audio_3d(&self, bus_id: Option<AudioBusID>, audio: Audio3D<'_>) -> bool;
     fn stop_audio(&self, bus_id: Option<AudioBusID>, audio: Audio<'_>, pan: AudioPan) -> bool;
+    fn crossfade_audio(&self, bus_id: Option<AudioBusID>, audio: Audio<'_>, seconds: f32) -> bool;
     fn stop_audio_source(&self, source: &str) -> bool;
     fn audio_length_seconds(&self, source: &str) -> Option<f32>;
     fn stop_all_audio(&self);
     fn set_master_volume(&self, volume: f32) -> bool;
+    fn set_master_muted(&self, muted: bool) -> bool;
+    fn set_master_effects(&self, effects: AudioBusEffects) -> bool;
     fn set_bus_volume(&self, bus_id: AudioBusID, volume: f32) -> bool;
+    fn set_bus_muted(&self, bus_id: AudioBusID, muted: bool) -> bool;
+    fn set_bus_effects(&self, bus_id: AudioBusID, effects: AudioBusEffects) -> bool;
     fn set_bus_speed(&self, bus_id: AudioBusID, speed: f32) -> bool;
     fn pause_bus(&self, bus_id: AudioBusID) -> bool;
     fn resume_bus(&self, bus_id: AudioBusID) -> bool;
@@ -87,6 +92,43 @@ pub enum AudioDirection<T> {
     Bidirectional(T),
 }
 
+/// Distance falloff curve. Every curve is 1 at the source and 0 at `range`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AudioAttenuation {
+    /// Straight line to silence.
+    Linear,
+    /// Squared line: louder up close, quick tail. Engine default.
+    #[default]
+    Quadratic,
+    /// Physical 1/d rolloff; `ref_distance` keeps full volume, then fades to 0 at range.
+    InverseDistance { ref_distance: f32 },
+    /// No falloff inside range (ambience, radios).
+    Constant,
+}
+
+impl AudioAttenuation {
+    pub fn gain(self, distance: f32, range: f32) -> f32 {
+        let t = (distance / range.max(0.0001)).clamp(0.0, 1.0);
+        match self {
+            Self::Linear => 1.0 - t,
+            Self::Quadratic => (1.0 - t) * (1.0 - t),
+            Self::InverseDistance { ref_distance } => {
+                let ref_distance = ref_distance.max(0.0001);
+                let inverse = ref_distance / distance.max(ref_distance);
+                // Edge fade so inverse curve still hits 0 at range.
+                inverse * (1.0 - t * t * t * t)
+            }
+            Self::Constant => {
+                if t >= 1.0 {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct SpatialAudioOptions {
     pub range: f32,
@@ -94,6 +136,9 @@ pub struct SpatialAudioOptions {
     pub enable_propagation: bool,
     pub direction_2d: AudioDirection<Vector2>,
     pub direction_3d: AudioDirection<Vector3>,
+    pub attenuation: AudioAttenuation,
+    /// 3D Doppler pitch scale. 0 = off, 1 = physical (343 units/s sound speed).
+    pub doppler: f32,
 }
 
 pub trait MidiSpatialPos {
@@ -223,6 +268,48 @@ impl Default for AudioEffects {
     }
 }
 
+/// Bus-wide chain: low-pass -> reverb -> compressor. Stacks w/ each sound's
+/// own effects and the master chain; stronger stage wins.
+#[derive(Clone, Copy, Debug)]
+pub struct AudioBusEffects {
+    /// 0 = dry, 1 = fully muffled.
+    pub low_pass: f32,
+    pub reverb: f32,
+    pub compression: AudioCompression,
+}
+
+impl AudioBusEffects {
+    pub const fn new() -> Self {
+        Self {
+            low_pass: 0.0,
+            reverb: 0.0,
+            compression: AudioCompression::new(),
+        }
+    }
+
+    pub const fn with_low_pass(mut self, low_pass: f32) -> Self {
+        self.low_pass = low_pass;
+        self
+    }
+
+    pub const fn with_reverb(mut self, reverb: f32) -> Self {
+        self.reverb = reverb;
+        self
+    }
+
+    pub const fn with_compression(mut self, threshold: f32, ratio: f32) -> Self {
+        self.compression.threshold = threshold;
+        self.compression.ratio = ratio;
+        self
+    }
+}
+
+impl Default for AudioBusEffects {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct Audio<'a> {
     pub source: &'a str,
@@ -231,6 +318,10 @@ pub struct Audio<'a> {
     pub effects: AudioEffects,
     pub from_start: f32,
     pub from_end: f32,
+    /// Looped playback jumps back here (seconds into the clip); 0 = `from_start`.
+    pub loop_start: f32,
+    /// Looped playback wraps here (seconds into the clip); 0 = clip end.
+    pub loop_end: f32,
 }
 
 impl<'a> Audio<'a> {
@@ -242,9 +333,19 @@ impl<'a> Audio<'a> {
             effects: AudioEffects::new(),
             from_start: 0.0,
             from_end: 0.0,
+            loop_start: 0.0,
+            loop_end: 0.0,
         }
     }
 
+    /// Loop forever: play the intro once, then repeat `[loop_start, loop_end)`.
+    pub const fn with_loop_points(mut self, loop_start: f32, loop_end: f32) -> Self {
+        self.looped = true;
+        self.loop_start = loop_start;
+        self.loop_end = loop_end;
+        self
+    }
+
     pub const fn with_effects(mut self, effects: AudioEffects) -> Self {
         self.effects = effects;
         self
@@ -303,6 +404,7 @@ pub struct Audio2D<'a> {
     pub audio_layer: BitMask,
     pub enable_propagation: bool,
     pub direction: Option<AudioDirection<Vector2>>,
+    pub attenuation: AudioAttenuation,
 }
 
 impl<'a> Audio2D<'a> {
@@ -314,6 +416,7 @@ impl<'a> Audio2D<'a> {
             audio_layer: BitMask::ALL,
             enable_propagation: true,
             direction: None,
+            attenuation: AudioAttenuation::Quadratic,
         }
     }
 
@@ -325,8 +428,14 @@ impl<'a> Audio2D<'a> {
             audio_layer: BitMask::ALL,
             enable_propagation: true,
             direction: None,
+            attenuation: AudioAttenuation::Quadratic,
         }
     }
+
+    pub const fn with_attenuation(mut self, attenuation: AudioAttenuation) -> Self {
+        self.attenuation = attenuation;
+        self
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -337,6 +446,8 @@ pub struct Audio3D<'a> {
     pub audio_layer: BitMask,
     pub enable_propagation: bool,
     pub direction: Option<AudioDirection<Vector3>>,
+    pub attenuation: AudioAttenuation,
+    pub doppler: f32,
 }
 
 impl<'a> Audio3D<'a> {
@@ -348,6 +459,8 @@ impl<'a> Audio3D<'a> {
             audio_layer: BitMask::ALL,
             enable_propagation: true,
             direction: None,
+            attenuation: AudioAttenuation::Quadratic,
+            doppler: 0.0,
         }
     }
 
@@ -359,8 +472,21 @@ impl<'a> Audio3D<'a> {
             audio_layer: BitMask::ALL,
             enable_propagation: true,
             direction: None,
+            attenuation: AudioAttenuation::Quadratic,
+            doppler: 0.0,
         }
     }
+
+    pub const fn with_attenuation(mut self, attenuation: AudioAttenuation) -> Self {
+        self.attenuation = attenuation;
+        self
+    }
+
+    /// Pitch shift from relative listener/source motion; 1 = physical.
+    pub const fn with_doppler(mut self, doppler: f32) -> Self {
+        self.doppler = doppler;
+        self
+    }
 }
 
 pub trait AudioPlayConfig<R: AudioAPI + ?Sized> {
@@ -535,6 +661,17 @@ impl<'res, R: AudioAPI + ?Sized> AudioModule<'res, R> {
         self.api.stop_audio(None, audio, AudioPan::CENTER)
     }
 
+    /// Fade out every voice on the bus while `audio` fades in over `seconds`.
+    #[inline]
+    pub fn crossfade(&self, bus_id: AudioBusID, audio: Audio<'_>, seconds: f32) -> bool {
+        self.api.crossfade_audio(Some(bus_id), audio, seconds)
+    }
+
+    #[inline]
+    pub fn crossfade_master(&self, audio: Audio<'_>, seconds: f32) -> bool {
+        self.api.crossfade_audio(None, audio, seconds)
+    }
+
     #[inline]
     pub fn stop_source<S: ResPathSource>(&self, source: S) -> bool {
         self.api.stop_audio_source(source.as_res_path_str())
@@ -561,11 +698,31 @@ impl<'res, R: AudioAPI + ?Sized> AudioModule<'res, R> {
         self.api.set_master_volume(volume)
     }
 
+    #[inline]
+    pub fn set_master_muted(&self, muted: bool) -> bool {
+        self.api.set_master_muted(muted)
+    }
+
+    #[inline]
+    pub fn set_master_effects(&self, effects: AudioBusEffects) -> bool {
+        self.api.set_master_effects(effects)
+    }
+
     #[inline]
     pub fn set_bus_volume(&self, bus_id: AudioBusID, volume: f32) -> bool {
         self.api.set_bus_volume(bus_id, volume)
     }
 
+    #[inline]
+    pub fn set_bus_muted(&self, bus_id: AudioBusID, muted: bool) -> bool {
+        self.api.set_bus_muted(bus_id, muted)
+    }
+
+    #[inline]
+    pub fn set_bus_effects(&self, bus_id: AudioBusID, effects: AudioBusEffects) -> bool {
+        self.api.set_bus_effects(bus_id, effects)
+    }
+
     #[inline]
     pub fn set_bus_speed(&self, bus_id: AudioBusID, speed: f32) -> bool {
         self.api.set_bus_speed(bus_id, speed)
@@ -807,6 +964,16 @@ macro_rules! audio_stop {
     };
 }
 
+#[macro_export]
+macro_rules! audio_crossfade {
+    ($res:expr, $bus_id:expr, $audio:expr, $seconds:expr) => {
+        $res.Audio().crossfade($bus_id, $audio, $seconds)
+    };
+    ($res:expr, $audio:expr, $seconds:expr) => {
+        $res.Audio().crossfade_master($audio, $seconds)
+    };
+}
+
 #[macro_export]
 macro_rules! audio_stop_source {
     ($res:expr, $source:expr) => {
@@ -842,6 +1009,20 @@ macro_rules! audio_set_master_volume {
     };
 }
 
+#[macro_export]
+macro_rules! audio_set_master_muted {
+    ($res:expr, $muted:expr) => {
+        $res.Audio().set_master_muted($muted)
+    };
+}
+
+#[macro_export]
+macro_rules! audio_set_master_effects {
+    ($res:expr, $effects:expr) => {
+        $res.Audio().set_master_effects($effects)
+    };
+}
+
 #[macro_export]
 macro_rules! audio_bus_set_volume {
     ($res:expr, $bus_id:expr, $volume:expr) => {
@@ -849,6 +1030,20 @@ macro_rules! audio_bus_set_volume {
     };
 }
 
+#[macro_export]
+macro_rules! audio_bus_set_muted {
+    ($res:expr, $bus_id:expr, $muted:expr) => {
+        $res.Audio().set_bus_muted($bus_id, $muted)
+    };
+}
+
+#[macro_export]
+macro_rules! audio_bus_set_effects {
+    ($res:expr, $bus_id:expr, $effects:expr) => {
+        $res.Audio().set_bus_effects($bus_id, $effects)
+    };
+}
+
 #[macro_export]
 macro_rules! audio_bus_set_speed {
     ($res:expr, $bus_id:expr, $speed:expr) => {
@@ -1042,6 +1237,15 @@ mod tests {
             true
         }
 
+        fn crossfade_audio(
+            &self,
+            _bus_id: Option<AudioBusID>,
+            _audio: Audio<'_>,
+            _seconds: f32,
+        ) -> bool {
+            true
+        }
+
         fn stop_audio_source(&self, _source: &str) -> bool {
             true
         }
@@ -1056,10 +1260,26 @@ mod tests {
             true
         }
 
+        fn set_master_muted(&self, _muted: bool) -> bool {
+            true
+        }
+
+        fn set_master_effects(&self, _effects: AudioBusEffects) -> bool {
+            true
+        }
+
         fn set_bus_volume(&self, _bus_id: AudioBusID, _volume: f32) -> bool {
             true
         }
 
+        fn set_bus_muted(&self, _bus_id: AudioBusID, _muted: bool) -> bool {
+            true
+        }
+
+        fn set_bus_effects(&self, _bus_id: AudioBusID, _effects: AudioBusEffects) -> bool {
+            true
+        }
+
         fn set_bus_speed(&self, _bus_id: AudioBusID, _speed: f32) -> bool {
             true
         }
@@ -1179,11 +1399,37 @@ mod tests {
             AudioClip::new(&clip).with_volume(0.5)
         ));
         assert!(crate::audio_play_clip!(res, bus, &clip, 0.5));
+        assert!(crate::audio_crossfade!(
+            res,
+            bus,
+            Audio::new("res://theme.ogg").with_loop_points(4.0, 96.0),
+            2.0
+        ));
         assert!(crate::audio_play_stream_clip!(
             res, bus, "voice/2", &clip, 0.5
         ));
     }
 
+    #[test]
+    fn attenuation_curves_hit_full_and_silent_ends() {
+        let curves = [
+            AudioAttenuation::Linear,
+            AudioAttenuation::Quadratic,
+            AudioAttenuation::InverseDistance { ref_distance: 1.0 },
+            AudioAttenuation::Constant,
+        ];
+        for curve in curves {
+            assert!((curve.gain(0.0, 10.0) - 1.0).abs() < 1e-5, "{curve:?}");
+            assert_eq!(curve.gain(10.0, 10.0), 0.0, "{curve:?}");
+        }
+        assert!((AudioAttenuation::Linear.gain(5.0, 10.0) - 0.5).abs() < 1e-5);
+        assert!((AudioAttenuation::Quadratic.gain(5.0, 10.0) - 0.25).abs() < 1e-5);
+        assert_eq!(AudioAttenuation::Constant.gain(9.0, 10.0), 1.0);
+        let inverse = AudioAttenuation::InverseDistance { ref_distance: 1.0 };
+        assert!(inverse.gain(2.0, 10.0) < 0.5);
+        assert!(inverse.gain(2.0, 10.0) > inverse.gain(4.0, 10.0));
+    }
+
     #[test]
     fn midi_macros_dispatch() {
         let api = DummyAudioApi;
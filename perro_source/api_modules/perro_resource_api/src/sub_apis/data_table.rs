@@ -0,0 +1,297 @@
+//! Data table resource API.
+//!
+//! Resolves spreadsheet-style tables under `res://data/` by name and reads
+//! keyed rows as [`Variant`] values. Tables are ordinary CSV tables (JSON
+//! tables are converted at build/load time), so this module sits on top of
+//! [`CsvAPI`] and needs no backing store of its own.
+
+use crate::sub_apis::CsvAPI;
+use perro_csv::{Csv, CsvRow, EMPTY_CSV};
+use perro_variant::Variant;
+use std::{collections::BTreeMap, sync::Arc};
+
+/// Directory data table names resolve under.
+pub const DATA_TABLE_ROOT: &str = "res://data/";
+
+pub struct DataModule<'res, R: CsvAPI + ?Sized> {
+    api: &'res R,
+}
+
+impl<'res, R: CsvAPI + ?Sized> DataModule<'res, R> {
+    pub fn new(api: &'res R) -> Self {
+        Self { api }
+    }
+
+    /// Table named `name`: `res://data/<name>.csv`, else `res://data/<name>.json`.
+    /// Missing tables come back empty with [`DataTable::exists`] `false`.
+    pub fn table(&self, name: &str) -> DataTable {
+        let csv = format!("{DATA_TABLE_ROOT}{name}.csv");
+        let json = format!("{DATA_TABLE_ROOT}{name}.json");
+        self.table_hashed_with_sources(
+            perro_ids::string_to_u64(&csv),
+            &csv,
+            perro_ids::string_to_u64(&json),
+            &json,
+        )
+    }
+
+    /// Used by `data_table!` with compile-time hashes of both candidate paths.
+    pub fn table_hashed_with_sources(
+        &self,
+        csv_hash: u64,
+        csv_source: &str,
+        json_hash: u64,
+        json_source: &str,
+    ) -> DataTable {
+        let csv = self.api.load_csv_source_hashed(csv_hash, Some(csv_source));
+        if !csv.headers().is_empty() {
+            return DataTable::new(csv);
+        }
+        DataTable::new(
+            self.api
+                .load_csv_source_hashed(json_hash, Some(json_source)),
+        )
+    }
+}
+
+/// Keyed view over a loaded table. The first column is the row key.
+#[derive(Clone, Copy, Debug)]
+pub struct DataTable {
+    csv: &'static Csv,
+}
+
+impl DataTable {
+    #[inline]
+    pub fn new(csv: &'static Csv) -> Self {
+        Self { csv }
+    }
+
+    /// An empty, missing table.
+    #[inline]
+    pub fn empty() -> Self {
+        Self { csv: &EMPTY_CSV }
+    }
+
+    /// `false` when no table file was found.
+    #[inline]
+    pub fn exists(&self) -> bool {
+        !self.csv.headers().is_empty()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.csv.row_count()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.csv.is_empty()
+    }
+
+    /// Column names, key column first.
+    pub fn columns(&self) -> impl Iterator<Item = &'static str> {
+        self.csv.headers().iter().map(|cell| cell.text)
+    }
+
+    /// Row whose key column equals `key`.
+    #[inline]
+    pub fn row(&self, key: &str) -> Option<DataRow> {
+        self.csv
+            .find_primary(key)
+            .map(|row| DataRow::new(self.csv, row))
+    }
+
+    /// Rows in file order.
+    pub fn rows(&self) -> impl Iterator<Item = DataRow> {
+        let csv = self.csv;
+        csv.rows().iter().map(move |row| DataRow::new(csv, row))
+    }
+
+    /// Shorthand for `row(key).get(column)`; `Variant::Null` when either is missing.
+    pub fn get(&self, key: &str, column: &str) -> Variant {
+        self.row(key)
+            .map(|row| row.get(column))
+            .unwrap_or(Variant::Null)
+    }
+
+    /// Underlying table, for `query()` filters and sorting.
+    #[inline]
+    pub fn csv(&self) -> &'static Csv {
+        self.csv
+    }
+}
+
+/// One keyed row of a [`DataTable`].
+#[derive(Clone, Copy, Debug)]
+pub struct DataRow {
+    csv: &'static Csv,
+    row: &'static CsvRow,
+}
+
+impl DataRow {
+    #[inline]
+    fn new(csv: &'static Csv, row: &'static CsvRow) -> Self {
+        Self { csv, row }
+    }
+
+    /// Key column text.
+    #[inline]
+    pub fn key(&self) -> &'static str {
+        self.row.get(0).unwrap_or_default()
+    }
+
+    /// Raw cell text by column name.
+    pub fn text(&self, column: &str) -> Option<&'static str> {
+        self.csv
+            .header_index(column)
+            .and_then(|col| self.row.get(col))
+    }
+
+    /// Typed cell by column name; see [`data_cell_variant`]. `Variant::Null`
+    /// for unknown columns and empty cells.
+    pub fn get(&self, column: &str) -> Variant {
+        self.text(column)
+            .map(data_cell_variant)
+            .unwrap_or(Variant::Null)
+    }
+
+    /// Whole row as a `Variant::Object`; the key column stays a string.
+    pub fn to_variant(&self) -> Variant {
+        let mut object = BTreeMap::new();
+        for (col, header) in self.csv.headers().iter().enumerate() {
+            let text = self.row.get(col).unwrap_or_default();
+            let value = if col == 0 {
+                Variant::from(text)
+            } else {
+                data_cell_variant(text)
+            };
+            object.insert(Arc::<str>::from(header.text), value);
+        }
+        Variant::Object(object)
+    }
+}
+
+/// Types one cell: empty is `Null`, `true`/`false` are `Bool`, integers and
+/// finite floats are `Number`, `[..]`/`{..}` JSON text becomes `Array`/
+/// `Object`, and anything else stays a `String`. Use [`DataRow::text`] when a
+/// numeric-looking cell (e.g. `"007"`) must stay text.
+pub fn data_cell_variant(text: &str) -> Variant {
+    match text {
+        "" => return Variant::Null,
+        "true" => return Variant::Bool(true),
+        "false" => return Variant::Bool(false),
+        _ => {}
+    }
+    if let Ok(value) = text.parse::<i64>() {
+        return Variant::from(value);
+    }
+    if let Ok(value) = text.parse::<f64>()
+        && value.is_finite()
+    {
+        return Variant::from(value);
+    }
+    if (text.starts_with('[') || text.starts_with('{'))
+        && let Ok(value) = serde_json::from_str(text)
+    {
+        return Variant::from_json_value(value);
+    }
+    Variant::from(text)
+}
+
+#[macro_export]
+macro_rules! data_table {
+    ($res:expr, $name:literal) => {{
+        const __CSV: &str = concat!("res://data/", $name, ".csv");
+        const __JSON: &str = concat!("res://data/", $name, ".json");
+        const __CSV_HASH: u64 = $crate::__perro_string_to_u64(__CSV);
+        const __JSON_HASH: u64 = $crate::__perro_string_to_u64(__JSON);
+        $res.Data()
+            .table_hashed_with_sources(__CSV_HASH, __CSV, __JSON_HASH, __JSON)
+    }};
+    ($res:expr, $name:expr) => {
+        $res.Data().table($name)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perro_csv::{CsvBuf, parse_csv_static, parse_json_table_static};
+    use std::collections::HashMap;
+
+    struct TableStore {
+        tables: HashMap<u64, &'static Csv>,
+    }
+
+    impl CsvAPI for TableStore {
+        fn load_csv_source_hashed(&self, source_hash: u64, _source: Option<&str>) -> &'static Csv {
+            self.tables.get(&source_hash).copied().unwrap_or(&EMPTY_CSV)
+        }
+
+        fn load_csv_bytes(&self, bytes: &[u8]) -> &'static Csv {
+            parse_csv_static(bytes).unwrap_or(&EMPTY_CSV)
+        }
+
+        fn save_csv_source(&self, _source: &str, _csv: &CsvBuf) -> Result<(), String> {
+            Err("read-only".to_string())
+        }
+    }
+
+    fn store() -> TableStore {
+        let items = parse_csv_static(
+            b"id,power,weight,stackable,tags,code\n\
+              sword_01,10,2.5,false,\"[\"\"edge\"\"]\",007\n\
+              potion,,0.1,true,,x1\n",
+        )
+        .expect("test setup must succeed");
+        let enemies = parse_json_table_static(br#"{"slime": {"hp": 5, "drops": {"gel": 2}}}"#)
+            .expect("test setup must succeed");
+        let tables = HashMap::from([
+            (perro_ids::string_to_u64("res://data/items.csv"), items),
+            (perro_ids::string_to_u64("res://data/enemies.json"), enemies),
+        ]);
+        TableStore { tables }
+    }
+
+    #[test]
+    fn table_rows_read_typed_variants() {
+        let store = store();
+        let items = DataModule::new(&store).table("items");
+        assert!(items.exists());
+        assert_eq!(items.len(), 2);
+
+        let sword = items.row("sword_01").expect("sword row");
+        assert_eq!(sword.key(), "sword_01");
+        assert_eq!(sword.get("power"), Variant::from(10_i64));
+        assert_eq!(sword.get("weight"), Variant::from(2.5_f64));
+        assert_eq!(sword.get("stackable"), Variant::Bool(false));
+        assert_eq!(
+            sword.get("tags"),
+            Variant::Array(vec![Variant::from("edge")])
+        );
+        assert_eq!(sword.get("code"), Variant::from(7_i64));
+        assert_eq!(sword.text("code"), Some("007"));
+        assert_eq!(sword.get("missing"), Variant::Null);
+
+        assert_eq!(items.get("potion", "power"), Variant::Null);
+        assert_eq!(items.get("potion", "code"), Variant::from("x1"));
+        assert!(items.row("axe").is_none());
+    }
+
+    #[test]
+    fn table_falls_back_to_json_and_reports_missing() {
+        let store = store();
+        let data = DataModule::new(&store);
+        let slime = data.table("enemies").row("slime").expect("slime row");
+        assert_eq!(slime.get("hp"), Variant::from(5_i64));
+        let Variant::Object(row) = slime.to_variant() else {
+            panic!("row should be an object");
+        };
+        assert_eq!(row.get("id"), Some(&Variant::from("slime")));
+        assert!(matches!(row.get("drops"), Some(Variant::Object(_))));
+
+        let missing = data.table("nope");
+        assert!(!missing.exists());
+        assert!(missing.row("slime").is_none());
+    }
+}
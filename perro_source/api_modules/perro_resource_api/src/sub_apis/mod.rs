@@ -10,6 +10,7 @@ mod animation;
 mod animation_tree;
 mod audio;
 mod csv_table;
+mod data_table;
 mod display;
 mod draw_2d;
 mod gltf;
@@ -31,15 +32,17 @@ mod webcam;
 pub use animation::{AnimationAPI, AnimationModule};
 pub use animation_tree::{AnimationTreeAPI, AnimationTreeModule};
 pub use audio::{
-    Audio, Audio2D, Audio2DModule, Audio3D, Audio3DModule, AudioAPI, AudioClip, AudioCompression,
-    AudioDirection, AudioEffects, AudioEq, AudioModule, AudioPan, AudioPlayConfig, MidiChannel,
-    MidiModule, MidiNoteHandle, MidiNoteOptions, MidiProgram, MidiSong, MidiSound, MidiSpatialPos,
-    MidiSpatialPosition, Note, PannedAudio, SpatialAudioOptions, bus_id, program,
+    Audio, Audio2D, Audio2DModule, Audio3D, Audio3DModule, AudioAPI, AudioAttenuation,
+    AudioBusEffects, AudioClip, AudioCompression, AudioDirection, AudioEffects, AudioEq,
+    AudioModule, AudioPan, AudioPlayConfig, MidiChannel, MidiModule, MidiNoteHandle,
+    MidiNoteOptions, MidiProgram, MidiSong, MidiSound, MidiSpatialPos, MidiSpatialPosition, Note,
+    PannedAudio, SpatialAudioOptions, bus_id, program,
 };
 
 // ---- Data + draw resources ----
 
 pub use csv_table::{CsvAPI, CsvModule};
+pub use data_table::{DATA_TABLE_ROOT, DataModule, DataRow, DataTable, data_cell_variant};
 pub use display::DisplayModule;
 pub use draw_2d::{Draw2DAPI, Draw2DModule};
 pub use gltf::{GlbModule, GltfAPI, GltfInfo};
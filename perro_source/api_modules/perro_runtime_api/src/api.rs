@@ -1,8 +1,10 @@
 use crate::sub_apis::{
-    AnimPlayerAPI, AnimPlayerModule, AnimTreeAPI, AnimTreeModule, MeshQueryModule, NavMeshAPI,
-    NavMeshModule, NodeAPI, NodeModule, NodeQueryModule, PhysicsAPI, PhysicsModule,
-    RuntimeAudioAPI, RuntimeAudioModule, SceneAPI, SceneModule, ScriptAPI, ScriptModule, SignalAPI,
-    SignalModule, TimeAPI, TimeModule, TimerAPI, TimerModule, WindowAPI, WindowModule,
+    AnimPlayerAPI, AnimPlayerModule, AnimTreeAPI, AnimTreeModule, DebugAPI, DebugModule, EventAPI,
+    EventModule, MeshQueryModule, NavMeshAPI, NavMeshModule, NodeAPI, NodeModule, NodeQueryModule,
+    OsAPI, OsModule, PhysicsAPI, PhysicsModule, RollbackAPI, RollbackModule, RuntimeAudioAPI,
+    RuntimeAudioModule, SaveAPI, SaveModule, SceneAPI, SceneModule, ScriptAPI, ScriptModule,
+    SignalAPI, SignalModule, SimAPI, SimModule, Space2DAPI, Space2DModule, TimeAPI, TimeModule,
+    TimerAPI, TimerModule, WindowAPI, WindowModule, WorldAPI, WorldModule,
 };
 
 /// Full runtime contract required by [`RuntimeApiSurface`].
@@ -22,6 +24,9 @@ pub trait RuntimeAPI:
     + AnimTreeAPI
     + SceneAPI
     + RuntimeAudioAPI
+    + OsAPI
+    + DebugAPI
+    + WorldAPI
 {
 }
 impl<T> RuntimeAPI for T where
@@ -36,6 +41,9 @@ impl<T> RuntimeAPI for T where
         + AnimTreeAPI
         + SceneAPI
         + RuntimeAudioAPI
+        + OsAPI
+        + DebugAPI
+        + WorldAPI
 {
 }
 
@@ -74,12 +82,51 @@ impl<'rt, RT: RuntimeAPI + ?Sized> RuntimeApiSurface<'rt, RT> {
         TimerModule::new(self.rt)
     }
 
+    /// Read the fixed-tick simulation clock and draw from its random stream.
+    #[inline]
+    pub fn Sim(&mut self) -> SimModule<'_, RT>
+    where
+        RT: SimAPI,
+    {
+        SimModule::new(self.rt)
+    }
+
+    /// Snapshot tracked scripts each fixed tick and roll back on late inputs.
+    #[inline]
+    pub fn Rollback(&mut self) -> RollbackModule<'_, RT>
+    where
+        RT: RollbackAPI,
+    {
+        RollbackModule::new(self.rt)
+    }
+
     /// Queue window requests and read active refresh data.
     #[inline]
     pub fn Window(&mut self) -> WindowModule<'_, RT> {
         WindowModule::new(self.rt)
     }
 
+    /// Read the embedded build stamp and host platform.
+    #[inline]
+    pub fn OS(&mut self) -> OsModule<'_, RT> {
+        OsModule::new(self.rt)
+    }
+
+    /// Snapshot `autosave` group scripts to a `user://saves` slot.
+    #[inline]
+    pub fn Save(&mut self) -> SaveModule<'_, RT>
+    where
+        RT: SaveAPI,
+    {
+        SaveModule::new(self.rt)
+    }
+
+    /// Read approximate per-subsystem memory usage.
+    #[inline]
+    pub fn Debug(&mut self) -> DebugModule<'_, RT> {
+        DebugModule::new(self.rt)
+    }
+
     // ---- Scene graph ----
 
     /// Access scene node creation, deletion, tags, transforms, and fields.
@@ -100,6 +147,21 @@ impl<'rt, RT: RuntimeAPI + ?Sized> RuntimeApiSurface<'rt, RT> {
         MeshQueryModule::new(self.rt)
     }
 
+    /// Read the frame-start snapshot of transforms, parents, and tags.
+    #[inline]
+    pub fn World(&mut self) -> WorldModule<'_, RT> {
+        WorldModule::new(self.rt)
+    }
+
+    /// Find 2D nodes near a point or inside a box through the grid index.
+    #[inline]
+    pub fn Space2D(&mut self) -> Space2DModule<'_, RT>
+    where
+        RT: Space2DAPI,
+    {
+        Space2DModule::new(self.rt)
+    }
+
     /// Query 3D navigation meshes.
     #[inline]
     pub fn NavMesh(&mut self) -> NavMeshModule<'_, RT>
@@ -123,6 +185,15 @@ impl<'rt, RT: RuntimeAPI + ?Sized> RuntimeApiSurface<'rt, RT> {
         SignalModule::new(self.rt)
     }
 
+    /// Subscribe to and publish topic events, delivered next frame.
+    #[inline]
+    pub fn Events(&mut self) -> EventModule<'_, RT>
+    where
+        RT: EventAPI,
+    {
+        EventModule::new(self.rt)
+    }
+
     // ---- Simulation ----
 
     /// Access physics state, forces, raycasts, prediction, and gravity.
@@ -1,9 +1,9 @@
 //! Public runtime scripting API.
 //!
 //! This crate exposes the runtime-side script surface: time, window requests,
-//! node access, node queries, scripts, signals, physics, animation, scene
-//! loading, and runtime audio. Scripts normally import [`prelude`] and receive a
-//! [`RuntimeApiSurface`] from the script context.
+//! node access, node queries, world snapshots, scripts, signals, physics,
+//! animation, scene loading, and runtime audio. Scripts normally import
+//! [`prelude`] and receive a [`RuntimeApiSurface`] from the script context.
 
 pub mod api;
 pub mod spec;
@@ -29,23 +29,26 @@ pub mod prelude {
 
     // Runtime domain APIs.
     pub use crate::sub_apis::{
-        AnimPlayerAPI, AnimPlayerModule, AttachedMidiTarget, CameraRay3D, CursorIcon, FrameRateCap,
+        AnimPlayerAPI, AnimPlayerModule, AttachedMidiTarget, BuildInfo, BuildProfile, CameraRay3D,
+        CursorIcon, DebugAPI, DebugModule, EventAPI, EventModule, FrameRateCap,
         IntoImpulseDirection, IntoNodeCollection, IntoNodeCreateBatch, IntoNodeTag, IntoNodeTags,
         IntoPreloadedSceneID, IntoPreloadedSceneTarget, IntoSceneLoadSource, IntoScenePath,
-        IntoScriptMemberID, MeshDataSurfaceHit3D, MeshDataSurfaceRegion3D, MeshMaterialRegion3D,
-        MeshQueryModule, MeshSurfaceHit3D, MeshSurfaceRay3D, MidiChannel, MidiNoteHandle,
-        MidiNoteOptions, MidiProgram, MidiSong, MidiSound, NavMeshAPI, NavMeshAreaCost,
-        NavMeshModule, NavMeshObstacle3D, NavMeshPath3D, NavMeshPathOptions, NavMeshPathStatus,
-        NavMeshQueryOptions, NodeAPI, NodeCollection, NodeCollectionEntry, NodeCreateBatch,
-        NodeModule, NodeQuery, NodeQueryModule, NodeQueryView, NodeSceneSpec, NodeScriptSpec,
-        NodeScriptVar, NodeSpec, Note, PhysicsAPI, PhysicsBodyPrediction2D,
-        PhysicsBodyPrediction3D, PhysicsLaunchSolution2D, PhysicsLaunchSolution3D, PhysicsModule,
-        PhysicsMoveResult2D, PhysicsMoveResult3D, PhysicsQueryFilter, PhysicsRayHit2D,
-        PhysicsRayHit3D, PhysicsShapeHit2D, PhysicsShapeHit3D, PhysicsSlideResult2D,
-        PhysicsSlideResult3D, PreloadedSceneTarget, ProfilingSnapshot, QueryBounds, QueryExpr,
-        QueryScope, RuntimeMidiModule, SceneAPI, SceneLoadSource, SceneModule, ScriptAPI,
-        ScriptModule, SignalAPI, SignalModule, SpatialAudioOptions, TimeAPI, TimeModule, TimerAPI,
-        TimerModule, WindowAPI, WindowMode, WindowModule, WindowRequest, program,
+        IntoScriptMemberID, LeakEntry, LeakKind, LeakReport, MemoryReport, MeshDataSurfaceHit3D,
+        MeshDataSurfaceRegion3D, MeshMaterialRegion3D, MeshQueryModule, MeshSurfaceHit3D,
+        MeshSurfaceRay3D, MidiChannel, MidiNoteHandle, MidiNoteOptions, MidiProgram, MidiSong,
+        MidiSound, NavMeshAPI, NavMeshAreaCost, NavMeshModule, NavMeshObstacle3D, NavMeshPath3D,
+        NavMeshPathOptions, NavMeshPathStatus, NavMeshQueryOptions, NodeAPI, NodeCollection,
+        NodeCollectionEntry, NodeCreateBatch, NodeModule, NodeQuery, NodeQueryModule,
+        NodeQueryView, NodeSceneSpec, NodeScriptSpec, NodeScriptVar, NodeSpec, Note, OsAPI,
+        OsModule, PhysicsAPI, PhysicsBodyPrediction2D, PhysicsBodyPrediction3D,
+        PhysicsLaunchSolution2D, PhysicsLaunchSolution3D, PhysicsModule, PhysicsMoveResult2D,
+        PhysicsMoveResult3D, PhysicsQueryFilter, PhysicsRayHit2D, PhysicsRayHit3D,
+        PhysicsShapeHit2D, PhysicsShapeHit3D, PhysicsSlideResult2D, PhysicsSlideResult3D,
+        PreloadedSceneTarget, ProfilingSnapshot, QueryBounds, QueryExpr, QueryScope, RollbackAPI,
+        RollbackModule, RuntimeMidiModule, SceneAPI, SceneLoadSource, SceneModule, ScriptAPI,
+        ScriptModule, SignalAPI, SignalModule, SimAPI, SimModule, Space2DAPI, Space2DModule,
+        SpatialAudioOptions, TimeAPI, TimeModule, TimerAPI, TimerModule, WindowAPI, WindowMode,
+        WindowModule, WindowRequest, WorldAPI, WorldModule, WorldSnapshot, program,
     };
 
     // Convenience macros.
@@ -53,52 +56,59 @@ pub mod prelude {
     pub use crate::{
         anim_player_bind, anim_player_clear_bindings, anim_player_pause, anim_player_play,
         anim_player_seek_frame, anim_player_set_clip, anim_player_set_speed, apply_force,
-        apply_impulse, audio_play_attached, bind_locale_placeholder, bind_locale_text,
-        broadcast_var, call_method, close_app, create_node, create_nodes, delta_time,
-        delta_time_capped, delta_time_clamped, descendants, elapsed_time, find_node,
-        fixed_delta_time, force_rerender, fps, frame_time, get_child, get_children,
-        get_global_pos_2d, get_global_pos_3d, get_global_rot_2d, get_global_rot_3d,
-        get_global_scale_2d, get_global_scale_3d, get_global_transform_2d, get_global_transform_3d,
-        get_local_pos_2d, get_local_pos_3d, get_local_rot_2d, get_local_rot_3d, get_local_scale_2d,
-        get_local_scale_3d, get_local_transform_2d, get_local_transform_3d, get_node_children_ids,
-        get_node_name, get_node_parent_id, get_node_tags, get_node_type, get_node_var, get_var,
-        graphics_time, is_mesh_instance_ready, look_at_3d, mesh_data_surface_at_local_point_3d,
-        mesh_data_surface_on_local_ray_3d, mesh_data_surface_regions_3d,
-        mesh_instance_material_regions_3d, mesh_instance_surface_at_global_point_3d,
-        mesh_instance_surface_global_point_3d, mesh_instance_surface_on_global_ray_3d,
-        mesh_instance_surfaces_on_global_rays_3d, midi_play_attached, midi_release_attached,
-        midi_start_attached, midi_stop_attached, navmesh_find_path_3d, node_collection,
-        physics_apply_gravity_2d, physics_apply_gravity_3d, physics_get_body_gravity_scale,
-        physics_get_coefficient, physics_get_gravity, physics_is_paused, physics_move_and_slide_2d,
+        apply_impulse, attributes_of, audio_play_attached, bind_locale_placeholder,
+        bind_locale_text, broadcast_var, build_info, call_method, close_app, create_node,
+        create_nodes, delta_time, delta_time_capped, delta_time_clamped, descendants, elapsed_time,
+        event_publish, event_subscribe, event_unsubscribe, find_node, fixed_delta_time,
+        force_rerender, fps, frame_time, get_child, get_children, get_global_pos_2d,
+        get_global_pos_3d, get_global_rot_2d, get_global_rot_3d, get_global_scale_2d,
+        get_global_scale_3d, get_global_transform_2d, get_global_transform_3d, get_local_pos_2d,
+        get_local_pos_3d, get_local_rot_2d, get_local_rot_3d, get_local_scale_2d,
+        get_local_scale_3d, get_local_transform_2d, get_local_transform_3d, get_meta,
+        get_node_children_ids, get_node_name, get_node_parent_id, get_node_tags, get_node_type,
+        get_node_var, get_nodes_in_group, get_var, graphics_time, instantiate_owned,
+        is_mesh_instance_ready, is_node_enabled, leak_report, look_at_3d, members_with,
+        memory_report, mesh_data_surface_at_local_point_3d, mesh_data_surface_on_local_ray_3d,
+        mesh_data_surface_regions_3d, mesh_instance_material_regions_3d,
+        mesh_instance_surface_at_global_point_3d, mesh_instance_surface_global_point_3d,
+        mesh_instance_surface_on_global_ray_3d, mesh_instance_surfaces_on_global_rays_3d,
+        midi_play_attached, midi_release_attached, midi_start_attached, midi_stop_attached,
+        navmesh_find_path_3d, node_collection, node_is_valid, physics_apply_gravity_2d,
+        physics_apply_gravity_3d, physics_get_body_gravity_scale, physics_get_coefficient,
+        physics_get_gravity, physics_is_paused, physics_move_and_slide_2d,
         physics_move_and_slide_3d, physics_move_body_2d, physics_move_body_3d, physics_pause,
         physics_predict_body_2d, physics_predict_body_3d, physics_raycast_3d,
         physics_raycast_3d_with_areas, physics_raycast_3d_without_areas,
         physics_set_body_gravity_scale, physics_set_coefficient, physics_set_gravity,
         physics_solve_launch_velocity_2d, physics_solve_launch_velocity_3d,
         physics_solve_velocity_to_target_2d, physics_solve_velocity_to_target_3d, profiling, query,
-        query_builder, query_each, query_expr, query_first, query_iter, query_map, remove_node,
-        reparent, reparent_multi, scene_drop_preloaded, scene_free_preloaded, scene_load,
-        scene_preload, script_attach, script_detach, script_set_fixed_update_enabled,
+        query_builder, query_each, query_expr, query_first, query_iter, query_map, quit,
+        remove_node, reparent, reparent_multi, rollback_input, rollback_resimulating,
+        rollback_set_input, scene_drop_preloaded, scene_free_preloaded, scene_load,
+        scene_load_additive, scene_preload, scene_unload, script_attach, script_detach,
+        script_restore_state, script_save_state, script_set_fixed_update_enabled,
         script_set_update_enabled, set_global_pos_2d, set_global_pos_3d, set_global_rot_2d,
         set_global_rot_3d, set_global_scale_2d, set_global_scale_3d, set_global_transform_2d,
         set_global_transform_3d, set_local_pos_2d, set_local_pos_3d, set_local_rot_2d,
         set_local_rot_3d, set_local_scale_2d, set_local_scale_3d, set_local_transform_2d,
-        set_local_transform_3d, set_node_name, set_tree_visible, set_ui_rotation, set_var,
-        signal_connect, signal_connect_many, signal_connect_pairs, signal_disconnect,
-        signal_disconnect_many, signal_emit, simulation_time, spawn, spec_begin, spec_end,
-        spec_point, tag_add, tag_remove, tag_set, timer_cancel, timer_finished, timer_is_active,
-        timer_remaining, timer_start, timer_started, to_global_point_2d, to_global_point_3d,
-        to_global_transform_2d, to_global_transform_3d, to_local_point_2d, to_local_point_3d,
-        to_local_transform_2d, to_local_transform_3d, window_get_active_refresh_rate,
-        window_set_cursor_icon, window_set_frame_rate_cap, window_set_frame_rate_limit,
-        window_set_mode, window_set_size, window_set_title, with_base_node, with_base_node_mut,
-        with_node, with_node_mut, with_state, with_state_mut,
+        set_local_transform_3d, set_meta, set_node_enabled, set_node_name, set_node_owner,
+        set_tree_visible, set_ui_rotation, set_var, signal_connect, signal_connect_many,
+        signal_connect_pairs, signal_disconnect, signal_disconnect_many, signal_emit, sim_rand,
+        sim_reset, sim_tick, sim_time, simulation_time, space2d_query_radius, space2d_query_rect,
+        spawn, spec_begin, spec_end, spec_point, tag_add, tag_remove, tag_set, timer_cancel,
+        timer_finished, timer_is_active, timer_remaining, timer_start, timer_started,
+        to_global_point_2d, to_global_point_3d, to_global_transform_2d, to_global_transform_3d,
+        to_local_point_2d, to_local_point_3d, to_local_transform_2d, to_local_transform_3d,
+        upgrade_node, window_get_active_refresh_rate, window_set_cursor_icon,
+        window_set_frame_rate_cap, window_set_frame_rate_limit, window_set_mode, window_set_size,
+        window_set_title, with_base_node, with_base_node_mut, with_node, with_node_mut, with_state,
+        with_state_mut, world_snapshot,
     };
 
     // Common id and variant helpers.
     pub use perro_ids::prelude::{
         AnimationID, AudioBusID, LightID, MaterialID, MeshID, NavMeshID, NodeID, PreloadedSceneID,
-        ScriptMemberID, SignalID, TagID, TextureID, TimerID,
+        ScriptMemberID, SignalID, TagID, TextureID, TimerID, WeakNodeRef,
     };
     pub use perro_ids::{func, method, sid, signal, smid, tag, tags, timer, var};
     pub use perro_nodes::prelude::*;
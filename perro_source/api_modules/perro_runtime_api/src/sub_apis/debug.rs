@@ -0,0 +1,197 @@
+//! Runtime debug API.
+//!
+//! Exposes approximate per-subsystem memory usage so scripts and overlays can
+//! spot leaks (counts that only grow) and bloat (one subsystem dwarfing the
+//! rest), plus debug-build leak reports listing still-alive objects with the
+//! script that created them.
+
+use perro_ids::NodeID;
+use std::fmt;
+
+/// Approximate bytes held by each runtime subsystem.
+///
+/// Sizes are estimates from container capacities and known element sizes, not
+/// allocator totals. Compare reports over time rather than treating one value
+/// as exact.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryReport {
+    /// Live scene nodes.
+    pub node_count: u32,
+    /// Node slots, side indexes, and per-node heap data.
+    pub node_arena_bytes: u64,
+    /// Attached script instances.
+    pub script_count: u32,
+    /// Per-instance script state plus schedule indexes.
+    pub script_state_bytes: u64,
+    /// Runtime-held `Variant` payloads (signal params, queued UI signals).
+    pub variant_bytes: u64,
+    /// Cached + preloaded scenes.
+    pub scene_cache_bytes: u64,
+    /// Loaded asset archives (project + mounted DLC).
+    pub asset_archive_bytes: u64,
+    /// GPU textures + mesh storage, as last reported by the graphics backend.
+    /// Zero when running headless.
+    pub gpu_bytes: u64,
+}
+
+impl MemoryReport {
+    /// CPU-side bytes across every subsystem.
+    pub fn cpu_bytes(&self) -> u64 {
+        self.node_arena_bytes
+            + self.script_state_bytes
+            + self.variant_bytes
+            + self.scene_cache_bytes
+            + self.asset_archive_bytes
+    }
+
+    /// CPU + GPU bytes.
+    pub fn total_bytes(&self) -> u64 {
+        self.cpu_bytes() + self.gpu_bytes
+    }
+}
+
+/// Object class in a [`LeakEntry`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LeakKind {
+    Node,
+    Texture,
+    Mesh,
+    Material,
+}
+
+impl LeakKind {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Node => "node",
+            Self::Texture => "texture",
+            Self::Mesh => "mesh",
+            Self::Material => "material",
+        }
+    }
+}
+
+/// One still-alive object that nothing in the active scene owns.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeakEntry {
+    pub kind: LeakKind,
+    /// Raw id bits (`NodeID::as_u64`, `TextureID::as_u64`, ...).
+    pub id: u64,
+    /// Node name + type + subtree size, or resource source path.
+    pub label: String,
+    /// Script node that was running when the object was created. `None` for
+    /// engine/scene-loader creates.
+    pub creator: Option<NodeID>,
+    /// Seconds since creation.
+    pub age_secs: f32,
+    /// Creator backtrace; `None` unless `RUST_BACKTRACE` /
+    /// `RUST_LIB_BACKTRACE` enabled capture.
+    pub backtrace: Option<String>,
+}
+
+/// Objects still alive outside the active scene + ownership roots.
+///
+/// Only debug builds track creation. Release builds return an empty report
+/// with `tracking == false`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LeakReport {
+    pub tracking: bool,
+    pub entries: Vec<LeakEntry>,
+}
+
+impl LeakReport {
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn count(&self, kind: LeakKind) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.kind == kind)
+            .count()
+    }
+}
+
+impl fmt::Display for LeakReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.tracking {
+            return write!(f, "leak tracking off (release build)");
+        }
+        write!(f, "{} leaked object(s)", self.entries.len())?;
+        for entry in &self.entries {
+            write!(
+                f,
+                "\n  {} {:#x} `{}` age={:.1}s",
+                entry.kind.as_str(),
+                entry.id,
+                entry.label,
+                entry.age_secs
+            )?;
+            match entry.creator {
+                Some(creator) => write!(f, " creator={creator}")?,
+                None => write!(f, " creator=engine")?,
+            }
+            if let Some(backtrace) = &entry.backtrace {
+                write!(f, "\n{backtrace}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub trait DebugAPI {
+    fn get_memory_report(&self) -> MemoryReport;
+    fn get_leak_report(&self) -> LeakReport;
+}
+
+pub struct DebugModule<'rt, R: DebugAPI + ?Sized> {
+    rt: &'rt mut R,
+}
+
+impl<'rt, R: DebugAPI + ?Sized> DebugModule<'rt, R> {
+    pub fn new(rt: &'rt mut R) -> Self {
+        Self { rt }
+    }
+
+    pub fn memory_report(&mut self) -> MemoryReport {
+        self.get_memory_report()
+    }
+
+    pub fn get_memory_report(&mut self) -> MemoryReport {
+        self.rt.get_memory_report()
+    }
+
+    pub fn leak_report(&mut self) -> LeakReport {
+        self.get_leak_report()
+    }
+
+    pub fn get_leak_report(&mut self) -> LeakReport {
+        self.rt.get_leak_report()
+    }
+}
+
+/// Returns the per-subsystem memory report.
+///
+/// Arguments:
+/// - `ctx`: `&mut RuntimeWindow<_>`
+#[macro_export]
+macro_rules! memory_report {
+    ($ctx:expr) => {
+        $ctx.Debug().memory_report()
+    };
+}
+
+/// Returns nodes + resources still alive outside the active scene, with the
+/// script that created each one. Empty in release builds.
+///
+/// Arguments:
+/// - `ctx`: `&mut RuntimeWindow<_>`
+#[macro_export]
+macro_rules! leak_report {
+    ($ctx:expr) => {
+        $ctx.Debug().leak_report()
+    };
+}
@@ -0,0 +1,95 @@
+//! Runtime event bus API.
+//!
+//! Publish/subscribe on string topics. Unlike signals, published events are
+//! queued and delivered as script method calls at the start of the next frame.
+
+use perro_ids::NodeID;
+use perro_variant::Variant;
+
+pub trait EventAPI {
+    fn event_subscribe(&mut self, topic: &str, script_id: NodeID, method: &str) -> bool;
+    fn event_unsubscribe(&mut self, topic: &str, script_id: NodeID, method: &str) -> bool;
+    fn event_publish(&mut self, topic: &str, payload: Variant);
+    fn event_debug_view(&self) -> String;
+}
+
+pub struct EventModule<'rt, R: EventAPI + ?Sized> {
+    rt: &'rt mut R,
+}
+
+impl<'rt, R: EventAPI + ?Sized> EventModule<'rt, R> {
+    pub fn new(rt: &'rt mut R) -> Self {
+        Self { rt }
+    }
+
+    pub fn subscribe(&mut self, topic: &str, script_id: NodeID, method: &str) -> bool {
+        self.rt.event_subscribe(topic, script_id, method)
+    }
+
+    pub fn unsubscribe(&mut self, topic: &str, script_id: NodeID, method: &str) -> bool {
+        self.rt.event_unsubscribe(topic, script_id, method)
+    }
+
+    pub fn publish(&mut self, topic: &str, payload: Variant) {
+        self.rt.event_publish(topic, payload);
+    }
+
+    /// One `topic -> script.method` line per active subscription.
+    pub fn debug_view(&self) -> String {
+        self.rt.event_debug_view()
+    }
+}
+
+/// Subscribes a script method to a topic.
+///
+/// Arguments:
+/// - `ctx`: `&mut RuntimeWindow<_>`
+/// - `script`: script `NodeID`
+/// - `topic`: topic name
+/// - `method`: method name, called w/ the published payload
+///
+/// Returns `false` when already subscribed.
+#[macro_export]
+macro_rules! event_subscribe {
+    ($ctx:expr, $script:expr, $topic:expr, $method:expr) => {
+        $ctx.Events().subscribe(
+            ::core::convert::AsRef::<str>::as_ref(&$topic),
+            $script,
+            ::core::convert::AsRef::<str>::as_ref(&$method),
+        )
+    };
+}
+
+/// Removes a subscription made w/ [`event_subscribe!`].
+#[macro_export]
+macro_rules! event_unsubscribe {
+    ($ctx:expr, $script:expr, $topic:expr, $method:expr) => {
+        $ctx.Events().unsubscribe(
+            ::core::convert::AsRef::<str>::as_ref(&$topic),
+            $script,
+            ::core::convert::AsRef::<str>::as_ref(&$method),
+        )
+    };
+}
+
+/// Queues a payload for every subscriber of a topic; delivered next frame.
+///
+/// Arguments:
+/// - `ctx`: `&mut RuntimeWindow<_>`
+/// - `topic`: topic name
+/// - `payload` (optional): anything `Into<Variant>`; `Variant::Null` if left out
+#[macro_export]
+macro_rules! event_publish {
+    ($ctx:expr, $topic:expr, $payload:expr) => {
+        $ctx.Events().publish(
+            ::core::convert::AsRef::<str>::as_ref(&$topic),
+            $crate::perro_variant::Variant::from($payload),
+        )
+    };
+    ($ctx:expr, $topic:expr) => {
+        $ctx.Events().publish(
+            ::core::convert::AsRef::<str>::as_ref(&$topic),
+            $crate::perro_variant::Variant::Null,
+        )
+    };
+}
@@ -10,15 +10,23 @@
 mod animation;
 mod animation_tree;
 mod audio;
+mod debug;
+mod event;
 mod navmesh;
 mod node;
+mod os;
 mod physics;
+mod rollback;
+mod save;
 mod scene;
 mod script;
 mod signal;
+mod sim;
+mod space2d;
 mod time;
 mod timer;
 mod window;
+mod world;
 
 // ---- Animation ----
 
@@ -28,9 +36,10 @@ pub use animation_tree::{AnimTreeAPI, AnimTreeModule, AnimTreeSlotArg, IntoAnimT
 // ---- Audio ----
 
 pub use audio::{
-    AttachedMidiTarget, AudioCompression, AudioDirection, AudioEffects, AudioEq, MidiChannel,
-    MidiNoteHandle, MidiNoteOptions, MidiProgram, MidiSong, MidiSound, Note, RuntimeAudio,
-    RuntimeAudioAPI, RuntimeAudioModule, RuntimeMidiModule, SpatialAudioOptions, program,
+    AttachedMidiTarget, AudioAttenuation, AudioCompression, AudioDirection, AudioEffects, AudioEq,
+    MidiChannel, MidiNoteHandle, MidiNoteOptions, MidiProgram, MidiSong, MidiSound, Note,
+    RuntimeAudio, RuntimeAudioAPI, RuntimeAudioModule, RuntimeMidiModule, SpatialAudioOptions,
+    program,
 };
 pub use navmesh::{
     NavMeshAPI, NavMeshAreaCost, NavMeshModule, NavMeshObstacle3D, NavMeshPath3D,
@@ -48,6 +57,7 @@ pub use node::{
     NodeScriptVar, NodeSpec, QueryBounds, QueryExpr, QueryScope, QueryTypeMask,
     collect_subtree_ids,
 };
+pub use space2d::{Space2DAPI, Space2DModule};
 
 // ---- Simulation domains ----
 
@@ -61,6 +71,7 @@ pub use physics::{
 
 // ---- Scene/script bus ----
 
+pub use event::{EventAPI, EventModule};
 pub use scene::{
     IntoPreloadedSceneID, IntoPreloadedSceneTarget, IntoSceneLoadSource, IntoScenePath,
     PreloadedSceneID, PreloadedSceneTarget, SceneAPI, SceneLoadSource, SceneModule,
@@ -70,6 +81,12 @@ pub use signal::{SignalAPI, SignalModule};
 
 // ---- Frame/window ----
 
+pub use debug::{DebugAPI, DebugModule, LeakEntry, LeakKind, LeakReport, MemoryReport};
+pub use os::{BuildInfo, BuildProfile, ON_QUIT_REQUEST, OsAPI, OsModule, QUIT_HANDLERS_GROUP};
+pub use rollback::{ROLLBACK_LOAD_METHOD, ROLLBACK_SAVE_METHOD, RollbackAPI, RollbackModule};
+pub use save::{AUTOSAVE_GROUP, MIN_SAVE_GAP_SECS, SaveAPI, SaveModule};
+pub use sim::{SimAPI, SimModule};
 pub use time::{ProfilingSnapshot, TimeAPI, TimeModule};
 pub use timer::{TimerAPI, TimerModule, timer_signal_ids};
 pub use window::{CursorIcon, FrameRateCap, WindowAPI, WindowMode, WindowModule, WindowRequest};
+pub use world::{WorldAPI, WorldModule, WorldSnapshot, WorldSnapshotBuilder};
@@ -4,7 +4,9 @@
 //! live scene nodes. Query helpers live beside node access because they operate
 //! on the same runtime scene graph.
 
-use perro_ids::{IntoTagID, MaterialID, MeshID, NodeID, NodeTag, ScriptMemberID, TagID};
+use perro_ids::{
+    IntoTagID, MaterialID, MeshID, Name, NodeID, NodeTag, ScriptMemberID, TagID, WeakNodeRef,
+};
 use perro_nodes::{
     Node2D, Node3D, NodeBaseDispatch, NodeType, NodeTypeDispatch, SceneNodeData, Skeleton3D, UiNode,
 };
@@ -43,12 +43,12 @@ pub trait NodeAPI {
         F: FnOnce(&mut T) -> V;
 
     /// Returns node display name if node exists.
-    fn get_node_name(&mut self, node_id: NodeID) -> Option<Cow<'static, str>>;
+    fn get_node_name(&mut self, node_id: NodeID) -> Option<Name>;
 
     /// Sets node display name; returns `true` on success.
     fn set_node_name<S>(&mut self, node_id: NodeID, name: S) -> bool
     where
-        S: Into<Cow<'static, str>>;
+        S: Into<Name>;
 
     /// Finds a node by name inside `root`'s subtree (including `root`
     /// itself). Pass `NodeID::nil()` as `root` to search the whole scene.
@@ -211,6 +211,39 @@ pub trait NodeAPI {
     /// Removes a node from the scene graph.
     fn remove_node(&mut self, node_id: NodeID) -> bool;
 
+    /// True while `node_id` names a live node. Ids are generational, so an id
+    /// kept past `remove_node` stays false even after its slot is reused.
+    fn is_valid(&mut self, node_id: NodeID) -> bool;
+
+    /// Ties `node_id`'s lifetime to `owner`: removing `owner` also removes
+    /// `node_id`, wherever it sits in the tree. A nil `owner` releases it.
+    /// Returns false when either node is missing, or they are the same node.
+    fn set_node_owner(&mut self, node_id: NodeID, owner: NodeID) -> bool;
+
+    /// Returns the owner set by [`NodeAPI::set_node_owner`], or nil.
+    fn get_node_owner(&mut self, node_id: NodeID) -> NodeID;
+
+    /// Enables or disables a node and its subtree. Disabled nodes skip script
+    /// updates, internal updates, physics, and rendering. Scenes set the
+    /// starting state with the `enabled = false` node header.
+    fn set_node_enabled(&mut self, node_id: NodeID, enabled: bool) -> bool;
+
+    /// Returns the node's own flag. A node under a disabled ancestor still
+    /// returns true. Missing nodes return false.
+    fn is_node_enabled(&mut self, node_id: NodeID) -> bool;
+
+    /// Returns the node's meta value for `key`, set by the scene `meta` header
+    /// or [`NodeAPI::set_node_meta`]. `Variant::Null` when unset or missing.
+    fn get_node_meta<S>(&mut self, node_id: NodeID, key: S) -> Variant
+    where
+        S: AsRef<str>;
+
+    /// Sets one meta value on a node; `Variant::Null` removes the key. Meta
+    /// is saved w/ `script_save_state` and needs no script state field.
+    fn set_node_meta<K>(&mut self, node_id: NodeID, key: K, value: Variant) -> bool
+    where
+        K: Into<Cow<'static, str>>;
+
     /// Returns node tag names if node exists.
     fn get_node_tags(&mut self, node_id: NodeID) -> Option<Vec<Cow<'static, str>>>;
 
@@ -237,6 +270,19 @@ pub trait NodeAPI {
     where
         T: IntoTagID;
 
+    /// Returns every live node in a group. Groups are node tags, so scene
+    /// `groups = [...]` and `tags = [...]` entries both count.
+    fn get_nodes_in_group<T>(&mut self, group: T) -> Vec<NodeID>
+    where
+        T: IntoTagID,
+    {
+        let expr = Some(QueryExpr::Tags(vec![group.into_tag_id()]));
+        self.query_nodes(NodeQueryView {
+            expr: &expr,
+            scope: QueryScope::Root,
+        })
+    }
+
     /// Executes a node query and returns matching node IDs.
     fn query_nodes(&mut self, query: NodeQueryView<'_>) -> Vec<NodeID>;
 
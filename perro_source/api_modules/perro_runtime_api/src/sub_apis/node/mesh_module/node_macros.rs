@@ -4,11 +4,12 @@
 /// - name (`get_node_name!`, `set_node_name!`)
 /// - hierarchy (`get_node_parent_id!`, `get_node_children_ids!`)
 /// - runtime typing (`get_node_type!`)
-/// - tags (`get_node_tags!`, `set_tags!`, `tag_set!`, `tag_add!`, `tag_remove!`)
+/// - tags (`get_node_tags!`, `set_tags!`, `tag_set!`, `tag_add!`, `tag_remove!`,
+///   `get_nodes_in_group!`)
 /// - global transform helpers (`get_global_transform_*`, `set_global_transform_*`, `to_*`)
 ///
 /// Gets node display name.
-/// Usage: `get_node_name!(ctx, node_id) -> Option<Cow<'static, str>>`.
+/// Usage: `get_node_name!(ctx, node_id) -> Option<Name>`.
 ///
 /// Arguments:
 /// - `ctx`: `&mut RuntimeWindow<_>`
@@ -25,7 +26,7 @@ macro_rules! get_node_name {
 /// Arguments:
 /// - `ctx`: `&mut RuntimeWindow<_>`
 /// - `node_id`: `NodeID`
-/// - `name`: `&str`, `String`, or `Cow<'static, str>`
+/// - `name`: `&str`, `String`, `Cow<'static, str>`, or `Name`
 #[macro_export]
 macro_rules! set_node_name {
     ($ctx:expr, $id:expr, $name:expr) => {
@@ -184,3 +185,68 @@ macro_rules! remove_node {
         $ctx.Nodes().remove_node($id)
     };
 }
+
+/// True while the id names a live node; false once it was removed.
+/// Usage: `node_is_valid!(ctx, node_id) -> bool`.
+#[macro_export]
+macro_rules! node_is_valid {
+    ($ctx:expr, $id:expr) => {
+        $ctx.Nodes().is_valid($id)
+    };
+}
+
+/// Resolves a `WeakNodeRef`; `None` once its node was removed.
+/// Usage: `upgrade_node!(ctx, weak_ref) -> Option<NodeID>`.
+#[macro_export]
+macro_rules! upgrade_node {
+    ($ctx:expr, $weak:expr) => {
+        $ctx.Nodes().upgrade($weak)
+    };
+}
+
+/// Ties a node's lifetime to an owner; removing the owner removes it too.
+/// Nil `owner` releases it.
+/// Usage: `set_node_owner!(ctx, node_id, owner) -> bool`.
+#[macro_export]
+macro_rules! set_node_owner {
+    ($ctx:expr, $id:expr, $owner:expr) => {
+        $ctx.Nodes().set_node_owner($id, $owner)
+    };
+}
+
+/// Enables or disables a node and its subtree.
+/// Disabled nodes skip script updates, internal updates, physics, and rendering.
+/// Usage: `set_node_enabled!(ctx, node_id, false) -> bool`.
+#[macro_export]
+macro_rules! set_node_enabled {
+    ($ctx:expr, $id:expr, $enabled:expr) => {
+        $ctx.Nodes().set_node_enabled($id, $enabled)
+    };
+}
+
+/// Returns the node's own enabled flag.
+/// Usage: `is_node_enabled!(ctx, node_id) -> bool`.
+#[macro_export]
+macro_rules! is_node_enabled {
+    ($ctx:expr, $id:expr) => {
+        $ctx.Nodes().is_node_enabled($id)
+    };
+}
+
+/// Reads a node meta value; `Variant::Null` when unset.
+/// Usage: `get_meta!(ctx, node_id, "loot_table") -> Variant`.
+#[macro_export]
+macro_rules! get_meta {
+    ($ctx:expr, $id:expr, $key:expr) => {
+        $ctx.Nodes().get_node_meta($id, $key)
+    };
+}
+
+/// Sets a node meta value; `Variant::Null` removes it.
+/// Usage: `set_meta!(ctx, node_id, "loot_table", variant!("res://loot/goblin.ron")) -> bool`.
+#[macro_export]
+macro_rules! set_meta {
+    ($ctx:expr, $id:expr, $key:expr, $value:expr) => {
+        $ctx.Nodes().set_node_meta($id, $key, $value)
+    };
+}
@@ -11,6 +11,18 @@ macro_rules! get_node_tags {
     };
 }
 
+/// Gets every live node in a group (scene `groups`/`tags` entry).
+/// Usage: `get_nodes_in_group!(ctx, "enemies") -> Vec<NodeID>`.
+/// Arguments:
+/// - `ctx`: `&mut RuntimeWindow<_>`
+/// - `group`: group name or `TagID`
+#[macro_export]
+macro_rules! get_nodes_in_group {
+    ($ctx:expr, $group:expr) => {
+        $ctx.Nodes().get_nodes_in_group($group)
+    };
+}
+
 /// Sets or clears node tags.
 /// Usage:
 /// - `set_tags!(ctx, node_id, tags)` where `tags` converts into node tag data.
@@ -54,17 +54,17 @@ impl<'rt, R: NodeAPI + ?Sized> NodeModule<'rt, R> {
         self.rt.with_base_node_mut::<T, V, F>(id, f)
     }
 
-    pub fn get_node_name(&mut self, node_id: NodeID) -> Option<Cow<'static, str>> {
+    pub fn get_node_name(&mut self, node_id: NodeID) -> Option<Name> {
         self.rt.get_node_name(node_id)
     }
 
-    pub fn name(&mut self, node_id: NodeID) -> Option<Cow<'static, str>> {
+    pub fn name(&mut self, node_id: NodeID) -> Option<Name> {
         self.get_node_name(node_id)
     }
 
     pub fn set_node_name<S>(&mut self, node_id: NodeID, name: S) -> bool
     where
-        S: Into<Cow<'static, str>>,
+        S: Into<Name>,
     {
         self.rt.set_node_name(node_id, name)
     }
@@ -209,6 +209,46 @@ impl<'rt, R: NodeAPI + ?Sized> NodeModule<'rt, R> {
         self.rt.remove_node(node_id)
     }
 
+    pub fn set_node_enabled(&mut self, node_id: NodeID, enabled: bool) -> bool {
+        self.rt.set_node_enabled(node_id, enabled)
+    }
+
+    pub fn is_node_enabled(&mut self, node_id: NodeID) -> bool {
+        self.rt.is_node_enabled(node_id)
+    }
+
+    pub fn is_valid(&mut self, node_id: NodeID) -> bool {
+        self.rt.is_valid(node_id)
+    }
+
+    pub fn set_node_owner(&mut self, node_id: NodeID, owner: NodeID) -> bool {
+        self.rt.set_node_owner(node_id, owner)
+    }
+
+    pub fn get_node_owner(&mut self, node_id: NodeID) -> NodeID {
+        self.rt.get_node_owner(node_id)
+    }
+
+    /// The node `weak` points at, or `None` once it was freed.
+    pub fn upgrade(&mut self, weak: WeakNodeRef) -> Option<NodeID> {
+        let id = weak.id_unchecked();
+        self.rt.is_valid(id).then_some(id)
+    }
+
+    pub fn get_node_meta<S>(&mut self, node_id: NodeID, key: S) -> Variant
+    where
+        S: AsRef<str>,
+    {
+        self.rt.get_node_meta(node_id, key)
+    }
+
+    pub fn set_node_meta<K>(&mut self, node_id: NodeID, key: K, value: Variant) -> bool
+    where
+        K: Into<Cow<'static, str>>,
+    {
+        self.rt.set_node_meta(node_id, key, value)
+    }
+
     pub fn get_node_tags(&mut self, node_id: NodeID) -> Option<Vec<Cow<'static, str>>> {
         self.rt.get_node_tags(node_id)
     }
@@ -259,6 +299,13 @@ impl<'rt, R: NodeAPI + ?Sized> NodeModule<'rt, R> {
         self.rt.remove_node_tag(node_id, tag)
     }
 
+    pub fn get_nodes_in_group<T>(&mut self, group: T) -> Vec<NodeID>
+    where
+        T: IntoTagID,
+    {
+        self.rt.get_nodes_in_group(group)
+    }
+
     pub fn get_global_transform_2d(&mut self, node_id: NodeID) -> Option<Transform2D> {
         self.rt.get_global_transform_2d(node_id)
     }
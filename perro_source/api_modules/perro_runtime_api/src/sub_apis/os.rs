@@ -0,0 +1,98 @@
+//! Runtime OS/build API.
+//!
+//! Exposes the build stamp embedded at export time and the host platform, for
+//! version strings and telemetry/crash-report tags, plus the soft quit request
+//! that `quit_handlers` scripts can delay or cancel.
+
+pub use perro_structs::{BuildInfo, BuildProfile};
+
+/// Group whose scripts get `on_quit_request(code)` before the app quits.
+pub const QUIT_HANDLERS_GROUP: &str = "quit_handlers";
+
+/// Method called on every `quit_handlers` script when a quit is requested.
+pub const ON_QUIT_REQUEST: &str = "on_quit_request";
+
+pub trait OsAPI {
+    fn get_build_info(&self) -> BuildInfo;
+    fn request_quit(&mut self, code: i32);
+    fn delay_quit(&mut self);
+    fn resume_quit(&mut self);
+    fn cancel_quit(&mut self);
+    fn is_quit_pending(&self) -> bool;
+}
+
+pub struct OsModule<'rt, R: OsAPI + ?Sized> {
+    rt: &'rt mut R,
+}
+
+impl<'rt, R: OsAPI + ?Sized> OsModule<'rt, R> {
+    pub fn new(rt: &'rt mut R) -> Self {
+        Self { rt }
+    }
+
+    pub fn build_info(&mut self) -> BuildInfo {
+        self.get_build_info()
+    }
+
+    pub fn get_build_info(&mut self) -> BuildInfo {
+        self.rt.get_build_info()
+    }
+
+    /// Host OS name (`"windows"`, `"linux"`, `"macos"`, `"android"`, ...).
+    pub fn platform(&mut self) -> &'static str {
+        std::env::consts::OS
+    }
+
+    /// Ask the app to quit with exit `code`.
+    ///
+    /// Scripts in the `quit_handlers` group get `on_quit_request(code)` first.
+    /// The app quits once every handler has returned without delaying or
+    /// cancelling. A second request while one is pending is ignored.
+    pub fn quit(&mut self, code: i32) {
+        self.rt.request_quit(code);
+    }
+
+    /// Hold the pending quit until a matching `resume_quit`, e.g. while an
+    /// autosave finishes or a confirm dialog is open. No-op with no request.
+    pub fn delay_quit(&mut self) {
+        self.rt.delay_quit();
+    }
+
+    /// Release one `delay_quit` hold; the app quits when the last hold goes.
+    pub fn resume_quit(&mut self) {
+        self.rt.resume_quit();
+    }
+
+    /// Drop the pending quit and every hold on it; the app keeps running.
+    pub fn cancel_quit(&mut self) {
+        self.rt.cancel_quit();
+    }
+
+    /// Whether a quit request is waiting on handlers.
+    pub fn is_quit_pending(&mut self) -> bool {
+        self.rt.is_quit_pending()
+    }
+}
+
+/// Returns the embedded build stamp.
+///
+/// Arguments:
+/// - `ctx`: `&mut RuntimeWindow<_>`
+#[macro_export]
+macro_rules! build_info {
+    ($ctx:expr) => {
+        $ctx.OS().build_info()
+    };
+}
+
+/// Requests a soft quit; `quit_handlers` scripts may delay or cancel it.
+///
+/// Arguments:
+/// - `ctx`: `&mut RuntimeWindow<_>`
+/// - `code`: process exit code (`i32`)
+#[macro_export]
+macro_rules! quit {
+    ($ctx:expr, $code:expr) => {
+        $ctx.OS().quit($code)
+    };
+}
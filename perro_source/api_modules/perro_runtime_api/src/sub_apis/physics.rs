@@ -79,11 +79,13 @@ pub struct PhysicsMoveResult3D {
 /// `position` is the final body position after sliding along up to 4 hit
 /// planes. `remainder` is unconsumed motion (blocked, e.g. cornered).
 /// `hits` holds one entry per slide iteration that clipped, in order.
+/// `floor` is the node a `CharacterBody2D` stands on afterwards, if any.
 #[derive(Clone, Debug, PartialEq)]
 pub struct PhysicsSlideResult2D {
     pub position: Vector2,
     pub remainder: Vector2,
     pub hits: Vec<PhysicsShapeHit2D>,
+    pub floor: Option<NodeID>,
 }
 
 /// Result of an iterative move-and-slide sweep.
@@ -380,6 +382,16 @@ pub trait PhysicsAPI {
     ) -> Option<PhysicsBodyPrediction3D> {
         None
     }
+
+    /// Layer number (1..=32) named in project.toml `[layer_names.2d_physics]`.
+    fn physics_layer_2d(&mut self, _name: &str) -> Option<u8> {
+        None
+    }
+
+    /// Layer number (1..=32) named in project.toml `[layer_names.3d_physics]`.
+    fn physics_layer_3d(&mut self, _name: &str) -> Option<u8> {
+        None
+    }
 }
 
 pub trait IntoImpulseDirection {
@@ -618,6 +630,8 @@ impl<'rt, R: PhysicsAPI + ?Sized> PhysicsModule<'rt, R> {
     }
 
     /// Sweep `body_id` along `motion`, sliding along hit planes (up to 4 slides).
+    /// A `CharacterBody2D` first rides its floor's motion, then snaps back
+    /// down onto the floor per its `floor_snap_distance`.
     pub fn move_and_slide_2d(
         &mut self,
         body_id: NodeID,
@@ -756,6 +770,35 @@ impl<'rt, R: PhysicsAPI + ?Sized> PhysicsModule<'rt, R> {
     pub fn paused(&mut self) -> bool {
         self.is_paused()
     }
+
+    /// Layer number for a `[layer_names.2d_physics]` name.
+    pub fn layer_2d(&mut self, name: &str) -> Option<u8> {
+        self.rt.physics_layer_2d(name)
+    }
+
+    /// Layer number for a `[layer_names.3d_physics]` name.
+    pub fn layer_3d(&mut self, name: &str) -> Option<u8> {
+        self.rt.physics_layer_3d(name)
+    }
+
+    /// Mask of named 2D layers, e.g. for [`PhysicsQueryFilter::layers`].
+    /// Returns `None` if any name is unknown.
+    pub fn layer_mask_2d(&mut self, names: &[&str]) -> Option<BitMask> {
+        let layers = names
+            .iter()
+            .map(|name| self.rt.physics_layer_2d(name))
+            .collect::<Option<Vec<_>>>()?;
+        BitMask::try_from_layers(layers)
+    }
+
+    /// Mask of named 3D layers. Returns `None` if any name is unknown.
+    pub fn layer_mask_3d(&mut self, names: &[&str]) -> Option<BitMask> {
+        let layers = names
+            .iter()
+            .map(|name| self.rt.physics_layer_3d(name))
+            .collect::<Option<Vec<_>>>()?;
+        BitMask::try_from_layers(layers)
+    }
 }
 
 const TRAJECTORY_EPS: f32 = 1.0e-5;
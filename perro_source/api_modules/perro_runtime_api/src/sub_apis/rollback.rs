@@ -0,0 +1,117 @@
+//! Rollback netcode API.
+//!
+//! Keeps the last N fixed-tick snapshots of tracked scripts and every
+//! player's inputs. Missing remote inputs are predicted; when one arrives
+//! late and differs, the runtime restores the snapshot from b4 that tick and
+//! resimulates the fixed ticks since, all b4 the next fixed update runs.
+
+use perro_ids::NodeID;
+use perro_variant::Variant;
+
+/// Script method that returns a tracked script's rollback state, for
+/// scripts tracked w/ [`RollbackModule::track_with_hooks`].
+pub const ROLLBACK_SAVE_METHOD: &str = "rollback_save";
+/// Script method called w/ a state from [`ROLLBACK_SAVE_METHOD`] to load it.
+pub const ROLLBACK_LOAD_METHOD: &str = "rollback_load";
+
+pub trait RollbackAPI {
+    fn rollback_start(&mut self, players: u32, window: u32);
+    fn rollback_stop(&mut self);
+    fn rollback_active(&self) -> bool;
+    fn rollback_track(&mut self, script_id: NodeID, hooks: bool) -> bool;
+    fn rollback_untrack(&mut self, script_id: NodeID) -> bool;
+    fn rollback_set_input(&mut self, player: u32, tick: u64, input: Variant) -> bool;
+    fn rollback_input(&mut self, player: u32) -> Variant;
+    fn rollback_resimulating(&self) -> bool;
+}
+
+pub struct RollbackModule<'rt, R: RollbackAPI + ?Sized> {
+    rt: &'rt mut R,
+}
+
+impl<'rt, R: RollbackAPI + ?Sized> RollbackModule<'rt, R> {
+    pub fn new(rt: &'rt mut R) -> Self {
+        Self { rt }
+    }
+
+    /// Start a session for `players` that can roll back up to `window`
+    /// fixed ticks, snapshotting tracked scripts at the current sim tick.
+    /// Replaces any running session; tracked scripts are kept.
+    pub fn start(&mut self, players: u32, window: u32) {
+        self.rt.rollback_start(players, window);
+    }
+
+    pub fn stop(&mut self) {
+        self.rt.rollback_stop();
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.rt.rollback_active()
+    }
+
+    /// Snapshot `script_id` each tick through `script_save_state`.
+    pub fn track(&mut self, script_id: NodeID) -> bool {
+        self.rt.rollback_track(script_id, false)
+    }
+
+    /// Snapshot `script_id` each tick through its own `rollback_save` +
+    /// `rollback_load` methods, for state cheaper to copy by hand.
+    pub fn track_with_hooks(&mut self, script_id: NodeID) -> bool {
+        self.rt.rollback_track(script_id, true)
+    }
+
+    pub fn untrack(&mut self, script_id: NodeID) -> bool {
+        self.rt.rollback_untrack(script_id)
+    }
+
+    /// Confirm `player`'s input for sim tick `tick`, local or from a peer.
+    /// A mispredicted past tick triggers a rollback b4 the next fixed update.
+    /// `false` w/o a session, for an unknown player, a tick older than the
+    /// window, or while resimulating.
+    pub fn set_input(&mut self, player: u32, tick: u64, input: Variant) -> bool {
+        self.rt.rollback_set_input(player, tick, input)
+    }
+
+    /// `player`'s input for the current sim tick: confirmed, else their last
+    /// confirmed one repeated, else `Variant::Null`.
+    pub fn input(&mut self, player: u32) -> Variant {
+        self.rt.rollback_input(player)
+    }
+
+    /// True while past ticks are being replayed; skip sounds, particles, and
+    /// device reads then.
+    pub fn is_resimulating(&self) -> bool {
+        self.rt.rollback_resimulating()
+    }
+}
+
+/// Confirms a player's input for a sim tick.
+///
+/// Arguments:
+/// - `ctx`: `&mut RuntimeWindow<_>`
+/// - `player`: player index
+/// - `tick`: sim tick (`sim_tick!`) the input belongs to
+/// - `input`: anything `Into<Variant>`
+#[macro_export]
+macro_rules! rollback_set_input {
+    ($ctx:expr, $player:expr, $tick:expr, $input:expr) => {
+        $ctx.Rollback()
+            .set_input($player, $tick, $crate::perro_variant::Variant::from($input))
+    };
+}
+
+/// A player's confirmed or predicted input for the current sim tick.
+#[macro_export]
+macro_rules! rollback_input {
+    ($ctx:expr, $player:expr) => {
+        $ctx.Rollback().input($player)
+    };
+}
+
+/// Whether the runtime is replaying past ticks after a rollback.
+#[macro_export]
+macro_rules! rollback_resimulating {
+    ($ctx:expr) => {
+        $ctx.Rollback().is_resimulating()
+    };
+}
@@ -0,0 +1,55 @@
+//! Runtime autosave API.
+//!
+//! Periodically snapshots the script state of every node in the `autosave`
+//! group (through `script_save_state`) and writes it to
+//! `user://saves/<slot>.json` on a background thread. Writes go through a temp
+//! file + rename, and a minimum gap between writes caps save frequency.
+
+/// Group whose scripts are captured by autosave.
+pub const AUTOSAVE_GROUP: &str = "autosave";
+
+/// Shortest allowed gap between two save writes, in seconds. Autosave
+/// intervals below this are raised to it.
+pub const MIN_SAVE_GAP_SECS: f32 = 5.0;
+
+pub trait SaveAPI {
+    fn enable_autosave(&mut self, interval: f32, slot: &str) -> bool;
+    fn disable_autosave(&mut self);
+    fn is_autosave_enabled(&self) -> bool;
+    fn save_now(&mut self) -> bool;
+}
+
+pub struct SaveModule<'rt, R: SaveAPI + ?Sized> {
+    rt: &'rt mut R,
+}
+
+impl<'rt, R: SaveAPI + ?Sized> SaveModule<'rt, R> {
+    pub fn new(rt: &'rt mut R) -> Self {
+        Self { rt }
+    }
+
+    /// Save every `interval` seconds (at least [`MIN_SAVE_GAP_SECS`]) to
+    /// `user://saves/<slot>.json`. Replaces any earlier autosave setup.
+    ///
+    /// `slot` may hold ASCII letters, digits, `_` and `-`. Returns false and
+    /// leaves autosave off for any other slot name.
+    pub fn enable_autosave(&mut self, interval: f32, slot: &str) -> bool {
+        self.rt.enable_autosave(interval, slot)
+    }
+
+    pub fn disable_autosave(&mut self) {
+        self.rt.disable_autosave();
+    }
+
+    pub fn is_autosave_enabled(&mut self) -> bool {
+        self.rt.is_autosave_enabled()
+    }
+
+    /// Snapshot + write the autosave slot now, e.g. at a checkpoint.
+    ///
+    /// Returns false when autosave is off, a write is still running, or the
+    /// last write was less than [`MIN_SAVE_GAP_SECS`] ago.
+    pub fn save_now(&mut self) -> bool {
+        self.rt.save_now()
+    }
+}
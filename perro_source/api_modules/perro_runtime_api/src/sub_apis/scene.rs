@@ -235,6 +235,24 @@ pub trait SceneAPI {
         let _ = path_hash;
         self.scene_load_typed(path)
     }
+    fn scene_load_additive(&mut self, path: &str) -> Result<NodeID, String> {
+        self.scene_load(path)
+    }
+    fn scene_load_additive_typed(&mut self, path: &str) -> LoadResult<NodeID> {
+        self.scene_load_additive(path).map_err(LoadError::Legacy)
+    }
+    fn scene_unload(&mut self, _root: NodeID) -> bool {
+        false
+    }
+    fn scene_additive_roots(&mut self) -> Vec<NodeID> {
+        Vec::new()
+    }
+    fn scene_set_paused(&mut self, _root: NodeID, _paused: bool) -> bool {
+        false
+    }
+    fn scene_is_paused(&mut self, _root: NodeID) -> bool {
+        false
+    }
     fn scene_preload(&mut self, _path: &str) -> Result<PreloadedSceneID, String> {
         Err("scene preload is not supported by this runtime".to_string())
     }
@@ -336,6 +354,39 @@ impl<'rt, R: SceneAPI + ?Sized> SceneModule<'rt, R> {
         self.rt.scene_load_doc_typed(doc.into_scene())
     }
 
+    /// Load a scene next to the ones already running, e.g. a level chunk
+    /// beside a persistent world scene. Pause or unload it by its root.
+    pub fn load_additive<P: IntoScenePath>(&mut self, path: P) -> Result<NodeID, String> {
+        let path = path.into_scene_path();
+        self.rt.scene_load_additive(path.as_ref())
+    }
+
+    pub fn load_additive_typed<P: IntoScenePath>(&mut self, path: P) -> LoadResult<NodeID> {
+        let path = path.into_scene_path();
+        self.rt.scene_load_additive_typed(path.as_ref())
+    }
+
+    /// Remove a loaded scene and everything it owns. `false` when `root` is
+    /// not a loaded scene root.
+    pub fn unload(&mut self, root: NodeID) -> bool {
+        self.rt.scene_unload(root)
+    }
+
+    /// Roots of scenes from [`Self::load_additive`] that are still loaded.
+    pub fn additive_roots(&mut self) -> Vec<NodeID> {
+        self.rt.scene_additive_roots()
+    }
+
+    /// Pause or resume one loaded scene. Paused scenes still render but skip
+    /// script updates, internal node updates, and physics.
+    pub fn set_paused(&mut self, root: NodeID, paused: bool) -> bool {
+        self.rt.scene_set_paused(root, paused)
+    }
+
+    pub fn is_paused(&mut self, root: NodeID) -> bool {
+        self.rt.scene_is_paused(root)
+    }
+
     pub fn preload<P: IntoScenePath>(&mut self, path: P) -> Result<PreloadedSceneID, String> {
         let path = path.into_scene_path();
         self.rt.scene_preload(path.as_ref())
@@ -399,6 +450,43 @@ macro_rules! scene_load {
     };
 }
 
+/// Loads a scene like [`scene_load!`] and ties its root to `owner`, so
+/// removing `owner` (ie the spawning script's node) also removes it.
+/// Usage: `instantiate_owned!(ctx, owner, path) -> Result<NodeID, String>`.
+#[macro_export]
+macro_rules! instantiate_owned {
+    (@own $ctx:expr, $owner:expr, $loaded:expr) => {{
+        let __owner = $owner;
+        match $loaded {
+            ::core::result::Result::Ok(__id) => {
+                let _ = $ctx.Nodes().set_node_owner(__id, __owner);
+                ::core::result::Result::Ok(__id)
+            }
+            __err => __err,
+        }
+    }};
+    ($ctx:expr, $owner:expr, $path:literal) => {
+        $crate::instantiate_owned!(@own $ctx, $owner, $crate::scene_load!($ctx, $path))
+    };
+    ($ctx:expr, $owner:expr, $path:expr) => {
+        $crate::instantiate_owned!(@own $ctx, $owner, $ctx.Scene().load($path))
+    };
+}
+
+#[macro_export]
+macro_rules! scene_load_additive {
+    ($ctx:expr, $path:expr) => {
+        $ctx.Scene().load_additive($path)
+    };
+}
+
+#[macro_export]
+macro_rules! scene_unload {
+    ($ctx:expr, $root:expr) => {
+        $ctx.Scene().unload($root)
+    };
+}
+
 #[macro_export]
 macro_rules! scene_preload {
     ($ctx:expr, $path:literal) => {{
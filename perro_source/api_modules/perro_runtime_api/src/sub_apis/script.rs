@@ -84,6 +84,28 @@ pub trait ScriptAPI {
         method: ScriptMemberID,
         params: &[Variant],
     ) -> Variant;
+
+    fn attributes_of(
+        &mut self,
+        script_id: NodeID,
+        member: ScriptMemberID,
+    ) -> &'static [&'static str] {
+        let _ = (script_id, member);
+        &[]
+    }
+    fn members_with(&mut self, script_id: NodeID, attribute: &str) -> &'static [ScriptMemberID] {
+        let _ = (script_id, attribute);
+        &[]
+    }
+
+    fn script_save_state(&mut self, script_id: NodeID) -> Variant {
+        let _ = script_id;
+        Variant::Null
+    }
+    fn script_restore_state(&mut self, script_id: NodeID, saved: Variant) -> bool {
+        let _ = (script_id, saved);
+        false
+    }
 }
 
 pub struct ScriptModule<'rt, R: ScriptAPI + ?Sized> {
@@ -152,6 +174,31 @@ impl<'rt, R: ScriptAPI + ?Sized> ScriptModule<'rt, R> {
         self.rt
             .call_method(script_id, method.into_script_member(), params)
     }
+
+    pub fn attributes_of<M: IntoScriptMemberID>(
+        &mut self,
+        script_id: NodeID,
+        member: M,
+    ) -> &'static [&'static str] {
+        self.rt
+            .attributes_of(script_id, member.into_script_member())
+    }
+
+    pub fn members_with(
+        &mut self,
+        script_id: NodeID,
+        attribute: &str,
+    ) -> &'static [ScriptMemberID] {
+        self.rt.members_with(script_id, attribute)
+    }
+
+    pub fn save_state(&mut self, script_id: NodeID) -> Variant {
+        self.rt.script_save_state(script_id)
+    }
+
+    pub fn restore_state(&mut self, script_id: NodeID, saved: Variant) -> bool {
+        self.rt.script_restore_state(script_id, saved)
+    }
 }
 
 /// Script state macros.
@@ -305,6 +352,71 @@ macro_rules! set_var {
     };
 }
 
+/// Lists the `#[attr("...")]` tags declared on a script state member.
+///
+/// Signature:
+/// - `attributes_of!(&mut RuntimeWindow<_, _>, NodeID, member) -> &'static [&'static str]`
+///
+/// Usage:
+/// - `attributes_of!(ctx, node_id, var!("health")) -> &["save", "replicated"]`
+/// - `attributes_of!(ctx, node_id, "health") -> &["save", "replicated"]`
+///
+/// Returns an empty slice for unknown scripts, unknown members, and untagged members.
+#[macro_export]
+macro_rules! attributes_of {
+    ($ctx:expr, $id:expr, $member:expr) => {
+        $ctx.Scripts().attributes_of($id, $member)
+    };
+}
+
+/// Lists every script state member tagged with `#[attr(attribute)]`.
+///
+/// Signature:
+/// - `members_with!(&mut RuntimeWindow<_, _>, NodeID, &str) -> &'static [ScriptMemberID]`
+///
+/// Usage:
+/// - `for &member in members_with!(ctx, node_id, "save") { let value = get_var!(ctx, node_id, member); }`
+///
+/// Members come back in declaration order; unknown scripts return an empty slice.
+#[macro_export]
+macro_rules! members_with {
+    ($ctx:expr, $id:expr, $attribute:expr) => {
+        $ctx.Scripts().members_with($id, $attribute)
+    };
+}
+
+/// Captures script state as a versioned save payload.
+///
+/// Signature:
+/// - `script_save_state!(&mut RuntimeWindow<_, _>, NodeID) -> Variant`
+///
+/// Payload shape:
+/// - `{ "version": STATE_VERSION, "state": { field: value, ... } }`
+/// - plus `"meta": { key: value, ... }` when the node has `set_meta!` values
+///
+/// Returns `Variant::Null` for unknown scripts.
+#[macro_export]
+macro_rules! script_save_state {
+    ($ctx:expr, $id:expr) => {
+        $ctx.Scripts().save_state($id)
+    };
+}
+
+/// Restores a payload from `script_save_state!` into script state.
+///
+/// Signature:
+/// - `script_restore_state!(&mut RuntimeWindow<_, _>, NodeID, Variant) -> bool`
+///
+/// Payloads saved under an older `STATE_VERSION` go through the script's
+/// `migrate_state` lifecycle before fields are written.
+/// Returns `false` for unknown scripts and malformed payloads.
+#[macro_export]
+macro_rules! script_restore_state {
+    ($ctx:expr, $id:expr, $saved:expr) => {
+        $ctx.Scripts().restore_state($id, $saved)
+    };
+}
+
 /// Calls a script method with params.
 ///
 /// Signature:
@@ -0,0 +1,88 @@
+//! Fixed-tick simulation clock and random stream.
+//!
+//! Advanced once per fixed update, never from wall-clock time, so lockstep
+//! peers and replays that run the same ticks from the same seed read the same
+//! times and draw the same numbers.
+
+pub trait SimAPI {
+    fn sim_tick(&self) -> u64;
+    fn sim_time(&self) -> f64;
+    fn sim_seed(&self) -> u32;
+    fn sim_reset(&mut self, seed: u32);
+    fn sim_next_u32(&mut self) -> u32;
+}
+
+pub struct SimModule<'rt, R: SimAPI + ?Sized> {
+    rt: &'rt mut R,
+}
+
+impl<'rt, R: SimAPI + ?Sized> SimModule<'rt, R> {
+    pub fn new(rt: &'rt mut R) -> Self {
+        Self { rt }
+    }
+
+    /// Fixed ticks run since the last reset.
+    pub fn tick(&self) -> u64 {
+        self.rt.sim_tick()
+    }
+
+    /// Sum of fixed deltas since the last reset, in seconds.
+    pub fn time(&self) -> f64 {
+        self.rt.sim_time()
+    }
+
+    pub fn seed(&self) -> u32 {
+        self.rt.sim_seed()
+    }
+
+    /// Restart at tick 0 w/ `seed`; peers call this w/ the same seed when a
+    /// match or replay starts.
+    pub fn reset(&mut self, seed: u32) {
+        self.rt.sim_reset(seed);
+    }
+
+    /// Next draw from this tick's stream. Each tick's stream depends only on
+    /// the seed + tick, so a peer that draws more one tick stays in sync on
+    /// the next.
+    pub fn next_u32(&mut self) -> u32 {
+        self.rt.sim_next_u32()
+    }
+
+    /// [`next_u32`](Self::next_u32) mapped to `[0, 1]`.
+    pub fn next_01(&mut self) -> f32 {
+        self.rt.sim_next_u32() as f32 / u32::MAX as f32
+    }
+}
+
+/// Fixed ticks run since the last `sim_reset!`.
+#[macro_export]
+macro_rules! sim_tick {
+    ($ctx:expr) => {
+        $ctx.Sim().tick()
+    };
+}
+
+/// Simulation seconds (sum of fixed deltas) since the last `sim_reset!`.
+#[macro_export]
+macro_rules! sim_time {
+    ($ctx:expr) => {
+        $ctx.Sim().time()
+    };
+}
+
+/// Restart the simulation clock at tick 0 w/ a shared seed.
+#[macro_export]
+macro_rules! sim_reset {
+    ($ctx:expr, $seed:expr) => {
+        $ctx.Sim().reset($seed)
+    };
+}
+
+/// Next `u32` from the current tick's random stream. Feed it to the
+/// `perro_modules::random` helpers as their seed for ranges and picks.
+#[macro_export]
+macro_rules! sim_rand {
+    ($ctx:expr) => {
+        $ctx.Sim().next_u32()
+    };
+}
@@ -0,0 +1,70 @@
+//! Runtime 2D spatial index API.
+//!
+//! Answers "which 2D nodes are near this point" from a uniform grid of global
+//! node positions. The runtime keeps the grid in sync with transform writes, so
+//! crowded scenes avoid scanning every node for each proximity check.
+
+use perro_ids::NodeID;
+use perro_structs::Vector2;
+
+pub trait Space2DAPI {
+    fn space2d_query_radius(&mut self, point: Vector2, radius: f32) -> Vec<NodeID>;
+    fn space2d_query_rect(&mut self, min: Vector2, max: Vector2) -> Vec<NodeID>;
+    fn space2d_set_cell_size(&mut self, size: f32);
+    fn space2d_cell_size(&self) -> f32;
+}
+
+pub struct Space2DModule<'rt, R: Space2DAPI + ?Sized> {
+    rt: &'rt mut R,
+}
+
+impl<'rt, R: Space2DAPI + ?Sized> Space2DModule<'rt, R> {
+    pub fn new(rt: &'rt mut R) -> Self {
+        Self { rt }
+    }
+
+    /// 2D nodes whose global position lies within `radius` of `point`.
+    pub fn query_radius(&mut self, point: Vector2, radius: f32) -> Vec<NodeID> {
+        self.rt.space2d_query_radius(point, radius)
+    }
+
+    /// 2D nodes whose global position lies inside the `min..=max` box.
+    pub fn query_rect(&mut self, min: Vector2, max: Vector2) -> Vec<NodeID> {
+        self.rt.space2d_query_rect(min, max)
+    }
+
+    /// Grid cell edge length in world units. Rebuilds the index on change.
+    pub fn set_cell_size(&mut self, size: f32) {
+        self.rt.space2d_set_cell_size(size);
+    }
+
+    pub fn cell_size(&self) -> f32 {
+        self.rt.space2d_cell_size()
+    }
+}
+
+/// Returns 2D node ids within `radius` of `point`.
+///
+/// Arguments:
+/// - `ctx`: `&mut RuntimeWindow<_>`
+/// - `point`: `Vector2`
+/// - `radius`: `f32`
+#[macro_export]
+macro_rules! space2d_query_radius {
+    ($ctx:expr, $point:expr, $radius:expr) => {
+        $ctx.Space2D().query_radius($point, $radius)
+    };
+}
+
+/// Returns 2D node ids inside the `min..=max` box.
+///
+/// Arguments:
+/// - `ctx`: `&mut RuntimeWindow<_>`
+/// - `min`: `Vector2`
+/// - `max`: `Vector2`
+#[macro_export]
+macro_rules! space2d_query_rect {
+    ($ctx:expr, $min:expr, $max:expr) => {
+        $ctx.Space2D().query_rect($min, $max)
+    };
+}
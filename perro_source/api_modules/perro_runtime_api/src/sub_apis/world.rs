@@ -0,0 +1,200 @@
+//! Runtime world snapshot API.
+//!
+//! Exposes a read-only copy of commonly read scene data (global transforms,
+//! parents, tags) captured at frame start. Scripts read neighbors through the
+//! snapshot without routing through `with_node` and without seeing writes other
+//! scripts made earlier in the same frame.
+
+use perro_ids::{IntoTagID, NodeID, TagID};
+use perro_structs::{Transform2D, Transform3D, Vector2, Vector3};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SnapshotTransform {
+    None,
+    TwoD(Transform2D),
+    ThreeD(Transform3D),
+}
+
+#[derive(Clone, Debug)]
+struct SnapshotNode {
+    parent: NodeID,
+    transform: SnapshotTransform,
+    tags: Box<[TagID]>,
+}
+
+#[derive(Debug, Default)]
+struct WorldSnapshotData {
+    revision: u64,
+    nodes: HashMap<NodeID, SnapshotNode>,
+    tagged: HashMap<TagID, Vec<NodeID>>,
+}
+
+/// Immutable frame-start copy of scene transforms, parents, and tags.
+///
+/// Cloning is cheap: every clone shares one `Arc` payload. Holding a snapshot
+/// across frames is allowed; it keeps describing the frame it was taken on.
+#[derive(Clone, Debug, Default)]
+pub struct WorldSnapshot {
+    data: Arc<WorldSnapshotData>,
+}
+
+impl WorldSnapshot {
+    /// Node arena mutation revision at capture time.
+    pub fn revision(&self) -> u64 {
+        self.data.revision
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.nodes.is_empty()
+    }
+
+    /// Returns whether `id` was alive at capture time.
+    pub fn contains(&self, id: NodeID) -> bool {
+        self.data.nodes.contains_key(&id)
+    }
+
+    /// Parent at capture time. `Some(NodeID::nil())` for roots.
+    pub fn parent(&self, id: NodeID) -> Option<NodeID> {
+        self.data.nodes.get(&id).map(|node| node.parent)
+    }
+
+    /// Global 2D transform. `None` for missing and non-2D nodes.
+    pub fn global_transform_2d(&self, id: NodeID) -> Option<Transform2D> {
+        match self.data.nodes.get(&id)?.transform {
+            SnapshotTransform::TwoD(transform) => Some(transform),
+            _ => None,
+        }
+    }
+
+    /// Global 3D transform. `None` for missing and non-3D nodes.
+    pub fn global_transform_3d(&self, id: NodeID) -> Option<Transform3D> {
+        match self.data.nodes.get(&id)?.transform {
+            SnapshotTransform::ThreeD(transform) => Some(transform),
+            _ => None,
+        }
+    }
+
+    pub fn global_pos_2d(&self, id: NodeID) -> Option<Vector2> {
+        self.global_transform_2d(id)
+            .map(|transform| transform.position)
+    }
+
+    pub fn global_pos_3d(&self, id: NodeID) -> Option<Vector3> {
+        self.global_transform_3d(id)
+            .map(|transform| transform.position)
+    }
+
+    /// Tags on `id` at capture time. Empty for missing nodes.
+    pub fn tags(&self, id: NodeID) -> &[TagID] {
+        self.data
+            .nodes
+            .get(&id)
+            .map(|node| node.tags.as_ref())
+            .unwrap_or(&[])
+    }
+
+    pub fn has_tag<T: IntoTagID>(&self, id: NodeID, tag: T) -> bool {
+        let tag = tag.into_tag_id();
+        self.tags(id).contains(&tag)
+    }
+
+    /// Every node carrying `tag` at capture time, in capture order.
+    pub fn tagged<T: IntoTagID>(&self, tag: T) -> &[NodeID] {
+        self.data
+            .tagged
+            .get(&tag.into_tag_id())
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+}
+
+/// Runtime-side builder used to capture a [`WorldSnapshot`].
+#[derive(Debug, Default)]
+pub struct WorldSnapshotBuilder {
+    data: WorldSnapshotData,
+}
+
+impl WorldSnapshotBuilder {
+    pub fn new(revision: u64) -> Self {
+        Self::with_capacity(revision, 0)
+    }
+
+    pub fn with_capacity(revision: u64, capacity: usize) -> Self {
+        Self {
+            data: WorldSnapshotData {
+                revision,
+                nodes: HashMap::with_capacity(capacity),
+                tagged: HashMap::new(),
+            },
+        }
+    }
+
+    /// Record a node without a spatial transform (UI, resource, base nodes).
+    pub fn push(&mut self, id: NodeID, parent: NodeID, tags: &[TagID]) {
+        self.insert(id, parent, SnapshotTransform::None, tags);
+    }
+
+    pub fn push_2d(&mut self, id: NodeID, parent: NodeID, global: Transform2D, tags: &[TagID]) {
+        self.insert(id, parent, SnapshotTransform::TwoD(global), tags);
+    }
+
+    pub fn push_3d(&mut self, id: NodeID, parent: NodeID, global: Transform3D, tags: &[TagID]) {
+        self.insert(id, parent, SnapshotTransform::ThreeD(global), tags);
+    }
+
+    pub fn finish(self) -> WorldSnapshot {
+        WorldSnapshot {
+            data: Arc::new(self.data),
+        }
+    }
+
+    fn insert(&mut self, id: NodeID, parent: NodeID, transform: SnapshotTransform, tags: &[TagID]) {
+        for &tag in tags {
+            self.data.tagged.entry(tag).or_default().push(id);
+        }
+        self.data.nodes.insert(
+            id,
+            SnapshotNode {
+                parent,
+                transform,
+                tags: tags.into(),
+            },
+        );
+    }
+}
+
+pub trait WorldAPI {
+    fn world_snapshot(&mut self) -> WorldSnapshot;
+}
+
+pub struct WorldModule<'rt, R: WorldAPI + ?Sized> {
+    rt: &'rt mut R,
+}
+
+impl<'rt, R: WorldAPI + ?Sized> WorldModule<'rt, R> {
+    pub fn new(rt: &'rt mut R) -> Self {
+        Self { rt }
+    }
+
+    /// Frame-start snapshot shared by every script this frame.
+    pub fn snapshot(&mut self) -> WorldSnapshot {
+        self.rt.world_snapshot()
+    }
+}
+
+/// Returns the frame-start world snapshot.
+///
+/// Arguments:
+/// - `ctx`: `&mut RuntimeWindow<_>`
+#[macro_export]
+macro_rules! world_snapshot {
+    ($ctx:expr) => {
+        $ctx.World().snapshot()
+    };
+}
@@ -2,9 +2,10 @@ use crate::{
     prelude::*,
     sub_apis::{
         AnimPlayerAPI, AnimTreeAPI, RuntimeAudio, RuntimeAudioAPI, SceneAPI, SpatialAudioOptions,
+        WorldSnapshotBuilder,
     },
 };
-use perro_ids::{AnimationID, AudioBusID, IntoTagID, MeshID, NodeID};
+use perro_ids::{AnimationID, AudioBusID, IntoTagID, MeshID, NodeID, TagID};
 use perro_nodes::prelude::{Node2D, NodeTypeDispatch, SceneNodeData, UiLabel};
 use perro_resource_api::{LoadError, res_path};
 use perro_structs::{Quaternion, Transform2D, Transform3D, Vector2, Vector3};
@@ -15,6 +16,7 @@ struct DummyRuntime {
     state: Box<dyn Any>,
     gravity: f32,
     coefficient: f32,
+    sim_seed: u32,
 }
 
 impl TimeAPI for DummyRuntime {
@@ -146,13 +148,13 @@ impl NodeAPI for DummyRuntime {
         None
     }
 
-    fn get_node_name(&mut self, _node: NodeID) -> Option<std::borrow::Cow<'static, str>> {
+    fn get_node_name(&mut self, _node: NodeID) -> Option<perro_ids::Name> {
         None
     }
 
     fn set_node_name<S>(&mut self, _node: NodeID, _name: S) -> bool
     where
-        S: Into<std::borrow::Cow<'static, str>>,
+        S: Into<perro_ids::Name>,
     {
         false
     }
@@ -217,6 +219,40 @@ impl NodeAPI for DummyRuntime {
         false
     }
 
+    fn is_valid(&mut self, _node_id: NodeID) -> bool {
+        false
+    }
+
+    fn set_node_owner(&mut self, _node_id: NodeID, _owner: NodeID) -> bool {
+        false
+    }
+
+    fn get_node_owner(&mut self, _node_id: NodeID) -> NodeID {
+        NodeID::nil()
+    }
+
+    fn set_node_enabled(&mut self, _node_id: NodeID, _enabled: bool) -> bool {
+        false
+    }
+
+    fn is_node_enabled(&mut self, _node_id: NodeID) -> bool {
+        false
+    }
+
+    fn get_node_meta<S>(&mut self, _node_id: NodeID, _key: S) -> Variant
+    where
+        S: AsRef<str>,
+    {
+        Variant::Null
+    }
+
+    fn set_node_meta<K>(&mut self, _node_id: NodeID, _key: K, _value: Variant) -> bool
+    where
+        K: Into<Cow<'static, str>>,
+    {
+        false
+    }
+
     fn get_node_tags(&mut self, _node_id: NodeID) -> Option<Vec<Cow<'static, str>>> {
         None
     }
@@ -504,6 +540,74 @@ impl SignalAPI for DummyRuntime {
     }
 }
 
+impl SimAPI for DummyRuntime {
+    fn sim_tick(&self) -> u64 {
+        12
+    }
+
+    fn sim_time(&self) -> f64 {
+        0.2
+    }
+
+    fn sim_seed(&self) -> u32 {
+        self.sim_seed
+    }
+
+    fn sim_reset(&mut self, seed: u32) {
+        self.sim_seed = seed;
+    }
+
+    fn sim_next_u32(&mut self) -> u32 {
+        u32::MAX
+    }
+}
+
+impl RollbackAPI for DummyRuntime {
+    fn rollback_start(&mut self, _players: u32, _window: u32) {}
+
+    fn rollback_stop(&mut self) {}
+
+    fn rollback_active(&self) -> bool {
+        false
+    }
+
+    fn rollback_track(&mut self, script_id: NodeID, _hooks: bool) -> bool {
+        !script_id.is_nil()
+    }
+
+    fn rollback_untrack(&mut self, script_id: NodeID) -> bool {
+        !script_id.is_nil()
+    }
+
+    fn rollback_set_input(&mut self, player: u32, _tick: u64, _input: Variant) -> bool {
+        player < 2
+    }
+
+    fn rollback_input(&mut self, player: u32) -> Variant {
+        Variant::from(player as i32)
+    }
+
+    fn rollback_resimulating(&self) -> bool {
+        false
+    }
+}
+
+impl EventAPI for DummyRuntime {
+    fn event_subscribe(&mut self, topic: &str, _script: NodeID, method: &str) -> bool {
+        !topic.is_empty() && !method.is_empty()
+    }
+
+    fn event_unsubscribe(&mut self, topic: &str, _script: NodeID, _method: &str) -> bool {
+        topic == "score"
+    }
+
+    fn event_publish(&mut self, _topic: &str, _payload: perro_variant::Variant) {}
+
+    fn event_debug_view(&self) -> String {
+        "score -> 42:0.on_score\n".to_string()
+    }
+}
+
 impl PhysicsAPI for DummyRuntime {
     fn get_gravity(&mut self) -> f32 {
         self.gravity
@@ -772,11 +876,113 @@ impl SceneAPI for DummyRuntime {
     }
 }
 
+impl OsAPI for DummyRuntime {
+    fn get_build_info(&self) -> BuildInfo {
+        BuildInfo::embedded(
+            "1.4.0",
+            Some("0123456789abcdef"),
+            1_700_000_000,
+            BuildProfile::Release,
+        )
+    }
+
+    fn request_quit(&mut self, code: i32) {
+        self.state = Box::new(code);
+    }
+
+    fn delay_quit(&mut self) {}
+
+    fn resume_quit(&mut self) {}
+
+    fn cancel_quit(&mut self) {
+        self.state = Box::new(());
+    }
+
+    fn is_quit_pending(&self) -> bool {
+        self.state.is::<i32>()
+    }
+}
+
+impl DebugAPI for DummyRuntime {
+    fn get_memory_report(&self) -> MemoryReport {
+        MemoryReport {
+            node_count: 3,
+            node_arena_bytes: 1_024,
+            script_count: 1,
+            script_state_bytes: 64,
+            variant_bytes: 32,
+            scene_cache_bytes: 512,
+            asset_archive_bytes: 4_096,
+            gpu_bytes: 65_536,
+        }
+    }
+
+    fn get_leak_report(&self) -> LeakReport {
+        LeakReport {
+            tracking: true,
+            entries: vec![LeakEntry {
+                kind: LeakKind::Node,
+                id: NodeID::new(7).as_u64(),
+                label: "Orphan (Node2D, 2 nodes)".to_string(),
+                creator: Some(NodeID::new(1)),
+                age_secs: 1.5,
+                backtrace: None,
+            }],
+        }
+    }
+}
+
+impl WorldAPI for DummyRuntime {
+    fn world_snapshot(&mut self) -> WorldSnapshot {
+        let enemy = TagID::from_string("enemy");
+        let mut builder = WorldSnapshotBuilder::new(9);
+        builder.push(NodeID::new(1), NodeID::nil(), &[]);
+        builder.push_2d(
+            NodeID::new(2),
+            NodeID::new(1),
+            Transform2D::new(Vector2::new(4.0, 5.0), 0.0, Vector2::new(1.0, 1.0)),
+            &[enemy],
+        );
+        builder.push_3d(
+            NodeID::new(3),
+            NodeID::new(1),
+            Transform3D::new(
+                Vector3::new(1.0, 2.0, 3.0),
+                Quaternion::IDENTITY,
+                Vector3::new(1.0, 1.0, 1.0),
+            ),
+            &[enemy],
+        );
+        builder.finish()
+    }
+}
+
+impl Space2DAPI for DummyRuntime {
+    fn space2d_query_radius(&mut self, point: Vector2, radius: f32) -> Vec<NodeID> {
+        if point.x.abs() <= radius && point.y.abs() <= radius {
+            vec![NodeID::new(2)]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn space2d_query_rect(&mut self, min: Vector2, max: Vector2) -> Vec<NodeID> {
+        vec![NodeID::new(min.x as u32), NodeID::new(max.x as u32)]
+    }
+
+    fn space2d_set_cell_size(&mut self, _size: f32) {}
+
+    fn space2d_cell_size(&self) -> f32 {
+        32.0
+    }
+}
+
 fn dummy_runtime() -> DummyRuntime {
     DummyRuntime {
         state: Box::new(0_i32),
         gravity: -9.81,
         coefficient: 1.0,
+        sim_seed: 0,
     }
 }
 
@@ -7,6 +7,7 @@ mod audio {
             state: Box::new(5_i32),
             gravity: -9.81,
             coefficient: 0.7,
+            sim_seed: 0,
         };
         let mut ctx = RuntimeWindow::new(&mut rt);
         let id = NodeID::new(42);
@@ -35,6 +36,7 @@ mod audio {
             state: Box::new(ids.clone()),
             gravity: -9.81,
             coefficient: 1.0,
+            sim_seed: 0,
         };
         let mut ctx = RuntimeWindow::new(&mut rt);
         let parent = NodeID::new(99);
@@ -14,4 +14,98 @@ mod core {
         let _data = SceneNodeData::Label3D(Label3D::new());
     }
 
+    #[test]
+    fn os_module_reports_embedded_build_info() {
+        let mut rt = dummy_runtime();
+        let mut ctx = RuntimeWindow::new(&mut rt);
+        let info = build_info!(ctx);
+        assert_eq!(info.version, "1.4.0");
+        assert_eq!(info.short_commit(), Some("0123456"));
+        assert_eq!(info.profile, BuildProfile::Release);
+        assert_eq!(ctx.OS().build_info().timestamp, 1_700_000_000);
+        assert_eq!(ctx.OS().platform(), std::env::consts::OS);
+    }
+
+    #[test]
+    fn quit_macro_routes_exit_code_through_os_module() {
+        let mut rt = dummy_runtime();
+        let mut ctx = RuntimeWindow::new(&mut rt);
+        quit!(ctx, 3);
+        assert!(ctx.OS().is_quit_pending());
+        ctx.OS().cancel_quit();
+        assert!(!ctx.OS().is_quit_pending());
+        ctx.OS().quit(7);
+        assert_eq!(rt.state.downcast_ref::<i32>(), Some(&7));
+    }
+
+    #[test]
+    fn debug_module_sums_memory_report_by_subsystem() {
+        let mut rt = dummy_runtime();
+        let mut ctx = RuntimeWindow::new(&mut rt);
+        let report = memory_report!(ctx);
+        assert_eq!(report.node_count, 3);
+        assert_eq!(report.cpu_bytes(), 1_024 + 64 + 32 + 512 + 4_096);
+        assert_eq!(report.total_bytes(), report.cpu_bytes() + 65_536);
+        assert_eq!(ctx.Debug().memory_report(), report);
+    }
+
+    #[test]
+    fn debug_module_lists_leaks_with_creator() {
+        let mut rt = dummy_runtime();
+        let mut ctx = RuntimeWindow::new(&mut rt);
+        let report = leak_report!(ctx);
+        assert!(report.tracking);
+        assert_eq!(report.count(LeakKind::Node), 1);
+        assert_eq!(report.count(LeakKind::Texture), 0);
+        assert_eq!(report.entries[0].creator, Some(NodeID::new(1)));
+        let text = report.to_string();
+        assert!(text.starts_with("1 leaked object(s)"));
+        assert!(text.contains("`Orphan (Node2D, 2 nodes)`"));
+        assert_eq!(
+            LeakReport::default().to_string(),
+            "leak tracking off (release build)"
+        );
+    }
+
+    #[test]
+    fn world_module_reads_snapshot_transforms_and_tags() {
+        let mut rt = dummy_runtime();
+        let mut ctx = RuntimeWindow::new(&mut rt);
+        let world = world_snapshot!(ctx);
+        assert_eq!(world.revision(), 9);
+        assert_eq!(world.len(), 3);
+        assert_eq!(world.parent(NodeID::new(2)), Some(NodeID::new(1)));
+        assert_eq!(world.parent(NodeID::new(1)), Some(NodeID::nil()));
+        assert_eq!(
+            world.global_pos_2d(NodeID::new(2)),
+            Some(Vector2::new(4.0, 5.0))
+        );
+        assert_eq!(world.global_pos_2d(NodeID::new(3)), None);
+        assert_eq!(
+            world.global_pos_3d(NodeID::new(3)),
+            Some(Vector3::new(1.0, 2.0, 3.0))
+        );
+        assert!(world.has_tag(NodeID::new(2), "enemy"));
+        assert!(!world.has_tag(NodeID::new(1), "enemy"));
+        assert_eq!(world.tagged("enemy"), &[NodeID::new(2), NodeID::new(3)]);
+        assert!(world.tagged("ally").is_empty());
+        assert!(!world.contains(NodeID::new(4)));
+        assert!(world.tags(NodeID::new(4)).is_empty());
+    }
+
+    #[test]
+    fn space2d_module_routes_queries_to_runtime() {
+        let mut rt = dummy_runtime();
+        let mut ctx = RuntimeWindow::new(&mut rt);
+        assert_eq!(
+            space2d_query_radius!(ctx, Vector2::new(1.0, 1.0), 4.0),
+            vec![NodeID::new(2)]
+        );
+        assert!(space2d_query_radius!(ctx, Vector2::new(9.0, 0.0), 4.0).is_empty());
+        assert_eq!(
+            space2d_query_rect!(ctx, Vector2::new(3.0, 0.0), Vector2::new(7.0, 0.0)),
+            vec![NodeID::new(3), NodeID::new(7)]
+        );
+        assert_eq!(ctx.Space2D().cell_size(), 32.0);
+    }
 }
@@ -152,6 +152,7 @@ mod scene {
             state: Box::new(5_i32),
             gravity: -9.81,
             coefficient: 1.0,
+            sim_seed: 0,
         };
         let mut ctx = RuntimeWindow::new(&mut rt);
         let id = NodeID::new(42);
@@ -220,6 +221,13 @@ mod scene {
         assert!(!reparent!(&mut ctx, NodeID::new(1), id));
         assert_eq!(reparent_multi!(&mut ctx, NodeID::new(1), [id]), 0);
         assert!(!remove_node!(&mut ctx, id));
+        assert!(!set_node_enabled!(&mut ctx, id, false));
+        assert!(!is_node_enabled!(&mut ctx, id));
+        assert!(!node_is_valid!(&mut ctx, id));
+        assert_eq!(upgrade_node!(&mut ctx, WeakNodeRef::new(id)), None);
+        assert!(!set_node_owner!(&mut ctx, id, NodeID::new(1)));
+        assert!(!set_meta!(&mut ctx, id, "loot_table", Variant::from(3_i32)));
+        assert!(get_meta!(&mut ctx, id, "loot_table").is_null());
         assert_eq!(get_global_transform_2d!(&mut ctx, id), None);
         assert_eq!(get_global_transform_3d!(&mut ctx, id), None);
         assert_eq!(get_local_transform_2d!(&mut ctx, id), None);
@@ -545,6 +553,25 @@ mod scene {
         );
         assert_eq!(signal_emit!(&mut ctx, signal!("on_test")), 1);
         assert_eq!(ctx.Signals().emit(signal!("on_test"), &[]), 1);
+        assert!(event_subscribe!(&mut ctx, id, "score", "on_score"));
+        let topic = String::from("score");
+        assert!(event_subscribe!(&mut ctx, id, topic, "on_score"));
+        assert!(event_unsubscribe!(&mut ctx, id, "score", "on_score"));
+        event_publish!(&mut ctx, "score", 10_i32);
+        event_publish!(&mut ctx, "score", Variant::from("ten"));
+        event_publish!(&mut ctx, String::from("level_done"));
+        assert_eq!(ctx.Events().debug_view(), "score -> 42:0.on_score\n");
+        assert_eq!(sim_tick!(&mut ctx), 12);
+        assert_eq!(sim_time!(&mut ctx), 0.2);
+        sim_reset!(&mut ctx, 77);
+        assert_eq!(ctx.Sim().seed(), 77);
+        assert_eq!(sim_rand!(&mut ctx), u32::MAX);
+        assert_eq!(ctx.Sim().next_01(), 1.0);
+        assert!(ctx.Rollback().track_with_hooks(id));
+        assert!(rollback_set_input!(&mut ctx, 1, 12, "jump"));
+        assert!(!rollback_set_input!(&mut ctx, 2, 12, Variant::Null));
+        assert_eq!(rollback_input!(&mut ctx, 1), Variant::from(1_i32));
+        assert!(!rollback_resimulating!(&mut ctx));
         assert_eq!(
             scene_load!(&mut ctx, "res://scenes/a.scene"),
             Ok(NodeID::new(7))
@@ -555,6 +582,15 @@ mod scene {
         );
         let cow_path = std::borrow::Cow::Borrowed("res://scenes/c.scene");
         assert_eq!(scene_load!(&mut ctx, cow_path), Ok(NodeID::new(7)));
+        assert_eq!(
+            instantiate_owned!(&mut ctx, id, "res://scenes/bullet.scene"),
+            Ok(NodeID::new(7))
+        );
+        let bullet_path = String::from("res://scenes/bullet.scene");
+        assert_eq!(
+            instantiate_owned!(&mut ctx, id, bullet_path),
+            Ok(NodeID::new(7))
+        );
         assert_eq!(
             ctx.Scene()
                 .load_typed("res://scenes/typed.scene")
@@ -85,6 +85,7 @@ fn spatial_params(id: u64) -> SpatialAudioParams {
             attack: 0.005 + t * 0.04,
             release: 0.05 + t * 0.3,
         },
+        pitch: 0.9 + t * 0.2,
     }
 }
 
@@ -111,6 +112,8 @@ fn request_for(
         compression: Default::default(),
         from_start: 0.0,
         from_end,
+        loop_start: 0.0,
+        loop_end: 0.0,
     }
 }
 
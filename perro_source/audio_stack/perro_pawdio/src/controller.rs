@@ -1,6 +1,6 @@
 use crossbeam_channel::{self, Receiver, Sender, TrySendError};
-use perro_ids::AudioBusID;
-use std::collections::{HashMap, HashSet};
+use perro_ids::{AssetPath, AudioBusID};
+use std::collections::HashSet;
 use std::fmt;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
@@ -10,7 +10,7 @@ use crate::internal::{OwnedMidiFileRequest, OwnedMidiNoteRequest};
 use crate::mic::MicClip;
 use crate::midi::{MidiFileRequest, MidiNoteHandle, MidiNoteRequest};
 use crate::player::BarkPlayer;
-use crate::types::{AudioPlaybackRequest, SpatialAudioParams};
+use crate::types::{AudioBusEffects, AudioPlaybackRequest, SpatialAudioParams};
 
 const AUDIO_DISABLED_ENV: &str = "PERRO_AUDIO_DISABLED";
 
@@ -40,7 +40,6 @@ pub type AudioEnqueueResult<T = ()> = Result<T, AudioEnqueueError>;
 pub struct AudioController {
     tx: Sender<AudioCommand>,
     next_playback_id: Arc<AtomicU64>,
-    source_pool: Mutex<HashMap<u64, Arc<str>>>,
     loaded: Arc<Mutex<AudioLoadedState>>,
 }
 
@@ -134,6 +133,9 @@ fn process_audio_command(
         AudioCommand::Play { request } => {
             let _ = player.play_source(request.as_request());
         }
+        AudioCommand::Crossfade { request, seconds } => {
+            let _ = player.crossfade(request.as_request(), seconds);
+        }
         AudioCommand::PlayClip {
             source,
             clip,
@@ -166,8 +168,12 @@ fn process_audio_command(
         }
         AudioCommand::StopAll => player.stop_all(),
         AudioCommand::SetMasterVolume { volume } => player.set_master_volume(volume),
+        AudioCommand::SetMasterMuted { muted } => player.set_master_muted(muted),
+        AudioCommand::SetMasterEffects { effects } => player.set_master_effects(effects),
         AudioCommand::SetBusVolume { bus_id, volume } => player.set_bus_volume(bus_id, volume),
         AudioCommand::SetBusSpeed { bus_id, speed } => player.set_bus_speed(bus_id, speed),
+        AudioCommand::SetBusMuted { bus_id, muted } => player.set_bus_muted(bus_id, muted),
+        AudioCommand::SetBusEffects { bus_id, effects } => player.set_bus_effects(bus_id, effects),
         AudioCommand::PauseBus { bus_id } => player.pause_bus(bus_id),
         AudioCommand::ResumeBus { bus_id } => player.resume_bus(bus_id),
         AudioCommand::StopBus { bus_id } => {
@@ -248,7 +254,6 @@ impl AudioController {
         Ok(Self {
             tx,
             next_playback_id,
-            source_pool: Mutex::new(HashMap::new()),
             loaded,
         })
     }
@@ -258,7 +263,6 @@ impl AudioController {
         Self {
             tx,
             next_playback_id: Arc::new(AtomicU64::new(1)),
-            source_pool: Mutex::new(HashMap::new()),
             loaded: Arc::new(Mutex::new(AudioLoadedState::default())),
         }
     }
@@ -271,18 +275,7 @@ impl AudioController {
     }
 
     fn intern_source(&self, source: &str) -> Arc<str> {
-        let hash = perro_ids::string_to_u64(source);
-        let Ok(mut pool) = self.source_pool.lock() else {
-            return Arc::from(source);
-        };
-        if let Some(existing) = pool.get(&hash)
-            && existing.as_ref() == source
-        {
-            return existing.clone();
-        }
-        let interned: Arc<str> = Arc::from(source);
-        pool.insert(hash, interned.clone());
-        interned
+        AssetPath::new(source).into()
     }
 
     pub fn source_handle(&self, source: &str) -> AudioSourceHandle {
@@ -391,6 +384,23 @@ impl AudioController {
         })
     }
 
+    pub fn crossfade(&self, request: AudioPlaybackRequest<'_>, seconds: f32) -> bool {
+        self.enqueue_crossfade(request, seconds).is_ok()
+    }
+
+    /// Enqueue a bus crossfade to `request`; success does not mean playback has started.
+    pub fn enqueue_crossfade(
+        &self,
+        request: AudioPlaybackRequest<'_>,
+        seconds: f32,
+    ) -> AudioEnqueueResult {
+        let source = self.intern_source(request.source);
+        self.enqueue(AudioCommand::Crossfade {
+            request: OwnedAudioPlaybackRequest::from_request_with_source(request, source),
+            seconds,
+        })
+    }
+
     pub fn play_spatial_source(&self, request: AudioPlaybackRequest<'_>) -> Option<u64> {
         self.enqueue_play_spatial_source(request).ok()
     }
@@ -590,6 +600,24 @@ impl AudioController {
         self.enqueue(AudioCommand::SetMasterVolume { volume })
     }
 
+    pub fn set_master_muted(&self, muted: bool) -> bool {
+        self.enqueue_set_master_muted(muted).is_ok()
+    }
+
+    /// Enqueue a master mute toggle; success does not mean it has been applied.
+    pub fn enqueue_set_master_muted(&self, muted: bool) -> AudioEnqueueResult {
+        self.enqueue(AudioCommand::SetMasterMuted { muted })
+    }
+
+    pub fn set_master_effects(&self, effects: AudioBusEffects) -> bool {
+        self.enqueue_set_master_effects(effects).is_ok()
+    }
+
+    /// Enqueue a master effect-chain change; success does not mean it has been applied.
+    pub fn enqueue_set_master_effects(&self, effects: AudioBusEffects) -> AudioEnqueueResult {
+        self.enqueue(AudioCommand::SetMasterEffects { effects })
+    }
+
     pub fn set_bus_volume(&self, bus_id: AudioBusID, volume: f32) -> bool {
         self.enqueue_set_bus_volume(bus_id, volume).is_ok()
     }
@@ -608,6 +636,28 @@ impl AudioController {
         self.enqueue(AudioCommand::SetBusSpeed { bus_id, speed })
     }
 
+    pub fn set_bus_muted(&self, bus_id: AudioBusID, muted: bool) -> bool {
+        self.enqueue_set_bus_muted(bus_id, muted).is_ok()
+    }
+
+    /// Enqueue a bus mute toggle; success does not mean it has been applied.
+    pub fn enqueue_set_bus_muted(&self, bus_id: AudioBusID, muted: bool) -> AudioEnqueueResult {
+        self.enqueue(AudioCommand::SetBusMuted { bus_id, muted })
+    }
+
+    pub fn set_bus_effects(&self, bus_id: AudioBusID, effects: AudioBusEffects) -> bool {
+        self.enqueue_set_bus_effects(bus_id, effects).is_ok()
+    }
+
+    /// Enqueue a bus effect-chain change; success does not mean it has been applied.
+    pub fn enqueue_set_bus_effects(
+        &self,
+        bus_id: AudioBusID,
+        effects: AudioBusEffects,
+    ) -> AudioEnqueueResult {
+        self.enqueue(AudioCommand::SetBusEffects { bus_id, effects })
+    }
+
     pub fn pause_bus(&self, bus_id: AudioBusID) -> bool {
         self.enqueue_pause_bus(bus_id).is_ok()
     }
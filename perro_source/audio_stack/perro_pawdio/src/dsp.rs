@@ -1,7 +1,7 @@
-use crate::types::{AudioCompression, AudioEq, SpatialAudioParams};
+use crate::types::{AudioBusEffects, AudioCompression, AudioEq, SpatialAudioParams};
 use rodio::Source;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::time::Duration;
 
 #[derive(Debug)]
@@ -16,6 +16,17 @@ pub(crate) struct DspControl {
     eq_high_gain: AtomicU32,
     compression_threshold: AtomicU32,
     compression_ratio: AtomicU32,
+    // Bus effect chain; kept apart so spatial updates never clobber it.
+    bus_low_pass: AtomicU32,
+    bus_reverb: AtomicU32,
+    bus_compression_threshold: AtomicU32,
+    bus_compression_ratio: AtomicU32,
+    // Crossfade ramp: gain the source starts at, gain it ramps to, and ramp
+    // length. `fade_stop` ends the source once the ramp reaches silence.
+    fade_start: AtomicU32,
+    fade_target: AtomicU32,
+    fade_seconds: AtomicU32,
+    fade_stop: AtomicBool,
 }
 
 impl DspControl {
@@ -31,9 +42,41 @@ impl DspControl {
             eq_high_gain: AtomicU32::new(params.eq.high_gain.to_bits()),
             compression_threshold: AtomicU32::new(params.compression.threshold.to_bits()),
             compression_ratio: AtomicU32::new(params.compression.ratio.to_bits()),
+            bus_low_pass: AtomicU32::new(0.0_f32.to_bits()),
+            bus_reverb: AtomicU32::new(0.0_f32.to_bits()),
+            bus_compression_threshold: AtomicU32::new(1.0_f32.to_bits()),
+            bus_compression_ratio: AtomicU32::new(1.0_f32.to_bits()),
+            fade_start: AtomicU32::new(1.0_f32.to_bits()),
+            fade_target: AtomicU32::new(1.0_f32.to_bits()),
+            fade_seconds: AtomicU32::new(0.0_f32.to_bits()),
+            fade_stop: AtomicBool::new(false),
         })
     }
 
+    /// Start silent and ramp to full over `seconds`. Call before the source is appended.
+    pub(crate) fn fade_in(&self, seconds: f32) {
+        self.fade_start.store(0.0_f32.to_bits(), Ordering::Relaxed);
+        self.fade_seconds
+            .store(seconds.max(0.0).to_bits(), Ordering::Relaxed);
+        self.fade_target.store(1.0_f32.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Ramp to silence over `seconds`, then end the source so the sink drains.
+    pub(crate) fn fade_out(&self, seconds: f32) {
+        self.fade_seconds
+            .store(seconds.max(0.0).to_bits(), Ordering::Relaxed);
+        self.fade_stop.store(true, Ordering::Relaxed);
+        self.fade_target.store(0.0_f32.to_bits(), Ordering::Relaxed);
+    }
+
+    fn fade_snapshot(&self) -> (f32, f32, bool) {
+        (
+            load_f32(&self.fade_target).clamp(0.0, 1.0),
+            load_f32(&self.fade_seconds).max(0.0),
+            self.fade_stop.load(Ordering::Relaxed),
+        )
+    }
+
     pub(crate) fn update_spatial(&self, params: SpatialAudioParams) {
         self.low_pass
             .store(params.low_pass.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
@@ -67,10 +110,33 @@ impl DspControl {
         );
     }
 
+    pub(crate) fn update_bus(&self, effects: AudioBusEffects) {
+        self.bus_low_pass.store(
+            effects.low_pass.clamp(0.0, 1.0).to_bits(),
+            Ordering::Relaxed,
+        );
+        self.bus_reverb
+            .store(effects.reverb.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+        self.bus_compression_threshold.store(
+            effects.compression.threshold.clamp(0.0, 1.0).to_bits(),
+            Ordering::Relaxed,
+        );
+        self.bus_compression_ratio.store(
+            effects.compression.ratio.max(1.0).to_bits(),
+            Ordering::Relaxed,
+        );
+    }
+
     fn snapshot(&self) -> DspParams {
+        // Bus chain merges w/ per-source params: stronger filter/send wins,
+        // lower threshold + higher ratio wins for compression.
+        let bus_low_pass = load_f32(&self.bus_low_pass);
+        let bus_reverb = load_f32(&self.bus_reverb);
+        let bus_threshold = load_f32(&self.bus_compression_threshold);
+        let bus_ratio = load_f32(&self.bus_compression_ratio);
         DspParams {
-            low_pass: load_f32(&self.low_pass).clamp(0.0, 1.0),
-            reverb_send: load_f32(&self.reverb_send).clamp(0.0, 1.0),
+            low_pass: load_f32(&self.low_pass).max(bus_low_pass).clamp(0.0, 1.0),
+            reverb_send: load_f32(&self.reverb_send).max(bus_reverb).clamp(0.0, 1.0),
             echo: load_f32(&self.echo).clamp(0.0, 1.0),
             reflection: load_f32(&self.reflection).clamp(0.0, 1.0),
             occlusion: load_f32(&self.occlusion).clamp(0.0, 1.0),
@@ -80,8 +146,10 @@ impl DspControl {
                 high_gain: load_f32(&self.eq_high_gain).max(0.0),
             },
             compression: AudioCompression {
-                threshold: load_f32(&self.compression_threshold).clamp(0.0, 1.0),
-                ratio: load_f32(&self.compression_ratio).max(1.0),
+                threshold: load_f32(&self.compression_threshold)
+                    .min(bus_threshold)
+                    .clamp(0.0, 1.0),
+                ratio: load_f32(&self.compression_ratio).max(bus_ratio).max(1.0),
                 attack: 0.01,
                 release: 0.1,
             },
@@ -145,6 +213,10 @@ pub(crate) struct DspSource<S> {
     channel_index: usize,
     params: DspParams,
     param_counter: u32,
+    fade_gain: f32,
+    fade_target: f32,
+    fade_step: f32,
+    fade_stop: bool,
     // Delay lines run only while wet; on a dry->wet transition their buffers
     // hold stale audio, so track wet state to clear them at the edge.
     wet_active: bool,
@@ -165,6 +237,7 @@ where
         let channels = input.channels().max(1) as usize;
         let sample_rate = input.sample_rate().max(1) as usize;
         let initially_wet = dsp_uses_delay_lines(control.snapshot());
+        let fade_gain = load_f32(&control.fade_start).clamp(0.0, 1.0);
         Self {
             input,
             control,
@@ -173,6 +246,10 @@ where
             channel_index: 0,
             params: DspParams::dry(),
             param_counter: 0,
+            fade_gain,
+            fade_target: fade_gain,
+            fade_step: 1.0,
+            fade_stop: false,
             wet_active: false,
             low_state: vec![0.0; channels],
             eq_low_state: vec![0.0; channels],
@@ -195,10 +272,23 @@ where
     type Item = f32;
 
     fn next(&mut self) -> Option<Self::Item> {
+        // Faded-out crossfade voice: end on a frame edge so the sink drains.
+        if self.fade_stop && self.fade_gain <= 0.0 && self.channel_index == 0 {
+            return None;
+        }
         let input = self.input.next()?;
         // First call (counter 0) always snapshots; refresh every N samples.
         if self.param_counter == 0 {
             self.params = self.control.snapshot();
+            let (target, seconds, stop) = self.control.fade_snapshot();
+            self.fade_target = target;
+            self.fade_stop = stop;
+            let ramp_samples = seconds * (self.sample_rate * self.channels) as f32;
+            self.fade_step = if ramp_samples >= 1.0 {
+                ramp_samples.recip()
+            } else {
+                1.0
+            };
         }
         self.param_counter += 1;
         if self.param_counter >= PARAM_REFRESH_SAMPLES {
@@ -252,6 +342,15 @@ where
         sample = apply_compression(sample, params.compression);
         sample *= 1.0 - params.occlusion.clamp(0.0, 1.0) * 0.45;
 
+        if self.fade_gain != self.fade_target {
+            self.fade_gain = if self.fade_gain < self.fade_target {
+                (self.fade_gain + self.fade_step).min(self.fade_target)
+            } else {
+                (self.fade_gain - self.fade_step).max(self.fade_target)
+            };
+        }
+        sample *= self.fade_gain;
+
         self.channel_index += 1;
         if self.channel_index >= self.channels {
             self.channel_index = 0;
@@ -438,6 +537,61 @@ mod tests {
         assert!(out[3] < 0.3);
     }
 
+    #[test]
+    fn bus_effects_survive_spatial_updates() {
+        let control = DspControl::new(DspParams::dry());
+        control.update_bus(AudioBusEffects {
+            low_pass: 0.7,
+            reverb: 0.3,
+            compression: AudioCompression {
+                threshold: 0.5,
+                ratio: 3.0,
+                ..AudioCompression::default()
+            },
+        });
+        control.update_spatial(SpatialAudioParams {
+            low_pass: 0.2,
+            reverb_send: 0.6,
+            ..SpatialAudioParams::default()
+        });
+        let params = control.snapshot();
+        assert_eq!(params.low_pass, 0.7);
+        assert_eq!(params.reverb_send, 0.6);
+        assert_eq!(params.compression.threshold, 0.5);
+        assert_eq!(params.compression.ratio, 3.0);
+
+        control.update_bus(AudioBusEffects::default());
+        assert_eq!(control.snapshot().low_pass, 0.2);
+    }
+
+    #[test]
+    fn fade_in_ramps_from_silence() {
+        let source = TestSource {
+            samples: vec![0.5; 8].into_iter(),
+            channels: 1,
+            rate: 100,
+        };
+        let control = DspControl::new(DspParams::dry());
+        control.fade_in(0.04);
+        let out = DspSource::new(source, control).collect::<Vec<_>>();
+        assert!(out[0] < out[1] && out[1] < out[2]);
+        assert_eq!(out[7], 0.5);
+    }
+
+    #[test]
+    fn fade_out_ends_source_after_ramp() {
+        let source = TestSource {
+            samples: vec![0.5; 64].into_iter(),
+            channels: 1,
+            rate: 100,
+        };
+        let control = DspControl::new(DspParams::dry());
+        control.fade_out(0.04);
+        let out = DspSource::new(source, control).collect::<Vec<_>>();
+        assert!(out.len() < 8);
+        assert!(out.windows(2).all(|pair| pair[1] <= pair[0]));
+    }
+
     #[test]
     fn compression_reduces_peak() {
         let sample = apply_compression(
@@ -11,7 +11,9 @@ use crate::midi::{
     BuiltInMidiFileData, MidiControl, MidiFileRequest, MidiMixerControl, MidiNoteOptions,
     MidiNoteRequest, MidiSong, Note, SoundFontMixerControl,
 };
-use crate::types::{AudioCompression, AudioEq, AudioPan, AudioPlaybackRequest, SpatialAudioParams};
+use crate::types::{
+    AudioBusEffects, AudioCompression, AudioEq, AudioPan, AudioPlaybackRequest, SpatialAudioParams,
+};
 
 pub(crate) struct Playback {
     pub(crate) id: u64,
@@ -22,6 +24,8 @@ pub(crate) struct Playback {
     pub(crate) looped: bool,
     pub(crate) base_volume: f32,
     pub(crate) speed: f32,
+    /// Doppler pitch from spatial updates; mult on top of `speed`.
+    pub(crate) pitch: f32,
     pub(crate) pan: AudioPan,
     pub(crate) dsp: std::sync::Arc<DspControl>,
     pub(crate) from_start: f32,
@@ -125,10 +129,38 @@ pub(crate) struct BusState {
     pub(crate) volume: f32,
     pub(crate) speed: f32,
     pub(crate) paused: bool,
+    pub(crate) muted: bool,
+    pub(crate) effects: AudioBusEffects,
+}
+
+impl BusState {
+    /// Volume mult applied to sinks; mute kps `volume` so unmute restores it.
+    pub(crate) fn gain(&self) -> f32 {
+        if self.muted {
+            0.0
+        } else {
+            self.volume.max(0.0)
+        }
+    }
+}
+
+impl Default for BusState {
+    fn default() -> Self {
+        Self {
+            volume: 1.0,
+            speed: 1.0,
+            paused: false,
+            muted: false,
+            effects: AudioBusEffects::default(),
+        }
+    }
 }
 
 pub(crate) struct AudioState {
     pub(crate) master_volume: f32,
+    pub(crate) master_muted: bool,
+    /// Stacks under every playback's bus chain, incl. bus-less playbacks.
+    pub(crate) master_effects: AudioBusEffects,
     pub(crate) buses: HashMap<AudioBusID, BusState>,
     pub(crate) playbacks: Vec<Playback>,
     pub(crate) midi_playbacks: Vec<MidiPlayback>,
@@ -146,6 +178,24 @@ pub(crate) struct AudioState {
     pub(crate) last_evict_sweep: Instant,
 }
 
+impl AudioState {
+    pub(crate) fn master_gain(&self) -> f32 {
+        if self.master_muted {
+            0.0
+        } else {
+            self.master_volume.max(0.0)
+        }
+    }
+
+    /// Master chain stacked w/ `bus_id` chain; what a new playback's DSP gets.
+    pub(crate) fn effects_for_bus(&self, bus_id: Option<AudioBusID>) -> AudioBusEffects {
+        match bus_id.and_then(|id| self.buses.get(&id)) {
+            Some(bus) => self.master_effects.stacked(bus.effects),
+            None => self.master_effects,
+        }
+    }
+}
+
 pub(crate) struct CachedSoundFont {
     pub(crate) source: Arc<str>,
     pub(crate) font: std::sync::Arc<rustysynth::SoundFont>,
@@ -174,6 +224,8 @@ pub(crate) struct OwnedAudioPlaybackRequest {
     pub(crate) compression: AudioCompression,
     pub(crate) from_start: f32,
     pub(crate) from_end: f32,
+    pub(crate) loop_start: f32,
+    pub(crate) loop_end: f32,
 }
 
 impl From<AudioPlaybackRequest<'_>> for OwnedAudioPlaybackRequest {
@@ -204,6 +256,8 @@ impl OwnedAudioPlaybackRequest {
             compression: value.compression,
             from_start: value.from_start,
             from_end: value.from_end,
+            loop_start: value.loop_start,
+            loop_end: value.loop_end,
         }
     }
 
@@ -225,6 +279,8 @@ impl OwnedAudioPlaybackRequest {
             compression: self.compression,
             from_start: self.from_start,
             from_end: self.from_end,
+            loop_start: self.loop_start,
+            loop_end: self.loop_end,
         }
     }
 }
@@ -413,6 +469,10 @@ pub(crate) enum AudioCommand {
     Play {
         request: OwnedAudioPlaybackRequest,
     },
+    Crossfade {
+        request: OwnedAudioPlaybackRequest,
+        seconds: f32,
+    },
     PlayClip {
         source: Arc<str>,
         clip: MicClip,
@@ -444,6 +504,12 @@ pub(crate) enum AudioCommand {
     SetMasterVolume {
         volume: f32,
     },
+    SetMasterMuted {
+        muted: bool,
+    },
+    SetMasterEffects {
+        effects: AudioBusEffects,
+    },
     SetBusVolume {
         bus_id: AudioBusID,
         volume: f32,
@@ -452,6 +518,14 @@ pub(crate) enum AudioCommand {
         bus_id: AudioBusID,
         speed: f32,
     },
+    SetBusMuted {
+        bus_id: AudioBusID,
+        muted: bool,
+    },
+    SetBusEffects {
+        bus_id: AudioBusID,
+        effects: AudioBusEffects,
+    },
     PauseBus {
         bus_id: AudioBusID,
     },
@@ -16,14 +16,16 @@ pub use midi::{
 pub use perro_ids::SoundFontID;
 pub use player::BarkPlayer;
 pub use types::{
-    Audio2D, Audio3D, AudioCompression, AudioEq, AudioListener2D, AudioListener3D, AudioPan,
-    AudioPlaybackRequest, SpatialAudioParams,
+    Audio2D, Audio3D, AudioBusEffects, AudioCompression, AudioEq, AudioListener2D, AudioListener3D,
+    AudioPan, AudioPlaybackRequest, SpatialAudioParams,
 };
 
 #[cfg(test)]
 mod tests {
     use crate::codec::decode_static_pawdio;
-    use crate::{Audio2D, Audio3D, AudioListener2D, AudioListener3D};
+    use crate::{
+        Audio2D, Audio3D, AudioBusEffects, AudioCompression, AudioListener2D, AudioListener3D,
+    };
 
     #[test]
     fn decode_static_pawdio_accepts_v1_raw_payload() {
@@ -65,6 +67,33 @@ mod tests {
         assert!((req.volume - 0.25).abs() < 1.0e-6);
     }
 
+    #[test]
+    fn bus_effects_stack_keeps_stronger_stage() {
+        let master = AudioBusEffects {
+            low_pass: 0.2,
+            reverb: 0.5,
+            compression: AudioCompression {
+                threshold: 0.8,
+                ratio: 2.0,
+                ..AudioCompression::default()
+            },
+        };
+        let underwater = AudioBusEffects {
+            low_pass: 0.85,
+            reverb: 0.1,
+            compression: AudioCompression {
+                threshold: 0.6,
+                ratio: 1.5,
+                ..AudioCompression::default()
+            },
+        };
+        let stacked = master.stacked(underwater);
+        assert_eq!(stacked.low_pass, 0.85);
+        assert_eq!(stacked.reverb, 0.5);
+        assert_eq!(stacked.compression.threshold, 0.6);
+        assert_eq!(stacked.compression.ratio, 2.0);
+    }
+
     #[test]
     fn audio_2d_culls_out_of_range() {
         let req = Audio2D::new("res://hit.wav", [11.0, 0.0], 10.0)
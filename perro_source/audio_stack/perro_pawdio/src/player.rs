@@ -21,7 +21,7 @@ use crate::midi::{
     MidiMixerNote, MidiNoteRequest, MidiSound, RustyFileSource, RustyNoteMixerSource,
     SoundFontMixerControl, SoundFontMixerNote, parse_built_in_midi_file,
 };
-use crate::types::{AudioPan, AudioPlaybackRequest, SpatialAudioParams};
+use crate::types::{AudioBusEffects, AudioPan, AudioPlaybackRequest, SpatialAudioParams};
 
 type LoadedAudioAsset = (Arc<[u8]>, Arc<str>, u64, u64, bool, SourceLoadStats);
 
@@ -52,6 +52,9 @@ impl BarkPlayer {
     const UNRESERVED_TTL_FACTOR: f32 = 2.0;
     const UNRESERVED_TTL_FALLBACK: Duration = Duration::from_secs(1);
     const UNRESERVED_TTL_MIN: Duration = Duration::from_millis(250);
+    // Doppler pitch clamp; past an octave either way sounds broken, not fast.
+    const MIN_SPATIAL_PITCH: f32 = 0.5;
+    const MAX_SPATIAL_PITCH: f32 = 2.0;
 
     pub fn new(static_audio_lookup: Option<fn(u64) -> &'static [u8]>) -> Result<Self, String> {
         let (stream, handle) = OutputStream::try_default()
@@ -62,6 +65,8 @@ impl BarkPlayer {
             static_audio_lookup,
             state: Mutex::new(AudioState {
                 master_volume: 1.0,
+                master_muted: false,
+                master_effects: AudioBusEffects::default(),
                 buses: HashMap::new(),
                 playbacks: Vec::new(),
                 midi_playbacks: Vec::new(),
@@ -106,4 +111,6 @@ mod cache;
 mod midi_player;
 mod pcm_source;
 mod playback;
+mod stream_source;
 use pcm_source::{CachedPcmSource, append_with_trims};
+use stream_source::{StreamingLoopSource, sample_index};
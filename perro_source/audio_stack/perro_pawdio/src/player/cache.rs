@@ -151,14 +151,19 @@ impl BarkPlayer {
         let cap = (sample_rate as usize)
             .saturating_mul(channels as usize)
             .saturating_mul(Self::PCM_CACHE_MAX_SECONDS);
+        // Header already says long track: skip the throwaway 12s decode.
+        let mut oversized = decoder
+            .total_duration()
+            .is_some_and(|duration| duration.as_secs_f32() > Self::PCM_CACHE_MAX_SECONDS as f32);
         let mut samples: Vec<f32> = Vec::new();
-        let mut oversized = false;
-        for sample in decoder.convert_samples::<f32>() {
-            if samples.len() >= cap {
-                oversized = true;
-                break;
+        if !oversized {
+            for sample in decoder.convert_samples::<f32>() {
+                if samples.len() >= cap {
+                    oversized = true;
+                    break;
+                }
+                samples.push(sample);
             }
-            samples.push(sample);
         }
 
         let mut state = self
@@ -422,41 +427,41 @@ impl BarkPlayer {
             let bus_volume = playback
                 .bus_id
                 .and_then(|bus_id| state.buses.get(&bus_id))
-                .map(|bus| bus.volume.max(0.0))
+                .map(BusState::gain)
                 .unwrap_or(1.0);
             playback
                 .sink
-                .set_volume(playback.base_volume * state.master_volume.max(0.0) * bus_volume);
+                .set_volume(playback.base_volume * state.master_gain() * bus_volume);
         }
         for playback in &state.midi_playbacks {
             let bus_volume = playback
                 .bus_id
                 .and_then(|bus_id| state.buses.get(&bus_id))
-                .map(|bus| bus.volume.max(0.0))
+                .map(BusState::gain)
                 .unwrap_or(1.0);
             playback
                 .sink
-                .set_volume(playback.base_volume * state.master_volume.max(0.0) * bus_volume);
+                .set_volume(playback.base_volume * state.master_gain() * bus_volume);
         }
         for playback in &state.built_in_midi_mixers {
             let bus_volume = playback
                 .bus_id
                 .and_then(|bus_id| state.buses.get(&bus_id))
-                .map(|bus| bus.volume.max(0.0))
+                .map(BusState::gain)
                 .unwrap_or(1.0);
             playback
                 .sink
-                .set_volume(playback.base_volume * state.master_volume.max(0.0) * bus_volume);
+                .set_volume(playback.base_volume * state.master_gain() * bus_volume);
         }
         for playback in &state.soundfont_midi_mixers {
             let bus_volume = playback
                 .bus_id
                 .and_then(|bus_id| state.buses.get(&bus_id))
-                .map(|bus| bus.volume.max(0.0))
+                .map(BusState::gain)
                 .unwrap_or(1.0);
             playback
                 .sink
-                .set_volume(playback.base_volume * state.master_volume.max(0.0) * bus_volume);
+                .set_volume(playback.base_volume * state.master_gain() * bus_volume);
         }
     }
 
@@ -469,7 +474,7 @@ impl BarkPlayer {
                 .unwrap_or(1.0);
             playback
                 .sink
-                .set_speed(playback.speed.max(0.01) * bus_speed);
+                .set_speed(playback.speed.max(0.01) * bus_speed * playback.pitch);
         }
         for playback in &state.midi_playbacks {
             let bus_speed = playback
@@ -497,6 +502,47 @@ impl BarkPlayer {
         }
     }
 
+    pub(super) fn apply_bus_effects_locked(
+        state: &AudioState,
+        bus_id: Option<AudioBusID>,
+        dsp: &DspControl,
+    ) {
+        dsp.update_bus(state.effects_for_bus(bus_id));
+    }
+
+    /// Push bus chains to live DSP. `None` = every playback (master chg).
+    pub(super) fn refresh_bus_effects(state: &AudioState, only_bus: Option<AudioBusID>) {
+        let touches = |bus_id: Option<AudioBusID>| only_bus.is_none() || bus_id == only_bus;
+        for playback in &state.playbacks {
+            if touches(playback.bus_id) {
+                playback
+                    .dsp
+                    .update_bus(state.effects_for_bus(playback.bus_id));
+            }
+        }
+        for playback in &state.midi_playbacks {
+            if touches(playback.bus_id) {
+                playback
+                    .dsp
+                    .update_bus(state.effects_for_bus(playback.bus_id));
+            }
+        }
+        for playback in &state.built_in_midi_mixers {
+            if touches(playback.bus_id) {
+                playback
+                    .dsp
+                    .update_bus(state.effects_for_bus(playback.bus_id));
+            }
+        }
+        for playback in &state.soundfont_midi_mixers {
+            if touches(playback.bus_id) {
+                playback
+                    .dsp
+                    .update_bus(state.effects_for_bus(playback.bus_id));
+            }
+        }
+    }
+
     pub(super) fn pan_emitter_position(pan: AudioPan) -> [f32; 3] {
         [pan.x, pan.y, pan.z]
     }
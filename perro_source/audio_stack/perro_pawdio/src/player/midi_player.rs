@@ -128,16 +128,17 @@ impl BarkPlayer {
                 dsp.clone(),
             ));
 
-            let master_volume = state.master_volume.max(0.0);
+            let master_volume = state.master_gain();
             let (bus_volume, bus_speed, bus_paused) =
                 match request.options.bus_id.and_then(|id| state.buses.get(&id)) {
                     Some(bus_state) => (
-                        bus_state.volume.max(0.0),
+                        bus_state.gain(),
                         bus_state.speed.max(0.01),
                         bus_state.paused,
                     ),
                     None => (1.0, 1.0, false),
                 };
+            Self::apply_bus_effects_locked(&state, request.options.bus_id, &dsp);
             sink.set_speed(bus_speed);
             sink.set_volume(master_volume * bus_volume);
             if bus_paused {
@@ -208,16 +209,17 @@ impl BarkPlayer {
                 dsp.clone(),
             ));
 
-            let master_volume = state.master_volume.max(0.0);
+            let master_volume = state.master_gain();
             let (bus_volume, bus_speed, bus_paused) =
                 match request.options.bus_id.and_then(|id| state.buses.get(&id)) {
                     Some(bus_state) => (
-                        bus_state.volume.max(0.0),
+                        bus_state.gain(),
                         bus_state.speed.max(0.01),
                         bus_state.paused,
                     ),
                     None => (1.0, 1.0, false),
                 };
+            Self::apply_bus_effects_locked(&state, request.options.bus_id, &dsp);
             sink.set_speed(bus_speed);
             sink.set_volume(master_volume * bus_volume);
             if bus_paused {
@@ -352,15 +354,16 @@ impl BarkPlayer {
             .state
             .lock()
             .map_err(|_| "audio mutex poisoned".to_string())?;
-        let master_volume = state.master_volume.max(0.0);
+        let master_volume = state.master_gain();
         let (bus_volume, bus_speed, bus_paused) = match bus_id.and_then(|id| state.buses.get(&id)) {
             Some(bus_state) => (
-                bus_state.volume.max(0.0),
+                bus_state.gain(),
                 bus_state.speed.max(0.01),
                 bus_state.paused,
             ),
             None => (1.0, 1.0, false),
         };
+        Self::apply_bus_effects_locked(&state, bus_id, &dsp);
         sink.set_speed(bus_speed);
         sink.set_volume(volume.max(0.0) * master_volume * bus_volume);
         if bus_paused {
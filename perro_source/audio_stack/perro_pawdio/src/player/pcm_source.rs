@@ -3,11 +3,37 @@ use super::*;
 pub(super) struct CachedPcmSource {
     pcm: Arc<CachedPcm>,
     position: usize,
+    // Interleaved [loop_start, loop_end) sample range; playhead jumps back at end.
+    loop_range: Option<(usize, usize)>,
 }
 
 impl CachedPcmSource {
     pub(super) fn new(pcm: Arc<CachedPcm>) -> Self {
-        Self { pcm, position: 0 }
+        Self {
+            pcm,
+            position: 0,
+            loop_range: None,
+        }
+    }
+
+    // Random access PCM loops in place; no repeat buffer needed.
+    pub(super) fn looping(
+        pcm: Arc<CachedPcm>,
+        start: Duration,
+        loop_start: Duration,
+        loop_end: Option<Duration>,
+    ) -> Self {
+        let len = pcm.samples.len();
+        let index = |at| (sample_index(at, pcm.sample_rate, pcm.channels) as usize).min(len);
+        let position = index(start);
+        let loop_start = index(loop_start);
+        let loop_end = loop_end.map_or(len, index);
+        let loop_range = (loop_end > loop_start).then_some((loop_start, loop_end));
+        Self {
+            pcm,
+            position,
+            loop_range,
+        }
     }
 }
 
@@ -16,12 +42,20 @@ impl Iterator for CachedPcmSource {
 
     #[inline]
     fn next(&mut self) -> Option<f32> {
+        if let Some((loop_start, loop_end)) = self.loop_range
+            && self.position >= loop_end
+        {
+            self.position = loop_start;
+        }
         let sample = self.pcm.samples.get(self.position).copied()?;
         self.position += 1;
         Some(sample)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.loop_range.is_some() {
+            return (usize::MAX, None);
+        }
         let remaining = self.pcm.samples.len().saturating_sub(self.position);
         (remaining, Some(remaining))
     }
@@ -29,6 +63,9 @@ impl Iterator for CachedPcmSource {
 
 impl Source for CachedPcmSource {
     fn current_frame_len(&self) -> Option<usize> {
+        if self.loop_range.is_some() {
+            return None;
+        }
         Some(self.pcm.samples.len().saturating_sub(self.position))
     }
 
@@ -41,38 +78,30 @@ impl Source for CachedPcmSource {
     }
 
     fn total_duration(&self) -> Option<Duration> {
+        if self.loop_range.is_some() {
+            return None;
+        }
         Some(self.pcm.duration())
     }
 }
 
-// Shared append tail for both the PCM and streaming decode paths: apply trim
-// (and optional take/loop) then route through the DSP chain into the sink.
+// Shared one-shot append tail for both the PCM and streaming decode paths:
+// apply trim (and optional take) then route through the DSP chain into the
+// sink. Looped plays use `CachedPcmSource::looping` / `StreamingLoopSource`.
 pub(super) fn append_with_trims<S>(
     sink: &SpatialSink,
     source: S,
     dsp: Arc<DspControl>,
     trim_start: Duration,
     play_duration: Option<Duration>,
-    looped: bool,
 ) where
     S: Source<Item = f32> + Send + 'static,
 {
-    match (play_duration, looped) {
-        (Some(duration), true) => sink.append(DspSource::new(
-            source
-                .skip_duration(trim_start)
-                .take_duration(duration)
-                .repeat_infinite(),
-            dsp,
-        )),
-        (Some(duration), false) => sink.append(DspSource::new(
+    match play_duration {
+        Some(duration) => sink.append(DspSource::new(
             source.skip_duration(trim_start).take_duration(duration),
             dsp,
         )),
-        (None, true) => sink.append(DspSource::new(
-            source.skip_duration(trim_start).repeat_infinite(),
-            dsp,
-        )),
-        (None, false) => sink.append(DspSource::new(source.skip_duration(trim_start), dsp)),
+        None => sink.append(DspSource::new(source.skip_duration(trim_start), dsp)),
     }
 }
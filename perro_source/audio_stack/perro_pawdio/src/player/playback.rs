@@ -2,6 +2,38 @@ use super::*;
 
 impl BarkPlayer {
     pub fn play_source(&self, request: AudioPlaybackRequest<'_>) -> Result<(), String> {
+        self.play_source_faded(request, 0.0)
+    }
+
+    /// Fade every voice on the request's bus (master-routed voices when `bus_id` is None)
+    /// to silence over `seconds` while the request fades in over the same span.
+    pub fn crossfade(&self, request: AudioPlaybackRequest<'_>, seconds: f32) -> Result<(), String> {
+        let seconds = seconds.max(0.0);
+        {
+            let mut state = self
+                .state
+                .lock()
+                .map_err(|_| "audio mutex poisoned".to_string())?;
+            Self::prune_finished_playbacks_locked(&mut state, Instant::now());
+            for playback in &state.playbacks {
+                if playback.bus_id == request.bus_id && playback.source.as_ref() != request.source {
+                    playback.dsp.fade_out(seconds);
+                }
+            }
+            for playback in &state.midi_playbacks {
+                if playback.bus_id == request.bus_id {
+                    playback.dsp.fade_out(seconds);
+                }
+            }
+        }
+        self.play_source_faded(request, seconds)
+    }
+
+    fn play_source_faded(
+        &self,
+        request: AudioPlaybackRequest<'_>,
+        fade_in: f32,
+    ) -> Result<(), String> {
         let AudioPlaybackRequest {
             id,
             source,
@@ -19,6 +51,8 @@ impl BarkPlayer {
             compression,
             from_start,
             from_end,
+            loop_start,
+            loop_end,
         } = request;
         #[cfg(feature = "profile")]
         let play_begin = Instant::now();
@@ -114,6 +148,9 @@ impl BarkPlayer {
             eq,
             compression,
         });
+        if fade_in > 0.0 {
+            dsp.fade_in(fade_in);
+        }
         let sink = SpatialSink::try_new(
             &self.handle,
             Self::pan_emitter_position(pan),
@@ -140,24 +177,37 @@ impl BarkPlayer {
         } else {
             None
         };
-        match (pcm, decoder) {
-            (Some(pcm), _) => append_with_trims(
+        // Loop region: [loop_start, loop_end) in clip time, clamped inside the trims.
+        let loop_from = trim_start.max(Duration::from_secs_f32(loop_start.max(0.0)));
+        let loop_to = if loop_end > 0.0 {
+            Some(Duration::from_secs_f32(loop_end))
+        } else {
+            play_duration.map(|duration| trim_start + duration)
+        };
+        match (pcm, decoder, looped) {
+            (Some(pcm), _, true) => sink.append(DspSource::new(
+                CachedPcmSource::looping(pcm, trim_start, loop_from, loop_to),
+                dsp.clone(),
+            )),
+            (Some(pcm), _, false) => append_with_trims(
                 &sink,
                 CachedPcmSource::new(pcm),
                 dsp.clone(),
                 trim_start,
                 play_duration,
-                looped,
             ),
-            (None, Some(decoder)) => append_with_trims(
+            (None, Some(decoder), true) => sink.append(DspSource::new(
+                StreamingLoopSource::new(bytes.clone(), decoder, trim_start, loop_from, loop_to),
+                dsp.clone(),
+            )),
+            (None, Some(decoder), false) => append_with_trims(
                 &sink,
                 decoder.convert_samples::<f32>(),
                 dsp.clone(),
                 trim_start,
                 play_duration,
-                looped,
             ),
-            (None, None) => unreachable!("play source has neither pcm nor decoder"),
+            (None, None, _) => unreachable!("play source has neither pcm nor decoder"),
         }
         #[cfg(feature = "profile")]
         let append_elapsed = append_begin.elapsed();
@@ -169,15 +219,16 @@ impl BarkPlayer {
             .lock()
             .map_err(|_| "audio mutex poisoned".to_string())?;
         let requested_volume = volume.max(0.0);
-        let master_volume = state.master_volume.max(0.0);
+        let master_volume = state.master_gain();
         let (bus_volume, bus_speed, bus_paused) = match bus_id.and_then(|id| state.buses.get(&id)) {
             Some(bus_state) => (
-                bus_state.volume.max(0.0),
+                bus_state.gain(),
                 bus_state.speed.max(0.01),
                 bus_state.paused,
             ),
             None => (1.0, 1.0, false),
         };
+        Self::apply_bus_effects_locked(&state, bus_id, &dsp);
         sink.set_speed(speed.max(0.01) * bus_speed);
         sink.set_volume(requested_volume * master_volume * bus_volume);
         if bus_paused {
@@ -211,6 +262,7 @@ impl BarkPlayer {
             looped,
             base_volume: requested_volume,
             speed: speed.max(0.01),
+            pitch: 1.0,
             pan,
             dsp,
             from_start: from_start.max(0.0),
@@ -284,15 +336,16 @@ impl BarkPlayer {
             .lock()
             .map_err(|_| "audio mutex poisoned".to_string())?;
         let requested_volume = volume.max(0.0);
-        let master_volume = state.master_volume.max(0.0);
+        let master_volume = state.master_gain();
         let (bus_volume, bus_speed, bus_paused) = match bus_id.and_then(|id| state.buses.get(&id)) {
             Some(bus_state) => (
-                bus_state.volume.max(0.0),
+                bus_state.gain(),
                 bus_state.speed.max(0.01),
                 bus_state.paused,
             ),
             None => (1.0, 1.0, false),
         };
+        Self::apply_bus_effects_locked(&state, bus_id, &dsp);
         sink.set_speed(bus_speed);
         sink.set_volume(requested_volume * master_volume * bus_volume);
         if bus_paused {
@@ -312,6 +365,7 @@ impl BarkPlayer {
             looped: false,
             base_volume: requested_volume,
             speed: 1.0,
+            pitch: 1.0,
             pan,
             dsp,
             from_start: 0.0,
@@ -341,10 +395,10 @@ impl BarkPlayer {
         let now = Instant::now();
         Self::prune_finished_playbacks_locked(&mut state, now);
 
-        let master_volume = state.master_volume.max(0.0);
+        let master_volume = state.master_gain();
         let (bus_volume, bus_speed, bus_paused) = match bus_id.and_then(|id| state.buses.get(&id)) {
             Some(bus_state) => (
-                bus_state.volume.max(0.0),
+                bus_state.gain(),
                 bus_state.speed.max(0.01),
                 bus_state.paused,
             ),
@@ -529,6 +583,8 @@ impl BarkPlayer {
             compression: _,
             from_start,
             from_end,
+            loop_start: _,
+            loop_end: _,
         } = request;
         let Ok(mut state) = self.state.lock() else {
             return false;
@@ -619,14 +675,18 @@ impl BarkPlayer {
         let Ok(mut state) = self.state.lock() else {
             return false;
         };
-        let master_volume = state.master_volume.max(0.0);
+        let master_volume = state.master_gain();
         let Some(index) = state.playbacks.iter().position(|p| p.id == id) else {
             return Self::update_midi_spatial_locked(&mut state, id, params);
         };
         let playback_bus_id = state.playbacks[index].bus_id;
         let bus_volume = playback_bus_id
             .and_then(|bus_id| state.buses.get(&bus_id))
-            .map(|bus| bus.volume.max(0.0))
+            .map(BusState::gain)
+            .unwrap_or(1.0);
+        let bus_speed = playback_bus_id
+            .and_then(|bus_id| state.buses.get(&bus_id))
+            .map(|bus| bus.speed.max(0.01))
             .unwrap_or(1.0);
         let playback = &mut state.playbacks[index];
         playback.base_volume = params.volume.max(0.0);
@@ -638,6 +698,15 @@ impl BarkPlayer {
         playback
             .sink
             .set_volume(playback.base_volume * master_volume * bus_volume);
+        let pitch = params
+            .pitch
+            .clamp(Self::MIN_SPATIAL_PITCH, Self::MAX_SPATIAL_PITCH);
+        if (playback.pitch - pitch).abs() > f32::EPSILON {
+            playback.pitch = pitch;
+            playback
+                .sink
+                .set_speed(playback.speed.max(0.01) * bus_speed * pitch);
+        }
         true
     }
 
@@ -646,14 +715,14 @@ impl BarkPlayer {
         id: u64,
         params: SpatialAudioParams,
     ) -> bool {
-        let master_volume = state.master_volume.max(0.0);
+        let master_volume = state.master_gain();
         let Some(index) = state.midi_playbacks.iter().position(|p| p.id == id) else {
             return Self::update_midi_note_mixer_spatial_locked(state, id, params, master_volume);
         };
         let playback_bus_id = state.midi_playbacks[index].bus_id;
         let bus_volume = playback_bus_id
             .and_then(|bus_id| state.buses.get(&bus_id))
-            .map(|bus| bus.volume.max(0.0))
+            .map(BusState::gain)
             .unwrap_or(1.0);
         let playback = &mut state.midi_playbacks[index];
         playback.base_volume = params.volume.max(0.0);
@@ -683,7 +752,7 @@ impl BarkPlayer {
             let bus_volume = state.built_in_midi_mixers[index]
                 .bus_id
                 .and_then(|bus_id| state.buses.get(&bus_id))
-                .map(|bus| bus.volume.max(0.0))
+                .map(BusState::gain)
                 .unwrap_or(1.0);
             let mixer = &mut state.built_in_midi_mixers[index];
             mixer.base_volume = params.volume.max(0.0);
@@ -705,7 +774,7 @@ impl BarkPlayer {
             let bus_volume = state.soundfont_midi_mixers[index]
                 .bus_id
                 .and_then(|bus_id| state.buses.get(&bus_id))
-                .map(|bus| bus.volume.max(0.0))
+                .map(BusState::gain)
                 .unwrap_or(1.0);
             let mixer = &mut state.soundfont_midi_mixers[index];
             mixer.base_volume = params.volume.max(0.0);
@@ -759,15 +828,27 @@ impl BarkPlayer {
         Self::refresh_volumes(&mut state);
     }
 
+    pub fn set_master_muted(&self, muted: bool) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        state.master_muted = muted;
+        Self::refresh_volumes(&mut state);
+    }
+
+    pub fn set_master_effects(&self, effects: AudioBusEffects) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        state.master_effects = effects;
+        Self::refresh_bus_effects(&state, None);
+    }
+
     pub fn set_bus_volume(&self, bus_id: AudioBusID, volume: f32) {
         let Ok(mut state) = self.state.lock() else {
             return;
         };
-        let bus = state.buses.entry(bus_id).or_insert(BusState {
-            volume: 1.0,
-            speed: 1.0,
-            paused: false,
-        });
+        let bus = state.buses.entry(bus_id).or_default();
         bus.volume = volume.max(0.0);
         Self::refresh_volumes(&mut state);
     }
@@ -776,24 +857,32 @@ impl BarkPlayer {
         let Ok(mut state) = self.state.lock() else {
             return;
         };
-        let bus = state.buses.entry(bus_id).or_insert(BusState {
-            volume: 1.0,
-            speed: 1.0,
-            paused: false,
-        });
+        let bus = state.buses.entry(bus_id).or_default();
         bus.speed = speed.max(0.01);
         Self::refresh_speeds(&mut state);
     }
 
+    pub fn set_bus_muted(&self, bus_id: AudioBusID, muted: bool) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        state.buses.entry(bus_id).or_default().muted = muted;
+        Self::refresh_volumes(&mut state);
+    }
+
+    pub fn set_bus_effects(&self, bus_id: AudioBusID, effects: AudioBusEffects) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        state.buses.entry(bus_id).or_default().effects = effects;
+        Self::refresh_bus_effects(&state, Some(bus_id));
+    }
+
     pub fn pause_bus(&self, bus_id: AudioBusID) {
         let Ok(mut state) = self.state.lock() else {
             return;
         };
-        let bus = state.buses.entry(bus_id).or_insert(BusState {
-            volume: 1.0,
-            speed: 1.0,
-            paused: false,
-        });
+        let bus = state.buses.entry(bus_id).or_default();
         bus.paused = true;
         for playback in &state.playbacks {
             if playback.bus_id == Some(bus_id) {
@@ -821,11 +910,7 @@ impl BarkPlayer {
         let Ok(mut state) = self.state.lock() else {
             return;
         };
-        let bus = state.buses.entry(bus_id).or_insert(BusState {
-            volume: 1.0,
-            speed: 1.0,
-            paused: false,
-        });
+        let bus = state.buses.entry(bus_id).or_default();
         bus.paused = false;
         for playback in &state.playbacks {
             if playback.bus_id == Some(bus_id) {
@@ -0,0 +1,158 @@
+use super::*;
+
+type StreamDecoder = rodio::source::SamplesConverter<Decoder<BufReader<Cursor<Arc<[u8]>>>>, f32>;
+
+// Rewind decoder pre-roll: samples skipped per output sample. >1 so the
+// rewind reaches the loop start well before the playhead hits loop end.
+const PREROLL_PER_SAMPLE: usize = 4;
+
+pub(super) fn open_stream_decoder(bytes: &Arc<[u8]>) -> Result<StreamDecoder, String> {
+    let reader = BufReader::new(Cursor::new(bytes.clone()));
+    Decoder::new(reader)
+        .map(|decoder| decoder.convert_samples::<f32>())
+        .map_err(|err| err.to_string())
+}
+
+// Interleaved sample index for a time offset, snapped to a frame boundary so
+// loop wraps never swap L/R.
+pub(super) fn sample_index(at: Duration, sample_rate: u32, channels: u16) -> u64 {
+    let frames = (at.as_secs_f64() * sample_rate.max(1) as f64).floor() as u64;
+    frames.saturating_mul(channels.max(1) as u64)
+}
+
+// Looping long-track source. Decodes lazily from the shared compressed bytes,
+// so a looped music track costs one decoder, not a fully buffered copy
+// (rodio `repeat_infinite` keeps every decoded frame). A second decoder is
+// pre-rolled to the loop start while the first plays, so the wrap is
+// sample-exact w/o a decode spike on the mixer thread.
+pub(super) struct StreamingLoopSource {
+    bytes: Arc<[u8]>,
+    current: StreamDecoder,
+    rewind: Option<StreamDecoder>,
+    rewind_position: u64,
+    rewind_failed: bool,
+    position: u64,
+    loop_start: u64,
+    loop_end: Option<u64>,
+    channels: u16,
+    sample_rate: u32,
+}
+
+impl StreamingLoopSource {
+    pub(super) fn new(
+        bytes: Arc<[u8]>,
+        decoder: Decoder<BufReader<Cursor<Arc<[u8]>>>>,
+        start: Duration,
+        loop_start: Duration,
+        loop_end: Option<Duration>,
+    ) -> Self {
+        let mut current = decoder.convert_samples::<f32>();
+        let channels = current.channels().max(1);
+        let sample_rate = current.sample_rate().max(1);
+        let start = sample_index(start, sample_rate, channels);
+        let loop_start = sample_index(loop_start, sample_rate, channels);
+        let loop_end = loop_end
+            .map(|end| sample_index(end, sample_rate, channels))
+            .filter(|end| *end > loop_start);
+        // Intro skip runs once on the audio worker, before the sink owns us.
+        let mut position = 0;
+        while position < start && current.next().is_some() {
+            position += 1;
+        }
+        Self {
+            bytes,
+            current,
+            rewind: None,
+            rewind_position: 0,
+            rewind_failed: false,
+            position,
+            loop_start,
+            loop_end,
+            channels,
+            sample_rate,
+        }
+    }
+
+    fn preroll(&mut self, budget: usize) {
+        if self.rewind.is_none() {
+            match open_stream_decoder(&self.bytes) {
+                Ok(decoder) => {
+                    self.rewind = Some(decoder);
+                    self.rewind_position = 0;
+                }
+                Err(_) => {
+                    self.rewind_failed = true;
+                    return;
+                }
+            }
+        }
+        let Some(rewind) = self.rewind.as_mut() else {
+            return;
+        };
+        let mut left = budget;
+        while left > 0 && self.rewind_position < self.loop_start {
+            if rewind.next().is_none() {
+                break;
+            }
+            self.rewind_position += 1;
+            left -= 1;
+        }
+    }
+
+    fn wrap(&mut self) -> bool {
+        if self.rewind_failed {
+            return false;
+        }
+        self.preroll(usize::MAX);
+        let Some(rewind) = self.rewind.take() else {
+            return false;
+        };
+        self.current = rewind;
+        self.position = self.rewind_position;
+        true
+    }
+}
+
+impl Iterator for StreamingLoopSource {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.loop_end.is_some_and(|end| self.position >= end) && !self.wrap() {
+            return None;
+        }
+        if !self.rewind_failed && (self.rewind.is_none() || self.rewind_position < self.loop_start)
+        {
+            self.preroll(PREROLL_PER_SAMPLE);
+        }
+        if let Some(sample) = self.current.next() {
+            self.position += 1;
+            return Some(sample);
+        }
+        // Decoder ran dry before loop end (or no loop end): wrap at EOF.
+        // Nothing played since the last wrap -> empty loop, end instead of spin.
+        if self.position <= self.loop_start || !self.wrap() {
+            return None;
+        }
+        let sample = self.current.next()?;
+        self.position += 1;
+        Some(sample)
+    }
+}
+
+impl Source for StreamingLoopSource {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        self.channels
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
@@ -38,7 +38,32 @@ impl Default for AudioCompression {
     }
 }
 
+/// Bus-wide effect chain: low-pass -> reverb -> compressor. Stacks on top of
+/// each playback's own DSP (stronger value wins).
 #[derive(Clone, Copy, Debug, Default)]
+pub struct AudioBusEffects {
+    /// 0 = dry, 1 = fully muffled (underwater / behind door).
+    pub low_pass: f32,
+    pub reverb: f32,
+    pub compression: AudioCompression,
+}
+
+impl AudioBusEffects {
+    /// Stronger of each stage wins, so master + bus chains never cancel out.
+    pub(crate) fn stacked(self, other: Self) -> Self {
+        Self {
+            low_pass: self.low_pass.max(other.low_pass),
+            reverb: self.reverb.max(other.reverb),
+            compression: AudioCompression {
+                threshold: self.compression.threshold.min(other.compression.threshold),
+                ratio: self.compression.ratio.max(other.compression.ratio),
+                ..self.compression
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
 pub struct SpatialAudioParams {
     pub pan: AudioPan,
     pub volume: f32,
@@ -49,6 +74,25 @@ pub struct SpatialAudioParams {
     pub occlusion: f32,
     pub eq: AudioEq,
     pub compression: AudioCompression,
+    /// Doppler playback-rate mult (1 = unchanged). Only file playbacks apply it.
+    pub pitch: f32,
+}
+
+impl Default for SpatialAudioParams {
+    fn default() -> Self {
+        Self {
+            pan: AudioPan::default(),
+            volume: 0.0,
+            low_pass: 0.0,
+            reverb_send: 0.0,
+            echo: 0.0,
+            reflection: 0.0,
+            occlusion: 0.0,
+            eq: AudioEq::default(),
+            compression: AudioCompression::default(),
+            pitch: 1.0,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -172,6 +216,8 @@ impl<'a> Audio2D<'a> {
             compression: AudioCompression::default(),
             from_start: self.from_start,
             from_end: self.from_end,
+            loop_start: 0.0,
+            loop_end: 0.0,
         })
     }
 }
@@ -233,6 +279,8 @@ impl<'a> Audio3D<'a> {
             compression: AudioCompression::default(),
             from_start: self.from_start,
             from_end: self.from_end,
+            loop_start: 0.0,
+            loop_end: 0.0,
         })
     }
 }
@@ -255,4 +303,8 @@ pub struct AudioPlaybackRequest<'a> {
     pub compression: AudioCompression,
     pub from_start: f32,
     pub from_end: f32,
+    /// Looped plays jump back here (seconds into the clip). 0 = `from_start`.
+    pub loop_start: f32,
+    /// Looped plays wrap here (seconds into the clip). 0 = clip end minus `from_end`.
+    pub loop_end: f32,
 }
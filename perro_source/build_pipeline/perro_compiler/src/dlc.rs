@@ -409,9 +409,10 @@ fn generate_dlc_static_assets(
         generate_dlc_static_modules(project_root, cfg.meshlets)?;
         perro_static_pipeline::write_static_mod_rs(project_root)
             .map_err(|e| CompilerError::SceneParse(e.to_string()))?;
-        build_perro_assets_archive(
+        build_perro_assets_archive_with_compiled(
             &embedded_root.join("assets.perro"),
             dlc_root,
+            Some(&embedded_root.join("scenes")),
             project_root,
             &[],
         )?;
@@ -436,6 +437,11 @@ pub fn compile_dlc_bundle(project_root: &Path, dlc_name: &str) -> Result<PathBuf
             dlc_root.display()
         )));
     }
+    let cfg = load_project_toml(project_root)
+        .map_err(|e| CompilerError::SceneParse(format!("failed to load project.toml: {e}")))?;
+    let _pack_codecs = push_codec_rules(pack_codec_rules(&cfg.pack));
+    // Same key as the game, which registers it b4 mounting the pack.
+    let _archive_key = push_archive_key(resolve_archive_key(project_root, &cfg.pack)?);
 
     let generated_root = project_root.join(".perro").join("dlc").join(dlc_name);
     let scripts_crate = generated_root.join("scripts");
@@ -531,7 +537,15 @@ pub fn compile_dlc_bundle(project_root: &Path, dlc_name: &str) -> Result<PathBuf
         Ok(())
     })?;
     rel_files.sort();
+    let compiled_scenes_dir = pack_dir.join("embedded").join("scenes");
     for rel in rel_files {
+        // Scenes ship as the static pipeline's `.scnb` so mounts skip parsing.
+        let compiled = perro_scene::compiled_scene_path(&rel);
+        if compiled != rel.as_str() {
+            let compiled_path = compiled_scenes_dir.join(dlc_rel_to_path(&compiled));
+            archive_entries.push((format!("res/{compiled}"), compiled_path));
+            continue;
+        }
         archive_entries.push((format!("res/{rel}"), dlc_root.join(dlc_rel_to_path(&rel))));
     }
 
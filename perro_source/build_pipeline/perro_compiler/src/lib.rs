@@ -1,6 +1,11 @@
 //! Project build pipeline, script crate generation, and bundle export helpers.
 
-use perro_assets::{build_compressed_perro_archive_from_entries, build_perro_assets_archive};
+use perro_assets::{
+    build_compressed_perro_archive_from_entries, build_perro_assets_archive_with_compiled,
+    compression::EntryCodec,
+    crypto::{ARCHIVE_KEY_LEN, ArchiveKey},
+    push_archive_key, push_codec_rules,
+};
 use perro_io::walkdir::walk_dir;
 use perro_project::{ensure_source_overrides, load_project_toml};
 use std::{
@@ -24,4 +29,6 @@ include!("script_writer.rs");
 include!("script_codegen.rs");
 include!("script_fields.rs");
 include!("script_methods.rs");
+include!("script_vars.rs");
+include!("script_docs.rs");
 include!("tests.rs");
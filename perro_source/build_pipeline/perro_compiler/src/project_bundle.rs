@@ -13,6 +13,10 @@ pub struct ProjectBuildOptions {
     /// Discard every incremental pipeline cache (embedded blobs, manifests,
     /// archive stat sidecar) and re-encode all assets from source.
     pub fresh: bool,
+    /// Byte-identical exports from identical inputs: zero the embedded build
+    /// timestamp (unless `SOURCE_DATE_EPOCH` is set) and remap the project
+    /// path out of compiled binaries.
+    pub reproducible: bool,
 }
 
 impl ProjectBuildOptions {
@@ -29,6 +33,7 @@ impl ProjectBuildOptions {
             native_target: None,
             demo: false,
             fresh: false,
+            reproducible: false,
         }
     }
 
@@ -37,6 +42,11 @@ impl ProjectBuildOptions {
         self
     }
 
+    pub fn with_reproducible(mut self, reproducible: bool) -> Self {
+        self.reproducible = reproducible;
+        self
+    }
+
     pub fn with_target(mut self, target: ProjectBuildTarget) -> Self {
         self.target = target;
         self
@@ -76,6 +86,53 @@ impl ProjectBuildOptions {
         self.android_ndk_root = ndk_root;
         self
     }
+
+    /// Server builds always run the headless entry; `--headless` opts native in.
+    pub fn runs_headless(&self) -> bool {
+        self.headless || self.target == ProjectBuildTarget::Server
+    }
+
+    /// `[platform.<name>]` table of project.toml this build merges.
+    pub fn project_platform(&self) -> perro_project::ProjectPlatform {
+        match self.target {
+            ProjectBuildTarget::Web => perro_project::ProjectPlatform::Web,
+            ProjectBuildTarget::Android => perro_project::ProjectPlatform::Android,
+            ProjectBuildTarget::Native | ProjectBuildTarget::Server => self
+                .native_target
+                .and_then(perro_project::ProjectPlatform::from_target_triple)
+                .unwrap_or_else(perro_project::ProjectPlatform::host),
+        }
+    }
+
+    /// Platform / features that scene `@if` guards see in this build.
+    pub fn scene_target(&self) -> perro_scene::SceneTarget {
+        let target = match self.target {
+            ProjectBuildTarget::Web => perro_scene::SceneTarget::new("wasm"),
+            ProjectBuildTarget::Android => {
+                perro_scene::SceneTarget::from_triple("aarch64-linux-android")
+            }
+            ProjectBuildTarget::Native | ProjectBuildTarget::Server => self
+                .native_target
+                .map(perro_scene::SceneTarget::from_triple)
+                .unwrap_or_else(perro_scene::SceneTarget::host),
+        };
+        target
+            .with_feature_if(self.runs_headless(), "headless")
+            .with_feature_if(self.target == ProjectBuildTarget::Server, "server")
+            .with_feature_if(self.demo, "demo")
+    }
+
+    pub(crate) fn load_project_config(
+        &self,
+        project_root: &Path,
+    ) -> Result<perro_project::ProjectConfig, CompilerError> {
+        perro_project::load_project_toml_for_platform(
+            project_root,
+            self.demo,
+            Some(self.project_platform()),
+        )
+        .map_err(|e| CompilerError::SceneParse(format!("failed to load project.toml: {e}")))
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -83,6 +140,8 @@ pub enum ProjectBuildTarget {
     Native,
     Web,
     Android,
+    /// Native headless binary w/ fixed-step ticks, net pump, and stdin console.
+    Server,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -95,8 +154,7 @@ pub fn compile_project_bundle(
     project_root: &Path,
     options: ProjectBuildOptions,
 ) -> Result<(), CompilerError> {
-    let cfg = perro_project::load_project_toml_with_demo(project_root, options.demo)
-        .map_err(|e| CompilerError::SceneParse(format!("failed to load project.toml: {e}")))?;
+    let cfg = options.load_project_config(project_root)?;
     validate_demo_entry_paths(&cfg)?;
     perro_project::ensure_build_crates_scaffold(project_root, &cfg.name)?;
     ensure_source_overrides(project_root)?;
@@ -105,14 +163,30 @@ pub fn compile_project_bundle(
         reset_embedded_dir(project_root)?;
     }
     sweep_unknown_embedded_entries(project_root)?;
-    let _path_filter = perro_io::walkdir::push_path_exclusions(cfg.demo.relative_patterns());
+    let imports = run_project_imports(project_root, &cfg, options.fresh)?;
+    let mut exclusions = cfg.excluded_asset_patterns();
+    exclusions.extend(imports.sources);
+    if cfg.prune.unused {
+        let mut pruned = plan_unused_asset_prune(project_root, &cfg)?;
+        pruned.retain(|asset| !exclusions.contains(&asset.rel_path));
+        write_prune_report(project_root, &pruned)?;
+        exclusions.extend(pruned.into_iter().map(|asset| asset.rel_path));
+    }
+    let _path_filter = perro_io::walkdir::push_path_exclusions(exclusions);
+    let _pack_codecs = push_codec_rules(pack_codec_rules(&cfg.pack));
+    let archive_key = resolve_archive_key(project_root, &cfg.pack)?;
+    let _archive_key = push_archive_key(archive_key);
     let _demo_mode = perro_static_pipeline::push_demo_mode(options.demo);
-    let _ = sync_scripts(project_root)?;
+    let _scene_target = perro_static_pipeline::push_scene_target(options.scene_target());
+    let _scene_lint = perro_static_pipeline::push_scene_lint(cfg.scene_lint);
+    let scripts = sync_project_scripts(project_root)?;
     generate_project_static_modules(project_root, &cfg)?;
     perro_static_pipeline::write_static_mod_rs(project_root)
         .map_err(|err| CompilerError::SceneParse(format!("static mod generation failed: {err}")))?;
     generate_embedded_entry_files_with_options(project_root, options)?;
     generate_perro_assets(project_root)?;
+    generate_perro_assets_integrity(project_root, &cfg.integrity, scripts.registered)?;
+    generate_perro_assets_key(project_root, archive_key.as_ref())?;
     build_project_crate(
         project_root,
         options,
@@ -122,15 +196,67 @@ pub fn compile_project_bundle(
     Ok(())
 }
 
+/// Code-registered import plugins, `[import]` tools, then built-ins whose
+/// extensions neither claims. Sources are returned so exports can leave them
+/// out; their outputs ship instead.
+fn run_project_imports(
+    project_root: &Path,
+    cfg: &perro_project::ProjectConfig,
+    fresh: bool,
+) -> Result<perro_static_pipeline::ImportReport, CompilerError> {
+    let mut plugins = perro_static_pipeline::registered_import_plugins();
+    for tool in &cfg.imports {
+        let program = if tool.command.contains(['/', '\\']) {
+            project_root.join(&tool.command)
+        } else {
+            PathBuf::from(&tool.command)
+        };
+        plugins.push(std::sync::Arc::new(
+            perro_static_pipeline::CommandImportPlugin {
+                name: tool.name.clone(),
+                extensions: tool.extensions.clone(),
+                program,
+                args: tool.args.clone(),
+                version: tool.version,
+            },
+        ));
+    }
+    let claimed = plugins
+        .iter()
+        .flat_map(|plugin| plugin.extensions().into_iter().map(str::to_ascii_lowercase))
+        .collect::<HashSet<_>>();
+    plugins.extend(
+        perro_static_pipeline::builtin_import_plugins()
+            .into_iter()
+            .filter(|builtin| {
+                builtin
+                    .extensions()
+                    .iter()
+                    .all(|ext| !claimed.contains(*ext))
+            }),
+    );
+    let report = perro_static_pipeline::run_import_plugins(project_root, &plugins, fresh)
+        .map_err(|err| CompilerError::SceneParse(format!("asset import failed: {err}")))?;
+    if report.imported > 0 || !report.removed.is_empty() {
+        println!(
+            "imported {} asset source(s) ({} unchanged, {} stale output(s) removed)",
+            report.imported,
+            report.reused,
+            report.removed.len()
+        );
+    }
+    Ok(report)
+}
+
 fn validate_demo_entry_paths(cfg: &perro_project::ProjectConfig) -> Result<(), CompilerError> {
     for (field, path) in [
         ("project.main_scene", cfg.main_scene.as_str()),
         ("project.icon", cfg.icon.as_str()),
         ("project.startup_splash", cfg.startup_splash.as_str()),
     ] {
-        if cfg.demo.excludes(path) {
+        if cfg.excludes_asset(path) {
             return Err(CompilerError::SceneParse(format!(
-                "`{field}` refs demo-excluded path `{path}`"
+                "`{field}` refs excluded path `{path}`"
             )));
         }
     }
@@ -160,12 +286,126 @@ pub fn compile_universal_macos_project_bundle(
     Ok(())
 }
 
+/// `[pack.codecs]` as archive codec overrides.
+fn pack_codec_rules(pack: &perro_project::PackConfig) -> Vec<(String, EntryCodec)> {
+    use perro_project::PackCodec;
+    pack.codecs
+        .iter()
+        .map(|(ext, codec)| {
+            let codec = match codec {
+                PackCodec::Store => EntryCodec::Store,
+                PackCodec::Zlib => EntryCodec::Zlib,
+                PackCodec::Lz4 => EntryCodec::Lz4,
+                PackCodec::Zstd => EntryCodec::Zstd,
+            };
+            (ext.clone(), codec)
+        })
+        .collect()
+}
+
 fn generate_perro_assets(project_root: &Path) -> Result<(), CompilerError> {
     let embedded_dir = project_root.join(".perro").join("project").join("embedded");
     fs::create_dir_all(&embedded_dir)?;
     let output = embedded_dir.join("assets.perro");
     let res_dir = project_root.join("res");
-    build_perro_assets_archive(&output, &res_dir, project_root, &[])?;
+    // `.scn` text never ships; the static pipeline's `.scnb` twins do.
+    build_perro_assets_archive_with_compiled(
+        &output,
+        &res_dir,
+        Some(&embedded_dir.join("scenes")),
+        project_root,
+        &[],
+    )?;
+    Ok(())
+}
+
+/// `[integrity] check = true`: manifest the shipped entry re-hashes at boot.
+/// Written empty when off so the entry's `include_str!` always resolves.
+fn generate_perro_assets_integrity(
+    project_root: &Path,
+    integrity: &perro_project::IntegrityConfig,
+    script_count: usize,
+) -> Result<(), CompilerError> {
+    let embedded_dir = project_root.join(".perro").join("project").join("embedded");
+    let output = embedded_dir.join("assets.perro.integrity");
+    if !integrity.check {
+        write_string_if_changed(&output, "")?;
+        return Ok(());
+    }
+    let archive = fs::read(embedded_dir.join("assets.perro"))?;
+    let critical = integrity
+        .critical
+        .iter()
+        .map(|path| format!("res/{}", path.trim_start_matches("res://")))
+        .collect::<Vec<_>>();
+    let manifest =
+        perro_assets::integrity::IntegrityManifest::compute(&archive, &critical, script_count)
+            .map_err(|err| {
+                CompilerError::SceneParse(format!("integrity manifest failed: {err}"))
+            })?;
+    write_string_if_changed(&output, &manifest.to_text())?;
+    Ok(())
+}
+
+/// Env override for the `[pack] encrypt` key, as hex; keeps one key across
+/// machines + CI so DLC packs built elsewhere still open in the game.
+const ASSETS_KEY_ENV: &str = "PERRO_ASSETS_KEY";
+
+/// `[pack] encrypt = true`: the key from `PERRO_ASSETS_KEY`, else the one
+/// persisted in `.perro/assets.key`, generated on first use.
+fn resolve_archive_key(
+    project_root: &Path,
+    pack: &perro_project::PackConfig,
+) -> Result<Option<ArchiveKey>, CompilerError> {
+    if !pack.encrypt {
+        return Ok(None);
+    }
+    if let Ok(hex) = env::var(ASSETS_KEY_ENV) {
+        return parse_archive_key(hex.trim()).map(Some).ok_or_else(|| {
+            CompilerError::SceneParse(format!(
+                "{ASSETS_KEY_ENV} must be {} hex chars",
+                ARCHIVE_KEY_LEN * 2
+            ))
+        });
+    }
+    let path = project_root.join(".perro").join("assets.key");
+    if let Ok(hex) = fs::read_to_string(&path) {
+        return parse_archive_key(hex.trim()).map(Some).ok_or_else(|| {
+            CompilerError::SceneParse(format!("{} is not a valid archive key", path.display()))
+        });
+    }
+    let mut key = [0u8; ARCHIVE_KEY_LEN];
+    getrandom::fill(&mut key).map_err(|err| {
+        CompilerError::SceneParse(format!("archive key generation failed: {err}"))
+    })?;
+    fs::create_dir_all(project_root.join(".perro"))?;
+    fs::write(&path, hex_bytes(&key))?;
+    Ok(Some(key))
+}
+
+fn parse_archive_key(hex: &str) -> Option<ArchiveKey> {
+    if hex.len() != ARCHIVE_KEY_LEN * 2 || !hex.is_ascii() {
+        return None;
+    }
+    let mut key = [0u8; ARCHIVE_KEY_LEN];
+    for (byte, pair) in key.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+    Some(key)
+}
+
+/// Raw key the entry registers b4 opening the archive. Written empty when
+/// encryption is off so the entry's `include_bytes!` always resolves.
+fn generate_perro_assets_key(
+    project_root: &Path,
+    key: Option<&ArchiveKey>,
+) -> Result<(), CompilerError> {
+    let embedded_dir = project_root.join(".perro").join("project").join("embedded");
+    let output = embedded_dir.join("assets.perro.key");
+    let bytes = key.map_or(&[][..], |key| &key[..]);
+    if fs::read(&output).ok().as_deref() != Some(bytes) {
+        fs::write(&output, bytes)?;
+    }
     Ok(())
 }
 
@@ -204,6 +444,8 @@ fn sweep_unknown_embedded_entries(project_root: &Path) -> Result<(), CompilerErr
         "localizations",
         "assets.perro",
         "assets.perro.stat",
+        "assets.perro.integrity",
+        "assets.perro.key",
     ];
     let embedded_dir = project_root.join(".perro").join("project").join("embedded");
     fs::create_dir_all(&embedded_dir)?;
@@ -262,12 +504,27 @@ fn build_project_crate(
     if options.release {
         cmd.arg("--release");
     }
-    if options.target == ProjectBuildTarget::Native && !options.console && !options.headless {
+    if options.target == ProjectBuildTarget::Native && !options.console && !options.runs_headless()
+    {
         cmd.env(
             "RUSTFLAGS",
             append_rustflag(env::var_os("RUSTFLAGS"), "--cfg perro_no_console"),
         );
     }
+    if options.reproducible {
+        // Panic locations and debug info embed absolute source paths; map the
+        // project checkout to a fixed prefix so the output does not depend on
+        // where the project was cloned.
+        let existing = cmd
+            .get_envs()
+            .find(|(key, _)| *key == "RUSTFLAGS")
+            .and_then(|(_, value)| value.map(std::ffi::OsStr::to_os_string))
+            .or_else(|| env::var_os("RUSTFLAGS"));
+        cmd.env(
+            "RUSTFLAGS",
+            append_rustflag(existing, &reproducible_remap_flag(project_root)?),
+        );
+    }
     if let Some(sdk_root) = options.android_sdk_root {
         cmd.env("ANDROID_SDK_ROOT", sdk_root)
             .env("ANDROID_HOME", sdk_root);
@@ -281,19 +538,22 @@ fn build_project_crate(
         cmd.env("PERRO_DEMO", "1");
     }
     let mut features = Vec::new();
-    if options.headless {
+    if options.runs_headless() {
         cmd.arg("--no-default-features");
         features.push("headless");
     }
+    if options.target == ProjectBuildTarget::Server {
+        features.push("server");
+    }
     if options.profile {
-        features.push(if options.headless {
+        features.push(if options.runs_headless() {
             "headless_profile"
         } else {
             "profile"
         });
     }
     if steam_enabled {
-        features.push(if options.headless {
+        features.push(if options.runs_headless() {
             "headless_steamworks"
         } else {
             "steamworks"
@@ -320,15 +580,9 @@ fn build_project_crate(
         return Err(CompilerError::CargoFailed(status.code().unwrap_or(-1)));
     }
     match options.target {
-        ProjectBuildTarget::Native => export_project_binary(
-            project_root,
-            &target_dir,
-            options.release,
-            steam_enabled,
-            version,
-            options.native_target,
-            options.demo,
-        )?,
+        ProjectBuildTarget::Native | ProjectBuildTarget::Server => {
+            export_project_binary(project_root, &target_dir, options, steam_enabled, version)?
+        }
         ProjectBuildTarget::Web => export_project_web_bundle(project_root, &target_dir, options)?,
         ProjectBuildTarget::Android => export_project_android_bundle(
             project_root,
@@ -357,6 +611,17 @@ fn validate_native_target_triple(target: &str) -> Result<(), CompilerError> {
     }
 }
 
+fn reproducible_remap_flag(project_root: &Path) -> Result<String, CompilerError> {
+    let root = project_root.to_string_lossy();
+    // RUSTFLAGS is whitespace-separated; a path with spaces cannot be passed.
+    if root.chars().any(char::is_whitespace) {
+        return Err(CompilerError::SceneParse(format!(
+            "`--reproducible` needs a project path without whitespace: {root}"
+        )));
+    }
+    Ok(format!("--remap-path-prefix={root}=/perro/project"))
+}
+
 fn append_rustflag(existing: Option<std::ffi::OsString>, flag: &str) -> std::ffi::OsString {
     let mut out = existing.unwrap_or_default();
     if !out.is_empty() {
@@ -369,15 +634,19 @@ fn append_rustflag(existing: Option<std::ffi::OsString>, flag: &str) -> std::ffi
 fn export_project_binary(
     project_root: &Path,
     target_dir: &Path,
-    release: bool,
+    options: ProjectBuildOptions,
     steam_enabled: bool,
     version: Option<&str>,
-    native_target: Option<&str>,
-    demo: bool,
 ) -> Result<(), CompilerError> {
+    let native_target = options.native_target;
     let package_bin_name = read_project_package_name(project_root)?;
-    let output_bin_name = read_project_output_binary_name(project_root, &package_bin_name, demo)?;
-    let profile_dir = if release { "release" } else { "debug" };
+    let mut output_bin_name =
+        read_project_output_binary_name(project_root, &package_bin_name, options.demo)?;
+    if options.target == ProjectBuildTarget::Server {
+        // Own folder + artifact name so a server export never clobbers the client one.
+        output_bin_name.push_str("-server");
+    }
+    let profile_dir = if options.release { "release" } else { "debug" };
     let artifact_dir = native_artifact_dir(target_dir, profile_dir, native_target);
     let built_bin = artifact_dir.join(target_binary_name(&package_bin_name, native_target));
     if !built_bin.exists() {
@@ -410,8 +679,12 @@ fn export_universal_macos_binary(
     release: bool,
     demo: bool,
 ) -> Result<(), CompilerError> {
-    let cfg = perro_project::load_project_toml_with_demo(project_root, demo)
-        .map_err(|e| CompilerError::SceneParse(format!("failed to load project.toml: {e}")))?;
+    let cfg = perro_project::load_project_toml_for_platform(
+        project_root,
+        demo,
+        Some(perro_project::ProjectPlatform::Macos),
+    )
+    .map_err(|e| CompilerError::SceneParse(format!("failed to load project.toml: {e}")))?;
     let package_bin_name = read_project_package_name(project_root)?;
     let output_bin_name = read_project_output_binary_name(project_root, &package_bin_name, demo)?;
     let artifact_name = format!(
@@ -703,6 +976,12 @@ pub(crate) use android::*;
 #[path = "project_bundle/web.rs"]
 mod web;
 pub(crate) use web::*;
+#[path = "project_bundle/build_info.rs"]
+mod build_info;
+pub(crate) use build_info::*;
 #[path = "project_bundle/codegen.rs"]
 mod codegen;
 pub(crate) use codegen::*;
+#[path = "project_bundle/prune.rs"]
+mod prune;
+pub(crate) use prune::*;
@@ -237,6 +237,8 @@ pub(super) fn generate_embedded_entry_files(project_root: &Path) -> Result<(), C
 // once; all three entry targets (native/web/android) splice this same block.
 pub(super) const STATIC_EMBEDDED_ASSETS_BLOCK: &str = "  assets: perro_app::entry::StaticEmbeddedAssetsConfig {\n\
         perro_assets: PERRO_ASSETS,\n\
+        integrity: PERRO_ASSETS_INTEGRITY,\n\
+        archive_key: PERRO_ASSETS_KEY,\n\
         scene_lookup: static_assets::scenes::lookup_scene,\n\
         localization_lookup: static_assets::localizations::lookup_localized_string,\n\
         material_lookup: static_assets::materials::lookup_material,\n\
@@ -261,8 +263,7 @@ pub(super) fn generate_embedded_entry_files_with_options(
     project_root: &Path,
     options: ProjectBuildOptions,
 ) -> Result<(), CompilerError> {
-    let cfg = perro_project::load_project_toml_with_demo(project_root, options.demo)
-        .map_err(|e| CompilerError::SceneParse(format!("failed to load project.toml: {e}")))?;
+    let cfg = options.load_project_config(project_root)?;
     let routes = perro_project::load_routes_toml(project_root, &cfg)
         .map_err(|e| CompilerError::SceneParse(format!("failed to load routes.toml: {e}")))?;
     let project_src = project_root.join(".perro").join("project").join("src");
@@ -289,6 +290,7 @@ pub(super) fn generate_embedded_entry_files_with_options(
     ensure_project_dependency_line(project_root, "perro_structs", "perro_structs = \"0.1.0\"")?;
     perro_project::ensure_source_overrides(project_root)?;
 
+    let build_block = emit_build_info_block(project_root, &cfg, options);
     let native_entry = "run_static_embedded_project";
     let mut embedded_block = format!(
         "let root = project_root();\n\
@@ -324,9 +326,13 @@ perro_app::entry::{native_entry}(perro_app::entry::StaticEmbeddedProject {{\n\
   }},\n\
   runtime: perro_app::entry::StaticEmbeddedRuntimeConfig {{\n\
         target_fixed_update: {target_fixed_update},\n\
+        max_fixed_steps_per_frame: {max_fixed_steps_per_frame},\n\
         frame_rate_cap: {frame_rate_cap},\n\
         physics_gravity: {physics_gravity},\n\
         physics_coef: {physics_coef},\n\
+        physics_interpolation: {physics_interpolation},\n\
+        physics_layer_names_2d: {physics_layer_names_2d},\n\
+        physics_layer_names_3d: {physics_layer_names_3d},\n\
   }},\n\
   metadata: perro_app::entry::StaticEmbeddedMetadataConfig {{\n\
         description: {metadata_description},\n\
@@ -335,6 +341,7 @@ perro_app::entry::{native_entry}(perro_app::entry::StaticEmbeddedProject {{\n\
         copyright: {metadata_copyright},\n\
         trademark: {metadata_trademark},\n\
   }},\n\
+{build_block}\
   localization: perro_app::entry::StaticEmbeddedLocalizationConfig {{\n\
         default_locale: {localization_default_locale},\n\
   }},\n\
@@ -355,6 +362,7 @@ perro_app::entry::{native_entry}(perro_app::entry::StaticEmbeddedProject {{\n\
         routes_block = emit_static_routes_block(&routes),
         input_map_block = emit_static_input_map_block(&cfg.input_map),
         assets_block = STATIC_EMBEDDED_ASSETS_BLOCK,
+        build_block = build_block,
         vsync = cfg.vsync,
         hdr = emit_hdr_expr(cfg.hdr),
         msaa = cfg.msaa,
@@ -368,9 +376,13 @@ perro_app::entry::{native_entry}(perro_app::entry::StaticEmbeddedProject {{\n\
         ui_pixel_snapping = cfg.rendering.ui.pixel_snapping,
         default_font = escape_str(&cfg.rendering.default_font),
         target_fixed_update = emit_optional_f32(cfg.target_fixed_update),
+        max_fixed_steps_per_frame = cfg.max_fixed_steps_per_frame,
         frame_rate_cap = emit_frame_rate_cap_expr(cfg.frame_rate_cap),
         physics_gravity = emit_f32(cfg.physics_gravity),
         physics_coef = emit_f32(cfg.physics_coef),
+        physics_interpolation = cfg.physics_interpolation,
+        physics_layer_names_2d = emit_static_layer_names(&cfg.layer_names.physics_2d),
+        physics_layer_names_3d = emit_static_layer_names(&cfg.layer_names.physics_3d),
         metadata_description = emit_optional_static_str(cfg.metadata.description.as_deref()),
         metadata_company = emit_optional_static_str(cfg.metadata.company.as_deref()),
         metadata_version = emit_optional_static_str(cfg.metadata.version.as_deref()),
@@ -386,7 +398,7 @@ perro_app::entry::{native_entry}(perro_app::entry::StaticEmbeddedProject {{\n\
         steam_app_id = emit_optional_steam_app_id_fn(cfg.steam.app_id),
         steam_input_mode = emit_steam_input_mode(cfg.steam.input_mode),
     );
-    if options.headless {
+    if options.runs_headless() {
         embedded_block = embedded_block.replace("perro_app::entry", "perro_headless");
     }
     let embedded_block = indent_block(&embedded_block, 2);
@@ -424,9 +436,13 @@ perro_app::entry::run_static_embedded_project_web(perro_app::entry::StaticEmbedd
   }},\n\
   runtime: perro_app::entry::StaticEmbeddedRuntimeConfig {{\n\
         target_fixed_update: {target_fixed_update},\n\
+        max_fixed_steps_per_frame: {max_fixed_steps_per_frame},\n\
         frame_rate_cap: {frame_rate_cap},\n\
         physics_gravity: {physics_gravity},\n\
         physics_coef: {physics_coef},\n\
+        physics_interpolation: {physics_interpolation},\n\
+        physics_layer_names_2d: {physics_layer_names_2d},\n\
+        physics_layer_names_3d: {physics_layer_names_3d},\n\
   }},\n\
   metadata: perro_app::entry::StaticEmbeddedMetadataConfig {{\n\
         description: {metadata_description},\n\
@@ -435,6 +451,7 @@ perro_app::entry::run_static_embedded_project_web(perro_app::entry::StaticEmbedd
         copyright: {metadata_copyright},\n\
         trademark: {metadata_trademark},\n\
   }},\n\
+{build_block}\
   localization: perro_app::entry::StaticEmbeddedLocalizationConfig {{\n\
         default_locale: {localization_default_locale},\n\
   }},\n\
@@ -454,6 +471,7 @@ perro_app::entry::run_static_embedded_project_web(perro_app::entry::StaticEmbedd
         routes_block = emit_static_routes_block(&routes),
         input_map_block = emit_static_input_map_block(&cfg.input_map),
         assets_block = STATIC_EMBEDDED_ASSETS_BLOCK,
+        build_block = build_block,
         vsync = cfg.vsync,
         hdr = emit_hdr_expr(cfg.hdr),
         msaa = cfg.msaa,
@@ -467,9 +485,13 @@ perro_app::entry::run_static_embedded_project_web(perro_app::entry::StaticEmbedd
         ui_pixel_snapping = cfg.rendering.ui.pixel_snapping,
         default_font = escape_str(&cfg.rendering.default_font),
         target_fixed_update = emit_optional_f32(cfg.target_fixed_update),
+        max_fixed_steps_per_frame = cfg.max_fixed_steps_per_frame,
         frame_rate_cap = emit_frame_rate_cap_expr(cfg.frame_rate_cap),
         physics_gravity = emit_f32(cfg.physics_gravity),
         physics_coef = emit_f32(cfg.physics_coef),
+        physics_interpolation = cfg.physics_interpolation,
+        physics_layer_names_2d = emit_static_layer_names(&cfg.layer_names.physics_2d),
+        physics_layer_names_3d = emit_static_layer_names(&cfg.layer_names.physics_3d),
         metadata_description = emit_optional_static_str(cfg.metadata.description.as_deref()),
         metadata_company = emit_optional_static_str(cfg.metadata.company.as_deref()),
         metadata_version = emit_optional_static_str(cfg.metadata.version.as_deref()),
@@ -519,9 +541,13 @@ perro_app::entry::run_static_embedded_project_android(app, perro_app::entry::Sta
   }},\n\
   runtime: perro_app::entry::StaticEmbeddedRuntimeConfig {{\n\
         target_fixed_update: {target_fixed_update},\n\
+        max_fixed_steps_per_frame: {max_fixed_steps_per_frame},\n\
         frame_rate_cap: {frame_rate_cap},\n\
         physics_gravity: {physics_gravity},\n\
         physics_coef: {physics_coef},\n\
+        physics_interpolation: {physics_interpolation},\n\
+        physics_layer_names_2d: {physics_layer_names_2d},\n\
+        physics_layer_names_3d: {physics_layer_names_3d},\n\
   }},\n\
   metadata: perro_app::entry::StaticEmbeddedMetadataConfig {{\n\
         description: {metadata_description},\n\
@@ -530,6 +556,7 @@ perro_app::entry::run_static_embedded_project_android(app, perro_app::entry::Sta
         copyright: {metadata_copyright},\n\
         trademark: {metadata_trademark},\n\
   }},\n\
+{build_block}\
   localization: perro_app::entry::StaticEmbeddedLocalizationConfig {{\n\
         default_locale: {localization_default_locale},\n\
   }},\n\
@@ -550,6 +577,7 @@ perro_app::entry::run_static_embedded_project_android(app, perro_app::entry::Sta
         routes_block = emit_static_routes_block(&routes),
         input_map_block = emit_static_input_map_block(&cfg.input_map),
         assets_block = STATIC_EMBEDDED_ASSETS_BLOCK,
+        build_block = build_block,
         vsync = cfg.vsync,
         hdr = emit_hdr_expr(cfg.hdr),
         msaa = cfg.msaa,
@@ -563,9 +591,13 @@ perro_app::entry::run_static_embedded_project_android(app, perro_app::entry::Sta
         ui_pixel_snapping = cfg.rendering.ui.pixel_snapping,
         default_font = escape_str(&cfg.rendering.default_font),
         target_fixed_update = emit_optional_f32(cfg.target_fixed_update),
+        max_fixed_steps_per_frame = cfg.max_fixed_steps_per_frame,
         frame_rate_cap = emit_frame_rate_cap_expr(cfg.frame_rate_cap),
         physics_gravity = emit_f32(cfg.physics_gravity),
         physics_coef = emit_f32(cfg.physics_coef),
+        physics_interpolation = cfg.physics_interpolation,
+        physics_layer_names_2d = emit_static_layer_names(&cfg.layer_names.physics_2d),
+        physics_layer_names_3d = emit_static_layer_names(&cfg.layer_names.physics_3d),
         metadata_description = emit_optional_static_str(cfg.metadata.description.as_deref()),
         metadata_company = emit_optional_static_str(cfg.metadata.company.as_deref()),
         metadata_version = emit_optional_static_str(cfg.metadata.version.as_deref()),
@@ -589,7 +621,9 @@ perro_app::entry::run_static_embedded_project_android(app, perro_app::entry::Sta
         "#![allow(dead_code)]\n\n\
 #[path = \"static/mod.rs\"]\n\
 mod static_assets;\n\n\
-pub(super) static PERRO_ASSETS: &[u8] = include_bytes!(\"../embedded/assets.perro\");\n\n\
+pub(super) static PERRO_ASSETS: &[u8] = include_bytes!(\"../embedded/assets.perro\");\n\
+pub(super) static PERRO_ASSETS_INTEGRITY: &str = include_str!(\"../embedded/assets.perro.integrity\");\n\
+pub(super) static PERRO_ASSETS_KEY: &[u8] = include_bytes!(\"../embedded/assets.perro.key\");\n\n\
 {steam_app_id_fn_block}\
 #[used]\n\
 #[unsafe(no_mangle)]\n\
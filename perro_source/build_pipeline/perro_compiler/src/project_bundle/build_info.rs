@@ -0,0 +1,65 @@
+use super::*;
+
+/// `build:` field for the generated entry source: a `BuildInfo::embedded`
+/// constant stamped with the project version, git commit, build time and
+/// cargo profile. Read back at runtime through `OS().build_info()`.
+pub(super) fn emit_build_info_block(
+    project_root: &Path,
+    cfg: &perro_project::ProjectConfig,
+    options: ProjectBuildOptions,
+) -> String {
+    let version = cfg
+        .metadata
+        .version
+        .as_deref()
+        .map(str::trim)
+        .filter(|version| !version.is_empty())
+        .unwrap_or("0.0.0");
+    let profile = if options.release {
+        "perro_app::entry::BuildProfile::Release"
+    } else {
+        "perro_app::entry::BuildProfile::Debug"
+    };
+    format!(
+        "  build: perro_app::entry::BuildInfo::embedded({version}, {commit}, {timestamp}u64, {profile}),\n",
+        version = emit_static_str(version),
+        commit = emit_optional_static_str(project_git_commit(project_root).as_deref()),
+        timestamp = build_timestamp(options.reproducible),
+    )
+}
+
+/// `HEAD` of the git checkout containing the project, if any. Builds outside
+/// a repo (or without git on PATH) embed `None`.
+pub(super) fn project_git_commit(project_root: &Path) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(project_root)
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let commit = String::from_utf8(output.stdout).ok()?;
+    let commit = commit.trim();
+    (!commit.is_empty() && commit.bytes().all(|b| b.is_ascii_hexdigit()))
+        .then(|| commit.to_string())
+}
+
+/// Build time in unix seconds. `SOURCE_DATE_EPOCH` wins when set;
+/// reproducible builds otherwise embed `0` so two exports match byte for byte.
+pub(super) fn build_timestamp(reproducible: bool) -> u64 {
+    if let Some(epoch) = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|raw| raw.trim().parse::<u64>().ok())
+    {
+        return epoch;
+    }
+    if reproducible {
+        return 0;
+    }
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
@@ -197,6 +197,7 @@ pub(super) fn emit_static_input_map_block(input_map: &perro_input_api::InputMap)
     let mut out = String::from("&[");
     for action in input_map.actions() {
         let mut keys = Vec::new();
+        let mut chords = Vec::new();
         let mut mouse = Vec::new();
         let mut gamepad = Vec::new();
         let mut joycon = Vec::new();
@@ -205,6 +206,13 @@ pub(super) fn emit_static_input_map_block(input_map: &perro_input_api::InputMap)
                 perro_input_api::InputBinding::Key(key) => {
                     keys.push(format!("perro_input_api::KeyCode::{key:?}"));
                 }
+                perro_input_api::InputBinding::Chord(chord) => {
+                    chords.push(format!(
+                        "perro_input_api::KeyChord::new(perro_input_api::KeyModifiers::from_bits({}), perro_input_api::KeyCode::{:?})",
+                        chord.modifiers.bits(),
+                        chord.key
+                    ));
+                }
                 perro_input_api::InputBinding::Mouse(button) => {
                     mouse.push(format!("perro_input_api::MouseButton::{button:?}"));
                 }
@@ -218,12 +226,15 @@ pub(super) fn emit_static_input_map_block(input_map: &perro_input_api::InputMap)
         }
         out.push_str("\n            perro_app::entry::StaticEmbeddedInputAction { ");
         out.push_str(&format!(
-            "name: {}, keys: &{}, mouse: &{}, gamepad: &{}, joycon: &{} }},",
+            "name: {}, keys: &{}, chords: &{}, mouse: &{}, gamepad: &{}, joycon: &{}, context: {}, consume: {} }},",
             emit_static_str(&action.name),
             emit_static_input_binding_array(&keys),
+            emit_static_input_binding_array(&chords),
             emit_static_input_binding_array(&mouse),
             emit_static_input_binding_array(&gamepad),
-            emit_static_input_binding_array(&joycon)
+            emit_static_input_binding_array(&joycon),
+            emit_static_str(&action.context),
+            action.consume
         ));
     }
     if !input_map.actions().is_empty() {
@@ -233,6 +244,14 @@ pub(super) fn emit_static_input_map_block(input_map: &perro_input_api::InputMap)
     out
 }
 
+pub(super) fn emit_static_layer_names(layers: &[(u8, String)]) -> String {
+    let items: Vec<String> = layers
+        .iter()
+        .map(|(layer, name)| format!("({layer}u8, {})", emit_static_str(name)))
+        .collect();
+    format!("&[{}]", items.join(", "))
+}
+
 pub(super) fn emit_static_input_binding_array(items: &[String]) -> String {
     if items.is_empty() {
         "[]".to_string()
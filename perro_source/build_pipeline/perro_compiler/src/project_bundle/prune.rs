@@ -0,0 +1,112 @@
+use super::*;
+use perro_scene::deps::{AssetDepGraph, text_asset_refs};
+use std::collections::BTreeSet;
+
+const PRUNE_REPORT: &str = "pruned_assets.txt";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PrunedAsset {
+    /// Relative to `res/`, the form walkdir exclusions match against.
+    pub(crate) rel_path: String,
+    pub(crate) bytes: u64,
+}
+
+/// `[prune] unused = true`: every `res/` file not reachable from a root.
+///
+/// Roots: `project.main_scene` / `icon` / `startup_splash`, any `res://`
+/// literal in project.toml, every script (compiled in regardless, so its
+/// string loads count), everything DLCs reference, and `prune.include`.
+pub(crate) fn plan_unused_asset_prune(
+    project_root: &Path,
+    cfg: &perro_project::ProjectConfig,
+) -> Result<Vec<PrunedAsset>, CompilerError> {
+    let res_dir = project_root.join("res");
+    if !res_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let graph = AssetDepGraph::scan_project(project_root)
+        .map_err(|err| CompilerError::SceneParse(format!("asset prune scan failed: {err}")))?;
+    let files = perro_io::walkdir::collect_file_paths(&res_dir, &res_dir)?
+        .into_iter()
+        .map(|rel| rel.replace('\\', "/"))
+        .collect::<Vec<_>>();
+
+    let include = cfg
+        .prune
+        .include
+        .iter()
+        .filter_map(|pattern| pattern.strip_prefix("res://"))
+        .collect::<Vec<_>>();
+    let mut roots: BTreeSet<String> = [&cfg.main_scene, &cfg.icon, &cfg.startup_splash]
+        .into_iter()
+        .chain(cfg.localization.iter().map(|loc| &loc.source_csv))
+        .filter(|path| !path.is_empty())
+        .cloned()
+        .collect();
+    if let Ok(toml) = fs::read_to_string(project_root.join("project.toml")) {
+        roots.extend(text_asset_refs(&toml));
+    }
+    for rel in &files {
+        let is_script = rel.ends_with(".rs");
+        let included = include
+            .iter()
+            .any(|pattern| perro_io::walkdir::matches_path_pattern(pattern, rel));
+        if is_script || included {
+            roots.insert(format!("res://{rel}"));
+        }
+    }
+    // Base assets a DLC uses must ship with the base game.
+    for source in graph.files().filter(|path| path.starts_with("dlc://")) {
+        roots.insert(source.to_string());
+    }
+
+    let mut reachable = BTreeSet::new();
+    for root in &roots {
+        let root = perro_scene::deps::normalize_asset_ref(root, "");
+        reachable.extend(graph.transitive_deps(&root).into_iter().map(str::to_string));
+        reachable.insert(root);
+    }
+
+    let mut pruned = Vec::new();
+    for rel in files {
+        let virtual_path = format!("res://{rel}");
+        if reachable.contains(&virtual_path) || cfg.excludes_asset(&virtual_path) {
+            continue;
+        }
+        let bytes = fs::metadata(res_dir.join(&rel)).map_or(0, |meta| meta.len());
+        pruned.push(PrunedAsset {
+            rel_path: rel,
+            bytes,
+        });
+    }
+    Ok(pruned)
+}
+
+/// `.output/pruned_assets.txt`, largest first, plus a one-line summary.
+pub(crate) fn write_prune_report(
+    project_root: &Path,
+    pruned: &[PrunedAsset],
+) -> Result<(), CompilerError> {
+    let mut sorted = pruned.to_vec();
+    sorted.sort_by(|a, b| {
+        b.bytes
+            .cmp(&a.bytes)
+            .then_with(|| a.rel_path.cmp(&b.rel_path))
+    });
+    let total = sorted.iter().map(|asset| asset.bytes).sum::<u64>();
+    let mut report = format!("pruned {} unused asset(s), {} bytes\n", sorted.len(), total);
+    for asset in &sorted {
+        report.push_str(&format!("{:>12}  res://{}\n", asset.bytes, asset.rel_path));
+    }
+    let output_dir = project_root.join(".output");
+    fs::create_dir_all(&output_dir)?;
+    let report_path = output_dir.join(PRUNE_REPORT);
+    fs::write(&report_path, report)?;
+    println!(
+        "pruned {} unused asset(s) ({:.1} KiB): {}",
+        sorted.len(),
+        total as f64 / 1024.0,
+        report_path.display()
+    );
+    Ok(())
+}
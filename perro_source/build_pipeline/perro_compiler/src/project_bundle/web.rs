@@ -7,8 +7,7 @@ pub(super) fn export_project_web_bundle(
 ) -> Result<(), CompilerError> {
     let package_name = read_project_package_name(project_root)?;
     let library_name = read_project_library_name(project_root, &package_name)?;
-    let project_cfg = perro_project::load_project_toml_with_demo(project_root, options.demo)
-        .map_err(|err| CompilerError::SceneParse(format!("failed to load project.toml: {err}")))?;
+    let project_cfg = options.load_project_config(project_root)?;
     let routes = perro_project::load_routes_toml(project_root, &project_cfg)
         .map_err(|err| CompilerError::SceneParse(format!("failed to load routes.toml: {err}")))?;
     let profile_dir = if options.release { "release" } else { "debug" };
@@ -450,10 +449,21 @@ pub(super) fn render_route_scene_html(
     let scene_file = res_root.join(rel);
     ensure_existing_path_within(&res_root, &scene_file, "web scene source")?;
     let scene_src = fs::read_to_string(&scene_file)?;
-    let scene = std::panic::catch_unwind(|| perro_scene::Parser::new(&scene_src).parse_scene())
-        .map_err(|_| {
+    let load_base = |path: &str| -> Result<String, String> {
+        let base_file = res_root.join(
+            checked_res_relative_path(path, "web base scene").map_err(|err| err.to_string())?,
+        );
+        ensure_existing_path_within(&res_root, &base_file, "web base scene source")
+            .map_err(|err| err.to_string())?;
+        fs::read_to_string(&base_file).map_err(|err| err.to_string())
+    };
+    let scene = perro_scene::Parser::new(&scene_src)
+        .with_scene_loader(&load_base)
+        .with_target(perro_scene::SceneTarget::new("wasm"))
+        .try_parse_scene()
+        .map_err(|err| {
             CompilerError::SceneParse(format!(
-                "failed to parse scene for static web html: {}",
+                "failed to parse scene for static web html {}: {err}",
                 scene_file.display()
             ))
         })?;
@@ -15,12 +15,7 @@ fn transpile_frontend_script(source: &str, source_include: &str) -> String {
         return format!("include!(\"{source_include}\");\n");
     }
 
-    let script_ty = parse_marked_struct_name(&source, "@Script")
-        .or_else(|| parse_attributed_struct_name(&source, "script"))
-        .or_else(|| parse_script_macro_target(&source, "lifecycle"))
-        .or_else(|| parse_script_macro_target(&source, "methods"))
-        .or_else(|| parse_named_struct(&stripped_source, "Script"))
-        .unwrap_or_else(|| "Script".to_string());
+    let script_ty = parse_script_ty(&source, &stripped_source);
     let needs_implicit_script_struct = parse_marked_struct_name(&source, "@Script").is_none()
         && parse_attributed_struct_name(&source, "script").is_none()
         && parse_named_struct(&stripped_source, &script_ty).is_none()
@@ -43,6 +38,7 @@ fn transpile_frontend_script(source: &str, source_include: &str) -> String {
     let has_update = has_nonempty_lifecycle_method(&source, "on_update");
     let has_fixed = has_nonempty_lifecycle_method(&source, "on_fixed_update");
     let has_removal = has_nonempty_lifecycle_method(&source, "on_removal");
+    let has_input = has_nonempty_lifecycle_method(&source, "on_input");
     let user_methods = parse_inherent_methods(&source, &script_ty);
     if debug_methods {
         let method_names = user_methods
@@ -88,6 +84,9 @@ fn transpile_frontend_script(source: &str, source_include: &str) -> String {
     if has_removal {
         flags.push_str(" | ScriptFlags::HAS_REMOVAL");
     }
+    if has_input {
+        flags.push_str(" | ScriptFlags::HAS_INPUT");
+    }
 
     let member_consts = generate_member_consts(&exposed_fields, &nested_fields, &user_methods);
     let state_cast_helpers = generate_state_cast_helpers(&state_ty, &exposed_fields);
@@ -96,6 +95,9 @@ fn transpile_frontend_script(source: &str, source_include: &str) -> String {
     let set_var_body = generate_set_var_body(&exposed_fields);
     let apply_scene_injected_vars_body = generate_apply_scene_injected_vars_body(&exposed_fields);
     let call_method_body = generate_call_method_body(&user_methods);
+    let attribute_methods = generate_attribute_methods(&exposed_fields);
+    let state_layout_methods =
+        generate_state_layout_methods(&exposed_fields, declares_state_version(&stripped_source));
 
     let implicit_script_decl = if needs_implicit_script_struct {
         format!("#[derive(Default)]\nstruct {script_ty};\n\n")
@@ -145,7 +147,7 @@ impl<API: ScriptAPI + ?Sized> ScriptBehavior<API> for {script_ty} {{
     ) -> Variant {{
 {call_method_body}
     }}
-}}
+{attribute_methods}{state_layout_methods}}}
 
 pub(crate) fn perro_create_script() -> *mut dyn ScriptBehavior<crate::RuntimeScriptApi> {{
     let script: Box<dyn ScriptBehavior<crate::RuntimeScriptApi>> =
@@ -189,6 +191,16 @@ fn strip_transpiler_attributes(source: &str) -> String {
     out
 }
 
+/// Struct the script's methods hang off; `Script` when none is named.
+fn parse_script_ty(source: &str, stripped_source: &str) -> String {
+    parse_marked_struct_name(source, "@Script")
+        .or_else(|| parse_attributed_struct_name(source, "script"))
+        .or_else(|| parse_script_macro_target(source, "lifecycle"))
+        .or_else(|| parse_script_macro_target(source, "methods"))
+        .or_else(|| parse_named_struct(stripped_source, "Script"))
+        .unwrap_or_else(|| "Script".to_string())
+}
+
 fn parse_marked_struct_name(source: &str, marker: &str) -> Option<String> {
     let lines: Vec<&str> = source.lines().collect();
     for i in 0..lines.len() {
@@ -338,6 +350,7 @@ fn is_unit_struct(source: &str, struct_name: &str) -> bool {
 struct ScriptField {
     name: String,
     ty: String,
+    attributes: Vec<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -0,0 +1,184 @@
+/// Public surface of one transpiled script, as `perro_cli doc` prints it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScriptDoc {
+    /// `res://` path of the script source.
+    pub path: String,
+    /// `//!` lines at the top of the file.
+    pub summary: Vec<String>,
+    /// State fields; reachable through `get_var!` / `set_var!` and scene `script_vars`.
+    pub vars: Vec<ScriptDocVar>,
+    /// Methods `call_method!` dispatches, sorted by name.
+    pub methods: Vec<ScriptDocMethod>,
+    /// `signal!("..")` names passed to `signal_emit!`, sorted.
+    pub signals: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScriptDocVar {
+    pub name: String,
+    pub ty: String,
+    /// `#[default = ..]` expression, as written.
+    pub default: Option<String>,
+    pub docs: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScriptDocMethod {
+    pub name: String,
+    /// `(name, type)` after `&self` + ctx; empty for `params: &[Variant]`.
+    pub params: Vec<(String, String)>,
+    pub takes_raw_params: bool,
+    pub return_ty: Option<String>,
+    pub docs: Vec<String>,
+}
+
+/// Docs for every transpiled script under `res/`, sorted by path. Helper
+/// modules and hand-written `ScriptBehavior` impls are skipped.
+pub fn collect_script_docs(project_root: &Path) -> Result<Vec<ScriptDoc>, CompilerError> {
+    let res_dir = project_root.join("res");
+    let mut docs = Vec::new();
+    if res_dir.exists() {
+        walk_dir(&res_dir, &mut |path| {
+            if path.extension().and_then(|e| e.to_str()) != Some("rs") {
+                return Ok(());
+            }
+            let Ok(rel) = path.strip_prefix(&res_dir) else {
+                return Ok(());
+            };
+            let res_path = format!("res://{}", rel.to_string_lossy().replace('\\', "/"));
+            let source = fs::read_to_string(path)?;
+            docs.extend(script_doc(&res_path, &source));
+            Ok(())
+        })?;
+    }
+    docs.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(docs)
+}
+
+fn script_doc(res_path: &str, source: &str) -> Option<ScriptDoc> {
+    let fields = script_exposed_fields(source)?;
+    let script_ty = parse_script_ty(source, &strip_transpiler_attributes(source));
+    let comments = DocComments::scan(source);
+    let vars = fields
+        .into_iter()
+        .map(|field| {
+            let (docs, default) = comments
+                .fields
+                .get(&field.name)
+                .cloned()
+                .unwrap_or_default();
+            ScriptDocVar {
+                name: field.name,
+                ty: field.ty,
+                default,
+                docs,
+            }
+        })
+        .collect();
+    let methods = parse_inherent_methods(source, &script_ty)
+        .into_iter()
+        .map(|method| ScriptDocMethod {
+            docs: comments.fns.get(&method.name).cloned().unwrap_or_default(),
+            name: method.name,
+            params: method
+                .params
+                .into_iter()
+                .map(|param| (param.name, param.ty))
+                .collect(),
+            takes_raw_params: method.takes_raw_params,
+            return_ty: method.return_ty,
+        })
+        .collect();
+    Some(ScriptDoc {
+        path: res_path.to_string(),
+        summary: comments.summary,
+        vars,
+        methods,
+        signals: emitted_signals(source),
+    })
+}
+
+/// `///` blocks keyed by the field or fn they sit on. Attributes between the
+/// docs and the item are skipped; `#[default = ..]` is kept for fields.
+#[derive(Default)]
+struct DocComments {
+    summary: Vec<String>,
+    fields: HashMap<String, (Vec<String>, Option<String>)>,
+    fns: HashMap<String, Vec<String>>,
+}
+
+impl DocComments {
+    fn scan(source: &str) -> Self {
+        let mut out = Self::default();
+        let mut docs = Vec::<String>::new();
+        let mut default = None;
+        let mut in_header = true;
+        for line in source.lines() {
+            let line = line.trim();
+            if in_header && let Some(text) = line.strip_prefix("//!") {
+                out.summary.push(text.trim().to_string());
+                continue;
+            }
+            in_header &= line.is_empty();
+            if let Some(text) = line.strip_prefix("///") {
+                // `///@State` style markers are transpiler input, not docs
+                if !text.starts_with('@') {
+                    docs.push(text.trim().to_string());
+                }
+                continue;
+            }
+            if line.starts_with("#[") {
+                if let Some(expr) = line
+                    .strip_prefix("#[default")
+                    .and_then(|rest| rest.trim_start().strip_prefix('='))
+                    .and_then(|rest| rest.trim().strip_suffix(']'))
+                {
+                    default = Some(expr.trim().to_string());
+                }
+                continue;
+            }
+            let item = line.trim_start_matches("pub ").trim_start();
+            if let Some(rest) = item.strip_prefix("fn ") {
+                let name = rest
+                    .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .next()
+                    .unwrap_or_default();
+                if !name.is_empty() {
+                    out.fns
+                        .entry(name.to_string())
+                        .or_insert_with(|| docs.clone());
+                }
+            } else if let Some(field) = parse_field_line(line) {
+                out.fields
+                    .entry(field.name)
+                    .or_insert_with(|| (docs.clone(), default.clone()));
+            }
+            docs.clear();
+            default = None;
+        }
+        out
+    }
+}
+
+fn emitted_signals(source: &str) -> Vec<String> {
+    let mut signals = Vec::new();
+    for (start, _) in source.match_indices("signal_emit!") {
+        let Some(open) = source[start..].find('(').map(|offset| start + offset) else {
+            continue;
+        };
+        let Some(close) = find_matching_delim(source, open, '(', ')') else {
+            continue;
+        };
+        let call = &source[open..close];
+        if let Some(name) = call
+            .split_once("signal!(\"")
+            .and_then(|(_, rest)| rest.split_once('"'))
+            .map(|(name, _)| name)
+        {
+            signals.push(name.to_string());
+        }
+    }
+    signals.sort();
+    signals.dedup();
+    signals
+}
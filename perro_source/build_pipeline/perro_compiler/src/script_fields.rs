@@ -12,6 +12,7 @@ fn parse_struct_fields(source: &str, struct_name: &str) -> Vec<ScriptField> {
     };
 
     let mut fields = Vec::new();
+    let mut pending_attributes = Vec::new();
     let mut depth = 0_i32;
     let mut opened = false;
     let mut i = start;
@@ -38,10 +39,13 @@ fn parse_struct_fields(source: &str, struct_name: &str) -> Vec<ScriptField> {
             continue;
         }
 
-        if depth == 1
-            && let Some(field) = parse_field_line(line)
-        {
-            fields.push(field);
+        if depth == 1 {
+            if let Some(attributes) = parse_field_attr_line(line) {
+                pending_attributes.extend(attributes);
+            } else if let Some(mut field) = parse_field_line(line) {
+                field.attributes = std::mem::take(&mut pending_attributes);
+                fields.push(field);
+            }
         }
         depth += brace_delta(line);
         if depth <= 0 {
@@ -90,9 +94,34 @@ fn parse_field_line(line: &str) -> Option<ScriptField> {
     Some(ScriptField {
         name: name.to_string(),
         ty: ty.to_string(),
+        attributes: Vec::new(),
     })
 }
 
+/// Parse `#[attr("save")]` / `#[attr("save", "replicated")]` field tags.
+fn parse_field_attr_line(line: &str) -> Option<Vec<String>> {
+    let inner = line
+        .trim()
+        .strip_prefix("#[")?
+        .strip_suffix(']')?
+        .trim()
+        .strip_prefix("attr")?
+        .trim()
+        .strip_prefix('(')?
+        .strip_suffix(')')?;
+    let attributes = inner
+        .split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            part.strip_prefix('"')
+                .and_then(|part| part.strip_suffix('"'))
+                .map(str::to_string)
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(attributes)
+}
+
 fn is_ident(s: &str) -> bool {
     let mut chars = s.chars();
     let Some(first) = chars.next() else {
@@ -185,6 +214,84 @@ fn generate_member_consts(
     out
 }
 
+fn generate_attribute_methods(fields: &[ScriptField]) -> String {
+    let mut attribute_names: Vec<&str> = Vec::new();
+    for field in fields {
+        for attribute in &field.attributes {
+            if !attribute_names.contains(&attribute.as_str()) {
+                attribute_names.push(attribute);
+            }
+        }
+    }
+    if attribute_names.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::new();
+    out.push_str(
+        "\n    fn attributes_of(&self, member: ScriptMemberID) -> &'static [&'static str] {\n",
+    );
+    out.push_str("        match member {\n");
+    for field in fields.iter().filter(|field| !field.attributes.is_empty()) {
+        let const_name = member_const_name(&field.name);
+        let tags = field
+            .attributes
+            .iter()
+            .map(|attribute| format!("{attribute:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        out.push_str(&format!("            {const_name} => &[{tags}],\n"));
+    }
+    out.push_str("            _ => &[],\n");
+    out.push_str("        }\n    }\n\n");
+
+    out.push_str("    fn members_with(&self, attribute: &str) -> &'static [ScriptMemberID] {\n");
+    out.push_str("        match attribute {\n");
+    for attribute in attribute_names {
+        let members = fields
+            .iter()
+            .filter(|field| field.attributes.iter().any(|a| a == attribute))
+            .map(|field| member_const_name(&field.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        out.push_str(&format!("            {attribute:?} => &[{members}],\n"));
+    }
+    out.push_str("            _ => &[],\n");
+    out.push_str("        }\n    }\n");
+    out
+}
+
+/// Detect a top-level `const STATE_VERSION: u32 = N;` in script source.
+fn declares_state_version(source: &str) -> bool {
+    source.lines().any(|line| {
+        let line = strip_line_comment(line).trim();
+        let line = line.strip_prefix("pub ").unwrap_or(line);
+        line.strip_prefix("const ")
+            .and_then(|rest| rest.trim_start().strip_prefix("STATE_VERSION"))
+            .is_some_and(|rest| rest.trim_start().starts_with(':'))
+    })
+}
+
+fn generate_state_layout_methods(fields: &[ScriptField], has_state_version: bool) -> String {
+    let mut out = String::new();
+    if has_state_version {
+        out.push_str("\n    fn state_version(&self) -> u32 {\n");
+        out.push_str("        STATE_VERSION\n");
+        out.push_str("    }\n");
+    }
+    if !fields.is_empty() {
+        let names = fields
+            .iter()
+            .map(|field| format!("{:?}", field.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        out.push_str("\n    fn state_fields(&self) -> &'static [&'static str] {\n");
+        out.push_str(&format!("        &[{names}]\n"));
+        out.push_str("    }\n");
+    }
+    out
+}
+
 fn nested_member_const_name(member: &str) -> String {
     format!(
         "{}_{}",
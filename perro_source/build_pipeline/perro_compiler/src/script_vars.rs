@@ -0,0 +1,108 @@
+/// State fields the generated `set_var` handles for `source`; `None` when the
+/// script is passed through untouched (hand-written `ScriptBehavior`, no state).
+fn script_exposed_fields(source: &str) -> Option<Vec<ScriptField>> {
+    if source.contains("impl ScriptBehavior") {
+        return None;
+    }
+    let state_ty = parse_marked_struct_name(source, "@State")
+        .or_else(|| parse_attributed_struct_name(source, "state"));
+    let Some(state_ty) = state_ty else {
+        let transpiled = has_script_macro_invocation(source, "lifecycle")
+            || has_script_macro_invocation(source, "methods");
+        return transpiled.then(Vec::new);
+    };
+    Some(supported_fields(&parse_struct_fields(source, &state_ty)))
+}
+
+/// Scene `script_vars` / `vars { .. }` go through the script's generated
+/// `set_var`, which drops unknown names and values that do not parse. Catch
+/// both at build time against the exposed fields of each synced script.
+fn validate_scene_script_vars(
+    source_dir: &Path,
+    script_path_prefix: &str,
+    exposed: &HashMap<String, Vec<ScriptField>>,
+) -> Result<(), CompilerError> {
+    if exposed.is_empty() || !source_dir.exists() {
+        return Ok(());
+    }
+    let mut scene_paths = Vec::<PathBuf>::new();
+    walk_dir(source_dir, &mut |path| {
+        if path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case(perro_asset_formats::source_ext::SCENE))
+        {
+            scene_paths.push(path.to_path_buf());
+        }
+        Ok(())
+    })?;
+    scene_paths.sort();
+
+    let load_base = |path: &str| {
+        let rel = path
+            .strip_prefix(script_path_prefix)
+            .ok_or_else(|| format!("base scene `{path}` is not under `{script_path_prefix}`"))?;
+        fs::read_to_string(source_dir.join(rel))
+            .map_err(|err| format!("failed to read `{path}`: {err}"))
+    };
+    for path in scene_paths {
+        let src = fs::read_to_string(&path)?;
+        // parse errors surface in the static scene pass w/ full context
+        let Ok(scene) = perro_scene::Parser::new(&src)
+            .with_scene_loader(&load_base)
+            .try_parse_scene()
+        else {
+            continue;
+        };
+        let rel = path
+            .strip_prefix(source_dir)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        for node in scene.nodes.iter() {
+            let Some(script) = node.script.as_deref() else {
+                continue;
+            };
+            let Some(fields) = exposed.get(script) else {
+                continue;
+            };
+            for (name, value) in node.script_vars.iter() {
+                let name = name.as_ref();
+                let node_key = scene.key_name_or_id(node.key);
+                let Some(field) = fields.iter().find(|field| field.name == name) else {
+                    return Err(CompilerError::SceneParse(format!(
+                        "{script_path_prefix}{rel}: node `{node_key}` sets script var `{name}`, \
+                         which `{script}` does not expose"
+                    )));
+                };
+                if !scene_var_fits(&normalize_type(&field.ty), value) {
+                    return Err(CompilerError::SceneParse(format!(
+                        "{script_path_prefix}{rel}: node `{node_key}` sets script var `{name}` to \
+                         {value:?}, which does not fit `{}`",
+                        field.ty
+                    )));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Only scalar field types are checked; everything else is left to the
+/// runtime `Variant` parse.
+fn scene_var_fits(ty: &str, value: &perro_scene::SceneValue) -> bool {
+    use perro_scene::SceneValue;
+    match ty {
+        "bool" => matches!(value, SceneValue::Bool(_)),
+        "f32" | "f64" => matches!(value, SceneValue::F32(_) | SceneValue::I32(_)),
+        "i8" | "i16" | "i32" | "i64" | "isize" | "u8" | "u16" | "u32" | "u64" | "usize" => {
+            match value {
+                SceneValue::I32(_) => true,
+                SceneValue::F32(v) => v.fract() == 0.0,
+                _ => false,
+            }
+        }
+        "String" | "&'staticstr" | "Cow<'static,str>" => matches!(value, SceneValue::Str(_)),
+        _ => true,
+    }
+}
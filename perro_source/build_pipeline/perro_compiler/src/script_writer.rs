@@ -1,5 +1,6 @@
 fn write_scripts_lib(
     scripts_src: &Path,
+    source_dir: &Path,
     copied: &[String],
     registrable: &[String],
     script_path_prefix: &str,
@@ -20,6 +21,17 @@ fn write_scripts_lib(
         out.push_str(&format!("pub mod {module};\n\n"));
     }
 
+    // rust-analyzer gives no completion inside `include!`d files; mount the
+    // original sources as plain modules so editing res/*.rs sees engine APIs.
+    let lib_path = scripts_src.join("lib.rs");
+    for rel in copied {
+        let module = module_name_from_rel(rel);
+        let source = relative_include_path(&lib_path, &source_dir.join(rel));
+        out.push_str("#[cfg(rust_analyzer)]\n");
+        out.push_str(&format!("#[path = \"{}\"]\n", escape_str(&source)));
+        out.push_str(&format!("mod ra_{module};\n\n"));
+    }
+
     if !copied.is_empty() {
         let mut short_name_counts = BTreeMap::<String, usize>::new();
         for rel in copied {
@@ -10,7 +10,18 @@ fn validate_dlc_name(dlc_name: &str) -> Result<(), CompilerError> {
         .map_err(|err| CompilerError::SceneParse(format!("invalid dlc name `{dlc_name}`: {err}")))
 }
 
+/// `copied` = every synced script; `registered` = how many export a ctor and
+/// land in `SCRIPT_REGISTRY` (the boot integrity check compares against it).
+struct SyncedScripts {
+    copied: Vec<String>,
+    registered: usize,
+}
+
 pub fn sync_scripts(project_root: &Path) -> Result<Vec<String>, CompilerError> {
+    sync_project_scripts(project_root).map(|synced| synced.copied)
+}
+
+fn sync_project_scripts(project_root: &Path) -> Result<SyncedScripts, CompilerError> {
     let res_dir = project_root.join("res");
     let scripts_src = project_root.join(".perro").join("scripts").join("src");
     sync_scripts_from_source(&res_dir, &scripts_src, "res://")
@@ -26,19 +37,20 @@ pub fn sync_dlc_scripts(project_root: &Path, dlc_name: &str) -> Result<Vec<Strin
         .join("scripts")
         .join("src");
     let prefix = format!("dlc://{dlc_name}/");
-    sync_scripts_from_source(&dlc_root, &scripts_src, &prefix)
+    sync_scripts_from_source(&dlc_root, &scripts_src, &prefix).map(|synced| synced.copied)
 }
 
 fn sync_scripts_from_source(
     source_dir: &Path,
     scripts_src: &Path,
     script_path_prefix: &str,
-) -> Result<Vec<String>, CompilerError> {
+) -> Result<SyncedScripts, CompilerError> {
     fs::create_dir_all(scripts_src)?;
 
     let mut copied = Vec::<String>::new();
     let mut registrable = Vec::<String>::new();
     let mut generated_rel_paths = HashSet::<String>::new();
+    let mut exposed = HashMap::<String, Vec<ScriptField>>::new();
     if source_dir.exists() {
         walk_dir(source_dir, &mut |path| {
             if path.extension().and_then(|e| e.to_str()) != Some("rs") {
@@ -66,6 +78,9 @@ fn sync_scripts_from_source(
             if transpiled_exports_script_ctor(&transformed) {
                 registrable.push(rel_norm.clone());
             }
+            if let Some(fields) = script_exposed_fields(&source) {
+                exposed.insert(format!("{script_path_prefix}{rel_norm}"), fields);
+            }
             write_string_if_changed(&dst, &transformed)?;
             generated_rel_paths.insert(generated_rel);
             copied.push(rel_norm);
@@ -73,11 +88,22 @@ fn sync_scripts_from_source(
         })?;
     }
 
+    validate_scene_script_vars(source_dir, script_path_prefix, &exposed)?;
+
     copied.sort();
     registrable.sort();
     let _ = remove_stale_generated_scripts(scripts_src, &generated_rel_paths)?;
-    let _ = write_scripts_lib(scripts_src, &copied, &registrable, script_path_prefix)?;
-    Ok(copied)
+    let _ = write_scripts_lib(
+        scripts_src,
+        source_dir,
+        &copied,
+        &registrable,
+        script_path_prefix,
+    )?;
+    Ok(SyncedScripts {
+        copied,
+        registered: registrable.len(),
+    })
 }
 
 pub fn compile_scripts(project_root: &Path) -> Result<Vec<String>, CompilerError> {
@@ -99,8 +125,10 @@ pub fn compile_scripts_with_profile_and_demo(
     ensure_source_overrides(project_root)?;
     let cfg = perro_project::load_project_toml_with_demo(project_root, demo)
         .map_err(|e| CompilerError::SceneParse(format!("failed to load project.toml: {e}")))?;
+    // Dev runs read res/ live, so imported outputs must exist before launch.
+    run_project_imports(project_root, &cfg, false)?;
     let copied = {
-        let _exclude_guard = perro_io::walkdir::push_path_exclusions(cfg.demo.relative_patterns());
+        let _exclude_guard = perro_io::walkdir::push_path_exclusions(cfg.excluded_asset_patterns());
         sync_scripts(project_root)?
     };
     let scripts_crate = project_root.join(".perro").join("scripts");
@@ -450,7 +478,7 @@ fn write_dlc_scripts_manifest(
             .join("perro_runtime"),
     );
     let mut manifest = format!(
-        "[workspace]\n\n[package]\nname = \"{crate_name}\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n[lib]\ncrate-type = [\"cdylib\", \"rlib\"]\n\n[dependencies]\nperro_api = {{ path = \"{perro_api_path}\" }}\nperro_runtime = {{ path = \"{perro_runtime_path}\" }}\n\n[features]\ndynamic-scripts = []\nperro-demo = []\nperro-spec = [\"perro_api/spec\"]\nsteamworks = [\"perro_api/steamworks\", \"perro_runtime/steamworks\"]\n"
+        "[workspace]\n\n[package]\nname = \"{crate_name}\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n[lib]\ncrate-type = [\"cdylib\", \"rlib\"]\n\n[dependencies]\nperro_api = {{ path = \"{perro_api_path}\" }}\nperro_runtime = {{ path = \"{perro_runtime_path}\" }}\n\n[features]\ndynamic-scripts = []\nperro-demo = []\nperro-spec = [\"perro_api/spec\"]\nsteamworks = [\"perro_api/steamworks\", \"perro_runtime/steamworks\"]\n\n[lints.rust]\nunexpected_cfgs = {{ level = \"warn\", check-cfg = [\"cfg(rust_analyzer)\"] }}\n"
     );
     let extra_deps = read_extra_script_deps(project_root)?;
     if !extra_deps.is_empty() {
@@ -2,16 +2,16 @@
 mod tests {
     use super::{
         ProjectBuildOptions, ProjectBuildTarget, ScriptMethodParam, ScriptsBuildProfile,
-        android_apk_artifact_path, checked_res_relative_path, compile_scripts_with_profile,
-        emit_static_steam_app_id_fn, emit_web_route_html_files, export_project_android_bundle,
-        generate_call_param_binding, generate_dlc_static_modules, generate_embedded_entry_files,
-        generate_perro_assets, generate_project_static_modules, module_name_from_rel,
-        module_short_name_from_rel, native_output_artifact_name, native_output_folder_name,
-        normalize_cargo_output_paths, sweep_unknown_embedded_entries,
-        steam_runtime_library_name, sync_android_project_manifest, sync_dlc_scripts, sync_scripts,
-        target_binary_name, target_slug_from_triple, transpile_frontend_script,
-        transpiled_exports_script_ctor, validate_native_target_triple, web_route_html_path,
-        write_scripts_lib,
+        android_apk_artifact_path, checked_res_relative_path, collect_script_docs,
+        compile_scripts_with_profile, emit_build_info_block, emit_static_steam_app_id_fn,
+        emit_web_route_html_files, export_project_android_bundle, generate_call_param_binding,
+        generate_dlc_static_modules, generate_embedded_entry_files, generate_perro_assets,
+        generate_project_static_modules, module_name_from_rel, module_short_name_from_rel,
+        native_output_artifact_name, native_output_folder_name, normalize_cargo_output_paths,
+        steam_runtime_library_name, sweep_unknown_embedded_entries, sync_android_project_manifest,
+        sync_dlc_scripts, sync_scripts, target_binary_name, target_slug_from_triple,
+        transpile_frontend_script, transpiled_exports_script_ctor, validate_native_target_triple,
+        web_route_html_path, write_scripts_lib,
     };
     use perro_project::{
         ensure_project_layout, ensure_project_scaffold, ensure_project_toml,
@@ -365,6 +365,21 @@ lifecycle!({});
         assert_generated_script_compiles(source, &transpiled);
     }
 
+    #[test]
+    fn build_info_block_embeds_version_and_profile() {
+        let root = unique_temp_dir("perro_compiler_build_info");
+        std::fs::create_dir_all(&root).expect("root dir");
+        let mut cfg = perro_project::ProjectConfig::default_for_name("Build Info");
+        cfg.metadata.version = Some("1.2.3".to_string());
+        let mut options = ProjectBuildOptions::new(false, false);
+        options.release = false;
+
+        let block = emit_build_info_block(&root, &cfg, options);
+        assert!(block.starts_with("  build: perro_app::entry::BuildInfo::embedded(\"1.2.3\", "));
+        assert!(block.contains("perro_app::entry::BuildProfile::Debug"));
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
     #[test]
     #[ignore = "spawns nested cargo check; run in CI slow job via --ignored"]
     fn generated_project_crate_compiles_after_static_embed() {
@@ -377,11 +392,14 @@ lifecycle!({});
 
         let cfg = load_project_toml(&root).expect("load project toml");
         sweep_unknown_embedded_entries(&root).expect("sweep embedded");
-        sync_scripts(&root).expect("sync scripts");
+        let scripts = super::sync_project_scripts(&root).expect("sync scripts");
         generate_project_static_modules(&root, &cfg).expect("generate static modules");
         perro_static_pipeline::write_static_mod_rs(&root).expect("write static mod");
         generate_embedded_entry_files(&root).expect("generate embedded main");
         generate_perro_assets(&root).expect("generate assets");
+        super::generate_perro_assets_integrity(&root, &cfg.integrity, scripts.registered)
+            .expect("generate integrity manifest");
+        super::generate_perro_assets_key(&root, None).expect("generate archive key");
         assert_static_module_fixture_refs(&root);
         assert_static_scene_fixture_node_types(&root);
         assert_generated_native_main_hides_windows_console(&root);
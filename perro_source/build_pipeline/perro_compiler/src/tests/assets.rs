@@ -102,6 +102,34 @@ mod assets {
         std::fs::remove_dir_all(&root).expect("cleanup");
     }
 
+    #[test]
+    fn pack_encrypt_key_persists_per_project() {
+        let root = unique_temp_dir("perro_compiler_archive_key");
+        let mut pack = perro_project::PackConfig::default();
+        assert_eq!(
+            super::super::resolve_archive_key(&root, &pack).expect("encrypt off"),
+            None
+        );
+        if std::env::var_os(super::super::ASSETS_KEY_ENV).is_none() {
+            pack.encrypt = true;
+            let key = super::super::resolve_archive_key(&root, &pack)
+                .expect("generate key")
+                .expect("encrypt on");
+            assert_eq!(
+                super::super::resolve_archive_key(&root, &pack).expect("reload key"),
+                Some(key)
+            );
+        }
+        assert_eq!(
+            super::super::parse_archive_key(&"0a".repeat(32)),
+            Some([0x0a; 32])
+        );
+        assert_eq!(super::super::parse_archive_key("0a0b"), None);
+        assert_eq!(super::super::parse_archive_key(&"zz".repeat(32)), None);
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
     #[test]
     fn web_export_paths_reject_root_escape() {
         let output = std::path::Path::new("web-output");
@@ -131,4 +159,62 @@ mod assets {
             "scripts_personality_module"
         );
     }
+
+    #[test]
+    fn unused_asset_prune_keeps_reachable_scripts_and_includes() {
+        let root = unique_temp_dir("perro_compiler_prune_unused");
+        let res = root.join("res");
+        for dir in ["art", "data", "levels", "scripts"] {
+            std::fs::create_dir_all(res.join(dir)).expect("res dir");
+        }
+        std::fs::write(
+            root.join("project.toml"),
+            r#"[project]
+name = "Prune"
+main_scene = "res://main.scn"
+
+[prune]
+unused = true
+include = ["res://levels/**"]
+"#,
+        )
+        .expect("project toml");
+        std::fs::write(
+            res.join("main.scn"),
+            r#"
+    [main]
+    script = "res://scripts/main.rs"
+    [Sprite2D]
+        texture = "res://art/player.png"
+    [/Sprite2D]
+    [/main]
+    "#,
+        )
+        .expect("main scene");
+        std::fs::write(
+            res.join("scripts/main.rs"),
+            "const ITEMS: &str = \"res://data/items.json\";\n",
+        )
+        .expect("script");
+        for file in [
+            "art/player.png",
+            "art/old_player.png",
+            "data/items.json",
+            "data/unused.json",
+            "levels/level_1.json",
+        ] {
+            std::fs::write(res.join(file), b"x").expect("asset");
+        }
+
+        let cfg = load_project_toml(&root).expect("load project toml");
+        let pruned = super::super::plan_unused_asset_prune(&root, &cfg).expect("plan prune");
+        let mut paths = pruned
+            .iter()
+            .map(|asset| asset.rel_path.as_str())
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(paths, vec!["art/old_player.png", "data/unused.json"]);
+
+        std::fs::remove_dir_all(root).expect("cleanup prune fixture");
+    }
 }
@@ -175,6 +175,23 @@ mod locking_paths {
         assert!(validate_native_target_triple("windows").is_err());
     }
 
+    #[test]
+    fn server_target_runs_headless_on_native_platform() {
+        let native = ProjectBuildOptions::new(false, false);
+        assert!(!native.runs_headless());
+        assert!(native.with_headless(true).runs_headless());
+
+        let server = native.with_target(ProjectBuildTarget::Server);
+        assert!(server.runs_headless());
+        assert_eq!(server.project_platform(), native.project_platform());
+        assert_eq!(
+            server
+                .with_native_target(Some("x86_64-unknown-linux-gnu"))
+                .project_platform(),
+            perro_project::ProjectPlatform::Linux
+        );
+    }
+
     #[test]
     fn steam_runtime_name_uses_target_arch() {
         assert_eq!(
@@ -542,6 +559,7 @@ mod locking_paths {
         let src = root.join("src");
         write_scripts_lib(
             &src,
+            &root.join("res"),
             &["player.rs".to_string()],
             &["player.rs".to_string()],
             "res://",
@@ -555,6 +573,11 @@ mod locking_paths {
         assert!(generated.contains("#[cfg(feature = \"dynamic-scripts\")]"));
         assert!(generated.contains("DYNAMIC_SCRIPT_REGISTRY"));
         assert!(generated.contains("perro_create_script_dynamic as DynamicScriptConstructor"));
+        assert!(
+            generated.contains(
+                "#[cfg(rust_analyzer)]\n#[path = \"../res/player.rs\"]\nmod ra_player_rs;"
+            )
+        );
 
         std::fs::remove_dir_all(root).expect("remove script ABI fixture");
     }
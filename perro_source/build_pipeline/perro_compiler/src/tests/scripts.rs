@@ -48,6 +48,28 @@ mod scripts {
     }
 
 
+    #[test]
+    fn on_input_lifecycle_sets_input_flag() {
+        let source = r#"
+    use perro_api::prelude::*;
+
+    lifecycle!({
+    fn on_input(
+        &self,
+        ctx: &mut ScriptContext<'_, API>,
+        event: &Variant,
+    ) {
+        let _ = (ctx.id, event);
+    }
+    });
+    "#;
+
+        let transpiled = transpile_frontend_script(source, "res://scripts/input_only.rs");
+        assert!(transpiled.contains("ScriptFlags::NONE | ScriptFlags::HAS_INPUT"));
+        assert!(!transpiled.contains("ScriptFlags::HAS_UPDATE"));
+    }
+
+
     #[test]
     fn methods_only_script_exports_ctor_with_implicit_script_and_empty_state() {
         let source = r#"
@@ -112,6 +134,95 @@ mod scripts {
         assert!(transpiled.contains("ScriptMemberID::from_string(full.as_str())"));
     }
 
+    #[test]
+    fn transpiled_state_emits_field_attribute_lookups() {
+        let source = r#"
+    use perro_api::prelude::*;
+
+    #[State]
+    pub struct SaveState {
+    #[attr("save")]
+    #[attr("replicated")]
+    #[default = 100]
+    pub health: i32,
+    #[attr("save", "editor_readonly")]
+    pub name: String,
+    pub scratch: f32,
+    }
+    "#;
+
+        let transpiled = transpile_frontend_script(source, "res://scripts/save_state.rs");
+        assert!(transpiled.contains(
+            "fn attributes_of(&self, member: ScriptMemberID) -> &'static [&'static str]"
+        ));
+        assert!(transpiled.contains("__PERRO_VAR_HEALTH => &[\"save\", \"replicated\"],"));
+        assert!(transpiled.contains("__PERRO_VAR_NAME => &[\"save\", \"editor_readonly\"],"));
+        assert!(!transpiled.contains("__PERRO_VAR_SCRATCH => &["));
+        assert!(transpiled.contains("\"save\" => &[__PERRO_VAR_HEALTH, __PERRO_VAR_NAME],"));
+        assert!(transpiled.contains("\"replicated\" => &[__PERRO_VAR_HEALTH],"));
+        assert!(transpiled.contains("\"editor_readonly\" => &[__PERRO_VAR_NAME],"));
+    }
+
+    #[test]
+    fn transpiled_state_without_field_attributes_keeps_default_lookups() {
+        let source = r#"
+    use perro_api::prelude::*;
+
+    #[State]
+    pub struct PlainState {
+    pub health: i32,
+    }
+    "#;
+
+        let transpiled = transpile_frontend_script(source, "res://scripts/plain_state.rs");
+        assert!(!transpiled.contains("fn attributes_of"));
+        assert!(!transpiled.contains("fn members_with"));
+    }
+
+    #[test]
+    fn transpiled_state_emits_version_and_field_list() {
+        let source = r#"
+    use perro_api::prelude::*;
+
+    const STATE_VERSION: u32 = 3;
+
+    #[State]
+    pub struct SaveState {
+    pub hp: i32,
+    pub name: String,
+    }
+
+    lifecycle!({
+    fn migrate_state(&self, old: Variant, old_version: u32) -> Variant {
+        old
+    }
+    });
+    "#;
+
+        let transpiled = transpile_frontend_script(source, "res://scripts/save_state.rs");
+        assert!(transpiled.contains("fn state_version(&self) -> u32 {\n        STATE_VERSION\n"));
+        assert!(transpiled.contains(
+            "fn state_fields(&self) -> &'static [&'static str] {\n        &[\"hp\", \"name\"]\n"
+        ));
+    }
+
+    #[test]
+    fn transpiled_state_without_version_const_keeps_default_version() {
+        let source = r#"
+    use perro_api::prelude::*;
+
+    // const STATE_VERSION: u32 = 2;
+    #[State]
+    pub struct PlainState {
+    pub health: i32,
+    }
+    "#;
+
+        let transpiled = transpile_frontend_script(source, "res://scripts/plain_state.rs");
+        assert!(!transpiled.contains("fn state_version"));
+        assert!(transpiled.contains("fn state_fields"));
+    }
+
 
     #[test]
     fn dlc_static_generators_keep_thread_local_pack_paths() {
@@ -173,4 +284,105 @@ mod scripts {
         let _ = std::fs::remove_dir_all(root);
     }
 
+    #[test]
+    fn sync_scripts_checks_scene_vars_against_exposed_state() {
+        let root = unique_temp_dir("perro_compiler_scene_vars");
+        let res = root.join("res");
+        std::fs::create_dir_all(res.join("scripts")).expect("create res");
+        std::fs::write(
+            res.join("scripts/enemy.rs"),
+            "#[State]\npub struct EnemyState {\n    pub speed: f32,\n    pub hp: i32,\n}\n",
+        )
+        .expect("write script");
+        let scene = |vars: &str| {
+            format!(
+                "[enemy]\nscript = \"res://scripts/enemy.rs\"\nvars {{ {vars} }}\n[Node/]\n[/enemy]\n"
+            )
+        };
+
+        std::fs::write(res.join("main.scn"), scene("speed = 240.0, hp = 3")).expect("write scene");
+        assert!(sync_scripts(&root).is_ok());
+
+        std::fs::write(res.join("main.scn"), scene("sped = 240.0")).expect("write scene");
+        let err = sync_scripts(&root).expect_err("unknown var");
+        assert!(err.to_string().contains("`sped`"), "{err}");
+
+        std::fs::write(res.join("main.scn"), scene("hp = 2.5")).expect("write scene");
+        let err = sync_scripts(&root).expect_err("fractional int");
+        assert!(err.to_string().contains("`i32`"), "{err}");
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn script_docs_list_vars_methods_and_signals() {
+        let root = unique_temp_dir("perro_compiler_script_docs");
+        let res = root.join("res");
+        std::fs::create_dir_all(res.join("scripts")).expect("create res");
+        std::fs::write(
+            res.join("scripts/player.rs"),
+            r#"//! Player pawn.
+use perro_api::prelude::*;
+
+#[State]
+struct PlayerState {
+    /// Points this run.
+    #[default = 0]
+    pub score: i32,
+    pub icon: NodeID,
+}
+
+lifecycle!({});
+
+methods!({
+    /// Adds `amount`, returns the new score.
+    fn add_score(
+        &self,
+        ctx: &mut ScriptContext<'_, API>,
+        amount: i32,
+    ) -> i32 {
+        signal_emit!(
+            ctx.run,
+            signal!("score_changed"),
+            params![amount]
+        );
+        amount
+    }
+
+    fn reset(&self, ctx: &mut ScriptContext<'_, API>, params: &[Variant]) {}
+});
+"#,
+        )
+        .expect("write script");
+        std::fs::write(
+            res.join("scripts/util.rs"),
+            "pub fn clamp01(v: f32) -> f32 { v }\n",
+        )
+        .expect("write helper");
+
+        let docs = collect_script_docs(&root).expect("docs");
+        assert_eq!(docs.len(), 1, "helper modules have no script surface");
+        let doc = &docs[0];
+        assert_eq!(doc.path, "res://scripts/player.rs");
+        assert_eq!(doc.summary, vec!["Player pawn."]);
+        assert_eq!(doc.vars.len(), 2);
+        assert_eq!(doc.vars[0].name, "score");
+        assert_eq!(doc.vars[0].ty, "i32");
+        assert_eq!(doc.vars[0].default.as_deref(), Some("0"));
+        assert_eq!(doc.vars[0].docs, vec!["Points this run."]);
+        assert!(doc.vars[1].docs.is_empty() && doc.vars[1].default.is_none());
+        let names = doc
+            .methods
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["add_score", "reset"]);
+        let add = &doc.methods[0];
+        assert_eq!(add.params, vec![("amount".to_string(), "i32".to_string())]);
+        assert_eq!(add.return_ty.as_deref(), Some("i32"));
+        assert_eq!(add.docs, vec!["Adds `amount`, returns the new score."]);
+        assert!(doc.methods[1].takes_raw_params);
+        assert_eq!(doc.signals, vec!["score_changed"]);
+        let _ = std::fs::remove_dir_all(root);
+    }
 }
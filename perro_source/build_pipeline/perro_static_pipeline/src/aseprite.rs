@@ -0,0 +1,774 @@
+//! Built-in `.aseprite` / `.ase` importer.
+//!
+//! `hero.aseprite` becomes `hero.png`, a sheet with one row per tag, and
+//! `hero.scn`, a single `AnimatedSprite2D` whose `animations` point into that
+//! sheet. Instance it with `root_of = "res://hero.scn"` or copy the node.
+//!
+//! Visible layers are flattened with normal blending; hidden, reference and
+//! tilemap layers are skipped. `AnimatedSprite2D` plays at a fixed fps per
+//! animation, so uneven frame durations are kept by repeating frames at the
+//! durations' common divisor, falling back to the mean when that would blow
+//! the sheet up.
+
+use crate::imports::scene_key;
+use crate::{ImportPlugin, ImportSource, ImportedAsset, StaticPipelineError};
+use std::fmt::Write as _;
+use std::io::Cursor;
+
+const HEADER_MAGIC: u16 = 0xA5E0;
+const FRAME_MAGIC: u16 = 0xF1FA;
+const CHUNK_OLD_PALETTE: u16 = 0x0004;
+const CHUNK_LAYER: u16 = 0x2004;
+const CHUNK_CEL: u16 = 0x2005;
+const CHUNK_TAGS: u16 = 0x2018;
+const CHUNK_PALETTE: u16 = 0x2019;
+const LAYER_VISIBLE: u16 = 1;
+const LAYER_REFERENCE: u16 = 64;
+const HEADER_LAYER_OPACITY_VALID: u32 = 1;
+/// Cap on repeated cells per tag before uneven durations fall back to mean fps.
+const MAX_EXPANDED_FRAMES: usize = 64;
+/// Decoded cel cap; a 16k x 16k RGBA canvas.
+const MAX_CEL_BYTES: usize = 16384 * 16384 * 4;
+
+pub struct AsepriteImportPlugin;
+
+impl ImportPlugin for AsepriteImportPlugin {
+    fn name(&self) -> &str {
+        "aseprite"
+    }
+
+    fn extensions(&self) -> Vec<&str> {
+        vec!["aseprite", "ase"]
+    }
+
+    fn import(&self, source: &ImportSource<'_>) -> Result<Vec<ImportedAsset>, StaticPipelineError> {
+        let sprite = AsepriteFile::parse(&source.read()?)?;
+        let stem = std::path::Path::new(source.rel_path)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("sprite");
+        let sheet_rel = source.sibling(&format!("{stem}.png"));
+        let (sheet, animations) = build_sheet(&sprite);
+        let mut png = Vec::new();
+        sheet.write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png)?;
+        let scene = sprite_frames_scene(
+            stem,
+            &format!("res://{sheet_rel}"),
+            (sprite.width, sprite.height),
+            &animations,
+        );
+        Ok(vec![
+            ImportedAsset {
+                rel_path: sheet_rel,
+                bytes: png,
+            },
+            ImportedAsset {
+                rel_path: source.sibling(&format!("{stem}.scn")),
+                bytes: scene.into_bytes(),
+            },
+        ])
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TagDirection {
+    Forward,
+    Reverse,
+    PingPong,
+    PingPongReverse,
+}
+
+#[derive(Clone, Debug)]
+struct Tag {
+    name: String,
+    from: usize,
+    to: usize,
+    direction: TagDirection,
+}
+
+#[derive(Clone, Debug)]
+struct Layer {
+    /// Own flag plus every parent group's.
+    visible: bool,
+    opacity: u8,
+    image: bool,
+}
+
+#[derive(Clone, Debug)]
+enum CelPixels {
+    Image { w: u32, h: u32, rgba: Vec<u8> },
+    Linked(usize),
+}
+
+#[derive(Clone, Debug)]
+struct Cel {
+    layer: usize,
+    x: i32,
+    y: i32,
+    opacity: u8,
+    z_index: i32,
+    pixels: CelPixels,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Frame {
+    duration_ms: u32,
+    cels: Vec<Cel>,
+}
+
+struct AsepriteFile {
+    width: u32,
+    height: u32,
+    layers: Vec<Layer>,
+    frames: Vec<Frame>,
+    tags: Vec<Tag>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct SheetAnimation {
+    name: String,
+    row: u32,
+    frame_count: u32,
+    fps: f32,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], StaticPipelineError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len());
+        let Some(end) = end else {
+            return Err(ase_error("unexpected end of file"));
+        };
+        let out = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(out)
+    }
+
+    fn u8(&mut self) -> Result<u8, StaticPipelineError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, StaticPipelineError> {
+        let b = self.take(2)?;
+        Ok(u16::from_le_bytes([b[0], b[1]]))
+    }
+
+    fn i16(&mut self) -> Result<i16, StaticPipelineError> {
+        Ok(self.u16()? as i16)
+    }
+
+    fn u32(&mut self) -> Result<u32, StaticPipelineError> {
+        let b = self.take(4)?;
+        Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn string(&mut self) -> Result<String, StaticPipelineError> {
+        let len = self.u16()? as usize;
+        Ok(String::from_utf8_lossy(self.take(len)?).into_owned())
+    }
+}
+
+fn ase_error(msg: &str) -> StaticPipelineError {
+    StaticPipelineError::SceneParse(format!("aseprite: {msg}"))
+}
+
+impl AsepriteFile {
+    fn parse(bytes: &[u8]) -> Result<Self, StaticPipelineError> {
+        let mut r = Reader::new(bytes);
+        r.u32()?;
+        if r.u16()? != HEADER_MAGIC {
+            return Err(ase_error("not an aseprite file"));
+        }
+        let frame_count = r.u16()? as usize;
+        let width = r.u16()? as u32;
+        let height = r.u16()? as u32;
+        let depth = r.u16()?;
+        let flags = r.u32()?;
+        r.take(2 + 4 + 4)?;
+        let transparent_index = r.u8()?;
+        r.take(128 - r.pos)?;
+        if width == 0 || height == 0 {
+            return Err(ase_error("empty canvas"));
+        }
+        if !matches!(depth, 8 | 16 | 32) {
+            return Err(ase_error("unsupported color depth"));
+        }
+
+        let mut file = Self {
+            width,
+            height,
+            layers: Vec::new(),
+            frames: Vec::with_capacity(frame_count),
+            tags: Vec::new(),
+        };
+        let mut palette = vec![[0u8; 4]; 256];
+        // Visibility of the enclosing group at each child level.
+        let mut group_visible = Vec::<bool>::new();
+        for _ in 0..frame_count {
+            let frame_start = r.pos;
+            let frame_len = r.u32()? as usize;
+            if r.u16()? != FRAME_MAGIC {
+                return Err(ase_error("bad frame magic"));
+            }
+            let old_chunks = r.u16()? as usize;
+            let duration_ms = r.u16()? as u32;
+            r.take(2)?;
+            let new_chunks = r.u32()? as usize;
+            let chunks = if new_chunks == 0 {
+                old_chunks
+            } else {
+                new_chunks
+            };
+            let mut frame = Frame {
+                duration_ms: duration_ms.max(1),
+                cels: Vec::new(),
+            };
+            for _ in 0..chunks {
+                let chunk_start = r.pos;
+                let chunk_len = r.u32()? as usize;
+                if chunk_len < 6 {
+                    return Err(ase_error("bad chunk size"));
+                }
+                let kind = r.u16()?;
+                let mut c = Reader::new(r.take(chunk_len - 6)?);
+                match kind {
+                    CHUNK_LAYER => {
+                        let layer_flags = c.u16()?;
+                        let layer_type = c.u16()?;
+                        let level = c.u16()? as usize;
+                        c.take(2 + 2 + 2)?;
+                        let opacity = c.u8()?;
+                        group_visible.truncate(level);
+                        let parent_visible = group_visible.iter().all(|visible| *visible);
+                        let visible = parent_visible
+                            && layer_flags & LAYER_VISIBLE != 0
+                            && layer_flags & LAYER_REFERENCE == 0;
+                        if layer_type == 1 {
+                            group_visible.resize(level, true);
+                            group_visible.push(visible);
+                        }
+                        file.layers.push(Layer {
+                            visible,
+                            opacity: if flags & HEADER_LAYER_OPACITY_VALID != 0 {
+                                opacity
+                            } else {
+                                255
+                            },
+                            image: layer_type == 0,
+                        });
+                    }
+                    CHUNK_CEL => {
+                        if let Some(cel) = parse_cel(&mut c, depth, &palette, transparent_index)? {
+                            frame.cels.push(cel);
+                        }
+                    }
+                    CHUNK_TAGS => {
+                        let count = c.u16()?;
+                        c.take(8)?;
+                        for _ in 0..count {
+                            let from = c.u16()? as usize;
+                            let to = c.u16()? as usize;
+                            let direction = match c.u8()? {
+                                1 => TagDirection::Reverse,
+                                2 => TagDirection::PingPong,
+                                3 => TagDirection::PingPongReverse,
+                                _ => TagDirection::Forward,
+                            };
+                            c.take(2 + 6 + 3 + 1)?;
+                            let name = c.string()?;
+                            file.tags.push(Tag {
+                                name,
+                                from: from.min(to),
+                                to: from.max(to),
+                                direction,
+                            });
+                        }
+                    }
+                    CHUNK_PALETTE => {
+                        c.u32()?;
+                        let first = c.u32()? as usize;
+                        let last = c.u32()? as usize;
+                        c.take(8)?;
+                        for index in first..=last {
+                            let entry_flags = c.u16()?;
+                            let rgba = [c.u8()?, c.u8()?, c.u8()?, c.u8()?];
+                            if entry_flags & 1 != 0 {
+                                c.string()?;
+                            }
+                            if let Some(slot) = palette.get_mut(index) {
+                                *slot = rgba;
+                            }
+                        }
+                    }
+                    CHUNK_OLD_PALETTE => {
+                        let packets = c.u16()?;
+                        let mut index = 0usize;
+                        for _ in 0..packets {
+                            index += c.u8()? as usize;
+                            let count = match c.u8()? {
+                                0 => 256,
+                                n => n as usize,
+                            };
+                            for _ in 0..count {
+                                let rgb = c.take(3)?;
+                                if let Some(slot) = palette.get_mut(index) {
+                                    *slot = [rgb[0], rgb[1], rgb[2], 255];
+                                }
+                                index += 1;
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+                r.pos = chunk_start + chunk_len;
+            }
+            file.frames.push(frame);
+            r.pos = frame_start + frame_len;
+        }
+        Ok(file)
+    }
+
+    fn compose_frame(&self, index: usize) -> image::RgbaImage {
+        let mut canvas = image::RgbaImage::new(self.width, self.height);
+        let Some(frame) = self.frames.get(index) else {
+            return canvas;
+        };
+        let mut cels = frame.cels.iter().collect::<Vec<_>>();
+        // Aseprite orders by layer + z-index, z-index breaking ties.
+        cels.sort_by_key(|cel| (cel.layer as i64 + cel.z_index as i64, cel.z_index));
+        for cel in cels {
+            let Some(layer) = self.layers.get(cel.layer) else {
+                continue;
+            };
+            if !layer.visible || !layer.image {
+                continue;
+            }
+            let Some((w, h, rgba)) = self.cel_pixels(index, cel) else {
+                continue;
+            };
+            let opacity = cel.opacity as u32 * layer.opacity as u32 / 255;
+            blend_cel(&mut canvas, cel.x, cel.y, w, h, rgba, opacity);
+        }
+        canvas
+    }
+
+    fn cel_pixels<'a>(&'a self, frame: usize, cel: &'a Cel) -> Option<(u32, u32, &'a [u8])> {
+        match &cel.pixels {
+            CelPixels::Image { w, h, rgba } => Some((*w, *h, rgba)),
+            CelPixels::Linked(target) if *target != frame => {
+                let linked = self
+                    .frames
+                    .get(*target)?
+                    .cels
+                    .iter()
+                    .find(|other| other.layer == cel.layer)?;
+                match &linked.pixels {
+                    CelPixels::Image { w, h, rgba } => Some((*w, *h, rgba)),
+                    CelPixels::Linked(_) => None,
+                }
+            }
+            CelPixels::Linked(_) => None,
+        }
+    }
+}
+
+fn parse_cel(
+    c: &mut Reader<'_>,
+    depth: u16,
+    palette: &[[u8; 4]],
+    transparent_index: u8,
+) -> Result<Option<Cel>, StaticPipelineError> {
+    let layer = c.u16()? as usize;
+    let x = c.i16()? as i32;
+    let y = c.i16()? as i32;
+    let opacity = c.u8()?;
+    let cel_type = c.u16()?;
+    let z_index = c.i16()? as i32;
+    c.take(5)?;
+    let pixels = match cel_type {
+        0 | 2 => {
+            let w = c.u16()? as u32;
+            let h = c.u16()? as u32;
+            let rest = c.take(c.bytes.len() - c.pos)?;
+            let bpp = depth as usize / 8;
+            let expected = w as usize * h as usize * bpp;
+            let raw = if cel_type == 2 {
+                perro_io::decompress_zlib_limited(rest, expected.min(MAX_CEL_BYTES))?
+            } else {
+                rest.to_vec()
+            };
+            if raw.len() < expected {
+                return Err(ase_error("truncated cel pixels"));
+            }
+            let rgba = raw[..expected]
+                .chunks_exact(bpp)
+                .flat_map(|px| match depth {
+                    32 => [px[0], px[1], px[2], px[3]],
+                    16 => [px[0], px[0], px[0], px[1]],
+                    _ if px[0] == transparent_index => [0, 0, 0, 0],
+                    _ => palette[px[0] as usize],
+                })
+                .collect();
+            CelPixels::Image { w, h, rgba }
+        }
+        1 => CelPixels::Linked(c.u16()? as usize),
+        // Compressed tilemap cels need the tileset; tilemap layers are skipped.
+        _ => return Ok(None),
+    };
+    Ok(Some(Cel {
+        layer,
+        x,
+        y,
+        opacity,
+        z_index,
+        pixels,
+    }))
+}
+
+/// Source-over, straight alpha.
+fn blend_cel(
+    canvas: &mut image::RgbaImage,
+    x: i32,
+    y: i32,
+    w: u32,
+    h: u32,
+    rgba: &[u8],
+    opacity: u32,
+) {
+    for cy in 0..h {
+        for cx in 0..w {
+            let (Ok(px), Ok(py)) = (u32::try_from(x + cx as i32), u32::try_from(y + cy as i32))
+            else {
+                continue;
+            };
+            if px >= canvas.width() || py >= canvas.height() {
+                continue;
+            }
+            let i = ((cy * w + cx) * 4) as usize;
+            let src = &rgba[i..i + 4];
+            let sa = src[3] as u32 * opacity / 255;
+            if sa == 0 {
+                continue;
+            }
+            let dst = canvas.get_pixel_mut(px, py);
+            let da = dst[3] as u32;
+            let out_a = sa + da * (255 - sa) / 255;
+            for ch in 0..3 {
+                let blended =
+                    (src[ch] as u32 * sa + dst[ch] as u32 * da * (255 - sa) / 255) / out_a.max(1);
+                dst[ch] = blended.min(255) as u8;
+            }
+            dst[3] = out_a.min(255) as u8;
+        }
+    }
+}
+
+/// Frames of one tag in playback order.
+fn tag_frames(tag: &Tag) -> Vec<usize> {
+    let forward = (tag.from..=tag.to).collect::<Vec<_>>();
+    let mut reverse = forward.clone();
+    reverse.reverse();
+    let bounce = |there: &[usize], back: &[usize]| {
+        let mut out = there.to_vec();
+        if back.len() > 2 {
+            out.extend_from_slice(&back[1..back.len() - 1]);
+        }
+        out
+    };
+    match tag.direction {
+        TagDirection::Forward => forward,
+        TagDirection::Reverse => reverse,
+        TagDirection::PingPong => bounce(&forward, &reverse),
+        TagDirection::PingPongReverse => bounce(&reverse, &forward),
+    }
+}
+
+/// Cells for one animation plus its fps: repeats each frame by
+/// `duration / gcd` when that fits, otherwise one cell per frame at mean fps.
+fn expand_durations(frames: &[usize], durations: &[u32]) -> (Vec<usize>, f32) {
+    let step = durations.iter().copied().fold(0, gcd).max(1);
+    let expanded = durations.iter().map(|d| (d / step) as usize).sum::<usize>();
+    if expanded <= MAX_EXPANDED_FRAMES {
+        let cells = frames
+            .iter()
+            .zip(durations)
+            .flat_map(|(frame, d)| std::iter::repeat_n(*frame, (d / step) as usize))
+            .collect();
+        return (cells, 1000.0 / step as f32);
+    }
+    let total = durations.iter().map(|d| *d as f32).sum::<f32>().max(1.0);
+    (frames.to_vec(), 1000.0 * frames.len() as f32 / total)
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+fn build_sheet(sprite: &AsepriteFile) -> (image::RgbaImage, Vec<SheetAnimation>) {
+    let mut tags = sprite.tags.clone();
+    tags.retain(|tag| tag.to < sprite.frames.len());
+    if tags.is_empty() {
+        tags.push(Tag {
+            name: "default".to_string(),
+            from: 0,
+            to: sprite.frames.len().saturating_sub(1),
+            direction: TagDirection::Forward,
+        });
+    }
+    let rows = tags
+        .iter()
+        .map(|tag| {
+            let frames = tag_frames(tag);
+            let durations = frames
+                .iter()
+                .map(|frame| sprite.frames.get(*frame).map_or(100, |f| f.duration_ms))
+                .collect::<Vec<_>>();
+            (tag.name.clone(), expand_durations(&frames, &durations))
+        })
+        .collect::<Vec<_>>();
+
+    let columns = rows
+        .iter()
+        .map(|(_, (cells, _))| cells.len())
+        .max()
+        .unwrap_or(1)
+        .max(1) as u32;
+    let mut sheet =
+        image::RgbaImage::new(columns * sprite.width, rows.len() as u32 * sprite.height);
+    let composed = (0..sprite.frames.len())
+        .map(|index| sprite.compose_frame(index))
+        .collect::<Vec<_>>();
+    let mut animations = Vec::with_capacity(rows.len());
+    for (row, (name, (cells, fps))) in rows.into_iter().enumerate() {
+        for (column, frame) in cells.iter().enumerate() {
+            if let Some(frame) = composed.get(*frame) {
+                image::imageops::replace(
+                    &mut sheet,
+                    frame,
+                    column as i64 * sprite.width as i64,
+                    row as i64 * sprite.height as i64,
+                );
+            }
+        }
+        animations.push(SheetAnimation {
+            name,
+            row: row as u32,
+            frame_count: cells.len().max(1) as u32,
+            fps,
+        });
+    }
+    (sheet, animations)
+}
+
+fn sprite_frames_scene(
+    stem: &str,
+    texture: &str,
+    frame_size: (u32, u32),
+    animations: &[SheetAnimation],
+) -> String {
+    let key = scene_key(stem);
+    let (w, h) = frame_size;
+    let mut out = String::new();
+    let _ = writeln!(out, "$root = @{key}\n");
+    let _ = writeln!(out, "[{key}]");
+    out.push_str("    [AnimatedSprite2D]\n");
+    let _ = writeln!(out, "        texture = \"{texture}\"");
+    if let Some(first) = animations.first() {
+        let _ = writeln!(out, "        current_animation = \"{}\"", first.name);
+    }
+    out.push_str("        playing = true\n");
+    out.push_str("        looping = true\n");
+    out.push_str("        animations = [\n");
+    for (index, animation) in animations.iter().enumerate() {
+        let separator = if index + 1 < animations.len() {
+            ","
+        } else {
+            ""
+        };
+        let _ = writeln!(
+            out,
+            "            {{ name = \"{}\", start = (0, {}), frame_size = ({w}, {h}), frame_count = {}, fps = {} }}{separator}",
+            animation.name,
+            animation.row * h,
+            animation.frame_count,
+            format_fps(animation.fps),
+        );
+    }
+    out.push_str("        ]\n");
+    out.push_str("    [/AnimatedSprite2D]\n");
+    let _ = writeln!(out, "[/{key}]");
+    out
+}
+
+fn format_fps(fps: f32) -> String {
+    let rounded = (fps * 1000.0).round() / 1000.0;
+    if rounded.fract() == 0.0 {
+        format!("{rounded:.0}")
+    } else {
+        format!("{rounded}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal RGBA file: `w` x `h`, one layer, one cel per frame filled with
+    /// `colors[i]`, optional tags `(name, from, to, direction)`.
+    fn ase_bytes(
+        w: u16,
+        h: u16,
+        frames: &[([u8; 4], u16)],
+        tags: &[(&str, u16, u16, u8)],
+        compressed: bool,
+    ) -> Vec<u8> {
+        fn chunk(kind: u16, data: &[u8]) -> Vec<u8> {
+            let mut out = ((data.len() + 6) as u32).to_le_bytes().to_vec();
+            out.extend_from_slice(&kind.to_le_bytes());
+            out.extend_from_slice(data);
+            out
+        }
+        let mut body = Vec::new();
+        for (index, (color, duration)) in frames.iter().enumerate() {
+            let mut chunks = Vec::new();
+            if index == 0 {
+                let mut layer = Vec::new();
+                layer.extend_from_slice(&LAYER_VISIBLE.to_le_bytes());
+                layer.extend_from_slice(&[0; 2 + 2 + 2 + 2 + 2]);
+                layer.push(255);
+                layer.extend_from_slice(&[0; 3]);
+                layer.extend_from_slice(&5u16.to_le_bytes());
+                layer.extend_from_slice(b"Layer");
+                chunks.push(chunk(CHUNK_LAYER, &layer));
+                if !tags.is_empty() {
+                    let mut data = (tags.len() as u16).to_le_bytes().to_vec();
+                    data.extend_from_slice(&[0; 8]);
+                    for (name, from, to, direction) in tags {
+                        data.extend_from_slice(&from.to_le_bytes());
+                        data.extend_from_slice(&to.to_le_bytes());
+                        data.push(*direction);
+                        data.extend_from_slice(&[0; 2 + 6 + 3 + 1]);
+                        data.extend_from_slice(&(name.len() as u16).to_le_bytes());
+                        data.extend_from_slice(name.as_bytes());
+                    }
+                    chunks.push(chunk(CHUNK_TAGS, &data));
+                }
+            }
+            let pixels = color.repeat(w as usize * h as usize);
+            let mut cel = Vec::new();
+            cel.extend_from_slice(&0u16.to_le_bytes());
+            cel.extend_from_slice(&[0; 4]);
+            cel.push(255);
+            cel.extend_from_slice(&(if compressed { 2u16 } else { 0 }).to_le_bytes());
+            cel.extend_from_slice(&[0; 2 + 5]);
+            cel.extend_from_slice(&w.to_le_bytes());
+            cel.extend_from_slice(&h.to_le_bytes());
+            if compressed {
+                cel.extend(perro_io::compress_zlib_best(&pixels).expect("compress"));
+            } else {
+                cel.extend(pixels);
+            }
+            chunks.push(chunk(CHUNK_CEL, &cel));
+
+            let chunk_bytes = chunks.concat();
+            body.extend_from_slice(&((16 + chunk_bytes.len()) as u32).to_le_bytes());
+            body.extend_from_slice(&FRAME_MAGIC.to_le_bytes());
+            body.extend_from_slice(&(chunks.len() as u16).to_le_bytes());
+            body.extend_from_slice(&duration.to_le_bytes());
+            body.extend_from_slice(&[0; 2]);
+            body.extend_from_slice(&(chunks.len() as u32).to_le_bytes());
+            body.extend(chunk_bytes);
+        }
+        let mut out = Vec::new();
+        out.extend_from_slice(&((128 + body.len()) as u32).to_le_bytes());
+        out.extend_from_slice(&HEADER_MAGIC.to_le_bytes());
+        out.extend_from_slice(&(frames.len() as u16).to_le_bytes());
+        out.extend_from_slice(&w.to_le_bytes());
+        out.extend_from_slice(&h.to_le_bytes());
+        out.extend_from_slice(&32u16.to_le_bytes());
+        out.extend_from_slice(&HEADER_LAYER_OPACITY_VALID.to_le_bytes());
+        out.resize(128, 0);
+        out.extend(body);
+        out
+    }
+
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const BLUE: [u8; 4] = [0, 0, 255, 255];
+
+    #[test]
+    fn tags_become_sheet_rows() {
+        let bytes = ase_bytes(
+            2,
+            2,
+            &[(RED, 100), (BLUE, 100), (RED, 100)],
+            &[("idle", 0, 0, 0), ("blink", 1, 2, 0)],
+            true,
+        );
+        let sprite = AsepriteFile::parse(&bytes).expect("parse");
+        let (sheet, animations) = build_sheet(&sprite);
+        assert_eq!(sheet.dimensions(), (4, 4));
+        assert_eq!(sheet.get_pixel(0, 0).0, RED);
+        assert_eq!(sheet.get_pixel(0, 2).0, BLUE);
+        assert_eq!(sheet.get_pixel(3, 3).0, RED);
+        assert_eq!(animations[1].name, "blink");
+        assert_eq!(animations[1].row, 1);
+        assert_eq!(animations[1].frame_count, 2);
+        assert_eq!(animations[1].fps, 10.0);
+    }
+
+    #[test]
+    fn uneven_durations_repeat_frames() {
+        let bytes = ase_bytes(1, 1, &[(RED, 100), (BLUE, 200)], &[], false);
+        let sprite = AsepriteFile::parse(&bytes).expect("parse");
+        let (sheet, animations) = build_sheet(&sprite);
+        assert_eq!(animations[0].name, "default");
+        assert_eq!(animations[0].frame_count, 3);
+        assert_eq!(animations[0].fps, 10.0);
+        assert_eq!(sheet.get_pixel(1, 0).0, BLUE);
+        assert_eq!(sheet.get_pixel(2, 0).0, BLUE);
+    }
+
+    #[test]
+    fn ping_pong_skips_end_frames_on_the_way_back() {
+        let tag = Tag {
+            name: "walk".to_string(),
+            from: 0,
+            to: 3,
+            direction: TagDirection::PingPong,
+        };
+        assert_eq!(tag_frames(&tag), vec![0, 1, 2, 3, 2, 1]);
+    }
+
+    #[test]
+    fn generated_scene_parses_as_animated_sprite() {
+        let bytes = ase_bytes(2, 2, &[(RED, 100), (BLUE, 100)], &[("run", 0, 1, 0)], true);
+        let sprite = AsepriteFile::parse(&bytes).expect("parse");
+        let (_, animations) = build_sheet(&sprite);
+        let text = sprite_frames_scene("hero-1", "res://hero-1.png", (2, 2), &animations);
+        let scene = perro_scene::Parser::new(&text)
+            .try_parse_scene()
+            .expect("generated scene parses");
+        assert_eq!(scene.nodes.len(), 1);
+        assert!(text.contains("$root = @hero_1"));
+        assert!(text.contains(
+            "{ name = \"run\", start = (0, 0), frame_size = (2, 2), frame_count = 2, fps = 10 }"
+        ));
+    }
+
+    #[test]
+    fn rejects_non_aseprite_bytes() {
+        assert!(AsepriteFile::parse(b"\x89PNG\r\n\x1a\n").is_err());
+        assert!(AsepriteFile::parse(&[0; 128]).is_err());
+    }
+}
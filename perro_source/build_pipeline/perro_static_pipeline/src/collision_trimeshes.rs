@@ -2,8 +2,8 @@
 
 use crate::{
     CachedSource, SourceCache, StaticPipelineError, asset_prefix, ensure_unique_hashes,
-    is_asset_uri, res_dir, source_stat, static_dir, strip_asset_prefix, write_hash_const,
-    write_if_changed, write_static_lookup_fn,
+    is_asset_uri, load_extended_scene_source, res_dir, source_stat, static_dir, strip_asset_prefix,
+    write_hash_const, write_if_changed, write_static_lookup_fn,
 };
 use perro_asset_formats::{
     pmesh::{
@@ -168,12 +168,17 @@ fn collect_collision_trimesh_sources(res_root: &Path) -> Result<Vec<String>, Sta
             continue;
         }
         let src = fs::read_to_string(res_root.join(&rel))?;
-        let scene = std::panic::catch_unwind(|| Parser::new(&src).parse_scene()).map_err(|_| {
-            StaticPipelineError::SceneParse(format!(
-                "failed to parse scene: {}{rel}",
-                asset_prefix()
-            ))
-        })?;
+        let load_base = |path: &str| load_extended_scene_source(res_root, path);
+        let scene = Parser::new(&src)
+            .with_scene_loader(&load_base)
+            .with_target(crate::scene_target())
+            .try_parse_scene()
+            .map_err(|err| {
+                StaticPipelineError::SceneParse(format!(
+                    "failed to parse scene {}{rel}: {err}",
+                    asset_prefix()
+                ))
+            })?;
         collect_collision_sources_from_scene(&scene, &mut out);
     }
     Ok(out.into_iter().collect())
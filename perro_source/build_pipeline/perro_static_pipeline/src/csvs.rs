@@ -2,6 +2,7 @@ use crate::{
     StaticPipelineError, asset_uri, ensure_unique_hashes, escape_rust_str, res_dir, static_dir,
     write_hash_const, write_static_lookup_fn,
 };
+use perro_csv::CsvBuf;
 use perro_ids::string_to_u64;
 use std::{
     collections::HashMap,
@@ -11,6 +12,9 @@ use std::{
 };
 
 const STRING_POOL_ITEMS_PER_LINE: usize = 50;
+/// `res://`-relative dir holding data tables. CSV and JSON files here are
+/// validated for keyed row access; JSON files here are embedded as tables.
+const DATA_TABLE_DIR: &str = "data/";
 
 #[derive(Debug)]
 struct StaticCsvTable {
@@ -127,7 +131,8 @@ fn collect_csv_paths(
             collect_csv_paths(root, &path, out)?;
             continue;
         }
-        if path.extension().and_then(|ext| ext.to_str()) != Some("csv") {
+        let ext = path.extension().and_then(|ext| ext.to_str());
+        if !matches!(ext, Some("csv" | "json")) {
             continue;
         }
         let rel = path
@@ -135,6 +140,9 @@ fn collect_csv_paths(
             .map_err(|err| StaticPipelineError::SceneParse(err.to_string()))?
             .to_string_lossy()
             .replace('\\', "/");
+        if ext == Some("json") && !rel.starts_with(DATA_TABLE_DIR) {
+            continue;
+        }
         out.push((asset_uri(&rel), path));
     }
     Ok(())
@@ -145,32 +153,33 @@ fn parse_csv_table(
     disk_path: &Path,
 ) -> Result<StaticCsvTable, StaticPipelineError> {
     let bytes = fs::read(disk_path)?;
-    let mut reader = csv::ReaderBuilder::new()
-        .has_headers(true)
-        .from_reader(bytes.as_slice());
-    let headers = reader.headers().map_err(|err| {
+    let parsed = if asset_path.ends_with(".json") {
+        CsvBuf::from_json_bytes(&bytes)
+    } else {
+        CsvBuf::from_bytes(&bytes)
+    }
+    .map_err(|err| {
         StaticPipelineError::SceneParse(format!("failed to parse csv `{asset_path}`: {err}"))
     })?;
-    let header_values: Vec<String> = headers
-        .iter()
-        .map(|value| value.trim().to_string())
-        .collect();
-
-    let mut rows = Vec::new();
-    for row in reader.records() {
-        let row = row.map_err(|err| {
-            StaticPipelineError::SceneParse(format!("failed to parse csv `{asset_path}`: {err}"))
+    if is_data_table(asset_path) {
+        parsed.validate_data_table().map_err(|err| {
+            StaticPipelineError::SceneParse(format!("invalid data table `{asset_path}`: {err}"))
         })?;
-        rows.push(row.iter().map(|value| value.trim().to_string()).collect());
     }
 
     Ok(StaticCsvTable {
         asset_path: asset_path.to_string(),
-        headers: header_values,
-        rows,
+        headers: parsed.headers().to_vec(),
+        rows: parsed.rows().to_vec(),
     })
 }
 
+fn is_data_table(asset_path: &str) -> bool {
+    asset_path
+        .strip_prefix("res://")
+        .is_some_and(|rel| rel.starts_with(DATA_TABLE_DIR))
+}
+
 fn emit_csv_table(
     out: &mut String,
     table_index: usize,
@@ -376,6 +385,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_csv_table_embeds_and_validates_data_tables() {
+        let dir = std::env::temp_dir().join(format!(
+            "perro_static_data_tables_{}_{}",
+            std::process::id(),
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("required value must be present")
+                .as_nanos()
+        ));
+        fs::create_dir_all(&dir).expect("required value must be present");
+        let json = dir.join("items.json");
+        fs::write(&json, r#"{"sword_01": {"power": 10}}"#).expect("required value must be present");
+        let dup = dir.join("dup.csv");
+        fs::write(&dup, "id,power\nsword,10\nsword,12\n").expect("required value must be present");
+
+        let table = parse_csv_table("res://data/items.json", &json)
+            .expect("required value must be present");
+        assert_eq!(table.headers, ["id", "power"]);
+        assert_eq!(table.rows, [vec!["sword_01".to_string(), "10".to_string()]]);
+
+        let err = parse_csv_table("res://data/dup.csv", &dup).expect_err("duplicate key");
+        assert!(err.to_string().contains("invalid data table"), "{err}");
+        assert!(parse_csv_table("res://misc/dup.csv", &dup).is_ok());
+        let _ = fs::remove_dir_all(dir);
+    }
+
     fn intern_table_strings(string_pool: &mut CsvStringPool, table: &StaticCsvTable) {
         for value in &table.headers {
             string_pool.intern(value);
@@ -0,0 +1,589 @@
+//! Import hooks: plugins that claim a file extension and turn those sources
+//! into engine assets before the static generators run.
+//!
+//! Outputs are written into `res/` next to their source, so dev runs (which
+//! read `res/` live) and release bakes see the same files. A manifest under
+//! `.perro/project/` records each source's stat key, plugin name + version and
+//! outputs: unchanged sources are not re-imported, outputs whose source went
+//! away are deleted, and an import never overwrites a file it did not create.
+
+use crate::{PERRO_DIR, PROJECT_DIR, StaticPipelineError, res_dir, source_stat, write_if_changed};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Component, Path, PathBuf},
+    process::Command,
+    sync::{Arc, LazyLock, RwLock},
+};
+
+const IMPORT_MANIFEST_FILE: &str = "imports.manifest";
+const IMPORT_MANIFEST_HEADER: &str = "perro-imports v1";
+
+/// One custom source format. Implementations must be deterministic: the same
+/// source bytes and `version` must produce the same outputs.
+pub trait ImportPlugin: Send + Sync {
+    /// Stable id, stored in the import manifest.
+    fn name(&self) -> &str;
+
+    /// Claimed extensions, lowercase, without the dot.
+    fn extensions(&self) -> Vec<&str>;
+
+    /// Bump when output changes for the same input; forces a re-import.
+    fn version(&self) -> u32 {
+        1
+    }
+
+    fn import(&self, source: &ImportSource<'_>) -> Result<Vec<ImportedAsset>, StaticPipelineError>;
+}
+
+pub struct ImportSource<'a> {
+    /// Absolute path on disk.
+    pub path: &'a Path,
+    /// Relative to `res/`, `/`-separated.
+    pub rel_path: &'a str,
+    pub res_dir: &'a Path,
+}
+
+impl ImportSource<'_> {
+    pub fn read(&self) -> Result<Vec<u8>, StaticPipelineError> {
+        Ok(fs::read(self.path)?)
+    }
+
+    /// `res/`-relative path for an output named `file_name` in the source's
+    /// directory.
+    pub fn sibling(&self, file_name: &str) -> String {
+        match self.rel_path.rsplit_once('/') {
+            Some((dir, _)) => format!("{dir}/{file_name}"),
+            None => file_name.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportedAsset {
+    /// Relative to `res/`, `/`-separated.
+    pub rel_path: String,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    /// Every claimed source, `res/`-relative. Exports leave these out of the
+    /// archive; the outputs ship instead.
+    pub sources: Vec<String>,
+    pub imported: usize,
+    pub reused: usize,
+    /// Outputs deleted because their source is gone or stopped producing them.
+    pub removed: Vec<String>,
+}
+
+static IMPORT_PLUGINS: LazyLock<RwLock<Vec<Arc<dyn ImportPlugin>>>> =
+    LazyLock::new(|| RwLock::new(Vec::new()));
+
+/// Process-wide registration for build tools that embed the compiler.
+/// Re-registering a name replaces the old plugin; claiming an extension
+/// another plugin already owns is an error.
+pub fn register_import_plugin(plugin: Arc<dyn ImportPlugin>) -> Result<(), StaticPipelineError> {
+    let mut plugins = IMPORT_PLUGINS
+        .write()
+        .map_err(|_| StaticPipelineError::SceneParse("import plugin registry poisoned".into()))?;
+    plugins.retain(|existing| existing.name() != plugin.name());
+    let mut candidate = plugins.clone();
+    candidate.push(plugin);
+    plugins_by_extension(&candidate)?;
+    *plugins = candidate;
+    Ok(())
+}
+
+pub fn unregister_import_plugin(name: &str) {
+    if let Ok(mut plugins) = IMPORT_PLUGINS.write() {
+        plugins.retain(|plugin| plugin.name() != name);
+    }
+}
+
+pub fn registered_import_plugins() -> Vec<Arc<dyn ImportPlugin>> {
+    IMPORT_PLUGINS
+        .read()
+        .map(|plugins| plugins.clone())
+        .unwrap_or_default()
+}
+
+/// Importers that ship with the engine. A project tool or registered plugin
+/// claiming the same extension replaces the built-in.
+pub fn builtin_import_plugins() -> Vec<Arc<dyn ImportPlugin>> {
+    vec![
+        Arc::new(crate::AsepriteImportPlugin),
+        Arc::new(crate::LevelImportPlugin),
+    ]
+}
+
+/// Runs `<program> <args...> <source> <out_dir>`; every file the tool leaves
+/// in `out_dir` becomes an output beside the source, keeping subdirectories.
+pub struct CommandImportPlugin {
+    pub name: String,
+    pub extensions: Vec<String>,
+    pub program: PathBuf,
+    pub args: Vec<String>,
+    pub version: u32,
+}
+
+impl ImportPlugin for CommandImportPlugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn extensions(&self) -> Vec<&str> {
+        self.extensions.iter().map(String::as_str).collect()
+    }
+
+    fn version(&self) -> u32 {
+        self.version
+    }
+
+    fn import(&self, source: &ImportSource<'_>) -> Result<Vec<ImportedAsset>, StaticPipelineError> {
+        let out_dir = std::env::temp_dir().join(format!(
+            "perro_import_{}_{}",
+            std::process::id(),
+            perro_ids::string_to_u64(source.rel_path)
+        ));
+        let _ = fs::remove_dir_all(&out_dir);
+        fs::create_dir_all(&out_dir)?;
+        let output = Command::new(&self.program)
+            .args(&self.args)
+            .arg(source.path)
+            .arg(&out_dir)
+            .current_dir(source.res_dir)
+            .output();
+        let result = match output {
+            Ok(output) if output.status.success() => collect_command_outputs(source, &out_dir),
+            Ok(output) => Err(StaticPipelineError::SceneParse(format!(
+                "`{}` exited with {}: {}",
+                self.program.display(),
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ))),
+            Err(err) => Err(StaticPipelineError::SceneParse(format!(
+                "failed to run `{}`: {err}",
+                self.program.display()
+            ))),
+        };
+        let _ = fs::remove_dir_all(&out_dir);
+        result
+    }
+}
+
+fn collect_command_outputs(
+    source: &ImportSource<'_>,
+    out_dir: &Path,
+) -> Result<Vec<ImportedAsset>, StaticPipelineError> {
+    let mut assets = perro_io::walkdir::collect_files(out_dir, out_dir)?
+        .into_iter()
+        .map(|(rel, bytes)| ImportedAsset {
+            rel_path: source.sibling(&rel.replace('\\', "/")),
+            bytes,
+        })
+        .collect::<Vec<_>>();
+    assets.sort_by(|a, b| a.rel_path.cmp(&b.rel_path));
+    Ok(assets)
+}
+
+struct ImportEntry {
+    len: u64,
+    mtime: u128,
+    plugin: String,
+    version: u32,
+    outputs: Vec<String>,
+}
+
+/// Imports every `res/` file whose extension a plugin claims. `fresh` ignores
+/// the manifest's stat keys (ownership still comes from it).
+pub fn run_import_plugins(
+    project_root: &Path,
+    plugins: &[Arc<dyn ImportPlugin>],
+    fresh: bool,
+) -> Result<ImportReport, StaticPipelineError> {
+    let by_extension = plugins_by_extension(plugins)?;
+    let res = res_dir(project_root);
+    let manifest_path = project_root
+        .join(PERRO_DIR)
+        .join(PROJECT_DIR)
+        .join(IMPORT_MANIFEST_FILE);
+    let mut old = read_import_manifest(&manifest_path);
+    let mut report = ImportReport::default();
+    if by_extension.is_empty() && old.is_empty() {
+        return Ok(report);
+    }
+    let owned = old
+        .values()
+        .flat_map(|entry| entry.outputs.iter().cloned())
+        .collect::<HashSet<_>>();
+
+    let mut files = if res.is_dir() {
+        perro_io::walkdir::collect_file_paths(&res, &res)?
+    } else {
+        Vec::new()
+    };
+    files.sort();
+
+    let mut written = HashMap::<String, String>::new();
+    let mut current = Vec::<(String, ImportEntry)>::new();
+    for rel in files.into_iter().map(|rel| rel.replace('\\', "/")) {
+        let Some(plugin) = extension_of(&rel).and_then(|ext| by_extension.get(&ext)) else {
+            continue;
+        };
+        report.sources.push(rel.clone());
+        let path = res.join(&rel);
+        let (len, mtime) = source_stat(&path).ok_or_else(|| {
+            StaticPipelineError::SceneParse(format!("import source `res://{rel}` is unreadable"))
+        })?;
+
+        if let Some(entry) = old.remove(&rel)
+            && !fresh
+            && entry.len == len
+            && entry.mtime == mtime
+            && entry.plugin == plugin.name()
+            && entry.version == plugin.version()
+            && entry.outputs.iter().all(|out| res.join(out).is_file())
+        {
+            for out in &entry.outputs {
+                claim_output(&mut written, out, &rel)?;
+            }
+            report.reused += 1;
+            current.push((rel, entry));
+            continue;
+        }
+
+        let source = ImportSource {
+            path: &path,
+            rel_path: &rel,
+            res_dir: &res,
+        };
+        let assets = plugin.import(&source).map_err(|err| {
+            StaticPipelineError::SceneParse(format!(
+                "import `res://{rel}` via `{}` failed: {err}",
+                plugin.name()
+            ))
+        })?;
+        let mut outputs = Vec::with_capacity(assets.len());
+        for asset in assets {
+            let out = validate_output_path(&asset.rel_path, &rel, &by_extension)?;
+            claim_output(&mut written, &out, &rel)?;
+            let target = res.join(&out);
+            if !owned.contains(&out)
+                && target.exists()
+                && fs::read(&target).ok().as_deref() != Some(asset.bytes.as_slice())
+            {
+                return Err(StaticPipelineError::SceneParse(format!(
+                    "import `res://{rel}` would overwrite `res://{out}`, which no import created"
+                )));
+            }
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            write_if_changed(&target, &asset.bytes)?;
+            outputs.push(out);
+        }
+        report.imported += 1;
+        current.push((
+            rel,
+            ImportEntry {
+                len,
+                mtime,
+                plugin: plugin.name().to_string(),
+                version: plugin.version(),
+                outputs,
+            },
+        ));
+    }
+
+    let mut stale = owned
+        .into_iter()
+        .filter(|out| !written.contains_key(out))
+        .collect::<Vec<_>>();
+    stale.sort();
+    for out in stale {
+        let target = res.join(&out);
+        if target.is_file() {
+            fs::remove_file(&target)?;
+        }
+        report.removed.push(out);
+    }
+
+    if let Some(parent) = manifest_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    write_if_changed(&manifest_path, write_import_manifest(&current).as_bytes())?;
+    Ok(report)
+}
+
+fn plugins_by_extension(
+    plugins: &[Arc<dyn ImportPlugin>],
+) -> Result<HashMap<String, Arc<dyn ImportPlugin>>, StaticPipelineError> {
+    let mut by_extension = HashMap::<String, Arc<dyn ImportPlugin>>::new();
+    for plugin in plugins {
+        for ext in plugin.extensions() {
+            let ext = ext.trim_start_matches('.').to_ascii_lowercase();
+            if let Some(other) = by_extension.get(&ext)
+                && other.name() != plugin.name()
+            {
+                return Err(StaticPipelineError::SceneParse(format!(
+                    "`.{ext}` claimed by both import plugins `{}` and `{}`",
+                    other.name(),
+                    plugin.name()
+                )));
+            }
+            by_extension.insert(ext, plugin.clone());
+        }
+    }
+    Ok(by_extension)
+}
+
+fn extension_of(rel: &str) -> Option<String> {
+    Path::new(rel)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase)
+}
+
+fn claim_output(
+    written: &mut HashMap<String, String>,
+    out: &str,
+    source: &str,
+) -> Result<(), StaticPipelineError> {
+    if let Some(other) = written.insert(out.to_string(), source.to_string())
+        && other != source
+    {
+        return Err(StaticPipelineError::SceneParse(format!(
+            "imports of `res://{other}` and `res://{source}` both write `res://{out}`"
+        )));
+    }
+    Ok(())
+}
+
+/// Outputs stay inside `res/`, never replace their own source, and never use
+/// a claimed extension (they would be imported again next run).
+fn validate_output_path(
+    raw: &str,
+    source: &str,
+    by_extension: &HashMap<String, Arc<dyn ImportPlugin>>,
+) -> Result<String, StaticPipelineError> {
+    let out = raw.replace('\\', "/");
+    let escapes = Path::new(&out)
+        .components()
+        .any(|part| !matches!(part, Component::Normal(_)));
+    if out.is_empty() || escapes {
+        return Err(StaticPipelineError::SceneParse(format!(
+            "import `res://{source}` produced invalid output path `{raw}`"
+        )));
+    }
+    if out == source {
+        return Err(StaticPipelineError::SceneParse(format!(
+            "import `res://{source}` tried to overwrite its own source"
+        )));
+    }
+    if let Some(ext) = extension_of(&out)
+        && by_extension.contains_key(&ext)
+    {
+        return Err(StaticPipelineError::SceneParse(format!(
+            "import `res://{source}` produced `res://{out}`, whose extension is itself imported"
+        )));
+    }
+    Ok(out)
+}
+
+/// Scene node keys are identifiers; file, tag and layer names are not.
+pub(crate) fn scene_key(raw: &str) -> String {
+    let mut key = raw
+        .chars()
+        .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '_' })
+        .collect::<String>();
+    if !key.starts_with(|ch: char| ch.is_ascii_alphabetic() || ch == '_') {
+        key.insert(0, '_');
+    }
+    key
+}
+
+fn read_import_manifest(path: &Path) -> HashMap<String, ImportEntry> {
+    let Ok(text) = fs::read_to_string(path) else {
+        return HashMap::new();
+    };
+    parse_import_manifest(&text).unwrap_or_default()
+}
+
+fn parse_import_manifest(text: &str) -> Option<HashMap<String, ImportEntry>> {
+    let mut lines = text.lines();
+    if lines.next()? != IMPORT_MANIFEST_HEADER {
+        return None;
+    }
+    let mut entries = HashMap::new();
+    while let Some(line) = lines.next() {
+        let mut parts = line.split('\t');
+        if parts.next()? != "S" {
+            return None;
+        }
+        let rel = parts.next()?.to_string();
+        let len = parts.next()?.parse().ok()?;
+        let mtime = parts.next()?.parse().ok()?;
+        let plugin = parts.next()?.to_string();
+        let version = parts.next()?.parse().ok()?;
+        let n_outputs: usize = parts.next()?.parse().ok()?;
+        let mut outputs = Vec::with_capacity(n_outputs);
+        for _ in 0..n_outputs {
+            outputs.push(lines.next()?.strip_prefix("O\t")?.to_string());
+        }
+        entries.insert(
+            rel,
+            ImportEntry {
+                len,
+                mtime,
+                plugin,
+                version,
+                outputs,
+            },
+        );
+    }
+    Some(entries)
+}
+
+fn write_import_manifest(entries: &[(String, ImportEntry)]) -> String {
+    let mut out = String::new();
+    out.push_str(IMPORT_MANIFEST_HEADER);
+    out.push('\n');
+    for (rel, entry) in entries {
+        out.push_str(&format!(
+            "S\t{rel}\t{}\t{}\t{}\t{}\t{}\n",
+            entry.len,
+            entry.mtime,
+            entry.plugin,
+            entry.version,
+            entry.outputs.len()
+        ));
+        for output in &entry.outputs {
+            out.push_str(&format!("O\t{output}\n"));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    /// `x.up` -> `x.txt` with the text uppercased.
+    #[derive(Default)]
+    struct UpperPlugin {
+        calls: AtomicUsize,
+    }
+
+    impl ImportPlugin for UpperPlugin {
+        fn name(&self) -> &str {
+            "upper"
+        }
+
+        fn extensions(&self) -> Vec<&str> {
+            vec!["up"]
+        }
+
+        fn import(
+            &self,
+            source: &ImportSource<'_>,
+        ) -> Result<Vec<ImportedAsset>, StaticPipelineError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let text = String::from_utf8_lossy(&source.read()?).to_uppercase();
+            let stem = Path::new(source.rel_path)
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or("out");
+            Ok(vec![ImportedAsset {
+                rel_path: source.sibling(&format!("{stem}.txt")),
+                bytes: text.into_bytes(),
+            }])
+        }
+    }
+
+    fn temp_project(tag: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or(0);
+        let root = std::env::temp_dir().join(format!("perro_imports_{tag}_{nanos}"));
+        fs::create_dir_all(root.join("res").join("levels")).expect("create res");
+        root
+    }
+
+    #[test]
+    fn imports_reuses_and_removes_stale_outputs() {
+        let root = temp_project("cycle");
+        fs::write(root.join("res/levels/one.up"), "hello").expect("write source");
+        let upper = Arc::new(UpperPlugin::default());
+        let plugins: Vec<Arc<dyn ImportPlugin>> = vec![upper.clone()];
+
+        let report = run_import_plugins(&root, &plugins, false).expect("first import");
+        assert_eq!(report.sources, vec!["levels/one.up"]);
+        assert_eq!(report.imported, 1);
+        assert_eq!(
+            fs::read_to_string(root.join("res/levels/one.txt")).expect("read output"),
+            "HELLO"
+        );
+
+        let report = run_import_plugins(&root, &plugins, false).expect("second import");
+        assert_eq!((report.imported, report.reused), (0, 1));
+        assert_eq!(upper.calls.load(Ordering::SeqCst), 1);
+
+        let report = run_import_plugins(&root, &plugins, true).expect("fresh import");
+        assert_eq!(report.imported, 1);
+        assert_eq!(upper.calls.load(Ordering::SeqCst), 2);
+
+        fs::remove_file(root.join("res/levels/one.up")).expect("remove source");
+        let report = run_import_plugins(&root, &plugins, false).expect("third import");
+        assert_eq!(report.removed, vec!["levels/one.txt"]);
+        assert!(!root.join("res/levels/one.txt").exists());
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn import_never_overwrites_foreign_file() {
+        let root = temp_project("foreign");
+        fs::write(root.join("res/levels/one.up"), "hello").expect("write source");
+        fs::write(root.join("res/levels/one.txt"), "hand written").expect("write foreign");
+        let plugins: Vec<Arc<dyn ImportPlugin>> = vec![Arc::new(UpperPlugin::default())];
+
+        let err = run_import_plugins(&root, &plugins, false).expect_err("must refuse");
+        assert!(err.to_string().contains("no import created"));
+        assert_eq!(
+            fs::read_to_string(root.join("res/levels/one.txt")).expect("read foreign"),
+            "hand written"
+        );
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn conflicting_extension_claims_are_rejected() {
+        let command = CommandImportPlugin {
+            name: "tool".to_string(),
+            extensions: vec!["up".to_string()],
+            program: PathBuf::from("tool"),
+            args: Vec::new(),
+            version: 1,
+        };
+        let plugins: Vec<Arc<dyn ImportPlugin>> =
+            vec![Arc::new(UpperPlugin::default()), Arc::new(command)];
+        assert!(plugins_by_extension(&plugins).is_err());
+    }
+
+    #[test]
+    fn output_paths_stay_inside_res() {
+        let plugins: Vec<Arc<dyn ImportPlugin>> = vec![Arc::new(UpperPlugin::default())];
+        let by_extension = plugins_by_extension(&plugins).expect("plugins");
+        assert!(validate_output_path("../escape.txt", "a.up", &by_extension).is_err());
+        assert!(validate_output_path("/abs.txt", "a.up", &by_extension).is_err());
+        assert!(validate_output_path("a.up", "a.up", &by_extension).is_err());
+        assert!(validate_output_path("b.up", "a.up", &by_extension).is_err());
+        assert_eq!(
+            validate_output_path("maps\\a.bin", "a.up", &by_extension).expect("valid"),
+            "maps/a.bin"
+        );
+    }
+}
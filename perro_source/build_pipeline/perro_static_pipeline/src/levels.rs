@@ -0,0 +1,911 @@
+//! Built-in LDtk (`.ldtk`) and Tiled JSON (`.tmj`) level importer.
+//!
+//! Each level becomes a `.scn`: a `Node2D` root, one `TileMap2D` per tile
+//! layer (bottom layer first, `z_index` counting up) and one `Node2D` per
+//! entity or object. Tilesets become `.ptileset` files next to the scenes.
+//!
+//! - `world.ldtk` -> `world/<Level>.scn` per level, `world/<Tileset>.ptileset`
+//! - `map.tmj` -> `map.scn`, `map/<tileset>.ptileset`
+//!
+//! Entities attach `res://**/<snake_case name>.rs` when such a script exists
+//! and pass simple fields (bool, int, float, string) as `script_vars`.
+//! Collision: LDtk IntGrid cells with a non-zero value bake into a hidden
+//! collision-only `TileMap2D`; tiles tagged `Collision`/`Solid` (LDtk enum
+//! tags) or carrying a `collision`/`solid` property or collision shapes
+//! (Tiled) are solid in the generated tileset.
+
+use crate::imports::scene_key;
+use crate::{ImportPlugin, ImportSource, ImportedAsset, StaticPipelineError};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write as _;
+use std::fs;
+use std::io::Cursor;
+
+pub struct LevelImportPlugin;
+
+impl ImportPlugin for LevelImportPlugin {
+    fn name(&self) -> &str {
+        "levels"
+    }
+
+    fn extensions(&self) -> Vec<&str> {
+        vec!["ldtk", "tmj"]
+    }
+
+    fn import(&self, source: &ImportSource<'_>) -> Result<Vec<ImportedAsset>, StaticPipelineError> {
+        let json: Value = serde_json::from_slice(&source.read()?)
+            .map_err(|err| level_error(&format!("invalid JSON: {err}")))?;
+        let stem = std::path::Path::new(source.rel_path)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("level")
+            .to_string();
+        let out_dir = source.sibling(&stem);
+        let scripts = scripts_by_stem(source);
+        let is_ldtk = source.rel_path.to_ascii_lowercase().ends_with(".ldtk");
+        let (levels, tilesets) = if is_ldtk {
+            read_ldtk(source, &json)?
+        } else {
+            read_tiled(source, &json)?
+        };
+
+        let mut outputs = BTreeMap::<String, Vec<u8>>::new();
+        let mut tileset_paths = Vec::with_capacity(tilesets.len());
+        for tileset in &tilesets {
+            let rel = format!("{out_dir}/{}.ptileset", tileset.file_name);
+            outputs.insert(rel.clone(), ptileset_text(tileset).into_bytes());
+            tileset_paths.push(format!("res://{rel}"));
+        }
+        let mut collision_paths = BTreeMap::<(u32, u32), String>::new();
+        for level in &levels {
+            for layer in &level.layers {
+                if matches!(layer.tiles, LayerTiles::Collision)
+                    && !collision_paths.contains_key(&layer.cell)
+                {
+                    let (w, h) = layer.cell;
+                    let rel = format!("{out_dir}/collision_{w}x{h}.ptileset");
+                    outputs.insert(
+                        rel.clone(),
+                        collision_ptileset_text(&format!("res://{out_dir}/collision.png"), w, h)
+                            .into_bytes(),
+                    );
+                    collision_paths.insert(layer.cell, format!("res://{rel}"));
+                }
+            }
+        }
+        if !collision_paths.is_empty() {
+            let mut png = Vec::new();
+            image::RgbaImage::new(1, 1)
+                .write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png)?;
+            outputs.insert(format!("{out_dir}/collision.png"), png);
+        }
+        for level in &levels {
+            let text = level_scene_text(level, &tileset_paths, &collision_paths, &scripts);
+            let rel = if is_ldtk {
+                format!("{out_dir}/{}.scn", level.name)
+            } else {
+                source.sibling(&format!("{stem}.scn"))
+            };
+            outputs.insert(rel, text.into_bytes());
+        }
+        Ok(outputs
+            .into_iter()
+            .map(|(rel_path, bytes)| ImportedAsset { rel_path, bytes })
+            .collect())
+    }
+}
+
+struct LevelTileset {
+    file_name: String,
+    texture: String,
+    tile_size: (u32, u32),
+    columns: u32,
+    rows: u32,
+    solid: BTreeSet<i32>,
+}
+
+enum LayerTiles {
+    /// Tile ids into `LevelTileset` at this index.
+    Atlas(usize, Vec<i32>),
+    /// Hidden collision-only grid; solid cells are `0`.
+    Collision,
+}
+
+struct LevelLayer {
+    name: String,
+    width: u32,
+    height: u32,
+    cell: (u32, u32),
+    offset: (f64, f64),
+    visible: bool,
+    tiles: LayerTiles,
+    /// Row-major, `-1` empty; only used by `LayerTiles::Collision`.
+    collision: Vec<i32>,
+}
+
+struct LevelEntity {
+    name: String,
+    kind: String,
+    position: (f64, f64),
+    vars: Vec<(String, String)>,
+}
+
+struct Level {
+    name: String,
+    /// Bottom layer first.
+    layers: Vec<LevelLayer>,
+    entities: Vec<LevelEntity>,
+}
+
+fn level_error(msg: &str) -> StaticPipelineError {
+    StaticPipelineError::SceneParse(format!("level import: {msg}"))
+}
+
+fn str_of<'a>(value: &'a Value, key: &str) -> &'a str {
+    value.get(key).and_then(Value::as_str).unwrap_or("")
+}
+
+fn i64_of(value: &Value, key: &str) -> i64 {
+    value.get(key).and_then(Value::as_i64).unwrap_or(0)
+}
+
+fn f64_of(value: &Value, key: &str) -> f64 {
+    value.get(key).and_then(Value::as_f64).unwrap_or(0.0)
+}
+
+fn u32_of(value: &Value, key: &str) -> u32 {
+    u32::try_from(i64_of(value, key)).unwrap_or(0)
+}
+
+fn array_of<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value
+        .get(key)
+        .and_then(Value::as_array)
+        .map_or(&[][..], Vec::as_slice)
+}
+
+/// `rel` resolved against the directory of `source`, kept inside `res/`.
+fn resolve_res_rel(source: &ImportSource<'_>, rel: &str) -> Result<String, StaticPipelineError> {
+    let mut parts = match source.rel_path.rsplit_once('/') {
+        Some((dir, _)) => dir.split('/').collect::<Vec<_>>(),
+        None => Vec::new(),
+    };
+    for part in rel.split(['/', '\\']) {
+        match part {
+            "" | "." => {}
+            ".." => {
+                if parts.pop().is_none() {
+                    return Err(level_error(&format!("`{rel}` points outside res/")));
+                }
+            }
+            part => parts.push(part),
+        }
+    }
+    Ok(parts.join("/"))
+}
+
+fn read_related_json(source: &ImportSource<'_>, rel: &str) -> Result<Value, StaticPipelineError> {
+    let res_rel = resolve_res_rel(source, rel)?;
+    let bytes = fs::read(source.res_dir.join(&res_rel))?;
+    serde_json::from_slice(&bytes).map_err(|err| level_error(&format!("`{rel}`: {err}")))
+}
+
+fn is_collision_name(name: &str) -> bool {
+    name.eq_ignore_ascii_case("collision") || name.eq_ignore_ascii_case("solid")
+}
+
+fn read_ldtk(
+    source: &ImportSource<'_>,
+    json: &Value,
+) -> Result<(Vec<Level>, Vec<LevelTileset>), StaticPipelineError> {
+    let mut tilesets = Vec::new();
+    let mut tileset_by_uid = HashMap::<i64, usize>::new();
+    let defs = json.get("defs");
+    for def in defs.map_or(&[][..], |defs| array_of(defs, "tilesets")) {
+        let Some(rel) = def.get("relPath").and_then(Value::as_str) else {
+            // Embedded LDtk icon atlases have no image on disk.
+            continue;
+        };
+        let name = str_of(def, "identifier");
+        if i64_of(def, "spacing") != 0 || i64_of(def, "padding") != 0 {
+            return Err(level_error(&format!(
+                "tileset `{name}` uses spacing/padding, which .ptileset atlases do not support"
+            )));
+        }
+        let mut solid = BTreeSet::new();
+        for tag in array_of(def, "enumTags") {
+            if is_collision_name(str_of(tag, "enumValueId")) {
+                solid.extend(
+                    array_of(tag, "tileIds")
+                        .iter()
+                        .filter_map(Value::as_i64)
+                        .filter_map(|id| i32::try_from(id).ok()),
+                );
+            }
+        }
+        let grid = u32_of(def, "tileGridSize").max(1);
+        tileset_by_uid.insert(i64_of(def, "uid"), tilesets.len());
+        tilesets.push(LevelTileset {
+            file_name: scene_key(name),
+            texture: format!("res://{}", resolve_res_rel(source, rel)?),
+            tile_size: (grid, grid),
+            columns: u32_of(def, "__cWid"),
+            rows: u32_of(def, "__cHei"),
+            solid,
+        });
+    }
+
+    let mut levels = Vec::new();
+    for level in array_of(json, "levels") {
+        let external;
+        let level_json = match level.get("externalRelPath").and_then(Value::as_str) {
+            Some(rel) if level.get("layerInstances").is_none_or(Value::is_null) => {
+                external = read_related_json(source, rel)?;
+                &external
+            }
+            _ => level,
+        };
+        let mut out = Level {
+            name: scene_key(str_of(level_json, "identifier")),
+            layers: Vec::new(),
+            entities: Vec::new(),
+        };
+        // LDtk lists the top layer first.
+        for layer in array_of(level_json, "layerInstances").iter().rev() {
+            read_ldtk_layer(layer, &tileset_by_uid, &mut out);
+        }
+        levels.push(out);
+    }
+    if levels.is_empty() {
+        return Err(level_error("no levels"));
+    }
+    Ok((levels, tilesets))
+}
+
+fn read_ldtk_layer(layer: &Value, tileset_by_uid: &HashMap<i64, usize>, out: &mut Level) {
+    let name = str_of(layer, "__identifier").to_string();
+    let width = u32_of(layer, "__cWid");
+    let height = u32_of(layer, "__cHei");
+    let grid = u32_of(layer, "__gridSize").max(1);
+    let offset = (
+        f64_of(layer, "__pxTotalOffsetX"),
+        f64_of(layer, "__pxTotalOffsetY"),
+    );
+    let visible = layer
+        .get("visible")
+        .and_then(Value::as_bool)
+        .unwrap_or(true);
+    let cells = width as usize * height as usize;
+
+    if str_of(layer, "__type") == "Entities" {
+        for entity in array_of(layer, "entityInstances") {
+            let px = array_of(entity, "px");
+            let x = px.first().and_then(Value::as_f64).unwrap_or(0.0) + offset.0;
+            let y = px.get(1).and_then(Value::as_f64).unwrap_or(0.0) + offset.1;
+            let vars = array_of(entity, "fieldInstances")
+                .iter()
+                .filter_map(|field| {
+                    let float = str_of(field, "__type") == "Float";
+                    scene_literal(field.get("__value")?, float)
+                        .map(|value| (str_of(field, "__identifier").to_string(), value))
+                })
+                .collect();
+            let kind = str_of(entity, "__identifier").to_string();
+            out.entities.push(LevelEntity {
+                name: kind.clone(),
+                kind,
+                position: (x, y),
+                vars,
+            });
+        }
+        return;
+    }
+
+    let intgrid = array_of(layer, "intGridCsv");
+    if !intgrid.is_empty() {
+        let collision = intgrid
+            .iter()
+            .take(cells)
+            .map(|value| {
+                if value.as_i64().unwrap_or(0) != 0 {
+                    0
+                } else {
+                    -1
+                }
+            })
+            .collect::<Vec<_>>();
+        out.layers.push(LevelLayer {
+            name: format!("{name}_collision"),
+            width,
+            height,
+            cell: (grid, grid),
+            offset,
+            visible: false,
+            tiles: LayerTiles::Collision,
+            collision,
+        });
+    }
+
+    let Some(tileset) = layer
+        .get("__tilesetDefUid")
+        .and_then(Value::as_i64)
+        .and_then(|uid| tileset_by_uid.get(&uid))
+    else {
+        return;
+    };
+    let mut tiles = vec![-1; cells];
+    let mut any = false;
+    for tile in array_of(layer, "gridTiles")
+        .iter()
+        .chain(array_of(layer, "autoLayerTiles"))
+    {
+        let px = array_of(tile, "px");
+        let cx = px.first().and_then(Value::as_u64).unwrap_or(0) / grid as u64;
+        let cy = px.get(1).and_then(Value::as_u64).unwrap_or(0) / grid as u64;
+        if cx >= width as u64 || cy >= height as u64 {
+            continue;
+        }
+        if let Ok(id) = i32::try_from(i64_of(tile, "t")) {
+            tiles[(cy * width as u64 + cx) as usize] = id;
+            any = true;
+        }
+    }
+    if any {
+        out.layers.push(LevelLayer {
+            name,
+            width,
+            height,
+            cell: (grid, grid),
+            offset,
+            visible,
+            tiles: LayerTiles::Atlas(*tileset, tiles),
+            collision: Vec::new(),
+        });
+    }
+}
+
+fn read_tiled(
+    source: &ImportSource<'_>,
+    json: &Value,
+) -> Result<(Vec<Level>, Vec<LevelTileset>), StaticPipelineError> {
+    if json.get("infinite").and_then(Value::as_bool) == Some(true) {
+        return Err(level_error("infinite Tiled maps are not supported"));
+    }
+    let orientation = str_of(json, "orientation");
+    if !orientation.is_empty() && orientation != "orthogonal" {
+        return Err(level_error(&format!(
+            "`{orientation}` Tiled maps are not supported; use orthogonal"
+        )));
+    }
+
+    let mut tilesets = Vec::new();
+    let mut first_gids = Vec::new();
+    for entry in array_of(json, "tilesets") {
+        let first_gid = i64_of(entry, "firstgid");
+        let (def, base_rel) = match entry.get("source").and_then(Value::as_str) {
+            Some(rel) if rel.to_ascii_lowercase().ends_with(".tsx") => {
+                return Err(level_error(&format!(
+                    "external tileset `{rel}` is XML; export it as JSON (.tsj)"
+                )));
+            }
+            Some(rel) => (read_related_json(source, rel)?, rel.to_string()),
+            None => (entry.clone(), String::new()),
+        };
+        let name = str_of(&def, "name");
+        if i64_of(&def, "spacing") != 0 || i64_of(&def, "margin") != 0 {
+            return Err(level_error(&format!(
+                "tileset `{name}` uses spacing/margin, which .ptileset atlases do not support"
+            )));
+        }
+        let image = str_of(&def, "image");
+        if image.is_empty() {
+            return Err(level_error(&format!(
+                "tileset `{name}` is an image collection; only single-image atlases are supported"
+            )));
+        }
+        // Images in an external tileset are relative to that tileset.
+        let image_rel = match base_rel.rsplit_once(['/', '\\']) {
+            Some((dir, _)) => format!("{dir}/{image}"),
+            None => image.to_string(),
+        };
+        let solid = array_of(&def, "tiles")
+            .iter()
+            .filter(|tile| {
+                tile.get("objectgroup").is_some()
+                    || array_of(tile, "properties").iter().any(|prop| {
+                        is_collision_name(str_of(prop, "name"))
+                            && prop.get("value").and_then(Value::as_bool) == Some(true)
+                    })
+            })
+            .filter_map(|tile| i32::try_from(i64_of(tile, "id")).ok())
+            .collect();
+        let columns = u32_of(&def, "columns").max(1);
+        let count = u32_of(&def, "tilecount");
+        first_gids.push(first_gid);
+        tilesets.push(LevelTileset {
+            file_name: scene_key(name),
+            texture: format!("res://{}", resolve_res_rel(source, &image_rel)?),
+            tile_size: (u32_of(&def, "tilewidth"), u32_of(&def, "tileheight")),
+            columns,
+            rows: count.div_ceil(columns),
+            solid,
+        });
+    }
+
+    let stem = std::path::Path::new(source.rel_path)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("level");
+    let mut level = Level {
+        name: scene_key(stem),
+        layers: Vec::new(),
+        entities: Vec::new(),
+    };
+    let cell = (u32_of(json, "tilewidth"), u32_of(json, "tileheight"));
+    read_tiled_layers(array_of(json, "layers"), cell, &first_gids, &mut level)?;
+    Ok((vec![level], tilesets))
+}
+
+/// Tiled gids carry flip flags in the top bits.
+const TILED_GID_MASK: u32 = 0x1FFF_FFFF;
+
+fn read_tiled_layers(
+    layers: &[Value],
+    cell: (u32, u32),
+    first_gids: &[i64],
+    out: &mut Level,
+) -> Result<(), StaticPipelineError> {
+    for layer in layers {
+        let name = str_of(layer, "name").to_string();
+        let offset = (f64_of(layer, "offsetx"), f64_of(layer, "offsety"));
+        let visible = layer
+            .get("visible")
+            .and_then(Value::as_bool)
+            .unwrap_or(true);
+        match str_of(layer, "type") {
+            "group" => read_tiled_layers(array_of(layer, "layers"), cell, first_gids, out)?,
+            "tilelayer" => {
+                if layer.get("encoding").and_then(Value::as_str) == Some("base64") {
+                    return Err(level_error(&format!(
+                        "layer `{name}` is base64; set Tiled's tile layer format to CSV"
+                    )));
+                }
+                let width = u32_of(layer, "width");
+                let height = u32_of(layer, "height");
+                // One TileMap2D per tileset the layer draws from.
+                let mut per_tileset = BTreeMap::<usize, Vec<i32>>::new();
+                let cells = width as usize * height as usize;
+                for (index, gid) in array_of(layer, "data").iter().take(cells).enumerate() {
+                    let gid = gid.as_u64().unwrap_or(0) as u32 & TILED_GID_MASK;
+                    if gid == 0 {
+                        continue;
+                    }
+                    let Some(tileset) = first_gids.iter().rposition(|first| *first <= gid as i64)
+                    else {
+                        continue;
+                    };
+                    let id = gid as i64 - first_gids[tileset];
+                    per_tileset
+                        .entry(tileset)
+                        .or_insert_with(|| vec![-1; cells])[index] = id as i32;
+                }
+                let split = per_tileset.len() > 1;
+                for (tileset, tiles) in per_tileset {
+                    out.layers.push(LevelLayer {
+                        name: if split {
+                            format!("{name}_{tileset}")
+                        } else {
+                            name.clone()
+                        },
+                        width,
+                        height,
+                        cell,
+                        offset,
+                        visible,
+                        tiles: LayerTiles::Atlas(tileset, tiles),
+                        collision: Vec::new(),
+                    });
+                }
+            }
+            "objectgroup" => {
+                for object in array_of(layer, "objects") {
+                    let class = match str_of(object, "type") {
+                        "" => str_of(object, "class"),
+                        kind => kind,
+                    };
+                    let name = match str_of(object, "name") {
+                        "" => class,
+                        name => name,
+                    };
+                    let vars = array_of(object, "properties")
+                        .iter()
+                        .filter_map(|prop| {
+                            let float = str_of(prop, "type") == "float";
+                            scene_literal(prop.get("value")?, float)
+                                .map(|value| (str_of(prop, "name").to_string(), value))
+                        })
+                        .collect();
+                    out.entities.push(LevelEntity {
+                        name: if name.is_empty() { "object" } else { name }.to_string(),
+                        kind: class.to_string(),
+                        position: (
+                            f64_of(object, "x") + offset.0,
+                            f64_of(object, "y") + offset.1,
+                        ),
+                        vars,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Bool, number and string values as scene literals; anything else is skipped.
+fn scene_literal(value: &Value, float: bool) -> Option<String> {
+    match value {
+        Value::Bool(v) => Some(v.to_string()),
+        Value::Number(n) if float => n.as_f64().map(|v| format!("{v:?}")),
+        Value::Number(n) => n
+            .as_i64()
+            .map(|v| v.to_string())
+            .or_else(|| n.as_f64().map(|v| format!("{v:?}"))),
+        Value::String(s) => Some(format!("\"{}\"", s.replace('"', "'").replace('\n', " "))),
+        _ => None,
+    }
+}
+
+/// `res://` scripts by snake_case file stem, so entity `PlayerStart` finds
+/// `res://scripts/player_start.rs`. Shortest path wins on duplicates, then
+/// the lexically first, so the pick never depends on walk order.
+fn scripts_by_stem(source: &ImportSource<'_>) -> HashMap<String, String> {
+    let mut out = HashMap::<String, String>::new();
+    let Ok(files) = perro_io::walkdir::collect_file_paths(source.res_dir, source.res_dir) else {
+        return out;
+    };
+    for rel in files {
+        let rel = rel.replace('\\', "/");
+        let Some(stem) = rel
+            .strip_suffix(".rs")
+            .map(|path| path.rsplit('/').next().unwrap_or(path))
+        else {
+            continue;
+        };
+        let path = format!("res://{rel}");
+        match out.get(stem) {
+            Some(existing)
+                if (existing.len(), existing.as_str()) <= (path.len(), path.as_str()) => {}
+            _ => {
+                out.insert(stem.to_string(), path);
+            }
+        }
+    }
+    out
+}
+
+fn snake_case(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len() + 4);
+    let mut prev_lower = false;
+    for ch in raw.chars() {
+        if ch.is_ascii_uppercase() {
+            if prev_lower {
+                out.push('_');
+            }
+            out.push(ch.to_ascii_lowercase());
+            prev_lower = false;
+        } else if ch.is_ascii_alphanumeric() {
+            out.push(ch);
+            prev_lower = true;
+        } else {
+            if !out.ends_with('_') && !out.is_empty() {
+                out.push('_');
+            }
+            prev_lower = false;
+        }
+    }
+    out.trim_end_matches('_').to_string()
+}
+
+fn ptileset_text(tileset: &LevelTileset) -> String {
+    let (w, h) = tileset.tile_size;
+    let mut out = String::new();
+    let _ = writeln!(out, "texture = \"{}\"", tileset.texture);
+    let _ = writeln!(out, "tile_size = ({w}, {h})");
+    let _ = writeln!(out, "columns = {}", tileset.columns);
+    let _ = writeln!(out, "rows = {}\n", tileset.rows);
+    out.push_str("tiles = [\n");
+    for id in 0..tileset.columns * tileset.rows {
+        let (x, y) = (id % tileset.columns, id / tileset.columns);
+        let collision = if tileset.solid.contains(&(id as i32)) {
+            " collision = true"
+        } else {
+            ""
+        };
+        let _ = writeln!(out, "    {{ id = {id} atlas = ({x}, {y}){collision} }},");
+    }
+    out.push_str("]\n");
+    out
+}
+
+fn collision_ptileset_text(texture: &str, w: u32, h: u32) -> String {
+    format!(
+        "texture = \"{texture}\"\ntile_size = ({w}, {h})\ncolumns = 1\nrows = 1\n\ntiles = [\n    {{ id = 0 atlas = (0, 0) collision = true }},\n]\n"
+    )
+}
+
+/// Level pixels grow down; Perro 2D grows up. Keeps `0` from printing as `-0`.
+fn flip_y(y: f64) -> f64 {
+    if y == 0.0 { 0.0 } else { -y }
+}
+
+/// `name`, then `name_2`, `name_3`, ... so node keys stay unique.
+fn unique_key(used: &mut BTreeSet<String>, raw: &str) -> String {
+    let base = scene_key(raw);
+    let mut key = base.clone();
+    let mut n = 2;
+    while !used.insert(key.clone()) {
+        key = format!("{base}_{n}");
+        n += 1;
+    }
+    key
+}
+
+fn level_scene_text(
+    level: &Level,
+    tileset_paths: &[String],
+    collision_paths: &BTreeMap<(u32, u32), String>,
+    scripts: &HashMap<String, String>,
+) -> String {
+    let mut used = BTreeSet::new();
+    let root = unique_key(&mut used, &level.name);
+    let mut out = String::new();
+    let _ = writeln!(out, "$root = @{root}\n");
+    let _ = writeln!(out, "[{root}]\n    [Node2D/]\n[/{root}]");
+
+    for (z_index, layer) in level.layers.iter().enumerate() {
+        let (tileset, tiles, collision) = match &layer.tiles {
+            LayerTiles::Atlas(index, tiles) => {
+                (tileset_paths.get(*index).map(String::as_str), tiles, false)
+            }
+            LayerTiles::Collision => (
+                collision_paths.get(&layer.cell).map(String::as_str),
+                &layer.collision,
+                true,
+            ),
+        };
+        let Some(tileset) = tileset else {
+            continue;
+        };
+        let key = unique_key(&mut used, &layer.name);
+        let _ = writeln!(out, "\n[{key}]\nparent = @{root}");
+        out.push_str("    [TileMap2D]\n");
+        let _ = writeln!(out, "        tileset = \"{tileset}\"");
+        let _ = writeln!(out, "        width = {}", layer.width);
+        let _ = writeln!(out, "        height = {}", layer.height);
+        out.push_str("        empty_tile = -1\n");
+        out.push_str("        tiles = [\n");
+        for row in tiles.chunks(layer.width.max(1) as usize) {
+            let row = row.iter().map(i32::to_string).collect::<Vec<_>>();
+            let _ = writeln!(out, "            {},", row.join(", "));
+        }
+        out.push_str("        ]\n");
+        let _ = writeln!(out, "        collision_enabled = {collision}");
+        out.push_str("        [Node2D]\n");
+        let _ = writeln!(
+            out,
+            "            position = ({}, {})",
+            layer.offset.0,
+            flip_y(layer.offset.1)
+        );
+        let _ = writeln!(out, "            z_index = {z_index}");
+        let _ = writeln!(out, "            visible = {}", layer.visible);
+        out.push_str("        [/Node2D]\n");
+        out.push_str("    [/TileMap2D]\n");
+        let _ = writeln!(out, "[/{key}]");
+    }
+
+    for entity in &level.entities {
+        let key = unique_key(&mut used, &entity.name);
+        let _ = writeln!(out, "\n[{key}]\nparent = @{root}");
+        if let Some(script) = scripts.get(&snake_case(&entity.kind)) {
+            let _ = writeln!(out, "script = \"{script}\"");
+        }
+        if !entity.vars.is_empty() {
+            let vars = entity
+                .vars
+                .iter()
+                .map(|(name, value)| format!("{} = {value}", scene_key(name)))
+                .collect::<Vec<_>>();
+            let _ = writeln!(out, "script_vars = {{ {} }}", vars.join(", "));
+        }
+        out.push_str("    [Node2D]\n");
+        let _ = writeln!(
+            out,
+            "        position = ({}, {})",
+            entity.position.0,
+            flip_y(entity.position.1)
+        );
+        out.push_str("    [/Node2D]\n");
+        let _ = writeln!(out, "[/{key}]");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::path::{Path, PathBuf};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_res(tag: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or(0);
+        let res = std::env::temp_dir().join(format!("perro_levels_{tag}_{nanos}"));
+        fs::create_dir_all(res.join("levels")).expect("create res");
+        fs::create_dir_all(res.join("scripts")).expect("create scripts");
+        fs::write(res.join("scripts/player_start.rs"), "").expect("write script");
+        res
+    }
+
+    fn import(res: &Path, rel: &str, json: &Value) -> BTreeMap<String, String> {
+        fs::write(res.join(rel), json.to_string()).expect("write source");
+        let path = res.join(rel);
+        let source = ImportSource {
+            path: &path,
+            rel_path: rel,
+            res_dir: res,
+        };
+        LevelImportPlugin
+            .import(&source)
+            .expect("import")
+            .into_iter()
+            .map(|asset| {
+                let text = String::from_utf8_lossy(&asset.bytes).into_owned();
+                (asset.rel_path, text)
+            })
+            .collect()
+    }
+
+    fn assert_scene_parses(text: &str) {
+        perro_scene::Parser::new(text)
+            .try_parse_scene()
+            .expect("generated scene parses");
+    }
+
+    #[test]
+    fn ldtk_levels_become_tilemap_scenes() {
+        let res = temp_res("ldtk");
+        let ldtk = json!({
+            "defs": { "tilesets": [{
+                "uid": 7, "identifier": "Cave", "relPath": "../art/cave.png",
+                "tileGridSize": 16, "__cWid": 2, "__cHei": 1, "spacing": 0, "padding": 0,
+                "enumTags": [{ "enumValueId": "Solid", "tileIds": [1] }]
+            }]},
+            "levels": [{
+                "identifier": "Level_0",
+                "layerInstances": [
+                    {
+                        "__identifier": "Entities", "__type": "Entities",
+                        "__cWid": 2, "__cHei": 2, "__gridSize": 16,
+                        "entityInstances": [{
+                            "__identifier": "PlayerStart", "px": [8, 24],
+                            "fieldInstances": [
+                                { "__identifier": "lives", "__type": "Int", "__value": 3 },
+                                { "__identifier": "speed", "__type": "Float", "__value": 2 }
+                            ]
+                        }]
+                    },
+                    {
+                        "__identifier": "Ground", "__type": "Tiles",
+                        "__cWid": 2, "__cHei": 2, "__gridSize": 16, "__tilesetDefUid": 7,
+                        "gridTiles": [{ "px": [0, 16], "t": 1 }, { "px": [16, 16], "t": 0 }]
+                    },
+                    {
+                        "__identifier": "Walls", "__type": "IntGrid",
+                        "__cWid": 2, "__cHei": 2, "__gridSize": 16,
+                        "intGridCsv": [1, 0, 0, 1]
+                    }
+                ]
+            }]
+        });
+        let outputs = import(&res, "levels/world.ldtk", &ldtk);
+
+        let tileset = &outputs["levels/world/Cave.ptileset"];
+        assert!(tileset.contains("texture = \"res://art/cave.png\""));
+        assert!(tileset.contains("{ id = 1 atlas = (1, 0) collision = true },"));
+        assert!(perro_render_bridge::parse_ptileset_source(tileset).is_some());
+        assert!(
+            perro_render_bridge::parse_ptileset_source(
+                &outputs["levels/world/collision_16x16.ptileset"]
+            )
+            .is_some()
+        );
+        assert!(outputs.contains_key("levels/world/collision.png"));
+
+        let scene = &outputs["levels/world/Level_0.scn"];
+        assert_scene_parses(scene);
+        assert!(scene.contains("$root = @Level_0"));
+        // IntGrid is the bottom layer, so its collision map comes first.
+        let walls = scene.find("[Walls_collision]").expect("collision layer");
+        let ground = scene.find("[Ground]").expect("tile layer");
+        assert!(walls < ground);
+        assert!(scene.contains("            -1, -1,\n            1, 0,\n"));
+        assert!(scene.contains("script = \"res://scripts/player_start.rs\""));
+        assert!(scene.contains("script_vars = { lives = 3, speed = 2.0 }"));
+        assert!(scene.contains("position = (8, -24)"));
+        let _ = fs::remove_dir_all(res);
+    }
+
+    #[test]
+    fn tiled_map_splits_layers_per_tileset() {
+        let res = temp_res("tiled");
+        let tmj = json!({
+            "orientation": "orthogonal", "infinite": false,
+            "width": 2, "height": 1, "tilewidth": 8, "tileheight": 8,
+            "tilesets": [
+                { "firstgid": 1, "name": "a", "image": "a.png", "tilewidth": 8, "tileheight": 8,
+                  "columns": 2, "tilecount": 4,
+                  "tiles": [{ "id": 3, "properties": [{ "name": "collision", "type": "bool", "value": true }] }] },
+                { "firstgid": 5, "name": "b", "image": "b.png", "tilewidth": 8, "tileheight": 8,
+                  "columns": 1, "tilecount": 1 }
+            ],
+            "layers": [
+                { "type": "tilelayer", "name": "ground", "width": 2, "height": 1,
+                  "data": [4, 5 | 0x8000_0000u32] },
+                { "type": "objectgroup", "name": "things", "objects": [
+                    { "name": "", "type": "PlayerStart", "x": 4, "y": 6,
+                      "properties": [{ "name": "hp", "type": "int", "value": 5 }] }
+                ]}
+            ]
+        });
+        let outputs = import(&res, "levels/map.tmj", &tmj);
+
+        assert!(
+            outputs["levels/map/a.ptileset"]
+                .contains("{ id = 3 atlas = (1, 1) collision = true },")
+        );
+        assert!(outputs["levels/map/a.ptileset"].contains("texture = \"res://levels/a.png\""));
+        assert_eq!(outputs["levels/map/b.ptileset"].matches("id = ").count(), 1);
+
+        let scene = &outputs["levels/map.scn"];
+        assert_scene_parses(scene);
+        assert!(scene.contains("[ground_0]"));
+        assert!(scene.contains("[ground_1]"));
+        assert!(scene.contains("            3, -1,\n"));
+        assert!(scene.contains("            -1, 0,\n"));
+        assert!(scene.contains("[PlayerStart]"));
+        assert!(scene.contains("script_vars = { hp = 5 }"));
+        let _ = fs::remove_dir_all(res);
+    }
+
+    #[test]
+    fn rejects_paths_outside_res_and_unsupported_maps() {
+        let res = temp_res("reject");
+        let path = res.join("map.tmj");
+        let source = ImportSource {
+            path: &path,
+            rel_path: "map.tmj",
+            res_dir: &res,
+        };
+        assert!(resolve_res_rel(&source, "../secret.png").is_err());
+        assert_eq!(
+            resolve_res_rel(&source, "./art/../tiles/a.png").expect("inside"),
+            "tiles/a.png"
+        );
+        assert!(read_tiled(&source, &json!({ "infinite": true })).is_err());
+        assert!(read_tiled(&source, &json!({ "orientation": "isometric" })).is_err());
+        let _ = fs::remove_dir_all(res);
+    }
+
+    #[test]
+    fn entity_names_map_to_snake_case_scripts() {
+        assert_eq!(snake_case("PlayerStart"), "player_start");
+        assert_eq!(snake_case("Enemy Spawner"), "enemy_spawner");
+        assert_eq!(snake_case("coin"), "coin");
+    }
+}
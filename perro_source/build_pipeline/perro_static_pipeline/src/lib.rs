@@ -1,16 +1,20 @@
 mod animation_trees;
 mod animations;
+mod aseprite;
 mod audios;
 mod cache;
 mod collision_trimeshes;
 mod csvs;
 mod error;
 mod fonts;
+mod imports;
+mod levels;
 mod localizations;
 mod materials;
 mod meshes;
 mod navmeshes;
 mod particles;
+mod scene_stats;
 mod scenes;
 mod shaders;
 mod skeletons;
@@ -24,11 +28,18 @@ pub(crate) use cache::{
 
 pub use animation_trees::generate_static_animation_trees;
 pub use animations::generate_static_animations;
+pub use aseprite::AsepriteImportPlugin;
 pub use audios::generate_static_audios;
 pub use collision_trimeshes::generate_static_collision_trimeshes;
 pub use csvs::generate_static_csvs;
 pub use error::StaticPipelineError;
 pub use fonts::generate_static_fonts;
+pub use imports::{
+    CommandImportPlugin, ImportPlugin, ImportReport, ImportSource, ImportedAsset,
+    builtin_import_plugins, register_import_plugin, registered_import_plugins, run_import_plugins,
+    unregister_import_plugin,
+};
+pub use levels::LevelImportPlugin;
 pub use localizations::generate_empty_localizations;
 pub use localizations::generate_static_localizations;
 pub use materials::generate_static_materials;
@@ -50,7 +61,10 @@ use std::{
     marker::PhantomData,
     path::{Path, PathBuf},
     rc::Rc,
-    sync::atomic::{AtomicBool, Ordering},
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
 };
 
 use perro_asset_formats::dlc::{DlcAssetAccess, DlcAssetFlags, DlcAssetKind};
@@ -80,6 +94,59 @@ pub(crate) fn demo_mode_active() -> bool {
     DEMO_MODE.load(Ordering::SeqCst)
 }
 
+static SCENE_TARGET: Mutex<Option<perro_scene::SceneTarget>> = Mutex::new(None);
+
+#[must_use = "dropping guard restores prior scene target"]
+pub struct StaticSceneTargetGuard(Option<perro_scene::SceneTarget>);
+
+/// Platform / features that scene `@if` guards resolve against while
+/// baking. Without a pushed target, the build host is used.
+pub fn push_scene_target(target: perro_scene::SceneTarget) -> StaticSceneTargetGuard {
+    let mut slot = SCENE_TARGET.lock().unwrap_or_else(|err| err.into_inner());
+    StaticSceneTargetGuard(slot.replace(target))
+}
+
+impl Drop for StaticSceneTargetGuard {
+    fn drop(&mut self) {
+        let mut slot = SCENE_TARGET.lock().unwrap_or_else(|err| err.into_inner());
+        *slot = self.0.take();
+    }
+}
+
+pub(crate) fn scene_target() -> perro_scene::SceneTarget {
+    SCENE_TARGET
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .clone()
+        .unwrap_or_else(perro_scene::SceneTarget::host)
+}
+
+static SCENE_LINT: Mutex<Option<perro_project::SceneLintConfig>> = Mutex::new(None);
+
+#[must_use = "dropping guard restores prior scene lint limits"]
+pub struct StaticSceneLintGuard(Option<perro_project::SceneLintConfig>);
+
+/// `[scene_lint]` limits scene baking warns past. Without a pushed config,
+/// the defaults apply.
+pub fn push_scene_lint(config: perro_project::SceneLintConfig) -> StaticSceneLintGuard {
+    let mut slot = SCENE_LINT.lock().unwrap_or_else(|err| err.into_inner());
+    StaticSceneLintGuard(slot.replace(config))
+}
+
+impl Drop for StaticSceneLintGuard {
+    fn drop(&mut self) {
+        let mut slot = SCENE_LINT.lock().unwrap_or_else(|err| err.into_inner());
+        *slot = self.0.take();
+    }
+}
+
+pub(crate) fn scene_lint() -> perro_project::SceneLintConfig {
+    SCENE_LINT
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .unwrap_or_default()
+}
+
 #[derive(Clone, Debug)]
 pub struct StaticPipelineOverrides {
     pub res_dir: PathBuf,
@@ -262,6 +329,13 @@ pub(crate) fn strip_asset_prefix(path: &str) -> Option<String> {
     path.strip_prefix(&asset_prefix()).map(str::to_string)
 }
 
+/// Reads an `@extends` base scene from this build's asset space.
+pub(crate) fn load_extended_scene_source(res_dir: &Path, path: &str) -> Result<String, String> {
+    let rel = strip_asset_prefix(path)
+        .ok_or_else(|| format!("base scene `{path}` is not in this build's asset space"))?;
+    fs::read_to_string(res_dir.join(rel)).map_err(|err| format!("failed to read `{path}`: {err}"))
+}
+
 pub(crate) fn ensure_unique_hashes<'a, I>(kind: &str, paths: I) -> Result<(), StaticPipelineError>
 where
     I: IntoIterator<Item = &'a str>,
@@ -622,8 +696,11 @@ mod tests {
         parent: None,
         script: None,
         clear_script: false,
+        enabled: true,
         root_of: None,
         script_vars: Cow::Borrowed(&[]),
+        meta: Cow::Borrowed(&[]),
+        connections: Cow::Borrowed(&[]),
     }];
     static SCENE_NAMES: &[Cow<'static, str>] = &[Cow::Borrowed("Sprite")];
     static SCENE_ITEM: Scene = Scene {
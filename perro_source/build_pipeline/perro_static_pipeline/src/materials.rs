@@ -141,6 +141,11 @@ pub fn generate_static_materials(project_root: &Path) -> Result<(), StaticPipeli
     Ok(())
 }
 
+/// Whether `source` is a `.pmat` the static tables can embed.
+pub(crate) fn pmat_source_is_valid(source: &str) -> bool {
+    load_pmat_literal(source).is_some()
+}
+
 mod model;
 use model::*;
 mod parse;
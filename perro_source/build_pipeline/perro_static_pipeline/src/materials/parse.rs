@@ -56,7 +56,7 @@ pub(super) fn load_pmat_literal(source: &str) -> Option<MaterialLiteral> {
 }
 
 pub(super) fn parse_pmat_object(text: &str) -> Option<Vec<SceneObjectField>> {
-    let value = std::panic::catch_unwind(|| Parser::new(text).parse_value_literal()).ok()?;
+    let value = Parser::new(text).try_parse_value_literal().ok()?;
     match value {
         SceneValue::Object(entries) => Some(entries.into_owned()),
         _ => None,
@@ -489,11 +489,7 @@ pub(super) fn as_alpha_mode(value: &SceneValue) -> Option<u8> {
 }
 
 pub(super) fn as_color4(value: &SceneValue) -> Option<[f32; 4]> {
-    match value {
-        SceneValue::Vec4 { x, y, z, w } => Some([*x, *y, *z, *w]),
-        SceneValue::Vec3 { x, y, z } => Some([*x, *y, *z, 1.0]),
-        _ => None,
-    }
+    value.as_color()
 }
 
 pub(super) fn as_texture_slot(value: &SceneValue) -> Option<u32> {
@@ -0,0 +1,232 @@
+use perro_project::SceneLintConfig;
+use perro_scene::{NodeFieldType, Scene, SceneAssetKind, SceneKey, SceneValue};
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::Path,
+};
+
+/// Per-scene numbers the bake checks against `[scene_lint]`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct SceneStats {
+    pub nodes: usize,
+    pub scripts: usize,
+    /// Deepest parent chain, root = 1.
+    pub max_depth: usize,
+    /// Decoded RGBA8 + mip chain of each distinct `res://` texture.
+    pub texture_bytes: u64,
+    /// Nodes whose parent is gone, or that sit beside a declared root
+    /// w/o a parent of their own.
+    pub orphans: Vec<String>,
+}
+
+pub(crate) fn scene_stats(scene: &Scene, res_dir: &Path) -> SceneStats {
+    let parents = scene
+        .nodes
+        .iter()
+        .map(|node| (node.key, node.parent))
+        .collect::<HashMap<SceneKey, Option<SceneKey>>>();
+    let mut depths = HashMap::<SceneKey, usize>::with_capacity(parents.len());
+    let mut stats = SceneStats {
+        nodes: scene.nodes.len(),
+        ..SceneStats::default()
+    };
+    let mut textures = BTreeSet::new();
+    for node in scene.nodes.iter() {
+        if node.script.is_some() {
+            stats.scripts += 1;
+        }
+        stats.max_depth = stats
+            .max_depth
+            .max(node_depth(node.key, &parents, &mut depths));
+        let orphaned = match node.parent {
+            Some(parent) => !parents.contains_key(&parent),
+            None => scene.root.is_some_and(|root| root != node.key),
+        };
+        if orphaned {
+            stats
+                .orphans
+                .push(scene.key_name_or_id(node.key).into_owned());
+        }
+        collect_texture_refs(node, &mut textures);
+    }
+    stats.texture_bytes = textures
+        .iter()
+        .filter_map(|rel| image::image_dimensions(res_dir.join(rel)).ok())
+        .map(|(width, height)| texture_bytes(width, height))
+        .sum();
+    stats
+}
+
+/// Walks up to the first node w/ a known depth. Cycles and missing parents
+/// end the chain instead of looping.
+fn node_depth(
+    key: SceneKey,
+    parents: &HashMap<SceneKey, Option<SceneKey>>,
+    depths: &mut HashMap<SceneKey, usize>,
+) -> usize {
+    let mut chain = Vec::new();
+    let mut current = Some(key);
+    let mut base = 0;
+    while let Some(node) = current {
+        if let Some(depth) = depths.get(&node) {
+            base = *depth;
+            break;
+        }
+        if chain.contains(&node) || !parents.contains_key(&node) {
+            break;
+        }
+        chain.push(node);
+        current = parents.get(&node).copied().flatten();
+    }
+    for (offset, node) in chain.iter().rev().enumerate() {
+        depths.insert(*node, base + offset + 1);
+    }
+    depths.get(&key).copied().unwrap_or(base)
+}
+
+fn collect_texture_refs(node: &perro_scene::SceneNodeEntry, out: &mut BTreeSet<String>) {
+    let mut data = Some(&node.data);
+    while let Some(level) = data {
+        for (name, value) in level.fields.iter() {
+            let SceneValue::Str(raw) = value else {
+                continue;
+            };
+            let is_texture = perro_scene::scene_node_field(level.node_type, name.as_ref())
+                .is_some_and(|field| {
+                    matches!(field.ty, NodeFieldType::Asset(SceneAssetKind::Texture))
+                });
+            // dlc:// textures ship w/ their pack, not this build
+            if is_texture && let Some(rel) = raw.strip_prefix("res://") {
+                out.insert(rel.to_string());
+            }
+        }
+        data = level.base_ref();
+    }
+}
+
+/// RGBA8 w/ a full mip chain, which adds about a third.
+fn texture_bytes(width: u32, height: u32) -> u64 {
+    u64::from(width) * u64::from(height) * 4 * 4 / 3
+}
+
+/// One line per exceeded limit, plus one per orphaned node.
+pub(crate) fn scene_lint_warnings(stats: &SceneStats, limits: &SceneLintConfig) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let over = |value: u64, limit: u32| limit > 0 && value > u64::from(limit);
+    if over(stats.nodes as u64, limits.max_nodes) {
+        warnings.push(format!(
+            "{} nodes (max_nodes = {})",
+            stats.nodes, limits.max_nodes
+        ));
+    }
+    if over(stats.max_depth as u64, limits.max_depth) {
+        warnings.push(format!(
+            "nesting {} deep (max_depth = {})",
+            stats.max_depth, limits.max_depth
+        ));
+    }
+    if over(stats.scripts as u64, limits.max_scripts) {
+        warnings.push(format!(
+            "{} scripted nodes (max_scripts = {})",
+            stats.scripts, limits.max_scripts
+        ));
+    }
+    let texture_mb = stats.texture_bytes.div_ceil(1024 * 1024);
+    if over(texture_mb, limits.max_texture_mb) {
+        warnings.push(format!(
+            "~{texture_mb} MB of textures (max_texture_mb = {})",
+            limits.max_texture_mb
+        ));
+    }
+    for node in &stats.orphans {
+        warnings.push(format!(
+            "node `{node}` has no parent in the scene; it loads beside the root"
+        ));
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SceneStats, scene_lint_warnings, scene_stats, texture_bytes};
+    use perro_project::SceneLintConfig;
+    use perro_scene::Parser;
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn scene_stats_count_nodes_depth_scripts_and_textures() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or(0);
+        let res = std::env::temp_dir().join(format!("perro_scene_stats_{nanos}"));
+        fs::create_dir_all(&res).expect("create res");
+        image::RgbaImage::new(64, 32)
+            .save(res.join("hero.png"))
+            .expect("write texture");
+        let scene = Parser::new(
+            r#"
+            $root = @main
+            [main]
+            script = "res://scripts/main.rs"
+            [Node2D/]
+            [/main]
+
+            [hero]
+            parent = @main
+            script = "res://scripts/hero.rs"
+            [Sprite2D]
+                texture = "res://hero.png"
+            [/Sprite2D]
+            [/hero]
+
+            [shadow]
+            parent = @hero
+            [Sprite2D]
+                texture = "res://hero.png"
+            [/Sprite2D]
+            [/shadow]
+
+            [stray]
+            [Node2D/]
+            [/stray]
+            "#,
+        )
+        .parse_scene();
+
+        let stats = scene_stats(&scene, &res);
+        assert_eq!(stats.nodes, 4);
+        assert_eq!(stats.scripts, 2);
+        assert_eq!(stats.max_depth, 3);
+        // shared texture counts once
+        assert_eq!(stats.texture_bytes, texture_bytes(64, 32));
+        assert_eq!(stats.orphans, vec!["stray".to_string()]);
+        let _ = fs::remove_dir_all(&res);
+    }
+
+    #[test]
+    fn scene_lint_warns_past_limits_and_zero_disables() {
+        let stats = SceneStats {
+            nodes: 12,
+            scripts: 3,
+            max_depth: 5,
+            texture_bytes: 3 * 1024 * 1024,
+            orphans: Vec::new(),
+        };
+        let limits = SceneLintConfig {
+            max_nodes: 10,
+            max_depth: 0,
+            max_scripts: 3,
+            max_texture_mb: 2,
+        };
+        assert_eq!(
+            scene_lint_warnings(&stats, &limits),
+            vec![
+                "12 nodes (max_nodes = 10)".to_string(),
+                "~3 MB of textures (max_texture_mb = 2)".to_string(),
+            ]
+        );
+        assert!(scene_lint_warnings(&stats, &SceneLintConfig::default()).is_empty());
+    }
+}
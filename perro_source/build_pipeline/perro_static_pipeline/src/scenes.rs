@@ -1,18 +1,30 @@
 use crate::{
-    StaticPipelineError, asset_prefix, asset_uri, ensure_unique_hashes, res_dir, static_dir,
-    strip_asset_prefix, write_hash_const, write_static_lookup_fn,
+    StaticPipelineError, asset_prefix, asset_uri, embedded_dir, ensure_unique_hashes,
+    load_extended_scene_source, prune_embedded_dir, res_dir, static_dir, strip_asset_prefix,
+    write_hash_const, write_static_lookup_fn,
 };
 use perro_asset_formats::source_ext;
 use perro_io::walkdir::collect_file_paths;
-use perro_scene::{NodeType, Parser, SceneFieldName, SceneNodeData, SceneNodeDataBase, SceneValue};
+use perro_scene::{
+    NodeType, Parser, SceneFieldName, SceneNodeData, SceneNodeDataBase, SceneObjectField,
+    SceneValue, deps::SceneResourceKind,
+};
 use perro_structs::Color;
 use rayon::prelude::*;
-use std::{borrow::Cow, collections::HashMap, fmt::Write as _, fs, io, path::Path};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fmt::Write as _,
+    fs, io,
+    path::Path,
+};
 
 pub fn generate_static_scenes(project_root: &Path) -> Result<(), StaticPipelineError> {
     let res_dir = res_dir(project_root);
     let static_dir = static_dir(project_root);
+    let embedded_scenes_dir = embedded_dir(project_root).join("scenes");
     fs::create_dir_all(&static_dir)?;
+    fs::create_dir_all(&embedded_scenes_dir)?;
 
     let mut scene_paths = Vec::<String>::new();
 
@@ -32,35 +44,68 @@ pub fn generate_static_scenes(project_root: &Path) -> Result<(), StaticPipelineE
     scene_paths.sort();
     ensure_unique_hashes("scene", scene_paths.iter().map(String::as_str))?;
 
+    let lint_limits = crate::scene_lint();
     let mut emitted_scenes = scene_paths
         .par_iter()
-        .map(|res_path| -> io::Result<(String, EmittedScene)> {
+        .map(|res_path| -> io::Result<(String, EmittedScene, String)> {
             let rel = strip_asset_prefix(res_path).ok_or_else(|| {
                 io::Error::other(format!("scene path not in asset space: {res_path}"))
             })?;
-            let full_path = res_dir.join(rel);
+            let full_path = res_dir.join(&rel);
             let src = fs::read_to_string(&full_path)?;
-            let mut parsed = std::panic::catch_unwind(|| Parser::new(&src).parse_scene())
-                .map_err(|_| io::Error::other(format!("failed to parse scene: {res_path}")))?;
+            let load_base = |path: &str| load_extended_scene_source(&res_dir, path);
+            let mut parsed = Parser::new(&src)
+                .with_scene_loader(&load_base)
+                .with_target(crate::scene_target())
+                .try_parse_scene()
+                .map_err(|err| {
+                    io::Error::other(format!("failed to parse scene {res_path}: {err}"))
+                })?;
             perro_scene::filter_demo_scene(&mut parsed, crate::demo_mode_active())
                 .map_err(|err| io::Error::other(format!("{res_path}: {err}")))?;
             validate_demo_scene_paths(&parsed)
                 .map_err(|err| io::Error::other(format!("{res_path}: {err}")))?;
+            validate_scene_resource_refs(&parsed, &res_dir)
+                .map_err(|err| io::Error::other(format!("{res_path}: {err}")))?;
+            let stats = crate::scene_stats::scene_stats(&parsed, &res_dir);
+            let warnings = crate::scene_stats::scene_lint_warnings(&stats, &lint_limits);
             if let Some(mount_name) = static_dlc_mount_name() {
                 resolve_scene_dlc_self_paths(&mut parsed, &mount_name);
             }
-            let emitted = emit_static_scene_const(res_path, &parsed)
+            let mut emitted = emit_static_scene_const(res_path, &parsed)
                 .map_err(|err| io::Error::other(err.to_string()))?;
-            Ok((res_path.clone(), emitted))
+            emitted.lint_warnings = warnings;
+            // Pre-parsed twin for runtime paths that miss the static table
+            // (`dlc://` mounts, static fallback); packers ship it over the text.
+            let compiled_rel = perro_scene::compiled_scene_path(&rel).into_owned();
+            let compiled_path = embedded_scenes_dir.join(&compiled_rel);
+            if let Some(parent) = compiled_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            crate::write_if_changed(&compiled_path, &perro_scene::encode_scene(&parsed))?;
+            Ok((res_path.clone(), emitted, compiled_rel))
         })
         .collect::<io::Result<Vec<_>>>()?;
     emitted_scenes.sort_by(|a, b| a.0.cmp(&b.0));
+    // warn, don't fail: limits are content budgets, not correctness
+    for (res_path, emitted, _) in &emitted_scenes {
+        for warning in &emitted.lint_warnings {
+            eprintln!("perro: scene {res_path}: {warning}");
+        }
+    }
+    prune_embedded_dir(
+        &embedded_scenes_dir,
+        &emitted_scenes
+            .iter()
+            .map(|(_, _, compiled_rel)| compiled_rel.clone())
+            .collect::<HashSet<_>>(),
+    )?;
 
     let mut scene_defs = String::new();
     let mut any_uses_empty_keys = false;
     let mut any_uses_empty_tags = false;
     let mut any_uses_empty_fields = false;
-    for (_res_path, emitted) in emitted_scenes {
+    for (_res_path, emitted, _compiled_rel) in emitted_scenes {
         scene_defs.push_str(&emitted.code);
         any_uses_empty_keys |= emitted.uses_empty_keys;
         any_uses_empty_tags |= emitted.uses_empty_tags;
@@ -115,7 +160,7 @@ pub fn generate_static_scenes(project_root: &Path) -> Result<(), StaticPipelineE
     let scenes_src = format!(
         "// Auto-generated by Perro Static Pipeline. Do not edit.\n\
 #![allow(unused_imports)]\n\
-use perro_scene::{{NodeType, SceneFieldName, SceneNodeDataBase, SceneObjectField, SceneValue, SceneValueKey, SceneNodeData, SceneNodeEntry, Scene, SceneKey}};\n\
+use perro_scene::{{NodeType, SceneConnection, SceneFieldName, SceneNodeDataBase, SceneObjectField, SceneValue, SceneValueKey, SceneNodeData, SceneNodeEntry, Scene, SceneKey}};\n\
 use std::borrow::Cow;\n\n\
 {shared_consts}\
 {scene_defs}\n\
@@ -145,6 +190,7 @@ fn validate_demo_scene_paths(scene: &perro_scene::Scene) -> Result<(), String> {
             }
         }
         validate_demo_fields(node.script_vars.as_ref())?;
+        validate_demo_fields(node.meta.as_ref())?;
         validate_demo_data(&node.data)?;
     }
     Ok(())
@@ -193,11 +239,44 @@ fn validate_demo_path(field: &str, path: &str) -> Result<(), String> {
     }
 }
 
+/// Every `res://` material / animation a scene names must exist, parse, and
+/// not be excluded from the build, so it ships in the same static tables.
+/// `dlc://` refs resolve against their pack at runtime.
+fn validate_scene_resource_refs(scene: &perro_scene::Scene, res_dir: &Path) -> Result<(), String> {
+    for resource in perro_scene::deps::scene_resource_refs(scene) {
+        let Some(relative) = resource.path.strip_prefix("res://") else {
+            continue;
+        };
+        let kind = resource.kind.name();
+        let node = &resource.node;
+        let path = &resource.path;
+        if perro_io::walkdir::is_relative_path_excluded(relative) {
+            return Err(format!(
+                "node `{node}` refs {kind} `{path}`, which is excluded from the build"
+            ));
+        }
+        let text = fs::read_to_string(res_dir.join(relative))
+            .map_err(|err| format!("node `{node}` refs missing {kind} `{path}`: {err}"))?;
+        let parsed = match resource.kind {
+            SceneResourceKind::Material => crate::materials::pmat_source_is_valid(&text)
+                .then_some(())
+                .ok_or_else(|| "must be an object with at least one valid field".to_string()),
+            SceneResourceKind::Animation => perro_animation::parse_panim(&text).map(drop),
+            SceneResourceKind::AnimationTree => perro_animation::parse_panimtree(&text).map(drop),
+            SceneResourceKind::Particle | SceneResourceKind::UiStyle => Ok(()),
+        };
+        parsed.map_err(|err| format!("node `{node}` refs invalid {kind} `{path}`: {err}"))?;
+    }
+    Ok(())
+}
+
 struct EmittedScene {
     code: String,
     uses_empty_keys: bool,
     uses_empty_tags: bool,
     uses_empty_fields: bool,
+    /// `[scene_lint]` findings, printed once every scene is baked.
+    lint_warnings: Vec<String>,
 }
 
 fn emit_static_scene_const(
@@ -267,8 +346,25 @@ fn emit_static_scene_const(
             out.push_str("];\n");
             tags_name
         };
+        let connections_ref = if node.connections.is_empty() {
+            "&[]".to_string()
+        } else {
+            let connections_name = format!("CONNECTIONS_{}_{}", scene_ident, index);
+            let _ = writeln!(out, "const {connections_name}: &[SceneConnection] = &[");
+            for connection in node.connections.as_ref() {
+                let _ = writeln!(
+                    out,
+                    "    SceneConnection {{ signal: Cow::Borrowed(\"{}\"), target: Cow::Borrowed(\"{}\"), method: Cow::Borrowed(\"{}\") }},",
+                    escape_str(connection.signal.as_ref()),
+                    escape_str(connection.target.as_ref()),
+                    escape_str(connection.method.as_ref())
+                );
+            }
+            out.push_str("];\n");
+            connections_name
+        };
         node_entries.push_str(&format!(
-            "    SceneNodeEntry {{ data: {data}, has_data_override: {has_data_override}, key: SceneKey({key}u32), name: {name}, tags: Cow::Borrowed({tags}), children: Cow::Borrowed({children}), parent: {parent}, script: {script}, clear_script: {clear_script}, root_of: {root_of}, script_vars: Cow::Borrowed({script_vars}) }},\n",
+            "    SceneNodeEntry {{ data: {data}, has_data_override: {has_data_override}, key: SceneKey({key}u32), name: {name}, tags: Cow::Borrowed({tags}), children: Cow::Borrowed({children}), parent: {parent}, script: {script}, clear_script: {clear_script}, enabled: {enabled}, root_of: {root_of}, script_vars: Cow::Borrowed({script_vars}), meta: Cow::Borrowed({meta}), connections: Cow::Borrowed({connections}) }},\n",
             data = data_const,
             has_data_override = node.has_data_override,
             key = node.key.as_u32(),
@@ -281,37 +377,25 @@ fn emit_static_scene_const(
             },
             script = opt_static_script_str(&node.script),
             clear_script = node.clear_script,
+            enabled = node.enabled,
             root_of = opt_static_root_of_str(&node.root_of),
-            script_vars = if node.script_vars.is_empty() {
-                uses_empty_fields = true;
-                "EMPTY_SCENE_FIELDS".to_string()
-            } else {
-                let script_vars_name = format!("SCRIPT_VARS_{}_{}", scene_ident, index);
-                let mut nested_consts = String::new();
-                let mut script_var_entries = String::new();
-                for (name, value) in node.script_vars.as_ref() {
-                    let emitted = emit_value_with_consts(
-                        &mut nested_consts,
-                        &scene_ident,
-                        value,
-                        &mut counter,
-                        None,
-                        None,
-                        true,
-                    );
-                    let _ = writeln!(
-                        script_var_entries,
-                        "    ({}, {}),",
-                        emit_custom_field_name(name),
-                        emitted
-                    );
-                }
-                out.push_str(&nested_consts);
-                let _ = writeln!(out, "const {script_vars_name}: &[SceneObjectField] = &[");
-                out.push_str(&script_var_entries);
-                out.push_str("];\n");
-                script_vars_name
-            },
+            connections = connections_ref,
+            script_vars = emit_custom_fields_const(
+                &mut out,
+                &format!("SCRIPT_VARS_{}_{}", scene_ident, index),
+                &scene_ident,
+                &node.script_vars,
+                &mut counter,
+                &mut uses_empty_fields,
+            ),
+            meta = emit_custom_fields_const(
+                &mut out,
+                &format!("META_{}_{}", scene_ident, index),
+                &scene_ident,
+                &node.meta,
+                &mut counter,
+                &mut uses_empty_fields,
+            ),
         ));
     }
 
@@ -335,6 +419,7 @@ fn emit_static_scene_const(
         uses_empty_keys,
         uses_empty_tags,
         uses_empty_fields,
+        lint_warnings: Vec::new(),
     })
 }
 
@@ -424,6 +509,45 @@ fn emit_field_name(name: &SceneFieldName) -> String {
     }
 }
 
+/// `script_vars` / `meta` as a `const_name` slice of custom-named fields.
+fn emit_custom_fields_const(
+    out: &mut String,
+    const_name: &str,
+    scene_ident: &str,
+    fields: &[SceneObjectField],
+    counter: &mut usize,
+    uses_empty_fields: &mut bool,
+) -> String {
+    if fields.is_empty() {
+        *uses_empty_fields = true;
+        return "EMPTY_SCENE_FIELDS".to_string();
+    }
+    let mut nested_consts = String::new();
+    let mut entries = String::new();
+    for (name, value) in fields {
+        let emitted = emit_value_with_consts(
+            &mut nested_consts,
+            scene_ident,
+            value,
+            counter,
+            None,
+            None,
+            true,
+        );
+        let _ = writeln!(
+            entries,
+            "    ({}, {}),",
+            emit_custom_field_name(name),
+            emitted
+        );
+    }
+    out.push_str(&nested_consts);
+    let _ = writeln!(out, "const {const_name}: &[SceneObjectField] = &[");
+    out.push_str(&entries);
+    out.push_str("];\n");
+    const_name.to_string()
+}
+
 fn emit_custom_field_name(name: &SceneFieldName) -> String {
     format!(
         "SceneFieldName::Custom(Cow::Borrowed(\"{}\"))",
@@ -471,6 +595,12 @@ fn emit_value_with_consts(
         SceneValue::UVec4 { x, y, z, w } => {
             format!("SceneValue::UVec4 {{ x: {x}, y: {y}, z: {z}, w: {w} }}")
         }
+        SceneValue::Color { r, g, b, a } => {
+            format!("SceneValue::Color {{ r: {r}, g: {g}, b: {b}, a: {a} }}")
+        }
+        SceneValue::Rect { x, y, w, h } => {
+            format!("SceneValue::Rect {{ x: {x:?}, y: {y:?}, w: {w:?}, h: {h:?} }}")
+        }
         SceneValue::Str(s) => emit_static_scene_value_str(node_type, field_name, s.as_ref()),
         SceneValue::Hashed(v) => format!("SceneValue::Hashed({v}u64)"),
         SceneValue::Key(s) => {
@@ -622,6 +752,7 @@ fn is_static_ui_node(node_type: &str) -> bool {
             | "UiVBox"
             | "UiGrid"
             | "UiTreeList"
+            | "UiMarkdown"
     )
 }
 
@@ -664,6 +795,7 @@ fn resolve_scene_dlc_self_paths(scene: &mut perro_scene::Scene, mount_name: &str
             node.root_of = Some(Cow::Owned(root_of.replacen(prefix, replacement_ref, 1)));
         }
         resolve_scene_value_fields_dlc_self(node.script_vars.to_mut(), prefix, replacement_ref);
+        resolve_scene_value_fields_dlc_self(node.meta.to_mut(), prefix, replacement_ref);
         resolve_scene_node_data_dlc_self(&mut node.data, prefix, replacement_ref);
     }
 }
@@ -722,10 +854,12 @@ fn sanitize_ident(path: &str) -> String {
 mod tests {
     use super::{
         emit_static_node_type, emit_static_scene_const, emit_static_scene_value_str,
-        resolve_scene_dlc_self_paths,
+        resolve_scene_dlc_self_paths, validate_scene_resource_refs,
     };
     use perro_scene::NodeType;
     use perro_scene::Parser;
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
 
     #[test]
     fn static_node_type_supports_registry_nodes() {
@@ -826,6 +960,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scene_resource_refs_must_exist_and_parse() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or(0);
+        let res = std::env::temp_dir().join(format!("perro_scene_refs_{nanos}"));
+        fs::create_dir_all(&res).expect("create res");
+        fs::write(
+            res.join("rock.pmat"),
+            "type = \"standard\"\nroughness_factor = 0.7\n",
+        )
+        .expect("write material");
+        fs::write(res.join("broken.pmat"), "nope").expect("write material");
+        let scene_with = |material: &str| {
+            Parser::new(&format!(
+                "$root = @rock\n[rock]\n[MeshInstance3D]\n    materials = [\"{material}\"]\n[/MeshInstance3D]\n[/rock]\n"
+            ))
+            .parse_scene()
+        };
+
+        validate_scene_resource_refs(&scene_with("res://rock.pmat"), &res)
+            .expect("existing material must pass");
+        let err = validate_scene_resource_refs(&scene_with("res://missing.pmat"), &res)
+            .expect_err("missing material must fail");
+        assert!(err.contains("refs missing material"), "{err}");
+        let err = validate_scene_resource_refs(&scene_with("res://broken.pmat"), &res)
+            .expect_err("invalid material must fail");
+        assert!(err.contains("refs invalid material"), "{err}");
+        validate_scene_resource_refs(&scene_with("dlc://pack/rock.pmat"), &res)
+            .expect("dlc refs resolve at runtime");
+        let _ = fs::remove_dir_all(&res);
+    }
+
     #[test]
     fn static_script_vars_emit_custom_field_names() {
         let scene = Parser::new(
@@ -863,6 +1031,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn static_meta_emits_own_const() {
+        let scene = Parser::new(
+            r#"
+            [chest]
+            meta = { loot_table = "res://loot/chest.ron", weight = 3 }
+            [Node/]
+            [/chest]
+            "#,
+        )
+        .parse_scene();
+
+        let emitted = emit_static_scene_const("res://main.scn", &scene)
+            .expect("required value must be present");
+
+        assert!(emitted.code.contains("meta: Cow::Borrowed(META_"));
+        assert!(
+            emitted
+                .code
+                .contains("SceneFieldName::Custom(Cow::Borrowed(\"loot_table\")), SceneValue::Str")
+        );
+        assert!(
+            emitted
+                .code
+                .contains("script_vars: Cow::Borrowed(EMPTY_SCENE_FIELDS)")
+        );
+    }
+
     #[test]
     fn resolve_scene_dlc_self_paths_rewrites_scene_strings() {
         let mut scene = Parser::new(
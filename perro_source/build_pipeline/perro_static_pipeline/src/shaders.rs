@@ -1,8 +1,10 @@
 use crate::{
     StaticPipelineError, asset_uri, embedded_dir, ensure_unique_hashes, prune_embedded_dir,
-    res_dir, static_dir, write_hash_const, write_if_changed, write_static_lookup_fn,
+    res_dir, static_dir, strip_asset_prefix, write_hash_const, write_if_changed,
+    write_static_lookup_fn,
 };
 use perro_asset_formats::source_ext;
+use perro_graphics_assets::resolve_shader_includes;
 use perro_io::walkdir::collect_file_paths;
 use std::{collections::HashSet, fmt::Write as _, fs, path::Path};
 
@@ -32,7 +34,8 @@ pub fn generate_static_shaders(project_root: &Path) -> Result<(), StaticPipeline
     for rel in shader_paths {
         let res_path = asset_uri(&rel);
         let full_path = res_dir.join(&rel);
-        let source = fs::read_to_string(&full_path)?;
+        let source =
+            flatten_shader_includes(&res_dir, &res_path, &fs::read_to_string(&full_path)?)?;
 
         let output_path = embedded_shaders_dir.join(&rel);
         if let Some(parent) = output_path.parent() {
@@ -102,6 +105,22 @@ pub fn generate_static_shaders(project_root: &Path) -> Result<(), StaticPipeline
     Ok(())
 }
 
+/// Embedded shaders ship flattened, so release builds never resolve
+/// `#include` at runtime and a missing include fails the build.
+fn flatten_shader_includes(
+    res_dir: &Path,
+    res_path: &str,
+    source: &str,
+) -> Result<String, StaticPipelineError> {
+    let resolved = resolve_shader_includes(res_path, source, &mut |path| {
+        let rel =
+            strip_asset_prefix(path).or_else(|| path.strip_prefix("res://").map(str::to_string))?;
+        fs::read_to_string(res_dir.join(rel)).ok()
+    })
+    .map_err(|err| StaticPipelineError::SceneParse(format!("shader include failed: {err}")))?;
+    Ok(resolved.source)
+}
+
 fn escape_str(input: &str) -> String {
     let mut out = String::with_capacity(input.len());
     for ch in input.chars() {
@@ -165,4 +184,55 @@ fn shade_material(in: FragmentInput) -> vec4<f32> {
         assert_eq!(embedded, source);
         let _ = fs::remove_dir_all(root);
     }
+
+    #[test]
+    fn static_shader_embed_flattens_includes() {
+        let root = unique_temp_root("perro_static_shader_include");
+        let res_dir = root.join("res");
+        let embedded_dir = root.join("embedded");
+        let static_dir = root.join("static");
+        fs::create_dir_all(res_dir.join("shaders/lib")).expect("create shader dir");
+        fs::write(
+            res_dir.join("shaders/lib/common.wgsl"),
+            "fn tint(c: vec3<f32>) -> vec3<f32> { return c; }\n",
+        )
+        .expect("write include");
+        fs::write(
+            res_dir.join("shaders/custom.wgsl"),
+            "#include \"res://shaders/lib/common.wgsl\"\nfn shade() {}\n",
+        )
+        .expect("write shader");
+        fs::write(
+            res_dir.join("shaders/broken.wgsl"),
+            "#include \"missing.wgsl\"\n",
+        )
+        .expect("write shader");
+
+        set_static_pipeline_overrides(Some(StaticPipelineOverrides {
+            res_dir: res_dir.clone(),
+            static_dir,
+            embedded_dir: embedded_dir.clone(),
+            asset_prefix: "res://".to_string(),
+        }));
+        let broken = generate_static_shaders(&root);
+        fs::remove_file(res_dir.join("shaders/broken.wgsl")).expect("remove broken shader");
+        let result = generate_static_shaders(&root);
+        set_static_pipeline_overrides(None);
+        let err = broken.expect_err("missing include fails the build");
+        assert!(
+            err.to_string().contains(
+                "res://shaders/broken.wgsl:1: include `res://shaders/missing.wgsl` not found"
+            ),
+            "{err}"
+        );
+        result.expect("generate static shaders");
+
+        let embedded = fs::read_to_string(embedded_dir.join("shaders/shaders/custom.wgsl"))
+            .expect("read embedded");
+        assert_eq!(
+            embedded,
+            "fn tint(c: vec3<f32>) -> vec3<f32> { return c; }\nfn shade() {}\n"
+        );
+        let _ = fs::remove_dir_all(root);
+    }
 }
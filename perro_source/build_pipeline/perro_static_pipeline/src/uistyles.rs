@@ -121,7 +121,7 @@ fn load_uistyle_literal(source: &str) -> Option<UiStyle> {
         wrapped = format!("{{\n{text}\n}}");
         wrapped.as_str()
     };
-    let parsed = std::panic::catch_unwind(|| Parser::new(parse_text).parse_value_literal()).ok()?;
+    let parsed = Parser::new(parse_text).try_parse_value_literal().ok()?;
     let SceneValue::Object(entries) = parsed else {
         return None;
     };
@@ -361,6 +361,7 @@ fn as_color(value: &SceneValue) -> Option<Color> {
     match value {
         SceneValue::Vec4 { x, y, z, w } => Some(Color::new(*x, *y, *z, *w)),
         SceneValue::Vec3 { x, y, z } => Some(Color::rgb(*x, *y, *z)),
+        SceneValue::Color { r, g, b, a } => Some(Color::from_rgba_u8([*r, *g, *b, *a])),
         SceneValue::Str(v) => Color::from_hex(v.as_ref()),
         SceneValue::Key(v) => Color::from_hex(v.as_ref()),
         _ => None,
@@ -97,6 +97,8 @@ fn parse_param(value: &SceneValue, line_no: usize) -> Result<AnimationParam, Str
         SceneValue::Vec2 { x, y } => Ok(AnimationParam::Vec2([*x, *y])),
         SceneValue::Vec3 { x, y, z } => Ok(AnimationParam::Vec3([*x, *y, *z])),
         SceneValue::Vec4 { x, y, z, w } => Ok(AnimationParam::Vec4([*x, *y, *z, *w])),
+        SceneValue::Rect { x, y, w, h } => Ok(AnimationParam::Vec4([*x, *y, *w, *h])),
+        SceneValue::Color { .. } => Ok(AnimationParam::Vec4(value.as_color().unwrap_or_default())),
         SceneValue::Object(fields) => {
             let mut position2 = None;
             let mut rotation2 = None;
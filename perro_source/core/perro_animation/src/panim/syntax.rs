@@ -1,6 +1,7 @@
 fn parse_scene_value(value: &str, line_no: usize) -> Result<SceneValue, String> {
-    std::panic::catch_unwind(|| SceneParser::new(value).parse_value_literal())
-        .map_err(|_| format!("line {}: invalid value `{}`", line_no, value))
+    SceneParser::new(value)
+        .try_parse_value_literal()
+        .map_err(|err| format!("line {line_no}: invalid value `{value}`: {}", err.message))
 }
 
 fn as_text(value: &SceneValue) -> Option<&str> {
@@ -4,8 +4,30 @@ pub mod archive {
     pub const EXTENSION: &str = "perro";
     pub const MAGIC: [u8; 4] = *b"PRA1";
     pub const COMPRESSED_MAGIC: [u8; 4] = *b"PRC1";
-    pub const VERSION: u32 = 1;
+    /// v2: index entries end w/ an xxh3 of the entry's stored bytes.
+    /// v3: then the source mtime + a small key/value metadata block.
+    pub const VERSION: u32 = 3;
     pub const FLAG_COMPRESSED: u32 = 1 << 0;
+    /// w/ `FLAG_COMPRESSED` and/or `FLAG_ENCRYPTED`: entry is a chunk table +
+    /// independently encoded chunks, so readers can inflate part of a file.
+    pub const FLAG_CHUNKED: u32 = 1 << 1;
+    /// w/ `FLAG_COMPRESSED`: LZ4 block(s) instead of zlib.
+    pub const FLAG_LZ4: u32 = 1 << 2;
+    /// w/ `FLAG_COMPRESSED`: zstd frame(s) instead of zlib. Exclusive w/
+    /// `FLAG_LZ4`; compressed entries w/ neither are zlib.
+    pub const FLAG_ZSTD: u32 = 1 << 3;
+    /// Each stored blob (the entry, or each chunk of a chunked one) is
+    /// `nonce || AES-256-GCM ciphertext || tag` over the codec output.
+    pub const FLAG_ENCRYPTED: u32 = 1 << 4;
+    /// Uncompressed bytes per chunk the packer writes. Readers use the size
+    /// stored in each entry.
+    pub const CHUNK_SIZE: u32 = 256 * 1024;
+    /// Delta patch over one base archive: the stored bytes of changed and
+    /// added entries, plus the paths the target removed.
+    pub const PATCH_EXTENSION: &str = "perropatch";
+    pub const PATCH_MAGIC: [u8; 4] = *b"PRP1";
+    /// v2: index entries in the archive v3 layout.
+    pub const PATCH_VERSION: u32 = 2;
 }
 
 pub mod dlc;
@@ -74,6 +96,13 @@ pub mod ptset {
     pub const VERSION: u32 = 1;
 }
 
+pub mod pscn {
+    //! Compiled scene produced from `.scn` text by the static pipeline.
+    pub const EXTENSION: &str = "scnb";
+    pub const MAGIC: &[u8; 5] = b"PSCNB";
+    pub const VERSION: u32 = 2;
+}
+
 pub mod pnav {
     pub const EXTENSION: &str = "pnav";
 }
@@ -133,8 +162,9 @@ pub mod source_ext {
 #[cfg(test)]
 mod tests {
     #[test]
-    fn current_versions_are_v1() {
-        assert_eq!(super::archive::VERSION, 1);
+    fn current_format_versions() {
+        assert_eq!(super::archive::VERSION, 3);
+        assert_eq!(super::archive::PATCH_VERSION, 2);
         assert_eq!(super::dlc::REGISTRY_ABI_VERSION, 1);
         assert_eq!(super::pawdio::VERSION, 1);
         assert_eq!(super::pmesh::VERSION, 1);
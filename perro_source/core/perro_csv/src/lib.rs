@@ -5,6 +5,8 @@ use std::{
 };
 
 const INVALID_COL: usize = usize::MAX;
+/// Key column name used by JSON data tables.
+pub const JSON_TABLE_KEY: &str = "id";
 type U64HashMap<V> = HashMap<u64, V, BuildHasherDefault<U64IdentityHasher>>;
 
 #[derive(Debug)]
@@ -342,6 +344,71 @@ impl CsvBuf {
         Ok(out)
     }
 
+    /// Builds a table from JSON data-table bytes.
+    ///
+    /// Accepts either an object keyed by row key (`{"sword": {"power": 10}}`)
+    /// or an array of row objects (`[{"id": "sword", "power": 10}]`). The key
+    /// column is `id` and always comes first; the remaining columns follow in
+    /// first-seen order (object fields arrive name-sorted, so keyed rows are
+    /// sorted by key). Scalars become cell text, `null` becomes an empty cell,
+    /// and nested arrays/objects are stored as compact JSON text.
+    pub fn from_json_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let value: serde_json::Value = serde_json::from_slice(bytes)
+            .map_err(|err| format!("failed to parse json table: {err}"))?;
+        let rows: Vec<(Option<String>, serde_json::Map<String, serde_json::Value>)> = match value {
+            serde_json::Value::Object(object) => object
+                .into_iter()
+                .map(|(key, row)| match row {
+                    serde_json::Value::Object(fields) => Ok((Some(key), fields)),
+                    _ => Err(format!("json table row `{key}` must be an object")),
+                })
+                .collect::<Result<_, _>>()?,
+            serde_json::Value::Array(items) => items
+                .into_iter()
+                .enumerate()
+                .map(|(index, row)| match row {
+                    serde_json::Value::Object(fields) => Ok((None, fields)),
+                    _ => Err(format!("json table row {index} must be an object")),
+                })
+                .collect::<Result<_, _>>()?,
+            _ => return Err("json table must be an object or an array of objects".to_string()),
+        };
+
+        let mut headers = vec![JSON_TABLE_KEY.to_string()];
+        for (_, fields) in &rows {
+            for name in fields.keys() {
+                if !headers.iter().any(|header| header == name) {
+                    headers.push(name.clone());
+                }
+            }
+        }
+
+        let mut out = Self {
+            headers,
+            rows: Vec::with_capacity(rows.len()),
+        };
+        for (key, mut fields) in rows {
+            let key_field = fields.remove(JSON_TABLE_KEY);
+            let key = match (key, key_field) {
+                (Some(key), _) => key,
+                (None, Some(field)) => json_cell_text(field),
+                (None, None) => String::new(),
+            };
+            let mut row = Vec::with_capacity(out.headers.len());
+            row.push(key);
+            for header in &out.headers[1..] {
+                row.push(
+                    fields
+                        .remove(header)
+                        .map(json_cell_text)
+                        .unwrap_or_default(),
+                );
+            }
+            out.rows.push(row);
+        }
+        Ok(out)
+    }
+
     pub fn from_static(table: &Csv) -> Self {
         let headers = table
             .headers
@@ -458,6 +525,46 @@ impl CsvBuf {
         String::from_utf8(self.to_bytes()?)
             .map_err(|err| format!("failed to encode csv as utf8: {err}"))
     }
+
+    /// Checks the shape a data table needs for keyed row access: non-empty
+    /// unique headers, every row as wide as the header row, and a non-empty
+    /// unique key in the first column.
+    pub fn validate_data_table(&self) -> Result<(), String> {
+        if self.headers.is_empty() {
+            return Err("data table has no columns".to_string());
+        }
+        let mut seen_headers = HashSet::<&str>::with_capacity(self.headers.len());
+        for (col, header) in self.headers.iter().enumerate() {
+            if header.is_empty() {
+                return Err(format!("data table column {col} has an empty header"));
+            }
+            if !seen_headers.insert(header) {
+                return Err(format!("data table header `{header}` is duplicated"));
+            }
+        }
+        let mut seen_keys = HashSet::<&str>::with_capacity(self.rows.len());
+        for (row, values) in self.rows.iter().enumerate() {
+            // Header is line 1, so data row `row` sits on line `row + 2`.
+            let line = row + 2;
+            if values.len() != self.headers.len() {
+                return Err(format!(
+                    "data table row on line {line} has {} cells, expected {}",
+                    values.len(),
+                    self.headers.len()
+                ));
+            }
+            let key = values[0].as_str();
+            if key.is_empty() {
+                return Err(format!("data table row on line {line} has an empty key"));
+            }
+            if !seen_keys.insert(key) {
+                return Err(format!(
+                    "data table key `{key}` on line {line} is duplicated"
+                ));
+            }
+        }
+        Ok(())
+    }
 }
 
 impl From<&Csv> for CsvBuf {
@@ -1091,7 +1198,16 @@ impl CSVQueryRow<'_> {
 
 pub fn parse_csv_static(bytes: &[u8]) -> Result<&'static Csv, String> {
     // Parse every fallible record before leaking promoted storage.
-    let parsed = CsvBuf::from_bytes(bytes)?;
+    Ok(promote_csv_buf(&CsvBuf::from_bytes(bytes)?))
+}
+
+/// JSON counterpart of [`parse_csv_static`]; see [`CsvBuf::from_json_bytes`]
+/// for the accepted shapes.
+pub fn parse_json_table_static(bytes: &[u8]) -> Result<&'static Csv, String> {
+    Ok(promote_csv_buf(&CsvBuf::from_json_bytes(bytes)?))
+}
+
+fn promote_csv_buf(parsed: &CsvBuf) -> &'static Csv {
     let row_capacity = parsed.rows.len();
     let mut interner = LocalCsvInterner::new(row_capacity);
 
@@ -1112,11 +1228,19 @@ pub fn parse_csv_static(bytes: &[u8]) -> Result<&'static Csv, String> {
     }
     index_rows.sort_by_key(|entry| entry.key_hash);
 
-    Ok(Box::leak(Box::new(Csv::new(
+    Box::leak(Box::new(Csv::new(
         headers,
         Box::leak(rows.into_boxed_slice()),
         Box::leak(index_rows.into_boxed_slice()),
-    ))))
+    )))
+}
+
+fn json_cell_text(value: serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(text) => text,
+        other => other.to_string(),
+    }
 }
 
 struct LocalCsvInterner {
@@ -1403,6 +1527,52 @@ mod tests {
         assert_eq!(parsed, csv);
     }
 
+    #[test]
+    fn json_table_reads_keyed_objects_and_arrays() {
+        let keyed = parse_json_table_static(
+            br#"{"sword_01": {"power": 10, "tags": ["edge"], "note": null},
+                 "bow_01": {"power": 8, "ranged": true}}"#,
+        )
+        .expect("test setup must succeed");
+        assert_eq!(keyed.row_count(), 2);
+        assert_eq!(keyed.get_by_header(0, "id"), Some("bow_01"));
+        let sword = keyed.find_primary("sword_01").expect("sword row");
+        let col = |name| keyed.header_index(name).expect("header present");
+        assert_eq!(sword.get(col("power")), Some("10"));
+        assert_eq!(sword.get(col("tags")), Some(r#"["edge"]"#));
+        assert_eq!(sword.get(col("note")), Some(""));
+        assert_eq!(sword.get(col("ranged")), Some(""));
+
+        let listed = CsvBuf::from_json_bytes(br#"[{"name": "Axe", "id": "axe"}, {"id": 7}]"#)
+            .expect("test setup must succeed");
+        assert_eq!(listed.headers(), ["id", "name"]);
+        assert_eq!(listed.get(1, 0), Some("7"));
+        assert_eq!(listed.get(1, 1), Some(""));
+
+        assert!(CsvBuf::from_json_bytes(b"[1, 2]").is_err());
+        assert!(CsvBuf::from_json_bytes(b"\"items\"").is_err());
+    }
+
+    #[test]
+    fn validate_data_table_rejects_bad_shapes() {
+        let ok = CsvBuf::from_bytes(b"id,power\nsword,10\nbow,8\n").expect("test setup");
+        assert!(ok.validate_data_table().is_ok());
+
+        let dup_key = CsvBuf::from_bytes(b"id,power\nsword,10\nsword,8\n").expect("test setup");
+        let err = dup_key.validate_data_table().expect_err("duplicate key");
+        assert!(err.contains("`sword` on line 3"), "{err}");
+
+        let empty_key = CsvBuf::from_bytes(b"id,power\n,10\n").expect("test setup");
+        assert!(empty_key.validate_data_table().is_err());
+
+        let dup_header = CsvBuf::from_bytes(b"id,id\na,b\n").expect("test setup");
+        assert!(dup_header.validate_data_table().is_err());
+
+        let mut ragged = CsvBuf::new(["id", "power"]);
+        ragged.rows.push(vec!["sword".to_string()]);
+        assert!(ragged.validate_data_table().is_err());
+    }
+
     #[test]
     fn csv_promotes_to_buf() {
         let csv = parse_csv_static(b"id,name\nsword,Sword\npotion,Potion\n")
@@ -2,7 +2,9 @@
 //! All IDs use u64 = index (low 32 bits) | generation (high 32 bits). Index 0 = nil.
 //! IDs are created by their owning arena/manager; slot reuse bumps generation so stale IDs are invalid.
 
-use std::{borrow::Cow, fmt, ops::Deref};
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use core::{fmt, ops::Deref};
 
 const STRING_HASH_SEED: u64 = 0xA0761D6478BD642F;
 const STRING_HASH_PRIME: u64 = 0xE7037ED1A0B428DB;
@@ -91,7 +93,7 @@ impl fmt::Display for ParseGenerationalIDError {
     }
 }
 
-impl std::error::Error for ParseGenerationalIDError {}
+impl core::error::Error for ParseGenerationalIDError {}
 
 /// Parse a generational ID from 1-16 hex digits.
 ///
@@ -205,7 +207,7 @@ macro_rules! define_generational {
             }
         }
 
-        impl std::str::FromStr for $type_name {
+        impl core::str::FromStr for $type_name {
             type Err = ParseGenerationalIDError;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -292,6 +294,45 @@ impl NodeID {
     }
 }
 
+/// Node handle kept across frames, e.g. a script's current target. Same bits
+/// as [`NodeID`]; the type just says "check b4 use": resolve it w/
+/// `Nodes().upgrade(..)` / `upgrade_node!`, which compares the slot
+/// generation and yields `None` once the node is freed, even after the slot
+/// is reused. Stored in a `Variant` as its node id.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+#[repr(transparent)]
+pub struct WeakNodeRef(NodeID);
+
+impl WeakNodeRef {
+    #[inline]
+    pub const fn new(id: NodeID) -> Self {
+        Self(id)
+    }
+
+    #[inline]
+    pub const fn nil() -> Self {
+        Self(NodeID::nil())
+    }
+
+    #[inline]
+    pub const fn is_nil(self) -> bool {
+        self.0.is_nil()
+    }
+
+    /// The id as stored, w/o checking it is still live.
+    #[inline]
+    pub const fn id_unchecked(self) -> NodeID {
+        self.0
+    }
+}
+
+impl From<NodeID> for WeakNodeRef {
+    #[inline]
+    fn from(id: NodeID) -> Self {
+        Self(id)
+    }
+}
+
 impl TextureID {
     /// Parse 1-16 hex digits, optionally prefixed with `0x`.
     pub fn parse_str(s: &str) -> Result<Self, ParseGenerationalIDError> {
@@ -0,0 +1,258 @@
+//! Interned, cheaply cloned strings for asset paths and node names.
+//! Every live distinct string is stored once in a process-wide pool; values carry a
+//! precomputed `string_to_u64` hash, so equality is a pointer compare and hashing
+//! writes one u64. The pool holds weak entries, so unused strings are reclaimed.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+
+use crate::string_to_u64;
+
+const MIN_PRUNE_THRESHOLD: usize = 1024;
+
+struct InternPool {
+    buckets: HashMap<u64, Vec<Weak<str>>>,
+    entries: usize,
+    prune_at: usize,
+}
+
+impl InternPool {
+    fn find(&self, text: &str, hash: u64) -> Option<Arc<str>> {
+        self.buckets
+            .get(&hash)?
+            .iter()
+            .filter_map(Weak::upgrade)
+            .find(|entry| entry.as_ref() == text)
+    }
+
+    fn prune(&mut self) {
+        self.buckets.retain(|_, bucket| {
+            bucket.retain(|entry| entry.strong_count() > 0);
+            !bucket.is_empty()
+        });
+        self.entries = self.buckets.values().map(Vec::len).sum();
+        self.prune_at = (self.entries * 2).max(MIN_PRUNE_THRESHOLD);
+    }
+}
+
+fn pool() -> &'static Mutex<InternPool> {
+    static POOL: OnceLock<Mutex<InternPool>> = OnceLock::new();
+    POOL.get_or_init(|| {
+        Mutex::new(InternPool {
+            buckets: HashMap::new(),
+            entries: 0,
+            prune_at: MIN_PRUNE_THRESHOLD,
+        })
+    })
+}
+
+fn intern_str(text: &str, hash: u64) -> Arc<str> {
+    let mut pool = pool()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(existing) = pool.find(text, hash) {
+        return existing;
+    }
+    if pool.entries >= pool.prune_at {
+        pool.prune();
+    }
+    let interned: Arc<str> = Arc::from(text);
+    pool.buckets
+        .entry(hash)
+        .or_default()
+        .push(Arc::downgrade(&interned));
+    pool.entries += 1;
+    interned
+}
+
+/// Number of distinct live strings held by the intern pool.
+pub fn interned_string_count() -> usize {
+    pool()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .buckets
+        .values()
+        .flatten()
+        .filter(|entry| entry.strong_count() > 0)
+        .count()
+}
+
+macro_rules! define_interned_str {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Clone)]
+        pub struct $name {
+            text: Arc<str>,
+            hash: u64,
+        }
+
+        impl $name {
+            /// Interns `text`, reusing the pooled allocation when it was seen before.
+            pub fn new(text: &str) -> Self {
+                let hash = string_to_u64(text);
+                Self {
+                    text: intern_str(text, hash),
+                    hash,
+                }
+            }
+
+            #[inline]
+            pub fn as_str(&self) -> &str {
+                &self.text
+            }
+
+            /// Shared pooled allocation; cloning it never copies the string.
+            #[inline]
+            pub fn as_arc(&self) -> &Arc<str> {
+                &self.text
+            }
+
+            /// Precomputed `string_to_u64` hash of the text.
+            #[inline]
+            pub const fn hash_u64(&self) -> u64 {
+                self.hash
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new("")
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            #[inline]
+            fn deref(&self) -> &str {
+                &self.text
+            }
+        }
+
+        impl AsRef<str> for $name {
+            #[inline]
+            fn as_ref(&self) -> &str {
+                &self.text
+            }
+        }
+
+        impl PartialEq for $name {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                Arc::ptr_eq(&self.text, &other.text)
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl Hash for $name {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                state.write_u64(self.hash);
+            }
+        }
+
+        impl PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            #[inline]
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.as_str().cmp(other.as_str())
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            #[inline]
+            fn eq(&self, other: &str) -> bool {
+                self.as_str() == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            #[inline]
+            fn eq(&self, other: &&str) -> bool {
+                self.as_str() == *other
+            }
+        }
+
+        impl PartialEq<String> for $name {
+            #[inline]
+            fn eq(&self, other: &String) -> bool {
+                self.as_str() == other.as_str()
+            }
+        }
+
+        impl From<&str> for $name {
+            #[inline]
+            fn from(value: &str) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<&String> for $name {
+            #[inline]
+            fn from(value: &String) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<String> for $name {
+            #[inline]
+            fn from(value: String) -> Self {
+                Self::new(&value)
+            }
+        }
+
+        impl From<Cow<'_, str>> for $name {
+            #[inline]
+            fn from(value: Cow<'_, str>) -> Self {
+                Self::new(&value)
+            }
+        }
+
+        impl From<&$name> for $name {
+            #[inline]
+            fn from(value: &$name) -> Self {
+                value.clone()
+            }
+        }
+
+        impl From<$name> for Arc<str> {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.text
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(self.as_str(), f)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+define_interned_str!(
+    /// Interned asset source such as `res://textures/player.png` or a `__cube__` builtin.
+    AssetPath
+);
+
+define_interned_str!(
+    /// Interned node name: `SceneNode::name`, the runtime name index, and the node API.
+    Name
+);
@@ -1,10 +1,20 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod ids;
+#[cfg(feature = "std")]
+pub mod interned;
 mod macros;
 
 pub use ids::*;
+#[cfg(feature = "std")]
+pub use interned::*;
 
 pub mod prelude {
     pub use crate::ids::*;
+    #[cfg(feature = "std")]
+    pub use crate::interned::{AssetPath, Name};
     pub use crate::{func, hash_str, method, sid, signal, smid, tag, tags, timer, var};
 }
 
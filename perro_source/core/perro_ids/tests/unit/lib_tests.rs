@@ -164,3 +164,30 @@ fn tags_macro_builds_slice() {
     assert_eq!(built[1].id(), TagID::from_string("boss"));
     assert_eq!(built[1].name(), "boss");
 }
+
+#[test]
+fn asset_path_interns_shared_allocation() {
+    let a = AssetPath::new("res://textures/player.png");
+    let b = AssetPath::from(String::from("res://textures/player.png"));
+    let c = AssetPath::new("res://textures/enemy.png");
+    assert!(std::sync::Arc::ptr_eq(a.as_arc(), b.as_arc()));
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert_eq!(a, "res://textures/player.png");
+    assert_eq!(a.hash_u64(), string_to_u64("res://textures/player.png"));
+    assert_eq!(&*a, "res://textures/player.png");
+}
+
+#[test]
+fn interned_names_hash_by_precomputed_value() {
+    let mut set = std::collections::HashSet::new();
+    set.insert(Name::new("Player"));
+    set.insert(Name::new("Player"));
+    set.insert(Name::new("Enemy"));
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&Name::from("Enemy")));
+    let empty = Name::default();
+    assert_eq!(empty.as_str(), "");
+    // "Player", "Enemy", "" stay live while held here
+    assert!(interned_string_count() >= 3);
+}
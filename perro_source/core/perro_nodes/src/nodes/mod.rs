@@ -1,6 +1,7 @@
 pub mod camera_stream;
 pub mod node_2d;
 pub mod node_3d;
+pub mod node_meta;
 pub mod node_registry;
 pub mod resources;
 pub mod sub_view;
@@ -10,6 +11,7 @@ pub mod water;
 pub use camera_stream::*;
 pub use node_2d::*;
 pub use node_3d::*;
+pub use node_meta::*;
 pub use node_registry::*;
 pub use perro_structs::BitMask;
 pub use perro_ui::*;
@@ -6,9 +6,17 @@ pub struct Node2D {
     pub transform: Transform2D,
     pub top_level: bool,
     pub z_index: i32,
+    /// Screen-space canvas layer. `0` = world (follow active Camera2D).
+    /// Non-zero: node + 2D descendants ignore camera pos/zoom, layer order
+    /// above (>0) or below (<0) world.
+    pub canvas_layer: i32,
     pub visible: bool,
     pub render_layers: BitMask,
     pub modulate: NodeModulate,
+    /// Render between the last two fixed-step transforms instead of the
+    /// latest one. Smooths nodes moved in `on_fixed_update` when the display
+    /// refreshes faster than the fixed rate.
+    pub interpolate: bool,
 }
 
 impl Node2D {
@@ -18,8 +26,10 @@ impl Node2D {
             top_level: false,
             visible: true,
             z_index: 0,
+            canvas_layer: 0,
             render_layers: BitMask::ALL,
             modulate: NodeModulate::WHITE,
+            interpolate: false,
         }
     }
 }
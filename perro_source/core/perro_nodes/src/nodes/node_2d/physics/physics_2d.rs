@@ -13,6 +13,18 @@ pub enum PhysicsForceProfile {
     Custom,
 }
 
+/// How an area's `gravity` combines with gravity from the world and from
+/// lower-priority areas.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum AreaGravityMode {
+    #[default]
+    Disabled,
+    /// Adds to gravity so far; lower-priority areas still apply.
+    Combine,
+    /// Replaces gravity so far; lower-priority areas are skipped.
+    Replace,
+}
+
 #[derive(Clone, Debug)]
 pub struct PhysicsForceEmitter2D {
     pub base: Node2D,
@@ -110,6 +122,9 @@ impl Default for Shape2D {
 pub struct CollisionShape2D {
     pub base: Node2D,
     pub shape: Shape2D,
+    /// Only blocks character motion against the shape's local +Y; bodies
+    /// pass through from below or the sides.
+    pub one_way: bool,
 }
 
 impl Default for CollisionShape2D {
@@ -126,6 +141,7 @@ impl CollisionShape2D {
                 width: 1.0,
                 height: 1.0,
             },
+            one_way: false,
         }
     }
 }
@@ -209,6 +225,11 @@ pub struct Area2D {
     pub physics_handle: Option<u64>,
     pub collision_layers: BitMask,
     pub collision_mask: BitMask,
+    /// Gravity override for rigid and character bodies inside the area.
+    pub gravity_mode: AreaGravityMode,
+    pub gravity: Vector2,
+    /// Higher priorities apply first when areas overlap.
+    pub priority: i32,
     pub audio_interaction: Option<AudioInteraction>,
 }
 
@@ -226,6 +247,9 @@ impl Area2D {
             physics_handle: None,
             collision_layers: BitMask::ALL,
             collision_mask: BitMask::NONE,
+            gravity_mode: AreaGravityMode::Disabled,
+            gravity: Vector2::new(0.0, -9.81),
+            priority: 0,
             audio_interaction: Some(AudioInteraction::new()),
         }
     }
@@ -343,6 +367,12 @@ pub struct CharacterBody2D {
     pub restitution: f32,
     pub density: f32,
     pub audio_interaction: Option<AudioInteraction>,
+    /// Steepest hit (radians from up) move_and_slide still counts as floor.
+    pub floor_max_angle: f32,
+    /// Downward snap after leaving a floor; 0 disables.
+    pub floor_snap_distance: f32,
+    /// Carry the body along with the floor it stands on.
+    pub inherit_platform_velocity: bool,
 }
 
 impl Default for CharacterBody2D {
@@ -363,6 +393,9 @@ impl CharacterBody2D {
             restitution: 0.0,
             density: 1.0,
             audio_interaction: Some(AudioInteraction::new()),
+            floor_max_angle: core::f32::consts::FRAC_PI_4,
+            floor_snap_distance: 0.0,
+            inherit_platform_velocity: true,
         }
     }
 
@@ -399,6 +432,19 @@ pub struct PinJoint2D {
     pub anchor_b: Vector2,
     pub enabled: bool,
     pub collide_connected: bool,
+    /// Clamp relative rotation to `lower_limit..=upper_limit` (radians).
+    pub limit_enabled: bool,
+    pub lower_limit: f32,
+    pub upper_limit: f32,
+    /// Drive relative rotation toward `motor_target_velocity` (rad/s);
+    /// `motor_max_force` 0 = unlimited.
+    pub motor_enabled: bool,
+    pub motor_target_velocity: f32,
+    pub motor_max_force: f32,
+    /// Joint disables itself once its constraint force passes this; 0 = never.
+    pub break_force: f32,
+    /// Same as `break_force` for constraint torque.
+    pub break_torque: f32,
 }
 
 impl Default for PinJoint2D {
@@ -417,6 +463,14 @@ impl PinJoint2D {
             anchor_b: Vector2::ZERO,
             enabled: true,
             collide_connected: false,
+            limit_enabled: false,
+            lower_limit: 0.0,
+            upper_limit: 0.0,
+            motor_enabled: false,
+            motor_target_velocity: 0.0,
+            motor_max_force: 0.0,
+            break_force: 0.0,
+            break_torque: 0.0,
         }
     }
 }
@@ -446,6 +500,10 @@ pub struct DistanceJoint2D {
     pub collide_connected: bool,
     pub min_distance: f32,
     pub max_distance: f32,
+    /// Joint disables itself once its constraint force passes this; 0 = never.
+    pub break_force: f32,
+    /// Same as `break_force` for constraint torque.
+    pub break_torque: f32,
 }
 
 impl Default for DistanceJoint2D {
@@ -466,6 +524,8 @@ impl DistanceJoint2D {
             collide_connected: false,
             min_distance: 0.0,
             max_distance: 1.0,
+            break_force: 0.0,
+            break_torque: 0.0,
         }
     }
 }
@@ -493,6 +553,10 @@ pub struct FixedJoint2D {
     pub anchor_b: Vector2,
     pub enabled: bool,
     pub collide_connected: bool,
+    /// Joint disables itself once its constraint force passes this; 0 = never.
+    pub break_force: f32,
+    /// Same as `break_force` for constraint torque.
+    pub break_torque: f32,
 }
 
 impl Default for FixedJoint2D {
@@ -511,6 +575,8 @@ impl FixedJoint2D {
             anchor_b: Vector2::ZERO,
             enabled: true,
             collide_connected: false,
+            break_force: 0.0,
+            break_torque: 0.0,
         }
     }
 }
@@ -528,3 +594,61 @@ impl DerefMut for FixedJoint2D {
         &mut self.base
     }
 }
+
+/// Pulls the anchors toward `rest_length` apart like a damped spring.
+#[derive(Clone, Debug)]
+pub struct SpringJoint2D {
+    pub base: Node2D,
+    pub body_a: NodeID,
+    pub body_b: NodeID,
+    pub anchor_a: Vector2,
+    pub anchor_b: Vector2,
+    pub enabled: bool,
+    pub collide_connected: bool,
+    pub rest_length: f32,
+    pub stiffness: f32,
+    pub damping: f32,
+    /// Joint disables itself once its constraint force passes this; 0 = never.
+    pub break_force: f32,
+    /// Same as `break_force` for constraint torque.
+    pub break_torque: f32,
+}
+
+impl Default for SpringJoint2D {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpringJoint2D {
+    pub const fn new() -> Self {
+        Self {
+            base: Node2D::new(),
+            body_a: NodeID::nil(),
+            body_b: NodeID::nil(),
+            anchor_a: Vector2::ZERO,
+            anchor_b: Vector2::ZERO,
+            enabled: true,
+            collide_connected: false,
+            rest_length: 1.0,
+            stiffness: 20.0,
+            damping: 1.0,
+            break_force: 0.0,
+            break_torque: 0.0,
+        }
+    }
+}
+
+impl Deref for SpringJoint2D {
+    type Target = Node2D;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl DerefMut for SpringJoint2D {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
@@ -8,6 +8,10 @@ pub struct Node3D {
     pub visible: bool,
     pub render_layers: BitMask,
     pub modulate: NodeModulate,
+    /// Render between the last two fixed-step transforms instead of the
+    /// latest one. Smooths nodes moved in `on_fixed_update` when the display
+    /// refreshes faster than the fixed rate.
+    pub interpolate: bool,
 }
 
 impl Node3D {
@@ -18,6 +22,7 @@ impl Node3D {
             visible: true,
             render_layers: BitMask::ALL,
             modulate: NodeModulate::WHITE,
+            interpolate: false,
         }
     }
 }
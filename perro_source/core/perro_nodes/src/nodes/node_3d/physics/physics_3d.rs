@@ -3,7 +3,7 @@ use perro_ids::NodeID;
 use perro_structs::{AudioInteraction, BitMask, CollisionPolicy, Vector3};
 use std::ops::{Deref, DerefMut};
 
-use crate::PhysicsForceProfile;
+use crate::{AreaGravityMode, PhysicsForceProfile};
 
 #[derive(Clone, Debug)]
 pub struct PhysicsForceEmitter3D {
@@ -192,6 +192,11 @@ pub struct Area3D {
     pub physics_handle: Option<u64>,
     pub collision_layers: BitMask,
     pub collision_mask: BitMask,
+    /// Gravity override for rigid and character bodies inside the area.
+    pub gravity_mode: AreaGravityMode,
+    pub gravity: Vector3,
+    /// Higher priorities apply first when areas overlap.
+    pub priority: i32,
     pub audio_interaction: Option<AudioInteraction>,
 }
 
@@ -209,6 +214,9 @@ impl Area3D {
             physics_handle: None,
             collision_layers: BitMask::ALL,
             collision_mask: BitMask::NONE,
+            gravity_mode: AreaGravityMode::Disabled,
+            gravity: Vector3::new(0.0, -9.81, 0.0),
+            priority: 0,
             audio_interaction: Some(AudioInteraction::new()),
         }
     }
@@ -380,6 +388,10 @@ pub struct BallJoint3D {
     pub anchor_b: Vector3,
     pub enabled: bool,
     pub collide_connected: bool,
+    /// Joint disables itself once its constraint force passes this; 0 = never.
+    pub break_force: f32,
+    /// Same as `break_force` for constraint torque.
+    pub break_torque: f32,
 }
 
 impl Default for BallJoint3D {
@@ -398,6 +410,8 @@ impl BallJoint3D {
             anchor_b: Vector3::ZERO,
             enabled: true,
             collide_connected: false,
+            break_force: 0.0,
+            break_torque: 0.0,
         }
     }
 }
@@ -426,6 +440,19 @@ pub struct HingeJoint3D {
     pub axis: Vector3,
     pub enabled: bool,
     pub collide_connected: bool,
+    /// Clamp relative rotation to `lower_limit..=upper_limit` (radians).
+    pub limit_enabled: bool,
+    pub lower_limit: f32,
+    pub upper_limit: f32,
+    /// Drive relative rotation toward `motor_target_velocity` (rad/s);
+    /// `motor_max_force` 0 = unlimited.
+    pub motor_enabled: bool,
+    pub motor_target_velocity: f32,
+    pub motor_max_force: f32,
+    /// Joint disables itself once its constraint force passes this; 0 = never.
+    pub break_force: f32,
+    /// Same as `break_force` for constraint torque.
+    pub break_torque: f32,
 }
 
 impl Default for HingeJoint3D {
@@ -445,6 +472,14 @@ impl HingeJoint3D {
             axis: Vector3::new(0.0, 1.0, 0.0),
             enabled: true,
             collide_connected: false,
+            limit_enabled: false,
+            lower_limit: 0.0,
+            upper_limit: 0.0,
+            motor_enabled: false,
+            motor_target_velocity: 0.0,
+            motor_max_force: 0.0,
+            break_force: 0.0,
+            break_torque: 0.0,
         }
     }
 }
@@ -472,6 +507,10 @@ pub struct FixedJoint3D {
     pub anchor_b: Vector3,
     pub enabled: bool,
     pub collide_connected: bool,
+    /// Joint disables itself once its constraint force passes this; 0 = never.
+    pub break_force: f32,
+    /// Same as `break_force` for constraint torque.
+    pub break_torque: f32,
 }
 
 impl Default for FixedJoint3D {
@@ -490,6 +529,8 @@ impl FixedJoint3D {
             anchor_b: Vector3::ZERO,
             enabled: true,
             collide_connected: false,
+            break_force: 0.0,
+            break_torque: 0.0,
         }
     }
 }
@@ -507,3 +548,180 @@ impl DerefMut for FixedJoint3D {
         &mut self.base
     }
 }
+
+/// Pulls the anchors toward `rest_length` apart like a damped spring.
+#[derive(Clone, Debug)]
+pub struct SpringJoint3D {
+    pub base: Node3D,
+    pub body_a: NodeID,
+    pub body_b: NodeID,
+    pub anchor_a: Vector3,
+    pub anchor_b: Vector3,
+    pub enabled: bool,
+    pub collide_connected: bool,
+    pub rest_length: f32,
+    pub stiffness: f32,
+    pub damping: f32,
+    /// Joint disables itself once its constraint force passes this; 0 = never.
+    pub break_force: f32,
+    /// Same as `break_force` for constraint torque.
+    pub break_torque: f32,
+}
+
+impl Default for SpringJoint3D {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpringJoint3D {
+    pub const fn new() -> Self {
+        Self {
+            base: Node3D::new(),
+            body_a: NodeID::nil(),
+            body_b: NodeID::nil(),
+            anchor_a: Vector3::ZERO,
+            anchor_b: Vector3::ZERO,
+            enabled: true,
+            collide_connected: false,
+            rest_length: 1.0,
+            stiffness: 20.0,
+            damping: 1.0,
+            break_force: 0.0,
+            break_torque: 0.0,
+        }
+    }
+}
+
+impl Deref for SpringJoint3D {
+    type Target = Node3D;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl DerefMut for SpringJoint3D {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
+
+/// Ray-cast car: a rigid chassis carried by `VehicleWheel3D` children.
+/// Scripts drive it through the input fields; wheels opt in via `steer` and
+/// `drive`.
+#[derive(Clone, Debug)]
+pub struct VehicleBody3D {
+    pub base: RigidBody3D,
+    /// Forward push per driven wheel; negative reverses.
+    pub engine_force: f32,
+    /// Braking impulse applied on every wheel.
+    pub brake: f32,
+    /// Steer angle of steering wheels in radians; positive turns left.
+    pub steering: f32,
+}
+
+impl Default for VehicleBody3D {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VehicleBody3D {
+    pub const fn new() -> Self {
+        let mut base = RigidBody3D::new();
+        base.mass = 800.0;
+        Self {
+            base,
+            engine_force: 0.0,
+            brake: 0.0,
+            steering: 0.0,
+        }
+    }
+}
+
+impl Deref for VehicleBody3D {
+    type Target = RigidBody3D;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl DerefMut for VehicleBody3D {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
+
+/// Wheel of a parent `VehicleBody3D`. Its position when the vehicle first
+/// simulates is the suspension mount; the runtime then moves + spins the node
+/// so child meshes follow the wheel.
+#[derive(Clone, Debug)]
+pub struct VehicleWheel3D {
+    pub base: Node3D,
+    pub radius: f32,
+    /// Suspension length at rest, measured down from the mount.
+    pub suspension_rest_length: f32,
+    /// How far the suspension may compress or extend past rest.
+    pub suspension_travel: f32,
+    pub suspension_stiffness: f32,
+    /// Damping while the spring compresses.
+    pub suspension_compression: f32,
+    /// Damping while the spring extends back.
+    pub suspension_damping: f32,
+    pub max_suspension_force: f32,
+    /// Grip along the rolling direction; lower values spin out sooner.
+    pub friction_slip: f32,
+    /// Grip across the rolling direction.
+    pub side_friction: f32,
+    /// Follows the vehicle `steering` input.
+    pub steer: bool,
+    /// Receives the vehicle `engine_force` input.
+    pub drive: bool,
+    /// Runtime readback: wheel touched ground last step.
+    pub in_contact: bool,
+    /// Runtime readback: current suspension length.
+    pub suspension_length: f32,
+}
+
+impl Default for VehicleWheel3D {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VehicleWheel3D {
+    pub const fn new() -> Self {
+        Self {
+            base: Node3D::new(),
+            radius: 0.4,
+            suspension_rest_length: 0.3,
+            suspension_travel: 0.2,
+            suspension_stiffness: 30.0,
+            suspension_compression: 0.83,
+            suspension_damping: 0.88,
+            max_suspension_force: 6000.0,
+            friction_slip: 10.5,
+            side_friction: 1.0,
+            steer: false,
+            drive: true,
+            in_contact: false,
+            suspension_length: 0.3,
+        }
+    }
+}
+
+impl Deref for VehicleWheel3D {
+    type Target = Node3D;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl DerefMut for VehicleWheel3D {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
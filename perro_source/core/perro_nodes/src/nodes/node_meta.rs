@@ -0,0 +1,55 @@
+use perro_variant::Variant;
+use std::borrow::Cow;
+
+/// Untyped key/value pairs a node carries outside its data + script state.
+/// Set from scene `meta = { .. }` headers or `set_meta!`; kept in insert order.
+/// The runtime stores it beside the node arena, keyed by `NodeID`, so nodes
+/// w/o meta pay nothing per slot.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeMeta {
+    entries: Vec<(Cow<'static, str>, Variant)>,
+}
+
+impl NodeMeta {
+    pub fn get(&self, key: &str) -> Option<&Variant> {
+        self.entries
+            .iter()
+            .find(|(name, _)| name.as_ref() == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Insert or replace `key`; `Variant::Null` removes it.
+    pub fn set<K>(&mut self, key: K, value: Variant)
+    where
+        K: Into<Cow<'static, str>>,
+    {
+        let key = key.into();
+        if value.is_null() {
+            self.remove(key.as_ref());
+            return;
+        }
+        match self.entries.iter_mut().find(|(name, _)| *name == key) {
+            Some((_, slot)) => *slot = value,
+            None => self.entries.push((key, value)),
+        }
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<Variant> {
+        let index = self.entries.iter().position(|(name, _)| name == key)?;
+        Some(self.entries.remove(index).1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Variant)> {
+        self.entries
+            .iter()
+            .map(|(name, value)| (name.as_ref(), value))
+    }
+}
@@ -8,18 +8,26 @@ use crate::{
     MultiMeshInstance3D, NineSlice2D, NineSliceButton2D, Node2D, Node3D, ParticleEmitter2D,
     ParticleEmitter3D, PhysicsBoneChain2D, PhysicsBoneChain3D, PhysicsForceEmitter2D,
     PhysicsForceEmitter3D, PinJoint2D, PointLight2D, PointLight3D, RayLight2D, RayLight3D,
-    RigidBody2D, RigidBody3D, Skeleton2D, Skeleton3D, Sky3D, SpotLight2D, SpotLight3D, Sprite2D,
-    Sprite3D, StaticBody2D, StaticBody3D, SubView2D, SubView3D, TileMap2D, UiCameraStream,
-    UiSubView, UiVideoPlayer, VideoPlayer2D, VideoPlayer3D, WaterBody2D, WaterBody3D, Webcam,
+    RigidBody2D, RigidBody3D, Skeleton2D, Skeleton3D, Sky3D, SpotLight2D, SpotLight3D,
+    SpringJoint2D, SpringJoint3D, Sprite2D, Sprite3D, StaticBody2D, StaticBody3D, SubView2D,
+    SubView3D, TileMap2D, UiCameraStream, UiSubView, UiVideoPlayer, VehicleBody3D, VehicleWheel3D,
+    VideoPlayer2D, VideoPlayer3D, WaterBody2D, WaterBody3D, Webcam,
 };
-use perro_ids::{NodeID, NodeTag, TagID};
+use perro_ids::{Name, NodeID, NodeTag, TagID};
 use perro_structs::{Transform2D, Transform3D};
 use perro_ui::{
     UiAnimatedImage, UiButton, UiCheckbox, UiColorPicker, UiDropdown, UiGrid, UiHLayout, UiImage,
-    UiImageButton, UiLabel, UiLayout, UiNineSlice, UiNineSliceButton, UiNode, UiNodeBase, UiPanel,
-    UiProgressBar, UiScrollContainer, UiShape, UiTextBlock, UiTextBox, UiTreeList, UiVLayout,
+    UiImageButton, UiLabel, UiLayout, UiMarkdown, UiNineSlice, UiNineSliceButton, UiNode,
+    UiNodeBase, UiPanel, UiProgressBar, UiScrollContainer, UiShape, UiTextBlock, UiTextBox,
+    UiTreeList, UiVLayout,
 };
-use std::borrow::Cow;
+use std::sync::OnceLock;
+
+/// Shared `"Node"` default, so `SceneNode::new` skips the intern pool lock.
+fn default_node_name() -> Name {
+    static NAME: OnceLock<Name> = OnceLock::new();
+    NAME.get_or_init(|| Name::new("Node")).clone()
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(u8)]
@@ -234,7 +242,7 @@ macro_rules! define_scene_nodes {
         #[derive(Clone, Debug)]
         pub struct SceneNode {
             pub data: SceneNodeData,
-            pub name: Cow<'static, str>,
+            pub name: Name,
             pub parent: NodeID,
             pub children: Vec<NodeID>,
             pub tags: Vec<NodeTag>,
@@ -265,7 +273,7 @@ macro_rules! define_scene_nodes {
         impl SceneNode {
             pub fn new(data: SceneNodeData) -> Self {
                 Self {
-                    name: Cow::Borrowed("Node"),
+                    name: default_node_name(),
                     parent: NodeID::nil(),
                     children: Vec::new(),
                     tags: Vec::new(),
@@ -283,7 +291,7 @@ macro_rules! define_scene_nodes {
 
             pub fn set_name<S>(&mut self, name: S)
             where
-                S: Into<Cow<'static, str>>,
+                S: Into<Name>,
             {
                 self.name = name.into();
             }
@@ -893,6 +901,7 @@ define_scene_nodes! {
         PinJoint2D => (Node2D, PinJoint2D, Inline, Renderable::False, InternalUpdate::False, InternalFixedUpdate::True),
         DistanceJoint2D => (Node2D, DistanceJoint2D, Inline, Renderable::False, InternalUpdate::False, InternalFixedUpdate::True),
         FixedJoint2D => (Node2D, FixedJoint2D, Inline, Renderable::False, InternalUpdate::False, InternalFixedUpdate::True),
+        SpringJoint2D => (Node2D, SpringJoint2D, Inline, Renderable::False, InternalUpdate::False, InternalFixedUpdate::True),
 
         // audio
         AudioMask2D => (Node2D, AudioMask2D, Inline, Renderable::False, InternalUpdate::False, InternalFixedUpdate::False),
@@ -942,6 +951,9 @@ define_scene_nodes! {
         BallJoint3D => (Node3D, BallJoint3D, Inline, Renderable::False, InternalUpdate::False, InternalFixedUpdate::True),
         HingeJoint3D => (Node3D, HingeJoint3D, Inline, Renderable::False, InternalUpdate::False, InternalFixedUpdate::True),
         FixedJoint3D => (Node3D, FixedJoint3D, Inline, Renderable::False, InternalUpdate::False, InternalFixedUpdate::True),
+        SpringJoint3D => (Node3D, SpringJoint3D, Inline, Renderable::False, InternalUpdate::False, InternalFixedUpdate::True),
+        VehicleBody3D => (Node3D, VehicleBody3D, Boxed, Renderable::False, InternalUpdate::False, InternalFixedUpdate::True),
+        VehicleWheel3D => (Node3D, VehicleWheel3D, Inline, Renderable::False, InternalUpdate::False, InternalFixedUpdate::False),
 
         // audio
         AudioMask3D => (Node3D, AudioMask3D, Inline, Renderable::False, InternalUpdate::False, InternalFixedUpdate::False),
@@ -978,7 +990,8 @@ define_scene_nodes! {
         UiHLayout => (UiNode, UiHLayout, Boxed, Renderable::False, InternalUpdate::False, InternalFixedUpdate::False),
         UiVLayout => (UiNode, UiVLayout, Boxed, Renderable::False, InternalUpdate::False, InternalFixedUpdate::False),
         UiGrid => (UiNode, UiGrid, Boxed, Renderable::False, InternalUpdate::False, InternalFixedUpdate::False),
-        UiTreeList => (UiNode, UiTreeList, Boxed, Renderable::False, InternalUpdate::False, InternalFixedUpdate::False)
+        UiTreeList => (UiNode, UiTreeList, Boxed, Renderable::False, InternalUpdate::False, InternalFixedUpdate::False),
+        UiMarkdown => (UiNode, UiMarkdown, Boxed, Renderable::False, InternalUpdate::False, InternalFixedUpdate::False)
     }
     resource: {
         // capture
@@ -1006,6 +1019,7 @@ impl NodeType {
                 | NodeType::PinJoint2D
                 | NodeType::DistanceJoint2D
                 | NodeType::FixedJoint2D
+                | NodeType::SpringJoint2D
                 | NodeType::WaterBody2D
                 | NodeType::PhysicsBoneChain2D
                 | NodeType::BoneCollider2D
@@ -1018,6 +1032,8 @@ impl NodeType {
                 | NodeType::BallJoint3D
                 | NodeType::HingeJoint3D
                 | NodeType::FixedJoint3D
+                | NodeType::SpringJoint3D
+                | NodeType::VehicleBody3D
                 | NodeType::WaterBody3D
                 | NodeType::PhysicsBoneChain3D
                 | NodeType::BoneCollider3D
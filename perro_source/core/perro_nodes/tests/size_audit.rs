@@ -68,6 +68,7 @@ fn print_scene_node_sizes() {
         PinJoint2D,
         DistanceJoint2D,
         FixedJoint2D,
+        SpringJoint2D,
         AudioMask2D,
         AudioEffectZone2D,
         AudioPortal2D,
@@ -99,6 +100,9 @@ fn print_scene_node_sizes() {
         BallJoint3D,
         HingeJoint3D,
         FixedJoint3D,
+        SpringJoint3D,
+        VehicleBody3D,
+        VehicleWheel3D,
         AudioMask3D,
         AudioEffectZone3D,
         AudioPortal3D,
@@ -126,6 +130,7 @@ fn print_scene_node_sizes() {
         UiVLayout,
         UiGrid,
         UiTreeList,
+        UiMarkdown,
         // resources
         AnimationPlayer,
         AnimationTree,
@@ -1,3 +1,12 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("perro_structs needs either the `std` or the `libm` feature");
+
+#[cfg(not(feature = "std"))]
+pub(crate) mod float;
 pub mod structs;
 pub use structs::*;
 
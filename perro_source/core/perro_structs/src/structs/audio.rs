@@ -1,4 +1,5 @@
 use crate::BitMask;
+use alloc::vec::Vec;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct AudioMaterial {
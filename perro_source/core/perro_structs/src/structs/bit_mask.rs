@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct BitMask(u32);
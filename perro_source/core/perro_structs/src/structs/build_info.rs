@@ -0,0 +1,111 @@
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use core::fmt;
+
+/// Build profile a binary was produced with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BuildProfile {
+    /// `perro dev` run straight from project sources.
+    #[default]
+    Dev,
+    Debug,
+    Release,
+}
+
+impl BuildProfile {
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Dev => "dev",
+            Self::Debug => "debug",
+            Self::Release => "release",
+        }
+    }
+}
+
+/// Version stamp embedded into exported builds.
+///
+/// Static builds get a constant generated by the compiler. Dev runs report
+/// the project.toml version with [`BuildProfile::Dev`] and no commit or
+/// timestamp.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BuildInfo {
+    /// Semantic version from project.toml `version`, `"0.0.0"` when unset.
+    pub version: Cow<'static, str>,
+    /// Full git commit hash of the project checkout, when it was a git repo.
+    pub git_commit: Option<Cow<'static, str>>,
+    /// Build time in unix seconds; `0` when unknown.
+    pub timestamp: u64,
+    pub profile: BuildProfile,
+}
+
+impl BuildInfo {
+    pub const UNKNOWN_VERSION: &'static str = "0.0.0";
+
+    /// Constant form used by generated static entry files.
+    pub const fn embedded(
+        version: &'static str,
+        git_commit: Option<&'static str>,
+        timestamp: u64,
+        profile: BuildProfile,
+    ) -> Self {
+        Self {
+            version: Cow::Borrowed(version),
+            git_commit: match git_commit {
+                Some(commit) => Some(Cow::Borrowed(commit)),
+                None => None,
+            },
+            timestamp,
+            profile,
+        }
+    }
+
+    pub fn dev(version: Option<&str>) -> Self {
+        Self {
+            version: Cow::Owned(version.unwrap_or(Self::UNKNOWN_VERSION).to_string()),
+            git_commit: None,
+            timestamp: 0,
+            profile: BuildProfile::Dev,
+        }
+    }
+
+    /// First 7 characters of the commit hash, for display.
+    pub fn short_commit(&self) -> Option<&str> {
+        let commit = self.git_commit.as_deref()?;
+        Some(commit.get(..7).unwrap_or(commit))
+    }
+}
+
+impl Default for BuildInfo {
+    fn default() -> Self {
+        Self::embedded(Self::UNKNOWN_VERSION, None, 0, BuildProfile::Dev)
+    }
+}
+
+/// `1.2.0 (3f2a9c1, release)`; the commit is omitted when unknown.
+impl fmt::Display for BuildInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.short_commit() {
+            Some(commit) => write!(f, "{} ({commit}, {})", self.version, self.profile.as_str()),
+            None => write!(f, "{} ({})", self.version, self.profile.as_str()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_includes_short_commit_and_profile() {
+        let info = BuildInfo::embedded(
+            "1.2.0",
+            Some("3f2a9c1d0e8b7a6f5e4d3c2b1a0f9e8d7c6b5a49"),
+            1_700_000_000,
+            BuildProfile::Release,
+        );
+        assert_eq!(info.short_commit(), Some("3f2a9c1"));
+        assert_eq!(info.to_string(), "1.2.0 (3f2a9c1, release)");
+        assert_eq!(BuildInfo::dev(None).to_string(), "0.0.0 (dev)");
+    }
+}
@@ -1,5 +1,7 @@
 use super::{Unit, UnitVector4};
-use std::fmt;
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
 
 #[derive(Clone, Copy, Debug, PartialEq, Default)]
 pub struct Color {
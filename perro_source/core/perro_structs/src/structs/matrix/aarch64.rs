@@ -2,7 +2,7 @@ use super::{
     scalar_add_assign, scalar_add_assign_generic, scalar_scale_assign, scalar_scale_assign_generic,
     scalar_sub_assign, scalar_sub_assign_generic,
 };
-use std::arch::aarch64::{
+use core::arch::aarch64::{
     float32x4_t, float64x2_t, int8x16_t, int16x8_t, int32x4_t, int64x2_t, vaddq_f32, vaddq_f64,
     vaddq_s8, vaddq_s16, vaddq_s32, vaddq_s64, vdupq_n_f32, vdupq_n_f64, vdupq_n_s16, vdupq_n_s32,
     vld1q_f32, vld1q_f64, vld1q_s8, vld1q_s16, vld1q_s32, vld1q_s64, vmulq_f32, vmulq_f64,
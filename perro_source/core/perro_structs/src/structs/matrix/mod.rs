@@ -1,9 +1,10 @@
-use glam::{Mat2, Mat3, Mat4};
-use std::fmt;
-use std::ops::{
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Shl, ShlAssign, Shr,
     ShrAssign, Sub, SubAssign,
 };
+use glam::{Mat2, Mat3, Mat4};
 
 #[cfg(target_arch = "aarch64")]
 mod aarch64;
@@ -360,32 +361,32 @@ impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T> {
     }
 
     #[inline]
-    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
         self.as_slice().iter()
     }
 
     #[inline]
-    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
         self.as_mut_slice().iter_mut()
     }
 
     #[inline]
-    pub fn rows_iter(&self) -> std::slice::Iter<'_, [T; COLS]> {
+    pub fn rows_iter(&self) -> core::slice::Iter<'_, [T; COLS]> {
         self.0.iter()
     }
 
     #[inline]
-    pub fn rows_iter_mut(&mut self) -> std::slice::IterMut<'_, [T; COLS]> {
+    pub fn rows_iter_mut(&mut self) -> core::slice::IterMut<'_, [T; COLS]> {
         self.0.iter_mut()
     }
 
     #[inline]
-    pub fn row_iter(&self, row: usize) -> Option<std::slice::Iter<'_, T>> {
+    pub fn row_iter(&self, row: usize) -> Option<core::slice::Iter<'_, T>> {
         self.0.get(row).map(|row| row.iter())
     }
 
     #[inline]
-    pub fn row_iter_mut(&mut self, row: usize) -> Option<std::slice::IterMut<'_, T>> {
+    pub fn row_iter_mut(&mut self, row: usize) -> Option<core::slice::IterMut<'_, T>> {
         self.0.get_mut(row).map(|row| row.iter_mut())
     }
 
@@ -802,7 +803,7 @@ impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T> {
     {
         self.cells()
             .filter_map(|(row, col, value)| value.partial_cmp(value).map(|_| (row, col, *value)))
-            .min_by(|(_, _, a), (_, _, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+            .min_by(|(_, _, a), (_, _, b)| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal))
     }
 
     #[inline]
@@ -812,7 +813,7 @@ impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T> {
     {
         self.cells()
             .filter_map(|(row, col, value)| value.partial_cmp(value).map(|_| (row, col, *value)))
-            .max_by(|(_, _, a), (_, _, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+            .max_by(|(_, _, a), (_, _, b)| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal))
     }
 
     #[inline]
@@ -820,8 +821,8 @@ impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T> {
     where
         T: Copy,
     {
-        Matrix(std::array::from_fn(|row| {
-            std::array::from_fn(|col| {
+        Matrix(core::array::from_fn(|row| {
+            core::array::from_fn(|col| {
                 // SAFETY: array::from_fn indexes are in matrix bounds.
                 f(row, col, unsafe { *self.get_unchecked(row, col) })
             })
@@ -857,8 +858,8 @@ impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T> {
     where
         T: Copy,
     {
-        Matrix(std::array::from_fn(|row| {
-            std::array::from_fn(|col| {
+        Matrix(core::array::from_fn(|row| {
+            core::array::from_fn(|col| {
                 if row < ROWS && col < COLS {
                     self.0[row][col]
                 } else {
@@ -871,13 +872,13 @@ impl<const ROWS: usize, const COLS: usize, T> Matrix<ROWS, COLS, T> {
     #[inline]
     pub fn as_slice(&self) -> &[T] {
         // SAFETY: nested arrays are contiguous and contain exactly ROWS * COLS elements.
-        unsafe { std::slice::from_raw_parts(self.0.as_ptr().cast(), ROWS * COLS) }
+        unsafe { core::slice::from_raw_parts(self.0.as_ptr().cast(), ROWS * COLS) }
     }
 
     #[inline]
     pub fn as_mut_slice(&mut self) -> &mut [T] {
         // SAFETY: nested arrays are contiguous and contain exactly ROWS * COLS elements.
-        unsafe { std::slice::from_raw_parts_mut(self.0.as_mut_ptr().cast(), ROWS * COLS) }
+        unsafe { core::slice::from_raw_parts_mut(self.0.as_mut_ptr().cast(), ROWS * COLS) }
     }
 
     /// Build from row-major flat slice.
@@ -579,7 +579,7 @@ pub(super) fn simd_sub_assign_u64(out: &mut [u64], rhs: &[u64]) {
 ))]
 pub(super) fn cast_u8(value: &[u8]) -> &[i8] {
     // SAFETY: u8 and i8 have same size/alignment; length unchanged.
-    unsafe { std::slice::from_raw_parts(value.as_ptr().cast(), value.len()) }
+    unsafe { core::slice::from_raw_parts(value.as_ptr().cast(), value.len()) }
 }
 
 #[inline]
@@ -591,7 +591,7 @@ pub(super) fn cast_u8(value: &[u8]) -> &[i8] {
 ))]
 pub(super) fn cast_u8_mut(value: &mut [u8]) -> &mut [i8] {
     // SAFETY: u8 and i8 have same size/alignment; length unchanged.
-    unsafe { std::slice::from_raw_parts_mut(value.as_mut_ptr().cast(), value.len()) }
+    unsafe { core::slice::from_raw_parts_mut(value.as_mut_ptr().cast(), value.len()) }
 }
 
 #[inline]
@@ -603,7 +603,7 @@ pub(super) fn cast_u8_mut(value: &mut [u8]) -> &mut [i8] {
 ))]
 pub(super) fn cast_u16(value: &[u16]) -> &[i16] {
     // SAFETY: u16 and i16 have same size/alignment; length unchanged.
-    unsafe { std::slice::from_raw_parts(value.as_ptr().cast(), value.len()) }
+    unsafe { core::slice::from_raw_parts(value.as_ptr().cast(), value.len()) }
 }
 
 #[inline]
@@ -615,7 +615,7 @@ pub(super) fn cast_u16(value: &[u16]) -> &[i16] {
 ))]
 pub(super) fn cast_u16_mut(value: &mut [u16]) -> &mut [i16] {
     // SAFETY: u16 and i16 have same size/alignment; length unchanged.
-    unsafe { std::slice::from_raw_parts_mut(value.as_mut_ptr().cast(), value.len()) }
+    unsafe { core::slice::from_raw_parts_mut(value.as_mut_ptr().cast(), value.len()) }
 }
 
 #[inline]
@@ -627,7 +627,7 @@ pub(super) fn cast_u16_mut(value: &mut [u16]) -> &mut [i16] {
 ))]
 pub(super) fn cast_u32(value: &[u32]) -> &[i32] {
     // SAFETY: u32 and i32 have same size/alignment; length unchanged.
-    unsafe { std::slice::from_raw_parts(value.as_ptr().cast(), value.len()) }
+    unsafe { core::slice::from_raw_parts(value.as_ptr().cast(), value.len()) }
 }
 
 #[inline]
@@ -639,7 +639,7 @@ pub(super) fn cast_u32(value: &[u32]) -> &[i32] {
 ))]
 pub(super) fn cast_u32_mut(value: &mut [u32]) -> &mut [i32] {
     // SAFETY: u32 and i32 have same size/alignment; length unchanged.
-    unsafe { std::slice::from_raw_parts_mut(value.as_mut_ptr().cast(), value.len()) }
+    unsafe { core::slice::from_raw_parts_mut(value.as_mut_ptr().cast(), value.len()) }
 }
 
 #[inline]
@@ -651,7 +651,7 @@ pub(super) fn cast_u32_mut(value: &mut [u32]) -> &mut [i32] {
 ))]
 pub(super) fn cast_u64(value: &[u64]) -> &[i64] {
     // SAFETY: u64 and i64 have same size/alignment; length unchanged.
-    unsafe { std::slice::from_raw_parts(value.as_ptr().cast(), value.len()) }
+    unsafe { core::slice::from_raw_parts(value.as_ptr().cast(), value.len()) }
 }
 
 #[inline]
@@ -663,7 +663,7 @@ pub(super) fn cast_u64(value: &[u64]) -> &[i64] {
 ))]
 pub(super) fn cast_u64_mut(value: &mut [u64]) -> &mut [i64] {
     // SAFETY: u64 and i64 have same size/alignment; length unchanged.
-    unsafe { std::slice::from_raw_parts_mut(value.as_mut_ptr().cast(), value.len()) }
+    unsafe { core::slice::from_raw_parts_mut(value.as_mut_ptr().cast(), value.len()) }
 }
 
 #[inline]
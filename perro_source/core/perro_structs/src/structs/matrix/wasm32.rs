@@ -2,7 +2,7 @@ use super::{
     scalar_add_assign, scalar_add_assign_generic, scalar_scale_assign, scalar_scale_assign_generic,
     scalar_sub_assign, scalar_sub_assign_generic,
 };
-use std::arch::wasm32::{
+use core::arch::wasm32::{
     f32x4_add, f32x4_extract_lane, f32x4_mul, f32x4_splat, f32x4_sub, f64x2_add, f64x2_mul,
     f64x2_splat, f64x2_sub, i8x16_add, i8x16_sub, i16x8_add, i16x8_mul, i16x8_splat, i16x8_sub,
     i32x4_add, i32x4_mul, i32x4_splat, i32x4_sub, i64x2_add, i64x2_sub, v128, v128_load,
@@ -4,7 +4,7 @@ use super::{
 };
 
 #[cfg(target_arch = "x86")]
-use std::arch::x86::{
+use core::arch::x86::{
     __m128i, _mm_add_epi8, _mm_add_epi16, _mm_add_epi32, _mm_add_epi64, _mm_add_pd, _mm_add_ps,
     _mm_loadu_pd, _mm_loadu_ps, _mm_loadu_si128, _mm_mul_pd, _mm_mul_ps, _mm_mullo_epi16,
     _mm_mullo_epi32, _mm_set1_epi16, _mm_set1_epi32, _mm_set1_pd, _mm_set1_ps, _mm_setzero_ps,
@@ -13,7 +13,7 @@ use std::arch::x86::{
 };
 
 #[cfg(target_arch = "x86_64")]
-use std::arch::x86_64::{
+use core::arch::x86_64::{
     __m128i, _mm_add_epi8, _mm_add_epi16, _mm_add_epi32, _mm_add_epi64, _mm_add_pd, _mm_add_ps,
     _mm_loadu_pd, _mm_loadu_ps, _mm_loadu_si128, _mm_mul_pd, _mm_mul_ps, _mm_mullo_epi16,
     _mm_mullo_epi32, _mm_set1_epi16, _mm_set1_epi32, _mm_set1_pd, _mm_set1_ps, _mm_setzero_ps,
@@ -21,11 +21,26 @@ use std::arch::x86_64::{
     _mm_sub_epi64, _mm_sub_pd, _mm_sub_ps,
 };
 
+// Runtime CPUID detection needs std; no_std builds fall back to compile-time target features.
+#[cfg(feature = "std")]
+macro_rules! x86_feature_detected {
+    ($feature:tt) => {
+        std::is_x86_feature_detected!($feature)
+    };
+}
+
+#[cfg(not(feature = "std"))]
+macro_rules! x86_feature_detected {
+    ($feature:tt) => {
+        cfg!(target_feature = $feature)
+    };
+}
+
 macro_rules! impl_try_binop {
     ($try_name:ident, $ty:ty, sse, $helper:ident) => {
         #[inline]
         pub(super) fn $try_name(out: &mut [$ty], rhs: &[$ty]) -> bool {
-            if std::is_x86_feature_detected!("sse") {
+            if x86_feature_detected!("sse") {
                 // SAFETY: runtime feature check gates helper use; helper keeps ptr math in bounds.
                 unsafe { $helper(out, rhs) };
                 return true;
@@ -36,7 +51,7 @@ macro_rules! impl_try_binop {
     ($try_name:ident, $ty:ty, sse2, $helper:ident) => {
         #[inline]
         pub(super) fn $try_name(out: &mut [$ty], rhs: &[$ty]) -> bool {
-            if std::is_x86_feature_detected!("sse2") {
+            if x86_feature_detected!("sse2") {
                 // SAFETY: runtime feature check gates helper use; helper keeps ptr math in bounds.
                 unsafe { $helper(out, rhs) };
                 return true;
@@ -50,7 +65,7 @@ macro_rules! impl_try_scale {
     ($try_name:ident, $ty:ty, sse, $helper:ident) => {
         #[inline]
         pub(super) fn $try_name(out: &mut [$ty], rhs: $ty) -> bool {
-            if std::is_x86_feature_detected!("sse") {
+            if x86_feature_detected!("sse") {
                 // SAFETY: runtime feature check gates helper use; helper keeps ptr math in bounds.
                 unsafe { $helper(out, rhs) };
                 return true;
@@ -61,7 +76,7 @@ macro_rules! impl_try_scale {
     ($try_name:ident, $ty:ty, sse2, $helper:ident) => {
         #[inline]
         pub(super) fn $try_name(out: &mut [$ty], rhs: $ty) -> bool {
-            if std::is_x86_feature_detected!("sse2") {
+            if x86_feature_detected!("sse2") {
                 // SAFETY: runtime feature check gates helper use; helper keeps ptr math in bounds.
                 unsafe { $helper(out, rhs) };
                 return true;
@@ -72,7 +87,7 @@ macro_rules! impl_try_scale {
     ($try_name:ident, $ty:ty, sse41, $helper:ident) => {
         #[inline]
         pub(super) fn $try_name(out: &mut [$ty], rhs: $ty) -> bool {
-            if std::is_x86_feature_detected!("sse4.1") {
+            if x86_feature_detected!("sse4.1") {
                 // SAFETY: runtime feature check gates helper use; helper keeps ptr math in bounds.
                 unsafe { $helper(out, rhs) };
                 return true;
@@ -345,7 +360,7 @@ impl_int_binop!(
 
 #[inline]
 pub(super) fn try_dot_f32(lhs: &[f32], rhs: &[f32]) -> Option<f32> {
-    if std::is_x86_feature_detected!("sse") {
+    if x86_feature_detected!("sse") {
         // SAFETY: runtime feature check gates SSE use; helper only reads in-bounds lanes.
         return Some(unsafe { simd_dot_f32_sse(lhs, rhs) });
     }
@@ -1,5 +1,6 @@
 pub mod audio;
 pub mod bit_mask;
+pub mod build_info;
 pub mod collision_policy;
 pub mod color;
 pub mod const_param;
@@ -18,6 +19,7 @@ pub mod visual_accessibility;
 
 pub use audio::*;
 pub use bit_mask::*;
+pub use build_info::*;
 pub use collision_policy::*;
 pub use color::*;
 pub use const_param::*;
@@ -1,6 +1,6 @@
 use super::Vector2;
 use bytemuck::{Pod, Zeroable};
-use std::fmt;
+use core::fmt;
 
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Pod, Zeroable)]
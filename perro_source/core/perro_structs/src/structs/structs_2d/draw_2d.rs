@@ -1,7 +1,7 @@
 use crate::Color;
 use crate::Vector2;
+use alloc::sync::Arc;
 use perro_ids::TextureID;
-use std::sync::Arc;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum DrawShape2D {
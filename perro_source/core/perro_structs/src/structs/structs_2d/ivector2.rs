@@ -1,7 +1,7 @@
 use super::{UVector2, Vector2};
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use glam::IVec2;
-use std::fmt;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// A 2D vector with signed integer `x` and `y` components.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
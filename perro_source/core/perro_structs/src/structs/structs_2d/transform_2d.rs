@@ -1,3 +1,5 @@
+#[cfg(not(feature = "std"))]
+use crate::float::FloatMath;
 use crate::{Matrix3, Vector2};
 use glam::{Mat3, Vec3};
 
@@ -1,7 +1,7 @@
 use super::{IVector2, Vector2};
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 use glam::UVec2;
-use std::fmt;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 /// A 2D vector with unsigned integer `x` and `y` components.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
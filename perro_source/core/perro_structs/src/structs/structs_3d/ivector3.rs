@@ -1,7 +1,7 @@
 use super::{UVector3, Vector3};
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use glam::IVec3;
-use std::fmt;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// A 3D vector with signed integer `x`, `y`, and `z` components.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
@@ -1,6 +1,8 @@
 use super::vector3::Vector3;
+#[cfg(not(feature = "std"))]
+use crate::float::FloatMath;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use glam::{Mat3, Quat, Vec3};
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// A quaternion representing rotation in 3D space.
 ///
@@ -21,8 +23,8 @@ pub struct Quaternion {
     pub w: f32,
 }
 
-impl std::fmt::Display for Quaternion {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Quaternion {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "Quaternion({}, {}, {}, {})",
@@ -70,7 +72,7 @@ impl Quaternion {
     /// ```rust
     /// use perro_structs::{Quaternion, Vector3};
     ///
-    /// let q = Quaternion::from_euler_xyz(0.0, std::f32::consts::FRAC_PI_2, 0.0);
+    /// let q = Quaternion::from_euler_xyz(0.0, core::f32::consts::FRAC_PI_2, 0.0);
     /// let v = q.rotate_vector3(Vector3::new(0.0, 0.0, -1.0));
     /// assert!((v.x + 1.0).abs() < 1e-5);
     /// ```
@@ -88,7 +90,7 @@ impl Quaternion {
     /// ```rust
     /// use perro_structs::{Quaternion, Vector3};
     ///
-    /// let q = Quaternion::from_euler(Vector3::new(0.0, std::f32::consts::FRAC_PI_2, 0.0));
+    /// let q = Quaternion::from_euler(Vector3::new(0.0, core::f32::consts::FRAC_PI_2, 0.0));
     /// let v = q.rotate_vector3(Vector3::new(0.0, 0.0, -1.0));
     /// assert!((v.x + 1.0).abs() < 1e-5);
     /// ```
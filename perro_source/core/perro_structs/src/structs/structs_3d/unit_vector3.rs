@@ -1,6 +1,6 @@
 use super::Vector3;
 use crate::Unit;
-use std::fmt;
+use core::fmt;
 
 #[derive(Clone, Copy, Debug, PartialEq, Default)]
 pub struct UnitVector3 {
@@ -1,7 +1,7 @@
 use super::{IVector3, Vector3};
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 use glam::UVec3;
-use std::fmt;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 /// A 3D vector with unsigned integer `x`, `y`, and `z` components.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
@@ -1,7 +1,9 @@
 use super::{IVector3, UVector3};
+#[cfg(not(feature = "std"))]
+use crate::float::FloatMath;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use glam::Vec3;
-use std::fmt;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// A 3D vector with `x`, `y`, and `z` components.
 ///
@@ -577,7 +579,7 @@ mod tests {
     #[test]
     fn vector3_slerp_rotates_between_axes() {
         let v = Vector3::new(1.0, 0.0, 0.0).slerped(Vector3::new(0.0, 1.0, 0.0), 0.5);
-        let s = std::f32::consts::FRAC_1_SQRT_2;
+        let s = core::f32::consts::FRAC_1_SQRT_2;
         assert!((v.x - s).abs() < 1e-5);
         assert!((v.y - s).abs() < 1e-5);
         assert!(v.z.abs() < 1e-5);
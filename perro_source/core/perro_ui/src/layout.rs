@@ -213,6 +213,8 @@ pub struct UiNode {
     pub input_enabled: bool,
     pub mouse_filter: UiMouseFilter,
     pub clip_children: bool,
+    /// Take keyboard/controller focus once when first focusable.
+    pub initial_focus: bool,
 }
 
 impl UiNode {
@@ -225,6 +227,7 @@ impl UiNode {
             input_enabled: true,
             mouse_filter: UiMouseFilter::Stop,
             clip_children: false,
+            initial_focus: false,
         }
     }
 }
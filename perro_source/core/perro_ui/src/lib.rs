@@ -5,6 +5,7 @@ use std::ops::{Deref, DerefMut};
 
 mod font;
 mod layout;
+mod markdown;
 mod style;
 mod tree;
 mod units;
@@ -12,6 +13,7 @@ mod widgets;
 
 pub use font::*;
 pub use layout::*;
+pub use markdown::*;
 pub use style::*;
 pub use tree::*;
 pub use units::*;
@@ -0,0 +1,260 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UiMarkdownBlockKind {
+    Paragraph,
+    Heading(u8),
+    ListItem { ordered: Option<u32> },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UiMarkdownSpan {
+    pub text: String,
+    pub bold: bool,
+    pub link: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UiMarkdownBlock {
+    pub kind: UiMarkdownBlockKind,
+    pub spans: Vec<UiMarkdownSpan>,
+}
+
+impl UiMarkdownBlock {
+    pub fn plain_text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+}
+
+/// Parse constrained markdown subset: `#` headings, `**bold**`/`__bold__`,
+/// `-`/`*`/`+` and `1.` list items, `[text](target)` links.
+/// Everything else is plain text; blank lines split paragraphs.
+pub fn parse_markdown(source: &str) -> Vec<UiMarkdownBlock> {
+    let mut blocks = Vec::new();
+    let mut paragraph = String::new();
+    for raw in source.lines() {
+        let line = raw.trim();
+        if line.is_empty() {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            continue;
+        }
+        if let Some((level, rest)) = parse_heading(line) {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            blocks.push(UiMarkdownBlock {
+                kind: UiMarkdownBlockKind::Heading(level),
+                spans: parse_inline(rest),
+            });
+            continue;
+        }
+        if let Some((ordered, rest)) = parse_list_item(line) {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            blocks.push(UiMarkdownBlock {
+                kind: UiMarkdownBlockKind::ListItem { ordered },
+                spans: parse_inline(rest),
+            });
+            continue;
+        }
+        if !paragraph.is_empty() {
+            paragraph.push(' ');
+        }
+        paragraph.push_str(line);
+    }
+    flush_paragraph(&mut paragraph, &mut blocks);
+    blocks
+}
+
+fn flush_paragraph(paragraph: &mut String, blocks: &mut Vec<UiMarkdownBlock>) {
+    if paragraph.is_empty() {
+        return;
+    }
+    blocks.push(UiMarkdownBlock {
+        kind: UiMarkdownBlockKind::Paragraph,
+        spans: parse_inline(paragraph),
+    });
+    paragraph.clear();
+}
+
+fn parse_heading(line: &str) -> Option<(u8, &str)> {
+    let level = line.bytes().take_while(|b| *b == b'#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = &line[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    Some((level as u8, rest.trim()))
+}
+
+fn parse_list_item(line: &str) -> Option<(Option<u32>, &str)> {
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(rest) = line.strip_prefix(marker) {
+            return Some((None, rest.trim()));
+        }
+    }
+    let digits = line.bytes().take_while(u8::is_ascii_digit).count();
+    if digits == 0 {
+        return None;
+    }
+    let rest = line[digits..].strip_prefix(". ")?;
+    let number = line[..digits].parse::<u32>().ok()?;
+    Some((Some(number), rest.trim()))
+}
+
+fn parse_inline(text: &str) -> Vec<UiMarkdownSpan> {
+    let mut spans = Vec::new();
+    let mut current = String::new();
+    let mut bold = false;
+    let mut rest = text;
+    while let Some(ch) = rest.chars().next() {
+        if rest.starts_with("**") || rest.starts_with("__") {
+            push_span(&mut spans, &mut current, bold, None);
+            bold = !bold;
+            rest = &rest[2..];
+            continue;
+        }
+        if ch == '['
+            && let Some((label, target, tail)) = parse_link(rest)
+        {
+            push_span(&mut spans, &mut current, bold, None);
+            let mut label = label.to_string();
+            push_span(&mut spans, &mut label, bold, Some(target.to_string()));
+            rest = tail;
+            continue;
+        }
+        current.push(ch);
+        rest = &rest[ch.len_utf8()..];
+    }
+    push_span(&mut spans, &mut current, bold, None);
+    spans
+}
+
+fn parse_link(text: &str) -> Option<(&str, &str, &str)> {
+    let close = text.find("](")?;
+    let label = &text[1..close];
+    if label.contains('[') {
+        return None;
+    }
+    let after = &text[close + 2..];
+    let end = after.find(')')?;
+    Some((label, after[..end].trim(), &after[end + 1..]))
+}
+
+fn push_span(spans: &mut Vec<UiMarkdownSpan>, text: &mut String, bold: bool, link: Option<String>) {
+    if text.is_empty() {
+        return;
+    }
+    spans.push(UiMarkdownSpan {
+        text: std::mem::take(text),
+        bold,
+        link,
+    });
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UiMarkdown {
+    pub base: UiNode,
+    /// `res://` text file; wins over inline `markdown` when set.
+    pub source: Cow<'static, str>,
+    pub markdown: Cow<'static, str>,
+    pub font: UiFont,
+    pub bold_font: UiFont,
+    pub font_size: f32,
+    pub heading_scale: f32,
+    pub line_spacing: f32,
+    pub paragraph_spacing: f32,
+    pub list_indent: f32,
+    pub text_color: Color,
+    pub bold_color: Color,
+    pub heading_color: Color,
+    pub link_color: Color,
+    /// Wrap width of last sync; runtime re-wraps when layout width chg.
+    pub synced_width: f32,
+    pub internal_labels: Vec<NodeID>,
+    pub internal_links: Vec<NodeID>,
+    pub link_targets: Vec<Cow<'static, str>>,
+    pub link_signals: Vec<SignalID>,
+}
+
+impl UiMarkdown {
+    pub fn new() -> Self {
+        let mut base = UiNode::new();
+        base.layout.h_align = UiHorizontalAlign::Left;
+        base.layout.v_align = UiVerticalAlign::Top;
+        Self {
+            base,
+            source: Cow::Borrowed(""),
+            markdown: Cow::Borrowed(""),
+            font: UiFont::Default,
+            bold_font: UiFont::Default,
+            font_size: 18.0,
+            heading_scale: 1.6,
+            line_spacing: 4.0,
+            paragraph_spacing: 10.0,
+            list_indent: 20.0,
+            text_color: Color::WHITE,
+            bold_color: Color::WHITE,
+            heading_color: Color::WHITE,
+            link_color: Color::new(0.45, 0.68, 1.0, 1.0),
+            synced_width: 0.0,
+            internal_labels: Vec::new(),
+            internal_links: Vec::new(),
+            link_targets: Vec::new(),
+            link_signals: Vec::new(),
+        }
+    }
+
+    pub fn set_markdown<T>(&mut self, markdown: T)
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        self.markdown = markdown.into();
+    }
+
+    /// Font size for block; headings shrink from `heading_scale` toward 1 by level.
+    pub fn block_font_size(&self, kind: UiMarkdownBlockKind) -> f32 {
+        match kind {
+            UiMarkdownBlockKind::Heading(level) => {
+                let extra = (self.heading_scale - 1.0).max(0.0);
+                let step = f32::from(level.clamp(1, 6) - 1) / 5.0;
+                self.font_size * (1.0 + extra * (1.0 - step))
+            }
+            _ => self.font_size,
+        }
+    }
+
+    pub fn link_target_for(&self, id: NodeID) -> Option<&str> {
+        let idx = self.internal_links.iter().position(|link| *link == id)?;
+        self.link_targets.get(idx).map(|target| target.as_ref())
+    }
+}
+
+impl Default for UiMarkdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for UiMarkdown {
+    type Target = UiNode;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl DerefMut for UiMarkdown {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
+
+impl UiNodeBase for UiMarkdown {
+    fn ui_base(&self) -> &UiNode {
+        &self.base
+    }
+
+    fn ui_base_mut(&mut self) -> &mut UiNode {
+        &mut self.base
+    }
+}
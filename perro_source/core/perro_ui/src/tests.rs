@@ -48,6 +48,7 @@ fn default_layout_aligns_children_to_center() {
 fn ui_node_defaults_to_no_child_clipping() {
     let base = UiNode::new();
     assert!(!base.clip_children);
+    assert!(!base.initial_focus);
 }
 
 #[test]
@@ -336,3 +337,34 @@ fn top_left_anchor_uses_center_origin_y_up() {
     assert_eq!(rect.min(), Vector2::new(-400.0, 250.0));
     assert_eq!(rect.max(), Vector2::new(-300.0, 300.0));
 }
+
+#[test]
+fn markdown_parses_headings_lists_bold_and_links() {
+    let blocks = parse_markdown(
+        "# Changelog\n\nFixed **jump** bug\nand more.\n\n- see [notes](open_notes)\n2. second\n",
+    );
+    assert_eq!(blocks.len(), 4);
+    assert_eq!(blocks[0].kind, UiMarkdownBlockKind::Heading(1));
+    assert_eq!(blocks[0].plain_text(), "Changelog");
+    assert_eq!(blocks[1].kind, UiMarkdownBlockKind::Paragraph);
+    assert_eq!(blocks[1].plain_text(), "Fixed jump bug and more.");
+    assert!(blocks[1].spans[1].bold);
+    assert_eq!(
+        blocks[2].kind,
+        UiMarkdownBlockKind::ListItem { ordered: None }
+    );
+    assert_eq!(blocks[2].spans[1].text, "notes");
+    assert_eq!(blocks[2].spans[1].link.as_deref(), Some("open_notes"));
+    assert_eq!(
+        blocks[3].kind,
+        UiMarkdownBlockKind::ListItem { ordered: Some(2) }
+    );
+}
+
+#[test]
+fn markdown_keeps_non_markup_hashes_and_brackets_as_text() {
+    let blocks = parse_markdown("#hashtag [not a link]");
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].kind, UiMarkdownBlockKind::Paragraph);
+    assert_eq!(blocks[0].plain_text(), "#hashtag [not a link]");
+}
@@ -59,6 +59,8 @@ pub struct UiScrollContainer {
     pub scroll_dir: UiScrollDirection,
     pub scroll_bar_side: UiScrollBarSide,
     pub scroll_bar_padding: f32,
+    /// Pointer drag on content scrolls (touch-style).
+    pub drag_scroll: bool,
 }
 
 impl UiScrollContainer {
@@ -72,6 +74,7 @@ impl UiScrollContainer {
             scroll_dir: UiScrollDirection::Vertical,
             scroll_bar_side: UiScrollBarSide::Right,
             scroll_bar_padding: -1.0,
+            drag_scroll: false,
         }
     }
 
@@ -2,27 +2,37 @@
 
 #![forbid(unsafe_code)]
 
-use std::borrow::Cow;
-use std::cell::{Cell, RefCell};
-use std::cmp::Reverse;
-use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
-use std::fmt;
-use std::hash::Hash;
-use std::num::{
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+use alloc::rc::Rc;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+use core::cmp::Reverse;
+use core::fmt;
+use core::hash::Hash;
+use core::num::{
     NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
     NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize, Saturating, Wrapping,
 };
-use std::ops::{Range, RangeInclusive};
-use std::path::PathBuf;
-use std::rc::Rc;
-use std::sync::{
-    Arc,
-    atomic::{AtomicBool, AtomicI32, AtomicI64, AtomicU32, AtomicU64, AtomicUsize, Ordering},
+use core::ops::{Range, RangeInclusive};
+use core::sync::atomic::{
+    AtomicBool, AtomicI32, AtomicI64, AtomicU32, AtomicU64, AtomicUsize, Ordering,
 };
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::path::PathBuf;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use perro_ids::*;
 use perro_structs::*;
+#[cfg(feature = "std")]
 use serde_json::{Map as JsonMap, Number as JsonNumber, Value as JsonValue};
 
 /// `i128` stored as two 8-aligned halves. A bare `i128` member is 16-aligned,
@@ -483,8 +493,6 @@ impl_empty_variant_schema!(
     PostProcessSet,
     VisualAccessibilitySettings,
     Duration,
-    SystemTime,
-    PathBuf,
     AtomicBool,
     AtomicI32,
     AtomicI64,
@@ -519,13 +527,18 @@ impl<T> VariantSchema for Vec<T> {}
 impl<T> VariantSchema for LinkedList<T> {}
 impl<T> VariantSchema for VecDeque<T> {}
 impl<T> VariantSchema for BTreeSet<T> {}
+#[cfg(feature = "std")]
 impl<T> VariantSchema for HashSet<T> {}
 impl<T> VariantSchema for BinaryHeap<T> {}
 impl<K, T> VariantSchema for BTreeMap<K, T> {}
+#[cfg(feature = "std")]
 impl<K, T> VariantSchema for HashMap<K, T> {}
 impl<T> VariantSchema for Range<T> {}
 impl<T> VariantSchema for RangeInclusive<T> {}
 
+#[cfg(feature = "std")]
+impl_empty_variant_schema!(SystemTime, PathBuf);
+
 pub trait VariantMatrixCell: Sized {
     fn from_matrix_cell_variant(value: &Variant) -> Option<Self>;
     fn to_matrix_cell_variant(&self) -> Variant;
@@ -587,7 +600,9 @@ impl fmt::Display for Variant {
 mod access;
 mod convert;
 mod derive;
+#[cfg(feature = "std")]
 mod json;
+mod matrix;
 #[cfg(test)]
 mod size_probe;
-use json::*;
+use matrix::*;
@@ -29,6 +29,35 @@ impl Variant {
         self.kind().as_str()
     }
 
+    /// Approx heap bytes owned by this value, excluding its own inline slot.
+    /// Shared `Arc` text/bytes count in full each time they are reached.
+    pub fn approx_heap_bytes(&self) -> usize {
+        use core::mem::size_of;
+        match self {
+            Variant::Null | Variant::Bool(_) | Variant::Number(_) | Variant::ID(_) => 0,
+            Variant::String(v) => v.len(),
+            Variant::Bytes(v) => v.len(),
+            Variant::EngineStruct(v) => match v {
+                EngineStruct::Matrix3(_) => size_of::<Matrix3>(),
+                EngineStruct::Matrix4(_) => size_of::<Matrix4>(),
+                EngineStruct::Transform2D(_) => size_of::<Transform2D>(),
+                EngineStruct::Transform3D(_) => size_of::<Transform3D>(),
+                EngineStruct::PostProcessSet(_) => size_of::<PostProcessSet>(),
+                _ => 0,
+            },
+            Variant::Array(items) => {
+                items.capacity() * size_of::<Variant>()
+                    + items.iter().map(Variant::approx_heap_bytes).sum::<usize>()
+            }
+            Variant::Object(fields) => fields
+                .iter()
+                .map(|(key, value)| {
+                    size_of::<(Arc<str>, Variant)>() + key.len() + value.approx_heap_bytes()
+                })
+                .sum(),
+        }
+    }
+
     #[inline]
     pub fn as_bool(&self) -> Option<bool> {
         match *self {
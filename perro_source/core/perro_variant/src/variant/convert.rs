@@ -170,6 +170,12 @@ impl From<NodeID> for Variant {
         Variant::ID(IDs::Node(v))
     }
 }
+impl From<WeakNodeRef> for Variant {
+    #[inline]
+    fn from(v: WeakNodeRef) -> Self {
+        Variant::ID(IDs::Node(v.id_unchecked()))
+    }
+}
 impl From<TextureID> for Variant {
     #[inline]
     fn from(v: TextureID) -> Self {
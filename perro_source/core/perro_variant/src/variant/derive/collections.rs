@@ -132,6 +132,7 @@ where
     }
 }
 
+#[cfg(feature = "std")]
 impl<K, T> DeriveVariant for HashMap<K, T>
 where
     K: Eq + Hash + VariantObjectKey,
@@ -245,6 +246,7 @@ where
     }
 }
 
+#[cfg(feature = "std")]
 impl<T> DeriveVariant for HashSet<T>
 where
     T: Eq + Hash + DeriveVariant,
@@ -432,6 +434,7 @@ impl DeriveVariant for Duration {
     }
 }
 
+#[cfg(feature = "std")]
 impl DeriveVariant for SystemTime {
     #[inline]
     fn from_variant(value: &Variant) -> Option<Self> {
@@ -50,7 +50,7 @@ impl Variant {
         T: DeriveVariant,
     {
         T::from_variant(self).ok_or_else(|| VariantParseError {
-            target: std::any::type_name::<T>(),
+            target: core::any::type_name::<T>(),
             actual: self.kind_name(),
         })
     }
@@ -65,7 +65,7 @@ impl Variant {
         T: DeriveVariant,
     {
         T::from_scene_variant(self, resolver).ok_or_else(|| VariantParseError {
-            target: std::any::type_name::<T>(),
+            target: core::any::type_name::<T>(),
             actual: self.kind_name(),
         })
     }
@@ -99,7 +99,7 @@ impl Variant {
     {
         let actual = self.kind_name();
         T::from_owned_variant(self).ok_or_else(|| VariantParseError {
-            target: std::any::type_name::<T>(),
+            target: core::any::type_name::<T>(),
             actual,
         })
     }
@@ -115,7 +115,7 @@ impl Variant {
     {
         let actual = self.kind_name();
         T::from_scene_variant(&self, resolver).ok_or_else(|| VariantParseError {
-            target: std::any::type_name::<T>(),
+            target: core::any::type_name::<T>(),
             actual,
         })
     }
@@ -447,6 +447,7 @@ impl DeriveVariant for Cow<'static, str> {
     }
 }
 
+#[cfg(feature = "std")]
 impl DeriveVariant for PathBuf {
     #[inline]
     fn from_variant(value: &Variant) -> Option<Self> {
@@ -496,6 +497,18 @@ impl DeriveVariant for NodeID {
     }
 }
 
+impl DeriveVariant for WeakNodeRef {
+    #[inline]
+    fn from_variant(value: &Variant) -> Option<Self> {
+        NodeID::from_variant(value).map(WeakNodeRef::new)
+    }
+
+    #[inline]
+    fn to_variant(&self) -> Variant {
+        Variant::from(*self)
+    }
+}
+
 impl DeriveVariant for TextureID {
     #[inline]
     fn from_variant(value: &Variant) -> Option<Self> {
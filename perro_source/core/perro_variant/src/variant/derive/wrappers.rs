@@ -52,12 +52,19 @@ where
 }
 
 /// Interned `Result` tag; clone is one atomic increment, no allocation.
+#[cfg(feature = "std")]
 fn result_tag(ok: bool) -> Arc<str> {
     static OK: std::sync::LazyLock<Arc<str>> = std::sync::LazyLock::new(|| Arc::from("Ok"));
     static ERR: std::sync::LazyLock<Arc<str>> = std::sync::LazyLock::new(|| Arc::from("Err"));
     Arc::clone(if ok { &OK } else { &ERR })
 }
 
+/// no_std has no lazy statics; allocate the tag per call.
+#[cfg(not(feature = "std"))]
+fn result_tag(ok: bool) -> Arc<str> {
+    Arc::from(if ok { "Ok" } else { "Err" })
+}
+
 impl<T, E> DeriveVariant for Result<T, E>
 where
     T: DeriveVariant,
@@ -537,193 +537,6 @@ pub(super) fn post_process_effect_to_json(effect: &PostProcessEffect) -> JsonVal
     JsonValue::Object(map)
 }
 
-pub(super) fn parse_matrix_rows<const N: usize>(value: &Variant) -> Option<[[f32; N]; N]> {
-    if let Variant::Object(obj) = value
-        && let Some(rows) = obj.get("rows")
-    {
-        return parse_matrix_rows::<N>(rows);
-    }
-
-    let values = value.as_array()?;
-    let mut rows = [[0.0; N]; N];
-    if values.len() == N {
-        for row in 0..N {
-            let cols = values[row].as_array()?;
-            if cols.len() != N {
-                return None;
-            }
-            for col in 0..N {
-                rows[row][col] = cols[col].as_f32()?;
-            }
-        }
-        return Some(rows);
-    }
-
-    if values.len() == N * N {
-        for row in 0..N {
-            for col in 0..N {
-                rows[row][col] = values[row * N + col].as_f32()?;
-            }
-        }
-        return Some(rows);
-    }
-
-    None
-}
-
-pub(super) fn parse_matrix_rows_generic<const ROWS: usize, const COLS: usize, T>(
-    value: &Variant,
-) -> Option<Matrix<ROWS, COLS, T>>
-where
-    T: VariantMatrixCell,
-{
-    if let Variant::Object(obj) = value
-        && let Some(rows) = obj.get("rows")
-    {
-        return parse_matrix_rows_generic(rows);
-    }
-
-    // `to_variant()` on a square 2x2/3x3/4x4 matrix takes the fast path and
-    // produces a `Variant::EngineStruct(Matrix2/3/4(..))`, not a plain
-    // array. Recognize that shape directly (no `serde_json` round trip
-    // needed) by rebuilding it as row arrays and re-dispatching.
-    if let Variant::EngineStruct(engine_struct) = value {
-        let rows: Option<[[f32; 4]; 4]> = match (engine_struct, ROWS, COLS) {
-            (EngineStruct::Matrix2(m), 2, 2) => {
-                let r = m.to_rows();
-                Some([
-                    [r[0][0], r[0][1], 0.0, 0.0],
-                    [r[1][0], r[1][1], 0.0, 0.0],
-                    [0.0; 4],
-                    [0.0; 4],
-                ])
-            }
-            (EngineStruct::Matrix3(m), 3, 3) => {
-                let r = m.to_rows();
-                Some([
-                    [r[0][0], r[0][1], r[0][2], 0.0],
-                    [r[1][0], r[1][1], r[1][2], 0.0],
-                    [r[2][0], r[2][1], r[2][2], 0.0],
-                    [0.0; 4],
-                ])
-            }
-            (EngineStruct::Matrix4(m), 4, 4) => Some(m.to_rows()),
-            _ => None,
-        };
-        if let Some(rows) = rows {
-            let array = Variant::Array(
-                rows.iter()
-                    .take(ROWS)
-                    .map(|row| {
-                        Variant::Array(row.iter().take(COLS).copied().map(Variant::from).collect())
-                    })
-                    .collect(),
-            );
-            return parse_matrix_rows_generic(&array);
-        }
-        return None;
-    }
-
-    let values = value.as_array()?;
-    let mut rows = Vec::with_capacity(ROWS);
-    if values.len() == ROWS {
-        for row in values {
-            let cols = row.as_array()?;
-            if cols.len() != COLS {
-                return None;
-            }
-            let row = cols
-                .iter()
-                .map(T::from_matrix_cell_variant)
-                .collect::<Option<Vec<_>>>()?
-                .try_into()
-                .ok()?;
-            rows.push(row);
-        }
-    } else if values.len() == ROWS * COLS {
-        for row in 0..ROWS {
-            let start = row * COLS;
-            let row = values[start..start + COLS]
-                .iter()
-                .map(T::from_matrix_cell_variant)
-                .collect::<Option<Vec<_>>>()?
-                .try_into()
-                .ok()?;
-            rows.push(row);
-        }
-    } else {
-        return None;
-    }
-
-    Some(Matrix::new(rows.try_into().ok()?))
-}
-
-pub(super) fn matrix_to_fast_variant<const ROWS: usize, const COLS: usize, T>(
-    matrix: &Matrix<ROWS, COLS, T>,
-) -> Option<Variant>
-where
-    T: VariantMatrixCell,
-{
-    if ROWS != COLS {
-        return None;
-    }
-    let values = matrix_to_f32_values(matrix)?;
-    match ROWS {
-        2 => Some(Variant::from(Matrix2::from_rows([
-            [values[0], values[1]],
-            [values[2], values[3]],
-        ]))),
-        3 => Some(Variant::from(Matrix3::from_rows([
-            [values[0], values[1], values[2]],
-            [values[3], values[4], values[5]],
-            [values[6], values[7], values[8]],
-        ]))),
-        4 => Some(Variant::from(Matrix4::from_rows([
-            [values[0], values[1], values[2], values[3]],
-            [values[4], values[5], values[6], values[7]],
-            [values[8], values[9], values[10], values[11]],
-            [values[12], values[13], values[14], values[15]],
-        ]))),
-        _ => None,
-    }
-}
-
-pub(super) fn matrix_to_f32_values<const ROWS: usize, const COLS: usize, T>(
-    matrix: &Matrix<ROWS, COLS, T>,
-) -> Option<Vec<f32>>
-where
-    T: VariantMatrixCell,
-{
-    let mut out = Vec::with_capacity(ROWS * COLS);
-    for row in matrix.rows() {
-        for cell in row {
-            out.push(cell.as_matrix_cell_f32()?);
-        }
-    }
-    Some(out)
-}
-
-pub(super) fn matrix_to_variant_array<const ROWS: usize, const COLS: usize, T>(
-    matrix: &Matrix<ROWS, COLS, T>,
-) -> Variant
-where
-    T: VariantMatrixCell,
-{
-    Variant::Array(
-        matrix
-            .rows()
-            .iter()
-            .map(|row| {
-                Variant::Array(
-                    row.iter()
-                        .map(VariantMatrixCell::to_matrix_cell_variant)
-                        .collect::<Vec<_>>(),
-                )
-            })
-            .collect(),
-    )
-}
-
 pub(super) fn matrix_rows_to_json<const N: usize>(rows: [[f32; N]; N]) -> JsonValue {
     JsonValue::Array(
         rows.into_iter()
@@ -731,19 +544,3 @@ pub(super) fn matrix_rows_to_json<const N: usize>(rows: [[f32; N]; N]) -> JsonVa
             .collect(),
     )
 }
-
-pub(super) fn variant_to_u32(value: &Variant) -> Option<u32> {
-    match value.as_number()? {
-        Number::I8(v) => u32::try_from(v).ok(),
-        Number::I16(v) => u32::try_from(v).ok(),
-        Number::I32(v) => u32::try_from(v).ok(),
-        Number::I64(v) => u32::try_from(v).ok(),
-        Number::I128(v) => u32::try_from(v.get()).ok(),
-        Number::U8(v) => Some(v as u32),
-        Number::U16(v) => Some(v as u32),
-        Number::U32(v) => Some(v),
-        Number::U64(v) => u32::try_from(v).ok(),
-        Number::U128(v) => u32::try_from(v.get()).ok(),
-        Number::F32(_) | Number::F64(_) => None,
-    }
-}
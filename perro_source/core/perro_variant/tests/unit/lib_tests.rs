@@ -291,6 +291,20 @@ fn test_variant_kind() {
     assert_eq!(VariantKind::Bool.as_str(), "Bool");
 }
 
+#[test]
+fn test_variant_approx_heap_bytes_walks_containers() {
+    assert_eq!(Variant::from(7_i32).approx_heap_bytes(), 0);
+    assert_eq!(Variant::from("abcd").approx_heap_bytes(), 4);
+
+    let items = vec![Variant::from("ab"), Variant::Null];
+    let slots = items.capacity();
+    let list = Variant::Array(items);
+    assert_eq!(
+        list.approx_heap_bytes(),
+        slots * std::mem::size_of::<Variant>() + 2
+    );
+}
+
 #[test]
 fn test_variant_as_bool() {
     let v = Variant::Bool(true);
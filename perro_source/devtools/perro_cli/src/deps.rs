@@ -0,0 +1,99 @@
+use crate::{find_project_root, parse_flag_value, resolve_local_path};
+use perro_project::load_project_toml;
+use perro_scene::deps::{AssetDepGraph, normalize_asset_ref};
+use std::path::Path;
+
+pub(crate) fn deps_command(args: &[String], cwd: &Path) -> Result<(), String> {
+    let project_dir = parse_flag_value(args, "--path")
+        .map(|p| resolve_local_path(&p, cwd))
+        .or_else(|| find_project_root(cwd))
+        .ok_or_else(|| {
+            "could not find project.toml. Run from a project directory or pass --path <project_dir>."
+                .to_string()
+        })?;
+    let project_dir = project_dir.canonicalize().unwrap_or(project_dir);
+    if !project_dir.join("project.toml").exists() {
+        return Err(format!(
+            "invalid --path `{}` for deps. Use project root (directory containing project.toml).",
+            project_dir.display()
+        ));
+    }
+
+    let target = match deps_target_arg(args) {
+        Some(raw) => normalize_asset_ref(&virtual_target(&raw), ""),
+        None => {
+            load_project_toml(&project_dir)
+                .map_err(|err| format!("project.toml parse failed: {err}"))?
+                .main_scene
+        }
+    };
+    let graph = AssetDepGraph::scan_project(&project_dir)?;
+
+    println!("depends on:");
+    print!("{}", graph.deps_tree_text(&target));
+    println!();
+    println!("used by:");
+    if graph.is_used(&target) {
+        print!("{}", graph.users_tree_text(&target));
+    } else {
+        println!("{target} (unused)");
+    }
+    Ok(())
+}
+
+/// First positional arg; `--path` value skipped.
+fn deps_target_arg(args: &[String]) -> Option<String> {
+    let mut iter = args.iter().skip(2);
+    while let Some(arg) = iter.next() {
+        if arg == "--path" {
+            iter.next();
+        } else if arg == "--" {
+            break;
+        } else if !arg.starts_with('-') {
+            return Some(arg.clone());
+        }
+    }
+    None
+}
+
+/// Bare `player.png` means `res://player.png`.
+fn virtual_target(raw: &str) -> String {
+    if raw.contains("://") {
+        raw.to_string()
+    } else {
+        format!("res://{}", raw.trim_start_matches('/').replace('\\', "/"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn target_arg_skips_path_value() {
+        assert_eq!(
+            deps_target_arg(&args(&[
+                "perro",
+                "deps",
+                "--path",
+                "game",
+                "res://main.scn"
+            ])),
+            Some("res://main.scn".to_string())
+        );
+        assert_eq!(
+            deps_target_arg(&args(&["perro", "deps", "--path", "game"])),
+            None
+        );
+    }
+
+    #[test]
+    fn bare_target_is_res_path() {
+        assert_eq!(virtual_target("art/player.png"), "res://art/player.png");
+        assert_eq!(virtual_target("dlc://extra/a.scn"), "dlc://extra/a.scn");
+    }
+}
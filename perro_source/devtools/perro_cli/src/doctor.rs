@@ -101,6 +101,7 @@ fn validate_project(project_dir: &Path) -> Result<ValidationReport, String> {
     }
 
     validate_script_warnings(project_dir, &mut report)?;
+    validate_scene_syntax(project_dir, &mut report)?;
 
     Ok(report)
 }
@@ -551,6 +552,36 @@ fn validate_node_ref_type_warnings(
     Ok(())
 }
 
+/// Reports every parse error in each scene, w/ file:line:col.
+fn validate_scene_syntax(project_dir: &Path, report: &mut ValidationReport) -> Result<(), String> {
+    let mut files = Vec::new();
+    collect_scene_files_recursive(&project_dir.join("res"), &mut files)?;
+    collect_scene_files_recursive(&project_dir.join("dlcs"), &mut files)?;
+    for file in files {
+        let text = fs::read_to_string(&file)
+            .map_err(|err| format!("failed to read scene {}: {err}", file.display()))?;
+        let loader = |path: &str| -> Result<String, String> {
+            let resolved = resolve_script_virtual_ref_path(project_dir, &file, path)
+                .ok_or_else(|| format!("unsupported scene path `{path}`"))?;
+            fs::read_to_string(&resolved).map_err(|err| err.to_string())
+        };
+        let Err(diagnostics) = Parser::new(&text)
+            .with_scene_loader(&loader)
+            .parse_scene_checked()
+        else {
+            continue;
+        };
+        for diag in diagnostics {
+            let source = format_source_location(project_dir, Some(&file), Some(diag.line));
+            report.error(format!(
+                "scene parse: {source}col {}: {}",
+                diag.column, diag.message
+            ));
+        }
+    }
+    Ok(())
+}
+
 fn collect_scene_files_recursive(dir: &Path, out: &mut Vec<PathBuf>) -> Result<(), String> {
     if !dir.exists() {
         return Ok(());
@@ -1042,6 +1073,8 @@ mod script_parser;
 use script_parser::*;
 mod lexer;
 use lexer::*;
+mod lint;
+pub(crate) use lint::lint_scenes_command;
 
 #[cfg(test)]
 #[path = "doctor/tests/mod.rs"]
@@ -0,0 +1,136 @@
+use super::*;
+use crate::find_project_root;
+use perro_scene::{SceneAssetKind, SceneNodeEntry};
+
+/// `perro_cli lint-scenes`: parse every res:// scene, then check node types
+/// and the script + texture refs each node points at.
+pub(crate) fn lint_scenes_command(args: &[String], cwd: &Path) -> Result<(), String> {
+    let project_dir = parse_flag_value(args, "--path")
+        .map(|p| resolve_local_path(&p, cwd))
+        .or_else(|| find_project_root(cwd))
+        .ok_or_else(|| {
+            "could not find project.toml. Run from a project directory or pass --path <project_dir>."
+                .to_string()
+        })?;
+    let project_dir = project_dir.canonicalize().unwrap_or(project_dir);
+    if !project_dir.join("project.toml").exists() {
+        return Err(format!(
+            "invalid --path `{}` for lint-scenes. Use project root (directory containing project.toml).",
+            project_dir.display()
+        ));
+    }
+
+    let report = lint_scenes(&project_dir)?;
+    report.print();
+    if report.warnings > 0 {
+        return Err(format!("lint failed: {} warning(s)", report.warnings));
+    }
+    log_done("Scenes Clean");
+    Ok(())
+}
+
+pub(super) fn lint_scenes(project_dir: &Path) -> Result<ValidationReport, String> {
+    let mut files = Vec::new();
+    collect_scene_files_recursive(&project_dir.join("res"), &mut files)?;
+    files.sort();
+
+    let mut report = ValidationReport::default();
+    for file in files {
+        report.checked_files += 1;
+        let text = fs::read_to_string(&file)
+            .map_err(|err| format!("failed to read scene {}: {err}", file.display()))?;
+        let loader = |path: &str| -> Result<String, String> {
+            let resolved = resolve_script_virtual_ref_path(project_dir, &file, path)
+                .ok_or_else(|| format!("unsupported scene path `{path}`"))?;
+            fs::read_to_string(&resolved).map_err(|err| err.to_string())
+        };
+        // unknown node types fail the parse against the node registry
+        let scene = match Parser::new(&text)
+            .with_scene_loader(&loader)
+            .parse_scene_checked()
+        {
+            Ok(scene) => scene,
+            Err(diagnostics) => {
+                for diag in diagnostics {
+                    let source = format_source_location(project_dir, Some(&file), Some(diag.line));
+                    report.warn(format!("{source}col {}: {}", diag.column, diag.message));
+                }
+                continue;
+            }
+        };
+
+        let mut refs = SceneRefLines::new(&text);
+        for node in scene.nodes.iter() {
+            let node_key = scene.key_name_or_id(node.key);
+            for (label, raw) in node_asset_refs(node) {
+                // not in this file's text -> came from an @extends base, linted there
+                let Some(line) = refs.next_line(&raw) else {
+                    continue;
+                };
+                report.checked_refs += 1;
+                let missing = resolve_script_virtual_ref_path(project_dir, &file, &raw)
+                    .is_none_or(|path| !path.exists());
+                if missing {
+                    let source = format_source_location(project_dir, Some(&file), Some(line));
+                    report.warn(format!(
+                        "{source}node `{node_key}` {label}: missing `{raw}`"
+                    ));
+                }
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// `script` plus every texture field on the node + its base blocks.
+fn node_asset_refs(node: &SceneNodeEntry) -> Vec<(String, String)> {
+    let mut out = Vec::new();
+    if let Some(script) = node.script.as_deref() {
+        out.push(("script".to_string(), script.to_string()));
+    }
+    let mut data = Some(&node.data);
+    while let Some(level) = data {
+        for (name, value) in level.fields.iter() {
+            let SceneValue::Str(raw) = value else {
+                continue;
+            };
+            let is_texture = perro_scene::scene_node_field(level.node_type, name.as_ref())
+                .is_some_and(|field| {
+                    matches!(field.ty, NodeFieldType::Asset(SceneAssetKind::Texture))
+                });
+            if is_texture {
+                out.push((name.to_string(), raw.to_string()));
+            }
+        }
+        data = level.base_ref();
+    }
+    out
+}
+
+/// Maps each quoted ref back to a source line. repeats of the same literal
+/// resolve to successive occurrences, in node order.
+struct SceneRefLines<'a> {
+    text: &'a str,
+    cursors: HashMap<String, usize>,
+}
+
+impl<'a> SceneRefLines<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            cursors: HashMap::new(),
+        }
+    }
+
+    fn next_line(&mut self, raw: &str) -> Option<usize> {
+        let quoted = format!("\"{raw}\"");
+        let from = self.cursors.get(raw).copied().unwrap_or(0);
+        let start = match self.text[from..].find(&quoted) {
+            Some(rel) => from + rel,
+            // var reused by several nodes: fall back to its first literal
+            None => self.text.find(&quoted)?,
+        };
+        self.cursors.insert(raw.to_string(), start + quoted.len());
+        Some(line_number_at(self.text, start))
+    }
+}
@@ -606,3 +606,55 @@ fn node_ref_hints_resolve_by_attached_script_for_shared_field_names() {
             .contains("BadGolfer.script_vars.config.orbit_camera wants Node(Camera3D)")
     );
 }
+
+#[test]
+fn scene_syntax_reports_every_parse_error_with_location() {
+    let project = temp_project();
+    fs::create_dir_all(project.join("res")).expect("test setup/result must succeed");
+    fs::write(
+        project.join("res/base.scn"),
+        "$root = @main\n[main]\n[Node2D]\n[/Node2D]\n[/main]\n",
+    )
+    .expect("test setup/result must succeed");
+    fs::write(
+        project.join("res/level.scn"),
+        "@extends = \"res://base.scn\"\n[main]\n    speed = )\n[/main]\n[hud]\nparent = @ghost\n[/hud]\n",
+    )
+    .expect("test setup/result must succeed");
+
+    let mut report = ValidationReport::default();
+    validate_scene_syntax(&project, &mut report).expect("test setup/result must succeed");
+
+    assert_eq!(report.errors, 2, "{:?}", report.messages);
+    assert!(report.messages[0].contains("res://level.scn:3: col 13"));
+    assert!(report.messages[1].contains("res://level.scn:6: "));
+    assert!(report.messages[1].contains("`ghost` not found"));
+}
+
+#[test]
+fn lint_scenes_reports_unknown_node_types_and_missing_assets_with_lines() {
+    let project = temp_project();
+    fs::create_dir_all(project.join("res/scripts")).expect("test setup/result must succeed");
+    fs::write(project.join("res/scripts/player.rs"), b"").expect("test setup/result must succeed");
+    fs::write(project.join("res/hero.png"), b"x").expect("test setup/result must succeed");
+    fs::write(
+        project.join("res/main.scn"),
+        "$root = @player\n[player]\nscript = \"res://scripts/player.rs\"\n[Sprite2D]\n    texture = \"res://hero.png\"\n[/Sprite2D]\n[/player]\n[enemy]\nscript = \"res://scripts/enemy.rs\"\n[Sprite2D]\n    texture = \"res://enemy.png\"\n[/Sprite2D]\n[/enemy]\n",
+    )
+    .expect("test setup/result must succeed");
+    fs::write(
+        project.join("res/broken.scn"),
+        "$root = @main\n[main]\n[Sprite9D]\n[/Sprite9D]\n[/main]\n",
+    )
+    .expect("test setup/result must succeed");
+
+    let report = lint::lint_scenes(&project).expect("test setup/result must succeed");
+
+    assert_eq!(report.checked_files, 2);
+    assert_eq!(report.checked_refs, 4);
+    assert_eq!(report.warnings, 3, "{:?}", report.messages);
+    assert!(report.messages[0].contains("res://broken.scn:"));
+    assert!(report.messages[0].contains("Sprite9D"));
+    assert!(report.messages[1].contains("res://main.scn:9: node `enemy` script"));
+    assert!(report.messages[2].contains("res://main.scn:11: node `enemy` texture"));
+}
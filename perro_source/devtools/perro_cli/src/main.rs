@@ -2,20 +2,27 @@ use std::env;
 use std::path::{Path, PathBuf};
 
 mod bench;
+mod deps;
 mod doctor;
 mod gltf_animation;
 mod install;
+mod open;
 mod profiling;
 mod project;
 mod scaffold;
+mod script_docs;
 mod script_tests;
 mod targets;
+mod thumbnail;
+mod visual_tests;
 mod vscode;
 
 use bench::bench_command;
-use doctor::doctor_command;
+use deps::deps_command;
+use doctor::{doctor_command, lint_scenes_command};
 use gltf_animation::gltf_to_panim_command;
 use install::install_command;
+use open::open_command;
 use profiling::{flamegraph_command, mem_profile_command, spec_command};
 use project::{
     clean_command, clippy_command, dev_command, dlc_command, format_command, project_command,
@@ -25,8 +32,10 @@ use scaffold::{
     new_animation_command, new_command, new_dlc_command, new_panimtree_command, new_scene_command,
     new_script_command,
 };
+use script_docs::doc_command;
 use script_tests::test_command;
 use targets::targets_command;
+use thumbnail::thumbnail_command;
 
 const DEFAULT_PROJECT_NAME: &str = "Perro Project";
 const COLOR_RESET: &str = "\x1b[0m";
@@ -81,8 +90,13 @@ fn main() {
             "targets" => targets_command(&args),
             "dlc" => dlc_command(&args, &cwd),
             "dev" => dev_command(&args, &cwd),
+            "thumbnail" => thumbnail_command(&args, &cwd),
             "bench" => bench_command(&args, &cwd),
             "doctor" => doctor_command(&args, &cwd),
+            "lint-scenes" => lint_scenes_command(&args, &cwd),
+            "deps" => deps_command(&args, &cwd),
+            "doc" => doc_command(&args, &cwd),
+            "open" => open_command(&args, &cwd),
             "mem-profile" => mem_profile_command(&args, &cwd),
             "spec" => spec_command(&args, &cwd),
             "flamegraph" => flamegraph_command(&args, &cwd),
@@ -136,13 +150,37 @@ const fn optional_value(name: &'static str) -> FlagSpec {
 }
 
 const PATH: &[FlagSpec] = &[value("--path")];
+const TEST: &[FlagSpec] = &[
+    value("--path"),
+    switch("--visual"),
+    value("--scene"),
+    value("--frames"),
+    value("--threshold"),
+    switch("--update"),
+];
+const PROJECT: &[FlagSpec] = &[value("--path"), value("--project")];
+const DOC: &[FlagSpec] = &[
+    value("--path"),
+    value("--project"),
+    value("--format"),
+    value("--out"),
+];
 const NEW: &[FlagSpec] = &[value("--path"), value("--name")];
 const NEW_DLC: &[FlagSpec] = &[value("--path"), value("--name"), switch("--no-open")];
+const NEW_RES_FILE: &[FlagSpec] = &[
+    value("--path"),
+    value("--name"),
+    value("--res"),
+    value("--dlc"),
+    switch("--no-open"),
+];
 const NEW_SCRIPT: &[FlagSpec] = &[
     value("--path"),
     value("--name"),
     value("--res"),
     value("--dlc"),
+    value("--template"),
+    value("--node"),
     switch("--no-open"),
 ];
 const NEW_SCENE: &[FlagSpec] = &[
@@ -168,6 +206,7 @@ const IMPORT_ANIM: &[FlagSpec] = &[
 const INSTALL: &[FlagSpec] = &[value("--profile")];
 const BUILD: &[FlagSpec] = &[
     value("--path"),
+    value("--project"),
     value("--target"),
     value("--triple"),
     switch("--universal-macos"),
@@ -175,11 +214,13 @@ const BUILD: &[FlagSpec] = &[
     switch("--console"),
     switch("--headless"),
     switch("--fresh"),
+    switch("--reproducible"),
     switch("--demo"),
 ];
-const DLC: &[FlagSpec] = &[value("--name"), value("--path")];
+const DLC: &[FlagSpec] = &[value("--name"), value("--path"), value("--project")];
 const DEV: &[FlagSpec] = &[
     value("--path"),
+    value("--project"),
     value("--target"),
     switch("--timings"),
     switch("--profile"),
@@ -190,7 +231,19 @@ const DEV: &[FlagSpec] = &[
     value("--port"),
     switch("--headless"),
     switch("--demo"),
+    value("--scene"),
+    value("--record"),
+    value("--record-fps"),
+    value("--record-seconds"),
 ];
+const THUMBNAIL: &[FlagSpec] = &[
+    value("--path"),
+    value("--project"),
+    value("--scene"),
+    value("--size"),
+    switch("--force"),
+];
+const OPEN: &[FlagSpec] = &[value("--path"), value("--project"), value("--editor")];
 const BENCH: &[FlagSpec] = &[
     value("--path"),
     value("--script"),
@@ -211,15 +264,21 @@ fn command_schema(command: &str) -> Option<&'static [FlagSpec]> {
     match command {
         "new" => Some(NEW),
         "new_dlc" => Some(NEW_DLC),
-        "new_script" | "new_animation" | "new_panimtree" => Some(NEW_SCRIPT),
+        "new_script" => Some(NEW_SCRIPT),
+        "new_animation" | "new_panimtree" => Some(NEW_RES_FILE),
         "new_scene" => Some(NEW_SCENE),
         "import_anim" | "gltf_to_panim" | "glb_to_panim" => Some(IMPORT_ANIM),
-        "clean" | "check" | "test" | "doctor" | "clippy" => Some(PATH),
+        "clean" | "doctor" | "lint-scenes" | "deps" => Some(PATH),
+        "test" => Some(TEST),
+        "doc" => Some(DOC),
+        "check" | "clippy" => Some(PROJECT),
         "install" => Some(INSTALL),
         "build" => Some(BUILD),
         "targets" => Some(TARGETS),
         "dlc" => Some(DLC),
         "dev" => Some(DEV),
+        "thumbnail" => Some(THUMBNAIL),
+        "open" => Some(OPEN),
         "bench" => Some(BENCH),
         "mem-profile" => Some(MEM_PROFILE),
         "spec" => Some(SPEC),
@@ -289,20 +348,26 @@ fn validate_command_args(command: &str, args: &[String]) -> Result<(), String> {
 fn print_usage() {
     eprintln!("Usage:");
     eprintln!(
-        "  perro_cli check [--path <project_dir>]    # scripts-only compile (.perro/scripts)"
+        "  perro_cli check [--path <project_dir> | --project <name>]    # scripts-only compile (.perro/scripts)"
     );
     eprintln!(
         "  perro_cli test [--path <project_dir>] [-- <cargo_test_args>]    # sync scripts + run cargo test for .perro/scripts"
     );
     eprintln!(
-        "  perro_cli build [--path <project_dir>] [--target native|web|android] [--triple <rust_target> | --universal-macos] [--profile] [--console] [--headless] [--fresh] [--demo]    # static project bundle + build"
+        "  perro_cli test --visual [--path <project_dir>] [--scene <res://scene>] [--frames <n>] [--threshold <fraction>] [--update]    # render scenes + compare against tests/visual golden PNGs"
+    );
+    eprintln!(
+        "  perro_cli build [--path <project_dir> | --project <name>] [--target native|web|android|server] [--triple <rust_target> | --universal-macos] [--profile] [--console] [--headless] [--fresh] [--reproducible] [--demo]    # static project bundle + build"
     );
     eprintln!("  perro_cli targets [--host windows|linux|macos]    # show build support by dev OS");
     eprintln!(
-        "  perro_cli dlc --name <dlc_name> [--path <project_dir>] # build one runtime-loadable DLC package"
+        "  perro_cli dlc --name <dlc_name> [--path <project_dir> | --project <name>] # build one runtime-loadable DLC package"
+    );
+    eprintln!(
+        "  perro_cli dev [--path <project_dir> | --project <name>] [--target native|web|android] [--headless] [--demo] [--scene <res://scene>] [--timings] [--profile] [--ui-profile] [--release] [--csv-profile [csv_name]] [--record <dir|video>] [--record-fps <fps>] [--record-seconds <secs>] [--host <addr>] [--port <num>]      # build scripts + run dev runner, web server, or android app"
     );
     eprintln!(
-        "  perro_cli dev [--path <project_dir>] [--target native|web|android] [--headless] [--demo] [--timings] [--profile] [--ui-profile] [--release] [--csv-profile [csv_name]] [--host <addr>] [--port <num>]      # build scripts + run dev runner, web server, or android app"
+        "  perro_cli thumbnail [--path <project_dir> | --project <name>] [--scene <res://scene>] [--size <px>] [--force]    # render scene thumbnails into .perro/cache/thumbnails (stale only)"
     );
     eprintln!(
         "  perro_cli bench [--path <project_dir>] [--script <hash>] [--method <name>] [--var <name>] [-- <criterion_args>]    # criterion bench scripts"
@@ -319,11 +384,23 @@ fn print_usage() {
     eprintln!(
         "  perro_cli doctor [--path <project_dir>]   # scene/resource/script reference checks"
     );
+    eprintln!(
+        "  perro_cli lint-scenes [--path <project_dir>]   # parse res:// scenes, check node types + script/texture refs"
+    );
+    eprintln!(
+        "  perro_cli open <res://file.rs[:line[:col]]> [--path <project_dir> | --project <name>] [--editor <cmd>]    # open a project file at a line (PERRO_EDITOR, default VS Code)"
+    );
+    eprintln!(
+        "  perro_cli deps [--path <project_dir>] [res://<asset>]   # asset dependency tree + what uses it (default: main scene)"
+    );
+    eprintln!(
+        "  perro_cli doc [--path <project_dir> | --project <name>] [--format md|html] [--out <file>]   # script API reference: vars, methods, signals + /// docs (default .output/docs/scripts.md)"
+    );
     eprintln!(
         "  perro_cli format [--path <project_dir>] [--dedup]   # format .rs, .scn, .fur, .pmat, .ppart, .uistyle under project res"
     );
     eprintln!(
-        "  perro_cli clippy [--path <project_dir>]   # cargo clippy for .rs under project res"
+        "  perro_cli clippy [--path <project_dir> | --project <name>]   # cargo clippy for .rs under project res"
     );
     eprintln!("  perro_cli clean [--path <project_dir>]    # remove project target/");
     eprintln!(
@@ -332,7 +409,7 @@ fn print_usage() {
     eprintln!("  perro_cli new [--path <parent_dir>] [--name <project_name>]");
     eprintln!("  perro_cli new_dlc --name <dlc_name> [--path <project_dir>]");
     eprintln!(
-        "  perro_cli new_script --name <script_name> [--path <project_dir>] [--res <res_subdir>] [--dlc <dlc_name>]"
+        "  perro_cli new_script --name <script_name> [--path <project_dir>] [--res <res_subdir>] [--dlc <dlc_name>] [--template empty|update|signal] [--node <NodeType>]"
     );
     eprintln!(
         "  perro_cli new_scene --name <scene_name> [--path <project_dir>] [--res <res_subdir>] [--dlc <dlc_name>] [--template 2D|3D]"
@@ -406,6 +483,62 @@ fn find_project_root(start: &Path) -> Option<PathBuf> {
     None
 }
 
+/// Project dir for `dev`/`build`/`check`/`dlc`/`clippy`: `--project <name>` from the nearest
+/// `projects.toml`, else `--path`, else cwd. A cwd without project.toml falls
+/// back to the registry `default`.
+fn resolve_cli_project_dir(args: &[String], cwd: &Path) -> Result<PathBuf, String> {
+    let path = parse_flag_value(args, "--path");
+    if let Some(name) = parse_flag_value(args, "--project") {
+        if path.is_some() {
+            return Err("use either `--project` or `--path`, not both".to_string());
+        }
+        let registry = load_cli_projects_registry(cwd)?.ok_or_else(|| {
+            format!(
+                "`--project {name}` needs a {} at or above {}",
+                perro_project::PROJECTS_TOML,
+                cwd.display()
+            )
+        })?;
+        let Some(project) = registry.get(&name) else {
+            let known = registry.names().collect::<Vec<_>>().join(", ");
+            return Err(format!("unknown project `{name}`; registered: {known}"));
+        };
+        let project_dir = registry.project_dir(project);
+        if !project_dir.join("project.toml").exists() {
+            return Err(format!(
+                "project `{name}` at {} has no project.toml",
+                project_dir.display()
+            ));
+        }
+        return Ok(project_dir);
+    }
+    if let Some(path) = path {
+        return Ok(resolve_local_path(&path, cwd));
+    }
+    if !cwd.join("project.toml").exists()
+        && let Some(registry) = load_cli_projects_registry(cwd)?
+        && let Some(project) = registry
+            .default
+            .as_deref()
+            .and_then(|name| registry.get(name))
+    {
+        log_note(&format!("Using Default Project `{}`", project.name));
+        return Ok(registry.project_dir(project));
+    }
+    Ok(cwd.to_path_buf())
+}
+
+fn load_cli_projects_registry(
+    cwd: &Path,
+) -> Result<Option<perro_project::ProjectsRegistry>, String> {
+    let Some(path) = perro_project::find_projects_toml(cwd) else {
+        return Ok(None);
+    };
+    perro_project::load_projects_toml(&path)
+        .map(Some)
+        .map_err(|err| format!("failed to load {}: {err}", path.display()))
+}
+
 #[cfg(test)]
 mod cli_arg_tests {
     use super::*;
@@ -454,6 +587,34 @@ mod cli_arg_tests {
         assert_eq!(validate_command_args("test", &args), Ok(()));
     }
 
+    #[test]
+    fn visual_test_flags_valid_for_test() {
+        let missing = args(&["perro", "test", "--visual", "--frames"]);
+        let args = args(&[
+            "perro",
+            "test",
+            "--visual",
+            "--scene",
+            "res://main.scn",
+            "--threshold",
+            "0.01",
+            "--update",
+        ]);
+
+        assert_eq!(validate_command_args("test", &args), Ok(()));
+        assert_eq!(
+            validate_command_args("test", &missing),
+            Err("missing value for flag `--frames` in `test`".to_string())
+        );
+    }
+
+    #[test]
+    fn doc_flags_valid_for_doc() {
+        let args = args(&["perro", "doc", "--format", "html", "--out", "api.html"]);
+
+        assert_eq!(validate_command_args("doc", &args), Ok(()));
+    }
+
     #[test]
     fn spec_target_fps_requires_value() {
         let args = args(&["perro", "spec", "--target-fps"]);
@@ -475,4 +636,70 @@ mod cli_arg_tests {
             Ok(())
         );
     }
+
+    #[test]
+    fn dev_record_flags_take_values() {
+        let record = args(&["perro", "dev", "--record", "out.mp4", "--record-fps", "30"]);
+
+        assert_eq!(validate_command_args("dev", &record), Ok(()));
+        assert_eq!(
+            parse_flag_value(&record, "--record-fps"),
+            Some("30".to_string())
+        );
+        assert_eq!(
+            validate_command_args("dev", &args(&["perro", "dev", "--record"])),
+            Err("missing value for flag `--record` in `dev`".to_string())
+        );
+    }
+
+    #[test]
+    fn dev_scene_flag_takes_value() {
+        let scene = args(&["perro", "dev", "--scene", "res://tests/arena.scn"]);
+
+        assert_eq!(validate_command_args("dev", &scene), Ok(()));
+        assert_eq!(
+            parse_flag_value(&scene, "--scene"),
+            Some("res://tests/arena.scn".to_string())
+        );
+        assert_eq!(
+            validate_command_args("dev", &args(&["perro", "dev", "--scene"])),
+            Err("missing value for flag `--scene` in `dev`".to_string())
+        );
+    }
+
+    #[test]
+    fn project_flag_resolves_through_nearest_registry() {
+        let root = env::temp_dir().join(format!("perro_cli_projects_{}", std::process::id()));
+        let game = root.join("games").join("Game");
+        std::fs::create_dir_all(&game).expect("create project dir");
+        std::fs::write(game.join("project.toml"), "").expect("write project.toml");
+        std::fs::write(
+            root.join("projects.toml"),
+            "default = \"Game\"\n\n[[project]]\nname = \"Game\"\npath = \"games/Game\"\n",
+        )
+        .expect("write projects.toml");
+
+        let dev = args(&["perro", "dev", "--project", "Game"]);
+        assert_eq!(validate_command_args("dev", &dev), Ok(()));
+        assert_eq!(resolve_cli_project_dir(&dev, &root), Ok(game.clone()));
+        assert_eq!(
+            resolve_cli_project_dir(&args(&["perro", "dev"]), &root),
+            Ok(game.clone())
+        );
+        assert_eq!(
+            resolve_cli_project_dir(&args(&["perro", "dev"]), &game),
+            Ok(game.clone())
+        );
+        let err = resolve_cli_project_dir(&args(&["perro", "dev", "--project", "Nope"]), &root)
+            .expect_err("operation must fail in this test");
+        assert_eq!(err, "unknown project `Nope`; registered: Game");
+        let err = resolve_cli_project_dir(
+            &args(&["perro", "dev", "--project", "Game", "--path", "x"]),
+            &root,
+        )
+        .expect_err("operation must fail in this test");
+        assert_eq!(err, "use either `--project` or `--path`, not both");
+
+        std::fs::remove_dir_all(&root).expect("cleanup");
+    }
 }
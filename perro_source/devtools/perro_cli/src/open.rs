@@ -0,0 +1,218 @@
+use crate::install::normalize_powershell_path;
+use crate::{parse_flag_value, resolve_cli_project_dir};
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Editor command used when neither `--editor` nor `PERRO_EDITOR` is set.
+const DEFAULT_EDITOR: &str = "code";
+
+/// `perro open res://scripts/player.rs:42[:7]`: open a project file at a line
+/// in the configured editor.
+///
+/// Editor lookup: `--editor`, then `PERRO_EDITOR`, then VS Code. The line
+/// flag syntax is picked from the editor binary name.
+pub(crate) fn open_command(args: &[String], cwd: &Path) -> Result<(), String> {
+    let Some(target) = open_target_arg(args) else {
+        return Err("missing target. Usage: perro open res://path.rs[:line[:col]]".to_string());
+    };
+    let location = parse_open_target(&target)?;
+    let file = match location.path.split_once("://") {
+        Some(_) => {
+            let project_dir = resolve_cli_project_dir(args, cwd)?;
+            let project_dir = project_dir.canonicalize().unwrap_or(project_dir);
+            map_virtual_path(&project_dir, &location.path)?
+        }
+        None => cwd.join(&location.path),
+    };
+    if !file.exists() {
+        return Err(format!("file not found: {}", file.display()));
+    }
+
+    let editor = parse_flag_value(args, "--editor")
+        .or_else(|| {
+            env::var("PERRO_EDITOR")
+                .ok()
+                .filter(|v| !v.trim().is_empty())
+        })
+        .unwrap_or_else(|| DEFAULT_EDITOR.to_string());
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or(DEFAULT_EDITOR);
+    let status = Command::new(program)
+        .args(parts)
+        .args(editor_goto_args(
+            program,
+            &normalize_powershell_path(&file),
+            location.line,
+            location.column,
+        ))
+        .status()
+        .map_err(|err| format!("failed to launch `{program}`: {err}"))?;
+    if !status.success() {
+        return Err(format!("`{program}` exited with code {:?}", status.code()));
+    }
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct OpenTarget {
+    path: String,
+    line: Option<u32>,
+    column: Option<u32>,
+}
+
+fn open_target_arg(args: &[String]) -> Option<String> {
+    let mut iter = args.iter().skip(2);
+    while let Some(arg) = iter.next() {
+        if matches!(arg.as_str(), "--path" | "--project" | "--editor") {
+            iter.next();
+        } else if !arg.starts_with('-') {
+            return Some(arg.clone());
+        }
+    }
+    None
+}
+
+/// Split trailing `:line[:col]` off a target. Drive letters and `res://`
+/// colons are left alone since they are not followed by digits only.
+fn parse_open_target(raw: &str) -> Result<OpenTarget, String> {
+    let mut path = raw.trim();
+    let mut numbers = Vec::new();
+    while numbers.len() < 2 {
+        let Some((head, tail)) = path.rsplit_once(':') else {
+            break;
+        };
+        let Ok(value) = tail.parse::<u32>() else {
+            break;
+        };
+        numbers.push(value);
+        path = head;
+    }
+    if path.is_empty() {
+        return Err(format!("invalid open target `{raw}`"));
+    }
+    numbers.reverse();
+    Ok(OpenTarget {
+        path: path.to_string(),
+        line: numbers.first().copied(),
+        column: numbers.get(1).copied(),
+    })
+}
+
+/// `res://a.rs` -> `<project>/res/a.rs`, `dlc://name/a.rs` ->
+/// `<project>/dlcs/name/a.rs`.
+fn map_virtual_path(project_dir: &Path, path: &str) -> Result<PathBuf, String> {
+    let (base, rel) = if let Some(rel) = path.strip_prefix("res://") {
+        (project_dir.join("res"), rel)
+    } else if let Some(rel) = path.strip_prefix("dlc://") {
+        (project_dir.join("dlcs"), rel)
+    } else {
+        return Err(format!(
+            "`{path}` is not a res:// or dlc:// path; user:// files live outside the project"
+        ));
+    };
+    let mut out = base;
+    for part in rel
+        .split('/')
+        .filter(|part| !part.is_empty() && *part != ".")
+    {
+        if part == ".." {
+            return Err(format!("`{path}` leaves the project tree"));
+        }
+        out.push(part);
+    }
+    Ok(out)
+}
+
+/// Goto-line arguments for common editors, keyed by binary name.
+fn editor_goto_args(
+    program: &str,
+    file: &str,
+    line: Option<u32>,
+    column: Option<u32>,
+) -> Vec<String> {
+    let name = Path::new(program)
+        .file_stem()
+        .and_then(|name| name.to_str())
+        .unwrap_or(program)
+        .to_ascii_lowercase();
+    let Some(line) = line else {
+        return vec![file.to_string()];
+    };
+    let col = column.unwrap_or(1);
+    match name.as_str() {
+        "code" | "code-insiders" | "codium" | "cursor" | "windsurf" => {
+            vec!["-g".to_string(), format!("{file}:{line}:{col}")]
+        }
+        "zed" | "subl" | "hx" | "helix" => vec![format!("{file}:{line}:{col}")],
+        "vim" | "nvim" | "vi" | "nano" | "emacs" | "emacsclient" | "kak" => {
+            vec![format!("+{line}"), file.to_string()]
+        }
+        "idea" | "idea64" | "rustrover" | "rustrover64" | "clion" | "clion64" => vec![
+            "--line".to_string(),
+            line.to_string(),
+            "--column".to_string(),
+            col.to_string(),
+            file.to_string(),
+        ],
+        _ => vec![format!("{file}:{line}")],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_open_target_splits_line_and_column() {
+        assert_eq!(
+            parse_open_target("res://scripts/player.rs:42:7"),
+            Ok(OpenTarget {
+                path: "res://scripts/player.rs".to_string(),
+                line: Some(42),
+                column: Some(7),
+            })
+        );
+        assert_eq!(
+            parse_open_target("C:/game/res/a.rs"),
+            Ok(OpenTarget {
+                path: "C:/game/res/a.rs".to_string(),
+                line: None,
+                column: None,
+            })
+        );
+        assert!(parse_open_target(":4").is_err());
+    }
+
+    #[test]
+    fn map_virtual_path_resolves_res_and_dlc() {
+        let root = Path::new("game");
+        assert_eq!(
+            map_virtual_path(root, "res://scripts/a.rs"),
+            Ok(root.join("res").join("scripts").join("a.rs"))
+        );
+        assert_eq!(
+            map_virtual_path(root, "dlc://Expansion/b.rs"),
+            Ok(root.join("dlcs").join("Expansion").join("b.rs"))
+        );
+        assert!(map_virtual_path(root, "res://../x.rs").is_err());
+        assert!(map_virtual_path(root, "user://save.json").is_err());
+    }
+
+    #[test]
+    fn editor_goto_args_match_editor_family() {
+        assert_eq!(
+            editor_goto_args("code", "a.rs", Some(3), None),
+            ["-g", "a.rs:3:1"]
+        );
+        assert_eq!(
+            editor_goto_args("/usr/bin/nvim", "a.rs", Some(3), Some(2)),
+            ["+3", "a.rs"]
+        );
+        assert_eq!(
+            editor_goto_args("rustrover64.exe", "a.rs", Some(3), Some(2)),
+            ["--line", "3", "--column", "2", "a.rs"]
+        );
+        assert_eq!(editor_goto_args("zed", "a.rs", None, None), ["a.rs"]);
+    }
+}
@@ -7,7 +7,7 @@ use crate::vscode::{
 };
 use crate::{
     find_project_root, log_done, log_note, log_step, parse_flag_value, parse_optional_flag_value,
-    resolve_local_path, workspace_root,
+    resolve_cli_project_dir, resolve_local_path, workspace_root,
 };
 use perro_compiler::{
     ProjectBuildOptions, ProjectBuildTarget, ScriptsBuildProfile, WebOutputDir, compile_dlc_bundle,
@@ -15,19 +15,15 @@ use perro_compiler::{
     compile_universal_macos_project_bundle, sync_scripts,
 };
 use perro_project::{ensure_source_overrides, load_project_toml_with_demo};
-use perro_scene::Parser;
+use perro_scene::{Parser, SceneParseError};
 use std::env;
 use std::fs;
 use std::io::Read;
 use std::io::{self, IsTerminal, Write};
 use std::net::{TcpListener, TcpStream};
-use std::panic::{self, AssertUnwindSafe};
 use std::path::{Path, PathBuf};
 use std::process::Command;
-use std::sync::{
-    LazyLock, Mutex,
-    atomic::{AtomicBool, Ordering},
-};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
@@ -36,6 +32,7 @@ enum CliTarget {
     Native,
     Web,
     Android,
+    Server,
 }
 
 pub(crate) fn clean_command(args: &[String], _cwd: &Path) -> Result<(), String> {
@@ -158,9 +155,7 @@ pub(crate) fn prompt_text(prompt: &str, default: Option<&str>) -> Result<String,
 }
 
 pub(crate) fn scripts_command(args: &[String], cwd: &Path) -> Result<(), String> {
-    let project_dir = parse_flag_value(args, "--path")
-        .map(|p| resolve_local_path(&p, cwd))
-        .unwrap_or_else(|| cwd.to_path_buf());
+    let project_dir = resolve_cli_project_dir(args, cwd)?;
     let project_dir = project_dir.canonicalize().unwrap_or(project_dir);
     update_workspace_vscode_linked_projects(&workspace_root(), &project_dir)?;
     update_project_vscode_linked_projects(&project_dir)?;
@@ -182,9 +177,7 @@ pub(crate) fn dlc_command(args: &[String], cwd: &Path) -> Result<(), String> {
         return Err("missing required flag `--name`".to_string());
     };
     let dlc_name = validate_dlc_name(&raw_dlc_name)?;
-    let project_dir = parse_flag_value(args, "--path")
-        .map(|p| resolve_local_path(&p, cwd))
-        .unwrap_or_else(|| cwd.to_path_buf());
+    let project_dir = resolve_cli_project_dir(args, cwd)?;
     let project_dir = project_dir.canonicalize().unwrap_or(project_dir);
     update_workspace_vscode_linked_projects(&workspace_root(), &project_dir)?;
     update_project_vscode_linked_projects(&project_dir)?;
@@ -207,6 +200,18 @@ pub(crate) fn dev_command(args: &[String], cwd: &Path) -> Result<(), String> {
     if headless && target != CliTarget::Native {
         return Err("`--headless` only supports `--target native`".to_string());
     }
+    if target != CliTarget::Native && args.iter().any(|a| a == "--record") {
+        return Err("`--record` only supports `--target native`".to_string());
+    }
+    if target != CliTarget::Native && args.iter().any(|a| a == "--scene") {
+        return Err("`--scene` only supports `--target native`".to_string());
+    }
+    if target == CliTarget::Server {
+        return Err(
+            "`perro dev` has no server target; use `--headless`, or `perro build --target server`"
+                .to_string(),
+        );
+    }
     if target == CliTarget::Web {
         return dev_web_command(args, cwd);
     }
@@ -224,9 +229,36 @@ pub(crate) fn dev_command(args: &[String], cwd: &Path) -> Result<(), String> {
     if headless && (timings || ui_profile) {
         return Err("`--timings` + `--ui-profile` do not support `--headless`".to_string());
     }
-    let project_dir = parse_flag_value(args, "--path")
-        .map(|p| resolve_local_path(&p, cwd))
-        .unwrap_or_else(|| cwd.to_path_buf());
+    let scene = parse_flag_value(args, "--scene");
+    if let Some(scene) = &scene
+        && !scene.starts_with("res://")
+    {
+        return Err(format!(
+            "`--scene` expects a `res://` scene path, got `{scene}`"
+        ));
+    }
+    let record = parse_flag_value(args, "--record").map(|p| resolve_local_path(&p, cwd));
+    let record_fps = parse_flag_value(args, "--record-fps");
+    let record_seconds = parse_flag_value(args, "--record-seconds");
+    if record.is_none() && (record_fps.is_some() || record_seconds.is_some()) {
+        return Err("`--record-fps` + `--record-seconds` need `--record <dir|video>`".to_string());
+    }
+    if headless && record.is_some() {
+        return Err("`--record` does not support `--headless`".to_string());
+    }
+    for (flag, raw) in [
+        ("--record-fps", &record_fps),
+        ("--record-seconds", &record_seconds),
+    ] {
+        if let Some(raw) = raw
+            && !raw
+                .parse::<f32>()
+                .is_ok_and(|value| value.is_finite() && value > 0.0)
+        {
+            return Err(format!("`{flag}` expects a positive number, got `{raw}`"));
+        }
+    }
+    let project_dir = resolve_cli_project_dir(args, cwd)?;
     let project_dir = project_dir.canonicalize().unwrap_or(project_dir);
     ensure_source_overrides(&project_dir)
         .map_err(|err| format!("failed to sync generated project crates: {err}"))?;
@@ -354,15 +386,33 @@ pub(crate) fn dev_command(args: &[String], cwd: &Path) -> Result<(), String> {
         .arg(project_dir.to_string_lossy().to_string())
         .current_dir(&project_dir)
         .env("PERRO_SCRIPTS_DYLIB_PATH", scripts_path);
+    // Lets tools running inside the dev runner (editor "play scene") call back into this CLI.
+    if let Ok(cli) = env::current_exe() {
+        run_cmd.env("PERRO_CLI", cli);
+    }
     if headless {
         run_cmd.arg("--headless");
     }
+    if let Some(scene) = &scene {
+        run_cmd.arg("--scene").arg(scene);
+        log_note(&format!("Booting {scene}"));
+    }
     if demo {
         run_cmd.env("PERRO_DEMO", "1");
     }
     if let Some(path) = &csv_profile_path {
         run_cmd.env("PERRO_PROFILE_CSV", path.to_string_lossy().to_string());
     }
+    if let Some(path) = &record {
+        run_cmd.env("PERRO_RECORD", path.to_string_lossy().to_string());
+        if let Some(fps) = &record_fps {
+            run_cmd.env("PERRO_RECORD_FPS", fps);
+        }
+        if let Some(seconds) = &record_seconds {
+            run_cmd.env("PERRO_RECORD_SECONDS", seconds);
+        }
+        log_note(&format!("Recording To {}", path.display()));
+    }
 
     install_dev_ctrl_c_handler();
     let run_status = run_cmd.status().map_err(|err| {
@@ -542,9 +592,7 @@ pub(crate) fn format_command(args: &[String], cwd: &Path) -> Result<(), String>
 }
 
 pub(crate) fn clippy_command(args: &[String], cwd: &Path) -> Result<(), String> {
-    let project_dir = parse_flag_value(args, "--path")
-        .map(|p| resolve_local_path(&p, cwd))
-        .unwrap_or_else(|| cwd.to_path_buf());
+    let project_dir = resolve_cli_project_dir(args, cwd)?;
     let project_dir = project_dir.canonicalize().unwrap_or(project_dir);
     let res_dir = resolve_project_res_root(&project_dir, "clippy")?;
     let mut script_files = Vec::new();
@@ -669,18 +717,16 @@ fn format_asset_file(path: &Path, dedup: bool) -> Result<(), String> {
         .unwrap_or_default()
         .to_ascii_lowercase();
     let formatted = match ext.as_str() {
-        "scn" => catch_unwind_silent(|| {
-            Parser::new(&src)
-                .parse_scene_doc()
-                .to_text_with_dedup(dedup)
-        })
-        .map_err(|err| {
-            format!(
-                "failed to parse scene file {}: {}",
-                path.display(),
-                scene_parse_error_detail(&src, err)
-            )
-        })?,
+        "scn" => Parser::new(&src)
+            .try_parse_scene_doc()
+            .map_err(|err| {
+                format!(
+                    "failed to parse scene file {}: {}",
+                    path.display(),
+                    scene_parse_error_detail(&src, &err)
+                )
+            })?
+            .to_text_with_dedup(dedup),
         "pmat" | "ppart" | "uistyle" => format_key_value_resource(&src),
         _ => return Ok(()),
     };
@@ -691,36 +737,11 @@ fn format_asset_file(path: &Path, dedup: bool) -> Result<(), String> {
     Ok(())
 }
 
-static PANIC_HOOK_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
-
-fn catch_unwind_silent<T>(f: impl FnOnce() -> T) -> Result<T, Box<dyn std::any::Any + Send>> {
-    if env::var_os("PERRO_FORMAT_DEBUG_PANIC").is_some() {
-        return panic::catch_unwind(AssertUnwindSafe(f));
-    }
-    let _guard = PANIC_HOOK_LOCK.lock().expect("panic hook lock poisoned");
-    let hook = panic::take_hook();
-    panic::set_hook(Box::new(|_| {}));
-    let result = panic::catch_unwind(AssertUnwindSafe(f));
-    panic::set_hook(hook);
-    result
-}
-
-fn scene_parse_error_detail(src: &str, err: Box<dyn std::any::Any + Send>) -> String {
-    let msg = panic_payload_to_string(&err);
+fn scene_parse_error_detail(src: &str, err: &SceneParseError) -> String {
     if let Some(hint) = scene_syntax_hint(src) {
-        return format!("{msg}; {hint}");
+        return format!("{err}; {hint}");
     }
-    msg
-}
-
-fn panic_payload_to_string(err: &(dyn std::any::Any + Send)) -> String {
-    if let Some(msg) = err.downcast_ref::<String>() {
-        return msg.clone();
-    }
-    if let Some(msg) = err.downcast_ref::<&'static str>() {
-        return (*msg).to_string();
-    }
-    "unknown parser error".to_string()
+    err.to_string()
 }
 
 fn scene_syntax_hint(src: &str) -> Option<String> {
@@ -826,8 +847,11 @@ pub(crate) fn project_command(args: &[String], cwd: &Path) -> Result<(), String>
     let target = parse_cli_target(args)?;
     let native_target = parse_flag_value(args, "--triple");
     let universal_macos = args.iter().any(|a| a == "--universal-macos");
-    if (native_target.is_some() || universal_macos) && target != CliTarget::Native {
-        return Err("`--triple` + `--universal-macos` only support `--target native`".to_string());
+    if universal_macos && target != CliTarget::Native {
+        return Err("`--universal-macos` only supports `--target native`".to_string());
+    }
+    if native_target.is_some() && !matches!(target, CliTarget::Native | CliTarget::Server) {
+        return Err("`--triple` only supports `--target native` or `--target server`".to_string());
     }
     if native_target.is_some() && universal_macos {
         return Err("use either `--triple` or `--universal-macos`, not both".to_string());
@@ -836,8 +860,10 @@ pub(crate) fn project_command(args: &[String], cwd: &Path) -> Result<(), String>
         return Err("`--universal-macos` requires a macOS host".to_string());
     }
     let headless = args.iter().any(|a| a == "--headless");
-    if headless && target != CliTarget::Native {
-        return Err("`--headless` only supports `--target native`".to_string());
+    if headless && !matches!(target, CliTarget::Native | CliTarget::Server) {
+        return Err(
+            "`--headless` only supports `--target native` or `--target server`".to_string(),
+        );
     }
     if target == CliTarget::Web {
         return build_web_command(args, cwd);
@@ -848,6 +874,7 @@ pub(crate) fn project_command(args: &[String], cwd: &Path) -> Result<(), String>
     let profile = args.iter().any(|a| a == "--profile");
     let console = args.iter().any(|a| a == "--console");
     let fresh = args.iter().any(|a| a == "--fresh");
+    let reproducible = args.iter().any(|a| a == "--reproducible");
     let demo = args.iter().any(|a| a == "--demo");
     if let Some(native_target) = native_target.as_deref() {
         validate_cli_native_target(native_target)?;
@@ -857,18 +884,23 @@ pub(crate) fn project_command(args: &[String], cwd: &Path) -> Result<(), String>
         ensure_rust_target_installed("aarch64-apple-darwin")?;
         ensure_rust_target_installed("x86_64-apple-darwin")?;
     }
-    let project_dir = parse_flag_value(args, "--path")
-        .map(|p| resolve_local_path(&p, cwd))
-        .unwrap_or_else(|| cwd.to_path_buf());
+    let project_dir = resolve_cli_project_dir(args, cwd)?;
     let project_dir = project_dir.canonicalize().unwrap_or(project_dir);
     update_workspace_vscode_linked_projects(&workspace_root(), &project_dir)?;
     update_project_vscode_linked_projects(&project_dir)?;
+    let build_target = if target == CliTarget::Server {
+        ProjectBuildTarget::Server
+    } else {
+        ProjectBuildTarget::Native
+    };
     log_step("Building Project Bundle");
     let options = ProjectBuildOptions::new(profile, console)
+        .with_target(build_target)
         .with_headless(headless)
         .with_native_target(native_target.map(leak_string))
         .with_demo(demo)
-        .with_fresh(fresh);
+        .with_fresh(fresh)
+        .with_reproducible(reproducible);
     let result = if universal_macos {
         compile_universal_macos_project_bundle(&project_dir, options)
     } else {
@@ -908,8 +940,9 @@ fn parse_cli_target(args: &[String]) -> Result<CliTarget, String> {
         "native" => Ok(CliTarget::Native),
         "web" => Ok(CliTarget::Web),
         "android" => Ok(CliTarget::Android),
+        "server" => Ok(CliTarget::Server),
         other => Err(format!(
-            "invalid `--target {other}`. use `native`, `web`, or `android`."
+            "invalid `--target {other}`. use `native`, `web`, `android`, or `server`."
         )),
     }
 }
@@ -919,9 +952,7 @@ fn build_web_command(args: &[String], cwd: &Path) -> Result<(), String> {
     if args.iter().any(|a| a == "--console") {
         return Err("`--console` is not supported with `--target web`".to_string());
     }
-    let project_dir = parse_flag_value(args, "--path")
-        .map(|p| resolve_local_path(&p, cwd))
-        .unwrap_or_else(|| cwd.to_path_buf());
+    let project_dir = resolve_cli_project_dir(args, cwd)?;
     let project_dir = project_dir.canonicalize().unwrap_or(project_dir);
     update_workspace_vscode_linked_projects(&workspace_root(), &project_dir)?;
     update_project_vscode_linked_projects(&project_dir)?;
@@ -932,7 +963,8 @@ fn build_web_command(args: &[String], cwd: &Path) -> Result<(), String> {
             .with_target(ProjectBuildTarget::Web)
             .with_demo(args.iter().any(|a| a == "--demo"))
             .with_web_output_dir(WebOutputDir::Build)
-            .with_fresh(args.iter().any(|a| a == "--fresh")),
+            .with_fresh(args.iter().any(|a| a == "--fresh"))
+            .with_reproducible(args.iter().any(|a| a == "--reproducible")),
     )
     .map(|_| {
         log_done("Web Project Bundle Built");
@@ -950,9 +982,7 @@ fn build_android_command(args: &[String], cwd: &Path) -> Result<(), String> {
         return Err("`--console` is not supported with `--target android`".to_string());
     }
     let profile = args.iter().any(|a| a == "--profile");
-    let project_dir = parse_flag_value(args, "--path")
-        .map(|p| resolve_local_path(&p, cwd))
-        .unwrap_or_else(|| cwd.to_path_buf());
+    let project_dir = resolve_cli_project_dir(args, cwd)?;
     let project_dir = project_dir.canonicalize().unwrap_or(project_dir);
     update_workspace_vscode_linked_projects(&workspace_root(), &project_dir)?;
     update_project_vscode_linked_projects(&project_dir)?;
@@ -966,6 +996,7 @@ fn build_android_command(args: &[String], cwd: &Path) -> Result<(), String> {
             .with_target(ProjectBuildTarget::Android)
             .with_demo(args.iter().any(|a| a == "--demo"))
             .with_fresh(args.iter().any(|a| a == "--fresh"))
+            .with_reproducible(args.iter().any(|a| a == "--reproducible"))
             .with_android_sdk_root(Some(leak_string(
                 android.sdk_root.to_string_lossy().to_string(),
             )))
@@ -1006,9 +1037,7 @@ fn dev_android_command(args: &[String], cwd: &Path) -> Result<(), String> {
 
     let profile = args.iter().any(|a| a == "--profile");
     let release = args.iter().any(|a| a == "--release");
-    let project_dir = parse_flag_value(args, "--path")
-        .map(|p| resolve_local_path(&p, cwd))
-        .unwrap_or_else(|| cwd.to_path_buf());
+    let project_dir = resolve_cli_project_dir(args, cwd)?;
     let project_dir = project_dir.canonicalize().unwrap_or(project_dir);
     update_workspace_vscode_linked_projects(&workspace_root(), &project_dir)?;
     update_project_vscode_linked_projects(&project_dir)?;
@@ -1247,9 +1276,7 @@ fn dev_web_command(args: &[String], cwd: &Path) -> Result<(), String> {
         })
         .transpose()?;
     let port = requested_port.unwrap_or(8000);
-    let project_dir = parse_flag_value(args, "--path")
-        .map(|p| resolve_local_path(&p, cwd))
-        .unwrap_or_else(|| cwd.to_path_buf());
+    let project_dir = resolve_cli_project_dir(args, cwd)?;
     let project_dir = project_dir.canonicalize().unwrap_or(project_dir);
     update_workspace_vscode_linked_projects(&workspace_root(), &project_dir)?;
     update_project_vscode_linked_projects(&project_dir)?;
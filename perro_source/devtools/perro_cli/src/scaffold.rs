@@ -8,7 +8,10 @@ use crate::{
     resolve_local_path, workspace_root,
 };
 use perro_compiler::{ScriptsBuildProfile, compile_scripts_with_profile};
-use perro_project::{create_new_project, default_script_empty_rs};
+use perro_project::{
+    ScriptTemplate, create_new_project, default_script_empty_rs, script_state_name,
+    script_template_rs,
+};
 use std::fs;
 use std::io::{self, IsTerminal};
 use std::path::{Path, PathBuf};
@@ -350,6 +353,13 @@ pub(crate) fn new_script_command(args: &[String], cwd: &Path) -> Result<(), Stri
         return Err("missing required flag `--name`".to_string());
     };
     let file_name = sanitize_script_file_name(&raw_name)?;
+    let template = parse_script_template(args)?;
+    let node_type = parse_flag_value(args, "--node").unwrap_or_else(|| "Node2D".to_string());
+    if node_type.is_empty() || !node_type.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(format!(
+            "invalid --node value `{node_type}`. Use a node type like Node3D."
+        ));
+    }
 
     let project_dir = if let Some(raw_project) = parse_flag_value(args, "--path") {
         resolve_local_path(&raw_project, cwd)
@@ -369,8 +379,12 @@ pub(crate) fn new_script_command(args: &[String], cwd: &Path) -> Result<(), Stri
         content_root
     };
 
-    let target_path = target_dir.join(file_name);
-    write_new_file(&target_path, &default_script_empty_rs())?;
+    let target_path = target_dir.join(&file_name);
+    let state_name = script_state_name(file_name.trim_end_matches(".rs"));
+    write_new_file(
+        &target_path,
+        &script_template_rs(template, &node_type, &state_name),
+    )?;
     println!(
         "created script at {}",
         normalize_powershell_path(&target_path)
@@ -392,6 +406,14 @@ pub(crate) fn new_script_command(args: &[String], cwd: &Path) -> Result<(), Stri
     Ok(())
 }
 
+fn parse_script_template(args: &[String]) -> Result<ScriptTemplate, String> {
+    let Some(raw) = parse_flag_value(args, "--template") else {
+        return Ok(ScriptTemplate::Empty);
+    };
+    ScriptTemplate::parse(&raw)
+        .ok_or_else(|| "invalid --template value. Use empty, update, or signal.".to_string())
+}
+
 fn parse_scene_template(args: &[String]) -> Result<SceneTemplate, String> {
     let Some(raw) = parse_flag_value(args, "--template") else {
         return Ok(SceneTemplate::TwoD);
@@ -479,7 +501,7 @@ pub(crate) fn new_scene_command(args: &[String], cwd: &Path) -> Result<(), Strin
         content_root
     };
 
-    let target_path = target_dir.join(file_name);
+    let target_path = target_dir.join(&file_name);
     let contents = match template {
         SceneTemplate::TwoD => default_scene_2d(),
         SceneTemplate::ThreeD => default_scene_3d(),
@@ -568,7 +590,7 @@ pub(crate) fn new_animation_command(args: &[String], cwd: &Path) -> Result<(), S
         content_root.join("animations")
     };
 
-    let target_path = target_dir.join(file_name);
+    let target_path = target_dir.join(&file_name);
     let animation_name = target_path
         .file_stem()
         .and_then(|s| s.to_str())
@@ -606,7 +628,7 @@ pub(crate) fn new_panimtree_command(args: &[String], cwd: &Path) -> Result<(), S
         content_root.join("animations")
     };
 
-    let target_path = target_dir.join(file_name);
+    let target_path = target_dir.join(&file_name);
     let tree_name = target_path
         .file_stem()
         .and_then(|s| s.to_str())
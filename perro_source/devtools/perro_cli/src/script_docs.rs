@@ -0,0 +1,227 @@
+use crate::{log_done, parse_flag_value, resolve_cli_project_dir, resolve_local_path};
+use perro_compiler::{ScriptDoc, ScriptDocMethod, collect_script_docs};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+/// `perro_cli doc`: API reference for every transpiled `res://` script, from
+/// the same field + method parse the transpiler uses, plus `///` comments.
+/// Written to `.output/docs/scripts.{md,html}` unless `--out` is given.
+pub(crate) fn doc_command(args: &[String], cwd: &Path) -> Result<(), String> {
+    let format = parse_flag_value(args, "--format").unwrap_or_else(|| "md".to_string());
+    let (render, ext): (fn(&[ScriptDoc]) -> String, &str) = match format.as_str() {
+        "md" | "markdown" => (render_markdown, "md"),
+        "html" => (render_html, "html"),
+        other => {
+            return Err(format!("`--format` expects `md` or `html`, got `{other}`"));
+        }
+    };
+    let project_dir = resolve_cli_project_dir(args, cwd)?;
+    let project_dir = project_dir.canonicalize().unwrap_or(project_dir);
+    let docs = collect_script_docs(&project_dir).map_err(|err| err.to_string())?;
+    if docs.is_empty() {
+        return Err(format!(
+            "no scripts with state, lifecycle!, or methods! under {}",
+            project_dir.join("res").display()
+        ));
+    }
+
+    let out = match parse_flag_value(args, "--out") {
+        Some(out) => resolve_local_path(&out, cwd),
+        None => project_dir
+            .join(".output")
+            .join("docs")
+            .join(format!("scripts.{ext}")),
+    };
+    if let Some(parent) = out.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("failed to create {}: {err}", parent.display()))?;
+    }
+    fs::write(&out, render(&docs))
+        .map_err(|err| format!("failed to write {}: {err}", out.display()))?;
+    log_done(&format!(
+        "Documented ({}) Scripts -> {}",
+        docs.len(),
+        out.display()
+    ));
+    Ok(())
+}
+
+/// `add_score(amount: i32) -> i32`, w/o `&self` + ctx.
+fn method_signature(method: &ScriptDocMethod) -> String {
+    let params = if method.takes_raw_params {
+        "params: &[Variant]".to_string()
+    } else {
+        method
+            .params
+            .iter()
+            .map(|(name, ty)| format!("{name}: {ty}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    match &method.return_ty {
+        Some(ret) => format!("{}({params}) -> {ret}", method.name),
+        None => format!("{}({params})", method.name),
+    }
+}
+
+fn render_markdown(docs: &[ScriptDoc]) -> String {
+    let mut out = String::from("# Script API\n");
+    for doc in docs {
+        let _ = write!(out, "\n## `{}`\n", doc.path);
+        if !doc.summary.is_empty() {
+            let _ = write!(out, "\n{}\n", doc.summary.join("\n"));
+        }
+        if !doc.vars.is_empty() {
+            out.push_str(
+                "\n### Vars\n\n| Name | Type | Default | Notes |\n| --- | --- | --- | --- |\n",
+            );
+            for var in &doc.vars {
+                let default = var
+                    .default
+                    .as_deref()
+                    .map(|default| format!("`{}`", table_cell(default)))
+                    .unwrap_or_default();
+                let _ = writeln!(
+                    out,
+                    "| `{}` | `{}` | {default} | {} |",
+                    var.name,
+                    table_cell(&var.ty),
+                    table_cell(&var.docs.join(" "))
+                );
+            }
+        }
+        if !doc.methods.is_empty() {
+            out.push_str("\n### Methods\n");
+            for method in &doc.methods {
+                let _ = write!(out, "\n#### `{}`\n", method_signature(method));
+                if !method.docs.is_empty() {
+                    let _ = write!(out, "\n{}\n", method.docs.join("\n"));
+                }
+            }
+        }
+        if !doc.signals.is_empty() {
+            out.push_str("\n### Signals\n\n");
+            for signal in &doc.signals {
+                let _ = writeln!(out, "- `{signal}`");
+            }
+        }
+    }
+    out
+}
+
+// `|` ends the cell even inside backticks.
+fn table_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+fn render_html(docs: &[ScriptDoc]) -> String {
+    let mut out = String::from(
+        "<!doctype html>\n<html><head><meta charset=\"utf-8\"><title>Script API</title>\n\
+<style>body{font-family:sans-serif;max-width:60rem;margin:2rem auto;padding:0 1rem}\
+table{border-collapse:collapse}td,th{border:1px solid #ccc;padding:.25rem .5rem;text-align:left}\
+code{background:#f3f3f3}</style></head><body>\n<h1>Script API</h1>\n",
+    );
+    for doc in docs {
+        let _ = writeln!(out, "<h2><code>{}</code></h2>", escape_html(&doc.path));
+        if !doc.summary.is_empty() {
+            let _ = writeln!(out, "<p>{}</p>", escape_html(&doc.summary.join(" ")));
+        }
+        if !doc.vars.is_empty() {
+            out.push_str("<h3>Vars</h3>\n<table><tr><th>Name</th><th>Type</th><th>Default</th><th>Notes</th></tr>\n");
+            for var in &doc.vars {
+                let _ = writeln!(
+                    out,
+                    "<tr><td><code>{}</code></td><td><code>{}</code></td><td><code>{}</code></td><td>{}</td></tr>",
+                    escape_html(&var.name),
+                    escape_html(&var.ty),
+                    escape_html(var.default.as_deref().unwrap_or_default()),
+                    escape_html(&var.docs.join(" "))
+                );
+            }
+            out.push_str("</table>\n");
+        }
+        if !doc.methods.is_empty() {
+            out.push_str("<h3>Methods</h3>\n");
+            for method in &doc.methods {
+                let _ = writeln!(
+                    out,
+                    "<h4><code>{}</code></h4>",
+                    escape_html(&method_signature(method))
+                );
+                if !method.docs.is_empty() {
+                    let _ = writeln!(out, "<p>{}</p>", escape_html(&method.docs.join(" ")));
+                }
+            }
+        }
+        if !doc.signals.is_empty() {
+            out.push_str("<h3>Signals</h3>\n<ul>\n");
+            for signal in &doc.signals {
+                let _ = writeln!(out, "<li><code>{}</code></li>", escape_html(signal));
+            }
+            out.push_str("</ul>\n");
+        }
+    }
+    out.push_str("</body></html>\n");
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perro_compiler::ScriptDocVar;
+
+    fn player_doc() -> ScriptDoc {
+        ScriptDoc {
+            path: "res://scripts/player.rs".to_string(),
+            summary: vec!["Player pawn.".to_string()],
+            vars: vec![ScriptDocVar {
+                name: "mask".to_string(),
+                ty: "Option<u32>".to_string(),
+                default: Some("None".to_string()),
+                docs: vec!["Bits a|b.".to_string()],
+            }],
+            methods: vec![
+                ScriptDocMethod {
+                    name: "add_score".to_string(),
+                    params: vec![("amount".to_string(), "i32".to_string())],
+                    return_ty: Some("i32".to_string()),
+                    docs: vec!["Adds points.".to_string()],
+                    ..ScriptDocMethod::default()
+                },
+                ScriptDocMethod {
+                    name: "reset".to_string(),
+                    takes_raw_params: true,
+                    ..ScriptDocMethod::default()
+                },
+            ],
+            signals: vec!["score_changed".to_string()],
+        }
+    }
+
+    #[test]
+    fn markdown_lists_vars_methods_and_signals() {
+        let md = render_markdown(&[player_doc()]);
+        assert!(md.contains("## `res://scripts/player.rs`\n\nPlayer pawn.\n"));
+        assert!(md.contains("| `mask` | `Option<u32>` | `None` | Bits a\\|b. |"));
+        assert!(md.contains("#### `add_score(amount: i32) -> i32`\n\nAdds points.\n"));
+        assert!(md.contains("#### `reset(params: &[Variant])`"));
+        assert!(md.contains("### Signals\n\n- `score_changed`\n"));
+    }
+
+    #[test]
+    fn html_escapes_types_and_docs() {
+        let html = render_html(&[player_doc()]);
+        assert!(html.contains("<code>Option&lt;u32&gt;</code>"));
+        assert!(html.contains("<h4><code>reset(params: &amp;[Variant])</code></h4>"));
+        assert!(html.contains("<li><code>score_changed</code></li>"));
+        assert!(html.ends_with("</body></html>\n"));
+    }
+}
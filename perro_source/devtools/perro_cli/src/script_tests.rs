@@ -1,3 +1,4 @@
+use crate::visual_tests::visual_test_command;
 use crate::{
     find_project_root, log_done, log_note, log_step, parse_flag_value, resolve_local_path,
 };
@@ -7,6 +8,9 @@ use std::path::Path;
 use std::process::Command;
 
 pub(crate) fn test_command(args: &[String], cwd: &Path) -> Result<(), String> {
+    if args.iter().any(|a| a == "--visual") {
+        return visual_test_command(args, cwd);
+    }
     let project_dir = parse_flag_value(args, "--path")
         .map(|p| resolve_local_path(&p, cwd))
         .or_else(|| find_project_root(cwd))
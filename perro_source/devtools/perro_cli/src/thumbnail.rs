@@ -0,0 +1,115 @@
+use crate::{log_done, log_note, log_step, parse_flag_value, resolve_cli_project_dir};
+use perro_project::{collect_project_scenes, scene_thumbnail_path, scene_thumbnail_stale};
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+/// `perro thumbnail`: render scene thumbnails into `.perro/cache/thumbnails`.
+///
+/// Each scene boots in the dev runner with `PERRO_THUMBNAIL` set; the runner
+/// frames the scene with an auto-fit camera, runs a short take and writes the
+/// last frame as a downscaled PNG. Fresh thumbnails are skipped unless
+/// `--force`.
+pub(crate) fn thumbnail_command(args: &[String], cwd: &Path) -> Result<(), String> {
+    let force = args.iter().any(|a| a == "--force");
+    let size = parse_flag_value(args, "--size");
+    if let Some(raw) = &size
+        && !raw.parse::<u32>().is_ok_and(|value| value > 0)
+    {
+        return Err(format!("`--size` expects a positive integer, got `{raw}`"));
+    }
+    let project_dir = resolve_cli_project_dir(args, cwd)?;
+    let project_dir = project_dir.canonicalize().unwrap_or(project_dir);
+
+    let scenes = match parse_flag_value(args, "--scene") {
+        Some(scene) => {
+            if !scene.starts_with("res://") {
+                return Err(format!(
+                    "`--scene` expects a `res://` scene path, got `{scene}`"
+                ));
+            }
+            vec![scene]
+        }
+        None => collect_project_scenes(&project_dir),
+    };
+    let pending = thumbnail_targets(&project_dir, scenes, force);
+    if pending.is_empty() {
+        log_done("Thumbnails Up To Date");
+        return Ok(());
+    }
+
+    let cli = env::current_exe().map_err(|err| format!("failed to locate perro_cli: {err}"))?;
+    let mut failed = Vec::new();
+    for scene in &pending {
+        let Some(out) = scene_thumbnail_path(&project_dir, scene) else {
+            continue;
+        };
+        log_step(&format!("Rendering Thumbnail {scene}"));
+        let mut cmd = Command::new(&cli);
+        cmd.arg("dev")
+            .arg("--path")
+            .arg(&project_dir)
+            .arg("--scene")
+            .arg(scene)
+            .env("PERRO_THUMBNAIL", &out);
+        if let Some(size) = &size {
+            cmd.env("PERRO_THUMBNAIL_SIZE", size);
+        }
+        let ok = cmd.status().is_ok_and(|status| status.success()) && out.exists();
+        if ok {
+            log_note(&format!("Wrote {}", out.display()));
+        } else {
+            failed.push(scene.as_str());
+        }
+    }
+    if !failed.is_empty() {
+        return Err(format!("thumbnail failed for {}", failed.join(", ")));
+    }
+    log_done(&format!("Rendered ({}) Thumbnails", pending.len()));
+    Ok(())
+}
+
+fn thumbnail_targets(project_dir: &Path, scenes: Vec<String>, force: bool) -> Vec<String> {
+    scenes
+        .into_iter()
+        .filter(|scene| scene_thumbnail_path(project_dir, scene).is_some())
+        .filter(|scene| force || scene_thumbnail_stale(project_dir, scene))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn thumbnail_targets_skip_fresh_and_invalid_scenes() {
+        let root = env::temp_dir().join(format!("perro_cli_thumbnail_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("res")).expect("res dir");
+        fs::write(root.join("res").join("main.scn"), "[main]\n").expect("scene");
+        let scenes = vec!["res://main.scn".to_string(), "res://../x.scn".to_string()];
+
+        assert_eq!(
+            thumbnail_targets(&root, scenes.clone(), false),
+            vec!["res://main.scn".to_string()]
+        );
+
+        let thumb = scene_thumbnail_path(&root, "res://main.scn").expect("thumb path");
+        fs::create_dir_all(thumb.parent().expect("thumb dir")).expect("cache dir");
+        fs::write(&thumb, b"png").expect("thumb");
+        fs::File::options()
+            .write(true)
+            .open(&thumb)
+            .and_then(|file| {
+                file.set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(60))
+            })
+            .expect("touch thumb");
+        assert!(thumbnail_targets(&root, scenes.clone(), false).is_empty());
+        assert_eq!(
+            thumbnail_targets(&root, scenes, true),
+            vec!["res://main.scn".to_string()]
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+}
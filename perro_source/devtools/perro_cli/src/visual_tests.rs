@@ -0,0 +1,292 @@
+use crate::{log_done, log_note, log_step, parse_flag_value, resolve_cli_project_dir};
+use image::{Rgba, RgbaImage};
+use perro_project::{collect_golden_scenes, scene_golden_path, visual_golden_dir};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Frames simulated per scene, incl. the captured one.
+const DEFAULT_FRAMES: u64 = 30;
+/// Share of px allowed to differ b4 a scene fails.
+const DEFAULT_THRESHOLD: f64 = 0.001;
+/// Per-px YIQ distance, as a share of the max, under which two px count as
+/// the same. Absorbs dithering and GPU rounding; real changes land far above.
+const PIXEL_TOLERANCE: f64 = 0.1;
+/// YIQ delta between black and white.
+const MAX_YIQ_DELTA: f64 = 35215.0;
+
+/// `perro test --visual`: golden image regression for the renderer.
+///
+/// Each scene boots in the dev runner with `PERRO_CAPTURE` set; the runner
+/// simulates `--frames` fixed 60 fps steps and writes the last frame as a
+/// full-size PNG under `.perro/visual`. That capture is compared against the
+/// checked-in golden in `tests/visual` w/ a perceptual per-px diff. Scenes
+/// default to every golden; `--update` rewrites goldens instead of comparing.
+pub(crate) fn visual_test_command(args: &[String], cwd: &Path) -> Result<(), String> {
+    let update = args.iter().any(|a| a == "--update");
+    let frames = match parse_flag_value(args, "--frames") {
+        Some(raw) => raw
+            .parse::<u64>()
+            .ok()
+            .filter(|frames| *frames > 0)
+            .ok_or_else(|| format!("`--frames` expects a positive integer, got `{raw}`"))?,
+        None => DEFAULT_FRAMES,
+    };
+    let threshold = match parse_flag_value(args, "--threshold") {
+        Some(raw) => raw
+            .parse::<f64>()
+            .ok()
+            .filter(|threshold| (0.0..=1.0).contains(threshold))
+            .ok_or_else(|| {
+                format!("`--threshold` expects a fraction between 0 and 1, got `{raw}`")
+            })?,
+        None => DEFAULT_THRESHOLD,
+    };
+    let project_dir = resolve_cli_project_dir(args, cwd)?;
+    let project_dir = project_dir.canonicalize().unwrap_or(project_dir);
+
+    let scenes = match parse_flag_value(args, "--scene") {
+        Some(scene) => {
+            if !scene.starts_with("res://") {
+                return Err(format!(
+                    "`--scene` expects a `res://` scene path, got `{scene}`"
+                ));
+            }
+            vec![scene]
+        }
+        None => collect_golden_scenes(&project_dir),
+    };
+    if scenes.is_empty() {
+        return Err(format!(
+            "no visual goldens in {}; add one w/ `perro test --visual --scene <res://scene> --update`",
+            visual_golden_dir(&project_dir).display()
+        ));
+    }
+
+    let cli = env::current_exe().map_err(|err| format!("failed to locate perro_cli: {err}"))?;
+    let mut failed = Vec::new();
+    for scene in &scenes {
+        let Some((golden, actual)) = capture_paths(&project_dir, scene) else {
+            failed.push(format!("{scene}: not a valid res:// scene path"));
+            continue;
+        };
+        log_step(&format!("Rendering {scene}"));
+        let _ = fs::remove_file(&actual);
+        let rendered = Command::new(&cli)
+            .arg("dev")
+            .arg("--path")
+            .arg(&project_dir)
+            .arg("--scene")
+            .arg(scene)
+            .env("PERRO_CAPTURE", &actual)
+            .env("PERRO_CAPTURE_FRAMES", frames.to_string())
+            .status()
+            .is_ok_and(|status| status.success())
+            && actual.exists();
+        if !rendered {
+            failed.push(format!("{scene}: render failed"));
+            continue;
+        }
+        if update {
+            let written = golden
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|()| fs::copy(&actual, &golden));
+            match written {
+                Ok(_) => log_note(&format!("Updated {}", golden.display())),
+                Err(err) => failed.push(format!("{scene}: failed to write golden: {err}")),
+            }
+            continue;
+        }
+        if !golden.exists() {
+            failed.push(format!(
+                "{scene}: no golden at {}; rerun w/ `--update`",
+                golden.display()
+            ));
+            continue;
+        }
+        match compare_capture(&golden, &actual) {
+            Ok(diff) if diff.fraction() <= threshold => {
+                log_note(&format!(
+                    "{scene} matches ({:.3}% px differ)",
+                    diff.fraction() * 100.0
+                ));
+            }
+            Ok(diff) => {
+                let diff_path = actual.with_extension("diff.png");
+                let saved = diff
+                    .image
+                    .save(&diff_path)
+                    .map(|()| format!("; diff at {}", diff_path.display()))
+                    .unwrap_or_default();
+                failed.push(format!(
+                    "{scene}: {:.3}% px differ (limit {:.3}%){saved}",
+                    diff.fraction() * 100.0,
+                    threshold * 100.0
+                ));
+            }
+            Err(err) => failed.push(format!("{scene}: {err}")),
+        }
+    }
+    if !failed.is_empty() {
+        return Err(format!("visual tests failed:\n  {}", failed.join("\n  ")));
+    }
+    if update {
+        log_done(&format!("Updated ({}) Goldens", scenes.len()));
+    } else {
+        log_done(&format!("({}) Visual Tests Passed", scenes.len()));
+    }
+    Ok(())
+}
+
+/// Golden path + capture path; captures mirror the golden tree under
+/// `.perro/visual`.
+fn capture_paths(project_dir: &Path, scene: &str) -> Option<(PathBuf, PathBuf)> {
+    let golden = scene_golden_path(project_dir, scene)?;
+    let rel = golden.strip_prefix(visual_golden_dir(project_dir)).ok()?;
+    let actual = project_dir.join(".perro").join("visual").join(rel);
+    Some((golden, actual))
+}
+
+fn compare_capture(golden: &Path, actual: &Path) -> Result<ImageDiff, String> {
+    let load = |path: &Path| {
+        image::open(path)
+            .map(|image| image.to_rgba8())
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))
+    };
+    diff_images(&load(golden)?, &load(actual)?)
+}
+
+struct ImageDiff {
+    differing: u64,
+    total: u64,
+    /// Red where px differ, faded grayscale golden elsewhere.
+    image: RgbaImage,
+}
+
+impl ImageDiff {
+    fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.differing as f64 / self.total as f64
+        }
+    }
+}
+
+fn diff_images(golden: &RgbaImage, actual: &RgbaImage) -> Result<ImageDiff, String> {
+    if golden.dimensions() != actual.dimensions() {
+        return Err(format!(
+            "size changed: golden {}x{}, capture {}x{}",
+            golden.width(),
+            golden.height(),
+            actual.width(),
+            actual.height()
+        ));
+    }
+    let limit = MAX_YIQ_DELTA * PIXEL_TOLERANCE * PIXEL_TOLERANCE;
+    let mut differing = 0u64;
+    let image = RgbaImage::from_fn(golden.width(), golden.height(), |x, y| {
+        let expected = golden.get_pixel(x, y).0;
+        if yiq_delta(expected, actual.get_pixel(x, y).0) > limit {
+            differing += 1;
+            return Rgba([255, 0, 0, 255]);
+        }
+        let [luma, ..] = yiq(expected);
+        let faded = (255.0 - (255.0 - luma) * 0.1).round() as u8;
+        Rgba([faded, faded, faded, 255])
+    });
+    Ok(ImageDiff {
+        differing,
+        total: u64::from(golden.width()) * u64::from(golden.height()),
+        image,
+    })
+}
+
+/// Weighted YIQ distance (brightness counts most), the same metric
+/// pixelmatch uses; tracks what people notice better than raw RGB.
+fn yiq_delta(a: [u8; 4], b: [u8; 4]) -> f64 {
+    let [ay, ai, aq] = yiq(a);
+    let [by, bi, bq] = yiq(b);
+    let (dy, di, dq) = (ay - by, ai - bi, aq - bq);
+    0.5053 * dy * dy + 0.299 * di * di + 0.1957 * dq * dq
+}
+
+// Blended over white first so transparent px compare by what shows.
+fn yiq([r, g, b, a]: [u8; 4]) -> [f64; 3] {
+    let alpha = f64::from(a) / 255.0;
+    let blend = |c: u8| 255.0 + (f64::from(c) - 255.0) * alpha;
+    let (r, g, b) = (blend(r), blend(g), blend(b));
+    [
+        0.298_895_31 * r + 0.586_622_47 * g + 0.114_482_23 * b,
+        0.595_977_99 * r - 0.274_176_10 * g - 0.321_801_89 * b,
+        0.211_470_17 * r - 0.522_617_11 * g + 0.311_146_94 * b,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solid(width: u32, height: u32, rgba: [u8; 4]) -> RgbaImage {
+        RgbaImage::from_pixel(width, height, Rgba(rgba))
+    }
+
+    #[test]
+    fn diff_ignores_small_color_noise_and_counts_real_changes() {
+        let golden = solid(4, 4, [120, 80, 40, 255]);
+        let mut actual = solid(4, 4, [122, 79, 41, 255]);
+        let diff = diff_images(&golden, &actual).expect("same size");
+        assert_eq!(diff.differing, 0);
+        assert_eq!(diff.fraction(), 0.0);
+
+        actual.put_pixel(1, 2, Rgba([255, 255, 255, 255]));
+        actual.put_pixel(3, 3, Rgba([0, 0, 0, 255]));
+        let diff = diff_images(&golden, &actual).expect("same size");
+        assert_eq!(diff.differing, 2);
+        assert_eq!(diff.fraction(), 2.0 / 16.0);
+        assert_eq!(diff.image.get_pixel(1, 2).0, [255, 0, 0, 255]);
+        assert_ne!(diff.image.get_pixel(0, 0).0, [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn diff_compares_transparent_pixels_by_what_shows() {
+        let golden = solid(1, 1, [0, 0, 0, 0]);
+        let actual = solid(1, 1, [255, 255, 255, 255]);
+        assert_eq!(diff_images(&golden, &actual).expect("size").differing, 0);
+    }
+
+    #[test]
+    fn diff_rejects_size_changes() {
+        let err = diff_images(&solid(2, 2, [0; 4]), &solid(3, 2, [0; 4]))
+            .err()
+            .expect("size mismatch");
+        assert_eq!(err, "size changed: golden 2x2, capture 3x2");
+    }
+
+    #[test]
+    fn captures_mirror_golden_tree_under_perro_dir() {
+        let root = Path::new("game");
+        let (golden, actual) = capture_paths(root, "res://levels/a.scn").expect("paths");
+        assert_eq!(
+            golden,
+            visual_golden_dir(root).join("levels").join("a.scn.png")
+        );
+        assert_eq!(
+            actual,
+            root.join(".perro")
+                .join("visual")
+                .join("levels")
+                .join("a.scn.png")
+        );
+        assert_eq!(
+            actual.with_extension("diff.png"),
+            root.join(".perro")
+                .join("visual")
+                .join("levels")
+                .join("a.scn.diff.png")
+        );
+        assert!(capture_paths(root, "levels/a.scn").is_none());
+    }
+}
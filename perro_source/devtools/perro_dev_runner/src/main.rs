@@ -38,10 +38,15 @@ fn main() {
 
     #[cfg(not(feature = "headless"))]
     match run_result {
-        Ok(result) => match result.kind {
-            AppExitKind::WindowClose => println!("perro exit: window close"),
-            AppExitKind::EventLoopExit => println!("perro exit: event loop exit"),
-        },
+        Ok(result) => {
+            match result.kind {
+                AppExitKind::WindowClose => println!("perro exit: window close"),
+                AppExitKind::EventLoopExit => println!("perro exit: event loop exit"),
+            }
+            if result.code != 0 {
+                process::exit(result.code);
+            }
+        }
         Err(err) => {
             eprintln!("perro exit error at `{}`: {err}", root.to_string_lossy());
             process::exit(1);
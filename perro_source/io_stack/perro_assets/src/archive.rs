@@ -4,14 +4,52 @@ use std::ops::{Deref, Range};
 use std::path::Path;
 use std::sync::Arc;
 
+use rayon::prelude::*;
+
 use super::common::{
-    FLAG_COMPRESSED, PERRO_ASSETS_COMPRESSED_MAGIC, PERRO_ASSETS_MAGIC, PerroAssetsEntryMeta,
+    CHUNK_TABLE_HEADER_LEN, FLAG_CHUNKED, FLAG_COMPRESSED, FLAG_ENCRYPTED,
+    PERRO_ASSETS_COMPRESSED_MAGIC, PERRO_ASSETS_MAGIC, PerroAssetsEntryMeta, entry_content_hash,
     read_header, read_index_entry,
 };
-use super::compression::decompress_zlib_limited;
+use super::compression::{EntryCodec, decompress_zlib_limited};
+use super::crypto::{ArchiveCipher, ArchiveKey};
+use super::patch::read_patch;
+use super::walkdir::matches_path_pattern;
 
 pub type PerroAssetsEntry = PerroAssetsEntryMeta;
 
+/// One file from [`PerroAssetsArchive::entry_info`], [`PerroAssetsArchive::list_dir`],
+/// or [`PerroAssetsArchive::glob`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PerroAssetsEntryInfo {
+    pub path: String,
+    /// Bytes `read_file` returns.
+    pub size: u64,
+    /// Bytes stored in the archive, after codec + seal.
+    pub stored_size: u64,
+    pub compressed: bool,
+    pub encrypted: bool,
+    /// Source mtime in nanos since the Unix epoch; 0 when the packer did not
+    /// record it.
+    pub mtime: u64,
+    /// Key/values the packer was given for this path, sorted by key.
+    pub metadata: Vec<(String, String)>,
+}
+
+impl PerroAssetsEntryInfo {
+    fn new(path: &str, entry: &PerroAssetsEntry) -> Self {
+        Self {
+            path: path.to_string(),
+            size: entry.original_size,
+            stored_size: entry.size,
+            compressed: entry.flags & FLAG_COMPRESSED != 0,
+            encrypted: entry.flags & FLAG_ENCRYPTED != 0,
+            mtime: entry.mtime,
+            metadata: entry.metadata.clone(),
+        }
+    }
+}
+
 const MAX_DECOMPRESSED_ARCHIVE_BYTES: usize = 1024 * 1024 * 1024;
 
 #[derive(Clone)]
@@ -34,6 +72,15 @@ impl Deref for ArchiveBytes {
 pub struct PerroAssetsArchive {
     data: ArchiveBytes,
     index: HashMap<String, PerroAssetsEntry>,
+    patch: Option<PatchOverlay>,
+    cipher: Option<ArchiveCipher>,
+}
+
+/// Entries a mounted patch changed or added, read from the patch bytes.
+/// Their paths are gone from the base index, as are removed ones.
+struct PatchOverlay {
+    data: ArchiveBytes,
+    index: HashMap<String, PerroAssetsEntry>,
 }
 
 impl PerroAssetsArchive {
@@ -58,7 +105,18 @@ impl PerroAssetsArchive {
     fn open_from_data(data: ArchiveBytes) -> io::Result<Self> {
         let mut cursor = Cursor::new(&*data);
         let index = Self::parse_index(&mut cursor)?;
-        Ok(Self { data, index })
+        Ok(Self {
+            data,
+            index,
+            patch: None,
+            cipher: None,
+        })
+    }
+
+    /// Key for `FLAG_ENCRYPTED` entries; w/o one they fail to read.
+    pub fn with_key(mut self, key: &ArchiveKey) -> Self {
+        self.cipher = Some(ArchiveCipher::new(key));
+        self
     }
 
     fn parse_index(cursor: &mut Cursor<&[u8]>) -> io::Result<HashMap<String, PerroAssetsEntry>> {
@@ -74,83 +132,264 @@ impl PerroAssetsArchive {
         Ok(index)
     }
 
-    /// Read a file fully into memory
-    pub fn read_file(&self, path: &str) -> io::Result<Vec<u8>> {
-        let entry = self
-            .index
-            .get(path)
-            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "File not found"))?;
-
-        let range = checked_entry_range(self.data.len(), entry)?;
-        let mut data_buf = self.data[range].to_vec();
-
-        // Decompress if needed
-        if entry.flags & FLAG_COMPRESSED != 0 {
-            let expected_size = checked_decompressed_size(entry.original_size)?;
-            let decompressed = decompress_zlib_limited(&data_buf, expected_size)?;
-
-            if decompressed.len() as u64 != entry.original_size {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!(
-                        "Size mismatch: expected {}, got {}",
-                        entry.original_size,
-                        decompressed.len()
-                    ),
-                ));
-            }
-            data_buf = decompressed;
+    /// Overlay a delta patch (see [`crate::patch`]) on this archive. Reads
+    /// of changed or added paths come from the patch bytes, removed paths
+    /// are gone, and everything else still reads from the base data w/o a
+    /// copy. Fails when the patch was built against a different base, or
+    /// when any patch entry does not match its index hash.
+    pub fn with_patch(&self, patch: Vec<u8>) -> io::Result<Self> {
+        if self.patch.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "archive already has a patch mounted",
+            ));
+        }
+        let parsed = read_patch(&patch)?;
+        let fingerprint = self.fingerprint();
+        if parsed.base_fingerprint != fingerprint {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "patch targets base {:016x}, archive is {fingerprint:016x}",
+                    parsed.base_fingerprint
+                ),
+            ));
+        }
+        for (path, entry) in &parsed.entries {
+            verify_entry(&patch, path, entry)?;
         }
 
-        Ok(data_buf)
+        let mut index = self.index.clone();
+        for path in parsed.entries.keys().chain(&parsed.removed) {
+            index.remove(path);
+        }
+        Ok(Self {
+            data: self.data.clone(),
+            index,
+            patch: Some(PatchOverlay {
+                data: ArchiveBytes::Owned(Arc::from(patch.into_boxed_slice())),
+                index: parsed.entries,
+            }),
+            cipher: self.cipher.clone(),
+        })
     }
 
-    /// Get a direct slice (only works for uncompressed files)
-    pub fn get_file_slice(&self, path: &str) -> io::Result<&[u8]> {
-        let entry = self
+    /// Hash of every entry's path + stored-bytes hash + flags; names the
+    /// exact archive a patch applies to. Patched archives hash their merged
+    /// entries.
+    pub fn fingerprint(&self) -> u64 {
+        let mut buf = Vec::new();
+        for (path, _, entry) in self.entries() {
+            buf.extend_from_slice(&(path.len() as u64).to_le_bytes());
+            buf.extend_from_slice(path.as_bytes());
+            buf.extend_from_slice(&entry.hash.to_le_bytes());
+            buf.extend_from_slice(&entry.original_size.to_le_bytes());
+            buf.extend_from_slice(&entry.flags.to_le_bytes());
+        }
+        entry_content_hash(&buf)
+    }
+
+    /// Every entry w/ the data its offsets point into, sorted by path.
+    pub(crate) fn entries(&self) -> Vec<(&str, &[u8], &PerroAssetsEntry)> {
+        let base = self
             .index
+            .iter()
+            .map(|(path, entry)| (path.as_str(), &*self.data, entry));
+        let mut entries = match &self.patch {
+            Some(patch) => base
+                .chain(
+                    patch
+                        .index
+                        .iter()
+                        .map(|(path, entry)| (path.as_str(), &*patch.data, entry)),
+                )
+                .collect::<Vec<_>>(),
+            None => base.collect::<Vec<_>>(),
+        };
+        entries.sort_unstable_by_key(|(path, _, _)| *path);
+        entries
+    }
+
+    /// Entry for `path` + the data it lives in (the patch's, once patched).
+    fn entry(&self, path: &str) -> io::Result<(&ArchiveBytes, &PerroAssetsEntry)> {
+        if let Some(patch) = &self.patch
+            && let Some(entry) = patch.index.get(path)
+        {
+            return Ok((&patch.data, entry));
+        }
+        self.index
             .get(path)
-            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "File not found"))?;
+            .map(|entry| (&self.data, entry))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "File not found"))
+    }
 
-        if entry.flags & FLAG_COMPRESSED != 0 {
-            return Err(io::Error::other(
-                "Cannot get slice of compressed file (use read_file)",
-            ));
+    /// Stored bytes of one entry, exactly as the index hash covers them.
+    pub(crate) fn stored_entry(&self, path: &str) -> io::Result<(&PerroAssetsEntry, &[u8])> {
+        let (data, entry) = self.entry(path)?;
+        let range = checked_entry_range(data.len(), entry)?;
+        Ok((entry, &data[range]))
+    }
+
+    /// Read a file fully into memory
+    pub fn read_file(&self, path: &str) -> io::Result<Vec<u8>> {
+        let (data, layout) = self.layout(path)?;
+        match layout {
+            EntryLayout::Stored(range) => Ok(data[range].to_vec()),
+            EntryLayout::Compressed(entry) => {
+                checked_decompressed_size(entry.len)?;
+                if entry.chunks.len() == 1 {
+                    return entry.inflate(data, 0);
+                }
+                let mut out = Vec::new();
+                for index in 0..entry.chunks.len() {
+                    out.extend_from_slice(&entry.inflate(data, index)?);
+                }
+                Ok(out)
+            }
         }
+    }
 
-        let range = checked_entry_range(self.data.len(), entry)?;
-        Ok(&self.data[range])
+    /// Read up to `len` bytes from `offset`, stopping at end of file.
+    /// Chunked entries only inflate the chunks the range touches.
+    pub fn read_range(&self, path: &str, offset: u64, len: usize) -> io::Result<Vec<u8>> {
+        let mut file = self.stream_file(path)?;
+        if offset > file.size() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "read offset out of bounds",
+            ));
+        }
+        file.seek(SeekFrom::Start(offset))?;
+        let available = usize::try_from(file.size() - offset).unwrap_or(usize::MAX);
+        let mut out = Vec::with_capacity(len.min(available));
+        file.take(len as u64).read_to_end(&mut out)?;
+        Ok(out)
     }
 
-    /// Stream a file (only works for uncompressed files)
-    pub fn stream_file(&self, path: &str) -> io::Result<PerroAssetsFile> {
-        let entry = self
-            .index
-            .get(path)
-            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "File not found"))?;
+    /// Get a direct slice (only works for uncompressed, unencrypted files)
+    pub fn get_file_slice(&self, path: &str) -> io::Result<&[u8]> {
+        let (data, entry) = self.entry(path)?;
 
-        if entry.flags & FLAG_COMPRESSED != 0 {
+        if entry.flags & (FLAG_COMPRESSED | FLAG_ENCRYPTED) != 0 {
             return Err(io::Error::other(
-                "Streaming compressed files not supported (use read_file)",
+                "Cannot get slice of compressed or encrypted file (use read_file)",
             ));
         }
 
-        checked_entry_range(self.data.len(), entry)?;
+        let range = checked_entry_range(data.len(), entry)?;
+        Ok(&data[range])
+    }
 
+    /// Stream a file. Compressed files inflate one chunk at a time as reads
+    /// reach it; files packed before chunking are one chunk.
+    pub fn stream_file(&self, path: &str) -> io::Result<PerroAssetsFile> {
+        let (data, layout) = self.layout(path)?;
         Ok(PerroAssetsFile {
-            data: self.data.clone(),
-            entry: entry.clone(),
+            data: data.clone(),
+            layout,
             pos: 0,
+            chunk: None,
         })
     }
 
+    fn layout(&self, path: &str) -> io::Result<(&ArchiveBytes, EntryLayout)> {
+        let (data, entry) = self.entry(path)?;
+        let layout = entry_layout(data, path, entry, self.cipher.as_ref())?;
+        Ok((data, layout))
+    }
+
+    /// Re-hash every entry's stored bytes against its index hash. Catches a
+    /// corrupt download or patched archive at open time instead of as a
+    /// decode failure deep in a loader; needs no key, sealed bytes are hashed
+    /// as stored. Reports the first bad entry by path order.
+    pub fn verify(&self) -> io::Result<()> {
+        match self
+            .entries()
+            .par_iter()
+            .find_map_first(|(path, data, entry)| verify_entry(data, path, entry).err())
+        {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// [`PerroAssetsArchive::verify`] for one entry.
+    pub fn verify_file(&self, path: &str) -> io::Result<()> {
+        let (data, entry) = self.entry(path)?;
+        verify_entry(data, path, entry)
+    }
+
+    /// Whether `path` is an entry, w/o reading it.
+    pub fn contains(&self, path: &str) -> bool {
+        self.entry(path).is_ok()
+    }
+
+    /// Index info for `path`, w/o reading it.
+    pub fn entry_info(&self, path: &str) -> Option<PerroAssetsEntryInfo> {
+        let (_, entry) = self.entry(path).ok()?;
+        Some(PerroAssetsEntryInfo::new(path, entry))
+    }
+
     /// List all files in the archive
     pub fn list_files(&self) -> Vec<String> {
-        self.index.keys().cloned().collect()
+        let patched = self.patch.iter().flat_map(|patch| patch.index.keys());
+        self.index.keys().chain(patched).cloned().collect()
+    }
+
+    /// Files directly inside `dir` (`"res/levels"` or `"res/levels/"`), by
+    /// path. Subdirectories are not descended; use [`Self::glob`] w/ `**`.
+    pub fn list_dir(&self, dir: &str) -> Vec<PerroAssetsEntryInfo> {
+        let dir = dir.trim_end_matches('/');
+        self.entries()
+            .into_iter()
+            .filter(|(path, _, _)| {
+                let name = if dir.is_empty() {
+                    Some(*path)
+                } else {
+                    path.strip_prefix(dir)
+                        .and_then(|rest| rest.strip_prefix('/'))
+                };
+                name.is_some_and(|name| !name.contains('/'))
+            })
+            .map(|(path, _, entry)| PerroAssetsEntryInfo::new(path, entry))
+            .collect()
+    }
+
+    /// Files matching `pattern`, by path. `*` matches within one path
+    /// segment and `**` any number of segments: `"res/**/*.scn"`.
+    pub fn glob(&self, pattern: &str) -> Vec<PerroAssetsEntryInfo> {
+        self.entries()
+            .into_iter()
+            .filter(|(path, _, _)| matches_path_pattern(pattern, path))
+            .map(|(path, _, entry)| PerroAssetsEntryInfo::new(path, entry))
+            .collect()
+    }
+
+    /// Approx heap bytes held by this archive: owned (file-loaded) data plus
+    /// the path index, and the same for a mounted patch. Embedded
+    /// `include_bytes!` data lives in the binary image and is not counted.
+    pub fn resident_bytes(&self) -> usize {
+        let patch = self
+            .patch
+            .as_ref()
+            .map_or(0, |patch| resident_bytes_of(&patch.data, &patch.index));
+        resident_bytes_of(&self.data, &self.index) + patch
     }
 }
 
-fn decode_archive_container(data: Vec<u8>) -> io::Result<Vec<u8>> {
+fn resident_bytes_of(data: &ArchiveBytes, index: &HashMap<String, PerroAssetsEntry>) -> usize {
+    let data = match data {
+        ArchiveBytes::Static(_) => 0,
+        ArchiveBytes::Owned(data) => data.len(),
+    };
+    let index = index
+        .keys()
+        .map(|path| path.capacity() + std::mem::size_of::<(String, PerroAssetsEntry)>())
+        .sum::<usize>();
+    data + index
+}
+
+pub(crate) fn decode_archive_container(data: Vec<u8>) -> io::Result<Vec<u8>> {
     if data.len() < 4 || data[..4] == PERRO_ASSETS_MAGIC {
         return Ok(data);
     }
@@ -203,6 +442,22 @@ fn checked_decompressed_size(size: u64) -> io::Result<usize> {
     Ok(size)
 }
 
+pub(crate) fn verify_entry(data: &[u8], path: &str, entry: &PerroAssetsEntry) -> io::Result<()> {
+    let range = checked_entry_range(data.len(), entry)
+        .map_err(|err| io::Error::new(err.kind(), format!("{path}: {err}")))?;
+    let actual = entry_content_hash(&data[range]);
+    if actual != entry.hash {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "{path}: content hash mismatch (expected {:016x}, got {actual:016x})",
+                entry.hash
+            ),
+        ));
+    }
+    Ok(())
+}
+
 fn checked_entry_range(data_len: usize, entry: &PerroAssetsEntry) -> io::Result<Range<usize>> {
     let start = usize::try_from(entry.offset)
         .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "archive offset too large"))?;
@@ -220,24 +475,187 @@ fn checked_entry_range(data_len: usize, entry: &PerroAssetsEntry) -> io::Result<
     Ok(start..end)
 }
 
-/// Streaming file handle (for uncompressed files only)
+/// Where an entry's file bytes live in the archive data.
+#[derive(Clone, Debug)]
+enum EntryLayout {
+    Stored(Range<usize>),
+    Compressed(CompressedEntry),
+}
+
+impl EntryLayout {
+    fn len(&self) -> u64 {
+        match self {
+            Self::Stored(range) => range.len() as u64,
+            Self::Compressed(entry) => entry.len,
+        }
+    }
+}
+
+/// Independent streams of one codec; chunk `i` inflates to file bytes
+/// `i * chunk_size..`. A non-chunked compressed entry is a single chunk.
+/// Encrypted entries use this path even when stored (codec `Store`).
+#[derive(Clone, Debug)]
+struct CompressedEntry {
+    codec: EntryCodec,
+    /// boxed: few entries are encrypted + the cipher is large
+    sealed: Option<Box<SealedEntry>>,
+    len: u64,
+    chunk_size: u64,
+    chunks: Vec<Range<usize>>,
+}
+
+/// Each chunk is opened w/ the entry path + chunk index as associated data.
+#[derive(Clone, Debug)]
+struct SealedEntry {
+    cipher: ArchiveCipher,
+    path: String,
+}
+
+impl CompressedEntry {
+    fn inflate(&self, data: &[u8], index: usize) -> io::Result<Vec<u8>> {
+        let start = index as u64 * self.chunk_size;
+        let expected = checked_decompressed_size(self.chunk_size.min(self.len - start))?;
+        let stored = &data[self.chunks[index].clone()];
+        let opened;
+        let stored = match &self.sealed {
+            Some(sealed) => {
+                opened = sealed.cipher.open(&sealed.path, index as u32, stored)?;
+                opened.as_slice()
+            }
+            None => stored,
+        };
+        let out = self.codec.decompress_limited(stored, expected)?;
+        if out.len() != expected {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Size mismatch: expected {}, got {}", expected, out.len()),
+            ));
+        }
+        Ok(out)
+    }
+}
+
+fn entry_layout(
+    data: &[u8],
+    path: &str,
+    entry: &PerroAssetsEntry,
+    cipher: Option<&ArchiveCipher>,
+) -> io::Result<EntryLayout> {
+    let range = checked_entry_range(data.len(), entry)?;
+    if entry.flags & (FLAG_COMPRESSED | FLAG_ENCRYPTED) == 0 {
+        return Ok(EntryLayout::Stored(range));
+    }
+    let sealed = if entry.flags & FLAG_ENCRYPTED != 0 {
+        let cipher = cipher.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("{path} is encrypted and no archive key is registered"),
+            )
+        })?;
+        Some(Box::new(SealedEntry {
+            cipher: cipher.clone(),
+            path: path.to_string(),
+        }))
+    } else {
+        None
+    };
+    if entry.flags & FLAG_CHUNKED == 0 {
+        return Ok(EntryLayout::Compressed(CompressedEntry {
+            codec: EntryCodec::from_flags(entry.flags),
+            sealed,
+            len: entry.original_size,
+            chunk_size: entry.original_size.max(1),
+            chunks: vec![range],
+        }));
+    }
+
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+    let stored = &data[range.clone()];
+    let header = stored
+        .get(..CHUNK_TABLE_HEADER_LEN)
+        .ok_or_else(|| invalid("chunk table truncated"))?;
+    let chunk_size = u64::from(u32::from_le_bytes([
+        header[0], header[1], header[2], header[3],
+    ]));
+    let count = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+    if chunk_size == 0 || entry.original_size.div_ceil(chunk_size) != count as u64 {
+        return Err(invalid("chunk table does not match entry size"));
+    }
+    // Bounds-check the table b4 allocating for it; `count` is untrusted.
+    let table_len = count
+        .checked_mul(4)
+        .and_then(|lens| lens.checked_add(CHUNK_TABLE_HEADER_LEN))
+        .filter(|len| *len <= stored.len())
+        .ok_or_else(|| invalid("chunk table truncated"))?;
+    let mut chunks = Vec::with_capacity(count);
+    let mut next = range.start + table_len;
+    for len in stored[CHUNK_TABLE_HEADER_LEN..table_len].chunks_exact(4) {
+        let len = u32::from_le_bytes([len[0], len[1], len[2], len[3]]) as usize;
+        let end = next
+            .checked_add(len)
+            .filter(|end| *end <= range.end)
+            .ok_or_else(|| invalid("chunk outside entry bounds"))?;
+        chunks.push(next..end);
+        next = end;
+    }
+    if next != range.end {
+        return Err(invalid("chunk lengths do not match entry size"));
+    }
+    Ok(EntryLayout::Compressed(CompressedEntry {
+        codec: EntryCodec::from_flags(entry.flags),
+        sealed,
+        len: entry.original_size,
+        chunk_size,
+        chunks,
+    }))
+}
+
+/// `Read + Seek` handle over one archive entry.
 pub struct PerroAssetsFile {
     data: ArchiveBytes,
-    entry: PerroAssetsEntry,
+    layout: EntryLayout,
     pos: u64,
+    /// Last inflated chunk; sequential reads inflate each chunk once.
+    chunk: Option<(usize, Vec<u8>)>,
+}
+
+impl PerroAssetsFile {
+    /// File size in bytes, uncompressed.
+    pub fn size(&self) -> u64 {
+        self.layout.len()
+    }
 }
 
 impl Read for PerroAssetsFile {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        let range = checked_entry_range(self.data.len(), &self.entry)?;
-        let data = &self.data[range];
-        let pos = usize::try_from(self.pos)
-            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "read offset too large"))?;
-        let remaining = data.get(pos..).ok_or_else(|| {
-            io::Error::new(io::ErrorKind::InvalidInput, "read offset out of bounds")
-        })?;
-        let amt = remaining.len().min(buf.len());
-        buf[..amt].copy_from_slice(&remaining[..amt]);
+        if buf.is_empty() || self.pos >= self.layout.len() {
+            return Ok(0);
+        }
+        let src = match &self.layout {
+            EntryLayout::Stored(range) => {
+                let start = usize::try_from(self.pos).map_err(|_| {
+                    io::Error::new(io::ErrorKind::InvalidInput, "read offset too large")
+                })?;
+                &self.data[range.start + start..range.end]
+            }
+            EntryLayout::Compressed(entry) => {
+                let index = (self.pos / entry.chunk_size) as usize;
+                if self
+                    .chunk
+                    .as_ref()
+                    .is_none_or(|(cached, _)| *cached != index)
+                {
+                    self.chunk = Some((index, entry.inflate(&self.data, index)?));
+                }
+                let Some((_, chunk)) = self.chunk.as_ref() else {
+                    return Ok(0);
+                };
+                let within = (self.pos - index as u64 * entry.chunk_size) as usize;
+                &chunk[within..]
+            }
+        };
+        let amt = src.len().min(buf.len());
+        buf[..amt].copy_from_slice(&src[..amt]);
         self.pos += amt as u64;
         Ok(amt)
     }
@@ -245,13 +663,14 @@ impl Read for PerroAssetsFile {
 
 impl Seek for PerroAssetsFile {
     fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let size = self.layout.len();
         let new_pos = match pos {
             SeekFrom::Start(n) => n,
-            SeekFrom::End(n) => checked_seek(self.entry.size, n)?,
+            SeekFrom::End(n) => checked_seek(size, n)?,
             SeekFrom::Current(n) => checked_seek(self.pos, n)?,
         };
 
-        if new_pos > self.entry.size {
+        if new_pos > size {
             return Err(io::Error::new(
                 io::ErrorKind::InvalidInput,
                 "Seek out of bounds",
@@ -275,10 +694,10 @@ fn checked_seek(base: u64, offset: i64) -> io::Result<u64> {
 
 #[cfg(test)]
 mod tests {
-    use super::{ArchiveBytes, PerroAssetsArchive, checked_seek};
+    use super::{ArchiveBytes, PerroAssetsArchive, PerroAssetsEntryInfo, checked_seek};
 
     static EMPTY_ARCHIVE: &[u8] = &[
-        b'P', b'R', b'A', b'1', 1, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0,
+        b'P', b'R', b'A', b'1', 3, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0,
     ];
 
     #[test]
@@ -304,4 +723,74 @@ mod tests {
         };
         assert_eq!(data.as_ptr(), EMPTY_ARCHIVE.as_ptr());
     }
+
+    // Each test passes its own `tag` so parallel runs never share a temp dir.
+    fn listing_archive(tag: &str) -> PerroAssetsArchive {
+        let root =
+            std::env::temp_dir().join(format!("perro_assets_listing_{tag}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).expect("required value must be present");
+        let entries = [
+            "res/levels/forest.scn",
+            "res/levels/cave.scn",
+            "res/levels/notes.txt",
+            "res/levels/bonus/secret.scn",
+            "res/mods/hats/mod.toml",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, path)| {
+            let source = root.join(format!("{i}.bin"));
+            std::fs::write(&source, path.as_bytes()).expect("required value must be present");
+            (path.to_string(), source)
+        })
+        .collect::<Vec<_>>();
+        let output = root.join("listing.perro");
+        crate::packer::build_perro_archive_from_entries(&output, &entries)
+            .expect("required value must be present");
+        let archive =
+            PerroAssetsArchive::open_from_file(&output).expect("required value must be present");
+        let _ = std::fs::remove_dir_all(&root);
+        archive
+    }
+
+    fn paths(entries: Vec<PerroAssetsEntryInfo>) -> Vec<String> {
+        entries.into_iter().map(|entry| entry.path).collect()
+    }
+
+    #[test]
+    fn list_dir_returns_direct_children_sorted() {
+        let archive = listing_archive("list_dir");
+        let expected = [
+            "res/levels/cave.scn",
+            "res/levels/forest.scn",
+            "res/levels/notes.txt",
+        ];
+        assert_eq!(paths(archive.list_dir("res/levels/")), expected);
+        assert_eq!(paths(archive.list_dir("res/levels")), expected);
+        assert!(archive.list_dir("res/lev").is_empty());
+
+        let listed = archive.list_dir("res/levels");
+        let cave = &listed[0];
+        assert_eq!(cave.size, "res/levels/cave.scn".len() as u64);
+        assert!(!cave.encrypted);
+    }
+
+    #[test]
+    fn glob_matches_across_directories() {
+        let archive = listing_archive("glob");
+        assert_eq!(
+            paths(archive.glob("res/**/*.scn")),
+            [
+                "res/levels/bonus/secret.scn",
+                "res/levels/cave.scn",
+                "res/levels/forest.scn",
+            ]
+        );
+        assert_eq!(
+            paths(archive.glob("res/mods/*/mod.toml")),
+            ["res/mods/hats/mod.toml"]
+        );
+        assert!(archive.glob("res/*.scn").is_empty());
+    }
 }
@@ -1,9 +1,15 @@
 use std::io::{self, Read, Write};
+use xxhash_rust::xxh3::xxh3_64;
 
 pub const PERRO_ASSETS_MAGIC: [u8; 4] = perro_asset_formats::archive::MAGIC;
 pub const PERRO_ASSETS_COMPRESSED_MAGIC: [u8; 4] = perro_asset_formats::archive::COMPRESSED_MAGIC;
 
 pub const FLAG_COMPRESSED: u32 = perro_asset_formats::archive::FLAG_COMPRESSED;
+pub const FLAG_CHUNKED: u32 = perro_asset_formats::archive::FLAG_CHUNKED;
+pub const FLAG_LZ4: u32 = perro_asset_formats::archive::FLAG_LZ4;
+pub const FLAG_ZSTD: u32 = perro_asset_formats::archive::FLAG_ZSTD;
+pub const FLAG_ENCRYPTED: u32 = perro_asset_formats::archive::FLAG_ENCRYPTED;
+const KNOWN_FLAGS: u32 = FLAG_COMPRESSED | FLAG_CHUNKED | FLAG_LZ4 | FLAG_ZSTD | FLAG_ENCRYPTED;
 
 /// Archive header
 #[derive(Debug, Clone, Copy)]
@@ -21,6 +27,27 @@ pub struct PerroAssetsEntryMeta {
     pub size: u64,          // Actual size in archive (compressed if FLAG_COMPRESSED)
     pub original_size: u64, // Original uncompressed size
     pub flags: u32,
+    /// xxh3 of the stored bytes (after codec + seal), checked by `verify`.
+    pub hash: u64,
+    /// Source file mtime in nanos since the Unix epoch; 0 when not recorded.
+    pub mtime: u64,
+    /// Small tool-defined key/values (import settings hash, asset type tag),
+    /// sorted by key; at most [`MAX_ENTRY_METADATA_LEN`] bytes encoded.
+    pub metadata: Vec<(String, String)>,
+}
+
+impl PerroAssetsEntryMeta {
+    pub fn metadata_value(&self, key: &str) -> Option<&str> {
+        self.metadata
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Hash recorded for an entry's stored bytes.
+pub fn entry_content_hash(stored: &[u8]) -> u64 {
+    xxh3_64(stored)
 }
 
 fn read_exact_array<const N: usize, R: Read>(reader: &mut R) -> io::Result<[u8; N]> {
@@ -29,6 +56,10 @@ fn read_exact_array<const N: usize, R: Read>(reader: &mut R) -> io::Result<[u8;
     Ok(buf)
 }
 
+fn read_u8<R: Read>(reader: &mut R) -> io::Result<u8> {
+    Ok(read_exact_array::<1, _>(reader)?[0])
+}
+
 fn read_u16<R: Read>(reader: &mut R) -> io::Result<u16> {
     Ok(u16::from_le_bytes(read_exact_array::<2, _>(reader)?))
 }
@@ -74,6 +105,14 @@ pub fn write_header<W: Write>(writer: &mut W, header: &PerroAssetsHeader) -> io:
     Ok(())
 }
 
+/// Smallest encoded index entry: empty path + offset, sizes, flags, hash,
+/// mtime, and an empty metadata block.
+pub const MIN_INDEX_ENTRY_LEN: usize = 2 + 8 + 8 + 8 + 4 + 8 + 8 + 2;
+
+/// Largest encoded metadata block: per pair a `u8` key len, key, `u16` value
+/// len, value.
+pub const MAX_ENTRY_METADATA_LEN: usize = u16::MAX as usize;
+
 pub fn read_index_entry<R: Read>(reader: &mut R) -> io::Result<(String, PerroAssetsEntryMeta)> {
     let path_len = read_u16(reader)? as usize;
     let mut path_buf = vec![0u8; path_len];
@@ -85,12 +124,24 @@ pub fn read_index_entry<R: Read>(reader: &mut R) -> io::Result<(String, PerroAss
     let size = read_u64(reader)?;
     let original_size = read_u64(reader)?;
     let flags = read_u32(reader)?;
-    if flags & !FLAG_COMPRESSED != 0 {
+    // Codec bits only mean something on a compressed entry; chunking needs
+    // the entry to be compressed or encrypted.
+    let stray_codec = flags & (FLAG_LZ4 | FLAG_ZSTD) != 0 && flags & FLAG_COMPRESSED == 0;
+    let stray_chunked =
+        flags & FLAG_CHUNKED != 0 && flags & (FLAG_COMPRESSED | FLAG_ENCRYPTED) == 0;
+    let stray_bits = stray_codec || stray_chunked;
+    let two_codecs = flags & (FLAG_LZ4 | FLAG_ZSTD) == FLAG_LZ4 | FLAG_ZSTD;
+    if flags & !KNOWN_FLAGS != 0 || stray_bits || two_codecs {
         return Err(io::Error::new(
             io::ErrorKind::InvalidData,
             format!("Unsupported PerroAssets entry flags {flags:#x}"),
         ));
     }
+    let hash = read_u64(reader)?;
+    let mtime = read_u64(reader)?;
+    let mut block = vec![0u8; read_u16(reader)? as usize];
+    reader.read_exact(&mut block)?;
+    let metadata = decode_entry_metadata(&block)?;
 
     Ok((
         path,
@@ -99,6 +150,9 @@ pub fn read_index_entry<R: Read>(reader: &mut R) -> io::Result<(String, PerroAss
             size,
             original_size,
             flags,
+            hash,
+            mtime,
+            metadata,
         },
     ))
 }
@@ -120,12 +174,79 @@ pub fn write_index_entry<W: Write>(
     writer.write_all(&meta.size.to_le_bytes())?;
     writer.write_all(&meta.original_size.to_le_bytes())?;
     writer.write_all(&meta.flags.to_le_bytes())?;
+    writer.write_all(&meta.hash.to_le_bytes())?;
+    writer.write_all(&meta.mtime.to_le_bytes())?;
+    let block = encode_entry_metadata(&meta.metadata)?;
+    writer.write_all(&(block.len() as u16).to_le_bytes())?;
+    writer.write_all(&block)?;
     Ok(())
 }
 
+fn encode_entry_metadata(metadata: &[(String, String)]) -> io::Result<Vec<u8>> {
+    let too_large = || io::Error::new(io::ErrorKind::InvalidInput, "Entry metadata too large");
+    let mut block = Vec::new();
+    for (key, value) in metadata {
+        let key_len = u8::try_from(key.len()).map_err(|_| too_large())?;
+        let value_len = u16::try_from(value.len()).map_err(|_| too_large())?;
+        block.push(key_len);
+        block.extend_from_slice(key.as_bytes());
+        block.extend_from_slice(&value_len.to_le_bytes());
+        block.extend_from_slice(value.as_bytes());
+    }
+    if block.len() > MAX_ENTRY_METADATA_LEN {
+        return Err(too_large());
+    }
+    Ok(block)
+}
+
+fn decode_entry_metadata(mut block: &[u8]) -> io::Result<Vec<(String, String)>> {
+    let mut metadata = Vec::new();
+    while !block.is_empty() {
+        let key_len = read_u8(&mut block)? as usize;
+        let key = read_metadata_str(&mut block, key_len)?;
+        let value_len = read_u16(&mut block)? as usize;
+        let value = read_metadata_str(&mut block, value_len)?;
+        metadata.push((key, value));
+    }
+    Ok(metadata)
+}
+
+fn read_metadata_str(block: &mut &[u8], len: usize) -> io::Result<String> {
+    let mut buf = vec![0u8; len];
+    block.read_exact(&mut buf)?;
+    String::from_utf8(buf)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Invalid UTF-8 metadata"))
+}
+
+/// Chunked entry prefix: chunk size + chunk count, each a `u32`.
+pub const CHUNK_TABLE_HEADER_LEN: usize = 4 + 4;
+
+/// Stored bytes of a `FLAG_CHUNKED` entry: chunk size, chunk count, each
+/// chunk's compressed len, then the chunks back to back. Chunk `i` inflates
+/// to file bytes `i * chunk_size..`; only the last chunk may be shorter.
+pub fn encode_chunked_entry(chunk_size: u32, chunks: &[Vec<u8>]) -> io::Result<Vec<u8>> {
+    let too_large = || io::Error::new(io::ErrorKind::InvalidInput, "Chunked entry too large");
+    let count = u32::try_from(chunks.len()).map_err(|_| too_large())?;
+    let body = chunks.iter().map(Vec::len).sum::<usize>();
+    let mut out = Vec::with_capacity(CHUNK_TABLE_HEADER_LEN + chunks.len() * 4 + body);
+    out.extend_from_slice(&chunk_size.to_le_bytes());
+    out.extend_from_slice(&count.to_le_bytes());
+    for chunk in chunks {
+        let len = u32::try_from(chunk.len()).map_err(|_| too_large())?;
+        out.extend_from_slice(&len.to_le_bytes());
+    }
+    for chunk in chunks {
+        out.extend_from_slice(chunk);
+    }
+    Ok(out)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{PERRO_ASSETS_MAGIC, read_header, read_index_entry};
+    use super::{
+        FLAG_CHUNKED, FLAG_COMPRESSED, FLAG_ENCRYPTED, FLAG_LZ4, FLAG_ZSTD, PERRO_ASSETS_MAGIC,
+        PerroAssetsEntryMeta, read_header, read_index_entry, write_index_entry,
+    };
     use std::io::{Cursor, ErrorKind};
 
     #[test]
@@ -140,6 +261,18 @@ mod tests {
         assert_eq!(err.kind(), ErrorKind::InvalidData);
     }
 
+    #[test]
+    fn index_rejects_chunked_without_compressed() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.push(b'x');
+        bytes.extend_from_slice(&[0; 24]);
+        bytes.extend_from_slice(&FLAG_CHUNKED.to_le_bytes());
+
+        let err = read_index_entry(&mut Cursor::new(bytes)).expect_err("chunked w/o zlib");
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
     #[test]
     fn index_rejects_unknown_flags() {
         let mut bytes = Vec::new();
@@ -148,9 +281,77 @@ mod tests {
         bytes.extend_from_slice(&0u64.to_le_bytes());
         bytes.extend_from_slice(&0u64.to_le_bytes());
         bytes.extend_from_slice(&0u64.to_le_bytes());
-        bytes.extend_from_slice(&2u32.to_le_bytes());
+        bytes.extend_from_slice(&(1u32 << 5).to_le_bytes());
 
         let err = read_index_entry(&mut Cursor::new(bytes)).expect_err("unknown flags");
         assert_eq!(err.kind(), ErrorKind::InvalidData);
     }
+
+    #[test]
+    fn index_rejects_stray_or_conflicting_codec_flags() {
+        let entry = |flags: u32| {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&1u16.to_le_bytes());
+            bytes.push(b'x');
+            bytes.extend_from_slice(&[0; 24]);
+            bytes.extend_from_slice(&flags.to_le_bytes());
+            bytes.extend_from_slice(&[0; 8 + 8 + 2]);
+            read_index_entry(&mut Cursor::new(bytes))
+        };
+        assert!(entry(FLAG_LZ4).is_err());
+        assert!(entry(FLAG_COMPRESSED | FLAG_LZ4 | FLAG_ZSTD).is_err());
+        assert!(entry(FLAG_ENCRYPTED | FLAG_ZSTD).is_err());
+        let (_, meta) = entry(FLAG_COMPRESSED | FLAG_CHUNKED | FLAG_ZSTD).expect("zstd chunked");
+        assert_eq!(meta.flags, FLAG_COMPRESSED | FLAG_CHUNKED | FLAG_ZSTD);
+        // stored-but-encrypted entries still chunk for range reads
+        assert!(entry(FLAG_ENCRYPTED | FLAG_CHUNKED).is_ok());
+    }
+
+    #[test]
+    fn index_roundtrips_mtime_and_metadata() {
+        let meta = PerroAssetsEntryMeta {
+            offset: 20,
+            size: 3,
+            original_size: 3,
+            flags: 0,
+            hash: 7,
+            mtime: 1_700_000_000_000_000_000,
+            metadata: vec![
+                ("import".to_string(), "9f3a".to_string()),
+                ("type".to_string(), "texture".to_string()),
+            ],
+        };
+        let mut bytes = Vec::new();
+        write_index_entry(&mut bytes, "res/a.png", &meta).expect("write entry");
+        let (path, read) = read_index_entry(&mut Cursor::new(bytes)).expect("read entry");
+        assert_eq!(path, "res/a.png");
+        assert_eq!(read.mtime, meta.mtime);
+        assert_eq!(read.metadata, meta.metadata);
+        assert_eq!(read.metadata_value("type"), Some("texture"));
+        assert_eq!(read.metadata_value("missing"), None);
+    }
+
+    #[test]
+    fn index_rejects_oversized_or_truncated_metadata() {
+        let long_key = PerroAssetsEntryMeta {
+            offset: 0,
+            size: 0,
+            original_size: 0,
+            flags: 0,
+            hash: 0,
+            mtime: 0,
+            metadata: vec![("k".repeat(256), String::new())],
+        };
+        let err = write_index_entry(&mut Vec::new(), "x", &long_key).expect_err("key too long");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.push(b'x');
+        bytes.extend_from_slice(&[0; 24 + 4 + 8 + 8]);
+        // Block claims a 4-byte key but holds only 2 bytes.
+        bytes.extend_from_slice(&3u16.to_le_bytes());
+        bytes.extend_from_slice(&[4, b'a', b'b']);
+        assert!(read_index_entry(&mut Cursor::new(bytes)).is_err());
+    }
 }
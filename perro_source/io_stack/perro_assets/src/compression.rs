@@ -1,29 +1,169 @@
 use flate2::Compression;
 use flate2::read::ZlibDecoder;
 use flate2::write::ZlibEncoder;
+use perro_asset_formats::archive::{FLAG_COMPRESSED, FLAG_LZ4, FLAG_ZSTD};
+use rayon::prelude::*;
 use std::io::{self, Read, Write};
 
 const DEFAULT_MAX_DECOMPRESSED_BYTES: usize = 1024 * 1024 * 1024;
 
+/// zlib level for every packed asset. Pinned together with the `zlib-rs`
+/// backend in Cargo.toml so the same inputs always produce the same archive
+/// bytes; changing either must bump the static pipeline cache version and the
+/// archive stat version so cached compressed bytes are not mixed in.
+pub const ZLIB_ARCHIVE_LEVEL: u32 = 9;
+
+/// zstd level for [`EntryCodec::Zstd`]. Pinned like [`ZLIB_ARCHIVE_LEVEL`].
+pub const ZSTD_ARCHIVE_LEVEL: i32 = 19;
+
+/// How one archive entry's bytes are stored. Picked per file at pack time and
+/// recorded in the entry's index flags, so readers never guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntryCodec {
+    /// Raw bytes; for formats that are already compressed.
+    Store,
+    Zlib,
+    /// Larger than zlib but inflates several times faster; for load-time
+    /// mesh data.
+    Lz4,
+    /// Slow to pack, smallest output; for text and scenes.
+    Zstd,
+}
+
+impl EntryCodec {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Store => "store",
+            Self::Zlib => "zlib",
+            Self::Lz4 => "lz4",
+            Self::Zstd => "zstd",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "store" => Some(Self::Store),
+            "zlib" => Some(Self::Zlib),
+            "lz4" => Some(Self::Lz4),
+            "zstd" => Some(Self::Zstd),
+            _ => None,
+        }
+    }
+
+    /// Index flags for an entry stored w/ this codec, minus `FLAG_CHUNKED`.
+    pub const fn flags(self) -> u32 {
+        match self {
+            Self::Store => 0,
+            Self::Zlib => FLAG_COMPRESSED,
+            Self::Lz4 => FLAG_COMPRESSED | FLAG_LZ4,
+            Self::Zstd => FLAG_COMPRESSED | FLAG_ZSTD,
+        }
+    }
+
+    /// Codec of an index entry; compressed w/o a codec flag is zlib, which
+    /// keeps archives packed b4 codec flags readable.
+    pub const fn from_flags(flags: u32) -> Self {
+        if flags & FLAG_COMPRESSED == 0 {
+            Self::Store
+        } else if flags & FLAG_LZ4 != 0 {
+            Self::Lz4
+        } else if flags & FLAG_ZSTD != 0 {
+            Self::Zstd
+        } else {
+            Self::Zlib
+        }
+    }
+
+    pub fn compress(self, data: &[u8]) -> io::Result<Vec<u8>> {
+        match self {
+            Self::Store => Ok(data.to_vec()),
+            Self::Zlib => compress_zlib_best(data),
+            Self::Lz4 => Ok(lz4_flex::block::compress(data)),
+            Self::Zstd => compress_zstd_best(data),
+        }
+    }
+
+    /// Inverse of [`EntryCodec::compress`]; errors instead of producing more
+    /// than `max_output_bytes`.
+    pub fn decompress_limited(self, data: &[u8], max_output_bytes: usize) -> io::Result<Vec<u8>> {
+        match self {
+            Self::Store if data.len() > max_output_bytes => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "stored entry exceeds limit",
+            )),
+            Self::Store => Ok(data.to_vec()),
+            Self::Zlib => decompress_zlib_limited(data, max_output_bytes),
+            // Block format has no length prefix; the index holds the size.
+            Self::Lz4 => lz4_flex::block::decompress(data, max_output_bytes)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string())),
+            Self::Zstd => decompress_zstd_limited(data, max_output_bytes),
+        }
+    }
+}
+
 pub fn compress_zlib_best(data: &[u8]) -> io::Result<Vec<u8>> {
-    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::new(ZLIB_ARCHIVE_LEVEL));
     encoder.write_all(data)?;
     encoder.finish()
 }
 
+/// `data` split into `chunk_size` pieces, each its own zlib stream so it
+/// inflates without the others.
+pub fn compress_zlib_chunks(data: &[u8], chunk_size: usize) -> io::Result<Vec<Vec<u8>>> {
+    compress_chunks(EntryCodec::Zlib, data, chunk_size)
+}
+
+/// Like [`compress_zlib_chunks`], w/ any codec.
+pub fn compress_chunks(
+    codec: EntryCodec,
+    data: &[u8],
+    chunk_size: usize,
+) -> io::Result<Vec<Vec<u8>>> {
+    data.par_chunks(chunk_size.max(1))
+        .map(|chunk| codec.compress(chunk))
+        .collect()
+}
+
 pub fn decompress_zlib(data: &[u8]) -> io::Result<Vec<u8>> {
     decompress_zlib_limited(data, DEFAULT_MAX_DECOMPRESSED_BYTES)
 }
 
 pub fn decompress_zlib_limited(data: &[u8], max_output_bytes: usize) -> io::Result<Vec<u8>> {
-    let mut decoder = ZlibDecoder::new(data);
+    read_to_end_limited(ZlibDecoder::new(data), max_output_bytes, "zlib")
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn compress_zstd_best(data: &[u8]) -> io::Result<Vec<u8>> {
+    zstd::bulk::compress(data, ZSTD_ARCHIVE_LEVEL)
+}
+
+// Archives are packed on the host; web builds only ever read them.
+#[cfg(target_arch = "wasm32")]
+pub fn compress_zstd_best(_data: &[u8]) -> io::Result<Vec<u8>> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "zstd packing is not available on wasm",
+    ))
+}
+
+pub fn decompress_zstd_limited(data: &[u8], max_output_bytes: usize) -> io::Result<Vec<u8>> {
+    let decoder = ruzstd::decoding::StreamingDecoder::new(data)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+    read_to_end_limited(decoder, max_output_bytes, "zstd")
+}
+
+fn read_to_end_limited(
+    decoder: impl Read,
+    max_output_bytes: usize,
+    codec: &str,
+) -> io::Result<Vec<u8>> {
     let mut out = Vec::new();
     let limit = (max_output_bytes as u64).saturating_add(1);
-    decoder.by_ref().take(limit).read_to_end(&mut out)?;
+    decoder.take(limit).read_to_end(&mut out)?;
     if out.len() > max_output_bytes {
         return Err(io::Error::new(
             io::ErrorKind::InvalidData,
-            "zlib output exceeds limit",
+            format!("{codec} output exceeds limit"),
         ));
     }
     Ok(out)
@@ -0,0 +1,107 @@
+//! AES-256-GCM sealing for `FLAG_ENCRYPTED` archive entries.
+//!
+//! Each blob is `nonce || ciphertext || tag`, w/ the entry path + chunk index
+//! as associated data so blobs cannot be swapped between entries or chunks.
+//! The nonce is a keyed hash of that slot and the plaintext: the same inputs
+//! seal to the same bytes (archives stay reproducible), and a file that
+//! changes never reuses a nonce under the same key.
+
+use aes_gcm::aead::{Aead, Payload};
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
+use sha2::{Digest, Sha256};
+use std::{fmt, io};
+
+pub const ARCHIVE_KEY_LEN: usize = 32;
+pub type ArchiveKey = [u8; ARCHIVE_KEY_LEN];
+
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+/// Bytes a sealed blob adds over its plaintext.
+pub const SEAL_OVERHEAD: usize = NONCE_LEN + TAG_LEN;
+
+/// Cipher for opening entries, built once per archive.
+#[derive(Clone)]
+pub struct ArchiveCipher(Aes256Gcm);
+
+impl ArchiveCipher {
+    pub fn new(key: &ArchiveKey) -> Self {
+        Self(Aes256Gcm::new(key.into()))
+    }
+
+    pub fn open(&self, path: &str, index: u32, sealed: &[u8]) -> io::Result<Vec<u8>> {
+        if sealed.len() < SEAL_OVERHEAD {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "encrypted chunk truncated",
+            ));
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let aad = associated_data(path, index);
+        self.0
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{path}: failed to decrypt (wrong archive key?)"),
+                )
+            })
+    }
+}
+
+// Key schedule stays out of logs.
+impl fmt::Debug for ArchiveCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ArchiveCipher(..)")
+    }
+}
+
+/// Seal chunk `index` of the entry at `path`.
+pub fn seal_chunk(
+    key: &ArchiveKey,
+    path: &str,
+    index: u32,
+    plaintext: &[u8],
+) -> io::Result<Vec<u8>> {
+    let aad = associated_data(path, index);
+    let mut hasher = Sha256::new();
+    hasher.update(key);
+    hasher.update(&aad);
+    hasher.update(plaintext);
+    let digest = hasher.finalize();
+    let nonce = Nonce::from_slice(&digest[..NONCE_LEN]);
+    let ciphertext = Aes256Gcm::new(key.into())
+        .encrypt(
+            nonce,
+            Payload {
+                msg: plaintext,
+                aad: &aad,
+            },
+        )
+        .map_err(|_| io::Error::other(format!("{path}: encryption failed")))?;
+    let mut out = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+/// Short hash of `key`, safe to write next to the archive; tells builds
+/// whether cached sealed bytes were made w/ the current key.
+pub fn key_fingerprint(key: &ArchiveKey) -> String {
+    Sha256::digest(key)[..8]
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+fn associated_data(path: &str, index: u32) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(path.len() + 4);
+    aad.extend_from_slice(path.as_bytes());
+    aad.extend_from_slice(&index.to_le_bytes());
+    aad
+}
@@ -0,0 +1,295 @@
+//! Boot-time integrity manifest for an embedded `.perro` archive.
+//!
+//! Export writes a small text manifest next to the archive: a hash over the
+//! header + index, the file count, the script registry length, and hashes of
+//! the entries the project marks critical. Shipped games re-hash at startup so
+//! a truncated or patched install reports "corrupted install" instead of
+//! failing later in a way that looks like an engine bug. Every other entry is
+//! checked against the content hash in its index record.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::io::{self, Cursor, Seek, SeekFrom};
+
+use super::archive::{decode_archive_container, verify_entry};
+use super::common::{
+    MIN_INDEX_ENTRY_LEN, PERRO_ASSETS_COMPRESSED_MAGIC, PerroAssetsEntryMeta, read_header,
+    read_index_entry,
+};
+
+pub const INTEGRITY_MANIFEST_VERSION: u32 = 1;
+
+const HEADER_LEN: usize = 20;
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegrityManifest {
+    pub index_hash: u64,
+    pub file_count: u32,
+    pub script_count: usize,
+    /// `(archive path, hash of stored bytes)`, e.g. `res/data/items.json`.
+    pub entries: Vec<(String, u64)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntegrityError {
+    /// Manifest text itself is unreadable (bad export, not a bad install).
+    Manifest(String),
+    /// Archive bytes do not parse as a `.perro` archive.
+    Archive(String),
+    IndexHash {
+        expected: u64,
+        actual: u64,
+    },
+    FileCount {
+        expected: u32,
+        actual: u32,
+    },
+    ScriptCount {
+        expected: usize,
+        actual: usize,
+    },
+    MissingEntry(String),
+    EntryHash {
+        path: String,
+        expected: u64,
+        actual: u64,
+    },
+    /// Entry bytes disagree w/ the hash in their own index record.
+    CorruptEntry(String),
+}
+
+impl fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Manifest(msg) => write!(f, "integrity manifest unreadable: {msg}"),
+            Self::Archive(msg) => write!(f, "asset archive unreadable: {msg}"),
+            Self::IndexHash { expected, actual } => write!(
+                f,
+                "asset index hash mismatch (expected {expected:016x}, got {actual:016x})"
+            ),
+            Self::FileCount { expected, actual } => write!(
+                f,
+                "asset file count mismatch (expected {expected}, got {actual})"
+            ),
+            Self::ScriptCount { expected, actual } => write!(
+                f,
+                "script registry size mismatch (expected {expected}, got {actual})"
+            ),
+            Self::MissingEntry(path) => write!(f, "critical asset `{path}` missing"),
+            Self::EntryHash {
+                path,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "critical asset `{path}` hash mismatch (expected {expected:016x}, got {actual:016x})"
+            ),
+            Self::CorruptEntry(msg) => write!(f, "asset corrupted: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for IntegrityError {}
+
+impl IntegrityManifest {
+    /// Hashes `archive` as exported. `critical` are archive paths (`res/...`);
+    /// a critical path not in the archive is an export error.
+    pub fn compute(archive: &[u8], critical: &[String], script_count: usize) -> io::Result<Self> {
+        let archive = plain_archive(archive)?;
+        let parsed = ParsedArchive::parse(&archive)?;
+        let mut entries = Vec::with_capacity(critical.len());
+        for path in critical {
+            let hash = parsed.entry_hash(&archive, path)?.ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("critical asset `{path}` not packed into archive"),
+                )
+            })?;
+            entries.push((path.clone(), hash));
+        }
+        entries.sort();
+        entries.dedup();
+        Ok(Self {
+            index_hash: parsed.index_hash,
+            file_count: parsed.file_count,
+            script_count,
+            entries,
+        })
+    }
+
+    /// Re-hashes `archive` and compares against this manifest. Stops at the
+    /// first mismatch; one clear reason beats a wall of them in a crash report.
+    pub fn verify(&self, archive: &[u8], script_count: usize) -> Result<(), IntegrityError> {
+        if script_count != self.script_count {
+            return Err(IntegrityError::ScriptCount {
+                expected: self.script_count,
+                actual: script_count,
+            });
+        }
+        let archive =
+            plain_archive(archive).map_err(|err| IntegrityError::Archive(err.to_string()))?;
+        let parsed = ParsedArchive::parse(&archive)
+            .map_err(|err| IntegrityError::Archive(err.to_string()))?;
+        if parsed.file_count != self.file_count {
+            return Err(IntegrityError::FileCount {
+                expected: self.file_count,
+                actual: parsed.file_count,
+            });
+        }
+        if parsed.index_hash != self.index_hash {
+            return Err(IntegrityError::IndexHash {
+                expected: self.index_hash,
+                actual: parsed.index_hash,
+            });
+        }
+        for (path, expected) in &self.entries {
+            let actual = parsed
+                .entry_hash(&archive, path)
+                .map_err(|err| IntegrityError::Archive(err.to_string()))?
+                .ok_or_else(|| IntegrityError::MissingEntry(path.clone()))?;
+            if actual != *expected {
+                return Err(IntegrityError::EntryHash {
+                    path: path.clone(),
+                    expected: *expected,
+                    actual,
+                });
+            }
+        }
+        for (path, meta) in &parsed.index {
+            verify_entry(&archive, path, meta)
+                .map_err(|err| IntegrityError::CorruptEntry(err.to_string()))?;
+        }
+        Ok(())
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut out = format!(
+            "perro_integrity {INTEGRITY_MANIFEST_VERSION}\nindex {:016x}\nfiles {}\nscripts {}\n",
+            self.index_hash, self.file_count, self.script_count
+        );
+        for (path, hash) in &self.entries {
+            out.push_str(&format!("entry {hash:016x} {path}\n"));
+        }
+        out
+    }
+
+    pub fn parse(text: &str) -> Result<Self, IntegrityError> {
+        let bad = |line: &str| IntegrityError::Manifest(format!("bad line `{line}`"));
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+        match lines.next() {
+            Some(line) if line == format!("perro_integrity {INTEGRITY_MANIFEST_VERSION}") => {}
+            Some(line) => return Err(bad(line)),
+            None => return Err(IntegrityError::Manifest("empty manifest".to_string())),
+        }
+        let mut index_hash = None;
+        let mut file_count = None;
+        let mut script_count = None;
+        let mut entries = Vec::new();
+        for line in lines {
+            let (key, rest) = line.split_once(' ').ok_or_else(|| bad(line))?;
+            match key {
+                "index" => index_hash = Some(parse_hex(rest).ok_or_else(|| bad(line))?),
+                "files" => file_count = Some(rest.parse().map_err(|_| bad(line))?),
+                "scripts" => script_count = Some(rest.parse().map_err(|_| bad(line))?),
+                "entry" => {
+                    let (hash, path) = rest.split_once(' ').ok_or_else(|| bad(line))?;
+                    entries.push((path.to_string(), parse_hex(hash).ok_or_else(|| bad(line))?));
+                }
+                _ => return Err(bad(line)),
+            }
+        }
+        let missing = |field: &str| IntegrityError::Manifest(format!("missing `{field}`"));
+        Ok(Self {
+            index_hash: index_hash.ok_or_else(|| missing("index"))?,
+            file_count: file_count.ok_or_else(|| missing("files"))?,
+            script_count: script_count.ok_or_else(|| missing("scripts"))?,
+            entries,
+        })
+    }
+}
+
+struct ParsedArchive {
+    index_hash: u64,
+    file_count: u32,
+    index: Vec<(String, PerroAssetsEntryMeta)>,
+}
+
+impl ParsedArchive {
+    fn parse(archive: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(archive);
+        let header = read_header(&mut cursor)?;
+        let index_start = usize::try_from(header.index_offset)
+            .ok()
+            .filter(|start| *start >= HEADER_LEN && *start <= archive.len())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "archive index outside data bounds",
+                )
+            })?;
+        cursor.seek(SeekFrom::Start(header.index_offset))?;
+        // `file_count` is untrusted; never reserve more entries than bytes allow.
+        let max_entries = (archive.len() - index_start) / MIN_INDEX_ENTRY_LEN;
+        let mut index = Vec::with_capacity((header.file_count as usize).min(max_entries));
+        for _ in 0..header.file_count {
+            index.push(read_index_entry(&mut cursor)?);
+        }
+        // Total length is folded in so a truncated data section still trips the
+        // index hash even though the index bytes themselves survived.
+        let mut hash = fnv1a(FNV_OFFSET, &(archive.len() as u64).to_le_bytes());
+        hash = fnv1a(hash, &archive[..HEADER_LEN]);
+        hash = fnv1a(hash, &archive[index_start..]);
+        Ok(Self {
+            index_hash: hash,
+            file_count: header.file_count,
+            index,
+        })
+    }
+
+    fn entry_hash(&self, archive: &[u8], path: &str) -> io::Result<Option<u64>> {
+        let Some((_, meta)) = self.index.iter().find(|(entry, _)| entry == path) else {
+            return Ok(None);
+        };
+        let start = usize::try_from(meta.offset).ok();
+        let end = start
+            .zip(usize::try_from(meta.size).ok())
+            .and_then(|(s, n)| s.checked_add(n));
+        let bytes = start
+            .zip(end)
+            .and_then(|(start, end)| archive.get(start..end))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "archive entry outside data bounds",
+                )
+            })?;
+        Ok(Some(fnv1a(FNV_OFFSET, bytes)))
+    }
+}
+
+// Embedded game archives are plain; only a compressed container pays for a copy.
+fn plain_archive(bytes: &[u8]) -> io::Result<Cow<'_, [u8]>> {
+    if bytes.starts_with(&PERRO_ASSETS_COMPRESSED_MAGIC) {
+        decode_archive_container(bytes.to_vec()).map(Cow::Owned)
+    } else {
+        Ok(Cow::Borrowed(bytes))
+    }
+}
+
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+fn parse_hex(raw: &str) -> Option<u64> {
+    u64::from_str_radix(raw.trim(), 16).ok()
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/integrity_tests.rs"]
+mod integrity_tests;
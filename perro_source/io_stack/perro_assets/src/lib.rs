@@ -1,7 +1,10 @@
 pub mod archive;
 pub mod common;
 pub mod compression;
+pub mod crypto;
+pub mod integrity;
 pub mod packer;
+pub mod patch;
 pub mod walkdir;
 
 pub use packer::*;
@@ -1,5 +1,6 @@
 use rayon::prelude::*;
 use std::{
+    cell::RefCell,
     collections::{HashMap, HashSet},
     fs,
     io::{self, Cursor, Seek, SeekFrom, Write},
@@ -8,12 +9,15 @@ use std::{
 };
 
 use super::common::{
-    FLAG_COMPRESSED, PERRO_ASSETS_COMPRESSED_MAGIC, PERRO_ASSETS_MAGIC, PerroAssetsEntryMeta,
-    PerroAssetsHeader, read_header, read_index_entry, write_header, write_index_entry,
+    FLAG_CHUNKED, FLAG_ENCRYPTED, PERRO_ASSETS_COMPRESSED_MAGIC, PERRO_ASSETS_MAGIC,
+    PerroAssetsEntryMeta, PerroAssetsHeader, encode_chunked_entry, entry_content_hash, read_header,
+    read_index_entry, write_header, write_index_entry,
 };
-use crate::compression::compress_zlib_best;
+use crate::compression::{EntryCodec, compress_chunks, compress_zlib_best};
+use crate::crypto::{ArchiveKey, key_fingerprint, seal_chunk};
 use crate::walkdir::collect_file_paths;
-use perro_asset_formats::{archive, source_ext};
+use perro_asset_formats::{archive, pmesh, pnav, pscn, pskel, ptset, source_ext};
+use xxhash_rust::xxh3::xxh3_64;
 
 fn should_skip(path: &str, extra_skip_rel_paths: &HashSet<&str>) -> bool {
     let ext = Path::new(path)
@@ -46,25 +50,210 @@ struct ProcessedFile {
     data: Vec<u8>,
     flags: u32,
     original_size: u64,
+    // Codec the rules picked, even when the bytes ended up stored.
+    codec: EntryCodec,
     // (len, mtime nanos) recorded in the stat sidecar; None skips recording
     // (unreadable stat, or the file changed between stat and read).
     stat: Option<(u64, u128)>,
+    // xxh3 of the source bytes, recorded next to the stat.
+    source_hash: u64,
 }
 
 /// Stat sidecar version; bump alongside any change to what a cached entry
 /// means (compression codec, entry layout) so stale sidecars self-invalidate.
-const ASSETS_STAT_VERSION: u32 = 1;
+const ASSETS_STAT_VERSION: u32 = 4;
 
-fn stat_manifest_header() -> String {
+/// The key fingerprint rides in the header: switching keys (or turning
+/// encryption on/off) re-seals every entry instead of reusing stale bytes.
+fn stat_manifest_header(key: Option<&ArchiveKey>) -> String {
     format!(
-        "perro-assets-stat v{ASSETS_STAT_VERSION} archive-v{}",
-        archive::VERSION
+        "perro-assets-stat v{ASSETS_STAT_VERSION} archive-v{} key-{}",
+        archive::VERSION,
+        key.map(key_fingerprint).as_deref().unwrap_or("none")
     )
 }
 
-/// `<output>.stat` sidecar recording each packed source's (len, mtime), which
-/// lets the next build reuse already-compressed bytes out of the previous
-/// archive instead of re-reading + re-deflating the whole res tree.
+thread_local! {
+    static ARCHIVE_KEY: RefCell<Option<ArchiveKey>> = const { RefCell::new(None) };
+}
+
+#[must_use = "dropping the guard restores the prior archive key"]
+pub struct ArchiveKeyGuard {
+    previous: Option<ArchiveKey>,
+}
+
+/// Seal every entry of archives built on this thread w/ `key` while the
+/// guard lives; `None` packs in the clear.
+pub fn push_archive_key(key: Option<ArchiveKey>) -> ArchiveKeyGuard {
+    let previous = ARCHIVE_KEY.with(|active| std::mem::replace(&mut *active.borrow_mut(), key));
+    ArchiveKeyGuard { previous }
+}
+
+impl Drop for ArchiveKeyGuard {
+    fn drop(&mut self) {
+        ARCHIVE_KEY.with(|active| *active.borrow_mut() = self.previous.take());
+    }
+}
+
+fn active_archive_key() -> Option<ArchiveKey> {
+    ARCHIVE_KEY.with(|active| *active.borrow())
+}
+
+/// Extra index metadata for archives built on this thread; see
+/// [`push_entry_metadata`].
+#[derive(Debug, Clone, Default)]
+pub struct EntryMetadataOptions {
+    /// Record each source's mtime. Off by default: mtimes differ between
+    /// checkouts, so recording them makes otherwise identical archives differ.
+    pub record_mtime: bool,
+    /// Key/values by archive path (`"res/textures/hero.png"`).
+    pub values: HashMap<String, Vec<(String, String)>>,
+}
+
+impl EntryMetadataOptions {
+    /// Index mtime + key/values for `path`, given its source mtime in nanos.
+    fn index_fields(&self, path: &str, mtime: Option<u128>) -> (u64, Vec<(String, String)>) {
+        let mtime = mtime
+            .filter(|_| self.record_mtime)
+            .map_or(0, |nanos| u64::try_from(nanos).unwrap_or(u64::MAX));
+        (mtime, self.values.get(path).cloned().unwrap_or_default())
+    }
+}
+
+thread_local! {
+    static ENTRY_METADATA: RefCell<EntryMetadataOptions> =
+        RefCell::new(EntryMetadataOptions::default());
+}
+
+#[must_use = "dropping the guard restores prior entry metadata options"]
+pub struct EntryMetadataGuard {
+    previous: EntryMetadataOptions,
+}
+
+/// Write source mtimes and/or per-path key/values into the index of archives
+/// built on this thread while the guard lives, so tools can make incremental
+/// decisions from the archive alone. Keys are sorted; the first of a repeated
+/// key wins.
+pub fn push_entry_metadata(mut options: EntryMetadataOptions) -> EntryMetadataGuard {
+    for values in options.values.values_mut() {
+        values.sort_by(|a, b| a.0.cmp(&b.0));
+        values.dedup_by(|a, b| a.0 == b.0);
+    }
+    let previous =
+        ENTRY_METADATA.with(|active| std::mem::replace(&mut *active.borrow_mut(), options));
+    EntryMetadataGuard { previous }
+}
+
+impl Drop for EntryMetadataGuard {
+    fn drop(&mut self) {
+        let previous = std::mem::take(&mut self.previous);
+        ENTRY_METADATA.with(|active| *active.borrow_mut() = previous);
+    }
+}
+
+fn active_entry_metadata() -> EntryMetadataOptions {
+    ENTRY_METADATA.with(|active| active.borrow().clone())
+}
+
+thread_local! {
+    /// Extension -> codec overrides, lowercase + sorted; see [`push_codec_rules`].
+    static CODEC_RULES: RefCell<Vec<(String, EntryCodec)>> = const { RefCell::new(Vec::new()) };
+}
+
+#[must_use = "dropping the guard restores prior codec rules"]
+pub struct CodecRulesGuard {
+    previous: Vec<(String, EntryCodec)>,
+}
+
+/// Per-extension codec overrides (`("pmesh", EntryCodec::Zstd)`) on top of
+/// [`default_entry_codec`] for archives built on this thread while the guard
+/// lives.
+pub fn push_codec_rules(rules: Vec<(String, EntryCodec)>) -> CodecRulesGuard {
+    let mut rules = rules
+        .into_iter()
+        .map(|(ext, codec)| (ext.trim_start_matches('.').to_ascii_lowercase(), codec))
+        .collect::<Vec<_>>();
+    rules.sort_by(|a, b| a.0.cmp(&b.0));
+    rules.dedup_by(|a, b| a.0 == b.0);
+    let previous = CODEC_RULES.with(|active| std::mem::replace(&mut *active.borrow_mut(), rules));
+    CodecRulesGuard { previous }
+}
+
+impl Drop for CodecRulesGuard {
+    fn drop(&mut self) {
+        let previous = std::mem::take(&mut self.previous);
+        CODEC_RULES.with(|active| *active.borrow_mut() = previous);
+    }
+}
+
+fn active_codec_rules() -> Vec<(String, EntryCodec)> {
+    CODEC_RULES.with(|active| active.borrow().clone())
+}
+
+/// Codec `path` packs with: an override from `rules` (as read by
+/// [`push_codec_rules`]) for its extension, else [`default_entry_codec`].
+pub fn entry_codec(rules: &[(String, EntryCodec)], path: &str) -> EntryCodec {
+    let ext = Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    rules
+        .iter()
+        .find(|(rule, _)| *rule == ext)
+        .map_or_else(|| default_entry_codec(&ext), |(_, codec)| *codec)
+}
+
+/// Built-in rules: already-compressed media is stored, load-time mesh data
+/// gets LZ4, text + scenes get zstd, everything else zlib.
+pub fn default_entry_codec(ext: &str) -> EntryCodec {
+    const STORE: &[&str] = &[
+        "png", "jpg", "jpeg", "gif", "webp", "ogg", "mp3", "flac", "aac", "m4a", "zip",
+    ];
+    const LZ4: &[&str] = &[
+        pmesh::EXTENSION,
+        pnav::EXTENSION,
+        pskel::EXTENSION,
+        pskel::EXTENSION_2D,
+        pskel::EXTENSION_3D,
+        source_ext::GLB,
+    ];
+    const ZSTD: &[&str] = &[
+        pscn::EXTENSION,
+        source_ext::SCENE,
+        source_ext::MATERIAL,
+        source_ext::PARTICLE,
+        source_ext::ANIMATION,
+        source_ext::ANIMATION_TREE,
+        source_ext::UI_STYLE,
+        source_ext::SHADER_WGSL,
+        source_ext::GLTF,
+        ptset::SOURCE_EXTENSION,
+        "txt",
+        "json",
+        "toml",
+        "csv",
+        "ron",
+        "xml",
+        "yaml",
+        "yml",
+    ];
+    if source_ext::contains(STORE, ext) {
+        EntryCodec::Store
+    } else if source_ext::contains(LZ4, ext) {
+        EntryCodec::Lz4
+    } else if source_ext::contains(ZSTD, ext) {
+        EntryCodec::Zstd
+    } else {
+        EntryCodec::Zlib
+    }
+}
+
+/// `<output>.stat` sidecar recording each packed source's (len, mtime), codec,
+/// and content hash, which lets the next build reuse already-compressed bytes
+/// out of the previous archive instead of re-reading + re-deflating the whole
+/// res tree. A stat miss w/ an unchanged hash (checkout, copy, `touch`) still
+/// reuses; a changed codec rule re-encodes just the files it moves.
 fn stat_sidecar_path(output: &Path) -> PathBuf {
     let mut name = output.file_name().unwrap_or_default().to_os_string();
     name.push(".stat");
@@ -82,9 +271,17 @@ fn file_stat(path: &Path) -> Option<(u64, u128)> {
     Some((meta.len(), mtime))
 }
 
+/// One stat sidecar line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct StatRecord {
+    stat: (u64, u128),
+    codec: EntryCodec,
+    source_hash: u64,
+}
+
 /// Previous archive + its stat sidecar, loaded for compressed-byte reuse.
 struct ReusedArchive {
-    stats: HashMap<String, (u64, u128)>,
+    stats: HashMap<String, StatRecord>,
     index: HashMap<String, PerroAssetsEntryMeta>,
     bytes: Vec<u8>,
 }
@@ -95,20 +292,47 @@ impl ReusedArchive {
         let size = usize::try_from(meta.size).ok()?;
         self.bytes.get(start..start.checked_add(size)?)
     }
+
+    /// Previous record + stored entry for `rel_path`, if it was packed w/ `codec`.
+    fn entry(
+        &self,
+        rel_path: &str,
+        codec: EntryCodec,
+    ) -> Option<(&StatRecord, &PerroAssetsEntryMeta, &[u8])> {
+        let record = self
+            .stats
+            .get(rel_path)
+            .filter(|record| record.codec == codec)?;
+        let meta = self.index.get(&format!("res/{rel_path}"))?;
+        Some((record, meta, self.entry_slice(meta)?))
+    }
 }
 
-fn load_reuse_archive(output: &Path, stat_path: &Path) -> Option<ReusedArchive> {
+fn load_reuse_archive(
+    output: &Path,
+    stat_path: &Path,
+    key: Option<&ArchiveKey>,
+) -> Option<ReusedArchive> {
     let text = fs::read_to_string(stat_path).ok()?;
     let mut lines = text.lines();
-    if lines.next()? != stat_manifest_header() {
+    if lines.next()? != stat_manifest_header(key) {
         return None;
     }
     let mut stats = HashMap::new();
     for line in lines {
-        let mut parts = line.splitn(3, '\t');
+        let mut parts = line.splitn(5, '\t');
         let len = parts.next()?.parse().ok()?;
         let mtime = parts.next()?.parse().ok()?;
-        stats.insert(parts.next()?.to_string(), (len, mtime));
+        let codec = EntryCodec::parse(parts.next()?)?;
+        let source_hash = u64::from_str_radix(parts.next()?, 16).ok()?;
+        stats.insert(
+            parts.next()?.to_string(),
+            StatRecord {
+                stat: (len, mtime),
+                codec,
+                source_hash,
+            },
+        );
     }
     let bytes = fs::read(output).ok()?;
     let mut cursor = Cursor::new(bytes.as_slice());
@@ -126,12 +350,21 @@ fn load_reuse_archive(output: &Path, stat_path: &Path) -> Option<ReusedArchive>
     })
 }
 
-fn write_stat_manifest(path: &Path, files: &[ProcessedFile]) -> io::Result<()> {
-    let mut out = stat_manifest_header();
+fn write_stat_manifest(
+    path: &Path,
+    files: &[ProcessedFile],
+    key: Option<&ArchiveKey>,
+) -> io::Result<()> {
+    let mut out = stat_manifest_header(key);
     out.push('\n');
     for file in files {
         if let Some((len, mtime)) = file.stat {
-            out.push_str(&format!("{len}\t{mtime}\t{}\n", file.rel_path));
+            out.push_str(&format!(
+                "{len}\t{mtime}\t{}\t{:016x}\t{}\n",
+                file.codec.as_str(),
+                file.source_hash,
+                file.rel_path
+            ));
         }
     }
     write_output_if_changed(path, out.as_bytes())
@@ -152,68 +385,124 @@ fn write_output_if_changed(path: &Path, bytes: &[u8]) -> io::Result<()> {
 
 /// Build a `.perro` archive.
 ///
-/// Incremental: a `<output>.stat` sidecar records each source's (len, mtime);
-/// sources whose stat is unchanged reuse their compressed bytes from the
-/// previous archive instead of being re-read and re-deflated.
+/// Incremental: a `<output>.stat` sidecar records each source's (len, mtime)
+/// and content hash; sources whose stat is unchanged reuse their compressed
+/// bytes from the previous archive w/o being re-read, and sources whose stat
+/// moved but whose bytes hash the same reuse them w/o being re-deflated.
 pub fn build_perro_assets_archive(
     output: &Path,
     res_dir: &Path,
+    project_root: &Path,
+    extra_skip_rel_paths: &[String],
+) -> io::Result<()> {
+    build_perro_assets_archive_with_compiled(
+        output,
+        res_dir,
+        None,
+        project_root,
+        extra_skip_rel_paths,
+    )
+}
+
+/// Build a `.perro` archive like [`build_perro_assets_archive`], also packing
+/// static-pipeline output from `compiled_dir` (e.g. `.scnb` scenes) under
+/// `res/`. Compiled files replace a source w/ the same relative path.
+pub fn build_perro_assets_archive_with_compiled(
+    output: &Path,
+    res_dir: &Path,
+    compiled_dir: Option<&Path>,
     _project_root: &Path,
     extra_skip_rel_paths: &[String],
 ) -> io::Result<()> {
     let extra_skip_set: HashSet<&str> = extra_skip_rel_paths.iter().map(String::as_str).collect();
 
     // Collect file paths and process file bytes/compression in parallel.
-    let mut rel_paths = collect_file_paths(res_dir, res_dir)?
+    let mut sources = collect_file_paths(res_dir, res_dir)?
         .into_iter()
         .map(|rel| rel.replace('\\', "/"))
         .filter(|rel| !should_skip(rel, &extra_skip_set))
+        .map(|rel| (res_dir.join(&rel), rel))
         .collect::<Vec<_>>();
-    rel_paths.sort();
+    if let Some(compiled_dir) = compiled_dir.filter(|dir| dir.exists()) {
+        let compiled = collect_file_paths(compiled_dir, compiled_dir)?
+            .into_iter()
+            .map(|rel| rel.replace('\\', "/"))
+            .collect::<HashSet<_>>();
+        sources.retain(|(_, rel)| !compiled.contains(rel));
+        sources.extend(
+            compiled
+                .into_iter()
+                .map(|rel| (compiled_dir.join(&rel), rel)),
+        );
+    }
+    sources.sort_by(|a, b| a.1.cmp(&b.1));
 
+    // Thread-locals, so read them here rather than on the rayon workers.
+    let key = active_archive_key();
+    let extra_meta = active_entry_metadata();
+    let codec_rules = active_codec_rules();
     let stat_path = stat_sidecar_path(output);
-    let reuse = load_reuse_archive(output, &stat_path);
+    let reuse = load_reuse_archive(output, &stat_path, key.as_ref());
 
-    let processed_files = rel_paths
+    let processed_files = sources
         .into_par_iter()
-        .map(|rel_path| -> io::Result<ProcessedFile> {
-            let full_path = res_dir.join(&rel_path);
+        .map(|(full_path, rel_path)| -> io::Result<ProcessedFile> {
             let stat = file_stat(&full_path);
-            // Unchanged stat: lift the already-compressed bytes straight out
-            // of the previous archive.
-            if let (Some(reuse), Some(stat)) = (reuse.as_ref(), stat)
-                && reuse.stats.get(&rel_path) == Some(&stat)
-                && let Some(meta) = reuse.index.get(&format!("res/{rel_path}"))
-                && let Some(data) = reuse.entry_slice(meta)
+            let codec = entry_codec(&codec_rules, &rel_path);
+            let prev = reuse
+                .as_ref()
+                .and_then(|reuse| reuse.entry(&rel_path, codec));
+            // Unchanged stat + codec: lift the already-compressed bytes
+            // straight out of the previous archive.
+            if let (Some((record, meta, stored)), Some(stat)) = (prev, stat)
+                && record.stat == stat
             {
                 return Ok(ProcessedFile {
                     rel_path,
-                    data: data.to_vec(),
+                    data: stored.to_vec(),
                     flags: meta.flags,
                     original_size: meta.original_size,
+                    codec,
                     stat: Some(stat),
+                    source_hash: record.source_hash,
                 });
             }
             let mut data = fs::read(&full_path)?;
             // A length mismatch means the file changed between stat and read;
             // drop the stat so the next build re-encodes instead of reusing.
             let stat = stat.filter(|(len, _)| *len == data.len() as u64);
+            let source_hash = xxh3_64(&data);
+            // Stat moved but the bytes did not: skip the re-encode.
+            if let Some((record, meta, stored)) = prev
+                && record.source_hash == source_hash
+                && meta.original_size == data.len() as u64
+            {
+                return Ok(ProcessedFile {
+                    rel_path,
+                    data: stored.to_vec(),
+                    flags: meta.flags,
+                    original_size: meta.original_size,
+                    codec,
+                    stat,
+                    source_hash,
+                });
+            }
             let original_size = data.len() as u64;
             let mut flags = 0;
-            if original_size > 0 {
-                let compressed = compress_zlib_best(&data)?;
-                // Only use compressed data if it's actually smaller
-                if compressed.len() < data.len() {
-                    data = compressed;
-                    flags |= FLAG_COMPRESSED;
-                }
+            let entry_path = format!("res/{rel_path}");
+            let seal = key.as_ref().map(|key| (key, entry_path.as_str()));
+            if let Some((compressed, compressed_flags)) = compress_entry(codec, &data, seal)? {
+                data = compressed;
+                flags = compressed_flags;
             }
             Ok(ProcessedFile {
                 rel_path,
                 data,
                 flags,
                 original_size,
+                codec,
                 stat,
+                source_hash,
             })
         })
         .collect::<io::Result<Vec<_>>>()?;
@@ -232,13 +521,19 @@ pub fn build_perro_assets_archive(
         let offset = archive.stream_position()?;
         archive.write_all(&processed.data)?;
 
+        let path = format!("res/{}", processed.rel_path);
+        let (mtime, metadata) =
+            extra_meta.index_fields(&path, processed.stat.map(|(_, mtime)| mtime));
         entries.push(PerroAssetsEntry {
-            path: format!("res/{}", processed.rel_path),
+            path,
             meta: PerroAssetsEntryMeta {
                 offset,
                 size: processed.data.len() as u64,
                 original_size: processed.original_size,
                 flags: processed.flags,
+                hash: entry_content_hash(&processed.data),
+                mtime,
+                metadata,
             },
         });
     }
@@ -260,7 +555,60 @@ pub fn build_perro_assets_archive(
     write_header(&mut archive, &header)?;
 
     write_output_if_changed(output, &archive.into_inner())?;
-    write_stat_manifest(&stat_path, &processed_files)
+    write_stat_manifest(&stat_path, &processed_files, key.as_ref())
+}
+
+/// Stored bytes + flags for one entry, or `None` when `codec` is `Store` or
+/// does not shrink it and there is nothing to seal. Files over one chunk
+/// compress (and seal) as independent chunks so `read_range`/`stream_file`
+/// never inflate the whole file. `seal` is the key + the entry's archive path.
+fn compress_entry(
+    codec: EntryCodec,
+    data: &[u8],
+    seal: Option<(&ArchiveKey, &str)>,
+) -> io::Result<Option<(Vec<u8>, u32)>> {
+    if data.is_empty() {
+        return Ok(None);
+    }
+    let chunk_size = archive::CHUNK_SIZE as usize;
+    let chunked = data.len() > chunk_size;
+    let mut pieces = Vec::new();
+    let mut flags = 0;
+    if codec != EntryCodec::Store {
+        let compressed = if chunked {
+            compress_chunks(codec, data, chunk_size)?
+        } else {
+            vec![codec.compress(data)?]
+        };
+        if compressed.iter().map(Vec::len).sum::<usize>() < data.len() {
+            pieces = compressed;
+            flags = codec.flags();
+        }
+    }
+    if pieces.is_empty() {
+        if seal.is_none() {
+            return Ok(None);
+        }
+        pieces = data.chunks(chunk_size).map(<[u8]>::to_vec).collect();
+    }
+    if let Some((key, path)) = seal {
+        pieces = pieces
+            .par_iter()
+            .enumerate()
+            .map(|(index, piece)| seal_chunk(key, path, index as u32, piece))
+            .collect::<io::Result<_>>()?;
+        flags |= FLAG_ENCRYPTED;
+    }
+    if !chunked {
+        let piece = pieces.pop().expect("one piece per unchunked entry");
+        return Ok(Some((piece, flags)));
+    }
+    let stored = encode_chunked_entry(archive::CHUNK_SIZE, &pieces)?;
+    // Unsealed chunk tables only pay off while the payload still shrinks.
+    if seal.is_none() && stored.len() >= data.len() {
+        return Ok(None);
+    }
+    Ok(Some((stored, flags | FLAG_CHUNKED)))
 }
 
 /// Build a generic `.perro` archive from explicit `(virtual_path, source_file)` entries.
@@ -280,11 +628,18 @@ pub fn build_perro_archive_from_entries(
 /// compressed archive and a whole-archive zlib wrap (which can win on
 /// cross-file redundancy). A fully raw archive can never beat the per-entry
 /// candidate — entries only stay compressed when smaller — so it is not built.
+/// W/ an archive key pushed the wrap would hold plaintext, so only the sealed
+/// per-entry archive is written.
 pub fn build_compressed_perro_archive_from_entries(
     output: &Path,
     entries: &[(String, std::path::PathBuf)],
 ) -> io::Result<()> {
     let read = read_archive_entries(entries)?;
+    if active_archive_key().is_some() {
+        let mut sealed = Cursor::new(Vec::<u8>::new());
+        write_perro_archive_from_bytes(&mut sealed, &read, true)?;
+        return write_output_if_changed(output, &sealed.into_inner());
+    }
 
     let mut raw = Cursor::new(Vec::<u8>::new());
     write_perro_archive_from_bytes(&mut raw, &read, false)?;
@@ -305,14 +660,20 @@ pub fn build_compressed_perro_archive_from_entries(
 struct ReadArchiveEntry {
     virtual_path: String,
     raw: Vec<u8>,
-    // Present only when zlib actually shrank the payload.
-    compressed: Option<Vec<u8>>,
+    // Source mtime nanos, when stat-able.
+    mtime: Option<u128>,
+    // Stored bytes + flags; present only when the codec actually shrank the
+    // payload, or when the entry was sealed.
+    compressed: Option<(Vec<u8>, u32)>,
 }
 
 /// Read and per-entry-compress every source exactly once, sorted by path.
 fn read_archive_entries(
     entries: &[(String, std::path::PathBuf)],
 ) -> io::Result<Vec<ReadArchiveEntry>> {
+    // Thread-locals, so read them here rather than on the rayon workers.
+    let key = active_archive_key();
+    let codec_rules = active_codec_rules();
     let mut sorted = entries.to_vec();
     sorted.sort_by(|a, b| a.0.cmp(&b.0));
     sorted
@@ -320,11 +681,14 @@ fn read_archive_entries(
         .map(
             |(virtual_path, source_path)| -> io::Result<ReadArchiveEntry> {
                 let raw = fs::read(&source_path)?;
-                let compressed = compress_zlib_best(&raw)?;
-                let compressed = (compressed.len() < raw.len()).then_some(compressed);
+                let mtime = file_stat(&source_path).map(|(_, mtime)| mtime);
+                let seal = key.as_ref().map(|key| (key, virtual_path.as_str()));
+                let compressed =
+                    compress_entry(entry_codec(&codec_rules, &virtual_path), &raw, seal)?;
                 Ok(ReadArchiveEntry {
                     virtual_path,
                     raw,
+                    mtime,
                     compressed,
                 })
             },
@@ -355,14 +719,16 @@ fn write_perro_archive_from_bytes<W: Write + Seek>(
     };
     write_header(writer, &header)?;
 
+    let extra_meta = active_entry_metadata();
     let mut index_entries = Vec::<PerroAssetsEntry>::with_capacity(entries.len());
     for entry in entries {
         let (data, flags) = match (&entry.compressed, compress_entries) {
-            (Some(compressed), true) => (compressed.as_slice(), FLAG_COMPRESSED),
+            (Some((compressed, flags)), true) => (compressed.as_slice(), *flags),
             _ => (entry.raw.as_slice(), 0),
         };
         let offset = writer.stream_position()?;
         writer.write_all(data)?;
+        let (mtime, metadata) = extra_meta.index_fields(&entry.virtual_path, entry.mtime);
         index_entries.push(PerroAssetsEntry {
             path: entry.virtual_path.clone(),
             meta: PerroAssetsEntryMeta {
@@ -370,6 +736,9 @@ fn write_perro_archive_from_bytes<W: Write + Seek>(
                 size: data.len() as u64,
                 original_size: entry.raw.len() as u64,
                 flags,
+                hash: entry_content_hash(data),
+                mtime,
+                metadata,
             },
         });
     }
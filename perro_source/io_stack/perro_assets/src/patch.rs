@@ -0,0 +1,225 @@
+//! Delta patches between two `.perro` archives, for shipping game updates
+//! w/o a full archive download.
+//!
+//! A patch holds the stored bytes (after codec + seal) of every entry the
+//! target changed or added, an index for them in the archive index format,
+//! and the paths the target removed. Unchanged entries are referenced from
+//! the base by path, so a patch is as small as the set of changed files.
+//! Layout: header (magic, version, base fingerprint, entry count, removed
+//! count, index offset), entry bytes, index entries, removed paths.
+//!
+//! Mount one w/ [`PerroAssetsArchive::with_patch`], or fold it into a full
+//! archive w/ [`apply_patch`].
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{self, Cursor, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use perro_asset_formats::archive;
+
+use crate::archive::{PerroAssetsArchive, PerroAssetsEntry};
+use crate::common::{
+    MIN_INDEX_ENTRY_LEN, PERRO_ASSETS_MAGIC, PerroAssetsEntryMeta, PerroAssetsHeader,
+    read_index_entry, write_header, write_index_entry,
+};
+
+/// Magic + version + base fingerprint + entry count + removed count + index
+/// offset.
+pub const PATCH_HEADER_LEN: usize = 4 + 4 + 8 + 4 + 4 + 8;
+
+pub(crate) struct ParsedPatch {
+    pub(crate) base_fingerprint: u64,
+    pub(crate) entries: HashMap<String, PerroAssetsEntry>,
+    pub(crate) removed: Vec<String>,
+}
+
+/// Patch that turns `base` into `target`. Entries whose stored bytes, flags,
+/// and size match are left out, keeping the base's mtime + metadata; both
+/// archives must be packed w/ the same archive key for sealed entries to
+/// match.
+pub fn build_patch(base: &PerroAssetsArchive, target: &PerroAssetsArchive) -> io::Result<Vec<u8>> {
+    let mut out = Cursor::new(Vec::<u8>::new());
+    out.write_all(&[0; PATCH_HEADER_LEN])?;
+
+    let mut changed = Vec::new();
+    let mut target_paths = HashSet::new();
+    for (path, _, entry) in target.entries() {
+        target_paths.insert(path);
+        let (_, stored) = target.stored_entry(path)?;
+        let unchanged = base
+            .stored_entry(path)
+            .is_ok_and(|(old, _)| same_stored_entry(old, entry));
+        if unchanged {
+            continue;
+        }
+        let offset = out.stream_position()?;
+        out.write_all(stored)?;
+        changed.push((
+            path,
+            PerroAssetsEntryMeta {
+                offset,
+                ..entry.clone()
+            },
+        ));
+    }
+    let removed = base
+        .entries()
+        .into_iter()
+        .map(|(path, _, _)| path)
+        .filter(|path| !target_paths.contains(path))
+        .collect::<Vec<_>>();
+
+    let index_offset = out.stream_position()?;
+    for (path, meta) in &changed {
+        write_index_entry(&mut out, path, meta)?;
+    }
+    for path in &removed {
+        write_patch_path(&mut out, path)?;
+    }
+
+    out.seek(SeekFrom::Start(0))?;
+    out.write_all(&archive::PATCH_MAGIC)?;
+    out.write_all(&archive::PATCH_VERSION.to_le_bytes())?;
+    out.write_all(&base.fingerprint().to_le_bytes())?;
+    out.write_all(&count_u32(changed.len())?.to_le_bytes())?;
+    out.write_all(&count_u32(removed.len())?.to_le_bytes())?;
+    out.write_all(&index_offset.to_le_bytes())?;
+    Ok(out.into_inner())
+}
+
+/// [`build_patch`] between two archive files, written to `output`.
+pub fn build_patch_file(base: &Path, target: &Path, output: &Path) -> io::Result<()> {
+    let base = PerroAssetsArchive::open_from_file(base)?;
+    let target = PerroAssetsArchive::open_from_file(target)?;
+    fs::write(output, build_patch(&base, &target)?)
+}
+
+/// Full archive bytes of `base` w/ `patch` applied; for installers that
+/// rewrite the archive on disk instead of mounting the patch.
+pub fn apply_patch(base: &PerroAssetsArchive, patch: Vec<u8>) -> io::Result<Vec<u8>> {
+    let patched = base.with_patch(patch)?;
+    let mut out = Cursor::new(Vec::<u8>::new());
+    let mut header = PerroAssetsHeader {
+        magic: PERRO_ASSETS_MAGIC,
+        version: archive::VERSION,
+        file_count: 0,
+        index_offset: 0,
+    };
+    write_header(&mut out, &header)?;
+
+    let entries = patched.entries();
+    let mut index = Vec::with_capacity(entries.len());
+    for (path, _, entry) in &entries {
+        let (_, stored) = patched.stored_entry(path)?;
+        let offset = out.stream_position()?;
+        out.write_all(stored)?;
+        index.push((
+            *path,
+            PerroAssetsEntryMeta {
+                offset,
+                ..(*entry).clone()
+            },
+        ));
+    }
+
+    header.index_offset = out.stream_position()?;
+    header.file_count = count_u32(index.len())?;
+    for (path, meta) in &index {
+        write_index_entry(&mut out, path, meta)?;
+    }
+    out.seek(SeekFrom::Start(0))?;
+    write_header(&mut out, &header)?;
+    Ok(out.into_inner())
+}
+
+/// [`apply_patch`] on files; `output` may be `base` itself.
+pub fn apply_patch_file(base: &Path, patch: &Path, output: &Path) -> io::Result<()> {
+    let archive = PerroAssetsArchive::open_from_file(base)?;
+    let bytes = apply_patch(&archive, fs::read(patch)?)?;
+    fs::write(output, bytes)
+}
+
+pub(crate) fn read_patch(data: &[u8]) -> io::Result<ParsedPatch> {
+    let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidData, msg);
+    let header = data
+        .get(..PATCH_HEADER_LEN)
+        .ok_or_else(|| invalid("patch header truncated".to_string()))?;
+    if header[..4] != archive::PATCH_MAGIC {
+        return Err(invalid("Not a PerroAssets patch".to_string()));
+    }
+    let u32_at = |at: usize| {
+        u32::from_le_bytes([header[at], header[at + 1], header[at + 2], header[at + 3]])
+    };
+    let u64_at = |at: usize| {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&header[at..at + 8]);
+        u64::from_le_bytes(bytes)
+    };
+    let version = u32_at(4);
+    if version != archive::PATCH_VERSION {
+        return Err(invalid(format!(
+            "Unsupported PerroAssets patch version {version}"
+        )));
+    }
+    let base_fingerprint = u64_at(8);
+    let file_count = u32_at(16) as usize;
+    let removed_count = u32_at(20) as usize;
+    let index_offset = u64_at(24);
+
+    // Counts are untrusted; each record needs at least its fixed bytes.
+    let index_len = (data.len() as u64).saturating_sub(index_offset);
+    let min_len = file_count as u64 * MIN_INDEX_ENTRY_LEN as u64 + removed_count as u64 * 2;
+    if index_offset < PATCH_HEADER_LEN as u64 || index_len < min_len {
+        return Err(invalid("patch index truncated".to_string()));
+    }
+
+    let mut cursor = Cursor::new(data);
+    cursor.seek(SeekFrom::Start(index_offset))?;
+    let mut entries = HashMap::with_capacity(file_count);
+    for _ in 0..file_count {
+        let (path, meta) = read_index_entry(&mut cursor)?;
+        entries.insert(path, meta);
+    }
+    let mut removed = Vec::with_capacity(removed_count);
+    for _ in 0..removed_count {
+        removed.push(read_patch_path(&mut cursor)?);
+    }
+    Ok(ParsedPatch {
+        base_fingerprint,
+        entries,
+        removed,
+    })
+}
+
+fn same_stored_entry(old: &PerroAssetsEntry, new: &PerroAssetsEntry) -> bool {
+    old.hash == new.hash
+        && old.size == new.size
+        && old.original_size == new.original_size
+        && old.flags == new.flags
+}
+
+fn count_u32(count: usize) -> io::Result<u32> {
+    u32::try_from(count)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "too many patch entries"))
+}
+
+fn write_patch_path<W: Write>(writer: &mut W, path: &str) -> io::Result<()> {
+    let len = u16::try_from(path.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "Path too long"))?;
+    writer.write_all(&len.to_le_bytes())?;
+    writer.write_all(path.as_bytes())
+}
+
+fn read_patch_path<R: Read>(reader: &mut R) -> io::Result<String> {
+    let mut len = [0; 2];
+    reader.read_exact(&mut len)?;
+    let mut path = vec![0; u16::from_le_bytes(len) as usize];
+    reader.read_exact(&mut path)?;
+    String::from_utf8(path)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Invalid UTF-8 path"))
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/patch_tests.rs"]
+mod tests;
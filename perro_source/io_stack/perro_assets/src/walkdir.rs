@@ -158,6 +158,8 @@ fn validate_collection_root(dir: &Path, base: &Path) -> io::Result<()> {
 }
 
 /// Visits all files in a directory tree, calling the provided callback for each file.
+///
+/// Files within a directory are visited in path order, before its subdirectories.
 pub fn walk_dir<F>(dir: &Path, callback: &mut F) -> io::Result<()>
 where
     F: FnMut(&Path) -> io::Result<()>,
@@ -184,14 +186,20 @@ where
             ));
         }
 
-        for entry in fs::read_dir(&current)? {
-            let path = entry?.path();
+        // read_dir order is filesystem-defined; sort so every walk (and the
+        // archives and generated code built from it) is reproducible.
+        let mut entries = fs::read_dir(&current)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        entries.sort();
+        let mut subdirs = Vec::new();
+        for path in entries {
             let metadata = fs::symlink_metadata(&path)?;
             reject_link(&path, &metadata)?;
 
             if metadata.is_dir() {
                 canonical_in_root(&path, &root)?;
-                pending.push(path);
+                subdirs.push(path);
             } else if metadata.is_file() {
                 canonical_in_root(&path, &root)?;
                 if !path_is_excluded(&path, dir) {
@@ -199,6 +207,8 @@ where
                 }
             }
         }
+        // `pending` is a stack: push in reverse so subdirs pop in name order.
+        pending.extend(subdirs.into_iter().rev());
     }
     Ok(())
 }
@@ -293,6 +303,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn walk_visits_files_in_path_order() {
+        let root = TempDir::new("order");
+        for dir in ["b", "a", "a/z", "a/c"] {
+            fs::create_dir_all(root.0.join(dir)).expect("required value must be present");
+        }
+        for file in [
+            "b/2.txt",
+            "b/1.txt",
+            "a/z/x.txt",
+            "a/c/y.txt",
+            "a/m.txt",
+            "0.txt",
+        ] {
+            fs::write(root.0.join(file), b"x").expect("required value must be present");
+        }
+
+        let paths = collect_file_paths(&root.0, &root.0)
+            .expect("required value must be present")
+            .into_iter()
+            .map(|path| path.replace('\\', "/"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "0.txt",
+                "a/m.txt",
+                "a/c/y.txt",
+                "a/z/x.txt",
+                "b/1.txt",
+                "b/2.txt"
+            ]
+        );
+    }
+
     #[test]
     fn walk_rejects_link_outside_root() {
         let root = TempDir::new("outside-root");
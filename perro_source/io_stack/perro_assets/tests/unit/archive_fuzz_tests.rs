@@ -4,11 +4,12 @@
 //! panic in header/index parsing, entry range resolution, or decompression.
 
 use crate::common::{
-    PERRO_ASSETS_COMPRESSED_MAGIC, PERRO_ASSETS_MAGIC, PerroAssetsEntryMeta, PerroAssetsHeader,
+    FLAG_CHUNKED, FLAG_COMPRESSED, PERRO_ASSETS_COMPRESSED_MAGIC, PERRO_ASSETS_MAGIC,
+    PerroAssetsEntryMeta, PerroAssetsHeader, encode_chunked_entry, entry_content_hash,
     write_header, write_index_entry,
 };
-use crate::compression::compress_zlib_best;
-use std::io::{Cursor, Seek, SeekFrom};
+use crate::compression::{compress_zlib_best, compress_zlib_chunks};
+use std::io::{Cursor, Read, Seek, SeekFrom};
 
 fn mutation_sweep(valid: &[u8], mut check: impl FnMut(&[u8])) {
     for len in 0..valid.len() {
@@ -40,7 +41,7 @@ fn mutation_sweep(valid: &[u8], mut check: impl FnMut(&[u8])) {
     }
 }
 
-/// Two-entry archive: one raw, one zlib-compressed payload.
+/// Three-entry archive: one raw, one zlib-compressed, one chunked payload.
 fn valid_archive_bytes() -> Vec<u8> {
     let mut archive = Cursor::new(Vec::<u8>::new());
     write_header(
@@ -63,6 +64,13 @@ fn valid_archive_bytes() -> Vec<u8> {
     let compressed_offset = raw_offset + raw_payload.len() as u64;
     archive.get_mut().extend_from_slice(&compressed);
 
+    let chunked_original = (0..40u8).collect::<Vec<_>>();
+    let chunks =
+        compress_zlib_chunks(&chunked_original, 16).expect("test setup/result must succeed");
+    let chunked = encode_chunked_entry(16, &chunks).expect("test setup/result must succeed");
+    let chunked_offset = compressed_offset + compressed.len() as u64;
+    archive.get_mut().extend_from_slice(&chunked);
+
     archive
         .seek(SeekFrom::End(0))
         .expect("test setup/result must succeed");
@@ -75,6 +83,9 @@ fn valid_archive_bytes() -> Vec<u8> {
             size: raw_payload.len() as u64,
             original_size: raw_payload.len() as u64,
             flags: 0,
+            hash: entry_content_hash(raw_payload),
+            mtime: 0,
+            metadata: Vec::new(),
         },
     )
     .expect("test setup/result must succeed");
@@ -85,7 +96,24 @@ fn valid_archive_bytes() -> Vec<u8> {
             offset: compressed_offset,
             size: compressed.len() as u64,
             original_size: original.len() as u64,
-            flags: perro_asset_formats::archive::FLAG_COMPRESSED,
+            flags: FLAG_COMPRESSED,
+            hash: entry_content_hash(&compressed),
+            mtime: 0,
+            metadata: Vec::new(),
+        },
+    )
+    .expect("test setup/result must succeed");
+    write_index_entry(
+        &mut archive,
+        "res/chunked.bin",
+        &PerroAssetsEntryMeta {
+            offset: chunked_offset,
+            size: chunked.len() as u64,
+            original_size: chunked_original.len() as u64,
+            flags: FLAG_COMPRESSED | FLAG_CHUNKED,
+            hash: entry_content_hash(&chunked),
+            mtime: 0,
+            metadata: Vec::new(),
         },
     )
     .expect("test setup/result must succeed");
@@ -98,7 +126,7 @@ fn valid_archive_bytes() -> Vec<u8> {
         &PerroAssetsHeader {
             magic: PERRO_ASSETS_MAGIC,
             version: perro_asset_formats::archive::VERSION,
-            file_count: 2,
+            file_count: 3,
             index_offset,
         },
     )
@@ -113,9 +141,15 @@ fn check_archive(bytes: &[u8]) {
     else {
         return;
     };
+    let _ = archive.verify();
     for path in archive.list_files() {
         let _ = archive.read_file(&path);
         let _ = archive.get_file_slice(&path);
+        let _ = archive.read_range(&path, 10, 20);
+        if let Ok(mut file) = archive.stream_file(&path) {
+            let _ = file.seek(SeekFrom::End(-5));
+            let _ = file.read_to_end(&mut Vec::new());
+        }
     }
 }
 
@@ -124,6 +158,7 @@ fn archive_survives_mutation_sweep() {
     let valid = valid_archive_bytes();
     let archive = crate::archive::PerroAssetsArchive::open_from_owned_bytes(valid.clone())
         .expect("baseline must open");
+    archive.verify().expect("baseline hashes match");
     assert_eq!(
         archive
             .read_file("res/raw.txt")
@@ -136,6 +171,18 @@ fn archive_survives_mutation_sweep() {
             .expect("test setup/result must succeed"),
         vec![0x5Au8; 64]
     );
+    assert_eq!(
+        archive
+            .read_file("res/chunked.bin")
+            .expect("test setup/result must succeed"),
+        (0..40u8).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        archive
+            .read_range("res/chunked.bin", 10, 20)
+            .expect("test setup/result must succeed"),
+        (10..30u8).collect::<Vec<_>>()
+    );
     mutation_sweep(&valid, check_archive);
 }
 
@@ -0,0 +1,129 @@
+use super::{IntegrityError, IntegrityManifest, ParsedArchive};
+use crate::packer::build_perro_archive_from_entries;
+use std::fs;
+
+fn sample_archive(tag: &str) -> Vec<u8> {
+    let root = std::env::temp_dir().join(format!(
+        "perro_assets_integrity_{tag}_{}",
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(&root).expect("test setup/result must succeed");
+    let items = root.join("items.json");
+    let notes = root.join("notes.txt");
+    fs::write(&items, br#"{"sword":10,"shield":4}"#).expect("test setup/result must succeed");
+    fs::write(&notes, b"hello").expect("test setup/result must succeed");
+    let output = root.join("assets.perro");
+    build_perro_archive_from_entries(
+        &output,
+        &[
+            ("res/data/items.json".to_string(), items),
+            ("res/notes.txt".to_string(), notes),
+        ],
+    )
+    .expect("test setup/result must succeed");
+    let bytes = fs::read(&output).expect("test setup/result must succeed");
+    let _ = fs::remove_dir_all(&root);
+    bytes
+}
+
+fn critical() -> Vec<String> {
+    vec!["res/data/items.json".to_string()]
+}
+
+#[test]
+fn manifest_roundtrips_and_verifies_untouched_archive() {
+    let bytes = sample_archive("roundtrip");
+    let manifest = IntegrityManifest::compute(&bytes, &critical(), 3).expect("compute");
+    assert_eq!(manifest.file_count, 2);
+    assert_eq!(manifest.entries.len(), 1);
+    let parsed = IntegrityManifest::parse(&manifest.to_text()).expect("parse");
+    assert_eq!(parsed, manifest);
+    parsed
+        .verify(&bytes, 3)
+        .expect("untouched archive verifies");
+}
+
+#[test]
+fn flipped_critical_entry_byte_is_reported_by_path() {
+    let mut bytes = sample_archive("flip");
+    let manifest = IntegrityManifest::compute(&bytes, &critical(), 0).expect("compute");
+    let offset = ParsedArchive::parse(&bytes)
+        .expect("parse")
+        .index
+        .iter()
+        .find(|(path, _)| path == "res/data/items.json")
+        .map(|(_, meta)| meta.offset as usize)
+        .expect("entry packed");
+    bytes[offset] ^= 0xff;
+    let err = manifest.verify(&bytes, 0).expect_err("corrupted entry");
+    assert!(
+        matches!(err, IntegrityError::EntryHash { ref path, .. } if path == "res/data/items.json")
+    );
+}
+
+#[test]
+fn truncated_archive_fails_before_entry_checks() {
+    let bytes = sample_archive("truncate");
+    let manifest = IntegrityManifest::compute(&bytes, &critical(), 0).expect("compute");
+    let err = manifest
+        .verify(&bytes[..bytes.len() - 1], 0)
+        .expect_err("truncated archive");
+    assert!(matches!(err, IntegrityError::Archive(_)));
+}
+
+#[test]
+fn huge_file_count_fails_without_reserving_it() {
+    let mut bytes = sample_archive("count");
+    bytes[8..12].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert!(ParsedArchive::parse(&bytes).is_err());
+}
+
+#[test]
+fn script_registry_length_mismatch_is_reported() {
+    let bytes = sample_archive("scripts");
+    let manifest = IntegrityManifest::compute(&bytes, &[], 4).expect("compute");
+    assert_eq!(
+        manifest.verify(&bytes, 3),
+        Err(IntegrityError::ScriptCount {
+            expected: 4,
+            actual: 3
+        })
+    );
+}
+
+#[test]
+fn unknown_critical_path_is_an_export_error() {
+    let bytes = sample_archive("missing");
+    assert!(IntegrityManifest::compute(&bytes, &["res/nope.bin".to_string()], 0).is_err());
+}
+
+#[test]
+fn manifest_parse_rejects_other_versions_and_missing_fields() {
+    assert!(matches!(
+        IntegrityManifest::parse("perro_integrity 99\nindex 0\nfiles 0\nscripts 0\n"),
+        Err(IntegrityError::Manifest(_))
+    ));
+    assert!(matches!(
+        IntegrityManifest::parse("perro_integrity 1\nindex 0\nfiles 0\n"),
+        Err(IntegrityError::Manifest(_))
+    ));
+}
+
+#[test]
+fn flipped_non_critical_entry_byte_fails_its_index_hash() {
+    let mut bytes = sample_archive("flip_other");
+    let manifest = IntegrityManifest::compute(&bytes, &critical(), 0).expect("compute");
+    let offset = ParsedArchive::parse(&bytes)
+        .expect("parse")
+        .index
+        .iter()
+        .find(|(path, _)| path == "res/notes.txt")
+        .map(|(_, meta)| meta.offset as usize)
+        .expect("entry packed");
+    bytes[offset] ^= 0xff;
+    let err = manifest.verify(&bytes, 0).expect_err("corrupted entry");
+    assert!(
+        matches!(err, IntegrityError::CorruptEntry(ref msg) if msg.starts_with("res/notes.txt"))
+    );
+}
@@ -1,8 +1,19 @@
-use super::{build_compressed_perro_archive_from_entries, build_perro_assets_archive, should_skip};
+use super::{
+    EntryMetadataOptions, active_codec_rules, build_compressed_perro_archive_from_entries,
+    build_perro_archive_from_entries, build_perro_assets_archive,
+    build_perro_assets_archive_with_compiled, entry_codec, push_archive_key, push_codec_rules,
+    push_entry_metadata, should_skip,
+};
 use crate::archive::PerroAssetsArchive;
-use crate::common::PERRO_ASSETS_COMPRESSED_MAGIC;
+use crate::common::{
+    FLAG_CHUNKED, FLAG_COMPRESSED, FLAG_ENCRYPTED, FLAG_LZ4, FLAG_ZSTD,
+    PERRO_ASSETS_COMPRESSED_MAGIC, read_header, read_index_entry,
+};
+use crate::compression::EntryCodec;
+use perro_asset_formats::archive::{CHUNK_SIZE, VERSION};
 use std::collections::HashSet;
 use std::fs;
+use std::io::{Cursor, Read, Seek, SeekFrom};
 use std::time::Duration;
 
 #[test]
@@ -35,15 +46,20 @@ fn compressed_archive_roundtrips() {
     ));
     let _ = fs::remove_dir_all(&root);
     fs::create_dir_all(&root).expect("test setup/result must succeed");
-    let source = root.join("payload.bin");
     let output = root.join("pack.dlc");
-    fs::write(&source, vec![b'x'; 4096]).expect("test setup/result must succeed");
+    // Entries too small to shrink alone stay raw per entry; only the
+    // whole-archive wrap sees the cross-file redundancy, so it wins.
+    let payload = b"perro-dlc-shared-bytes".to_vec();
+    let entries: Vec<_> = (0..32)
+        .map(|i| {
+            let source = root.join(format!("payload_{i}.bin"));
+            fs::write(&source, &payload).expect("test setup/result must succeed");
+            (format!("res/payload_{i}.bin"), source)
+        })
+        .collect();
 
-    build_compressed_perro_archive_from_entries(
-        &output,
-        &[("res/payload.bin".to_string(), source.clone())],
-    )
-    .expect("test setup/result must succeed");
+    build_compressed_perro_archive_from_entries(&output, &entries)
+        .expect("test setup/result must succeed");
 
     let bytes = fs::read(&output).expect("test setup/result must succeed");
     assert_eq!(&bytes[..4], &PERRO_ASSETS_COMPRESSED_MAGIC);
@@ -53,16 +69,18 @@ fn compressed_archive_roundtrips() {
                 .try_into()
                 .expect("test setup/result must succeed")
         ),
-        1
+        VERSION
     );
     let archive =
         PerroAssetsArchive::open_from_file(&output).expect("test setup/result must succeed");
-    assert_eq!(
-        archive
-            .read_file("res/payload.bin")
-            .expect("test setup/result must succeed"),
-        vec![b'x'; 4096]
-    );
+    for (virtual_path, _) in &entries {
+        assert_eq!(
+            archive
+                .read_file(virtual_path)
+                .expect("test setup/result must succeed"),
+            payload
+        );
+    }
 
     let _ = fs::remove_dir_all(&root);
 }
@@ -134,3 +152,476 @@ fn assets_archive_reuses_prev_compressed_bytes_on_stat_match() {
 
     let _ = fs::remove_dir_all(&root);
 }
+
+#[test]
+fn assets_archive_reuses_bytes_when_only_mtime_moved() {
+    let root = std::env::temp_dir().join(format!("perro_assets_touch_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    let res_dir = root.join("res");
+    fs::create_dir_all(&res_dir).expect("test setup/result must succeed");
+    // `.zip` is stored raw + not skipped, so the source bytes appear verbatim.
+    let source = res_dir.join("bundle.zip");
+    let output = root.join("assets.perro");
+    fs::write(&source, b"pixels-v1").expect("test setup/result must succeed");
+    build_perro_assets_archive(&output, &res_dir, &root, &[])
+        .expect("test setup/result must succeed");
+    let mtime = fs::metadata(&source)
+        .expect("test setup/result must succeed")
+        .modified()
+        .expect("test setup/result must succeed");
+
+    // Mark the previous archive's copy; only a reuse can carry the mark over.
+    let mut prev = fs::read(&output).expect("test setup/result must succeed");
+    let at = prev
+        .windows(9)
+        .position(|w| w == b"pixels-v1")
+        .expect("stored entry");
+    prev[at..at + 9].copy_from_slice(b"PIXELS-v1");
+    fs::write(&output, &prev).expect("test setup/result must succeed");
+
+    set_source_mtime(&source, mtime + Duration::from_secs(5));
+    build_perro_assets_archive(&output, &res_dir, &root, &[])
+        .expect("test setup/result must succeed");
+    let archive =
+        PerroAssetsArchive::open_from_file(&output).expect("test setup/result must succeed");
+    assert_eq!(
+        archive
+            .read_file("res/bundle.zip")
+            .expect("test setup/result must succeed"),
+        b"PIXELS-v1",
+        "same content hash must reuse"
+    );
+
+    // Same len, new bytes: the hash misses and the entry is re-read.
+    fs::write(&source, b"pixels-v2").expect("test setup/result must succeed");
+    set_source_mtime(&source, mtime + Duration::from_secs(10));
+    build_perro_assets_archive(&output, &res_dir, &root, &[])
+        .expect("test setup/result must succeed");
+    let archive =
+        PerroAssetsArchive::open_from_file(&output).expect("test setup/result must succeed");
+    assert_eq!(
+        archive
+            .read_file("res/bundle.zip")
+            .expect("test setup/result must succeed"),
+        b"pixels-v2"
+    );
+
+    let _ = fs::remove_dir_all(&root);
+}
+
+#[test]
+fn assets_archive_is_byte_identical_across_trees() {
+    let root = std::env::temp_dir().join(format!("perro_assets_repro_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    let files: [(&str, &[u8]); 4] = [
+        ("data/levels.txt", b"one two three one two three"),
+        ("data/b.bin", &[7; 300]),
+        ("config.txt", b"vsync = true"),
+        ("zz/last.txt", b""),
+    ];
+    // Same inputs written in opposite order, with different mtimes: the
+    // archive must not depend on directory iteration order or file times.
+    let mut outputs = Vec::new();
+    for (tree, reversed) in [("first", false), ("second", true)] {
+        let res_dir = root.join(tree).join("res");
+        let mut order = files.to_vec();
+        if reversed {
+            order.reverse();
+        }
+        for (rel, bytes) in order {
+            let path = res_dir.join(rel);
+            fs::create_dir_all(path.parent().expect("test setup/result must succeed"))
+                .expect("test setup/result must succeed");
+            fs::write(&path, bytes).expect("test setup/result must succeed");
+            if reversed {
+                set_source_mtime(&path, std::time::UNIX_EPOCH + Duration::from_secs(1));
+            }
+        }
+        let output = root.join(tree).join("assets.perro");
+        build_perro_assets_archive(&output, &res_dir, &root.join(tree), &[])
+            .expect("test setup/result must succeed");
+        outputs.push(fs::read(&output).expect("test setup/result must succeed"));
+    }
+    assert_eq!(outputs[0], outputs[1]);
+
+    let _ = fs::remove_dir_all(&root);
+}
+
+#[test]
+fn assets_archive_packs_compiled_scenes_in_place_of_text() {
+    let root = std::env::temp_dir().join(format!("perro_assets_compiled_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    let res_dir = root.join("res");
+    let compiled_dir = root.join("compiled");
+    fs::create_dir_all(res_dir.join("levels")).expect("test setup/result must succeed");
+    fs::create_dir_all(compiled_dir.join("levels")).expect("test setup/result must succeed");
+    fs::write(res_dir.join("levels/a.scn"), "[a]\n[Node]\n[/Node]\n[/a]\n")
+        .expect("test setup/result must succeed");
+    fs::write(res_dir.join("data.txt"), b"data").expect("test setup/result must succeed");
+    fs::write(compiled_dir.join("levels/a.scnb"), b"PSCNB")
+        .expect("test setup/result must succeed");
+
+    let output = root.join("assets.perro");
+    build_perro_assets_archive_with_compiled(&output, &res_dir, Some(&compiled_dir), &root, &[])
+        .expect("test setup/result must succeed");
+    let archive =
+        PerroAssetsArchive::open_from_file(&output).expect("test setup/result must succeed");
+    assert_eq!(
+        archive
+            .read_file("res/levels/a.scnb")
+            .expect("test setup/result must succeed"),
+        b"PSCNB".to_vec()
+    );
+    assert!(archive.read_file("res/levels/a.scn").is_err());
+    assert!(archive.read_file("res/data.txt").is_ok());
+
+    let _ = fs::remove_dir_all(&root);
+}
+
+#[test]
+fn large_entries_pack_as_chunks_for_range_reads() {
+    let root = std::env::temp_dir().join(format!("perro_assets_chunked_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(&root).expect("test setup/result must succeed");
+    let source = root.join("video.bin");
+    let output = root.join("pack.perro");
+    let payload = (0..700_000u32).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+    fs::write(&source, &payload).expect("test setup/result must succeed");
+    build_perro_archive_from_entries(&output, &[("res/video.bin".to_string(), source)])
+        .expect("test setup/result must succeed");
+
+    let bytes = fs::read(&output).expect("test setup/result must succeed");
+    let mut cursor = Cursor::new(bytes.as_slice());
+    let header = read_header(&mut cursor).expect("test setup/result must succeed");
+    cursor
+        .seek(SeekFrom::Start(header.index_offset))
+        .expect("test setup/result must succeed");
+    let (_, meta) = read_index_entry(&mut cursor).expect("test setup/result must succeed");
+    assert_eq!(meta.flags, FLAG_COMPRESSED | FLAG_CHUNKED);
+
+    let archive =
+        PerroAssetsArchive::open_from_file(&output).expect("test setup/result must succeed");
+    let path = "res/video.bin";
+    let chunk = CHUNK_SIZE as usize;
+    // Straddles the first chunk boundary.
+    assert_eq!(
+        archive
+            .read_range(path, chunk as u64 - 8, 16)
+            .expect("test setup/result must succeed"),
+        payload[chunk - 8..chunk + 8]
+    );
+    assert_eq!(
+        archive
+            .read_range(path, payload.len() as u64 - 4, 100)
+            .expect("test setup/result must succeed"),
+        payload[payload.len() - 4..]
+    );
+    assert!(
+        archive
+            .read_range(path, payload.len() as u64 + 1, 1)
+            .is_err()
+    );
+
+    let mut file = archive
+        .stream_file(path)
+        .expect("test setup/result must succeed");
+    assert_eq!(file.size(), payload.len() as u64);
+    file.seek(SeekFrom::Start(2 * chunk as u64 + 3))
+        .expect("test setup/result must succeed");
+    let mut buf = [0u8; 32];
+    file.read_exact(&mut buf)
+        .expect("test setup/result must succeed");
+    assert_eq!(buf[..], payload[2 * chunk + 3..2 * chunk + 35]);
+    assert_eq!(
+        archive
+            .read_file(path)
+            .expect("test setup/result must succeed"),
+        payload
+    );
+
+    let _ = fs::remove_dir_all(&root);
+}
+
+#[test]
+fn entries_pack_with_their_extension_codec() {
+    let root = std::env::temp_dir().join(format!("perro_assets_codecs_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(&root).expect("test setup/result must succeed");
+    let text = b"[main]\n[Node2D/]\n[/main]\n".repeat(64);
+    let mesh = (0..700_000u32).map(|i| (i % 7) as u8).collect::<Vec<_>>();
+    let files: [(&str, &[u8], u32); 4] = [
+        ("res/levels/a.scnb", &text, FLAG_COMPRESSED | FLAG_ZSTD),
+        (
+            "res/meshes/rock.pmesh",
+            &mesh,
+            FLAG_COMPRESSED | FLAG_LZ4 | FLAG_CHUNKED,
+        ),
+        ("res/icon.png", &[0; 4096], 0),
+        ("res/blob.bin", &[0; 4096], FLAG_COMPRESSED),
+    ];
+    let entries = files
+        .iter()
+        .map(|(path, data, _)| {
+            let source = root.join(path.replace('/', "_"));
+            fs::write(&source, data).expect("test setup/result must succeed");
+            (path.to_string(), source)
+        })
+        .collect::<Vec<_>>();
+    let output = root.join("pack.perro");
+    build_perro_archive_from_entries(&output, &entries).expect("test setup/result must succeed");
+
+    let bytes = fs::read(&output).expect("test setup/result must succeed");
+    let mut cursor = Cursor::new(bytes.as_slice());
+    let header = read_header(&mut cursor).expect("test setup/result must succeed");
+    cursor
+        .seek(SeekFrom::Start(header.index_offset))
+        .expect("test setup/result must succeed");
+    let mut flags = std::collections::HashMap::new();
+    for _ in 0..header.file_count {
+        let (path, meta) = read_index_entry(&mut cursor).expect("test setup/result must succeed");
+        flags.insert(path, meta.flags);
+    }
+    let archive =
+        PerroAssetsArchive::open_from_file(&output).expect("test setup/result must succeed");
+    for (path, data, expected_flags) in files {
+        assert_eq!(flags[path], expected_flags, "{path}");
+        assert_eq!(
+            archive
+                .read_file(path)
+                .expect("test setup/result must succeed"),
+            data,
+            "{path}"
+        );
+    }
+    assert_eq!(
+        archive
+            .read_range("res/meshes/rock.pmesh", CHUNK_SIZE as u64 - 2, 4)
+            .expect("test setup/result must succeed"),
+        mesh[CHUNK_SIZE as usize - 2..CHUNK_SIZE as usize + 2]
+    );
+
+    let _ = fs::remove_dir_all(&root);
+}
+
+#[test]
+fn pushed_codec_rules_override_extension_defaults() {
+    assert_eq!(
+        entry_codec(&active_codec_rules(), "res/chunks/0_0.pdata"),
+        EntryCodec::Zlib
+    );
+    assert_eq!(
+        entry_codec(&active_codec_rules(), "res/meshes/rock.PMESH"),
+        EntryCodec::Lz4
+    );
+    {
+        let _rules = push_codec_rules(vec![(".PDATA".to_string(), EntryCodec::Zstd)]);
+        assert_eq!(
+            entry_codec(&active_codec_rules(), "res/chunks/0_0.pdata"),
+            EntryCodec::Zstd
+        );
+        assert_eq!(
+            entry_codec(&active_codec_rules(), "res/meshes/rock.pmesh"),
+            EntryCodec::Lz4
+        );
+    }
+    assert_eq!(
+        entry_codec(&active_codec_rules(), "res/chunks/0_0.pdata"),
+        EntryCodec::Zlib
+    );
+}
+
+#[test]
+fn sealed_entries_need_the_archive_key() {
+    let root = std::env::temp_dir().join(format!("perro_assets_sealed_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(&root).expect("test setup/result must succeed");
+    let text = b"secret_level_name = \"vault\"\n".repeat(64);
+    let blob = (0..700_000u32).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+    let files: [(&str, &[u8], u32); 3] = [
+        (
+            "res/levels/a.scnb",
+            &text,
+            FLAG_ENCRYPTED | FLAG_COMPRESSED | FLAG_ZSTD,
+        ),
+        (
+            "res/video.bin",
+            &blob,
+            FLAG_ENCRYPTED | FLAG_COMPRESSED | FLAG_CHUNKED,
+        ),
+        ("res/icon.png", b"not really a png", FLAG_ENCRYPTED),
+    ];
+    let entries = files
+        .iter()
+        .map(|(path, data, _)| {
+            let source = root.join(path.replace('/', "_"));
+            fs::write(&source, data).expect("test setup/result must succeed");
+            (path.to_string(), source)
+        })
+        .collect::<Vec<_>>();
+    let output = root.join("pack.perro");
+    let key = [7u8; 32];
+    {
+        let _key = push_archive_key(Some(key));
+        build_compressed_perro_archive_from_entries(&output, &entries)
+            .expect("test setup/result must succeed");
+    }
+
+    let bytes = fs::read(&output).expect("test setup/result must succeed");
+    // Never the whole-archive wrap: that would hold plaintext.
+    assert_ne!(bytes[..4], PERRO_ASSETS_COMPRESSED_MAGIC);
+    assert!(!bytes.windows(16).any(|w| w == b"not really a png"));
+    let mut cursor = Cursor::new(bytes.as_slice());
+    let header = read_header(&mut cursor).expect("test setup/result must succeed");
+    cursor
+        .seek(SeekFrom::Start(header.index_offset))
+        .expect("test setup/result must succeed");
+    let mut flags = std::collections::HashMap::new();
+    for _ in 0..header.file_count {
+        let (path, meta) = read_index_entry(&mut cursor).expect("test setup/result must succeed");
+        flags.insert(path, meta.flags);
+    }
+
+    let archive = PerroAssetsArchive::open_from_file(&output)
+        .expect("test setup/result must succeed")
+        .with_key(&key);
+    for (path, data, expected_flags) in files {
+        assert_eq!(flags[path], expected_flags, "{path}");
+        assert_eq!(
+            archive
+                .read_file(path)
+                .expect("test setup/result must succeed"),
+            data,
+            "{path}"
+        );
+    }
+    assert_eq!(
+        archive
+            .read_range("res/video.bin", CHUNK_SIZE as u64 - 8, 16)
+            .expect("test setup/result must succeed"),
+        blob[CHUNK_SIZE as usize - 8..CHUNK_SIZE as usize + 8]
+    );
+    assert!(archive.get_file_slice("res/icon.png").is_err());
+
+    let keyless =
+        PerroAssetsArchive::open_from_file(&output).expect("test setup/result must succeed");
+    let err = keyless
+        .read_file("res/icon.png")
+        .expect_err("sealed entry must not read w/o a key");
+    assert_eq!(err.kind(), std::io::ErrorKind::PermissionDenied);
+    let wrong = PerroAssetsArchive::open_from_file(&output)
+        .expect("test setup/result must succeed")
+        .with_key(&[8u8; 32]);
+    assert!(wrong.read_file("res/levels/a.scnb").is_err());
+    assert!(wrong.read_range("res/video.bin", 0, 4).is_err());
+
+    let _ = fs::remove_dir_all(&root);
+}
+
+#[test]
+fn verify_reports_the_corrupted_entry_by_path() {
+    let root = std::env::temp_dir().join(format!("perro_assets_verify_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(&root).expect("test setup/result must succeed");
+    let entries = [
+        ("res/a.txt", b"alpha".as_slice()),
+        ("res/b.bin", &[9u8; 3000]),
+    ]
+    .iter()
+    .map(|(path, data)| {
+        let source = root.join(path.replace('/', "_"));
+        fs::write(&source, data).expect("test setup/result must succeed");
+        (path.to_string(), source)
+    })
+    .collect::<Vec<_>>();
+    let output = root.join("pack.perro");
+    build_perro_archive_from_entries(&output, &entries).expect("test setup/result must succeed");
+
+    let mut bytes = fs::read(&output).expect("test setup/result must succeed");
+    PerroAssetsArchive::open_from_owned_bytes(bytes.clone())
+        .expect("test setup/result must succeed")
+        .verify()
+        .expect("fresh archive verifies");
+
+    let mut cursor = Cursor::new(bytes.as_slice());
+    let header = read_header(&mut cursor).expect("test setup/result must succeed");
+    cursor
+        .seek(SeekFrom::Start(header.index_offset))
+        .expect("test setup/result must succeed");
+    let mut offsets = std::collections::HashMap::new();
+    for _ in 0..header.file_count {
+        let (path, meta) = read_index_entry(&mut cursor).expect("test setup/result must succeed");
+        offsets.insert(path, meta.offset as usize);
+    }
+    bytes[offsets["res/b.bin"] + 1] ^= 0xff;
+
+    let archive =
+        PerroAssetsArchive::open_from_owned_bytes(bytes).expect("test setup/result must succeed");
+    archive
+        .verify_file("res/a.txt")
+        .expect("untouched entry still verifies");
+    let err = archive.verify().expect_err("flipped byte must fail verify");
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert!(
+        err.to_string()
+            .starts_with("res/b.bin: content hash mismatch")
+    );
+
+    let _ = fs::remove_dir_all(&root);
+}
+
+#[test]
+fn pushed_entry_metadata_lands_in_the_index() {
+    let root = std::env::temp_dir().join(format!("perro_assets_meta_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(&root).expect("test setup/result must succeed");
+    let source = root.join("hero.png");
+    fs::write(&source, b"pixels").expect("test setup/result must succeed");
+    let mtime = std::time::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    set_source_mtime(&source, mtime);
+    let entries = [("res/hero.png".to_string(), source)];
+    let output = root.join("pack.perro");
+
+    // Default: no mtime, no key/values.
+    build_perro_archive_from_entries(&output, &entries).expect("test setup/result must succeed");
+    let archive =
+        PerroAssetsArchive::open_from_file(&output).expect("test setup/result must succeed");
+    let info = archive.entry_info("res/hero.png").expect("entry info");
+    assert_eq!(info.mtime, 0);
+    assert!(info.metadata.is_empty());
+
+    {
+        let _meta = push_entry_metadata(EntryMetadataOptions {
+            record_mtime: true,
+            values: [(
+                "res/hero.png".to_string(),
+                vec![
+                    ("type".to_string(), "texture".to_string()),
+                    ("import".to_string(), "9f3a".to_string()),
+                ],
+            )]
+            .into(),
+        });
+        build_perro_archive_from_entries(&output, &entries)
+            .expect("test setup/result must succeed");
+    }
+    let archive =
+        PerroAssetsArchive::open_from_file(&output).expect("test setup/result must succeed");
+    let info = archive.entry_info("res/hero.png").expect("entry info");
+    assert_eq!(info.mtime, 1_700_000_000 * 1_000_000_000);
+    assert_eq!(
+        info.metadata,
+        [
+            ("import".to_string(), "9f3a".to_string()),
+            ("type".to_string(), "texture".to_string()),
+        ]
+    );
+    assert_eq!(
+        archive
+            .read_file("res/hero.png")
+            .expect("test setup/result must succeed"),
+        b"pixels"
+    );
+    assert!(archive.entry_info("res/missing.png").is_none());
+
+    let _ = fs::remove_dir_all(&root);
+}
@@ -0,0 +1,125 @@
+use super::{apply_patch, build_patch, read_patch};
+use crate::archive::PerroAssetsArchive;
+use crate::packer::build_perro_archive_from_entries;
+use std::fs;
+use std::io::ErrorKind;
+
+fn sample_archive(tag: &str, files: &[(&str, &[u8])]) -> PerroAssetsArchive {
+    let root =
+        std::env::temp_dir().join(format!("perro_assets_patch_{tag}_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(&root).expect("test setup/result must succeed");
+    let entries = files
+        .iter()
+        .enumerate()
+        .map(|(i, (path, bytes))| {
+            let source = root.join(format!("{i}.bin"));
+            fs::write(&source, bytes).expect("test setup/result must succeed");
+            (path.to_string(), source)
+        })
+        .collect::<Vec<_>>();
+    let output = root.join("assets.perro");
+    build_perro_archive_from_entries(&output, &entries).expect("test setup/result must succeed");
+    let archive =
+        PerroAssetsArchive::open_from_file(&output).expect("test setup/result must succeed");
+    let _ = fs::remove_dir_all(&root);
+    archive
+}
+
+fn v1() -> PerroAssetsArchive {
+    sample_archive(
+        "v1",
+        &[
+            ("res/keep.txt", &[b'k'; 4096]),
+            ("res/change.txt", b"old balance"),
+            ("res/drop.txt", b"gone in v2"),
+        ],
+    )
+}
+
+fn v2() -> PerroAssetsArchive {
+    sample_archive(
+        "v2",
+        &[
+            ("res/keep.txt", &[b'k'; 4096]),
+            ("res/change.txt", b"new balance values"),
+            ("res/added.txt", b"v2 only"),
+        ],
+    )
+}
+
+#[test]
+fn patch_carries_only_changed_entries_and_removals() {
+    let patch = build_patch(&v1(), &v2()).expect("build patch");
+    let parsed = read_patch(&patch).expect("parse patch");
+
+    let mut changed = parsed.entries.keys().cloned().collect::<Vec<_>>();
+    changed.sort();
+    assert_eq!(changed, ["res/added.txt", "res/change.txt"]);
+    assert_eq!(parsed.removed, ["res/drop.txt"]);
+}
+
+#[test]
+fn mounted_patch_reads_like_the_target_archive() {
+    let target = v2();
+    let patched = v1()
+        .with_patch(build_patch(&v1(), &target).expect("build patch"))
+        .expect("mount patch");
+
+    assert_eq!(
+        patched.read_file("res/keep.txt").expect("base entry"),
+        vec![b'k'; 4096]
+    );
+    assert_eq!(
+        patched.read_file("res/change.txt").expect("patched entry"),
+        b"new balance values"
+    );
+    assert_eq!(
+        patched.read_file("res/added.txt").expect("added entry"),
+        b"v2 only"
+    );
+    let err = patched
+        .read_file("res/drop.txt")
+        .expect_err("removed entry");
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+
+    let mut files = patched.list_files();
+    files.sort();
+    assert_eq!(files, ["res/added.txt", "res/change.txt", "res/keep.txt"]);
+    patched.verify().expect("patched archive verifies");
+    assert_eq!(patched.fingerprint(), target.fingerprint());
+}
+
+#[test]
+fn applied_patch_writes_the_target_archive() {
+    let target = v2();
+    let bytes =
+        apply_patch(&v1(), build_patch(&v1(), &target).expect("build patch")).expect("apply patch");
+    let applied = PerroAssetsArchive::open_from_owned_bytes(bytes).expect("open applied");
+
+    applied.verify().expect("applied archive verifies");
+    assert_eq!(applied.fingerprint(), target.fingerprint());
+    assert_eq!(
+        applied.read_file("res/change.txt").expect("patched entry"),
+        b"new balance values"
+    );
+}
+
+#[test]
+fn patch_rejects_other_bases_and_corrupt_bytes() {
+    let patch = build_patch(&v1(), &v2()).expect("build patch");
+
+    let err = v2()
+        .with_patch(patch.clone())
+        .err()
+        .expect("patch built for v1 must not mount on v2");
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    let mut corrupt = patch;
+    corrupt[super::PATCH_HEADER_LEN] ^= 0xff;
+    let err = v1()
+        .with_patch(corrupt)
+        .err()
+        .expect("corrupt patch entry must not mount");
+    assert!(err.to_string().contains("content hash mismatch"), "{err}");
+}
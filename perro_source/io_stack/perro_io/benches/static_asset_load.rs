@@ -2,7 +2,7 @@ use criterion::{Criterion, black_box, criterion_group, criterion_main};
 use perro_io::asset_io::{ProjectRoot, StaticResourceLookups, load_asset, set_project_root};
 
 const EMPTY_ARCHIVE: &[u8] = &[
-    b'P', b'R', b'A', b'1', 1, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0,
+    b'P', b'R', b'A', b'1', 3, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0,
 ];
 
 const TEXTURE_PATH: &str = "res://textures/bench.png";
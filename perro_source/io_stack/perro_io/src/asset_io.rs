@@ -5,11 +5,13 @@ use std::{
     io::{self, Read, Seek, Write},
     path::{Component, Path, PathBuf},
     sync::{Arc, LazyLock, RwLock},
+    time::SystemTime,
 };
 
 #[cfg(not(target_arch = "wasm32"))]
 use crate::data_local_dir;
 use perro_assets::archive::{PerroAssetsArchive, PerroAssetsFile};
+use perro_assets::crypto::{ARCHIVE_KEY_LEN, ArchiveKey};
 
 pub type StaticBytesLookup = fn(u64) -> &'static [u8];
 pub type StaticShaderLookup = fn(u64) -> &'static str;
@@ -57,12 +59,21 @@ static PROJECT_ASSET_STATE: RwLock<ProjectAssetState> = RwLock::new(ProjectAsset
     demo: false,
     demo_excludes: Vec::new(),
 });
+/// Opens encrypted entries of the project archive + DLC archives installed
+/// after it is registered.
+static ARCHIVE_KEY: RwLock<Option<ArchiveKey>> = RwLock::new(None);
 static DLC_MOUNTS: LazyLock<RwLock<HashMap<String, DlcMount>>> =
     LazyLock::new(|| RwLock::new(HashMap::new()));
 static DLC_ARCHIVES: LazyLock<RwLock<HashMap<String, Arc<PerroAssetsArchive>>>> =
     LazyLock::new(|| RwLock::new(HashMap::new()));
 static DLC_STATIC_BINARY_LOOKUPS: LazyLock<RwLock<HashMap<String, DlcStaticBinaryLookup>>> =
     LazyLock::new(|| RwLock::new(HashMap::new()));
+/// `mount_archive` stack, lowest priority first; `res://` reads check it
+/// top-down b4 the project root.
+static ARCHIVE_MOUNTS: RwLock<Vec<ArchiveMount>> = RwLock::new(Vec::new());
+/// Virtual path -> disk mtime at the last check, for dev hot reload.
+static WATCHED_ASSETS: LazyLock<RwLock<HashMap<String, Option<SystemTime>>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
 
 thread_local! {
     static DLC_SELF_CONTEXT: RefCell<Option<String>> = const { RefCell::new(None) };
@@ -85,6 +96,11 @@ pub struct DlcMount {
     pub source: DlcMountSource,
 }
 
+struct ArchiveMount {
+    name: String,
+    archive: Arc<PerroAssetsArchive>,
+}
+
 pub fn is_reserved_dlc_name(name: &str) -> bool {
     name.eq_ignore_ascii_case("self")
 }
@@ -117,12 +133,32 @@ pub fn get_project_root() -> ProjectRoot {
         .expect("Project root not set")
 }
 
+/// Register the key encrypted archives were packed with; call b4
+/// [`try_set_project_root`] / [`mount_dlc_archive`].
+pub fn register_archive_key(key: &[u8]) -> io::Result<()> {
+    let key = ArchiveKey::try_from(key).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("archive key must be {ARCHIVE_KEY_LEN} bytes"),
+        )
+    })?;
+    *ARCHIVE_KEY.write().expect("required value must be present") = Some(key);
+    Ok(())
+}
+
+fn with_archive_key(archive: PerroAssetsArchive) -> PerroAssetsArchive {
+    match *ARCHIVE_KEY.read().expect("required value must be present") {
+        Some(key) => archive.with_key(&key),
+        None => archive,
+    }
+}
+
 /// Parse and atomically install a project root and its archive backing.
 pub fn try_set_project_root(root: ProjectRoot) -> io::Result<()> {
     let archive = match &root {
-        ProjectRoot::PerroAssets { data, .. } => {
-            Some(Arc::new(PerroAssetsArchive::open_from_bytes(data)?))
-        }
+        ProjectRoot::PerroAssets { data, .. } => Some(Arc::new(with_archive_key(
+            PerroAssetsArchive::open_from_bytes(data)?,
+        ))),
         ProjectRoot::Disk { .. } => None,
     };
 
@@ -134,6 +170,24 @@ pub fn try_set_project_root(root: ProjectRoot) -> io::Result<()> {
     Ok(())
 }
 
+/// Overlay a delta patch (`.perropatch`) on the installed project archive,
+/// so a shipped game picks up an update w/o rewriting its embedded assets.
+/// Call after [`try_set_project_root`]; disk roots have nothing to patch.
+pub fn apply_project_archive_patch(patch_path: impl AsRef<Path>) -> io::Result<()> {
+    let patch = fs::read(patch_path)?;
+    let mut state = PROJECT_ASSET_STATE
+        .write()
+        .expect("required value must be present");
+    let archive = state.archive.as_ref().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            "project root has no archive to patch",
+        )
+    })?;
+    state.archive = Some(Arc::new(archive.with_patch(patch)?));
+    Ok(())
+}
+
 /// Install a project root, panicking when an embedded archive is invalid.
 ///
 /// Use [`try_set_project_root`] for untrusted or fallible startup paths.
@@ -169,6 +223,30 @@ pub fn clear_dlc_mounts() {
     lookups.clear();
 }
 
+/// Approx heap bytes held by the loaded project archive + mounted DLC
+/// archives. Disk mounts hold no archive data and count as zero.
+pub fn loaded_archive_bytes() -> usize {
+    let project = PROJECT_ASSET_STATE
+        .read()
+        .expect("required value must be present")
+        .archive
+        .as_ref()
+        .map_or(0, |archive| archive.resident_bytes());
+    let dlc = DLC_ARCHIVES
+        .read()
+        .expect("required value must be present")
+        .values()
+        .map(|archive| archive.resident_bytes())
+        .sum::<usize>();
+    let mounted = ARCHIVE_MOUNTS
+        .read()
+        .expect("required value must be present")
+        .iter()
+        .map(|mount| mount.archive.resident_bytes())
+        .sum::<usize>();
+    project + dlc + mounted
+}
+
 pub fn mounted_dlc_names() -> Vec<String> {
     let mut out = DLC_MOUNTS
         .read()
@@ -219,9 +297,33 @@ pub fn mount_dlc_disk(name: &str, root: impl AsRef<Path>) -> io::Result<()> {
 }
 
 pub fn mount_dlc_archive(name: &str, archive_path: impl AsRef<Path>) -> io::Result<()> {
+    mount_dlc_archive_inner(name, archive_path.as_ref(), None)
+}
+
+/// [`mount_dlc_archive`] w/ a delta patch overlaid on the installed pack.
+pub fn mount_dlc_archive_patched(
+    name: &str,
+    archive_path: impl AsRef<Path>,
+    patch_path: impl AsRef<Path>,
+) -> io::Result<()> {
+    mount_dlc_archive_inner(name, archive_path.as_ref(), Some(patch_path.as_ref()))
+}
+
+fn mount_dlc_archive_inner(
+    name: &str,
+    archive_path: &Path,
+    patch_path: Option<&Path>,
+) -> io::Result<()> {
     validate_dlc_name(name)?;
-    let archive_path = archive_path.as_ref().to_path_buf();
-    let archive = Arc::new(PerroAssetsArchive::open_from_file(&archive_path)?);
+    let archive_path = archive_path.to_path_buf();
+    let mut archive = with_archive_key(PerroAssetsArchive::open_from_file(&archive_path)?);
+    if let Some(patch_path) = patch_path {
+        archive = archive.with_patch(fs::read(patch_path)?)?;
+    }
+    // Installed packs come from downloads; fail the mount on a bad entry
+    // rather than on the first load that touches it.
+    archive.verify()?;
+    let archive = Arc::new(archive);
     replace_dlc_mount(
         name,
         DlcMount {
@@ -250,6 +352,91 @@ fn replace_dlc_mount(name: &str, mount: DlcMount, archive: Option<Arc<PerroAsset
     mounts.insert(key, mount);
 }
 
+/// Mount a `.perro` archive (base content, DLC, a mod) over `res://`. Later
+/// mounts override earlier ones, and all of them the project root, for every
+/// path they contain; other paths fall through. Remounting `name` replaces
+/// that archive and moves it to the top.
+pub fn mount_archive(name: &str, archive_path: impl AsRef<Path>) -> io::Result<()> {
+    if name.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "archive mount name is empty",
+        ));
+    }
+    let archive = with_archive_key(PerroAssetsArchive::open_from_file(archive_path.as_ref())?);
+    archive.verify()?;
+    let key = name.to_ascii_lowercase();
+    let mut mounts = ARCHIVE_MOUNTS
+        .write()
+        .expect("required value must be present");
+    mounts.retain(|mount| mount.name != key);
+    mounts.push(ArchiveMount {
+        name: key,
+        archive: Arc::new(archive),
+    });
+    Ok(())
+}
+
+/// Drop a [`mount_archive`] mount; paths it overrode resolve to the next
+/// mount down again. `false` when `name` is not mounted.
+pub fn unmount_archive(name: &str) -> bool {
+    let key = name.to_ascii_lowercase();
+    let mut mounts = ARCHIVE_MOUNTS
+        .write()
+        .expect("required value must be present");
+    let before = mounts.len();
+    mounts.retain(|mount| mount.name != key);
+    mounts.len() != before
+}
+
+/// [`mount_archive`] names, lowest priority first.
+pub fn mounted_archive_names() -> Vec<String> {
+    ARCHIVE_MOUNTS
+        .read()
+        .expect("required value must be present")
+        .iter()
+        .map(|mount| mount.name.clone())
+        .collect()
+}
+
+pub fn clear_archive_mounts() {
+    ARCHIVE_MOUNTS
+        .write()
+        .expect("required value must be present")
+        .clear();
+}
+
+/// Topmost mount holding `res/<relative>`, as (mount, virtual path).
+fn mounted_archive_entry(relative: &str) -> Option<(String, String)> {
+    let mounts = ARCHIVE_MOUNTS
+        .read()
+        .expect("required value must be present");
+    if mounts.is_empty() {
+        return None;
+    }
+    let virtual_path = format!("res/{relative}");
+    mounts
+        .iter()
+        .rev()
+        .find(|mount| mount.archive.contains(&virtual_path))
+        .map(|mount| (mount.name.clone(), virtual_path))
+}
+
+fn mounted_archive(name: &str) -> io::Result<Arc<PerroAssetsArchive>> {
+    ARCHIVE_MOUNTS
+        .read()
+        .expect("required value must be present")
+        .iter()
+        .find(|mount| mount.name == name)
+        .map(|mount| Arc::clone(&mount.archive))
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("archive mount not found: {name}"),
+            )
+        })
+}
+
 /// Register a DLC callback that returns borrowed binary asset bytes.
 ///
 /// # Safety
@@ -297,6 +484,7 @@ pub enum ResolvedPath {
     StaticBinary(String),
     DlcStaticBinary { dlc: String, path: String },
     DlcPerroAssets { dlc: String, virtual_path: String },
+    MountedArchive { mount: String, virtual_path: String },
 }
 
 fn normalize_user_app_name(name: &str) -> String {
@@ -461,6 +649,15 @@ pub fn resolve_path(path: &str) -> ResolvedPath {
         return ResolvedPath::Disk(path_buf);
     }
 
+    if let Some(stripped) = path.strip_prefix("res://")
+        && let Some((mount, virtual_path)) = mounted_archive_entry(stripped)
+    {
+        return ResolvedPath::MountedArchive {
+            mount,
+            virtual_path,
+        };
+    }
+
     match project_root_opt {
         Some(ProjectRoot::Disk { root, .. }) => {
             if let Some(stripped) = path.strip_prefix("res://") {
@@ -529,6 +726,10 @@ pub fn load_asset(path: &str) -> io::Result<Vec<u8>> {
                 ))
             }
         }
+        ResolvedPath::MountedArchive {
+            mount,
+            virtual_path,
+        } => mounted_archive(&mount)?.read_file(&virtual_path),
     }
 }
 
@@ -581,6 +782,13 @@ pub fn stream_asset(path: &str) -> io::Result<Box<dyn ReadSeek>> {
                 ))
             }
         }
+        ResolvedPath::MountedArchive {
+            mount,
+            virtual_path,
+        } => {
+            let file: PerroAssetsFile = mounted_archive(&mount)?.stream_file(&virtual_path)?;
+            Ok(Box::new(file))
+        }
     }
 }
 
@@ -603,12 +811,79 @@ pub fn save_asset(path: &str, data: &[u8]) -> io::Result<()> {
         ResolvedPath::PerroAssets(_)
         | ResolvedPath::StaticBinary(_)
         | ResolvedPath::DlcStaticBinary { .. }
-        | ResolvedPath::DlcPerroAssets { .. } => {
+        | ResolvedPath::DlcPerroAssets { .. }
+        | ResolvedPath::MountedArchive { .. } => {
             Err(io::Error::other("Cannot save to packed archive"))
         }
     }
 }
 
+/// Save an asset through a sibling `.tmp` file + rename, so readers see either
+/// the old bytes or the new ones, never a half-written file. Web user storage
+/// writes one key at a time already and goes through [`save_web_user_asset`].
+pub fn save_asset_atomic(path: &str, data: &[u8]) -> io::Result<()> {
+    validate_virtual_asset_path(path)?;
+    match resolve_path(path) {
+        ResolvedPath::Disk(pb) => {
+            if let Some(parent) = pb.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut tmp = pb.clone().into_os_string();
+            tmp.push(".tmp");
+            let tmp = PathBuf::from(tmp);
+            let written = File::create(&tmp).and_then(|mut file| {
+                file.write_all(data)?;
+                file.sync_all()
+            });
+            if let Err(err) = written.and_then(|()| fs::rename(&tmp, &pb)) {
+                let _ = fs::remove_file(&tmp);
+                return Err(err);
+            }
+            Ok(())
+        }
+        _ => save_asset(path, data),
+    }
+}
+
+/// Track a disk-backed asset so [`poll_changed_assets`] reports later edits.
+/// Packed, static, and web assets never change at runtime and are skipped.
+pub fn watch_asset(path: &str) {
+    let ResolvedPath::Disk(file) = resolve_path(path) else {
+        return;
+    };
+    let modified = disk_mtime(&file);
+    WATCHED_ASSETS
+        .write()
+        .expect("required value must be present")
+        .entry(path.to_string())
+        .or_insert(modified);
+}
+
+/// Watched paths whose file changed since the last poll, sorted. Each edit
+/// is reported once.
+pub fn poll_changed_assets() -> Vec<String> {
+    let mut watched = WATCHED_ASSETS
+        .write()
+        .expect("required value must be present");
+    let mut changed = Vec::new();
+    for (path, modified) in watched.iter_mut() {
+        let ResolvedPath::Disk(file) = resolve_path(path) else {
+            continue;
+        };
+        let current = disk_mtime(&file);
+        if current != *modified {
+            *modified = current;
+            changed.push(path.clone());
+        }
+    }
+    changed.sort();
+    changed
+}
+
+fn disk_mtime(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
 #[cfg(target_arch = "wasm32")]
 fn load_web_user_asset(key: &str) -> io::Result<Vec<u8>> {
     perro_web::storage::load_local_bytes(key)?.ok_or_else(|| {
@@ -811,7 +1086,7 @@ mod tests {
     }
 
     static EMPTY_ARCHIVE: &[u8] = &[
-        b'P', b'R', b'A', b'1', 1, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0,
+        b'P', b'R', b'A', b'1', 3, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0,
     ];
     static TEST_LOCK: LazyLock<std::sync::Mutex<()>> = LazyLock::new(|| std::sync::Mutex::new(()));
 
@@ -923,6 +1198,29 @@ mod tests {
         let _ = fs::remove_dir_all(disk);
     }
 
+    #[test]
+    fn atomic_save_replaces_file_without_leaving_tmp() {
+        let _guard = TEST_LOCK.lock().expect("required value must be present");
+        let disk =
+            std::env::temp_dir().join(format!("perro_io_atomic_save_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&disk);
+        try_set_project_root(ProjectRoot::Disk {
+            root: disk.clone(),
+            name: "Atomic Root".to_string(),
+        })
+        .expect("required value must be present");
+
+        save_asset_atomic("res://saves/slot.json", b"first").expect("first save");
+        save_asset_atomic("res://saves/slot.json", b"second").expect("second save");
+
+        assert_eq!(
+            load_asset("res://saves/slot.json").expect("required value must be present"),
+            b"second"
+        );
+        assert!(!disk.join("res/saves/slot.json.tmp").exists());
+        let _ = fs::remove_dir_all(disk);
+    }
+
     #[test]
     fn resolve_user_path_normalizes_game_name_spaces() {
         let _guard = TEST_LOCK.lock().expect("required value must be present");
@@ -1280,4 +1578,98 @@ mod tests {
         clear_dlc_mounts();
         let _ = fs::remove_dir_all(&root);
     }
+
+    fn write_mount_archive(root: &Path, name: &str, files: &[(&str, &[u8])]) -> PathBuf {
+        let entries = files
+            .iter()
+            .map(|(path, bytes)| {
+                let source = root.join(format!("{name}_{}", path.replace('/', "_")));
+                fs::write(&source, bytes).expect("required value must be present");
+                (format!("res/{path}"), source)
+            })
+            .collect::<Vec<_>>();
+        let archive = root.join(format!("{name}.perro"));
+        perro_assets::packer::build_perro_archive_from_entries(&archive, &entries)
+            .expect("required value must be present");
+        archive
+    }
+
+    #[test]
+    fn later_archive_mounts_override_earlier_paths() {
+        let _guard = TEST_LOCK.lock().expect("required value must be present");
+        let root =
+            std::env::temp_dir().join(format!("perro_io_archive_mounts_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).expect("required value must be present");
+        try_set_project_root(ProjectRoot::PerroAssets {
+            data: test_archive("mount-base", b"base config"),
+            name: "Mount Root".to_string(),
+            static_resource_lookups: StaticResourceLookups::default(),
+        })
+        .expect("required value must be present");
+        let dlc = write_mount_archive(
+            &root,
+            "dlc",
+            &[("config.txt", b"dlc config"), ("dlc.txt", b"dlc only")],
+        );
+        let modded = write_mount_archive(&root, "mod", &[("config.txt", b"mod config")]);
+
+        clear_archive_mounts();
+        mount_archive("DLC", &dlc).expect("mount dlc");
+        mount_archive("Mod", &modded).expect("mount mod");
+        assert_eq!(mounted_archive_names(), ["dlc", "mod"]);
+        assert_eq!(
+            load_asset("res://config.txt").expect("required value must be present"),
+            b"mod config"
+        );
+        assert_eq!(
+            load_asset("res://dlc.txt").expect("required value must be present"),
+            b"dlc only"
+        );
+
+        // remount moves to the top
+        mount_archive("dlc", &dlc).expect("remount dlc");
+        assert_eq!(mounted_archive_names(), ["mod", "dlc"]);
+        assert_eq!(
+            load_asset("res://config.txt").expect("required value must be present"),
+            b"dlc config"
+        );
+
+        assert!(unmount_archive("DLC"));
+        assert!(!unmount_archive("dlc"));
+        assert_eq!(
+            load_asset("res://config.txt").expect("required value must be present"),
+            b"mod config"
+        );
+        assert!(load_asset("res://dlc.txt").is_err());
+        assert!(unmount_archive("mod"));
+        assert_eq!(
+            load_asset("res://config.txt").expect("required value must be present"),
+            b"base config"
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn poll_changed_assets_reports_each_edit_once() {
+        let dir = std::env::temp_dir().join(format!("perro_io_watch_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).expect("required value must be present");
+        let file = dir.join("level.scn");
+        fs::write(&file, "[root]\n[/root]\n").expect("required value must be present");
+        let path = file.to_string_lossy().to_string();
+
+        watch_asset(&path);
+        assert!(!poll_changed_assets().contains(&path));
+
+        File::options()
+            .write(true)
+            .open(&file)
+            .and_then(|f| f.set_modified(SystemTime::now() + std::time::Duration::from_secs(60)))
+            .expect("required value must be present");
+        assert!(poll_changed_assets().contains(&path));
+        assert!(!poll_changed_assets().contains(&path));
+        let _ = fs::remove_dir_all(&dir);
+    }
 }
@@ -1,5 +1,6 @@
 pub mod asset_io;
 pub mod dirs;
+pub mod missing;
 pub mod walkdir;
 pub mod zip;
 
@@ -9,5 +10,6 @@ pub use perro_assets::compression;
 pub use asset_io::*;
 pub use compression::*;
 pub use dirs::*;
+pub use missing::*;
 pub use walkdir::*;
 pub use zip::*;
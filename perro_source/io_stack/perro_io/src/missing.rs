@@ -0,0 +1,44 @@
+//! Once-per-path reporting for assets swapped for placeholders.
+//!
+//! Texture, mesh, and scene loaders substitute a visible stand-in when a path
+//! does not resolve. They report through here so a missing file referenced by
+//! every frame or every instance logs one line, not thousands.
+
+use perro_ids::string_to_u64;
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+fn reported() -> &'static Mutex<HashSet<(u64, u64)>> {
+    static REPORTED: OnceLock<Mutex<HashSet<(u64, u64)>>> = OnceLock::new();
+    REPORTED.get_or_init(|| Mutex::new(HashSet::new()))
+}
+
+/// Marks `path` of `kind` as missing. Returns `true` the first time only.
+pub fn note_missing_asset(kind: &str, path: &str) -> bool {
+    let key = (string_to_u64(kind), string_to_u64(path));
+    match reported().lock() {
+        Ok(mut reported) => reported.insert(key),
+        Err(poisoned) => poisoned.into_inner().insert(key),
+    }
+}
+
+/// Logs that `path` failed to load and a placeholder is used, once per path.
+pub fn report_missing_asset(kind: &str, path: &str, reason: &str) {
+    if note_missing_asset(kind, path) {
+        eprintln!("[perro][assets] missing {kind} `{path}`: {reason}; using placeholder");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::note_missing_asset;
+
+    #[test]
+    fn missing_asset_is_noted_once_per_kind_and_path() {
+        let path = "res://missing/noted_once.png";
+        assert!(note_missing_asset("texture", path));
+        assert!(!note_missing_asset("texture", path));
+        assert!(note_missing_asset("mesh", path));
+        assert!(note_missing_asset("texture", "res://missing/other.png"));
+    }
+}
@@ -0,0 +1,241 @@
+//! Crash capture for exported desktop builds: panics and native faults
+//! (segfaults, access violations from native code or GPU drivers).
+//!
+//! Native faults can't be dumped reliably from inside the dying process, so
+//! the game relaunches its own exe as a tiny monitor (`PERRO_CRASH_MONITOR`
+//! set) and talks to it over a local socket. On a fault the game sends the
+//! render trail, then asks the monitor to write a minidump.
+//!
+//! Everything lands in `<local data>/<Game_Name>/logs/`:
+//! - `errors.log`: panics + one line per native crash
+//! - `crash-<unix secs>.dmp`: minidump (open with any minidump tool)
+//! - `crash-<unix secs>.render.txt`: last render commands submitted
+//!
+//! `PERRO_CRASH_HANDLER=0` turns capture off (debuggers, sanitizers).
+
+use crate::render_trail::{enable_render_trail, with_render_trail_text};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+use std::sync::atomic::AtomicBool;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const MONITOR_ENV: &str = "PERRO_CRASH_MONITOR";
+const DISABLE_ENV: &str = "PERRO_CRASH_HANDLER";
+const ERRORS_LOG: &str = "errors.log";
+const RENDER_TRAIL_MESSAGE: u32 = 1;
+const MONITOR_CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Keeps the handler attached and the monitor alive for the game's lifetime.
+pub(crate) struct CrashReporter {
+    _handler: crash_handler::CrashHandler,
+    monitor: Child,
+}
+
+impl Drop for CrashReporter {
+    fn drop(&mut self) {
+        // Clean exit: the monitor would also leave on disconnect, don't wait on it.
+        let _ = self.monitor.kill();
+        let _ = self.monitor.wait();
+    }
+}
+
+/// Call first thing in an exported build. In the monitor child this never
+/// returns; in the game it returns a guard, or `None` if capture is off or
+/// could not start (the game still runs, just without minidumps).
+pub(crate) fn install_crash_reporter(project_name: &str) -> Option<CrashReporter> {
+    let log_dir = crash_log_dir(project_name);
+    if let Ok(socket) = std::env::var(MONITOR_ENV) {
+        run_monitor(&socket, &log_dir);
+        std::process::exit(0);
+    }
+    if std::env::var(DISABLE_ENV).is_ok_and(|raw| matches!(raw.trim(), "0" | "false" | "off")) {
+        return None;
+    }
+    if let Err(err) = fs::create_dir_all(&log_dir) {
+        eprintln!(
+            "[perro][crash] log dir {} unavailable: {err}",
+            log_dir.display()
+        );
+        return None;
+    }
+    install_panic_log(log_dir.join(ERRORS_LOG));
+    match attach_native_handler() {
+        Ok(reporter) => {
+            enable_render_trail();
+            Some(reporter)
+        }
+        Err(err) => {
+            eprintln!("[perro][crash] native crash capture off: {err}");
+            None
+        }
+    }
+}
+
+pub(crate) fn crash_log_dir(project_name: &str) -> PathBuf {
+    perro_io::data_local_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join(project_name.replace(' ', "_"))
+        .join("logs")
+}
+
+fn attach_native_handler() -> Result<CrashReporter, String> {
+    let exe = std::env::current_exe().map_err(|err| format!("current exe: {err}"))?;
+    let socket = format!("perro-crash-{}", std::process::id());
+    let mut monitor = Command::new(exe)
+        .env(MONITOR_ENV, &socket)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .spawn()
+        .map_err(|err| format!("spawn monitor: {err}"))?;
+
+    let started = Instant::now();
+    let client = loop {
+        match minidumper::Client::with_name(socket.as_str()) {
+            Ok(client) => break client,
+            Err(err) if started.elapsed() >= MONITOR_CONNECT_TIMEOUT => {
+                let _ = monitor.kill();
+                return Err(format!("connect monitor: {err}"));
+            }
+            Err(_) => std::thread::sleep(Duration::from_millis(20)),
+        }
+    };
+
+    // SAFETY: The callback runs in a signal/exception context. It only sends
+    // two IPC messages; the trail is formatted into a static buffer (no heap,
+    // so a fault inside the allocator can't deadlock it) under `try_lock`, so
+    // a thread that died holding either lock can't deadlock the handler.
+    let handler = crash_handler::CrashHandler::attach(unsafe {
+        crash_handler::make_crash_event(move |context: &crash_handler::CrashContext| {
+            with_render_trail_text(|trail| {
+                let _ = client.send_message(RENDER_TRAIL_MESSAGE, trail);
+            });
+            crash_handler::CrashEventResult::Handled(client.request_dump(context).is_ok())
+        })
+    });
+    match handler {
+        Ok(handler) => Ok(CrashReporter {
+            _handler: handler,
+            monitor,
+        }),
+        Err(err) => {
+            let _ = monitor.kill();
+            Err(format!("attach handler: {err}"))
+        }
+    }
+}
+
+fn install_panic_log(errors_log: PathBuf) {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        append_errors_log(
+            &errors_log,
+            &format!(
+                "panic: {info}\nbacktrace:\n{}",
+                std::backtrace::Backtrace::force_capture()
+            ),
+        );
+        previous(info);
+    }));
+}
+
+fn append_errors_log(path: &Path, entry: &str) {
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) {
+        let _ = writeln!(file, "[{}] {entry}", unix_secs());
+    }
+}
+
+fn unix_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+fn run_monitor(socket: &str, log_dir: &Path) {
+    let Ok(mut server) = minidumper::Server::with_name(socket) else {
+        return;
+    };
+    let _ = fs::create_dir_all(log_dir);
+    let handler = MonitorHandler {
+        log_dir: log_dir.to_path_buf(),
+        stamp: Mutex::new(None),
+    };
+    let shutdown = AtomicBool::new(false);
+    let _ = server.run(Box::new(handler), &shutdown, None);
+}
+
+struct MonitorHandler {
+    log_dir: PathBuf,
+    // Trail message and dump share one stamp so the pair is easy to match.
+    stamp: Mutex<Option<u64>>,
+}
+
+impl MonitorHandler {
+    fn stamp(&self) -> u64 {
+        let mut stamp = self.stamp.lock().unwrap_or_else(|err| err.into_inner());
+        *stamp.get_or_insert_with(unix_secs)
+    }
+}
+
+impl minidumper::ServerHandler for MonitorHandler {
+    fn create_minidump_file(&self) -> Result<(File, PathBuf), std::io::Error> {
+        let path = self.log_dir.join(format!("crash-{}.dmp", self.stamp()));
+        Ok((File::create(&path)?, path))
+    }
+
+    fn on_minidump_created(
+        &self,
+        result: Result<minidumper::MinidumpBinary, minidumper::Error>,
+    ) -> minidumper::LoopAction {
+        let entry = match result {
+            Ok(dump) => format!("native crash: minidump {}", dump.path.display()),
+            Err(err) => format!("native crash: minidump failed: {err}"),
+        };
+        append_errors_log(&self.log_dir.join(ERRORS_LOG), &entry);
+        minidumper::LoopAction::Exit
+    }
+
+    fn on_message(&self, kind: u32, buffer: Vec<u8>) {
+        if kind == RENDER_TRAIL_MESSAGE {
+            let path = self
+                .log_dir
+                .join(format!("crash-{}.render.txt", self.stamp()));
+            let _ = fs::write(path, buffer);
+        }
+    }
+
+    fn on_client_disconnected(&self, num_clients: usize) -> minidumper::LoopAction {
+        if num_clients == 0 {
+            minidumper::LoopAction::Exit
+        } else {
+            minidumper::LoopAction::Continue
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crash_logs_live_under_game_local_data() {
+        let dir = crash_log_dir("Dog Game");
+        assert!(dir.ends_with(Path::new("Dog_Game").join("logs")));
+    }
+
+    #[test]
+    fn errors_log_appends_entries() {
+        let dir = std::env::temp_dir().join(format!("perro_crash_log_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).expect("test setup/result must succeed");
+        let log = dir.join(ERRORS_LOG);
+        append_errors_log(&log, "first");
+        append_errors_log(&log, "second");
+        let text = fs::read_to_string(&log).expect("test setup/result must succeed");
+        assert!(text.contains("] first\n"));
+        assert!(text.contains("] second\n"));
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -1,11 +1,14 @@
 use crate::App;
+use crate::integrity_check;
 #[cfg(not(target_arch = "wasm32"))]
 use crate::winit_runner::image_helpers::{preload_project_images, spawn_preload_project_images};
 use crate::winit_runner::{AppExitError, AppExitResult, WinitRunner};
 use perro_graphics::{
     GraphicsBackend, OcclusionCullingMode, PerroGraphics, SsaoQuality as GraphicsSsaoQuality,
 };
-pub use perro_runtime::{FrameRateCap, OcclusionCulling, ParticleSimDefault};
+pub use perro_runtime::{
+    BuildInfo, BuildProfile, FrameRateCap, OcclusionCulling, ParticleSimDefault,
+};
 use perro_runtime::{ProjectLoadError, ProviderMode, Runtime, RuntimeProject, WindowRequest};
 use perro_scripting::ScriptConstructor;
 use std::path::Path;
@@ -40,6 +43,7 @@ fn clear_steam_fossilize_application_filter(_steam_enabled: bool) {}
 pub enum RunProjectError {
     Load(ProjectLoadError),
     Exit(AppExitError),
+    Integrity(perro_assets::integrity::IntegrityError),
 }
 
 impl std::fmt::Display for RunProjectError {
@@ -47,6 +51,7 @@ impl std::fmt::Display for RunProjectError {
         match self {
             Self::Load(err) => write!(f, "{err}"),
             Self::Exit(err) => write!(f, "{err}"),
+            Self::Integrity(err) => write!(f, "{err}"),
         }
     }
 }
@@ -103,6 +108,13 @@ fn static_embedded_input_map(
                 .iter()
                 .copied()
                 .map(perro_input_api::InputBinding::Key)
+                .chain(
+                    action
+                        .chords
+                        .iter()
+                        .copied()
+                        .map(perro_input_api::InputBinding::Chord),
+                )
                 .chain(
                     action
                         .mouse
@@ -126,6 +138,8 @@ fn static_embedded_input_map(
                 )
                 .collect();
             perro_input_api::InputAction::new(action.name, bindings)
+                .with_context(action.context)
+                .with_consume(action.consume)
         })
         .collect();
     perro_input_api::InputMap::from_actions(actions)
@@ -163,7 +177,7 @@ fn graphics_from_project_config(
     release_mode: bool,
 ) -> PerroGraphics {
     let occlusion_culling = effective_occlusion_culling(config.occlusion_culling);
-    PerroGraphics::new()
+    let graphics = PerroGraphics::new()
         .with_vsync(config.vsync)
         .with_hdr_mode(config.hdr)
         .with_msaa(effective_msaa(config.msaa))
@@ -177,7 +191,12 @@ fn graphics_from_project_config(
             OcclusionCulling::Cpu => OcclusionCullingMode::Cpu,
             OcclusionCulling::Gpu => OcclusionCullingMode::Gpu,
             OcclusionCulling::Off => OcclusionCullingMode::Off,
-        })
+        });
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(recorder) = crate::movie_mode::movie_recorder_from_env() {
+        return graphics.with_movie_recorder(recorder);
+    }
+    graphics
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -254,7 +273,8 @@ pub fn run_dev_project_from_path(
         "perro dev runner: load project {}",
         project_root.to_string_lossy()
     );
-    let project = RuntimeProject::from_project_dir_with_default_name(project_root, default_name)?;
+    let project = RuntimeProject::from_project_dir_with_default_name(project_root, default_name)?
+        .with_scene_override_from_env()?;
     clear_steam_fossilize_application_filter(project.config.steam.enabled);
     let _ = perro_web::init_router();
     let preload = spawn_preload_project_images(project.clone());
@@ -281,7 +301,8 @@ pub fn run_headless_dev_project_from_path(
     project_root: &Path,
     default_name: &str,
 ) -> Result<(), RunProjectError> {
-    let project = RuntimeProject::from_project_dir_with_default_name(project_root, default_name)?;
+    let project = RuntimeProject::from_project_dir_with_default_name(project_root, default_name)?
+        .with_scene_override_from_env()?;
     run_headless_runtime(create_dev_runtime(project));
     Ok(())
 }
@@ -297,6 +318,10 @@ fn run_headless_runtime(mut runtime: Runtime) {
         .filter(|fps| *fps > 0.0)
         .map(|fps| 1.0 / fps)
         .unwrap_or(1.0 / 60.0);
+    let max_fixed_steps = runtime
+        .project()
+        .map(|project| project.config.max_fixed_steps_per_frame.max(1))
+        .unwrap_or(perro_runtime::DEFAULT_MAX_FIXED_STEPS_PER_FRAME);
     let step = Duration::from_secs_f32(fixed_step);
     let mut last = Instant::now();
     let mut accumulator = Duration::ZERO;
@@ -307,9 +332,15 @@ fn run_headless_runtime(mut runtime: Runtime) {
         last = frame_start;
         accumulator += delta.min(Duration::from_millis(250));
         runtime.update(delta.as_secs_f32());
-        while accumulator >= step {
+        let mut steps = 0;
+        while accumulator >= step && steps < max_fixed_steps {
             runtime.fixed_update(fixed_step);
             accumulator -= step;
+            steps += 1;
+        }
+        // Spiral guard: backlog past the cap is dropped, not carried.
+        while accumulator >= step {
+            accumulator -= step;
         }
         runtime.drain_window_requests(&mut requests);
         if requests
@@ -329,7 +360,8 @@ pub fn run_static_project_from_path(
     project_root: &Path,
     default_name: &str,
 ) -> Result<AppExitResult, RunProjectError> {
-    let project = RuntimeProject::from_project_dir_with_default_name(project_root, default_name)?;
+    let project = RuntimeProject::from_project_dir_with_default_name(project_root, default_name)?
+        .with_scene_override_from_env()?;
     clear_steam_fossilize_application_filter(project.config.steam.enabled);
     let _ = perro_web::init_router();
     let window_title = project.config.name.clone();
@@ -351,6 +383,7 @@ pub struct StaticEmbeddedProject<'a> {
     pub graphics: StaticEmbeddedGraphicsConfig,
     pub runtime: StaticEmbeddedRuntimeConfig,
     pub metadata: StaticEmbeddedMetadataConfig,
+    pub build: BuildInfo,
     pub localization: StaticEmbeddedLocalizationConfig,
     pub steam: StaticEmbeddedSteamConfig,
     pub assets: StaticEmbeddedAssetsConfig,
@@ -381,9 +414,12 @@ pub struct StaticEmbeddedRoutesConfig<'a> {
 pub struct StaticEmbeddedInputAction {
     pub name: &'static str,
     pub keys: &'static [perro_input_api::KeyCode],
+    pub chords: &'static [perro_input_api::KeyChord],
     pub mouse: &'static [perro_input_api::MouseButton],
     pub gamepad: &'static [perro_input_api::GamepadButton],
     pub joycon: &'static [perro_input_api::JoyConButton],
+    pub context: &'static str,
+    pub consume: bool,
 }
 
 pub struct StaticEmbeddedInputMapConfig<'a> {
@@ -407,9 +443,13 @@ pub struct StaticEmbeddedGraphicsConfig {
 
 pub struct StaticEmbeddedRuntimeConfig {
     pub target_fixed_update: Option<f32>,
+    pub max_fixed_steps_per_frame: u32,
     pub frame_rate_cap: FrameRateCap,
     pub physics_gravity: f32,
     pub physics_coef: f32,
+    pub physics_interpolation: bool,
+    pub physics_layer_names_2d: &'static [(u8, &'static str)],
+    pub physics_layer_names_3d: &'static [(u8, &'static str)],
 }
 
 pub struct StaticEmbeddedMetadataConfig {
@@ -432,6 +472,10 @@ pub struct StaticEmbeddedSteamConfig {
 
 pub struct StaticEmbeddedAssetsConfig {
     pub perro_assets: &'static [u8],
+    /// Boot integrity manifest text; empty when the export disabled the check.
+    pub integrity: &'static str,
+    /// Key for encrypted archive entries; empty when the export did not encrypt.
+    pub archive_key: &'static [u8],
     pub scene_lookup: perro_runtime::StaticSceneLookup,
     pub localization_lookup: perro_runtime::StaticLocalizationLookup,
     pub material_lookup: perro_runtime::StaticMaterialLookup,
@@ -452,9 +496,27 @@ pub struct StaticEmbeddedAssetsConfig {
     pub static_script_registry: Option<StaticScriptRegistry>,
 }
 
+/// Must run b4 the runtime opens the embedded archive.
+fn register_embedded_archive_key(assets: &StaticEmbeddedAssetsConfig) {
+    if assets.archive_key.is_empty() {
+        return;
+    }
+    if let Err(err) = perro_io::register_archive_key(assets.archive_key) {
+        eprintln!("[perro][assets] {err}");
+    }
+}
+
 pub fn run_static_embedded_project(
     input: StaticEmbeddedProject<'_>,
 ) -> Result<AppExitResult, RunProjectError> {
+    // First: in the crash monitor child this runs the monitor and exits.
+    #[cfg(any(target_os = "windows", target_os = "linux", target_os = "macos"))]
+    let _crash_reporter = crate::crash_report::install_crash_reporter(input.project.project_name);
+    if let Err(err) = integrity_check::verify_embedded_assets(&input.assets) {
+        integrity_check::report_integrity_failure(input.project.project_name, &err);
+        return Err(RunProjectError::Integrity(err));
+    }
+    register_embedded_archive_key(&input.assets);
     clear_steam_fossilize_application_filter(input.steam.enabled);
     let _ = perro_web::init_router();
     let mut static_config = perro_runtime::StaticProjectConfig::new(
@@ -468,9 +530,15 @@ pub fn run_static_embedded_project(
     .with_vsync(input.graphics.vsync)
     .with_hdr(input.graphics.hdr)
     .with_target_fixed_update(input.runtime.target_fixed_update)
+    .with_max_fixed_steps_per_frame(input.runtime.max_fixed_steps_per_frame)
     .with_frame_rate_cap(input.runtime.frame_rate_cap)
     .with_physics_gravity(input.runtime.physics_gravity)
     .with_physics_coef(input.runtime.physics_coef)
+    .with_physics_interpolation(input.runtime.physics_interpolation)
+    .with_physics_layer_names(
+        input.runtime.physics_layer_names_2d,
+        input.runtime.physics_layer_names_3d,
+    )
     .with_msaa(input.graphics.msaa)
     .with_ssao(input.graphics.ssao)
     .with_meshlets(input.graphics.meshlets)
@@ -495,7 +563,12 @@ pub fn run_static_embedded_project(
     let mut project =
         RuntimeProject::from_static(static_config, input.project.project_root.to_path_buf())
             .with_routes(static_embedded_routes(&input.routes))
-            .with_input_map(static_embedded_input_map(&input.input));
+            .with_input_map(static_embedded_input_map(&input.input))
+            .with_build_info(input.build);
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        project = project.with_scene_override_from_env()?;
+    }
 
     project = project
         .with_static_scene_lookup(input.assets.scene_lookup)
@@ -553,6 +626,11 @@ pub fn run_static_embedded_project(
 
 #[cfg(not(target_arch = "wasm32"))]
 pub fn run_static_embedded_project_headless(input: StaticEmbeddedProject<'_>) {
+    if let Err(err) = integrity_check::verify_embedded_assets(&input.assets) {
+        eprintln!("[perro][integrity] {}: {err}", input.project.project_name);
+        return;
+    }
+    register_embedded_archive_key(&input.assets);
     let mut static_config = perro_runtime::StaticProjectConfig::new(
         input.project.project_name,
         input.project.main_scene_hash,
@@ -562,9 +640,15 @@ pub fn run_static_embedded_project_headless(input: StaticEmbeddedProject<'_>) {
         input.project.virtual_height,
     )
     .with_target_fixed_update(input.runtime.target_fixed_update)
+    .with_max_fixed_steps_per_frame(input.runtime.max_fixed_steps_per_frame)
     .with_frame_rate_cap(input.runtime.frame_rate_cap)
     .with_physics_gravity(input.runtime.physics_gravity)
     .with_physics_coef(input.runtime.physics_coef)
+    .with_physics_interpolation(input.runtime.physics_interpolation)
+    .with_physics_layer_names(
+        input.runtime.physics_layer_names_2d,
+        input.runtime.physics_layer_names_3d,
+    )
     .with_particle_sim_default(input.graphics.particle_sim_default)
     .with_metadata(
         input.metadata.description,
@@ -581,6 +665,7 @@ pub fn run_static_embedded_project_headless(input: StaticEmbeddedProject<'_>) {
         RuntimeProject::from_static(static_config, input.project.project_root.to_path_buf())
             .with_routes(static_embedded_routes(&input.routes))
             .with_input_map(static_embedded_input_map(&input.input))
+            .with_build_info(input.build)
             .with_static_scene_lookup(input.assets.scene_lookup)
             .with_static_localization_lookup(input.assets.localization_lookup)
             .with_static_material_lookup(input.assets.material_lookup)
@@ -595,6 +680,13 @@ pub fn run_static_embedded_project_headless(input: StaticEmbeddedProject<'_>) {
             .with_static_skeleton_lookup(input.assets.skeleton_lookup)
             .with_static_audio_lookup(input.assets.audio_lookup)
             .with_perro_assets_bytes(input.assets.perro_assets);
+    let project = match project.with_scene_override_from_env() {
+        Ok(project) => project,
+        Err(err) => {
+            eprintln!("[perro] {}: {err}", input.project.project_name);
+            return;
+        }
+    };
     let runtime = Runtime::from_project_with_script_registry(
         project,
         ProviderMode::Static,
@@ -608,6 +700,7 @@ pub fn run_static_embedded_project_android(
     android_app: AndroidApp,
     input: StaticEmbeddedProject<'_>,
 ) -> Result<AppExitResult, RunProjectError> {
+    register_embedded_archive_key(&input.assets);
     let _ = perro_web::init_router();
     let mut static_config = perro_runtime::StaticProjectConfig::new(
         input.project.project_name,
@@ -620,9 +713,15 @@ pub fn run_static_embedded_project_android(
     .with_vsync(input.graphics.vsync)
     .with_hdr(input.graphics.hdr)
     .with_target_fixed_update(input.runtime.target_fixed_update)
+    .with_max_fixed_steps_per_frame(input.runtime.max_fixed_steps_per_frame)
     .with_frame_rate_cap(input.runtime.frame_rate_cap)
     .with_physics_gravity(input.runtime.physics_gravity)
     .with_physics_coef(input.runtime.physics_coef)
+    .with_physics_interpolation(input.runtime.physics_interpolation)
+    .with_physics_layer_names(
+        input.runtime.physics_layer_names_2d,
+        input.runtime.physics_layer_names_3d,
+    )
     .with_msaa(input.graphics.msaa)
     .with_ssao(input.graphics.ssao)
     .with_meshlets(input.graphics.meshlets)
@@ -647,7 +746,8 @@ pub fn run_static_embedded_project_android(
     let mut project =
         RuntimeProject::from_static(static_config, input.project.project_root.to_path_buf())
             .with_routes(static_embedded_routes(&input.routes))
-            .with_input_map(static_embedded_input_map(&input.input));
+            .with_input_map(static_embedded_input_map(&input.input))
+            .with_build_info(input.build);
 
     project = project
         .with_static_scene_lookup(input.assets.scene_lookup)
@@ -704,6 +804,7 @@ pub fn run_static_embedded_project_web(input: StaticEmbeddedProject<'_>) -> Resu
     }
 
     std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        register_embedded_archive_key(&input.assets);
         let _ = perro_web::init_router();
         let mut static_config = perro_runtime::StaticProjectConfig::new(
             input.project.project_name,
@@ -716,9 +817,15 @@ pub fn run_static_embedded_project_web(input: StaticEmbeddedProject<'_>) -> Resu
         .with_vsync(input.graphics.vsync)
         .with_hdr(input.graphics.hdr)
         .with_target_fixed_update(input.runtime.target_fixed_update)
+        .with_max_fixed_steps_per_frame(input.runtime.max_fixed_steps_per_frame)
         .with_frame_rate_cap(input.runtime.frame_rate_cap)
         .with_physics_gravity(input.runtime.physics_gravity)
         .with_physics_coef(input.runtime.physics_coef)
+        .with_physics_interpolation(input.runtime.physics_interpolation)
+        .with_physics_layer_names(
+            input.runtime.physics_layer_names_2d,
+            input.runtime.physics_layer_names_3d,
+        )
         .with_msaa(input.graphics.msaa)
         .with_ssao(input.graphics.ssao)
         .with_meshlets(input.graphics.meshlets)
@@ -743,7 +850,8 @@ pub fn run_static_embedded_project_web(input: StaticEmbeddedProject<'_>) -> Resu
         let mut project =
             RuntimeProject::from_static(static_config, input.project.project_root.to_path_buf())
                 .with_routes(static_embedded_routes(&input.routes))
-                .with_input_map(static_embedded_input_map(&input.input));
+                .with_input_map(static_embedded_input_map(&input.input))
+                .with_build_info(input.build);
 
         project = project
             .with_static_scene_lookup(input.assets.scene_lookup)
@@ -5,6 +5,12 @@
 //! normalization, wake headroom, and vsync interaction live in exactly one
 //! place. Project knobs stay two: `graphics.vsync` and
 //! `runtime.frame_rate_cap` in project.toml.
+//!
+//! Waiting is a sleep/spin hybrid: the event loop sleeps until just before
+//! the deadline, then [`FramePacer::wait_for_deadline`] finishes with short
+//! sleeps and a final yield/spin. Wake headroom is learned from measured
+//! sleep overshoot, so a coarse OS timer (Windows without the 1ms request)
+//! widens the spin window instead of oversleeping every frame.
 
 use perro_runtime_api::sub_apis::FrameRateCap as RuntimeFrameRateCap;
 use std::time::Duration;
@@ -22,6 +28,13 @@ pub(crate) const MAX_FRAME_RATE_CAP_FPS: f32 = 1000.0;
 pub(crate) const FRAME_WAKE_HEADROOM: Duration = Duration::from_millis(2);
 // Used when the monitor refresh rate cannot be queried.
 pub(crate) const FALLBACK_REFRESH_HZ: f32 = 60.0;
+// Bounds 4 learned sleep overshoot. Floor keeps a little spin margin even on
+// precise timers; ceiling covers the ~15.6ms Windows default tick.
+const MIN_SLEEP_SLACK: Duration = Duration::from_micros(500);
+const MAX_SLEEP_SLACK: Duration = Duration::from_millis(20);
+// Final stretch below this spins w/ `spin_loop`; above it yields the core.
+#[cfg(not(target_arch = "wasm32"))]
+const SPIN_YIELD_THRESHOLD: Duration = Duration::from_micros(200);
 
 #[inline]
 pub(crate) fn normalize_frame_rate_cap(cap: RuntimeFrameRateCap) -> RuntimeFrameRateCap {
@@ -74,6 +87,32 @@ pub(crate) fn query_refresh_rate_hz(_window: Option<&Window>) -> Option<f32> {
     Some(FALLBACK_REFRESH_HZ)
 }
 
+/// Learned OS sleep overshoot. Jumps to any larger overshoot seen, decays
+/// slowly otherwise, so one late wake widens the margin right away while a
+/// quiet system earns it back over ~16 sleeps.
+#[derive(Clone, Copy, Debug)]
+struct SleepSlack {
+    estimate: Duration,
+}
+
+impl SleepSlack {
+    const fn new() -> Self {
+        Self {
+            estimate: FRAME_WAKE_HEADROOM,
+        }
+    }
+
+    fn record(&mut self, requested: Duration, actual: Duration) {
+        let overshoot = actual.saturating_sub(requested);
+        let next = if overshoot > self.estimate {
+            overshoot
+        } else {
+            (self.estimate * 15 + overshoot) / 16
+        };
+        self.estimate = next.clamp(MIN_SLEEP_SLACK, MAX_SLEEP_SLACK);
+    }
+}
+
 /// CPU-side frame pacer: owns the cap, the next frame deadline, and the
 /// cached refresh rate. Knows about vsync so the CPU deadline and the
 /// present block never fight over pacing.
@@ -82,6 +121,7 @@ pub(crate) struct FramePacer {
     vsync: bool,
     refresh_hz: Option<f32>,
     next_deadline: Option<Instant>,
+    slack: SleepSlack,
 }
 
 impl FramePacer {
@@ -91,6 +131,7 @@ impl FramePacer {
             vsync,
             refresh_hz: None,
             next_deadline: None,
+            slack: SleepSlack::new(),
         }
     }
 
@@ -170,6 +211,48 @@ impl FramePacer {
         self.next_deadline.is_some_and(|deadline| deadline > now)
     }
 
+    /// How early the event loop should wake before the deadline. Tracks the
+    /// measured sleep overshoot; never below [`FRAME_WAKE_HEADROOM`].
+    #[inline]
+    pub(crate) fn wake_headroom(&self) -> Duration {
+        self.slack.estimate.max(FRAME_WAKE_HEADROOM)
+    }
+
+    /// True when `now` is inside the final headroom window, where the event
+    /// loop cannot sleep precisely enough and [`Self::wait_for_deadline`]
+    /// should finish the wait.
+    #[inline]
+    pub(crate) fn in_final_wait(&self, now: Instant) -> bool {
+        self.next_deadline
+            .filter(|&deadline| deadline > now)
+            .is_some_and(|deadline| deadline - now <= self.wake_headroom())
+    }
+
+    /// Block until the deadline: sleep while the remaining time exceeds the
+    /// learned overshoot, then yield/spin the last stretch. Returns the wake
+    /// instant. Each sleep feeds the overshoot estimate.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn wait_for_deadline(&mut self) -> Instant {
+        let Some(deadline) = self.next_deadline else {
+            return Instant::now();
+        };
+        loop {
+            let now = Instant::now();
+            let Some(remaining) = deadline.checked_duration_since(now) else {
+                return now;
+            };
+            if remaining > self.slack.estimate {
+                let request = remaining - self.slack.estimate;
+                std::thread::sleep(request);
+                self.slack.record(request, now.elapsed());
+            } else if remaining > SPIN_YIELD_THRESHOLD {
+                std::thread::yield_now();
+            } else {
+                std::hint::spin_loop();
+            }
+        }
+    }
+
     /// Advance the deadline after a frame. Deadlines chain from the previous
     /// deadline for drift-free pacing, re-anchoring from frame_start when the
     /// frame overran.
@@ -248,6 +331,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sleep_slack_widens_fast_and_decays_slow() {
+        let mut slack = SleepSlack::new();
+        let request = Duration::from_millis(5);
+        // Coarse timer: 15ms overshoot lifts the margin in one sample.
+        slack.record(request, request + Duration::from_millis(15));
+        assert_eq!(slack.estimate, Duration::from_millis(15));
+        // Precise wakes shrink it gradually, never below the floor.
+        slack.record(request, request);
+        assert!(slack.estimate < Duration::from_millis(15));
+        assert!(slack.estimate > Duration::from_millis(13));
+        for _ in 0..200 {
+            slack.record(request, request);
+        }
+        assert_eq!(slack.estimate, MIN_SLEEP_SLACK);
+        slack.record(request, request + Duration::from_secs(1));
+        assert_eq!(slack.estimate, MAX_SLEEP_SLACK);
+    }
+
+    #[test]
+    fn final_wait_window_follows_wake_headroom() {
+        let mut pacer = FramePacer::new(RuntimeFrameRateCap::Fps(50.0), false);
+        let start = Instant::now();
+        pacer.update_deadline(start, start, false);
+        let deadline = pacer.deadline().expect("capped pacer sets a deadline");
+        assert!(!pacer.in_final_wait(start));
+        assert!(pacer.in_final_wait(deadline - FRAME_WAKE_HEADROOM));
+        assert!(!pacer.in_final_wait(deadline));
+        pacer.slack.estimate = Duration::from_millis(10);
+        assert_eq!(pacer.wake_headroom(), Duration::from_millis(10));
+        assert!(pacer.in_final_wait(deadline - Duration::from_millis(9)));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn wait_for_deadline_never_returns_early() {
+        let mut pacer = FramePacer::new(RuntimeFrameRateCap::Fps(250.0), false);
+        let start = Instant::now();
+        pacer.update_deadline(start, start, false);
+        let deadline = pacer.deadline().expect("capped pacer sets a deadline");
+        let woke = pacer.wait_for_deadline();
+        assert!(woke >= deadline);
+        assert!(!pacer.blocks_frame(woke));
+    }
+
     #[test]
     fn deadline_chains_and_reanchors() {
         let mut pacer = FramePacer::new(RuntimeFrameRateCap::Fps(100.0), false);
@@ -9,6 +9,7 @@ trait GamepadSink {
     fn set_gamepad_axis(&mut self, index: usize, axis: GamepadAxis, value: f32);
     fn set_gamepad_gyro(&mut self, index: usize, x: f32, y: f32, z: f32);
     fn set_gamepad_accel(&mut self, index: usize, x: f32, y: f32, z: f32);
+    fn set_gamepad_rumble_supported(&mut self, index: usize, supported: bool);
     fn take_gamepad_rumble_requests(&mut self) -> Vec<perro_input_api::GamepadRumbleRequest>;
 }
 
@@ -30,6 +31,10 @@ impl<B: GraphicsBackend> GamepadSink for App<B> {
         App::set_gamepad_accel(self, index, x, y, z);
     }
 
+    fn set_gamepad_rumble_supported(&mut self, index: usize, supported: bool) {
+        App::set_gamepad_rumble_supported(self, index, supported);
+    }
+
     fn take_gamepad_rumble_requests(&mut self) -> Vec<perro_input_api::GamepadRumbleRequest> {
         App::take_gamepad_rumble_requests(self)
     }
@@ -43,6 +48,7 @@ mod backend {
     use perro_input_api::{GamepadAxis, GamepadButton, GamepadIndex};
     use std::collections::{HashMap, HashSet};
     use std::sync::OnceLock;
+    use std::time::Instant;
 
     const ALL_BUTTONS: [GamepadButton; GamepadButton::COUNT] = [
         GamepadButton::Bottom,
@@ -92,6 +98,7 @@ mod backend {
         down_masks: HashMap<GamepadId, u32>,
         uuid_in_use: HashSet<[u8; 16]>,
         rumble_effects: HashMap<usize, Effect>,
+        rumble_stop_at: HashMap<usize, Instant>,
         sync_ids: Vec<GamepadId>,
         state_sync_frame_counter: u32,
         idle_poll_frame_counter: u32,
@@ -156,6 +163,29 @@ mod backend {
                     req.rumble.low_frequency,
                     req.rumble.high_frequency,
                 );
+                if let Some(duration) = req.duration
+                    && self.rumble_effects.contains_key(&req.index)
+                {
+                    self.rumble_stop_at
+                        .insert(req.index, Instant::now() + duration);
+                }
+            }
+            self.stop_expired_rumble();
+        }
+
+        fn stop_expired_rumble(&mut self) {
+            if self.rumble_stop_at.is_empty() {
+                return;
+            }
+            let now = Instant::now();
+            let expired: Vec<usize> = self
+                .rumble_stop_at
+                .iter()
+                .filter(|(_, stop_at)| now >= **stop_at)
+                .map(|(index, _)| *index)
+                .collect();
+            for index in expired {
+                self.stop_rumble(index);
             }
         }
 
@@ -213,6 +243,7 @@ mod backend {
         }
 
         fn stop_rumble(&mut self, index: usize) {
+            self.rumble_stop_at.remove(&index);
             if let Some(effect) = self.rumble_effects.remove(&index) {
                 let _ = effect.stop();
             }
@@ -252,6 +283,7 @@ mod backend {
                     if let Some(index) = self.assign_index_if_unique(gilrs, id) {
                         log_gamepad_connected(gilrs, id, index);
                         clear_gamepad(app, index);
+                        app.set_gamepad_rumble_supported(index, gp.is_ff_supported());
                     }
                 }
                 EventType::Disconnected => {
@@ -618,6 +650,7 @@ mod backend {
         }
         app.set_gamepad_gyro(index, 0.0, 0.0, 0.0);
         app.set_gamepad_accel(index, 0.0, 0.0, 0.0);
+        app.set_gamepad_rumble_supported(index, false);
     }
 
     fn log_gamepad_connected(gilrs: &Gilrs, id: GamepadId, index: usize) {
@@ -145,12 +145,65 @@ pub(super) fn enable_sensors(device: &hidapi::HidDevice) -> Result<(), hidapi::H
     const CMD_SET_REPORT_30: [u8; 12] = [
         0x01, 0x01, 0x00, 0x01, 0x40, 0x40, 0x00, 0x01, 0x40, 0x40, 0x03, 0x30,
     ];
+    const CMD_ENABLE_VIBRATION: [u8; 12] = [
+        0x01, 0x02, 0x00, 0x01, 0x40, 0x40, 0x00, 0x01, 0x40, 0x40, 0x48, 0x01,
+    ];
 
     device.write(&CMD_ENABLE_IMU)?;
     device.write(&CMD_SET_REPORT_30)?;
+    device.write(&CMD_ENABLE_VIBRATION)?;
     Ok(())
 }
 
+// HD rumble: the low motor plays at 160 Hz, the high motor at 320 Hz. Both
+// sides of the 8-byte rumble block get the same frame.
+const RUMBLE_LOW_HZ: f32 = 160.0;
+const RUMBLE_HIGH_HZ: f32 = 320.0;
+
+pub(super) fn send_rumble(
+    device: &hidapi::HidDevice,
+    packet_number: &mut u8,
+    low_frequency: f32,
+    high_frequency: f32,
+) -> Result<(), hidapi::HidError> {
+    // HID output report: [0x10, packet_no, rumble(8)]
+    let frame = encode_rumble(low_frequency, high_frequency);
+    let mut report = [0u8; 10];
+    report[0] = 0x10;
+    report[1] = *packet_number;
+    report[2..6].copy_from_slice(&frame);
+    report[6..10].copy_from_slice(&frame);
+    *packet_number = packet_number.wrapping_add(1) & 0x0F;
+    device.write(&report)?;
+    Ok(())
+}
+
+fn encode_rumble(low_amplitude: f32, high_amplitude: f32) -> [u8; 4] {
+    let high_freq = (((RUMBLE_HIGH_HZ / 10.0).log2() * 32.0).round() as u16 - 0x60) * 4;
+    let low_freq = ((RUMBLE_LOW_HZ / 10.0).log2() * 32.0).round() as u8 - 0x40;
+    let high_amp = encode_amplitude(high_amplitude) * 2;
+    let low_amp = u16::from(encode_amplitude(low_amplitude) / 2) + 0x40;
+    [
+        (high_freq & 0xFF) as u8,
+        (high_freq >> 8) as u8 + high_amp,
+        low_freq + (low_amp >> 8) as u8,
+        (low_amp & 0xFF) as u8,
+    ]
+}
+
+// Amplitude curve from the public HD rumble tables; 1.0 encodes to 0x64.
+fn encode_amplitude(amplitude: f32) -> u8 {
+    let amplitude = amplitude.clamp(0.0, 1.0);
+    let encoded = if amplitude > 0.23 {
+        (amplitude * 8.7).log2() * 32.0
+    } else if amplitude > 0.0 {
+        (amplitude * 17.0).log2() * 16.0
+    } else {
+        0.0
+    };
+    encoded.clamp(0.0, 100.0) as u8
+}
+
 pub(super) fn send_hid_subcommand_with_rumble(
     device: &hidapi::HidDevice,
     packet_number: &mut u8,
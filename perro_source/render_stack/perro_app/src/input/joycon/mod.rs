@@ -13,6 +13,7 @@ trait JoyConSink {
     fn set_joycon_stick_unit(&mut self, index: usize, stick: SignedUnitVector2);
     fn set_joycon_side(&mut self, index: usize, side: JoyConSide);
     fn set_joycon_connected(&mut self, index: usize, connected: bool);
+    fn set_joycon_rumble_supported(&mut self, index: usize, supported: bool);
     fn set_joycon_calibrated(&mut self, index: usize, calibrated: bool);
     fn set_joycon_calibration_in_progress(&mut self, index: usize, in_progress: bool);
     fn set_joycon_calibration_bias(&mut self, index: usize, x: f32, y: f32, z: f32);
@@ -43,6 +44,9 @@ impl<B: GraphicsBackend> JoyConSink for App<B> {
     fn set_joycon_connected(&mut self, index: usize, connected: bool) {
         App::set_joycon_connected(self, index, connected);
     }
+    fn set_joycon_rumble_supported(&mut self, index: usize, supported: bool) {
+        App::set_joycon_rumble_supported(self, index, supported);
+    }
     fn set_joycon_calibrated(&mut self, index: usize, calibrated: bool) {
         App::set_joycon_calibrated(self, index, calibrated);
     }
@@ -222,6 +226,7 @@ mod backend {
             side: JoyConSide,
             serial: String,
             device_key: String,
+            rumble: bool,
         },
         Report {
             index: usize,
@@ -307,6 +312,7 @@ mod backend {
         connected: HashMap<(usize, JoyConSide), ConnectedJoyCon>,
         output_txs: HashMap<String, Sender<DeviceCommand>>,
         last_player_lamp: HashMap<usize, u8>,
+        rumble_stop_at: HashMap<usize, Instant>,
         scan_connected_keys: HashSet<String>,
         last_scan: Option<Instant>,
         ble_started: bool,
@@ -409,6 +415,7 @@ mod backend {
                         side,
                         serial: serial.clone(),
                         device_key: slot_key.clone(),
+                        rumble: true,
                     })
                     .ok()
                 });
@@ -430,6 +437,7 @@ mod backend {
                         self.connected.retain(|(idx, _), _| *idx != index);
                         self.output_txs.remove(slot_key);
                         self.last_player_lamp.remove(&index);
+                        self.rumble_stop_at.remove(&index);
                     }
                 }
                 connected
@@ -480,7 +488,17 @@ mod backend {
                                     pattern,
                                 );
                             }
-                            DeviceCommand::SetRumble { .. } => {}
+                            DeviceCommand::SetRumble {
+                                low_frequency,
+                                high_frequency,
+                            } => {
+                                let _ = joycon1::send_rumble(
+                                    &device,
+                                    &mut packet_number,
+                                    low_frequency,
+                                    high_frequency,
+                                );
+                            }
                         }
                     }
                     match device.read_timeout(&mut buffer, READ_TIMEOUT.as_millis() as i32) {
@@ -550,8 +568,10 @@ mod backend {
                     side,
                     serial,
                     device_key,
+                    rumble,
                 } => {
                     self.on_connected(app, index, side, serial, device_key);
+                    app.set_joycon_rumble_supported(index, rumble);
                 }
                 JoyConEvent::Report {
                     index,
@@ -583,6 +603,7 @@ mod backend {
                     self.last_buttons.retain(|(idx, _), _| *idx != index);
                     self.connected.retain(|(idx, _), _| *idx != index);
                     self.last_player_lamp.remove(&index);
+                    self.rumble_stop_at.remove(&index);
                 }
             }
         }
@@ -596,12 +617,22 @@ mod backend {
 
         fn consume_output_requests<S: JoyConSink>(&mut self, app: &mut S) {
             for req in app.take_joycon_rumble_requests() {
+                match req.duration {
+                    Some(duration) => {
+                        self.rumble_stop_at
+                            .insert(req.index, Instant::now() + duration);
+                    }
+                    None => {
+                        self.rumble_stop_at.remove(&req.index);
+                    }
+                }
                 self.apply_rumble(
                     req.index,
                     req.rumble.low_frequency,
                     req.rumble.high_frequency,
                 );
             }
+            self.stop_expired_rumble();
             for req in app.take_joycon_indicator_requests() {
                 self.apply_indicator(req.index, req.indicator.to_lamp_pattern());
             }
@@ -621,6 +652,23 @@ mod backend {
             }
         }
 
+        fn stop_expired_rumble(&mut self) {
+            if self.rumble_stop_at.is_empty() {
+                return;
+            }
+            let now = Instant::now();
+            let expired: Vec<usize> = self
+                .rumble_stop_at
+                .iter()
+                .filter(|(_, stop_at)| now >= **stop_at)
+                .map(|(index, _)| *index)
+                .collect();
+            for index in expired {
+                self.rumble_stop_at.remove(&index);
+                self.apply_rumble(index, 0.0, 0.0);
+            }
+        }
+
         fn apply_indicator(&mut self, index: usize, indicator: u8) {
             self.last_player_lamp.insert(index, indicator);
             for controller in self.connected.values() {
@@ -968,6 +1016,7 @@ mod backend {
                             side,
                             serial: serial.clone(),
                             device_key: key.clone(),
+                            rumble: vibration_char.is_some(),
                         });
                         let tx_clone = tx.clone();
                         let slots_clone = Arc::clone(&slots);
@@ -1247,6 +1296,7 @@ mod backend {
             app.set_joycon_button_state(index, button, false);
         }
         app.set_joycon_connected(index, false);
+        app.set_joycon_rumble_supported(index, false);
         app.set_joycon_calibration_in_progress(index, false);
         app.set_joycon_calibrated(index, false);
         app.set_joycon_calibration_bias(index, 0.0, 0.0, 0.0);
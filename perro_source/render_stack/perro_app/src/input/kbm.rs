@@ -46,9 +46,16 @@ impl KbmInput {
             WindowEvent::Focused(false) => {
                 self.modifiers = Modifiers::default().state();
             }
+            WindowEvent::Ime(winit::event::Ime::Preedit(text, _)) => {
+                app.set_ime_preedit(text.clone());
+            }
             WindowEvent::Ime(winit::event::Ime::Commit(text)) => {
+                app.set_ime_preedit(String::new());
                 app.push_text_input(text.clone());
             }
+            WindowEvent::Ime(winit::event::Ime::Disabled) => {
+                app.set_ime_preedit(String::new());
+            }
             WindowEvent::MouseInput { state, button, .. } => {
                 if let Some(mapped) = map_winit_mouse_button(*button) {
                     app.set_mouse_button_state(mapped, *state == ElementState::Pressed);
@@ -1,7 +1,9 @@
 mod gamepad;
 mod joycon;
 pub(crate) mod kbm;
+mod touch;
 
 pub use gamepad::GamepadInput;
 pub use joycon::JoyConInput;
 pub use kbm::KbmInput;
+pub(crate) use touch::map_winit_touch;
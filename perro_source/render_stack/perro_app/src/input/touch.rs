@@ -0,0 +1,32 @@
+use perro_input_api::{TouchPhase, TouchPoint};
+use perro_structs::Vector2;
+use winit::event::{Force, Touch, TouchPhase as WinitTouchPhase};
+
+/// Map a winit finger/pen contact to engine touch input.
+///
+/// winit reports pen pressure and altitude only; azimuth and barrel buttons
+/// are not exposed, so tilt is altitude-only.
+pub(crate) fn map_winit_touch(touch: &Touch) -> (TouchPhase, TouchPoint) {
+    let phase = match touch.phase {
+        WinitTouchPhase::Started => TouchPhase::Started,
+        WinitTouchPhase::Moved => TouchPhase::Moved,
+        WinitTouchPhase::Ended => TouchPhase::Ended,
+        WinitTouchPhase::Cancelled => TouchPhase::Cancelled,
+    };
+    let altitude = match touch.force {
+        Some(Force::Calibrated {
+            altitude_angle: Some(angle),
+            ..
+        }) => Some(angle as f32),
+        _ => None,
+    };
+    let point = TouchPoint {
+        id: touch.id,
+        position: Vector2::new(touch.location.x as f32, touch.location.y as f32),
+        pressure: touch
+            .force
+            .map_or(1.0, |force| force.normalized().clamp(0.0, 1.0) as f32),
+        altitude,
+    };
+    (phase, point)
+}
@@ -0,0 +1,64 @@
+//! Boot-time self-check for exported games (`[integrity] check = true`).
+//!
+//! Re-hashes the embedded archive against the manifest the export wrote and
+//! compares the script registry length. On mismatch the player sees a
+//! "reinstall / verify files" dialog instead of a crash that gets reported
+//! as an engine bug.
+//!
+//! `PERRO_SKIP_INTEGRITY=1` skips the check (modded installs, support triage).
+
+use crate::entry::StaticEmbeddedAssetsConfig;
+use perro_assets::integrity::{IntegrityError, IntegrityManifest};
+
+const SKIP_ENV: &str = "PERRO_SKIP_INTEGRITY";
+
+/// Empty manifest = export built with the check off.
+pub(crate) fn verify_embedded_assets(
+    assets: &StaticEmbeddedAssetsConfig,
+) -> Result<(), IntegrityError> {
+    if assets.integrity.trim().is_empty() || skip_requested() {
+        return Ok(());
+    }
+    let manifest = IntegrityManifest::parse(assets.integrity)?;
+    let script_count = assets.static_script_registry.map_or(0, <[_]>::len);
+    manifest.verify(assets.perro_assets, script_count)
+}
+
+pub(crate) fn report_integrity_failure(project_name: &str, err: &IntegrityError) {
+    eprintln!("[perro][integrity] {project_name}: {err}");
+    #[cfg(any(target_os = "windows", target_os = "linux", target_os = "macos"))]
+    {
+        let _ = rfd::MessageDialog::new()
+            .set_level(rfd::MessageLevel::Error)
+            .set_title(format!("{project_name} - damaged installation"))
+            .set_description(integrity_failure_message(project_name, err))
+            .set_buttons(rfd::MessageButtons::Ok)
+            .show();
+    }
+}
+
+pub(crate) fn integrity_failure_message(project_name: &str, err: &IntegrityError) -> String {
+    format!(
+        "{project_name} could not start because some of its game files are damaged or were modified.\n\n\
+Please reinstall the game, or use your store's \"verify game files\" option.\n\n\
+Details for support: {err}"
+    )
+}
+
+fn skip_requested() -> bool {
+    std::env::var(SKIP_ENV).is_ok_and(|raw| matches!(raw.trim(), "1" | "true" | "yes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failure_message_points_players_at_reinstall_and_keeps_details() {
+        let err = IntegrityError::MissingEntry("res/data/items.json".to_string());
+        let message = integrity_failure_message("Dog Game", &err);
+        assert!(message.starts_with("Dog Game could not start"));
+        assert!(message.contains("reinstall"));
+        assert!(message.contains("res/data/items.json"));
+    }
+}
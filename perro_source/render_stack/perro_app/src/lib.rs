@@ -1,7 +1,8 @@
 use perro_graphics::GraphicsBackend;
 use perro_input_api::{
     GamepadAxis, GamepadButton, GamepadRumbleRequest, JoyConButton, JoyConIndicatorRequest,
-    JoyConRumbleRequest, KeyCode, MouseButton, MouseMode, PlayerBinding, PlayerState,
+    JoyConRumbleRequest, KeyCode, MouseButton, MouseMode, PlayerBinding, PlayerState, TouchPhase,
+    TouchPoint,
 };
 use perro_render_bridge::RenderEvent;
 use perro_runtime::{Runtime, WindowRequest};
@@ -169,6 +170,13 @@ impl<B: GraphicsBackend> App<B> {
         self.runtime.time.fps = fps;
     }
 
+    /// Push backend GPU resource bytes into the runtime memory report.
+    #[inline]
+    pub fn refresh_gpu_memory_bytes(&mut self) {
+        let bytes = self.graphics.profile_snapshot().gpu_memory_bytes;
+        self.runtime.set_gpu_memory_bytes(bytes);
+    }
+
     #[inline]
     pub fn set_frame_timing(
         &mut self,
@@ -285,6 +293,11 @@ impl<B: GraphicsBackend> App<B> {
         self.runtime.push_text_input(text);
     }
 
+    #[inline]
+    pub fn set_ime_preedit(&mut self, text: impl Into<String>) {
+        self.runtime.set_ime_preedit(text);
+    }
+
     #[inline]
     pub fn set_mouse_button_state(&mut self, button: MouseButton, is_down: bool) {
         self.runtime.set_mouse_button_state(button, is_down);
@@ -310,6 +323,11 @@ impl<B: GraphicsBackend> App<B> {
         self.runtime.set_mouse_position(x, y);
     }
 
+    #[inline]
+    pub fn set_touch(&mut self, phase: TouchPhase, point: TouchPoint) {
+        self.runtime.set_touch(phase, point);
+    }
+
     #[inline]
     pub fn set_mouse_mode_state(&mut self, mode: MouseMode) {
         self.runtime.set_mouse_mode_state(mode);
@@ -361,6 +379,11 @@ impl<B: GraphicsBackend> App<B> {
         self.runtime.set_gamepad_accel(index, x, y, z);
     }
 
+    #[inline]
+    pub fn set_gamepad_rumble_supported(&mut self, index: usize, supported: bool) {
+        self.runtime.set_gamepad_rumble_supported(index, supported);
+    }
+
     #[inline]
     pub fn set_joycon_button_state(&mut self, index: usize, button: JoyConButton, is_down: bool) {
         self.runtime.set_joycon_button_state(index, button, is_down);
@@ -390,6 +413,11 @@ impl<B: GraphicsBackend> App<B> {
         self.runtime.set_joycon_connected(index, connected);
     }
 
+    #[inline]
+    pub fn set_joycon_rumble_supported(&mut self, index: usize, supported: bool) {
+        self.runtime.set_joycon_rumble_supported(index, supported);
+    }
+
     #[inline]
     pub fn set_joycon_calibrated(&mut self, index: usize, calibrated: bool) {
         self.runtime.set_joycon_calibrated(index, calibrated);
@@ -516,6 +544,7 @@ impl<B: GraphicsBackend> App<B> {
         self.runtime.extract_render_3d_commands();
         self.runtime.extract_render_ui_commands();
         self.runtime.drain_render_commands(&mut self.command_buffer);
+        render_trail::record_render_commands(&self.command_buffer);
         self.graphics.submit_many(self.command_buffer.drain(..));
         if late_overlay {
             self.graphics.draw_frame_with_late_overlay(overlay_commands);
@@ -584,6 +613,7 @@ impl<B: GraphicsBackend> App<B> {
 
         #[cfg(feature = "profile_heavy")]
         let submit_start = Instant::now();
+        render_trail::record_render_commands(&self.command_buffer);
         self.graphics.submit_many(self.command_buffer.drain(..));
         #[cfg(feature = "profile_heavy")]
         let submit_commands = submit_start.elapsed();
@@ -852,9 +882,14 @@ impl<B: GraphicsBackend> App<B> {
     }
 }
 
+#[cfg(any(target_os = "windows", target_os = "linux", target_os = "macos"))]
+mod crash_report;
 pub mod entry;
 pub(crate) mod frame_pacing;
 pub mod input;
+pub(crate) mod integrity_check;
+pub(crate) mod movie_mode;
+pub(crate) mod render_trail;
 #[cfg(not(target_arch = "wasm32"))]
 mod timer_resolution;
 pub mod winit_runner;
@@ -0,0 +1,208 @@
+//! Movie-maker mode: the runner steps at a fixed simulated delta regardless
+//! of wall clock and perro_graphics writes every frame to disk.
+//!
+//! Driven by env so shipped builds and the dev runner share one switch
+//! (`perro_cli dev --record` sets these):
+//! - `PERRO_RECORD`: output dir (PNG sequence) or video file (piped to ffmpeg)
+//! - `PERRO_RECORD_FPS`: movie frame rate, default 60
+//! - `PERRO_RECORD_SECONDS`: optional; exit after this much simulated time
+//!
+//! Thumbnail capture (`perro_cli thumbnail`) reuses the same path:
+//! - `PERRO_THUMBNAIL`: output PNG; runs a short take, frames the scene with
+//!   an auto-fitted camera and keeps only the last frame
+//! - `PERRO_THUMBNAIL_SIZE`: longest side in px, default 256
+//!
+//! Visual tests (`perro_cli test --visual`) capture one full-size still:
+//! - `PERRO_CAPTURE`: output PNG; keeps the last frame, scene camera as-is
+//! - `PERRO_CAPTURE_FRAMES`: frames simulated incl. the captured one, default 30
+
+use perro_graphics::movie::{DEFAULT_MOVIE_FPS, normalize_movie_fps};
+#[cfg(not(target_arch = "wasm32"))]
+use perro_graphics::movie::{DEFAULT_THUMBNAIL_SIZE, MovieOutput, MovieRecorder, MovieSettings};
+use std::time::Duration;
+
+const RECORD_ENV: &str = "PERRO_RECORD";
+const RECORD_FPS_ENV: &str = "PERRO_RECORD_FPS";
+const RECORD_SECONDS_ENV: &str = "PERRO_RECORD_SECONDS";
+const THUMBNAIL_ENV: &str = "PERRO_THUMBNAIL";
+#[cfg(not(target_arch = "wasm32"))]
+const THUMBNAIL_SIZE_ENV: &str = "PERRO_THUMBNAIL_SIZE";
+const CAPTURE_ENV: &str = "PERRO_CAPTURE";
+const CAPTURE_FRAMES_ENV: &str = "PERRO_CAPTURE_FRAMES";
+/// Frames simulated b4 the thumbnail frame; lets scene load + camera settle.
+const THUMBNAIL_FRAMES: u64 = 30;
+
+/// Simulated clock for a recording. Frame N is always at N/fps seconds, so
+/// a slow capture never drops or stretches frames.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct MovieClock {
+    fps: f32,
+    max_frames: Option<u64>,
+    /// Thumbnail take still waiting to add its auto-fit camera.
+    frame_thumbnail: bool,
+}
+
+impl MovieClock {
+    pub(crate) fn new(fps: f32, seconds: Option<f32>) -> Self {
+        let fps = normalize_movie_fps(fps);
+        Self {
+            fps,
+            max_frames: seconds
+                .filter(|s| s.is_finite() && *s > 0.0)
+                .map(|s| (f64::from(s) * f64::from(fps)).ceil().max(1.0) as u64),
+            frame_thumbnail: false,
+        }
+    }
+
+    pub(crate) fn thumbnail() -> Self {
+        Self {
+            fps: DEFAULT_MOVIE_FPS,
+            max_frames: Some(THUMBNAIL_FRAMES),
+            frame_thumbnail: true,
+        }
+    }
+
+    /// Fixed 60 fps so a golden image always sees the same simulated time.
+    pub(crate) fn capture(frames: u64) -> Self {
+        Self {
+            fps: DEFAULT_MOVIE_FPS,
+            max_frames: Some(frames.max(1)),
+            frame_thumbnail: false,
+        }
+    }
+
+    pub(crate) fn from_env() -> Option<Self> {
+        if env_set(CAPTURE_ENV).is_some() {
+            return Some(Self::capture(
+                env_set(CAPTURE_FRAMES_ENV)
+                    .and_then(|raw| raw.trim().parse::<u64>().ok())
+                    .unwrap_or(THUMBNAIL_FRAMES),
+            ));
+        }
+        if env_set(THUMBNAIL_ENV).is_some() {
+            return Some(Self::thumbnail());
+        }
+        std::env::var(RECORD_ENV)
+            .ok()
+            .filter(|raw| !raw.trim().is_empty())?;
+        Some(Self::new(
+            env_f32(RECORD_FPS_ENV).unwrap_or(DEFAULT_MOVIE_FPS),
+            env_f32(RECORD_SECONDS_ENV),
+        ))
+    }
+
+    pub(crate) fn fps(&self) -> f32 {
+        self.fps
+    }
+
+    pub(crate) fn frame_delta(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / f64::from(self.fps))
+    }
+
+    // Computed from the frame count, not accumulated, so long takes never drift.
+    pub(crate) fn elapsed(&self, frames: u64) -> Duration {
+        Duration::from_secs_f64(frames as f64 / f64::from(self.fps))
+    }
+
+    pub(crate) fn finished(&self, frames: u64) -> bool {
+        self.max_frames.is_some_and(|max| frames >= max)
+    }
+
+    pub(crate) fn needs_thumbnail_framing(&self) -> bool {
+        self.frame_thumbnail
+    }
+
+    pub(crate) fn mark_thumbnail_framed(&mut self) {
+        self.frame_thumbnail = false;
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn movie_recorder_from_env() -> Option<MovieRecorder> {
+    let clock = MovieClock::from_env()?;
+    let output = if let Some(path) = env_set(CAPTURE_ENV) {
+        MovieOutput::Still(path.trim().into())
+    } else if let Some(path) = env_set(THUMBNAIL_ENV) {
+        MovieOutput::Thumbnail {
+            path: path.trim().into(),
+            size: std::env::var(THUMBNAIL_SIZE_ENV)
+                .ok()
+                .and_then(|raw| raw.trim().parse::<u32>().ok())
+                .filter(|size| *size > 0)
+                .unwrap_or(DEFAULT_THUMBNAIL_SIZE),
+        }
+    } else {
+        MovieOutput::from_path(env_set(RECORD_ENV)?.trim())
+    };
+    let settings = MovieSettings::new(output, clock.fps());
+    match MovieRecorder::new(settings) {
+        Ok(recorder) => {
+            eprintln!(
+                "[perro][movie] recording=({}) fps=({})",
+                recorder.settings().output.path().display(),
+                clock.fps()
+            );
+            Some(recorder)
+        }
+        Err(err) => {
+            eprintln!("[perro][movie] {err}; recording disabled");
+            None
+        }
+    }
+}
+
+fn env_set(key: &str) -> Option<String> {
+    std::env::var(key).ok().filter(|raw| !raw.trim().is_empty())
+}
+
+fn env_f32(key: &str) -> Option<f32> {
+    std::env::var(key).ok()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock_steps_are_exact_per_frame() {
+        let clock = MovieClock::new(30.0, None);
+        assert_eq!(clock.elapsed(30), Duration::from_secs(1));
+        assert!((clock.frame_delta().as_secs_f64() - 1.0 / 30.0).abs() < 1e-9);
+        assert!(!clock.finished(u64::MAX));
+    }
+
+    #[test]
+    fn clock_stops_after_requested_seconds() {
+        let clock = MovieClock::new(24.0, Some(2.5));
+        assert!(!clock.finished(59));
+        assert!(clock.finished(60));
+    }
+
+    #[test]
+    fn clock_rejects_bad_fps_and_seconds() {
+        let clock = MovieClock::new(-5.0, Some(f32::NAN));
+        assert_eq!(clock.fps(), DEFAULT_MOVIE_FPS);
+        assert!(!clock.finished(1_000_000));
+    }
+
+    #[test]
+    fn thumbnail_clock_is_short_and_frames_once() {
+        let mut clock = MovieClock::thumbnail();
+        assert!(clock.finished(THUMBNAIL_FRAMES));
+        assert!(!clock.finished(THUMBNAIL_FRAMES - 1));
+        assert!(clock.needs_thumbnail_framing());
+        clock.mark_thumbnail_framed();
+        assert!(!clock.needs_thumbnail_framing());
+        assert!(!MovieClock::new(60.0, None).needs_thumbnail_framing());
+    }
+
+    #[test]
+    fn capture_clock_keeps_scene_camera_and_frame_count() {
+        let clock = MovieClock::capture(12);
+        assert_eq!(clock.fps(), DEFAULT_MOVIE_FPS);
+        assert!(!clock.finished(11));
+        assert!(clock.finished(12));
+        assert!(!clock.needs_thumbnail_framing());
+        assert!(MovieClock::capture(0).finished(1));
+    }
+}
@@ -0,0 +1,144 @@
+//! Ring of recently submitted render command tags, dumped next to a crash
+//! minidump so a driver crash shows what the engine last asked the GPU for.
+//!
+//! Off unless the crash reporter enables it; recording is a tag copy per
+//! command (no formatting) and skips the frame if the lock is contended.
+//! The dump formats into a static buffer, so the crash handler never
+//! touches the allocator.
+
+use perro_render_bridge::RenderCommand;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+pub(crate) const RENDER_TRAIL_LEN: usize = 256;
+// Room for `frame <u64> <tag>` per entry; tags are short static names.
+const RENDER_TRAIL_TEXT_CAP: usize = RENDER_TRAIL_LEN * 64;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static TRAIL: Mutex<RenderTrail> = Mutex::new(RenderTrail::new());
+static TRAIL_TEXT: Mutex<[u8; RENDER_TRAIL_TEXT_CAP]> = Mutex::new([0; RENDER_TRAIL_TEXT_CAP]);
+
+pub(crate) struct RenderTrail {
+    entries: [(u64, &'static str); RENDER_TRAIL_LEN],
+    next: usize,
+    len: usize,
+    frame: u64,
+}
+
+impl RenderTrail {
+    pub(crate) const fn new() -> Self {
+        Self {
+            entries: [(0, ""); RENDER_TRAIL_LEN],
+            next: 0,
+            len: 0,
+            frame: 0,
+        }
+    }
+
+    pub(crate) fn record_frame(&mut self, labels: impl ExactSizeIterator<Item = &'static str>) {
+        self.frame += 1;
+        // Only the tail of a huge frame can survive the ring anyway.
+        let skip = labels.len().saturating_sub(RENDER_TRAIL_LEN);
+        for label in labels.skip(skip) {
+            self.entries[self.next] = (self.frame, label);
+            self.next = (self.next + 1) % RENDER_TRAIL_LEN;
+            self.len = (self.len + 1).min(RENDER_TRAIL_LEN);
+        }
+    }
+
+    /// Oldest first, one `frame <n> <tag>` line per command. Writes into
+    /// `out` w/o allocating, keeping only whole lines that fit; returns the
+    /// byte count.
+    pub(crate) fn write_text(&self, out: &mut [u8]) -> usize {
+        let start = (self.next + RENDER_TRAIL_LEN - self.len) % RENDER_TRAIL_LEN;
+        let mut written = 0;
+        for i in 0..self.len {
+            let (frame, label) = self.entries[(start + i) % RENDER_TRAIL_LEN];
+            let mut digits = [0_u8; 20];
+            let digits = format_u64(frame, &mut digits);
+            let line = [b"frame ".as_slice(), digits, b" ", label.as_bytes(), b"\n"];
+            let line_len: usize = line.iter().map(|part| part.len()).sum();
+            if written + line_len > out.len() {
+                break;
+            }
+            for part in line {
+                out[written..written + part.len()].copy_from_slice(part);
+                written += part.len();
+            }
+        }
+        written
+    }
+}
+
+fn format_u64(mut value: u64, buf: &mut [u8; 20]) -> &[u8] {
+    let mut at = buf.len();
+    loop {
+        at -= 1;
+        buf[at] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            return &buf[at..];
+        }
+    }
+}
+
+pub(crate) fn enable_render_trail() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+#[inline]
+pub(crate) fn record_render_commands(commands: &[RenderCommand]) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    if let Ok(mut trail) = TRAIL.try_lock() {
+        trail.record_frame(commands.iter().map(RenderCommand::trace_label));
+    }
+}
+
+/// Hands the formatted trail to `send` w/o allocating (safe from a signal
+/// handler). Skipped if the crashing thread held a lock mid-record.
+pub(crate) fn with_render_trail_text(send: impl FnOnce(&[u8])) {
+    let (Ok(trail), Ok(mut text)) = (TRAIL.try_lock(), TRAIL_TEXT.try_lock()) else {
+        return;
+    };
+    let len = trail.write_text(text.as_mut_slice());
+    send(&text[..len]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(trail: &RenderTrail, cap: usize) -> String {
+        let mut out = vec![0; cap];
+        let len = trail.write_text(&mut out);
+        out.truncate(len);
+        String::from_utf8(out).expect("trail text is utf-8")
+    }
+
+    #[test]
+    fn trail_keeps_newest_commands_in_order() {
+        let mut trail = RenderTrail::new();
+        trail.record_frame(["2d", "ui"].into_iter());
+        let many = vec!["3d"; RENDER_TRAIL_LEN - 1];
+        trail.record_frame(many.into_iter());
+        let text = text(&trail, RENDER_TRAIL_TEXT_CAP);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), RENDER_TRAIL_LEN);
+        assert_eq!(lines[0], "frame 1 ui");
+        assert_eq!(lines[RENDER_TRAIL_LEN - 1], "frame 2 3d");
+    }
+
+    #[test]
+    fn empty_trail_dumps_nothing() {
+        assert!(text(&RenderTrail::new(), RENDER_TRAIL_TEXT_CAP).is_empty());
+    }
+
+    #[test]
+    fn full_buffer_keeps_whole_lines_only() {
+        let mut trail = RenderTrail::new();
+        trail.record_frame(["2d", "ui"].into_iter());
+        assert_eq!(text(&trail, 20), "frame 1 2d\n");
+    }
+}
@@ -4,7 +4,8 @@
 //! overshoot badly and forces frame-pacing code to fall back to busy-spinning
 //! for high-rate caps. Requesting 1ms resolution (`timeBeginPeriod(1)`) lets
 //! sleep-based waits land within ~1-2ms, so the sim/render loops can sleep
-//! most of the remaining interval instead of spinning a full core.
+//! most of the remaining interval instead of spinning a full core. If the
+//! request fails, `FramePacer` learns the larger overshoot and spins longer.
 //!
 //! This is process-wide OS state, so it is acquired once near app startup
 //! and released via `Drop` when the app exits.
@@ -10,8 +10,10 @@ use perro_render_bridge::{
     Camera2DState, Command2D, Rect2DCommand, RenderCommand, RenderRequestID, ResourceCommand,
     Sprite2DCommand,
 };
-use perro_runtime::{WindowMode, WindowRequest};
+use perro_runtime::{DEFAULT_MAX_FIXED_STEPS_PER_FRAME, WindowMode, WindowRequest};
 use perro_runtime_api::sub_apis::FrameRateCap as RuntimeFrameRateCap;
+#[cfg(any(feature = "profile_heavy", feature = "mem_profile"))]
+use perro_runtime_api::sub_apis::MemoryReport;
 use std::io::Write;
 #[cfg(not(target_arch = "wasm32"))]
 use std::sync::{
@@ -52,10 +54,10 @@ use startup_splash::{
     STARTUP_SPLASH_TEXTURE_REQUEST, StartupSplashState,
 };
 
-use crate::frame_pacing::{FRAME_WAKE_HEADROOM, FramePacer, project_frame_rate_cap};
+use crate::frame_pacing::{FramePacer, project_frame_rate_cap};
+use crate::movie_mode::MovieClock;
 
 const DEFAULT_FIXED_TIMESTEP: Option<f32> = None;
-const MAX_FIXED_STEPS_PER_FRAME: u32 = 2;
 const MAX_FRAME_DELTA_SECONDS: f32 = 0.250;
 const LOG_INTERVAL_SECONDS: f32 = 3.0;
 #[cfg(not(any(feature = "profile_heavy", feature = "ui_profile", feature = "fps")))]
@@ -144,15 +146,18 @@ fn plan_fixed_steps(
     frame_delta_seconds: f32,
     fixed_timestep: f32,
     accumulator: f32,
+    max_steps: u32,
 ) -> FixedStepPlan {
     let mut next_accumulator =
         accumulator + frame_delta_seconds.clamp(0.0, MAX_FRAME_DELTA_SECONDS);
     let mut steps = 0u32;
-    while next_accumulator >= fixed_timestep && steps < MAX_FIXED_STEPS_PER_FRAME {
+    while next_accumulator >= fixed_timestep && steps < max_steps {
         next_accumulator -= fixed_timestep;
         steps += 1;
     }
-    let dropped_catchup = steps == MAX_FIXED_STEPS_PER_FRAME && next_accumulator >= fixed_timestep;
+    // Spiral-of-death guard: a backlog past `max_steps` is dropped, not
+    // carried, so one slow frame cannot force every later frame to catch up.
+    let dropped_catchup = steps == max_steps && next_accumulator >= fixed_timestep;
     if dropped_catchup {
         next_accumulator %= fixed_timestep;
     }
@@ -366,6 +371,7 @@ struct MemProfileCsvSample {
     avg_present_wait_us: u128,
     avg_frame_us: u128,
     avg_fps: f64,
+    memory: MemoryReport,
 }
 
 #[cfg(any(feature = "profile_heavy", feature = "mem_profile"))]
@@ -379,7 +385,7 @@ impl MemProfileCsvWriter {
             .ok()?;
         let _ = writeln!(
             file,
-            "batch_end_frame,rss_bytes,virtual_bytes,rss_mib,virtual_mib,avg_update_us,avg_render_us,avg_idle_us,avg_present_wait_us,avg_frame_us,avg_fps"
+            "batch_end_frame,rss_bytes,virtual_bytes,rss_mib,virtual_mib,avg_update_us,avg_render_us,avg_idle_us,avg_present_wait_us,avg_frame_us,avg_fps,node_bytes,script_bytes,variant_bytes,scene_cache_bytes,archive_bytes,gpu_bytes"
         );
         Some(Self { file })
     }
@@ -387,7 +393,7 @@ impl MemProfileCsvWriter {
     fn write(&mut self, row: MemProfileCsvSample) {
         let _ = writeln!(
             self.file,
-            "{},{},{},{:.6},{:.6},{},{},{},{},{},{:.6}",
+            "{},{},{},{:.6},{:.6},{},{},{},{},{},{:.6},{},{},{},{},{},{}",
             row.batch_end_frame,
             row.sample.physical_mem,
             row.sample.virtual_mem,
@@ -399,6 +405,12 @@ impl MemProfileCsvWriter {
             row.avg_present_wait_us,
             row.avg_frame_us,
             row.avg_fps,
+            row.memory.node_arena_bytes,
+            row.memory.script_state_bytes,
+            row.memory.variant_bytes,
+            row.memory.scene_cache_bytes,
+            row.memory.asset_archive_bytes,
+            row.memory.gpu_bytes,
         );
         let _ = self.file.flush();
     }
@@ -410,6 +422,28 @@ fn bytes_to_mib(bytes: usize) -> f64 {
     bytes as f64 / (1024.0 * 1024.0)
 }
 
+#[cfg(all(
+    any(feature = "profile_heavy", feature = "mem_profile"),
+    not(perro_no_console)
+))]
+fn log_memory_report(sample: ProcessMemorySample, report: &MemoryReport) {
+    let mib = |bytes: u64| bytes as f64 / (1024.0 * 1024.0);
+    let mut out = std::io::stdout().lock();
+    let _ = writeln!(
+        out,
+        "mem: rss=({:.2}MiB) | nodes=({}, {:.2}MiB) | scripts=({}, {:.2}MiB) | variants=({:.2}MiB) | scenes=({:.2}MiB) | archives=({:.2}MiB) | gpu=({:.2}MiB)",
+        bytes_to_mib(sample.physical_mem),
+        report.node_count,
+        mib(report.node_arena_bytes),
+        report.script_count,
+        mib(report.script_state_bytes),
+        mib(report.variant_bytes),
+        mib(report.scene_cache_bytes),
+        mib(report.asset_archive_bytes),
+        mib(report.gpu_bytes),
+    );
+}
+
 #[cfg(all(feature = "fps", not(perro_no_console)))]
 #[inline]
 fn log_avg_sampled(
@@ -463,18 +497,22 @@ pub enum AppExitKind {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct AppExitResult {
     pub kind: AppExitKind,
+    /// Code passed to `ctx.OS().quit(code)`; 0 for every other exit.
+    pub code: i32,
 }
 
 impl AppExitResult {
     pub fn window_close() -> Self {
         Self {
             kind: AppExitKind::WindowClose,
+            code: 0,
         }
     }
 
     pub fn event_loop_exit() -> Self {
         Self {
             kind: AppExitKind::EventLoopExit,
+            code: 0,
         }
     }
 
@@ -738,7 +776,9 @@ struct RunnerState<B: GraphicsBackend> {
     batch_heavy: BatchHeavyStats,
     fixed_timestep: Option<f32>,
     fixed_accumulator: f32,
+    max_fixed_steps: u32,
     pacer: FramePacer,
+    movie: Option<MovieClock>,
     frame_index: u64,
     fps_window_start: Instant,
     fps_window_frames: u32,
@@ -955,6 +995,13 @@ impl<B: GraphicsBackend> winit::application::ApplicationHandler<RunnerUserEvent>
                 }
                 self.kbm_input.handle_window_event(&mut self.app, &event);
             }
+            WindowEvent::Touch(touch) => {
+                if self.startup_splash.blocks_input() {
+                    return;
+                }
+                let (phase, point) = crate::input::map_winit_touch(&touch);
+                self.app.set_touch(phase, point);
+            }
             ime_event @ WindowEvent::Ime(_) => {
                 if self.startup_splash.blocks_input() {
                     return;
@@ -985,7 +1032,11 @@ impl<B: GraphicsBackend> winit::application::ApplicationHandler<RunnerUserEvent>
                 self.step_frame(event_loop, Instant::now());
             }
             WindowEvent::CloseRequested => {
-                self.request_exit(event_loop, AppExitResult::window_close());
+                // quit_handlers may hold or cancel; a held quit closes later
+                // through the CloseApp window request
+                if self.app.runtime.begin_quit(0) {
+                    self.request_exit(event_loop, AppExitResult::window_close());
+                }
             }
             _ => {}
         }
@@ -1,12 +1,12 @@
-use super::{MAX_FIXED_STEPS_PER_FRAME, StartupSplashState, plan_fixed_steps};
+use super::{DEFAULT_MAX_FIXED_STEPS_PER_FRAME, StartupSplashState, plan_fixed_steps};
 use std::time::Instant;
 #[cfg(not(target_arch = "wasm32"))]
 use winit::dpi::PhysicalSize;
 
 #[test]
 fn fixed_step_plan_caps_large_delta() {
-    let plan = plan_fixed_steps(1.0, 1.0 / 60.0, 0.0);
-    assert_eq!(plan.steps, MAX_FIXED_STEPS_PER_FRAME);
+    let plan = plan_fixed_steps(1.0, 1.0 / 60.0, 0.0, DEFAULT_MAX_FIXED_STEPS_PER_FRAME);
+    assert_eq!(plan.steps, DEFAULT_MAX_FIXED_STEPS_PER_FRAME);
     assert!(plan.dropped_catchup);
     assert!(plan.accumulator_after < 1.0 / 60.0);
 }
@@ -15,7 +15,7 @@ fn fixed_step_plan_caps_large_delta() {
 fn fixed_step_plan_keeps_substep_remainder() {
     let step = 1.0 / 60.0;
     let start = step * 0.5;
-    let plan = plan_fixed_steps(step * 2.25, step, start);
+    let plan = plan_fixed_steps(step * 2.25, step, start, DEFAULT_MAX_FIXED_STEPS_PER_FRAME);
     assert_eq!(plan.steps, 2);
     assert!(!plan.dropped_catchup);
     assert!((plan.accumulator_after - (step * 0.75)).abs() < 1e-6);
@@ -25,12 +25,25 @@ fn fixed_step_plan_keeps_substep_remainder() {
 fn fixed_step_plan_drops_full_catchup_but_keeps_fractional_progress() {
     let step = 1.0 / 60.0;
     let start = step * 0.25;
-    let plan = plan_fixed_steps(step * 20.0, step, start);
-    assert_eq!(plan.steps, MAX_FIXED_STEPS_PER_FRAME);
+    let plan = plan_fixed_steps(step * 20.0, step, start, DEFAULT_MAX_FIXED_STEPS_PER_FRAME);
+    assert_eq!(plan.steps, DEFAULT_MAX_FIXED_STEPS_PER_FRAME);
     assert!(plan.dropped_catchup);
     assert!(plan.accumulator_after < step);
 }
 
+#[test]
+fn fixed_step_plan_honors_configured_catchup_limit() {
+    let step = 1.0 / 60.0;
+    let plan = plan_fixed_steps(step * 6.5, step, 0.0, 8);
+    assert_eq!(plan.steps, 6);
+    assert!(!plan.dropped_catchup);
+
+    let plan = plan_fixed_steps(step * 6.5, step, 0.0, 1);
+    assert_eq!(plan.steps, 1);
+    assert!(plan.dropped_catchup);
+    assert!((plan.accumulator_after - step * 0.5).abs() < 1e-5);
+}
+
 #[test]
 fn startup_splash_blocks_input_only_until_first_frame_capture() {
     let mut splash = StartupSplashState {
@@ -5,6 +5,13 @@ impl<B: GraphicsBackend> RunnerState<B> {
         if event_loop.exiting() || self.exit_result.is_some() {
             return;
         }
+        #[cfg(not(target_arch = "wasm32"))]
+        let now = if self.pacer.blocks_frame(now) && self.pacer.in_final_wait(now) {
+            // inside wake headroom: finish precisely here, no Poll bounce
+            self.pacer.wait_for_deadline()
+        } else {
+            now
+        };
         if self.pacer.blocks_frame(now) {
             self.apply_frame_control_flow(event_loop, now);
             return;
@@ -12,19 +19,26 @@ impl<B: GraphicsBackend> RunnerState<B> {
         self.frame_index = self.frame_index.saturating_add(1);
         let frame_index = self.frame_index;
         let frame_start = now;
-        let frame_delta = frame_start.duration_since(self.last_frame_start);
+        let frame_delta = match self.movie.as_ref() {
+            Some(clock) => clock.frame_delta(),
+            None => frame_start.duration_since(self.last_frame_start),
+        };
         self.last_frame_start = frame_start;
 
         let fps_window_elapsed = frame_start.duration_since(self.fps_window_start);
         if fps_window_elapsed.as_secs_f32() >= FPS_WINDOW_SECONDS && self.fps_window_frames > 0 {
             self.app
                 .set_fps(self.fps_window_frames as f32 / fps_window_elapsed.as_secs_f32());
+            self.app.refresh_gpu_memory_bytes();
             self.fps_window_start = frame_start;
             self.fps_window_frames = 0;
         }
         self.fps_window_frames = self.fps_window_frames.saturating_add(1);
 
-        let elapsed_since_start = frame_start.duration_since(self.run_start);
+        let elapsed_since_start = match self.movie.as_ref() {
+            Some(clock) => clock.elapsed(frame_index),
+            None => frame_start.duration_since(self.run_start),
+        };
         self.app.set_elapsed_time(elapsed_since_start.as_secs_f32());
         let simulated_delta_seconds;
         let should_sample_timing = self.should_sample_timing();
@@ -80,6 +94,7 @@ impl<B: GraphicsBackend> RunnerState<B> {
                     frame_delta.as_secs_f32(),
                     effective_fixed_step,
                     self.fixed_accumulator,
+                    self.max_fixed_steps,
                 );
                 fixed_steps = plan.steps;
                 fixed_step_seconds = plan.step_seconds;
@@ -157,6 +172,13 @@ impl<B: GraphicsBackend> RunnerState<B> {
             .unwrap_or(Duration::ZERO);
         let runtime_timing = self.app.update_runtime(frame_delta.as_secs_f32());
         runtime_update_duration += runtime_timing.total;
+        if let Some(clock) = self.movie.as_mut()
+            && clock.needs_thumbnail_framing()
+            && self.app.runtime.frame_scene_for_thumbnail().is_some()
+        {
+            // retried each frame until the scene has spatial nodes
+            clock.mark_thumbnail_framed();
+        }
         self.apply_mouse_mode_request();
         self.apply_cursor_icon_request();
         self.apply_window_requests(event_loop);
@@ -185,6 +207,12 @@ impl<B: GraphicsBackend> RunnerState<B> {
             None
         };
         self.apply_cursor_icon_request();
+        if let Some(clock) = self.movie.as_ref()
+            && clock.finished(frame_index)
+        {
+            eprintln!("[perro][movie] recorded ({frame_index}) frames");
+            self.request_exit(event_loop, AppExitResult::event_loop_exit());
+        }
         #[cfg(feature = "profile_heavy")]
         let work_duration = work_start.elapsed();
         #[cfg(not(feature = "profile_heavy"))]
@@ -434,6 +462,8 @@ impl<B: GraphicsBackend> RunnerState<B> {
                 } else {
                     0.0
                 };
+                let memory = self.app.runtime.memory_report();
+                log_memory_report(sample, &memory);
                 if let Some(csv) = &mut self.mem_profile_csv {
                     csv.write(MemProfileCsvSample {
                         batch_end_frame: self.frame_index,
@@ -444,6 +474,7 @@ impl<B: GraphicsBackend> RunnerState<B> {
                         avg_present_wait_us,
                         avg_frame_us,
                         avg_fps,
+                        memory,
                     });
                 }
             }
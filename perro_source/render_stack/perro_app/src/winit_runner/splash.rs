@@ -69,7 +69,8 @@ impl<B: GraphicsBackend> RunnerState<B> {
                     .startup_splash
                     .source_hash
                     .map(|v| v.to_string())
-                    .unwrap_or(source),
+                    .unwrap_or(source)
+                    .into(),
                 reserved: true,
             }));
         }
@@ -164,6 +165,7 @@ impl<B: GraphicsBackend> RunnerState<B> {
                     frame_delta.as_secs_f32(),
                     effective_fixed_step,
                     self.fixed_accumulator,
+                    self.max_fixed_steps,
                 );
                 fixed_steps = plan.steps;
                 fixed_step_seconds = plan.step_seconds;
@@ -22,11 +22,23 @@ impl<B: GraphicsBackend> RunnerState<B> {
             .project()
             .map(|project| project_frame_rate_cap(project.config.frame_rate_cap))
             .unwrap_or(RuntimeFrameRateCap::Unlimited);
+        let max_fixed_steps = app
+            .runtime
+            .project()
+            .map(|project| project.config.max_fixed_steps_per_frame.max(1))
+            .unwrap_or(DEFAULT_MAX_FIXED_STEPS_PER_FRAME);
         let vsync_enabled = app
             .runtime
             .project()
             .map(|project| project.config.vsync)
             .unwrap_or(false);
+        let movie = MovieClock::from_env();
+        // Recording runs as fast as capture allows; the movie clock sets time.
+        let (frame_rate_cap, vsync_enabled) = if movie.is_some() {
+            (RuntimeFrameRateCap::Unlimited, false)
+        } else {
+            (frame_rate_cap, vsync_enabled)
+        };
         eprintln!("[perro][runtime] frame_rate_cap=({frame_rate_cap:?})");
         Self {
             app,
@@ -34,7 +46,9 @@ impl<B: GraphicsBackend> RunnerState<B> {
             window: None,
             fixed_timestep: normalized_fixed_timestep,
             fixed_accumulator: 0.0,
+            max_fixed_steps,
             pacer: FramePacer::new(frame_rate_cap, vsync_enabled),
+            movie,
             last_frame_start: now,
             last_frame_end: now,
             run_start: now,
@@ -248,10 +262,11 @@ impl<B: GraphicsBackend> RunnerState<B> {
         }
     }
 
-    pub(super) fn request_exit(&mut self, event_loop: &ActiveEventLoop, result: AppExitResult) {
+    pub(super) fn request_exit(&mut self, event_loop: &ActiveEventLoop, mut result: AppExitResult) {
         if self.exit_result.is_some() {
             return;
         }
+        result.code = self.app.runtime.exit_code();
         self.exit_result = Some(result);
         self.reset_mouse_mode_for_exit();
         if let Some(window) = self.window.take() {
@@ -290,12 +305,11 @@ impl<B: GraphicsBackend> RunnerState<B> {
         if let Some(deadline) = self.pacer.deadline()
             && deadline > now
         {
-            // OS timers overshoot by a few ms; wake early and poll the rest.
-            // With 1ms-resolution system timers (see timer_resolution.rs),
-            // WaitUntil is accurate enough to use for high-rate caps too, so
-            // only intervals too small to leave any wake headroom fall back
-            // to Poll (busy event loop).
-            let wake_at = deadline.checked_sub(FRAME_WAKE_HEADROOM);
+            // OS timers overshoot; wake early by the learned overshoot and
+            // let step_frame finish w/ sleep+spin (FramePacer::wait_for_deadline).
+            // 1ms system timers (timer_resolution.rs) kp that margin small;
+            // a coarse timer just widens it instead of oversleeping.
+            let wake_at = deadline.checked_sub(self.pacer.wake_headroom());
             if wake_at.is_none_or(|wake_at| wake_at <= now) {
                 event_loop.set_control_flow(ControlFlow::Poll);
             } else if let Some(wake_at) = wake_at {
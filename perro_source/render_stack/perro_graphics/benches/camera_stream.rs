@@ -290,7 +290,7 @@ fn setup_stream_webcam(window: &Arc<Window>, resolution: u32, sprite_count: u32)
         ResourceCommand::CreateExternalTexture {
             request: RenderRequestID::new(9),
             id: texture,
-            source: "webcam://bench".to_string(),
+            source: "webcam://bench".into(),
             reserved: true,
             width: resolution,
             height: resolution,
@@ -471,7 +471,7 @@ fn create_texture(graphics: &mut PerroGraphics) -> TextureID {
         ResourceCommand::CreateRuntimeTexture {
             request: RenderRequestID::new(1),
             id: TextureID::nil(),
-            source: "runtime://camera-stream-bench".to_string(),
+            source: "runtime://camera-stream-bench".into(),
             reserved: true,
             width: 1,
             height: 1,
@@ -495,7 +495,7 @@ fn create_mesh_material(graphics: &mut PerroGraphics) -> (MeshID, MaterialID) {
         RenderCommand::Resource(ResourceCommand::CreateRuntimeMesh {
             request: RenderRequestID::new(2),
             id: MeshID::nil(),
-            source: "__bench_mesh__".to_string(),
+            source: "__bench_mesh__".into(),
             mesh: tiny_mesh(),
             reserved: true,
         }),
@@ -283,7 +283,7 @@ fn create_texture(graphics: &mut PerroGraphics) -> TextureID {
         ResourceCommand::CreateRuntimeTexture {
             request: RenderRequestID::new(1),
             id: TextureID::nil(),
-            source: "runtime://cpu-prepare-bench".to_string(),
+            source: "runtime://cpu-prepare-bench".into(),
             reserved: true,
             width: 1,
             height: 1,
@@ -299,7 +299,7 @@ fn create_mesh_material(graphics: &mut PerroGraphics) -> (MeshID, MaterialID) {
         RenderCommand::Resource(ResourceCommand::CreateRuntimeMesh {
             request: RenderRequestID::new(2),
             id: MeshID::nil(),
-            source: "__bench_mesh__".to_string(),
+            source: "__bench_mesh__".into(),
             reserved: true,
             mesh: tiny_mesh(),
         }),
@@ -509,7 +509,7 @@ fn bench_resource_churn(c: &mut Criterion) {
                             RenderCommand::Resource(ResourceCommand::CreateTexture {
                                 request: RenderRequestID::new(i as u64),
                                 id: TextureID::nil(),
-                                source: format!("__bench_texture_{i}__"),
+                                source: format!("__bench_texture_{i}__").into(),
                                 reserved: false,
                             }),
                             RenderCommand::Resource(ResourceCommand::CreateMaterial {
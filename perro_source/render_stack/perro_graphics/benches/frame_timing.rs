@@ -215,7 +215,7 @@ fn create_texture(graphics: &mut PerroGraphics) -> TextureID {
         ResourceCommand::CreateRuntimeTexture {
             request: RenderRequestID::new(1),
             id: TextureID::nil(),
-            source: "runtime://frame-timing-bench".to_string(),
+            source: "runtime://frame-timing-bench".into(),
             reserved: true,
             width: 1,
             height: 1,
@@ -239,7 +239,7 @@ fn create_mesh_material(graphics: &mut PerroGraphics) -> (MeshID, MaterialID) {
         RenderCommand::Resource(ResourceCommand::CreateRuntimeMesh {
             request: RenderRequestID::new(2),
             id: MeshID::nil(),
-            source: "__bench_mesh__".to_string(),
+            source: "__bench_mesh__".into(),
             reserved: true,
             mesh: tiny_mesh(),
         }),
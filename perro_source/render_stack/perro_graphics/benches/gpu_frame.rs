@@ -1098,7 +1098,7 @@ fn create_texture(graphics: &mut PerroGraphics) -> TextureID {
         ResourceCommand::CreateRuntimeTexture {
             request: RenderRequestID::new(1),
             id: TextureID::nil(),
-            source: "runtime://gpu-frame-bench".to_string(),
+            source: "runtime://gpu-frame-bench".into(),
             reserved: true,
             width: 1,
             height: 1,
@@ -1129,7 +1129,7 @@ fn create_mesh_material_with(
         RenderCommand::Resource(ResourceCommand::CreateRuntimeMesh {
             request: RenderRequestID::new(2),
             id: MeshID::nil(),
-            source: "__bench_mesh__".to_string(),
+            source: "__bench_mesh__".into(),
             reserved: true,
             mesh: mesh_data,
         }),
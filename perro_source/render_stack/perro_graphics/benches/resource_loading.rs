@@ -9,7 +9,7 @@ fn texture_create(source: &str, request: u64) -> RenderCommand {
     RenderCommand::Resource(ResourceCommand::CreateTexture {
         request: RenderRequestID::new(request),
         id: TextureID::nil(),
-        source: source.to_string(),
+        source: source.into(),
         reserved: false,
     })
 }
@@ -18,7 +18,7 @@ fn mesh_create(source: &str, request: u64) -> RenderCommand {
     RenderCommand::Resource(ResourceCommand::CreateMesh {
         request: RenderRequestID::new(request),
         id: MeshID::nil(),
-        source: source.to_string(),
+        source: source.into(),
         reserved: false,
     })
 }
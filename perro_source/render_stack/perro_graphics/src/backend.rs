@@ -18,8 +18,9 @@ use crate::{
 use ahash::AHashMap;
 use perro_graphics_assets::{
     SVG_RASTER_SCALE, decode_image_rgba, decode_ptex, load_mesh3d_from_bytes, load_texture_rgba,
+    missing_mesh3d, missing_texture_rgba,
 };
-use perro_ids::{MaterialID, MeshID, NodeID, TextureID};
+use perro_ids::{AssetPath, MaterialID, MeshID, NodeID, TextureID};
 use perro_render_bridge::{
     CameraStreamCommand, CameraStreamSourceState, CameraStreamState, Command2D, Command3D,
     Decal3DState, DisplayCommand, HdrMode, Light2DState, Material3D, PointParticles3DState,
@@ -143,6 +144,8 @@ pub struct GraphicsProfileSnapshot {
     pub active_meshes: u32,
     pub active_materials: u32,
     pub active_textures: u32,
+    /// Approx bytes held by cached GPU textures + mesh storage.
+    pub gpu_memory_bytes: u64,
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -209,16 +212,15 @@ struct CommandSummary {
 struct AsyncMeshLoadResult {
     request: perro_render_bridge::RenderRequestID,
     id: MeshID,
-    source: String,
+    source: AssetPath,
     mesh: Option<perro_render_bridge::Mesh3D>,
-    error: Option<String>,
 }
 
 #[cfg(all(not(target_arch = "wasm32"), not(test)))]
 struct AsyncMeshLoadJob {
     request: perro_render_bridge::RenderRequestID,
     id: MeshID,
-    source: String,
+    source: AssetPath,
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -230,7 +232,7 @@ struct AsyncTextureLoadResult {
 #[cfg(not(target_arch = "wasm32"))]
 struct AsyncTextureLoadJob {
     id: TextureID,
-    source: String,
+    source: AssetPath,
 }
 
 impl FrameState {
@@ -451,9 +453,15 @@ pub struct PerroGraphics {
     accessibility: VisualAccessibilitySettings,
     frame_index: u32,
     redraw_requested: bool,
+    // Dev shader error set last shown, and the window title before the
+    // error suffix was appended.
+    shader_error_revision: u64,
+    shader_error_title_base: Option<String>,
     frame_time_seconds: f32,
     frame_delta_seconds: f32,
     last_frame_instant: Option<Instant>,
+    #[cfg(not(target_arch = "wasm32"))]
+    movie: Option<crate::movie::MovieRecorder>,
     #[cfg(target_arch = "wasm32")]
     pending_gpu: Option<Arc<Mutex<Option<Gpu>>>>,
 }
@@ -470,8 +478,11 @@ mod config;
 
 mod assets;
 mod graphics_backend;
+#[cfg(not(target_arch = "wasm32"))]
+mod movie;
 mod queries;
 mod render_bridge;
+mod shader_reload;
 
 #[cfg(test)]
 #[path = "../tests/unit/backend_tests.rs"]
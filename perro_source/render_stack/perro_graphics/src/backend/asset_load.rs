@@ -16,12 +16,12 @@ impl PerroGraphics {
                 .unwrap_or_else(|| perro_ids::string_to_u64(source));
             let bytes = lookup(source_hash);
             if !bytes.is_empty() {
-                decode_ptex(bytes)?
+                decode_ptex(bytes).unwrap_or_else(|| Self::missing_texture(source))
             } else {
-                Self::decode_texture_file(source)?
+                Self::decode_texture_file(source)
             }
         } else {
-            Self::decode_texture_file(source)?
+            Self::decode_texture_file(source)
         };
         Some(DecodedTextureRgba {
             rgba,
@@ -30,8 +30,26 @@ impl PerroGraphics {
         })
     }
 
-    pub(super) fn decode_texture_file(source: &str) -> Option<(Vec<u8>, u32, u32)> {
-        load_texture_rgba(source)
+    /// Unresolvable sources decode to the missing-texture checker.
+    pub(super) fn decode_texture_file(source: &str) -> (Vec<u8>, u32, u32) {
+        load_texture_rgba(source).unwrap_or_else(|| Self::missing_texture(source))
+    }
+
+    fn missing_texture(source: &str) -> (Vec<u8>, u32, u32) {
+        perro_io::report_missing_asset("texture", source, "not found or failed to decode");
+        missing_texture_rgba()
+    }
+
+    /// Unresolvable sources load as the missing-mesh cube.
+    pub(super) fn load_mesh_or_missing(
+        source: &str,
+        static_mesh_lookup: Option<StaticMeshLookup>,
+    ) -> Option<perro_render_bridge::Mesh3D> {
+        if let Err(reason) = validate_mesh_source(source, static_mesh_lookup) {
+            perro_io::report_missing_asset("mesh", source, &reason);
+            return Some(missing_mesh3d());
+        }
+        load_mesh3d_from_source(source, static_mesh_lookup)
     }
 
     #[cfg(all(not(target_arch = "wasm32"), not(test)))]
@@ -39,7 +57,7 @@ impl PerroGraphics {
         &mut self,
         request: perro_render_bridge::RenderRequestID,
         id: MeshID,
-        source: String,
+        source: AssetPath,
     ) {
         self.queued_async_mesh_loads.push(AsyncMeshLoadJob {
             request,
@@ -58,18 +76,12 @@ impl PerroGraphics {
         let static_mesh_lookup = self.static_mesh_lookup;
         rayon::spawn(move || {
             for job in jobs {
-                let error = validate_mesh_source(job.source.as_str(), static_mesh_lookup).err();
-                let mesh = if error.is_none() {
-                    load_mesh3d_from_source(job.source.as_str(), static_mesh_lookup)
-                } else {
-                    None
-                };
+                let mesh = Self::load_mesh_or_missing(job.source.as_str(), static_mesh_lookup);
                 let _ = tx.send(AsyncMeshLoadResult {
                     request: job.request,
                     id: job.id,
                     source: job.source,
                     mesh,
-                    error,
                 });
             }
         });
@@ -80,14 +92,9 @@ impl PerroGraphics {
         &mut self,
         request: perro_render_bridge::RenderRequestID,
         id: MeshID,
-        source: String,
+        source: AssetPath,
     ) {
-        if let Err(reason) = validate_mesh_source(source.as_str(), self.static_mesh_lookup) {
-            self.resources.drop_mesh(id);
-            self.events.push(RenderEvent::Failed { request, reason });
-            return;
-        }
-        let mesh_data = load_mesh3d_from_source(source.as_str(), self.static_mesh_lookup);
+        let mesh_data = Self::load_mesh_or_missing(source.as_str(), self.static_mesh_lookup);
         if let Some(mesh) = mesh_data.clone() {
             self.resources
                 .set_runtime_mesh_data(source.as_str(), mesh.clone());
@@ -107,16 +114,6 @@ impl PerroGraphics {
                 .pending_async_mesh_loads
                 .remove(&result.id)
                 .unwrap_or_else(|| vec![result.request]);
-            if let Some(reason) = result.error {
-                self.resources.drop_mesh(result.id);
-                for request in requests {
-                    self.events.push(RenderEvent::Failed {
-                        request,
-                        reason: reason.clone(),
-                    });
-                }
-                continue;
-            }
             if let Some(mesh) = result.mesh.clone() {
                 self.resources
                     .set_runtime_mesh_data(result.source.as_str(), mesh.clone());
@@ -140,7 +137,7 @@ impl PerroGraphics {
     pub(super) fn flush_async_mesh_loads(&mut self) {}
 
     #[cfg(not(target_arch = "wasm32"))]
-    pub(super) fn start_async_texture_load(&mut self, id: TextureID, source: String) {
+    pub(super) fn start_async_texture_load(&mut self, id: TextureID, source: AssetPath) {
         self.queued_async_texture_loads
             .push(AsyncTextureLoadJob { id, source });
     }
@@ -185,7 +182,7 @@ impl PerroGraphics {
         &mut self,
         request: perro_render_bridge::RenderRequestID,
         id: TextureID,
-        source: String,
+        source: AssetPath,
     ) {
         match Self::decode_texture_source(source.as_str(), self.static_texture_lookup) {
             Some(texture) => {
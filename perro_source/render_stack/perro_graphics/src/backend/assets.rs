@@ -17,6 +17,24 @@ impl PerroGraphics {
         &mut self,
         late_overlay_commands: I,
     ) -> Option<DrawFrameTiming>
+    where
+        I: IntoIterator<Item = RenderCommand>,
+    {
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.movie.is_some() {
+            // Every movie frame must hit the swapchain, changed or not.
+            self.redraw_requested = true;
+            let timing = self.draw_frame_timed_uncaptured(late_overlay_commands);
+            self.record_movie_frame();
+            return timing;
+        }
+        self.draw_frame_timed_uncaptured(late_overlay_commands)
+    }
+
+    fn draw_frame_timed_uncaptured<I>(
+        &mut self,
+        late_overlay_commands: I,
+    ) -> Option<DrawFrameTiming>
     where
         I: IntoIterator<Item = RenderCommand>,
     {
@@ -36,11 +54,14 @@ impl PerroGraphics {
         self.poll_async_mesh_loads();
         self.poll_async_texture_loads();
         let now = Instant::now();
-        self.frame_delta_seconds = self
+        let wall_delta = self
             .last_frame_instant
             .map(|prev| now.duration_since(prev).as_secs_f32())
             .unwrap_or(0.0)
             .max(0.0);
+        #[cfg(not(target_arch = "wasm32"))]
+        let wall_delta = self.movie_frame_delta_seconds().unwrap_or(wall_delta);
+        self.frame_delta_seconds = wall_delta;
         self.last_frame_instant = Some(now);
         self.frame_time_seconds =
             (self.frame_time_seconds + self.frame_delta_seconds).rem_euclid(1.0e9);
@@ -48,6 +69,7 @@ impl PerroGraphics {
             std::mem::take(&mut self.frame.scratch_late_overlay_commands);
         late_overlay_pending.clear();
         late_overlay_pending.extend(late_overlay_commands);
+        let shaders_invalidated = self.poll_dev_shader_reload(&mut late_overlay_pending);
         let has_pending = !self.frame.pending_commands.is_empty();
         let has_late_overlay = !late_overlay_pending.is_empty()
             || self.late_overlay_2d.retained_sprite_count() > 0
@@ -87,7 +109,12 @@ impl PerroGraphics {
         std::mem::swap(&mut pending, &mut self.frame.pending_commands);
         let pending_command_count = pending.len();
         let command_summary = summarize_commands(&pending);
-        let frame_dirty_bits = command_summary.dirty_bits;
+        let mut frame_dirty_bits = command_summary.dirty_bits;
+        if shaders_invalidated {
+            // Retained draws keep their pipeline tokens; force a full
+            // re-prepare so reloaded custom materials are rebuilt.
+            frame_dirty_bits |= DIRTY_RESOURCES;
+        }
         let process_start = Instant::now();
         self.reserve_command_buckets(&command_summary);
         let mut camera_commands = std::mem::take(&mut self.frame.scratch_camera_commands);
@@ -88,9 +88,13 @@ impl PerroGraphics {
             accessibility: VisualAccessibilitySettings::default(),
             frame_index: 0,
             redraw_requested: true,
+            shader_error_revision: 0,
+            shader_error_title_base: None,
             frame_time_seconds: 0.0,
             frame_delta_seconds: 0.0,
             last_frame_instant: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            movie: None,
             #[cfg(target_arch = "wasm32")]
             pending_gpu: None,
         }
@@ -54,6 +54,7 @@ impl GraphicsBackend for PerroGraphics {
                         .push(RenderEvent::HdrStatusChanged(gpu_ref.hdr_status()));
                 }
                 self.gpu = gpu;
+                self.enable_movie_capture();
                 self.redraw_requested = true;
             }
         }
@@ -105,6 +106,7 @@ impl GraphicsBackend for PerroGraphics {
             active_meshes: self.resources.active_mesh_count() as u32,
             active_materials: self.resources.active_material_count() as u32,
             active_textures: self.resources.active_texture_count() as u32,
+            gpu_memory_bytes: self.gpu.as_ref().map_or(0, Gpu::approx_memory_bytes),
         }
     }
 
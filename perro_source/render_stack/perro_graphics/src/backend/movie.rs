@@ -0,0 +1,48 @@
+use super::*;
+use crate::movie::MovieRecorder;
+
+impl PerroGraphics {
+    /// Movie-maker mode: every drawn frame is captured and handed to the
+    /// recorder, and shader time advances by the movie frame delta instead of
+    /// wall clock. Forces SDR + no vsync so capture is 8-bit and unthrottled.
+    pub fn with_movie_recorder(mut self, recorder: MovieRecorder) -> Self {
+        self.hdr_mode = HdrMode::Off;
+        self.vsync_enabled = false;
+        self.movie = Some(recorder);
+        self
+    }
+
+    pub fn movie_frames_written(&self) -> Option<u64> {
+        self.movie.as_ref().map(MovieRecorder::frames_written)
+    }
+
+    pub(super) fn movie_frame_delta_seconds(&self) -> Option<f32> {
+        self.movie
+            .as_ref()
+            .map(|movie| movie.settings().frame_delta_seconds())
+    }
+
+    pub(super) fn enable_movie_capture(&mut self) {
+        if self.movie.is_none() {
+            return;
+        }
+        let Some(gpu) = self.gpu.as_mut() else {
+            return;
+        };
+        if !gpu.set_frame_capture(true) {
+            eprintln!("[perro][movie] surface does not support readback; recording disabled");
+            self.movie = None;
+        }
+    }
+
+    pub(super) fn record_movie_frame(&mut self) {
+        let Some(movie) = self.movie.as_mut() else {
+            return;
+        };
+        let frame = self.gpu.as_mut().and_then(Gpu::take_captured_frame);
+        if let Err(err) = movie.write_frame(frame) {
+            eprintln!("[perro][movie] {err}; recording stopped");
+            self.movie = None;
+        }
+    }
+}
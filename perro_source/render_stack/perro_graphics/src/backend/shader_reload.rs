@@ -0,0 +1,78 @@
+use super::*;
+use crate::shader_source::{poll_changed_shaders, shader_errors};
+
+const SHADER_ERROR_BANNER_NODE: NodeID =
+    NodeID::from_u64(perro_ids::string_to_u64("__shader_error_banner__"));
+const SHADER_ERROR_BANNER_Z: i32 = 990;
+const SHADER_ERROR_BANNER_HEIGHT_FRAC: f32 = 0.04;
+const SHADER_ERROR_BANNER_COLOR: [f32; 4] = [0.78, 0.08, 0.08, 0.92];
+
+impl PerroGraphics {
+    /// Dev runs only: rebuild custom pipelines whose shader files changed and
+    /// surface load/validation errors as a red banner plus a window-title
+    /// suffix. Returns true when pipelines were invalidated, so the caller can
+    /// force a full re-prepare of retained draws.
+    pub(super) fn poll_dev_shader_reload(&mut self, late_overlay: &mut Vec<RenderCommand>) -> bool {
+        if self.static_shader_lookup.is_some() {
+            return false;
+        }
+        let changed = poll_changed_shaders();
+        let invalidated = !changed.is_empty();
+        if invalidated {
+            if let Some(gpu) = self.gpu.as_mut() {
+                gpu.invalidate_custom_shaders(&changed);
+            }
+            self.redraw_requested = true;
+        }
+        let (revision, errors) = shader_errors();
+        if revision == self.shader_error_revision {
+            return invalidated;
+        }
+        self.shader_error_revision = revision;
+        self.redraw_requested = true;
+
+        let headline = errors
+            .first()
+            .and_then(|error| error.lines().next())
+            .map(str::to_string);
+        match headline.as_ref() {
+            Some(_) => {
+                let [width, height] = Gpu::virtual_size();
+                let banner_height = (height * SHADER_ERROR_BANNER_HEIGHT_FRAC).max(8.0);
+                late_overlay.push(RenderCommand::TwoD(Command2D::UpsertRect {
+                    node: SHADER_ERROR_BANNER_NODE,
+                    rect: perro_render_bridge::Rect2DCommand {
+                        center: [0.0, (height - banner_height) * 0.5],
+                        size: [width, banner_height],
+                        color: SHADER_ERROR_BANNER_COLOR.into(),
+                        z_index: SHADER_ERROR_BANNER_Z,
+                    },
+                }));
+            }
+            None => late_overlay.push(RenderCommand::TwoD(Command2D::RemoveNode {
+                node: SHADER_ERROR_BANNER_NODE,
+            })),
+        }
+        if let Some(gpu) = self.gpu.as_ref() {
+            let window = gpu.window();
+            match headline {
+                Some(headline) => {
+                    let base = self
+                        .shader_error_title_base
+                        .get_or_insert_with(|| window.title());
+                    let more = match errors.len() {
+                        0 | 1 => String::new(),
+                        count => format!(" (+{} more)", count - 1),
+                    };
+                    window.set_title(&format!("{base} - shader error: {headline}{more}"));
+                }
+                None => {
+                    if let Some(base) = self.shader_error_title_base.take() {
+                        window.set_title(&base);
+                    }
+                }
+            }
+        }
+        invalidated
+    }
+}
@@ -554,6 +554,10 @@ pub struct Gpu {
     indirect_first_instance_enabled: bool,
     multi_draw_indirect_enabled: bool,
     gpu_timer: Option<GpuTimestampTimer>,
+    #[cfg(not(target_arch = "wasm32"))]
+    frame_capture: Option<capture::FrameCapture>,
+    #[cfg(not(target_arch = "wasm32"))]
+    captured_frame: Option<crate::movie::CapturedFrame>,
 }
 
 #[derive(Clone, Copy)]
@@ -710,6 +714,8 @@ pub struct RenderGpuTiming {
     pub presented: bool,
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+mod capture;
 mod frame;
 mod lifecycle;
 mod textures;
@@ -0,0 +1,204 @@
+use super::*;
+use crate::movie::CapturedFrame;
+
+const CAPTURE_BYTES_PER_PIXEL: u32 = 4;
+
+/// Swapchain readback for movie recording. Copies the presented frame into a
+/// mappable buffer and blocks until it lands on the CPU, so every simulated
+/// step yields exactly one frame (throughput is not a goal here).
+pub(super) struct FrameCapture {
+    buffer: Option<wgpu::Buffer>,
+    width: u32,
+    height: u32,
+    padded_bytes_per_row: u32,
+    bgra: bool,
+    copied: bool,
+    warned_format: bool,
+}
+
+impl FrameCapture {
+    pub(super) fn new() -> Self {
+        Self {
+            buffer: None,
+            width: 0,
+            height: 0,
+            padded_bytes_per_row: 0,
+            bgra: false,
+            copied: false,
+            warned_format: false,
+        }
+    }
+
+    pub(super) fn encode(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture: &wgpu::Texture,
+        format: wgpu::TextureFormat,
+    ) {
+        self.copied = false;
+        let Some(bgra) = capture_format_is_bgra(format) else {
+            if !self.warned_format {
+                eprintln!(
+                    "[perro][movie] surface format {format:?} not capturable; set graphics.hdr = \"off\""
+                );
+                self.warned_format = true;
+            }
+            return;
+        };
+        let width = texture.width().max(1);
+        let height = texture.height().max(1);
+        if self.buffer.is_none() || self.width != width || self.height != height {
+            self.padded_bytes_per_row = padded_bytes_per_row(width);
+            self.buffer = Some(device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("perro_movie_capture_readback"),
+                size: u64::from(self.padded_bytes_per_row) * u64::from(height),
+                usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+                mapped_at_creation: false,
+            }));
+            self.width = width;
+            self.height = height;
+        }
+        let Some(buffer) = self.buffer.as_ref() else {
+            return;
+        };
+        self.bgra = bgra;
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(self.padded_bytes_per_row),
+                    rows_per_image: Some(height),
+                },
+            },
+            wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        );
+        self.copied = true;
+    }
+
+    /// Call after the encoder carrying `encode` was submitted.
+    pub(super) fn read(&mut self, device: &wgpu::Device) -> Option<CapturedFrame> {
+        if !std::mem::take(&mut self.copied) {
+            return None;
+        }
+        let buffer = self.buffer.as_ref()?;
+        let slice = buffer.slice(..);
+        let (tx, rx) = mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = tx.send(result);
+        });
+        let _ = device.poll(wgpu::PollType::wait_indefinitely());
+        if !matches!(rx.recv(), Ok(Ok(()))) {
+            buffer.unmap();
+            return None;
+        }
+        let rgba = {
+            let Ok(mapped) = slice.get_mapped_range() else {
+                buffer.unmap();
+                return None;
+            };
+            unpad_rows(
+                &mapped,
+                self.width,
+                self.height,
+                self.padded_bytes_per_row,
+                self.bgra,
+            )
+        };
+        buffer.unmap();
+        Some(CapturedFrame {
+            width: self.width,
+            height: self.height,
+            rgba,
+        })
+    }
+}
+
+/// `Some(true)` = BGRA byte order, `Some(false)` = RGBA, `None` = not 8-bit.
+pub(super) fn capture_format_is_bgra(format: wgpu::TextureFormat) -> Option<bool> {
+    match format {
+        wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb => Some(false),
+        wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => Some(true),
+        _ => None,
+    }
+}
+
+pub(super) fn padded_bytes_per_row(width: u32) -> u32 {
+    let unpadded = width * CAPTURE_BYTES_PER_PIXEL;
+    unpadded.div_ceil(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT) * wgpu::COPY_BYTES_PER_ROW_ALIGNMENT
+}
+
+pub(super) fn unpad_rows(
+    mapped: &[u8],
+    width: u32,
+    height: u32,
+    padded_bytes_per_row: u32,
+    bgra: bool,
+) -> Vec<u8> {
+    let row_bytes = (width * CAPTURE_BYTES_PER_PIXEL) as usize;
+    let mut rgba = Vec::with_capacity(row_bytes * height as usize);
+    for row in mapped
+        .chunks(padded_bytes_per_row as usize)
+        .take(height as usize)
+    {
+        let start = rgba.len();
+        rgba.extend_from_slice(&row[..row_bytes.min(row.len())]);
+        if bgra {
+            for px in rgba[start..].chunks_exact_mut(4) {
+                px.swap(0, 2);
+            }
+        }
+    }
+    rgba
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_pad_to_copy_alignment() {
+        assert_eq!(padded_bytes_per_row(1), wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+        assert_eq!(padded_bytes_per_row(64), 256);
+        assert_eq!(padded_bytes_per_row(65), 512);
+    }
+
+    #[test]
+    fn unpad_strips_row_padding_and_swizzles_bgra() {
+        let padded = padded_bytes_per_row(1) as usize;
+        let mut mapped = vec![0u8; padded * 2];
+        mapped[..4].copy_from_slice(&[1, 2, 3, 4]);
+        mapped[padded..padded + 4].copy_from_slice(&[5, 6, 7, 8]);
+        assert_eq!(
+            unpad_rows(&mapped, 1, 2, padded as u32, true),
+            vec![3, 2, 1, 4, 7, 6, 5, 8]
+        );
+        assert_eq!(
+            unpad_rows(&mapped, 1, 2, padded as u32, false),
+            vec![1, 2, 3, 4, 5, 6, 7, 8]
+        );
+    }
+
+    #[test]
+    fn hdr_surfaces_are_not_capturable() {
+        assert_eq!(
+            capture_format_is_bgra(wgpu::TextureFormat::Bgra8UnormSrgb),
+            Some(true)
+        );
+        assert_eq!(
+            capture_format_is_bgra(wgpu::TextureFormat::Rgba16Float),
+            None
+        );
+    }
+}
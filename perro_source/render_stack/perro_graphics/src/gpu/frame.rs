@@ -1476,6 +1476,15 @@ impl Gpu {
         if let Some(water) = self.water.as_mut() {
             water.encode_readback(&mut encoder);
         }
+        #[cfg(not(target_arch = "wasm32"))]
+        if let (Some(capture), Some(frame)) = (self.frame_capture.as_mut(), frame.as_ref()) {
+            capture.encode(
+                &self.device,
+                &mut encoder,
+                &frame.texture,
+                self.config.format,
+            );
+        }
         let submit_start = Instant::now();
         let submit_finish_start = Instant::now();
         let command_buffer = encoder.finish();
@@ -1513,6 +1522,10 @@ impl Gpu {
             timing.present = present_start.elapsed();
             timing.presented = true;
         }
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(capture) = self.frame_capture.as_mut() {
+            self.captured_frame = capture.read(&self.device);
+        }
         timing.total = total_start.elapsed();
         timing
     }
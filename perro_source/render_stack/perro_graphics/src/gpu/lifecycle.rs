@@ -57,15 +57,95 @@ impl Gpu {
                 multiview_mask: None,
             });
         }
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(capture) = self.frame_capture.as_mut() {
+            capture.encode(
+                &self.device,
+                &mut encoder,
+                &frame.texture,
+                self.config.format,
+            );
+        }
         self.queue.submit(Some(encoder.finish()));
         self.queue.present(frame);
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(capture) = self.frame_capture.as_mut() {
+            self.captured_frame = capture.read(&self.device);
+        }
         true
     }
 
+    /// Movie recording: swapchain needs COPY_SRC so presented frames can be
+    /// read back. Returns false if the surface cannot be copied from.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn set_frame_capture(&mut self, enabled: bool) -> bool {
+        if enabled == self.frame_capture.is_some() {
+            return true;
+        }
+        if enabled {
+            let caps = self.surface.get_capabilities(&self.adapter);
+            if !caps.usages.contains(wgpu::TextureUsages::COPY_SRC) {
+                return false;
+            }
+            self.config.usage |= wgpu::TextureUsages::COPY_SRC;
+            self.frame_capture = Some(capture::FrameCapture::new());
+        } else {
+            self.config.usage.remove(wgpu::TextureUsages::COPY_SRC);
+            self.frame_capture = None;
+            self.captured_frame = None;
+        }
+        self.surface.configure(&self.device, &self.config);
+        true
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn take_captured_frame(&mut self) -> Option<crate::movie::CapturedFrame> {
+        self.captured_frame.take()
+    }
+
+    /// Drop custom material, sky and post pipelines built from `shader_paths`,
+    /// including camera-stream copies, so the next frame rebuilds them.
+    pub fn invalidate_custom_shaders(&mut self, shader_paths: &[String]) {
+        if shader_paths.is_empty() {
+            return;
+        }
+        if let Some(three_d) = self.three_d.as_mut() {
+            three_d.invalidate_custom_shaders(shader_paths);
+        }
+        if let Some(three_d) = self.camera_stream_3d.as_mut() {
+            three_d.invalidate_custom_shaders(shader_paths);
+        }
+        self.post.invalidate_custom_shaders(shader_paths);
+        if let Some(post) = self.camera_stream_post.as_mut() {
+            post.invalidate_custom_shaders(shader_paths);
+        }
+    }
+
+    pub fn window(&self) -> &Window {
+        &self.window_handle
+    }
+
     pub fn hdr_status(&self) -> HdrStatus {
         self.hdr_status
     }
 
+    /// Approx GPU bytes held by cached textures + mesh storage across every
+    /// renderer. Surface, post, and per-frame scratch targets not counted.
+    pub fn approx_memory_bytes(&self) -> u64 {
+        let two_d = [&self.two_d, &self.late_overlay_2d, &self.camera_stream_2d]
+            .into_iter()
+            .flatten()
+            .map(Gpu2D::approx_texture_bytes)
+            .sum::<u64>();
+        let three_d = [&self.three_d, &self.camera_stream_3d]
+            .into_iter()
+            .flatten()
+            .map(Gpu3D::approx_resource_bytes)
+            .sum::<u64>();
+        let ui = self.ui.as_ref().map_or(0, GpuUi::approx_texture_bytes);
+        two_d + three_d + ui
+    }
+
     pub fn set_hdr_mode(&mut self, mode: HdrMode) -> HdrStatus {
         let caps = self.surface.get_capabilities(&self.adapter);
         let display = self.surface.display_hdr_info(&self.adapter);
@@ -306,6 +386,10 @@ impl Gpu {
             indirect_first_instance_enabled,
             multi_draw_indirect_enabled,
             gpu_timer,
+            #[cfg(not(target_arch = "wasm32"))]
+            frame_capture: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            captured_frame: None,
         })
     }
 
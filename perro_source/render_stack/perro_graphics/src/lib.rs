@@ -1,7 +1,9 @@
 mod backend;
 mod gpu;
+pub mod movie;
 mod postprocess;
 mod resources;
+mod shader_source;
 mod texture_mips;
 pub mod three_d;
 pub mod two_d;
@@ -0,0 +1,491 @@
+//! Movie-maker output: writes captured swapchain frames to disk as a PNG
+//! sequence, pipes them into ffmpeg as raw RGBA video, or keeps the last
+//! frame as a single PNG (downscaled thumbnail or full-size still).
+//!
+//! The runner drives the clock (fixed simulated delta, one frame per step);
+//! this module only owns where the pixels go.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, Command, Stdio};
+
+pub const DEFAULT_MOVIE_FPS: f32 = 60.0;
+pub const MIN_MOVIE_FPS: f32 = 1.0;
+pub const MAX_MOVIE_FPS: f32 = 240.0;
+pub const DEFAULT_THUMBNAIL_SIZE: u32 = 256;
+
+const VIDEO_EXTENSIONS: &[&str] = &["mp4", "mov", "mkv", "webm", "avi", "gif"];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MovieOutput {
+    /// `frame_000001.png`, `frame_000002.png`, ... in this directory.
+    PngSequence(PathBuf),
+    /// Encoded by an `ffmpeg` process found on PATH.
+    Ffmpeg(PathBuf),
+    /// Last frame only, box-filtered to fit `size` x `size`, written on finish.
+    Thumbnail { path: PathBuf, size: u32 },
+    /// Last frame only, full size, written on finish. Visual test capture.
+    Still(PathBuf),
+}
+
+impl MovieOutput {
+    /// Video extensions pipe to ffmpeg; anything else is a PNG directory.
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let is_video = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| {
+                VIDEO_EXTENSIONS
+                    .iter()
+                    .any(|video| ext.eq_ignore_ascii_case(video))
+            });
+        if is_video {
+            Self::Ffmpeg(path)
+        } else {
+            Self::PngSequence(path)
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::PngSequence(path)
+            | Self::Ffmpeg(path)
+            | Self::Thumbnail { path, .. }
+            | Self::Still(path) => path,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MovieSettings {
+    pub output: MovieOutput,
+    pub fps: f32,
+}
+
+impl MovieSettings {
+    pub fn new(output: MovieOutput, fps: f32) -> Self {
+        Self {
+            output,
+            fps: normalize_movie_fps(fps),
+        }
+    }
+
+    pub fn frame_delta_seconds(&self) -> f32 {
+        1.0 / self.fps
+    }
+}
+
+pub fn normalize_movie_fps(fps: f32) -> f32 {
+    if fps.is_finite() && fps > 0.0 {
+        fps.clamp(MIN_MOVIE_FPS, MAX_MOVIE_FPS)
+    } else {
+        DEFAULT_MOVIE_FPS
+    }
+}
+
+/// Tightly packed RGBA8 frame read back from the swapchain.
+#[derive(Clone, Debug, Default)]
+pub struct CapturedFrame {
+    pub width: u32,
+    pub height: u32,
+    pub rgba: Vec<u8>,
+}
+
+struct FfmpegPipe {
+    child: Child,
+    stdin: Option<ChildStdin>,
+    width: u32,
+    height: u32,
+}
+
+pub struct MovieRecorder {
+    settings: MovieSettings,
+    frames_written: u64,
+    last_frame: Option<CapturedFrame>,
+    ffmpeg: Option<FfmpegPipe>,
+    last_frame_written: bool,
+}
+
+impl MovieRecorder {
+    pub fn new(settings: MovieSettings) -> Result<Self, String> {
+        if let MovieOutput::PngSequence(dir) = &settings.output {
+            std::fs::create_dir_all(dir).map_err(|err| {
+                format!("failed to create movie output dir {}: {err}", dir.display())
+            })?;
+        }
+        Ok(Self {
+            settings,
+            frames_written: 0,
+            last_frame: None,
+            ffmpeg: None,
+            last_frame_written: false,
+        })
+    }
+
+    pub fn settings(&self) -> &MovieSettings {
+        &self.settings
+    }
+
+    pub fn frames_written(&self) -> u64 {
+        self.frames_written
+    }
+
+    /// Writes one output frame. `None` = renderer skipped/failed the capture;
+    /// the last frame repeats so the video stays locked to simulation steps.
+    pub fn write_frame(&mut self, frame: Option<CapturedFrame>) -> Result<(), String> {
+        if let Some(frame) = frame {
+            self.last_frame = Some(frame);
+        }
+        let Some(frame) = self.last_frame.as_ref() else {
+            return Ok(());
+        };
+        let index = self.frames_written + 1;
+        match &self.settings.output {
+            MovieOutput::PngSequence(dir) => write_png_frame(dir, index, frame)?,
+            MovieOutput::Ffmpeg(path) => {
+                if self.ffmpeg.is_none() {
+                    self.ffmpeg = Some(spawn_ffmpeg(path, self.settings.fps, frame)?);
+                }
+                if let Some(pipe) = self.ffmpeg.as_mut() {
+                    write_ffmpeg_frame(pipe, frame)?;
+                }
+            }
+            // written once in finish(); later frames just replace last_frame
+            MovieOutput::Thumbnail { .. } | MovieOutput::Still(_) => {}
+        }
+        self.frames_written = index;
+        Ok(())
+    }
+
+    /// Closes the ffmpeg pipe and waits for the encoder to flush, or writes
+    /// the thumbnail/still from the last captured frame.
+    pub fn finish(&mut self) -> Result<(), String> {
+        let single = match &self.settings.output {
+            MovieOutput::Thumbnail { path, size } => Some((path, *size)),
+            // thumbnail_size never upscales, so no cap = full size
+            MovieOutput::Still(path) => Some((path, u32::MAX)),
+            MovieOutput::PngSequence(_) | MovieOutput::Ffmpeg(_) => None,
+        };
+        if let Some((path, size)) = single {
+            if self.last_frame_written {
+                return Ok(());
+            }
+            let Some(frame) = self.last_frame.as_ref() else {
+                return Err(format!("no frame captured for {}", path.display()));
+            };
+            self.last_frame_written = true;
+            return write_single_frame(path, size, frame);
+        }
+        let Some(mut pipe) = self.ffmpeg.take() else {
+            return Ok(());
+        };
+        drop(pipe.stdin.take());
+        let status = pipe
+            .child
+            .wait()
+            .map_err(|err| format!("failed to wait for ffmpeg: {err}"))?;
+        if !status.success() {
+            return Err(format!("ffmpeg exited with {:?}", status.code()));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for MovieRecorder {
+    fn drop(&mut self) {
+        if let Err(err) = self.finish() {
+            eprintln!("[perro][movie] {err}");
+        }
+    }
+}
+
+pub fn png_frame_path(dir: &Path, index: u64) -> PathBuf {
+    dir.join(format!("frame_{index:06}.png"))
+}
+
+pub fn ffmpeg_args(fps: f32, width: u32, height: u32, output: &Path) -> Vec<String> {
+    let mut args = vec![
+        "-y".to_string(),
+        "-loglevel".to_string(),
+        "error".to_string(),
+        "-f".to_string(),
+        "rawvideo".to_string(),
+        "-pix_fmt".to_string(),
+        "rgba".to_string(),
+        "-s".to_string(),
+        format!("{width}x{height}"),
+        "-framerate".to_string(),
+        format!("{fps}"),
+        "-i".to_string(),
+        "-".to_string(),
+    ];
+    let is_gif = output
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("gif"));
+    if !is_gif {
+        // yuv420p needs even dims; pad by one px instead of failing on odd windows.
+        args.extend(
+            [
+                "-vf",
+                "pad=ceil(iw/2)*2:ceil(ih/2)*2",
+                "-pix_fmt",
+                "yuv420p",
+            ]
+            .map(str::to_string),
+        );
+    }
+    args.push(output.to_string_lossy().to_string());
+    args
+}
+
+fn write_png_frame(dir: &Path, index: u64, frame: &CapturedFrame) -> Result<(), String> {
+    let path = png_frame_path(dir, index);
+    image::save_buffer(
+        &path,
+        &frame.rgba,
+        frame.width,
+        frame.height,
+        image::ExtendedColorType::Rgba8,
+    )
+    .map_err(|err| format!("failed to write movie frame {}: {err}", path.display()))
+}
+
+/// Largest size with `width`/`height`'s aspect that fits `max` x `max`.
+/// Never upscales; each side stays >= 1.
+pub fn thumbnail_size(width: u32, height: u32, max: u32) -> (u32, u32) {
+    let max = max.max(1);
+    if width <= max && height <= max {
+        return (width.max(1), height.max(1));
+    }
+    let scale = max as f64 / width.max(height) as f64;
+    (
+        ((width as f64 * scale).round() as u32).clamp(1, max),
+        ((height as f64 * scale).round() as u32).clamp(1, max),
+    )
+}
+
+/// Box-filter `frame` down to fit `max` x `max`. Each output px averages
+/// the source px block it covers.
+pub fn downscale_rgba(frame: &CapturedFrame, max: u32) -> CapturedFrame {
+    let (width, height) = thumbnail_size(frame.width, frame.height, max);
+    if width == frame.width && height == frame.height {
+        return frame.clone();
+    }
+    let (src_w, src_h) = (frame.width as usize, frame.height as usize);
+    let mut rgba = Vec::with_capacity(width as usize * height as usize * 4);
+    for y in 0..height as usize {
+        let y0 = y * src_h / height as usize;
+        let y1 = ((y + 1) * src_h / height as usize).max(y0 + 1);
+        for x in 0..width as usize {
+            let x0 = x * src_w / width as usize;
+            let x1 = ((x + 1) * src_w / width as usize).max(x0 + 1);
+            let mut sum = [0u32; 4];
+            for sy in y0..y1 {
+                for sx in x0..x1 {
+                    let i = (sy * src_w + sx) * 4;
+                    for (c, total) in sum.iter_mut().enumerate() {
+                        *total += frame.rgba.get(i + c).copied().unwrap_or(0) as u32;
+                    }
+                }
+            }
+            let count = ((y1 - y0) * (x1 - x0)) as u32;
+            rgba.extend(sum.map(|total| (total / count) as u8));
+        }
+    }
+    CapturedFrame {
+        width,
+        height,
+        rgba,
+    }
+}
+
+fn write_single_frame(path: &Path, size: u32, frame: &CapturedFrame) -> Result<(), String> {
+    if let Some(parent) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .map_err(|err| format!("failed to create capture dir {}: {err}", parent.display()))?;
+    }
+    let thumb = downscale_rgba(frame, size);
+    image::save_buffer(
+        path,
+        &thumb.rgba,
+        thumb.width,
+        thumb.height,
+        image::ExtendedColorType::Rgba8,
+    )
+    .map_err(|err| format!("failed to write {}: {err}", path.display()))
+}
+
+fn spawn_ffmpeg(path: &Path, fps: f32, frame: &CapturedFrame) -> Result<FfmpegPipe, String> {
+    if let Some(parent) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(|err| {
+            format!(
+                "failed to create movie output dir {}: {err}",
+                parent.display()
+            )
+        })?;
+    }
+    let mut child = Command::new("ffmpeg")
+        .args(ffmpeg_args(fps, frame.width, frame.height, path))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .spawn()
+        .map_err(|err| format!("failed to launch ffmpeg (is it on PATH?): {err}"))?;
+    let stdin = child.stdin.take();
+    Ok(FfmpegPipe {
+        child,
+        stdin,
+        width: frame.width,
+        height: frame.height,
+    })
+}
+
+fn write_ffmpeg_frame(pipe: &mut FfmpegPipe, frame: &CapturedFrame) -> Result<(), String> {
+    // rawvideo stream has a fixed size; a resized window would corrupt it.
+    if frame.width != pipe.width || frame.height != pipe.height {
+        return Err(format!(
+            "window resized mid-recording ({}x{} -> {}x{}); ffmpeg output needs a fixed size",
+            pipe.width, pipe.height, frame.width, frame.height
+        ));
+    }
+    let Some(stdin) = pipe.stdin.as_mut() else {
+        return Err("ffmpeg stdin closed".to_string());
+    };
+    stdin
+        .write_all(&frame.rgba)
+        .map_err(|err| format!("failed to pipe frame to ffmpeg: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_kind_follows_extension() {
+        assert_eq!(
+            MovieOutput::from_path("captures/trailer.MP4"),
+            MovieOutput::Ffmpeg(PathBuf::from("captures/trailer.MP4"))
+        );
+        assert_eq!(
+            MovieOutput::from_path("captures/frames"),
+            MovieOutput::PngSequence(PathBuf::from("captures/frames"))
+        );
+    }
+
+    #[test]
+    fn movie_fps_clamps_and_defaults() {
+        assert_eq!(normalize_movie_fps(0.0), DEFAULT_MOVIE_FPS);
+        assert_eq!(normalize_movie_fps(f32::NAN), DEFAULT_MOVIE_FPS);
+        assert_eq!(normalize_movie_fps(1000.0), MAX_MOVIE_FPS);
+        assert_eq!(normalize_movie_fps(30.0), 30.0);
+    }
+
+    #[test]
+    fn png_frames_are_zero_padded_and_one_based() {
+        assert_eq!(
+            png_frame_path(Path::new("out"), 1),
+            Path::new("out").join("frame_000001.png")
+        );
+    }
+
+    #[test]
+    fn ffmpeg_args_describe_raw_rgba_input() {
+        let args = ffmpeg_args(30.0, 1280, 720, Path::new("out.mp4"));
+        let joined = args.join(" ");
+        assert!(joined.contains("-f rawvideo -pix_fmt rgba -s 1280x720 -framerate 30 -i -"));
+        assert!(joined.contains("yuv420p"));
+        assert_eq!(args.last().map(String::as_str), Some("out.mp4"));
+    }
+
+    #[test]
+    fn missing_capture_repeats_last_frame() {
+        let dir = std::env::temp_dir().join(format!("perro_movie_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let mut recorder = MovieRecorder::new(MovieSettings::new(
+            MovieOutput::PngSequence(dir.clone()),
+            24.0,
+        ))
+        .expect("recorder");
+        recorder.write_frame(None).expect("empty write");
+        assert_eq!(recorder.frames_written(), 0);
+        let frame = CapturedFrame {
+            width: 2,
+            height: 1,
+            rgba: vec![255, 0, 0, 255, 0, 255, 0, 255],
+        };
+        recorder.write_frame(Some(frame)).expect("first frame");
+        recorder.write_frame(None).expect("repeat frame");
+        assert_eq!(recorder.frames_written(), 2);
+        assert!(png_frame_path(&dir, 2).exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn thumbnail_size_keeps_aspect_and_never_upscales() {
+        assert_eq!(thumbnail_size(1920, 1080, 256), (256, 144));
+        assert_eq!(thumbnail_size(1080, 1920, 256), (144, 256));
+        assert_eq!(thumbnail_size(100, 50, 256), (100, 50));
+        assert_eq!(thumbnail_size(4000, 1, 256), (256, 1));
+    }
+
+    #[test]
+    fn downscale_rgba_averages_source_blocks() {
+        let frame = CapturedFrame {
+            width: 2,
+            height: 2,
+            rgba: vec![
+                255, 0, 0, 255, 0, 0, 0, 255, //
+                255, 0, 0, 255, 0, 0, 0, 255,
+            ],
+        };
+        let thumb = downscale_rgba(&frame, 1);
+        assert_eq!((thumb.width, thumb.height), (1, 1));
+        assert_eq!(thumb.rgba, vec![127, 0, 0, 255]);
+    }
+
+    #[test]
+    fn thumbnail_output_writes_last_frame_on_finish() {
+        let dir = std::env::temp_dir().join(format!("perro_thumb_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("nested").join("a.scn.png");
+        let mut recorder = MovieRecorder::new(MovieSettings::new(
+            MovieOutput::Thumbnail {
+                path: path.clone(),
+                size: 1,
+            },
+            60.0,
+        ))
+        .expect("recorder");
+        let frame = CapturedFrame {
+            width: 2,
+            height: 1,
+            rgba: vec![255, 0, 0, 255, 0, 255, 0, 255],
+        };
+        recorder.write_frame(Some(frame)).expect("frame");
+        assert!(!path.exists());
+        recorder.finish().expect("finish");
+        assert!(path.exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn still_output_keeps_full_size_last_frame() {
+        let dir = std::env::temp_dir().join(format!("perro_still_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("main.scn.png");
+        let mut recorder =
+            MovieRecorder::new(MovieSettings::new(MovieOutput::Still(path.clone()), 60.0))
+                .expect("recorder");
+        let frame = CapturedFrame {
+            width: 3,
+            height: 2,
+            rgba: vec![40; 3 * 2 * 4],
+        };
+        recorder.write_frame(Some(frame)).expect("frame");
+        recorder.finish().expect("finish");
+        let still = image::open(&path).expect("still png");
+        assert_eq!((still.width(), still.height()), (3, 2));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
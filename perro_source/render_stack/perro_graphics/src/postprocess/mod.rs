@@ -2,6 +2,7 @@
 
 use crate::backend::{StaticShaderLookup, StaticTextureLookup};
 use crate::postprocess::shaders::{build_post_shader, create_builtin_shader_module};
+use crate::shader_source::{load_custom_shader_source, validate_custom_wgsl};
 use bytemuck::{Pod, Zeroable};
 use perro_graphics_assets::{decode_image_rgba, decode_ptex};
 use perro_io::load_asset;
@@ -1118,6 +1119,13 @@ impl PostProcessor {
         );
     }
 
+    /// Drop custom post pipelines built from `shader_paths` (dev hot-reload).
+    pub fn invalidate_custom_shaders(&mut self, shader_paths: &[String]) {
+        for shader_path in shader_paths {
+            self.custom_pipelines.remove(&post_shader_key(shader_path));
+        }
+    }
+
     fn ensure_custom_pipeline(
         &mut self,
         device: &wgpu::Device,
@@ -1128,20 +1136,11 @@ impl PostProcessor {
         if self.custom_pipelines.contains_key(&shader_key) {
             return self.custom_pipelines.get(&shader_key);
         }
-        let src = if let Some(lookup) = static_shader_lookup {
-            let shader_hash = perro_ids::parse_hashed_source_uri(shader_path)
-                .unwrap_or_else(|| perro_ids::string_to_u64(shader_path));
-            let src = lookup(shader_hash);
-            (!src.is_empty()).then(|| src.to_string())
-        } else {
-            None
-        }
-        .or_else(|| {
-            let bytes = load_asset(shader_path).ok()?;
-            let src = std::str::from_utf8(&bytes).ok()?;
-            Some(src.to_string())
-        })?;
+        let src = load_custom_shader_source(shader_path, static_shader_lookup)?;
         let wgsl = build_post_shader(&src);
+        if !validate_custom_wgsl(shader_path, static_shader_lookup, &wgsl) {
+            return None;
+        }
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("perro_post_custom"),
             source: wgpu::ShaderSource::Wgsl(wgsl.into()),
@@ -0,0 +1,238 @@
+//! Custom shader source loading shared by material, sky and post pipelines.
+//!
+//! Static builds read sources the pipeline already flattened. Dev runs load
+//! from `res://`, resolve `#include` directives, validate the final WGSL with
+//! naga before it reaches wgpu, and watch every file a shader was built from
+//! so an edit rebuilds the affected pipelines on the next frame. A shader that
+//! fails to load or validate is recorded as an error (shown in the dev error
+//! banner) and skipped until one of its files changes, instead of panicking
+//! inside `create_shader_module`.
+
+use crate::backend::StaticShaderLookup;
+use ahash::{AHashMap, AHashSet};
+use perro_graphics_assets::resolve_shader_includes;
+use perro_io::load_asset;
+use std::collections::BTreeMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, SystemTime};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+/// Minimum time between dev file polls; edits show up within a few frames.
+const SHADER_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Default)]
+struct ShaderDevState {
+    /// Watched file -> last seen mtime and the root shaders built from it.
+    watched: AHashMap<String, WatchedShaderFile>,
+    /// Root shader path -> first line of its load/validation error.
+    errors: BTreeMap<String, String>,
+    revision: u64,
+    last_poll: Option<Instant>,
+}
+
+struct WatchedShaderFile {
+    modified: Option<SystemTime>,
+    roots: AHashSet<String>,
+}
+
+static SHADER_DEV_STATE: LazyLock<Mutex<ShaderDevState>> =
+    LazyLock::new(|| Mutex::new(ShaderDevState::default()));
+
+fn with_state<T>(f: impl FnOnce(&mut ShaderDevState) -> T) -> T {
+    let mut state = SHADER_DEV_STATE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(&mut state)
+}
+
+/// Source for a custom shader path. Static lookups are used as-is (includes
+/// were flattened at embed time); otherwise the file loads from `res://` with
+/// includes resolved. Returns `None` while a dev shader has an unresolved
+/// error, so callers skip the draw instead of retrying every frame.
+pub(crate) fn load_custom_shader_source(
+    shader_path: &str,
+    static_shader_lookup: Option<StaticShaderLookup>,
+) -> Option<String> {
+    if let Some(lookup) = static_shader_lookup {
+        let shader_hash = perro_ids::parse_hashed_source_uri(shader_path)
+            .unwrap_or_else(|| perro_ids::string_to_u64(shader_path));
+        let src = lookup(shader_hash);
+        if !src.is_empty() {
+            return Some(src.to_string());
+        }
+    }
+    if with_state(|state| state.errors.contains_key(shader_path)) {
+        return None;
+    }
+    let Some(source) = load_shader_text(shader_path) else {
+        watch_shader_files(shader_path, &[shader_path.to_string()]);
+        report_shader_error(shader_path, format!("{shader_path}: shader not found"));
+        return None;
+    };
+    match resolve_shader_includes(shader_path, &source, &mut load_shader_text) {
+        Ok(resolved) => {
+            watch_shader_files(shader_path, &resolved.files);
+            Some(resolved.source)
+        }
+        Err(err) => {
+            // Watch the failing file too, so fixing or creating it retries.
+            watch_shader_files(shader_path, &[shader_path.to_string(), err.file.clone()]);
+            report_shader_error(shader_path, err.to_string());
+            None
+        }
+    }
+}
+
+/// Validate final WGSL built from `shader_path` before pipeline creation.
+/// Dev runs parse and validate with naga and record failures as shader
+/// errors; static builds skip the check.
+pub(crate) fn validate_custom_wgsl(
+    shader_path: &str,
+    static_shader_lookup: Option<StaticShaderLookup>,
+    wgsl: &str,
+) -> bool {
+    if static_shader_lookup.is_some() {
+        return true;
+    }
+    match check_wgsl(wgsl) {
+        Ok(()) => {
+            clear_shader_error(shader_path);
+            true
+        }
+        Err(message) => {
+            report_shader_error(shader_path, format!("{shader_path}: {message}"));
+            false
+        }
+    }
+}
+
+fn check_wgsl(wgsl: &str) -> Result<(), String> {
+    let module = naga::front::wgsl::parse_str(wgsl).map_err(|err| err.emit_to_string(wgsl))?;
+    naga::valid::Validator::new(
+        naga::valid::ValidationFlags::all(),
+        naga::valid::Capabilities::all(),
+    )
+    .validate(&module)
+    .map_err(|err| err.emit_to_string(wgsl))?;
+    Ok(())
+}
+
+/// Root shader paths whose watched files changed since the last poll. Their
+/// errors are cleared so the next pipeline request reloads them. Polls are
+/// throttled to [`SHADER_POLL_INTERVAL`].
+pub(crate) fn poll_changed_shaders() -> Vec<String> {
+    with_state(|state| {
+        let now = Instant::now();
+        if state
+            .last_poll
+            .is_some_and(|last| now.duration_since(last) < SHADER_POLL_INTERVAL)
+        {
+            return Vec::new();
+        }
+        state.last_poll = Some(now);
+        let mut changed = AHashSet::new();
+        for (path, file) in state.watched.iter_mut() {
+            let modified = shader_file_mtime(path);
+            if modified != file.modified {
+                file.modified = modified;
+                changed.extend(file.roots.iter().cloned());
+            }
+        }
+        let mut changed: Vec<String> = changed.into_iter().collect();
+        changed.sort();
+        for root in &changed {
+            if state.errors.remove(root).is_some() {
+                state.revision = state.revision.wrapping_add(1);
+            }
+        }
+        changed
+    })
+}
+
+/// Current dev shader errors, keyed by root shader path, with a revision that
+/// changes whenever the set does.
+pub(crate) fn shader_errors() -> (u64, Vec<String>) {
+    with_state(|state| (state.revision, state.errors.values().cloned().collect()))
+}
+
+fn report_shader_error(shader_path: &str, message: String) {
+    eprintln!("[perro_graphics] shader error: {message}");
+    with_state(|state| {
+        state.errors.insert(shader_path.to_string(), message);
+        state.revision = state.revision.wrapping_add(1);
+    });
+}
+
+fn clear_shader_error(shader_path: &str) {
+    with_state(|state| {
+        if state.errors.remove(shader_path).is_some() {
+            state.revision = state.revision.wrapping_add(1);
+        }
+    });
+}
+
+fn watch_shader_files(root: &str, files: &[String]) {
+    with_state(|state| {
+        for file in files {
+            let entry = state
+                .watched
+                .entry(file.clone())
+                .or_insert_with(|| WatchedShaderFile {
+                    modified: shader_file_mtime(file),
+                    roots: AHashSet::new(),
+                });
+            entry.roots.insert(root.to_string());
+        }
+    });
+}
+
+fn load_shader_text(path: &str) -> Option<String> {
+    let bytes = load_asset(path).ok()?;
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn shader_file_mtime(path: &str) -> Option<SystemTime> {
+    match perro_io::resolve_path(path) {
+        perro_io::ResolvedPath::Disk(disk) => std::fs::metadata(disk).ok()?.modified().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn shader_file_mtime(_path: &str) -> Option<SystemTime> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_wgsl_is_reported_and_cleared_on_success() {
+        let path = "res://shaders/__test_invalid.wgsl";
+        assert!(!validate_custom_wgsl(path, None, "fn main( {"));
+        let (revision, errors) = shader_errors();
+        assert!(errors.iter().any(|error| error.starts_with(path)));
+
+        assert!(validate_custom_wgsl(path, None, "fn main() {}"));
+        let (next_revision, errors) = shader_errors();
+        assert_ne!(revision, next_revision);
+        assert!(!errors.iter().any(|error| error.starts_with(path)));
+    }
+
+    #[test]
+    fn missing_dev_shader_is_skipped_until_it_changes() {
+        let path = "res://shaders/__test_missing.wgsl";
+        assert!(load_custom_shader_source(path, None).is_none());
+        assert!(with_state(|state| state.errors.contains_key(path)));
+        assert!(with_state(|state| state.watched.contains_key(path)));
+        // Negative cache: the second request returns without touching disk.
+        assert!(load_custom_shader_source(path, None).is_none());
+        clear_shader_error(path);
+    }
+}
@@ -12,6 +12,25 @@ pub(crate) fn rgba_mip_level_count(width: u32, height: u32) -> u32 {
     u32::BITS - max_dim.leading_zeros()
 }
 
+/// Approx resident bytes 4 one texture: every mip level, layer, and sample.
+/// Block-compressed formats count per block; formats w/o a copy size (depth
+/// + stencil) fall back 2 4 bytes/texel.
+pub(crate) fn approx_texture_bytes(texture: &wgpu::Texture) -> u64 {
+    let format = texture.format();
+    let block_bytes = u64::from(format.block_copy_size(None).unwrap_or(4));
+    let (block_w, block_h) = format.block_dimensions();
+    let layers =
+        u64::from(texture.depth_or_array_layers()) * u64::from(texture.sample_count().max(1));
+    (0..texture.mip_level_count())
+        .map(|level| {
+            let width = (texture.width() >> level).max(1);
+            let height = (texture.height() >> level).max(1);
+            u64::from(width.div_ceil(block_w)) * u64::from(height.div_ceil(block_h)) * block_bytes
+        })
+        .sum::<u64>()
+        * layers
+}
+
 pub(crate) fn build_rgba_levels_for_filter(
     rgba: &[u8],
     width: u32,
@@ -27,6 +27,7 @@ use crate::backend::{
     OcclusionCullingMode, StaticMeshLookup, StaticShaderLookup, StaticTextureLookup,
 };
 use crate::resources::ResourceStore;
+use crate::shader_source::{load_custom_shader_source, validate_custom_wgsl};
 use ahash::{AHashMap, AHashSet};
 use bytemuck::{Pod, Zeroable};
 use glam::{Mat4, Quat, Vec3, Vec4};
@@ -37,7 +38,6 @@ use perro_graphics_assets::{
     load_mesh_from_source_no_dynamic_lods, load_texture_rgba,
 };
 use perro_ids::MeshID;
-use perro_io::load_asset;
 use perro_render_bridge::{
     Camera3DState, CameraProjectionState, CustomMaterialLighting3D, DenseInstancePose3D,
     LODOptions3D, Material3D, MaterialParamOverride3D, MaterialParamOverrideValue3D,
@@ -25,6 +25,23 @@ impl Gpu3D {
         self.last_prepare_step_timing
     }
 
+    /// Approx bytes held by owned material textures + shared mesh
+    /// vertex/index buffers. Per-frame instance/cull scratch not counted.
+    pub fn approx_resource_bytes(&self) -> u64 {
+        let textures: u64 = self
+            .material_textures
+            .values()
+            .filter_map(|cached| cached.texture.as_ref())
+            .map(crate::texture_mips::approx_texture_bytes)
+            .sum();
+        let meshes = self.vertex_buffer.size()
+            + self.rigid_vertex_buffer.size()
+            + self.packed_lod_vertex_buffer.size()
+            + self.index_buffer.size()
+            + self.packed_lod_index_buffer.size();
+        textures + meshes
+    }
+
     pub(in super::super) fn fallback_material_texture_bind_group(
         &self,
     ) -> Option<&wgpu::BindGroup> {
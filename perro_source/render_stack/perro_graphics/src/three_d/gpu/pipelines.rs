@@ -25,6 +25,32 @@ impl Gpu3D {
         token
     }
 
+    /// Drop pipelines built from `shader_paths` so the next draw reloads
+    /// them (dev hot-reload). Tokens stay stable; only their pipelines and
+    /// vertex-hook flags go. Sky pipelines compose several passes under one
+    /// key, so any change rebuilds them all.
+    pub(crate) fn invalidate_custom_shaders(&mut self, shader_paths: &[String]) {
+        for shader_path in shader_paths {
+            for lighting in [
+                CustomMaterialLighting3D::Standard,
+                CustomMaterialLighting3D::Raw,
+            ] {
+                let key = custom_pipeline_key(shader_path, lighting);
+                let Some(&token) = self.custom_pipeline_tokens.get(&key) else {
+                    continue;
+                };
+                self.custom_pipelines.remove(&token);
+                self.custom_pipelines_rigid.remove(&token);
+                self.custom_pipelines_multimesh.remove(&token);
+                self.custom_pipeline_vertex_hooks.remove(&token);
+            }
+        }
+        if !shader_paths.is_empty() {
+            self.custom_sky_pipelines.clear();
+            self.active_sky_pipeline_key = None;
+        }
+    }
+
     pub(super) fn ensure_custom_pipeline(
         &mut self,
         device: &wgpu::Device,
@@ -43,19 +69,7 @@ impl Gpu3D {
         if path == RenderPath3D::MultiMesh && self.custom_pipelines_multimesh.contains_key(&token) {
             return Some(token);
         }
-        let src = if let Some(lookup) = static_shader_lookup {
-            let shader_hash = perro_ids::parse_hashed_source_uri(shader_path)
-                .unwrap_or_else(|| perro_ids::string_to_u64(shader_path));
-            let src = lookup(shader_hash);
-            (!src.is_empty()).then_some(Cow::Borrowed(src))
-        } else {
-            None
-        }
-        .or_else(|| {
-            let bytes = load_asset(shader_path).ok()?;
-            let src = std::str::from_utf8(&bytes).ok()?;
-            Some(Cow::Owned(src.to_string()))
-        })?;
+        let src = load_custom_shader_source(shader_path, static_shader_lookup)?;
         // Record whether this shader defines a shade_vertex hook (same probe
         // as build_material_shader composition). Depth-only passes consult
         // this: a hook displaces geometry the shared depth shaders can't
@@ -63,16 +77,15 @@ impl Gpu3D {
         self.custom_pipeline_vertex_hooks
             .insert(token, src.contains("shade_vertex("));
         let wgsl = if path == RenderPath3D::MultiMesh {
-            build_custom_multimesh_material_shader(src.as_ref(), lighting)
+            build_custom_multimesh_material_shader(&src, lighting)
         } else if path == RenderPath3D::Rigid {
-            build_custom_material_shader_with_prelude(prelude_rigid_wgsl(), src.as_ref(), lighting)
+            build_custom_material_shader_with_prelude(prelude_rigid_wgsl(), &src, lighting)
         } else {
-            build_custom_material_shader_with_prelude(
-                prelude_skinned_wgsl(),
-                src.as_ref(),
-                lighting,
-            )
+            build_custom_material_shader_with_prelude(prelude_skinned_wgsl(), &src, lighting)
         };
+        if !validate_custom_wgsl(shader_path, static_shader_lookup, &wgsl) {
+            return None;
+        }
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("perro_mesh_custom"),
             source: wgpu::ShaderSource::Wgsl(wgsl.into()),
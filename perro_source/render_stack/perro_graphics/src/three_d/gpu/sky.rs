@@ -78,6 +78,16 @@ impl Gpu3D {
             self.active_sky_pipeline_key = None;
             return;
         };
+        // Passes compose into one module, so a failure is reported under
+        // every pass path; editing any of them retries the whole sky.
+        let mut valid = true;
+        for shader in sky.shaders.iter() {
+            valid &= validate_custom_wgsl(shader.path.as_ref(), static_shader_lookup, &wgsl);
+        }
+        if !valid {
+            self.active_sky_pipeline_key = None;
+            return;
+        }
         let shader = create_sky_shader_module_from_source(device, wgsl);
         let pipeline = create_sky_pipeline(
             device,
@@ -97,29 +107,12 @@ fn build_sky_custom_source(
 ) -> Option<String> {
     let mut passes = Vec::with_capacity(sky.shaders.len());
     for shader in sky.shaders.iter() {
-        let source = load_shader_source(shader.path.as_ref(), static_shader_lookup)?;
+        let source = load_custom_shader_source(shader.path.as_ref(), static_shader_lookup)?;
         passes.push((source, shader.params.as_ref()));
     }
     Some(super::super::shaders::build_sky_shader_with_passes(&passes))
 }
 
-fn load_shader_source(
-    shader_path: &str,
-    static_shader_lookup: Option<StaticShaderLookup>,
-) -> Option<String> {
-    if let Some(lookup) = static_shader_lookup {
-        let shader_hash = perro_ids::parse_hashed_source_uri(shader_path)
-            .unwrap_or_else(|| perro_ids::string_to_u64(shader_path));
-        let src = lookup(shader_hash);
-        if !src.is_empty() {
-            return Some(src.to_string());
-        }
-    }
-    let bytes = load_asset(shader_path).ok()?;
-    let src = std::str::from_utf8(&bytes).ok()?;
-    Some(src.to_string())
-}
-
 // view_proj / inv_view_proj are computed once by the caller and shared with the
 // scene uniform and frustum extraction; inv_view_proj is the raw inverse and the
 // is_finite fallback stays here.
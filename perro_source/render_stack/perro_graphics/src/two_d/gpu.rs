@@ -5,7 +5,7 @@ use super::shaders::{
 use crate::backend::StaticTextureLookup;
 use crate::resources::ResourceStore;
 use crate::texture_mips::{
-    build_rgba_levels_for_filter, sampler_descriptor, write_rgba_mip_chain,
+    approx_texture_bytes, build_rgba_levels_for_filter, sampler_descriptor, write_rgba_mip_chain,
     write_texture_base_level,
 };
 use ahash::{AHashMap, AHashSet};
@@ -852,6 +852,16 @@ impl Gpu2D {
             + u32::from(!self.point_light_instances.is_empty())
     }
 
+    /// Approx bytes held by cached sprite textures this renderer owns.
+    /// Externally owned views (camera streams) count 4 their owner.
+    pub fn approx_texture_bytes(&self) -> u64 {
+        self.sprite_textures
+            .values()
+            .filter_map(|cached| cached._texture.as_ref())
+            .map(approx_texture_bytes)
+            .sum()
+    }
+
     fn ensure_sprite_texture(
         &mut self,
         device: &wgpu::Device,
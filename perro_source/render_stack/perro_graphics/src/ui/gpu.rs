@@ -2,8 +2,8 @@ use crate::{
     backend::StaticTextureLookup,
     resources::ResourceStore,
     texture_mips::{
-        build_rgba_levels_for_filter, sampler_descriptor, write_rgba_mip_chain,
-        write_texture_base_level,
+        approx_texture_bytes, build_rgba_levels_for_filter, sampler_descriptor,
+        write_rgba_mip_chain, write_texture_base_level,
     },
 };
 use ahash::{AHashMap, AHashSet};
@@ -943,6 +943,15 @@ impl GpuUi {
             .map(|texture| &texture.bind_group)
     }
 
+    /// Approx bytes held by cached UI image textures this renderer owns.
+    pub fn approx_texture_bytes(&self) -> u64 {
+        self.image_textures
+            .values()
+            .filter_map(|cached| cached.texture.as_ref())
+            .map(approx_texture_bytes)
+            .sum()
+    }
+
     pub fn invalidate_image_texture(&mut self, texture: TextureID) {
         self.image_textures.remove(&texture);
         self.prepared_mesh_signature = None;
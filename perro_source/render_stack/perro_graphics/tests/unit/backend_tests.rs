@@ -85,7 +85,7 @@ fn write_texture_rgba_updates_resource_texture() {
         ResourceCommand::CreateExternalTexture {
             request: perro_render_bridge::RenderRequestID::new(1),
             id: texture,
-            source: "webcam://test".to_string(),
+            source: "webcam://test".into(),
             reserved: true,
             width: 2,
             height: 1,
@@ -122,7 +122,7 @@ fn write_texture_rgba_region_updates_only_dirty_rect() {
         ResourceCommand::CreateRuntimeTexture {
             request: perro_render_bridge::RenderRequestID::new(3),
             id: texture,
-            source: "runtime://texture/78:0".to_string(),
+            source: "runtime://texture/78:0".into(),
             reserved: true,
             width: 3,
             height: 2,
@@ -161,7 +161,7 @@ fn write_texture_rgba_region_rejects_out_of_bounds_rect() {
         ResourceCommand::CreateRuntimeTexture {
             request: perro_render_bridge::RenderRequestID::new(4),
             id: texture,
-            source: "runtime://texture/79:0".to_string(),
+            source: "runtime://texture/79:0".into(),
             reserved: true,
             width: 2,
             height: 2,
@@ -198,7 +198,7 @@ fn repeat_same_size_write_reuses_texture_via_texels_updated() {
         ResourceCommand::CreateExternalTexture {
             request: perro_render_bridge::RenderRequestID::new(1),
             id: texture,
-            source: "webcam://reuse".to_string(),
+            source: "webcam://reuse".into(),
             reserved: true,
             width: 2,
             height: 1,
@@ -287,7 +287,7 @@ fn webcam_camera_stream_does_not_overwrite_webcam_texture() {
         ResourceCommand::CreateExternalTexture {
             request: perro_render_bridge::RenderRequestID::new(2),
             id: texture,
-            source: "webcam://test/no_overwrite".to_string(),
+            source: "webcam://test/no_overwrite".into(),
             reserved: true,
             width: 2,
             height: 1,
@@ -358,7 +358,7 @@ fn runtime_texture_size_limit_rejects_oversized_allocations() {
         ResourceCommand::CreateExternalTexture {
             request,
             id: texture,
-            source: "webcam://oversized".to_string(),
+            source: "webcam://oversized".into(),
             reserved: true,
             width: 8_192,
             height: 8_192,
@@ -574,7 +574,7 @@ fn sprite_texture_upsert_is_accepted_after_texture_creation() {
     graphics.submit(RenderCommand::Resource(ResourceCommand::CreateTexture {
         request,
         id: TextureID::nil(),
-        source: "__default__".to_string(),
+        source: "__default__".into(),
         reserved: false,
     }));
     graphics.draw_frame();
@@ -630,7 +630,7 @@ fn runtime_texture_rgba_create_sets_decoded_data_and_loaded_event() {
         ResourceCommand::CreateRuntimeTexture {
             request,
             id: texture,
-            source: "runtime://texture/test".to_string(),
+            source: "runtime://texture/test".into(),
             reserved: false,
             width: 2,
             height: 1,
@@ -678,7 +678,7 @@ fn runtime_texture_bytes_create_decodes_ptex() {
         ResourceCommand::CreateRuntimeTextureBytes {
             request,
             id: texture,
-            source: "runtime://texture-bytes/test".to_string(),
+            source: "runtime://texture-bytes/test".into(),
             reserved: false,
             bytes: Arc::from(bytes.as_slice()),
         },
@@ -703,7 +703,7 @@ fn draw_3d_updates_retained_state_per_node() {
     graphics.submit(RenderCommand::Resource(ResourceCommand::CreateMesh {
         request: perro_render_bridge::RenderRequestID::new(1001),
         id: MeshID::nil(),
-        source: "__cube__".to_string(),
+        source: "__cube__".into(),
         reserved: false,
     }));
     graphics.submit(RenderCommand::Resource(ResourceCommand::CreateMaterial {
@@ -716,7 +716,7 @@ fn draw_3d_updates_retained_state_per_node() {
     graphics.submit(RenderCommand::Resource(ResourceCommand::CreateMesh {
         request: perro_render_bridge::RenderRequestID::new(1003),
         id: MeshID::nil(),
-        source: "__sphere__".to_string(),
+        source: "__sphere__".into(),
         reserved: false,
     }));
     graphics.submit(RenderCommand::Resource(ResourceCommand::CreateMaterial {
@@ -887,7 +887,7 @@ fn draw_multi_3d_retains_all_instance_mats() {
     graphics.submit(RenderCommand::Resource(ResourceCommand::CreateMesh {
         request: perro_render_bridge::RenderRequestID::new(1201),
         id: MeshID::nil(),
-        source: "__cube__".to_string(),
+        source: "__cube__".into(),
         reserved: false,
     }));
     graphics.submit(RenderCommand::Resource(ResourceCommand::CreateMaterial {
@@ -979,7 +979,7 @@ fn rejected_3d_draw_keeps_previous_retained_binding() {
     graphics.submit(RenderCommand::Resource(ResourceCommand::CreateMesh {
         request: perro_render_bridge::RenderRequestID::new(2001),
         id: MeshID::nil(),
-        source: "__cube__".to_string(),
+        source: "__cube__".into(),
         reserved: false,
     }));
     graphics.submit(RenderCommand::Resource(ResourceCommand::CreateMaterial {
@@ -1094,7 +1094,7 @@ fn rejected_3d_material_swap_keeps_previous_material_binding() {
     graphics.submit(RenderCommand::Resource(ResourceCommand::CreateMesh {
         request: perro_render_bridge::RenderRequestID::new(2101),
         id: MeshID::nil(),
-        source: "__cube__".to_string(),
+        source: "__cube__".into(),
         reserved: false,
     }));
     graphics.submit(RenderCommand::Resource(ResourceCommand::CreateMaterial {
@@ -1252,7 +1252,7 @@ fn rejected_sprite_texture_swap_keeps_previous_texture_binding() {
     graphics.submit(RenderCommand::Resource(ResourceCommand::CreateTexture {
         request,
         id: TextureID::nil(),
-        source: "__default__".to_string(),
+        source: "__default__".into(),
         reserved: false,
     }));
     graphics.draw_frame();
@@ -1544,3 +1544,58 @@ fn post_processing_commands_update_global_post_processing_state() {
     graphics.draw_frame();
     assert!(graphics.global_post_processing.is_empty());
 }
+
+#[test]
+fn missing_texture_and_mesh_sources_load_placeholders() {
+    let mut graphics = PerroGraphics::new();
+    let texture_request = perro_render_bridge::RenderRequestID::new(88_201);
+    let mesh_request = perro_render_bridge::RenderRequestID::new(88_202);
+    graphics.submit(RenderCommand::Resource(ResourceCommand::CreateTexture {
+        request: texture_request,
+        id: TextureID::nil(),
+        source: "res://missing/placeholder_texture.png".into(),
+        reserved: false,
+    }));
+    graphics.submit(RenderCommand::Resource(ResourceCommand::CreateMesh {
+        request: mesh_request,
+        id: MeshID::nil(),
+        source: "res://missing/placeholder_mesh.glb".into(),
+        reserved: false,
+    }));
+    graphics.draw_frame();
+
+    let mut events = Vec::new();
+    graphics.drain_events(&mut events);
+    assert!(
+        !events
+            .iter()
+            .any(|event| matches!(event, perro_render_bridge::RenderEvent::Failed { .. }))
+    );
+    let texture = events
+        .iter()
+        .find_map(|event| match event {
+            perro_render_bridge::RenderEvent::TextureCreated { request, id }
+                if *request == texture_request =>
+            {
+                Some(*id)
+            }
+            _ => None,
+        })
+        .expect("missing texture should still be created");
+    let decoded = graphics
+        .resources
+        .decoded_texture_data(texture)
+        .expect("missing texture should hold placeholder pixels");
+    assert_eq!(
+        (decoded.width, decoded.height),
+        (
+            perro_graphics_assets::MISSING_TEXTURE_SIZE,
+            perro_graphics_assets::MISSING_TEXTURE_SIZE
+        )
+    );
+    assert!(events.iter().any(|event| matches!(
+        event,
+        perro_render_bridge::RenderEvent::MeshCreated { request, mesh: Some(_), .. }
+            if *request == mesh_request
+    )));
+}
@@ -1,4 +1,6 @@
 mod mesh;
+mod placeholder;
+mod shader;
 mod texture;
 
 #[cfg(test)]
@@ -10,6 +12,11 @@ pub use mesh::{
     decode_gltf_mesh, decode_pmesh, load_mesh_from_source, load_mesh_from_source_no_dynamic_lods,
     load_mesh3d_from_bytes, load_mesh3d_from_source, validate_mesh_source,
 };
+pub use placeholder::{MISSING_TEXTURE_SIZE, missing_mesh3d, missing_texture_rgba};
+pub use shader::{
+    ResolvedShader, SHADER_INCLUDE_MAX_DEPTH, ShaderIncludeError, resolve_shader_include_path,
+    resolve_shader_includes,
+};
 pub use texture::{
     SVG_RASTER_SCALE, decode_gltf_texture, decode_image_logical_size, decode_image_rgba,
     decode_image_rgba_max_size, decode_image_size, decode_ptex,
@@ -0,0 +1,82 @@
+//! Visible stand-ins for textures and meshes whose source does not resolve.
+
+use perro_builtin_meshes::{CUBE_SOURCE, build_builtin_mesh};
+use perro_render_bridge::{Mesh3D, MeshSurfaceRange, RuntimeMeshVertex};
+
+pub const MISSING_TEXTURE_SIZE: u32 = 64;
+const MISSING_TEXTURE_CELL: u32 = 8;
+const MISSING_TEXTURE_ON: [u8; 4] = [255, 0, 255, 255];
+const MISSING_TEXTURE_OFF: [u8; 4] = [0, 0, 0, 255];
+
+/// Magenta/black checker, `MISSING_TEXTURE_SIZE` square.
+pub fn missing_texture_rgba() -> (Vec<u8>, u32, u32) {
+    let size = MISSING_TEXTURE_SIZE;
+    let mut rgba = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            let on = (x / MISSING_TEXTURE_CELL + y / MISSING_TEXTURE_CELL).is_multiple_of(2);
+            rgba.extend_from_slice(if on {
+                &MISSING_TEXTURE_ON
+            } else {
+                &MISSING_TEXTURE_OFF
+            });
+        }
+    }
+    (rgba, size, size)
+}
+
+/// Unit cube, one surface.
+pub fn missing_mesh3d() -> Mesh3D {
+    let cube = build_builtin_mesh(CUBE_SOURCE).expect("cube is a builtin mesh");
+    let indices: Vec<u32> = cube.indices.into_iter().map(u32::from).collect();
+    let index_count = indices.len() as u32;
+    Mesh3D {
+        vertices: cube
+            .vertices
+            .into_iter()
+            .map(|vertex| RuntimeMeshVertex {
+                position: vertex.pos,
+                normal: vertex.normal,
+                uv: vertex.uv,
+                paint_uv: vertex.uv,
+                joints: [0; 4],
+                weights: [1.0, 0.0, 0.0, 0.0].into(),
+            })
+            .collect(),
+        indices,
+        surface_ranges: vec![MeshSurfaceRange {
+            index_start: 0,
+            index_count,
+        }],
+        blend_shapes: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MISSING_TEXTURE_SIZE, missing_mesh3d, missing_texture_rgba};
+
+    #[test]
+    fn missing_texture_is_magenta_checker() {
+        let (rgba, width, height) = missing_texture_rgba();
+        assert_eq!(
+            (width, height),
+            (MISSING_TEXTURE_SIZE, MISSING_TEXTURE_SIZE)
+        );
+        assert_eq!(rgba.len(), (width * height * 4) as usize);
+        assert_eq!(&rgba[..4], &[255, 0, 255, 255]);
+        let next_cell = (8 * 4) as usize;
+        assert_eq!(&rgba[next_cell..next_cell + 4], &[0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn missing_mesh_is_indexed_cube() {
+        let mesh = missing_mesh3d();
+        assert!(!mesh.vertices.is_empty());
+        assert_eq!(mesh.indices.len() % 3, 0);
+        assert_eq!(
+            mesh.surface_ranges[0].index_count as usize,
+            mesh.indices.len()
+        );
+    }
+}
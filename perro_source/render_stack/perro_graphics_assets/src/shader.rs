@@ -0,0 +1,227 @@
+//! WGSL `#include` resolution shared by the static pipeline (flattened at
+//! embed time) and dev-mode shader loads (flattened on load).
+//!
+//! A directive is a line of the form `#include "res://shaders/common.wgsl"`.
+//! Paths without a scheme resolve against the including file's directory.
+//! Each file is pasted at most once per shader, so shared helpers included
+//! from several places do not redefine symbols; cycles are an error.
+
+use std::fmt;
+
+/// Deepest include chain accepted before the resolver assumes a runaway.
+pub const SHADER_INCLUDE_MAX_DEPTH: usize = 32;
+
+const INCLUDE_DIRECTIVE: &str = "#include";
+
+/// Flattened shader source plus every file it was built from (root first),
+/// which dev hot-reload watches for edits.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ResolvedShader {
+    pub source: String,
+    pub files: Vec<String>,
+}
+
+/// Include failure, located at the directive that caused it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShaderIncludeError {
+    pub file: String,
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ShaderIncludeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.file, self.line, self.message)
+    }
+}
+
+impl std::error::Error for ShaderIncludeError {}
+
+/// Flatten `#include` directives in `source` (loaded from `origin`). `load`
+/// returns the text of an included path, or `None` when it does not exist.
+/// Sources without directives come back byte-for-byte unchanged.
+pub fn resolve_shader_includes(
+    origin: &str,
+    source: &str,
+    load: &mut dyn FnMut(&str) -> Option<String>,
+) -> Result<ResolvedShader, ShaderIncludeError> {
+    let mut out = ResolvedShader {
+        source: String::with_capacity(source.len()),
+        files: vec![origin.to_string()],
+    };
+    let mut stack = vec![origin.to_string()];
+    expand(origin, source, load, &mut stack, &mut out)?;
+    Ok(out)
+}
+
+/// Resolve an include target against the including file's path. Returns
+/// `None` when a `..` segment climbs above the scheme root.
+pub fn resolve_shader_include_path(from: &str, target: &str) -> Option<String> {
+    let (scheme, joined) = if let Some((scheme, rest)) = target.split_once("://") {
+        (scheme, rest.to_string())
+    } else {
+        let (scheme, rest) = from.split_once("://").unwrap_or(("res", from));
+        let dir = rest.rsplit_once('/').map_or("", |(dir, _)| dir);
+        if dir.is_empty() {
+            (scheme, target.to_string())
+        } else {
+            (scheme, format!("{dir}/{target}"))
+        }
+    };
+    let mut parts = Vec::<&str>::new();
+    for part in joined.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop()?;
+            }
+            _ => parts.push(part),
+        }
+    }
+    Some(format!("{scheme}://{}", parts.join("/")))
+}
+
+fn expand(
+    file: &str,
+    source: &str,
+    load: &mut dyn FnMut(&str) -> Option<String>,
+    stack: &mut Vec<String>,
+    out: &mut ResolvedShader,
+) -> Result<(), ShaderIncludeError> {
+    for (index, line) in source.split_inclusive('\n').enumerate() {
+        let Some(rest) = line.trim_start().strip_prefix(INCLUDE_DIRECTIVE) else {
+            out.source.push_str(line);
+            continue;
+        };
+        let error = |message: String| ShaderIncludeError {
+            file: file.to_string(),
+            line: index + 1,
+            message,
+        };
+        let target = parse_include_target(rest)
+            .ok_or_else(|| error("expected `#include \"path\"`".to_string()))?;
+        let path = resolve_shader_include_path(file, target)
+            .ok_or_else(|| error(format!("include `{target}` escapes its root")))?;
+        if stack.contains(&path) {
+            let mut chain = stack.clone();
+            chain.push(path);
+            return Err(error(format!("include cycle: {}", chain.join(" -> "))));
+        }
+        if out.files.contains(&path) {
+            continue;
+        }
+        if stack.len() >= SHADER_INCLUDE_MAX_DEPTH {
+            return Err(error(format!(
+                "includes nest deeper than {SHADER_INCLUDE_MAX_DEPTH}"
+            )));
+        }
+        let included = load(&path).ok_or_else(|| error(format!("include `{path}` not found")))?;
+        out.files.push(path.clone());
+        stack.push(path.clone());
+        expand(&path, &included, load, stack, out)?;
+        stack.pop();
+        if !out.source.ends_with('\n') {
+            out.source.push('\n');
+        }
+    }
+    Ok(())
+}
+
+fn parse_include_target(rest: &str) -> Option<&str> {
+    let rest = rest.trim();
+    let inner = rest.strip_prefix('"')?.strip_suffix('"')?;
+    (!inner.is_empty() && !inner.contains('"')).then_some(inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn resolve(files: &[(&str, &str)], root: &str) -> Result<ResolvedShader, ShaderIncludeError> {
+        let files: HashMap<String, String> = files
+            .iter()
+            .map(|(path, text)| (path.to_string(), text.to_string()))
+            .collect();
+        let source = files.get(root).cloned().unwrap_or_default();
+        resolve_shader_includes(root, &source, &mut |path| files.get(path).cloned())
+    }
+
+    #[test]
+    fn includes_paste_once_and_track_files() {
+        let resolved = resolve(
+            &[
+                (
+                    "res://shaders/water.wgsl",
+                    "#include \"res://shaders/common.wgsl\"\n  #include \"lib/noise.wgsl\"\nfn main() {}\n",
+                ),
+                ("res://shaders/common.wgsl", "const PI: f32 = 3.14;"),
+                (
+                    "res://shaders/lib/noise.wgsl",
+                    "#include \"../common.wgsl\"\nfn noise() {}\n",
+                ),
+            ],
+            "res://shaders/water.wgsl",
+        )
+        .expect("includes resolve");
+        assert_eq!(
+            resolved.source,
+            "const PI: f32 = 3.14;\nfn noise() {}\nfn main() {}\n"
+        );
+        assert_eq!(
+            resolved.files,
+            [
+                "res://shaders/water.wgsl",
+                "res://shaders/common.wgsl",
+                "res://shaders/lib/noise.wgsl",
+            ]
+        );
+    }
+
+    #[test]
+    fn source_without_includes_is_unchanged() {
+        let text = "// #include in a comment stays\nfn main() {}";
+        let resolved = resolve(&[("res://a.wgsl", text)], "res://a.wgsl").expect("resolves");
+        assert_eq!(resolved.source, text);
+        assert_eq!(resolved.files, ["res://a.wgsl"]);
+    }
+
+    #[test]
+    fn missing_cyclic_and_malformed_includes_report_location() {
+        let missing = resolve(
+            &[("res://a.wgsl", "fn f() {}\n#include \"gone.wgsl\"\n")],
+            "res://a.wgsl",
+        )
+        .expect_err("missing include");
+        assert_eq!(
+            missing.to_string(),
+            "res://a.wgsl:2: include `res://gone.wgsl` not found"
+        );
+
+        let cycle = resolve(
+            &[
+                ("res://a.wgsl", "#include \"b.wgsl\"\n"),
+                ("res://b.wgsl", "#include \"a.wgsl\"\n"),
+            ],
+            "res://a.wgsl",
+        )
+        .expect_err("cycle");
+        assert!(
+            cycle
+                .message
+                .contains("res://a.wgsl -> res://b.wgsl -> res://a.wgsl")
+        );
+
+        let malformed = resolve(
+            &[("res://a.wgsl", "#include common.wgsl\n")],
+            "res://a.wgsl",
+        )
+        .expect_err("malformed");
+        assert_eq!(malformed.line, 1);
+
+        assert_eq!(
+            resolve_shader_include_path("res://a.wgsl", "../b.wgsl"),
+            None
+        );
+    }
+}
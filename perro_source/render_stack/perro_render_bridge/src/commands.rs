@@ -46,20 +46,20 @@ pub enum ResourceCommand {
     CreateMesh {
         request: RenderRequestID,
         id: MeshID,
-        source: String,
+        source: AssetPath,
         reserved: bool,
     },
     CreateRuntimeMesh {
         request: RenderRequestID,
         id: MeshID,
-        source: String,
+        source: AssetPath,
         reserved: bool,
         mesh: Mesh3D,
     },
     CreateRuntimeMeshBytes {
         request: RenderRequestID,
         id: MeshID,
-        source: String,
+        source: AssetPath,
         reserved: bool,
         bytes: Arc<[u8]>,
     },
@@ -70,13 +70,13 @@ pub enum ResourceCommand {
     CreateTexture {
         request: RenderRequestID,
         id: TextureID,
-        source: String,
+        source: AssetPath,
         reserved: bool,
     },
     CreateRuntimeTexture {
         request: RenderRequestID,
         id: TextureID,
-        source: String,
+        source: AssetPath,
         reserved: bool,
         width: u32,
         height: u32,
@@ -85,14 +85,14 @@ pub enum ResourceCommand {
     CreateRuntimeTextureBytes {
         request: RenderRequestID,
         id: TextureID,
-        source: String,
+        source: AssetPath,
         reserved: bool,
         bytes: Arc<[u8]>,
     },
     CreateExternalTexture {
         request: RenderRequestID,
         id: TextureID,
-        source: String,
+        source: AssetPath,
         reserved: bool,
         width: u32,
         height: u32,
@@ -330,6 +330,50 @@ pub enum RenderCommand {
     Display(DisplayCommand),
 }
 
+impl RenderCommand {
+    /// Short static tag for crash trails. Resource commands keep their variant
+    /// (GPU allocs are the usual driver-crash suspects); the rest stay coarse
+    /// so tagging never formats or allocates on the submit path.
+    pub fn trace_label(&self) -> &'static str {
+        match self {
+            Self::Resource(cmd) => match cmd {
+                ResourceCommand::CreateMesh { .. } => "resource.create_mesh",
+                ResourceCommand::CreateRuntimeMesh { .. } => "resource.create_runtime_mesh",
+                ResourceCommand::CreateRuntimeMeshBytes { .. } => {
+                    "resource.create_runtime_mesh_bytes"
+                }
+                ResourceCommand::WriteMeshData { .. } => "resource.write_mesh_data",
+                ResourceCommand::CreateTexture { .. } => "resource.create_texture",
+                ResourceCommand::CreateRuntimeTexture { .. } => "resource.create_runtime_texture",
+                ResourceCommand::CreateRuntimeTextureBytes { .. } => {
+                    "resource.create_runtime_texture_bytes"
+                }
+                ResourceCommand::CreateExternalTexture { .. } => "resource.create_external_texture",
+                ResourceCommand::WriteTextureRgba { .. } => "resource.write_texture_rgba",
+                ResourceCommand::WriteTextureRgbaRegion { .. } => {
+                    "resource.write_texture_rgba_region"
+                }
+                ResourceCommand::CreateMaterial { .. } => "resource.create_material",
+                ResourceCommand::SetSceneResourceRefs { .. } => "resource.set_scene_resource_refs",
+                ResourceCommand::WriteMaterialData { .. } => "resource.write_material_data",
+                ResourceCommand::SetMeshReserved { .. } => "resource.set_mesh_reserved",
+                ResourceCommand::SetTextureReserved { .. } => "resource.set_texture_reserved",
+                ResourceCommand::SetMaterialReserved { .. } => "resource.set_material_reserved",
+                ResourceCommand::DropMesh { .. } => "resource.drop_mesh",
+                ResourceCommand::DropTexture { .. } => "resource.drop_texture",
+                ResourceCommand::DropMaterial { .. } => "resource.drop_material",
+            },
+            Self::CameraStream(_) => "camera_stream",
+            Self::TwoD(_) => "2d",
+            Self::ThreeD(_) => "3d",
+            Self::Ui(_) => "ui",
+            Self::PostProcessing(_) => "post_processing",
+            Self::VisualAccessibility(_) => "visual_accessibility",
+            Self::Display(_) => "display",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DisplayCommand {
     SetHdrMode(HdrMode),
@@ -1,5 +1,5 @@
 use perro_asset_formats::ptset::{MAGIC as TILESET2D_MAGIC, VERSION as TILESET2D_VERSION};
-use perro_ids::{MaterialID, MeshID, NodeID, TextureID};
+use perro_ids::{AssetPath, MaterialID, MeshID, NodeID, TextureID};
 pub use perro_particle_math::Op as ParticleExprOp2D;
 pub use perro_particle_math::Op as ParticleExprOp3D;
 use perro_structs::{
@@ -1,13 +1,21 @@
-use perro_runtime::{ProviderMode, Runtime, RuntimeProject, WindowRequest};
+#[cfg(not(feature = "server"))]
+use perro_runtime::WindowRequest;
+use perro_runtime::{ProviderMode, Runtime, RuntimeProject};
 use perro_scripting::ScriptConstructor;
 use std::path::Path;
 use std::sync::{
     Arc,
     atomic::{AtomicBool, Ordering},
 };
+#[cfg(not(feature = "server"))]
 use std::time::{Duration, Instant};
 
-pub use perro_runtime::{FrameRateCap, OcclusionCulling, ParticleSimDefault};
+#[cfg(feature = "server")]
+mod server;
+
+pub use perro_runtime::{
+    BuildInfo, BuildProfile, FrameRateCap, OcclusionCulling, ParticleSimDefault,
+};
 
 pub type StaticScriptRegistry =
     &'static [(u64, ScriptConstructor<perro_runtime::RuntimeScriptApi>)];
@@ -17,7 +25,8 @@ pub fn run_dev_project_from_path(
     default_name: &str,
 ) -> Result<(), perro_runtime::ProjectLoadError> {
     let mut project =
-        RuntimeProject::from_project_dir_with_default_name(project_root, default_name)?;
+        RuntimeProject::from_project_dir_with_default_name(project_root, default_name)?
+            .with_scene_override_from_env()?;
     init_steam_server(&mut project);
     run_runtime(Runtime::from_project(project, ProviderMode::Dynamic));
     Ok(())
@@ -48,9 +57,15 @@ pub fn run_static_embedded_project(
         input.project.virtual_height,
     )
     .with_target_fixed_update(input.runtime.target_fixed_update)
+    .with_max_fixed_steps_per_frame(input.runtime.max_fixed_steps_per_frame)
     .with_frame_rate_cap(input.runtime.frame_rate_cap)
     .with_physics_gravity(input.runtime.physics_gravity)
     .with_physics_coef(input.runtime.physics_coef)
+    .with_physics_interpolation(input.runtime.physics_interpolation)
+    .with_physics_layer_names(
+        input.runtime.physics_layer_names_2d,
+        input.runtime.physics_layer_names_3d,
+    )
     .with_particle_sim_default(ParticleSimDefault::Cpu)
     .with_metadata(
         input.metadata.description,
@@ -65,6 +80,7 @@ pub fn run_static_embedded_project(
     let project = RuntimeProject::from_static(config, input.project.project_root.to_path_buf())
         .with_routes(routes(&input.routes))
         .with_input_map(input_map(&input.input))
+        .with_build_info(input.build)
         .with_static_scene_lookup(input.assets.scene_lookup)
         .with_static_localization_lookup(input.assets.localization_lookup)
         .with_static_material_lookup(input.assets.material_lookup)
@@ -79,6 +95,13 @@ pub fn run_static_embedded_project(
         .with_static_skeleton_lookup(input.assets.skeleton_lookup)
         .with_static_audio_lookup(input.assets.audio_lookup)
         .with_perro_assets_bytes(input.assets.perro_assets);
+    let project = match project.with_scene_override_from_env() {
+        Ok(project) => project,
+        Err(err) => {
+            eprintln!("[headless] {}: {err}", input.project.project_name);
+            return Ok(());
+        }
+    };
     run_runtime(Runtime::from_project_with_script_registry(
         project,
         ProviderMode::Static,
@@ -106,7 +129,7 @@ fn init_steam_server(project: &mut RuntimeProject) {
     let _ = project;
 }
 
-fn run_runtime(mut runtime: Runtime) {
+fn run_runtime(runtime: Runtime) {
     let running = Arc::new(AtomicBool::new(true));
     let signal = Arc::clone(&running);
     let _ = ctrlc::set_handler(move || signal.store(false, Ordering::SeqCst));
@@ -116,6 +139,23 @@ fn run_runtime(mut runtime: Runtime) {
         .filter(|fps| *fps > 0.0)
         .map(|fps| 1.0 / fps)
         .unwrap_or(1.0 / 60.0);
+    let max_fixed_steps = runtime
+        .project()
+        .map(|project| project.config.max_fixed_steps_per_frame.max(1))
+        .unwrap_or(perro_runtime::DEFAULT_MAX_FIXED_STEPS_PER_FRAME);
+    #[cfg(feature = "server")]
+    server::run_server_loop(runtime, &running, fixed_delta, max_fixed_steps);
+    #[cfg(not(feature = "server"))]
+    run_headless_loop(runtime, &running, fixed_delta, max_fixed_steps);
+}
+
+#[cfg(not(feature = "server"))]
+fn run_headless_loop(
+    mut runtime: Runtime,
+    running: &AtomicBool,
+    fixed_delta: f32,
+    max_fixed_steps: u32,
+) {
     let step = Duration::from_secs_f32(fixed_delta);
     let mut last = Instant::now();
     let mut accumulator = Duration::ZERO;
@@ -128,9 +168,15 @@ fn run_runtime(mut runtime: Runtime) {
         last = frame_start;
         accumulator += delta;
         runtime.update(delta.as_secs_f32());
-        while accumulator >= step {
+        let mut steps = 0;
+        while accumulator >= step && steps < max_fixed_steps {
             runtime.fixed_update(fixed_delta);
             accumulator -= step;
+            steps += 1;
+        }
+        // Spiral guard: backlog past the cap is dropped, not carried.
+        while accumulator >= step {
+            accumulator -= step;
         }
         runtime.drain_window_requests(&mut requests);
         if requests
@@ -174,6 +220,13 @@ fn input_map(input: &StaticEmbeddedInputMapConfig<'_>) -> perro_input_api::Input
                     .iter()
                     .copied()
                     .map(perro_input_api::InputBinding::Key)
+                    .chain(
+                        action
+                            .chords
+                            .iter()
+                            .copied()
+                            .map(perro_input_api::InputBinding::Chord),
+                    )
                     .chain(
                         action
                             .mouse
@@ -197,6 +250,8 @@ fn input_map(input: &StaticEmbeddedInputMapConfig<'_>) -> perro_input_api::Input
                     )
                     .collect();
                 perro_input_api::InputAction::new(action.name, bindings)
+                    .with_context(action.context)
+                    .with_consume(action.consume)
             })
             .collect(),
     )
@@ -209,6 +264,7 @@ pub struct StaticEmbeddedProject<'a> {
     pub graphics: StaticEmbeddedGraphicsConfig,
     pub runtime: StaticEmbeddedRuntimeConfig,
     pub metadata: StaticEmbeddedMetadataConfig,
+    pub build: BuildInfo,
     pub localization: StaticEmbeddedLocalizationConfig,
     pub steam: StaticEmbeddedSteamConfig,
     pub assets: StaticEmbeddedAssetsConfig,
@@ -235,9 +291,12 @@ pub struct StaticEmbeddedRoutesConfig<'a> {
 pub struct StaticEmbeddedInputAction {
     pub name: &'static str,
     pub keys: &'static [perro_input_api::KeyCode],
+    pub chords: &'static [perro_input_api::KeyChord],
     pub mouse: &'static [perro_input_api::MouseButton],
     pub gamepad: &'static [perro_input_api::GamepadButton],
     pub joycon: &'static [perro_input_api::JoyConButton],
+    pub context: &'static str,
+    pub consume: bool,
 }
 pub struct StaticEmbeddedInputMapConfig<'a> {
     pub actions: &'a [StaticEmbeddedInputAction],
@@ -256,9 +315,13 @@ pub struct StaticEmbeddedGraphicsConfig {
 }
 pub struct StaticEmbeddedRuntimeConfig {
     pub target_fixed_update: Option<f32>,
+    pub max_fixed_steps_per_frame: u32,
     pub frame_rate_cap: FrameRateCap,
     pub physics_gravity: f32,
     pub physics_coef: f32,
+    pub physics_interpolation: bool,
+    pub physics_layer_names_2d: &'static [(u8, &'static str)],
+    pub physics_layer_names_3d: &'static [(u8, &'static str)],
 }
 pub struct StaticEmbeddedMetadataConfig {
     pub description: Option<&'static str>,
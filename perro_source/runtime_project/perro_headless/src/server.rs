@@ -0,0 +1,118 @@
+//! Dedicated server loop: one fixed delta per tick for both `update` and
+//! `fixed_update`, so sim results do not depend on wall-clock jitter. The net
+//! session is pumped once per tick before scripts run, and stdin doubles as
+//! the operator console.
+
+use perro_runtime::{Runtime, WindowRequest};
+use std::io::BufRead;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant};
+
+enum ConsoleAction {
+    Continue,
+    Quit,
+}
+
+pub(crate) fn run_server_loop(
+    mut runtime: Runtime,
+    running: &AtomicBool,
+    fixed_delta: f32,
+    max_fixed_steps: u32,
+) {
+    let step = Duration::from_secs_f32(fixed_delta);
+    // Ticks further behind than `max_fixed_steps_per_frame` are dropped
+    // instead of replayed, so a stall does not turn into a catch-up burst.
+    let max_backlog = step * max_fixed_steps;
+    let console = spawn_console();
+    let started = Instant::now();
+    let mut next_tick = started;
+    let mut tick: u64 = 0;
+    let mut requests = Vec::new();
+    println!(
+        "[server] running at {:.0} ticks/s; type `help` for commands",
+        1.0 / fixed_delta
+    );
+    while running.load(Ordering::SeqCst) {
+        for line in console.try_iter() {
+            if let ConsoleAction::Quit =
+                run_console_command(line.trim(), tick, fixed_delta, started)
+            {
+                running.store(false, Ordering::SeqCst);
+            }
+        }
+        if !running.load(Ordering::SeqCst) {
+            break;
+        }
+        perro_networking::multiplayer::poll();
+        runtime.update(fixed_delta);
+        runtime.fixed_update(fixed_delta);
+        tick += 1;
+        runtime.drain_window_requests(&mut requests);
+        if requests
+            .iter()
+            .any(|request| matches!(request, WindowRequest::CloseApp))
+        {
+            break;
+        }
+        requests.clear();
+
+        next_tick += step;
+        let now = Instant::now();
+        if let Some(rest) = next_tick.checked_duration_since(now) {
+            std::thread::sleep(rest);
+        } else if now.duration_since(next_tick) > max_backlog {
+            eprintln!("[server][warn] tick {tick} fell behind; dropping backlog");
+            next_tick = now;
+        }
+    }
+    perro_networking::multiplayer::disconnect();
+    println!("[server] stopped after {tick} ticks");
+}
+
+fn spawn_console() -> Receiver<String> {
+    let (tx, rx) = mpsc::channel();
+    let spawned = std::thread::Builder::new()
+        .name("perro-server-console".to_string())
+        .spawn(move || {
+            for line in std::io::stdin().lock().lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                if tx.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+    if let Err(err) = spawned {
+        eprintln!("[server][warn] console unavailable: {err}");
+    }
+    rx
+}
+
+fn run_console_command(
+    command: &str,
+    tick: u64,
+    fixed_delta: f32,
+    started: Instant,
+) -> ConsoleAction {
+    match command {
+        "" => {}
+        "help" => {
+            println!("[server] commands: status, quit (aliases: exit, stop)");
+        }
+        "status" => {
+            println!(
+                "[server] tick={tick} sim={:.2}s uptime={:.2}s net={:?}",
+                tick as f64 * fixed_delta as f64,
+                started.elapsed().as_secs_f64(),
+                perro_networking::multiplayer::mode()
+            );
+        }
+        "quit" | "exit" | "stop" => return ConsoleAction::Quit,
+        other => {
+            println!("[server] unknown command `{other}`; type `help`");
+        }
+    }
+    ConsoleAction::Continue
+}
@@ -27,6 +27,7 @@ fn static_body_2d(i: u32) -> BodyDesc2D {
             friction: 0.7,
             restitution: 0.1,
             density: 1.0,
+            one_way: false,
         }],
     }
 }
@@ -2,9 +2,9 @@ use criterion::{BatchSize, Criterion, black_box, criterion_group, criterion_main
 use perro_ids::NodeID;
 use perro_nodes::{Shape2D, Shape3D};
 use perro_physics::{
-    BodyDesc2D, BodyDesc3D, BodyKind, JointDesc2D, JointDesc3D, JointKind2D, JointKind3D,
-    PhysicsAssetContext, PhysicsProviderMode, PhysicsSystem, RigidProps2D, RigidProps3D,
-    ShapeDesc2D, ShapeDesc3D, ShapeKind2D, ShapeKind3D,
+    BodyDesc2D, BodyDesc3D, BodyKind, JointAngularDesc, JointDesc2D, JointDesc3D, JointKind2D,
+    JointKind3D, PhysicsAssetContext, PhysicsProviderMode, PhysicsSystem, RigidProps2D,
+    RigidProps3D, ShapeDesc2D, ShapeDesc3D, ShapeKind2D, ShapeKind3D,
 };
 use perro_structs::{BitMask, Quaternion, Transform2D, Transform3D, Vector2, Vector3};
 
@@ -59,6 +59,7 @@ fn shape_2d() -> ShapeDesc2D {
         friction: 0.7,
         restitution: 0.1,
         density: 1.0,
+        one_way: false,
     }
 }
 
@@ -185,7 +186,7 @@ fn joints_2d(count: u32) -> Vec<JointDesc2D> {
             anchor_b: Vector2::ZERO,
             enabled: true,
             collide_connected: false,
-            kind: JointKind2D::Pin,
+            kind: JointKind2D::Pin(JointAngularDesc::default()),
             signature: i as u64 + 1,
         })
         .collect()
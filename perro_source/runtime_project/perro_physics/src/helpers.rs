@@ -16,9 +16,9 @@ use perro_runtime_api::sub_apis::{PhysicsQueryFilter, PhysicsRayHit2D, PhysicsRa
 use perro_structs::{BitMask, Transform2D, Transform3D, Vector2, Vector3};
 
 use crate::{
-    BodyDesc2D, BodyDesc3D, BodyKind, JointDesc2D, JointDesc3D, JointKind2D, JointKind3D,
-    PhysicsWorld2D, PhysicsWorld3D, ShapeDesc2D, ShapeDesc3D, ShapeKind2D, ShapeKind3D,
-    TriMeshData, na2, na3, r2, r3,
+    BodyDesc2D, BodyDesc3D, BodyKind, JointAngularDesc, JointDesc2D, JointDesc3D, JointKind2D,
+    JointKind3D, JointMotorDesc, PhysicsWorld2D, PhysicsWorld3D, ShapeDesc2D, ShapeDesc3D,
+    ShapeKind2D, ShapeKind3D, TriMeshData, na2, na3, r2, r3,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
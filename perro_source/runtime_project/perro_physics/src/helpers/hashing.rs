@@ -139,6 +139,7 @@ pub fn hash_shape_3d(state: u64, shape: &Shape3D) -> u64 {
 pub fn hash_collision_shape_2d(state: u64, shape: &CollisionShape2D, kind: BodyKind) -> u64 {
     let mut state = hash_u64(state, (kind == BodyKind::Area) as u64);
     state = hash_transform_2d(state, shape.base.transform);
+    state = hash_u64(state, shape.one_way as u64);
     hash_shape_2d(state, shape.shape)
 }
 
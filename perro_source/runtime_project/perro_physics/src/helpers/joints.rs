@@ -17,13 +17,18 @@ pub fn joint_signature_2d(
     hash = hash_u32(hash, enabled as u32);
     hash = hash_u32(hash, collide_connected as u32);
     match kind {
-        JointKind2D::Pin => hash_u32(hash, 1),
+        JointKind2D::Pin(angular) => hash_joint_angular(hash_u32(hash, 1), angular),
         JointKind2D::Distance { min, max } => {
             let hash = hash_u32(hash, 2);
             let hash = hash_u32(hash, min.to_bits());
             hash_u32(hash, max.to_bits())
         }
         JointKind2D::Fixed => hash_u32(hash, 3),
+        JointKind2D::Spring {
+            rest_length,
+            stiffness,
+            damping,
+        } => hash_joint_spring(hash_u32(hash, 4), rest_length, stiffness, damping),
     }
 }
 
@@ -47,25 +52,76 @@ pub fn joint_signature_3d(
     hash = hash_u32(hash, collide_connected as u32);
     match kind {
         JointKind3D::Ball => hash_u32(hash, 1),
-        JointKind3D::Hinge { axis } => {
+        JointKind3D::Hinge { axis, angular } => {
             let hash = hash_u32(hash, 2);
             let hash = hash_u32(hash, axis.x.to_bits());
             let hash = hash_u32(hash, axis.y.to_bits());
-            hash_u32(hash, axis.z.to_bits())
+            let hash = hash_u32(hash, axis.z.to_bits());
+            hash_joint_angular(hash, angular)
         }
         JointKind3D::Fixed => hash_u32(hash, 3),
+        JointKind3D::Spring {
+            rest_length,
+            stiffness,
+            damping,
+        } => hash_joint_spring(hash_u32(hash, 4), rest_length, stiffness, damping),
     }
 }
 
+fn hash_joint_angular(mut hash: u64, angular: JointAngularDesc) -> u64 {
+    if let Some([lower, upper]) = angular.limits {
+        hash = hash_u32(hash, 1);
+        hash = hash_u32(hash, lower.to_bits());
+        hash = hash_u32(hash, upper.to_bits());
+    }
+    if let Some(motor) = angular.motor {
+        hash = hash_u32(hash, 2);
+        hash = hash_u32(hash, motor.target_velocity.to_bits());
+        hash = hash_u32(hash, motor.max_force.to_bits());
+    }
+    hash
+}
+
+fn hash_joint_spring(hash: u64, rest_length: f32, stiffness: f32, damping: f32) -> u64 {
+    let hash = hash_u32(hash, rest_length.to_bits());
+    let hash = hash_u32(hash, stiffness.to_bits());
+    hash_u32(hash, damping.to_bits())
+}
+
+/// rapier motor gain 4 velocity targets; max_force caps the pull.
+const JOINT_MOTOR_FACTOR: f32 = 1.0;
+
+fn motor_max_force(motor: JointMotorDesc) -> f32 {
+    if motor.max_force > 0.0 {
+        motor.max_force
+    } else {
+        f32::MAX
+    }
+}
+
+fn ordered_limits([a, b]: [f32; 2]) -> [f32; 2] {
+    [a.min(b), a.max(b)]
+}
+
 pub fn build_joint_2d(desc: &JointDesc2D) -> r2::GenericJoint {
     let anchor_a = na2::Point2::new(desc.anchor_a.x, desc.anchor_a.y);
     let anchor_b = na2::Point2::new(desc.anchor_b.x, desc.anchor_b.y);
     match desc.kind {
-        JointKind2D::Pin => r2::RevoluteJointBuilder::new()
-            .contacts_enabled(desc.collide_connected)
-            .local_anchor1(anchor_a)
-            .local_anchor2(anchor_b)
-            .into(),
+        JointKind2D::Pin(angular) => {
+            let mut joint = r2::RevoluteJointBuilder::new()
+                .contacts_enabled(desc.collide_connected)
+                .local_anchor1(anchor_a)
+                .local_anchor2(anchor_b);
+            if let Some(limits) = angular.limits {
+                joint = joint.limits(ordered_limits(limits));
+            }
+            if let Some(motor) = angular.motor {
+                joint = joint
+                    .motor_velocity(motor.target_velocity, JOINT_MOTOR_FACTOR)
+                    .motor_max_force(motor_max_force(motor));
+            }
+            joint.into()
+        }
         JointKind2D::Distance { min, max } => {
             let min = min.max(0.0);
             let max = max.max(min).max(0.0001);
@@ -82,6 +138,17 @@ pub fn build_joint_2d(desc: &JointDesc2D) -> r2::GenericJoint {
             .local_anchor1(anchor_a)
             .local_anchor2(anchor_b)
             .into(),
+        JointKind2D::Spring {
+            rest_length,
+            stiffness,
+            damping,
+        } => {
+            r2::SpringJointBuilder::new(rest_length.max(0.0), stiffness.max(0.0), damping.max(0.0))
+                .contacts_enabled(desc.collide_connected)
+                .local_anchor1(anchor_a)
+                .local_anchor2(anchor_b)
+                .into()
+        }
     }
 }
 
@@ -94,26 +161,66 @@ pub fn build_joint_3d(desc: &JointDesc3D) -> r3::GenericJoint {
             .local_anchor1(anchor_a)
             .local_anchor2(anchor_b)
             .into(),
-        JointKind3D::Hinge { axis } => {
+        JointKind3D::Hinge { axis, angular } => {
             let axis = if axis.x * axis.x + axis.y * axis.y + axis.z * axis.z <= 0.000_001 {
                 na3::Vector3::y_axis()
             } else {
                 na3::Unit::new_normalize(na3::Vector3::new(axis.x, axis.y, axis.z))
             };
-            r3::RevoluteJointBuilder::new(axis)
+            let mut joint = r3::RevoluteJointBuilder::new(axis)
                 .contacts_enabled(desc.collide_connected)
                 .local_anchor1(anchor_a)
-                .local_anchor2(anchor_b)
-                .into()
+                .local_anchor2(anchor_b);
+            if let Some(limits) = angular.limits {
+                joint = joint.limits(ordered_limits(limits));
+            }
+            if let Some(motor) = angular.motor {
+                joint = joint
+                    .motor_velocity(motor.target_velocity, JOINT_MOTOR_FACTOR)
+                    .motor_max_force(motor_max_force(motor));
+            }
+            joint.into()
         }
         JointKind3D::Fixed => r3::FixedJointBuilder::new()
             .contacts_enabled(desc.collide_connected)
             .local_anchor1(anchor_a)
             .local_anchor2(anchor_b)
             .into(),
+        JointKind3D::Spring {
+            rest_length,
+            stiffness,
+            damping,
+        } => {
+            r3::SpringJointBuilder::new(rest_length.max(0.0), stiffness.max(0.0), damping.max(0.0))
+                .contacts_enabled(desc.collide_connected)
+                .local_anchor1(anchor_a)
+                .local_anchor2(anchor_b)
+                .into()
+        }
     }
 }
 
+/// (linear, angular) force the joint applied last step: impulse / dt.
+pub fn joint_load_2d(world: &PhysicsWorld2D, id: NodeID) -> Option<(f32, f32)> {
+    let state = world.joint_map.get(&id)?;
+    let joint = world.impulse_joints.get(state.handle)?;
+    let dt = world.integration_parameters.dt.max(f32::EPSILON);
+    let impulses = joint.impulses;
+    let linear = (impulses[0] * impulses[0] + impulses[1] * impulses[1]).sqrt();
+    Some((linear / dt, impulses[2].abs() / dt))
+}
+
+/// 3d twin of joint_load_2d.
+pub fn joint_load_3d(world: &PhysicsWorld3D, id: NodeID) -> Option<(f32, f32)> {
+    let state = world.joint_map.get(&id)?;
+    let joint = world.impulse_joints.get(state.handle)?;
+    let dt = world.integration_parameters.dt.max(f32::EPSILON);
+    let impulses = joint.impulses;
+    let linear = impulses.fixed_rows::<3>(0).norm();
+    let angular = impulses.fixed_rows::<3>(3).norm();
+    Some((linear / dt, angular / dt))
+}
+
 pub fn remove_joint_2d(world: &mut PhysicsWorld2D, id: NodeID) {
     if let Some(state) = world.joint_map.remove(&id) {
         let _ = world.impulse_joints.remove(state.handle, true);
@@ -104,6 +104,7 @@ pub fn tilemap_shape_descs_2d(
                 friction,
                 restitution,
                 density,
+                one_way: false,
             });
         }
     }
@@ -126,6 +127,7 @@ pub fn tilemap_shape_descs_2d(
             friction,
             restitution,
             density,
+            one_way: false,
         });
     }
     out
@@ -159,6 +161,7 @@ pub fn shape_desc_2d(shape: &CollisionShape2D, friction: f32, restitution: f32)
         friction,
         restitution,
         density: 1.0,
+        one_way: shape.one_way,
     }
 }
 
@@ -288,6 +291,9 @@ pub fn build_rigid_body_3d(desc: &BodyDesc3D) -> r3::RigidBody {
     builder.build()
 }
 
+/// Collider `user_data` bit for one-way 2D shapes; see [`ShapeDesc2D::one_way`].
+pub const ONE_WAY_USER_DATA: u128 = 1;
+
 pub fn collider_builder_2d(desc: &ShapeDesc2D) -> Option<r2::Collider> {
     let sx = desc.local.scale.x.abs().max(0.0001);
     let sy = desc.local.scale.y.abs().max(0.0001);
@@ -332,6 +338,7 @@ pub fn collider_builder_2d(desc: &ShapeDesc2D) -> Option<r2::Collider> {
             .friction(desc.friction)
             .restitution(desc.restitution)
             .density(desc.density.max(0.0))
+            .user_data(if desc.one_way { ONE_WAY_USER_DATA } else { 0 })
             .build(),
     )
 }
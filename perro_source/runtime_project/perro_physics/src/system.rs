@@ -218,6 +218,7 @@ mod audio;
 mod queries;
 mod step;
 mod sync;
+mod vehicles;
 
 impl Default for PhysicsSystem {
     fn default() -> Self {
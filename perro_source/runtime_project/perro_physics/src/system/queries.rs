@@ -283,6 +283,41 @@ impl PhysicsSystem {
         let rb = world.bodies.get(state.handle)?;
         let start_orig = *rb.position();
 
+        // one-way shapes only block motion against their up axis, and never
+        // once the body already overlaps them (mid jump-through).
+        let motion = na2::Vector2::new(
+            target.x - start_orig.translation.vector.x,
+            target.y - start_orig.translation.vector.y,
+        );
+        let mut inside_one_way: Vec<r2::ColliderHandle> = Vec::new();
+        let one_way_only = |handle, collider: &r2::Collider| {
+            collider.user_data & ONE_WAY_USER_DATA != 0
+                && world.collider_owners.get(&handle) != Some(&body_id)
+        };
+        for collider_handle in &state.colliders {
+            let Some(collider) = world.colliders.get(*collider_handle) else {
+                continue;
+            };
+            if collider.is_sensor() {
+                continue;
+            }
+            let local_pos = collider
+                .position_wrt_parent()
+                .copied()
+                .unwrap_or_else(|| *collider.position());
+            world.query_pipeline.intersections_with_shape(
+                &world.bodies,
+                &world.colliders,
+                &(start_orig * local_pos),
+                collider.shape(),
+                query_filter_2d(filter).predicate(&one_way_only),
+                |handle| {
+                    inside_one_way.push(handle);
+                    true
+                },
+            );
+        }
+
         let excluded = filter.exclude_nodes.as_slice();
         let layers = filter.layers.bits();
         let mask = filter.mask.bits();
@@ -293,6 +328,12 @@ impl PhysicsSystem {
             if owner == body_id || excluded.contains(&owner) {
                 return false;
             }
+            if collider.user_data & ONE_WAY_USER_DATA != 0 {
+                let up = collider.position().rotation * na2::Vector2::y();
+                if motion.dot(&up) >= 0.0 || inside_one_way.contains(&handle) {
+                    return false;
+                }
+            }
             let collider_layers = collider.collision_groups().memberships.bits();
             (collider_layers & layers) != 0 && (collider_layers & mask) == 0
         };
@@ -785,6 +826,7 @@ mod recovery_tests {
             friction: 0.7,
             restitution: 0.0,
             density: 1.0,
+            one_way: false,
         }
     }
 
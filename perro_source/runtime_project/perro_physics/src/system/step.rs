@@ -295,6 +295,7 @@ mod tests {
             friction: 0.7,
             restitution: 0.1,
             density: 1.0,
+            one_way: false,
         }
     }
 
@@ -0,0 +1,154 @@
+use super::*;
+use crate::{BodyKind, VehicleDesc3D, VehicleState3D, VehicleWheelDesc3D, VehicleWheelState3D};
+use perro_structs::Quaternion;
+use rapier3d::control::{DynamicRayCastVehicleController, Wheel, WheelTuning};
+
+/// chassis local forward is -Z; rapier measures wheel spin + speed sign
+/// along this axis, so +Z keeps spin == angle about +X.
+const VEHICLE_FORWARD_AXIS: usize = 2;
+
+impl PhysicsSystem {
+    /// drive raycast vehicles 4 one step: suspension, engine, brake + side
+    /// friction land as impulses on each chassis. run b4 the world step.
+    /// vehicles not in `vehicles` r dropped; wheel poses r pushed to `out`.
+    pub fn update_vehicles_3d(
+        &mut self,
+        vehicles: &[VehicleDesc3D],
+        fixed_delta: f32,
+        out: &mut Vec<VehicleWheelState3D>,
+    ) {
+        out.clear();
+        let Some(world) = self.world_3d.as_mut() else {
+            return;
+        };
+        world
+            .vehicle_map
+            .retain(|id, _| vehicles.iter().any(|vehicle| vehicle.id == *id));
+        if vehicles.is_empty() {
+            return;
+        }
+        if self.query_pipeline_dirty_3d {
+            world.query_pipeline.update(&world.colliders);
+            self.query_pipeline_dirty_3d = false;
+        }
+        let dt = fixed_delta.max(0.000_1);
+        for vehicle in vehicles {
+            let Some(handle) = world
+                .body_map
+                .get(&vehicle.id)
+                .filter(|state| state.kind == BodyKind::Rigid)
+                .map(|state| state.handle)
+                .filter(|handle| world.bodies.get(*handle).is_some())
+            else {
+                world.vehicle_map.remove(&vehicle.id);
+                continue;
+            };
+            let state = world
+                .vehicle_map
+                .entry(vehicle.id)
+                .or_insert_with(|| build_vehicle_3d(handle, &vehicle.wheels));
+            // wheel set / chassis changed: rebuild (drops spin + contact state)
+            if state.controller.chassis != handle
+                || !state
+                    .wheel_ids
+                    .iter()
+                    .copied()
+                    .eq(vehicle.wheels.iter().map(|wheel| wheel.id))
+            {
+                *state = build_vehicle_3d(handle, &vehicle.wheels);
+            }
+            for (wheel, desc) in state
+                .controller
+                .wheels_mut()
+                .iter_mut()
+                .zip(&vehicle.wheels)
+            {
+                apply_wheel_desc_3d(wheel, desc, vehicle);
+            }
+            if vehicle.engine_force != 0.0
+                && let Some(body) = world.bodies.get_mut(handle)
+            {
+                body.wake_up(true);
+            }
+            state.controller.update_vehicle(
+                dt,
+                &mut world.bodies,
+                &world.colliders,
+                &world.query_pipeline,
+                r3::QueryFilter::new()
+                    .exclude_sensors()
+                    .exclude_rigid_body(handle),
+            );
+            for (wheel, desc) in state.controller.wheels().iter().zip(&vehicle.wheels) {
+                out.push(wheel_state_3d(wheel, desc));
+            }
+        }
+        self.world_3d_idle_cached = false;
+    }
+}
+
+fn build_vehicle_3d(handle: r3::RigidBodyHandle, wheels: &[VehicleWheelDesc3D]) -> VehicleState3D {
+    let mut controller = DynamicRayCastVehicleController::new(handle);
+    controller.index_forward_axis = VEHICLE_FORWARD_AXIS;
+    for desc in wheels {
+        let tuning = WheelTuning {
+            suspension_stiffness: desc.suspension_stiffness,
+            suspension_compression: desc.suspension_compression,
+            suspension_damping: desc.suspension_damping,
+            max_suspension_travel: desc.suspension_travel,
+            side_friction_stiffness: desc.side_friction,
+            friction_slip: desc.friction_slip,
+            max_suspension_force: desc.max_suspension_force,
+        };
+        controller.add_wheel(
+            na3::Point3::new(desc.mount.x, desc.mount.y, desc.mount.z),
+            -na3::Vector3::y(),
+            na3::Vector3::x(),
+            desc.suspension_rest_length,
+            desc.radius,
+            &tuning,
+        );
+    }
+    VehicleState3D {
+        controller,
+        wheel_ids: wheels.iter().map(|wheel| wheel.id).collect(),
+    }
+}
+
+/// tuning + inputs r re-applied every step so node edits land live.
+fn apply_wheel_desc_3d(wheel: &mut Wheel, desc: &VehicleWheelDesc3D, vehicle: &VehicleDesc3D) {
+    wheel.chassis_connection_point_cs = na3::Point3::new(desc.mount.x, desc.mount.y, desc.mount.z);
+    wheel.radius = desc.radius;
+    wheel.suspension_rest_length = desc.suspension_rest_length;
+    wheel.max_suspension_travel = desc.suspension_travel;
+    wheel.suspension_stiffness = desc.suspension_stiffness;
+    wheel.damping_compression = desc.suspension_compression;
+    wheel.damping_relaxation = desc.suspension_damping;
+    wheel.max_suspension_force = desc.max_suspension_force;
+    wheel.friction_slip = desc.friction_slip;
+    wheel.side_friction_stiffness = desc.side_friction;
+    wheel.engine_force = if desc.drive {
+        vehicle.engine_force
+    } else {
+        0.0
+    };
+    wheel.steering = if desc.steer { vehicle.steering } else { 0.0 };
+    wheel.brake = vehicle.brake;
+}
+
+fn wheel_state_3d(wheel: &Wheel, desc: &VehicleWheelDesc3D) -> VehicleWheelState3D {
+    let info = wheel.raycast_info();
+    let mut rotation = Quaternion::IDENTITY;
+    rotation.rotate_x(wheel.rotation).rotate_y(wheel.steering);
+    VehicleWheelState3D {
+        id: desc.id,
+        position: Vector3::new(
+            desc.mount.x,
+            desc.mount.y - info.suspension_length,
+            desc.mount.z,
+        ),
+        rotation,
+        suspension_length: info.suspension_length,
+        in_contact: info.is_in_contact,
+    }
+}
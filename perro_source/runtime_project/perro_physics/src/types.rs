@@ -1,7 +1,7 @@
 use perro_ids::NodeID;
 use perro_nodes::{Shape2D, Shape3D};
 use perro_runtime_api::sub_apis::{PhysicsRayHit2D, PhysicsRayHit3D};
-use perro_structs::{BitMask, Transform2D, Transform3D, Vector2, Vector3};
+use perro_structs::{BitMask, Quaternion, Transform2D, Transform3D, Vector2, Vector3};
 
 use crate::na3;
 
@@ -50,6 +50,9 @@ pub struct ShapeDesc2D {
     pub friction: f32,
     pub restitution: f32,
     pub density: f32,
+    /// Blocks character sweeps only when they move against the shape's local
+    /// +Y (e.g. falling onto a platform); sweeps from below pass through.
+    pub one_way: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -100,18 +103,48 @@ pub struct BodyDesc3D {
     pub shapes: Vec<ShapeDesc3D>,
 }
 
+/// Velocity motor on a pin / hinge axis. `max_force` <= 0 is unlimited.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JointMotorDesc {
+    pub target_velocity: f32,
+    pub max_force: f32,
+}
+
+/// Rotation limits (radians) and motor of a pin / hinge joint.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct JointAngularDesc {
+    pub limits: Option<[f32; 2]>,
+    pub motor: Option<JointMotorDesc>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum JointKind2D {
-    Pin,
-    Distance { min: f32, max: f32 },
+    Pin(JointAngularDesc),
+    Distance {
+        min: f32,
+        max: f32,
+    },
     Fixed,
+    Spring {
+        rest_length: f32,
+        stiffness: f32,
+        damping: f32,
+    },
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum JointKind3D {
     Ball,
-    Hinge { axis: Vector3 },
+    Hinge {
+        axis: Vector3,
+        angular: JointAngularDesc,
+    },
     Fixed,
+    Spring {
+        rest_length: f32,
+        stiffness: f32,
+        damping: f32,
+    },
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -140,6 +173,45 @@ pub struct JointDesc3D {
     pub signature: u64,
 }
 
+/// Raycast wheel of a vehicle. `mount` is the suspension top in chassis
+/// space; the ray runs down chassis -Y from there.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VehicleWheelDesc3D {
+    pub id: NodeID,
+    pub mount: Vector3,
+    pub radius: f32,
+    pub suspension_rest_length: f32,
+    pub suspension_travel: f32,
+    pub suspension_stiffness: f32,
+    pub suspension_compression: f32,
+    pub suspension_damping: f32,
+    pub max_suspension_force: f32,
+    pub friction_slip: f32,
+    pub side_friction: f32,
+    pub steer: bool,
+    pub drive: bool,
+}
+
+/// Vehicle driven by raycast wheels; `id` is the chassis rigid body.
+#[derive(Clone, Debug)]
+pub struct VehicleDesc3D {
+    pub id: NodeID,
+    pub engine_force: f32,
+    pub brake: f32,
+    pub steering: f32,
+    pub wheels: Vec<VehicleWheelDesc3D>,
+}
+
+/// Wheel pose after a vehicle update, in chassis space.
+#[derive(Clone, Copy, Debug)]
+pub struct VehicleWheelState3D {
+    pub id: NodeID,
+    pub position: Vector3,
+    pub rotation: Quaternion,
+    pub suspension_length: f32,
+    pub in_contact: bool,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct RigidProps2D {
     pub enabled: bool,
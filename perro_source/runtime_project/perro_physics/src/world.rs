@@ -51,6 +51,11 @@ pub struct JointState3D {
     pub sync_epoch: u64,
 }
 
+pub struct VehicleState3D {
+    pub controller: rapier3d::control::DynamicRayCastVehicleController,
+    pub wheel_ids: Vec<NodeID>,
+}
+
 pub struct PhysicsWorld2D {
     pub pipeline: r2::PhysicsPipeline,
     pub gravity: r2::Vector<f32>,
@@ -85,6 +90,7 @@ pub struct PhysicsWorld3D {
     pub collider_owners: AHashMap<r3::ColliderHandle, NodeID>,
     pub body_map: AHashMap<NodeID, BodyState3D>,
     pub joint_map: AHashMap<NodeID, JointState3D>,
+    pub vehicle_map: AHashMap<NodeID, VehicleState3D>,
 }
 
 impl PhysicsWorld2D {
@@ -141,6 +147,7 @@ impl PhysicsWorld3D {
             collider_owners: AHashMap::default(),
             body_map: AHashMap::default(),
             joint_map: AHashMap::default(),
+            vehicle_map: AHashMap::default(),
         }
     }
 }
@@ -2,6 +2,8 @@ const LOCALIZATION_CSV_CANDIDATES: &[&str] =
     &["localization.csv", "locale.csv", "translations.csv"];
 
 pub const MAX_AUDIO_PROPAGATION_BOUNCES: u32 = 32;
+/// Fixed steps one frame may run before leftover catch-up time is dropped.
+pub const DEFAULT_MAX_FIXED_STEPS_PER_FRAME: u32 = 2;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OcclusionCulling {
@@ -101,6 +103,132 @@ pub struct SteamConfig {
     pub input_mode: SteamInputMode,
 }
 
+/// `[integrity]`: boot-time self-check for exported games. Build-only; the
+/// runtime just verifies whatever manifest the export embedded.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IntegrityConfig {
+    pub check: bool,
+    /// `res://` paths hashed in full at startup, on top of the archive index.
+    pub critical: Vec<String>,
+}
+
+/// `[prune]`: drop `res://` files nothing reaches from the export archive.
+/// Build-only.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PruneConfig {
+    pub unused: bool,
+    /// `res://` globs always kept, e.g. files loaded by computed path.
+    pub include: Vec<String>,
+}
+
+/// Codec for one `[pack.codecs]` rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackCodec {
+    Store,
+    Zlib,
+    Lz4,
+    Zstd,
+}
+
+impl PackCodec {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Store => "store",
+            Self::Zlib => "zlib",
+            Self::Lz4 => "lz4",
+            Self::Zstd => "zstd",
+        }
+    }
+}
+
+/// `[pack]`: how archive entries are stored. `codecs` maps an extension to a
+/// codec over the built-in rules (media stored, meshes LZ4, text + scenes
+/// zstd, the rest zlib). Build-only; readers take the codec from each entry.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PackConfig {
+    /// `(lowercase extension w/o dot, codec)`, sorted by extension.
+    pub codecs: Vec<(String, PackCodec)>,
+    /// AES-GCM seal every entry; the key is baked into the exported binary.
+    pub encrypt: bool,
+}
+
+/// `[scene_lint]`: per-scene limits the static bake warns past. `0` turns a
+/// check off. Build-only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SceneLintConfig {
+    pub max_nodes: u32,
+    /// Deepest parent chain, root = 1.
+    pub max_depth: u32,
+    pub max_scripts: u32,
+    /// Decoded RGBA8 + mips of every distinct texture the scene names.
+    pub max_texture_mb: u32,
+}
+
+impl Default for SceneLintConfig {
+    fn default() -> Self {
+        Self {
+            max_nodes: 5000,
+            max_depth: 32,
+            max_scripts: 500,
+            max_texture_mb: 512,
+        }
+    }
+}
+
+/// `[layer_names.2d_physics]` / `[layer_names.3d_physics]`: `1 = "player"`.
+/// Scenes and scripts may name collision layers instead of numbering them.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LayerNamesConfig {
+    /// `(layer 1..=32, name)`, sorted by layer.
+    pub physics_2d: Vec<(u8, String)>,
+    pub physics_3d: Vec<(u8, String)>,
+}
+
+impl LayerNamesConfig {
+    pub fn from_static(physics_2d: &[(u8, &str)], physics_3d: &[(u8, &str)]) -> Self {
+        let owned = |layers: &[(u8, &str)]| {
+            layers
+                .iter()
+                .map(|(layer, name)| (*layer, (*name).to_string()))
+                .collect()
+        };
+        Self {
+            physics_2d: owned(physics_2d),
+            physics_3d: owned(physics_3d),
+        }
+    }
+
+    pub fn physics_2d_layer(&self, name: &str) -> Option<u8> {
+        find_layer(&self.physics_2d, name)
+    }
+
+    pub fn physics_3d_layer(&self, name: &str) -> Option<u8> {
+        find_layer(&self.physics_3d, name)
+    }
+}
+
+fn find_layer(layers: &[(u8, String)], name: &str) -> Option<u8> {
+    layers
+        .iter()
+        .find(|(_, layer_name)| layer_name == name)
+        .map(|(layer, _)| *layer)
+}
+
+/// `[import] <name> = { ... }`: external tool that turns a custom source format in
+/// `res/` into engine assets before the static bake. Build-only.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ImportToolConfig {
+    pub name: String,
+    /// Lowercase, without the dot.
+    pub extensions: Vec<String>,
+    /// Relative paths resolve against the project root; bare names use `PATH`.
+    pub command: String,
+    /// Passed before `<source> <out_dir>`.
+    pub args: Vec<String>,
+    /// Bump to force a re-import after the tool changes output.
+    pub version: u32,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct AudioPropagationConfig {
     pub max_bounces: u32,
@@ -118,6 +246,60 @@ impl Default for AudioPropagationConfig {
     }
 }
 
+/// One named bus from `[audio.buses.<name>]`. Effects are 0..1 amounts,
+/// compression threshold 0..1 w/ ratio >= 1 (1 = off).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AudioBusConfig {
+    pub volume: f32,
+    pub muted: bool,
+    pub low_pass: f32,
+    pub reverb: f32,
+    pub compression_threshold: f32,
+    pub compression_ratio: f32,
+}
+
+impl AudioBusConfig {
+    pub const DEFAULT: Self = Self {
+        volume: 1.0,
+        muted: false,
+        low_pass: 0.0,
+        reverb: 0.0,
+        compression_threshold: 1.0,
+        compression_ratio: 1.0,
+    };
+}
+
+impl Default for AudioBusConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Built-in buses. `master` drives master volume/chain; `music`/`sfx` map to
+/// `AudioBusID::from_string("music" / "sfx")` (same as `audio_bus!`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AudioBusesConfig {
+    pub master: AudioBusConfig,
+    pub music: AudioBusConfig,
+    pub sfx: AudioBusConfig,
+}
+
+impl AudioBusesConfig {
+    pub const NAMES: [&'static str; 3] = ["master", "music", "sfx"];
+
+    pub const DEFAULT: Self = Self {
+        master: AudioBusConfig::DEFAULT,
+        music: AudioBusConfig::DEFAULT,
+        sfx: AudioBusConfig::DEFAULT,
+    };
+}
+
+impl Default for AudioBusesConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct AudioConfig {
     pub listener_max_distance: f32,
@@ -126,6 +308,7 @@ pub struct AudioConfig {
     pub debug_rays: bool,
     pub propagation_2d: AudioPropagationConfig,
     pub propagation_3d: AudioPropagationConfig,
+    pub buses: AudioBusesConfig,
 }
 
 impl Default for AudioConfig {
@@ -141,6 +324,7 @@ impl Default for AudioConfig {
                 rays_per_tick: 128,
                 max_ray_distance: 500.0,
             },
+            buses: AudioBusesConfig::DEFAULT,
         }
     }
 }
@@ -220,8 +404,12 @@ pub struct StaticProjectConfig {
     pub vsync: bool,
     pub frame_rate_cap: FrameRateCap,
     pub target_fixed_update: Option<f32>,
+    pub max_fixed_steps_per_frame: u32,
     pub physics_gravity: f32,
     pub physics_coef: f32,
+    pub physics_interpolation: bool,
+    pub physics_layer_names_2d: &'static [(u8, &'static str)],
+    pub physics_layer_names_3d: &'static [(u8, &'static str)],
     pub msaa: bool,
     pub ssao: SsaoQuality,
     pub meshlets: bool,
@@ -244,6 +432,7 @@ pub struct StaticProjectConfig {
     pub audio_3d_max_bounces: u32,
     pub audio_3d_rays_per_tick: u32,
     pub audio_3d_max_ray_distance: f32,
+    pub audio_buses: AudioBusesConfig,
     pub localization_default_locale: &'static str,
     pub steam_enabled: bool,
     pub steam_app_id: Option<u32>,
@@ -274,8 +463,12 @@ impl StaticProjectConfig {
             vsync: false,
             frame_rate_cap: FrameRateCap::Unlimited,
             target_fixed_update: Some(60.0),
+            max_fixed_steps_per_frame: DEFAULT_MAX_FIXED_STEPS_PER_FRAME,
             physics_gravity: -9.81,
             physics_coef: 1.0,
+            physics_interpolation: true,
+            physics_layer_names_2d: &[],
+            physics_layer_names_3d: &[],
             msaa: true,
             ssao: SsaoQuality::Medium,
             meshlets: false,
@@ -298,6 +491,7 @@ impl StaticProjectConfig {
             audio_3d_max_bounces: 4,
             audio_3d_rays_per_tick: 128,
             audio_3d_max_ray_distance: 500.0,
+            audio_buses: AudioBusesConfig::DEFAULT,
             localization_default_locale: "en",
             steam_enabled: false,
             steam_app_id: None,
@@ -320,6 +514,11 @@ impl StaticProjectConfig {
         self
     }
 
+    pub const fn with_max_fixed_steps_per_frame(mut self, max_steps: u32) -> Self {
+        self.max_fixed_steps_per_frame = max_steps;
+        self
+    }
+
     pub const fn with_physics_gravity(mut self, gravity: f32) -> Self {
         self.physics_gravity = gravity;
         self
@@ -330,6 +529,21 @@ impl StaticProjectConfig {
         self
     }
 
+    pub const fn with_physics_interpolation(mut self, enabled: bool) -> Self {
+        self.physics_interpolation = enabled;
+        self
+    }
+
+    pub const fn with_physics_layer_names(
+        mut self,
+        layers_2d: &'static [(u8, &'static str)],
+        layers_3d: &'static [(u8, &'static str)],
+    ) -> Self {
+        self.physics_layer_names_2d = layers_2d;
+        self.physics_layer_names_3d = layers_3d;
+        self
+    }
+
     pub const fn with_msaa(mut self, enabled: bool) -> Self {
         self.msaa = enabled;
         self
@@ -401,6 +615,7 @@ impl StaticProjectConfig {
         self.audio_3d_max_bounces = config.propagation_3d.max_bounces;
         self.audio_3d_rays_per_tick = config.propagation_3d.rays_per_tick;
         self.audio_3d_max_ray_distance = config.propagation_3d.max_ray_distance;
+        self.audio_buses = config.buses;
         self
     }
 
@@ -458,8 +673,14 @@ impl StaticProjectConfig {
             vsync: self.vsync,
             frame_rate_cap: self.frame_rate_cap,
             target_fixed_update: self.target_fixed_update,
+            max_fixed_steps_per_frame: self.max_fixed_steps_per_frame,
             physics_gravity: self.physics_gravity,
             physics_coef: self.physics_coef,
+            physics_interpolation: self.physics_interpolation,
+            layer_names: LayerNamesConfig::from_static(
+                self.physics_layer_names_2d,
+                self.physics_layer_names_3d,
+            ),
             msaa: self.msaa,
             ssao: self.ssao,
             meshlets: self.meshlets,
@@ -491,6 +712,7 @@ impl StaticProjectConfig {
                     rays_per_tick: self.audio_3d_rays_per_tick,
                     max_ray_distance: self.audio_3d_max_ray_distance,
                 },
+                buses: self.audio_buses,
             },
             localization: Some(LocalizationConfig {
                 source_csv: String::new(),
@@ -503,7 +725,13 @@ impl StaticProjectConfig {
                 app_id: self.steam_app_id,
                 input_mode: self.steam_input_mode,
             },
+            integrity: IntegrityConfig::default(),
+            prune: PruneConfig::default(),
+            scene_lint: SceneLintConfig::default(),
+            pack: PackConfig::default(),
+            imports: Vec::new(),
             demo: DemoBuildConfig::default(),
+            platform: PlatformBuildConfig::default(),
         }
     }
 }
@@ -524,8 +752,12 @@ pub struct ProjectConfig {
     pub vsync: bool,
     pub frame_rate_cap: FrameRateCap,
     pub target_fixed_update: Option<f32>,
+    pub max_fixed_steps_per_frame: u32,
     pub physics_gravity: f32,
     pub physics_coef: f32,
+    /// `[physics] interpolation`: blend rigid body render poses between fixed steps.
+    pub physics_interpolation: bool,
+    pub layer_names: LayerNamesConfig,
     pub msaa: bool,
     pub ssao: SsaoQuality,
     pub meshlets: bool,
@@ -541,7 +773,111 @@ pub struct ProjectConfig {
     pub localization: Option<LocalizationConfig>,
     pub input_map: perro_input_api::InputMap,
     pub steam: SteamConfig,
+    pub integrity: IntegrityConfig,
+    pub prune: PruneConfig,
+    pub scene_lint: SceneLintConfig,
+    pub pack: PackConfig,
+    pub imports: Vec<ImportToolConfig>,
     pub demo: DemoBuildConfig,
+    pub platform: PlatformBuildConfig,
+}
+
+/// Export/run target a `[platform.<name>]` table in project.toml overrides for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProjectPlatform {
+    Windows,
+    Linux,
+    Macos,
+    Web,
+    Android,
+}
+
+impl ProjectPlatform {
+    pub const ALL: [Self; 5] = [
+        Self::Windows,
+        Self::Linux,
+        Self::Macos,
+        Self::Web,
+        Self::Android,
+    ];
+
+    /// Table name under `[platform]`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Windows => "windows",
+            Self::Linux => "linux",
+            Self::Macos => "macos",
+            Self::Web => "web",
+            Self::Android => "android",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|platform| platform.as_str() == name)
+    }
+
+    /// Platform of a Rust target triple (`x86_64-pc-windows-msvc`,
+    /// `wasm32-unknown-unknown`, ...). `None` for unsupported targets.
+    pub fn from_target_triple(triple: &str) -> Option<Self> {
+        if triple.starts_with("wasm32") {
+            Some(Self::Web)
+        } else if triple.contains("android") {
+            Some(Self::Android)
+        } else if triple.contains("windows") {
+            Some(Self::Windows)
+        } else if triple.contains("apple-darwin") {
+            Some(Self::Macos)
+        } else if triple.contains("linux") {
+            Some(Self::Linux)
+        } else {
+            None
+        }
+    }
+
+    /// Platform this binary was compiled for.
+    pub const fn host() -> Self {
+        if cfg!(target_arch = "wasm32") {
+            Self::Web
+        } else if cfg!(target_os = "android") {
+            Self::Android
+        } else if cfg!(target_os = "windows") {
+            Self::Windows
+        } else if cfg!(target_os = "macos") {
+            Self::Macos
+        } else {
+            Self::Linux
+        }
+    }
+}
+
+/// `[platform.<name>]` overlay applied for the target platform. Override
+/// tables are already merged into the config; only the exclude globs remain.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PlatformBuildConfig {
+    /// Target the config was parsed for; `None` means base config only.
+    pub target: Option<ProjectPlatform>,
+    pub exclude: Vec<String>,
+}
+
+impl PlatformBuildConfig {
+    pub fn excludes(&self, path: &str) -> bool {
+        path.strip_prefix("res://").is_some_and(|rel| {
+            self.exclude.iter().any(|pattern| {
+                pattern
+                    .strip_prefix("res://")
+                    .is_some_and(|pattern| demo_glob_matches(pattern, rel))
+            })
+        })
+    }
+
+    pub fn relative_patterns(&self) -> Vec<String> {
+        self.exclude
+            .iter()
+            .filter_map(|path| path.strip_prefix("res://").map(str::to_string))
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -638,8 +974,11 @@ impl ProjectConfig {
             vsync: false,
             frame_rate_cap: FrameRateCap::Unlimited,
             target_fixed_update: Some(60.0),
+            max_fixed_steps_per_frame: DEFAULT_MAX_FIXED_STEPS_PER_FRAME,
             physics_gravity: -9.81,
             physics_coef: 1.0,
+            physics_interpolation: true,
+            layer_names: LayerNamesConfig::default(),
             msaa: true,
             ssao: SsaoQuality::Medium,
             meshlets: false,
@@ -655,9 +994,27 @@ impl ProjectConfig {
             localization: None,
             input_map: perro_input_api::InputMap::new(),
             steam: SteamConfig::default(),
+            integrity: IntegrityConfig::default(),
+            prune: PruneConfig::default(),
+            scene_lint: SceneLintConfig::default(),
+            pack: PackConfig::default(),
+            imports: Vec::new(),
             demo: DemoBuildConfig::default(),
+            platform: PlatformBuildConfig::default(),
         }
     }
+
+    /// True when the demo or target-platform config strips `path` from exports.
+    pub fn excludes_asset(&self, path: &str) -> bool {
+        self.demo.excludes(path) || self.platform.excludes(path)
+    }
+
+    /// Demo and platform exclude globs relative to `res://`, for walkdir filters.
+    pub fn excluded_asset_patterns(&self) -> Vec<String> {
+        let mut patterns = self.demo.relative_patterns();
+        patterns.extend(self.platform.relative_patterns());
+        patterns
+    }
 }
 
 impl ProjectRoutesConfig {
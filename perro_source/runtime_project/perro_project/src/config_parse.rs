@@ -36,11 +36,13 @@ pixel_snapping = true
 
 [runtime]
 frame_rate_cap = "unlimited"     # fps number | "unlimited" | "refresh_rate"
-target_fixed_update = 60
+physics_fps = 60                 # fixed update rate in hz
+max_fixed_steps_per_frame = 2    # catch-up cap; extra backlog is dropped
 
 [physics]
 gravity = -9.81
 coef = 1.0
+interpolation = true             # blend rigid body render poses between fixed steps
 
 [audio]
 listener_max_distance = 500.0
@@ -55,11 +57,13 @@ rays_per_tick_2d = 64
 rays_per_tick_3d = 128
 
 # Optional localization table.
-# Put localization.csv, locale.csv, or translations.csv next to project.toml.
+# Put localization.csv, locale.csv, or translations.csv next to project.toml,
+# or point `source` at the CSV (relative to project.toml).
 # First column must be key. Other columns use language codes.
 #
 # [localization]
 # default_locale = "en"
+# source = "locale/strings.csv"
 
 # Optional Steam integration.
 # [steam]
@@ -84,9 +88,19 @@ pub fn load_project_toml(root: &Path) -> Result<ProjectConfig, ProjectError> {
     load_project_toml_with_demo(root, false)
 }
 
+/// Load for the platform this binary runs on; exports for another target use
+/// [`load_project_toml_for_platform`].
 pub fn load_project_toml_with_demo(root: &Path, demo: bool) -> Result<ProjectConfig, ProjectError> {
+    load_project_toml_for_platform(root, demo, Some(ProjectPlatform::host()))
+}
+
+pub fn load_project_toml_for_platform(
+    root: &Path,
+    demo: bool,
+    platform: Option<ProjectPlatform>,
+) -> Result<ProjectConfig, ProjectError> {
     let project_toml = fs::read_to_string(root.join("project.toml"))?;
-    let mut config = parse_project_toml_with_demo(&project_toml, demo)?;
+    let mut config = parse_project_toml_for_platform(&project_toml, demo, platform)?;
     apply_sibling_localization(root, &mut config)?;
     config.input_map = load_input_map_toml(root)?;
     Ok(config)
@@ -125,6 +139,12 @@ const KNOWN_PROJECT_TOML_TABLES: &[&str] = &[
     "audio",
     "localization",
     "steam",
+    "integrity",
+    "prune",
+    "scene_lint",
+    "pack",
+    "import",
+    "layer_names",
     "demo",
 ];
 
@@ -135,8 +155,20 @@ pub fn parse_project_toml(contents: &str) -> Result<ProjectConfig, ProjectError>
 pub fn parse_project_toml_with_demo(
     contents: &str,
     demo: bool,
+) -> Result<ProjectConfig, ProjectError> {
+    parse_project_toml_for_platform(contents, demo, None)
+}
+
+/// Parse with the `[platform.<name>]` overlay for `platform` merged over the
+/// base tables (then the demo overlay, when `demo`). `None` parses the base
+/// config; every platform table is validated either way.
+pub fn parse_project_toml_for_platform(
+    contents: &str,
+    demo: bool,
+    platform: Option<ProjectPlatform>,
 ) -> Result<ProjectConfig, ProjectError> {
     let mut value = parse_toml_document_value(contents)?;
+    let platform_config = apply_platform_overlay(&mut value, platform)?;
     let demo_config = apply_demo_overlay(&mut value, demo)?;
     let project_table = value
         .get("project")
@@ -161,10 +193,16 @@ pub fn parse_project_toml_with_demo(
     let localization_table = value.get("localization").and_then(Value::as_table);
     let metadata_table = value.get("metadata").and_then(Value::as_table);
     let steam_table = value.get("steam").and_then(Value::as_table);
+    let integrity_table = value.get("integrity").and_then(Value::as_table);
+    let prune_table = value.get("prune").and_then(Value::as_table);
+    let scene_lint_table = value.get("scene_lint").and_then(Value::as_table);
+    let pack_table = value.get("pack").and_then(Value::as_table);
+    let import_table = value.get("import").and_then(Value::as_table);
     let audio_table = value.get("audio").and_then(Value::as_table);
     let web_table = value.get("web").and_then(Value::as_table);
     let rendering_table = value.get("rendering").and_then(Value::as_table);
     let ui_table = value.get("ui").and_then(Value::as_table);
+    let layer_names_table = value.get("layer_names").and_then(Value::as_table);
 
     let name = project_table
         .get("name")
@@ -205,8 +243,11 @@ pub fn parse_project_toml_with_demo(
     let vsync = parse_bool_with_default(graphics_table, "vsync", false)?;
     let frame_rate_cap = parse_frame_rate_cap(runtime_table)?;
     let target_fixed_update = parse_target_fixed_update(runtime_table)?;
+    let max_fixed_steps_per_frame = parse_max_fixed_steps_per_frame(runtime_table)?;
     let physics_gravity = parse_physics_gravity(physics_table)?;
     let physics_coef = parse_physics_coef(physics_table)?;
+    let physics_interpolation = parse_physics_interpolation(physics_table)?;
+    let layer_names = parse_layer_names(layer_names_table)?;
     let msaa = parse_bool_with_default(graphics_table, "msaa", true)?;
     let ssao = parse_ssao_with_default(graphics_table, "ssao", SsaoQuality::Medium)?;
     let meshlets = parse_bool_with_default(graphics_table, "meshlets", false)?;
@@ -233,6 +274,11 @@ pub fn parse_project_toml_with_demo(
     let mut metadata = parse_metadata(metadata_table)?;
     apply_project_identity(project_table, &mut metadata)?;
     let steam = parse_steam(steam_table)?;
+    let integrity = parse_integrity(integrity_table)?;
+    let prune = parse_prune(prune_table)?;
+    let scene_lint = parse_scene_lint(scene_lint_table)?;
+    let pack = parse_pack(pack_table)?;
+    let imports = parse_imports(import_table)?;
     let audio = parse_audio(audio_table)?;
     let web = parse_web(web_table)?;
     let rendering = parse_rendering(graphics_table, rendering_table, ui_table)?;
@@ -252,8 +298,11 @@ pub fn parse_project_toml_with_demo(
         vsync,
         frame_rate_cap,
         target_fixed_update,
+        max_fixed_steps_per_frame,
         physics_gravity,
         physics_coef,
+        physics_interpolation,
+        layer_names,
         msaa,
         ssao,
         meshlets,
@@ -269,7 +318,13 @@ pub fn parse_project_toml_with_demo(
         localization,
         input_map: perro_input_api::InputMap::new(),
         steam,
+        integrity,
+        prune,
+        scene_lint,
+        pack,
+        imports,
         demo: demo_config,
+        platform: platform_config,
     })
 }
 
@@ -298,7 +353,7 @@ fn apply_demo_overlay(value: &mut Value, active: bool) -> Result<DemoBuildConfig
                         "entries must be strings".to_string(),
                     )
                 })?;
-                validate_demo_pattern(path)?;
+                validate_res_glob("demo.exclude", path)?;
                 Ok(path.to_string())
             })
             .collect::<Result<Vec<_>, ProjectError>>()?,
@@ -328,18 +383,101 @@ fn apply_demo_overlay(value: &mut Value, active: bool) -> Result<DemoBuildConfig
             let target = root
                 .entry(key)
                 .or_insert_with(|| Value::Table(toml::map::Map::new()));
-            merge_demo_value(target, override_value)?;
+            merge_overlay_value(target, override_value)?;
         }
     }
     Ok(DemoBuildConfig { active, exclude })
 }
 
-fn merge_demo_value(target: &mut Value, overlay: Value) -> Result<(), ProjectError> {
+fn apply_platform_overlay(
+    value: &mut Value,
+    target: Option<ProjectPlatform>,
+) -> Result<PlatformBuildConfig, ProjectError> {
+    let root = value.as_table_mut().ok_or_else(|| {
+        ProjectError::InvalidField("project.toml", "must be a TOML table".to_string())
+    })?;
+    let Some(platforms_value) = root.remove("platform") else {
+        return Ok(PlatformBuildConfig {
+            target,
+            exclude: Vec::new(),
+        });
+    };
+    let platforms = platforms_value.as_table().cloned().ok_or_else(|| {
+        ProjectError::InvalidField("platform", "must be a TOML table".to_string())
+    })?;
+    let mut selected = None;
+    for (name, platform_value) in platforms {
+        let Some(platform) = ProjectPlatform::from_name(&name) else {
+            let known = ProjectPlatform::ALL.map(ProjectPlatform::as_str).join(", ");
+            return Err(ProjectError::InvalidField(
+                "platform",
+                format!("unknown platform `{name}` (expected one of: {known})"),
+            ));
+        };
+        let mut overlay = platform_value.as_table().cloned().ok_or_else(|| {
+            ProjectError::InvalidField("platform", format!("`{name}` must be a table"))
+        })?;
+        let exclude = match overlay.remove("exclude") {
+            None => Vec::new(),
+            Some(Value::Array(values)) => values
+                .into_iter()
+                .map(|value| {
+                    let path = value.as_str().ok_or_else(|| {
+                        ProjectError::InvalidField(
+                            "platform.exclude",
+                            "entries must be strings".to_string(),
+                        )
+                    })?;
+                    validate_res_glob("platform.exclude", path)?;
+                    Ok(path.to_string())
+                })
+                .collect::<Result<Vec<_>, ProjectError>>()?,
+            Some(_) => {
+                return Err(ProjectError::InvalidField(
+                    "platform.exclude",
+                    "must be an array of strings".to_string(),
+                ));
+            }
+        };
+        for (key, table) in &overlay {
+            if !KNOWN_PROJECT_TOML_TABLES.contains(&key.as_str()) || key == "demo" {
+                return Err(ProjectError::InvalidField(
+                    "platform",
+                    format!("unknown override table `{key}` in `{name}`"),
+                ));
+            }
+            if !table.is_table() {
+                return Err(ProjectError::InvalidField(
+                    "platform",
+                    format!("override `{name}.{key}` must be a table"),
+                ));
+            }
+        }
+        if Some(platform) == target {
+            selected = Some((overlay, exclude));
+        }
+    }
+    let Some((overlay, exclude)) = selected else {
+        return Ok(PlatformBuildConfig {
+            target,
+            exclude: Vec::new(),
+        });
+    };
+    for (key, override_value) in overlay {
+        let base = root
+            .entry(key)
+            .or_insert_with(|| Value::Table(toml::map::Map::new()));
+        merge_overlay_value(base, override_value)?;
+    }
+    Ok(PlatformBuildConfig { target, exclude })
+}
+
+fn merge_overlay_value(target: &mut Value, overlay: Value) -> Result<(), ProjectError> {
     match (target, overlay) {
         (Value::Table(target), Value::Table(overlay)) => {
             for (key, value) in overlay {
                 if let Some(existing) = target.get_mut(&key) {
-                    merge_demo_value(existing, value)?;
+                    merge_overlay_value(existing, value)?;
                 } else {
                     target.insert(key, value);
                 }
@@ -353,10 +491,10 @@ fn merge_demo_value(target: &mut Value, overlay: Value) -> Result<(), ProjectErr
     }
 }
 
-fn validate_demo_pattern(path: &str) -> Result<(), ProjectError> {
+fn validate_res_glob(field: &'static str, path: &str) -> Result<(), ProjectError> {
     let Some(rel) = path.strip_prefix("res://") else {
         return Err(ProjectError::InvalidField(
-            "demo.exclude",
+            field,
             format!("path `{path}` must start with `res://`"),
         ));
     };
@@ -367,7 +505,7 @@ fn validate_demo_pattern(path: &str) -> Result<(), ProjectError> {
             .any(|part| part.is_empty() || part == "." || part == "..")
     {
         return Err(ProjectError::InvalidField(
-            "demo.exclude",
+            field,
             format!("invalid asset glob `{path}`"),
         ));
     }
@@ -430,7 +568,29 @@ pub fn parse_input_map_toml(contents: &str) -> Result<perro_input_api::InputMap,
                 format!("action `{action_name}` needs at least 1 binding"),
             ));
         }
-        actions.push(perro_input_api::InputAction::new(action_name, bindings));
+        let context = match table.get("context") {
+            None => "",
+            Some(value) => value.as_str().map(str::trim).ok_or_else(|| {
+                ProjectError::InvalidField(
+                    "input_map",
+                    format!("action `{action_name}` field `context` must be a string"),
+                )
+            })?,
+        };
+        let consume = match table.get("consume") {
+            None => false,
+            Some(value) => value.as_bool().ok_or_else(|| {
+                ProjectError::InvalidField(
+                    "input_map",
+                    format!("action `{action_name}` field `consume` must be a boolean"),
+                )
+            })?,
+        };
+        actions.push(
+            perro_input_api::InputAction::new(action_name, bindings)
+                .with_context(context)
+                .with_consume(consume),
+        );
     }
     Ok(perro_input_api::InputMap::from_actions(actions))
 }
@@ -557,6 +717,9 @@ fn parse_input_map_binding_list(
 }
 
 fn parse_input_map_key_binding(raw: &str) -> Option<perro_input_api::InputBinding> {
+    if raw.contains('+') {
+        return perro_input_api::KeyChord::from_name(raw).map(perro_input_api::InputBinding::Chord);
+    }
     perro_input_api::KeyCode::from_name(raw).map(perro_input_api::InputBinding::Key)
 }
 
@@ -668,6 +831,240 @@ fn parse_steam(table: Option<&toml::map::Map<String, Value>>) -> Result<SteamCon
     })
 }
 
+fn parse_integrity(
+    table: Option<&toml::map::Map<String, Value>>,
+) -> Result<IntegrityConfig, ProjectError> {
+    let Some(table) = table else {
+        return Ok(IntegrityConfig::default());
+    };
+    let check = match table.get("check") {
+        Some(value) => value.as_bool().ok_or_else(|| {
+            ProjectError::InvalidField("integrity.check", "must be a boolean".to_string())
+        })?,
+        None => false,
+    };
+    let critical = match table.get("critical") {
+        Some(Value::Array(items)) => items
+            .iter()
+            .map(|item| {
+                let path = item.as_str().ok_or_else(|| {
+                    ProjectError::InvalidField(
+                        "integrity.critical",
+                        "must be an array of res:// strings".to_string(),
+                    )
+                })?;
+                validate_res_path("integrity.critical", path)?;
+                Ok(path.to_string())
+            })
+            .collect::<Result<Vec<_>, ProjectError>>()?,
+        Some(_) => {
+            return Err(ProjectError::InvalidField(
+                "integrity.critical",
+                "must be an array of res:// strings".to_string(),
+            ));
+        }
+        None => Vec::new(),
+    };
+    Ok(IntegrityConfig { check, critical })
+}
+
+fn parse_prune(table: Option<&toml::map::Map<String, Value>>) -> Result<PruneConfig, ProjectError> {
+    let Some(table) = table else {
+        return Ok(PruneConfig::default());
+    };
+    let unused = match table.get("unused") {
+        Some(value) => value.as_bool().ok_or_else(|| {
+            ProjectError::InvalidField("prune.unused", "must be a boolean".to_string())
+        })?,
+        None => false,
+    };
+    let include = match table.get("include") {
+        Some(Value::Array(items)) => items
+            .iter()
+            .map(|item| {
+                let path = item.as_str().ok_or_else(|| {
+                    ProjectError::InvalidField(
+                        "prune.include",
+                        "must be an array of res:// strings".to_string(),
+                    )
+                })?;
+                validate_res_glob("prune.include", path)?;
+                Ok(path.to_string())
+            })
+            .collect::<Result<Vec<_>, ProjectError>>()?,
+        Some(_) => {
+            return Err(ProjectError::InvalidField(
+                "prune.include",
+                "must be an array of res:// strings".to_string(),
+            ));
+        }
+        None => Vec::new(),
+    };
+    Ok(PruneConfig { unused, include })
+}
+
+fn parse_scene_lint(
+    table: Option<&toml::map::Map<String, Value>>,
+) -> Result<SceneLintConfig, ProjectError> {
+    let defaults = SceneLintConfig::default();
+    let Some(table) = table else {
+        return Ok(defaults);
+    };
+    Ok(SceneLintConfig {
+        max_nodes: parse_u32_table_field(table, "max_nodes", defaults.max_nodes, "scene_lint")?,
+        max_depth: parse_u32_table_field(table, "max_depth", defaults.max_depth, "scene_lint")?,
+        max_scripts: parse_u32_table_field(
+            table,
+            "max_scripts",
+            defaults.max_scripts,
+            "scene_lint",
+        )?,
+        max_texture_mb: parse_u32_table_field(
+            table,
+            "max_texture_mb",
+            defaults.max_texture_mb,
+            "scene_lint",
+        )?,
+    })
+}
+
+fn parse_pack(table: Option<&toml::map::Map<String, Value>>) -> Result<PackConfig, ProjectError> {
+    let encrypt = match table.and_then(|table| table.get("encrypt")) {
+        Some(value) => value.as_bool().ok_or_else(|| {
+            ProjectError::InvalidField("pack.encrypt", "must be a boolean".to_string())
+        })?,
+        None => false,
+    };
+    let Some(codecs) = table.and_then(|table| table.get("codecs")) else {
+        return Ok(PackConfig {
+            encrypt,
+            ..PackConfig::default()
+        });
+    };
+    let codecs = codecs.as_table().ok_or_else(|| {
+        ProjectError::InvalidField("pack.codecs", "must be a table of ext = codec".to_string())
+    })?;
+    let mut rules = Vec::with_capacity(codecs.len());
+    for (ext, codec) in codecs {
+        let codec = match codec.as_str() {
+            Some("store") => PackCodec::Store,
+            Some("zlib") => PackCodec::Zlib,
+            Some("lz4") => PackCodec::Lz4,
+            Some("zstd") => PackCodec::Zstd,
+            _ => {
+                return Err(ProjectError::InvalidField(
+                    "pack.codecs",
+                    format!("`{ext}` must be one of: store, zlib, lz4, zstd"),
+                ));
+            }
+        };
+        rules.push((ext.trim_start_matches('.').to_ascii_lowercase(), codec));
+    }
+    rules.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(PackConfig {
+        codecs: rules,
+        encrypt,
+    })
+}
+
+fn parse_imports(
+    table: Option<&toml::map::Map<String, Value>>,
+) -> Result<Vec<ImportToolConfig>, ProjectError> {
+    let Some(table) = table else {
+        return Ok(Vec::new());
+    };
+    let mut tools = Vec::with_capacity(table.len());
+    for (name, value) in table {
+        let tool = value.as_table().ok_or_else(|| {
+            ProjectError::InvalidField("import", format!("`import.{name}` must be a table"))
+        })?;
+        let extensions = match tool.get("extensions") {
+            Some(Value::Array(items)) if !items.is_empty() => items
+                .iter()
+                .map(|item| {
+                    item.as_str()
+                        .map(|ext| ext.trim_start_matches('.').to_ascii_lowercase())
+                        .filter(|ext| !ext.is_empty())
+                        .ok_or_else(|| {
+                            ProjectError::InvalidField(
+                                "import.extensions",
+                                format!("`import.{name}` extensions must be non-empty strings"),
+                            )
+                        })
+                })
+                .collect::<Result<Vec<_>, ProjectError>>()?,
+            _ => {
+                return Err(ProjectError::InvalidField(
+                    "import.extensions",
+                    format!("`import.{name}` needs a non-empty array of extensions"),
+                ));
+            }
+        };
+        let command = tool
+            .get("command")
+            .and_then(Value::as_str)
+            .filter(|command| !command.trim().is_empty())
+            .ok_or_else(|| {
+                ProjectError::InvalidField(
+                    "import.command",
+                    format!("`import.{name}` needs a command string"),
+                )
+            })?
+            .to_string();
+        let args = match tool.get("args") {
+            Some(Value::Array(items)) => items
+                .iter()
+                .map(|item| {
+                    item.as_str().map(str::to_string).ok_or_else(|| {
+                        ProjectError::InvalidField(
+                            "import.args",
+                            format!("`import.{name}` args must be strings"),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>, ProjectError>>()?,
+            Some(_) => {
+                return Err(ProjectError::InvalidField(
+                    "import.args",
+                    format!("`import.{name}` args must be an array of strings"),
+                ));
+            }
+            None => Vec::new(),
+        };
+        let version = match tool.get("version") {
+            Some(value) => value
+                .as_integer()
+                .and_then(|v| u32::try_from(v).ok())
+                .ok_or_else(|| {
+                    ProjectError::InvalidField(
+                        "import.version",
+                        format!("`import.{name}` version must be a non-negative integer"),
+                    )
+                })?,
+            None => 1,
+        };
+        for ext in &extensions {
+            if let Some(other) = tools
+                .iter()
+                .find(|other: &&ImportToolConfig| other.extensions.contains(ext))
+            {
+                return Err(ProjectError::InvalidField(
+                    "import.extensions",
+                    format!("`.{ext}` claimed by both `{}` and `{name}`", other.name),
+                ));
+            }
+        }
+        tools.push(ImportToolConfig {
+            name: name.clone(),
+            extensions,
+            command,
+            args,
+            version,
+        });
+    }
+    Ok(tools)
+}
+
 fn parse_steam_input_mode(
     table: &toml::map::Map<String, Value>,
 ) -> Result<SteamInputMode, ProjectError> {
@@ -735,9 +1132,92 @@ fn parse_audio(table: Option<&toml::map::Map<String, Value>>) -> Result<AudioCon
     apply_flat_audio_propagation(table, &mut cfg)?;
     validate_audio_bounces(&cfg.propagation_2d, "audio.max_bounces_2d")?;
     validate_audio_bounces(&cfg.propagation_3d, "audio.max_bounces_3d")?;
+    if let Some(buses) = table.get("buses") {
+        cfg.buses = parse_audio_buses(buses)?;
+    }
     Ok(cfg)
 }
 
+fn parse_audio_buses(value: &Value) -> Result<AudioBusesConfig, ProjectError> {
+    let Some(table) = value.as_table() else {
+        return Err(ProjectError::InvalidField(
+            "audio.buses",
+            "must be a table of bus tables".to_string(),
+        ));
+    };
+    let mut buses = AudioBusesConfig::default();
+    for (name, bus) in table {
+        let (slot, path) = match name.as_str() {
+            "master" => (&mut buses.master, "audio.buses.master"),
+            "music" => (&mut buses.music, "audio.buses.music"),
+            "sfx" => (&mut buses.sfx, "audio.buses.sfx"),
+            _ => {
+                return Err(ProjectError::InvalidField(
+                    "audio.buses",
+                    format!(
+                        "unknown bus `{name}`; expected one of {}",
+                        AudioBusesConfig::NAMES.join(", ")
+                    ),
+                ));
+            }
+        };
+        let Some(bus) = bus.as_table() else {
+            return Err(ProjectError::InvalidField(
+                path,
+                "must be a table".to_string(),
+            ));
+        };
+        *slot = parse_audio_bus(bus, path)?;
+    }
+    Ok(buses)
+}
+
+fn parse_audio_bus(
+    table: &toml::map::Map<String, Value>,
+    path: &'static str,
+) -> Result<AudioBusConfig, ProjectError> {
+    let mut bus = AudioBusConfig::default();
+    bus.volume = parse_f32_table_field(table, "volume", bus.volume, path)?;
+    bus.muted = table
+        .get("muted")
+        .map(|value| {
+            value.as_bool().ok_or_else(|| {
+                ProjectError::InvalidField(path, "muted must be a boolean".to_string())
+            })
+        })
+        .transpose()?
+        .unwrap_or(bus.muted);
+    bus.low_pass = parse_f32_table_field(table, "low_pass", bus.low_pass, path)?;
+    bus.reverb = parse_f32_table_field(table, "reverb", bus.reverb, path)?;
+    bus.compression_threshold = parse_f32_table_field(
+        table,
+        "compression_threshold",
+        bus.compression_threshold,
+        path,
+    )?;
+    bus.compression_ratio =
+        parse_f32_table_field(table, "compression_ratio", bus.compression_ratio, path)?;
+    for (key, value) in [
+        ("low_pass", bus.low_pass),
+        ("reverb", bus.reverb),
+        ("compression_threshold", bus.compression_threshold),
+    ] {
+        if value > 1.0 {
+            return Err(ProjectError::InvalidField(
+                path,
+                format!("{key} must be <= 1"),
+            ));
+        }
+    }
+    if bus.compression_ratio < 1.0 {
+        return Err(ProjectError::InvalidField(
+            path,
+            "compression_ratio must be >= 1".to_string(),
+        ));
+    }
+    Ok(bus)
+}
+
 fn apply_flat_audio_propagation(
     table: &toml::map::Map<String, Value>,
     cfg: &mut AudioConfig,
@@ -962,8 +1442,21 @@ fn parse_target_fixed_update(
     let Some(runtime) = runtime else {
         return Ok(Some(60.0));
     };
-    let Some(value) = runtime.get("target_fixed_update") else {
-        return Ok(Some(60.0));
+    // `physics_fps` is the documented key; `target_fixed_update` stays as the
+    // legacy spelling. Both set the same fixed rate.
+    let (field, value) = match (
+        runtime.get("physics_fps"),
+        runtime.get("target_fixed_update"),
+    ) {
+        (Some(_), Some(_)) => {
+            return Err(ProjectError::InvalidField(
+                "runtime.physics_fps",
+                "set either `physics_fps` or legacy `target_fixed_update`, not both".to_string(),
+            ));
+        }
+        (Some(value), None) => ("runtime.physics_fps", value),
+        (None, Some(value)) => ("runtime.target_fixed_update", value),
+        (None, None) => return Ok(Some(60.0)),
     };
     if let Some(num) = value.as_float() {
         let parsed = num as f32;
@@ -980,11 +1473,29 @@ fn parse_target_fixed_update(
         return Ok(parsed.is_finite().then_some(parsed));
     }
     Err(ProjectError::InvalidField(
-        "runtime.target_fixed_update",
+        field,
         "expected a positive number".to_string(),
     ))
 }
 
+fn parse_max_fixed_steps_per_frame(
+    runtime: Option<&toml::map::Map<String, Value>>,
+) -> Result<u32, ProjectError> {
+    let Some(value) = runtime.and_then(|runtime| runtime.get("max_fixed_steps_per_frame")) else {
+        return Ok(crate::DEFAULT_MAX_FIXED_STEPS_PER_FRAME);
+    };
+    value
+        .as_integer()
+        .filter(|steps| *steps >= 1)
+        .and_then(|steps| u32::try_from(steps).ok())
+        .ok_or_else(|| {
+            ProjectError::InvalidField(
+                "runtime.max_fixed_steps_per_frame",
+                "expected an integer >= 1".to_string(),
+            )
+        })
+}
+
 fn parse_physics_gravity(
     physics: Option<&toml::map::Map<String, Value>>,
 ) -> Result<f32, ProjectError> {
@@ -1040,6 +1551,79 @@ fn parse_physics_coef(
     }
     Ok(parsed)
 }
+
+fn parse_physics_interpolation(
+    physics: Option<&toml::map::Map<String, Value>>,
+) -> Result<bool, ProjectError> {
+    let Some(value) = physics.and_then(|physics| physics.get("interpolation")) else {
+        return Ok(true);
+    };
+    value.as_bool().ok_or_else(|| {
+        ProjectError::InvalidField("physics.interpolation", "must be a boolean".to_string())
+    })
+}
+
+fn parse_layer_names(
+    table: Option<&toml::map::Map<String, Value>>,
+) -> Result<LayerNamesConfig, ProjectError> {
+    let Some(table) = table else {
+        return Ok(LayerNamesConfig::default());
+    };
+    let mut layer_names = LayerNamesConfig::default();
+    for (key, value) in table {
+        let (field, slot) = match key.as_str() {
+            "2d_physics" => ("layer_names.2d_physics", &mut layer_names.physics_2d),
+            "3d_physics" => ("layer_names.3d_physics", &mut layer_names.physics_3d),
+            other => {
+                return Err(ProjectError::InvalidField(
+                    "layer_names",
+                    format!("unknown layer set `{other}`; expected `2d_physics` or `3d_physics`"),
+                ));
+            }
+        };
+        let layers = value
+            .as_table()
+            .ok_or_else(|| ProjectError::InvalidField(field, "must be a table".to_string()))?;
+        *slot = parse_layer_name_table(field, layers)?;
+    }
+    Ok(layer_names)
+}
+
+fn parse_layer_name_table(
+    field: &'static str,
+    table: &toml::map::Map<String, Value>,
+) -> Result<Vec<(u8, String)>, ProjectError> {
+    let mut layers = Vec::with_capacity(table.len());
+    for (key, value) in table {
+        let layer = key
+            .parse::<u8>()
+            .ok()
+            .filter(|layer| (1..=32).contains(layer))
+            .ok_or_else(|| {
+                ProjectError::InvalidField(field, format!("layer `{key}` must be 1..=32"))
+            })?;
+        let name = value
+            .as_str()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| {
+                ProjectError::InvalidField(
+                    field,
+                    format!("layer {layer} needs a non-empty name string"),
+                )
+            })?;
+        if layers.iter().any(|(_, existing)| existing == name) {
+            return Err(ProjectError::InvalidField(
+                field,
+                format!("layer name `{name}` is used more than once"),
+            ));
+        }
+        layers.push((layer, name.to_string()));
+    }
+    layers.sort_by_key(|(layer, _)| *layer);
+    Ok(layers)
+}
+
 fn parse_ssao_with_default(
     table: &toml::map::Map<String, Value>,
     key: &'static str,
@@ -1183,14 +1767,37 @@ fn parse_localization(
         ));
     }
 
+    let source_csv = match table.get("source") {
+        None => String::new(),
+        Some(Value::String(source)) if is_portable_relative_path(source, false) => source.clone(),
+        Some(_) => {
+            return Err(ProjectError::InvalidField(
+                "localization.source",
+                "must be a CSV path relative to project.toml".to_string(),
+            ));
+        }
+    };
+
     Ok(Some(LocalizationConfig {
-        source_csv: String::new(),
+        source_csv,
         key_column: "key".to_string(),
         default_locale,
     }))
 }
 
 fn apply_sibling_localization(root: &Path, config: &mut ProjectConfig) -> Result<(), ProjectError> {
+    // explicit `source` wins over sibling lookup
+    if let Some(localization) = &config.localization
+        && !localization.source_csv.is_empty()
+    {
+        if root.join(&localization.source_csv).is_file() {
+            return Ok(());
+        }
+        return Err(ProjectError::InvalidField(
+            "localization.source",
+            format!("`{}` not found", localization.source_csv),
+        ));
+    }
     let source_csv = find_sibling_localization_csv(root);
     match (&mut config.localization, source_csv) {
         (Some(localization), Some(source_csv)) => {
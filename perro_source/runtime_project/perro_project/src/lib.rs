@@ -1,4 +1,5 @@
-//! Project config parsing, scaffold templates, and source override maintenance.
+//! Project config parsing, scaffold templates, source override maintenance, and
+//! the `projects.toml` multi-project registry.
 
 use std::{
     collections::BTreeSet,
@@ -18,4 +19,7 @@ include!("scaffold.rs");
 include!("config_parse.rs");
 include!("templates.rs");
 include!("manifest.rs");
+include!("workspace.rs");
+include!("thumbnails.rs");
+include!("visual_goldens.rs");
 include!("tests.rs");
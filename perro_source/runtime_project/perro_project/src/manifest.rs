@@ -268,6 +268,11 @@ fn ensure_project_manifest_features(path: &Path) -> std::io::Result<()> {
             "scripts/steamworks",
         ],
     );
+    changed |= ensure_feature_values(
+        features_table,
+        "server",
+        &["headless", "perro_headless/server"],
+    );
 
     if !features_table.contains_key("profile") {
         features_table.insert(
@@ -9,6 +9,43 @@ pub fn create_new_project(project_root: &Path, project_name: &str) -> Result<(),
     Ok(())
 }
 
+/// Write `res://scripts/<name>.rs` from `template` for a node of `node_type`.
+/// Returns the new script's `res://` path. Never overwrites an existing file.
+pub fn create_script_from_template(
+    project_root: &Path,
+    name: &str,
+    template: ScriptTemplate,
+    node_type: &str,
+) -> Result<String, ProjectError> {
+    let stem = name.trim().trim_end_matches(".rs");
+    if stem.is_empty()
+        || !stem
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(ProjectError::InvalidField(
+            "script name",
+            format!("`{name}` must use letters, digits, `_` or `-`"),
+        ));
+    }
+    if node_type.is_empty() || !node_type.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(ProjectError::InvalidField(
+            "node type",
+            format!("`{node_type}` is not a node type name"),
+        ));
+    }
+    let dir = project_root.join("res").join("scripts");
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{stem}.rs"));
+    let source = script_template_rs(template, node_type, &script_state_name(stem));
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .and_then(|mut file| std::io::Write::write_all(&mut file, source.as_bytes()))?;
+    Ok(format!("res://scripts/{stem}.rs"))
+}
+
 pub fn resolve_local_path(input: &str, local_root: &Path) -> PathBuf {
     if let Some(stripped) = input.strip_prefix("local://") {
         let rel = stripped.trim_start_matches('/');
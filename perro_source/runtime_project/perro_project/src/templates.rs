@@ -406,15 +406,64 @@ methods!({
 }
 
 pub fn default_script_empty_rs() -> String {
-    r#"use perro_api::prelude::*;
+    script_template_rs(ScriptTemplate::Empty, "Node2D", "EmptyState")
+}
 
-type SelfNodeType = Node2D;
+/// Starting point for a generated script file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScriptTemplate {
+    /// Every lifecycle hook plus one method, all empty.
+    #[default]
+    Empty,
+    /// `on_update` moving the node with an exposed speed.
+    Update,
+    /// `on_init` connecting a signal to a `methods!` handler.
+    Signal,
+}
 
-#[State]
-struct EmptyState {}
+impl ScriptTemplate {
+    pub const ALL: [Self; 3] = [Self::Empty, Self::Update, Self::Signal];
 
-lifecycle!({
-    fn on_init(
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Empty => "empty",
+            Self::Update => "update",
+            Self::Signal => "signal",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|template| template.name().eq_ignore_ascii_case(raw.trim()))
+    }
+}
+
+/// `player_ctrl` -> `PlayerCtrlState`. Falls back to `ScriptState` when the
+/// stem has no usable identifier characters.
+pub fn script_state_name(file_stem: &str) -> String {
+    let mut name = String::new();
+    for part in file_stem.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            name.push(first.to_ascii_uppercase());
+            name.extend(chars);
+        }
+    }
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert_str(0, "Script");
+    }
+    name.push_str("State");
+    name
+}
+
+/// Script source for `template`, authored against `node_type` with state
+/// struct `state_name`.
+pub fn script_template_rs(template: ScriptTemplate, node_type: &str, state_name: &str) -> String {
+    let (state_body, lifecycle, methods) = match template {
+        ScriptTemplate::Empty => (
+            "{}".to_string(),
+            r#"    fn on_init(
         &self,
         ctx: &mut ScriptContext<'_, API>,
     ) {}
@@ -437,17 +486,87 @@ lifecycle!({
     fn on_removal(
         &self,
         ctx: &mut ScriptContext<'_, API>,
-    ) {}
-});
-
-methods!({
-    fn default_method(
+    ) {}"#
+                .to_string(),
+            r#"    fn default_method(
         &self,
         ctx: &mut ScriptContext<'_, API>,
-    ) {}
-});
+    ) {}"#
+                .to_string(),
+        ),
+        ScriptTemplate::Update => (
+            r#"{
+    #[expose]
+    #[default = 1.0]
+    speed: f32,
+
+    elapsed: f32,
+}"#
+            .to_string(),
+            format!(
+                r#"    fn on_update(
+        &self,
+        ctx: &mut ScriptContext<'_, API>,
+    ) {{
+        let dt = delta_time!(ctx.run);
+        let _ = with_state_mut!(ctx.run, {state_name}, ctx.id, |state| {{
+            state.elapsed += dt * state.speed;
+        }});
+    }}"#
+            ),
+            format!(
+                r#"    fn reset(
+        &self,
+        ctx: &mut ScriptContext<'_, API>,
+    ) {{
+        let _ = with_state_mut!(ctx.run, {state_name}, ctx.id, |state| {{
+            state.elapsed = 0.0;
+        }});
+    }}"#
+            ),
+        ),
+        ScriptTemplate::Signal => (
+            r#"{
+    #[default = 0]
+    hits: i32,
+}"#
+            .to_string(),
+            r#"    fn on_init(
+        &self,
+        ctx: &mut ScriptContext<'_, API>,
+    ) {
+        let _ = signal_connect!(ctx.run, ctx.id, signal!("hit"), func!("on_hit"));
+    }"#
+            .to_string(),
+            format!(
+                r#"    fn on_hit(
+        &self,
+        ctx: &mut ScriptContext<'_, API>,
+    ) {{
+        let _ = with_state_mut!(ctx.run, {state_name}, ctx.id, |state| {{
+            state.hits += 1;
+        }});
+    }}"#
+            ),
+        ),
+    };
+    format!(
+        r#"use perro_api::prelude::*;
+
+type SelfNodeType = {node_type};
+
+#[State]
+struct {state_name} {state_body}
+
+lifecycle!({{
+{lifecycle}
+}});
+
+methods!({{
+{methods}
+}});
 "#
-    .to_string()
+    )
 }
 
 fn default_gitignore() -> String {
@@ -502,6 +621,7 @@ profile = ["perro_app/profile"]
 headless = ["dep:perro_headless"]
 headless_profile = ["perro_headless/profile"]
 headless_steamworks = ["perro_headless/steamworks", "perro_api/steamworks", "perro_runtime/steamworks", "scripts/steamworks"]
+server = ["headless", "perro_headless/server"]
 steamworks = ["perro_app/steamworks", "perro_api/steamworks", "perro_runtime/steamworks", "scripts/steamworks"]
 
 [target.'cfg(target_arch = "wasm32")'.dependencies]
@@ -1109,10 +1229,15 @@ fn main() {
 
     #[cfg(not(feature = "headless"))]
     match run_result {
-        Ok(result) => match result.kind {
-            AppExitKind::WindowClose => println!("perro exit: window close"),
-            AppExitKind::EventLoopExit => println!("perro exit: event loop exit"),
-        },
+        Ok(result) => {
+            match result.kind {
+                AppExitKind::WindowClose => println!("perro exit: window close"),
+                AppExitKind::EventLoopExit => println!("perro exit: event loop exit"),
+            }
+            if result.code != 0 {
+                process::exit(result.code);
+            }
+        }
         Err(err) => {
             eprintln!("perro exit error at `{}`: {err}", root.to_string_lossy());
             process::exit(1);
@@ -1207,9 +1332,12 @@ fn project_root() -> std::path::PathBuf {
               actions: &[perro_app::entry::StaticEmbeddedInputAction {
                   name: "jump",
                   keys: &[perro_input_api::KeyCode::Space, perro_input_api::KeyCode::ArrowUp],
+                  chords: &[],
                   mouse: &[],
                   gamepad: &[],
                   joycon: &[],
+                  context: "",
+                  consume: false,
               }],
           },
           graphics: perro_app::entry::StaticEmbeddedGraphicsConfig {
@@ -1228,9 +1356,13 @@ fn project_root() -> std::path::PathBuf {
           },
           runtime: perro_app::entry::StaticEmbeddedRuntimeConfig {
               target_fixed_update: Some(60.0),
+              max_fixed_steps_per_frame: 2,
               frame_rate_cap: perro_app::entry::FrameRateCap::Unlimited,
               physics_gravity: -9.81,
               physics_coef: 1.0,
+              physics_interpolation: true,
+              physics_layer_names_2d: &[],
+              physics_layer_names_3d: &[],
           },
           metadata: perro_app::entry::StaticEmbeddedMetadataConfig {
               description: None,
@@ -1239,6 +1371,12 @@ fn project_root() -> std::path::PathBuf {
               copyright: None,
               trademark: None,
           },
+          build: perro_app::entry::BuildInfo::embedded(
+              "0.0.0",
+              None,
+              0,
+              perro_app::entry::BuildProfile::Debug,
+          ),
           localization: perro_app::entry::StaticEmbeddedLocalizationConfig {
               default_locale: "en",
           },
@@ -1249,6 +1387,8 @@ fn project_root() -> std::path::PathBuf {
           },
           assets: perro_app::entry::StaticEmbeddedAssetsConfig {
               perro_assets: PERRO_ASSETS,
+              integrity: "",
+              archive_key: &[],
               scene_lookup: static_assets::scenes::lookup_scene,
               localization_lookup: static_assets::localizations::lookup_localized_string,
               material_lookup: static_assets::materials::lookup_material,
@@ -0,0 +1,96 @@
+/// Rendered scene thumbnails live under `.perro/cache/thumbnails`, mirroring
+/// the `res://` tree: `res://levels/a.scn` -> `levels/a.scn.png`.
+pub fn thumbnail_cache_dir(project_root: &Path) -> PathBuf {
+    project_root.join(".perro").join("cache").join("thumbnails")
+}
+
+/// Cache path for one scene thumbnail. `None` for non-`res://` paths and
+/// paths that try to leave the resource tree.
+pub fn scene_thumbnail_path(project_root: &Path, scene_res: &str) -> Option<PathBuf> {
+    res_mirror_png_path(thumbnail_cache_dir(project_root), scene_res)
+}
+
+/// `dir` + the `res://` path + `.png`. `None` for non-`res://` paths and
+/// paths that try to leave the resource tree.
+fn res_mirror_png_path(dir: PathBuf, scene_res: &str) -> Option<PathBuf> {
+    let rel = scene_res.strip_prefix("res://")?;
+    let mut path = dir;
+    let mut parts = 0usize;
+    for part in rel.split('/') {
+        if part.is_empty() || part == "." {
+            continue;
+        }
+        if part == ".." || part.contains('\\') || part.contains(':') {
+            return None;
+        }
+        path.push(part);
+        parts += 1;
+    }
+    if parts == 0 {
+        return None;
+    }
+    let mut name = path.file_name()?.to_os_string();
+    name.push(".png");
+    path.set_file_name(name);
+    Some(path)
+}
+
+/// True when the thumbnail is missing or older than the scene source.
+pub fn scene_thumbnail_stale(project_root: &Path, scene_res: &str) -> bool {
+    let Some(thumb) = scene_thumbnail_path(project_root, scene_res) else {
+        return false;
+    };
+    let Ok(thumb_modified) = fs::metadata(&thumb).and_then(|meta| meta.modified()) else {
+        return true;
+    };
+    let source = project_root
+        .join("res")
+        .join(scene_res.trim_start_matches("res://"));
+    fs::metadata(source)
+        .and_then(|meta| meta.modified())
+        .is_ok_and(|source_modified| source_modified > thumb_modified)
+}
+
+/// Every `.scn` under `res/` as a sorted list of `res://` paths.
+pub fn collect_project_scenes(project_root: &Path) -> Vec<String> {
+    let mut scenes = relative_files(&project_root.join("res"))
+        .into_iter()
+        .filter(|rel| {
+            Path::new(rel)
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("scn"))
+        })
+        .map(|rel| format!("res://{rel}"))
+        .collect::<Vec<_>>();
+    scenes.sort();
+    scenes
+}
+
+/// Every file under `dir` as a `/`-joined path relative to `dir`.
+fn relative_files(dir: &Path) -> Vec<String> {
+    fn walk(dir: &Path, prefix: &str, out: &mut Vec<String>) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            let rel = if prefix.is_empty() {
+                name.to_string()
+            } else {
+                format!("{prefix}/{name}")
+            };
+            if path.is_dir() {
+                walk(&path, &rel, out);
+            } else {
+                out.push(rel);
+            }
+        }
+    }
+
+    let mut files = Vec::new();
+    walk(dir, "", &mut files);
+    files
+}
@@ -0,0 +1,28 @@
+/// Visual regression goldens are checked in under `tests/visual`, mirroring
+/// the `res://` tree like thumbnails: `res://levels/a.scn` -> `levels/a.scn.png`.
+pub fn visual_golden_dir(project_root: &Path) -> PathBuf {
+    project_root.join("tests").join("visual")
+}
+
+/// Golden image path for one scene. `None` for non-`res://` paths and paths
+/// that try to leave the resource tree.
+pub fn scene_golden_path(project_root: &Path, scene_res: &str) -> Option<PathBuf> {
+    res_mirror_png_path(visual_golden_dir(project_root), scene_res)
+}
+
+/// Scenes w/ a golden image, as a sorted list of `res://` paths. These are
+/// the scenes `perro test --visual` checks by default.
+pub fn collect_golden_scenes(project_root: &Path) -> Vec<String> {
+    let mut scenes = relative_files(&visual_golden_dir(project_root))
+        .into_iter()
+        .filter_map(|rel| {
+            let scene = rel.strip_suffix(".png")?;
+            Path::new(scene)
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("scn"))
+                .then(|| format!("res://{scene}"))
+        })
+        .collect::<Vec<_>>();
+    scenes.sort();
+    scenes
+}
@@ -0,0 +1,134 @@
+pub const PROJECTS_TOML: &str = "projects.toml";
+
+/// `projects.toml` registry: named game projects hosted by one checkout.
+///
+/// Entry paths are relative to the directory holding `projects.toml`. Each
+/// project keeps its own `.perro/` and `target/`, so entries may not nest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectsRegistry {
+    /// Directory containing `projects.toml`; entry paths resolve against it.
+    pub root: PathBuf,
+    /// Project picked when a command gets no `--project`/`--path`.
+    pub default: Option<String>,
+    pub projects: Vec<RegisteredProject>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisteredProject {
+    pub name: String,
+    /// Relative `/`-separated path from the registry root.
+    pub path: String,
+}
+
+impl ProjectsRegistry {
+    pub fn get(&self, name: &str) -> Option<&RegisteredProject> {
+        self.projects.iter().find(|project| project.name == name)
+    }
+
+    pub fn project_dir(&self, project: &RegisteredProject) -> PathBuf {
+        self.root.join(&project.path)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.projects.iter().map(|project| project.name.as_str())
+    }
+}
+
+/// Nearest `projects.toml` at or above `start`.
+pub fn find_projects_toml(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(PROJECTS_TOML))
+        .find(|path| path.is_file())
+}
+
+pub fn load_projects_toml(path: &Path) -> Result<ProjectsRegistry, ProjectError> {
+    let contents = fs::read_to_string(path)?;
+    let root = path.parent().unwrap_or_else(|| Path::new("."));
+    parse_projects_toml(&contents, root)
+}
+
+pub fn parse_projects_toml(contents: &str, root: &Path) -> Result<ProjectsRegistry, ProjectError> {
+    let doc = contents.parse::<toml::Table>()?;
+    let entries = doc
+        .get("project")
+        .and_then(Value::as_array)
+        .ok_or(ProjectError::MissingField("project"))?;
+    let mut projects: Vec<RegisteredProject> = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let table = entry.as_table().ok_or_else(|| {
+            ProjectError::InvalidField("project", "must be table array".to_string())
+        })?;
+        let name = table
+            .get("name")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .ok_or(ProjectError::MissingField("project.name"))?
+            .to_string();
+        let path = table
+            .get("path")
+            .and_then(Value::as_str)
+            .map(|value| value.trim().trim_end_matches('/'))
+            .ok_or(ProjectError::MissingField("project.path"))?
+            .to_string();
+        if !is_portable_relative_path(&path, false) {
+            return Err(ProjectError::InvalidField(
+                "project.path",
+                format!("`{path}` must be a relative path inside the registry directory"),
+            ));
+        }
+        if projects.iter().any(|project| project.name == name) {
+            return Err(ProjectError::InvalidField(
+                "project.name",
+                format!("duplicate project `{name}`"),
+            ));
+        }
+        // Nested roots would put one project's `.perro/` + `target/` inside the other.
+        if let Some(other) = projects
+            .iter()
+            .find(|project| paths_overlap(&project.path, &path))
+        {
+            return Err(ProjectError::InvalidField(
+                "project.path",
+                format!(
+                    "`{path}` overlaps project `{}` at `{}`; projects may not nest",
+                    other.name, other.path
+                ),
+            ));
+        }
+        projects.push(RegisteredProject { name, path });
+    }
+    if projects.is_empty() {
+        return Err(ProjectError::InvalidField(
+            "project",
+            "need at least 1 project".to_string(),
+        ));
+    }
+    let default = parse_optional_table_str(&doc, "default", "default")?;
+    if let Some(default) = &default
+        && !projects.iter().any(|project| project.name == *default)
+    {
+        return Err(ProjectError::InvalidField(
+            "default",
+            format!("`{default}` is not a registered project"),
+        ));
+    }
+    Ok(ProjectsRegistry {
+        root: root.to_path_buf(),
+        default,
+        projects,
+    })
+}
+
+fn paths_overlap(a: &str, b: &str) -> bool {
+    let mut a = a.split('/');
+    let mut b = b.split('/');
+    loop {
+        match (a.next(), b.next()) {
+            (Some(left), Some(right)) if left == right => {}
+            (Some(_), Some(_)) => return false,
+            _ => return true,
+        }
+    }
+}
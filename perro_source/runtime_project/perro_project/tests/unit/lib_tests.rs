@@ -51,6 +51,94 @@ exclude = ["../secret/**"]
     assert!(err.to_string().contains("must start with `res://`"));
 }
 
+#[test]
+fn platform_overlay_merges_only_target_platform() {
+    let src = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+
+[graphics]
+vsync = false
+msaa = true
+ssao = "high"
+
+[platform.web]
+exclude = ["res://videos/**"]
+
+[platform.web.graphics]
+msaa = false
+ssao = "low"
+
+[platform.windows.graphics]
+vsync = true
+"#;
+    let base = parse_project_toml(src).expect("base cfg");
+    let web =
+        parse_project_toml_for_platform(src, false, Some(ProjectPlatform::Web)).expect("web cfg");
+    let windows = parse_project_toml_for_platform(src, false, Some(ProjectPlatform::Windows))
+        .expect("windows cfg");
+    let linux = parse_project_toml_for_platform(src, false, Some(ProjectPlatform::Linux))
+        .expect("linux cfg");
+
+    assert!(base.msaa);
+    assert_eq!(base.platform.target, None);
+    assert!(!web.msaa);
+    assert_eq!(web.ssao, SsaoQuality::Low);
+    assert!(!web.vsync);
+    assert!(web.excludes_asset("res://videos/intro.webm"));
+    assert_eq!(web.excluded_asset_patterns(), ["videos/**"]);
+    assert!(windows.vsync);
+    assert!(windows.msaa);
+    assert_eq!(windows.ssao, SsaoQuality::High);
+    assert!(!windows.excludes_asset("res://videos/intro.webm"));
+    let mut expected = base;
+    expected.platform.target = Some(ProjectPlatform::Linux);
+    assert_eq!(linux, expected);
+}
+
+#[test]
+fn platform_overlay_rejects_unknown_platform_and_table() {
+    let unknown_platform = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+
+[platform.playstation.graphics]
+vsync = true
+"#;
+    let err = parse_project_toml(unknown_platform).expect_err("unknown platform");
+    assert!(err.to_string().contains("unknown platform `playstation`"));
+
+    let unknown_table = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+
+[platform.web.grafics]
+vsync = true
+"#;
+    let err = parse_project_toml(unknown_table).expect_err("unknown override table");
+    assert!(err.to_string().contains("unknown override table `grafics`"));
+}
+
+#[test]
+fn platform_from_target_triple() {
+    for (triple, platform) in [
+        ("x86_64-pc-windows-msvc", ProjectPlatform::Windows),
+        ("aarch64-apple-darwin", ProjectPlatform::Macos),
+        ("x86_64-unknown-linux-gnu", ProjectPlatform::Linux),
+        ("aarch64-linux-android", ProjectPlatform::Android),
+        ("wasm32-unknown-unknown", ProjectPlatform::Web),
+    ] {
+        assert_eq!(ProjectPlatform::from_target_triple(triple), Some(platform));
+    }
+    assert_eq!(
+        ProjectPlatform::from_target_triple("riscv64gc-unknown-none-elf"),
+        None
+    );
+}
+
 #[test]
 fn parse_project_toml_reads_aspect_ratio() {
     let landscape = r#"
@@ -370,6 +458,54 @@ coef = 0.5
     assert_eq!(parsed.physics_coef, 0.5);
 }
 
+#[test]
+fn parse_project_toml_reads_layer_names_and_interpolation() {
+    let toml = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+
+[physics]
+interpolation = false
+
+[layer_names.2d_physics]
+3 = "enemy"
+1 = "player"
+
+[layer_names.3d_physics]
+32 = "world"
+"#;
+
+    let parsed = parse_project_toml(toml).expect("failed to parse project.toml");
+    assert!(!parsed.physics_interpolation);
+    assert_eq!(
+        parsed.layer_names.physics_2d,
+        vec![(1, "player".to_string()), (3, "enemy".to_string())]
+    );
+    assert_eq!(parsed.layer_names.physics_2d_layer("enemy"), Some(3));
+    assert_eq!(parsed.layer_names.physics_3d_layer("world"), Some(32));
+    assert_eq!(parsed.layer_names.physics_3d_layer("player"), None);
+
+    let defaults =
+        parse_project_toml("[project]\nname = \"Game\"\nmain_scene = \"res://main.scn\"\n")
+            .expect("failed to parse project.toml");
+    assert!(defaults.physics_interpolation);
+    assert!(defaults.layer_names.physics_2d.is_empty());
+
+    for bad in [
+        "[layer_names.2d_physics]\n33 = \"far\"\n",
+        "[layer_names.2d_physics]\n1 = \"a\"\n2 = \"a\"\n",
+        "[layer_names.2d_physics]\n1 = \"\"\n",
+        "[layer_names.ui]\n1 = \"a\"\n",
+    ] {
+        let toml = format!("[project]\nname = \"Game\"\nmain_scene = \"res://main.scn\"\n{bad}");
+        let err = parse_project_toml(&toml).expect_err("bad layer names should fail");
+        assert!(
+            matches!(err, ProjectError::InvalidField(field, _) if field.starts_with("layer_names"))
+        );
+    }
+}
+
 #[test]
 fn parse_project_toml_reads_steam_config() {
     let toml = r#"
@@ -393,6 +529,174 @@ input = "metadata"
     assert_eq!(parsed.steam.input_mode, SteamInputMode::Metadata);
 }
 
+#[test]
+fn parse_project_toml_reads_integrity_config() {
+    let toml = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+
+[integrity]
+check = true
+critical = ["res://data/items.json"]
+"#;
+
+    let parsed = parse_project_toml(toml).expect("failed to parse project.toml");
+    assert!(parsed.integrity.check);
+    assert_eq!(parsed.integrity.critical, vec!["res://data/items.json"]);
+
+    let bad = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+
+[integrity]
+critical = ["data/items.json"]
+"#;
+    assert!(parse_project_toml(bad).is_err());
+}
+
+#[test]
+fn parse_project_toml_reads_prune_config() {
+    let toml = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+
+[prune]
+unused = true
+include = ["res://levels/**", "res://data/items.json"]
+"#;
+
+    let parsed = parse_project_toml(toml).expect("failed to parse project.toml");
+    assert!(parsed.prune.unused);
+    assert_eq!(
+        parsed.prune.include,
+        vec!["res://levels/**", "res://data/items.json"]
+    );
+
+    let bad = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+
+[prune]
+include = ["levels/**"]
+"#;
+    assert!(parse_project_toml(bad).is_err());
+}
+
+#[test]
+fn parse_project_toml_reads_scene_lint_config() {
+    let toml = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+
+[scene_lint]
+max_nodes = 200
+max_depth = 0
+"#;
+
+    let parsed = parse_project_toml(toml).expect("failed to parse project.toml");
+    assert_eq!(parsed.scene_lint.max_nodes, 200);
+    assert_eq!(parsed.scene_lint.max_depth, 0);
+    assert_eq!(
+        parsed.scene_lint.max_scripts,
+        SceneLintConfig::default().max_scripts
+    );
+
+    let bad = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+
+[scene_lint]
+max_nodes = -1
+"#;
+    assert!(parse_project_toml(bad).is_err());
+}
+
+#[test]
+fn parse_project_toml_reads_pack_codecs() {
+    let toml = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+
+[pack]
+encrypt = true
+
+[pack.codecs]
+pmesh = "zstd"
+".PNG" = "zlib"
+"#;
+
+    let parsed = parse_project_toml(toml).expect("failed to parse project.toml");
+    assert_eq!(
+        parsed.pack.codecs,
+        vec![
+            ("pmesh".to_string(), PackCodec::Zstd),
+            ("png".to_string(), PackCodec::Zlib),
+        ]
+    );
+    assert!(parsed.pack.encrypt);
+    assert_eq!(
+        parse_project_toml("[project]\nname = \"Game\"\nmain_scene = \"res://main.scn\"\n")
+            .expect("failed to parse project.toml")
+            .pack,
+        PackConfig::default()
+    );
+
+    let bad = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+
+[pack.codecs]
+pmesh = "brotli"
+"#;
+    assert!(parse_project_toml(bad).is_err());
+    assert!(
+        parse_project_toml(
+            "[project]\nname = \"Game\"\nmain_scene = \"res://main.scn\"\n[pack]\nencrypt = \"yes\"\n"
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn parse_project_toml_reads_import_tools() {
+    let toml = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+
+[import]
+levels = { extensions = [".LDtk", "tmj"], command = "tools/level_import", args = ["--compact"], version = 3 }
+"#;
+
+    let parsed = parse_project_toml(toml).expect("failed to parse project.toml");
+    assert_eq!(parsed.imports.len(), 1);
+    let tool = &parsed.imports[0];
+    assert_eq!(tool.name, "levels");
+    assert_eq!(tool.extensions, vec!["ldtk", "tmj"]);
+    assert_eq!(tool.command, "tools/level_import");
+    assert_eq!(tool.args, vec!["--compact"]);
+    assert_eq!(tool.version, 3);
+
+    let clash = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+
+[import]
+a = { extensions = ["ldtk"], command = "a" }
+b = { extensions = ["ldtk"], command = "b" }
+"#;
+    assert!(parse_project_toml(clash).is_err());
+}
+
 #[test]
 fn parse_project_toml_reads_steam_fallback_input() {
     let toml = r#"
@@ -660,6 +964,62 @@ default_locale = "en"
     fs::remove_dir_all(&root).expect("cleanup");
 }
 
+#[test]
+fn load_project_toml_prefers_localization_source_over_sibling_csv() {
+    let root = unique_temp_dir("perro_localization_source");
+    ensure_project_layout(&root).expect("layout");
+    let project_toml = |source: &str| {
+        format!(
+            r#"[project]
+name = "Game"
+main_scene = "res://main.scn"
+icon = "res://icon.png"
+
+[graphics]
+aspect_ratio = "16:9"
+
+[localization]
+default_locale = "en"
+source = "{source}"
+"#
+        )
+    };
+    fs::write(root.join("locale.csv"), "key,en\nmenu.start,Start\n").expect("write locale.csv");
+    fs::create_dir_all(root.join("i18n")).expect("create i18n");
+    fs::write(
+        root.join("i18n/strings.csv"),
+        "key,en,fr\nmenu.start,Start,Jouer\n",
+    )
+    .expect("write strings.csv");
+
+    fs::write(root.join("project.toml"), project_toml("i18n/strings.csv"))
+        .expect("write project.toml");
+    let parsed = load_project_toml(&root).expect("failed to load project.toml");
+    let localization = parsed
+        .localization
+        .as_ref()
+        .expect("localization should be present");
+    assert_eq!(localization.source_csv, "i18n/strings.csv");
+
+    fs::write(root.join("project.toml"), project_toml("i18n/missing.csv"))
+        .expect("write project.toml");
+    let err = load_project_toml(&root).expect_err("expected missing source failure");
+    assert!(matches!(
+        err,
+        ProjectError::InvalidField("localization.source", _)
+    ));
+
+    fs::write(root.join("project.toml"), project_toml("../outside.csv"))
+        .expect("write project.toml");
+    let err = load_project_toml(&root).expect_err("expected escaping source failure");
+    assert!(matches!(
+        err,
+        ProjectError::InvalidField("localization.source", _)
+    ));
+
+    fs::remove_dir_all(&root).expect("cleanup");
+}
+
 #[test]
 fn parse_project_toml_reads_export_metadata() {
     let toml = r#"
@@ -887,6 +1247,38 @@ joycon = ["Bottom"]
     );
 }
 
+#[test]
+fn parse_input_map_toml_reads_chords_context_and_consume() {
+    let parsed = parse_input_map_toml(
+        r#"
+[save]
+keys = ["Ctrl+KeyS"]
+
+[ui_back]
+keys = ["Escape"]
+context = "ui"
+consume = true
+"#,
+    )
+    .expect("parse input map");
+    let save = parsed.action("save").expect("save action");
+    let back = parsed.action("ui_back").expect("ui_back action");
+
+    assert_eq!(
+        save.bindings,
+        vec![perro_input_api::InputBinding::Chord(
+            perro_input_api::KeyChord::new(
+                perro_input_api::KeyModifiers::CTRL,
+                perro_input_api::KeyCode::KeyS
+            )
+        )]
+    );
+    assert!(save.context.is_empty() && !save.consume);
+    assert_eq!(back.context, "ui");
+    assert_eq!(back.context_hash, perro_input_api::context_hash("ui"));
+    assert!(back.consume);
+}
+
 #[test]
 fn parse_input_map_toml_rejects_unknown_binding() {
     let err = parse_input_map_toml(
@@ -939,6 +1331,174 @@ fn load_input_map_toml_missing_returns_empty() {
     fs::remove_dir_all(&root).expect("cleanup");
 }
 
+#[test]
+fn projects_toml_resolves_named_projects_against_registry_root() {
+    let src = r#"
+default = "Demo2D"
+
+[[project]]
+name = "Demo2D"
+path = "demos/Demo2D/"
+
+[[project]]
+name = "Editor"
+path = "perro_editor"
+"#;
+    let registry = parse_projects_toml(src, Path::new("/checkout")).expect("registry");
+
+    assert_eq!(registry.default.as_deref(), Some("Demo2D"));
+    assert_eq!(
+        registry.names().collect::<Vec<_>>(),
+        vec!["Demo2D", "Editor"]
+    );
+    let demo = registry.get("Demo2D").expect("demo entry");
+    assert_eq!(demo.path, "demos/Demo2D");
+    assert_eq!(
+        registry.project_dir(demo),
+        Path::new("/checkout").join("demos/Demo2D")
+    );
+    assert!(registry.get("Missing").is_none());
+}
+
+#[test]
+fn projects_toml_rejects_nested_duplicate_and_escaping_entries() {
+    let nested = r#"
+[[project]]
+name = "Game"
+path = "games/game"
+
+[[project]]
+name = "Tools"
+path = "games/game/tools"
+"#;
+    let err = parse_projects_toml(nested, Path::new(".")).expect_err("nested");
+    assert!(err.to_string().contains("projects may not nest"));
+
+    let duplicate = r#"
+[[project]]
+name = "Game"
+path = "a"
+
+[[project]]
+name = "Game"
+path = "b"
+"#;
+    let err = parse_projects_toml(duplicate, Path::new(".")).expect_err("duplicate");
+    assert!(err.to_string().contains("duplicate project `Game`"));
+
+    let escaping = r#"
+[[project]]
+name = "Game"
+path = "../elsewhere"
+"#;
+    assert!(parse_projects_toml(escaping, Path::new(".")).is_err());
+
+    let bad_default = r#"
+default = "Nope"
+
+[[project]]
+name = "Game"
+path = "game"
+"#;
+    let err = parse_projects_toml(bad_default, Path::new(".")).expect_err("default");
+    assert!(
+        err.to_string()
+            .contains("`Nope` is not a registered project")
+    );
+}
+
+#[test]
+fn find_projects_toml_walks_up_from_project_dir() {
+    let root = unique_temp_dir("perro_projects_registry");
+    let project = root.join("demos").join("Game");
+    fs::create_dir_all(&project).expect("project dir");
+    fs::write(
+        root.join(PROJECTS_TOML),
+        "[[project]]\nname = \"Game\"\npath = \"demos/Game\"\n",
+    )
+    .expect("write registry");
+
+    let found = find_projects_toml(&project).expect("registry found");
+    assert_eq!(found, root.join(PROJECTS_TOML));
+    let registry = load_projects_toml(&found).expect("load registry");
+    let entry = registry.get("Game").expect("entry");
+    assert_eq!(registry.project_dir(entry), project);
+
+    fs::remove_dir_all(&root).expect("cleanup");
+}
+
+#[test]
+fn scene_thumbnail_path_mirrors_res_tree_under_cache() {
+    let root = Path::new("game");
+    assert_eq!(
+        scene_thumbnail_path(root, "res://levels/a.scn"),
+        Some(
+            root.join(".perro")
+                .join("cache")
+                .join("thumbnails")
+                .join("levels")
+                .join("a.scn.png")
+        )
+    );
+    assert_eq!(scene_thumbnail_path(root, "levels/a.scn"), None);
+    assert_eq!(scene_thumbnail_path(root, "res://../a.scn"), None);
+    assert_eq!(scene_thumbnail_path(root, "res://"), None);
+}
+
+#[test]
+fn scene_thumbnail_stale_tracks_source_and_cache_mtimes() {
+    let root = unique_temp_dir("perro_thumbnail_stale");
+    fs::create_dir_all(root.join("res").join("levels")).expect("res dir");
+    fs::write(root.join("res").join("levels").join("a.scn"), "[main]\n").expect("scene");
+    assert!(scene_thumbnail_stale(&root, "res://levels/a.scn"));
+
+    let thumb = scene_thumbnail_path(&root, "res://levels/a.scn").expect("thumb path");
+    fs::create_dir_all(thumb.parent().expect("thumb dir")).expect("cache dir");
+    fs::write(&thumb, b"png").expect("thumb");
+    let later = SystemTime::now() + std::time::Duration::from_secs(60);
+    fs::File::options()
+        .write(true)
+        .open(&thumb)
+        .and_then(|file| file.set_modified(later))
+        .expect("touch thumb");
+    assert!(!scene_thumbnail_stale(&root, "res://levels/a.scn"));
+
+    assert_eq!(
+        collect_project_scenes(&root),
+        vec!["res://levels/a.scn".to_string()]
+    );
+    let _ = fs::remove_dir_all(&root);
+}
+
+#[test]
+fn golden_scenes_come_from_checked_in_pngs() {
+    let root = unique_temp_dir("perro_visual_goldens");
+    assert!(collect_golden_scenes(&root).is_empty());
+
+    let golden = scene_golden_path(&root, "res://levels/a.scn").expect("golden path");
+    assert_eq!(
+        golden,
+        root.join("tests")
+            .join("visual")
+            .join("levels")
+            .join("a.scn.png")
+    );
+    fs::create_dir_all(golden.parent().expect("golden dir")).expect("golden dir");
+    fs::write(&golden, b"png").expect("golden");
+    fs::write(visual_golden_dir(&root).join("main.scn.png"), b"png").expect("golden");
+    fs::write(visual_golden_dir(&root).join("notes.png"), b"png").expect("stray png");
+
+    assert_eq!(
+        collect_golden_scenes(&root),
+        vec![
+            "res://levels/a.scn".to_string(),
+            "res://main.scn".to_string()
+        ]
+    );
+    assert_eq!(scene_golden_path(&root, "res://../a.scn"), None);
+    let _ = fs::remove_dir_all(&root);
+}
+
 fn unique_temp_dir(prefix: &str) -> PathBuf {
     let nanos = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -1409,11 +1969,73 @@ target_fixed_update = 1e300
     assert_eq!(cfg.target_fixed_update, None);
 }
 
+#[test]
+fn parse_project_toml_reads_physics_fps_and_catchup_cap() {
+    let toml = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+icon = "res://icon.png"
+
+[graphics]
+aspect_ratio = "16:9"
+
+[runtime]
+physics_fps = 120
+max_fixed_steps_per_frame = 5
+"#;
+
+    let cfg = parse_project_toml(toml).expect("physics rate parses");
+    assert_eq!(cfg.target_fixed_update, Some(120.0));
+    assert_eq!(cfg.max_fixed_steps_per_frame, 5);
+
+    let legacy = toml.replace("physics_fps", "target_fixed_update");
+    let cfg = parse_project_toml(&legacy).expect("legacy key parses");
+    assert_eq!(cfg.target_fixed_update, Some(120.0));
+}
+
+#[test]
+fn parse_project_toml_rejects_bad_fixed_step_config() {
+    let base = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+icon = "res://icon.png"
+
+[graphics]
+aspect_ratio = "16:9"
+
+[runtime]
+"#;
+
+    let cfg = parse_project_toml(base).expect("defaults");
+    assert_eq!(
+        cfg.max_fixed_steps_per_frame,
+        DEFAULT_MAX_FIXED_STEPS_PER_FRAME
+    );
+    for bad in [
+        "max_fixed_steps_per_frame = 0",
+        "max_fixed_steps_per_frame = 2.5",
+        "physics_fps = 60\ntarget_fixed_update = 30",
+    ] {
+        let toml = format!("{base}{bad}\n");
+        assert!(
+            matches!(
+                parse_project_toml(&toml),
+                Err(ProjectError::InvalidField(..))
+            ),
+            "{bad} must be rejected"
+        );
+    }
+}
+
 #[test]
 fn default_project_toml_template_parses() {
     let toml = default_project_toml("Template Game");
     let cfg = parse_project_toml(&toml).expect("default template parses");
     assert_eq!(cfg.name, "Template Game");
+    assert_eq!(cfg.target_fixed_update, Some(60.0));
+    assert_eq!(cfg.max_fixed_steps_per_frame, 2);
     assert!(cfg.rendering.ui.pixel_snapping);
     assert_eq!(cfg.rendering.default_font, "default");
     assert_eq!(cfg.particle_sim_default, ParticleSimDefault::GpuCompute);
@@ -1536,6 +2158,52 @@ rays_per_tick_3d = 96
     assert_eq!(cfg.audio.propagation_3d.rays_per_tick, 96);
 }
 
+#[test]
+fn parse_project_toml_reads_audio_buses() {
+    let toml = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+
+[audio.buses.master]
+volume = 0.8
+
+[audio.buses.music]
+volume = 0.5
+muted = true
+
+[audio.buses.sfx]
+low_pass = 0.75
+reverb = 0.3
+compression_threshold = 0.6
+compression_ratio = 4
+"#;
+    let cfg = parse_project_toml(toml).expect("audio buses");
+    assert_eq!(cfg.audio.buses.master.volume, 0.8);
+    assert!(!cfg.audio.buses.master.muted);
+    assert_eq!(cfg.audio.buses.music.volume, 0.5);
+    assert!(cfg.audio.buses.music.muted);
+    assert_eq!(cfg.audio.buses.sfx.volume, 1.0);
+    assert_eq!(cfg.audio.buses.sfx.low_pass, 0.75);
+    assert_eq!(cfg.audio.buses.sfx.reverb, 0.3);
+    assert_eq!(cfg.audio.buses.sfx.compression_threshold, 0.6);
+    assert_eq!(cfg.audio.buses.sfx.compression_ratio, 4.0);
+}
+
+#[test]
+fn parse_project_toml_rejects_unknown_audio_bus() {
+    let toml = r#"
+[project]
+name = "Game"
+main_scene = "res://main.scn"
+
+[audio.buses.voice]
+volume = 0.5
+"#;
+    let err = parse_project_toml(toml).expect_err("unknown bus");
+    assert!(err.to_string().contains("audio.buses"), "{err}");
+}
+
 #[test]
 fn parse_project_toml_flat_audio_keys_override_legacy_subtables() {
     let toml = r#"
@@ -1687,3 +2355,36 @@ company = "Legacy Studio"
     assert_eq!(cfg.metadata.version.as_deref(), Some("2.0.0"));
     assert_eq!(cfg.metadata.company.as_deref(), Some("Legacy Studio"));
 }
+
+#[test]
+fn script_template_fills_node_type_and_state_name() {
+    assert_eq!(script_state_name("player_ctrl"), "PlayerCtrlState");
+    assert_eq!(script_state_name("2d-enemy"), "Script2dEnemyState");
+    assert_eq!(
+        ScriptTemplate::parse("Update"),
+        Some(ScriptTemplate::Update)
+    );
+    assert_eq!(ScriptTemplate::parse("nope"), None);
+
+    let source = script_template_rs(ScriptTemplate::Signal, "Area3D", "DoorState");
+    assert!(source.contains("type SelfNodeType = Area3D;"));
+    assert!(source.contains("struct DoorState {"));
+    assert!(source.contains("with_state_mut!(ctx.run, DoorState, ctx.id"));
+    assert!(default_script_empty_rs().contains("struct EmptyState {}"));
+}
+
+#[test]
+fn create_script_from_template_writes_res_scripts_once() {
+    let root = unique_temp_dir("perro_script_template");
+    let path = create_script_from_template(&root, "door", ScriptTemplate::Update, "Node3D")
+        .expect("create script");
+    assert_eq!(path, "res://scripts/door.rs");
+    let source =
+        fs::read_to_string(root.join("res").join("scripts").join("door.rs")).expect("read script");
+    assert!(source.contains("type SelfNodeType = Node3D;"));
+    assert!(source.contains("struct DoorState {"));
+
+    assert!(create_script_from_template(&root, "door", ScriptTemplate::Empty, "Node3D").is_err());
+    assert!(create_script_from_template(&root, "../x", ScriptTemplate::Empty, "Node3D").is_err());
+    let _ = fs::remove_dir_all(&root);
+}
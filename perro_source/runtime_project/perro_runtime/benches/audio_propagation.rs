@@ -5,7 +5,8 @@ use perro_nodes::{
 };
 use perro_runtime::Runtime;
 use perro_runtime_api::sub_apis::{
-    AudioDirection, AudioEffects, NodeAPI, RuntimeAudio, RuntimeAudioAPI, SpatialAudioOptions,
+    AudioAttenuation, AudioDirection, AudioEffects, NodeAPI, RuntimeAudio, RuntimeAudioAPI,
+    SpatialAudioOptions,
 };
 use perro_structs::{BitMask, Quaternion, Transform2D, Transform3D, Vector2, Vector3};
 
@@ -27,6 +28,8 @@ fn spatial_options(range: f32) -> SpatialAudioOptions {
         enable_propagation: true,
         direction_2d: AudioDirection::Omni,
         direction_3d: AudioDirection::Omni,
+        attenuation: AudioAttenuation::Quadratic,
+        doppler: 0.0,
     }
 }
 
@@ -0,0 +1,82 @@
+use criterion::{Criterion, Throughput, black_box, criterion_group, criterion_main};
+use perro_ids::NodeID;
+use perro_runtime::Runtime;
+use perro_runtime::api::scripts::bench_insert_flagged_script;
+use perro_scripting::ScriptFlags;
+
+const SCRIPTED_NODES: u32 = 10_000;
+const DELTA: f32 = 1.0 / 60.0;
+
+fn runtime_with_scripts(flags_for: impl Fn(u32) -> u8) -> Runtime {
+    let mut runtime = Runtime::new();
+    for index in 0..SCRIPTED_NODES {
+        bench_insert_flagged_script(&mut runtime, NodeID::new(index + 1), flags_for(index));
+    }
+    // First tick snapshots the schedules so the measured loop is steady-state.
+    runtime.update(DELTA);
+    runtime.fixed_update(DELTA);
+    runtime
+}
+
+/// Every third script updates, every third fixed-updates, the rest implement nothing.
+fn mixed_flags(index: u32) -> u8 {
+    match index % 3 {
+        0 => ScriptFlags::HAS_UPDATE,
+        1 => ScriptFlags::HAS_FIXED_UPDATE,
+        _ => ScriptFlags::NONE,
+    }
+}
+
+fn bench_script_dispatch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scripts/dispatch_10k");
+    group.throughput(Throughput::Elements(u64::from(SCRIPTED_NODES)));
+
+    group.bench_function("update_none_scripted", |b| {
+        let mut runtime = Runtime::new();
+        runtime.update(DELTA);
+        b.iter(|| runtime.update(black_box(DELTA)))
+    });
+
+    group.bench_function("update_all_update", |b| {
+        let mut runtime = runtime_with_scripts(|_| ScriptFlags::HAS_UPDATE);
+        b.iter(|| runtime.update(black_box(DELTA)))
+    });
+
+    group.bench_function("update_all_idle", |b| {
+        let mut runtime = runtime_with_scripts(|_| ScriptFlags::NONE);
+        b.iter(|| runtime.update(black_box(DELTA)))
+    });
+
+    group.bench_function("update_mixed", |b| {
+        let mut runtime = runtime_with_scripts(mixed_flags);
+        b.iter(|| runtime.update(black_box(DELTA)))
+    });
+
+    group.bench_function("fixed_update_mixed", |b| {
+        let mut runtime = runtime_with_scripts(mixed_flags);
+        b.iter(|| runtime.fixed_update(black_box(DELTA)))
+    });
+
+    group.finish();
+}
+
+fn bench_script_insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scripts/insert_10k");
+    group.throughput(Throughput::Elements(u64::from(SCRIPTED_NODES)));
+    group.bench_function("mixed", |b| {
+        b.iter(|| black_box(runtime_with_scripts(mixed_flags)))
+    });
+    group.finish();
+}
+
+fn benches(c: &mut Criterion) {
+    bench_script_dispatch(c);
+    bench_script_insert(c);
+}
+
+criterion_group! {
+    name = script_dispatch;
+    config = Criterion::default().sample_size(20);
+    targets = benches
+}
+criterion_main!(script_dispatch);
@@ -1,4 +1,6 @@
 pub(crate) mod node_arena;
+#[cfg(debug_assertions)]
+pub(crate) mod origin;
 pub(crate) mod script_collection;
 pub(crate) mod scripts;
 pub(crate) mod signal_registry;
@@ -1,6 +1,7 @@
 use ahash::{AHashMap, AHashSet};
-use perro_ids::{NodeID, NodeTag, TagID};
-use perro_nodes::{NodeType, SceneNode};
+use perro_ids::{Name, NodeID, NodeTag, TagID, string_to_u64};
+use perro_nodes::{NodeMeta, NodeType, SceneNode};
+use perro_variant::Variant;
 use std::borrow::Cow;
 use std::ops::{Deref, DerefMut};
 
@@ -40,8 +41,12 @@ pub struct NodeArena {
     packed_child_ids: Vec<NodeID>,
     packed_children_revision: u64,
     free_indices: Vec<usize>,
-    name_index: AHashMap<Cow<'static, str>, Vec<NodeID>>,
+    /// Keyed by the interned name's precomputed hash; no string per entry.
+    name_index: AHashMap<u64, Vec<NodeID>>,
     tag_index: AHashMap<TagID, AHashSet<NodeID>>,
+    /// Per-node `meta` key/values. Side map, not a `SceneNode` field: few
+    /// nodes carry meta + every slot would pay for it.
+    meta: AHashMap<NodeID, NodeMeta>,
     active_len: usize,
     /// bump on any mut access / structural chg; cache invalidation key 4 systems
     /// that mirror node data (resource-ref scan)
@@ -57,6 +62,9 @@ pub struct NodeArena {
     /// systems that care only whether node set/topology chg (audio scene-flag
     /// rescan). Structural bumps also move mutation_revision + physics_revision.
     structural_revision: u64,
+    /// debug-only creator record per live node 4 leak reports.
+    #[cfg(debug_assertions)]
+    origins: AHashMap<NodeID, super::origin::CreationOrigin>,
 }
 
 /// Tracked mutable node access from [`NodeArena::get_mut`].
@@ -69,7 +77,7 @@ pub struct NodeMut<'a> {
     arena: &'a mut NodeArena,
     id: NodeID,
     index: usize,
-    old_name: Cow<'static, str>,
+    old_name: Name,
     old_tags: Vec<TagID>,
     old_parent: NodeID,
     old_node_type: NodeType,
@@ -114,7 +122,7 @@ impl Drop for NodeMut<'_> {
             if !new_name.is_empty() {
                 self.arena
                     .name_index
-                    .entry(new_name)
+                    .entry(new_name.hash_u64())
                     .or_default()
                     .push(self.id);
             }
@@ -170,10 +178,13 @@ impl NodeArena {
             free_indices: Vec::new(),
             name_index: AHashMap::default(),
             tag_index: AHashMap::default(),
+            meta: AHashMap::default(),
             active_len: 0,
             mutation_revision: 0,
             physics_revision: 0,
             structural_revision: 0,
+            #[cfg(debug_assertions)]
+            origins: AHashMap::default(),
         }
     }
 
@@ -201,10 +212,13 @@ impl NodeArena {
             free_indices: Vec::new(),
             name_index: AHashMap::default(),
             tag_index: AHashMap::default(),
+            meta: AHashMap::default(),
             active_len: 0,
             mutation_revision: 0,
             physics_revision: 0,
             structural_revision: 0,
+            #[cfg(debug_assertions)]
+            origins: AHashMap::default(),
         }
     }
 
@@ -298,8 +312,11 @@ impl NodeArena {
             NodeID::from_parts(index as u32, generation)
         };
         if !name.is_empty() {
-            self.name_index.entry(name).or_default().push(id);
+            self.name_index.entry(name.hash_u64()).or_default().push(id);
         }
+        #[cfg(debug_assertions)]
+        self.origins
+            .insert(id, super::origin::CreationOrigin::capture());
         // Read tags from the stored node. Building a temporary Vec<TagID> here
         // made every tagged insert allocate once before the real index update.
         let index = id.index() as usize;
@@ -412,6 +429,9 @@ impl NodeArena {
             for tag in node.get_tag_ids() {
                 self.unindex_tag(tag, id);
             }
+            self.meta.remove(&id);
+            #[cfg(debug_assertions)]
+            self.origins.remove(&id);
         }
         removed
     }
@@ -420,12 +440,16 @@ impl NodeArena {
 
     /// All live nodes currently carrying `name`, in insertion order.
     pub fn named_ids(&self, name: &str) -> &[NodeID] {
-        self.name_index.get(name).map(Vec::as_slice).unwrap_or(&[])
+        self.name_index
+            .get(&string_to_u64(name))
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
     }
 
     /// Rename a node, keeping the name index in sync. Bumps the mutation
     /// revision like any `get_mut` write. Returns `false` for dead ids.
-    pub fn rename(&mut self, id: NodeID, name: Cow<'static, str>) -> bool {
+    pub fn rename(&mut self, id: NodeID, name: impl Into<Name>) -> bool {
+        let name = name.into();
         let Some(index) = self.valid_slot(id) else {
             return false;
         };
@@ -440,19 +464,19 @@ impl NodeArena {
         let old = std::mem::replace(&mut node.name, name.clone());
         self.unindex_name(&old, id);
         if !name.is_empty() {
-            self.name_index.entry(name).or_default().push(id);
+            self.name_index.entry(name.hash_u64()).or_default().push(id);
         }
         true
     }
 
-    fn unindex_name(&mut self, name: &str, id: NodeID) {
+    fn unindex_name(&mut self, name: &Name, id: NodeID) {
         if name.is_empty() {
             return;
         }
-        if let Some(ids) = self.name_index.get_mut(name) {
+        if let Some(ids) = self.name_index.get_mut(&name.hash_u64()) {
             ids.retain(|item| *item != id);
             if ids.is_empty() {
-                self.name_index.remove(name);
+                self.name_index.remove(&name.hash_u64());
             }
         }
     }
@@ -534,6 +558,36 @@ impl NodeArena {
         true
     }
 
+    // ---- Node meta ----
+
+    /// A live node's meta; `None` when it has no keys.
+    pub fn node_meta(&self, id: NodeID) -> Option<&NodeMeta> {
+        self.meta.get(&id)
+    }
+
+    pub fn get_meta(&self, id: NodeID, key: &str) -> Option<&Variant> {
+        self.meta.get(&id).and_then(|meta| meta.get(key))
+    }
+
+    /// Insert or replace `key` on a live node; `Variant::Null` removes it.
+    /// Meta is not mirrored, so only the mutation revision moves. Returns
+    /// `false` for dead ids.
+    pub fn set_meta<K>(&mut self, id: NodeID, key: K, value: Variant) -> bool
+    where
+        K: Into<Cow<'static, str>>,
+    {
+        if !self.contains(id) {
+            return false;
+        }
+        self.bump_data_revision_only();
+        let meta = self.meta.entry(id).or_default();
+        meta.set(key, value);
+        if meta.is_empty() {
+            self.meta.remove(&id);
+        }
+        true
+    }
+
     // ---- Parent mirror ----
 
     /// Reparent a live node, keeping the slot parent mirror in sync. Bumps
@@ -633,6 +687,9 @@ impl NodeArena {
         self.free_indices.clear();
         self.name_index.clear();
         self.tag_index.clear();
+        self.meta.clear();
+        #[cfg(debug_assertions)]
+        self.origins.clear();
         self.active_len = 0;
     }
 
@@ -646,6 +703,57 @@ impl NodeArena {
         self.active_len == 0
     }
 
+    /// Debug-only creator record 4 a live node. `None` 4 dead ids.
+    #[cfg(debug_assertions)]
+    pub(crate) fn origin(&self, id: NodeID) -> Option<&super::origin::CreationOrigin> {
+        self.origins.get(&id)
+    }
+
+    /// Approx bytes held by slots, hot mirrors, name/tag indexes, node meta,
+    /// and each live node's owned name + child/tag lists. Boxed node payloads
+    /// count as their slot size only.
+    pub fn approx_memory_bytes(&self) -> usize {
+        use std::mem::size_of;
+        let slots = self.nodes.capacity() * size_of::<Option<SceneNode>>()
+            + self.generations.capacity() * size_of::<u32>()
+            + self.node_types.capacity() * size_of::<NodeType>()
+            + self.parents.capacity() * size_of::<NodeID>()
+            + self.packed_child_offsets.capacity() * size_of::<u32>()
+            + self.packed_child_ids.capacity() * size_of::<NodeID>()
+            + self.free_indices.capacity() * size_of::<usize>();
+        let names = self
+            .name_index
+            .values()
+            .map(|ids| size_of::<(u64, Vec<NodeID>)>() + ids.capacity() * size_of::<NodeID>())
+            .sum::<usize>();
+        let tags = self
+            .tag_index
+            .values()
+            .map(|ids| {
+                size_of::<(TagID, AHashSet<NodeID>)>() + ids.capacity() * size_of::<NodeID>()
+            })
+            .sum::<usize>();
+        let meta = self
+            .meta
+            .values()
+            .map(|meta| {
+                size_of::<(NodeID, NodeMeta)>()
+                    + meta.len() * size_of::<(Cow<'static, str>, Variant)>()
+            })
+            .sum::<usize>();
+        let heap = self
+            .nodes
+            .iter()
+            .flatten()
+            .map(|node| {
+                // names live in the shared intern pool, not per node
+                node.children.capacity() * size_of::<NodeID>()
+                    + node.tags.capacity() * size_of::<NodeTag>()
+            })
+            .sum::<usize>();
+        slots + names + tags + meta + heap
+    }
+
     // ---- Raw slot fast paths ----
 
     /// Number of internal slots including the reserved nil slot at index 0.
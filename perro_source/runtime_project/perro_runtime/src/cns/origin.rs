@@ -0,0 +1,45 @@
+//! Debug-only creation records 4 leak reports.
+//!
+//! Node arena + resource slots stamp each new id w/ an origin: creation time,
+//! the script node running at the time, and a creator backtrace. Backtraces
+//! follow std rules (`RUST_BACKTRACE=1` / `RUST_LIB_BACKTRACE=1`), so the
+//! default debug run only pays one env-cached status check per create.
+
+use perro_ids::NodeID;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::cell::Cell;
+use std::time::Instant;
+
+thread_local! {
+    /// script node whose callback is running on this thread; nil outside
+    /// script callbacks. Kept in sync by push/pop_active_script.
+    static ACTIVE_CREATOR: Cell<NodeID> = const { Cell::new(NodeID::nil()) };
+}
+
+#[inline]
+pub(crate) fn set_active_creator(id: NodeID) {
+    ACTIVE_CREATOR.with(|creator| creator.set(id));
+}
+
+pub(crate) struct CreationOrigin {
+    pub(crate) created: Instant,
+    /// script node that created this object; nil for engine/scene creates.
+    pub(crate) creator: NodeID,
+    backtrace: Backtrace,
+}
+
+impl CreationOrigin {
+    #[inline]
+    pub(crate) fn capture() -> Self {
+        Self {
+            created: Instant::now(),
+            creator: ACTIVE_CREATOR.with(Cell::get),
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Resolved creator backtrace, or `None` when capture was disabled.
+    pub(crate) fn backtrace_text(&self) -> Option<String> {
+        (self.backtrace.status() == BacktraceStatus::Captured).then(|| self.backtrace.to_string())
+    }
+}
@@ -42,6 +42,8 @@ pub(crate) struct ScriptInstance {
 ///   equality rejects stale generations.
 /// - `update` and `fixed` store instance indexes. Reverse arrays store each
 ///   schedule position, so enable/disable/remove stay O(1) via swap-remove.
+/// - `input_count` counts `on_input` scripts; input slots are snapshotted by
+///   flag scan on schedule epoch change since event dispatch is rare.
 ///
 /// Removing an instance may move the last instance into the removed slot. All
 /// side indexes must be updated with the moved instance index in the same step.
@@ -57,6 +59,7 @@ pub(crate) struct ScriptCollection {
     // instance index -> schedule position
     update_pos: Vec<u32>,
     fixed_pos: Vec<u32>,
+    input_count: usize,
     schedule_epoch: u64,
 }
 
@@ -82,6 +85,7 @@ impl ScriptCollection {
             fixed: Vec::new(),
             update_pos: Vec::new(),
             fixed_pos: Vec::new(),
+            input_count: 0,
             schedule_epoch: 0,
         }
     }
@@ -186,6 +190,12 @@ impl ScriptCollection {
         let state_type = state.as_ref().type_id();
 
         if let Some(i) = self.instance_index_for(id) {
+            if self.instances[i].behavior.script_flags().has_input() {
+                self.input_count -= 1;
+            }
+            if flags.has_input() {
+                self.input_count += 1;
+            }
             self.instances[i] = ScriptInstance {
                 behavior,
                 state_type,
@@ -224,6 +234,9 @@ impl ScriptCollection {
             self.fixed.push(i);
             Self::set_reverse_slot(&mut self.fixed_pos, i, Some(pos));
         }
+        if flags.has_input() {
+            self.input_count += 1;
+        }
         self.bump_schedule_epoch();
     }
 
@@ -249,6 +262,9 @@ impl ScriptCollection {
             .pop()
             .expect("script instance and node id arrays stay aligned");
         debug_assert!(removed_node == id);
+        if removed.behavior.script_flags().has_input() {
+            self.input_count -= 1;
+        }
 
         if i != last {
             let moved = self.ids[i];
@@ -290,6 +306,24 @@ impl ScriptCollection {
         }
     }
 
+    /// Append `on_input` script entries as dense index plus validating node id.
+    pub(crate) fn append_input_slots(&self, out: &mut Vec<(usize, NodeID)>) {
+        if self.input_count == 0 {
+            return;
+        }
+        for (i, instance) in self.instances.iter().enumerate() {
+            if instance.behavior.script_flags().has_input() {
+                out.push((i, self.ids[i]));
+            }
+        }
+    }
+
+    /// Return number of attached scripts that implement `on_input`.
+    #[inline]
+    pub(crate) fn input_script_count(&self) -> usize {
+        self.input_count
+    }
+
     /// Enable or disable `on_update` scheduling for a script.
     ///
     /// Returns `true` only when the schedule membership changed.
@@ -345,6 +379,30 @@ impl ScriptCollection {
         self.schedule_epoch
     }
 
+    /// Return number of attached script instances.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.instances.len()
+    }
+
+    /// Approx bytes held by per-instance state boxes + schedule indexes.
+    /// State counts its concrete shallow size; heap owned by state fields and
+    /// the shared behaviors are not walked.
+    pub(crate) fn approx_memory_bytes(&self) -> usize {
+        use std::mem::size_of;
+        let states = self
+            .instances
+            .iter()
+            .map(|instance| std::mem::size_of_val(&*instance.state))
+            .sum::<usize>();
+        states
+            + self.instances.capacity() * size_of::<ScriptInstance>()
+            + self.ids.capacity() * size_of::<NodeID>()
+            + (self.index.capacity() + self.update_pos.capacity() + self.fixed_pos.capacity())
+                * size_of::<u32>()
+            + (self.update.capacity() + self.fixed.capacity()) * size_of::<usize>()
+    }
+
     // ---- Typed state access ----
 
     /// Read concrete script state by node id inside a closure.
@@ -825,4 +883,74 @@ mod scheduled_instance_tests {
                 .is_none()
         );
     }
+
+    // Scripts that implement neither update callback never enter a schedule, and
+    // removals keep the remaining schedule entries dense.
+    #[test]
+    fn idle_scripts_stay_out_of_dense_schedules() {
+        let mut coll = ScriptCollection::new();
+        for i in 1..=6u32 {
+            let flags = match i % 3 {
+                0 => ScriptFlags::HAS_UPDATE,
+                1 => ScriptFlags::HAS_FIXED_UPDATE,
+                _ => ScriptFlags::NONE,
+            };
+            insert_marker(&mut coll, NodeID::new(i), i64::from(i), flags);
+        }
+        assert_eq!(coll.update_schedule_len(), 2);
+        assert_eq!(coll.fixed_schedule_len(), 2);
+
+        coll.remove(NodeID::new(3));
+        coll.remove(NodeID::new(2));
+
+        let mut update = Vec::new();
+        coll.append_update_slots(&mut update);
+        assert_eq!(update.len(), 1);
+        let (index, id) = update[0];
+        assert_eq!(id, NodeID::new(6));
+        let instance = coll
+            .scheduled_instance(index, id, ScheduleKind::Update)
+            .expect("remaining update script stays scheduled");
+        assert_eq!(marker_of(instance), 6);
+
+        let mut fixed = Vec::new();
+        coll.append_fixed_update_slots(&mut fixed);
+        assert_eq!(fixed.len(), 2);
+        for (index, id) in fixed {
+            assert!(
+                coll.scheduled_instance(index, id, ScheduleKind::Fixed)
+                    .is_some()
+            );
+        }
+    }
+
+    // Input slots track the on_input flag across insert, replace, and remove.
+    #[test]
+    fn input_slots_follow_on_input_flag() {
+        let mut coll = ScriptCollection::new();
+        let a = NodeID::new(1);
+        let b = NodeID::new(2);
+        insert_marker(&mut coll, a, 1, ScriptFlags::HAS_INPUT);
+        insert_marker(&mut coll, b, 2, ScriptFlags::HAS_UPDATE);
+        assert_eq!(coll.input_script_count(), 1);
+
+        // Replacing b's behavior w/ an input script counts it once.
+        insert_marker(&mut coll, b, 3, ScriptFlags::HAS_INPUT);
+        insert_marker(&mut coll, b, 3, ScriptFlags::HAS_INPUT);
+        assert_eq!(coll.input_script_count(), 2);
+
+        coll.remove(a);
+        let mut input = Vec::new();
+        coll.append_input_slots(&mut input);
+        assert_eq!(input.len(), 1);
+        let (index, id) = input[0];
+        assert_eq!(id, b);
+        let instance = coll
+            .get_instance_scheduled_indexed(index, id)
+            .expect("moved input script resolves");
+        assert_eq!(marker_of(instance), 3);
+
+        coll.remove(b);
+        assert_eq!(coll.input_script_count(), 0);
+    }
 }
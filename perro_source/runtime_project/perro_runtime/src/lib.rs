@@ -27,16 +27,21 @@ pub use runtime::{
     BenchPreparedScene, BenchSceneSpawner, bench_compile_scene, bench_merge_compiled_scene,
     bench_prepare_and_merge_scene, bench_prepare_merge_extract_scene, bench_prepare_scene,
 };
-pub use runtime::{Runtime, RuntimeFixedUpdateTiming, RuntimeScriptApi, RuntimeUpdateTiming};
+pub use runtime::{
+    Runtime, RuntimeFixedUpdateTiming, RuntimeHarness, RuntimeScriptApi, RuntimeUpdateTiming,
+    THUMBNAIL_CAMERA_NAME,
+};
 pub use runtime_project::{
-    AudioConfig, AudioPropagationConfig, FrameRateCap, LocalizationConfig, OcclusionCulling,
+    AudioConfig, AudioPropagationConfig, BuildInfo, BuildProfile,
+    DEFAULT_MAX_FIXED_STEPS_PER_FRAME, FrameRateCap, LocalizationConfig, OcclusionCulling,
     ParticleSimDefault, ProjectLoadError, ProjectMetadata, ProjectRoute, ProjectRoutesConfig,
     ProviderMode, RenderUiConfig, RenderingConfig, RuntimeProject, RuntimeProjectConfig,
-    SsaoQuality, StaticAnimationLookup, StaticAnimationTreeLookup, StaticAudioLookup,
-    StaticBytesLookup, StaticCsvLookup, StaticLocalizationLookup, StaticMaterialLookup,
-    StaticParticleLookup, StaticProjectConfig, StaticSceneLookup, StaticShaderLookup,
-    StaticSkeletonLookup, StaticTilesetLookup, StaticUiStyleLookup, SteamInputMode,
-    default_input_map_toml, default_project_toml, default_routes_config, ensure_project_layout,
-    ensure_project_toml, load_input_map_toml, load_project_toml, load_routes_toml,
-    normalize_route_href, parse_input_map_toml, parse_project_toml, parse_routes_toml,
+    SCENE_OVERRIDE_ENV, SsaoQuality, StaticAnimationLookup, StaticAnimationTreeLookup,
+    StaticAudioLookup, StaticBytesLookup, StaticCsvLookup, StaticLocalizationLookup,
+    StaticMaterialLookup, StaticParticleLookup, StaticProjectConfig, StaticSceneLookup,
+    StaticShaderLookup, StaticSkeletonLookup, StaticTilesetLookup, StaticUiStyleLookup,
+    SteamInputMode, default_input_map_toml, default_project_toml, default_routes_config,
+    ensure_project_layout, ensure_project_toml, load_input_map_toml, load_project_toml,
+    load_routes_toml, normalize_route_href, parse_input_map_toml, parse_project_toml,
+    parse_routes_toml, scene_override_from_env,
 };
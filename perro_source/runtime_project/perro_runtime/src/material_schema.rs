@@ -161,7 +161,7 @@ fn pmat_looks_like_object(text: &str) -> bool {
 }
 
 fn parse_pmat_object(text: &str) -> Option<Vec<SceneObjectField>> {
-    let value = std::panic::catch_unwind(|| Parser::new(text).parse_value_literal()).ok()?;
+    let value = Parser::new(text).try_parse_value_literal().ok()?;
     match value {
         SceneValue::Object(entries) => Some(entries.into_owned()),
         _ => None,
@@ -614,11 +614,7 @@ fn as_source_token(value: &SceneValue) -> Option<String> {
 }
 
 fn as_color4(value: &SceneValue) -> Option<[f32; 4]> {
-    match value {
-        SceneValue::Vec4 { x, y, z, w } => Some([*x, *y, *z, *w]),
-        SceneValue::Vec3 { x, y, z } => Some([*x, *y, *z, 1.0]),
-        _ => None,
-    }
+    value.as_color()
 }
 
 fn as_texture_slot(value: &SceneValue) -> Option<u32> {
@@ -4,8 +4,8 @@ use super::core::{
 };
 use perro_ids::{AudioBusID, SoundFontID};
 use perro_resource_api::sub_apis::{
-    Audio, Audio2D, Audio3D, AudioAPI, AudioDirection, MicClip, MidiNoteHandle, MidiNoteOptions,
-    MidiSong, MidiSpatialPosition, Note,
+    Audio, Audio2D, Audio3D, AudioAPI, AudioBusEffects, AudioDirection, MicClip, MidiNoteHandle,
+    MidiNoteOptions, MidiSong, MidiSpatialPosition, Note,
 };
 use std::{
     collections::hash_map::DefaultHasher,
@@ -13,6 +13,59 @@ use std::{
     sync::{Arc, atomic::Ordering},
 };
 
+fn bus_effects(effects: AudioBusEffects) -> perro_pawdio::AudioBusEffects {
+    perro_pawdio::AudioBusEffects {
+        low_pass: effects.low_pass,
+        reverb: effects.reverb,
+        compression: perro_pawdio::AudioCompression {
+            threshold: effects.compression.threshold,
+            ratio: effects.compression.ratio,
+            attack: effects.compression.attack,
+            release: effects.compression.release,
+        },
+    }
+}
+
+fn playback_request<'a>(
+    bus_id: Option<AudioBusID>,
+    audio: Audio<'a>,
+    pan: perro_resource_api::sub_apis::AudioPan,
+) -> perro_pawdio::AudioPlaybackRequest<'a> {
+    perro_pawdio::AudioPlaybackRequest {
+        id: 0,
+        source: audio.source,
+        bus_id,
+        looped: audio.looped,
+        volume: audio.volume,
+        speed: audio.effects.speed,
+        pan: perro_pawdio::AudioPan {
+            x: pan.x,
+            y: pan.y,
+            z: pan.z,
+        },
+        low_pass: audio.effects.low_pass,
+        reverb_send: audio.effects.reverb_send,
+        echo: audio.effects.echo,
+        reflection: audio.effects.reflection,
+        occlusion: audio.effects.occlusion,
+        eq: perro_pawdio::AudioEq {
+            low_gain: audio.effects.eq.low_gain,
+            mid_gain: audio.effects.eq.mid_gain,
+            high_gain: audio.effects.eq.high_gain,
+        },
+        compression: perro_pawdio::AudioCompression {
+            threshold: audio.effects.compression.threshold,
+            ratio: audio.effects.compression.ratio,
+            attack: audio.effects.compression.attack,
+            release: audio.effects.compression.release,
+        },
+        from_start: audio.from_start,
+        from_end: audio.from_end,
+        loop_start: audio.loop_start,
+        loop_end: audio.loop_end,
+    }
+}
+
 fn bytes_hash(bytes: &[u8]) -> u64 {
     let mut hasher = DefaultHasher::new();
     bytes.hash(&mut hasher);
@@ -86,37 +139,7 @@ impl AudioAPI for RuntimeResourceApi {
         let Some(player) = guard.as_ref() else {
             return false;
         };
-        player.play_source(perro_pawdio::AudioPlaybackRequest {
-            id: 0,
-            source: audio.source,
-            bus_id,
-            looped: audio.looped,
-            volume: audio.volume,
-            speed: audio.effects.speed,
-            pan: perro_pawdio::AudioPan {
-                x: pan.x,
-                y: pan.y,
-                z: pan.z,
-            },
-            low_pass: audio.effects.low_pass,
-            reverb_send: audio.effects.reverb_send,
-            echo: audio.effects.echo,
-            reflection: audio.effects.reflection,
-            occlusion: audio.effects.occlusion,
-            eq: perro_pawdio::AudioEq {
-                low_gain: audio.effects.eq.low_gain,
-                mid_gain: audio.effects.eq.mid_gain,
-                high_gain: audio.effects.eq.high_gain,
-            },
-            compression: perro_pawdio::AudioCompression {
-                threshold: audio.effects.compression.threshold,
-                ratio: audio.effects.compression.ratio,
-                attack: audio.effects.compression.attack,
-                release: audio.effects.compression.release,
-            },
-            from_start: audio.from_start,
-            from_end: audio.from_end,
-        })
+        player.play_source(playback_request(bus_id, audio, pan))
     }
 
     fn play_audio_clip(
@@ -190,6 +213,8 @@ impl AudioAPI for RuntimeResourceApi {
             pos: QueuedSpatialAudioPos::TwoD(audio.position),
             direction_2d: audio.direction.unwrap_or(AudioDirection::Omni),
             direction_3d: AudioDirection::Omni,
+            attenuation: audio.attenuation,
+            doppler: 0.0,
         });
         true
     }
@@ -212,6 +237,8 @@ impl AudioAPI for RuntimeResourceApi {
             pos: QueuedSpatialAudioPos::ThreeD(audio.position),
             direction_2d: AudioDirection::Omni,
             direction_3d: audio.direction.unwrap_or(AudioDirection::Omni),
+            attenuation: audio.attenuation,
+            doppler: audio.doppler,
         });
         true
     }
@@ -228,37 +255,24 @@ impl AudioAPI for RuntimeResourceApi {
         let Some(player) = guard.as_ref() else {
             return false;
         };
-        player.stop_match(perro_pawdio::AudioPlaybackRequest {
-            id: 0,
-            source: audio.source,
-            bus_id,
-            looped: audio.looped,
-            volume: audio.volume,
-            speed: audio.effects.speed,
-            pan: perro_pawdio::AudioPan {
-                x: pan.x,
-                y: pan.y,
-                z: pan.z,
-            },
-            low_pass: audio.effects.low_pass,
-            reverb_send: audio.effects.reverb_send,
-            echo: audio.effects.echo,
-            reflection: audio.effects.reflection,
-            occlusion: audio.effects.occlusion,
-            eq: perro_pawdio::AudioEq {
-                low_gain: audio.effects.eq.low_gain,
-                mid_gain: audio.effects.eq.mid_gain,
-                high_gain: audio.effects.eq.high_gain,
-            },
-            compression: perro_pawdio::AudioCompression {
-                threshold: audio.effects.compression.threshold,
-                ratio: audio.effects.compression.ratio,
-                attack: audio.effects.compression.attack,
-                release: audio.effects.compression.release,
-            },
-            from_start: audio.from_start,
-            from_end: audio.from_end,
-        })
+        player.stop_match(playback_request(bus_id, audio, pan))
+    }
+
+    fn crossfade_audio(&self, bus_id: Option<AudioBusID>, audio: Audio<'_>, seconds: f32) -> bool {
+        let Ok(guard) = self.bark.lock() else {
+            return false;
+        };
+        let Some(player) = guard.as_ref() else {
+            return false;
+        };
+        player.crossfade(
+            playback_request(
+                bus_id,
+                audio,
+                perro_resource_api::sub_apis::AudioPan::CENTER,
+            ),
+            seconds,
+        )
     }
 
     fn stop_audio_source(&self, source: &str) -> bool {
@@ -298,6 +312,26 @@ impl AudioAPI for RuntimeResourceApi {
         player.set_master_volume(volume)
     }
 
+    fn set_master_muted(&self, muted: bool) -> bool {
+        let Ok(guard) = self.bark.lock() else {
+            return false;
+        };
+        let Some(player) = guard.as_ref() else {
+            return false;
+        };
+        player.set_master_muted(muted)
+    }
+
+    fn set_master_effects(&self, effects: AudioBusEffects) -> bool {
+        let Ok(guard) = self.bark.lock() else {
+            return false;
+        };
+        let Some(player) = guard.as_ref() else {
+            return false;
+        };
+        player.set_master_effects(bus_effects(effects))
+    }
+
     fn set_bus_volume(&self, bus_id: AudioBusID, volume: f32) -> bool {
         let Ok(guard) = self.bark.lock() else {
             return false;
@@ -308,6 +342,26 @@ impl AudioAPI for RuntimeResourceApi {
         player.set_bus_volume(bus_id, volume)
     }
 
+    fn set_bus_muted(&self, bus_id: AudioBusID, muted: bool) -> bool {
+        let Ok(guard) = self.bark.lock() else {
+            return false;
+        };
+        let Some(player) = guard.as_ref() else {
+            return false;
+        };
+        player.set_bus_muted(bus_id, muted)
+    }
+
+    fn set_bus_effects(&self, bus_id: AudioBusID, effects: AudioBusEffects) -> bool {
+        let Ok(guard) = self.bark.lock() else {
+            return false;
+        };
+        let Some(player) = guard.as_ref() else {
+            return false;
+        };
+        player.set_bus_effects(bus_id, bus_effects(effects))
+    }
+
     fn set_bus_speed(&self, bus_id: AudioBusID, speed: f32) -> bool {
         let Ok(guard) = self.bark.lock() else {
             return false;
@@ -1,4 +1,6 @@
 use super::state::{RuntimeLocalizationState, RuntimeResourceState};
+#[cfg(debug_assertions)]
+use crate::cns::origin::CreationOrigin;
 use crate::runtime_project::{
     StaticAnimationLookup, StaticAnimationTreeLookup, StaticAudioLookup, StaticCsvLookup,
     StaticLocalizationLookup, StaticMaterialLookup, StaticSkeletonLookup,
@@ -12,6 +14,8 @@ use perro_project::LocalizationConfig;
 #[cfg(not(target_arch = "wasm32"))]
 use perro_render_bridge::Material3D;
 use perro_render_bridge::{RenderCommand, RenderEvent};
+#[cfg(debug_assertions)]
+use perro_runtime_api::sub_apis::{LeakEntry, LeakKind};
 use std::{
     borrow::Cow,
     collections::HashMap,
@@ -164,6 +168,8 @@ pub(crate) struct QueuedSpatialAudio {
     pub pos: QueuedSpatialAudioPos,
     pub direction_2d: perro_resource_api::sub_apis::AudioDirection<perro_structs::Vector2>,
     pub direction_3d: perro_resource_api::sub_apis::AudioDirection<perro_structs::Vector3>,
+    pub attenuation: perro_resource_api::sub_apis::AudioAttenuation,
+    pub doppler: f32,
 }
 
 #[derive(Clone, Debug)]
@@ -720,4 +726,51 @@ impl RuntimeResourceApi {
             RenderEvent::WaterSamples { .. } | RenderEvent::WaterBodySamples { .. } => {}
         }
     }
+
+    /// Debug-only: every live texture/mesh/material id w/ its creator record.
+    /// Caller drops ids still referenced by scene nodes. Engine default
+    /// material skipped; it lives 4 the whole run by design.
+    #[cfg(debug_assertions)]
+    pub(crate) fn live_resource_origins(&self) -> Vec<LeakEntry> {
+        let state = self.state.lock().expect("resource api mutex poisoned");
+        let entry = |kind, id: u64, label: String, origin: &CreationOrigin| LeakEntry {
+            kind,
+            id,
+            label,
+            creator: (!origin.creator.is_nil()).then_some(origin.creator),
+            age_secs: origin.created.elapsed().as_secs_f32(),
+            backtrace: origin.backtrace_text(),
+        };
+        let mut out = Vec::with_capacity(
+            state.texture_origins.len() + state.mesh_origins.len() + state.material_origins.len(),
+        );
+        for (id, origin) in &state.texture_origins {
+            out.push(entry(
+                LeakKind::Texture,
+                id.as_u64(),
+                format!("{id}"),
+                origin,
+            ));
+        }
+        for (id, origin) in &state.mesh_origins {
+            let label = state
+                .mesh_source_by_id
+                .get(id)
+                .cloned()
+                .unwrap_or_else(|| format!("{id}"));
+            out.push(entry(LeakKind::Mesh, id.as_u64(), label, origin));
+        }
+        for (id, origin) in &state.material_origins {
+            if state.default_material_id == Some(*id) {
+                continue;
+            }
+            out.push(entry(
+                LeakKind::Material,
+                id.as_u64(),
+                format!("{id}"),
+                origin,
+            ));
+        }
+        out
+    }
 }
@@ -25,7 +25,13 @@ impl CsvAPI for RuntimeResourceApi {
         let Ok(bytes) = perro_io::load_asset(source) else {
             return &EMPTY_CSV;
         };
-        let Ok(table) = perro_csv::parse_csv_static(&bytes) else {
+        // `res://data/*.json` data tables load as keyed tables.
+        let parsed = if source.ends_with(".json") {
+            perro_csv::parse_json_table_static(&bytes)
+        } else {
+            perro_csv::parse_csv_static(&bytes)
+        };
+        let Ok(table) = parsed else {
             return &EMPTY_CSV;
         };
         self.csv_cache
@@ -114,6 +120,29 @@ mod tests {
         assert!(std::ptr::eq(table, cached));
     }
 
+    #[test]
+    fn data_table_loads_dev_json_table() {
+        let _project_root_guard = crate::rs_ctx::PROJECT_ROOT_TEST_LOCK
+            .lock()
+            .expect("test or bench setup must succeed");
+        let root = setup_csv_project();
+        std::fs::write(
+            root.join("res/data/enemies.json"),
+            r#"{"slime": {"hp": 5, "speed": 1.5}}"#,
+        )
+        .expect("failed to write json table");
+        let api = RuntimeResourceApi::new(None, None, None, None, None, None, None, None);
+        let data = perro_resource_api::sub_apis::DataModule::new(api.as_ref());
+
+        let slime = data.table("enemies").row("slime").expect("slime row");
+        assert_eq!(slime.get("hp"), perro_variant::Variant::from(5_i64));
+        assert_eq!(
+            data.table("items").get("sword", "power"),
+            perro_variant::Variant::from(10_i64)
+        );
+        assert!(!data.table("missing").exists());
+    }
+
     #[test]
     fn csv_loads_static_table_by_hash() {
         static HEADERS: [perro_csv::CsvCell; 2] = [
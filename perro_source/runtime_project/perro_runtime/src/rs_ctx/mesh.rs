@@ -42,7 +42,7 @@ impl MeshAPI for RuntimeResourceApi {
             ResourceCommand::CreateRuntimeMesh {
                 request,
                 id,
-                source,
+                source: source.into(),
                 reserved: false,
                 mesh: data,
             },
@@ -70,7 +70,7 @@ impl MeshAPI for RuntimeResourceApi {
             ResourceCommand::CreateRuntimeMeshBytes {
                 request,
                 id,
-                source,
+                source: source.into(),
                 reserved: false,
                 bytes: Arc::from(bytes),
             },
@@ -161,7 +161,7 @@ impl MeshAPI for RuntimeResourceApi {
             .push(RenderCommand::Resource(ResourceCommand::CreateMesh {
                 request,
                 id,
-                source: source.to_string(),
+                source: source.into(),
                 reserved: false,
             }));
         id
@@ -217,7 +217,7 @@ impl MeshAPI for RuntimeResourceApi {
             .push(RenderCommand::Resource(ResourceCommand::CreateMesh {
                 request,
                 id,
-                source: source.to_string(),
+                source: source.into(),
                 reserved: true,
             }));
         id
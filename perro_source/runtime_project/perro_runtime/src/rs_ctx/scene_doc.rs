@@ -18,7 +18,10 @@ impl SceneDocAPI for RuntimeResourceApi {
             path: path.to_string(),
             message: err.to_string(),
         })?;
-        Ok(SceneDoc::parse(source))
+        SceneDoc::try_parse(source).map_err(|err| LoadError::Parse {
+            path: path.to_string(),
+            message: err.to_string(),
+        })
     }
 
     fn scene_save_doc(&self, path: &str, doc: &SceneDoc) -> Result<(), String> {
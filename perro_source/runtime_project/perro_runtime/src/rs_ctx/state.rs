@@ -1,3 +1,5 @@
+#[cfg(debug_assertions)]
+use crate::cns::origin::CreationOrigin;
 use perro_animation::{AnimationClip, AnimationTreeAsset};
 use perro_ids::{
     AnimationID, AnimationTreeID, MaterialID, MeshID, NavMeshID, NodeID, TextureID, WebcamID,
@@ -199,6 +201,13 @@ pub(super) struct RuntimeResourceState {
     pub(super) animation_tree_by_source: HashMap<u64, AnimationTreeID>,
     pub(super) animation_tree_data_by_id: HashMap<AnimationTreeID, Arc<AnimationTreeAsset>>,
     pub(super) animation_tree_loaded_by_id: HashSet<AnimationTreeID>,
+    /// debug-only creator records 4 leak reports; kp in sync w/ slot alloc/free.
+    #[cfg(debug_assertions)]
+    pub(super) texture_origins: HashMap<TextureID, CreationOrigin>,
+    #[cfg(debug_assertions)]
+    pub(super) mesh_origins: HashMap<MeshID, CreationOrigin>,
+    #[cfg(debug_assertions)]
+    pub(super) material_origins: HashMap<MaterialID, CreationOrigin>,
 }
 
 impl RuntimeResourceState {
@@ -219,17 +228,26 @@ impl RuntimeResourceState {
 
     pub(super) fn allocate_texture_id(&mut self) -> TextureID {
         let (index, generation) = self.texture_slots.allocate_parts();
-        TextureID::from_parts(index, generation)
+        let id = TextureID::from_parts(index, generation);
+        #[cfg(debug_assertions)]
+        self.texture_origins.insert(id, CreationOrigin::capture());
+        id
     }
 
     pub(super) fn allocate_mesh_id(&mut self) -> MeshID {
         let (index, generation) = self.mesh_slots.allocate_parts();
-        MeshID::from_parts(index, generation)
+        let id = MeshID::from_parts(index, generation);
+        #[cfg(debug_assertions)]
+        self.mesh_origins.insert(id, CreationOrigin::capture());
+        id
     }
 
     pub(super) fn allocate_material_id(&mut self) -> MaterialID {
         let (index, generation) = self.material_slots.allocate_parts();
-        MaterialID::from_parts(index, generation)
+        let id = MaterialID::from_parts(index, generation);
+        #[cfg(debug_assertions)]
+        self.material_origins.insert(id, CreationOrigin::capture());
+        id
     }
 
     pub(super) fn allocate_webcam_id(&mut self) -> WebcamID {
@@ -243,28 +261,50 @@ impl RuntimeResourceState {
     }
 
     pub(super) fn free_texture_id(&mut self, id: TextureID) -> bool {
+        #[cfg(debug_assertions)]
+        self.texture_origins.remove(&id);
         self.texture_slots.free_parts(id.index(), id.generation())
     }
 
     pub(super) fn occupy_texture_id(&mut self, id: TextureID) -> bool {
-        self.texture_slots.occupy_parts(id.index(), id.generation())
+        let occupied = self.texture_slots.occupy_parts(id.index(), id.generation());
+        #[cfg(debug_assertions)]
+        if occupied {
+            self.texture_origins.insert(id, CreationOrigin::capture());
+        }
+        occupied
     }
 
     pub(super) fn free_mesh_id(&mut self, id: MeshID) -> bool {
+        #[cfg(debug_assertions)]
+        self.mesh_origins.remove(&id);
         self.mesh_slots.free_parts(id.index(), id.generation())
     }
 
     pub(super) fn occupy_mesh_id(&mut self, id: MeshID) -> bool {
-        self.mesh_slots.occupy_parts(id.index(), id.generation())
+        let occupied = self.mesh_slots.occupy_parts(id.index(), id.generation());
+        #[cfg(debug_assertions)]
+        if occupied {
+            self.mesh_origins.insert(id, CreationOrigin::capture());
+        }
+        occupied
     }
 
     pub(super) fn free_material_id(&mut self, id: MaterialID) -> bool {
+        #[cfg(debug_assertions)]
+        self.material_origins.remove(&id);
         self.material_slots.free_parts(id.index(), id.generation())
     }
 
     pub(super) fn occupy_material_id(&mut self, id: MaterialID) -> bool {
-        self.material_slots
-            .occupy_parts(id.index(), id.generation())
+        let occupied = self
+            .material_slots
+            .occupy_parts(id.index(), id.generation());
+        #[cfg(debug_assertions)]
+        if occupied {
+            self.material_origins.insert(id, CreationOrigin::capture());
+        }
+        occupied
     }
 
     pub(super) fn free_webcam_id(&mut self, id: WebcamID) -> bool {
@@ -46,7 +46,7 @@ impl TextureAPI for RuntimeResourceApi {
             ResourceCommand::CreateRuntimeTexture {
                 request,
                 id,
-                source,
+                source: source.into(),
                 reserved: false,
                 width,
                 height,
@@ -75,7 +75,7 @@ impl TextureAPI for RuntimeResourceApi {
             ResourceCommand::CreateRuntimeTextureBytes {
                 request,
                 id,
-                source,
+                source: source.into(),
                 reserved: false,
                 bytes: Arc::from(bytes),
             },
@@ -164,7 +164,7 @@ impl TextureAPI for RuntimeResourceApi {
             .push(RenderCommand::Resource(ResourceCommand::CreateTexture {
                 request,
                 id,
-                source: source.to_string(),
+                source: source.into(),
                 reserved: false,
             }));
         id
@@ -200,7 +200,7 @@ impl TextureAPI for RuntimeResourceApi {
             .push(RenderCommand::Resource(ResourceCommand::CreateTexture {
                 request,
                 id,
-                source: source.to_string(),
+                source: source.into(),
                 reserved: true,
             }));
         id
@@ -144,7 +144,7 @@ impl RuntimeResourceApi {
             ResourceCommand::CreateRuntimeTexture {
                 request,
                 id,
-                source,
+                source: source.into(),
                 reserved: true,
                 width: clip.width.max(1),
                 height: clip.height.max(1),
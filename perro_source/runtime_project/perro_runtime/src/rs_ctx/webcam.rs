@@ -284,7 +284,7 @@ impl RuntimeResourceApi {
             ResourceCommand::CreateExternalTexture {
                 request,
                 id: texture,
-                source,
+                source: source.into(),
                 reserved: true,
                 width,
                 height,
@@ -377,7 +377,7 @@ impl WebcamAPI for RuntimeResourceApi {
             ResourceCommand::CreateExternalTexture {
                 request,
                 id: texture,
-                source,
+                source: source.into(),
                 reserved: true,
                 width,
                 height,
@@ -0,0 +1,178 @@
+use perro_runtime_api::sub_apis::{DebugAPI, LeakReport, MemoryReport};
+
+use crate::Runtime;
+#[cfg(debug_assertions)]
+use ahash::AHashMap;
+#[cfg(debug_assertions)]
+use perro_ids::NodeID;
+#[cfg(debug_assertions)]
+use perro_runtime_api::sub_apis::{LeakEntry, LeakKind};
+
+impl DebugAPI for Runtime {
+    fn get_memory_report(&self) -> MemoryReport {
+        MemoryReport {
+            node_count: self.nodes.len() as u32,
+            node_arena_bytes: self.nodes.approx_memory_bytes() as u64,
+            script_count: self.scripts.len() as u32,
+            script_state_bytes: self.scripts.approx_memory_bytes() as u64,
+            variant_bytes: self.variant_memory_bytes() as u64,
+            scene_cache_bytes: self.scene_cache_memory_bytes() as u64,
+            asset_archive_bytes: perro_io::loaded_archive_bytes() as u64,
+            gpu_bytes: self.gpu_memory_bytes,
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    fn get_leak_report(&self) -> LeakReport {
+        let mut entries = self.node_leaks();
+        entries.extend(self.resource_leaks());
+        LeakReport {
+            tracking: true,
+            entries,
+        }
+    }
+
+    #[cfg(not(debug_assertions))]
+    fn get_leak_report(&self) -> LeakReport {
+        LeakReport::default()
+    }
+}
+
+impl Runtime {
+    /// Per-subsystem memory report, same as `ctx.Debug().memory_report()`.
+    #[inline]
+    pub fn memory_report(&self) -> MemoryReport {
+        self.get_memory_report()
+    }
+
+    /// Live nodes + resources nothing in the active scene owns, w/ creator.
+    /// Same as `ctx.Debug().leak_report()`; empty in release builds.
+    #[inline]
+    pub fn leak_report(&self) -> LeakReport {
+        self.get_leak_report()
+    }
+
+    /// Record GPU resource bytes from the graphics backend 4 the memory report.
+    #[inline]
+    pub fn set_gpu_memory_bytes(&mut self, bytes: u64) {
+        self.gpu_memory_bytes = bytes;
+    }
+
+    fn variant_memory_bytes(&self) -> usize {
+        use perro_variant::Variant;
+        use std::mem::size_of;
+        let signals = &self.signal_runtime;
+        let scratch = signals.param_scratch.capacity() * size_of::<Variant>()
+            + signals
+                .param_scratch
+                .iter()
+                .map(Variant::approx_heap_bytes)
+                .sum::<usize>();
+        let queued = signals
+            .queued_ui_signals
+            .iter()
+            .flat_map(|(_, params)| params.iter())
+            .map(|param| size_of::<Variant>() + param.approx_heap_bytes())
+            .sum::<usize>();
+        scratch + queued
+    }
+
+    fn scene_cache_memory_bytes(&self) -> usize {
+        use std::mem::size_of;
+        let scene_bytes = |scene: &perro_scene::Scene| {
+            scene.nodes.len() * size_of::<perro_scene::SceneNodeEntry>()
+                + scene.key_names.iter().map(|name| name.len()).sum::<usize>()
+        };
+        let cached = self
+            .scene_cache
+            .borrow()
+            .iter()
+            .map(|(path, scene)| path.capacity() + scene_bytes(scene))
+            .sum::<usize>();
+        let prepared = self
+            .prepared_scene_cache
+            .borrow()
+            .iter()
+            .map(|(path, scene)| path.capacity() + scene.approx_memory_bytes())
+            .sum::<usize>();
+        let preloaded = self
+            .preloaded_scenes
+            .values()
+            .map(|scene| scene_bytes(scene))
+            .sum::<usize>()
+            + self
+                .preloaded_prepared_scenes
+                .values()
+                .map(|scene| scene.approx_memory_bytes())
+                .sum::<usize>();
+        cached + prepared + preloaded
+    }
+
+    /// Orphan subtrees: top node's chain never reaches the active scene root
+    /// or a registered scene/ownership root. One entry per subtree top.
+    #[cfg(debug_assertions)]
+    fn node_leaks(&self) -> Vec<LeakEntry> {
+        let owned = |root: NodeID| {
+            self.active_route_root == Some(root)
+                || self
+                    .scene_ownership_roots
+                    .iter()
+                    .any(|(&scene, &owner)| scene == root || owner == root)
+        };
+        let mut subtree_sizes: AHashMap<NodeID, usize> = AHashMap::new();
+        for (id, _) in self.nodes.iter() {
+            let mut top = id;
+            while let Some(parent) = self.nodes.get(top).map(|node| node.parent)
+                && self.nodes.get(parent).is_some()
+            {
+                top = parent;
+            }
+            if !owned(top) {
+                *subtree_sizes.entry(top).or_default() += 1;
+            }
+        }
+        let mut out = Vec::with_capacity(subtree_sizes.len());
+        for (top, size) in subtree_sizes {
+            let (Some(node), Some(origin)) = (self.nodes.get(top), self.nodes.origin(top)) else {
+                continue;
+            };
+            let name = if node.name.is_empty() {
+                "<unnamed>"
+            } else {
+                node.name.as_ref()
+            };
+            out.push(LeakEntry {
+                kind: LeakKind::Node,
+                id: top.as_u64(),
+                label: format!("{name} ({:?}, {size} nodes)", node.node_type()),
+                creator: (!origin.creator.is_nil()).then_some(origin.creator),
+                age_secs: origin.created.elapsed().as_secs_f32(),
+                backtrace: origin.backtrace_text(),
+            });
+        }
+        out.sort_unstable_by_key(|entry| entry.id);
+        out
+    }
+
+    /// Live texture/mesh/material ids no scene node references. Ref caches
+    /// refresh on render extraction, so headless runs see every id unowned.
+    #[cfg(debug_assertions)]
+    fn resource_leaks(&self) -> Vec<LeakEntry> {
+        use perro_ids::{MaterialID, MeshID, TextureID};
+        let mut out = self.resource_api.live_resource_origins();
+        out.retain(|entry| match entry.kind {
+            LeakKind::Texture => !self
+                .scene_texture_refs_cache
+                .contains_key(&TextureID::from_u64(entry.id)),
+            LeakKind::Mesh => !self
+                .scene_mesh_refs_cache
+                .contains_key(&MeshID::from_u64(entry.id)),
+            LeakKind::Material => !self
+                .scene_material_refs_cache
+                .contains_key(&MaterialID::from_u64(entry.id)),
+            LeakKind::Node => true,
+        });
+        out.sort_unstable_by_key(|entry| (entry.kind as u8, entry.id));
+        out
+    }
+}
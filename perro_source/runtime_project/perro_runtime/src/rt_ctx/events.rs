@@ -0,0 +1,155 @@
+use perro_ids::{NodeID, ScriptMemberID};
+use perro_runtime_api::sub_apis::{EventAPI, ScriptAPI};
+use perro_variant::Variant;
+
+use crate::Runtime;
+
+impl EventAPI for Runtime {
+    fn event_subscribe(&mut self, topic: &str, script_id: NodeID, method: &str) -> bool {
+        if self.scripts.instance_index_for_id(script_id).is_none() {
+            return false;
+        }
+        self.event_bus.subscribe(topic, script_id, method)
+    }
+
+    fn event_unsubscribe(&mut self, topic: &str, script_id: NodeID, method: &str) -> bool {
+        self.event_bus.unsubscribe(topic, script_id, method)
+    }
+
+    fn event_publish(&mut self, topic: &str, payload: Variant) {
+        self.event_bus.publish(topic, payload);
+    }
+
+    fn event_debug_view(&self) -> String {
+        self.event_bus.debug_view()
+    }
+}
+
+impl Runtime {
+    /// Deliver events published since the last flush, once per frame. Events
+    /// a handler publishes wait for the next flush, so topic loops can't stall
+    /// a frame.
+    pub(crate) fn flush_event_bus(&mut self) -> usize {
+        if self.event_bus.pending_len() == 0 {
+            return 0;
+        }
+        let mut calls = 0usize;
+        for (topic, payload) in self.event_bus.take_pending() {
+            for (script_id, method) in self.event_bus.subscribers(&topic) {
+                let method = ScriptMemberID::from_string(&method);
+                ScriptAPI::call_method(self, script_id, method, std::slice::from_ref(&payload));
+                calls += 1;
+            }
+        }
+        calls
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perro_scripting::{ScriptBehavior, ScriptContext, ScriptFlags, ScriptLifecycle};
+    use std::{any::Any, sync::Arc};
+
+    const ON_SCORE: ScriptMemberID = ScriptMemberID::from_string("on_score");
+
+    #[derive(Default)]
+    struct ScoreState {
+        total: i64,
+    }
+
+    struct ScoreScript;
+
+    impl ScriptLifecycle<crate::RuntimeScriptApi> for ScoreScript {}
+
+    impl ScriptBehavior<crate::RuntimeScriptApi> for ScoreScript {
+        fn script_flags(&self) -> ScriptFlags {
+            ScriptFlags::new(ScriptFlags::NONE)
+        }
+
+        fn create_state(&self) -> Box<dyn Any> {
+            Box::<ScoreState>::default()
+        }
+
+        fn get_var(&self, _state: &dyn Any, _var: ScriptMemberID) -> Variant {
+            Variant::Null
+        }
+
+        fn set_var(&self, _state: &mut dyn Any, _var: ScriptMemberID, _value: Variant) {}
+
+        fn call_method(
+            &self,
+            method: ScriptMemberID,
+            ctx: &mut ScriptContext<'_, crate::RuntimeScriptApi>,
+            params: &[Variant],
+        ) -> Variant {
+            if method == ON_SCORE {
+                let points = params.first().and_then(Variant::as_i64).unwrap_or(0);
+                ctx.run
+                    .Scripts()
+                    .with_state_mut::<ScoreState, _, _>(ctx.id, |state| state.total += points);
+                ctx.run.Events().publish("score", Variant::from(1_i64));
+            }
+            Variant::Null
+        }
+    }
+
+    fn total(runtime: &mut Runtime, id: NodeID) -> Option<i64> {
+        ScriptAPI::with_state::<ScoreState, _, _>(runtime, id, |state| state.total)
+    }
+
+    #[test]
+    fn published_events_arrive_on_the_next_flush() {
+        let mut runtime = Runtime::new();
+        let hud = NodeID::new(1);
+        runtime
+            .scripts
+            .insert(hud, Arc::new(ScoreScript), Box::<ScoreState>::default());
+
+        assert!(EventAPI::event_subscribe(
+            &mut runtime,
+            "score",
+            hud,
+            "on_score"
+        ));
+        assert!(!EventAPI::event_subscribe(
+            &mut runtime,
+            "score",
+            NodeID::new(9),
+            "on_score"
+        ));
+        EventAPI::event_publish(&mut runtime, "score", Variant::from(10_i64));
+        assert_eq!(total(&mut runtime, hud), Some(0));
+
+        assert_eq!(runtime.flush_event_bus(), 1);
+        assert_eq!(total(&mut runtime, hud), Some(10));
+        // the handler's own publish waits for the next flush
+        assert_eq!(runtime.event_bus.pending_len(), 1);
+        assert_eq!(runtime.flush_event_bus(), 1);
+        assert_eq!(total(&mut runtime, hud), Some(11));
+    }
+
+    #[test]
+    fn removed_scripts_drop_their_subscriptions() {
+        let mut runtime = Runtime::new();
+        let hud = NodeID::new(1);
+        runtime
+            .scripts
+            .insert(hud, Arc::new(ScoreScript), Box::<ScoreState>::default());
+        assert!(EventAPI::event_subscribe(
+            &mut runtime,
+            "score",
+            hud,
+            "on_score"
+        ));
+        assert_eq!(
+            EventAPI::event_debug_view(&runtime),
+            format!("score -> {hud}.on_score\n")
+        );
+
+        assert!(runtime.remove_script_instance(hud));
+        assert!(EventAPI::event_debug_view(&runtime).is_empty());
+        EventAPI::event_publish(&mut runtime, "score", Variant::from(10_i64));
+        assert_eq!(runtime.flush_event_bus(), 0);
+    }
+}
@@ -1,12 +1,20 @@
 pub mod animation;
 pub mod animation_tree;
+pub mod debug;
+pub mod events;
 pub mod navmesh;
 pub mod nodes;
+pub mod os;
 pub mod physics;
 pub mod query;
+pub mod rollback;
+pub mod save;
 pub mod scene;
 pub mod scripts;
 pub mod signals;
+pub mod sim;
+pub mod space2d;
 pub mod time;
 pub mod timers;
 pub mod window;
+pub mod world;
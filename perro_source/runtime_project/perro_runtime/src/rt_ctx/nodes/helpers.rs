@@ -357,6 +357,7 @@ const TAG_HLAYOUT: u8 = 10;
 const TAG_VLAYOUT: u8 = 11;
 const TAG_GRID: u8 = 12;
 const TAG_TREELIST: u8 = 13;
+const TAG_MARKDOWN: u8 = 14;
 
 fn text_edit_fingerprint(edit: &perro_ui::UiTextEdit) -> UiPayloadFingerprint {
     // Group A: text/font group -> TEXT|LAYOUT_SELF|LAYOUT_PARENT|COMMANDS.
@@ -614,6 +615,27 @@ pub(super) fn ui_payload_fingerprint(data: &SceneNodeData) -> UiPayloadFingerpri
                 group_b: 0,
             }
         }
+        SceneNodeData::UiMarkdown(node) => {
+            // Group A: content + metrics -> re-wrap. Group B: colors only.
+            let mut a = new_hasher();
+            a.write(node.source.as_bytes());
+            a.write(node.markdown.as_bytes());
+            feed_f32(&mut a, node.font_size);
+            feed_f32(&mut a, node.heading_scale);
+            feed_f32(&mut a, node.line_spacing);
+            feed_f32(&mut a, node.paragraph_spacing);
+            feed_f32(&mut a, node.list_indent);
+            let mut b = new_hasher();
+            feed_color(&mut b, node.text_color);
+            feed_color(&mut b, node.bold_color);
+            feed_color(&mut b, node.heading_color);
+            feed_color(&mut b, node.link_color);
+            UiPayloadFingerprint {
+                tag: TAG_MARKDOWN,
+                group_a: a.finish(),
+                group_b: b.finish(),
+            }
+        }
         _ => UiPayloadFingerprint::default(),
     }
 }
@@ -659,6 +681,12 @@ pub(super) fn classify_ui_payload_fingerprint(
                 | Runtime::UI_DIRTY_LAYOUT_PARENT
                 | Runtime::UI_DIRTY_COMMANDS
         }
+        TAG_MARKDOWN if group_a_changed => {
+            Runtime::UI_DIRTY_LAYOUT_SELF
+                | Runtime::UI_DIRTY_LAYOUT_PARENT
+                | Runtime::UI_DIRTY_COMMANDS
+        }
+        TAG_MARKDOWN if group_b_changed => Runtime::UI_DIRTY_COMMANDS,
         _ => 0,
     }
 }
@@ -793,6 +821,7 @@ pub(super) fn ui_base_from_data(data: &SceneNodeData) -> Option<&UiNode> {
         SceneNodeData::UiVLayout(node) => Some(&node.inner.base),
         SceneNodeData::UiGrid(node) => Some(&node.base),
         SceneNodeData::UiTreeList(node) => Some(&node.base),
+        SceneNodeData::UiMarkdown(node) => Some(&node.base),
         _ => None,
     }
 }
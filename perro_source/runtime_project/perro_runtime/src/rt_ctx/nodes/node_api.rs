@@ -314,16 +314,16 @@ impl NodeAPI for Runtime {
         Runtime::bind_locale_placeholder(self, node_id, key.as_ref())
     }
 
-    fn get_node_name(&mut self, node_id: perro_ids::NodeID) -> Option<Cow<'static, str>> {
+    fn get_node_name(&mut self, node_id: perro_ids::NodeID) -> Option<perro_ids::Name> {
         self.nodes.get(node_id).map(|node| node.name.clone())
     }
 
     fn set_node_name<S>(&mut self, node_id: perro_ids::NodeID, name: S) -> bool
     where
-        S: Into<Cow<'static, str>>,
+        S: Into<perro_ids::Name>,
     {
         // Route through the arena so the name index stays in sync.
-        self.nodes.rename(node_id, name.into())
+        self.nodes.rename(node_id, name)
     }
 
     fn find_node_by_name<S>(
@@ -618,6 +618,9 @@ impl NodeAPI for Runtime {
 
         self.scene_ownership_roots
             .retain(|scene_root, owner| !visited.contains(scene_root) && !visited.contains(owner));
+        self.forget_removed_scene_instances(&visited);
+        self.forget_removed_disabled_nodes(&visited);
+        let orphaned = self.take_orphaned_spawns(&visited);
 
         stack.clear();
         postorder.clear();
@@ -626,9 +629,56 @@ impl NodeAPI for Runtime {
         self.node_api_scratch.remove_postorder = postorder;
         self.node_api_scratch.remove_visited = visited;
 
+        // Owned spawns go after the scratch is back; they may own spawns too.
+        for spawn in orphaned {
+            let _ = self.remove_node(spawn);
+        }
+
         true
     }
 
+    fn set_node_enabled(&mut self, node_id: perro_ids::NodeID, enabled: bool) -> bool {
+        Runtime::set_node_enabled(self, node_id, enabled)
+    }
+
+    fn is_node_enabled(&mut self, node_id: perro_ids::NodeID) -> bool {
+        Runtime::is_node_enabled(self, node_id)
+    }
+
+    fn is_valid(&mut self, node_id: perro_ids::NodeID) -> bool {
+        self.nodes.get(node_id).is_some()
+    }
+
+    fn set_node_owner(&mut self, node_id: perro_ids::NodeID, owner: perro_ids::NodeID) -> bool {
+        Runtime::set_node_owner(self, node_id, owner)
+    }
+
+    fn get_node_owner(&mut self, node_id: perro_ids::NodeID) -> perro_ids::NodeID {
+        self.node_owner(node_id)
+    }
+
+    fn get_node_meta<S>(&mut self, node_id: perro_ids::NodeID, key: S) -> perro_variant::Variant
+    where
+        S: AsRef<str>,
+    {
+        self.nodes
+            .get_meta(node_id, key.as_ref())
+            .cloned()
+            .unwrap_or(perro_variant::Variant::Null)
+    }
+
+    fn set_node_meta<K>(
+        &mut self,
+        node_id: perro_ids::NodeID,
+        key: K,
+        value: perro_variant::Variant,
+    ) -> bool
+    where
+        K: Into<Cow<'static, str>>,
+    {
+        self.nodes.set_meta(node_id, key, value)
+    }
+
     fn get_node_tags(&mut self, node_id: perro_ids::NodeID) -> Option<Vec<Cow<'static, str>>> {
         self.nodes.get(node_id).map(|node| {
             node.tags_slice()
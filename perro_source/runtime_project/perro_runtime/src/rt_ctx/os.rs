@@ -0,0 +1,31 @@
+use perro_runtime_api::sub_apis::{BuildInfo, OsAPI};
+
+use crate::Runtime;
+
+impl OsAPI for Runtime {
+    fn get_build_info(&self) -> BuildInfo {
+        self.project()
+            .map(|project| project.build_info.clone())
+            .unwrap_or_default()
+    }
+
+    fn request_quit(&mut self, code: i32) {
+        self.begin_quit(code);
+    }
+
+    fn delay_quit(&mut self) {
+        self.delay_pending_quit();
+    }
+
+    fn resume_quit(&mut self) {
+        self.resume_pending_quit();
+    }
+
+    fn cancel_quit(&mut self) {
+        self.cancel_pending_quit();
+    }
+
+    fn is_quit_pending(&self) -> bool {
+        Runtime::is_quit_pending(self)
+    }
+}
@@ -23,6 +23,7 @@ impl PhysicsAPI for Runtime {
         match &self.nodes.get(body_id)?.data {
             SceneNodeData::RigidBody2D(body) => Some(body.gravity_scale),
             SceneNodeData::RigidBody3D(body) => Some(body.gravity_scale),
+            SceneNodeData::VehicleBody3D(body) => Some(body.gravity_scale),
             _ => None,
         }
     }
@@ -43,6 +44,10 @@ impl PhysicsAPI for Runtime {
                 body.gravity_scale = scale;
                 true
             }
+            SceneNodeData::VehicleBody3D(body) => {
+                body.gravity_scale = scale;
+                true
+            }
             _ => false,
         }
     }
@@ -76,7 +81,10 @@ impl PhysicsAPI for Runtime {
         let Some(node) = self.nodes.get(body_id) else {
             return false;
         };
-        if !matches!(node.data, SceneNodeData::RigidBody3D(_)) {
+        if !matches!(
+            node.data,
+            SceneNodeData::RigidBody3D(_) | SceneNodeData::VehicleBody3D(_)
+        ) {
             return false;
         }
         self.queue_force_3d(body_id, force);
@@ -104,7 +112,10 @@ impl PhysicsAPI for Runtime {
         let Some(node) = self.nodes.get(body_id) else {
             return false;
         };
-        if !matches!(node.data, SceneNodeData::RigidBody3D(_)) {
+        if !matches!(
+            node.data,
+            SceneNodeData::RigidBody3D(_) | SceneNodeData::VehicleBody3D(_)
+        ) {
             return false;
         }
         self.queue_impulse_3d(body_id, impulse);
@@ -301,8 +312,10 @@ impl PhysicsAPI for Runtime {
 
         let (velocity, angular_velocity, gravity_scale) = {
             let node = self.nodes.get(body_id)?;
-            let SceneNodeData::RigidBody3D(body) = &node.data else {
-                return None;
+            let body = match &node.data {
+                SceneNodeData::RigidBody3D(body) => body,
+                SceneNodeData::VehicleBody3D(vehicle) => &vehicle.base,
+                _ => return None,
             };
             if !body.enabled {
                 return None;
@@ -328,6 +341,14 @@ impl PhysicsAPI for Runtime {
             angular_velocity,
         })
     }
+
+    fn physics_layer_2d(&mut self, name: &str) -> Option<u8> {
+        self.project()?.config.layer_names.physics_2d_layer(name)
+    }
+
+    fn physics_layer_3d(&mut self, name: &str) -> Option<u8> {
+        self.project()?.config.layer_names.physics_3d_layer(name)
+    }
 }
 
 fn predict_rotation_3d(rotation: Quaternion, angular_velocity: Vector3, time: f32) -> Quaternion {
@@ -364,6 +385,31 @@ mod tests {
         assert_eq!(PhysicsAPI::get_gravity(&mut runtime), -4.0);
     }
 
+    #[test]
+    fn physics_api_resolves_project_layer_names() {
+        let mut project = crate::RuntimeProject::new("Game", ".");
+        project.config.layer_names = crate::runtime_project::LayerNamesConfig::from_static(
+            &[(1, "player"), (4, "enemy")],
+            &[(2, "world")],
+        );
+        let mut runtime = Runtime::from_project_unbooted(
+            project,
+            crate::runtime_project::ProviderMode::Dynamic,
+            None,
+        );
+
+        assert_eq!(PhysicsAPI::physics_layer_2d(&mut runtime, "enemy"), Some(4));
+        assert_eq!(PhysicsAPI::physics_layer_3d(&mut runtime, "world"), Some(2));
+        assert_eq!(PhysicsAPI::physics_layer_3d(&mut runtime, "enemy"), None);
+
+        let mut physics = perro_runtime_api::sub_apis::PhysicsModule::new(&mut runtime);
+        assert_eq!(
+            physics.layer_mask_2d(&["player", "enemy"]),
+            Some(perro_structs::BitMask::from_bits(0b1001))
+        );
+        assert_eq!(physics.layer_mask_2d(&["player", "ghost"]), None);
+    }
+
     #[test]
     fn physics_api_sets_body_gravity_scale() {
         let mut runtime = Runtime::new();
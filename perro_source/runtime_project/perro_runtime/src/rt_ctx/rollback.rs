@@ -0,0 +1,178 @@
+use perro_ids::NodeID;
+use perro_runtime_api::sub_apis::RollbackAPI;
+use perro_variant::Variant;
+
+use crate::Runtime;
+
+impl RollbackAPI for Runtime {
+    fn rollback_start(&mut self, players: u32, window: u32) {
+        self.start_rollback_session(players as usize, window as usize);
+    }
+
+    fn rollback_stop(&mut self) {
+        self.stop_rollback_session();
+    }
+
+    fn rollback_active(&self) -> bool {
+        self.is_rollback_active()
+    }
+
+    fn rollback_track(&mut self, script_id: NodeID, hooks: bool) -> bool {
+        self.track_rollback_script(script_id, hooks)
+    }
+
+    fn rollback_untrack(&mut self, script_id: NodeID) -> bool {
+        self.untrack_rollback_script(script_id)
+    }
+
+    fn rollback_set_input(&mut self, player: u32, tick: u64, input: Variant) -> bool {
+        self.set_rollback_input(player as usize, tick, input)
+    }
+
+    fn rollback_input(&mut self, player: u32) -> Variant {
+        self.rollback_input_now(player as usize)
+    }
+
+    fn rollback_resimulating(&self) -> bool {
+        self.is_rollback_resimulating()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perro_ids::ScriptMemberID;
+    use perro_runtime_api::sub_apis::{ROLLBACK_LOAD_METHOD, ROLLBACK_SAVE_METHOD, ScriptAPI};
+    use perro_scripting::{ScriptBehavior, ScriptContext, ScriptFlags, ScriptLifecycle};
+    use std::{any::Any, sync::Arc};
+
+    const SAVE: ScriptMemberID = ScriptMemberID::from_string(ROLLBACK_SAVE_METHOD);
+    const LOAD: ScriptMemberID = ScriptMemberID::from_string(ROLLBACK_LOAD_METHOD);
+    const DT: f32 = 1.0 / 60.0;
+
+    #[derive(Default)]
+    struct FighterState {
+        pos: i64,
+        replayed_ticks: u32,
+    }
+
+    /// Moves by player 1's input each tick; rolls back `pos` through hooks.
+    struct FighterScript;
+
+    impl ScriptLifecycle<crate::RuntimeScriptApi> for FighterScript {
+        fn on_fixed_update(&self, ctx: &mut ScriptContext<'_, crate::RuntimeScriptApi>) {
+            let step = ctx.run.Rollback().input(1).as_i64().unwrap_or(0);
+            let replay = ctx.run.Rollback().is_resimulating();
+            ctx.run
+                .Scripts()
+                .with_state_mut::<FighterState, _, _>(ctx.id, |state| {
+                    state.pos += step;
+                    state.replayed_ticks += u32::from(replay);
+                });
+        }
+    }
+
+    impl ScriptBehavior<crate::RuntimeScriptApi> for FighterScript {
+        fn script_flags(&self) -> ScriptFlags {
+            ScriptFlags::new(ScriptFlags::HAS_FIXED_UPDATE)
+        }
+
+        fn create_state(&self) -> Box<dyn Any> {
+            Box::<FighterState>::default()
+        }
+
+        fn get_var(&self, _state: &dyn Any, _var: ScriptMemberID) -> Variant {
+            Variant::Null
+        }
+
+        fn set_var(&self, _state: &mut dyn Any, _var: ScriptMemberID, _value: Variant) {}
+
+        fn call_method(
+            &self,
+            method: ScriptMemberID,
+            ctx: &mut ScriptContext<'_, crate::RuntimeScriptApi>,
+            params: &[Variant],
+        ) -> Variant {
+            if method == SAVE {
+                let pos = ctx
+                    .run
+                    .Scripts()
+                    .with_state::<FighterState, _, _>(ctx.id, |state| state.pos);
+                return pos.map_or(Variant::Null, Variant::from);
+            }
+            if method == LOAD
+                && let Some(pos) = params.first().and_then(Variant::as_i64)
+            {
+                ctx.run
+                    .Scripts()
+                    .with_state_mut::<FighterState, _, _>(ctx.id, |state| state.pos = pos);
+            }
+            Variant::Null
+        }
+    }
+
+    fn fighter(runtime: &mut Runtime, id: NodeID) -> Option<(i64, u32)> {
+        ScriptAPI::with_state::<FighterState, _, _>(runtime, id, |state| {
+            (state.pos, state.replayed_ticks)
+        })
+    }
+
+    #[test]
+    fn late_input_resimulates_tracked_scripts() {
+        let mut runtime = Runtime::new();
+        let id = NodeID::new(1);
+        runtime
+            .scripts
+            .insert(id, Arc::new(FighterScript), Box::<FighterState>::default());
+        assert!(RollbackAPI::rollback_track(&mut runtime, id, true));
+        RollbackAPI::rollback_start(&mut runtime, 2, 8);
+        assert!(RollbackAPI::rollback_set_input(
+            &mut runtime,
+            1,
+            1,
+            Variant::from(1_i64)
+        ));
+        for _ in 0..4 {
+            runtime.fixed_update(DT);
+        }
+        // ticks 2-4 predicted from tick 1
+        assert_eq!(fighter(&mut runtime, id), Some((4, 0)));
+
+        // the peer's tick 3 was 10: ticks 3 + 4 replay b4 tick 5 runs
+        assert!(RollbackAPI::rollback_set_input(
+            &mut runtime,
+            1,
+            3,
+            Variant::from(10_i64)
+        ));
+        runtime.fixed_update(DT);
+        assert_eq!(fighter(&mut runtime, id), Some((1 + 1 + 10 + 10 + 10, 2)));
+        assert_eq!(runtime.sim_clock_tick(), 5);
+        assert!(!RollbackAPI::rollback_resimulating(&runtime));
+    }
+
+    #[test]
+    fn inputs_need_a_session_and_tracking_needs_a_script() {
+        let mut runtime = Runtime::new();
+        let id = NodeID::new(1);
+        assert!(!RollbackAPI::rollback_track(&mut runtime, id, false));
+        assert!(!RollbackAPI::rollback_set_input(
+            &mut runtime,
+            0,
+            1,
+            Variant::Null
+        ));
+        assert_eq!(RollbackAPI::rollback_input(&mut runtime, 0), Variant::Null);
+
+        runtime
+            .scripts
+            .insert(id, Arc::new(FighterScript), Box::<FighterState>::default());
+        assert!(RollbackAPI::rollback_track(&mut runtime, id, false));
+        RollbackAPI::rollback_start(&mut runtime, 1, 4);
+        assert!(RollbackAPI::rollback_active(&runtime));
+        assert!(runtime.remove_script_instance(id));
+        assert!(!RollbackAPI::rollback_untrack(&mut runtime, id));
+        RollbackAPI::rollback_stop(&mut runtime);
+        assert!(!RollbackAPI::rollback_active(&runtime));
+    }
+}
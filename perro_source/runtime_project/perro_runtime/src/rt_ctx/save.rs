@@ -0,0 +1,21 @@
+use perro_runtime_api::sub_apis::SaveAPI;
+
+use crate::Runtime;
+
+impl SaveAPI for Runtime {
+    fn enable_autosave(&mut self, interval: f32, slot: &str) -> bool {
+        self.enable_autosave_slot(interval, slot)
+    }
+
+    fn disable_autosave(&mut self) {
+        self.disable_autosave_slot();
+    }
+
+    fn is_autosave_enabled(&self) -> bool {
+        Runtime::is_autosave_enabled(self)
+    }
+
+    fn save_now(&mut self) -> bool {
+        self.write_autosave()
+    }
+}
@@ -33,6 +33,32 @@ impl SceneAPI for Runtime {
             .map_err(LoadError::Legacy)
     }
 
+    fn scene_load_additive(&mut self, path: &str) -> Result<NodeID, String> {
+        self.scene_load_additive_typed(path)
+            .map_err(|err| err.to_string())
+    }
+
+    fn scene_load_additive_typed(&mut self, path: &str) -> LoadResult<NodeID> {
+        self.load_scene_additive_at_runtime(path)
+            .map_err(LoadError::Legacy)
+    }
+
+    fn scene_unload(&mut self, root: NodeID) -> bool {
+        self.unload_scene_at_runtime(root)
+    }
+
+    fn scene_additive_roots(&mut self) -> Vec<NodeID> {
+        self.additive_scene_roots().to_vec()
+    }
+
+    fn scene_set_paused(&mut self, root: NodeID, paused: bool) -> bool {
+        self.set_scene_paused(root, paused)
+    }
+
+    fn scene_is_paused(&mut self, root: NodeID) -> bool {
+        self.is_scene_paused(root)
+    }
+
     fn scene_preload(&mut self, path: &str) -> Result<PreloadedSceneID, String> {
         self.scene_preload_typed(path)
             .map_err(|err| err.to_string())
@@ -6,10 +6,17 @@ use perro_resource_api::ResourceWindow;
 use perro_runtime_api::{RuntimeWindow, sub_apis::ScriptAPI};
 use perro_scripting::ScriptContext;
 use perro_variant::Variant;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use crate::Runtime;
 
+#[cfg(debug_assertions)]
+thread_local! {
+    static WARNED_STALE_SCRIPT_IDS: std::cell::RefCell<std::collections::HashSet<NodeID>> =
+        std::cell::RefCell::new(std::collections::HashSet::new());
+}
+
 #[cfg(feature = "bench")]
 #[derive(Clone, Debug, Default)]
 pub struct BenchScriptState {
@@ -84,6 +91,54 @@ pub fn bench_insert_state_script(runtime: &mut Runtime, id: NodeID) {
     );
 }
 
+/// Insert a stateless script whose lifecycle callbacks are no-ops and whose
+/// schedule membership comes only from `flags` (`ScriptFlags::HAS_*` bits).
+#[cfg(feature = "bench")]
+pub fn bench_insert_flagged_script(runtime: &mut Runtime, id: NodeID, flags: u8) {
+    use crate::RuntimeScriptApi;
+    use perro_scripting::{ScriptBehavior, ScriptFlags, ScriptLifecycle};
+    use std::any::Any;
+
+    struct BenchFlaggedScript {
+        flags: ScriptFlags,
+    }
+
+    impl ScriptLifecycle<RuntimeScriptApi> for BenchFlaggedScript {}
+
+    impl ScriptBehavior<RuntimeScriptApi> for BenchFlaggedScript {
+        fn script_flags(&self) -> ScriptFlags {
+            self.flags
+        }
+
+        fn create_state(&self) -> Box<dyn Any> {
+            Box::new(())
+        }
+
+        fn get_var(&self, _state: &dyn Any, _var: ScriptMemberID) -> Variant {
+            Variant::Null
+        }
+
+        fn set_var(&self, _state: &mut dyn Any, _var: ScriptMemberID, _value: Variant) {}
+
+        fn call_method(
+            &self,
+            _method: ScriptMemberID,
+            _ctx: &mut ScriptContext<'_, RuntimeScriptApi>,
+            _params: &[Variant],
+        ) -> Variant {
+            Variant::Null
+        }
+    }
+
+    runtime.scripts.insert(
+        id,
+        Arc::new(BenchFlaggedScript {
+            flags: ScriptFlags::new(flags),
+        }),
+        Box::new(()),
+    );
+}
+
 #[cfg(feature = "bench")]
 pub fn bench_with_active_script<V, F>(runtime: &mut Runtime, id: NodeID, f: F) -> Option<V>
 where
@@ -116,6 +171,8 @@ impl Runtime {
         self.script_runtime
             .active_script_stack
             .push((instance_index, id));
+        #[cfg(debug_assertions)]
+        crate::cns::origin::set_active_creator(id);
     }
 
     #[inline(always)]
@@ -125,6 +182,13 @@ impl Runtime {
         if self.script_runtime.active_script_stack.is_empty() {
             self.script_runtime.active_callback_context = None;
         }
+        #[cfg(debug_assertions)]
+        crate::cns::origin::set_active_creator(
+            self.script_runtime
+                .active_script_stack
+                .last()
+                .map_or(NodeID::nil(), |&(_, top)| top),
+        );
     }
 
     #[inline(always)]
@@ -284,6 +348,8 @@ impl Runtime {
         self.call_removal_script(id);
         self.unqueue_start_script(id);
         self.signal_runtime.registry.disconnect_script(id);
+        self.event_bus.unsubscribe_script(id);
+        self.untrack_rollback_script(id);
         self.script_runtime.script_instance_dlc_mounts.remove(&id);
         let removed = self.scripts.remove(id).is_some();
         self.script_runtime.removing_scripts.remove(&id);
@@ -357,6 +423,57 @@ impl Runtime {
         behavior.on_fixed_update(&mut sctx);
         self.pop_active_script(instance_index, id);
     }
+
+    #[inline(always)]
+    /// Deliver one buffered input event to a scheduled `on_input` script.
+    ///
+    /// Input slots are a flag-scan snapshot, so the flag is rechecked here in
+    /// case the instance was replaced by a script without `on_input`.
+    pub(crate) fn call_input_script_scheduled_with_context(
+        &mut self,
+        instance_index: usize,
+        id: NodeID,
+        event: &Variant,
+        res: &ResourceWindow<'_, crate::RuntimeResourceApi>,
+        ipt: &InputWindow<'_, perro_input_api::InputSnapshot>,
+    ) {
+        let behavior = match self
+            .scripts
+            .get_instance_scheduled_indexed(instance_index, id)
+        {
+            Some(instance) if instance.behavior.script_flags().has_input() => {
+                Arc::clone(&instance.behavior)
+            }
+            _ => return,
+        };
+        self.push_active_script_with_context(instance_index, id, self.script_callback_context());
+        let _dlc_self_context = self.push_script_dlc_self_context(id);
+        let mut run = RuntimeWindow::new(self);
+        let mut sctx = ScriptContext {
+            run: &mut run,
+            res,
+            ipt,
+            id,
+        };
+        behavior.on_input(&mut sctx, event);
+        self.pop_active_script(instance_index, id);
+    }
+
+    /// Debug builds name script calls aimed at a freed node, once per id; a
+    /// live node that just has no script stays quiet. Release is silent.
+    fn warn_stale_script_target(&self, op: &str, script_id: NodeID) {
+        #[cfg(debug_assertions)]
+        if !script_id.is_nil()
+            && self.nodes.get(script_id).is_none()
+            && WARNED_STALE_SCRIPT_IDS.with(|warned| warned.borrow_mut().insert(script_id))
+        {
+            eprintln!(
+                "[perro][scripts] {op} on freed node {script_id}; keep a WeakNodeRef and `upgrade_node!` it b4 use"
+            );
+        }
+        #[cfg(not(debug_assertions))]
+        let _ = (op, script_id);
+    }
 }
 
 impl ScriptAPI for Runtime {
@@ -454,15 +571,102 @@ impl ScriptAPI for Runtime {
             .with_instance(script_id, |instance| {
                 instance.behavior.get_var(instance.state.as_ref(), member)
             })
-            .unwrap_or(Variant::Null)
+            .unwrap_or_else(|| {
+                self.warn_stale_script_target("get_var", script_id);
+                Variant::Null
+            })
     }
 
     fn set_var(&mut self, script_id: NodeID, member: ScriptMemberID, value: Variant) {
-        let _ = self.scripts.with_instance_mut(script_id, |instance| {
+        let found = self.scripts.with_instance_mut(script_id, |instance| {
             instance
                 .behavior
                 .set_var(instance.state.as_mut(), member, value);
         });
+        if found.is_none() {
+            self.warn_stale_script_target("set_var", script_id);
+        }
+    }
+
+    fn attributes_of(
+        &mut self,
+        script_id: NodeID,
+        member: ScriptMemberID,
+    ) -> &'static [&'static str] {
+        self.scripts
+            .with_instance(script_id, |instance| {
+                instance.behavior.attributes_of(member)
+            })
+            .unwrap_or(&[])
+    }
+
+    fn members_with(&mut self, script_id: NodeID, attribute: &str) -> &'static [ScriptMemberID] {
+        self.scripts
+            .with_instance(script_id, |instance| {
+                instance.behavior.members_with(attribute)
+            })
+            .unwrap_or(&[])
+    }
+
+    fn script_save_state(&mut self, script_id: NodeID) -> Variant {
+        let mut saved = self
+            .scripts
+            .with_instance(script_id, |instance| {
+                let mut payload = BTreeMap::new();
+                payload.insert(
+                    Arc::<str>::from("version"),
+                    Variant::from(instance.behavior.state_version()),
+                );
+                payload.insert(
+                    Arc::<str>::from("state"),
+                    instance.behavior.save_state(instance.state.as_ref()),
+                );
+                Variant::Object(payload)
+            })
+            .unwrap_or(Variant::Null);
+        // node meta rides along so saves keep runtime `set_meta!` changes
+        if let Variant::Object(payload) = &mut saved
+            && let Some(meta) = self.nodes.node_meta(script_id)
+        {
+            let meta = meta
+                .iter()
+                .map(|(key, value)| (Arc::<str>::from(key), value.clone()))
+                .collect();
+            payload.insert(Arc::<str>::from("meta"), Variant::Object(meta));
+        }
+        saved
+    }
+
+    fn script_restore_state(&mut self, script_id: NodeID, saved: Variant) -> bool {
+        let Variant::Object(mut payload) = saved else {
+            return false;
+        };
+        let Some(version) = payload
+            .get("version")
+            .and_then(Variant::as_number)
+            .and_then(|version| version.as_u64_lossy())
+            .and_then(|version| u32::try_from(version).ok())
+        else {
+            return false;
+        };
+        let Some(state) = payload.remove("state") else {
+            return false;
+        };
+        let restored = self
+            .scripts
+            .with_instance_mut(script_id, |instance| {
+                instance
+                    .behavior
+                    .restore_state(instance.state.as_mut(), state, version);
+            })
+            .is_some();
+        // saved keys win; keys only the current scene sets are kept
+        if restored && let Some(Variant::Object(meta)) = payload.remove("meta") {
+            for (key, value) in meta {
+                self.nodes.set_meta(script_id, key.to_string(), value);
+            }
+        }
+        restored
     }
 
     fn call_method(
@@ -479,7 +683,10 @@ impl ScriptAPI for Runtime {
                 };
                 (i, behavior)
             }
-            None => return Variant::Null,
+            None => {
+                self.warn_stale_script_target("call_method", script_id);
+                return Variant::Null;
+            }
         };
         let active_context = self.current_script_callback_context();
         let resource_api = active_context.is_none().then(|| self.resource_api.clone());
@@ -517,7 +724,7 @@ impl ScriptAPI for Runtime {
 mod active_script_stack_tests {
     use super::*;
     use perro_nodes::{NodeType, SceneNode, SceneNodeData};
-    use perro_runtime_api::sub_apis::{ScriptAPI, SignalAPI};
+    use perro_runtime_api::sub_apis::{NodeAPI, ScriptAPI, SignalAPI};
     use perro_scene::{Scene, SceneKey, SceneNodeEntry};
     use perro_scripting::{ScriptBehavior, ScriptFlags, ScriptLifecycle};
     use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
@@ -617,8 +824,11 @@ mod active_script_stack_tests {
         parent: None,
         script: None,
         clear_script: false,
+        enabled: true,
         root_of: None,
         script_vars: Cow::Borrowed(&[]),
+        meta: Cow::Borrowed(&[]),
+        connections: Cow::Borrowed(&[]),
     }];
     static BOOT_SCENE: Scene = Scene {
         nodes: Cow::Borrowed(BOOT_NODES),
@@ -908,4 +1118,240 @@ mod active_script_stack_tests {
         assert!(runtime.script_runtime.active_script_stack.is_empty());
         assert!(runtime.current_script_callback_context().is_none());
     }
+
+    struct TaggedScript;
+
+    const HEALTH: ScriptMemberID = ScriptMemberID::from_string("health");
+    const NAME: ScriptMemberID = ScriptMemberID::from_string("name");
+
+    impl ScriptLifecycle<crate::RuntimeScriptApi> for TaggedScript {}
+
+    impl ScriptBehavior<crate::RuntimeScriptApi> for TaggedScript {
+        fn script_flags(&self) -> ScriptFlags {
+            ScriptFlags::new(0)
+        }
+
+        fn get_var(&self, _state: &dyn Any, _var: ScriptMemberID) -> Variant {
+            Variant::Null
+        }
+
+        fn set_var(&self, _state: &mut dyn Any, _var: ScriptMemberID, _value: Variant) {}
+
+        fn call_method(
+            &self,
+            _method: ScriptMemberID,
+            _ctx: &mut ScriptContext<'_, crate::RuntimeScriptApi>,
+            _params: &[Variant],
+        ) -> Variant {
+            Variant::Null
+        }
+
+        fn attributes_of(&self, member: ScriptMemberID) -> &'static [&'static str] {
+            match member {
+                HEALTH => &["save", "replicated"],
+                NAME => &["save"],
+                _ => &[],
+            }
+        }
+
+        fn members_with(&self, attribute: &str) -> &'static [ScriptMemberID] {
+            match attribute {
+                "save" => &[HEALTH, NAME],
+                "replicated" => &[HEALTH],
+                _ => &[],
+            }
+        }
+    }
+
+    #[test]
+    fn script_attribute_lookups_route_through_instance_behavior() {
+        let mut runtime = Runtime::new();
+        let id = NodeID::new(1);
+        runtime
+            .scripts
+            .insert(id, Arc::new(TaggedScript), Box::new(()));
+
+        assert_eq!(
+            ScriptAPI::attributes_of(&mut runtime, id, HEALTH),
+            &["save", "replicated"]
+        );
+        assert_eq!(
+            ScriptAPI::members_with(&mut runtime, id, "save"),
+            &[HEALTH, NAME]
+        );
+        assert!(ScriptAPI::members_with(&mut runtime, id, "editor_readonly").is_empty());
+        assert!(ScriptAPI::attributes_of(&mut runtime, NodeID::new(2), HEALTH).is_empty());
+    }
+
+    #[derive(Default)]
+    struct VersionedState {
+        hp: i32,
+    }
+
+    struct VersionedScript;
+
+    const HP: ScriptMemberID = ScriptMemberID::from_string("hp");
+
+    impl ScriptLifecycle<crate::RuntimeScriptApi> for VersionedScript {
+        fn migrate_state(&self, old: Variant, old_version: u32) -> Variant {
+            let Variant::Object(mut fields) = old else {
+                return Variant::Null;
+            };
+            if old_version < 2
+                && let Some(health) = fields.remove("health")
+            {
+                fields.insert(Arc::from("hp"), health);
+            }
+            Variant::Object(fields)
+        }
+    }
+
+    impl ScriptBehavior<crate::RuntimeScriptApi> for VersionedScript {
+        fn script_flags(&self) -> ScriptFlags {
+            ScriptFlags::new(0)
+        }
+
+        fn create_state(&self) -> Box<dyn Any> {
+            Box::new(VersionedState::default())
+        }
+
+        fn get_var(&self, state: &dyn Any, var: ScriptMemberID) -> Variant {
+            let state = state
+                .downcast_ref::<VersionedState>()
+                .expect("versioned state");
+            match var {
+                HP => Variant::from(state.hp),
+                _ => Variant::Null,
+            }
+        }
+
+        fn set_var(&self, state: &mut dyn Any, var: ScriptMemberID, value: Variant) {
+            let state = state
+                .downcast_mut::<VersionedState>()
+                .expect("versioned state");
+            if var == HP
+                && let Some(hp) = value.as_i32()
+            {
+                state.hp = hp;
+            }
+        }
+
+        fn call_method(
+            &self,
+            _method: ScriptMemberID,
+            _ctx: &mut ScriptContext<'_, crate::RuntimeScriptApi>,
+            _params: &[Variant],
+        ) -> Variant {
+            Variant::Null
+        }
+
+        fn state_version(&self) -> u32 {
+            2
+        }
+
+        fn state_fields(&self) -> &'static [&'static str] {
+            &["hp"]
+        }
+    }
+
+    fn saved_payload(version: u32, field: &str, value: i32) -> Variant {
+        let state = [(Arc::<str>::from(field), Variant::from(value))]
+            .into_iter()
+            .collect();
+        let payload = [
+            (Arc::<str>::from("version"), Variant::from(version)),
+            (Arc::<str>::from("state"), Variant::Object(state)),
+        ];
+        Variant::Object(payload.into_iter().collect())
+    }
+
+    #[test]
+    fn script_state_save_restore_round_trips_current_version() {
+        let mut runtime = Runtime::new();
+        let id = NodeID::new(1);
+        runtime.scripts.insert(
+            id,
+            Arc::new(VersionedScript),
+            Box::new(VersionedState { hp: 75 }),
+        );
+
+        let saved = ScriptAPI::script_save_state(&mut runtime, id);
+        assert_eq!(saved, saved_payload(2, "hp", 75));
+
+        ScriptAPI::set_var(&mut runtime, id, HP, Variant::from(1_i32));
+        assert!(ScriptAPI::script_restore_state(&mut runtime, id, saved));
+        assert_eq!(
+            ScriptAPI::get_var(&mut runtime, id, HP),
+            Variant::from(75_i32)
+        );
+    }
+
+    #[test]
+    fn script_state_save_restore_carries_node_meta() {
+        let mut runtime = Runtime::new();
+        let id = runtime.nodes.insert(SceneNode::new(SceneNodeData::Node));
+        runtime.scripts.insert(
+            id,
+            Arc::new(VersionedScript),
+            Box::new(VersionedState { hp: 75 }),
+        );
+        assert!(NodeAPI::set_node_meta(
+            &mut runtime,
+            id,
+            "loot_table",
+            Variant::from("res://loot/goblin.ron")
+        ));
+
+        let saved = ScriptAPI::script_save_state(&mut runtime, id);
+        let Variant::Object(payload) = &saved else {
+            panic!("payload must be an object");
+        };
+        assert!(payload.contains_key("meta"));
+
+        assert!(NodeAPI::set_node_meta(
+            &mut runtime,
+            id,
+            "loot_table",
+            Variant::Null
+        ));
+        assert!(NodeAPI::get_node_meta(&mut runtime, id, "loot_table").is_null());
+        assert!(ScriptAPI::script_restore_state(&mut runtime, id, saved));
+        assert_eq!(
+            NodeAPI::get_node_meta(&mut runtime, id, "loot_table"),
+            Variant::from("res://loot/goblin.ron")
+        );
+    }
+
+    #[test]
+    fn script_state_restore_migrates_older_versions() {
+        let mut runtime = Runtime::new();
+        let id = NodeID::new(1);
+        runtime.scripts.insert(
+            id,
+            Arc::new(VersionedScript),
+            Box::new(VersionedState::default()),
+        );
+
+        assert!(ScriptAPI::script_restore_state(
+            &mut runtime,
+            id,
+            saved_payload(1, "health", 40)
+        ));
+        assert_eq!(
+            ScriptAPI::get_var(&mut runtime, id, HP),
+            Variant::from(40_i32)
+        );
+
+        assert!(!ScriptAPI::script_restore_state(
+            &mut runtime,
+            id,
+            Variant::Null
+        ));
+        assert!(!ScriptAPI::script_restore_state(
+            &mut runtime,
+            NodeID::new(2),
+            saved_payload(2, "hp", 5)
+        ));
+        assert!(ScriptAPI::script_save_state(&mut runtime, NodeID::new(2)).is_null());
+    }
 }
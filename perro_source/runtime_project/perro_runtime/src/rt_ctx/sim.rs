@@ -0,0 +1,25 @@
+use perro_runtime_api::sub_apis::SimAPI;
+
+use crate::Runtime;
+
+impl SimAPI for Runtime {
+    fn sim_tick(&self) -> u64 {
+        self.sim_clock_tick()
+    }
+
+    fn sim_time(&self) -> f64 {
+        self.sim_clock_elapsed()
+    }
+
+    fn sim_seed(&self) -> u32 {
+        self.sim_clock_seed()
+    }
+
+    fn sim_reset(&mut self, seed: u32) {
+        self.reset_sim_clock(seed);
+    }
+
+    fn sim_next_u32(&mut self) -> u32 {
+        self.next_sim_random()
+    }
+}
@@ -0,0 +1,135 @@
+use perro_ids::NodeID;
+use perro_runtime_api::sub_apis::Space2DAPI;
+use perro_structs::Vector2;
+
+use crate::Runtime;
+
+impl Space2DAPI for Runtime {
+    fn space2d_query_radius(&mut self, point: Vector2, radius: f32) -> Vec<NodeID> {
+        self.sync_space2d_index();
+        let mut out = Vec::new();
+        self.space2d.query_radius(point, radius, &mut out);
+        out
+    }
+
+    fn space2d_query_rect(&mut self, min: Vector2, max: Vector2) -> Vec<NodeID> {
+        self.sync_space2d_index();
+        let mut out = Vec::new();
+        self.space2d.query_rect(min, max, &mut out);
+        out
+    }
+
+    fn space2d_set_cell_size(&mut self, size: f32) {
+        self.space2d.set_cell_size(size);
+    }
+
+    fn space2d_cell_size(&self) -> f32 {
+        self.space2d.cell_size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perro_nodes::{Node2D, Node3D, SceneNode, SceneNodeData};
+    use perro_runtime_api::sub_apis::NodeAPI;
+
+    fn insert_node_2d(runtime: &mut Runtime, x: f32, y: f32) -> NodeID {
+        let mut node = Node2D::new();
+        node.transform.position = Vector2::new(x, y);
+        runtime
+            .nodes
+            .insert(SceneNode::new(SceneNodeData::Node2D(node)))
+    }
+
+    fn move_node_2d(runtime: &mut Runtime, id: NodeID, x: f32, y: f32) {
+        runtime
+            .nodes
+            .get_mut(id)
+            .expect("node must exist")
+            .with_typed_mut::<Node2D, _>(|node| node.transform.position = Vector2::new(x, y));
+        runtime.mark_transform_dirty_recursive(id);
+    }
+
+    fn sorted(mut ids: Vec<NodeID>) -> Vec<NodeID> {
+        ids.sort_unstable();
+        ids
+    }
+
+    #[test]
+    fn space2d_radius_and_rect_return_only_nodes_in_range() {
+        let mut runtime = Runtime::new();
+        let near = insert_node_2d(&mut runtime, 10.0, 0.0);
+        let edge = insert_node_2d(&mut runtime, 0.0, 30.0);
+        let far = insert_node_2d(&mut runtime, 500.0, 500.0);
+        let _ = runtime
+            .nodes
+            .insert(SceneNode::new(SceneNodeData::Node3D(Node3D::new())));
+
+        let hits = runtime.space2d_query_radius(Vector2::ZERO, 30.0);
+        assert_eq!(sorted(hits), sorted(vec![near, edge]));
+
+        let boxed = runtime.space2d_query_rect(Vector2::new(600.0, 600.0), Vector2::new(5.0, -5.0));
+        assert_eq!(sorted(boxed), sorted(vec![near, far]));
+
+        assert!(runtime.space2d_query_radius(Vector2::ZERO, -1.0).is_empty());
+    }
+
+    #[test]
+    fn space2d_tracks_moves_and_structure_changes() {
+        let mut runtime = Runtime::new();
+        let mover = insert_node_2d(&mut runtime, 0.0, 0.0);
+        assert_eq!(
+            runtime.space2d_query_radius(Vector2::ZERO, 1.0),
+            vec![mover]
+        );
+
+        move_node_2d(&mut runtime, mover, 200.0, 0.0);
+        assert!(runtime.space2d_query_radius(Vector2::ZERO, 1.0).is_empty());
+        assert_eq!(
+            runtime.space2d_query_radius(Vector2::new(200.0, 0.0), 1.0),
+            vec![mover]
+        );
+
+        // reparent keeps global pos; child sits @ local -195 under mover
+        let child = insert_node_2d(&mut runtime, 5.0, 0.0);
+        assert!(runtime.reparent(mover, child));
+        assert_eq!(
+            runtime.space2d_query_radius(Vector2::new(5.0, 0.0), 1.0),
+            vec![child]
+        );
+
+        // parent move re-buckets the whole subtree
+        move_node_2d(&mut runtime, mover, 100.0, 0.0);
+        assert!(
+            runtime
+                .space2d_query_radius(Vector2::new(5.0, 0.0), 1.0)
+                .is_empty()
+        );
+        assert_eq!(
+            runtime.space2d_query_radius(Vector2::new(-95.0, 0.0), 1.0),
+            vec![child]
+        );
+
+        let _ = runtime.nodes.remove(child);
+        assert!(
+            runtime
+                .space2d_query_radius(Vector2::new(-95.0, 0.0), 1.0)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn space2d_cell_size_change_rebuilds_index() {
+        let mut runtime = Runtime::new();
+        let a = insert_node_2d(&mut runtime, 3.0, 3.0);
+        assert_eq!(runtime.space2d_query_radius(Vector2::ZERO, 5.0), vec![a]);
+
+        runtime.space2d_set_cell_size(2.0);
+        assert_eq!(runtime.space2d_cell_size(), 2.0);
+        assert_eq!(runtime.space2d_query_radius(Vector2::ZERO, 5.0), vec![a]);
+
+        runtime.space2d_set_cell_size(0.0);
+        assert_eq!(runtime.space2d_cell_size(), 2.0);
+    }
+}
@@ -0,0 +1,123 @@
+use perro_runtime_api::sub_apis::{WorldAPI, WorldSnapshot, WorldSnapshotBuilder};
+
+use crate::Runtime;
+
+impl WorldAPI for Runtime {
+    fn world_snapshot(&mut self) -> WorldSnapshot {
+        self.world_snapshot_state.requested = true;
+        if let Some(snapshot) = &self.world_snapshot_state.current {
+            return snapshot.clone();
+        }
+        // 1st request after idle frames: capture now, later frames capture
+        // b4 any script runs.
+        let snapshot = self.capture_world_snapshot();
+        self.world_snapshot_state.current = Some(snapshot.clone());
+        snapshot
+    }
+}
+
+impl Runtime {
+    /// Drop last frame's snapshot; recapture only if scripts asked 4 one since.
+    pub(crate) fn begin_world_snapshot_frame(&mut self) {
+        self.world_snapshot_state.current = None;
+        if std::mem::take(&mut self.world_snapshot_state.requested) {
+            self.world_snapshot_state.current = Some(self.capture_world_snapshot());
+        }
+    }
+
+    fn capture_world_snapshot(&mut self) -> WorldSnapshot {
+        let mut ids = std::mem::take(&mut self.world_snapshot_state.ids_scratch);
+        let mut tags = std::mem::take(&mut self.world_snapshot_state.tags_scratch);
+        ids.clear();
+        ids.extend(self.nodes.iter().map(|(id, _)| id));
+
+        let mut builder =
+            WorldSnapshotBuilder::with_capacity(self.nodes.mutation_revision(), ids.len());
+        for &id in &ids {
+            let Some(node) = self.nodes.get(id) else {
+                continue;
+            };
+            let parent = node.get_parent();
+            let (is_2d, is_3d) = (node.is_2d(), node.is_3d());
+            tags.clear();
+            tags.extend(node.tags_slice().iter().map(|tag| tag.id));
+
+            if is_2d && let Some(global) = self.get_global_transform_2d(id) {
+                builder.push_2d(id, parent, global, &tags);
+            } else if is_3d && let Some(global) = self.get_global_transform_3d(id) {
+                builder.push_3d(id, parent, global, &tags);
+            } else {
+                builder.push(id, parent, &tags);
+            }
+        }
+
+        self.world_snapshot_state.ids_scratch = ids;
+        self.world_snapshot_state.tags_scratch = tags;
+        builder.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perro_ids::{NodeID, TagID};
+    use perro_nodes::{Node2D, SceneNode, SceneNodeData};
+    use perro_structs::Vector2;
+
+    fn insert_node_2d(runtime: &mut Runtime, x: f32, tag: Option<&'static str>) -> NodeID {
+        let mut node = Node2D::new();
+        node.transform.position = Vector2::new(x, 0.0);
+        let mut node = SceneNode::new(SceneNodeData::Node2D(node));
+        if let Some(tag) = tag {
+            node.add_tag(TagID::from_string(tag));
+        }
+        runtime.nodes.insert(node)
+    }
+
+    fn move_node_2d(runtime: &mut Runtime, id: NodeID, x: f32) {
+        runtime
+            .nodes
+            .get_mut(id)
+            .expect("node must exist")
+            .with_typed_mut::<Node2D, _>(|node| node.transform.position.x = x);
+        runtime.mark_transform_dirty_recursive(id);
+    }
+
+    #[test]
+    fn world_snapshot_is_stable_within_frame_and_refreshes_at_frame_start() {
+        let mut runtime = Runtime::new();
+        let a = insert_node_2d(&mut runtime, 1.0, Some("enemy"));
+        let b = insert_node_2d(&mut runtime, 2.0, None);
+
+        let first = runtime.world_snapshot();
+        assert_eq!(first.global_pos_2d(a), Some(Vector2::new(1.0, 0.0)));
+        assert_eq!(first.tagged("enemy"), &[a]);
+        assert!(!first.has_tag(b, "enemy"));
+
+        // mid-frame write stays invisible 2 snapshot readers
+        move_node_2d(&mut runtime, a, 5.0);
+        let same_frame = runtime.world_snapshot();
+        assert_eq!(same_frame.global_pos_2d(a), Some(Vector2::new(1.0, 0.0)));
+
+        runtime.begin_world_snapshot_frame();
+        let next_frame = runtime.world_snapshot();
+        assert_eq!(next_frame.global_pos_2d(a), Some(Vector2::new(5.0, 0.0)));
+        assert_eq!(first.global_pos_2d(a), Some(Vector2::new(1.0, 0.0)));
+    }
+
+    #[test]
+    fn world_snapshot_skips_capture_when_unused() {
+        let mut runtime = Runtime::new();
+        let _ = insert_node_2d(&mut runtime, 1.0, None);
+
+        runtime.begin_world_snapshot_frame();
+        assert!(runtime.world_snapshot_state.current.is_none());
+
+        let _ = runtime.world_snapshot();
+        runtime.begin_world_snapshot_frame();
+        assert!(runtime.world_snapshot_state.current.is_some());
+
+        runtime.begin_world_snapshot_frame();
+        assert!(runtime.world_snapshot_state.current.is_none());
+    }
+}
@@ -3,7 +3,7 @@ use crate::{
     rs_ctx::RuntimeResourceApi,
     runtime_project::{ProviderMode, RuntimeProject},
 };
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 use perro_ids::{MaterialID, MeshID, NodeID, TextureID};
 use perro_input_api::InputSnapshot;
 use perro_runtime_api::sub_apis::{PreloadedSceneID, WindowRequest};
@@ -20,11 +20,17 @@ const STARTUP_INPUT_CLEAR_FRAMES: u32 = 100;
 
 // Runtime subsystem leaves. Public API glue stays here; heavy behavior lives in folders.
 mod audio;
+mod autosave;
+mod harness;
 mod input_bridge;
 mod internal_updates;
 mod mesh_query;
 pub(crate) mod navmesh;
+mod node_enabled;
+mod owned_spawns;
 mod physics;
+mod project_reload;
+mod quit;
 #[path = "runtime/render/two_d.rs"]
 mod render_2d;
 #[path = "runtime/render/three_d.rs"]
@@ -33,27 +39,36 @@ mod render_3d;
 mod render_bridge;
 #[path = "runtime/render/ui.rs"]
 mod render_ui;
+mod rollback;
+mod scene_hot_reload;
+mod scene_instances;
 mod scene_loader;
 mod scheduling;
+mod sim_clock;
+pub(crate) mod space2d;
 pub(crate) mod state;
+mod thumbnail;
 mod timers;
 mod transforms;
 mod world_state;
 
 use audio::AudioPropagationState;
+pub use harness::RuntimeHarness;
 pub(crate) use scene_loader::PendingScriptAttach;
 #[cfg(feature = "bench")]
 pub use scene_loader::{
     BenchPreparedScene, BenchSceneSpawner, bench_compile_scene, bench_merge_compiled_scene,
     bench_prepare_and_merge_scene, bench_prepare_merge_extract_scene, bench_prepare_scene,
 };
+use space2d::Space2DIndex;
 pub(crate) use state::CollisionDebugState;
 pub(crate) use state::ScriptCallbackContext;
 use state::{
     DirtyState, InternalUpdateState, NodeApiScratchState, NodeIndexState, Render2DState,
     Render3DState, RenderState, RenderUiState, ScriptRuntimeState, ScriptSchedules,
-    SignalRuntimeState, TransformRuntimeState,
+    SignalRuntimeState, TransformRuntimeState, WorldSnapshotState,
 };
+pub use thumbnail::THUMBNAIL_CAMERA_NAME;
 use timers::TimerRuntimeState;
 
 pub struct RuntimeScriptApi;
@@ -182,9 +197,9 @@ pub struct Runtime {
     schedules: ScriptSchedules,
     pub(crate) script_runtime: ScriptRuntimeState,
     render: RenderState,
-    scene_texture_refs_cache: AHashMap<TextureID, Vec<NodeID>>,
-    scene_mesh_refs_cache: AHashMap<MeshID, Vec<NodeID>>,
-    scene_material_refs_cache: AHashMap<MaterialID, Vec<NodeID>>,
+    pub(crate) scene_texture_refs_cache: AHashMap<TextureID, Vec<NodeID>>,
+    pub(crate) scene_mesh_refs_cache: AHashMap<MeshID, Vec<NodeID>>,
+    pub(crate) scene_material_refs_cache: AHashMap<MaterialID, Vec<NodeID>>,
     /// last arena mutation_revision seen by resource-ref scan. gate re-scan.
     scene_resource_refs_scanned_version: u64,
     /// force resource-ref re-scan next drain. set on resource render events
@@ -208,14 +223,42 @@ pub struct Runtime {
     pub(crate) signal_runtime: SignalRuntimeState,
     pub(crate) node_index: NodeIndexState,
     pub(crate) node_api_scratch: NodeApiScratchState,
+    pub(crate) world_snapshot_state: WorldSnapshotState,
+    pub(crate) space2d: Space2DIndex,
     pub(crate) resource_api: Arc<RuntimeResourceApi>,
     pub(crate) input: InputSnapshot,
+    /// zero point of the `on_input` event clock
+    input_epoch: Instant,
+    /// load/save `user://input_bindings.toml`; off for bare and harness runtimes
+    pub(crate) persist_input_bindings: bool,
     startup_input_clear_frames_left: u32,
     cursor_icon_request: Option<perro_ui::CursorIcon>,
     pub(crate) window_requests: Vec<WindowRequest>,
     pub(crate) active_refresh_rate: Option<f32>,
+    /// soft quit request + `quit_handlers` holds; see `begin_quit`
+    quit: quit::QuitState,
+    /// `ctx.run.Save()` autosave slot + write throttle; see `tick_autosave`
+    autosave: autosave::AutosaveState,
+    /// last GPU resource bytes reported by the graphics backend; 0 headless.
+    pub(crate) gpu_memory_bytes: u64,
     pub(crate) physics_gravity_override: Option<f32>,
     pub(crate) physics_coef_override: Option<f32>,
+    /// dev-run project.toml watch; see `poll_project_reload`
+    project_reload: project_reload::ProjectReloadState,
+    /// dev-run `.scn` watch; see `poll_scene_hot_reload`
+    scene_hot_reload: scene_hot_reload::SceneHotReloadState,
+    /// additive scene roots + per-scene pause; see `load_scene_additive_at_runtime`
+    scene_instances: scene_instances::SceneInstanceState,
+    /// `enabled = false` nodes; their subtrees are suspended and hidden
+    disabled_nodes: AHashSet<NodeID>,
+    /// `instantiate_owned!` spawns by owner; see `set_node_owner`
+    owned_spawns: owned_spawns::OwnedSpawnState,
+    /// `event_publish!` topics; see `flush_event_bus`
+    pub(crate) event_bus: perro_modules::events::EventBus,
+    /// lockstep tick clock + rng; see `advance_sim_clock`
+    sim_clock: sim_clock::SimClockState,
+    /// tick snapshots + inputs 4 rollback netplay; see `resimulate_rollback`
+    rollback: rollback::RollbackState,
     physics: physics::PhysicsState,
     /// arena mutation revision @ last node->world sync; match + no dirty => skip re-sync
     physics_synced_node_revision_2d: Option<u64>,
@@ -228,6 +271,9 @@ pub struct Runtime {
     /// ! exposed on node (char body has no velocity state)
     character_fall_speed_2d: AHashMap<NodeID, f32>,
     character_fall_speed_3d: AHashMap<NodeID, f32>,
+    /// floor each 2d char body last stood on + its global pose then;
+    /// drives platform carry + floor snap
+    character_floor_2d: AHashMap<NodeID, (NodeID, perro_structs::Transform2D)>,
     /// last sweep hit per char body (node, point, normal); merged -> contacts_*
     /// cuz kinematic-vs-fixed pairs never activate in solver narrow phase
     character_sweep_hit_2d:
@@ -538,14 +584,29 @@ impl Runtime {
             signal_runtime: SignalRuntimeState::new(),
             node_index: NodeIndexState::new(),
             node_api_scratch: NodeApiScratchState::new(),
+            world_snapshot_state: WorldSnapshotState::new(),
+            space2d: Space2DIndex::new(),
             resource_api: RuntimeResourceApi::new(None, None, None, None, None, None, None, None),
             input: InputSnapshot::new(),
+            input_epoch: Instant::now(),
+            persist_input_bindings: false,
             startup_input_clear_frames_left: 0,
             cursor_icon_request: None,
             window_requests: Vec::new(),
             active_refresh_rate: None,
+            quit: quit::QuitState::default(),
+            autosave: autosave::AutosaveState::default(),
+            gpu_memory_bytes: 0,
             physics_gravity_override: None,
             physics_coef_override: None,
+            project_reload: project_reload::ProjectReloadState::default(),
+            scene_hot_reload: scene_hot_reload::SceneHotReloadState::default(),
+            scene_instances: scene_instances::SceneInstanceState::default(),
+            disabled_nodes: AHashSet::default(),
+            owned_spawns: owned_spawns::OwnedSpawnState::default(),
+            event_bus: perro_modules::events::EventBus::new(),
+            sim_clock: sim_clock::SimClockState::new(0),
+            rollback: rollback::RollbackState::default(),
             physics: physics::PhysicsState::new(),
             physics_synced_node_revision_2d: None,
             physics_synced_node_revision_3d: None,
@@ -554,6 +615,7 @@ impl Runtime {
             physics_joint_descs_2d: Vec::new(),
             physics_joint_descs_3d: Vec::new(),
             character_fall_speed_2d: AHashMap::new(),
+            character_floor_2d: AHashMap::new(),
             character_fall_speed_3d: AHashMap::new(),
             character_sweep_hit_2d: AHashMap::new(),
             character_sweep_hit_3d: AHashMap::new(),
@@ -720,12 +782,22 @@ impl Runtime {
         let version = self.nodes.physics_revision();
         if self.water_rigid_body_ids_3d_cache_version != Some(version) {
             self.water_rigid_body_ids_3d_cache.clear();
-            scan_node_type_slots(
+            scan_node_type_slots_any(
                 &self.nodes,
-                perro_nodes::NodeType::RigidBody3D,
-                |node| matches!(&node.data, perro_nodes::SceneNodeData::RigidBody3D(body) if body.enabled),
+                &[
+                    perro_nodes::NodeType::RigidBody3D,
+                    perro_nodes::NodeType::VehicleBody3D,
+                ],
                 &mut self.water_rigid_body_ids_3d_cache,
             );
+            let nodes = &self.nodes;
+            self.water_rigid_body_ids_3d_cache.retain(|&id| {
+                nodes.get(id).is_some_and(|node| match &node.data {
+                    perro_nodes::SceneNodeData::RigidBody3D(body) => body.enabled,
+                    perro_nodes::SceneNodeData::VehicleBody3D(body) => body.enabled,
+                    _ => false,
+                })
+            });
             self.water_rigid_body_ids_3d_cache_version = Some(version);
         }
         &self.water_rigid_body_ids_3d_cache
@@ -752,7 +824,8 @@ impl Runtime {
         &self.water_collision_body_ids_2d_cache
     }
 
-    /// Coastline scan candidates: StaticBody3D + RigidBody3D + CharacterBody3D.
+    /// Coastline scan candidates: StaticBody3D + RigidBody3D + VehicleBody3D +
+    /// CharacterBody3D.
     pub(crate) fn cached_water_collision_body_ids_3d(&mut self) -> &[NodeID] {
         let version = self.nodes.physics_revision();
         if self.water_collision_body_ids_3d_cache_version != Some(version) {
@@ -762,6 +835,7 @@ impl Runtime {
                 &[
                     perro_nodes::NodeType::StaticBody3D,
                     perro_nodes::NodeType::RigidBody3D,
+                    perro_nodes::NodeType::VehicleBody3D,
                     perro_nodes::NodeType::CharacterBody3D,
                 ],
                 &mut self.water_collision_body_ids_3d_cache,
@@ -848,6 +922,19 @@ impl Runtime {
         project: RuntimeProject,
         provider_mode: ProviderMode,
         script_registry: Option<StaticScriptRegistry>,
+    ) -> Self {
+        let mut runtime = Self::from_project_unbooted(project, provider_mode, script_registry);
+        if let Err(err) = runtime.load_boot_scene() {
+            panic!("failed to load boot scene: {err}");
+        }
+        runtime
+    }
+
+    /// Project wiring without the boot scene; callers run `load_boot_scene`.
+    pub(crate) fn from_project_unbooted(
+        project: RuntimeProject,
+        provider_mode: ProviderMode,
+        script_registry: Option<StaticScriptRegistry>,
     ) -> Self {
         let mut runtime = Self::new();
         let static_material_lookup = project.static_material_lookup;
@@ -859,11 +946,13 @@ impl Runtime {
         let static_csv_lookup = project.static_csv_lookup;
         let localization_config = project.config.localization.clone();
         let input_map = project.config.input_map.clone();
+        physics::install_physics_layer_names(&project.config.layer_names);
         #[cfg(feature = "steamworks")]
         let steam_config = project.config.steam.clone();
         runtime.project = Some(Arc::new(project));
         runtime.provider_mode = provider_mode;
         runtime.startup_input_clear_frames_left = STARTUP_INPUT_CLEAR_FRAMES;
+        runtime.persist_input_bindings = true;
         runtime.resource_api = RuntimeResourceApi::new(
             static_material_lookup,
             static_audio_lookup,
@@ -890,9 +979,6 @@ impl Runtime {
                 "[runtime][warn] Steam enabled but init failed: {err}. Steam features stay unavailable. Check that Steam is open, the app_id is valid, and the account has access."
             );
         }
-        if let Err(err) = runtime.load_boot_scene() {
-            panic!("failed to load boot scene: {err}");
-        }
         runtime
     }
 
@@ -906,13 +992,19 @@ impl Runtime {
 
     #[inline]
     pub fn update(&mut self, delta_time: f32) {
+        self.poll_project_reload();
+        self.poll_scene_hot_reload();
         self.clear_startup_keyboard_mouse();
         self.time.delta = delta_time;
         self.advance_timers(delta_time);
         self.flush_queued_ui_signals();
+        self.flush_event_bus();
         self.process_pending_web_route_change();
         self.apply_loaded_skeleton_bones();
+        self.begin_world_snapshot_frame();
         self.run_start_schedule();
+        self.run_input_schedule();
+        self.run_rebind_completions();
         self.schedules.snapshot_update(&self.scripts);
         self.run_update_schedule();
         #[cfg(feature = "steamworks")]
@@ -921,22 +1013,30 @@ impl Runtime {
         self.nodes.refresh_packed_children();
         self.propagate_pending_transform_dirty();
         self.update_audio_propagation(delta_time);
+        self.tick_autosave(delta_time);
     }
 
     #[inline]
     pub fn update_timed(&mut self, delta_time: f32) -> RuntimeUpdateTiming {
         let total_start = Instant::now();
+        self.poll_project_reload();
+        self.poll_scene_hot_reload();
         self.clear_startup_keyboard_mouse();
         self.time.delta = delta_time;
         self.advance_timers(delta_time);
         self.flush_queued_ui_signals();
+        self.flush_event_bus();
         self.process_pending_web_route_change();
         self.apply_loaded_skeleton_bones();
+        self.begin_world_snapshot_frame();
 
         let start_schedule_start = Instant::now();
         self.run_start_schedule();
         let start_schedule = start_schedule_start.elapsed();
 
+        self.run_input_schedule();
+        self.run_rebind_completions();
+
         let snapshot_start = Instant::now();
         self.schedules.snapshot_update(&self.scripts);
         let snapshot_update = snapshot_start.elapsed();
@@ -952,6 +1052,7 @@ impl Runtime {
         self.nodes.refresh_packed_children();
         self.propagate_pending_transform_dirty();
         self.update_audio_propagation(delta_time);
+        self.tick_autosave(delta_time);
 
         RuntimeUpdateTiming {
             start_schedule,
@@ -966,6 +1067,8 @@ impl Runtime {
     pub fn fixed_update(&mut self, fixed_delta_time: f32) {
         self.clear_startup_keyboard_mouse();
         self.time.fixed_delta = fixed_delta_time;
+        self.resimulate_rollback(fixed_delta_time);
+        self.advance_sim_clock(fixed_delta_time);
         self.schedules.snapshot_fixed(&self.scripts);
         self.run_fixed_schedule();
         self.nodes.refresh_packed_children();
@@ -973,6 +1076,8 @@ impl Runtime {
         self.run_internal_fixed_update_schedule();
         self.nodes.refresh_packed_children();
         self.propagate_pending_transform_dirty();
+        self.record_interpolated_node_poses();
+        self.record_rollback_tick();
     }
 
     #[inline]
@@ -980,6 +1085,8 @@ impl Runtime {
         let total_start = Instant::now();
         self.clear_startup_keyboard_mouse();
         self.time.fixed_delta = fixed_delta_time;
+        self.resimulate_rollback(fixed_delta_time);
+        self.advance_sim_clock(fixed_delta_time);
 
         let snapshot_start = Instant::now();
         self.schedules.snapshot_fixed(&self.scripts);
@@ -997,6 +1104,8 @@ impl Runtime {
         let internal_fixed_update = internal_fixed_start.elapsed();
         self.nodes.refresh_packed_children();
         self.propagate_pending_transform_dirty();
+        self.record_interpolated_node_poses();
+        self.record_rollback_tick();
 
         RuntimeFixedUpdateTiming {
             snapshot_update,
@@ -1082,6 +1191,14 @@ impl Default for Runtime {
 
 impl Drop for Runtime {
     fn drop(&mut self) {
+        #[cfg(all(debug_assertions, not(test)))]
+        {
+            let leaks = self.leak_report();
+            if !leaks.is_empty() {
+                eprintln!("[perro][leaks] shutdown: {leaks}");
+            }
+        }
+
         let mut script_ids = Vec::new();
         self.scripts.append_instance_ids(&mut script_ids);
         for id in script_ids {
@@ -3,13 +3,13 @@
 use super::Runtime;
 use super::physics::{AudioRaycastInput, AudioRaycastResult};
 use crate::rs_ctx::QueuedSpatialAudioPos;
-use perro_ids::NodeID;
+use perro_ids::{AudioBusID, NodeID};
 use perro_nodes::{CollisionShape2D, CollisionShape3D, SceneNodeData};
 use perro_render_bridge::{Command2D, Command3D, DrawShape2DCommand, RenderCommand};
 use perro_resource_api::sub_apis::AudioAPI;
 use perro_runtime_api::sub_apis::{
-    AttachedMidiTarget, AudioDirection, AudioEffects, PhysicsQueryFilter, RuntimeAudio,
-    RuntimeAudioAPI, SpatialAudioOptions,
+    AttachedMidiTarget, AudioAttenuation, AudioDirection, AudioEffects, PhysicsQueryFilter,
+    RuntimeAudio, RuntimeAudioAPI, SpatialAudioOptions,
 };
 use perro_structs::{
     AudioDiffusion, AudioEffect, AudioMaterial, BitMask, DrawShape2D, Transform2D, Transform3D,
@@ -33,12 +33,26 @@ const AUDIO_BOUNCE_RAYS_3D: usize = 6;
 const MAX_AUDIO_PORTAL_HOPS: usize = 32;
 const AUDIO_PORTAL_EPSILON: f32 = 0.01;
 const AUDIO_PORTAL_MISS_TOLERANCE: f32 = 0.25;
+// World units per second; Doppler scale 1 treats 1 unit as 1 meter.
+const SPEED_OF_SOUND: f32 = 343.0;
 
 #[inline]
 fn bounded_audio_bounces(value: u32) -> u32 {
     value.min(perro_project::MAX_AUDIO_PROPAGATION_BOUNCES)
 }
 
+fn project_bus_effects(bus: perro_project::AudioBusConfig) -> perro_pawdio::AudioBusEffects {
+    perro_pawdio::AudioBusEffects {
+        low_pass: bus.low_pass,
+        reverb: bus.reverb,
+        compression: perro_pawdio::AudioCompression {
+            threshold: bus.compression_threshold,
+            ratio: bus.compression_ratio,
+            ..perro_pawdio::AudioCompression::default()
+        },
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct AudioPropagationConfigRt {
     pub listener_max_distance: f32,
@@ -113,6 +127,10 @@ struct ActiveSpatialSound {
     elapsed_since_prop: f32,
     remaining: Option<f32>,
     last_result: Option<PropagationResult>,
+    // Listener distance + solve interval from the last 3D solve; Doppler reads
+    // the radial speed off the delta.
+    doppler_distance: Option<f32>,
+    doppler_dt: f32,
     // Persistent reconciled-aperture cache (Phase 1). When the direct ray is
     // blocked, the reconciler finds a virtual source (aperture) where
     // listener-side and source-side ray paths meet. Cached across ticks and
@@ -203,6 +221,7 @@ pub(crate) struct PropagationResult {
     pub occlusion: f32,
     pub perceived_2d: Option<Vector2>,
     pub perceived_3d: Option<Vector3>,
+    pub pitch: f32,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -420,6 +439,31 @@ impl Runtime {
             rays_per_tick_3d: cfg.propagation_3d.rays_per_tick,
             max_ray_distance_3d: cfg.propagation_3d.max_ray_distance,
         };
+        self.apply_project_audio_buses(cfg.buses);
+    }
+
+    fn apply_project_audio_buses(&self, buses: perro_project::AudioBusesConfig) {
+        let Ok(guard) = self.resource_api.bark.lock() else {
+            return;
+        };
+        let Some(player) = guard.as_ref() else {
+            return;
+        };
+        // Defaults are no-ops on a fresh mixer; only send what project chg.
+        if buses.master != perro_project::AudioBusConfig::DEFAULT {
+            player.set_master_volume(buses.master.volume);
+            player.set_master_muted(buses.master.muted);
+            player.set_master_effects(project_bus_effects(buses.master));
+        }
+        for (name, bus) in [("music", buses.music), ("sfx", buses.sfx)] {
+            if bus == perro_project::AudioBusConfig::DEFAULT {
+                continue;
+            }
+            let bus_id = AudioBusID::from_string(name);
+            player.set_bus_volume(bus_id, bus.volume);
+            player.set_bus_muted(bus_id, bus.muted);
+            player.set_bus_effects(bus_id, project_bus_effects(bus));
+        }
     }
 
     pub(crate) fn update_audio_propagation(&mut self, dt: f32) {
@@ -593,6 +637,7 @@ impl Runtime {
                 self.audio.counters.cache_hits = self.audio.counters.cache_hits.saturating_add(1);
                 continue;
             }
+            sound.doppler_dt = sound.elapsed_since_prop;
             sound.elapsed_since_prop = 0.0;
             if matches!(
                 self.audio.scratch_sound_ray_results[index],
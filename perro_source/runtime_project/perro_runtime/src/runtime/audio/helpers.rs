@@ -29,10 +29,25 @@ pub(super) fn spatial_pan(local: [f32; 3]) -> [f32; 3] {
     [x * scale, y * scale, z * scale]
 }
 
-// Squared linear falloff: audibly louder up close, still exactly 0 at range.
-pub(super) fn distance_attenuation(distance: f32, range: f32) -> f32 {
-    let linear = 1.0 - (distance / range.max(0.0001)).clamp(0.0, 1.0);
-    linear * linear
+// Curve picked per sound; default Quadratic kp old squared-linear falloff.
+pub(super) fn distance_attenuation(curve: AudioAttenuation, distance: f32, range: f32) -> f32 {
+    curve.gain(distance, range)
+}
+
+// Radial speed off the distance delta between solves: receding (+) drops pitch,
+// approaching (-) raises it. Clamped to [0.5, 2.0] so teleports don't squeal.
+pub(super) fn doppler_pitch(sound: &mut ActiveSpatialSound, distance: f32) -> f32 {
+    let prev = sound.doppler_distance.replace(distance);
+    let scale = sound.options.doppler;
+    let dt = sound.doppler_dt;
+    let Some(prev) = prev else {
+        return 1.0;
+    };
+    if scale <= 0.0 || !dt.is_finite() || dt <= 0.0 {
+        return 1.0;
+    }
+    let radial = ((distance - prev) / dt * scale).clamp(-SPEED_OF_SOUND * 0.5, SPEED_OF_SOUND);
+    SPEED_OF_SOUND / (SPEED_OF_SOUND + radial)
 }
 
 pub(super) fn rotate_vec2(v: Vector2, radians: f32) -> Vector2 {
@@ -42,6 +57,7 @@ pub(super) fn rotate_vec2(v: Vector2, radians: f32) -> Vector2 {
 
 pub(super) fn normalize_spatial_options(mut options: SpatialAudioOptions) -> SpatialAudioOptions {
     options.range = options.range.max(0.0001);
+    options.doppler = options.doppler.max(0.0);
     options.direction_2d = normalize_direction_2d(options.direction_2d);
     options.direction_3d = normalize_direction_3d(options.direction_3d);
     options
@@ -207,6 +207,7 @@ impl Runtime {
                 self.audio.counters.cache_hits = self.audio.counters.cache_hits.saturating_add(1);
                 continue;
             }
+            sound.doppler_dt = sound.elapsed_since_prop;
             sound.elapsed_since_prop = 0.0;
             self.refresh_spatial_position(sound);
             let Some(pos) = sound.last_2d else {
@@ -288,6 +289,7 @@ impl Runtime {
                 self.audio.counters.cache_hits = self.audio.counters.cache_hits.saturating_add(1);
                 continue;
             }
+            sound.doppler_dt = sound.elapsed_since_prop;
             sound.elapsed_since_prop = 0.0;
             self.refresh_spatial_position(sound);
             let Some(pos) = sound.last_3d else {
@@ -370,6 +372,7 @@ impl Runtime {
                         attack: sound.effects.compression.attack,
                         release: sound.effects.compression.release,
                     },
+                    pitch: result.pitch,
                 },
             );
         }
@@ -439,6 +442,8 @@ impl Runtime {
                         AudioDirection::Bidirectional(v)
                     }
                 },
+                attenuation: request.attenuation,
+                doppler: request.doppler,
             };
             match request.pos {
                 QueuedSpatialAudioPos::TwoD(position) => {
@@ -477,6 +482,8 @@ impl Runtime {
                 enable_propagation: true,
                 direction_2d: AudioDirection::Omni,
                 direction_3d: AudioDirection::Omni,
+                attenuation: AudioAttenuation::Quadratic,
+                doppler: 0.0,
             };
             match (request.kind, request.pos) {
                 (
@@ -621,6 +628,7 @@ fn smooth_propagation_result(
         occlusion: smooth_toward(prev.occlusion, next.occlusion),
         perceived_2d: next.perceived_2d,
         perceived_3d: next.perceived_3d,
+        pitch: smooth_toward(prev.pitch, next.pitch),
     }
 }
 
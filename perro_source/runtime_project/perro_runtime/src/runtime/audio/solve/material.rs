@@ -93,6 +93,7 @@ impl Runtime {
             SceneNodeData::StaticBody3D(v) => v.audio_interaction.map(|audio| audio.material),
             SceneNodeData::RigidBody2D(v) => v.audio_interaction.map(|audio| audio.material),
             SceneNodeData::RigidBody3D(v) => v.audio_interaction.map(|audio| audio.material),
+            SceneNodeData::VehicleBody3D(v) => v.audio_interaction.map(|audio| audio.material),
             SceneNodeData::CharacterBody2D(v) => v.audio_interaction.map(|audio| audio.material),
             SceneNodeData::CharacterBody3D(v) => v.audio_interaction.map(|audio| audio.material),
             SceneNodeData::Area2D(v) => v.audio_interaction.map(|audio| audio.material),
@@ -124,6 +125,10 @@ impl Runtime {
                 .audio_interaction
                 .map(|audio| audio.diffusion)
                 .unwrap_or_default(),
+            SceneNodeData::VehicleBody3D(v) => v
+                .audio_interaction
+                .map(|audio| audio.diffusion)
+                .unwrap_or_default(),
             SceneNodeData::CharacterBody2D(v) => v
                 .audio_interaction
                 .map(|audio| audio.diffusion)
@@ -11,6 +11,7 @@ impl Runtime {
     ) -> Option<PropagationResult> {
         let listener_pos = Vector2::new(listener.position[0], listener.position[1]);
         let range = sound.options.range.max(0.0001);
+        let curve = sound.options.attenuation;
         let distance = listener_pos.distance_to(source_pos);
         if distance > range.min(self.audio.config.listener_max_distance) {
             return None;
@@ -20,7 +21,7 @@ impl Runtime {
         } else {
             None
         };
-        let direct_attenuation = distance_attenuation(distance, range);
+        let direct_attenuation = distance_attenuation(curve, distance, range);
         let mut attenuation =
             direct_attenuation * self.emitter_attenuation_2d(sound, source_pos, listener_pos);
         let unoccluded_attenuation = attenuation;
@@ -125,7 +126,7 @@ impl Runtime {
                 self.best_audio_portal_2d(source_pos, listener_pos, sound.options.audio_layer)
         {
             let portal_strength = path.strength.clamp(0.0, 1.0);
-            let portal_attenuation = distance_attenuation(path.distance, range);
+            let portal_attenuation = distance_attenuation(curve, path.distance, range);
             attenuation = attenuation.max(portal_attenuation * (0.65 + portal_strength * 0.35));
             low_pass *= 1.0 - portal_strength * 0.75;
             occlusion *= 1.0 - portal_strength * 0.75;
@@ -140,7 +141,8 @@ impl Runtime {
                 range,
             )
         {
-            let bounce_attenuation = distance_attenuation(path.distance, range) * path.volume;
+            let bounce_attenuation =
+                distance_attenuation(curve, path.distance, range) * path.volume;
             if bounce_attenuation > attenuation {
                 attenuation = bounce_attenuation;
                 perceived = path.perceived;
@@ -188,7 +190,7 @@ impl Runtime {
                 }
             };
             if let Some((point, total, loss)) = aperture {
-                let recon_attenuation = distance_attenuation(total, range) * loss;
+                let recon_attenuation = distance_attenuation(curve, total, range) * loss;
                 if recon_attenuation > attenuation {
                     attenuation = recon_attenuation;
                     perceived = point;
@@ -250,6 +252,7 @@ impl Runtime {
             occlusion,
             perceived_2d: Some(perceived),
             perceived_3d: None,
+            pitch: 1.0,
         };
         if hit.is_none() {
             self.queue_audio_debug_ray_2d(listener_pos, perceived, AUDIO_DEBUG_DIRECT, attenuation);
@@ -271,12 +274,15 @@ impl Runtime {
             listener.position[2],
         );
         let range = sound.options.range.max(0.0001);
+        let curve = sound.options.attenuation;
         let distance = listener_pos.distance_to(source_pos);
+        // Track distance even out of range so re-entry has no stale delta.
+        let pitch = doppler_pitch(sound, distance);
         if distance > range.min(self.audio.config.listener_max_distance) {
             return None;
         }
         let dir = listener_pos.direction_to(source_pos);
-        let mut attenuation = distance_attenuation(distance, range)
+        let mut attenuation = distance_attenuation(curve, distance, range)
             * self.emitter_attenuation_3d(sound, source_pos, listener_pos);
         let unoccluded_attenuation = attenuation;
         let mut low_pass = 0.0;
@@ -382,7 +388,7 @@ impl Runtime {
             && let Some(path) = self.best_audio_portal_3d(source_pos, listener_pos)
         {
             let portal_strength = path.strength.clamp(0.0, 1.0);
-            let portal_attenuation = distance_attenuation(path.distance, range);
+            let portal_attenuation = distance_attenuation(curve, path.distance, range);
             attenuation = attenuation.max(portal_attenuation * (0.65 + portal_strength * 0.35));
             low_pass *= 1.0 - portal_strength * 0.75;
             occlusion *= 1.0 - portal_strength * 0.75;
@@ -397,7 +403,8 @@ impl Runtime {
                 range,
             )
         {
-            let bounce_attenuation = distance_attenuation(path.distance, range) * path.volume;
+            let bounce_attenuation =
+                distance_attenuation(curve, path.distance, range) * path.volume;
             if bounce_attenuation > attenuation {
                 attenuation = bounce_attenuation;
                 perceived = path.perceived;
@@ -443,7 +450,7 @@ impl Runtime {
                 }
             };
             if let Some((point, total, loss)) = aperture {
-                let recon_attenuation = distance_attenuation(total, range) * loss;
+                let recon_attenuation = distance_attenuation(curve, total, range) * loss;
                 if recon_attenuation > attenuation {
                     attenuation = recon_attenuation;
                     perceived = point;
@@ -508,6 +515,7 @@ impl Runtime {
             occlusion,
             perceived_2d: None,
             perceived_3d: Some(perceived),
+            pitch,
         };
         if hit.is_none() {
             self.queue_audio_debug_ray_3d(listener_pos, perceived, AUDIO_DEBUG_DIRECT, attenuation);
@@ -55,6 +55,8 @@ fn spatial_options(range: f32) -> SpatialAudioOptions {
         enable_propagation: true,
         direction_2d: AudioDirection::Omni,
         direction_3d: AudioDirection::Omni,
+        attenuation: AudioAttenuation::Quadratic,
+        doppler: 0.0,
     }
 }
 
@@ -168,6 +170,49 @@ fn directional_audio_3d_front_is_louder_than_back() {
     assert!(front_volume > back_volume * 4.0);
 }
 
+#[test]
+fn attenuation_curve_shapes_distance_falloff() {
+    let volume_for = |attenuation| {
+        let mut runtime = Runtime::new();
+        assert!(runtime.play_runtime_audio_3d(
+            looped_audio(),
+            Vector3::new(0.0, 0.0, -5.0),
+            SpatialAudioOptions {
+                attenuation,
+                ..spatial_options(10.0)
+            },
+        ));
+        runtime.update_audio_propagation(1.0);
+        runtime.audio.sounds[0].last_result.expect("result").volume
+    };
+    let linear = volume_for(AudioAttenuation::Linear);
+    let quadratic = volume_for(AudioAttenuation::Quadratic);
+    let constant = volume_for(AudioAttenuation::Constant);
+    assert!(linear > quadratic * 1.5);
+    assert!(constant > linear);
+}
+
+#[test]
+fn doppler_raises_pitch_for_approaching_3d_source() {
+    let pitch_after_approach = |doppler| {
+        let mut runtime = Runtime::new();
+        assert!(runtime.play_runtime_audio_3d(
+            looped_audio(),
+            Vector3::new(0.0, 0.0, -40.0),
+            SpatialAudioOptions {
+                doppler,
+                ..spatial_options(100.0)
+            },
+        ));
+        runtime.update_audio_propagation(1.0);
+        runtime.audio.sounds[0].pos = SpatialSoundPos::ThreeD(Vector3::new(0.0, 0.0, -20.0));
+        runtime.update_audio_propagation(1.0);
+        runtime.audio.sounds[0].last_result.expect("result").pitch
+    };
+    assert!(pitch_after_approach(1.0) > 1.0);
+    assert_eq!(pitch_after_approach(0.0), 1.0);
+}
+
 #[test]
 fn attached_directional_audio_uses_node_forward() {
     let mut runtime = Runtime::new();
@@ -246,6 +291,7 @@ fn resource_point_audio_preserves_spatial_options() {
             audio_layer: BitMask::from_bits(0x10),
             enable_propagation: false,
             direction: Some(AudioDirection::Directional(Vector2::new(2.0, 0.0))),
+            attenuation: AudioAttenuation::Linear,
         },
     ));
     runtime.update_audio_propagation(1.0);
@@ -253,6 +299,7 @@ fn resource_point_audio_preserves_spatial_options() {
     assert_eq!(sound.options.range, 24.0);
     assert_eq!(sound.options.audio_layer, BitMask::from_bits(0x10));
     assert!(!sound.options.enable_propagation);
+    assert_eq!(sound.options.attenuation, AudioAttenuation::Linear);
     assert_eq!(
         sound.options.direction_2d,
         AudioDirection::Directional(Vector2::new(1.0, 0.0))
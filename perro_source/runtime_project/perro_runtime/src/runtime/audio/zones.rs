@@ -427,6 +427,8 @@ impl Runtime {
                     },
                     from_start: audio.from_start,
                     from_end: audio.from_end,
+                    loop_start: 0.0,
+                    loop_end: 0.0,
                 })
             })
         });
@@ -449,6 +451,8 @@ impl Runtime {
             elapsed_since_prop: f32::MAX,
             remaining,
             last_result: None,
+            doppler_distance: None,
+            doppler_dt: 0.0,
             aperture_2d: None,
             aperture_3d: None,
             aperture_age: 0,
@@ -503,6 +507,8 @@ impl Runtime {
             elapsed_since_prop: f32::MAX,
             remaining,
             last_result: None,
+            doppler_distance: None,
+            doppler_dt: 0.0,
             aperture_2d: None,
             aperture_3d: None,
             aperture_age: 0,
@@ -546,6 +552,8 @@ impl Runtime {
             elapsed_since_prop: f32::MAX,
             remaining: None,
             last_result: None,
+            doppler_distance: None,
+            doppler_dt: 0.0,
             aperture_2d: None,
             aperture_3d: None,
             aperture_age: 0,
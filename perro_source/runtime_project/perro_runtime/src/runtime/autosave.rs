@@ -0,0 +1,149 @@
+use super::Runtime;
+use perro_runtime_api::sub_apis::{AUTOSAVE_GROUP, MIN_SAVE_GAP_SECS, NodeAPI, ScriptAPI};
+use perro_variant::Variant;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+/// `ctx.run.Save()` autosave: snapshot `autosave` group scripts every `interval`
+/// secs, write off-thread to `user://saves/<slot>.json`.
+#[derive(Default)]
+pub(crate) struct AutosaveState {
+    /// `None` when autosave is off
+    slot: Option<String>,
+    interval: f32,
+    /// secs since the last autosave write
+    elapsed: f32,
+    /// start of the last write; gates the min save gap
+    last_write: Option<Instant>,
+    /// set while a write job runs; the job clears it when done
+    writing: Arc<AtomicBool>,
+}
+
+impl AutosaveState {
+    fn can_write(&self, now: Instant) -> bool {
+        !self.writing.load(Ordering::Acquire)
+            && self
+                .last_write
+                .is_none_or(|last| now.duration_since(last).as_secs_f32() >= MIN_SAVE_GAP_SECS)
+    }
+}
+
+impl Runtime {
+    pub(crate) fn enable_autosave_slot(&mut self, interval: f32, slot: &str) -> bool {
+        if !is_valid_slot(slot) {
+            eprintln!("[perro][save] invalid autosave slot `{slot}`");
+            self.autosave.slot = None;
+            return false;
+        }
+        self.autosave.slot = Some(slot.to_string());
+        // NaN -> min gap too
+        self.autosave.interval = interval.max(MIN_SAVE_GAP_SECS);
+        self.autosave.elapsed = 0.0;
+        true
+    }
+
+    pub(crate) fn disable_autosave_slot(&mut self) {
+        self.autosave.slot = None;
+    }
+
+    #[inline]
+    pub(crate) fn is_autosave_enabled(&self) -> bool {
+        self.autosave.slot.is_some()
+    }
+
+    pub(crate) fn tick_autosave(&mut self, delta_time: f32) {
+        if self.autosave.slot.is_none() {
+            return;
+        }
+        self.autosave.elapsed += delta_time;
+        if self.autosave.elapsed < self.autosave.interval {
+            return;
+        }
+        // throttled or still writing -> keep elapsed, retry next frame
+        if self.write_autosave() {
+            self.autosave.elapsed = 0.0;
+        }
+    }
+
+    /// Snapshot + queue the slot write. false when off, throttled, or a write
+    /// is still running.
+    pub(crate) fn write_autosave(&mut self) -> bool {
+        let Some(slot) = self.autosave.slot.clone() else {
+            return false;
+        };
+        let now = Instant::now();
+        if !self.autosave.can_write(now) {
+            return false;
+        }
+        let snapshot = self.autosave_snapshot();
+        let bytes = match serde_json::to_vec_pretty(&snapshot.to_json_value()) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                eprintln!("[perro][save] failed to encode autosave `{slot}`: {err}");
+                return false;
+            }
+        };
+        self.autosave.last_write = Some(now);
+        self.autosave.writing.store(true, Ordering::Release);
+        let path = format!("user://saves/{slot}.json");
+        let writing = Arc::clone(&self.autosave.writing);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        rayon::spawn(move || {
+            write_slot(&path, &bytes);
+            writing.store(false, Ordering::Release);
+        });
+        #[cfg(target_arch = "wasm32")]
+        {
+            write_slot(&path, &bytes);
+            writing.store(false, Ordering::Release);
+        }
+        true
+    }
+
+    /// `{ node name: script_save_state payload }` 4 every scripted node in
+    /// the `autosave` group. ids r not stable across runs, names r.
+    pub(crate) fn autosave_snapshot(&mut self) -> Variant {
+        let mut ids = self.get_nodes_in_group(AUTOSAVE_GROUP);
+        ids.sort();
+        let mut out = BTreeMap::new();
+        for id in ids {
+            let saved = self.script_save_state(id);
+            if saved.is_null() {
+                continue;
+            }
+            let Some(name) = self.nodes.get(id).map(|node| node.name.clone()) else {
+                continue;
+            };
+            let key = Arc::<str>::from(name.as_ref());
+            if out.contains_key(&key) {
+                eprintln!("[perro][save] autosave skips duplicate node name `{key}`");
+                continue;
+            }
+            out.insert(key, saved);
+        }
+        Variant::Object(out)
+    }
+}
+
+fn is_valid_slot(slot: &str) -> bool {
+    !slot.is_empty()
+        && slot
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+}
+
+fn write_slot(path: &str, bytes: &[u8]) {
+    if let Err(err) = perro_io::save_asset_atomic(path, bytes) {
+        eprintln!("[perro][save] failed to write {path}: {err}");
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/unit/runtime_autosave_tests.rs"]
+mod tests;
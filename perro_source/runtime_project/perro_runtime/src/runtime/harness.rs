@@ -0,0 +1,266 @@
+//! Headless runtime driver for integration tests.
+//!
+//! Boots a project without a window, feeds synthetic input, steps whole
+//! frames with a fixed clock, and reads node/script state back through the
+//! same reflection API scripts use. Frames follow the windowed runner order:
+//! input events, `update`, catch-up `fixed_update`s (capped at
+//! `max_fixed_steps_per_frame`), window requests, then the pressed/released
+//! edge reset.
+
+use super::{Runtime, StaticScriptRegistry};
+use crate::runtime_project::{ProviderMode, RuntimeProject};
+use perro_ids::NodeID;
+use perro_input_api::{
+    GamepadAxis, GamepadButton, InputEvent, InputFrame, KeyCode, MouseButton, TouchPhase,
+    TouchPoint,
+};
+use perro_runtime_api::{
+    RuntimeWindow,
+    sub_apis::{IntoScriptMemberID, WindowRequest},
+};
+use perro_variant::Variant;
+use std::path::PathBuf;
+
+const DEFAULT_FIXED_DELTA: f32 = 1.0 / 60.0;
+
+pub struct RuntimeHarness {
+    runtime: Runtime,
+    frame_delta: f32,
+    fixed_delta: f32,
+    fixed_accumulator: f32,
+    max_fixed_steps: u32,
+    frame: u64,
+    pending_input: Vec<InputEvent>,
+    window_requests: Vec<WindowRequest>,
+}
+
+impl RuntimeHarness {
+    /// Boot `project` headlessly. Boot-scene failures return `Err` instead of
+    /// panicking like [`Runtime::from_project_with_script_registry`].
+    pub fn from_project(
+        project: RuntimeProject,
+        provider_mode: ProviderMode,
+        script_registry: Option<StaticScriptRegistry>,
+    ) -> Result<Self, String> {
+        let fixed_delta = project
+            .config
+            .target_fixed_update
+            .filter(|fps| *fps > 0.0)
+            .map(|fps| 1.0 / fps)
+            .unwrap_or(DEFAULT_FIXED_DELTA);
+        let max_fixed_steps = project.config.max_fixed_steps_per_frame.max(1);
+        let mut runtime = Runtime::from_project_unbooted(project, provider_mode, script_registry);
+        // Synthetic input is deliberate; skip the stale-key startup gate.
+        runtime.startup_input_clear_frames_left = 0;
+        // Tests must not read or write the player's saved bindings.
+        runtime.persist_input_bindings = false;
+        runtime.load_boot_scene()?;
+        Ok(Self {
+            runtime,
+            frame_delta: fixed_delta,
+            fixed_delta,
+            fixed_accumulator: 0.0,
+            max_fixed_steps,
+            frame: 0,
+            pending_input: Vec::new(),
+            window_requests: Vec::new(),
+        })
+    }
+
+    /// Boot the project at `project_root`, reading scenes and assets from disk
+    /// and scripts from `script_registry` (a game crate's `SCRIPT_REGISTRY`).
+    ///
+    /// The asset root is process-global, so one test binary should drive one
+    /// project.
+    pub fn from_project_dir(
+        project_root: impl Into<PathBuf>,
+        script_registry: StaticScriptRegistry,
+    ) -> Result<Self, String> {
+        let project =
+            RuntimeProject::from_project_dir(project_root).map_err(|err| err.to_string())?;
+        Self::from_project(project, ProviderMode::Static, Some(script_registry))
+    }
+
+    /// Seconds passed to `update` each frame. Defaults to the fixed step.
+    pub fn with_frame_delta(mut self, frame_delta: f32) -> Self {
+        self.frame_delta = frame_delta.max(0.0);
+        self
+    }
+
+    #[inline]
+    pub fn runtime(&self) -> &Runtime {
+        &self.runtime
+    }
+
+    #[inline]
+    pub fn runtime_mut(&mut self) -> &mut Runtime {
+        &mut self.runtime
+    }
+
+    /// Frames stepped since boot.
+    #[inline]
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    #[inline]
+    pub fn fixed_delta(&self) -> f32 {
+        self.fixed_delta
+    }
+
+    /// Queue a raw event for the next stepped frame.
+    pub fn send(&mut self, event: InputEvent) {
+        self.pending_input.push(event);
+    }
+
+    pub fn press_key(&mut self, key: KeyCode) {
+        self.send(InputEvent::Key { key, is_down: true });
+    }
+
+    pub fn release_key(&mut self, key: KeyCode) {
+        self.send(InputEvent::Key {
+            key,
+            is_down: false,
+        });
+    }
+
+    pub fn press_mouse(&mut self, button: MouseButton) {
+        self.send(InputEvent::MouseButton {
+            button,
+            is_down: true,
+        });
+    }
+
+    pub fn release_mouse(&mut self, button: MouseButton) {
+        self.send(InputEvent::MouseButton {
+            button,
+            is_down: false,
+        });
+    }
+
+    pub fn move_mouse(&mut self, x: f32, y: f32) {
+        self.send(InputEvent::MousePosition { x, y });
+    }
+
+    pub fn touch(&mut self, phase: TouchPhase, point: TouchPoint) {
+        self.send(InputEvent::Touch { phase, point });
+    }
+
+    pub fn type_text(&mut self, text: impl Into<String>) {
+        self.send(InputEvent::Text(text.into()));
+    }
+
+    pub fn press_gamepad(&mut self, index: usize, button: GamepadButton) {
+        self.send(InputEvent::GamepadButton {
+            index,
+            button,
+            is_down: true,
+        });
+    }
+
+    pub fn release_gamepad(&mut self, index: usize, button: GamepadButton) {
+        self.send(InputEvent::GamepadButton {
+            index,
+            button,
+            is_down: false,
+        });
+    }
+
+    pub fn set_gamepad_axis(&mut self, index: usize, axis: GamepadAxis, value: f32) {
+        self.send(InputEvent::GamepadAxis { index, axis, value });
+    }
+
+    /// Run one frame: queued input, `update`, then every fixed step that fits,
+    /// up to the project's `max_fixed_steps_per_frame`.
+    pub fn step(&mut self) {
+        let events = std::mem::take(&mut self.pending_input);
+        self.runtime.apply_input_frame(&InputFrame::new(events, 0));
+        self.runtime.update(self.frame_delta);
+        self.fixed_accumulator += self.frame_delta;
+        // Epsilon keeps `frame_delta == fixed_delta` at exactly one fixed step.
+        let mut steps = 0;
+        while self.fixed_accumulator + f32::EPSILON >= self.fixed_delta {
+            if steps == self.max_fixed_steps {
+                // Spiral guard: drop whole-step backlog, kp sub-step progress.
+                self.fixed_accumulator %= self.fixed_delta;
+                break;
+            }
+            self.runtime.fixed_update(self.fixed_delta);
+            self.fixed_accumulator = (self.fixed_accumulator - self.fixed_delta).max(0.0);
+            steps += 1;
+        }
+        self.runtime
+            .drain_window_requests(&mut self.window_requests);
+        self.runtime.begin_input_frame();
+        self.frame += 1;
+    }
+
+    pub fn step_frames(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.step();
+        }
+    }
+
+    /// Step until `done` holds, up to `max_frames`. Returns whether it held.
+    pub fn step_until(
+        &mut self,
+        max_frames: usize,
+        mut done: impl FnMut(&mut Self) -> bool,
+    ) -> bool {
+        for _ in 0..max_frames {
+            if done(self) {
+                return true;
+            }
+            self.step();
+        }
+        done(self)
+    }
+
+    /// First node named `name`, in arena order.
+    pub fn find_node(&self, name: &str) -> Option<NodeID> {
+        self.runtime
+            .nodes
+            .iter()
+            .find(|(_, node)| node.name.as_ref() == name)
+            .map(|(id, _)| id)
+    }
+
+    pub fn get_var<M: IntoScriptMemberID>(&mut self, script_id: NodeID, member: M) -> Variant {
+        self.run(|run| run.Scripts().get_var(script_id, member))
+    }
+
+    pub fn set_var<M: IntoScriptMemberID>(&mut self, script_id: NodeID, member: M, value: Variant) {
+        self.run(|run| run.Scripts().set_var(script_id, member, value));
+    }
+
+    pub fn call_method<M: IntoScriptMemberID>(
+        &mut self,
+        script_id: NodeID,
+        method: M,
+        params: &[Variant],
+    ) -> Variant {
+        self.run(|run| run.Scripts().call_method(script_id, method, params))
+    }
+
+    /// Full runtime API, as scripts see it through `ctx.run`.
+    pub fn run<V>(&mut self, f: impl FnOnce(&mut RuntimeWindow<'_, Runtime>) -> V) -> V {
+        let mut run = RuntimeWindow::new(&mut self.runtime);
+        f(&mut run)
+    }
+
+    /// Window requests collected across stepped frames.
+    #[inline]
+    pub fn window_requests(&self) -> &[WindowRequest] {
+        &self.window_requests
+    }
+
+    pub fn quit_requested(&self) -> bool {
+        self.window_requests
+            .iter()
+            .any(|request| matches!(request, WindowRequest::CloseApp))
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/unit/runtime_harness_tests.rs"]
+mod tests;
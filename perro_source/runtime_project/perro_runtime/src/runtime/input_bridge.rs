@@ -1,8 +1,13 @@
 use super::Runtime;
 use perro_input_api::{
-    GamepadAxis, GamepadButton, GamepadRumbleRequest, InputFrame, JoyConIndicatorRequest,
-    JoyConRumbleRequest, KeyCode, MouseButton, MouseMode, PlayerBinding, PlayerState,
+    GamepadAxis, GamepadButton, GamepadRumbleRequest, InputEvent, InputFrame,
+    JoyConIndicatorRequest, JoyConRumbleRequest, KeyCode, MouseButton, MouseMode, PlayerBinding,
+    PlayerState, RebindResult, TimedInputEvent, TouchPhase, TouchPoint,
 };
+use perro_runtime_api::sub_apis::ScriptAPI;
+use perro_variant::Variant;
+use std::collections::BTreeMap;
+use std::sync::Arc;
 
 impl Runtime {
     #[inline]
@@ -15,6 +20,7 @@ impl Runtime {
 
     #[inline]
     pub fn apply_input_frame(&mut self, frame: &InputFrame) {
+        self.stamp_input_event();
         frame.apply_to_snapshot(&mut self.input);
         self.clear_startup_keyboard_mouse();
         self.tick_startup_input_clear_frame();
@@ -32,21 +38,30 @@ impl Runtime {
 
     #[inline]
     pub fn set_key_state(&mut self, key: KeyCode, is_down: bool) {
+        self.stamp_input_event();
         self.input.set_key_state(key, is_down);
     }
 
     #[inline]
     pub fn push_text_input(&mut self, text: impl Into<String>) {
+        self.stamp_input_event();
         self.input.push_text_input(text);
     }
 
+    #[inline]
+    pub fn set_ime_preedit(&mut self, text: impl Into<String>) {
+        self.input.set_ime_preedit(text);
+    }
+
     #[inline]
     pub fn set_mouse_button_state(&mut self, button: MouseButton, is_down: bool) {
+        self.stamp_input_event();
         self.input.set_mouse_button_state(button, is_down);
     }
 
     #[inline]
     pub fn add_mouse_delta(&mut self, dx: f32, dy: f32) {
+        self.stamp_input_event();
         self.input.add_mouse_delta(dx, dy);
     }
 
@@ -57,14 +72,22 @@ impl Runtime {
 
     #[inline]
     pub fn add_mouse_wheel(&mut self, dx: f32, dy: f32) {
+        self.stamp_input_event();
         self.input.add_mouse_wheel(dx, dy);
     }
 
     #[inline]
     pub fn set_mouse_position(&mut self, x: f32, y: f32) {
+        self.stamp_input_event();
         self.input.set_mouse_position(x, y);
     }
 
+    #[inline]
+    pub fn set_touch(&mut self, phase: TouchPhase, point: TouchPoint) {
+        self.stamp_input_event();
+        self.input.set_touch(phase, point);
+    }
+
     #[inline]
     pub fn set_mouse_mode_state(&mut self, mode: MouseMode) {
         self.input.set_mouse_mode_state(mode);
@@ -148,6 +171,7 @@ impl Runtime {
 
     #[inline]
     pub fn set_gamepad_button_state(&mut self, index: usize, button: GamepadButton, is_down: bool) {
+        self.stamp_input_event();
         self.input.set_gamepad_button_state(index, button, is_down);
     }
 
@@ -166,6 +190,11 @@ impl Runtime {
         self.input.set_gamepad_accel(index, x, y, z);
     }
 
+    #[inline]
+    pub fn set_gamepad_rumble_supported(&mut self, index: usize, supported: bool) {
+        self.input.set_gamepad_rumble_supported(index, supported);
+    }
+
     #[inline]
     pub fn set_joycon_button_state(
         &mut self,
@@ -196,6 +225,11 @@ impl Runtime {
         self.input.set_joycon_connected(index, connected);
     }
 
+    #[inline]
+    pub fn set_joycon_rumble_supported(&mut self, index: usize, supported: bool) {
+        self.input.set_joycon_rumble_supported(index, supported);
+    }
+
     #[inline]
     pub fn set_joycon_calibrated(&mut self, index: usize, calibrated: bool) {
         self.input.set_joycon_calibrated(index, calibrated);
@@ -273,6 +307,26 @@ impl Runtime {
         self.input.clear_keyboard_mouse_state();
     }
 
+    /// Seconds since runtime start on the `on_input` event clock.
+    #[inline]
+    pub fn input_clock(&self) -> f64 {
+        self.input_epoch.elapsed().as_secs_f64()
+    }
+
+    /// Gate + timestamp the event log before device ingestion. Recording runs
+    /// only while an `on_input` script exists and the startup clear is done.
+    #[inline]
+    fn stamp_input_event(&mut self) {
+        let enabled =
+            self.scripts.input_script_count() > 0 && self.startup_input_clear_frames_left == 0;
+        if self.input.event_log_enabled() != enabled {
+            self.input.set_event_log_enabled(enabled);
+        }
+        if enabled {
+            self.input.set_event_time(self.input_clock());
+        }
+    }
+
     #[inline]
     fn tick_startup_input_clear_frame(&mut self) {
         if self.startup_input_clear_frames_left == 0 {
@@ -282,6 +336,134 @@ impl Runtime {
     }
 }
 
+/// Player bindings saved after live rebinds; applied over project defaults.
+pub(crate) const INPUT_BINDINGS_PATH: &str = "user://input_bindings.toml";
+
+impl Runtime {
+    /// Apply bindings saved by an earlier run. Missing file = project defaults.
+    pub(crate) fn load_saved_input_bindings(&mut self) {
+        if !self.persist_input_bindings {
+            return;
+        }
+        let Ok(bytes) = perro_io::load_asset(INPUT_BINDINGS_PATH) else {
+            return;
+        };
+        match std::str::from_utf8(&bytes) {
+            Ok(settings) => {
+                self.input.apply_binding_settings(settings);
+            }
+            Err(err) => eprintln!("[perro][input] ignoring {INPUT_BINDINGS_PATH}: {err}"),
+        }
+    }
+
+    /// Persist changed bindings and run rebind callbacks. Rebinds complete
+    /// during event ingestion; callbacks run here, inside `update`.
+    pub(crate) fn run_rebind_completions(&mut self) {
+        if self.input.take_bindings_changed() && self.persist_input_bindings {
+            let settings = self.input.input_map().bindings_to_settings();
+            if let Err(err) = perro_io::save_asset(INPUT_BINDINGS_PATH, settings.as_bytes()) {
+                eprintln!("[perro][input] failed to save {INPUT_BINDINGS_PATH}: {err}");
+            }
+        }
+        for (callback, result) in self.input.take_completed_rebinds() {
+            let Some(callback) = callback else {
+                continue;
+            };
+            let payload = rebind_result_variant(&result);
+            self.call_method(callback.script, callback.method, &[payload]);
+        }
+    }
+}
+
+/// Rebind callback payload: `action`, `binding` (saved form), `display`
+/// (menu label), and `conflicts` (other action names on that button).
+pub(crate) fn rebind_result_variant(result: &RebindResult) -> Variant {
+    let mut object = BTreeMap::<Arc<str>, Variant>::new();
+    object.insert("action".into(), Variant::from(result.action.as_str()));
+    object.insert(
+        "binding".into(),
+        Variant::from(result.binding.setting_name().as_str()),
+    );
+    object.insert(
+        "display".into(),
+        Variant::from(result.binding.display_name().as_str()),
+    );
+    object.insert(
+        "conflicts".into(),
+        Variant::Array(
+            result
+                .conflicts
+                .iter()
+                .map(|name| Variant::from(name.as_str()))
+                .collect(),
+        ),
+    );
+    Variant::Object(object)
+}
+
+/// Script-facing `on_input` payload: a `Variant::Object` w/ `type`, `time`,
+/// and per-kind fields. `None` for events not recorded in the log.
+pub(crate) fn input_event_variant(event: &TimedInputEvent) -> Option<Variant> {
+    let mut object = BTreeMap::<Arc<str>, Variant>::new();
+    let kind = match &event.event {
+        InputEvent::Key { key, is_down } => {
+            object.insert("key".into(), Variant::from(key.as_name()));
+            object.insert("down".into(), Variant::from(*is_down));
+            "key"
+        }
+        InputEvent::Text(text) => {
+            object.insert("text".into(), Variant::from(text.as_str()));
+            "text"
+        }
+        InputEvent::MouseButton { button, is_down } => {
+            object.insert("button".into(), Variant::from(button.as_name()));
+            object.insert("down".into(), Variant::from(*is_down));
+            "mouse_button"
+        }
+        InputEvent::MouseDelta { dx, dy } => {
+            object.insert("dx".into(), Variant::from(*dx));
+            object.insert("dy".into(), Variant::from(*dy));
+            "mouse_motion"
+        }
+        InputEvent::MouseWheel { dx, dy } => {
+            object.insert("dx".into(), Variant::from(*dx));
+            object.insert("dy".into(), Variant::from(*dy));
+            "mouse_wheel"
+        }
+        InputEvent::MousePosition { x, y } => {
+            object.insert("x".into(), Variant::from(*x));
+            object.insert("y".into(), Variant::from(*y));
+            "mouse_position"
+        }
+        InputEvent::Touch { phase, point } => {
+            object.insert("id".into(), Variant::from(point.id));
+            object.insert("phase".into(), Variant::from(phase.as_name()));
+            object.insert("x".into(), Variant::from(point.position.x));
+            object.insert("y".into(), Variant::from(point.position.y));
+            object.insert("pressure".into(), Variant::from(point.pressure));
+            object.insert(
+                "altitude".into(),
+                point.altitude.map_or(Variant::Null, Variant::from),
+            );
+            "touch"
+        }
+        InputEvent::GamepadButton {
+            index,
+            button,
+            is_down,
+        } => {
+            object.insert("index".into(), Variant::from(*index as u64));
+            object.insert("button".into(), Variant::from(button.as_name()));
+            object.insert("down".into(), Variant::from(*is_down));
+            "gamepad_button"
+        }
+        _ => return None,
+    };
+    object.insert("type".into(), Variant::from(kind));
+    object.insert("time".into(), Variant::from(event.time));
+    Some(Variant::Object(object))
+}
+
 #[cfg(test)]
 mod tests {
     use super::Runtime;
@@ -19,6 +19,7 @@ impl Runtime {
         self.register_physics_body(id, ty);
         self.register_button_2d(id, ty);
         self.register_physics_joint(id, ty);
+        self.register_physics_vehicle(id, ty);
         self.register_internal_fixed_dispatch(id, ty);
         if matches!(ty.get_internal_update(), InternalUpdate::True) {
             let slot = id.index() as usize;
@@ -59,14 +60,17 @@ impl Runtime {
             | NodeType::StaticBody3D
             | NodeType::Area3D
             | NodeType::RigidBody3D
+            | NodeType::VehicleBody3D
             | NodeType::CharacterBody3D
             | NodeType::WaterBody3D
             | NodeType::PinJoint2D
             | NodeType::DistanceJoint2D
             | NodeType::FixedJoint2D
+            | NodeType::SpringJoint2D
             | NodeType::BallJoint3D
             | NodeType::HingeJoint3D
-            | NodeType::FixedJoint3D => self.invalidate_physics_query_sync(),
+            | NodeType::FixedJoint3D
+            | NodeType::SpringJoint3D => self.invalidate_physics_query_sync(),
             _ => {}
         }
 
@@ -89,16 +93,27 @@ impl Runtime {
         }
 
         match ty {
-            NodeType::PinJoint2D | NodeType::DistanceJoint2D | NodeType::FixedJoint2D => {
+            NodeType::PinJoint2D
+            | NodeType::DistanceJoint2D
+            | NodeType::FixedJoint2D
+            | NodeType::SpringJoint2D => {
                 self.internal_updates
                     .physics_joint_nodes_2d
                     .retain(|&node_id| node_id != id);
             }
-            NodeType::BallJoint3D | NodeType::HingeJoint3D | NodeType::FixedJoint3D => {
+            NodeType::BallJoint3D
+            | NodeType::HingeJoint3D
+            | NodeType::FixedJoint3D
+            | NodeType::SpringJoint3D => {
                 self.internal_updates
                     .physics_joint_nodes_3d
                     .retain(|&node_id| node_id != id);
             }
+            NodeType::VehicleBody3D => {
+                self.internal_updates
+                    .physics_vehicle_nodes_3d
+                    .retain(|&node_id| node_id != id);
+            }
             _ => {}
         }
 
@@ -174,6 +189,7 @@ impl Runtime {
         self.internal_updates.physics_body_nodes_3d.clear();
         self.internal_updates.physics_joint_nodes_2d.clear();
         self.internal_updates.physics_joint_nodes_3d.clear();
+        self.internal_updates.physics_vehicle_nodes_3d.clear();
         self.internal_updates.physics_body_pos_2d.clear();
         self.internal_updates.physics_body_pos_3d.clear();
         self.internal_updates.button_nodes_2d.clear();
@@ -198,12 +214,14 @@ impl Runtime {
 
     fn register_physics_joint(&mut self, id: NodeID, ty: NodeType) {
         let nodes = match ty {
-            NodeType::PinJoint2D | NodeType::DistanceJoint2D | NodeType::FixedJoint2D => {
-                &mut self.internal_updates.physics_joint_nodes_2d
-            }
-            NodeType::BallJoint3D | NodeType::HingeJoint3D | NodeType::FixedJoint3D => {
-                &mut self.internal_updates.physics_joint_nodes_3d
-            }
+            NodeType::PinJoint2D
+            | NodeType::DistanceJoint2D
+            | NodeType::FixedJoint2D
+            | NodeType::SpringJoint2D => &mut self.internal_updates.physics_joint_nodes_2d,
+            NodeType::BallJoint3D
+            | NodeType::HingeJoint3D
+            | NodeType::FixedJoint3D
+            | NodeType::SpringJoint3D => &mut self.internal_updates.physics_joint_nodes_3d,
             _ => return,
         };
         if !nodes.contains(&id) {
@@ -211,6 +229,15 @@ impl Runtime {
         }
     }
 
+    fn register_physics_vehicle(&mut self, id: NodeID, ty: NodeType) {
+        if ty != NodeType::VehicleBody3D {
+            return;
+        }
+        if !self.internal_updates.physics_vehicle_nodes_3d.contains(&id) {
+            self.internal_updates.physics_vehicle_nodes_3d.push(id);
+        }
+    }
+
     fn register_physics_body(&mut self, id: NodeID, ty: NodeType) {
         match ty {
             NodeType::StaticBody2D
@@ -234,6 +261,7 @@ impl Runtime {
             NodeType::StaticBody3D
             | NodeType::Area3D
             | NodeType::RigidBody3D
+            | NodeType::VehicleBody3D
             | NodeType::CharacterBody3D
             | NodeType::WaterBody3D => {
                 let slot = id.index() as usize;
@@ -366,7 +394,7 @@ impl Runtime {
             std::mem::take(&mut self.internal_updates.internal_update_dispatch_scratch);
         snapshot_dispatch(&self.internal_updates.internal_update_nodes, &mut dispatch);
         for id in dispatch.iter().copied() {
-            if self.nodes.get(id).is_none() || self.is_suspended_by_sub_view(id) {
+            if self.nodes.get(id).is_none() || self.is_suspended(id) {
                 continue;
             }
             self.call_internal_update_node_with_context(id, &res, &ipt);
@@ -396,7 +424,7 @@ impl Runtime {
             &mut dispatch,
         );
         for id in dispatch.iter().copied() {
-            if self.nodes.get(id).is_none() || self.is_suspended_by_sub_view(id) {
+            if self.nodes.get(id).is_none() || self.is_suspended(id) {
                 continue;
             }
             self.call_internal_fixed_update_node_with_context(id, &res, &ipt);
@@ -0,0 +1,56 @@
+use super::Runtime;
+use ahash::AHashSet;
+use perro_ids::NodeID;
+
+impl Runtime {
+    /// Enable or disable a node and its subtree. Disabled nodes skip script
+    /// callbacks, internal node updates, physics, and rendering. `false`
+    /// when `node` does not exist.
+    pub(crate) fn set_node_enabled(&mut self, node: NodeID, enabled: bool) -> bool {
+        if node.is_nil() || self.nodes.get(node).is_none() {
+            return false;
+        }
+        let changed = if enabled {
+            self.disabled_nodes.remove(&node)
+        } else {
+            self.disabled_nodes.insert(node)
+        };
+        if changed {
+            // body descs cache on physics_revision; suspension feeds `enabled`.
+            self.nodes.mark_physics_change();
+            self.force_rerender(node);
+        }
+        true
+    }
+
+    /// The node's own flag; ancestors are not checked.
+    pub(crate) fn is_node_enabled(&self, node: NodeID) -> bool {
+        !node.is_nil() && self.nodes.get(node).is_some() && !self.disabled_nodes.contains(&node)
+    }
+
+    pub(crate) fn is_in_disabled_subtree(&self, node: NodeID) -> bool {
+        if self.disabled_nodes.is_empty() {
+            return false;
+        }
+        let mut current = node;
+        let mut hops = 0usize;
+        let max_hops = self.nodes.len().saturating_add(1);
+        while !current.is_nil() && hops < max_hops {
+            if self.disabled_nodes.contains(&current) {
+                return true;
+            }
+            let Some(scene_node) = self.nodes.get(current) else {
+                return false;
+            };
+            current = scene_node.parent;
+            hops += 1;
+        }
+        false
+    }
+
+    pub(crate) fn forget_removed_disabled_nodes(&mut self, removed: &AHashSet<NodeID>) {
+        if !self.disabled_nodes.is_empty() {
+            self.disabled_nodes.retain(|node| !removed.contains(node));
+        }
+    }
+}
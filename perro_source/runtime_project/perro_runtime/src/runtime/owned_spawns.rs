@@ -0,0 +1,78 @@
+use super::Runtime;
+use ahash::{AHashMap, AHashSet};
+use perro_ids::NodeID;
+
+/// Nodes a script spawned w/ `instantiate_owned!`, freed w/ their owner.
+/// Kept both ways so removing either side stays a hash lookup.
+#[derive(Default)]
+pub(crate) struct OwnedSpawnState {
+    by_owner: AHashMap<NodeID, Vec<NodeID>>,
+    owner_of: AHashMap<NodeID, NodeID>,
+}
+
+impl Runtime {
+    /// Tie `node`'s lifetime to `owner`: removing `owner` also removes
+    /// `node`. A nil `owner` releases it. `false` when either side does not
+    /// exist, or `owner` is `node`.
+    pub(crate) fn set_node_owner(&mut self, node: NodeID, owner: NodeID) -> bool {
+        if node.is_nil() || node == owner || self.nodes.get(node).is_none() {
+            return false;
+        }
+        if !owner.is_nil() && self.nodes.get(owner).is_none() {
+            return false;
+        }
+        self.release_owned_spawn(node);
+        if !owner.is_nil() {
+            let state = &mut self.owned_spawns;
+            state.owner_of.insert(node, owner);
+            state.by_owner.entry(owner).or_default().push(node);
+        }
+        true
+    }
+
+    pub(crate) fn node_owner(&self, node: NodeID) -> NodeID {
+        self.owned_spawns
+            .owner_of
+            .get(&node)
+            .copied()
+            .unwrap_or(NodeID::nil())
+    }
+
+    fn release_owned_spawn(&mut self, node: NodeID) {
+        let state = &mut self.owned_spawns;
+        let Some(owner) = state.owner_of.remove(&node) else {
+            return;
+        };
+        if let Some(owned) = state.by_owner.get_mut(&owner) {
+            owned.retain(|id| *id != node);
+            if owned.is_empty() {
+                state.by_owner.remove(&owner);
+            }
+        }
+    }
+
+    /// Drop ownership records for a removed subtree and return the spawns
+    /// its nodes owned that live outside it; the caller removes those next.
+    pub(crate) fn take_orphaned_spawns(&mut self, removed: &AHashSet<NodeID>) -> Vec<NodeID> {
+        if self.owned_spawns.owner_of.is_empty() {
+            return Vec::new();
+        }
+        let mut orphaned = Vec::new();
+        for node in removed {
+            self.release_owned_spawn(*node);
+            if let Some(owned) = self.owned_spawns.by_owner.remove(node) {
+                for spawn in owned {
+                    self.owned_spawns.owner_of.remove(&spawn);
+                    if !removed.contains(&spawn) {
+                        orphaned.push(spawn);
+                    }
+                }
+            }
+        }
+        orphaned
+    }
+
+    pub(crate) fn clear_owned_spawns(&mut self) {
+        self.owned_spawns = OwnedSpawnState::default();
+    }
+}
@@ -57,14 +57,39 @@ pub(crate) struct StagedBodyPose3D {
 const CHARACTER_MOVE_MARGIN: f32 = 0.005;
 /// max slide iterations per move_and_slide call
 const MAX_SLIDE_ITERATIONS: usize = 4;
+/// char floor tests compare hit normals vs this (2d gravity pulls -y)
+const FLOOR_UP_2D: Vector2 = Vector2::new(0.0, 1.0);
+pub(crate) use layer_names::{install_physics_layer_names, physics_layer_2d, physics_layer_3d};
 pub(crate) use perro_physics::{AudioRaycastInput, AudioRaycastResult};
 
+mod area_gravity;
 mod forces;
+mod joints;
+mod layer_names;
 mod queries;
 mod signals;
 mod step;
+mod vehicles;
 mod world_sync;
 
+/// rigid body behind a 3d node: RigidBody3D itself or a VehicleBody3D chassis.
+pub(crate) fn rigid_body_3d(data: &SceneNodeData) -> Option<&perro_nodes::RigidBody3D> {
+    match data {
+        SceneNodeData::RigidBody3D(body) => Some(body),
+        SceneNodeData::VehicleBody3D(vehicle) => Some(&vehicle.base),
+        _ => None,
+    }
+}
+
+/// mut twin of rigid_body_3d.
+pub(crate) fn rigid_body_3d_mut(data: &mut SceneNodeData) -> Option<&mut perro_nodes::RigidBody3D> {
+    match data {
+        SceneNodeData::RigidBody3D(body) => Some(body),
+        SceneNodeData::VehicleBody3D(vehicle) => Some(&mut vehicle.base),
+        _ => None,
+    }
+}
+
 fn body_sync_same_2d(
     state: &BodyState2D,
     position: Vector2,
@@ -0,0 +1,164 @@
+use super::*;
+use perro_nodes::AreaGravityMode;
+use std::cmp::Reverse;
+
+/// (priority, area, mode, gravity) of an overlapping gravity area. sorts so
+/// the area that applies first comes first; ties go by id.
+type AreaGravity<V> = (Reverse<i32>, NodeID, AreaGravityMode, V);
+
+impl Runtime {
+    /// rigid bodies inside gravity areas: world gravity already acts in the
+    /// step, so queue the diff (area g - world g) as a force. overlaps r frm
+    /// last step's area signals.
+    pub(super) fn queue_area_gravity_2d(&mut self) {
+        if self.physics.active_area_overlaps_2d.is_empty() {
+            return;
+        }
+        let mut bodies: Vec<NodeID> = self
+            .physics
+            .active_area_overlaps_2d
+            .iter()
+            .map(|overlap| overlap.other)
+            .collect();
+        bodies.sort_unstable();
+        bodies.dedup();
+        let world_g = Vector2::new(0.0, self.physics_gravity_raw());
+        for body in bodies {
+            let Some(scale) = self.nodes.get(body).and_then(|node| match &node.data {
+                SceneNodeData::RigidBody2D(rigid) => Some(rigid.gravity_scale),
+                _ => None,
+            }) else {
+                continue;
+            };
+            let Some(gravity) = self.area_gravity_2d(body) else {
+                continue;
+            };
+            let Some(mass) = self.physics.world_2d.as_ref().and_then(|world| {
+                world
+                    .body_map
+                    .get(&body)
+                    .and_then(|state| world.bodies.get(state.handle))
+                    .map(|rb| rb.mass())
+            }) else {
+                continue;
+            };
+            self.physics
+                .queue_force_2d(body, (gravity - world_g) * (mass * scale));
+        }
+    }
+
+    /// 3d twin of queue_area_gravity_2d.
+    pub(super) fn queue_area_gravity_3d(&mut self) {
+        if self.physics.active_area_overlaps_3d.is_empty() {
+            return;
+        }
+        let mut bodies: Vec<NodeID> = self
+            .physics
+            .active_area_overlaps_3d
+            .iter()
+            .map(|overlap| overlap.other)
+            .collect();
+        bodies.sort_unstable();
+        bodies.dedup();
+        let world_g = Vector3::new(0.0, self.physics_gravity_raw(), 0.0);
+        for body in bodies {
+            let Some(scale) = self.nodes.get(body).and_then(|node| match &node.data {
+                SceneNodeData::RigidBody3D(rigid) => Some(rigid.gravity_scale),
+                SceneNodeData::VehicleBody3D(vehicle) => Some(vehicle.gravity_scale),
+                _ => None,
+            }) else {
+                continue;
+            };
+            let Some(gravity) = self.area_gravity_3d(body) else {
+                continue;
+            };
+            let Some(mass) = self.physics.world_3d.as_ref().and_then(|world| {
+                world
+                    .body_map
+                    .get(&body)
+                    .and_then(|state| world.bodies.get(state.handle))
+                    .map(|rb| rb.mass())
+            }) else {
+                continue;
+            };
+            self.physics
+                .queue_force_3d(body, (gravity - world_g) * (mass * scale));
+        }
+    }
+
+    /// gravity (pre physics_coef) acting on `body` frm the gravity areas it
+    /// overlaps; None when no area overrides it.
+    pub(super) fn area_gravity_2d(&self, body: NodeID) -> Option<Vector2> {
+        let mut areas: Vec<AreaGravity<Vector2>> = self
+            .physics
+            .active_area_overlaps_2d
+            .iter()
+            .filter(|overlap| overlap.other == body)
+            .filter_map(|overlap| match &self.nodes.get(overlap.area)?.data {
+                SceneNodeData::Area2D(area)
+                    if area.enabled && area.gravity_mode != AreaGravityMode::Disabled =>
+                {
+                    Some((
+                        Reverse(area.priority),
+                        overlap.area,
+                        area.gravity_mode,
+                        area.gravity,
+                    ))
+                }
+                _ => None,
+            })
+            .collect();
+        if areas.is_empty() {
+            return None;
+        }
+        areas.sort_unstable_by_key(|(priority, id, _, _)| (*priority, *id));
+        let world_g = Vector2::new(0.0, self.physics_gravity_raw());
+        Some(combine_area_gravity(&areas, world_g))
+    }
+
+    /// 3d twin of area_gravity_2d.
+    pub(super) fn area_gravity_3d(&self, body: NodeID) -> Option<Vector3> {
+        let mut areas: Vec<AreaGravity<Vector3>> = self
+            .physics
+            .active_area_overlaps_3d
+            .iter()
+            .filter(|overlap| overlap.other == body)
+            .filter_map(|overlap| match &self.nodes.get(overlap.area)?.data {
+                SceneNodeData::Area3D(area)
+                    if area.enabled && area.gravity_mode != AreaGravityMode::Disabled =>
+                {
+                    Some((
+                        Reverse(area.priority),
+                        overlap.area,
+                        area.gravity_mode,
+                        area.gravity,
+                    ))
+                }
+                _ => None,
+            })
+            .collect();
+        if areas.is_empty() {
+            return None;
+        }
+        areas.sort_unstable_by_key(|(priority, id, _, _)| (*priority, *id));
+        let world_g = Vector3::new(0.0, self.physics_gravity_raw(), 0.0);
+        Some(combine_area_gravity(&areas, world_g))
+    }
+}
+
+/// walk areas high -> low priority: combine adds, replace adds + stops.
+/// world gravity only counts when no replace area was reached.
+fn combine_area_gravity<V>(areas: &[AreaGravity<V>], world_g: V) -> V
+where
+    V: Copy + std::ops::Add<Output = V>,
+{
+    let mut gravity: Option<V> = None;
+    for &(_, _, mode, area_g) in areas {
+        let sum = gravity.map_or(area_g, |g| g + area_g);
+        if mode == AreaGravityMode::Replace {
+            return sum;
+        }
+        gravity = Some(sum);
+    }
+    gravity.map_or(world_g, |g| g + world_g)
+}
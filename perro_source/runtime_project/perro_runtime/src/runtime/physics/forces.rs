@@ -155,9 +155,7 @@ impl Runtime {
                 continue;
             };
             let Some((layers, mask)) = self.nodes.get(id).and_then(|node| {
-                let SceneNodeData::RigidBody3D(body) = &node.data else {
-                    return None;
-                };
+                let body = rigid_body_3d(&node.data)?;
                 Some((body.collision_layers, body.collision_mask))
             }) else {
                 continue;
@@ -518,9 +516,7 @@ impl Runtime {
             };
             let Some((velocity, mass, density, collision_layers, collision_mask)) =
                 self.nodes.get(body_id).and_then(|scene_node| {
-                    let SceneNodeData::RigidBody3D(body) = &scene_node.data else {
-                        return None;
-                    };
+                    let body = rigid_body_3d(&scene_node.data)?;
                     Some((
                         body.linear_velocity,
                         body.mass,
@@ -0,0 +1,157 @@
+use super::*;
+
+impl Runtime {
+    /// joints w/ a break threshold: disable once last step's constraint
+    /// force / torque passes it, then emit `<Name>_Broken`. runs aft the step
+    /// so rapier impulses r fresh. joint leaves the world here; the step
+    /// re-records the sync revision aft this, so next sync wont catch it.
+    pub(super) fn break_overloaded_joints_2d(&mut self) {
+        let Some(world) = self.physics.world_2d.as_ref() else {
+            return;
+        };
+        let mut broken = Vec::new();
+        for &id in &self.internal_updates.physics_joint_nodes_2d {
+            let Some((break_force, break_torque)) =
+                self.nodes.get(id).and_then(|node| match &node.data {
+                    SceneNodeData::PinJoint2D(joint) if joint.enabled => {
+                        Some((joint.break_force, joint.break_torque))
+                    }
+                    SceneNodeData::DistanceJoint2D(joint) if joint.enabled => {
+                        Some((joint.break_force, joint.break_torque))
+                    }
+                    SceneNodeData::FixedJoint2D(joint) if joint.enabled => {
+                        Some((joint.break_force, joint.break_torque))
+                    }
+                    SceneNodeData::SpringJoint2D(joint) if joint.enabled => {
+                        Some((joint.break_force, joint.break_torque))
+                    }
+                    _ => None,
+                })
+            else {
+                continue;
+            };
+            if break_force <= 0.0 && break_torque <= 0.0 {
+                continue;
+            }
+            let Some((force, torque)) = joint_load_2d(world, id) else {
+                continue;
+            };
+            if exceeds_break(force, break_force) || exceeds_break(torque, break_torque) {
+                broken.push(id);
+            }
+        }
+        for id in broken {
+            if let Some(world) = self.physics.world_2d.as_mut() {
+                remove_joint_2d(world, id);
+            }
+            self.break_joint(id);
+        }
+    }
+
+    /// 3d twin of break_overloaded_joints_2d.
+    pub(super) fn break_overloaded_joints_3d(&mut self) {
+        let Some(world) = self.physics.world_3d.as_ref() else {
+            return;
+        };
+        let mut broken = Vec::new();
+        for &id in &self.internal_updates.physics_joint_nodes_3d {
+            let Some((break_force, break_torque)) =
+                self.nodes.get(id).and_then(|node| match &node.data {
+                    SceneNodeData::BallJoint3D(joint) if joint.enabled => {
+                        Some((joint.break_force, joint.break_torque))
+                    }
+                    SceneNodeData::HingeJoint3D(joint) if joint.enabled => {
+                        Some((joint.break_force, joint.break_torque))
+                    }
+                    SceneNodeData::FixedJoint3D(joint) if joint.enabled => {
+                        Some((joint.break_force, joint.break_torque))
+                    }
+                    SceneNodeData::SpringJoint3D(joint) if joint.enabled => {
+                        Some((joint.break_force, joint.break_torque))
+                    }
+                    _ => None,
+                })
+            else {
+                continue;
+            };
+            if break_force <= 0.0 && break_torque <= 0.0 {
+                continue;
+            }
+            let Some((force, torque)) = joint_load_3d(world, id) else {
+                continue;
+            };
+            if exceeds_break(force, break_force) || exceeds_break(torque, break_torque) {
+                broken.push(id);
+            }
+        }
+        for id in broken {
+            if let Some(world) = self.physics.world_3d.as_mut() {
+                remove_joint_3d(world, id);
+            }
+            self.break_joint(id);
+        }
+    }
+
+    /// disable joint `id` + emit `<Name>_Broken(joint, body_a, body_b)`.
+    fn break_joint(&mut self, id: NodeID) {
+        let Some(node) = self.nodes.get_mut_untracked(id) else {
+            return;
+        };
+        let (body_a, body_b) = match &mut node.data {
+            SceneNodeData::PinJoint2D(joint) => {
+                joint.enabled = false;
+                (joint.body_a, joint.body_b)
+            }
+            SceneNodeData::DistanceJoint2D(joint) => {
+                joint.enabled = false;
+                (joint.body_a, joint.body_b)
+            }
+            SceneNodeData::FixedJoint2D(joint) => {
+                joint.enabled = false;
+                (joint.body_a, joint.body_b)
+            }
+            SceneNodeData::SpringJoint2D(joint) => {
+                joint.enabled = false;
+                (joint.body_a, joint.body_b)
+            }
+            SceneNodeData::BallJoint3D(joint) => {
+                joint.enabled = false;
+                (joint.body_a, joint.body_b)
+            }
+            SceneNodeData::HingeJoint3D(joint) => {
+                joint.enabled = false;
+                (joint.body_a, joint.body_b)
+            }
+            SceneNodeData::FixedJoint3D(joint) => {
+                joint.enabled = false;
+                (joint.body_a, joint.body_b)
+            }
+            SceneNodeData::SpringJoint3D(joint) => {
+                joint.enabled = false;
+                (joint.body_a, joint.body_b)
+            }
+            _ => return,
+        };
+        if node.name.is_empty() {
+            return;
+        }
+        self.physics.signal_name_scratch.clear();
+        self.physics
+            .signal_name_scratch
+            .push_str(node.name.as_ref());
+        self.physics.signal_name_scratch.push_str("_Broken");
+        let signal_id = SignalID::from_string(&self.physics.signal_name_scratch);
+
+        let params = [
+            Variant::from(id),
+            Variant::from(body_a),
+            Variant::from(body_b),
+        ];
+        let _ = SignalAPI::signal_emit(self, signal_id, &params);
+    }
+}
+
+/// threshold 0 (or less) never breaks.
+fn exceeds_break(load: f32, threshold: f32) -> bool {
+    threshold > 0.0 && load > threshold
+}
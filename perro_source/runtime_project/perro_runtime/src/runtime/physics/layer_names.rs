@@ -0,0 +1,23 @@
+use crate::runtime_project::LayerNamesConfig;
+use std::sync::{OnceLock, RwLock};
+
+/// `[layer_names.*]` from the running project. Scene prepare runs off the
+/// runtime on worker threads, so names resolve through this table.
+fn layer_names() -> &'static RwLock<LayerNamesConfig> {
+    static NAMES: OnceLock<RwLock<LayerNamesConfig>> = OnceLock::new();
+    NAMES.get_or_init(|| RwLock::new(LayerNamesConfig::default()))
+}
+
+pub(crate) fn install_physics_layer_names(names: &LayerNamesConfig) {
+    if let Ok(mut slot) = layer_names().write() {
+        slot.clone_from(names);
+    }
+}
+
+pub(crate) fn physics_layer_2d(name: &str) -> Option<u8> {
+    layer_names().read().ok()?.physics_2d_layer(name)
+}
+
+pub(crate) fn physics_layer_3d(name: &str) -> Option<u8> {
+    layer_names().read().ok()?.physics_3d_layer(name)
+}
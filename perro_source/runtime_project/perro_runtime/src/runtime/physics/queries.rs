@@ -256,34 +256,42 @@ impl Runtime {
             SceneNodeData::StaticBody3D(body) => Some((BodyKind::Static, body.enabled, None)),
             SceneNodeData::Area3D(body) => Some((BodyKind::Area, body.enabled, None)),
             SceneNodeData::CharacterBody3D(body) => Some((BodyKind::Character, body.enabled, None)),
-            SceneNodeData::RigidBody3D(body) => Some((
-                BodyKind::Rigid,
-                body.enabled,
-                Some(RigidProps3D {
-                    enabled: body.enabled,
-                    can_sleep: body.can_sleep,
-                    mass: body.mass,
-                    density: body.density,
-                    continuous_collision_detection: body.continuous_collision_detection,
-                    linear_velocity: body.linear_velocity,
-                    angular_velocity: body.angular_velocity,
-                    gravity_scale: body.gravity_scale,
-                    linear_damping: body.linear_damping,
-                    angular_damping: body.angular_damping,
-                }),
-            )),
-            _ => None,
+            data => rigid_body_3d(data).map(|body| {
+                (
+                    BodyKind::Rigid,
+                    body.enabled,
+                    Some(RigidProps3D {
+                        enabled: body.enabled,
+                        can_sleep: body.can_sleep,
+                        mass: body.mass,
+                        density: body.density,
+                        continuous_collision_detection: body.continuous_collision_detection,
+                        linear_velocity: body.linear_velocity,
+                        angular_velocity: body.angular_velocity,
+                        gravity_scale: body.gravity_scale,
+                        linear_damping: body.linear_damping,
+                        angular_damping: body.angular_damping,
+                    }),
+                )
+            }),
         }
     }
 
     /// sweep along `motion`; on hit, project remainder onto hit plane +
     /// re-sweep, up to MAX_SLIDE_ITERATIONS. body only mv here, never by solver.
+    /// char bodies first ride their floor (platform carry), then snap down
+    /// onto it after sliding if they left it w/o moving up.
     pub fn physics_move_and_slide_2d(
         &mut self,
         body_id: NodeID,
         motion: Vector2,
         filter: &PhysicsQueryFilter,
     ) -> Option<PhysicsSlideResult2D> {
+        let floor_props = self.character_floor_props_2d(body_id);
+        if let Some((_, _, inherit)) = floor_props {
+            self.carry_character_on_floor_2d(body_id, inherit, filter);
+        }
+        let had_floor = self.character_floor_2d.contains_key(&body_id);
         let mut position = self.get_global_transform_2d(body_id)?.position;
         let mut remaining = motion;
         // hits move into the returned (API-owned) result -> no scratch reuse;
@@ -306,10 +314,47 @@ impl Runtime {
             let unconsumed = target - position;
             remaining = unconsumed - hit.normal * unconsumed.dot(hit.normal);
         }
+        let Some((max_angle, snap, _)) = floor_props else {
+            return Some(PhysicsSlideResult2D {
+                position,
+                remainder: remaining,
+                hits,
+                floor: None,
+            });
+        };
+        let min_floor_dot = max_angle.cos();
+        let mut floor = hits
+            .iter()
+            .rev()
+            .find(|hit| hit.normal.dot(FLOOR_UP_2D) >= min_floor_dot)
+            .map(|hit| hit.node);
+        if floor.is_none() && had_floor && snap > 0.0 && motion.y <= 0.0 {
+            let result = self.physics_move_body_2d(
+                body_id,
+                position - FLOOR_UP_2D * snap,
+                CHARACTER_MOVE_MARGIN,
+                filter,
+            )?;
+            match result.hit {
+                Some(hit) if hit.normal.dot(FLOOR_UP_2D) >= min_floor_dot => {
+                    position = result.position;
+                    floor = Some(hit.node);
+                }
+                // nothing to snap onto -> undo the probe
+                _ => {
+                    self.physics_move_body_2d(body_id, position, 0.0, filter)?;
+                }
+            }
+        }
+        // horizontal mv w/o a floor hit keeps the old floor (still on it)
+        if floor.is_some() || motion.y != 0.0 {
+            self.record_character_floor_2d(body_id, floor);
+        }
         Some(PhysicsSlideResult2D {
             position,
             remainder: remaining,
             hits,
+            floor: self.character_floor_2d.get(&body_id).map(|(node, _)| *node),
         })
     }
 
@@ -351,8 +396,9 @@ impl Runtime {
     }
 
     /// script-invoked engine gravity 4 char bodies. integrate internal fall
-    /// speed frm world gravity, sweep down, reset on ground hit. separate frm
-    /// move_and_slide: cal each step when engine gravity wanted, skip 4 custom.
+    /// speed frm world gravity (or y of an overlapping gravity area), sweep
+    /// down, reset on ground hit. separate frm move_and_slide: cal each step
+    /// when engine gravity wanted, skip 4 custom.
     pub fn physics_apply_gravity_2d(
         &mut self,
         body_id: NodeID,
@@ -360,14 +406,14 @@ impl Runtime {
         max_fall_speed: f32,
         filter: &PhysicsQueryFilter,
     ) -> Option<PhysicsMoveResult2D> {
-        let is_char = matches!(
-            self.nodes.get(body_id).map(|node| &node.data),
-            Some(SceneNodeData::CharacterBody2D(_))
-        );
-        if !is_char || !dt.is_finite() || dt <= 0.0 {
+        let (max_angle, _, inherit) = self.character_floor_props_2d(body_id)?;
+        if !dt.is_finite() || dt <= 0.0 {
             return None;
         }
-        let gravity = self.physics_gravity();
+        self.carry_character_on_floor_2d(body_id, inherit, filter);
+        let gravity = self
+            .area_gravity_2d(body_id)
+            .map_or_else(|| self.physics_gravity(), |g| g.y * self.physics_coef());
         let fall = self.character_fall_speed_2d.entry(body_id).or_insert(0.0);
         let limit = max_fall_speed.abs().max(0.001);
         *fall = (*fall + gravity * dt).clamp(-limit, limit);
@@ -378,12 +424,18 @@ impl Runtime {
         if result.is_none_or(|result| result.clipped) {
             self.character_fall_speed_2d.insert(body_id, 0.0);
         }
+        let floor = result
+            .and_then(|result| result.hit)
+            .filter(|hit| hit.normal.dot(FLOOR_UP_2D) >= max_angle.cos())
+            .map(|hit| hit.node);
+        self.record_character_floor_2d(body_id, floor);
         result
     }
 
     /// script-invoked engine gravity 4 char bodies. integrate internal fall
-    /// speed frm world gravity, sweep down, reset on ground hit. separate frm
-    /// move_and_slide: cal each step when engine gravity wanted, skip 4 custom.
+    /// speed frm world gravity (or y of an overlapping gravity area), sweep
+    /// down, reset on ground hit. separate frm move_and_slide: cal each step
+    /// when engine gravity wanted, skip 4 custom.
     pub fn physics_apply_gravity_3d(
         &mut self,
         body_id: NodeID,
@@ -398,7 +450,9 @@ impl Runtime {
         if !is_char || !dt.is_finite() || dt <= 0.0 {
             return None;
         }
-        let gravity = self.physics_gravity();
+        let gravity = self
+            .area_gravity_3d(body_id)
+            .map_or_else(|| self.physics_gravity(), |g| g.y * self.physics_coef());
         let fall = self.character_fall_speed_3d.entry(body_id).or_insert(0.0);
         let limit = max_fall_speed.abs().max(0.001);
         *fall = (*fall + gravity * dt).clamp(-limit, limit);
@@ -416,6 +470,66 @@ impl Runtime {
         result
     }
 
+    /// (floor_max_angle, floor_snap_distance, inherit_platform_velocity) 4
+    /// a 2d char body; None 4 any other node.
+    fn character_floor_props_2d(&self, id: NodeID) -> Option<(f32, f32, bool)> {
+        match &self.nodes.get(id)?.data {
+            SceneNodeData::CharacterBody2D(body) => Some((
+                body.floor_max_angle,
+                body.floor_snap_distance,
+                body.inherit_platform_velocity,
+            )),
+            _ => None,
+        }
+    }
+
+    /// mv char by however its floor moved/rotated since last recorded, then
+    /// re-snapshot floor pose so repeat calls in one step don't double-carry.
+    /// floor itself excluded frm the sweep: it already moved into/away frm us.
+    fn carry_character_on_floor_2d(
+        &mut self,
+        body_id: NodeID,
+        inherit: bool,
+        filter: &PhysicsQueryFilter,
+    ) {
+        let Some((floor, then)) = self.character_floor_2d.get(&body_id).copied() else {
+            return;
+        };
+        let Some(now) = self.get_global_transform_2d(floor) else {
+            self.character_floor_2d.remove(&body_id);
+            return;
+        };
+        self.character_floor_2d.insert(body_id, (floor, now));
+        if !inherit || now == then {
+            return;
+        }
+        let Some(global) = self.get_global_transform_2d(body_id) else {
+            return;
+        };
+        let offset = global.position - then.position;
+        let (sin, cos) = (now.rotation - then.rotation).sin_cos();
+        let carried = now.position
+            + Vector2::new(
+                offset.x * cos - offset.y * sin,
+                offset.x * sin + offset.y * cos,
+            );
+        let mut carry_filter = filter.clone();
+        carry_filter.exclude_nodes.push(floor);
+        self.physics_move_body_2d(body_id, carried, CHARACTER_MOVE_MARGIN, &carry_filter);
+    }
+
+    fn record_character_floor_2d(&mut self, body_id: NodeID, floor: Option<NodeID>) {
+        let entry = floor.and_then(|floor| Some((floor, self.get_global_transform_2d(floor)?)));
+        match entry {
+            Some(entry) => {
+                self.character_floor_2d.insert(body_id, entry);
+            }
+            None => {
+                self.character_floor_2d.remove(&body_id);
+            }
+        }
+    }
+
     /// kp last sweep hit per char body; solver narrow phase skip
     /// kinematic-vs-fixed pairs so contacts_* merge these in.
     /// new hit node -> emit collision signal at move time (char never
@@ -501,6 +615,12 @@ impl Runtime {
                 Some(SceneNodeData::CharacterBody3D(_))
             )
         });
+        self.character_floor_2d.retain(|id, (floor, _)| {
+            matches!(
+                nodes.get(*id).map(|node| &node.data),
+                Some(SceneNodeData::CharacterBody2D(_))
+            ) && nodes.get(*floor).is_some()
+        });
     }
 
     pub fn physics_contacts_2d(&mut self, body_id: NodeID) -> Vec<PhysicsContact2D> {
@@ -196,6 +196,12 @@ impl Runtime {
         for overlap in current.iter().copied() {
             if !previous.contains(&overlap) {
                 self.emit_area_signal(overlap.area, overlap.other, "Entered");
+                let action = if self.is_area_node(overlap.other) {
+                    "AreaEntered"
+                } else {
+                    "BodyEntered"
+                };
+                self.emit_area_signal(overlap.area, overlap.other, action);
             }
             self.emit_area_signal(overlap.area, overlap.other, "Occupied");
         }
@@ -203,6 +209,13 @@ impl Runtime {
         for overlap in previous.iter().copied() {
             if !current.contains(&overlap) {
                 self.emit_area_signal(overlap.area, overlap.other, "Exited");
+                // freed nodes report as bodies
+                let action = if self.is_area_node(overlap.other) {
+                    "AreaExited"
+                } else {
+                    "BodyExited"
+                };
+                self.emit_area_signal(overlap.area, overlap.other, action);
             }
         }
 
@@ -216,6 +229,13 @@ impl Runtime {
         }
     }
 
+    fn is_area_node(&self, id: NodeID) -> bool {
+        matches!(
+            self.nodes.get(id).map(|node| &node.data),
+            Some(SceneNodeData::Area2D(_) | SceneNodeData::Area3D(_))
+        )
+    }
+
     pub(super) fn emit_area_signal(&mut self, area: NodeID, other: NodeID, action: &str) {
         let signal_id = {
             let Some(node) = self.nodes.get(area) else {
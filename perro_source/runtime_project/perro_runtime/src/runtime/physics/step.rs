@@ -143,7 +143,10 @@ impl Runtime {
         self.queue_physics_force_emitters_3d();
         self.queue_water_forces_2d();
         self.queue_water_forces_3d();
+        self.queue_area_gravity_2d();
+        self.queue_area_gravity_3d();
         self.apply_pending_forces_and_impulses_parallel();
+        self.update_physics_vehicles_3d();
         let apply_forces_impulses = apply_forces_impulses_start.elapsed();
 
         let (step, sync_nodes, post_transforms) = if self.physics.can_skip_step() {
@@ -155,6 +158,8 @@ impl Runtime {
         } else {
             let step_start = Instant::now();
             self.step_worlds_parallel();
+            self.break_overloaded_joints_2d();
+            self.break_overloaded_joints_3d();
             let step = step_start.elapsed();
 
             let sync_nodes_start = Instant::now();
@@ -206,6 +211,7 @@ impl Runtime {
     pub(super) fn can_skip_physics_fixed_step_pre_sync(&self) -> bool {
         self.schedules.fixed_slots_empty()
             && !self.has_physics_joint_nodes()
+            && self.internal_updates.physics_vehicle_nodes_3d.is_empty()
             && self.physics_synced_node_revision_2d == Some(self.nodes.physics_revision())
             && self.physics_synced_node_revision_3d == Some(self.nodes.physics_revision())
             && (self.internal_updates.physics_body_nodes_2d.is_empty()
@@ -302,6 +308,7 @@ impl Runtime {
         self.physics.clear();
         self.character_fall_speed_2d.clear();
         self.character_fall_speed_3d.clear();
+        self.character_floor_2d.clear();
         self.character_sweep_hit_2d.clear();
         self.character_sweep_hit_3d.clear();
         self.invalidate_physics_query_sync();
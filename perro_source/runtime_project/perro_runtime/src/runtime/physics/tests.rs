@@ -3,10 +3,10 @@ use crate::runtime::render_2d::{
     ParsedTile2D, ParsedTileCollisionShape2D, ParsedTileset2D, TileSetShape2D,
 };
 use perro_nodes::{
-    Area2D, Area3D, CharacterBody2D, CharacterBody3D, CollisionShape2D, CollisionShape3D,
-    FixedJoint2D, FixedJoint3D, MeshInstance3D, RigidBody2D, RigidBody3D, Sprite2D, StaticBody2D,
-    StaticBody3D, UiSubView, WaterBody2D, WaterBody3D, WaterIdleMode, WaterShape,
-    WaterSurfaceParams,
+    Area2D, Area3D, AreaGravityMode, Camera3D, CharacterBody2D, CharacterBody3D, CollisionShape2D,
+    CollisionShape3D, FixedJoint2D, FixedJoint3D, MeshInstance3D, RigidBody2D, RigidBody3D,
+    Sprite2D, StaticBody2D, StaticBody3D, UiSubView, VehicleBody3D, VehicleWheel3D, WaterBody2D,
+    WaterBody3D, WaterIdleMode, WaterShape, WaterSurfaceParams,
 };
 use perro_runtime_api::sub_apis::PhysicsAPI;
 use perro_structs::CollisionPolicy;
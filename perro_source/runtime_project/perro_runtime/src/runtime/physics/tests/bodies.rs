@@ -663,4 +663,110 @@ mod bodies {
         );
     }
 
+    /// area w/ a big quad + a rigid circle inside it. ret (area_id, body_id).
+    fn body_in_gravity_area_2d(
+        runtime: &mut Runtime,
+        mode: AreaGravityMode,
+        gravity: Vector2,
+        priority: i32,
+    ) -> (NodeID, NodeID) {
+        let area = NodeAPI::create::<Area2D>(runtime);
+        let area_shape = NodeAPI::create::<CollisionShape2D>(runtime);
+        assert!(NodeAPI::reparent(runtime, area, area_shape));
+        if let Some(mut node) = runtime.nodes.get_mut(area_shape)
+            && let SceneNodeData::CollisionShape2D(shape) = &mut node.data
+        {
+            shape.shape = Shape2D::Quad {
+                width: 100.0,
+                height: 100.0,
+            };
+        }
+        if let Some(mut node) = runtime.nodes.get_mut(area)
+            && let SceneNodeData::Area2D(area) = &mut node.data
+        {
+            area.gravity_mode = mode;
+            area.gravity = gravity;
+            area.priority = priority;
+        }
+
+        let body = NodeAPI::create::<RigidBody2D>(runtime);
+        let body_shape = NodeAPI::create::<CollisionShape2D>(runtime);
+        assert!(NodeAPI::reparent(runtime, body, body_shape));
+        (area, body)
+    }
+
+    #[test]
+    fn area_2d_replace_gravity_pushes_rigid_body_up() {
+        let mut runtime = Runtime::new();
+        let (_, body) = body_in_gravity_area_2d(
+            &mut runtime,
+            AreaGravityMode::Replace,
+            Vector2::new(0.0, 9.81),
+            0,
+        );
+        for _ in 0..30 {
+            runtime.physics_fixed_step();
+        }
+
+        let velocity = runtime
+            .nodes
+            .get(body)
+            .and_then(|node| {
+                let SceneNodeData::RigidBody2D(body) = &node.data else {
+                    return None;
+                };
+                Some(body.linear_velocity)
+            })
+            .expect("body should exist");
+        assert!(velocity.y > 0.0);
+    }
+
+    #[test]
+    fn area_gravity_replace_stops_lower_priority_areas() {
+        let mut runtime = Runtime::new();
+        let (_, body) = body_in_gravity_area_2d(
+            &mut runtime,
+            AreaGravityMode::Combine,
+            Vector2::new(1.0, 0.0),
+            2,
+        );
+        let (zero_g, _) = body_in_gravity_area_2d(
+            &mut runtime,
+            AreaGravityMode::Replace,
+            Vector2::new(0.0, 5.0),
+            1,
+        );
+        let (heavy, _) = body_in_gravity_area_2d(
+            &mut runtime,
+            AreaGravityMode::Replace,
+            Vector2::new(0.0, -100.0),
+            0,
+        );
+        runtime.physics_fixed_step();
+
+        let gravity = runtime.area_gravity_2d(body).expect("body is in areas");
+        assert!(approx(gravity.x, 1.0));
+        assert!(approx(gravity.y, 5.0));
+
+        // drop the replace area: wind combines w/ the heavy area instead
+        if let Some(mut node) = runtime.nodes.get_mut(zero_g)
+            && let SceneNodeData::Area2D(area) = &mut node.data
+        {
+            area.gravity_mode = AreaGravityMode::Disabled;
+        }
+        let gravity = runtime.area_gravity_2d(body).expect("body is in areas");
+        assert!(approx(gravity.x, 1.0));
+        assert!(approx(gravity.y, -100.0));
+
+        for id in [zero_g, heavy] {
+            if let Some(mut node) = runtime.nodes.get_mut(id)
+                && let SceneNodeData::Area2D(area) = &mut node.data
+            {
+                area.gravity_mode = AreaGravityMode::Disabled;
+            }
+        }
+        let gravity = runtime.area_gravity_2d(body).expect("body is in wind");
+        assert!(approx(gravity.x, 1.0));
+        assert!(approx(gravity.y, runtime.physics_gravity_raw()));
+    }
 }
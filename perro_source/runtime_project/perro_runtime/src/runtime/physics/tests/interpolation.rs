@@ -132,6 +132,67 @@ mod interpolation {
         assert!(approx(render.position.x, 8.0));
     }
 
+    #[test]
+    fn interpolate_flag_blends_scripted_node_between_fixed_steps() {
+        let mut runtime = Runtime::new();
+        let sprite_id = NodeAPI::create::<Sprite2D>(&mut runtime);
+        runtime
+            .with_node_mut::<Sprite2D, _, _>(sprite_id, |sprite| {
+                sprite.interpolate = true;
+            })
+            .expect("sprite exists");
+        runtime.record_interpolated_node_poses();
+
+        runtime
+            .with_node_mut::<Sprite2D, _, _>(sprite_id, |sprite| {
+                sprite.transform.position = Vector2::new(10.0, 0.0);
+            })
+            .expect("sprite exists");
+        runtime.record_interpolated_node_poses();
+        runtime.set_physics_render_alpha(0.25);
+
+        let render = runtime
+            .get_render_global_transform_2d(sprite_id)
+            .expect("render transform");
+        assert!(approx(render.position.x, 2.5));
+
+        runtime
+            .with_node_mut::<Sprite2D, _, _>(sprite_id, |sprite| {
+                sprite.interpolate = false;
+            })
+            .expect("sprite exists");
+        runtime.record_interpolated_node_poses();
+        let render = runtime
+            .get_render_global_transform_2d(sprite_id)
+            .expect("render transform");
+        assert!(approx(render.position.x, 10.0));
+    }
+
+    #[test]
+    fn interpolate_flag_blends_camera_3d_between_fixed_steps() {
+        let mut runtime = Runtime::new();
+        let camera_id = NodeAPI::create::<Camera3D>(&mut runtime);
+        runtime
+            .with_node_mut::<Camera3D, _, _>(camera_id, |camera| {
+                camera.interpolate = true;
+            })
+            .expect("camera exists");
+        runtime.record_interpolated_node_poses();
+
+        runtime
+            .with_node_mut::<Camera3D, _, _>(camera_id, |camera| {
+                camera.transform.position = Vector3::new(0.0, 0.0, -8.0);
+            })
+            .expect("camera exists");
+        runtime.record_interpolated_node_poses();
+        runtime.set_physics_render_alpha(0.5);
+
+        let render = runtime
+            .get_render_global_transform_3d(camera_id)
+            .expect("render transform");
+        assert!(approx(render.position.z, -4.0));
+    }
+
     #[test]
     fn physics_2d_body_desc_carries_mass_and_density() {
         let mut runtime = Runtime::new();
@@ -657,4 +657,81 @@ mod joints_signals {
         );
     }
 
+    fn char_over_platform_2d(runtime: &mut Runtime, one_way: bool) -> (NodeID, NodeID) {
+        let floor_id = NodeAPI::create::<StaticBody2D>(runtime);
+        let floor_shape = NodeAPI::create::<CollisionShape2D>(runtime);
+        assert!(NodeAPI::reparent(runtime, floor_id, floor_shape));
+        if let Some(mut node) = runtime.nodes.get_mut(floor_shape)
+            && let SceneNodeData::CollisionShape2D(shape) = &mut node.data
+        {
+            shape.shape = Shape2D::Quad {
+                width: 20.0,
+                height: 1.0,
+            };
+            shape.one_way = one_way;
+        }
+
+        let char_id = NodeAPI::create::<CharacterBody2D>(runtime);
+        let char_shape = NodeAPI::create::<CollisionShape2D>(runtime);
+        assert!(NodeAPI::reparent(runtime, char_id, char_shape));
+        (char_id, floor_id)
+    }
+
+    #[test]
+    fn character_body_2d_passes_up_through_one_way_platform_and_lands_on_it() {
+        let mut runtime = Runtime::new();
+        let (char_id, floor_id) = char_over_platform_2d(&mut runtime, true);
+        assert!(NodeAPI::set_global_transform_2d(
+            &mut runtime,
+            char_id,
+            Transform2D::new(Vector2::new(0.0, -3.0), 0.0, Vector2::ONE),
+        ));
+        let filter = PhysicsQueryFilter::default();
+
+        let up = runtime
+            .physics_move_and_slide_2d(char_id, Vector2::new(0.0, 5.0), &filter)
+            .expect("jump result");
+        assert!(
+            up.hits.is_empty(),
+            "one-way platform must not block from below"
+        );
+        assert!(approx(up.position.y, 2.0), "y={}", up.position.y);
+
+        let down = runtime
+            .physics_move_and_slide_2d(char_id, Vector2::new(0.0, -5.0), &filter)
+            .expect("fall result");
+        assert!(
+            down.position.y < 1.2 && down.position.y > 0.9,
+            "char must land on one-way platform, y={}",
+            down.position.y
+        );
+        assert_eq!(down.floor, Some(floor_id));
+    }
+
+    #[test]
+    fn character_body_2d_rides_moving_platform() {
+        let mut runtime = Runtime::new();
+        let (char_id, floor_id) = char_over_platform_2d(&mut runtime, false);
+        assert!(NodeAPI::set_global_transform_2d(
+            &mut runtime,
+            char_id,
+            Transform2D::new(Vector2::new(0.0, 3.0), 0.0, Vector2::ONE),
+        ));
+        let filter = PhysicsQueryFilter::default();
+        let landed = runtime
+            .physics_move_and_slide_2d(char_id, Vector2::new(0.0, -5.0), &filter)
+            .expect("land result");
+        assert_eq!(landed.floor, Some(floor_id));
+
+        assert!(NodeAPI::set_global_transform_2d(
+            &mut runtime,
+            floor_id,
+            Transform2D::new(Vector2::new(3.0, 0.0), 0.0, Vector2::ONE),
+        ));
+        let carried = runtime
+            .physics_move_and_slide_2d(char_id, Vector2::new(0.0, -0.1), &filter)
+            .expect("carry result");
+        assert!(approx(carried.position.x, 3.0), "x={}", carried.position.x);
+        assert_eq!(carried.floor, Some(floor_id));
+    }
 }
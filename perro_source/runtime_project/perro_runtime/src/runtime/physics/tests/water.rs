@@ -624,6 +624,81 @@ mod water {
         assert_eq!(data.coupled_axes, r2::JointAxesMask::LIN_AXES);
     }
 
+    #[test]
+    fn physics_2d_pin_joint_applies_ordered_limits_and_motor() {
+        let joint = JointDesc2D {
+            id: NodeID::new(1),
+            body_a: NodeID::new(2),
+            body_b: NodeID::new(3),
+            anchor_a: Vector2::ZERO,
+            anchor_b: Vector2::ZERO,
+            enabled: true,
+            collide_connected: false,
+            kind: JointKind2D::Pin(JointAngularDesc {
+                limits: Some([0.5, -0.5]),
+                motor: Some(JointMotorDesc {
+                    target_velocity: 2.0,
+                    max_force: 10.0,
+                }),
+            }),
+            signature: 0,
+        };
+
+        let data = build_joint_2d(&joint);
+        let limits = data
+            .limits(r2::JointAxis::AngX)
+            .expect("pin joint should set angle limits");
+        assert_eq!(limits.min, -0.5);
+        assert_eq!(limits.max, 0.5);
+        let motor = data
+            .motor(r2::JointAxis::AngX)
+            .expect("pin joint should set a motor");
+        assert_eq!(motor.target_vel, 2.0);
+        assert_eq!(motor.max_force, 10.0);
+    }
+
+    #[test]
+    fn physics_2d_joint_breaks_past_break_force() {
+        let mut runtime = Runtime::new();
+
+        let anchor = NodeAPI::create::<StaticBody2D>(&mut runtime);
+        let body = NodeAPI::create::<RigidBody2D>(&mut runtime);
+        let shape = NodeAPI::create::<CollisionShape2D>(&mut runtime);
+        assert!(NodeAPI::reparent(&mut runtime, body, shape));
+        let joint = NodeAPI::create::<FixedJoint2D>(&mut runtime);
+
+        if let Some(mut node) = runtime.nodes.get_mut(shape)
+            && let SceneNodeData::CollisionShape2D(shape) = &mut node.data
+        {
+            shape.shape = Shape2D::Circle { radius: 0.5 };
+        }
+        if let Some(mut node) = runtime.nodes.get_mut(joint)
+            && let SceneNodeData::FixedJoint2D(joint_data) = &mut node.data
+        {
+            joint_data.body_a = anchor;
+            joint_data.body_b = body;
+            joint_data.break_force = 0.01;
+        }
+
+        runtime.time.fixed_delta = 1.0 / 60.0;
+        for _ in 0..3 {
+            runtime.physics_fixed_step();
+        }
+
+        let enabled = match runtime.nodes.get(joint).map(|node| &node.data) {
+            Some(SceneNodeData::FixedJoint2D(joint_data)) => joint_data.enabled,
+            _ => true,
+        };
+        assert!(!enabled, "gravity load should break the joint");
+        assert!(
+            runtime
+                .physics
+                .world_2d
+                .as_ref()
+                .is_none_or(|world| !world.joint_map.contains_key(&joint))
+        );
+    }
+
     #[test]
     fn physics_3d_fixed_joint_syncs_and_disables() {
         let mut runtime = Runtime::new();
@@ -674,4 +749,116 @@ mod water {
         );
     }
 
+    /// helper: 4-wheel vehicle dropped over a wide floor. ret (vehicle, wheels).
+    fn vehicle_over_floor_3d(runtime: &mut Runtime) -> (NodeID, Vec<NodeID>) {
+        let floor = NodeAPI::create::<StaticBody3D>(runtime);
+        let floor_shape = NodeAPI::create::<CollisionShape3D>(runtime);
+        assert!(NodeAPI::reparent(runtime, floor, floor_shape));
+        if let Some(mut node) = runtime.nodes.get_mut(floor_shape)
+            && let SceneNodeData::CollisionShape3D(shape) = &mut node.data
+        {
+            shape.shape = Shape3D::Cube {
+                size: Vector3::new(200.0, 1.0, 200.0),
+            };
+        }
+
+        let vehicle = NodeAPI::create::<VehicleBody3D>(runtime);
+        let chassis_shape = NodeAPI::create::<CollisionShape3D>(runtime);
+        assert!(NodeAPI::reparent(runtime, vehicle, chassis_shape));
+        if let Some(mut node) = runtime.nodes.get_mut(chassis_shape)
+            && let SceneNodeData::CollisionShape3D(shape) = &mut node.data
+        {
+            shape.shape = Shape3D::Cube {
+                size: Vector3::new(2.0, 0.5, 4.0),
+            };
+        }
+        assert!(NodeAPI::set_global_transform_3d(
+            runtime,
+            vehicle,
+            Transform3D::new(
+                Vector3::new(0.0, 1.5, 0.0),
+                Quaternion::IDENTITY,
+                Vector3::ONE
+            ),
+        ));
+
+        let mut wheels = Vec::new();
+        for (x, z) in [(-1.0, -1.5), (1.0, -1.5), (-1.0, 1.5), (1.0, 1.5)] {
+            let wheel = NodeAPI::create::<VehicleWheel3D>(runtime);
+            assert!(NodeAPI::reparent(runtime, vehicle, wheel));
+            if let Some(mut node) = runtime.nodes.get_mut(wheel)
+                && let SceneNodeData::VehicleWheel3D(wheel) = &mut node.data
+            {
+                wheel.transform.position = Vector3::new(x, -0.3, z);
+                wheel.steer = z < 0.0;
+            }
+            wheels.push(wheel);
+        }
+        (vehicle, wheels)
+    }
+
+    #[test]
+    fn vehicle_3d_settles_on_wheels_and_drives_forward() {
+        let mut runtime = Runtime::new();
+        let (vehicle, wheels) = vehicle_over_floor_3d(&mut runtime);
+        runtime.time.fixed_delta = 1.0 / 60.0;
+        for _ in 0..120 {
+            runtime.physics_fixed_step();
+        }
+
+        for &wheel in &wheels {
+            let in_contact = match runtime.nodes.get(wheel).map(|node| &node.data) {
+                Some(SceneNodeData::VehicleWheel3D(wheel)) => wheel.in_contact,
+                _ => false,
+            };
+            assert!(in_contact, "every wheel should rest on the floor");
+        }
+        let rest = runtime
+            .get_global_transform_3d(vehicle)
+            .expect("vehicle transform");
+        // chassis hangs on the suspension, clear of the floor top (y = 0.5)
+        assert!(rest.position.y > 0.9, "y={}", rest.position.y);
+
+        if let Some(mut node) = runtime.nodes.get_mut(vehicle)
+            && let SceneNodeData::VehicleBody3D(body) = &mut node.data
+        {
+            body.engine_force = 2000.0;
+        }
+        for _ in 0..60 {
+            runtime.physics_fixed_step();
+        }
+        let driven = runtime
+            .get_global_transform_3d(vehicle)
+            .expect("vehicle transform");
+        assert!(
+            driven.position.z < rest.position.z - 0.1,
+            "engine force should push toward -z, z={}",
+            driven.position.z
+        );
+    }
+
+    #[test]
+    fn vehicle_3d_drops_controller_when_chassis_removed() {
+        let mut runtime = Runtime::new();
+        let (vehicle, _) = vehicle_over_floor_3d(&mut runtime);
+        runtime.physics_fixed_step();
+        assert!(
+            runtime
+                .physics
+                .world_3d
+                .as_ref()
+                .is_some_and(|world| world.vehicle_map.contains_key(&vehicle))
+        );
+
+        assert!(NodeAPI::remove_node(&mut runtime, vehicle));
+        runtime.physics_fixed_step();
+        assert!(
+            runtime
+                .physics
+                .world_3d
+                .as_ref()
+                .is_none_or(|world| !world.vehicle_map.contains_key(&vehicle))
+        );
+    }
+
 }
@@ -0,0 +1,88 @@
+use super::*;
+
+impl Runtime {
+    /// VehicleBody3D: gather the VehicleWheel3D children, run the raycast
+    /// controller b4 the step, then write wheel pose + contact back. wheel
+    /// position is the wheel center, so mount = center + suspension_length up.
+    pub(super) fn update_physics_vehicles_3d(&mut self) {
+        if self.internal_updates.physics_vehicle_nodes_3d.is_empty()
+            && self
+                .physics
+                .world_3d
+                .as_ref()
+                .is_none_or(|world| world.vehicle_map.is_empty())
+        {
+            return;
+        }
+        let mut vehicles = Vec::new();
+        for &id in &self.internal_updates.physics_vehicle_nodes_3d {
+            if self.is_suspended(id) {
+                continue;
+            }
+            let Some(node) = self.nodes.get(id) else {
+                continue;
+            };
+            let SceneNodeData::VehicleBody3D(vehicle) = &node.data else {
+                continue;
+            };
+            if !vehicle.enabled {
+                continue;
+            }
+            let wheels = node
+                .children_slice()
+                .iter()
+                .filter_map(|&child| {
+                    let SceneNodeData::VehicleWheel3D(wheel) = &self.nodes.get(child)?.data else {
+                        return None;
+                    };
+                    Some(VehicleWheelDesc3D {
+                        id: child,
+                        mount: wheel.transform.position
+                            + Vector3::new(0.0, wheel.suspension_length, 0.0),
+                        radius: wheel.radius,
+                        suspension_rest_length: wheel.suspension_rest_length,
+                        suspension_travel: wheel.suspension_travel,
+                        suspension_stiffness: wheel.suspension_stiffness,
+                        suspension_compression: wheel.suspension_compression,
+                        suspension_damping: wheel.suspension_damping,
+                        max_suspension_force: wheel.max_suspension_force,
+                        friction_slip: wheel.friction_slip,
+                        side_friction: wheel.side_friction,
+                        steer: wheel.steer,
+                        drive: wheel.drive,
+                    })
+                })
+                .collect();
+            vehicles.push(VehicleDesc3D {
+                id,
+                engine_force: vehicle.engine_force,
+                brake: vehicle.brake,
+                steering: vehicle.steering,
+                wheels,
+            });
+        }
+
+        let mut states = Vec::new();
+        self.physics
+            .update_vehicles_3d(&vehicles, self.time.fixed_delta, &mut states);
+        // wheels r not physics nodes -> world stays in sync
+        for state in states {
+            let Some(node) = self.nodes.get_mut_untracked_non_physics(state.id) else {
+                continue;
+            };
+            let SceneNodeData::VehicleWheel3D(wheel) = &mut node.data else {
+                continue;
+            };
+            wheel.in_contact = state.in_contact;
+            wheel.suspension_length = state.suspension_length;
+            if wheel.transform.position == state.position
+                && wheel.transform.rotation == state.rotation
+            {
+                continue;
+            }
+            wheel.transform.position = state.position;
+            wheel.transform.rotation = state.rotation;
+            self.mark_transform_dirty_recursive(state.id);
+        }
+    }
+}
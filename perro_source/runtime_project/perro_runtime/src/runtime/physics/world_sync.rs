@@ -13,7 +13,7 @@ impl Runtime {
         }
         for i in 0..node_count {
             let id = self.internal_updates.physics_body_nodes_2d[i];
-            let suspended = self.is_suspended_by_sub_view(id);
+            let suspended = self.is_suspended(id);
             let (kind, enabled, rigid, material, groups) = {
                 let Some(node) = self.nodes.get(id) else {
                     continue;
@@ -162,6 +162,7 @@ impl Runtime {
                             friction: material.0,
                             restitution: material.1,
                             density: material.2,
+                            one_way: false,
                         });
                     }
                     let children = self.nodes.children(id).unwrap_or_default();
@@ -211,7 +212,7 @@ impl Runtime {
         }
         for i in 0..node_count {
             let id = self.internal_updates.physics_body_nodes_3d[i];
-            let suspended = self.is_suspended_by_sub_view(id);
+            let suspended = self.is_suspended(id);
             let (kind, enabled, rigid, material, groups) = {
                 let Some(node) = self.nodes.get(id) else {
                     continue;
@@ -238,24 +239,6 @@ impl Runtime {
                         (0.7, 0.0, 1.0),
                         (water.water.collision_layers, water.water.collision_mask),
                     ),
-                    SceneNodeData::RigidBody3D(body) => (
-                        BodyKind::Rigid,
-                        body.enabled,
-                        Some(RigidProps3D {
-                            enabled: body.enabled,
-                            can_sleep: body.can_sleep,
-                            mass: body.mass,
-                            density: body.density,
-                            continuous_collision_detection: body.continuous_collision_detection,
-                            linear_velocity: body.linear_velocity,
-                            angular_velocity: body.angular_velocity,
-                            gravity_scale: body.gravity_scale,
-                            linear_damping: body.linear_damping,
-                            angular_damping: body.angular_damping,
-                        }),
-                        (body.friction, body.restitution, body.density),
-                        (body.collision_layers, body.collision_mask),
-                    ),
                     SceneNodeData::CharacterBody3D(body) => (
                         BodyKind::Character,
                         body.enabled,
@@ -263,7 +246,29 @@ impl Runtime {
                         (body.friction, body.restitution, body.density),
                         (body.collision_layers, body.collision_mask),
                     ),
-                    _ => continue,
+                    data => {
+                        let Some(body) = rigid_body_3d(data) else {
+                            continue;
+                        };
+                        (
+                            BodyKind::Rigid,
+                            body.enabled,
+                            Some(RigidProps3D {
+                                enabled: body.enabled,
+                                can_sleep: body.can_sleep,
+                                mass: body.mass,
+                                density: body.density,
+                                continuous_collision_detection: body.continuous_collision_detection,
+                                linear_velocity: body.linear_velocity,
+                                angular_velocity: body.angular_velocity,
+                                gravity_scale: body.gravity_scale,
+                                linear_damping: body.linear_damping,
+                                angular_damping: body.angular_damping,
+                            }),
+                            (body.friction, body.restitution, body.density),
+                            (body.collision_layers, body.collision_mask),
+                        )
+                    }
                 }
             };
             let enabled = enabled && !suspended;
@@ -375,7 +380,7 @@ impl Runtime {
         }
         for i in 0..self.internal_updates.physics_joint_nodes_2d.len() {
             let id = self.internal_updates.physics_joint_nodes_2d[i];
-            let suspended = self.is_suspended_by_sub_view(id);
+            let suspended = self.is_suspended(id);
             let Some(node) = self.nodes.get(id) else {
                 continue;
             };
@@ -388,7 +393,14 @@ impl Runtime {
                         joint.anchor_b,
                         joint.enabled,
                         joint.collide_connected,
-                        JointKind2D::Pin,
+                        JointKind2D::Pin(joint_angular(
+                            joint
+                                .limit_enabled
+                                .then_some([joint.lower_limit, joint.upper_limit]),
+                            joint.motor_enabled,
+                            joint.motor_target_velocity,
+                            joint.motor_max_force,
+                        )),
                     ),
                     SceneNodeData::DistanceJoint2D(joint) => (
                         joint.body_a,
@@ -411,6 +423,19 @@ impl Runtime {
                         joint.collide_connected,
                         JointKind2D::Fixed,
                     ),
+                    SceneNodeData::SpringJoint2D(joint) => (
+                        joint.body_a,
+                        joint.body_b,
+                        joint.anchor_a,
+                        joint.anchor_b,
+                        joint.enabled,
+                        joint.collide_connected,
+                        JointKind2D::Spring {
+                            rest_length: joint.rest_length,
+                            stiffness: joint.stiffness,
+                            damping: joint.damping,
+                        },
+                    ),
                     _ => continue,
                 };
             let enabled = enabled && !suspended;
@@ -447,7 +472,7 @@ impl Runtime {
         }
         for i in 0..self.internal_updates.physics_joint_nodes_3d.len() {
             let id = self.internal_updates.physics_joint_nodes_3d[i];
-            let suspended = self.is_suspended_by_sub_view(id);
+            let suspended = self.is_suspended(id);
             let Some(node) = self.nodes.get(id) else {
                 continue;
             };
@@ -469,7 +494,17 @@ impl Runtime {
                         joint.anchor_b,
                         joint.enabled,
                         joint.collide_connected,
-                        JointKind3D::Hinge { axis: joint.axis },
+                        JointKind3D::Hinge {
+                            axis: joint.axis,
+                            angular: joint_angular(
+                                joint
+                                    .limit_enabled
+                                    .then_some([joint.lower_limit, joint.upper_limit]),
+                                joint.motor_enabled,
+                                joint.motor_target_velocity,
+                                joint.motor_max_force,
+                            ),
+                        },
                     ),
                     SceneNodeData::FixedJoint3D(joint) => (
                         joint.body_a,
@@ -480,6 +515,19 @@ impl Runtime {
                         joint.collide_connected,
                         JointKind3D::Fixed,
                     ),
+                    SceneNodeData::SpringJoint3D(joint) => (
+                        joint.body_a,
+                        joint.body_b,
+                        joint.anchor_a,
+                        joint.anchor_b,
+                        joint.enabled,
+                        joint.collide_connected,
+                        JointKind3D::Spring {
+                            rest_length: joint.rest_length,
+                            stiffness: joint.stiffness,
+                            damping: joint.damping,
+                        },
+                    ),
                     _ => continue,
                 };
             let enabled = enabled && !suspended;
@@ -610,6 +658,7 @@ impl Runtime {
         // slot order -> arena writes sequential-ish, not hashmap order
         staged.sort_unstable_by_key(|pose| pose.id.index());
 
+        let interpolate = self.physics_interpolation();
         let mut changed = false;
         for pose in &staged {
             // 1 fat-slot touch: parent read + b4 capture + pose/vel write fused
@@ -645,7 +694,9 @@ impl Runtime {
                     rotation: pose.rotation,
                     scale: before_local.scale,
                 };
-                self.record_physics_pose_2d(pose.id, parent, before_local, curr);
+                if interpolate {
+                    self.record_physics_pose_2d(pose.id, parent, before_local, curr);
+                }
                 if moved {
                     self.mark_transform_dirty_recursive(pose.id);
                 }
@@ -657,7 +708,9 @@ impl Runtime {
                 let mut curr = before;
                 curr.position = pose.position;
                 curr.rotation = pose.rotation;
-                self.record_physics_pose_2d(pose.id, parent, before, curr);
+                if interpolate {
+                    self.record_physics_pose_2d(pose.id, parent, before, curr);
+                }
                 let _ = NodeAPI::set_global_transform_2d(self, pose.id, curr);
             }
         }
@@ -719,6 +772,7 @@ impl Runtime {
         // slot order -> arena writes sequential-ish, not hashmap order
         staged.sort_unstable_by_key(|pose| pose.id.index());
 
+        let interpolate = self.physics_interpolation();
         let mut changed = false;
         for pose in &staged {
             // 1 fat-slot touch: parent read + b4 capture + pose/vel write fused
@@ -727,9 +781,7 @@ impl Runtime {
                 .get_mut_untracked(pose.id)
                 .and_then(|scene_node| {
                     let parent = scene_node.parent;
-                    let SceneNodeData::RigidBody3D(node) = &mut scene_node.data else {
-                        return None;
-                    };
+                    let node = rigid_body_3d_mut(&mut scene_node.data)?;
                     let before_local = node.transform;
                     node.linear_velocity = pose.lin;
                     node.angular_velocity = pose.ang;
@@ -754,7 +806,9 @@ impl Runtime {
                     rotation: pose.rotation,
                     scale: before_local.scale,
                 };
-                self.record_physics_pose_3d(pose.id, parent, before_local, curr);
+                if interpolate {
+                    self.record_physics_pose_3d(pose.id, parent, before_local, curr);
+                }
                 if moved {
                     self.mark_transform_dirty_recursive(pose.id);
                 }
@@ -766,7 +820,9 @@ impl Runtime {
                 let mut curr = before;
                 curr.position = pose.position;
                 curr.rotation = pose.rotation;
-                self.record_physics_pose_3d(pose.id, parent, before, curr);
+                if interpolate {
+                    self.record_physics_pose_3d(pose.id, parent, before, curr);
+                }
                 let _ = NodeAPI::set_global_transform_3d(self, pose.id, curr);
             }
         }
@@ -822,6 +878,7 @@ impl Runtime {
                 SceneNodeData::StaticBody3D(body) => body.physics_handle = handle,
                 SceneNodeData::Area3D(body) => body.physics_handle = handle,
                 SceneNodeData::RigidBody3D(body) => body.physics_handle = handle,
+                SceneNodeData::VehicleBody3D(body) => body.physics_handle = handle,
                 SceneNodeData::CharacterBody3D(body) => body.physics_handle = handle,
                 _ => {}
             }
@@ -845,4 +902,25 @@ impl Runtime {
             .filter(|v| v.is_finite() && *v > 0.0)
             .unwrap_or(1.0)
     }
+
+    /// `[physics] interpolation`; off snaps rigid bodies to their latest step.
+    pub(super) fn physics_interpolation(&self) -> bool {
+        self.project()
+            .is_none_or(|p| p.config.physics_interpolation)
+    }
+}
+
+fn joint_angular(
+    limits: Option<[f32; 2]>,
+    motor_enabled: bool,
+    target_velocity: f32,
+    max_force: f32,
+) -> JointAngularDesc {
+    JointAngularDesc {
+        limits,
+        motor: motor_enabled.then_some(JointMotorDesc {
+            target_velocity,
+            max_force,
+        }),
+    }
 }
@@ -0,0 +1,277 @@
+use super::Runtime;
+use crate::runtime_project::{ProviderMode, RuntimeProjectConfig};
+use perro_runtime_api::sub_apis::{FrameRateCap, WindowRequest};
+use std::path::Path;
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+use std::time::{Duration, SystemTime};
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+/// Min time between project.toml mtime checks.
+const PROJECT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Files whose edits trigger a reload; both feed one `ProjectConfig`.
+const WATCHED_FILES: [&str; 2] = ["project.toml", "input_map.toml"];
+
+/// Dev-run watch state for project.toml live reload.
+#[derive(Default)]
+pub(crate) struct ProjectReloadState {
+    last_poll: Option<Instant>,
+    /// mtimes of [`WATCHED_FILES`] at the last check; `None` before the first.
+    modified: Option<[Option<SystemTime>; 2]>,
+    /// Last config read from disk; diffs run against this, not the runtime
+    /// copy, so boot overrides like `--scene` don't read as edits.
+    baseline: Option<RuntimeProjectConfig>,
+}
+
+/// What a project.toml edit changes in a running game.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct ProjectReloadPlan {
+    pub title: Option<String>,
+    pub frame_rate_cap: Option<crate::runtime_project::FrameRateCap>,
+    pub input_map: bool,
+    pub physics: bool,
+    /// Changed settings that only apply on the next run.
+    pub restart: Vec<&'static str>,
+}
+
+impl ProjectReloadPlan {
+    fn applied(&self) -> Vec<&'static str> {
+        let mut out = Vec::new();
+        if self.title.is_some() {
+            out.push("name");
+        }
+        if self.frame_rate_cap.is_some() {
+            out.push("frame_rate_cap");
+        }
+        if self.input_map {
+            out.push("input_map");
+        }
+        if self.physics {
+            out.push("physics");
+        }
+        out
+    }
+}
+
+pub(crate) fn plan_project_reload(
+    old: &RuntimeProjectConfig,
+    new: &RuntimeProjectConfig,
+) -> ProjectReloadPlan {
+    let restart_checks = [
+        ("main_scene", old.main_scene != new.main_scene),
+        ("icon", old.icon != new.icon),
+        ("startup_splash", old.startup_splash != new.startup_splash),
+        (
+            "virtual_resolution",
+            old.virtual_width != new.virtual_width || old.virtual_height != new.virtual_height,
+        ),
+        ("vsync", old.vsync != new.vsync),
+        (
+            "target_fixed_update",
+            old.target_fixed_update != new.target_fixed_update
+                || old.max_fixed_steps_per_frame != new.max_fixed_steps_per_frame,
+        ),
+        ("msaa", old.msaa != new.msaa),
+        ("ssao", old.ssao != new.ssao),
+        (
+            "meshlets",
+            old.meshlets != new.meshlets
+                || old.dev_meshlets != new.dev_meshlets
+                || old.release_meshlets != new.release_meshlets
+                || old.meshlet_debug_view != new.meshlet_debug_view,
+        ),
+        (
+            "occlusion_culling",
+            old.occlusion_culling != new.occlusion_culling,
+        ),
+        (
+            "particle_sim_default",
+            old.particle_sim_default != new.particle_sim_default,
+        ),
+        ("texture_filter", old.texture_filter != new.texture_filter),
+        ("hdr", old.hdr != new.hdr),
+        ("rendering", old.rendering != new.rendering),
+        ("audio", old.audio != new.audio),
+        ("localization", old.localization != new.localization),
+        ("steam", old.steam != new.steam),
+        ("metadata", old.metadata != new.metadata),
+        ("web", old.web != new.web),
+        (
+            "physics.interpolation",
+            old.physics_interpolation != new.physics_interpolation,
+        ),
+    ];
+    ProjectReloadPlan {
+        title: (old.name != new.name).then(|| new.name.clone()),
+        frame_rate_cap: (old.frame_rate_cap != new.frame_rate_cap).then_some(new.frame_rate_cap),
+        input_map: old.input_map != new.input_map,
+        physics: old.physics_gravity != new.physics_gravity
+            || old.physics_coef != new.physics_coef
+            || old.layer_names != new.layer_names,
+        restart: restart_checks
+            .into_iter()
+            .filter_map(|(name, changed)| changed.then_some(name))
+            .collect(),
+    }
+}
+
+impl Runtime {
+    /// Dev runs only: reload project.toml / input_map.toml when they change
+    /// on disk and apply the settings that are safe mid-run.
+    ///
+    /// Window title, frame rate cap, input map, physics gravity, and layer
+    /// names (for scenes loaded afterwards) apply right away. Everything else
+    /// is logged as needing a restart.
+    pub(crate) fn poll_project_reload(&mut self) {
+        if self.provider_mode != ProviderMode::Dynamic {
+            return;
+        }
+        let Some(root) = self.project().map(|project| project.root.clone()) else {
+            return;
+        };
+        let now = Instant::now();
+        if self
+            .project_reload
+            .last_poll
+            .is_some_and(|last| now.duration_since(last) < PROJECT_POLL_INTERVAL)
+        {
+            return;
+        }
+        self.project_reload.last_poll = Some(now);
+
+        let modified = WATCHED_FILES.map(|name| file_mtime(&root.join(name)));
+        if self.project_reload.modified == Some(modified) {
+            return;
+        }
+        self.project_reload.modified = Some(modified);
+
+        let demo = std::env::var_os("PERRO_DEMO").is_some();
+        let config = match perro_project::load_project_toml_with_demo(&root, demo) {
+            Ok(config) => config,
+            Err(err) => {
+                eprintln!(
+                    "[perro][project] project.toml reload failed, keeping old settings: {err}"
+                );
+                return;
+            }
+        };
+        let Some(baseline) = self.project_reload.baseline.replace(config.clone()) else {
+            return;
+        };
+        let plan = plan_project_reload(&baseline, &config);
+        self.apply_project_reload(&plan, config);
+    }
+
+    fn apply_project_reload(&mut self, plan: &ProjectReloadPlan, config: RuntimeProjectConfig) {
+        if let Some(project) = self.project.as_mut() {
+            let project = Arc::make_mut(project);
+            project.config.name = config.name;
+            project.config.frame_rate_cap = config.frame_rate_cap;
+            project.config.physics_gravity = config.physics_gravity;
+            project.config.physics_coef = config.physics_coef;
+            if project.config.layer_names != config.layer_names {
+                super::physics::install_physics_layer_names(&config.layer_names);
+                project.config.layer_names = config.layer_names;
+            }
+            project.config.input_map = config.input_map.clone();
+        }
+        if let Some(title) = &plan.title {
+            self.window_requests
+                .push(WindowRequest::SetTitle(title.clone()));
+        }
+        if let Some(cap) = plan.frame_rate_cap {
+            self.window_requests
+                .push(WindowRequest::SetFrameRateCap(match cap {
+                    crate::runtime_project::FrameRateCap::Unlimited => FrameRateCap::Unlimited,
+                    crate::runtime_project::FrameRateCap::Fps(fps) => FrameRateCap::Fps(fps),
+                    crate::runtime_project::FrameRateCap::RefreshRate => FrameRateCap::RefreshRate,
+                }));
+        }
+        if plan.input_map {
+            self.input.set_input_map(config.input_map);
+            // Player rebinds sit on top of project defaults.
+            self.load_saved_input_bindings();
+        }
+
+        let applied = plan.applied();
+        if !applied.is_empty() {
+            eprintln!(
+                "[perro][project] project.toml reloaded: applied {}",
+                applied.join(", ")
+            );
+        }
+        if !plan.restart.is_empty() {
+            eprintln!(
+                "[perro][project] project.toml: restart to apply {}",
+                plan.restart.join(", ")
+            );
+        }
+    }
+}
+
+fn file_mtime(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime_project::{FrameRateCap as ProjectFrameRateCap, parse_project_toml};
+
+    const BASE_TOML: &str = "[project]\nname = \"Game\"\nmain_scene = \"res://main.scn\"\n";
+
+    #[test]
+    fn plan_project_reload_splits_live_and_restart_settings() {
+        let old = parse_project_toml(BASE_TOML).expect("base config");
+        let mut new = old.clone();
+        new.name = "Game (tuning)".to_string();
+        new.frame_rate_cap = ProjectFrameRateCap::Fps(30.0);
+        new.physics_gravity = -20.0;
+        new.vsync = !old.vsync;
+        new.main_scene = "res://other.scn".to_string();
+
+        let plan = plan_project_reload(&old, &new);
+        assert_eq!(plan.title.as_deref(), Some("Game (tuning)"));
+        assert_eq!(plan.frame_rate_cap, Some(ProjectFrameRateCap::Fps(30.0)));
+        assert!(plan.physics);
+        assert!(!plan.input_map);
+        assert_eq!(plan.restart, vec!["main_scene", "vsync"]);
+        assert_eq!(
+            plan_project_reload(&old, &old),
+            ProjectReloadPlan::default()
+        );
+    }
+
+    #[test]
+    fn apply_project_reload_queues_window_requests_and_updates_config() {
+        let config = parse_project_toml(BASE_TOML).expect("base config");
+        let mut runtime = Runtime::from_project_unbooted(
+            crate::RuntimeProject::new("Game", "."),
+            ProviderMode::Dynamic,
+            None,
+        );
+        let mut new = config.clone();
+        new.name = "Renamed".to_string();
+        new.frame_rate_cap = ProjectFrameRateCap::Fps(60.0);
+        new.physics_coef = 2.0;
+        let plan = plan_project_reload(&config, &new);
+        runtime.apply_project_reload(&plan, new);
+
+        let mut requests = Vec::new();
+        runtime.drain_window_requests(&mut requests);
+        assert_eq!(
+            requests,
+            vec![
+                WindowRequest::SetTitle("Renamed".to_string()),
+                WindowRequest::SetFrameRateCap(FrameRateCap::Fps(60.0)),
+            ]
+        );
+        let project = runtime.project().expect("project");
+        assert_eq!(project.config.name, "Renamed");
+        assert_eq!(project.config.physics_coef, 2.0);
+    }
+}
@@ -0,0 +1,98 @@
+use super::Runtime;
+use perro_ids::ScriptMemberID;
+use perro_runtime_api::sub_apis::{
+    NodeAPI, ON_QUIT_REQUEST, QUIT_HANDLERS_GROUP, ScriptAPI, WindowRequest,
+};
+use perro_variant::Variant;
+
+/// Soft quit: `ctx.OS().quit(code)` + window close both land here.
+#[derive(Default)]
+pub(crate) struct QuitState {
+    /// code of the request waiting on handlers; `None` when idle
+    pending: Option<i32>,
+    /// outstanding `delay_quit` holds
+    holds: u32,
+    /// handlers still being called; commit waits till they all return
+    dispatching: bool,
+    /// quit went through; `CloseApp` already queued
+    committed: bool,
+    exit_code: i32,
+}
+
+impl Runtime {
+    /// Ask to quit w/ exit `code`. Scripts in the `quit_handlers` group get
+    /// `on_quit_request(code)` first and may delay or cancel. Returns true
+    /// once the quit has gone through (`CloseApp` queued); false while
+    /// handlers hold it or after a cancel.
+    pub fn begin_quit(&mut self, code: i32) -> bool {
+        if self.quit.committed {
+            return true;
+        }
+        if self.quit.pending.is_some() {
+            return false;
+        }
+        self.quit.pending = Some(code);
+        self.quit.holds = 0;
+        self.quit.dispatching = true;
+        let mut handlers = self.get_nodes_in_group(QUIT_HANDLERS_GROUP);
+        handlers.sort();
+        let method = ScriptMemberID::from_string(ON_QUIT_REQUEST);
+        let params = [Variant::from(code)];
+        for id in handlers {
+            // cancelled by an earlier handler -> rest r not asked
+            if self.quit.pending.is_none() {
+                break;
+            }
+            let _ = ScriptAPI::call_method(self, id, method, &params);
+        }
+        self.quit.dispatching = false;
+        self.try_commit_quit();
+        self.quit.committed
+    }
+
+    pub(crate) fn delay_pending_quit(&mut self) {
+        if self.quit.pending.is_some() {
+            self.quit.holds += 1;
+        }
+    }
+
+    pub(crate) fn resume_pending_quit(&mut self) {
+        if self.quit.pending.is_none() || self.quit.holds == 0 {
+            return;
+        }
+        self.quit.holds -= 1;
+        self.try_commit_quit();
+    }
+
+    pub(crate) fn cancel_pending_quit(&mut self) {
+        self.quit.pending = None;
+        self.quit.holds = 0;
+    }
+
+    #[inline]
+    pub fn is_quit_pending(&self) -> bool {
+        self.quit.pending.is_some()
+    }
+
+    /// Exit code of the committed quit; 0 when the app closed any other way.
+    #[inline]
+    pub fn exit_code(&self) -> i32 {
+        self.quit.exit_code
+    }
+
+    fn try_commit_quit(&mut self) {
+        if self.quit.dispatching || self.quit.holds > 0 {
+            return;
+        }
+        let Some(code) = self.quit.pending.take() else {
+            return;
+        };
+        self.quit.committed = true;
+        self.quit.exit_code = code;
+        self.window_requests.push(WindowRequest::CloseApp);
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/unit/runtime_quit_tests.rs"]
+mod tests;
@@ -62,6 +62,7 @@ fn is_ui_node_data(data: &SceneNodeData) -> bool {
             | SceneNodeData::UiVLayout(_)
             | SceneNodeData::UiGrid(_)
             | SceneNodeData::UiTreeList(_)
+            | SceneNodeData::UiMarkdown(_)
     )
 }
 
@@ -504,6 +504,7 @@ impl Runtime {
         let Some(node) = self.nodes.get(root) else {
             return;
         };
+        self.space2d.note_moved(root);
         if self.nodes.children(root).is_none_or(<[NodeID]>::is_empty) {
             // leaf: type known now -> scoped physics gate. w/ children,
             // defer to root walk (propagate) where each descendant typed.
@@ -325,7 +325,7 @@ impl Runtime {
                         ResourceCommand::CreateMesh {
                             request,
                             id: MeshID::nil(),
-                            source,
+                            source: source.into(),
                             reserved: false,
                         },
                     ));
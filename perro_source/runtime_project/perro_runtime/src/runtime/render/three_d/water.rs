@@ -34,6 +34,12 @@ impl Runtime {
                         body.collision_mask,
                         1.00f32,
                     )),
+                    SceneNodeData::VehicleBody3D(body) => Some((
+                        body.enabled,
+                        body.collision_layers,
+                        body.collision_mask,
+                        1.00f32,
+                    )),
                     SceneNodeData::CharacterBody3D(body) => Some((
                         body.enabled,
                         body.collision_layers,
@@ -267,9 +273,7 @@ impl Runtime {
         for body_id in body_ids.iter().copied() {
             let Some((layers, mask, mass, density, velocity)) =
                 self.nodes.get(body_id).and_then(|node| {
-                    let SceneNodeData::RigidBody3D(body) = &node.data else {
-                        return None;
-                    };
+                    let body = crate::runtime::physics::rigid_body_3d(&node.data)?;
                     Some((
                         body.collision_layers,
                         body.collision_mask,
@@ -102,6 +102,46 @@ fn label_2d_rect(
     }
 }
 
+/// z lanes / canvas layer. layer = major sort key, node z = minor.
+const CANVAS_LAYER_Z_STRIDE: i64 = 1 << 16;
+
+fn canvas_z_index_2d(canvas_layer: i32, z_index: i32) -> i32 {
+    if canvas_layer == 0 {
+        return z_index;
+    }
+    let half = CANVAS_LAYER_Z_STRIDE / 2;
+    let z = (z_index as i64).clamp(-half, half - 1);
+    (canvas_layer as i64 * CANVAS_LAYER_Z_STRIDE + z).clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+/// Map screen-space canvas transform -> world so active camera view lands
+/// it back on same screen spot. Zoom rule match renderer `ndc_scale`.
+fn canvas_screen_to_world_2d(
+    transform: perro_structs::Transform2D,
+    camera: Option<&Camera2DState>,
+) -> perro_structs::Transform2D {
+    let Some(camera) = camera else {
+        return transform;
+    };
+    let zoom = if camera.zoom.is_finite() && camera.zoom > 0.0 {
+        camera.zoom
+    } else {
+        1.0
+    };
+    let inv_zoom = 1.0 / zoom;
+    let (sin, cos) = camera.rotation_radians.sin_cos();
+    let x = transform.position.x * inv_zoom;
+    let y = transform.position.y * inv_zoom;
+    perro_structs::Transform2D::new(
+        Vector2::new(
+            camera.position[0] + x * cos - y * sin,
+            camera.position[1] + x * sin + y * cos,
+        ),
+        transform.rotation + camera.rotation_radians,
+        transform.scale * inv_zoom,
+    )
+}
+
 fn button_2d_style(
     button: &perro_nodes::Button2D,
     state: UiButtonVisualState,
@@ -45,7 +45,7 @@ impl Runtime {
                     ResourceCommand::CreateTexture {
                         request,
                         id: TextureID::nil(),
-                        source,
+                        source: source.into(),
                         reserved: false,
                     },
                 ));
@@ -113,7 +113,7 @@ impl Runtime {
             self.queue_render_command(RenderCommand::Resource(ResourceCommand::CreateTexture {
                 request,
                 id: TextureID::nil(),
-                source: source.to_string(),
+                source: source.into(),
                 reserved: false,
             }));
         }
@@ -66,6 +66,10 @@ impl Runtime {
                 .iter()
                 .filter_map(|&raw_index| nodes.slot_get(raw_index as usize).map(|(id, _)| id)),
         );
+        if camera_changed {
+            // screen-space canvas nodes bake camera into model -> re-emit.
+            dirty_ids.extend(self.render_2d.canvas_nodes.iter().copied());
+        }
         let traversal_ids = self.render_2d.collect_traversal_with_scratch(
             &dirty_ids,
             nodes.iter().map(|(id, _)| id),
@@ -84,6 +88,12 @@ impl Runtime {
             visible_now.remove(&node);
             let effective_visible =
                 self.is_effectively_visible(node) && !self.is_under_sub_view(node);
+            let canvas_layer = self.canvas_layer_2d(node);
+            if canvas_layer != 0 {
+                self.render_2d.canvas_nodes.insert(node);
+            } else {
+                self.render_2d.canvas_nodes.remove(&node);
+            }
             let sprite_data = self
                 .nodes
                 .get(node)
@@ -165,7 +175,7 @@ impl Runtime {
             )) = sprite_data
             {
                 let model = self
-                    .get_render_global_transform_2d(node)
+                    .get_canvas_global_transform_2d(node, canvas_layer, active_camera.as_ref())
                     .unwrap_or(local_transform)
                     .to_mat3()
                     .to_cols_array_2d();
@@ -180,7 +190,7 @@ impl Runtime {
                         model,
                         tint,
                         size_override,
-                        z_index,
+                        z_index: canvas_z_index_2d(canvas_layer, z_index),
                     },
                     &mut visible_now,
                 );
@@ -223,7 +233,7 @@ impl Runtime {
             {
                 if visible {
                     let transform = self
-                        .get_render_global_transform_2d(node)
+                        .get_canvas_global_transform_2d(node, canvas_layer, active_camera.as_ref())
                         .unwrap_or(local_transform);
                     let rect = label_2d_rect(
                         transform,
@@ -284,7 +294,7 @@ impl Runtime {
             {
                 let color = Runtime::color_modulate(color, self.effective_self_modulate(node));
                 let transform = self
-                    .get_render_global_transform_2d(node)
+                    .get_canvas_global_transform_2d(node, canvas_layer, active_camera.as_ref())
                     .unwrap_or(local_transform);
                 self.queue_render_command(RenderCommand::TwoD(Command2D::UpsertRect {
                     node,
@@ -295,7 +305,7 @@ impl Runtime {
                             size.y * transform.scale.y.abs(),
                         ],
                         color,
-                        z_index,
+                        z_index: canvas_z_index_2d(canvas_layer, z_index),
                     },
                 }));
                 visible_now.insert(node);
@@ -326,7 +336,11 @@ impl Runtime {
                             _ => stream_state.resolution,
                         };
                         let model = self
-                            .get_render_global_transform_2d(node)
+                            .get_canvas_global_transform_2d(
+                                node,
+                                canvas_layer,
+                                active_camera.as_ref(),
+                            )
                             .unwrap_or(local_transform)
                             .to_mat3()
                             .to_cols_array_2d();
@@ -338,7 +352,7 @@ impl Runtime {
                             uv_max: [texture_resolution[0] as f32, texture_resolution[1] as f32],
                             uv_normalized: false,
                             size: [aspect, 1.0],
-                            z_index,
+                            z_index: canvas_z_index_2d(canvas_layer, z_index),
                         };
                         self.queue_render_command(RenderCommand::CameraStream(
                             CameraStreamCommand::Upsert {
@@ -417,12 +431,19 @@ impl Runtime {
             {
                 let tint = Runtime::color_modulate(tint, self.effective_self_modulate(node));
                 let model = self
-                    .get_render_global_transform_2d(node)
+                    .get_canvas_global_transform_2d(node, canvas_layer, active_camera.as_ref())
                     .unwrap_or(local_transform)
                     .to_mat3()
                     .to_cols_array_2d();
-                let sprites =
-                    build_nine_slice_sprites(texture, region, model, size, margins, tint, z_index);
+                let sprites = build_nine_slice_sprites(
+                    texture,
+                    region,
+                    model,
+                    size,
+                    margins,
+                    tint,
+                    canvas_z_index_2d(canvas_layer, z_index),
+                );
                 self.queue_render_command(RenderCommand::TwoD(Command2D::UpsertTileMap {
                     node,
                     tilemap: TileMap2DCommand {
@@ -480,7 +501,7 @@ impl Runtime {
                         emitter_mut.internal_lifetime_max = lifetime_max;
                     }
                     let model = self
-                        .get_render_global_transform_2d(node)
+                        .get_canvas_global_transform_2d(node, canvas_layer, active_camera.as_ref())
                         .unwrap_or(emitter_transform)
                         .to_mat3()
                         .to_cols_array_2d();
@@ -489,7 +510,7 @@ impl Runtime {
                             node,
                             particles: Box::new(PointParticles2DState {
                                 model,
-                                z_index: emitter_z_index,
+                                z_index: canvas_z_index_2d(canvas_layer, emitter_z_index),
                                 active: emitter_active,
                                 looping: emitter_looping,
                                 prewarm: emitter_prewarm,
@@ -548,7 +569,11 @@ impl Runtime {
                             Runtime::color_modulate(tint, self.effective_self_modulate(node));
                         let texture_resolution = stream_state.resolution;
                         let model = self
-                            .get_render_global_transform_2d(node)
+                            .get_canvas_global_transform_2d(
+                                node,
+                                canvas_layer,
+                                active_camera.as_ref(),
+                            )
                             .unwrap_or(local_transform)
                             .to_mat3()
                             .to_cols_array_2d();
@@ -560,7 +585,7 @@ impl Runtime {
                             uv_max: [texture_resolution[0] as f32, texture_resolution[1] as f32],
                             uv_normalized: false,
                             size: [size.x.max(0.001), size.y.max(0.001)],
-                            z_index,
+                            z_index: canvas_z_index_2d(canvas_layer, z_index),
                         };
                         self.queue_render_command(RenderCommand::CameraStream(
                             CameraStreamCommand::Upsert {
@@ -938,13 +963,17 @@ impl Runtime {
                             self.resolve_tilemap_texture(node, tileset.texture.as_ref())
                     {
                         let global_transform = self
-                            .get_render_global_transform_2d(node)
+                            .get_canvas_global_transform_2d(
+                                node,
+                                canvas_layer,
+                                active_camera.as_ref(),
+                            )
                             .unwrap_or(tilemap.transform);
                         let global = global_transform.to_mat3().to_cols_array_2d();
                         let sprites = build_tilemap_sprites(TilemapSpriteBuild {
                             texture,
                             base_model: global,
-                            z_index: tilemap.z_index,
+                            z_index: canvas_z_index_2d(canvas_layer, tilemap.z_index),
                             width: tilemap.width,
                             height: tilemap.height,
                             empty_tile: tilemap.empty_tile,
@@ -981,6 +1010,7 @@ impl Runtime {
             self.queue_render_command(RenderCommand::TwoD(Command2D::RemoveNode { node }));
             self.queue_render_command(RenderCommand::Ui(UiCommand::RemoveNode { node }));
             self.render_2d.retained_sprites.remove(&node);
+            self.render_2d.canvas_nodes.remove(&node);
         }
         self.render_2d
             .finish_visible_pass(traversal_ids, visible_now);
@@ -1065,4 +1095,41 @@ impl Runtime {
         }
         visible_now.insert(node);
     }
+
+    /// Nearest non-zero `canvas_layer` on node or ancestor. `0` = world.
+    pub(super) fn canvas_layer_2d(&self, node: NodeID) -> i32 {
+        let mut cur = node;
+        let max_hops = self.nodes.len().saturating_add(1);
+        for _ in 0..max_hops {
+            let Some(scene_node) = self.nodes.get(cur) else {
+                break;
+            };
+            if let Some(layer) =
+                scene_node.with_base_ref::<perro_nodes::Node2D, _>(|base| base.canvas_layer)
+                && layer != 0
+            {
+                return layer;
+            }
+            cur = scene_node.parent;
+            if cur.is_nil() {
+                break;
+            }
+        }
+        0
+    }
+
+    /// Render global transform, mapped out of screen space when node sit on
+    /// canvas layer so camera pos/zoom/rot cancel out.
+    pub(super) fn get_canvas_global_transform_2d(
+        &mut self,
+        node: NodeID,
+        canvas_layer: i32,
+        camera: Option<&Camera2DState>,
+    ) -> Option<perro_structs::Transform2D> {
+        let global = self.get_render_global_transform_2d(node)?;
+        if canvas_layer == 0 {
+            return Some(global);
+        }
+        Some(canvas_screen_to_world_2d(global, camera))
+    }
 }
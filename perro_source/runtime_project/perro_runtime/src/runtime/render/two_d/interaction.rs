@@ -93,13 +93,15 @@ impl Runtime {
             {
                 continue;
             }
-            let Some(local) = self.button_2d_local_point(node, world) else {
+            let canvas_layer = self.canvas_layer_2d(node);
+            let Some(local) = self.button_2d_local_point(node, world, canvas_layer, camera) else {
                 continue;
             };
             let half = size * 0.5;
             if local.x.abs() > half.x || local.y.abs() > half.y {
                 continue;
             }
+            let z_index = canvas_z_index_2d(canvas_layer, z_index);
             match best {
                 Some((best_node, best_z))
                     if best_z > z_index
@@ -132,8 +134,10 @@ impl Runtime {
         &mut self,
         node: NodeID,
         world: Vector2,
+        canvas_layer: i32,
+        camera: Option<&Camera2DState>,
     ) -> Option<Vector2> {
-        let transform = self.get_render_global_transform_2d(node)?;
+        let transform = self.get_canvas_global_transform_2d(node, canvas_layer, camera)?;
         let local = transform.to_mat3().inverse() * glam::Vec3::new(world.x, world.y, 1.0);
         Some(Vector2::new(local.x, local.y))
     }
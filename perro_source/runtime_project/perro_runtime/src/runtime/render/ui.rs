@@ -6,7 +6,9 @@ use ahash::AHashMap;
 use perro_ids::{NodeID, SignalID, TextureID};
 #[cfg(test)]
 use perro_input_api::GamepadAxis;
-use perro_input_api::{GamepadButton, JoyConButton, KeyCode, MouseButton, PlayerBinding};
+use perro_input_api::{
+    GamepadButton, JoyConButton, KeyCode, MouseButton, PlayerBinding, action_hash,
+};
 use perro_nodes::{SceneNode, SceneNodeData};
 use perro_render_bridge::{
     CameraStreamCommand, CameraStreamSourceState, RenderCommand, ResourceCommand, UiCommand,
@@ -41,6 +43,14 @@ const UI_NAV_REPEAT_DELAY: f32 = 0.35;
 const UI_NAV_REPEAT_RATE: f32 = 0.15;
 const UI_NAV_STICK_ON: f32 = 0.55;
 const UI_NAV_STICK_OFF: f32 = 0.35;
+// optional input map actions. bound -> drive UI focus alongside built-in keys/pads.
+const UI_ACTION_UP: u64 = action_hash("ui_up");
+const UI_ACTION_DOWN: u64 = action_hash("ui_down");
+const UI_ACTION_LEFT: u64 = action_hash("ui_left");
+const UI_ACTION_RIGHT: u64 = action_hash("ui_right");
+const UI_ACTION_FOCUS_NEXT: u64 = action_hash("ui_focus_next");
+const UI_ACTION_FOCUS_PREV: u64 = action_hash("ui_focus_prev");
+const UI_ACTION_ACCEPT: u64 = action_hash("ui_accept");
 
 #[path = "ui/commands.rs"]
 mod commands;
@@ -55,6 +65,8 @@ mod layout_core;
 mod layout_rects;
 #[path = "ui/layout_size.rs"]
 mod layout_size;
+#[path = "ui/markdown.rs"]
+mod markdown;
 
 #[path = "ui/helpers.rs"]
 mod helpers;
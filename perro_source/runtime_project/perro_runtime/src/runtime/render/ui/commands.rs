@@ -953,7 +953,7 @@ impl Runtime {
     pub(super) fn insert_color_picker_internal_node(
         &mut self,
         parent_id: NodeID,
-        name: impl Into<std::borrow::Cow<'static, str>>,
+        name: impl Into<perro_ids::Name>,
         data: SceneNodeData,
     ) -> NodeID {
         let mut node = SceneNode::new(data);
@@ -19,6 +19,10 @@ pub(super) enum UiInputSource {
     Kbm,
     Gamepad(usize),
     JoyCon(usize),
+    /// Input map action (`ui_*`). Device unknown, so only passes masks w/o allow filter.
+    Action,
+    /// Scene-driven focus (`initial_focus`). Not user input, so masks skip.
+    Scene,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -173,6 +173,7 @@ impl Runtime {
                 self.toggle_checkbox(node);
                 self.toggle_color_picker_from_child(node);
                 self.process_tree_list_click(node);
+                self.process_markdown_link_click(node);
                 self.process_dropdown_click(node);
                 self.process_button_web_action(node);
             }
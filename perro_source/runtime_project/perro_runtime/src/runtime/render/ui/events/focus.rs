@@ -38,6 +38,38 @@ impl Runtime {
         }
     }
 
+    /// Focus first newly focusable node w/ `initial_focus`. Each node claims
+    /// focus once, so menus opened later still grab it but user moves kp.
+    pub(super) fn apply_ui_initial_focus(
+        &mut self,
+        computed: &AHashMap<NodeID, ComputedUiRect>,
+        command_ids: &mut Vec<NodeID>,
+        command_seen: &mut ahash::AHashSet<NodeID>,
+    ) {
+        let mut next = None;
+        for &node in &self.render_ui.focusable_nodes {
+            if self.render_ui.initial_focus_applied.contains(&node)
+                || !self.nodes.get(node).is_some_and(|scene_node| {
+                    ui_root_from_data(&scene_node.data).is_some_and(|ui| ui.initial_focus)
+                })
+            {
+                continue;
+            }
+            if let Some(candidate) = self.focus_candidate(computed, node, UiInputSource::Scene)
+                && next.is_none_or(|(_, best)| {
+                    compare_focus_visual_order(&candidate, &best) == std::cmp::Ordering::Less
+                })
+            {
+                next = Some((node, candidate));
+            }
+        }
+        let Some((node, _)) = next else {
+            return;
+        };
+        self.render_ui.initial_focus_applied.insert(node);
+        self.set_ui_focus(Some(node), command_ids, command_seen);
+    }
+
     pub(super) fn hovered_focusable(
         &self,
         computed: &AHashMap<NodeID, ComputedUiRect>,
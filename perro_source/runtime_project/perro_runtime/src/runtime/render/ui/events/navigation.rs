@@ -32,6 +32,40 @@ impl Runtime {
         None
     }
 
+    pub(super) fn ui_action_nav_direction(&self) -> Option<UiDirectionalNav> {
+        let dir = if self.input.is_action_pressed_hash(UI_ACTION_UP) {
+            [0, 1]
+        } else if self.input.is_action_pressed_hash(UI_ACTION_DOWN) {
+            [0, -1]
+        } else if self.input.is_action_pressed_hash(UI_ACTION_LEFT) {
+            [-1, 0]
+        } else if self.input.is_action_pressed_hash(UI_ACTION_RIGHT) {
+            [1, 0]
+        } else {
+            return None;
+        };
+        Some(UiDirectionalNav {
+            source: UiInputSource::Action,
+            dir,
+        })
+    }
+
+    /// `(reverse, source)` when Tab or a bound `ui_focus_next`/`ui_focus_prev` action fired.
+    pub(super) fn ui_tab_nav(&self) -> Option<(bool, UiInputSource)> {
+        if self.input.is_key_pressed(KeyCode::Tab) {
+            let reverse = self.input.is_key_down(KeyCode::ShiftLeft)
+                || self.input.is_key_down(KeyCode::ShiftRight);
+            return Some((reverse, UiInputSource::Kbm));
+        }
+        if self.input.is_action_pressed_hash(UI_ACTION_FOCUS_NEXT) {
+            return Some((false, UiInputSource::Action));
+        }
+        if self.input.is_action_pressed_hash(UI_ACTION_FOCUS_PREV) {
+            return Some((true, UiInputSource::Action));
+        }
+        None
+    }
+
     pub(super) fn ui_repeating_stick_nav_direction(&mut self) -> Option<UiDirectionalNav> {
         let nav = self.ui_stick_nav_direction();
         let Some(nav) = nav else {
@@ -102,7 +136,9 @@ impl Runtime {
                 return Some(UiInputSource::JoyCon(index));
             }
         }
-        None
+        self.input
+            .is_action_pressed_hash(UI_ACTION_ACCEPT)
+            .then_some(UiInputSource::Action)
     }
 
     pub(super) fn ui_action_released(&self) -> Option<UiInputSource> {
@@ -120,7 +156,9 @@ impl Runtime {
                 return Some(UiInputSource::JoyCon(index));
             }
         }
-        None
+        self.input
+            .is_action_released_hash(UI_ACTION_ACCEPT)
+            .then_some(UiInputSource::Action)
     }
 
     pub(super) fn process_focused_button_action(&mut self) {
@@ -187,6 +225,9 @@ impl Runtime {
         mask: &perro_ui::UiInputMask,
         source: UiInputSource,
     ) -> bool {
+        if source == UiInputSource::Scene {
+            return true;
+        }
         if self.ui_input_mask_matches_kbm(mask.deny_kbm, source)
             || self.ui_input_mask_matches_ids(&mask.deny_gamepads, source, UiInputSource::Gamepad)
             || self.ui_input_mask_matches_ids(&mask.deny_joycons, source, UiInputSource::JoyCon)
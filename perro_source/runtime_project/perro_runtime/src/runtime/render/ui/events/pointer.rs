@@ -21,12 +21,13 @@ impl Runtime {
     }
 
     pub(in super::super) fn ui_nav_input_changed(&self) -> bool {
-        self.input.is_key_pressed(KeyCode::Tab)
+        self.ui_tab_nav().is_some()
             || self.input.is_key_pressed(KeyCode::Enter)
             || self.input.is_key_pressed(KeyCode::Space)
             || self.input.is_key_released(KeyCode::Enter)
             || self.input.is_key_released(KeyCode::Space)
             || self.ui_gamepad_dpad_direction().is_some()
+            || self.ui_action_nav_direction().is_some()
             || self.ui_action_pressed().is_some()
             || self.ui_action_released().is_some()
             || self.ui_stick_nav_direction().is_some()
@@ -42,6 +43,7 @@ impl Runtime {
 
     pub(in super::super) fn ui_text_input_changed(&self) -> bool {
         !self.input.text_inputs().is_empty()
+            || self.input.ime_preedit_changed()
             || self.input.mouse_wheel() != Vector2::ZERO
             || text_edit_keys()
                 .iter()
@@ -62,16 +64,17 @@ impl Runtime {
             self.set_ui_focus(hit, command_ids, command_seen);
         }
 
-        if self.input.is_key_pressed(KeyCode::Tab) {
-            let reverse = self.input.is_key_down(KeyCode::ShiftLeft)
-                || self.input.is_key_down(KeyCode::ShiftRight);
-            if let Some(next) = self.next_tab_focus(computed, reverse, UiInputSource::Kbm) {
-                self.set_ui_focus(Some(next), command_ids, command_seen);
-            }
+        self.apply_ui_initial_focus(computed, command_ids, command_seen);
+
+        if let Some((reverse, source)) = self.ui_tab_nav()
+            && let Some(next) = self.next_tab_focus(computed, reverse, source)
+        {
+            self.set_ui_focus(Some(next), command_ids, command_seen);
         }
 
         let dir = self
             .ui_gamepad_dpad_direction()
+            .or_else(|| self.ui_action_nav_direction())
             .or_else(|| self.ui_repeating_stick_nav_direction());
         if let Some(nav) = dir
             && let Some(next) = self.next_directional_focus(computed, nav.dir, nav.source)
@@ -143,7 +146,8 @@ impl Runtime {
             return;
         }
 
-        let mut changed = false;
+        // preedit only chg drawn text; edit text unchanged until IME commit.
+        let mut changed = self.input.ime_preedit_changed();
         let mut text_changed = false;
         let mut changed_text = None;
         let text_inputs: Vec<String> = self.input.text_inputs().to_vec();
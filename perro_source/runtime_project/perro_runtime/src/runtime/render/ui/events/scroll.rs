@@ -27,6 +27,12 @@ impl Runtime {
             changed.push(scroller);
         }
 
+        if let Some(scroller) = self.process_scroll_drag_input(computed)
+            && changed_seen.insert(scroller)
+        {
+            changed.push(scroller);
+        }
+
         if wheel.y != 0.0 && !self.focused_multiline_text_edit_consumes_wheel() {
             let pointer = self.ui_pointer_screen_point();
             if let Some(scroller) = self.hovered_scroll_container(computed, pointer) {
@@ -178,6 +184,53 @@ impl Runtime {
         None
     }
 
+    /// Grab-and-drag content scrolling for `drag_scroll` containers. Press must
+    /// land on container, not scrollbar or focusable control.
+    pub(super) fn process_scroll_drag_input(
+        &mut self,
+        computed: &AHashMap<NodeID, ComputedUiRect>,
+    ) -> Option<NodeID> {
+        if !self.input.is_mouse_down(MouseButton::Left) {
+            self.render_ui.drag_scroll = None;
+            return None;
+        }
+        let pointer = self.ui_pointer_screen_point();
+        if self.input.is_mouse_pressed(MouseButton::Left) {
+            self.render_ui.drag_scroll = None;
+            if self.render_ui.active_scrollbar.is_some()
+                || self
+                    .hovered_focusable(computed, UiInputSource::Kbm, pointer)
+                    .is_some()
+            {
+                return None;
+            }
+            let node = self.hovered_scroll_container(computed, pointer)?;
+            let drag_enabled = self.nodes.get(node).is_some_and(|scene_node| {
+                matches!(
+                    &scene_node.data,
+                    SceneNodeData::UiScrollContainer(scroller) if scroller.drag_scroll
+                )
+            });
+            if drag_enabled {
+                self.render_ui.drag_scroll = Some((node, pointer));
+            }
+            return None;
+        }
+        let (node, last) = self.render_ui.drag_scroll?;
+        if !self.is_effectively_visible_for_ui(node) {
+            self.render_ui.drag_scroll = None;
+            return None;
+        }
+        self.render_ui.drag_scroll = Some((node, pointer));
+        // content follows pointer: drag up/left -> scroll fwd.
+        let delta = if self.scroll_container_is_horizontal(node) {
+            last.x - pointer.x
+        } else {
+            pointer.y - last.y
+        };
+        (delta != 0.0 && self.adjust_scroll_container(node, computed, delta)).then_some(node)
+    }
+
     pub(super) fn hit_scrollbar(
         &self,
         point: Vector2,
@@ -44,6 +44,7 @@ pub(super) struct TextEditCommandCtx<'a> {
     pub(super) edit: &'a UiTextEdit,
     pub(super) multiline: bool,
     pub(super) focused: bool,
+    pub(super) ime_preedit: &'a str,
 }
 
 pub(super) fn ui_root_from_data(data: &SceneNodeData) -> Option<&UiNode> {
@@ -73,6 +74,7 @@ pub(super) fn ui_root_from_data(data: &SceneNodeData) -> Option<&UiNode> {
         SceneNodeData::UiVLayout(node) => Some(&node.inner.base),
         SceneNodeData::UiGrid(node) => Some(&node.base),
         SceneNodeData::UiTreeList(node) => Some(&node.base),
+        SceneNodeData::UiMarkdown(node) => Some(&node.base),
         _ => None,
     }
 }
@@ -104,6 +106,7 @@ pub(super) fn ui_root_mut_from_data(data: &mut SceneNodeData) -> Option<&mut UiN
         SceneNodeData::UiVLayout(node) => Some(&mut node.inner.base),
         SceneNodeData::UiGrid(node) => Some(&mut node.base),
         SceneNodeData::UiTreeList(node) => Some(&mut node.base),
+        SceneNodeData::UiMarkdown(node) => Some(&mut node.base),
         _ => None,
     }
 }
@@ -582,6 +585,7 @@ pub(super) fn ui_command_from_node(
     command_ctx: UiCommandCtx,
     button_state: UiButtonVisualState,
     focused_text_edit: Option<NodeID>,
+    ime_preedit: &str,
 ) -> Option<UiCommand> {
     let UiCommandCtx {
         node,
@@ -891,12 +895,14 @@ pub(super) fn ui_command_from_node(
             edit: &text_box.inner,
             multiline: false,
             focused: focused_text_edit == Some(node),
+            ime_preedit,
         })),
         SceneNodeData::UiTextBlock(text_block) => Some(text_edit_command(TextEditCommandCtx {
             command: command_ctx,
             edit: &text_block.inner,
             multiline: true,
             focused: focused_text_edit == Some(node),
+            ime_preedit,
         })),
         _ => None,
     }
@@ -1157,6 +1163,7 @@ pub(super) fn ui_command_matches_node(
     command_ctx: UiCommandCtx,
     button_state: UiButtonVisualState,
     focused_text_edit: Option<NodeID>,
+    ime_preedit: &str,
 ) -> bool {
     let node = match command {
         UiCommand::UpsertPanel { node, .. }
@@ -1180,6 +1187,7 @@ pub(super) fn ui_command_matches_node(
         },
         button_state,
         focused_text_edit,
+        ime_preedit,
     ) else {
         return false;
     };
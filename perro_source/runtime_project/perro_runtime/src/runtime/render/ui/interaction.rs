@@ -167,7 +167,7 @@ impl Runtime {
                     ResourceCommand::CreateTexture {
                         request,
                         id: TextureID::nil(),
-                        source,
+                        source: source.into(),
                         reserved: false,
                     },
                 ));
@@ -260,6 +260,7 @@ impl Runtime {
         let mut timing = timing;
         self.ensure_color_picker_internal_nodes();
         self.ensure_tree_list_internal_nodes();
+        self.ensure_markdown_internal_nodes();
         self.ensure_dropdown_internal_nodes();
 
         self.propagate_pending_transform_dirty();
@@ -370,6 +371,7 @@ impl Runtime {
             if self.render_ui.focused_ui_node == Some(node) {
                 self.render_ui.focused_ui_node = None;
             }
+            self.render_ui.initial_focus_applied.remove(&node);
             if self.render_ui.nav_pressed_button == Some(node) {
                 self.render_ui.nav_pressed_button = None;
             }
@@ -386,6 +388,13 @@ impl Runtime {
                 self.render_ui.active_scrollbar = None;
                 self.render_ui.scrollbar_drag_offset = 0.0;
             }
+            if self
+                .render_ui
+                .drag_scroll
+                .is_some_and(|(scroller, _)| scroller == node)
+            {
+                self.render_ui.drag_scroll = None;
+            }
             visible_now.remove(&node);
             self.render_ui.computed_rects.remove(&node);
             self.render_ui
@@ -463,6 +472,7 @@ impl Runtime {
             &mut command_seen,
         );
         self.refresh_button_visual_states(&computed, &mut command_ids, &mut command_seen);
+        self.mark_markdown_width_changes(&computed);
         self.render_ui.defer_dirty_marks = false;
 
         let commands_start = timing.as_ref().map(|_| Instant::now());
@@ -666,6 +676,7 @@ impl Runtime {
                             command_ctx,
                             state,
                             self.render_ui.focused_text_edit,
+                            self.input.ime_preedit(),
                         )
                     });
             if !retained_matches {
@@ -684,6 +695,7 @@ impl Runtime {
                     command_ctx,
                     state,
                     self.render_ui.focused_text_edit,
+                    self.input.ime_preedit(),
                 ) else {
                     self.remove_retained_ui_node(node);
                     if let Some(timing) = timing.as_deref_mut() {
@@ -0,0 +1,373 @@
+use super::*;
+use perro_ui::{UiMarkdown, UiMarkdownBlockKind, parse_markdown};
+
+/// One wrapped, single-style text run. Pos is top-left in parent px, y down.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct UiMarkdownRun {
+    pub text: String,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub font_size: f32,
+    pub color: Color,
+    pub bold: bool,
+    pub link: Option<String>,
+}
+
+/// Wrap parsed blocks into runs at `width`. `width <= 0` -> no wrap.
+/// Uses approx `measure_text` metrics, same as label fit sizing.
+pub(super) fn layout_markdown_runs(
+    markdown: &UiMarkdown,
+    text: &str,
+    width: f32,
+) -> Vec<UiMarkdownRun> {
+    let max_x = if width > 0.0 { width } else { f32::INFINITY };
+    let mut runs = Vec::new();
+    let mut y = 0.0_f32;
+    for block in parse_markdown(text) {
+        let font_size = markdown.block_font_size(block.kind);
+        let line_height = measure_text("", font_size).y;
+        let heading = matches!(block.kind, UiMarkdownBlockKind::Heading(_));
+        let indent = match block.kind {
+            UiMarkdownBlockKind::ListItem { ordered } => {
+                let marker = match ordered {
+                    Some(n) => format!("{n}."),
+                    None => "\u{2022}".to_string(),
+                };
+                let marker_width = measure_text(&marker, font_size).x;
+                runs.push(UiMarkdownRun {
+                    text: marker,
+                    x: 0.0,
+                    y,
+                    width: marker_width,
+                    height: line_height,
+                    font_size,
+                    color: markdown.text_color,
+                    bold: false,
+                    link: None,
+                });
+                markdown.list_indent.max(marker_width + font_size * 0.3)
+            }
+            _ => 0.0,
+        };
+        let mut x = indent;
+        for span in &block.spans {
+            let bold = span.bold || heading;
+            let color = if span.link.is_some() {
+                markdown.link_color
+            } else if heading {
+                markdown.heading_color
+            } else if bold {
+                markdown.bold_color
+            } else {
+                markdown.text_color
+            };
+            let mut run = String::new();
+            let mut run_x = x;
+            for word in span.text.split_inclusive(' ') {
+                let word_width = measure_text(word, font_size).x;
+                if x + measure_text(word.trim_end(), font_size).x > max_x && x > indent {
+                    push_markdown_run(
+                        &mut runs,
+                        &mut run,
+                        run_x,
+                        y,
+                        line_height,
+                        font_size,
+                        color,
+                        bold,
+                        &span.link,
+                    );
+                    y += line_height + markdown.line_spacing;
+                    x = indent;
+                    run_x = x;
+                    let word = word.trim_start();
+                    run.push_str(word);
+                    x += measure_text(word, font_size).x;
+                    continue;
+                }
+                run.push_str(word);
+                x += word_width;
+            }
+            push_markdown_run(
+                &mut runs,
+                &mut run,
+                run_x,
+                y,
+                line_height,
+                font_size,
+                color,
+                bold,
+                &span.link,
+            );
+        }
+        y += line_height + markdown.paragraph_spacing;
+    }
+    runs
+}
+
+#[allow(clippy::too_many_arguments)]
+fn push_markdown_run(
+    runs: &mut Vec<UiMarkdownRun>,
+    text: &mut String,
+    x: f32,
+    y: f32,
+    height: f32,
+    font_size: f32,
+    color: Color,
+    bold: bool,
+    link: &Option<String>,
+) {
+    // trailing space only advances cursor; kp it out of label + hit rect.
+    let trimmed = text.trim_end();
+    if trimmed.is_empty() {
+        text.clear();
+        return;
+    }
+    runs.push(UiMarkdownRun {
+        text: trimmed.to_string(),
+        x,
+        y,
+        width: measure_text(trimmed, font_size).x,
+        height,
+        font_size,
+        color,
+        bold,
+        link: link.clone(),
+    });
+    text.clear();
+}
+
+impl Runtime {
+    pub(super) fn ensure_markdown_internal_nodes(&mut self) {
+        // New nodes start dirty, so the dirty set is enough to find every
+        // markdown node; after that only tracked ids are checked.
+        for &raw_index in self.dirty.dirty_indices() {
+            if let Some((id, node)) = self.nodes.slot_get(raw_index as usize)
+                && matches!(node.data, SceneNodeData::UiMarkdown(_))
+            {
+                self.render_ui.markdown_nodes.insert(id);
+            }
+        }
+        // Re-wrap only when node dirty or layout width moved since last wrap.
+        // Width read from last computed rect.
+        let mut markdown_ids = Vec::with_capacity(self.render_ui.markdown_nodes.len());
+        let nodes = &self.nodes;
+        let computed_rects = &self.render_ui.computed_rects;
+        self.render_ui
+            .markdown_nodes
+            .retain(|&id| match nodes.get(id).map(|node| &node.data) {
+                Some(SceneNodeData::UiMarkdown(markdown)) => {
+                    let width = computed_rects
+                        .get(&id)
+                        .map(|rect| rect.size.x)
+                        .unwrap_or(0.0);
+                    markdown_ids.push((id, width, (width - markdown.synced_width).abs() > 0.5));
+                    true
+                }
+                _ => false,
+            });
+        for (markdown_id, width, stale) in markdown_ids {
+            if stale || self.dirty.ui_flags_at(markdown_id.index() as usize) != 0 {
+                self.sync_markdown_internal_nodes(markdown_id, width);
+            }
+        }
+    }
+
+    /// Mark markdown nodes whose laid-out width differs from wrap width, so
+    /// next extraction re-wraps against real rect.
+    pub(super) fn mark_markdown_width_changes(
+        &mut self,
+        computed: &AHashMap<NodeID, ComputedUiRect>,
+    ) {
+        let changed = self
+            .render_ui
+            .markdown_nodes
+            .iter()
+            .copied()
+            .filter(|&id| {
+                let Some(SceneNodeData::UiMarkdown(markdown)) =
+                    self.nodes.get(id).map(|node| &node.data)
+                else {
+                    return false;
+                };
+                computed
+                    .get(&id)
+                    .is_some_and(|rect| (rect.size.x - markdown.synced_width).abs() > 0.5)
+            })
+            .collect::<Vec<_>>();
+        for id in changed {
+            self.mark_ui_dirty(id, Self::UI_DIRTY_LAYOUT_SELF | Self::UI_DIRTY_COMMANDS);
+        }
+    }
+
+    pub(super) fn markdown_text(markdown: &UiMarkdown) -> Cow<'static, str> {
+        let source = markdown.source.trim();
+        if source.is_empty() {
+            return markdown.markdown.clone();
+        }
+        match perro_io::load_asset(source)
+            .ok()
+            .and_then(|bytes| std::str::from_utf8(&bytes).ok().map(str::to_string))
+        {
+            Some(text) => Cow::Owned(text),
+            None => markdown.markdown.clone(),
+        }
+    }
+
+    pub(super) fn sync_markdown_internal_nodes(&mut self, markdown_id: NodeID, width: f32) {
+        let Some((runs, visible, font, bold_font, mut labels, mut links)) = self
+            .nodes
+            .get(markdown_id)
+            .and_then(|node| match &node.data {
+                SceneNodeData::UiMarkdown(markdown) => Some((
+                    layout_markdown_runs(markdown, &Self::markdown_text(markdown), width),
+                    markdown.visible,
+                    markdown.font.clone(),
+                    markdown.bold_font.clone(),
+                    markdown.internal_labels.clone(),
+                    markdown.internal_links.clone(),
+                )),
+                _ => None,
+            })
+        else {
+            return;
+        };
+        let link_count = runs.iter().filter(|run| run.link.is_some()).count();
+        if labels.len() < runs.len() {
+            labels.resize(runs.len(), NodeID::nil());
+        }
+        if links.len() < link_count {
+            links.resize(link_count, NodeID::nil());
+        }
+        for (idx, label) in labels.iter_mut().enumerate().take(runs.len()) {
+            if !self.tree_list_internal_valid(*label, markdown_id, "label") {
+                *label = self.insert_markdown_label(markdown_id, idx);
+            }
+        }
+        for (idx, link) in links.iter_mut().enumerate().take(link_count) {
+            if !self.tree_list_internal_valid(*link, markdown_id, "button") {
+                *link = self.insert_markdown_link(markdown_id, idx);
+            }
+        }
+
+        let mut targets = Vec::with_capacity(link_count);
+        let mut link_idx = 0;
+        for (run, label_id) in runs.iter().zip(labels.iter().copied()) {
+            let size = UiVector2::pixels(run.width, run.height);
+            let position = UiVector2::pixels(run.x, -run.y);
+            if let Some(node) = self.nodes.get_mut_untracked(label_id)
+                && let SceneNodeData::UiLabel(label) = &mut node.data
+            {
+                label.base.visible = visible;
+                label.base.layout.size = size;
+                label.base.transform.position = position;
+                label.font_size = run.font_size;
+                label.color = run.color;
+                label.font = if run.bold {
+                    bold_font.clone()
+                } else {
+                    font.clone()
+                };
+                if label.text != run.text {
+                    label.set_text(run.text.clone());
+                }
+            }
+            let Some(target) = &run.link else {
+                continue;
+            };
+            if let Some(node) = self.nodes.get_mut_untracked(links[link_idx])
+                && let SceneNodeData::UiButton(button) = &mut node.data
+            {
+                button.base.visible = visible;
+                button.base.layout.size = size;
+                button.base.transform.position = position;
+                button.hover_style.fill = run.color.with_alpha(0.16);
+                button.pressed_style.fill = run.color.with_alpha(0.28);
+            }
+            targets.push(Cow::Owned(target.clone()));
+            link_idx += 1;
+        }
+        for id in labels.iter().copied().skip(runs.len()) {
+            self.hide_tree_list_internal_node(id);
+        }
+        for id in links.iter().copied().skip(link_count) {
+            self.hide_tree_list_internal_node(id);
+        }
+
+        if let Some(node) = self.nodes.get_mut_untracked(markdown_id)
+            && let SceneNodeData::UiMarkdown(markdown) = &mut node.data
+        {
+            markdown.synced_width = width;
+            markdown.internal_labels = labels.clone();
+            markdown.internal_links = links.clone();
+            markdown.link_targets = targets;
+        }
+        self.mark_ui_dirty(
+            markdown_id,
+            Self::UI_DIRTY_LAYOUT_SELF | Self::UI_DIRTY_COMMANDS,
+        );
+        for id in labels.into_iter().chain(links) {
+            if !id.is_nil() {
+                self.mark_ui_dirty(
+                    id,
+                    Self::UI_DIRTY_LAYOUT_SELF
+                        | Self::UI_DIRTY_LAYOUT_PARENT
+                        | Self::UI_DIRTY_COMMANDS,
+                );
+            }
+        }
+    }
+
+    pub(super) fn insert_markdown_label(&mut self, markdown_id: NodeID, idx: usize) -> NodeID {
+        let mut label = perro_ui::UiLabel::new();
+        label.base.layout.anchor = UiAnchor::TopLeft;
+        label.base.layout.z_index = 1;
+        label.base.input_enabled = false;
+        label.base.mouse_filter = perro_ui::UiMouseFilter::Pass;
+        label.h_align = perro_ui::UiTextAlign::Start;
+        label.v_align = perro_ui::UiTextAlign::Center;
+        // fixed px size; run rect is sized from font metrics.
+        label.text_size_ratio = 0.0;
+        self.insert_color_picker_internal_node(
+            markdown_id,
+            format!("__perro_markdown_label_{idx}"),
+            SceneNodeData::UiLabel(Box::new(label)),
+        )
+    }
+
+    pub(super) fn insert_markdown_link(&mut self, markdown_id: NodeID, idx: usize) -> NodeID {
+        let mut button = UiButton::new();
+        button.base.layout.anchor = UiAnchor::TopLeft;
+        button.base.layout.z_index = 2;
+        button.style.fill = Color::TRANSPARENT;
+        button.style.stroke = Color::TRANSPARENT;
+        button.hover_style.stroke = Color::TRANSPARENT;
+        button.pressed_style.stroke = Color::TRANSPARENT;
+        self.insert_color_picker_internal_node(
+            markdown_id,
+            format!("__perro_markdown_link_{idx}"),
+            SceneNodeData::UiButton(Box::new(button)),
+        )
+    }
+
+    pub(super) fn process_markdown_link_click(&mut self, node: NodeID) {
+        let Some((markdown_id, target, signals)) =
+            self.nodes.iter().find_map(|(id, scene_node)| {
+                let SceneNodeData::UiMarkdown(markdown) = &scene_node.data else {
+                    return None;
+                };
+                markdown
+                    .link_target_for(node)
+                    .map(|target| (id, target.to_string(), markdown.link_signals.clone()))
+            })
+        else {
+            return;
+        };
+        let params = [Variant::from(markdown_id), Variant::from(target.as_str())];
+        for signal in signals {
+            self.queue_ui_signal(signal, &params);
+        }
+    }
+}
@@ -250,7 +250,7 @@ mod controls {
         let mut runtime = Runtime::new();
         runtime.set_viewport_size(800, 600);
         let node = insert_button(&mut runtime, [120.0, 40.0]);
-        runtime.nodes.get_mut(node).expect("button").name = Cow::Borrowed("play");
+        runtime.nodes.get_mut(node).expect("button").set_name("play");
 
         runtime.extract_render_ui_commands();
         runtime.drain_render_commands(&mut Vec::new());
@@ -957,6 +957,66 @@ mod controls {
         assert_eq!(runtime.render_ui.focused_ui_node, Some(up));
     }
 
+    #[test]
+    fn ui_input_map_actions_drive_focus_and_accept() {
+        let mut runtime = Runtime::new();
+        runtime.set_viewport_size(800, 600);
+        runtime
+            .input
+            .set_input_map(perro_input_api::InputMap::from_actions(vec![
+                perro_input_api::InputAction::new(
+                    "ui_right",
+                    vec![perro_input_api::InputBinding::Key(KeyCode::ArrowRight)],
+                ),
+                perro_input_api::InputAction::new(
+                    "ui_focus_next",
+                    vec![perro_input_api::InputBinding::Key(KeyCode::KeyE)],
+                ),
+                perro_input_api::InputAction::new(
+                    "ui_accept",
+                    vec![perro_input_api::InputBinding::Key(KeyCode::KeyF)],
+                ),
+            ]));
+        let left = insert_button_at(&mut runtime, [120.0, 40.0], -160.0, 0.0);
+        let right = insert_button_at(&mut runtime, [120.0, 40.0], 160.0, 0.0);
+
+        runtime.extract_render_ui_commands();
+        runtime.drain_render_commands(&mut Vec::new());
+        runtime.clear_dirty_flags();
+
+        tap_key_and_extract(&mut runtime, KeyCode::KeyE);
+        assert_eq!(runtime.render_ui.focused_ui_node, Some(left));
+
+        tap_key_and_extract(&mut runtime, KeyCode::ArrowRight);
+        assert_eq!(runtime.render_ui.focused_ui_node, Some(right));
+
+        runtime.begin_input_frame();
+        runtime.set_key_state(KeyCode::KeyF, true);
+        runtime.extract_render_ui_commands();
+        assert_eq!(runtime.render_ui.nav_pressed_button, Some(right));
+    }
+
+    #[test]
+    fn initial_focus_claims_focus_once() {
+        let mut runtime = Runtime::new();
+        runtime.set_viewport_size(800, 600);
+        let _top = insert_button_at(&mut runtime, [120.0, 40.0], 0.0, 120.0);
+        let mut button = perro_ui::UiButton::new();
+        button.layout.size = UiVector2::pixels(120.0, 40.0);
+        button.initial_focus = true;
+        let start = insert_ui_node(&mut runtime, SceneNodeData::UiButton(Box::new(button)));
+
+        runtime.extract_render_ui_commands();
+        assert_eq!(runtime.render_ui.focused_ui_node, Some(start));
+
+        click_mouse_and_extract(&mut runtime, 700.0, 550.0);
+        assert_eq!(runtime.render_ui.focused_ui_node, None);
+
+        runtime.begin_input_frame();
+        runtime.extract_render_ui_commands();
+        assert_eq!(runtime.render_ui.focused_ui_node, None);
+    }
+
     #[test]
     fn gamepad_stick_nav_repeats_after_delay() {
         let mut runtime = Runtime::new();
@@ -1058,4 +1118,73 @@ mod controls {
         runtime.extract_render_ui_commands();
         assert_eq!(runtime.render_ui.focused_ui_node, Some(right));
     }
+
+    #[test]
+    fn markdown_wraps_runs_and_links_fire_signal_with_target() {
+        let mut runtime = Runtime::new();
+        runtime.set_viewport_size(800, 600);
+        let mut markdown = perro_ui::UiMarkdown::new();
+        markdown.layout.size = UiVector2::pixels(240.0, 200.0);
+        markdown.font_size = 10.0;
+        markdown.set_markdown(
+            "# Help\n\nThis paragraph is long enough to wrap twice at this width.\n\n- press [start](begin)",
+        );
+        markdown.link_signals = vec![SignalID::from_string("help_link")];
+        let markdown_id =
+            insert_ui_node(&mut runtime, SceneNodeData::UiMarkdown(Box::new(markdown)));
+
+        // 1st pass wraps before layout knows width; 2nd re-wraps at 240px.
+        runtime.extract_render_ui_commands();
+        runtime.drain_render_commands(&mut Vec::new());
+        runtime.clear_dirty_flags();
+        runtime.extract_render_ui_commands();
+        runtime.drain_render_commands(&mut Vec::new());
+        runtime.clear_dirty_flags();
+
+        let (labels, links, synced_width) = runtime
+            .nodes
+            .get(markdown_id)
+            .and_then(|node| match &node.data {
+                SceneNodeData::UiMarkdown(markdown) => Some((
+                    markdown.internal_labels.clone(),
+                    markdown.internal_links.clone(),
+                    markdown.synced_width,
+                )),
+                _ => None,
+            })
+            .expect("markdown internals");
+        assert_eq!(synced_width, 240.0);
+        assert_eq!(links.len(), 1);
+        let paragraph_rows = labels
+            .iter()
+            .filter_map(|id| runtime.nodes.get(*id))
+            .filter(|node| matches!(&node.data, SceneNodeData::UiLabel(label) if label.font_size == 10.0))
+            .count();
+        assert!(paragraph_rows >= 4, "paragraph wraps + bullet + link text");
+        let markdown_rect = runtime.render_ui.computed_rects[&markdown_id];
+        assert!(labels.iter().all(|id| {
+            let rect = runtime.render_ui.computed_rects[id];
+            rect.max().x <= markdown_rect.max().x + 0.5
+        }));
+
+        let link_rect = runtime.render_ui.computed_rects[&links[0]];
+        click_mouse_and_extract(
+            &mut runtime,
+            link_rect.center.x + 400.0,
+            300.0 - link_rect.center.y,
+        );
+        runtime.begin_input_frame();
+        runtime.extract_render_ui_commands();
+        assert!(
+            runtime
+                .signal_runtime
+                .queued_ui_signals
+                .iter()
+                .any(|(signal, params)| {
+                    *signal == SignalID::from_string("help_link")
+                        && params.first() == Some(&Variant::from(markdown_id))
+                        && params.get(1) == Some(&Variant::from("begin"))
+                })
+        );
+    }
 }
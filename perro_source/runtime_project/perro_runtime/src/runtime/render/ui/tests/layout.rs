@@ -175,7 +175,7 @@ mod layout {
     fn button_event_signals_include_named_and_custom_signals() {
         let mut runtime = Runtime::new();
         let named = insert_button(&mut runtime, [120.0, 40.0]);
-        runtime.nodes.get_mut(named).expect("named button").name = Cow::Borrowed("play");
+        runtime.nodes.get_mut(named).expect("named button").set_name("play");
         assert_eq!(
             runtime.button_event_signals(named, "click"),
             vec![SignalID::from_string("play_clicked")]
@@ -189,7 +189,7 @@ mod layout {
             .pressed_signals
             .push(SignalID::from_string("custom_b"));
         let custom = insert_ui_node(&mut runtime, SceneNodeData::UiButton(Box::new(button)));
-        runtime.nodes.get_mut(custom).expect("custom button").name = Cow::Borrowed("fire");
+        runtime.nodes.get_mut(custom).expect("custom button").set_name("fire");
         assert_eq!(
             runtime.button_event_signals(custom, "pressed"),
             vec![
@@ -208,7 +208,7 @@ mod layout {
             .clicked_signals
             .push(SignalID::from_string("custom_click"));
         let node = insert_ui_node(&mut runtime, SceneNodeData::UiImageButton(Box::new(button)));
-        runtime.nodes.get_mut(node).expect("image button").name = Cow::Borrowed("icon");
+        runtime.nodes.get_mut(node).expect("image button").set_name("icon");
 
         assert_eq!(
             runtime.button_event_signals(node, "click"),
@@ -237,7 +237,7 @@ mod layout {
                 .clicked_signals
                 .push(SignalID::from_string("custom_click"));
         }
-        runtime.nodes.get_mut(node).expect("named button").name = Cow::Borrowed("play");
+        runtime.nodes.get_mut(node).expect("named button").set_name("play");
 
         assert!(runtime.button_event_signals(node, "hover_enter").is_empty());
         assert!(runtime.button_event_signals(node, "pressed").is_empty());
@@ -262,7 +262,7 @@ mod layout {
                 .clicked_signals
                 .push(SignalID::from_string("custom_click"));
         }
-        runtime.nodes.get_mut(node).expect("named button").name = Cow::Borrowed("play");
+        runtime.nodes.get_mut(node).expect("named button").set_name("play");
 
         assert!(runtime.button_event_signals(node, "hover_enter").is_empty());
         assert!(runtime.button_event_signals(node, "pressed").is_empty());
@@ -274,7 +274,7 @@ mod layout {
         let mut runtime = Runtime::new();
         runtime.set_viewport_size(800, 600);
         let button = insert_button_at(&mut runtime, [120.0, 40.0], 0.0, 0.0);
-        runtime.nodes.get_mut(button).expect("button").name = Cow::Borrowed("play");
+        runtime.nodes.get_mut(button).expect("button").set_name("play");
 
         let calls = Arc::new(AtomicUsize::new(0));
         let script_id = runtime.create::<Node3D>();
@@ -345,7 +345,7 @@ mod layout {
             &mut runtime,
             SceneNodeData::UiTextBox(Box::new(perro_ui::UiTextBox::new())),
         );
-        runtime.nodes.get_mut(named).expect("named text box").name = Cow::Borrowed("name");
+        runtime.nodes.get_mut(named).expect("named text box").set_name("name");
         assert_eq!(
             runtime.text_edit_event_signals(named, "focused"),
             vec![SignalID::from_string("name_focused")]
@@ -372,7 +372,7 @@ mod layout {
             .nodes
             .get_mut(custom)
             .expect("custom text block")
-            .name = Cow::Borrowed("bio");
+            .set_name("bio");
         assert_eq!(
             runtime.text_edit_event_signals(custom, "hovered"),
             vec![
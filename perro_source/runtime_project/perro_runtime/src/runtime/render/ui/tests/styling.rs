@@ -429,6 +429,53 @@ mod styling {
         assert!((scroll - 100.0).abs() < 1.0e-4);
     }
 
+    #[test]
+    fn scroll_container_content_drag_scrolls_when_enabled() {
+        let mut runtime = Runtime::new();
+        runtime.set_viewport_size(800, 600);
+
+        let mut scroller = UiScrollContainer::new();
+        scroller.layout.size = UiVector2::pixels(200.0, 100.0);
+        scroller.drag_scroll = true;
+        let scroller_id = insert_ui_node(
+            &mut runtime,
+            SceneNodeData::UiScrollContainer(Box::new(scroller)),
+        );
+
+        let mut list = UiVLayout::new();
+        list.layout.size = UiVector2::pixels(200.0, 300.0);
+        let list_id = insert_ui_node(&mut runtime, list.into());
+        attach_child(&mut runtime, scroller_id, list_id);
+
+        runtime.extract_render_ui_commands();
+        runtime.drain_render_commands(&mut Vec::new());
+        runtime.clear_dirty_flags();
+
+        runtime.begin_input_frame();
+        runtime.set_mouse_position(400.0, 300.0);
+        runtime.set_mouse_button_state(MouseButton::Left, true);
+        runtime.extract_render_ui_commands();
+
+        runtime.begin_input_frame();
+        runtime.set_mouse_position(400.0, 260.0);
+        runtime.extract_render_ui_commands();
+
+        let scroll = runtime
+            .nodes
+            .get(scroller_id)
+            .and_then(|node| match &node.data {
+                SceneNodeData::UiScrollContainer(scroller) => Some(scroller.scroll.y),
+                _ => None,
+            })
+            .expect("scroller node");
+        assert!((scroll - 40.0).abs() < 1.0e-4);
+
+        runtime.begin_input_frame();
+        runtime.set_mouse_button_state(MouseButton::Left, false);
+        runtime.extract_render_ui_commands();
+        assert!(runtime.render_ui.drag_scroll.is_none());
+    }
+
     #[test]
     fn scroll_container_track_click_updates_scroll() {
         let mut runtime = Runtime::new();
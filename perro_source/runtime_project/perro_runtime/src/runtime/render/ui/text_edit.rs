@@ -15,10 +15,16 @@ pub(in crate::runtime::render_ui) fn text_edit_command(ctx: TextEditCommandCtx<'
         edit,
         multiline,
         focused,
+        ime_preedit,
     } = ctx;
     let focused_style = &edit.focused_style;
     let style = &edit.style;
     let style_scale = ui_style_scale(scale);
+    let (text, caret, anchor) = if focused && !ime_preedit.is_empty() {
+        text_with_ime_preedit(edit, ime_preedit)
+    } else {
+        (edit.text.to_string(), edit.caret, edit.anchor)
+    };
     UiCommand::UpsertTextEdit {
         node,
         rect,
@@ -88,7 +94,7 @@ pub(in crate::runtime::render_ui) fn text_edit_command(ctx: TextEditCommandCtx<'
             },
             style_scale,
         ),
-        text: Cow::Owned(edit.text.to_string()),
+        text: Cow::Owned(text),
         placeholder: Cow::Owned(edit.placeholder.to_string()),
         color: Runtime::color_modulate(edit.color, modulate),
         placeholder_color: Runtime::color_modulate(edit.placeholder_color, modulate),
@@ -100,13 +106,29 @@ pub(in crate::runtime::render_ui) fn text_edit_command(ctx: TextEditCommandCtx<'
         v_align: text_align_state(edit.v_align),
         padding: scaled_text_edit_padding(edit, scale),
         scroll: [edit.h_scroll, edit.v_scroll],
-        caret: edit.caret,
-        anchor: edit.anchor,
+        caret,
+        anchor,
         focused,
         multiline,
     }
 }
 
+/// Draw IME composition in place of selection, caret after it. Edit text
+/// stays untouched until commit arrives as normal text input.
+pub(in crate::runtime::render_ui) fn text_with_ime_preedit(
+    edit: &UiTextEdit,
+    preedit: &str,
+) -> (String, usize, usize) {
+    let (start, end) = selection_range(edit);
+    let text = edit.text.as_ref();
+    let mut out = String::with_capacity(text.len() + preedit.len());
+    out.push_str(&text[..start]);
+    out.push_str(preedit);
+    out.push_str(&text[end..]);
+    let caret = start + preedit.len();
+    (out, caret, caret)
+}
+
 /// Font size the renderer actually draws with; input hit-testing must use
 /// this same value or caret placement drifts when window != virtual size.
 pub(in crate::runtime::render_ui) fn text_edit_effective_font_size(
@@ -0,0 +1,170 @@
+use super::Runtime;
+use perro_ids::{NodeID, ScriptMemberID};
+use perro_modules::rollback::{RollbackSession, TickSnapshot};
+use perro_runtime_api::sub_apis::{ROLLBACK_LOAD_METHOD, ROLLBACK_SAVE_METHOD, ScriptAPI};
+use perro_variant::Variant;
+
+/// `ctx.run.Rollback()`: the running session + which scripts it snapshots.
+#[derive(Default)]
+pub(crate) struct RollbackState {
+    /// `None` when no session runs
+    session: Option<RollbackSession>,
+    /// tracked scripts; `true` = saved/loaded through their own hooks
+    tracked: Vec<(NodeID, bool)>,
+    /// set while `resimulate_rollback` replays past ticks
+    resimulating: bool,
+}
+
+impl Runtime {
+    pub(crate) fn start_rollback_session(&mut self, players: usize, window: usize) {
+        let mut session = RollbackSession::new(players, window);
+        session.push_snapshot(self.capture_rollback_snapshot());
+        self.rollback.session = Some(session);
+    }
+
+    pub(crate) fn stop_rollback_session(&mut self) {
+        self.rollback.session = None;
+    }
+
+    #[inline]
+    pub(crate) fn is_rollback_active(&self) -> bool {
+        self.rollback.session.is_some()
+    }
+
+    #[inline]
+    pub(crate) fn is_rollback_resimulating(&self) -> bool {
+        self.rollback.resimulating
+    }
+
+    /// Scene reset: no session, nothing tracked.
+    pub(crate) fn clear_rollback(&mut self) {
+        self.rollback = RollbackState::default();
+    }
+
+    /// Track `id` (or switch how it is saved). false when it has no script.
+    pub(crate) fn track_rollback_script(&mut self, id: NodeID, hooks: bool) -> bool {
+        if self.scripts.instance_index_for_id(id).is_none() {
+            return false;
+        }
+        match self
+            .rollback
+            .tracked
+            .iter_mut()
+            .find(|(tracked, _)| *tracked == id)
+        {
+            Some(entry) => entry.1 = hooks,
+            None => self.rollback.tracked.push((id, hooks)),
+        }
+        true
+    }
+
+    pub(crate) fn untrack_rollback_script(&mut self, id: NodeID) -> bool {
+        let before = self.rollback.tracked.len();
+        self.rollback.tracked.retain(|(tracked, _)| *tracked != id);
+        self.rollback.tracked.len() != before
+    }
+
+    /// Ignored while resimulating, so fixed-update code can confirm local
+    /// input every tick w/o overwriting it during a replay.
+    pub(crate) fn set_rollback_input(&mut self, player: usize, tick: u64, input: Variant) -> bool {
+        if self.rollback.resimulating {
+            return false;
+        }
+        self.rollback
+            .session
+            .as_mut()
+            .is_some_and(|session| session.set_input(player, tick, input))
+    }
+
+    /// `player`'s input 4 the current sim tick.
+    pub(crate) fn rollback_input_now(&mut self, player: usize) -> Variant {
+        let tick = self.sim_clock_tick();
+        self.rollback
+            .session
+            .as_mut()
+            .map_or(Variant::Null, |session| session.input(player, tick))
+    }
+
+    /// End-of-tick snapshot; runs after every fixed update while a session
+    /// is active.
+    pub(crate) fn record_rollback_tick(&mut self) {
+        if self.rollback.session.is_none() {
+            return;
+        }
+        let snapshot = self.capture_rollback_snapshot();
+        if let Some(session) = &mut self.rollback.session {
+            session.push_snapshot(snapshot);
+        }
+    }
+
+    /// Replay the fixed ticks a late input mispredicted, b4 the next tick
+    /// runs. Only tracked script state + the sim clock roll back; physics
+    /// bodies keep their present state and are not stepped during the replay.
+    pub(crate) fn resimulate_rollback(&mut self, fixed_delta_time: f32) -> usize {
+        let Some(session) = &mut self.rollback.session else {
+            return 0;
+        };
+        let Some(present) = session.current_tick() else {
+            return 0;
+        };
+        let Some(snapshot) = session.take_rollback() else {
+            return 0;
+        };
+        self.restore_rollback_snapshot(snapshot);
+        self.rollback.resimulating = true;
+        let mut ticks = 0;
+        while self.sim_clock_tick() < present {
+            self.advance_sim_clock(fixed_delta_time);
+            self.schedules.snapshot_fixed(&self.scripts);
+            self.run_fixed_schedule();
+            self.nodes.refresh_packed_children();
+            self.propagate_pending_transform_dirty();
+            self.record_rollback_tick();
+            ticks += 1;
+        }
+        self.rollback.resimulating = false;
+        ticks
+    }
+
+    fn capture_rollback_snapshot(&mut self) -> TickSnapshot {
+        let tracked = self.rollback.tracked.clone();
+        let mut states = Vec::with_capacity(tracked.len());
+        for (id, hooks) in tracked {
+            let state = if hooks {
+                let save = ScriptMemberID::from_string(ROLLBACK_SAVE_METHOD);
+                ScriptAPI::call_method(self, id, save, &[])
+            } else {
+                self.script_save_state(id)
+            };
+            states.push((id, state));
+        }
+        TickSnapshot {
+            tick: self.sim_clock_tick(),
+            elapsed: self.sim_clock_elapsed(),
+            states,
+        }
+    }
+
+    fn restore_rollback_snapshot(&mut self, snapshot: TickSnapshot) {
+        self.restore_sim_clock(snapshot.tick, snapshot.elapsed);
+        for (id, state) in snapshot.states {
+            // untracked since the snapshot -> keep its present state
+            let hooks = self
+                .rollback
+                .tracked
+                .iter()
+                .find(|(tracked, _)| *tracked == id)
+                .map(|(_, hooks)| *hooks);
+            match hooks {
+                Some(true) => {
+                    let load = ScriptMemberID::from_string(ROLLBACK_LOAD_METHOD);
+                    ScriptAPI::call_method(self, id, load, &[state]);
+                }
+                Some(false) => {
+                    self.script_restore_state(id, state);
+                }
+                None => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use super::Runtime;
+use crate::runtime_project::ProviderMode;
+use ahash::AHashMap;
+use perro_ids::NodeID;
+use perro_runtime_api::sub_apis::{NodeAPI, ScriptAPI};
+use perro_variant::Variant;
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+/// Min time between `.scn` mtime checks.
+const SCENE_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Dev-run bookkeeping for scene hot reload.
+#[derive(Default)]
+pub(crate) struct SceneHotReloadState {
+    last_poll: Option<Instant>,
+    /// Loaded scene root -> source path, for every disk-loaded instance.
+    sources: AHashMap<NodeID, String>,
+}
+
+impl Runtime {
+    /// Remember where a loaded scene instance came from so edits to the file
+    /// can replace it. Dev runs only.
+    pub(crate) fn track_scene_source(&mut self, scene_root: NodeID, path: &str) {
+        if self.provider_mode != ProviderMode::Dynamic {
+            return;
+        }
+        perro_io::watch_asset(path);
+        self.scene_hot_reload
+            .sources
+            .insert(scene_root, path.to_string());
+    }
+
+    /// Dev runs only: reload every live instance of a `.scn` that changed on
+    /// disk. Parse errors keep the running scene.
+    pub(crate) fn poll_scene_hot_reload(&mut self) {
+        if self.provider_mode != ProviderMode::Dynamic || self.scene_hot_reload.sources.is_empty() {
+            return;
+        }
+        let now = Instant::now();
+        if self
+            .scene_hot_reload
+            .last_poll
+            .is_some_and(|last| now.duration_since(last) < SCENE_POLL_INTERVAL)
+        {
+            return;
+        }
+        self.scene_hot_reload.last_poll = Some(now);
+
+        for path in perro_io::poll_changed_assets() {
+            match self.hot_reload_scene(&path) {
+                Ok(0) => {}
+                Ok(count) => eprintln!("[perro][scene] reloaded {path} ({count} instance(s))"),
+                Err(err) => {
+                    eprintln!("[perro][scene] reload of {path} failed, keeping old scene: {err}")
+                }
+            }
+        }
+    }
+
+    /// Replace every live instance of `path` with a fresh load of the file.
+    ///
+    /// Each new instance takes the old root's parent and global transform.
+    /// Scripts on nodes that exist in both versions, matched by name path
+    /// from the scene root, get their saved state restored. Returns the
+    /// number of instances replaced.
+    pub(crate) fn hot_reload_scene(&mut self, path: &str) -> Result<usize, String> {
+        let nodes = &self.nodes;
+        self.scene_hot_reload
+            .sources
+            .retain(|root, _| nodes.get(*root).is_some());
+        let mut roots: Vec<NodeID> = self
+            .scene_hot_reload
+            .sources
+            .iter()
+            .filter(|(_, source)| source.as_str() == path)
+            .map(|(root, _)| *root)
+            .collect();
+        if roots.is_empty() {
+            return Ok(0);
+        }
+        roots.sort();
+
+        // Other cached scenes may import this one; drop everything.
+        self.scene_cache.borrow_mut().clear();
+        self.prepared_scene_cache.borrow_mut().clear();
+        // Parse before touching the tree so a half-saved file is harmless.
+        let scene = self.get_or_load_dynamic_scene_cached(path)?;
+        self.get_or_prepare_scene_cached(path, scene.as_ref())?;
+
+        for old_root in &roots {
+            self.replace_scene_instance(*old_root, path)?;
+        }
+        Ok(roots.len())
+    }
+
+    fn replace_scene_instance(&mut self, old_root: NodeID, path: &str) -> Result<(), String> {
+        let old_owner = self
+            .scene_ownership_roots
+            .get(&old_root)
+            .copied()
+            .unwrap_or(old_root);
+        let old_parent = self
+            .nodes
+            .get(old_root)
+            .map(|node| node.get_parent())
+            .unwrap_or_else(NodeID::nil);
+        let global_2d = self.get_global_transform_2d(old_root);
+        let global_3d = self.get_global_transform_3d(old_root);
+        let saved = self.save_instance_script_states(old_owner, old_root);
+
+        let new_root = self.load_scene_at_runtime(path)?;
+        if !old_parent.is_nil() && old_parent != old_owner {
+            let _ = NodeAPI::reparent(self, old_parent, new_root);
+        }
+        if let Some(global) = global_2d {
+            let _ = self.set_global_transform_2d(new_root, global);
+        }
+        if let Some(global) = global_3d {
+            let _ = self.set_global_transform_3d(new_root, global);
+        }
+        let new_owner = self
+            .scene_ownership_roots
+            .get(&new_root)
+            .copied()
+            .unwrap_or(new_root);
+        for (id, name_path) in self.instance_name_paths(new_owner, new_root) {
+            if let Some(state) = saved.get(&name_path) {
+                let _ = self.script_restore_state(id, state.clone());
+            }
+        }
+
+        self.transfer_scene_instance(old_root, new_root);
+
+        let _ = NodeAPI::remove_node(self, old_root);
+        // A root reparented out of its owner is not under it anymore.
+        if self.nodes.get(old_root).is_some() {
+            let _ = NodeAPI::remove_node(self, old_root);
+        }
+        if self.active_route_root == Some(old_root) {
+            self.active_route_root = Some(new_root);
+        }
+        Ok(())
+    }
+
+    fn save_instance_script_states(
+        &mut self,
+        owner: NodeID,
+        root: NodeID,
+    ) -> AHashMap<String, Variant> {
+        let mut saved = AHashMap::new();
+        for (id, name_path) in self.instance_name_paths(owner, root) {
+            let state = self.script_save_state(id);
+            if !matches!(state, Variant::Null) {
+                saved.entry(name_path).or_insert(state);
+            }
+        }
+        saved
+    }
+
+    /// Every node of a loaded instance with its `/`-joined name path. Top
+    /// level scene nodes sit under the hidden owner; the root may have been
+    /// reparented out of it.
+    fn instance_name_paths(&self, owner: NodeID, root: NodeID) -> Vec<(NodeID, String)> {
+        let mut tops: Vec<NodeID> = if owner == root {
+            Vec::new()
+        } else {
+            self.nodes
+                .children(owner)
+                .map(<[NodeID]>::to_vec)
+                .unwrap_or_default()
+        };
+        if !tops.contains(&root) {
+            tops.push(root);
+        }
+        let mut out = Vec::new();
+        let mut stack: Vec<(NodeID, String)> = tops
+            .into_iter()
+            .filter_map(|id| Some((id, self.nodes.get(id)?.name.to_string())))
+            .collect();
+        while let Some((id, name_path)) = stack.pop() {
+            if let Some(children) = self.nodes.children(id) {
+                for child in children {
+                    if let Some(node) = self.nodes.get(*child) {
+                        stack.push((*child, format!("{name_path}/{}", node.name)));
+                    }
+                }
+            }
+            out.push((id, name_path));
+        }
+        out
+    }
+}
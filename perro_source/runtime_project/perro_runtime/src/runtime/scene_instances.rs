@@ -0,0 +1,118 @@
+use super::Runtime;
+use ahash::AHashSet;
+use perro_ids::NodeID;
+use perro_runtime_api::sub_apis::NodeAPI;
+
+/// Scenes loaded side by side with the boot scene, plus per-scene pause.
+#[derive(Default)]
+pub(crate) struct SceneInstanceState {
+    /// Roots from `load_scene_additive`, in load order.
+    additive_roots: Vec<NodeID>,
+    /// Paused scene roots and their ownership roots. Both are stored so
+    /// top-level siblings of the root pause too.
+    paused: AHashSet<NodeID>,
+}
+
+impl Runtime {
+    /// Load a scene next to the ones already running. The returned root can
+    /// be paused or unloaded without touching other scenes.
+    pub(crate) fn load_scene_additive_at_runtime(&mut self, path: &str) -> Result<NodeID, String> {
+        let root = self.load_scene_at_runtime(path)?;
+        self.scene_instances.additive_roots.push(root);
+        Ok(root)
+    }
+
+    /// Remove a loaded scene instance and everything it owns. Returns
+    /// `false` when `root` is not a live scene root.
+    pub(crate) fn unload_scene_at_runtime(&mut self, root: NodeID) -> bool {
+        if !self.scene_ownership_roots.contains_key(&root) {
+            return false;
+        }
+        if !NodeAPI::remove_node(self, root) {
+            return false;
+        }
+        if self.active_route_root == Some(root) {
+            self.active_route_root = None;
+            self.active_route_href = None;
+        }
+        true
+    }
+
+    /// Additive scene roots that are still loaded, in load order.
+    pub(crate) fn additive_scene_roots(&self) -> &[NodeID] {
+        &self.scene_instances.additive_roots
+    }
+
+    /// Pause or resume one scene instance. Paused scenes keep rendering but
+    /// skip script callbacks, internal node updates, and physics.
+    pub(crate) fn set_scene_paused(&mut self, root: NodeID, paused: bool) -> bool {
+        let Some(owner) = self.scene_ownership_roots.get(&root).copied() else {
+            return false;
+        };
+        let state = &mut self.scene_instances;
+        let changed = if paused {
+            state.paused.insert(owner) | state.paused.insert(root)
+        } else {
+            state.paused.remove(&owner) | state.paused.remove(&root)
+        };
+        if changed {
+            // body descs cache on physics_revision; suspension feeds `enabled`.
+            self.nodes.mark_physics_change();
+        }
+        true
+    }
+
+    pub(crate) fn is_scene_paused(&self, root: NodeID) -> bool {
+        self.scene_instances.paused.contains(&root)
+    }
+
+    pub(crate) fn is_in_paused_scene(&self, node: NodeID) -> bool {
+        if self.scene_instances.paused.is_empty() {
+            return false;
+        }
+        let mut current = node;
+        let mut hops = 0usize;
+        let max_hops = self.nodes.len().saturating_add(1);
+        while !current.is_nil() && hops < max_hops {
+            if self.scene_instances.paused.contains(&current) {
+                return true;
+            }
+            let Some(scene_node) = self.nodes.get(current) else {
+                return false;
+            };
+            current = scene_node.parent;
+            hops += 1;
+        }
+        false
+    }
+
+    /// Drop bookkeeping for removed scene instances.
+    pub(crate) fn forget_removed_scene_instances(&mut self, removed: &AHashSet<NodeID>) {
+        self.scene_instances
+            .additive_roots
+            .retain(|root| !removed.contains(root));
+        self.scene_instances
+            .paused
+            .retain(|root| !removed.contains(root));
+    }
+
+    /// Carry additive / paused state from a replaced instance to its
+    /// replacement (scene hot reload).
+    pub(crate) fn transfer_scene_instance(&mut self, old_root: NodeID, new_root: NodeID) {
+        if let Some(slot) = self
+            .scene_instances
+            .additive_roots
+            .iter_mut()
+            .find(|root| **root == old_root)
+        {
+            *slot = new_root;
+        }
+        if self.is_scene_paused(old_root) {
+            self.set_scene_paused(new_root, true);
+        }
+    }
+
+    pub(crate) fn clear_scene_instances(&mut self) {
+        self.scene_instances = SceneInstanceState::default();
+    }
+}
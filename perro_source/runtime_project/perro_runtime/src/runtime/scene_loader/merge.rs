@@ -1,7 +1,7 @@
 use super::{PendingScriptAttach, prepare::PreparedScene};
 use crate::Runtime;
 use ahash::AHashMap;
-use perro_ids::{NodeID, ScriptMemberID};
+use perro_ids::{NodeID, ScriptMemberID, SignalID};
 use perro_nodes::animation_player::AnimationObjectBinding;
 use perro_nodes::animation_tree::AnimationTreeAnimation;
 use perro_nodes::{SceneNode, SceneNodeData};
@@ -9,13 +9,21 @@ use perro_resource_api::ResourceWindow;
 use perro_scene::SceneValue;
 use perro_structs::{IVector2, IVector3, IVector4, UVector2, UVector3, UVector4, Vector2, Vector3};
 use perro_variant::Variant;
+use std::collections::BTreeMap;
 use std::sync::Arc;
-use std::{borrow::Cow, collections::BTreeMap};
 
 pub(super) struct MergePreparedSceneResult {
     pub(super) scene_root: NodeID,
     pub(super) ownership_root: NodeID,
     pub(super) script_nodes: Vec<PendingScriptAttach>,
+    pub(super) connections: Vec<SceneConnectionBind>,
+}
+
+/// A scene `connect` line resolved to live nodes; bound after scripts attach.
+pub(super) struct SceneConnectionBind {
+    pub(super) target: NodeID,
+    pub(super) signal: SignalID,
+    pub(super) method: ScriptMemberID,
 }
 
 type AnimationPlayerSceneBindings = Vec<(String, u32)>;
@@ -37,6 +45,9 @@ fn set_joint_body(
         SceneNodeData::FixedJoint2D(joint) => {
             set_joint_body_2d(&mut joint.body_a, &mut joint.body_b, field, target)
         }
+        SceneNodeData::SpringJoint2D(joint) => {
+            set_joint_body_2d(&mut joint.body_a, &mut joint.body_b, field, target)
+        }
         SceneNodeData::BallJoint3D(joint) => {
             set_joint_body_3d(&mut joint.body_a, &mut joint.body_b, field, target)
         }
@@ -46,6 +57,9 @@ fn set_joint_body(
         SceneNodeData::FixedJoint3D(joint) => {
             set_joint_body_3d(&mut joint.body_a, &mut joint.body_b, field, target)
         }
+        SceneNodeData::SpringJoint3D(joint) => {
+            set_joint_body_3d(&mut joint.body_a, &mut joint.body_b, field, target)
+        }
         _ => {}
     }
 }
@@ -105,7 +119,7 @@ pub(super) fn merge_prepared_scene(
     }
 
     let mut engine_root = SceneNode::new(SceneNodeData::Node);
-    engine_root.name = Cow::Borrowed("Game Root");
+    engine_root.set_name("Game Root");
     engine_root.children.reserve_exact(top_level_count);
     runtime.nodes.reserve(nodes.len().saturating_add(1));
     // Read the type from the owned node before it moves into the arena,
@@ -135,6 +149,8 @@ pub(super) fn merge_prepared_scene(
     let mut camera_stream_links: Vec<(NodeID, u32)> = Vec::new();
     let mut joint_body_links: Vec<(NodeID, super::prepare::PendingJointBodyField, u32)> =
         Vec::new();
+    let mut connection_links: Vec<(NodeID, super::prepare::PendingConnection)> = Vec::new();
+    let mut meta_links: Vec<(NodeID, Vec<(String, SceneValue)>)> = Vec::new();
     let resource_api = runtime.resource_api.clone();
     // One resource window reused across the whole merge loop. It only holds a
     // shared borrow of `resource_api`, compatible with the direct
@@ -147,6 +163,7 @@ pub(super) fn merge_prepared_scene(
             key_name,
             parent_key,
             node,
+            enabled,
             animation_source,
             animation_tree_source,
             animation_tree_animations,
@@ -164,6 +181,8 @@ pub(super) fn merge_prepared_scene(
             joint_body_links: pending_joint_body_links,
             animation_bindings,
             locale_text_bindings,
+            connections: pending_connections,
+            meta,
         } = pending;
 
         if key_to.contains_key(&key) {
@@ -178,6 +197,9 @@ pub(super) fn merge_prepared_scene(
             matches!(&node.data, SceneNodeData::Camera3D(camera) if camera.active);
         let node = runtime.nodes.insert(node);
         runtime.register_internal_node_schedules(node, node_type);
+        if !enabled {
+            runtime.disabled_nodes.insert(node);
+        }
         if camera_3d_active {
             runtime.note_camera_3d_activated(node);
         }
@@ -350,6 +372,12 @@ pub(super) fn merge_prepared_scene(
         for link in pending_joint_body_links {
             joint_body_links.push((node, link.field, link.target_key));
         }
+        for connection in pending_connections {
+            connection_links.push((node, connection));
+        }
+        if !meta.is_empty() {
+            meta_links.push((node, meta));
+        }
         if let Some(parent_key) = parent_key {
             parent_pairs.push((key, parent_key));
         }
@@ -476,6 +504,14 @@ pub(super) fn merge_prepared_scene(
         player.replace_bindings(resolved);
     }
 
+    // `@key` meta values need every node's id, so meta lands after the loop.
+    for (id, meta) in meta_links {
+        for (name, value) in meta {
+            let value = scene_value_to_variant(&value, &key_to, key_name_to.as_ref());
+            runtime.nodes.set_meta(id, name, value);
+        }
+    }
+
     for (tree_id, scene_bindings) in animation_tree_animation_bindings {
         let Some(mut node_data) = runtime.nodes.get_mut(tree_id) else {
             continue;
@@ -543,6 +579,8 @@ pub(super) fn merge_prepared_scene(
         );
     }
 
+    let connections = resolve_scene_connections(runtime, &key_to, connection_links);
+
     let mut script_nodes = Vec::with_capacity(scripts.len());
     for pending_script in scripts {
         let id = *key_to.get(&pending_script.node_key).ok_or_else(|| {
@@ -573,9 +611,70 @@ pub(super) fn merge_prepared_scene(
         scene_root: primary_root,
         ownership_root: engine_root,
         script_nodes,
+        connections,
     })
 }
 
+/// Node-event signals are named `{node name}_{event}`, so `connect pressed`
+/// on `play_button` listens for `play_button_pressed`.
+fn resolve_scene_connections(
+    runtime: &Runtime,
+    key_to: &AHashMap<u32, NodeID>,
+    links: Vec<(NodeID, super::prepare::PendingConnection)>,
+) -> Vec<SceneConnectionBind> {
+    let mut out = Vec::with_capacity(links.len());
+    for (source, connection) in links {
+        let target = key_to
+            .get(&connection.target_key)
+            .copied()
+            .and_then(|anchor| {
+                connection.path.iter().try_fold(anchor, |parent, name| {
+                    runtime
+                        .nodes
+                        .children(parent)?
+                        .iter()
+                        .copied()
+                        .find(|child| {
+                            runtime
+                                .nodes
+                                .get(*child)
+                                .is_some_and(|node| node.name.as_ref() == name.as_str())
+                        })
+                })
+            });
+        let Some(target) = target else {
+            let target = if connection.path.is_empty() {
+                format!("#{}", connection.target_key)
+            } else {
+                format!("/root/{}", connection.path.join("/"))
+            };
+            eprintln!(
+                "[perro][scene] connect `{} -> {target}.{}`: target node not found",
+                connection.signal, connection.method
+            );
+            continue;
+        };
+        let Some(source_name) = runtime
+            .nodes
+            .get(source)
+            .map(|node| node.name.clone())
+            .filter(|name| !name.is_empty())
+        else {
+            eprintln!(
+                "[perro][scene] connect `{}`: node has no name to emit signals under",
+                connection.signal
+            );
+            continue;
+        };
+        out.push(SceneConnectionBind {
+            target,
+            signal: SignalID::from_string(&format!("{source_name}_{}", connection.signal)),
+            method: ScriptMemberID::from_string(&connection.method),
+        });
+    }
+    out
+}
+
 fn validate_prepared_scene(prepared: &PreparedScene) -> Result<(), String> {
     let mut parents = AHashMap::with_capacity(prepared.nodes.len());
     for pending in &prepared.nodes {
@@ -699,6 +798,21 @@ fn scene_value_to_variant(
             Variant::from(*z),
             Variant::from(*w),
         ]),
+        // colors go out as normalized rgba, same as vec4 colors
+        SceneValue::Color { .. } => Variant::Array(
+            value
+                .as_color()
+                .unwrap_or_default()
+                .into_iter()
+                .map(Variant::from)
+                .collect(),
+        ),
+        SceneValue::Rect { x, y, w, h } => Variant::Array(vec![
+            Variant::from(*x),
+            Variant::from(*y),
+            Variant::from(*w),
+            Variant::from(*h),
+        ]),
         SceneValue::Str(v) => Variant::from(v.to_string()),
         SceneValue::Hashed(v) => Variant::from(*v),
         SceneValue::Key(v) => {
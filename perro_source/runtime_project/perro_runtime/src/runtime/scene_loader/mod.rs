@@ -9,8 +9,8 @@ use perro_io::{
     data_local_dir, is_reserved_dlc_name, mount_dlc_archive, mount_dlc_disk, read_mounted_dlc_file,
     register_dlc_static_binary_lookup, validate_asset_relative_path,
 };
-use perro_runtime_api::sub_apis::NodeAPI;
 use perro_runtime_api::sub_apis::PreloadedSceneID;
+use perro_runtime_api::sub_apis::{NodeAPI, SignalAPI};
 use perro_scene::Scene;
 use perro_variant::Variant;
 #[cfg(not(target_arch = "wasm32"))]
@@ -166,7 +166,10 @@ impl Runtime {
         }
     }
 
-    fn get_or_load_dynamic_scene_cached(&self, path: &str) -> Result<Arc<Scene>, String> {
+    pub(super) fn get_or_load_dynamic_scene_cached(
+        &self,
+        path: &str,
+    ) -> Result<Arc<Scene>, String> {
         if let Some(scene) = self.scene_cache.borrow().get(path).cloned() {
             return Ok(scene);
         }
@@ -238,6 +241,28 @@ impl Runtime {
             let _ = NodeAPI::remove_node(self, ownership_root);
             return Err(err);
         }
+        for connection in merged.connections {
+            if self
+                .scripts
+                .instance_index_for_id(connection.target)
+                .is_none()
+            {
+                let name = self
+                    .nodes
+                    .get(connection.target)
+                    .map(|node| node.name.to_string())
+                    .unwrap_or_default();
+                eprintln!("[perro][scene] connect target `{name}` has no script; skipping");
+                continue;
+            }
+            let _ = SignalAPI::signal_connect(
+                self,
+                connection.target,
+                connection.signal,
+                connection.method,
+                &[],
+            );
+        }
         self.scene_ownership_roots
             .insert(scene_root, ownership_root);
         Ok(scene_root)
@@ -254,7 +279,7 @@ impl Runtime {
         prepare_scene_with_loader_and_styles(scene, load_scene, static_ui_style_lookup)
     }
 
-    fn get_or_prepare_scene_cached(
+    pub(super) fn get_or_prepare_scene_cached(
         &self,
         path: &str,
         scene: &Scene,
@@ -398,8 +423,7 @@ impl Runtime {
         };
 
         let scene_root = self.finish_scene_merge(merged)?;
-        #[cfg(not(feature = "profile"))]
-        let _ = path;
+        self.track_scene_source(scene_root, path);
         Ok(scene_root)
     }
 
@@ -476,6 +500,7 @@ impl Runtime {
         }
         self.reload_dlc_mounts()?;
         self.resource_api.initialize_localization();
+        self.load_saved_input_bindings();
 
         let mut existing_script_ids = Vec::new();
         self.scripts.append_instance_ids(&mut existing_script_ids);
@@ -485,6 +510,12 @@ impl Runtime {
 
         self.nodes.clear();
         self.scene_ownership_roots.clear();
+        self.scene_hot_reload = Default::default();
+        self.clear_scene_instances();
+        self.disabled_nodes.clear();
+        self.clear_owned_spawns();
+        self.event_bus.clear();
+        self.clear_rollback();
         self.clear_physics();
         self.force_water_impacts_2d.clear();
         self.force_water_impacts_3d.clear();
@@ -529,6 +560,7 @@ impl Runtime {
         self.render_ui.retained_rects.clear();
         self.render_ui.button_states.clear();
         self.render_ui.focused_ui_node = None;
+        self.render_ui.initial_focus_applied.clear();
         self.render_ui.nav_pressed_button = None;
         self.render_ui.ui_nav_repeat_dir = None;
         self.render_ui.ui_nav_repeat_timer = 0.0;
@@ -536,6 +568,7 @@ impl Runtime {
         self.render_ui.hovered_text_edit = None;
         self.render_ui.pressed_text_edit = None;
         self.render_ui.pressed_ui_button = None;
+        self.render_ui.drag_scroll = None;
         self.render_ui.last_ui_pointer = None;
         self.render_ui.cursor_icon = perro_ui::CursorIcon::Default;
         self.render_ui.cursor_icon_2d = perro_ui::CursorIcon::Default;
@@ -630,6 +663,7 @@ impl Runtime {
             }
         }
         let scene_root = self.finish_scene_merge(merged)?;
+        self.track_scene_source(scene_root, &boot_scene_path);
         self.active_route_href = boot_route_href;
         self.active_route_root = Some(scene_root);
         #[cfg(not(feature = "profile"))]
@@ -45,6 +45,34 @@ fn as_bitmask(value: &SceneValue) -> Option<BitMask> {
     }
 }
 
+/// Collision layers/masks also accept names from `[layer_names.2d_physics]`:
+/// `collision_mask = ["player", 3]`.
+fn as_physics_bitmask_2d(value: &SceneValue) -> Option<BitMask> {
+    as_bitmask(value).or_else(|| as_named_bitmask(value, crate::runtime::physics::physics_layer_2d))
+}
+
+fn as_physics_bitmask_3d(value: &SceneValue) -> Option<BitMask> {
+    as_bitmask(value).or_else(|| as_named_bitmask(value, crate::runtime::physics::physics_layer_3d))
+}
+
+fn as_named_bitmask(value: &SceneValue, lookup: fn(&str) -> Option<u8>) -> Option<BitMask> {
+    let layer = |item: &SceneValue| match item {
+        SceneValue::Key(name) => lookup(name.as_ref()),
+        SceneValue::Str(name) => lookup(name.as_ref()),
+        other => u8::try_from(as_u32(other)?).ok(),
+    };
+    match value {
+        SceneValue::Array(items) => {
+            let mut mask = BitMask::NONE;
+            for item in items.iter() {
+                mask = mask.union(BitMask::try_layer(layer(item)?)?);
+            }
+            Some(mask)
+        }
+        _ => BitMask::try_layer(layer(value)?),
+    }
+}
+
 fn parse_bitmask_call(raw: &str) -> Option<BitMask> {
     let (op, rest) = raw.split_once('(')?;
     let args = rest.strip_suffix(')')?.trim();
@@ -7,15 +7,15 @@ use crate::{material_schema, runtime_project::StaticUiStyleLookup};
 use perro_ids::{NodeID, string_to_u64};
 use perro_io::load_asset;
 use perro_nodes::{
-    AmbientLight2D, Area2D, Area3D, AudioEffectZone2D, AudioEffectZone3D, AudioMask2D, AudioMask3D,
-    AudioPortal2D, AudioPortal3D, BallJoint3D, Button2D, CameraStream, CameraStream2D,
+    AmbientLight2D, Area2D, Area3D, AreaGravityMode, AudioEffectZone2D, AudioEffectZone3D,
+    AudioMask2D, AudioMask3D, AudioPortal2D, AudioPortal3D, BallJoint3D, Button2D, CameraStream, CameraStream2D,
     CameraStream3D, CharacterBody2D, CharacterBody3D, CollisionShape2D, CollisionShape3D,
     Decal3D, DistanceJoint2D, FixedJoint2D,
     FixedJoint3D, HingeJoint3D, ImageButton2D, Label2D, Label3D, NineSlice2D, NineSliceButton2D, NodeType, PhysicsForceEmitter2D,
     PhysicsForceEmitter3D, PhysicsForceProfile, PinJoint2D, PointLight2D, RayLight2D,
-    RigidBody2D, RigidBody3D, SceneNode, SceneNodeData, Shape2D, Shape3D, SpotLight2D,
+    RigidBody2D, RigidBody3D, SceneNode, SceneNodeData, Shape2D, Shape3D, SpotLight2D, SpringJoint2D, SpringJoint3D,
     StaticBody2D, StaticBody3D, SubView, SubView2D, SubView3D, Triangle2DKind, UiCameraStream,
-    UiSubView, UiVideoPlayer,
+    UiSubView, UiVideoPlayer, VehicleBody3D, VehicleWheel3D,
     VideoPlayer, VideoPlayer2D, VideoPlayer3D, WaterBody2D, WaterBody3D, Webcam,
     WaterIdleMode, WaterShape, WaterSkyBias, WaterSurfaceParams,
     ambient_light_3d::AmbientLight3D,
@@ -52,19 +52,19 @@ use perro_render_bridge::Material3D;
 use perro_scene::{
     AnimatedSprite2DField, AnimationPlayerField, AnimationTreeField, Area2DField, Area3DField,
     BoneAttachment2DField, BoneAttachment3DField, BoneCollider2DField, BoneCollider3DField,
-    Camera2DField, Camera3DField, CharacterBodyField, CollisionShape2DField,
+    Camera2DField, Camera3DField, CharacterBody2DField, CharacterBodyField, CollisionShape2DField,
     CollisionShape3DField,
     DistanceJoint2DField, HingeJoint3DField, IKTarget2DField, IKTarget3DField, Joint2DField,
-    Joint3DField, Light2DField, Light3DField, MeshInstance3DField, NodeField, Parser,
+    Joint3DField, JointLimitMotorField, JointSpringField, Light2DField, Light3DField, MeshInstance3DField, NodeField, Parser,
     ParticleEmitter2DField, ParticleEmitter3DField, PhysicsBoneChain2DField,
-    PhysicsBoneChain3DField, PhysicsForceEmitterField, PointLight2DField, PointLight3DField,
+    PhysicsBoneChain3DField, PhysicsForceEmitterField, PinJoint2DField, PointLight2DField, PointLight3DField,
     NodeFieldType, RayLight2DField, RayLight3DField, RigidBody2DField, RigidBody3DField, Scene,
-    SceneAssetKind, SceneFieldIterRef, SceneFieldName, SceneKey,
+    SceneAssetKind, SceneConnection, SceneFieldIterRef, SceneFieldName, SceneKey,
     SceneFieldKey,
     SceneNodeData as SceneDefNodeData,
     SceneNodeEntry as SceneDefNodeEntry, SceneObjectField, SceneValue, Skeleton3DField, Sky3DField,
-    SpotLight2DField, SpotLight3DField, StaticBody2DField, StaticBody3DField, TileMap2DField,
-    UiAnimatedImageField, WaterBodyField, resolve_node_field,
+    SpotLight2DField, SpotLight3DField, SpringJoint2DField, SpringJoint3DField, StaticBody2DField, StaticBody3DField, TileMap2DField,
+    UiAnimatedImageField, VehicleBody3DField, VehicleWheel3DField, WaterBodyField, resolve_node_field,
     audio_effect_zone_fields, audio_mask_fields, audio_portal_fields, resolve_scene_node_field,
     scene_node_field,
 };
@@ -75,7 +75,7 @@ use perro_structs::{
 use perro_ui::{
     UiAnimatedImage, UiAnimatedImageFrameSet, UiNode, UiButton, UiCheckbox, UiColorPicker,
     UiDropdown, UiGrid, UiHLayout, UiImage, UiImageButton, UiImageScaleMode, UiLabel, UiLayout,
-    UiMouseFilter, UiNineSlice, UiNineSliceButton, UiPanel, UiProgressBar, UiScrollContainer, UiShape, UiShapeKind, UiTextAlign,
+    UiMarkdown, UiMouseFilter, UiNineSlice, UiNineSliceButton, UiPanel, UiProgressBar, UiScrollContainer, UiShape, UiShapeKind, UiTextAlign,
     UiTextBlock, UiTextBox, UiTreeList, UiTreeListItem, UiVLayout,
 };
 use rayon::prelude::*;
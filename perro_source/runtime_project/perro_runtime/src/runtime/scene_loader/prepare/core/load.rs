@@ -5,17 +5,38 @@ pub(in super::super) fn load_runtime_scene_from_disk(
 ) -> Result<(Scene, RuntimeSceneLoadStats), String> {
     #[cfg(feature = "profile")]
     let source_load_start = Instant::now();
-    let bytes = load_asset(path).map_err(|err| format!("failed to load scene `{path}`: {err}"))?;
+    // Packed builds ship `.scnb` in place of `.scn` text; dev trees only have text.
+    let compiled_path = perro_scene::compiled_scene_path(path);
+    let compiled = (compiled_path != path)
+        .then(|| load_asset(&compiled_path).ok())
+        .flatten();
+    let bytes = match compiled {
+        Some(bytes) => bytes,
+        None => load_asset(path).map_err(|err| format!("failed to load scene `{path}`: {err}"))?,
+    };
     #[cfg(feature = "profile")]
     let source_load = source_load_start.elapsed();
 
-    let source = std::str::from_utf8(&bytes)
-        .map_err(|err| format!("scene `{path}` is not valid UTF-8: {err}"))?;
     #[cfg(feature = "profile")]
     let parse_start = Instant::now();
-    let mut scene = Parser::new(source)
-        .try_parse_scene()
-        .map_err(|err| format!("failed to parse scene `{path}`: {err}"))?;
+    let mut scene = if perro_scene::is_compiled_scene(&bytes) {
+        perro_scene::decode_scene(&bytes)
+            .map_err(|err| format!("failed to decode scene `{path}`: {err}"))?
+    } else {
+        let source = std::str::from_utf8(&bytes)
+            .map_err(|err| format!("scene `{path}` is not valid UTF-8: {err}"))?;
+        let load_base = |base: &str| {
+            let bytes = load_asset(base).map_err(|err| err.to_string())?;
+            String::from_utf8(bytes).map_err(|err| format!("not valid UTF-8: {err}"))
+        };
+        let target =
+            perro_scene::SceneTarget::host().with_feature_if(perro_io::demo_mode_active(), "demo");
+        Parser::new(source)
+            .with_scene_loader(&load_base)
+            .with_target(target)
+            .try_parse_scene()
+            .map_err(|err| format!("failed to parse scene `{path}`: {err}"))?
+    };
     perro_scene::filter_demo_scene(&mut scene, perro_io::demo_mode_active())
         .map_err(|err| format!("failed to filter scene `{path}` for demo: {err}"))?;
     if perro_io::demo_mode_active() {
@@ -74,6 +95,7 @@ pub(super) fn resolve_scene_dlc_self_paths(scene: &mut Scene, mount_name: &str)
             node.root_of = Some(Cow::Owned(resolved));
         }
         resolve_scene_value_fields_dlc_self(node.script_vars.to_mut(), prefix, replacement_ref);
+        resolve_scene_value_fields_dlc_self(node.meta.to_mut(), prefix, replacement_ref);
         resolve_scene_node_data_dlc_self(&mut node.data, prefix, replacement_ref);
     }
 }
@@ -273,12 +295,13 @@ pub(super) fn ensure_default_ray_light_3d(prepared: &mut PreparedScene) {
         .unwrap_or(0)
         .saturating_add(1);
     let mut node = SceneNode::new(SceneNodeData::RayLight3D(RayLight3D::new()));
-    node.name = Cow::Borrowed("__perro_default_ray_light");
+    node.set_name("__perro_default_ray_light");
     prepared.nodes.push(PendingNode {
         key,
         key_name: "__perro_default_ray_light".to_string(),
         parent_key: None,
         node,
+        enabled: true,
         animation_source: None,
         animation_tree_source: None,
         animation_tree_animations: Vec::new(),
@@ -296,6 +319,8 @@ pub(super) fn ensure_default_ray_light_3d(prepared: &mut PreparedScene) {
         joint_body_links: Vec::new(),
         animation_bindings: Vec::new(),
         locale_text_bindings: Vec::new(),
+        connections: Vec::new(),
+        meta: Vec::new(),
     });
 }
 
@@ -361,6 +386,7 @@ pub(super) fn prepare_node_no_root(
         key_name,
         parent_key,
         node,
+        enabled: entry.enabled,
         animation_source,
         animation_tree_source,
         animation_tree_animations: animation_tree_animations
@@ -418,6 +444,8 @@ pub(super) fn prepare_node_no_root(
             })
             .collect(),
         locale_text_bindings,
+        connections: pending_connections(scene, &entry.connections, &HashMap::new()),
+        meta: pending_meta(scene, &entry.meta, &HashMap::new()),
     })
 }
 
@@ -432,6 +460,8 @@ pub(super) fn push_entry_prepared(
     let key_name = scene.key_name_or_id(entry.key).into_owned();
     let parent_key = entry.parent.map(|p| remap_key(p, key_map));
     let mut merged_root_entry = None;
+    let mut imported_root_connections = Vec::new();
+    let mut missing_root_of = false;
 
     let root_of_source = entry.root_of.as_ref().map(|v| v.as_ref().to_string());
     if let Some(root_of_path) = root_of_source.as_ref() {
@@ -443,7 +473,13 @@ pub(super) fn push_entry_prepared(
         }
         ctx.include_stack.insert(root_of_path.clone());
         let root_merge_result = (|| {
-            let import_scene = (ctx.load_scene)(root_of_path.as_str())?;
+            let import_scene = match (ctx.load_scene)(root_of_path.as_str()) {
+                Ok(import_scene) => import_scene,
+                Err(err) => {
+                    perro_io::report_missing_asset("scene", root_of_path, &err);
+                    return Ok(None);
+                }
+            };
             let import_root = import_scene
                 .root
                 .ok_or_else(|| format!("root_of scene `{}` has no $root", root_of_path))?;
@@ -459,20 +495,28 @@ pub(super) fn push_entry_prepared(
                     )
                 })?;
             let merged = merge_root_host_entry(entry, import_root_node);
-            expand_import_children_into_host(
+            let root_connections = expand_import_children_into_host(
                 key,
                 root_of_path.as_str(),
                 import_scene.as_ref(),
                 &import_root,
                 ctx,
             )?;
-            Ok::<SceneDefNodeEntry, String>(merged)
+            Ok::<Option<(SceneDefNodeEntry, Vec<PendingConnection>)>, String>(Some((
+                merged,
+                root_connections,
+            )))
         })();
         ctx.include_stack.remove(root_of_path);
-        merged_root_entry = Some(root_merge_result?);
+        let (merged, root_connections) = root_merge_result?.unzip();
+        merged_root_entry = merged;
+        imported_root_connections = root_connections.unwrap_or_default();
+        missing_root_of = merged_root_entry.is_none();
     }
 
     let entry = merged_root_entry.as_ref().unwrap_or(entry);
+    let mut connections = pending_connections(scene, &entry.connections, key_map);
+    connections.extend(imported_root_connections);
 
     let (
         node,
@@ -501,6 +545,7 @@ pub(super) fn push_entry_prepared(
         key_name,
         parent_key,
         node,
+        enabled: entry.enabled,
         animation_source,
         animation_tree_source,
         animation_tree_animations: animation_tree_animations
@@ -559,6 +604,8 @@ pub(super) fn push_entry_prepared(
             })
             .collect(),
         locale_text_bindings,
+        connections,
+        meta: pending_meta(scene, &entry.meta, key_map),
     });
 
     if let Some(script) = entry.script.as_ref() {
@@ -581,5 +628,9 @@ pub(super) fn push_entry_prepared(
         });
     }
 
+    if missing_root_of && let Some(root_of_path) = root_of_source.as_deref() {
+        push_missing_scene_label(key, entry.data.node_type, root_of_path, ctx)?;
+    }
+
     Ok(())
 }
@@ -21,7 +21,7 @@ pub(super) fn expand_import_children_into_host(
     import_scene: &Scene,
     import_root: &SceneKey,
     ctx: &mut PrepareSceneCtx<'_>,
-) -> Result<(), String> {
+) -> Result<Vec<PendingConnection>, String> {
     let mut map = HashMap::<SceneKey, u32>::new();
     map.insert(*import_root, host_key);
     for node in import_scene.nodes.as_ref() {
@@ -45,7 +45,78 @@ pub(super) fn expand_import_children_into_host(
         })?;
         push_entry_prepared(import_scene, node, Some(remapped_key), &map, ctx)?;
     }
-    Ok(())
+    // The imported root becomes the host node; its own `connect` lines still
+    // name keys in the imported scene.
+    let root_connections = import_scene
+        .nodes
+        .iter()
+        .find(|node| node.key == *import_root)
+        .map(|node| pending_connections(import_scene, &node.connections, &map))
+        .unwrap_or_default();
+    Ok(root_connections)
+}
+
+pub(super) const MISSING_SCENE_LABEL_NAME: &str = "__missing_scene__";
+
+/// Stand-in for a `root_of` scene that failed to load: a magenta label under
+/// the host, matching the host's 2D/3D/UI space.
+pub(super) fn push_missing_scene_label(
+    host_key: u32,
+    host_type: NodeType,
+    path: &str,
+    ctx: &mut PrepareSceneCtx<'_>,
+) -> Result<(), String> {
+    let label_type = if host_type.is_3d() {
+        NodeType::Label3D
+    } else if host_type.is_2d() {
+        NodeType::Label2D
+    } else {
+        NodeType::UiLabel
+    };
+    let field = |name: &'static str, value: SceneValue| {
+        let name = SceneFieldName::from_borrowed(name)
+            .unwrap_or(SceneFieldName::Custom(Cow::Borrowed(name)));
+        (name, value)
+    };
+    let fields = vec![
+        field(
+            "text",
+            SceneValue::Str(Cow::Owned(format!("missing scene: {path}"))),
+        ),
+        field(
+            "color",
+            SceneValue::Vec4 {
+                x: 1.0,
+                y: 0.0,
+                z: 1.0,
+                w: 1.0,
+            },
+        ),
+    ];
+    let key = *ctx.next_key;
+    *ctx.next_key = ctx.next_key.saturating_add(1);
+    let label = SceneDefNodeEntry {
+        data: SceneDefNodeData::new(label_type, Cow::Owned(fields), None),
+        has_data_override: true,
+        key: SceneKey::new(key),
+        name: Some(Cow::Borrowed(MISSING_SCENE_LABEL_NAME)),
+        tags: Cow::Borrowed(&[]),
+        children: Cow::Borrowed(&[]),
+        parent: Some(SceneKey::new(host_key)),
+        script: None,
+        clear_script: false,
+        enabled: true,
+        root_of: None,
+        script_vars: Cow::Borrowed(&[]),
+        meta: Cow::Borrowed(&[]),
+        connections: Cow::Borrowed(&[]),
+    };
+    let scene = Scene {
+        nodes: Cow::Borrowed(&[]),
+        root: None,
+        key_names: Cow::Borrowed(&[]),
+    };
+    push_entry_prepared(&scene, &label, Some(key), &HashMap::new(), ctx)
 }
 
 pub(super) fn merge_root_host_entry(
@@ -69,7 +140,9 @@ pub(super) fn merge_root_host_entry(
         merged.script = base_root.script.clone();
     }
     merged.clear_script = false;
+    merged.enabled = host.enabled && base_root.enabled;
     merged.script_vars = merge_scene_object_fields(&base_root.script_vars, &host.script_vars);
+    merged.meta = merge_scene_object_fields(&base_root.meta, &host.meta);
     merged.data = if host.has_data_override {
         merge_scene_node_data(&base_root.data, &host.data)
     } else {
@@ -173,6 +246,60 @@ pub(super) fn scene_key_by_name(scene: &Scene, name: &str) -> Option<SceneKey> {
         .map(SceneKey::new)
 }
 
+/// Maps `connect` targets to prepared keys. `/root` is the declaring scene's
+/// root, which for a `root_of` import is the host node.
+pub(super) fn pending_connections(
+    scene: &Scene,
+    connections: &[SceneConnection],
+    key_map: &HashMap<SceneKey, u32>,
+) -> Vec<PendingConnection> {
+    connections
+        .iter()
+        .filter_map(|connection| {
+            let (target, path) = match connection.target.strip_prefix("/root") {
+                Some(rest) => (
+                    scene.root,
+                    rest.split('/')
+                        .filter(|name| !name.is_empty())
+                        .map(str::to_string)
+                        .collect(),
+                ),
+                None => (scene_key_by_name(scene, &connection.target), Vec::new()),
+            };
+            let Some(target) = target else {
+                eprintln!(
+                    "[perro][scene] connect target `{}` not found; skipping `{} -> {}.{}`",
+                    connection.target, connection.signal, connection.target, connection.method
+                );
+                return None;
+            };
+            Some(PendingConnection {
+                signal: connection.signal.to_string(),
+                target_key: remap_key(target, key_map),
+                path,
+                method: connection.method.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// `meta` header values w/ `@key` refs mapped to prepared keys; they become
+/// node ids once the scene is merged.
+pub(super) fn pending_meta(
+    scene: &Scene,
+    meta: &[SceneObjectField],
+    key_map: &HashMap<SceneKey, u32>,
+) -> Vec<(String, SceneValue)> {
+    meta.iter()
+        .map(|(name, value)| {
+            (
+                name.to_string(),
+                remap_scene_value_keys(value, scene, key_map),
+            )
+        })
+        .collect()
+}
+
 pub(super) fn remap_scene_value_keys(
     value: &SceneValue,
     scene: &Scene,
@@ -218,6 +345,7 @@ pub(super) fn remap_scene_value_keys(
             z: *z,
             w: *w,
         },
+        SceneValue::Color { .. } | SceneValue::Rect { .. } => value.clone(),
         SceneValue::Str(v) => SceneValue::Str(v.clone()),
         SceneValue::Hashed(v) => SceneValue::Hashed(*v),
         SceneValue::Key(v) => scene_key_by_name(scene, v.as_ref())
@@ -244,7 +372,7 @@ pub(super) fn scene_node_from_entry(
 ) -> Result<SceneNodeExtraction, String> {
     let mut node = SceneNode::new(scene_node_data_from(&entry.data, static_ui_style_lookup)?);
     if let Some(name) = &entry.name {
-        node.name = name.clone();
+        node.set_name(name.as_ref());
     }
     if !entry.tags.is_empty() {
         let tags = entry
@@ -419,8 +547,8 @@ pub(super) fn extract_joint_body_targets(
 pub(super) fn joint_body_fields_for(ty: NodeType) -> Option<(NodeField, NodeField)> {
     match ty {
         NodeType::PinJoint2D => Some((
-            NodeField::PinJoint2D(Joint2DField::BodyA),
-            NodeField::PinJoint2D(Joint2DField::BodyB),
+            NodeField::PinJoint2D(PinJoint2DField::Common(Joint2DField::BodyA)),
+            NodeField::PinJoint2D(PinJoint2DField::Common(Joint2DField::BodyB)),
         )),
         NodeType::DistanceJoint2D => Some((
             NodeField::DistanceJoint2D(DistanceJoint2DField::Common(Joint2DField::BodyA)),
@@ -430,6 +558,10 @@ pub(super) fn joint_body_fields_for(ty: NodeType) -> Option<(NodeField, NodeFiel
             NodeField::FixedJoint2D(Joint2DField::BodyA),
             NodeField::FixedJoint2D(Joint2DField::BodyB),
         )),
+        NodeType::SpringJoint2D => Some((
+            NodeField::SpringJoint2D(SpringJoint2DField::Common(Joint2DField::BodyA)),
+            NodeField::SpringJoint2D(SpringJoint2DField::Common(Joint2DField::BodyB)),
+        )),
         NodeType::BallJoint3D => Some((
             NodeField::BallJoint3D(Joint3DField::BodyA),
             NodeField::BallJoint3D(Joint3DField::BodyB),
@@ -442,6 +574,10 @@ pub(super) fn joint_body_fields_for(ty: NodeType) -> Option<(NodeField, NodeFiel
             NodeField::FixedJoint3D(Joint3DField::BodyA),
             NodeField::FixedJoint3D(Joint3DField::BodyB),
         )),
+        NodeType::SpringJoint3D => Some((
+            NodeField::SpringJoint3D(SpringJoint3DField::Common(Joint3DField::BodyA)),
+            NodeField::SpringJoint3D(SpringJoint3DField::Common(Joint3DField::BodyB)),
+        )),
         _ => None,
     }
 }
@@ -505,6 +641,7 @@ define_runtime_scene_node_specs! {
         PinJoint2D => build_pin_joint_2d,
         DistanceJoint2D => build_distance_joint_2d,
         FixedJoint2D => build_fixed_joint_2d,
+        SpringJoint2D => build_spring_joint_2d,
         AudioMask2D => build_audio_mask_2d,
         AudioEffectZone2D => build_audio_effect_zone_2d,
         AudioPortal2D => build_audio_portal_2d,
@@ -525,6 +662,9 @@ define_runtime_scene_node_specs! {
         BallJoint3D => build_ball_joint_3d,
         HingeJoint3D => build_hinge_joint_3d,
         FixedJoint3D => build_fixed_joint_3d,
+        SpringJoint3D => build_spring_joint_3d,
+        VehicleBody3D => build_vehicle_body_3d,
+        VehicleWheel3D => build_vehicle_wheel_3d,
         AudioMask3D => build_audio_mask_3d,
         AudioEffectZone3D => build_audio_effect_zone_3d,
         AudioPortal3D => build_audio_portal_3d,
@@ -561,6 +701,7 @@ define_runtime_scene_node_specs! {
         UiVLayout => build_ui_vlayout,
         UiGrid => build_ui_grid,
         UiTreeList => build_ui_tree_list,
+        UiMarkdown => build_ui_markdown,
     }
     styled {
         UiPanel => build_ui_panel,
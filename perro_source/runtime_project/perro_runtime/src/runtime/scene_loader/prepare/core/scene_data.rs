@@ -21,6 +21,19 @@ impl Clone for PreparedScene {
     }
 }
 
+impl PreparedScene {
+    /// Approx bytes: pending node/script slots + key names. Per-node source
+    /// strings + side lists not walked.
+    pub(crate) fn approx_memory_bytes(&self) -> usize {
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|node| std::mem::size_of::<PendingNode>() + node.key_name.capacity())
+            .sum::<usize>();
+        nodes + self.scripts.capacity() * std::mem::size_of::<PendingScript>()
+    }
+}
+
 #[derive(Clone)]
 pub(in super::super) struct PendingScript {
     pub(in super::super) node_key: u32,
@@ -36,6 +49,8 @@ pub(in super::super) struct PendingNode {
     pub(in super::super) key_name: String,
     pub(in super::super) parent_key: Option<u32>,
     pub(in super::super) node: SceneNode,
+    /// `enabled = false` header.
+    pub(in super::super) enabled: bool,
     pub(in super::super) animation_source: Option<String>,
     pub(in super::super) animation_tree_source: Option<String>,
     pub(in super::super) animation_tree_animations: Vec<PendingAnimationTreeAnimation>,
@@ -54,6 +69,20 @@ pub(in super::super) struct PendingNode {
     pub(in super::super) joint_body_links: Vec<PendingJointBodyLink>,
     pub(in super::super) animation_bindings: Vec<(String, u32)>,
     pub(in super::super) locale_text_bindings: Vec<PendingLocaleTextBinding>,
+    pub(in super::super) connections: Vec<PendingConnection>,
+    /// `meta` header, applied once scene keys map to node ids.
+    pub(in super::super) meta: Vec<(String, SceneValue)>,
+}
+
+/// A scene `connect` line with its target mapped to a prepared key.
+#[derive(Clone)]
+pub(in super::super) struct PendingConnection {
+    pub(in super::super) signal: String,
+    /// `@key` target, or the scene root a `/root/..` path starts from.
+    pub(in super::super) target_key: u32,
+    /// Child names below `target_key`; empty for `@key` targets.
+    pub(in super::super) path: Vec<String>,
+    pub(in super::super) method: String,
 }
 
 impl PendingNode {
@@ -67,6 +96,7 @@ impl PendingNode {
             },
             parent_key: self.parent_key,
             node: self.node.clone(),
+            enabled: self.enabled,
             animation_source: self.animation_source.clone(),
             animation_tree_source: self.animation_tree_source.clone(),
             animation_tree_animations: self.animation_tree_animations.clone(),
@@ -84,6 +114,8 @@ impl PendingNode {
             joint_body_links: self.joint_body_links.clone(),
             animation_bindings: self.animation_bindings.clone(),
             locale_text_bindings: self.locale_text_bindings.clone(),
+            connections: self.connections.clone(),
+            meta: self.meta.clone(),
         }
     }
 }
@@ -570,6 +570,7 @@ pub(super) fn as_color(value: &SceneValue) -> Option<Color> {
     match value {
         SceneValue::Vec4 { x, y, z, w } => Some(Color::new(*x, *y, *z, *w)),
         SceneValue::Vec3 { x, y, z } => Some(Color::rgb(*x, *y, *z)),
+        SceneValue::Color { r, g, b, a } => Some(Color::from_rgba_u8([*r, *g, *b, *a])),
         SceneValue::Str(v) => Color::from_hex(v.as_ref()),
         SceneValue::Key(v) => Color::from_hex(v.as_ref()),
         _ => None,
@@ -598,6 +599,15 @@ pub(super) fn as_force_profile(value: &SceneValue) -> Option<PhysicsForceProfile
     }
 }
 
+pub(super) fn as_area_gravity_mode(value: &SceneValue) -> Option<AreaGravityMode> {
+    match as_str(value)?.trim().to_ascii_lowercase().as_str() {
+        "disabled" | "off" => Some(AreaGravityMode::Disabled),
+        "combine" => Some(AreaGravityMode::Combine),
+        "replace" => Some(AreaGravityMode::Replace),
+        _ => None,
+    }
+}
+
 pub(super) fn as_vec2_array(value: &SceneValue) -> Option<Vec<Vector2>> {
     let SceneValue::Array(items) = value else {
         return None;
@@ -47,6 +47,46 @@ mod assets {
         }
     }
 
+    #[test]
+    fn missing_root_of_scene_keeps_host_and_adds_error_label() {
+        let host = Parser::new(
+            r#"
+            $root = @host
+            [host]
+            root_of = "res://missing.scn"
+            [Node2D]
+                position = (3, 4)
+            [/Node2D]
+            [/host]
+            "#,
+        )
+        .parse_scene();
+
+        let prepared = prepare_scene_with_loader(&host, &|path| {
+            Err(format!("failed to load scene `{path}`: not found"))
+        })
+        .expect("missing root_of should not fail prepare");
+
+        let host_node = prepared
+            .nodes
+            .iter()
+            .find(|pending| pending.key_name == "host")
+            .expect("host node");
+        assert!(matches!(host_node.node.data, SceneNodeData::Node2D(_)));
+        let label = prepared
+            .nodes
+            .iter()
+            .find(|pending| pending.node.name.as_ref() == MISSING_SCENE_LABEL_NAME)
+            .expect("missing scene label");
+        assert_eq!(label.parent_key, Some(host_node.key));
+        match &label.node.data {
+            SceneNodeData::Label2D(label) => {
+                assert_eq!(label.text.as_ref(), "missing scene: res://missing.scn");
+            }
+            other => panic!("expected Label2D placeholder, got {other:?}"),
+        }
+    }
+
     #[test]
     fn scene_loader_accepts_rotation_deg_for_spatial_nodes() {
         let scene = Parser::new(
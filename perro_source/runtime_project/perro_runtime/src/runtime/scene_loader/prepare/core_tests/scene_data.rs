@@ -905,6 +905,66 @@ mod scene_data {
         }
     }
 
+    #[test]
+    fn scene_loader_resolves_named_physics_layers() {
+        crate::runtime::physics::install_physics_layer_names(
+            &crate::runtime_project::LayerNamesConfig::from_static(
+                &[(1, "player"), (3, "enemy")],
+                &[(5, "world")],
+            ),
+        );
+        let scene = Parser::new(
+            r#"
+            $root = @body
+            [body]
+            [StaticBody2D]
+                collision_layers = "player"
+                collision_mask = ["enemy", 2]
+            [/StaticBody2D]
+            [/body]
+
+            [floor]
+            [StaticBody3D]
+                collision_layers = ["world"]
+                collision_mask = "enemy"
+            [/StaticBody3D]
+            [/floor]
+            "#,
+        )
+        .parse_scene();
+
+        let prepared =
+            prepare_scene_with_loader(&scene, &|path| Err(format!("unknown scene path `{path}`")))
+                .expect("prepare scene");
+
+        let body = prepared
+            .nodes
+            .iter()
+            .find(|pending| pending.key_name == "body")
+            .expect("body node");
+        match &body.node.data {
+            SceneNodeData::StaticBody2D(node) => {
+                assert_eq!(node.collision_layers.bits(), 0b1);
+                assert_eq!(node.collision_mask.bits(), 0b110);
+            }
+            other => panic!("expected StaticBody2D node, got {other:?}"),
+        }
+
+        // 3D names come from their own table; "enemy" is 2D-only.
+        let floor = prepared
+            .nodes
+            .iter()
+            .find(|pending| pending.key_name == "floor")
+            .expect("floor node");
+        match &floor.node.data {
+            SceneNodeData::StaticBody3D(node) => {
+                assert_eq!(node.collision_layers.bits(), 0b10000);
+                assert_eq!(node.collision_mask, StaticBody3D::new().collision_mask);
+            }
+            other => panic!("expected StaticBody3D node, got {other:?}"),
+        }
+    }
+
     #[test]
     fn scene_loader_accepts_bitmask_only_and_without_calls() {
         let scene = Parser::new(
@@ -102,7 +102,7 @@ define_scene_node_builder! {
 fn apply_sprite_3d_fields(node: &mut Sprite3D, fields: &[SceneObjectField]) {
     SceneFieldIterRef::new(fields).for_each(|name, value| match name {
         name if scene_key_in(name, TEXTURE_REGION_KEYS) => {
-            if let Some((x, y, w, h)) = value.as_vec4()
+            if let Some((x, y, w, h)) = value.as_rect()
                 && w > 0.0
                 && h > 0.0
             {
@@ -355,6 +355,11 @@ fn apply_node_3d_fields(node: &mut Node3D, fields: &[SceneObjectField]) {
                     node.top_level = v;
                 }
             }
+            SceneFieldName::Custom(name) if name == "interpolate" => {
+                if let Some(v) = as_bool(value) {
+                    node.interpolate = v;
+                }
+            }
             SceneFieldName::Visible => {
                 if let Some(v) = as_bool(value) {
                     node.visible = v;
@@ -969,6 +974,9 @@ fn parse_color(value: &SceneValue) -> Option<perro_structs::Color> {
     match value {
         SceneValue::Vec4 { x, y, z, w } => Some(perro_structs::Color::new(*x, *y, *z, *w)),
         SceneValue::Vec3 { x, y, z } => Some(perro_structs::Color::rgb(*x, *y, *z)),
+        SceneValue::Color { r, g, b, a } => {
+            Some(perro_structs::Color::from_rgba_u8([*r, *g, *b, *a]))
+        }
         _ => None,
     }
 }
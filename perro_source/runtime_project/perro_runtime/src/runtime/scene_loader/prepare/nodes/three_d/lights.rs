@@ -479,6 +479,10 @@ fn as_color_array(value: &SceneValue) -> Option<Vec<[f32; 3]>> {
         match item {
             SceneValue::Vec3 { x, y, z } => out.push([*x, *y, *z]),
             SceneValue::Vec4 { x, y, z, .. } => out.push([*x, *y, *z]),
+            SceneValue::Color { .. } => {
+                let [r, g, b, _] = item.as_color()?;
+                out.push([r, g, b]);
+            }
             SceneValue::Object(entries) => {
                 let mut r = None;
                 let mut g = None;
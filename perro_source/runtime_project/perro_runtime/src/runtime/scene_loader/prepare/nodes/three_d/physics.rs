@@ -31,12 +31,12 @@ fn apply_character_body_3d_fields(node: &mut CharacterBody3D, fields: &[SceneObj
                 }
             }
             Some(NodeField::CharacterBody3D(CharacterBodyField::CollisionLayers)) => {
-                if let Some(v) = as_bitmask(value) {
+                if let Some(v) = as_physics_bitmask_3d(value) {
                     node.collision_layers = v;
                 }
             }
             Some(NodeField::CharacterBody3D(CharacterBodyField::CollisionMask)) => {
-                if let Some(v) = as_bitmask(value) {
+                if let Some(v) = as_physics_bitmask_3d(value) {
                     node.collision_mask = v;
                 }
             }
@@ -124,12 +124,12 @@ fn apply_physics_force_emitter_3d_fields(
                 }
             }
             Some(NodeField::PhysicsForceEmitter3D(PhysicsForceEmitterField::CollisionLayers)) => {
-                if let Some(v) = as_bitmask(value) {
+                if let Some(v) = as_physics_bitmask_3d(value) {
                     node.collision_layers = v;
                 }
             }
             Some(NodeField::PhysicsForceEmitter3D(PhysicsForceEmitterField::CollisionMask)) => {
-                if let Some(v) = as_bitmask(value) {
+                if let Some(v) = as_physics_bitmask_3d(value) {
                     node.collision_mask = v;
                 }
             }
@@ -161,6 +161,24 @@ define_scene_node_builder! {
     apply [apply_fixed_joint_3d_fields];
 }
 
+define_scene_node_builder! {
+    fn build_spring_joint_3d -> SpringJoint3D = SpringJoint3D::default();
+    base node_3d;
+    apply [apply_spring_joint_3d_fields];
+}
+
+define_scene_node_builder! {
+    fn build_vehicle_body_3d -> VehicleBody3D = VehicleBody3D::default();
+    base node_3d;
+    apply [apply_rigid_body_3d_fields, apply_vehicle_body_3d_fields];
+}
+
+define_scene_node_builder! {
+    fn build_vehicle_wheel_3d -> VehicleWheel3D = VehicleWheel3D::default();
+    base node_3d;
+    apply [apply_vehicle_wheel_3d_fields];
+}
+
 fn apply_collision_shape_3d_fields(node: &mut CollisionShape3D, fields: &[SceneObjectField]) {
     SceneFieldIterRef::new(fields).for_each(|name, value| {
         match resolve_node_field("CollisionShape3D", name) {
@@ -208,12 +226,12 @@ fn apply_static_body_3d_fields(node: &mut StaticBody3D, fields: &[SceneObjectFie
                 }
             }
             Some(NodeField::StaticBody3D(StaticBody3DField::CollisionLayers)) => {
-                if let Some(v) = as_bitmask(value) {
+                if let Some(v) = as_physics_bitmask_3d(value) {
                     node.collision_layers = v;
                 }
             }
             Some(NodeField::StaticBody3D(StaticBody3DField::CollisionMask)) => {
-                if let Some(v) = as_bitmask(value) {
+                if let Some(v) = as_physics_bitmask_3d(value) {
                     node.collision_mask = v;
                 }
             }
@@ -246,12 +264,12 @@ fn apply_rigid_body_3d_fields(node: &mut RigidBody3D, fields: &[SceneObjectField
                 }
             }
             Some(NodeField::RigidBody3D(RigidBody3DField::CollisionLayers)) => {
-                if let Some(v) = as_bitmask(value) {
+                if let Some(v) = as_physics_bitmask_3d(value) {
                     node.collision_layers = v;
                 }
             }
             Some(NodeField::RigidBody3D(RigidBody3DField::CollisionMask)) => {
-                if let Some(v) = as_bitmask(value) {
+                if let Some(v) = as_physics_bitmask_3d(value) {
                     node.collision_mask = v;
                 }
             }
@@ -326,15 +344,30 @@ fn apply_area_3d_fields(node: &mut Area3D, fields: &[SceneObjectField]) {
                 }
             }
             Some(NodeField::Area3D(Area3DField::CollisionLayers)) => {
-                if let Some(v) = as_bitmask(value) {
+                if let Some(v) = as_physics_bitmask_3d(value) {
                     node.collision_layers = v;
                 }
             }
             Some(NodeField::Area3D(Area3DField::CollisionMask)) => {
-                if let Some(v) = as_bitmask(value) {
+                if let Some(v) = as_physics_bitmask_3d(value) {
                     node.collision_mask = v;
                 }
             }
+            Some(NodeField::Area3D(Area3DField::GravityMode)) => {
+                if let Some(v) = as_area_gravity_mode(value) {
+                    node.gravity_mode = v;
+                }
+            }
+            Some(NodeField::Area3D(Area3DField::Gravity)) => {
+                if let Some(v) = as_vec3(value) {
+                    node.gravity = v;
+                }
+            }
+            Some(NodeField::Area3D(Area3DField::Priority)) => {
+                if let Some(v) = as_i32(value) {
+                    node.priority = v;
+                }
+            }
             _ => {}
         }
     });
@@ -347,6 +380,8 @@ struct Joint3DCommonMut<'a> {
     anchor_b: &'a mut Vector3,
     enabled: &'a mut bool,
     collide_connected: &'a mut bool,
+    break_force: &'a mut f32,
+    break_torque: &'a mut f32,
 }
 
 fn apply_joint_3d_common(
@@ -359,6 +394,7 @@ fn apply_joint_3d_common(
         Some(NodeField::BallJoint3D(field)) => Some(field),
         Some(NodeField::FixedJoint3D(field)) => Some(field),
         Some(NodeField::HingeJoint3D(HingeJoint3DField::Common(field))) => Some(field),
+        Some(NodeField::SpringJoint3D(SpringJoint3DField::Common(field))) => Some(field),
         _ => None,
     };
     match common {
@@ -392,6 +428,16 @@ fn apply_joint_3d_common(
                 *node.collide_connected = v;
             }
         }
+        Some(Joint3DField::BreakForce) => {
+            if let Some(v) = as_f32(value) {
+                *node.break_force = v.max(0.0);
+            }
+        }
+        Some(Joint3DField::BreakTorque) => {
+            if let Some(v) = as_f32(value) {
+                *node.break_torque = v.max(0.0);
+            }
+        }
         None => {}
     }
 }
@@ -406,6 +452,8 @@ fn apply_ball_joint_3d_fields(node: &mut BallJoint3D, fields: &[SceneObjectField
                 anchor_b: &mut node.anchor_b,
                 enabled: &mut node.enabled,
                 collide_connected: &mut node.collide_connected,
+                break_force: &mut node.break_force,
+                break_torque: &mut node.break_torque,
             },
             "BallJoint3D",
             name,
@@ -424,6 +472,8 @@ fn apply_fixed_joint_3d_fields(node: &mut FixedJoint3D, fields: &[SceneObjectFie
                 anchor_b: &mut node.anchor_b,
                 enabled: &mut node.enabled,
                 collide_connected: &mut node.collide_connected,
+                break_force: &mut node.break_force,
+                break_torque: &mut node.break_torque,
             },
             "FixedJoint3D",
             name,
@@ -440,6 +490,20 @@ fn apply_hinge_joint_3d_fields(node: &mut HingeJoint3D, fields: &[SceneObjectFie
                     node.axis = v;
                 }
             }
+            Some(NodeField::HingeJoint3D(HingeJoint3DField::LimitMotor(field))) => {
+                apply_joint_limit_motor(
+                    JointLimitMotorMut {
+                        limit_enabled: &mut node.limit_enabled,
+                        lower_limit: &mut node.lower_limit,
+                        upper_limit: &mut node.upper_limit,
+                        motor_enabled: &mut node.motor_enabled,
+                        motor_target_velocity: &mut node.motor_target_velocity,
+                        motor_max_force: &mut node.motor_max_force,
+                    },
+                    field,
+                    value,
+                )
+            }
             _ => apply_joint_3d_common(
                 Joint3DCommonMut {
                     body_a: &mut node.body_a,
@@ -448,6 +512,8 @@ fn apply_hinge_joint_3d_fields(node: &mut HingeJoint3D, fields: &[SceneObjectFie
                     anchor_b: &mut node.anchor_b,
                     enabled: &mut node.enabled,
                     collide_connected: &mut node.collide_connected,
+                    break_force: &mut node.break_force,
+                    break_torque: &mut node.break_torque,
                 },
                 "HingeJoint3D",
                 name,
@@ -457,6 +523,97 @@ fn apply_hinge_joint_3d_fields(node: &mut HingeJoint3D, fields: &[SceneObjectFie
     });
 }
 
+fn apply_spring_joint_3d_fields(node: &mut SpringJoint3D, fields: &[SceneObjectField]) {
+    SceneFieldIterRef::new(fields).for_each(|name, value| {
+        match resolve_node_field("SpringJoint3D", name) {
+            Some(NodeField::SpringJoint3D(SpringJoint3DField::Spring(field))) => {
+                apply_joint_spring(
+                    JointSpringMut {
+                        rest_length: &mut node.rest_length,
+                        stiffness: &mut node.stiffness,
+                        damping: &mut node.damping,
+                    },
+                    field,
+                    value,
+                )
+            }
+            _ => apply_joint_3d_common(
+                Joint3DCommonMut {
+                    body_a: &mut node.body_a,
+                    body_b: &mut node.body_b,
+                    anchor_a: &mut node.anchor_a,
+                    anchor_b: &mut node.anchor_b,
+                    enabled: &mut node.enabled,
+                    collide_connected: &mut node.collide_connected,
+                    break_force: &mut node.break_force,
+                    break_torque: &mut node.break_torque,
+                },
+                "SpringJoint3D",
+                name,
+                value,
+            ),
+        }
+    });
+}
+
+fn apply_vehicle_body_3d_fields(node: &mut VehicleBody3D, fields: &[SceneObjectField]) {
+    SceneFieldIterRef::new(fields).for_each(|name, value| {
+        let Some(v) = as_f32(value) else {
+            return;
+        };
+        match resolve_node_field("VehicleBody3D", name) {
+            Some(NodeField::VehicleBody3D(VehicleBody3DField::EngineForce)) => {
+                node.engine_force = v;
+            }
+            Some(NodeField::VehicleBody3D(VehicleBody3DField::Brake)) => node.brake = v.max(0.0),
+            Some(NodeField::VehicleBody3D(VehicleBody3DField::Steering)) => node.steering = v,
+            _ => {}
+        }
+    });
+}
+
+fn apply_vehicle_wheel_3d_fields(node: &mut VehicleWheel3D, fields: &[SceneObjectField]) {
+    SceneFieldIterRef::new(fields).for_each(|name, value| {
+        let Some(NodeField::VehicleWheel3D(field)) = resolve_node_field("VehicleWheel3D", name)
+        else {
+            return;
+        };
+        match field {
+            VehicleWheel3DField::Steer => {
+                if let Some(steer) = as_bool(value) {
+                    node.steer = steer;
+                }
+            }
+            VehicleWheel3DField::Drive => {
+                if let Some(drive) = as_bool(value) {
+                    node.drive = drive;
+                }
+            }
+            field => {
+                let Some(v) = as_f32(value) else {
+                    return;
+                };
+                let v = v.max(0.0);
+                match field {
+                    VehicleWheel3DField::Radius => node.radius = v,
+                    VehicleWheel3DField::SuspensionRestLength => {
+                        node.suspension_rest_length = v;
+                        node.suspension_length = v;
+                    }
+                    VehicleWheel3DField::SuspensionTravel => node.suspension_travel = v,
+                    VehicleWheel3DField::SuspensionStiffness => node.suspension_stiffness = v,
+                    VehicleWheel3DField::SuspensionCompression => node.suspension_compression = v,
+                    VehicleWheel3DField::SuspensionDamping => node.suspension_damping = v,
+                    VehicleWheel3DField::MaxSuspensionForce => node.max_suspension_force = v,
+                    VehicleWheel3DField::FrictionSlip => node.friction_slip = v,
+                    VehicleWheel3DField::SideFriction => node.side_friction = v,
+                    VehicleWheel3DField::Steer | VehicleWheel3DField::Drive => {}
+                }
+            }
+        }
+    });
+}
+
 fn as_shape_3d(value: &SceneValue) -> Option<Shape3D> {
     if let Some(source) = as_asset_source(value) {
         return Some(Shape3D::TriMesh { source });
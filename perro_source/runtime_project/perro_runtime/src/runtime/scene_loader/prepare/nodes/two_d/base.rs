@@ -332,6 +332,16 @@ fn apply_node_2d_fields(node: &mut Node2D, fields: &[SceneObjectField]) {
                     node.top_level = v;
                 }
             }
+            SceneFieldName::Custom(name) if name == "interpolate" => {
+                if let Some(v) = value.as_bool() {
+                    node.interpolate = v;
+                }
+            }
+            SceneFieldName::Custom(name) if name == "canvas_layer" => {
+                if let Some(v) = value.as_i32() {
+                    node.canvas_layer = v;
+                }
+            }
             SceneFieldName::ZIndex => {
                 if let Some(v) = value.as_i32() {
                     node.z_index = v;
@@ -2,6 +2,7 @@ fn as_light_color(value: &SceneValue) -> Option<Color> {
     match value {
         SceneValue::Vec4 { x, y, z, w } => Some(Color::new(*x, *y, *z, *w)),
         SceneValue::Vec3 { x, y, z } => Some(Color::rgb(*x, *y, *z)),
+        SceneValue::Color { r, g, b, a } => Some(Color::from_rgba_u8([*r, *g, *b, *a])),
         SceneValue::Str(v) => Color::from_hex(v.as_ref()),
         SceneValue::Key(v) => Color::from_hex(v.as_ref()),
         SceneValue::Object(entries) => {
@@ -25,36 +25,51 @@ define_scene_node_builder! {
 fn apply_character_body_2d_fields(node: &mut CharacterBody2D, fields: &[SceneObjectField]) {
     SceneFieldIterRef::new(fields).for_each(|name, value| {
         match resolve_node_field("CharacterBody2D", name) {
-            Some(NodeField::CharacterBody2D(CharacterBodyField::Enabled)) => {
+            Some(NodeField::CharacterBody2D(CharacterBody2DField::Enabled)) => {
                 if let Some(v) = as_bool(value) {
                     node.enabled = v;
                 }
             }
-            Some(NodeField::CharacterBody2D(CharacterBodyField::CollisionLayers)) => {
-                if let Some(v) = as_bitmask(value) {
+            Some(NodeField::CharacterBody2D(CharacterBody2DField::CollisionLayers)) => {
+                if let Some(v) = as_physics_bitmask_2d(value) {
                     node.collision_layers = v;
                 }
             }
-            Some(NodeField::CharacterBody2D(CharacterBodyField::CollisionMask)) => {
-                if let Some(v) = as_bitmask(value) {
+            Some(NodeField::CharacterBody2D(CharacterBody2DField::CollisionMask)) => {
+                if let Some(v) = as_physics_bitmask_2d(value) {
                     node.collision_mask = v;
                 }
             }
-            Some(NodeField::CharacterBody2D(CharacterBodyField::Friction)) => {
+            Some(NodeField::CharacterBody2D(CharacterBody2DField::Friction)) => {
                 if let Some(v) = as_f32(value) {
                     node.friction = v;
                 }
             }
-            Some(NodeField::CharacterBody2D(CharacterBodyField::Restitution)) => {
+            Some(NodeField::CharacterBody2D(CharacterBody2DField::Restitution)) => {
                 if let Some(v) = as_f32(value) {
                     node.restitution = v;
                 }
             }
-            Some(NodeField::CharacterBody2D(CharacterBodyField::Density)) => {
+            Some(NodeField::CharacterBody2D(CharacterBody2DField::Density)) => {
                 if let Some(v) = as_f32(value) {
                     node.density = v;
                 }
             }
+            Some(NodeField::CharacterBody2D(CharacterBody2DField::FloorMaxAngle)) => {
+                if let Some(v) = as_f32(value) {
+                    node.floor_max_angle = v;
+                }
+            }
+            Some(NodeField::CharacterBody2D(CharacterBody2DField::FloorSnapDistance)) => {
+                if let Some(v) = as_f32(value) {
+                    node.floor_snap_distance = v.max(0.0);
+                }
+            }
+            Some(NodeField::CharacterBody2D(CharacterBody2DField::InheritPlatformVelocity)) => {
+                if let Some(v) = as_bool(value) {
+                    node.inherit_platform_velocity = v;
+                }
+            }
             _ => {}
         }
     });
@@ -124,12 +139,12 @@ fn apply_physics_force_emitter_2d_fields(
                 }
             }
             Some(NodeField::PhysicsForceEmitter2D(PhysicsForceEmitterField::CollisionLayers)) => {
-                if let Some(v) = as_bitmask(value) {
+                if let Some(v) = as_physics_bitmask_2d(value) {
                     node.collision_layers = v;
                 }
             }
             Some(NodeField::PhysicsForceEmitter2D(PhysicsForceEmitterField::CollisionMask)) => {
-                if let Some(v) = as_bitmask(value) {
+                if let Some(v) = as_physics_bitmask_2d(value) {
                     node.collision_mask = v;
                 }
             }
@@ -161,13 +176,26 @@ define_scene_node_builder! {
     apply [apply_fixed_joint_2d_fields];
 }
 
+define_scene_node_builder! {
+    fn build_spring_joint_2d -> SpringJoint2D = SpringJoint2D::default();
+    base node_2d;
+    apply [apply_spring_joint_2d_fields];
+}
+
 fn apply_collision_shape_2d_fields(node: &mut CollisionShape2D, fields: &[SceneObjectField]) {
     SceneFieldIterRef::new(fields).for_each(|name, value| {
-        if let Some(NodeField::CollisionShape2D(CollisionShape2DField::Shape)) =
-            resolve_node_field("CollisionShape2D", name)
-            && let Some(shape) = as_shape_2d(value)
-        {
-            node.shape = shape;
+        match resolve_node_field("CollisionShape2D", name) {
+            Some(NodeField::CollisionShape2D(CollisionShape2DField::Shape))
+                if let Some(shape) = as_shape_2d(value) =>
+            {
+                node.shape = shape;
+            }
+            Some(NodeField::CollisionShape2D(CollisionShape2DField::OneWay))
+                if let Some(v) = as_bool(value) =>
+            {
+                node.one_way = v;
+            }
+            _ => {}
         }
     });
 }
@@ -181,12 +209,12 @@ fn apply_static_body_2d_fields(node: &mut StaticBody2D, fields: &[SceneObjectFie
                 node.enabled = enabled;
             }
             Some(NodeField::StaticBody2D(StaticBody2DField::CollisionLayers))
-                if let Some(v) = as_bitmask(value) =>
+                if let Some(v) = as_physics_bitmask_2d(value) =>
             {
                 node.collision_layers = v;
             }
             Some(NodeField::StaticBody2D(StaticBody2DField::CollisionMask))
-                if let Some(v) = as_bitmask(value) =>
+                if let Some(v) = as_physics_bitmask_2d(value) =>
             {
                 node.collision_mask = v;
             }
@@ -219,12 +247,12 @@ fn apply_rigid_body_2d_fields(node: &mut RigidBody2D, fields: &[SceneObjectField
                 }
             }
             Some(NodeField::RigidBody2D(RigidBody2DField::CollisionLayers)) => {
-                if let Some(v) = as_bitmask(value) {
+                if let Some(v) = as_physics_bitmask_2d(value) {
                     node.collision_layers = v;
                 }
             }
             Some(NodeField::RigidBody2D(RigidBody2DField::CollisionMask)) => {
-                if let Some(v) = as_bitmask(value) {
+                if let Some(v) = as_physics_bitmask_2d(value) {
                     node.collision_mask = v;
                 }
             }
@@ -304,15 +332,30 @@ fn apply_area_2d_fields(node: &mut Area2D, fields: &[SceneObjectField]) {
                 }
             }
             Some(NodeField::Area2D(Area2DField::CollisionLayers)) => {
-                if let Some(v) = as_bitmask(value) {
+                if let Some(v) = as_physics_bitmask_2d(value) {
                     node.collision_layers = v;
                 }
             }
             Some(NodeField::Area2D(Area2DField::CollisionMask)) => {
-                if let Some(v) = as_bitmask(value) {
+                if let Some(v) = as_physics_bitmask_2d(value) {
                     node.collision_mask = v;
                 }
             }
+            Some(NodeField::Area2D(Area2DField::GravityMode)) => {
+                if let Some(v) = as_area_gravity_mode(value) {
+                    node.gravity_mode = v;
+                }
+            }
+            Some(NodeField::Area2D(Area2DField::Gravity)) => {
+                if let Some(v) = as_vec2(value) {
+                    node.gravity = v;
+                }
+            }
+            Some(NodeField::Area2D(Area2DField::Priority)) => {
+                if let Some(v) = as_i32(value) {
+                    node.priority = v;
+                }
+            }
             _ => {}
         }
     });
@@ -325,6 +368,8 @@ struct Joint2DCommonMut<'a> {
     anchor_b: &'a mut Vector2,
     enabled: &'a mut bool,
     collide_connected: &'a mut bool,
+    break_force: &'a mut f32,
+    break_torque: &'a mut f32,
 }
 
 fn apply_joint_2d_common(
@@ -334,9 +379,10 @@ fn apply_joint_2d_common(
     value: &SceneValue,
 ) {
     let common = match resolve_node_field(ty, name) {
-        Some(NodeField::PinJoint2D(field)) => Some(field),
+        Some(NodeField::PinJoint2D(PinJoint2DField::Common(field))) => Some(field),
         Some(NodeField::FixedJoint2D(field)) => Some(field),
         Some(NodeField::DistanceJoint2D(DistanceJoint2DField::Common(field))) => Some(field),
+        Some(NodeField::SpringJoint2D(SpringJoint2DField::Common(field))) => Some(field),
         _ => None,
     };
     match common {
@@ -370,25 +416,119 @@ fn apply_joint_2d_common(
                 *node.collide_connected = v;
             }
         }
+        Some(Joint2DField::BreakForce) => {
+            if let Some(v) = as_f32(value) {
+                *node.break_force = v.max(0.0);
+            }
+        }
+        Some(Joint2DField::BreakTorque) => {
+            if let Some(v) = as_f32(value) {
+                *node.break_torque = v.max(0.0);
+            }
+        }
         None => {}
     }
 }
 
+/// angle limit + motor fields of PinJoint2D / HingeJoint3D.
+struct JointLimitMotorMut<'a> {
+    limit_enabled: &'a mut bool,
+    lower_limit: &'a mut f32,
+    upper_limit: &'a mut f32,
+    motor_enabled: &'a mut bool,
+    motor_target_velocity: &'a mut f32,
+    motor_max_force: &'a mut f32,
+}
+
+fn apply_joint_limit_motor(
+    node: JointLimitMotorMut<'_>,
+    field: JointLimitMotorField,
+    value: &SceneValue,
+) {
+    match field {
+        JointLimitMotorField::LimitEnabled => {
+            if let Some(v) = as_bool(value) {
+                *node.limit_enabled = v;
+            }
+        }
+        JointLimitMotorField::LowerLimit => {
+            if let Some(v) = as_f32(value) {
+                *node.lower_limit = v;
+            }
+        }
+        JointLimitMotorField::UpperLimit => {
+            if let Some(v) = as_f32(value) {
+                *node.upper_limit = v;
+            }
+        }
+        JointLimitMotorField::MotorEnabled => {
+            if let Some(v) = as_bool(value) {
+                *node.motor_enabled = v;
+            }
+        }
+        JointLimitMotorField::MotorTargetVelocity => {
+            if let Some(v) = as_f32(value) {
+                *node.motor_target_velocity = v;
+            }
+        }
+        JointLimitMotorField::MotorMaxForce => {
+            if let Some(v) = as_f32(value) {
+                *node.motor_max_force = v.max(0.0);
+            }
+        }
+    }
+}
+
+struct JointSpringMut<'a> {
+    rest_length: &'a mut f32,
+    stiffness: &'a mut f32,
+    damping: &'a mut f32,
+}
+
+fn apply_joint_spring(node: JointSpringMut<'_>, field: JointSpringField, value: &SceneValue) {
+    let Some(v) = as_f32(value) else {
+        return;
+    };
+    match field {
+        JointSpringField::RestLength => *node.rest_length = v.max(0.0),
+        JointSpringField::Stiffness => *node.stiffness = v.max(0.0),
+        JointSpringField::Damping => *node.damping = v.max(0.0),
+    }
+}
+
 fn apply_pin_joint_2d_fields(node: &mut PinJoint2D, fields: &[SceneObjectField]) {
     SceneFieldIterRef::new(fields).for_each(|name, value| {
-        apply_joint_2d_common(
-            Joint2DCommonMut {
-                body_a: &mut node.body_a,
-                body_b: &mut node.body_b,
-                anchor_a: &mut node.anchor_a,
-                anchor_b: &mut node.anchor_b,
-                enabled: &mut node.enabled,
-                collide_connected: &mut node.collide_connected,
-            },
-            "PinJoint2D",
-            name,
-            value,
-        );
+        match resolve_node_field("PinJoint2D", name) {
+            Some(NodeField::PinJoint2D(PinJoint2DField::LimitMotor(field))) => {
+                apply_joint_limit_motor(
+                    JointLimitMotorMut {
+                        limit_enabled: &mut node.limit_enabled,
+                        lower_limit: &mut node.lower_limit,
+                        upper_limit: &mut node.upper_limit,
+                        motor_enabled: &mut node.motor_enabled,
+                        motor_target_velocity: &mut node.motor_target_velocity,
+                        motor_max_force: &mut node.motor_max_force,
+                    },
+                    field,
+                    value,
+                )
+            }
+            _ => apply_joint_2d_common(
+                Joint2DCommonMut {
+                    body_a: &mut node.body_a,
+                    body_b: &mut node.body_b,
+                    anchor_a: &mut node.anchor_a,
+                    anchor_b: &mut node.anchor_b,
+                    enabled: &mut node.enabled,
+                    collide_connected: &mut node.collide_connected,
+                    break_force: &mut node.break_force,
+                    break_torque: &mut node.break_torque,
+                },
+                "PinJoint2D",
+                name,
+                value,
+            ),
+        }
     });
 }
 
@@ -402,6 +542,8 @@ fn apply_fixed_joint_2d_fields(node: &mut FixedJoint2D, fields: &[SceneObjectFie
                 anchor_b: &mut node.anchor_b,
                 enabled: &mut node.enabled,
                 collide_connected: &mut node.collide_connected,
+                break_force: &mut node.break_force,
+                break_torque: &mut node.break_torque,
             },
             "FixedJoint2D",
             name,
@@ -431,6 +573,8 @@ fn apply_distance_joint_2d_fields(node: &mut DistanceJoint2D, fields: &[SceneObj
                     anchor_b: &mut node.anchor_b,
                     enabled: &mut node.enabled,
                     collide_connected: &mut node.collide_connected,
+                    break_force: &mut node.break_force,
+                    break_torque: &mut node.break_torque,
                 },
                 "DistanceJoint2D",
                 name,
@@ -440,6 +584,39 @@ fn apply_distance_joint_2d_fields(node: &mut DistanceJoint2D, fields: &[SceneObj
     });
 }
 
+fn apply_spring_joint_2d_fields(node: &mut SpringJoint2D, fields: &[SceneObjectField]) {
+    SceneFieldIterRef::new(fields).for_each(|name, value| {
+        match resolve_node_field("SpringJoint2D", name) {
+            Some(NodeField::SpringJoint2D(SpringJoint2DField::Spring(field))) => {
+                apply_joint_spring(
+                    JointSpringMut {
+                        rest_length: &mut node.rest_length,
+                        stiffness: &mut node.stiffness,
+                        damping: &mut node.damping,
+                    },
+                    field,
+                    value,
+                )
+            }
+            _ => apply_joint_2d_common(
+                Joint2DCommonMut {
+                    body_a: &mut node.body_a,
+                    body_b: &mut node.body_b,
+                    anchor_a: &mut node.anchor_a,
+                    anchor_b: &mut node.anchor_b,
+                    enabled: &mut node.enabled,
+                    collide_connected: &mut node.collide_connected,
+                    break_force: &mut node.break_force,
+                    break_torque: &mut node.break_torque,
+                },
+                "SpringJoint2D",
+                name,
+                value,
+            ),
+        }
+    });
+}
+
 fn as_shape_2d(value: &SceneValue) -> Option<Shape2D> {
     let SceneValue::Object(entries) = value else {
         return None;
@@ -116,7 +116,7 @@ fn apply_sprite_2d_fields(node: &mut Sprite2D, fields: &[SceneObjectField]) {
     SceneFieldIterRef::new(fields).for_each_field(|field, value| {
         match field {
             SceneFieldName::TextureRegion => {
-                if let Some((x, y, w, h)) = value.as_vec4()
+                if let Some((x, y, w, h)) = value.as_rect()
                     && w > 0.0
                     && h > 0.0
                 {
@@ -217,7 +217,7 @@ fn apply_image_button_2d_fields(node: &mut ImageButton2D, fields: &[SceneObjectF
             }
         }
         name if scene_key_in(name, TEXTURE_REGION_KEYS) => {
-            if let Some((x, y, w, h)) = value.as_vec4()
+            if let Some((x, y, w, h)) = value.as_rect()
                 && w > 0.0
                 && h > 0.0
             {
@@ -271,7 +271,7 @@ fn apply_nine_slice_2d_fields(node: &mut NineSlice2D, fields: &[SceneObjectField
             }
         }
         name if scene_key_in(name, TEXTURE_REGION_KEYS) => {
-            if let Some((x, y, w, h)) = value.as_vec4() && w > 0.0 && h > 0.0 {
+            if let Some((x, y, w, h)) = value.as_rect() && w > 0.0 && h > 0.0 {
                 node.texture_region = Some([x, y, w, h]);
             }
         }
@@ -493,7 +493,7 @@ fn apply_nine_slice_button_2d_fields(node: &mut NineSliceButton2D, fields: &[Sce
             }
         }
         name if scene_key_in(name, TEXTURE_REGION_KEYS) => {
-            if let Some((x, y, w, h)) = value.as_vec4() && w > 0.0 && h > 0.0 {
+            if let Some((x, y, w, h)) = value.as_rect() && w > 0.0 && h > 0.0 {
                 node.texture_region = Some([x, y, w, h]);
             }
         }
@@ -538,3 +538,83 @@ pub(super) fn build_ui_tree_list(data: &SceneDefNodeData) -> UiTreeList {
     });
     node
 }
+
+pub(super) fn build_ui_markdown(data: &SceneDefNodeData) -> UiMarkdown {
+    let mut node = UiMarkdown::new();
+    if let Some(base) = data.base_ref() {
+        apply_ui_root_data(&mut node.base, base);
+    }
+    apply_ui_root_fields(&mut node.base, &data.fields);
+    SceneFieldIterRef::new(&data.fields).for_each(|name, value| match name {
+        "source" => {
+            if let Some(v) = as_str(value) {
+                node.source = Cow::Owned(v.to_string());
+            }
+        }
+        "markdown" | "text" => {
+            if let Some(v) = as_str(value) {
+                node.markdown = Cow::Owned(decode_scene_text_literal(v));
+            }
+        }
+        "font" => {
+            if let Some(v) = as_str(value).and_then(perro_ui::UiFont::parse) {
+                node.font = v;
+            }
+        }
+        "bold_font" => {
+            if let Some(v) = as_str(value).and_then(perro_ui::UiFont::parse) {
+                node.bold_font = v;
+            }
+        }
+        "font_size" => {
+            if let Some(v) = as_f32(value) {
+                node.font_size = v.max(1.0);
+            }
+        }
+        "heading_scale" => {
+            if let Some(v) = as_f32(value) {
+                node.heading_scale = v.max(1.0);
+            }
+        }
+        "line_spacing" => {
+            if let Some(v) = as_f32(value) {
+                node.line_spacing = v.max(0.0);
+            }
+        }
+        "paragraph_spacing" => {
+            if let Some(v) = as_f32(value) {
+                node.paragraph_spacing = v.max(0.0);
+            }
+        }
+        "list_indent" => {
+            if let Some(v) = as_f32(value) {
+                node.list_indent = v.max(0.0);
+            }
+        }
+        "text_color" | "color" => {
+            if let Some(v) = as_scene_color(value) {
+                node.text_color = v;
+            }
+        }
+        "bold_color" => {
+            if let Some(v) = as_scene_color(value) {
+                node.bold_color = v;
+            }
+        }
+        "heading_color" => {
+            if let Some(v) = as_scene_color(value) {
+                node.heading_color = v;
+            }
+        }
+        "link_color" => {
+            if let Some(v) = as_scene_color(value) {
+                node.link_color = v;
+            }
+        }
+        "link_signals" | "link_clicked_signals" => {
+            node.link_signals = as_signal_ids(value);
+        }
+        _ => {}
+    });
+    node
+}
@@ -44,6 +44,11 @@ pub(super) fn apply_ui_root_fields(node: &mut UiNode, fields: &[SceneObjectField
                 node.clip_children = v;
             }
         }
+        "initial_focus" => {
+            if let Some(v) = as_bool(value) {
+                node.initial_focus = v;
+            }
+        }
         "anchor" => {
             if let Some(v) = as_ui_anchor(value) {
                 node.layout.anchor = v;
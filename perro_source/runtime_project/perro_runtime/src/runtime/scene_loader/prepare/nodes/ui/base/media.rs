@@ -98,7 +98,7 @@ pub(super) fn apply_ui_nine_slice_fields(node: &mut UiNineSlice, fields: &[Scene
             }
         }
         name if scene_key_in(name, TEXTURE_REGION_KEYS) => {
-            if let Some((x, y, w, h)) = value.as_vec4() && w > 0.0 && h > 0.0 {
+            if let Some((x, y, w, h)) = value.as_rect() && w > 0.0 && h > 0.0 {
                 node.texture_region = Some([x, y, w, h]);
             }
         }
@@ -136,7 +136,7 @@ pub(super) fn apply_ui_nine_slice_button_fields(node: &mut UiNineSliceButton, fi
             }
         }
         name if scene_key_in(name, TEXTURE_REGION_KEYS) => {
-            if let Some((x, y, w, h)) = value.as_vec4() && w > 0.0 && h > 0.0 {
+            if let Some((x, y, w, h)) = value.as_rect() && w > 0.0 && h > 0.0 {
                 node.texture_region = Some([x, y, w, h]);
             }
         }
@@ -223,6 +223,11 @@ pub(super) fn apply_ui_scroll_container_fields(node: &mut UiScrollContainer, fie
                 node.scroll_bar_padding = v;
             }
         }
+        "drag_scroll" => {
+            if let Some(v) = as_bool(value) {
+                node.drag_scroll = v;
+            }
+        }
         _ => {}
     });
 }
@@ -9,7 +9,7 @@ pub(super) fn parse_ui_style_source(source: &str) -> Option<perro_ui::UiStyle> {
         wrapped = format!("{{\n{text}\n}}");
         wrapped.as_str()
     };
-    let parsed = std::panic::catch_unwind(|| Parser::new(parse_text).parse_value_literal()).ok()?;
+    let parsed = Parser::new(parse_text).try_parse_value_literal().ok()?;
     let SceneValue::Object(entries) = parsed else {
         return None;
     };
@@ -680,6 +680,7 @@ pub(super) fn as_ui_image_scale_mode(value: &SceneValue) -> Option<UiImageScaleM
 pub(super) fn as_vec4_array(value: &SceneValue) -> Option<[f32; 4]> {
     match value {
         SceneValue::Vec4 { x, y, z, w } => Some([*x, *y, *z, *w]),
+        SceneValue::Rect { x, y, w, h } => Some([*x, *y, *w, *h]),
         SceneValue::Array(values) if values.len() == 4 => Some([
             as_f32(&values[0])?,
             as_f32(&values[1])?,
@@ -724,6 +725,7 @@ pub(super) fn as_scene_color(value: &SceneValue) -> Option<Color> {
     match value {
         SceneValue::Vec4 { x, y, z, w } => Some(Color::new(*x, *y, *z, *w)),
         SceneValue::Vec3 { x, y, z } => Some(Color::rgb(*x, *y, *z)),
+        SceneValue::Color { r, g, b, a } => Some(Color::from_rgba_u8([*r, *g, *b, *a])),
         SceneValue::Str(v) => Color::from_hex(v.as_ref()),
         SceneValue::Key(v) => Color::from_hex(v.as_ref()),
         _ => None,
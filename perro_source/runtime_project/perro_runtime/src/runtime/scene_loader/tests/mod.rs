@@ -1,6 +1,7 @@
 use super::*;
 use crate::rs_ctx::RuntimeResourceApi;
 use crate::runtime_project::RuntimeProject;
+use perro_ids::SignalID;
 use perro_nodes::{NodeType, SceneNode};
 use perro_project::LocalizationConfig;
 use perro_render_bridge::{RenderCommand, UiCommand};
@@ -55,8 +56,11 @@ const HOST_NODES: &[SceneNodeEntry] = &[SceneNodeEntry {
     parent: None,
     script: None,
     clear_script: false,
+    enabled: true,
     root_of: Some(Cow::Borrowed("dlc://test/scenes/main.scn")),
     script_vars: Cow::Borrowed(EMPTY_FIELDS),
+    meta: Cow::Borrowed(EMPTY_FIELDS),
+    connections: Cow::Borrowed(&[]),
 }];
 static HOST_SCENE: Scene = Scene {
     nodes: Cow::Borrowed(HOST_NODES),
@@ -75,8 +79,11 @@ const HOME_NODES: &[SceneNodeEntry] = &[SceneNodeEntry {
     parent: None,
     script: None,
     clear_script: false,
+    enabled: true,
     root_of: None,
     script_vars: Cow::Borrowed(EMPTY_FIELDS),
+    meta: Cow::Borrowed(EMPTY_FIELDS),
+    connections: Cow::Borrowed(&[]),
 }];
 static HOME_SCENE: Scene = Scene {
     nodes: Cow::Borrowed(HOME_NODES),
@@ -99,8 +106,11 @@ const DOCS_NODES: &[SceneNodeEntry] = &[
         parent: None,
         script: None,
         clear_script: false,
+        enabled: true,
         root_of: None,
         script_vars: Cow::Borrowed(EMPTY_FIELDS),
+        meta: Cow::Borrowed(EMPTY_FIELDS),
+        connections: Cow::Borrowed(&[]),
     },
     SceneNodeEntry {
         data: DOCS_COPY_DATA,
@@ -112,8 +122,11 @@ const DOCS_NODES: &[SceneNodeEntry] = &[
         parent: Some(SceneKey(0)),
         script: None,
         clear_script: false,
+        enabled: true,
         root_of: None,
         script_vars: Cow::Borrowed(EMPTY_FIELDS),
+        meta: Cow::Borrowed(EMPTY_FIELDS),
+        connections: Cow::Borrowed(&[]),
     },
 ];
 static DOCS_SCENE: Scene = Scene {
@@ -132,8 +145,11 @@ const BAD_SCRIPT_NODES: &[SceneNodeEntry] = &[SceneNodeEntry {
     parent: None,
     script: Some(Cow::Borrowed("res://missing_script.rs")),
     clear_script: false,
+    enabled: true,
     root_of: None,
     script_vars: Cow::Borrowed(EMPTY_FIELDS),
+    meta: Cow::Borrowed(EMPTY_FIELDS),
+    connections: Cow::Borrowed(&[]),
 }];
 static BAD_SCRIPT_SCENE: Scene = Scene {
     nodes: Cow::Borrowed(BAD_SCRIPT_NODES),
@@ -276,6 +292,37 @@ fn test_lookup(path_hash: u64) -> &'static Scene {
     }
 }
 
+#[test]
+fn scene_override_replaces_main_scene_and_root_route() {
+    let args = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+    let parse = crate::runtime_project::parse_scene_override;
+
+    assert_eq!(
+        parse(&args(&["game", "--scene", "res://tests/arena.scn"]), None).expect("flag"),
+        Some("res://tests/arena.scn".to_string())
+    );
+    assert_eq!(
+        parse(&args(&["game"]), Some("res://env.scn".to_string())).expect("env"),
+        Some("res://env.scn".to_string())
+    );
+    assert_eq!(parse(&args(&["game"]), None).expect("none"), None);
+    assert!(parse(&args(&["game", "--scene"]), None).is_err());
+    assert!(parse(&args(&["game", "--scene", "arena.scn"]), None).is_err());
+    assert!(parse(&args(&["game", "--scene", "res://../x.scn"]), None).is_err());
+
+    let mut project = RuntimeProject::new("Override Test", ".");
+    project.config.main_scene_hash = Some(7);
+    let project = project.with_main_scene_override("res://tests/arena.scn");
+    assert_eq!(project.config.main_scene, "res://tests/arena.scn");
+    assert_eq!(project.config.main_scene_hash, None);
+    let mut runtime = Runtime::new();
+    runtime.project = Some(Arc::new(project));
+    assert_eq!(
+        runtime.initial_route_scene_for_href(Some("/")),
+        Some(("/".to_string(), "res://tests/arena.scn".to_string()))
+    );
+}
+
 #[test]
 fn initial_route_scene_uses_match_or_root_fallback() {
     let mut project = RuntimeProject::new("Route Test", ".");
@@ -443,7 +490,7 @@ fn merge_prevalidation_rejects_late_link_without_live_mutation() {
 
     let mut runtime = Runtime::new();
     let mut sentinel = SceneNode::new(perro_nodes::SceneNodeData::Node);
-    sentinel.name = Cow::Borrowed("sentinel");
+    sentinel.set_name("sentinel");
     let sentinel = runtime.nodes.insert(sentinel);
     let node_count = runtime.nodes.len();
     let update_count = runtime.internal_updates.internal_update_nodes.len();
@@ -575,6 +622,113 @@ fn scene_load_updates_tag_index_during_merge() {
     );
 }
 
+#[test]
+fn scene_groups_header_registers_group_membership_during_merge() {
+    let scene = Parser::new(
+        "$root = @root\n\n[root]\n[Node]\n[/Node]\n[/root]\n[goblin]\nparent = @root\ngroups = [\"enemies\", \"saveable\"]\ntags = [\"enemies\", \"melee\"]\n[Node]\n[/Node]\n[/goblin]\n",
+    )
+    .parse_scene();
+    let prepared = prepare_scene_with_loader_and_styles(&scene, &|_| unreachable!(), None)
+        .expect("test or bench setup must succeed");
+    let mut runtime = Runtime::new();
+
+    merge_prepared_scene(&mut runtime, prepared).expect("test or bench setup must succeed");
+    let goblin = runtime.nodes.named_ids("goblin")[0];
+
+    for group in ["enemies", "saveable", "melee"] {
+        let tag = perro_ids::TagID::from_string(group);
+        let members = runtime.nodes.tag_index().get(&tag);
+        assert_eq!(members.map(|nodes| nodes.len()), Some(1), "{group}");
+        assert!(
+            members.is_some_and(|nodes| nodes.contains(&goblin)),
+            "{group}"
+        );
+    }
+}
+
+#[test]
+fn scene_meta_header_lands_on_nodes_with_key_refs_resolved() {
+    let scene = Parser::new(
+        "$root = @root\n\n[root]\nmeta = { loot_table = \"res://loot/goblin.ron\", guard = @guard }\n[Node]\n[/Node]\n[/root]\n[guard]\nparent = @root\n[Node]\n[/Node]\n[/guard]\n",
+    )
+    .parse_scene();
+    let prepared = prepare_scene_with_loader_and_styles(&scene, &|_| unreachable!(), None)
+        .expect("test or bench setup must succeed");
+    let mut runtime = Runtime::new();
+
+    let merged =
+        merge_prepared_scene(&mut runtime, prepared).expect("test or bench setup must succeed");
+    let guard = runtime.nodes.named_ids("guard")[0];
+    let root = merged.scene_root;
+
+    assert_eq!(
+        runtime.nodes.get_meta(root, "loot_table"),
+        Some(&Variant::from("res://loot/goblin.ron"))
+    );
+    assert_eq!(
+        runtime.nodes.get_meta(root, "guard"),
+        Some(&Variant::from(guard))
+    );
+    assert!(runtime.nodes.node_meta(guard).is_none());
+}
+
+#[test]
+fn scene_connect_lines_resolve_to_node_event_signals() {
+    let scene = Parser::new(
+        r#"$root = @root
+[root]
+[Node]
+[/Node]
+[/root]
+[play_button]
+parent = @root
+connect pressed -> /root/ui/controller.on_button_pressed
+connect hovered -> @controller.on_hover
+[Node]
+[/Node]
+[/play_button]
+[ui]
+parent = @root
+[Node]
+[/Node]
+[/ui]
+[controller]
+parent = @ui
+[Node]
+[/Node]
+[/controller]
+"#,
+    )
+    .parse_scene();
+    let prepared = prepare_scene_with_loader_and_styles(&scene, &|_| unreachable!(), None)
+        .expect("test or bench setup must succeed");
+    let mut runtime = Runtime::new();
+
+    let merged =
+        merge_prepared_scene(&mut runtime, prepared).expect("test or bench setup must succeed");
+    let controller = runtime.nodes.named_ids("controller")[0];
+    let bound = merged
+        .connections
+        .iter()
+        .map(|bind| (bind.target, bind.signal, bind.method))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        bound,
+        vec![
+            (
+                controller,
+                SignalID::from_string("play_button_pressed"),
+                ScriptMemberID::from_string("on_button_pressed"),
+            ),
+            (
+                controller,
+                SignalID::from_string("play_button_hovered"),
+                ScriptMemberID::from_string("on_hover"),
+            ),
+        ]
+    );
+}
+
 #[test]
 fn runtime_scene_load_marks_ui_dirty_for_same_frame_extract() {
     let first_scene = Parser::new(
@@ -762,6 +916,67 @@ fn scene_locale_text_binding_refreshes_on_locale_change() {
     assert_eq!(label_text, "Centro");
 }
 
+#[test]
+fn scene_tr_call_resolves_at_instancing_and_on_locale_change() {
+    fn static_lookup(locale: Locale, key_hash: u64) -> &'static str {
+        if key_hash != perro_ids::string_to_u64("MENU_START") {
+            return "";
+        }
+        match locale {
+            Locale::EN => "Start",
+            Locale::FR => "Jouer",
+            _ => "",
+        }
+    }
+
+    let scene = Parser::new(
+        r#"
+            $root = @title
+            [title]
+            [UiLabel]
+                text = tr("MENU_START")
+            [/UiLabel]
+            [/title]
+            "#,
+    )
+    .parse_scene();
+    let prepared = prepare::prepare_scene_with_loader(&scene, &|path| {
+        Err(format!("unknown scene path `{path}`"))
+    })
+    .expect("prepare scene");
+    let mut runtime = Runtime::new();
+    runtime.resource_api = RuntimeResourceApi::new(
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(static_lookup),
+        None,
+        Some(LocalizationConfig {
+            source_csv: "locale.csv".to_string(),
+            key_column: "key".to_string(),
+            default_locale: "en".to_string(),
+        }),
+    );
+    merge::merge_prepared_scene(&mut runtime, prepared).expect("merge scene");
+    let title_text = |runtime: &Runtime| {
+        runtime
+            .nodes
+            .iter()
+            .find_map(|(_, node)| match &node.data {
+                perro_nodes::SceneNodeData::UiLabel(label) => Some(label.text.as_ref().to_string()),
+                _ => None,
+            })
+            .expect("title text")
+    };
+    assert_eq!(title_text(&runtime), "Start");
+
+    assert!(runtime.resource_api.localization_set_locale(Locale::FR));
+    runtime.extract_render_ui_commands();
+    assert_eq!(title_text(&runtime), "Jouer");
+}
+
 #[test]
 fn runtime_locale_text_binding_can_switch_key() {
     fn static_lookup(locale: Locale, key_hash: u64) -> &'static str {
@@ -911,3 +1126,123 @@ fn runtime_locale_text_binding_supports_world_labels() {
     label_texts.sort();
     assert_eq!(label_texts, ["Nombre", "PV"]);
 }
+
+#[test]
+fn hot_reload_scene_replaces_live_instances_from_disk() {
+    let dir = CacheTempDir::new("hot-reload");
+    let file = dir.0.join("level.scn");
+    let path = file.to_string_lossy().to_string();
+    fs::write(
+        &file,
+        "$root = @level\n\n[level]\n[Node]\n[/Node]\n[/level]\n",
+    )
+    .expect("test or bench setup must succeed");
+    let mut runtime = Runtime::new();
+    runtime.project = Some(Arc::new(RuntimeProject::new("Scene Test", ".")));
+
+    let old_root = runtime
+        .load_scene_at_runtime(&path)
+        .expect("test or bench setup must succeed");
+    fs::write(
+        &file,
+        "$root = @level\n\n[level]\n[Node]\n[/Node]\n[/level]\n[spawn]\nparent = @level\n[Node]\n[/Node]\n[/spawn]\n",
+    )
+    .expect("test or bench setup must succeed");
+
+    assert_eq!(runtime.hot_reload_scene(&path), Ok(1));
+    assert!(runtime.nodes.get(old_root).is_none());
+    let new_root = runtime.nodes.named_ids("level").to_vec();
+    assert_eq!(new_root.len(), 1);
+    let spawn = runtime.nodes.named_ids("spawn").to_vec();
+    assert_eq!(spawn.len(), 1);
+    assert_eq!(
+        runtime.nodes.get(spawn[0]).map(|node| node.get_parent()),
+        Some(new_root[0])
+    );
+
+    // A broken edit keeps the running instance.
+    fs::write(
+        &file,
+        "$root = @level\n[level]\nscript_vars = { v = 1 / 0 }\n[/level]\n",
+    )
+    .expect("test or bench setup must succeed");
+    assert!(runtime.hot_reload_scene(&path).is_err());
+    assert!(runtime.nodes.get(new_root[0]).is_some());
+}
+
+#[test]
+fn additive_scenes_pause_and_unload_independently() {
+    let dir = CacheTempDir::new("additive-scenes");
+    let world_file = dir.0.join("world.scn");
+    let chunk_file = dir.0.join("chunk.scn");
+    fs::write(
+        &world_file,
+        "$root = @world\n\n[world]\n[Node]\n[/Node]\n[/world]\n[sun]\nparent = @world\n[Node]\n[/Node]\n[/sun]\n",
+    )
+    .expect("test or bench setup must succeed");
+    fs::write(
+        &chunk_file,
+        "$root = @chunk\n\n[chunk]\n[Node]\n[/Node]\n[/chunk]\n",
+    )
+    .expect("test or bench setup must succeed");
+    let mut runtime = Runtime::new();
+    runtime.project = Some(Arc::new(RuntimeProject::new("Scene Test", ".")));
+
+    let world = runtime
+        .load_scene_additive_at_runtime(&world_file.to_string_lossy())
+        .expect("test or bench setup must succeed");
+    let chunk = runtime
+        .load_scene_additive_at_runtime(&chunk_file.to_string_lossy())
+        .expect("test or bench setup must succeed");
+    assert_eq!(runtime.additive_scene_roots(), [world, chunk]);
+
+    let sun = runtime.nodes.named_ids("sun").to_vec();
+    assert!(runtime.set_scene_paused(world, true));
+    assert!(runtime.is_scene_paused(world));
+    assert!(runtime.is_suspended(sun[0]));
+    assert!(!runtime.is_suspended(chunk));
+    assert!(!runtime.set_scene_paused(sun[0], true));
+
+    assert!(runtime.unload_scene_at_runtime(chunk));
+    assert!(runtime.nodes.get(chunk).is_none());
+    assert_eq!(runtime.additive_scene_roots(), [world]);
+    assert!(!runtime.unload_scene_at_runtime(chunk));
+
+    assert!(runtime.set_scene_paused(world, false));
+    assert!(!runtime.is_suspended(sun[0]));
+}
+
+#[test]
+fn enabled_header_suspends_and_hides_subtree_until_enabled() {
+    let dir = CacheTempDir::new("node-enabled");
+    let file = dir.0.join("level.scn");
+    fs::write(
+        &file,
+        "$root = @level\n\n[level]\n[Node2D]\n[/Node2D]\n[/level]\n[hud]\nparent = @level\nenabled = false\n[Node2D]\n[/Node2D]\n[/hud]\n[icon]\nparent = @hud\nvisible = false\n[Node2D]\n[/Node2D]\n[/icon]\n",
+    )
+    .expect("test or bench setup must succeed");
+    let mut runtime = Runtime::new();
+    runtime.project = Some(Arc::new(RuntimeProject::new("Scene Test", ".")));
+
+    let level = runtime
+        .load_scene_at_runtime(&file.to_string_lossy())
+        .expect("test or bench setup must succeed");
+    let hud = runtime.nodes.named_ids("hud")[0];
+    let icon = runtime.nodes.named_ids("icon")[0];
+    assert!(!runtime.is_node_enabled(hud));
+    assert!(runtime.is_node_enabled(icon));
+    assert!(runtime.is_suspended(icon));
+    assert!(!runtime.is_effectively_visible(hud));
+    assert!(!runtime.is_suspended(level));
+
+    assert!(runtime.set_node_enabled(hud, true));
+    assert!(!runtime.is_suspended(icon));
+    assert!(runtime.is_effectively_visible(hud));
+    // `visible = false` in the header is the node's own field.
+    assert!(!runtime.is_effectively_visible(icon));
+
+    assert!(runtime.set_node_enabled(icon, false));
+    assert!(NodeAPI::remove_node(&mut runtime, icon));
+    assert!(runtime.disabled_nodes.is_empty());
+    assert!(!runtime.set_node_enabled(icon, true));
+}
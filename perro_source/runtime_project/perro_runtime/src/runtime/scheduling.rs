@@ -1,3 +1,4 @@
+use super::input_bridge::input_event_variant;
 use super::{Runtime, UpdateScheduleTiming};
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::Instant;
@@ -18,7 +19,7 @@ impl Runtime {
         let mut i = 0;
         while i < self.schedules.update_slots.len() {
             let (instance_index, id) = self.schedules.update_slots[i];
-            if !self.is_suspended_by_sub_view(id) {
+            if !self.is_suspended(id) {
                 self.call_update_script_scheduled_with_context(instance_index, id, &res, &ipt);
             }
             i += 1;
@@ -38,7 +39,7 @@ impl Runtime {
         let mut i = 0;
         while i < self.schedules.fixed_slots.len() {
             let (instance_index, id) = self.schedules.fixed_slots[i];
-            if !self.is_suspended_by_sub_view(id) {
+            if !self.is_suspended(id) {
                 self.call_fixed_update_script_scheduled_with_context(
                     instance_index,
                     id,
@@ -50,6 +51,47 @@ impl Runtime {
         }
     }
 
+    /// Deliver buffered device events to `on_input` scripts, event-major so
+    /// every script sees event N before any script sees event N+1.
+    pub(crate) fn run_input_schedule(&mut self) {
+        self.schedules.snapshot_input(&self.scripts);
+        if self.schedules.input_slots.is_empty() {
+            return;
+        }
+        let mut events = std::mem::take(&mut self.schedules.input_events);
+        let dropped = self.input.drain_input_events_into(&mut events);
+        if dropped > 0 {
+            eprintln!("[perro][input] event log full; dropped {dropped} input event(s)");
+        }
+        let resource_api = self.resource_api.clone();
+        let res = perro_resource_api::ResourceWindow::new(resource_api.as_ref());
+        let input_ptr = std::ptr::addr_of!(self.input);
+        // SAFETY: During callback dispatch, input is treated as immutable runtime state.
+        // Engine invariant: only window/event ingestion mutates input, outside script callback execution.
+        let ipt = unsafe { perro_input_api::InputWindow::new(&*input_ptr) };
+        for event in &events {
+            let Some(event) = input_event_variant(event) else {
+                continue;
+            };
+            let mut i = 0;
+            while i < self.schedules.input_slots.len() {
+                let (instance_index, id) = self.schedules.input_slots[i];
+                if !self.is_suspended(id) {
+                    self.call_input_script_scheduled_with_context(
+                        instance_index,
+                        id,
+                        &event,
+                        &res,
+                        &ipt,
+                    );
+                }
+                i += 1;
+            }
+        }
+        events.clear();
+        self.schedules.input_events = events;
+    }
+
     pub(crate) fn run_start_schedule(&mut self) {
         let mut queued = std::mem::take(&mut self.script_runtime.pending_start_scripts);
         let mut ran_start = false;
@@ -106,7 +148,7 @@ impl Runtime {
         let mut i = 0;
         while i < self.schedules.update_slots.len() {
             let (instance_index, id) = self.schedules.update_slots[i];
-            if self.is_suspended_by_sub_view(id) {
+            if self.is_suspended(id) {
                 i += 1;
                 continue;
             }
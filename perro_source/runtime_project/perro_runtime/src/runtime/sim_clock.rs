@@ -0,0 +1,111 @@
+use super::Runtime;
+use perro_modules::random::{SeededRng, hash_combine, hash_u64};
+
+/// Fixed-tick clock + random stream for lockstep and replays. Only
+/// `fixed_update` advances it, so peers that run the same ticks from the same
+/// seed read the same times and draw the same numbers at any frame rate.
+pub(crate) struct SimClockState {
+    seed: u32,
+    tick: u64,
+    elapsed: f64,
+    rng: SeededRng,
+}
+
+impl SimClockState {
+    pub(crate) fn new(seed: u32) -> Self {
+        Self {
+            seed,
+            tick: 0,
+            elapsed: 0.0,
+            rng: tick_rng(seed, 0),
+        }
+    }
+}
+
+/// Each tick's stream depends only on seed + tick, so one extra draw on a
+/// peer can't shift every later tick.
+fn tick_rng(seed: u32, tick: u64) -> SeededRng {
+    SeededRng::new(hash_combine(seed, hash_u64(tick)))
+}
+
+impl Runtime {
+    pub(crate) fn advance_sim_clock(&mut self, fixed_delta_time: f32) {
+        let clock = &mut self.sim_clock;
+        clock.tick += 1;
+        clock.elapsed += f64::from(fixed_delta_time);
+        clock.rng = tick_rng(clock.seed, clock.tick);
+    }
+
+    pub(crate) fn sim_clock_tick(&self) -> u64 {
+        self.sim_clock.tick
+    }
+
+    pub(crate) fn sim_clock_elapsed(&self) -> f64 {
+        self.sim_clock.elapsed
+    }
+
+    pub(crate) fn sim_clock_seed(&self) -> u32 {
+        self.sim_clock.seed
+    }
+
+    pub(crate) fn reset_sim_clock(&mut self, seed: u32) {
+        self.sim_clock = SimClockState::new(seed);
+    }
+
+    /// Rewind to the end of `tick` for a rollback; the next advance redraws
+    /// `tick + 1`'s stream.
+    pub(crate) fn restore_sim_clock(&mut self, tick: u64, elapsed: f64) {
+        let clock = &mut self.sim_clock;
+        clock.tick = tick;
+        clock.elapsed = elapsed;
+        clock.rng = tick_rng(clock.seed, tick);
+    }
+
+    pub(crate) fn next_sim_random(&mut self) -> u32 {
+        self.sim_clock.rng.next_u32()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_ticks(runtime: &mut Runtime, ticks: usize, draws_per_tick: usize) -> Vec<u32> {
+        let mut out = Vec::new();
+        for _ in 0..ticks {
+            runtime.advance_sim_clock(1.0 / 60.0);
+            out.push(runtime.next_sim_random());
+            for _ in 1..draws_per_tick {
+                runtime.next_sim_random();
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn same_seed_replays_the_same_ticks_and_draws() {
+        let mut a = Runtime::new();
+        let mut b = Runtime::new();
+        a.reset_sim_clock(1234);
+        b.reset_sim_clock(1234);
+
+        assert_eq!(run_ticks(&mut a, 8, 1), run_ticks(&mut b, 8, 1));
+        assert_eq!(a.sim_clock_tick(), 8);
+        assert_eq!(a.sim_clock_elapsed(), b.sim_clock_elapsed());
+
+        b.reset_sim_clock(99);
+        assert_eq!(b.sim_clock_tick(), 0);
+        assert_eq!(b.sim_clock_elapsed(), 0.0);
+        assert_eq!(b.sim_clock_seed(), 99);
+    }
+
+    #[test]
+    fn extra_draws_do_not_shift_later_ticks() {
+        let mut a = Runtime::new();
+        let mut b = Runtime::new();
+        a.reset_sim_clock(7);
+        b.reset_sim_clock(7);
+
+        assert_eq!(run_ticks(&mut a, 4, 1), run_ticks(&mut b, 4, 5));
+    }
+}
@@ -0,0 +1,231 @@
+use super::Runtime;
+use ahash::AHashMap;
+use perro_ids::NodeID;
+use perro_structs::Vector2;
+
+pub(crate) const DEFAULT_SPACE2D_CELL_SIZE: f32 = 64.0;
+
+type CellKey = (i32, i32);
+
+#[derive(Clone, Copy, Debug)]
+struct Space2DEntry {
+    pos: Vector2,
+    cell: CellKey,
+}
+
+/// Uniform grid over global 2D node positions backing `ctx.Space2D()`.
+///
+/// Built on first query and kept in sync after that: transform marks feed
+/// `moved_roots`, and the next query re-buckets only those subtrees. Node
+/// insert/remove/reparent bumps the arena structural revision, which forces a
+/// full rebuild on the next query.
+pub(crate) struct Space2DIndex {
+    cell_size: f32,
+    cells: AHashMap<CellKey, Vec<NodeID>>,
+    entries: AHashMap<NodeID, Space2DEntry>,
+    /// arena structural_revision at last build. `None` until 1st query, so
+    /// games that never query pay nothing on transform writes.
+    built_revision: Option<u64>,
+    moved_roots: Vec<NodeID>,
+    ids_scratch: Vec<NodeID>,
+}
+
+impl Space2DIndex {
+    pub(crate) fn new() -> Self {
+        Self {
+            cell_size: DEFAULT_SPACE2D_CELL_SIZE,
+            cells: AHashMap::new(),
+            entries: AHashMap::new(),
+            built_revision: None,
+            moved_roots: Vec::new(),
+            ids_scratch: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Ignores non-finite and non-positive sizes.
+    pub(crate) fn set_cell_size(&mut self, size: f32) {
+        if !size.is_finite() || size <= 0.0 || size == self.cell_size {
+            return;
+        }
+        self.cell_size = size;
+        self.invalidate();
+    }
+
+    pub(crate) fn invalidate(&mut self) {
+        self.cells.clear();
+        self.entries.clear();
+        self.moved_roots.clear();
+        self.built_revision = None;
+    }
+
+    /// Record a transform write under `root`. No-op until the index is built.
+    #[inline]
+    pub(crate) fn note_moved(&mut self, root: NodeID) {
+        if self.built_revision.is_some() {
+            self.moved_roots.push(root);
+        }
+    }
+
+    #[inline]
+    fn cell_of(&self, pos: Vector2) -> CellKey {
+        (
+            (pos.x / self.cell_size).floor() as i32,
+            (pos.y / self.cell_size).floor() as i32,
+        )
+    }
+
+    pub(crate) fn set(&mut self, id: NodeID, pos: Vector2) {
+        let cell = self.cell_of(pos);
+        if let Some(entry) = self.entries.get_mut(&id) {
+            let old_cell = entry.cell;
+            *entry = Space2DEntry { pos, cell };
+            if old_cell == cell {
+                return;
+            }
+            Self::remove_from_cell(&mut self.cells, old_cell, id);
+        } else {
+            self.entries.insert(id, Space2DEntry { pos, cell });
+        }
+        self.cells.entry(cell).or_default().push(id);
+    }
+
+    pub(crate) fn remove(&mut self, id: NodeID) {
+        if let Some(entry) = self.entries.remove(&id) {
+            Self::remove_from_cell(&mut self.cells, entry.cell, id);
+        }
+    }
+
+    fn remove_from_cell(cells: &mut AHashMap<CellKey, Vec<NodeID>>, cell: CellKey, id: NodeID) {
+        let Some(ids) = cells.get_mut(&cell) else {
+            return;
+        };
+        if let Some(slot) = ids.iter().position(|&other| other == id) {
+            ids.swap_remove(slot);
+        }
+        if ids.is_empty() {
+            cells.remove(&cell);
+        }
+    }
+
+    pub(crate) fn query_rect(&self, min: Vector2, max: Vector2, out: &mut Vec<NodeID>) {
+        let (min, max) = (
+            Vector2::new(min.x.min(max.x), min.y.min(max.y)),
+            Vector2::new(min.x.max(max.x), min.y.max(max.y)),
+        );
+        self.collect(min, max, out, |pos| {
+            pos.x >= min.x && pos.x <= max.x && pos.y >= min.y && pos.y <= max.y
+        });
+    }
+
+    pub(crate) fn query_radius(&self, point: Vector2, radius: f32, out: &mut Vec<NodeID>) {
+        if radius.is_nan() || radius < 0.0 {
+            return;
+        }
+        let reach = Vector2::new(radius, radius);
+        let radius_sq = radius * radius;
+        self.collect(point - reach, point + reach, out, |pos| {
+            (pos - point).length_squared() <= radius_sq
+        });
+    }
+
+    fn collect(
+        &self,
+        min: Vector2,
+        max: Vector2,
+        out: &mut Vec<NodeID>,
+        accept: impl Fn(Vector2) -> bool,
+    ) {
+        let (min_x, min_y) = self.cell_of(min);
+        let (max_x, max_y) = self.cell_of(max);
+        let span = (max_x as i64 - min_x as i64 + 1) * (max_y as i64 - min_y as i64 + 1);
+        // huge boxes cover more cells than there are entries; scan entries
+        if span as usize > self.entries.len() {
+            for (&id, entry) in &self.entries {
+                if accept(entry.pos) {
+                    out.push(id);
+                }
+            }
+            return;
+        }
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                let Some(ids) = self.cells.get(&(x, y)) else {
+                    continue;
+                };
+                for &id in ids {
+                    if self.entries.get(&id).is_some_and(|entry| accept(entry.pos)) {
+                        out.push(id);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Runtime {
+    /// Bring the 2D grid up to date before a query.
+    pub(crate) fn sync_space2d_index(&mut self) {
+        let revision = self.nodes.structural_revision();
+        if self.space2d.built_revision != Some(revision) {
+            self.rebuild_space2d_index(revision);
+            return;
+        }
+        if self.space2d.moved_roots.is_empty() {
+            return;
+        }
+
+        let mut roots = std::mem::take(&mut self.space2d.moved_roots);
+        let mut stack = std::mem::take(&mut self.space2d.ids_scratch);
+        roots.sort_unstable();
+        roots.dedup();
+        stack.clear();
+        stack.extend_from_slice(&roots);
+        while let Some(id) = stack.pop() {
+            let Some(node) = self.nodes.get(id) else {
+                self.space2d.remove(id);
+                continue;
+            };
+            let is_2d = node.is_2d();
+            if let Some(children) = self.nodes.children(id) {
+                stack.extend_from_slice(children);
+            }
+            let global = if is_2d {
+                self.get_global_transform_2d(id)
+            } else {
+                None
+            };
+            match global {
+                Some(global) => self.space2d.set(id, global.position),
+                None => self.space2d.remove(id),
+            }
+        }
+        roots.clear();
+        self.space2d.moved_roots = roots;
+        self.space2d.ids_scratch = stack;
+    }
+
+    fn rebuild_space2d_index(&mut self, revision: u64) {
+        let mut ids = std::mem::take(&mut self.space2d.ids_scratch);
+        self.space2d.invalidate();
+        ids.clear();
+        ids.extend(
+            self.nodes
+                .iter()
+                .filter(|(_, node)| node.is_2d())
+                .map(|(id, _)| id),
+        );
+        for &id in &ids {
+            if let Some(global) = self.get_global_transform_2d(id) {
+                self.space2d.set(id, global.position);
+            }
+        }
+        ids.clear();
+        self.space2d.ids_scratch = ids;
+        self.space2d.built_revision = Some(revision);
+    }
+}
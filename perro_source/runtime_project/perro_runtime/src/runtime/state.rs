@@ -4,9 +4,10 @@ use crate::{
     runtime::{RuntimeScriptApi, RuntimeScriptBehavior, RuntimeScriptCtor},
 };
 use ahash::{AHashMap, AHashSet};
-use perro_ids::{NodeID, SignalID};
-use perro_input_api::InputSnapshot;
+use perro_ids::{NodeID, SignalID, TagID};
+use perro_input_api::{InputSnapshot, TimedInputEvent};
 use perro_nodes::Spatial;
+use perro_runtime_api::sub_apis::WorldSnapshot;
 use perro_scripting::{DynamicScriptConstructor, ScriptConstructor};
 use perro_structs::{Transform2D, Transform3D};
 use std::{path::PathBuf, sync::Arc};
@@ -93,6 +94,11 @@ pub(crate) struct TransformRuntimeState {
     pub(crate) physics_pose_id_flags_2d: Vec<u8>,
     pub(crate) physics_pose_id_flags_3d: Vec<u8>,
     pub(crate) render_alpha: f32,
+    /// non-body nodes w/ `interpolate` set, in slot order. rescanned when
+    /// `interp_node_ids_revision` != arena mutation_revision.
+    pub(crate) interp_node_ids_2d: Vec<NodeID>,
+    pub(crate) interp_node_ids_3d: Vec<NodeID>,
+    pub(crate) interp_node_ids_revision: Option<u64>,
     /// per-pass memo of render-transform interp-ancestor walks: a node index
     /// whose stamp == `interp_clean_current` proved to have NO interpolating
     /// physics ancestor (inclusive), so its render transform takes the plain
@@ -127,6 +133,9 @@ impl TransformRuntimeState {
             physics_pose_id_flags_2d: Vec::new(),
             physics_pose_id_flags_3d: Vec::new(),
             render_alpha: 1.0,
+            interp_node_ids_2d: Vec::new(),
+            interp_node_ids_3d: Vec::new(),
+            interp_node_ids_revision: None,
             interp_clean_stamp_2d: Vec::new(),
             interp_clean_stamp_3d: Vec::new(),
             interp_clean_current: 1,
@@ -189,6 +198,7 @@ pub(crate) struct InternalUpdateState {
     pub(crate) physics_body_nodes_3d: Vec<NodeID>,
     pub(crate) physics_joint_nodes_2d: Vec<NodeID>,
     pub(crate) physics_joint_nodes_3d: Vec<NodeID>,
+    pub(crate) physics_vehicle_nodes_3d: Vec<NodeID>,
     pub(crate) physics_body_pos_2d: Vec<u32>,
     pub(crate) physics_body_pos_3d: Vec<u32>,
     pub(crate) button_nodes_2d: Vec<NodeID>,
@@ -209,6 +219,7 @@ impl InternalUpdateState {
             physics_body_nodes_3d: Vec::new(),
             physics_joint_nodes_2d: Vec::new(),
             physics_joint_nodes_3d: Vec::new(),
+            physics_vehicle_nodes_3d: Vec::new(),
             physics_body_pos_2d: Vec::new(),
             physics_body_pos_3d: Vec::new(),
             button_nodes_2d: Vec::new(),
@@ -273,12 +284,37 @@ impl NodeApiScratchState {
     }
 }
 
-/// Scratch buffers used to snapshot script update/fixed schedules without allocating each frame.
+/// Frame-start world snapshot handed out by `ctx.World().snapshot()`.
+pub(crate) struct WorldSnapshotState {
+    /// `None` until captured this frame.
+    pub(crate) current: Option<WorldSnapshot>,
+    /// set by any request since last frame start; drives eager capture
+    pub(crate) requested: bool,
+    pub(crate) ids_scratch: Vec<NodeID>,
+    pub(crate) tags_scratch: Vec<TagID>,
+}
+
+impl WorldSnapshotState {
+    pub(crate) fn new() -> Self {
+        Self {
+            current: None,
+            requested: false,
+            ids_scratch: Vec::new(),
+            tags_scratch: Vec::new(),
+        }
+    }
+}
+
+/// Scratch buffers used to snapshot script update/fixed/input schedules without allocating each frame.
 pub(crate) struct ScriptSchedules {
     pub(crate) update_slots: Vec<(usize, NodeID)>,
     pub(crate) fixed_slots: Vec<(usize, NodeID)>,
+    pub(crate) input_slots: Vec<(usize, NodeID)>,
+    /// drained input event log, reused across frames
+    pub(crate) input_events: Vec<TimedInputEvent>,
     update_epoch: u64,
     fixed_epoch: u64,
+    input_epoch: u64,
 }
 
 impl ScriptSchedules {
@@ -287,8 +323,11 @@ impl ScriptSchedules {
         Self {
             update_slots: Vec::new(),
             fixed_slots: Vec::new(),
+            input_slots: Vec::new(),
+            input_events: Vec::new(),
             update_epoch: u64::MAX,
             fixed_epoch: u64::MAX,
+            input_epoch: u64::MAX,
         }
     }
 
@@ -324,6 +363,17 @@ impl ScriptSchedules {
         self.fixed_epoch = epoch;
     }
 
+    pub(crate) fn snapshot_input(&mut self, scripts: &ScriptCollection) {
+        let epoch = scripts.schedule_epoch();
+        if self.input_epoch == epoch {
+            return;
+        }
+
+        self.input_slots.clear();
+        scripts.append_input_slots(&mut self.input_slots);
+        self.input_epoch = epoch;
+    }
+
     #[inline]
     pub(crate) fn fixed_slots_empty(&self) -> bool {
         self.fixed_slots.is_empty()
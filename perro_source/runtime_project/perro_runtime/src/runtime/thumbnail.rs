@@ -0,0 +1,224 @@
+use super::Runtime;
+use perro_ids::NodeID;
+use perro_nodes::{Camera2D, Camera3D, CameraProjection, SceneNode, SceneNodeData};
+use perro_structs::{Transform3D, Vector2, Vector3};
+
+pub const THUMBNAIL_CAMERA_NAME: &str = "__perro_thumbnail_camera";
+
+/// Empty border around framed content, as a fraction of its extent.
+const FRAME_MARGIN: f32 = 1.15;
+/// Smallest framed extent; keeps single-node scenes from zooming to infinity.
+const MIN_FRAME_EXTENT: f32 = 1.0;
+/// 3/4 view direction from scene center to the 3D thumbnail camera.
+const CAMERA_3D_DIRECTION: Vector3 = Vector3::new(1.0, 0.8, 1.0);
+
+impl Runtime {
+    /// Add an active camera that frames every spatial node in the scene.
+    ///
+    /// Used by thumbnail capture: other cameras are deactivated so the next
+    /// rendered frame shows the whole scene. 3D content wins when a scene has
+    /// both. Returns `None` for scenes without 2D or 3D nodes.
+    pub fn frame_scene_for_thumbnail(&mut self) -> Option<NodeID> {
+        let mut ids_2d = Vec::new();
+        let mut ids_3d = Vec::new();
+        for (id, node) in self.nodes.iter() {
+            if matches!(
+                node.data,
+                SceneNodeData::Camera2D(_) | SceneNodeData::Camera3D(_)
+            ) {
+                continue;
+            }
+            if node.is_3d() {
+                ids_3d.push(id);
+            } else if node.is_2d() {
+                ids_2d.push(id);
+            }
+        }
+
+        if !ids_3d.is_empty() {
+            let points: Vec<Vector3> = ids_3d
+                .into_iter()
+                .filter_map(|id| self.get_global_transform_3d(id))
+                .map(|global| global.position)
+                .collect();
+            let camera = fit_camera_3d(&points)?;
+            return Some(self.insert_thumbnail_camera(SceneNodeData::Camera3D(camera)));
+        }
+
+        let points: Vec<Vector2> = ids_2d
+            .into_iter()
+            .filter_map(|id| self.get_global_transform_2d(id))
+            .map(|global| global.position)
+            .collect();
+        let virtual_size = self
+            .project()
+            .map(|project| {
+                Vector2::new(
+                    project.config.virtual_width.max(1) as f32,
+                    project.config.virtual_height.max(1) as f32,
+                )
+            })
+            .unwrap_or(Vector2::new(1920.0, 1080.0));
+        let camera = fit_camera_2d(&points, virtual_size)?;
+        Some(self.insert_thumbnail_camera(SceneNodeData::Camera2D(camera)))
+    }
+
+    fn insert_thumbnail_camera(&mut self, data: SceneNodeData) -> NodeID {
+        let mut others = Vec::new();
+        for (id, node) in self.nodes.iter() {
+            let active = match &node.data {
+                SceneNodeData::Camera2D(camera) => camera.active,
+                SceneNodeData::Camera3D(camera) => camera.active,
+                _ => false,
+            };
+            if active {
+                others.push(id);
+            }
+        }
+        for id in others {
+            if let Some(mut node) = self.nodes.get_mut(id) {
+                match &mut node.data {
+                    SceneNodeData::Camera2D(camera) => camera.active = false,
+                    SceneNodeData::Camera3D(camera) => camera.active = false,
+                    _ => {}
+                }
+            }
+            self.mark_needs_rerender(id);
+        }
+
+        let is_3d = matches!(data, SceneNodeData::Camera3D(_));
+        let mut node = SceneNode::new(data);
+        node.set_name(THUMBNAIL_CAMERA_NAME);
+        let id = self.nodes.insert(node);
+        if is_3d {
+            self.note_camera_3d_activated(id);
+        }
+        self.mark_needs_rerender(id);
+        self.mark_transform_dirty_recursive(id);
+        id
+    }
+}
+
+/// Center on the bounds of `points` and zoom so they fill the virtual view.
+fn fit_camera_2d(points: &[Vector2], virtual_size: Vector2) -> Option<Camera2D> {
+    let (&first, rest) = points.split_first()?;
+    let (mut min, mut max) = (first, first);
+    for &point in rest {
+        min = Vector2::new(min.x.min(point.x), min.y.min(point.y));
+        max = Vector2::new(max.x.max(point.x), max.y.max(point.y));
+    }
+    let extent_x = (max.x - min.x).max(MIN_FRAME_EXTENT) * FRAME_MARGIN;
+    let extent_y = (max.y - min.y).max(MIN_FRAME_EXTENT) * FRAME_MARGIN;
+    // view covers virtual_size / zoom world units
+    let zoom = (virtual_size.x / extent_x).min(virtual_size.y / extent_y);
+
+    let mut camera = Camera2D::default();
+    camera.transform.position = (min + max) * 0.5;
+    camera.zoom = zoom;
+    camera.active = true;
+    Some(camera)
+}
+
+/// Back a perspective camera off along a 3/4 view until the bounding sphere
+/// of `points` fits the vertical field of view.
+fn fit_camera_3d(points: &[Vector3]) -> Option<Camera3D> {
+    let (&first, rest) = points.split_first()?;
+    let (mut min, mut max) = (first, first);
+    for &point in rest {
+        min = Vector3::new(min.x.min(point.x), min.y.min(point.y), min.z.min(point.z));
+        max = Vector3::new(max.x.max(point.x), max.y.max(point.y), max.z.max(point.z));
+    }
+    let center = (min + max) * 0.5;
+    let radius = points
+        .iter()
+        .map(|&point| (point - center).length())
+        .fold(MIN_FRAME_EXTENT * 0.5, f32::max);
+
+    let mut camera = Camera3D::default();
+    let fov_y_degrees = match camera.projection {
+        CameraProjection::Perspective { fov_y_degrees, .. } => fov_y_degrees,
+        _ => 60.0,
+    };
+    let half_fov = (fov_y_degrees.to_radians() * 0.5).max(0.01);
+    let distance = radius * FRAME_MARGIN / half_fov.sin();
+    let position = center + CAMERA_3D_DIRECTION.normalized() * distance;
+    camera.transform = Transform3D::looking_at(position, center, Vector3::new(0.0, 1.0, 0.0));
+    camera.active = true;
+    Some(camera)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perro_nodes::{Node2D, Node3D};
+
+    #[test]
+    fn fit_camera_2d_centers_and_fills_virtual_view() {
+        let camera = fit_camera_2d(
+            &[Vector2::new(0.0, 0.0), Vector2::new(200.0, 50.0)],
+            Vector2::new(400.0, 400.0),
+        )
+        .expect("camera");
+        assert_eq!(camera.transform.position, Vector2::new(100.0, 25.0));
+        // width is the limiting axis: 400 / (200 * margin)
+        assert!((camera.zoom - 400.0 / (200.0 * FRAME_MARGIN)).abs() < 1e-4);
+        assert!(camera.active);
+        assert!(fit_camera_2d(&[], Vector2::new(1.0, 1.0)).is_none());
+    }
+
+    #[test]
+    fn fit_camera_3d_looks_at_center_from_outside_bounds() {
+        let camera = fit_camera_3d(&[Vector3::new(-2.0, 0.0, 0.0), Vector3::new(2.0, 0.0, 0.0)])
+            .expect("camera");
+        let position = camera.transform.position;
+        assert!(position.length() > 2.0);
+        let forward = camera.transform.forward();
+        let to_center = (Vector3::ZERO - position).normalized();
+        assert!((forward - to_center).length() < 1e-3);
+    }
+
+    #[test]
+    fn frame_scene_for_thumbnail_replaces_active_camera() {
+        let mut runtime = Runtime::new();
+        let scene_camera = Camera2D {
+            active: true,
+            ..Default::default()
+        };
+        let scene_camera = runtime
+            .nodes
+            .insert(SceneNode::new(SceneNodeData::Camera2D(scene_camera)));
+        let mut node = Node2D::new();
+        node.transform.position = Vector2::new(30.0, 0.0);
+        let _ = runtime
+            .nodes
+            .insert(SceneNode::new(SceneNodeData::Node2D(node)));
+
+        let thumb = runtime.frame_scene_for_thumbnail().expect("camera");
+        let active: Vec<NodeID> = runtime
+            .nodes
+            .iter()
+            .filter(|(_, node)| matches!(&node.data, SceneNodeData::Camera2D(c) if c.active))
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(active, vec![thumb]);
+        assert_ne!(thumb, scene_camera);
+    }
+
+    #[test]
+    fn frame_scene_for_thumbnail_prefers_3d_content() {
+        let mut runtime = Runtime::new();
+        let _ = runtime
+            .nodes
+            .insert(SceneNode::new(SceneNodeData::Node2D(Node2D::new())));
+        let _ = runtime
+            .nodes
+            .insert(SceneNode::new(SceneNodeData::Node3D(Node3D::new())));
+
+        let thumb = runtime.frame_scene_for_thumbnail().expect("camera");
+        let node = runtime.nodes.get(thumb).expect("thumbnail camera");
+        assert!(matches!(node.data, SceneNodeData::Camera3D(_)));
+
+        let mut empty = Runtime::new();
+        assert!(empty.frame_scene_for_thumbnail().is_none());
+    }
+}
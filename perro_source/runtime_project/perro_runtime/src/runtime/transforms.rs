@@ -497,6 +497,106 @@ impl Runtime {
         self.mark_needs_rerender(id);
     }
 
+    /// Record this fixed step's global transform for every node with
+    /// `interpolate` set, so render can blend prev -> curr by `render_alpha`.
+    /// Rigid bodies record their own poses during physics writeback.
+    pub(crate) fn record_interpolated_node_poses(&mut self) {
+        self.refresh_interp_node_ids();
+        let ids = std::mem::take(&mut self.transforms.interp_node_ids_2d);
+        for id in ids.iter().copied() {
+            let Some(parent) = self.nodes.get(id).map(|node| node.parent) else {
+                continue;
+            };
+            let Some(curr) = self.get_global_transform_2d(id) else {
+                continue;
+            };
+            // scripted motion has no pre-step pose to spot teleports with;
+            // only first record / reparent snaps.
+            let before = self
+                .transforms
+                .physics_pose_2d
+                .get(id.index() as usize)
+                .map_or(curr, |pose| pose.curr);
+            self.record_physics_pose_2d(id, parent, before, curr);
+        }
+        self.transforms.interp_node_ids_2d = ids;
+
+        let ids = std::mem::take(&mut self.transforms.interp_node_ids_3d);
+        for id in ids.iter().copied() {
+            let Some(parent) = self.nodes.get(id).map(|node| node.parent) else {
+                continue;
+            };
+            let Some(curr) = self.get_global_transform_3d(id) else {
+                continue;
+            };
+            let before = self
+                .transforms
+                .physics_pose_3d
+                .get(id.index() as usize)
+                .map_or(curr, |pose| pose.curr);
+            self.record_physics_pose_3d(id, parent, before, curr);
+        }
+        self.transforms.interp_node_ids_3d = ids;
+    }
+
+    /// Rescan `interpolate` flags when node data may have chg. Nodes that
+    /// dropped the flag lose their pose so render goes back to the live
+    /// transform.
+    fn refresh_interp_node_ids(&mut self) {
+        let revision = self.nodes.mutation_revision();
+        if self.transforms.interp_node_ids_revision == Some(revision) {
+            return;
+        }
+        self.transforms.interp_node_ids_revision = Some(revision);
+
+        let mut ids_2d = Vec::new();
+        let mut ids_3d = Vec::new();
+        for (id, node) in self.nodes.iter() {
+            if matches!(
+                node.data,
+                SceneNodeData::RigidBody2D(_)
+                    | SceneNodeData::RigidBody3D(_)
+                    | SceneNodeData::VehicleBody3D(_)
+            ) {
+                continue;
+            }
+            if node
+                .with_base_ref::<Node2D, _>(|base| base.interpolate)
+                .unwrap_or(false)
+            {
+                ids_2d.push(id);
+            } else if node
+                .with_base_ref::<Node3D, _>(|base| base.interpolate)
+                .unwrap_or(false)
+            {
+                ids_3d.push(id);
+            }
+        }
+        ids_2d.sort_unstable();
+        ids_3d.sort_unstable();
+
+        for id in std::mem::take(&mut self.transforms.interp_node_ids_2d) {
+            if ids_2d.binary_search(&id).is_err()
+                && let Some(pose) = self.transforms.physics_pose_2d.get_mut(id.index() as usize)
+                && pose.generation == id.generation()
+            {
+                pose.valid = false;
+                self.mark_needs_rerender(id);
+            }
+        }
+        for id in std::mem::take(&mut self.transforms.interp_node_ids_3d) {
+            if ids_3d.binary_search(&id).is_err()
+                && let Some(pose) = self.transforms.physics_pose_3d.get_mut(id.index() as usize)
+                && pose.generation == id.generation()
+            {
+                pose.valid = false;
+                self.mark_needs_rerender(id);
+            }
+        }
+        self.transforms.interp_node_ids_2d = ids_2d;
+        self.transforms.interp_node_ids_3d = ids_3d;
+    }
+
     /// Invalidate the interp-ancestor memo. Call on any pose-set / render-alpha
     /// change; structural changes are caught lazily in `refresh_interp_clean`.
     #[inline]
@@ -620,12 +720,11 @@ impl Runtime {
                 break;
             };
             chain.push(cursor);
-            if matches!(node.data, SceneNodeData::RigidBody2D(_))
-                && self
-                    .transforms
-                    .physics_pose_2d
-                    .get(cursor.index() as usize)
-                    .is_some_and(|pose| pose.valid && pose.generation == cursor.generation())
+            if self
+                .transforms
+                .physics_pose_2d
+                .get(cursor.index() as usize)
+                .is_some_and(|pose| pose.valid && pose.generation == cursor.generation())
             {
                 has_interp = true;
             }
@@ -649,19 +748,13 @@ impl Runtime {
         let mut parent_world = Mat3::IDENTITY;
         let mut result = None;
         for chain_id in chain.iter().rev().copied() {
-            let Some((local, parent, is_rigid)) = self.nodes.get(chain_id).and_then(|node| {
-                node.with_base_ref::<Node2D, _>(|base| {
-                    (
-                        base.transform,
-                        node.parent,
-                        matches!(node.data, SceneNodeData::RigidBody2D(_)),
-                    )
-                })
+            let Some((local, parent)) = self.nodes.get(chain_id).and_then(|node| {
+                node.with_base_ref::<Node2D, _>(|base| (base.transform, node.parent))
             }) else {
                 continue;
             };
-            let global = if is_rigid {
-                self.interpolated_physics_pose_2d(chain_id).unwrap_or(local)
+            let global = if let Some(pose) = self.interpolated_physics_pose_2d(chain_id) {
+                pose
             } else {
                 let parent_is_2d = !parent.is_nil()
                     && self
@@ -725,12 +818,11 @@ impl Runtime {
                 break;
             };
             chain.push(cursor);
-            if matches!(node.data, SceneNodeData::RigidBody3D(_))
-                && self
-                    .transforms
-                    .physics_pose_3d
-                    .get(cursor.index() as usize)
-                    .is_some_and(|pose| pose.valid && pose.generation == cursor.generation())
+            if self
+                .transforms
+                .physics_pose_3d
+                .get(cursor.index() as usize)
+                .is_some_and(|pose| pose.valid && pose.generation == cursor.generation())
             {
                 has_interp = true;
             }
@@ -754,19 +846,13 @@ impl Runtime {
         let mut parent_world = Mat4::IDENTITY;
         let mut result = None;
         for chain_id in chain.iter().rev().copied() {
-            let Some((local, parent, is_rigid)) = self.nodes.get(chain_id).and_then(|node| {
-                node.with_base_ref::<Node3D, _>(|base| {
-                    (
-                        base.transform,
-                        node.parent,
-                        matches!(node.data, SceneNodeData::RigidBody3D(_)),
-                    )
-                })
+            let Some((local, parent)) = self.nodes.get(chain_id).and_then(|node| {
+                node.with_base_ref::<Node3D, _>(|base| (base.transform, node.parent))
             }) else {
                 continue;
             };
-            let global = if is_rigid {
-                self.interpolated_physics_pose_3d(chain_id).unwrap_or(local)
+            let global = if let Some(pose) = self.interpolated_physics_pose_3d(chain_id) {
+                pose
             } else {
                 let parent_is_3d = !parent.is_nil()
                     && self
@@ -832,12 +918,11 @@ impl Runtime {
                 if let Some(node_children) = self.nodes.children(id) {
                     children.extend_from_slice(node_children);
                 }
-                if children.is_empty() {
-                    self.mark_needs_rerender(id);
-                } else {
-                    for child in children.iter().copied() {
-                        self.mark_needs_rerender(child);
-                    }
+                // interpolated sprites/cameras draw themselves; bodies draw via
+                // their children.
+                self.mark_needs_rerender(id);
+                for child in children.iter().copied() {
+                    self.mark_needs_rerender(child);
                 }
                 ids[write] = id;
                 write += 1;
@@ -871,12 +956,11 @@ impl Runtime {
                 if let Some(node_children) = self.nodes.children(id) {
                     children.extend_from_slice(node_children);
                 }
-                if children.is_empty() {
-                    self.mark_needs_rerender(id);
-                } else {
-                    for child in children.iter().copied() {
-                        self.mark_needs_rerender(child);
-                    }
+                // interpolated sprites/cameras draw themselves; bodies draw via
+                // their children.
+                self.mark_needs_rerender(id);
+                for child in children.iter().copied() {
+                    self.mark_needs_rerender(child);
                 }
                 ids[write] = id;
                 write += 1;
@@ -41,6 +41,7 @@ impl Runtime {
             SceneNodeData::PinJoint2D(node) => node.visible,
             SceneNodeData::DistanceJoint2D(node) => node.visible,
             SceneNodeData::FixedJoint2D(node) => node.visible,
+            SceneNodeData::SpringJoint2D(node) => node.visible,
             SceneNodeData::AudioMask2D(node) => node.visible,
             SceneNodeData::AudioEffectZone2D(node) => node.visible,
             SceneNodeData::AudioPortal2D(node) => node.visible,
@@ -56,6 +57,9 @@ impl Runtime {
             SceneNodeData::BallJoint3D(node) => node.visible,
             SceneNodeData::HingeJoint3D(node) => node.visible,
             SceneNodeData::FixedJoint3D(node) => node.visible,
+            SceneNodeData::SpringJoint3D(node) => node.visible,
+            SceneNodeData::VehicleBody3D(node) => node.visible,
+            SceneNodeData::VehicleWheel3D(node) => node.visible,
             SceneNodeData::Camera3D(node) => node.visible,
             SceneNodeData::CameraStream3D(node) => node.visible,
             SceneNodeData::SubView3D(node) => node.visible,
@@ -103,6 +107,7 @@ impl Runtime {
             SceneNodeData::UiVLayout(node) => node.visible,
             SceneNodeData::UiGrid(node) => node.visible,
             SceneNodeData::UiTreeList(node) => node.visible,
+            SceneNodeData::UiMarkdown(node) => node.visible,
             SceneNodeData::AnimationPlayer(_) => true,
             SceneNodeData::AnimationTree(_) => true,
             SceneNodeData::Webcam(node) => node.enabled,
@@ -120,7 +125,8 @@ impl Runtime {
             let Some(scene_node) = self.nodes.get(current) else {
                 return false;
             };
-            if !Self::node_local_visible(&scene_node.data) {
+            if !Self::node_local_visible(&scene_node.data) || self.disabled_nodes.contains(&current)
+            {
                 return false;
             }
             if scene_node.parent.is_nil() {
@@ -156,6 +162,14 @@ impl Runtime {
         self.sub_view_ancestor(node).is_some()
     }
 
+    /// Skip scripts, internal updates, and physics for `node`: it sits in a
+    /// hidden suspending sub view or a paused scene.
+    pub(crate) fn is_suspended(&self, node: NodeID) -> bool {
+        self.is_suspended_by_sub_view(node)
+            || self.is_in_paused_scene(node)
+            || self.is_in_disabled_subtree(node)
+    }
+
     pub(crate) fn is_suspended_by_sub_view(&self, node: NodeID) -> bool {
         let Some(viewport_id) = self.sub_view_ancestor(node) else {
             return false;
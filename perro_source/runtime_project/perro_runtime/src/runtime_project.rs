@@ -7,14 +7,19 @@ use perro_ui::UiStyle;
 use std::{collections::BTreeMap, path::PathBuf};
 
 pub use perro_project::{
-    AudioConfig, AudioPropagationConfig, FrameRateCap, LocalizationConfig, OcclusionCulling,
-    ParticleSimDefault, ProjectConfig as RuntimeProjectConfig, ProjectError as ProjectLoadError,
-    ProjectMetadata, ProjectRoute, ProjectRoutesConfig, RenderUiConfig, RenderingConfig,
-    SsaoQuality, StaticProjectConfig, SteamInputMode, default_input_map_toml, default_project_toml,
+    AudioConfig, AudioPropagationConfig, DEFAULT_MAX_FIXED_STEPS_PER_FRAME, FrameRateCap,
+    LayerNamesConfig, LocalizationConfig, OcclusionCulling, ParticleSimDefault,
+    ProjectConfig as RuntimeProjectConfig, ProjectError as ProjectLoadError, ProjectMetadata,
+    ProjectRoute, ProjectRoutesConfig, RenderUiConfig, RenderingConfig, SsaoQuality,
+    StaticProjectConfig, SteamInputMode, default_input_map_toml, default_project_toml,
     default_routes_config, ensure_project_layout, ensure_project_toml, load_input_map_toml,
     load_project_toml, load_routes_toml, normalize_route_href, parse_input_map_toml,
     parse_project_toml, parse_routes_toml,
 };
+pub use perro_structs::{BuildInfo, BuildProfile};
+
+/// Env form of `--scene`, for launchers that cannot pass process args.
+pub const SCENE_OVERRIDE_ENV: &str = "PERRO_SCENE";
 
 /// Script/provider loading mode used when constructing the runtime.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -63,6 +68,7 @@ pub struct RuntimeProject {
     pub static_icon_lookup: Option<StaticBytesLookup>,
     pub static_resource_lookups: perro_io::asset_io::StaticResourceLookups,
     pub perro_assets_bytes: Option<&'static [u8]>,
+    pub build_info: BuildInfo,
 }
 
 impl RuntimeProject {
@@ -92,6 +98,7 @@ impl RuntimeProject {
             static_icon_lookup: None,
             static_resource_lookups: perro_io::asset_io::StaticResourceLookups::default(),
             perro_assets_bytes: None,
+            build_info: BuildInfo::default(),
         }
     }
 
@@ -121,6 +128,7 @@ impl RuntimeProject {
             static_icon_lookup: None,
             static_resource_lookups: perro_io::asset_io::StaticResourceLookups::default(),
             perro_assets_bytes: None,
+            build_info: BuildInfo::default(),
         }
     }
 
@@ -137,6 +145,7 @@ impl RuntimeProject {
         let config = perro_project::load_project_toml_with_demo(&root, demo)?;
         let routes = perro_project::load_routes_toml(&root, &config)?;
         let _ = default_name;
+        let build_info = BuildInfo::dev(config.metadata.version.as_deref());
         Ok(Self {
             name: config.name.clone(),
             root,
@@ -160,6 +169,7 @@ impl RuntimeProject {
             static_icon_lookup: None,
             static_resource_lookups: perro_io::asset_io::StaticResourceLookups::default(),
             perro_assets_bytes: None,
+            build_info,
         })
     }
 
@@ -173,6 +183,32 @@ impl RuntimeProject {
         self
     }
 
+    /// Version stamp reported by `OS().build_info()`.
+    pub fn with_build_info(mut self, build_info: BuildInfo) -> Self {
+        self.build_info = build_info;
+        self
+    }
+
+    /// Boots `scene` instead of `main_scene` for this run. The root route
+    /// follows so web builds land on it too.
+    pub fn with_main_scene_override(mut self, scene: impl Into<String>) -> Self {
+        self.config.main_scene = scene.into();
+        self.config.main_scene_hash = None;
+        self.routes = perro_project::default_routes_config(&self.config);
+        self
+    }
+
+    /// Applies [`scene_override_from_env`] when one was given.
+    pub fn with_scene_override_from_env(self) -> Result<Self, ProjectLoadError> {
+        Ok(match scene_override_from_env()? {
+            Some(scene) => {
+                eprintln!("[perro] boot scene override: {scene}");
+                self.with_main_scene_override(scene)
+            }
+            None => self,
+        })
+    }
+
     pub fn with_input_map(mut self, input_map: perro_input_api::InputMap) -> Self {
         self.config.input_map = input_map;
         self
@@ -279,3 +315,35 @@ impl RuntimeProject {
         self
     }
 }
+
+/// Boot scene override for this run: `--scene <res://...>` from the process
+/// args, else [`SCENE_OVERRIDE_ENV`].
+pub fn scene_override_from_env() -> Result<Option<String>, ProjectLoadError> {
+    let args: Vec<String> = std::env::args().collect();
+    parse_scene_override(&args, std::env::var(SCENE_OVERRIDE_ENV).ok())
+}
+
+pub(crate) fn parse_scene_override(
+    args: &[String],
+    env: Option<String>,
+) -> Result<Option<String>, ProjectLoadError> {
+    let raw = match args.iter().position(|arg| arg == "--scene") {
+        Some(idx) => Some(args.get(idx + 1).cloned().ok_or_else(|| {
+            ProjectLoadError::InvalidField("--scene", "expects a `res://` scene path".to_string())
+        })?),
+        None => env,
+    };
+    let Some(scene) = raw
+        .map(|raw| raw.trim().to_string())
+        .filter(|raw| !raw.is_empty())
+    else {
+        return Ok(None);
+    };
+    match scene.strip_prefix("res://") {
+        Some(rel) if !rel.is_empty() && !rel.split('/').any(|part| part == "..") => Ok(Some(scene)),
+        _ => Err(ProjectLoadError::InvalidField(
+            "--scene",
+            format!("`{scene}` must be a `res://` scene path"),
+        )),
+    }
+}
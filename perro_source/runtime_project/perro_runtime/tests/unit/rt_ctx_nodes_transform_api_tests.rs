@@ -2,14 +2,15 @@ use crate::{
     Runtime,
     runtime_project::{ProviderMode, RuntimeProject},
 };
-use perro_ids::{NodeID, ScriptMemberID, TagID, tags};
+use perro_ids::{NodeID, ScriptMemberID, TagID, WeakNodeRef, tags};
 use perro_nodes::{
     Bone3D, BoneAttachment3D, Camera2D, Camera3D, CameraProjection, Node2D, Node3D, NodeType,
     SceneNode, SceneNodeData, Skeleton3D, Sprite2D, UiButton, UiLabel, UiNode, UiPanel,
 };
 use perro_runtime_api::node_collection;
 use perro_runtime_api::sub_apis::{
-    NodeAPI, NodeQuery, NodeScriptSpec, NodeScriptVar, NodeSpec, QueryBounds, QueryExpr, QueryScope,
+    NodeAPI, NodeModule, NodeQuery, NodeScriptSpec, NodeScriptVar, NodeSpec, QueryBounds,
+    QueryExpr, QueryScope,
 };
 use perro_scene::{Scene, SceneKey, SceneNodeEntry};
 use perro_structs::{Quaternion, Transform2D, Transform3D, Vector2, Vector3};
@@ -42,8 +43,11 @@ const STATIC_SCENE_NODES: &[SceneNodeEntry] = &[
         parent: None,
         script: None,
         clear_script: false,
+        enabled: true,
         root_of: None,
         script_vars: Cow::Borrowed(EMPTY_FIELDS),
+        meta: Cow::Borrowed(EMPTY_FIELDS),
+        connections: Cow::Borrowed(&[]),
     },
     SceneNodeEntry {
         data: SCENE_CHILD_DATA,
@@ -55,8 +59,11 @@ const STATIC_SCENE_NODES: &[SceneNodeEntry] = &[
         parent: Some(SceneKey(0)),
         script: None,
         clear_script: false,
+        enabled: true,
         root_of: None,
         script_vars: Cow::Borrowed(EMPTY_FIELDS),
+        meta: Cow::Borrowed(EMPTY_FIELDS),
+        connections: Cow::Borrowed(&[]),
     },
 ];
 static STATIC_SCENE: Scene = Scene {
@@ -113,12 +120,7 @@ fn child_with_name(
     let children = runtime.get_node_children_ids(parent).unwrap_or_default();
     let names = children
         .iter()
-        .map(|&child| {
-            runtime
-                .get_node_name(child)
-                .unwrap_or_default()
-                .into_owned()
-        })
+        .map(|&child| runtime.get_node_name(child).unwrap_or_default().to_string())
         .collect::<Vec<_>>();
     children
         .into_iter()
@@ -154,6 +156,28 @@ fn create_nodes_batches_parent_names_and_tags() {
     );
 }
 
+#[test]
+fn get_nodes_in_group_returns_tagged_nodes() {
+    let mut runtime = Runtime::new();
+    let parent_id = runtime.create::<Node2D>();
+    let requests = [
+        NodeSpec::new(Node2D::new()).tags(tags!["enemies", "saveable"]),
+        NodeSpec::new(Node2D::new()).tags(tags!["saveable"]),
+        NodeSpec::new(Node2D::new()).tags(tags!["enemies"]),
+    ];
+    let ids = runtime.create_nodes(&requests, parent_id);
+
+    let mut enemies = runtime.get_nodes_in_group("enemies");
+    enemies.sort();
+    let mut expected = vec![ids[0], ids[2]];
+    expected.sort();
+    assert_eq!(enemies, expected);
+
+    assert!(runtime.remove_node(ids[2]));
+    assert_eq!(runtime.get_nodes_in_group("enemies"), vec![ids[0]]);
+    assert!(runtime.get_nodes_in_group("bosses").is_empty());
+}
+
 #[test]
 fn create_nodes_supports_root_requests_without_metadata() {
     let mut runtime = Runtime::new();
@@ -912,6 +936,72 @@ fn top_level_nodes_skip_parent_transform_2d_and_3d() {
     );
 }
 
+#[test]
+fn deep_chain_global_2d_stays_cached_until_ancestor_moves() {
+    let mut runtime = Runtime::new();
+    let mut chain = Vec::new();
+    let mut parent = NodeID::nil();
+    for _ in 0..64 {
+        let mut node = Node2D::new();
+        node.transform.position = Vector2::new(1.0, 0.0);
+        let id = runtime
+            .nodes
+            .insert(SceneNode::new(SceneNodeData::Node2D(node)));
+        if !parent.is_nil() {
+            runtime
+                .nodes
+                .get_mut(parent)
+                .expect("test or bench setup must succeed")
+                .add_child(id);
+            runtime
+                .nodes
+                .get_mut(id)
+                .expect("test or bench setup must succeed")
+                .parent = parent;
+        }
+        chain.push(id);
+        parent = id;
+    }
+    let unrelated = runtime
+        .nodes
+        .insert(SceneNode::new(SceneNodeData::Node2D(Node2D::new())));
+    let root = chain[0];
+    let leaf = chain[chain.len() - 1];
+    runtime.mark_transform_dirty_recursive(root);
+
+    let leaf_global = runtime
+        .get_global_transform_2d(leaf)
+        .expect("test or bench setup must succeed");
+    assert!(approx(leaf_global.position.x, 64.0));
+    runtime
+        .get_global_transform_2d(unrelated)
+        .expect("test or bench setup must succeed");
+    for &id in &chain {
+        assert!(runtime.cached_clean_global_2d(id).is_some());
+    }
+
+    runtime
+        .nodes
+        .get_mut(root)
+        .expect("test or bench setup must succeed")
+        .with_base_mut::<Node2D, _>(|base| base.transform.position.x = 11.0);
+    runtime.mark_transform_dirty_recursive(root);
+    runtime.propagate_pending_transform_dirty();
+
+    for &id in &chain {
+        assert!(runtime.cached_clean_global_2d(id).is_none());
+    }
+    assert!(runtime.cached_clean_global_2d(unrelated).is_some());
+
+    let leaf_global = runtime
+        .get_global_transform_2d(leaf)
+        .expect("test or bench setup must succeed");
+    assert!(approx(leaf_global.position.x, 74.0));
+    for &id in &chain {
+        assert!(runtime.cached_clean_global_2d(id).is_some());
+    }
+}
+
 #[test]
 fn bone_attachment_3d_follows_skeleton_bone_global_transform() {
     let mut runtime = Runtime::new();
@@ -1319,6 +1409,47 @@ fn remove_node_removes_entire_subtree() {
     assert!(!runtime.remove_node(root_id));
 }
 
+#[test]
+fn weak_node_ref_goes_stale_when_slot_is_reused() {
+    let mut runtime = Runtime::new();
+    let enemy = runtime.create::<Node2D>();
+    let weak = WeakNodeRef::new(enemy);
+    assert!(runtime.is_valid(enemy));
+    assert_eq!(NodeModule::new(&mut runtime).upgrade(weak), Some(enemy));
+
+    assert!(runtime.remove_node(enemy));
+    let reused = runtime.create::<Node2D>();
+    assert_eq!(reused.index(), enemy.index());
+
+    assert!(!runtime.is_valid(enemy));
+    assert!(runtime.is_valid(reused));
+    assert!(!runtime.is_valid(NodeID::nil()));
+    assert_eq!(NodeModule::new(&mut runtime).upgrade(weak), None);
+}
+
+#[test]
+fn owned_spawns_are_removed_with_their_owner() {
+    let mut runtime = Runtime::new();
+    let turret = runtime.create::<Node2D>();
+    let bullet = runtime.create::<Node2D>();
+    let trail = runtime.create::<Node2D>();
+    let survivor = runtime.create::<Node2D>();
+    assert!(runtime.set_node_owner(bullet, turret));
+    assert!(runtime.set_node_owner(trail, bullet));
+    assert!(runtime.set_node_owner(survivor, turret));
+    assert!(!runtime.set_node_owner(turret, turret));
+    assert_eq!(runtime.get_node_owner(bullet), turret);
+
+    // Released spawns outlive their old owner.
+    assert!(runtime.set_node_owner(survivor, NodeID::nil()));
+    assert!(runtime.get_node_owner(survivor).is_nil());
+
+    assert!(runtime.remove_node(turret));
+    assert!(!runtime.is_valid(bullet));
+    assert!(!runtime.is_valid(trail));
+    assert!(runtime.is_valid(survivor));
+}
+
 #[test]
 fn remove_node_unlinks_root_from_live_parent() {
     // Removing a subtree must unlink its root from a live parent outside the
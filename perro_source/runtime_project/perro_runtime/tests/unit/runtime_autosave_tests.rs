@@ -0,0 +1,107 @@
+use crate::{Runtime, RuntimeScriptApi};
+use perro_ids::{NodeID, ScriptMemberID};
+use perro_nodes::{SceneNode, SceneNodeData};
+use perro_runtime_api::sub_apis::{AUTOSAVE_GROUP, MIN_SAVE_GAP_SECS, NodeAPI, SaveAPI};
+use perro_scripting::{ScriptBehavior, ScriptContext, ScriptFlags, ScriptLifecycle};
+use perro_variant::Variant;
+use std::any::Any;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+
+/// Saves a fixed `hp` value.
+struct HpScript(i32);
+
+impl ScriptLifecycle<RuntimeScriptApi> for HpScript {}
+
+impl ScriptBehavior<RuntimeScriptApi> for HpScript {
+    fn script_flags(&self) -> ScriptFlags {
+        ScriptFlags::new(ScriptFlags::NONE)
+    }
+
+    fn get_var(&self, _state: &dyn Any, _var: ScriptMemberID) -> Variant {
+        Variant::Null
+    }
+
+    fn set_var(&self, _state: &mut dyn Any, _var: ScriptMemberID, _value: Variant) {}
+
+    fn call_method(
+        &self,
+        _method: ScriptMemberID,
+        _ctx: &mut ScriptContext<'_, RuntimeScriptApi>,
+        _params: &[Variant],
+    ) -> Variant {
+        Variant::Null
+    }
+
+    fn save_state(&self, _state: &dyn Any) -> Variant {
+        Variant::Object([(Arc::<str>::from("hp"), Variant::from(self.0))].into())
+    }
+}
+
+fn saved_node(runtime: &mut Runtime, name: &'static str, hp: i32, grouped: bool) -> NodeID {
+    let mut node = SceneNode::new(SceneNodeData::Node);
+    node.name = name.into();
+    let id = runtime.nodes.insert(node);
+    if grouped {
+        assert!(runtime.add_node_tag(id, AUTOSAVE_GROUP));
+    }
+    runtime
+        .scripts
+        .insert(id, Arc::new(HpScript(hp)), Box::new(()));
+    id
+}
+
+#[test]
+fn snapshot_keys_autosave_group_scripts_by_node_name() {
+    let mut runtime = Runtime::new();
+    saved_node(&mut runtime, "player", 75, true);
+    saved_node(&mut runtime, "enemy", 10, false);
+    // grouped but unscripted -> nothing to save
+    let bare = runtime.nodes.insert(SceneNode::new(SceneNodeData::Node));
+    assert!(runtime.add_node_tag(bare, AUTOSAVE_GROUP));
+
+    let Variant::Object(snapshot) = runtime.autosave_snapshot() else {
+        panic!("autosave snapshot must be an object");
+    };
+    assert_eq!(snapshot.len(), 1);
+    let Some(Variant::Object(player)) = snapshot.get("player") else {
+        panic!("player payload missing");
+    };
+    let Some(Variant::Object(state)) = player.get("state") else {
+        panic!("player state missing");
+    };
+    assert_eq!(state.get("hp"), Some(&Variant::from(75)));
+}
+
+#[test]
+fn autosave_rejects_bad_slots_and_clamps_interval() {
+    let mut runtime = Runtime::new();
+
+    assert!(!runtime.enable_autosave(10.0, "../escape"));
+    assert!(!runtime.enable_autosave(10.0, ""));
+    assert!(!runtime.is_autosave_enabled());
+
+    assert!(runtime.enable_autosave(0.5, "slot_1"));
+    assert!(runtime.is_autosave_enabled());
+    assert_eq!(runtime.autosave.interval, MIN_SAVE_GAP_SECS);
+
+    runtime.disable_autosave();
+    assert!(!runtime.is_autosave_enabled());
+    assert!(!runtime.save_now());
+}
+
+#[test]
+fn autosave_waits_for_interval_and_running_write() {
+    let mut runtime = Runtime::new();
+    assert!(runtime.enable_autosave(10.0, "slot_1"));
+
+    runtime.tick_autosave(4.0);
+    assert!(runtime.autosave.last_write.is_none());
+
+    // write still running: no new snapshot, retry kept pending
+    runtime.autosave.writing.store(true, Ordering::Release);
+    runtime.tick_autosave(7.0);
+    assert!(runtime.autosave.last_write.is_none());
+    assert_eq!(runtime.autosave.elapsed, 11.0);
+    assert!(!runtime.save_now());
+}
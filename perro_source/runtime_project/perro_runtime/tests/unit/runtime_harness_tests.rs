@@ -0,0 +1,238 @@
+use super::RuntimeHarness;
+use crate::{RuntimeScriptApi, runtime_project::RuntimeProject};
+use perro_ids::{ScriptMemberID, string_to_u64};
+use perro_input_api::KeyCode;
+use perro_nodes::NodeType;
+use perro_scene::{Scene, SceneKey, SceneNodeData, SceneNodeEntry};
+use perro_scripting::{ScriptBehavior, ScriptContext, ScriptFlags, ScriptLifecycle};
+use perro_variant::Variant;
+use std::{any::Any, borrow::Cow};
+
+const PLAYER_SCRIPT: &str = "res://scripts/player.rs";
+
+const PLAYER_DATA: SceneNodeData = SceneNodeData::new(NodeType::Node, Cow::Borrowed(&[]), None);
+const PLAYER_NODES: &[SceneNodeEntry] = &[SceneNodeEntry {
+    data: PLAYER_DATA,
+    has_data_override: true,
+    key: SceneKey(0),
+    name: Some(Cow::Borrowed("player")),
+    tags: Cow::Borrowed(&[]),
+    children: Cow::Borrowed(&[]),
+    parent: None,
+    script: Some(Cow::Borrowed(PLAYER_SCRIPT)),
+    clear_script: false,
+    enabled: true,
+    root_of: None,
+    script_vars: Cow::Borrowed(&[]),
+    meta: Cow::Borrowed(&[]),
+    connections: Cow::Borrowed(&[]),
+}];
+static PLAYER_SCENE: Scene = Scene {
+    nodes: Cow::Borrowed(PLAYER_NODES),
+    root: Some(SceneKey(0)),
+    key_names: Cow::Borrowed(&[]),
+};
+
+fn player_scene_lookup(_path_hash: u64) -> &'static Scene {
+    &PLAYER_SCENE
+}
+
+#[derive(Default)]
+struct PlayerState {
+    jumps: i64,
+    fixed_ticks: i64,
+    buffered_jumps: i64,
+}
+
+struct PlayerScript;
+
+impl ScriptLifecycle<RuntimeScriptApi> for PlayerScript {
+    fn on_update(&self, ctx: &mut ScriptContext<'_, RuntimeScriptApi>) {
+        if ctx.ipt.Keys().pressed(KeyCode::Space) {
+            ctx.run
+                .Scripts()
+                .with_state_mut::<PlayerState, _, _>(ctx.id, |state| state.jumps += 1);
+        }
+    }
+
+    fn on_fixed_update(&self, ctx: &mut ScriptContext<'_, RuntimeScriptApi>) {
+        ctx.run
+            .Scripts()
+            .with_state_mut::<PlayerState, _, _>(ctx.id, |state| state.fixed_ticks += 1);
+    }
+
+    fn on_input(&self, ctx: &mut ScriptContext<'_, RuntimeScriptApi>, event: &Variant) {
+        let Some(event) = event.as_object() else {
+            return;
+        };
+        let is_space_down = event.get("type").and_then(Variant::as_str) == Some("key")
+            && event.get("key").and_then(Variant::as_str) == Some("Space")
+            && event.get("down").and_then(Variant::as_bool) == Some(true);
+        if is_space_down {
+            ctx.run
+                .Scripts()
+                .with_state_mut::<PlayerState, _, _>(ctx.id, |state| state.buffered_jumps += 1);
+        }
+    }
+}
+
+impl ScriptBehavior<RuntimeScriptApi> for PlayerScript {
+    fn script_flags(&self) -> ScriptFlags {
+        ScriptFlags::new(
+            ScriptFlags::HAS_UPDATE | ScriptFlags::HAS_FIXED_UPDATE | ScriptFlags::HAS_INPUT,
+        )
+    }
+
+    fn create_state(&self) -> Box<dyn Any> {
+        Box::<PlayerState>::default()
+    }
+
+    fn get_var(&self, state: &dyn Any, var: ScriptMemberID) -> Variant {
+        let Some(state) = state.downcast_ref::<PlayerState>() else {
+            return Variant::Null;
+        };
+        if var == ScriptMemberID::from_string("jumps") {
+            Variant::from(state.jumps)
+        } else if var == ScriptMemberID::from_string("fixed_ticks") {
+            Variant::from(state.fixed_ticks)
+        } else if var == ScriptMemberID::from_string("buffered_jumps") {
+            Variant::from(state.buffered_jumps)
+        } else {
+            Variant::Null
+        }
+    }
+
+    fn set_var(&self, state: &mut dyn Any, var: ScriptMemberID, value: Variant) {
+        let Some(state) = state.downcast_mut::<PlayerState>() else {
+            return;
+        };
+        if var == ScriptMemberID::from_string("jumps")
+            && let Some(value) = value.as_i64()
+        {
+            state.jumps = value;
+        }
+    }
+
+    fn call_method(
+        &self,
+        _method: ScriptMemberID,
+        _ctx: &mut ScriptContext<'_, RuntimeScriptApi>,
+        _params: &[Variant],
+    ) -> Variant {
+        Variant::Null
+    }
+}
+
+fn player_script_ctor() -> *mut dyn ScriptBehavior<RuntimeScriptApi> {
+    Box::into_raw(Box::new(PlayerScript))
+}
+
+static PLAYER_REGISTRY: &[(u64, perro_scripting::ScriptConstructor<RuntimeScriptApi>)] =
+    &[(string_to_u64(PLAYER_SCRIPT), player_script_ctor)];
+
+fn player_harness() -> RuntimeHarness {
+    let mut project = RuntimeProject::new("Harness Test", ".");
+    project.static_scene_lookup = Some(player_scene_lookup);
+    RuntimeHarness::from_project(
+        project,
+        crate::runtime_project::ProviderMode::Static,
+        Some(PLAYER_REGISTRY),
+    )
+    .expect("test or bench setup must succeed")
+}
+
+#[test]
+fn harness_drives_input_and_reads_script_state() {
+    let mut harness = player_harness();
+    let player = harness
+        .find_node("player")
+        .expect("boot scene spawns player");
+
+    harness.press_key(KeyCode::Space);
+    harness.step();
+    assert_eq!(harness.get_var(player, "jumps"), Variant::from(1_i64));
+
+    // Held key has no new press edge.
+    harness.step_frames(3);
+    assert_eq!(harness.get_var(player, "jumps"), Variant::from(1_i64));
+
+    harness.release_key(KeyCode::Space);
+    harness.step();
+    harness.press_key(KeyCode::Space);
+    harness.step();
+    assert_eq!(harness.get_var(player, "jumps"), Variant::from(2_i64));
+    assert_eq!(harness.frame(), 6);
+    assert_eq!(harness.get_var(player, "fixed_ticks"), Variant::from(6_i64));
+}
+
+#[test]
+fn on_input_sees_every_transition_polling_merges() {
+    let mut harness = player_harness();
+    let player = harness
+        .find_node("player")
+        .expect("boot scene spawns player");
+
+    // Tap, release, tap inside one frame: polling sees one press edge, the
+    // event log delivers both downs in order.
+    harness.press_key(KeyCode::Space);
+    harness.release_key(KeyCode::Space);
+    harness.press_key(KeyCode::Space);
+    harness.step();
+    assert_eq!(harness.get_var(player, "jumps"), Variant::from(1_i64));
+    assert_eq!(
+        harness.get_var(player, "buffered_jumps"),
+        Variant::from(2_i64)
+    );
+
+    // Log is drained once delivered.
+    harness.step();
+    assert_eq!(
+        harness.get_var(player, "buffered_jumps"),
+        Variant::from(2_i64)
+    );
+}
+
+#[test]
+fn harness_long_frames_cap_fixed_catchup() {
+    let mut project = RuntimeProject::new("Harness Test", ".");
+    project.static_scene_lookup = Some(player_scene_lookup);
+    project.config.max_fixed_steps_per_frame = 3;
+    let harness = RuntimeHarness::from_project(
+        project,
+        crate::runtime_project::ProviderMode::Static,
+        Some(PLAYER_REGISTRY),
+    )
+    .expect("test or bench setup must succeed");
+    let stall = harness.fixed_delta() * 10.0;
+    let mut harness = harness.with_frame_delta(stall);
+    let player = harness
+        .find_node("player")
+        .expect("boot scene spawns player");
+
+    harness.step_frames(2);
+    // 10 steps owed per frame; only 3 run and the rest is dropped, so the
+    // backlog never carries into the next frame.
+    assert_eq!(harness.get_var(player, "fixed_ticks"), Variant::from(6_i64));
+}
+
+#[test]
+fn harness_half_rate_frames_run_fixed_steps_every_other_frame() {
+    let harness = player_harness();
+    let half = harness.fixed_delta() * 0.5;
+    let mut harness = harness.with_frame_delta(half);
+    let player = harness
+        .find_node("player")
+        .expect("boot scene spawns player");
+
+    harness.step_frames(4);
+    assert_eq!(harness.get_var(player, "fixed_ticks"), Variant::from(2_i64));
+
+    harness.set_var(player, "jumps", Variant::from(10_i64));
+    assert!(harness.step_until(5, |h| {
+        h.run(|run| {
+            run.Scripts()
+                .get_var(player, ScriptMemberID::from_string("jumps"))
+        }) == Variant::from(10_i64)
+    }));
+    assert!(!harness.quit_requested());
+}
@@ -305,6 +305,97 @@ fn node_arena_len_tracks_active_nodes() {
     assert!(arena.is_empty());
 }
 
+#[test]
+fn memory_report_tracks_nodes_and_script_state() {
+    let mut runtime = Runtime::new();
+    let empty = runtime.memory_report();
+    assert_eq!(empty.node_count, 0);
+    assert_eq!(empty.script_count, 0);
+    assert_eq!(empty.gpu_bytes, 0);
+
+    let a = runtime
+        .nodes
+        .insert(SceneNode::new(SceneNodeData::Node3D(Node3D::new())));
+    let _ = runtime
+        .nodes
+        .insert(SceneNode::new(SceneNodeData::Node3D(Node3D::new())));
+    runtime.scripts.insert(
+        a,
+        Arc::new(CountScript {
+            update_count: Arc::new(AtomicUsize::new(0)),
+            fixed_count: Arc::new(AtomicUsize::new(0)),
+        }),
+        Box::new([0_u64; 32]),
+    );
+    runtime.set_gpu_memory_bytes(4_096);
+
+    let report = runtime.memory_report();
+    assert_eq!(report.node_count, 2);
+    assert!(report.node_arena_bytes > empty.node_arena_bytes);
+    assert_eq!(report.script_count, 1);
+    assert!(report.script_state_bytes >= 32 * 8);
+    assert_eq!(report.gpu_bytes, 4_096);
+    assert_eq!(report.total_bytes(), report.cpu_bytes() + 4_096);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn leak_report_lists_orphan_subtrees_and_unreferenced_resources() {
+    use perro_resource_api::sub_apis::MeshAPI;
+    use perro_runtime_api::sub_apis::LeakKind;
+
+    let mut runtime = Runtime::new();
+    let scene_root = runtime
+        .nodes
+        .insert(SceneNode::new(SceneNodeData::Node3D(Node3D::new())));
+    let mut child = SceneNode::new(SceneNodeData::Node3D(Node3D::new()));
+    child.parent = scene_root;
+    let _ = runtime.nodes.insert(child);
+    runtime.active_route_root = Some(scene_root);
+    assert!(runtime.leak_report().is_empty());
+
+    let creator = NodeID::from_parts(42, 0);
+    crate::cns::origin::set_active_creator(creator);
+    let mut orphan = SceneNode::new(SceneNodeData::Node3D(Node3D::new()));
+    orphan.name = "Stray".into();
+    let orphan = runtime.nodes.insert(orphan);
+    let mut orphan_child = SceneNode::new(SceneNodeData::Node3D(Node3D::new()));
+    orphan_child.parent = orphan;
+    let _ = runtime.nodes.insert(orphan_child);
+    let mesh = MeshAPI::create_mesh_data(
+        runtime.resource_api.as_ref(),
+        perro_render_bridge::Mesh3D {
+            vertices: Vec::new(),
+            indices: Vec::new(),
+            surface_ranges: Vec::new(),
+            blend_shapes: Vec::new(),
+        },
+    );
+    crate::cns::origin::set_active_creator(NodeID::nil());
+
+    let report = runtime.leak_report();
+    assert!(report.tracking);
+    assert_eq!(report.count(LeakKind::Node), 1);
+    let node = &report.entries[0];
+    assert_eq!(node.id, orphan.as_u64());
+    assert_eq!(node.creator, Some(creator));
+    assert!(node.label.starts_with("Stray (Node3D, 2 nodes)"));
+    let leaked_mesh = report
+        .entries
+        .iter()
+        .find(|entry| entry.kind == LeakKind::Mesh)
+        .expect("unreferenced mesh reported");
+    assert_eq!(leaked_mesh.id, mesh.as_u64());
+    assert_eq!(leaked_mesh.creator, Some(creator));
+
+    runtime.scene_mesh_refs_cache.insert(mesh, vec![scene_root]);
+    let _ = runtime.nodes.remove(orphan);
+    let report = runtime.leak_report();
+    // child lost its parent, so it now tops its own orphan subtree.
+    assert_eq!(report.count(LeakKind::Node), 1);
+    assert_eq!(report.count(LeakKind::Mesh), 0);
+}
+
 #[test]
 fn node_arena_clear_never_revives_old_ids() {
     let mut arena = NodeArena::new();
@@ -331,22 +422,28 @@ fn node_arena_name_index_tracks_insert_rename_remove() {
     assert!(arena.named_ids("beta").is_empty());
 
     // Rename keeps the index in sync (old entry gone, new one present).
-    assert!(arena.rename(a, "beta".into()));
+    assert!(arena.rename(a, "beta"));
     assert!(arena.named_ids("alpha").is_empty());
     assert_eq!(arena.named_ids("beta"), &[a]);
 
     // Naming a second node appends in insertion order.
-    assert!(arena.rename(b, "beta".into()));
+    assert!(arena.rename(b, "beta"));
     assert_eq!(arena.named_ids("beta"), &[a, b]);
+    // Same name, one interned allocation.
+    let (name_a, name_b) = (
+        &arena.get(a).expect("live node").name,
+        &arena.get(b).expect("live node").name,
+    );
+    assert!(std::sync::Arc::ptr_eq(name_a.as_arc(), name_b.as_arc()));
 
     // Removal drops only the removed id; empty names never indexed.
     let _ = arena.remove(a);
     assert_eq!(arena.named_ids("beta"), &[b]);
-    assert!(arena.rename(b, "".into()));
+    assert!(arena.rename(b, ""));
     assert!(arena.named_ids("beta").is_empty());
 
     // Dead ids fail.
-    assert!(!arena.rename(a, "gamma".into()));
+    assert!(!arena.rename(a, "gamma"));
 }
 
 #[test]
@@ -0,0 +1,123 @@
+use crate::{Runtime, RuntimeScriptApi};
+use perro_ids::{NodeID, ScriptMemberID};
+use perro_nodes::{SceneNode, SceneNodeData};
+use perro_runtime_api::sub_apis::{
+    NodeAPI, ON_QUIT_REQUEST, OsAPI, QUIT_HANDLERS_GROUP, WindowRequest,
+};
+use perro_scripting::{ScriptBehavior, ScriptContext, ScriptFlags, ScriptLifecycle};
+use perro_variant::Variant;
+use std::any::Any;
+use std::sync::Arc;
+
+#[derive(Clone, Copy)]
+enum QuitReply {
+    Allow,
+    Delay,
+    Cancel,
+}
+
+/// Answers `on_quit_request` w/ a fixed reply.
+struct QuitHandlerScript(QuitReply);
+
+impl ScriptLifecycle<RuntimeScriptApi> for QuitHandlerScript {}
+
+impl ScriptBehavior<RuntimeScriptApi> for QuitHandlerScript {
+    fn script_flags(&self) -> ScriptFlags {
+        ScriptFlags::new(ScriptFlags::NONE)
+    }
+
+    fn get_var(&self, _state: &dyn Any, _var: ScriptMemberID) -> Variant {
+        Variant::Null
+    }
+
+    fn set_var(&self, _state: &mut dyn Any, _var: ScriptMemberID, _value: Variant) {}
+
+    fn call_method(
+        &self,
+        method: ScriptMemberID,
+        ctx: &mut ScriptContext<'_, RuntimeScriptApi>,
+        _params: &[Variant],
+    ) -> Variant {
+        if method != ScriptMemberID::from_string(ON_QUIT_REQUEST) {
+            return Variant::Null;
+        }
+        match self.0 {
+            QuitReply::Allow => {}
+            QuitReply::Delay => ctx.run.OS().delay_quit(),
+            QuitReply::Cancel => ctx.run.OS().cancel_quit(),
+        }
+        Variant::Null
+    }
+}
+
+fn quit_handler(runtime: &mut Runtime, reply: QuitReply) -> NodeID {
+    let id = runtime.nodes.insert(SceneNode::new(SceneNodeData::Node));
+    assert!(runtime.add_node_tag(id, QUIT_HANDLERS_GROUP));
+    runtime
+        .scripts
+        .insert(id, Arc::new(QuitHandlerScript(reply)), Box::new(()));
+    id
+}
+
+fn close_requests(runtime: &mut Runtime) -> usize {
+    let mut requests = Vec::new();
+    runtime.drain_window_requests(&mut requests);
+    requests
+        .iter()
+        .filter(|request| matches!(request, WindowRequest::CloseApp))
+        .count()
+}
+
+#[test]
+fn quit_without_handlers_closes_with_code() {
+    let mut runtime = Runtime::new();
+
+    assert!(runtime.begin_quit(4));
+
+    assert_eq!(close_requests(&mut runtime), 1);
+    assert_eq!(runtime.exit_code(), 4);
+    assert!(!runtime.is_quit_pending());
+}
+
+#[test]
+fn allowing_handler_lets_quit_through() {
+    let mut runtime = Runtime::new();
+    quit_handler(&mut runtime, QuitReply::Allow);
+
+    assert!(runtime.begin_quit(0));
+    assert_eq!(close_requests(&mut runtime), 1);
+}
+
+#[test]
+fn delaying_handler_holds_quit_until_resumed() {
+    let mut runtime = Runtime::new();
+    quit_handler(&mut runtime, QuitReply::Delay);
+
+    assert!(!runtime.begin_quit(2));
+    assert!(runtime.is_quit_pending());
+    assert_eq!(close_requests(&mut runtime), 0);
+
+    // window close spam while the autosave runs does not re-ask handlers
+    assert!(!runtime.begin_quit(0));
+    assert_eq!(runtime.quit.holds, 1);
+
+    runtime.resume_quit();
+    assert_eq!(close_requests(&mut runtime), 1);
+    assert_eq!(runtime.exit_code(), 2);
+}
+
+#[test]
+fn cancelling_handler_keeps_app_running() {
+    let mut runtime = Runtime::new();
+    quit_handler(&mut runtime, QuitReply::Cancel);
+
+    assert!(!runtime.begin_quit(1));
+    assert!(!runtime.is_quit_pending());
+    assert_eq!(close_requests(&mut runtime), 0);
+    assert_eq!(runtime.exit_code(), 0);
+
+    // next request asks again
+    assert!(!runtime.begin_quit(1));
+    runtime.resume_quit();
+    assert_eq!(close_requests(&mut runtime), 0);
+}
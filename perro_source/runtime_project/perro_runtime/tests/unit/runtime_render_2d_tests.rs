@@ -1046,6 +1046,44 @@ fn unchanged_sprite_skips_redundant_upsert() {
     assert!(second.is_empty());
 }
 
+#[test]
+fn static_scene_reextracts_only_changed_sprite() {
+    let mut runtime = Runtime::new();
+    let mut template = Sprite2D::new();
+    template.texture = TextureID::from_parts(31, 0);
+    let templates = vec![NodeSpec::new(template); 1_000];
+    let ids = runtime.create_nodes(&templates, perro_ids::NodeID::nil());
+
+    runtime.extract_render_2d_commands();
+    let first = collect_commands(&mut runtime);
+    let first_upserts = first
+        .iter()
+        .filter(|command| matches!(command, RenderCommand::TwoD(Command2D::UpsertSprite { .. })))
+        .count();
+    assert_eq!(first_upserts, 1_000);
+    runtime.clear_dirty_flags();
+
+    runtime.extract_render_2d_commands();
+    assert!(collect_commands(&mut runtime).is_empty());
+
+    let moved = ids[500];
+    runtime
+        .with_node_mut::<Sprite2D, _, _>(moved, |sprite| {
+            sprite.transform.position = Vector2::new(64.0, 32.0);
+        })
+        .expect("sprite exists");
+    runtime.extract_render_2d_commands();
+    let changed = collect_commands(&mut runtime);
+    let changed_upserts: Vec<_> = changed
+        .iter()
+        .filter_map(|command| match command {
+            RenderCommand::TwoD(Command2D::UpsertSprite { node, .. }) => Some(*node),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(changed_upserts, vec![moved]);
+}
+
 #[test]
 fn parent_modulate_change_reemits_child_sprite_with_effective_tint() {
     let mut runtime = Runtime::new();
@@ -1699,3 +1737,55 @@ fn force_rerender_marks_subtree_dirty() {
     runtime.force_rerender(parent);
     assert_eq!(runtime.dirty_node_count(), 2);
 }
+
+#[test]
+fn canvas_layer_sprite_2d_stays_pinned_to_screen_when_camera_moves() {
+    let mut runtime = Runtime::new();
+    let mut camera = Camera2D {
+        active: true,
+        zoom: 2.0,
+        ..Default::default()
+    };
+    camera.transform.position = Vector2::new(300.0, -50.0);
+    let camera_node = runtime
+        .nodes
+        .insert(SceneNode::new(SceneNodeData::Camera2D(camera)));
+
+    let mut sprite = Sprite2D::new();
+    sprite.texture = TextureID::from_parts(92, 0);
+    sprite.transform.position = Vector2::new(10.0, 20.0);
+    sprite.z_index = 3;
+    sprite.canvas_layer = 1;
+    let sprite_node = runtime
+        .nodes
+        .insert(SceneNode::new(SceneNodeData::Sprite2D(sprite)));
+
+    let sprite_model = |commands: &[RenderCommand]| {
+        commands.iter().find_map(|command| match command {
+            RenderCommand::TwoD(Command2D::UpsertSprite { node, sprite })
+                if *node == sprite_node =>
+            {
+                Some(*sprite)
+            }
+            _ => None,
+        })
+    };
+
+    runtime.extract_render_2d_commands();
+    let first = sprite_model(&collect_commands(&mut runtime)).expect("canvas sprite upsert");
+    // world = cam + screen / zoom, so camera view maps it back 2 (10, 20).
+    assert_eq!(first.model[2][0], 305.0);
+    assert_eq!(first.model[2][1], -40.0);
+    assert_eq!(first.model[0][0], 0.5);
+    assert!(first.z_index > 3);
+
+    runtime
+        .with_node_mut::<Camera2D, _, _>(camera_node, |camera| {
+            camera.transform.position.x = 0.0;
+        })
+        .expect("camera exists");
+    runtime.extract_render_2d_commands();
+    let second = sprite_model(&collect_commands(&mut runtime)).expect("canvas sprite re-emit");
+    assert_eq!(second.model[2][0], 5.0);
+    assert_eq!(second.model[2][1], -40.0);
+}
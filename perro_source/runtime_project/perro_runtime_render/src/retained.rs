@@ -185,6 +185,9 @@ pub struct Render2DState {
     pub last_button_pointer: Option<(Vector2, bool)>,
     pub removed_nodes: Vec<NodeID>,
     pub force_full_scan_once: bool,
+    /// nodes last extracted on screen-space canvas layer. re-extract on
+    /// camera chg so they stay pinned 2 screen.
+    pub canvas_nodes: AHashSet<NodeID>,
 }
 
 pub type Render2dSystem = Render2DState;
@@ -215,6 +218,7 @@ impl Render2DState {
             last_button_pointer: None,
             removed_nodes: Vec::new(),
             force_full_scan_once: false,
+            canvas_nodes: AHashSet::default(),
         }
     }
 
@@ -370,6 +374,8 @@ pub struct RenderUiState {
     pub focusable_nodes: Vec<NodeID>,
     pub hovered_text_edit: Option<NodeID>,
     pub focused_ui_node: Option<NodeID>,
+    /// `initial_focus` nodes that already claimed focus once.
+    pub initial_focus_applied: AHashSet<NodeID>,
     pub nav_pressed_button: Option<NodeID>,
     pub ui_nav_repeat_dir: Option<[i8; 2]>,
     pub ui_nav_repeat_timer: f32,
@@ -378,6 +384,8 @@ pub struct RenderUiState {
     pub pressed_ui_button: Option<NodeID>,
     pub active_scrollbar: Option<NodeID>,
     pub scrollbar_drag_offset: f32,
+    /// `drag_scroll` container grabbed by pointer + last pointer point.
+    pub drag_scroll: Option<(NodeID, Vector2)>,
     pub text_edit_repeat_key: Option<perro_input_api::KeyCode>,
     pub text_edit_repeat_timer: f32,
     pub last_ui_pointer: Option<(Vector2, bool)>,
@@ -395,6 +403,8 @@ pub struct RenderUiState {
     /// re-applied after the clear.
     pub deferred_dirty: Vec<(NodeID, u16)>,
     pub defer_dirty_marks: bool,
+    /// UiMarkdown nodes seen dirty; width/re-wrap checks only walk these.
+    pub markdown_nodes: AHashSet<NodeID>,
 }
 
 pub type RenderUiSystem = RenderUiState;
@@ -449,6 +459,7 @@ impl RenderUiState {
             focusable_nodes: Vec::new(),
             hovered_text_edit: None,
             focused_ui_node: None,
+            initial_focus_applied: AHashSet::default(),
             nav_pressed_button: None,
             ui_nav_repeat_dir: None,
             ui_nav_repeat_timer: 0.0,
@@ -457,6 +468,7 @@ impl RenderUiState {
             pressed_ui_button: None,
             active_scrollbar: None,
             scrollbar_drag_offset: 0.0,
+            drag_scroll: None,
             text_edit_repeat_key: None,
             text_edit_repeat_timer: 0.0,
             last_ui_pointer: None,
@@ -470,6 +482,7 @@ impl RenderUiState {
             event_signal_name_scratch: String::new(),
             deferred_dirty: Vec::new(),
             defer_dirty_marks: false,
+            markdown_nodes: AHashSet::default(),
         }
     }
 
@@ -43,6 +43,7 @@ pub fn filter_demo_scene(scene: &mut Scene, demo: bool) -> Result<(), String> {
         .filter(|node| !removed.contains(&node.key))
     {
         check_fields(node.script_vars.as_ref(), &removed_names)?;
+        check_fields(node.meta.as_ref(), &removed_names)?;
         check_data(&node.data, &removed_names)?;
     }
 
@@ -0,0 +1,382 @@
+//! Asset dependency graph for a project on disk.
+//!
+//! Scenes go through the real parser (scripts, `root_of`, field values);
+//! materials, particles, styles, animations, data files and scripts are
+//! scanned for quoted `res://` / `dlc://` literals. Keys are virtual paths
+//! with any `:sub_asset` suffix dropped, so `res://hero.glb:mesh0` counts as
+//! a use of `res://hero.glb`.
+
+use crate::{Parser, Scene, SceneNodeData, SceneNodeDataBase, SceneValue};
+use perro_asset_formats::source_ext;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AssetDepGraph {
+    deps: Edges,
+    users: Edges,
+}
+
+impl AssetDepGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Walks `res/` and every `dlcs/<name>/`.
+    pub fn scan_project(project_dir: &Path) -> Result<Self, String> {
+        let mut graph = Self::new();
+        graph.scan_dir(&project_dir.join("res"), "res://")?;
+        let dlcs = project_dir.join("dlcs");
+        if dlcs.is_dir() {
+            let entries = fs::read_dir(&dlcs)
+                .map_err(|err| format!("failed to read directory {}: {err}", dlcs.display()))?;
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    let name = entry.file_name().to_string_lossy().to_ascii_lowercase();
+                    graph.scan_dir(&path, &format!("dlc://{name}/"))?;
+                }
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Replaces the outgoing edges of `source`. Lets the editor refresh one
+    /// file on save instead of rescanning the project.
+    pub fn set_file_refs(&mut self, source: &str, refs: impl IntoIterator<Item = String>) {
+        self.remove_file(source);
+        let refs: BTreeSet<String> = refs
+            .into_iter()
+            .map(|raw| normalize_asset_ref(&raw, source))
+            .filter(|target| target != source)
+            .collect();
+        for target in &refs {
+            self.users
+                .entry(target.clone())
+                .or_default()
+                .insert(source.to_string());
+        }
+        self.deps.insert(source.to_string(), refs);
+    }
+
+    pub fn remove_file(&mut self, source: &str) {
+        let Some(old) = self.deps.remove(source) else {
+            return;
+        };
+        for target in old {
+            if let Some(users) = self.users.get_mut(&target) {
+                users.remove(source);
+                if users.is_empty() {
+                    self.users.remove(&target);
+                }
+            }
+        }
+    }
+
+    /// Every scanned file, sorted.
+    pub fn files(&self) -> impl Iterator<Item = &str> {
+        self.deps.keys().map(String::as_str)
+    }
+
+    /// Files `source` references directly.
+    pub fn deps_of(&self, source: &str) -> impl Iterator<Item = &str> {
+        self.deps
+            .get(source)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+    }
+
+    /// Files that reference `target` directly ("what uses player.png?").
+    pub fn users_of(&self, target: &str) -> impl Iterator<Item = &str> {
+        self.users
+            .get(target)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+    }
+
+    /// Everything `source` pulls in, sorted, without `source` itself.
+    pub fn transitive_deps(&self, source: &str) -> BTreeSet<&str> {
+        walk(&self.deps, source)
+    }
+
+    /// Everything that breaks if `target` goes away.
+    pub fn transitive_users(&self, target: &str) -> BTreeSet<&str> {
+        walk(&self.users, target)
+    }
+
+    pub fn is_used(&self, target: &str) -> bool {
+        self.users.contains_key(target)
+    }
+
+    /// Indented tree, two spaces per level. A file already expanded above is
+    /// printed once more with ` (see above)` and not expanded again, which
+    /// also ends cycles.
+    pub fn deps_tree_text(&self, root: &str) -> String {
+        tree_text(&self.deps, root)
+    }
+
+    pub fn users_tree_text(&self, root: &str) -> String {
+        tree_text(&self.users, root)
+    }
+
+    fn scan_dir(&mut self, dir: &Path, prefix: &str) -> Result<(), String> {
+        let mut files = Vec::new();
+        collect_dep_files(dir, dir, &mut files)?;
+        files.sort();
+        for (rel, path) in files {
+            let text = fs::read_to_string(&path)
+                .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+            let source = format!("{prefix}{rel}");
+            let refs = file_asset_refs(&path, &text);
+            self.set_file_refs(&source, refs);
+        }
+        Ok(())
+    }
+}
+
+/// Raw refs in one file. `.scn` parses; anything else (or a scene that does
+/// not parse yet) falls back to the literal scan.
+pub fn file_asset_refs(path: &Path, text: &str) -> Vec<String> {
+    let is_scene = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("scn"));
+    if is_scene && let Ok(scene) = Parser::new(text).try_parse_scene() {
+        return scene_asset_refs(&scene);
+    }
+    text_asset_refs(text)
+}
+
+pub fn scene_asset_refs(scene: &Scene) -> Vec<String> {
+    let mut refs = Vec::new();
+    for node in scene.nodes.iter() {
+        refs.extend(node.script.iter().map(|path| path.to_string()));
+        refs.extend(node.root_of.iter().map(|path| path.to_string()));
+        for (_, value) in node.script_vars.iter().chain(node.meta.iter()) {
+            value_asset_refs(value, &mut refs);
+        }
+        data_asset_refs(&node.data, &mut refs);
+    }
+    refs.retain(|raw| is_asset_ref(raw));
+    refs
+}
+
+/// Standalone resource file type a scene field can point at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SceneResourceKind {
+    Material,
+    Animation,
+    AnimationTree,
+    Particle,
+    UiStyle,
+}
+
+impl SceneResourceKind {
+    /// Kind from a ref's extension. `None` for textures, meshes, scripts, and
+    /// other files that are not standalone resources.
+    pub fn from_ref(raw: &str) -> Option<Self> {
+        let path = raw.split_once("://").map_or(raw, |(_, rest)| rest);
+        let path = path.split_once(':').map_or(path, |(path, _)| path);
+        let (_, ext) = path.rsplit_once('.')?;
+        [
+            (source_ext::MATERIAL, Self::Material),
+            (source_ext::ANIMATION, Self::Animation),
+            (source_ext::ANIMATION_TREE, Self::AnimationTree),
+            (source_ext::PARTICLE, Self::Particle),
+            (source_ext::UI_STYLE, Self::UiStyle),
+        ]
+        .into_iter()
+        .find(|(candidate, _)| ext.eq_ignore_ascii_case(candidate))
+        .map(|(_, kind)| kind)
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Material => "material",
+            Self::Animation => "animation",
+            Self::AnimationTree => "animation tree",
+            Self::Particle => "particle",
+            Self::UiStyle => "ui style",
+        }
+    }
+}
+
+/// A scene field value naming a standalone resource file, e.g.
+/// `material = "res://rock.pmat"`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SceneResourceRef {
+    pub kind: SceneResourceKind,
+    pub path: String,
+    /// Key of the node holding the ref.
+    pub node: String,
+}
+
+/// Typed refs to `.pmat`, `.panim`, `.panimtree`, `.ppart`, and `.uistyle`
+/// files, in node order, one per node and path.
+pub fn scene_resource_refs(scene: &Scene) -> Vec<SceneResourceRef> {
+    let mut out = Vec::new();
+    for node in scene.nodes.iter() {
+        let mut raw = Vec::new();
+        for (_, value) in node.script_vars.iter().chain(node.meta.iter()) {
+            value_asset_refs(value, &mut raw);
+        }
+        data_asset_refs(&node.data, &mut raw);
+        let key = scene.key_name(node.key).unwrap_or_default();
+        for path in raw {
+            let Some(kind) = SceneResourceKind::from_ref(&path).filter(|_| is_asset_ref(&path))
+            else {
+                continue;
+            };
+            let entry = SceneResourceRef {
+                kind,
+                path,
+                node: key.to_string(),
+            };
+            if !out.contains(&entry) {
+                out.push(entry);
+            }
+        }
+    }
+    out
+}
+
+/// Quoted `"res://..."` / `'dlc://...'` literals.
+pub fn text_asset_refs(text: &str) -> Vec<String> {
+    let mut refs = Vec::new();
+    for quote in ['"', '\''] {
+        for part in text.split(quote).skip(1).step_by(2) {
+            if is_asset_ref(part) {
+                refs.push(part.to_string());
+            }
+        }
+    }
+    refs
+}
+
+/// Drops `:sub_asset` suffixes and resolves `dlc://self/` against the DLC the
+/// `source` file lives in.
+pub fn normalize_asset_ref(raw: &str, source: &str) -> String {
+    let (scheme, rest) = match raw.split_once("://") {
+        Some((scheme, rest)) => (scheme, rest),
+        None => return raw.to_string(),
+    };
+    let rest = rest.split_once(':').map_or(rest, |(path, _)| path);
+    if scheme == "dlc"
+        && let Some(rel) = rest.strip_prefix("self/")
+        && let Some(dlc) = source
+            .strip_prefix("dlc://")
+            .and_then(|src| src.split_once('/'))
+            .map(|(dlc, _)| dlc)
+    {
+        return format!("dlc://{dlc}/{rel}");
+    }
+    format!("{scheme}://{rest}")
+}
+
+fn is_asset_ref(raw: &str) -> bool {
+    raw.len() > "res://".len() && (raw.starts_with("res://") || raw.starts_with("dlc://"))
+}
+
+fn data_asset_refs(data: &SceneNodeData, out: &mut Vec<String>) {
+    for (_, value) in data.fields.iter() {
+        value_asset_refs(value, out);
+    }
+    match &data.base {
+        Some(SceneNodeDataBase::Borrowed(base)) => data_asset_refs(base, out),
+        Some(SceneNodeDataBase::Owned(base)) => data_asset_refs(base, out),
+        None => {}
+    }
+}
+
+fn value_asset_refs(value: &SceneValue, out: &mut Vec<String>) {
+    match value {
+        SceneValue::Str(raw) => out.push(raw.to_string()),
+        SceneValue::Object(fields) => {
+            for (_, value) in fields.iter() {
+                value_asset_refs(value, out);
+            }
+        }
+        SceneValue::Array(items) => {
+            for value in items.iter() {
+                value_asset_refs(value, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+type Edges = BTreeMap<String, BTreeSet<String>>;
+
+fn walk<'a>(edges: &'a Edges, start: &str) -> BTreeSet<&'a str> {
+    let mut seen = BTreeSet::new();
+    let mut stack = vec![start];
+    while let Some(path) = stack.pop() {
+        for child in edges.get(path).into_iter().flatten() {
+            if child != start && seen.insert(child.as_str()) {
+                stack.push(child.as_str());
+            }
+        }
+    }
+    seen
+}
+
+fn tree_text(edges: &Edges, root: &str) -> String {
+    let mut out = String::new();
+    let mut expanded = BTreeSet::new();
+    let mut stack = vec![(root, 0usize)];
+    while let Some((path, depth)) = stack.pop() {
+        let indent = "  ".repeat(depth);
+        if !expanded.insert(path) {
+            let _ = writeln!(out, "{indent}{path} (see above)");
+            continue;
+        }
+        let _ = writeln!(out, "{indent}{path}");
+        for child in edges.get(path).into_iter().flatten().rev() {
+            stack.push((child.as_str(), depth + 1));
+        }
+    }
+    out
+}
+
+fn collect_dep_files(
+    root: &Path,
+    dir: &Path,
+    out: &mut Vec<(String, PathBuf)>,
+) -> Result<(), String> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+    let entries = fs::read_dir(dir)
+        .map_err(|err| format!("failed to read directory {}: {err}", dir.display()))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_dep_files(root, &path, out)?;
+        } else if path.extension().is_some_and(is_dep_source_extension)
+            && let Ok(rel) = path.strip_prefix(root)
+        {
+            out.push((rel.to_string_lossy().replace('\\', "/"), path));
+        }
+    }
+    Ok(())
+}
+
+fn is_dep_source_extension(ext: &std::ffi::OsStr) -> bool {
+    matches!(
+        ext.to_string_lossy().to_ascii_lowercase().as_str(),
+        "scn"
+            | "pmat"
+            | "ppart"
+            | "uistyle"
+            | "fur"
+            | "panim"
+            | "panimtree"
+            | "toml"
+            | "ron"
+            | "json"
+            | "rs"
+    )
+}
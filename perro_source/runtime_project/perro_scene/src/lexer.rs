@@ -5,6 +5,7 @@ pub enum Token<'a> {
     Ident(&'a str), // name, position, Sprite2D
     Number(f32),
     String(String),
+    Color([u8; 4]), // #RRGGBB / #RRGGBBAA in value position
 
     At,      // @
     Dollar,  // $
@@ -16,6 +17,12 @@ pub enum Token<'a> {
     LBrace,  // {
     RBrace,  // }
     Colon,   // :
+    Plus,    // +
+    Minus,   // - (not directly before a digit)
+    Star,    // *
+    Arrow,   // ->
+    Dot,     // .
+    Bang,    // !
 
     LBracket, // [
     RBracket, // ]
@@ -78,11 +85,28 @@ impl LexError {
 pub struct Lexer<'a> {
     src: &'a str,
     pos: usize,
+    token_start: usize,
+    /// last token can be followed by a value, so `#ff00aa` is a color, not a
+    /// comment. true at the start so bare value literals lex too.
+    value_next: bool,
 }
 
 impl<'a> Lexer<'a> {
     pub fn new(src: &'a str) -> Self {
-        Self { src, pos: 0 }
+        Self {
+            src,
+            pos: 0,
+            token_start: 0,
+            value_next: true,
+        }
+    }
+
+    /// Byte span of the token last returned by [`Lexer::next_token`].
+    pub fn token_span(&self) -> Span {
+        Span {
+            start: self.token_start,
+            end: self.pos,
+        }
     }
 
     fn peek(&self) -> Option<char> {
@@ -111,9 +135,50 @@ impl<'a> Lexer<'a> {
     }
 
     pub fn next_token(&mut self) -> Token<'a> {
+        let token = self.lex_token();
+        self.value_next = matches!(
+            token,
+            Token::Equals | Token::Comma | Token::LParen | Token::LBracket | Token::Colon
+        );
+        token
+    }
+
+    /// hex digit count of a `#` color literal at the cursor; 6 or 8 w/ a word
+    /// boundary after, else None.
+    fn color_literal_len(&self) -> Option<usize> {
+        let rest = self.src[self.pos..].strip_prefix('#')?;
+        let digits = rest.bytes().take_while(u8::is_ascii_hexdigit).count();
+        let boundary = !matches!(
+            rest[digits..].chars().next(),
+            Some(c) if c.is_alphanumeric() || c == '_'
+        );
+        (boundary && matches!(digits, 6 | 8)).then_some(digits)
+    }
+
+    fn lex_color(&mut self, digits: usize) -> Token<'a> {
+        let start = self.pos;
+        self.token_start = start;
+        self.pos += 1 + digits;
+        let hex = &self.src[start + 1..self.pos];
+        let mut rgba = [255u8; 4];
+        for (i, channel) in rgba.iter_mut().enumerate().take(digits / 2) {
+            // digits r all ascii hex, so this cant fail
+            *channel = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).unwrap_or(0);
+        }
+        Token::Color(rgba)
+    }
+
+    fn lex_token(&mut self) -> Token<'a> {
         loop {
             self.skip_ws();
             match (self.peek(), self.src[self.pos..].chars().nth(1)) {
+                (Some('#'), _) if self.value_next => {
+                    if let Some(digits) = self.color_literal_len() {
+                        return self.lex_color(digits);
+                    }
+                    self.bump();
+                    self.skip_until_newline();
+                }
                 (Some('#'), _) => {
                     self.bump();
                     self.skip_until_newline();
@@ -128,6 +193,7 @@ impl<'a> Lexer<'a> {
         }
 
         let start = self.pos;
+        self.token_start = start;
         let c = match self.bump() {
             Some(c) => c,
             None => return Token::Eof,
@@ -147,6 +213,10 @@ impl<'a> Lexer<'a> {
             '[' => Token::LBracket,
             ']' => Token::RBracket,
             '/' => Token::Slash,
+            '+' => Token::Plus,
+            '*' => Token::Star,
+            '.' => Token::Dot,
+            '!' => Token::Bang,
 
             '"' => {
                 let mut s = String::new();
@@ -178,8 +248,14 @@ impl<'a> Lexer<'a> {
                 || (c == '-'
                     && matches!(self.peek(), Some(p) if p.is_ascii_digit() || p == '.')) =>
             {
-                while matches!(self.peek(), Some(p) if p.is_ascii_digit() || p == '.' || p == 'e' || p == 'E' || p == '+' || p == '-')
-                {
+                // Sign only after an exponent, so `16+2` lexes as an expression.
+                let mut prev = c;
+                while let Some(p) = self.peek() {
+                    let exp_sign = matches!(p, '+' | '-') && matches!(prev, 'e' | 'E');
+                    if !(p.is_ascii_digit() || matches!(p, '.' | 'e' | 'E') || exp_sign) {
+                        break;
+                    }
+                    prev = p;
                     self.bump();
                 }
                 match self.src[start..self.pos].parse::<f32>() {
@@ -192,6 +268,12 @@ impl<'a> Lexer<'a> {
                 }
             }
 
+            '-' if self.peek() == Some('>') => {
+                self.bump();
+                Token::Arrow
+            }
+            '-' => Token::Minus,
+
             c if c.is_alphanumeric() || c == '_' => {
                 while matches!(self.peek(), Some(p) if p.is_alphanumeric() || p == '_') {
                     self.bump();
@@ -254,6 +336,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn lexes_arithmetic_between_numbers() {
+        let mut lexer = Lexer::new("16+2e-1*3 - $x");
+        assert_eq!(lexer.next_token(), Token::Number(16.0));
+        assert_eq!(lexer.next_token(), Token::Plus);
+        assert_eq!(lexer.next_token(), Token::Number(0.2));
+        assert_eq!(lexer.next_token(), Token::Star);
+        assert_eq!(lexer.next_token(), Token::Number(3.0));
+        assert_eq!(lexer.next_token(), Token::Minus);
+        assert_eq!(lexer.next_token(), Token::Dollar);
+    }
+
+    #[test]
+    fn lexes_connect_arrow_and_dot() {
+        let mut lexer = Lexer::new("pressed -> /root/hud.on_press");
+        assert_eq!(lexer.next_token(), Token::Ident("pressed"));
+        assert_eq!(lexer.next_token(), Token::Arrow);
+        assert_eq!(lexer.next_token(), Token::Slash);
+        assert_eq!(lexer.next_token(), Token::Ident("root"));
+        assert_eq!(lexer.next_token(), Token::Slash);
+        assert_eq!(lexer.next_token(), Token::Ident("hud"));
+        assert_eq!(lexer.next_token(), Token::Dot);
+        assert_eq!(lexer.next_token(), Token::Ident("on_press"));
+    }
+
+    #[test]
+    fn lexes_color_literals_in_value_position() {
+        let mut lexer = Lexer::new("tint = #FF8000\nfill = [#11223344, 2] # note");
+        assert_eq!(lexer.next_token(), Token::Ident("tint"));
+        assert_eq!(lexer.next_token(), Token::Equals);
+        assert_eq!(lexer.next_token(), Token::Color([255, 128, 0, 255]));
+        assert_eq!(lexer.next_token(), Token::Ident("fill"));
+        assert_eq!(lexer.next_token(), Token::Equals);
+        assert_eq!(lexer.next_token(), Token::LBracket);
+        assert_eq!(lexer.next_token(), Token::Color([0x11, 0x22, 0x33, 0x44]));
+        assert_eq!(lexer.next_token(), Token::Comma);
+        assert_eq!(lexer.next_token(), Token::Number(2.0));
+        assert_eq!(lexer.next_token(), Token::RBracket);
+        assert_eq!(lexer.next_token(), Token::Eof);
+    }
+
+    #[test]
+    fn hash_after_value_or_without_hex_stays_a_comment() {
+        let mut lexer = Lexer::new("x = 1 #ff8000\ny = #ffff todo");
+        assert_eq!(lexer.next_token(), Token::Ident("x"));
+        assert_eq!(lexer.next_token(), Token::Equals);
+        assert_eq!(lexer.next_token(), Token::Number(1.0));
+        assert_eq!(lexer.next_token(), Token::Ident("y"));
+        assert_eq!(lexer.next_token(), Token::Equals);
+        assert_eq!(lexer.next_token(), Token::Eof);
+    }
+
     #[test]
     fn skips_many_comments_without_recursion() {
         let src = "# comment\n".repeat(100_000) + "done";
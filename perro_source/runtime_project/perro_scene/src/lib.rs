@@ -1,11 +1,16 @@
 mod demo;
+pub mod deps;
 pub mod lexer;
 pub mod node_field_schema;
 pub mod node_fields;
 pub mod node_specs;
 pub mod parser;
+pub mod runtime_scene;
 pub mod scene;
 pub mod scene_doc;
+pub mod scene_patch;
+pub mod scene_writer;
+mod target;
 
 pub use demo::*;
 pub use lexer::*;
@@ -14,8 +19,12 @@ pub use node_fields::*;
 pub use node_specs::*;
 pub use parser::*;
 pub use perro_nodes::NodeType;
+pub use runtime_scene::{compiled_scene_path, decode_scene, encode_scene, is_compiled_scene};
 pub use scene::*;
 pub use scene_doc::*;
+pub use scene_patch::{ScenePatch, ScenePatchOp, apply_patch, diff};
+pub use scene_writer::*;
+pub use target::*;
 
 #[cfg(test)]
 #[path = "../tests/unit/lib_tests.rs"]
@@ -16,6 +16,7 @@ const BODY_2D_REF_TYPES: &[NodeType] = &[
 const BODY_3D_REF_TYPES: &[NodeType] = &[
     NodeType::StaticBody3D,
     NodeType::RigidBody3D,
+    NodeType::VehicleBody3D,
     NodeType::CharacterBody3D,
     NodeType::Area3D,
 ];
@@ -37,6 +38,7 @@ const UI_TEXT_ALIGN_OPTIONS: &[&str] = &["start", "center", "end"];
 const UI_FILL_KIND_OPTIONS: &[&str] = &["solid", "linear"];
 const UI_COLOR_PICKER_MODE_OPTIONS: &[&str] = &["smooth_wheel", "block_wheel", "swatches"];
 const UI_SCROLL_DIRECTION_OPTIONS: &[&str] = &["vertical", "horizontal"];
+const AREA_GRAVITY_MODE_OPTIONS: &[&str] = &["disabled", "combine", "replace"];
 const UI_SCROLL_BAR_SIDE_OPTIONS: &[&str] = &["right", "left", "bottom", "top"];
 const PARTICLE_SIM_MODE_2D_OPTIONS: &[&str] = &["default", "cpu"];
 const PARTICLE_SIM_MODE_3D_OPTIONS: &[&str] = &["default", "cpu", "hybrid", "gpu"];
@@ -63,6 +63,9 @@ pub(super) fn push_base_fields(fields: &mut Vec<SceneNodeField>, node_type: Node
         let mut top_level = SceneNodeField::new("Transform", "top_level", NodeFieldType::Bool);
         top_level.default = Some(SceneValue::Bool(false));
         fields.push(top_level);
+        let mut interpolate = SceneNodeField::new("Transform", "interpolate", NodeFieldType::Bool);
+        interpolate.default = Some(SceneValue::Bool(false));
+        fields.push(interpolate);
         push_default(
             fields,
             node_type,
@@ -70,6 +73,10 @@ pub(super) fn push_base_fields(fields: &mut Vec<SceneNodeField>, node_type: Node
             "visible",
             NodeFieldType::Bool,
         );
+        let mut canvas_layer =
+            SceneNodeField::new("Visibility", "canvas_layer", NodeFieldType::I32);
+        canvas_layer.default = Some(SceneValue::I32(0));
+        fields.push(canvas_layer);
         push_default(
             fields,
             node_type,
@@ -103,6 +110,9 @@ pub(super) fn push_base_fields(fields: &mut Vec<SceneNodeField>, node_type: Node
         let mut top_level = SceneNodeField::new("Transform", "top_level", NodeFieldType::Bool);
         top_level.default = Some(SceneValue::Bool(false));
         fields.push(top_level);
+        let mut interpolate = SceneNodeField::new("Transform", "interpolate", NodeFieldType::Bool);
+        interpolate.default = Some(SceneValue::Bool(false));
+        fields.push(interpolate);
         push_default(
             fields,
             node_type,
@@ -492,6 +502,7 @@ define_scene_node_specs! { fields, node_type;
                 "shape",
                 NodeFieldType::object(Vec::new()),
             );
+            push(fields, "Physics", "one_way", NodeFieldType::Bool);
         }
         NodeType::CollisionShape3D => {
             push(
@@ -512,8 +523,10 @@ define_scene_node_specs! { fields, node_type;
         | NodeType::Area3D
         | NodeType::RigidBody2D
         | NodeType::RigidBody3D
+        | NodeType::VehicleBody3D
         | NodeType::CharacterBody2D
         | NodeType::CharacterBody3D => physics_body_fields(fields, node_type),
+        NodeType::VehicleWheel3D => vehicle_wheel_fields(fields),
         NodeType::PhysicsForceEmitter2D | NodeType::PhysicsForceEmitter3D => {
             push(fields, "Force", "enabled", NodeFieldType::Bool);
             push(
@@ -541,9 +554,11 @@ define_scene_node_specs! { fields, node_type;
         NodeType::PinJoint2D
         | NodeType::DistanceJoint2D
         | NodeType::FixedJoint2D
+        | NodeType::SpringJoint2D
         | NodeType::BallJoint3D
         | NodeType::HingeJoint3D
-        | NodeType::FixedJoint3D => {
+        | NodeType::FixedJoint3D
+        | NodeType::SpringJoint3D => {
             joint_fields(fields, node_type);
         }
         NodeType::AnimationPlayer => {
@@ -725,6 +740,21 @@ define_scene_node_specs! { fields, node_type;
                 NodeFieldType::enumeration(UI_TEXT_ALIGN_OPTIONS),
             );
         }
+        NodeType::UiMarkdown => {
+            push(fields, "Markdown", "source", NodeFieldType::String);
+            push(fields, "Markdown", "markdown", NodeFieldType::String);
+            push(fields, "Markdown", "font", NodeFieldType::String);
+            push(fields, "Markdown", "bold_font", NodeFieldType::String);
+            push(fields, "Markdown", "font_size", NodeFieldType::F32);
+            push(fields, "Markdown", "heading_scale", NodeFieldType::F32);
+            push(fields, "Markdown", "line_spacing", NodeFieldType::F32);
+            push(fields, "Markdown", "paragraph_spacing", NodeFieldType::F32);
+            push(fields, "Markdown", "list_indent", NodeFieldType::F32);
+            push(fields, "Markdown", "text_color", NodeFieldType::Color);
+            push(fields, "Markdown", "bold_color", NodeFieldType::Color);
+            push(fields, "Markdown", "heading_color", NodeFieldType::Color);
+            push(fields, "Markdown", "link_color", NodeFieldType::Color);
+        }
         NodeType::UiScrollContainer => {
             push(fields, "Scroll", "scroll", NodeFieldType::Vec2);
             push(
@@ -740,6 +770,7 @@ define_scene_node_specs! { fields, node_type;
                 NodeFieldType::enumeration(UI_SCROLL_BAR_SIDE_OPTIONS),
             );
             push(fields, "Scroll", "scrollbar_padding", NodeFieldType::F32);
+            push(fields, "Scroll", "drag_scroll", NodeFieldType::Bool);
         }
         NodeType::UiLayout
         | NodeType::UiHLayout
@@ -439,6 +439,7 @@ pub(super) fn physics_body_fields(fields: &mut Vec<SceneNodeField>, node_type: N
             | NodeType::StaticBody3D
             | NodeType::RigidBody2D
             | NodeType::RigidBody3D
+            | NodeType::VehicleBody3D
             | NodeType::CharacterBody2D
             | NodeType::CharacterBody3D
     ) {
@@ -446,7 +447,10 @@ pub(super) fn physics_body_fields(fields: &mut Vec<SceneNodeField>, node_type: N
         push(fields, "Physics", "restitution", NodeFieldType::F32);
         push(fields, "Physics", "density", NodeFieldType::F32);
     }
-    if matches!(node_type, NodeType::RigidBody2D | NodeType::RigidBody3D) {
+    if matches!(
+        node_type,
+        NodeType::RigidBody2D | NodeType::RigidBody3D | NodeType::VehicleBody3D
+    ) {
         push(
             fields,
             "Rigid Body",
@@ -482,6 +486,50 @@ pub(super) fn physics_body_fields(fields: &mut Vec<SceneNodeField>, node_type: N
             push(fields, "Rigid Body", "lock_rotation", NodeFieldType::Bool);
         }
     }
+    if node_type == NodeType::VehicleBody3D {
+        push(fields, "Vehicle", "engine_force", NodeFieldType::F32);
+        push(fields, "Vehicle", "brake", NodeFieldType::F32);
+        push(fields, "Vehicle", "steering", NodeFieldType::F32);
+    }
+    if matches!(node_type, NodeType::Area2D | NodeType::Area3D) {
+        push(
+            fields,
+            "Area",
+            "gravity_mode",
+            NodeFieldType::enumeration(AREA_GRAVITY_MODE_OPTIONS),
+        );
+        push(
+            fields,
+            "Area",
+            "gravity",
+            if node_type.is_3d() {
+                NodeFieldType::Vec3
+            } else {
+                NodeFieldType::Vec2
+            },
+        );
+        push(fields, "Area", "priority", NodeFieldType::I32);
+    }
+    if node_type == NodeType::CharacterBody2D {
+        push(
+            fields,
+            "Character Body",
+            "floor_max_angle",
+            NodeFieldType::F32,
+        );
+        push(
+            fields,
+            "Character Body",
+            "floor_snap_distance",
+            NodeFieldType::F32,
+        );
+        push(
+            fields,
+            "Character Body",
+            "inherit_platform_velocity",
+            NodeFieldType::Bool,
+        );
+    }
 }
 
 pub(super) fn joint_fields(fields: &mut Vec<SceneNodeField>, node_type: NodeType) {
@@ -511,6 +559,8 @@ pub(super) fn joint_fields(fields: &mut Vec<SceneNodeField>, node_type: NodeType
     push(fields, "Joint", "anchor_b", vec_kind);
     push(fields, "Joint", "enabled", NodeFieldType::Bool);
     push(fields, "Joint", "collide_connected", NodeFieldType::Bool);
+    push(fields, "Joint", "break_force", NodeFieldType::F32);
+    push(fields, "Joint", "break_torque", NodeFieldType::F32);
     if node_type == NodeType::DistanceJoint2D {
         push(fields, "Joint", "min_distance", NodeFieldType::F32);
         push(fields, "Joint", "max_distance", NodeFieldType::F32);
@@ -518,6 +568,63 @@ pub(super) fn joint_fields(fields: &mut Vec<SceneNodeField>, node_type: NodeType
     if node_type == NodeType::HingeJoint3D {
         push(fields, "Joint", "axis", NodeFieldType::Vec3);
     }
+    if matches!(node_type, NodeType::PinJoint2D | NodeType::HingeJoint3D) {
+        push(fields, "Joint", "limit_enabled", NodeFieldType::Bool);
+        push(fields, "Joint", "lower_limit", NodeFieldType::F32);
+        push(fields, "Joint", "upper_limit", NodeFieldType::F32);
+        push(fields, "Joint", "motor_enabled", NodeFieldType::Bool);
+        push(fields, "Joint", "motor_target_velocity", NodeFieldType::F32);
+        push(fields, "Joint", "motor_max_force", NodeFieldType::F32);
+    }
+    if matches!(node_type, NodeType::SpringJoint2D | NodeType::SpringJoint3D) {
+        push(fields, "Joint", "rest_length", NodeFieldType::F32);
+        push(fields, "Joint", "stiffness", NodeFieldType::F32);
+        push(fields, "Joint", "damping", NodeFieldType::F32);
+    }
+}
+
+pub(super) fn vehicle_wheel_fields(fields: &mut Vec<SceneNodeField>) {
+    push(fields, "Wheel", "radius", NodeFieldType::F32);
+    push(fields, "Wheel", "steer", NodeFieldType::Bool);
+    push(fields, "Wheel", "drive", NodeFieldType::Bool);
+    push(fields, "Wheel", "friction_slip", NodeFieldType::F32);
+    push(fields, "Wheel", "side_friction", NodeFieldType::F32);
+    push(
+        fields,
+        "Suspension",
+        "suspension_rest_length",
+        NodeFieldType::F32,
+    );
+    push(
+        fields,
+        "Suspension",
+        "suspension_travel",
+        NodeFieldType::F32,
+    );
+    push(
+        fields,
+        "Suspension",
+        "suspension_stiffness",
+        NodeFieldType::F32,
+    );
+    push(
+        fields,
+        "Suspension",
+        "suspension_compression",
+        NodeFieldType::F32,
+    );
+    push(
+        fields,
+        "Suspension",
+        "suspension_damping",
+        NodeFieldType::F32,
+    );
+    push(
+        fields,
+        "Suspension",
+        "max_suspension_force",
+        NodeFieldType::F32,
+    );
 }
 
 pub(super) fn sky_fields(fields: &mut Vec<SceneNodeField>) {
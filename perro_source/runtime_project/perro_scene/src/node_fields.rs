@@ -1,7 +1,7 @@
 use perro_nodes::{
-    Area2D, Area3D, Camera2D, Camera3D, CharacterBody3D, MeshBlendOptions, MeshInstance3D, Node2D,
-    Node3D, NodeType, PhysicsForceEmitter2D, PhysicsForceEmitter3D, RigidBody2D, RigidBody3D,
-    StaticBody2D, StaticBody3D,
+    Area2D, Area3D, Camera2D, Camera3D, CharacterBody2D, CharacterBody3D, MeshBlendOptions,
+    MeshInstance3D, Node2D, Node3D, NodeType, PhysicsForceEmitter2D, PhysicsForceEmitter3D,
+    RigidBody2D, RigidBody3D, StaticBody2D, StaticBody3D, VehicleBody3D, VehicleWheel3D,
 };
 use perro_structs::{BitMask, Color, Quaternion, Vector2, Vector3};
 use perro_ui::{UiNode, UiUnit, UiVector2};
@@ -155,6 +155,14 @@ mod tests {
             ("RigidBody2D", "continuous_collision_detection"),
             ("RigidBody3D", "mass"),
             ("DistanceJoint2D", "body_a"),
+            ("PinJoint2D", "break_force"),
+            ("PinJoint2D", "upper_limit"),
+            ("SpringJoint2D", "stiffness"),
+            ("HingeJoint3D", "motor_target_velocity"),
+            ("SpringJoint3D", "damping"),
+            ("VehicleBody3D", "mass"),
+            ("VehicleBody3D", "engine_force"),
+            ("VehicleWheel3D", "friction_slip"),
             ("MeshInstance3D", "mesh"),
             ("MeshInstance3D", "min_lod"),
             ("Camera3D", "perspective_fov_y_degrees"),
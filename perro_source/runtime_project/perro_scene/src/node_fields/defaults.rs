@@ -37,9 +37,10 @@ pub fn default_node_field_value(field: NodeField) -> Option<SceneValue> {
         NodeField::StaticBody3D(field) => default_static_body_3d_field_value(field),
         NodeField::RigidBody2D(field) => default_rigid_body_2d_field_value(field),
         NodeField::RigidBody3D(field) => default_rigid_body_3d_field_value(field),
-        NodeField::CharacterBody2D(field) | NodeField::CharacterBody3D(field) => {
-            default_character_body_field_value(field)
-        }
+        NodeField::VehicleBody3D(field) => default_vehicle_body_3d_field_value(field),
+        NodeField::VehicleWheel3D(field) => default_vehicle_wheel_3d_field_value(field),
+        NodeField::CharacterBody2D(field) => default_character_body_2d_field_value(field),
+        NodeField::CharacterBody3D(field) => default_character_body_field_value(field),
         NodeField::Area2D(field) => default_area_2d_field_value(field),
         NodeField::Area3D(field) => default_area_3d_field_value(field),
         NodeField::PhysicsForceEmitter2D(field) => {
@@ -93,6 +94,7 @@ pub(super) fn default_ui_node_field_value(field: UiNodeField) -> Option<SceneVal
         UiNodeField::ChildrenModulate => color_value(node.modulate.children_modulate),
         UiNodeField::InputEnabled => SceneValue::Bool(node.input_enabled),
         UiNodeField::ClipChildren => SceneValue::Bool(node.clip_children),
+        UiNodeField::InitialFocus => SceneValue::Bool(node.initial_focus),
         UiNodeField::ZIndex => SceneValue::I32(node.layout.z_index),
     })
 }
@@ -166,8 +168,26 @@ pub(super) fn default_mesh_instance_3d_field_value(
     })
 }
 
+pub(super) fn default_character_body_2d_field_value(
+    field: CharacterBody2DField,
+) -> Option<SceneValue> {
+    let node = CharacterBody2D::default();
+    Some(match field {
+        CharacterBody2DField::Enabled => SceneValue::Bool(node.enabled),
+        CharacterBody2DField::CollisionLayers => bit_mask_value(node.collision_layers),
+        CharacterBody2DField::CollisionMask => bit_mask_value(node.collision_mask),
+        CharacterBody2DField::Friction => SceneValue::F32(node.friction),
+        CharacterBody2DField::Restitution => SceneValue::F32(node.restitution),
+        CharacterBody2DField::Density => SceneValue::F32(node.density),
+        CharacterBody2DField::FloorMaxAngle => SceneValue::F32(node.floor_max_angle),
+        CharacterBody2DField::FloorSnapDistance => SceneValue::F32(node.floor_snap_distance),
+        CharacterBody2DField::InheritPlatformVelocity => {
+            SceneValue::Bool(node.inherit_platform_velocity)
+        }
+    })
+}
+
 pub(super) fn default_character_body_field_value(field: CharacterBodyField) -> Option<SceneValue> {
-    // 2d + 3d char defaults match; use 3d node as source
     let node = CharacterBody3D::default();
     Some(match field {
         CharacterBodyField::Enabled => SceneValue::Bool(node.enabled),
@@ -248,12 +268,45 @@ pub(super) fn default_rigid_body_3d_field_value(field: RigidBody3DField) -> Opti
     })
 }
 
+pub(super) fn default_vehicle_body_3d_field_value(field: VehicleBody3DField) -> Option<SceneValue> {
+    let node = VehicleBody3D::default();
+    Some(match field {
+        VehicleBody3DField::Body(RigidBody3DField::Mass) => SceneValue::F32(node.mass),
+        VehicleBody3DField::Body(field) => return default_rigid_body_3d_field_value(field),
+        VehicleBody3DField::EngineForce => SceneValue::F32(node.engine_force),
+        VehicleBody3DField::Brake => SceneValue::F32(node.brake),
+        VehicleBody3DField::Steering => SceneValue::F32(node.steering),
+    })
+}
+
+pub(super) fn default_vehicle_wheel_3d_field_value(
+    field: VehicleWheel3DField,
+) -> Option<SceneValue> {
+    let node = VehicleWheel3D::default();
+    Some(match field {
+        VehicleWheel3DField::Radius => SceneValue::F32(node.radius),
+        VehicleWheel3DField::SuspensionRestLength => SceneValue::F32(node.suspension_rest_length),
+        VehicleWheel3DField::SuspensionTravel => SceneValue::F32(node.suspension_travel),
+        VehicleWheel3DField::SuspensionStiffness => SceneValue::F32(node.suspension_stiffness),
+        VehicleWheel3DField::SuspensionCompression => SceneValue::F32(node.suspension_compression),
+        VehicleWheel3DField::SuspensionDamping => SceneValue::F32(node.suspension_damping),
+        VehicleWheel3DField::MaxSuspensionForce => SceneValue::F32(node.max_suspension_force),
+        VehicleWheel3DField::FrictionSlip => SceneValue::F32(node.friction_slip),
+        VehicleWheel3DField::SideFriction => SceneValue::F32(node.side_friction),
+        VehicleWheel3DField::Steer => SceneValue::Bool(node.steer),
+        VehicleWheel3DField::Drive => SceneValue::Bool(node.drive),
+    })
+}
+
 pub(super) fn default_area_2d_field_value(field: Area2DField) -> Option<SceneValue> {
     let node = Area2D::default();
     Some(match field {
         Area2DField::Enabled => SceneValue::Bool(node.enabled),
         Area2DField::CollisionLayers => bit_mask_value(node.collision_layers),
         Area2DField::CollisionMask => bit_mask_value(node.collision_mask),
+        Area2DField::GravityMode => SceneValue::Key("disabled".to_string().into()),
+        Area2DField::Gravity => vec2_value(node.gravity),
+        Area2DField::Priority => SceneValue::I32(node.priority),
     })
 }
 
@@ -263,6 +316,9 @@ pub(super) fn default_area_3d_field_value(field: Area3DField) -> Option<SceneVal
         Area3DField::Enabled => SceneValue::Bool(node.enabled),
         Area3DField::CollisionLayers => bit_mask_value(node.collision_layers),
         Area3DField::CollisionMask => bit_mask_value(node.collision_mask),
+        Area3DField::GravityMode => SceneValue::Key("disabled".to_string().into()),
+        Area3DField::Gravity => vec3_value(node.gravity),
+        Area3DField::Priority => SceneValue::I32(node.priority),
     })
 }
 
@@ -209,19 +209,29 @@ pub(in super::super) fn resolve_scene_node_field_for_type(
             SceneFieldName::Shape => {
                 Some(NodeField::CollisionShape2D(CollisionShape2DField::Shape))
             }
+            SceneFieldName::OneWay => {
+                Some(NodeField::CollisionShape2D(CollisionShape2DField::OneWay))
+            }
             _ => None,
         },
         NodeType::StaticBody2D => resolve_scene_static_body_2d(field).map(NodeField::StaticBody2D),
         NodeType::RigidBody2D => resolve_scene_rigid_body_2d(field).map(NodeField::RigidBody2D),
         NodeType::CharacterBody2D => {
-            resolve_scene_character_body(field).map(NodeField::CharacterBody2D)
+            resolve_scene_character_body_2d(field).map(NodeField::CharacterBody2D)
         }
         NodeType::PhysicsForceEmitter2D => {
             resolve_scene_physics_force_emitter(field).map(NodeField::PhysicsForceEmitter2D)
         }
         NodeType::Area2D => resolve_scene_area_2d(field).map(NodeField::Area2D),
-        NodeType::PinJoint2D => resolve_scene_joint2d_common(field).map(NodeField::PinJoint2D),
+        NodeType::PinJoint2D => resolve_scene_joint2d_common(field)
+            .map(PinJoint2DField::Common)
+            .or_else(|| resolve_joint_limit_motor(field.as_ref()).map(PinJoint2DField::LimitMotor))
+            .map(NodeField::PinJoint2D),
         NodeType::FixedJoint2D => resolve_scene_joint2d_common(field).map(NodeField::FixedJoint2D),
+        NodeType::SpringJoint2D => resolve_scene_joint2d_common(field)
+            .map(SpringJoint2DField::Common)
+            .or_else(|| resolve_joint_spring(field.as_ref()).map(SpringJoint2DField::Spring))
+            .map(NodeField::SpringJoint2D),
         NodeType::DistanceJoint2D => match field {
             SceneFieldName::MinDistance => Some(NodeField::DistanceJoint2D(
                 DistanceJoint2DField::MinDistance,
@@ -435,8 +445,22 @@ pub(in super::super) fn resolve_scene_node_field_for_type(
             SceneFieldName::Axis => Some(NodeField::HingeJoint3D(HingeJoint3DField::Axis)),
             _ => resolve_scene_joint3d_common(field)
                 .map(HingeJoint3DField::Common)
+                .or_else(|| {
+                    resolve_joint_limit_motor(field.as_ref()).map(HingeJoint3DField::LimitMotor)
+                })
                 .map(NodeField::HingeJoint3D),
         },
+        NodeType::SpringJoint3D => resolve_scene_joint3d_common(field)
+            .map(SpringJoint3DField::Common)
+            .or_else(|| resolve_joint_spring(field.as_ref()).map(SpringJoint3DField::Spring))
+            .map(NodeField::SpringJoint3D),
+        NodeType::VehicleBody3D => resolve_scene_rigid_body_3d(field)
+            .map(VehicleBody3DField::Body)
+            .or_else(|| resolve_vehicle_body_3d(field.as_ref()))
+            .map(NodeField::VehicleBody3D),
+        NodeType::VehicleWheel3D => {
+            resolve_vehicle_wheel_3d(field.as_ref()).map(NodeField::VehicleWheel3D)
+        }
         NodeType::UiImage
         | NodeType::UiImageButton
         | NodeType::UiNineSliceButton
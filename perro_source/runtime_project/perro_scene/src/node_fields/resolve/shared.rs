@@ -10,7 +10,7 @@ pub(in super::super) fn resolve_scene_joint2d_common(
         SceneFieldName::AnchorB => Some(Joint2DField::AnchorB),
         SceneFieldName::Enabled => Some(Joint2DField::Enabled),
         SceneFieldName::CollideConnected => Some(Joint2DField::CollideConnected),
-        _ => None,
+        _ => resolve_joint2d_common(field.as_ref()),
     }
 }
 
@@ -24,7 +24,7 @@ pub(in super::super) fn resolve_scene_joint3d_common(
         SceneFieldName::AnchorB => Some(Joint3DField::AnchorB),
         SceneFieldName::Enabled => Some(Joint3DField::Enabled),
         SceneFieldName::CollideConnected => Some(Joint3DField::CollideConnected),
-        _ => None,
+        _ => resolve_joint3d_common(field.as_ref()),
     }
 }
 
@@ -213,6 +213,52 @@ pub(in super::super) fn resolve_scene_area_2d(field: &SceneFieldName) -> Option<
         SceneFieldName::Enabled => Some(Area2DField::Enabled),
         SceneFieldName::CollisionLayers => Some(Area2DField::CollisionLayers),
         SceneFieldName::CollisionMask => Some(Area2DField::CollisionMask),
+        _ => resolve_area_2d(field.as_ref()),
+    }
+}
+
+pub(in super::super) fn resolve_area_2d(field: &str) -> Option<Area2DField> {
+    match field {
+        "enabled" => Some(Area2DField::Enabled),
+        "collision_layers" => Some(Area2DField::CollisionLayers),
+        "collision_mask" => Some(Area2DField::CollisionMask),
+        "gravity_mode" => Some(Area2DField::GravityMode),
+        "gravity" => Some(Area2DField::Gravity),
+        "priority" => Some(Area2DField::Priority),
+        _ => None,
+    }
+}
+
+pub(in super::super) fn resolve_scene_character_body_2d(
+    field: &SceneFieldName,
+) -> Option<CharacterBody2DField> {
+    match field {
+        SceneFieldName::Enabled => Some(CharacterBody2DField::Enabled),
+        SceneFieldName::CollisionLayers => Some(CharacterBody2DField::CollisionLayers),
+        SceneFieldName::CollisionMask => Some(CharacterBody2DField::CollisionMask),
+        SceneFieldName::Friction => Some(CharacterBody2DField::Friction),
+        SceneFieldName::Restitution => Some(CharacterBody2DField::Restitution),
+        SceneFieldName::Density => Some(CharacterBody2DField::Density),
+        SceneFieldName::FloorMaxAngle => Some(CharacterBody2DField::FloorMaxAngle),
+        SceneFieldName::FloorSnapDistance => Some(CharacterBody2DField::FloorSnapDistance),
+        SceneFieldName::InheritPlatformVelocity => {
+            Some(CharacterBody2DField::InheritPlatformVelocity)
+        }
+        _ => resolve_character_body_2d(field.as_ref()),
+    }
+}
+
+pub(in super::super) fn resolve_character_body_2d(field: &str) -> Option<CharacterBody2DField> {
+    match field {
+        "enabled" => Some(CharacterBody2DField::Enabled),
+        "collision_layers" => Some(CharacterBody2DField::CollisionLayers),
+        "collision_mask" => Some(CharacterBody2DField::CollisionMask),
+        "friction" => Some(CharacterBody2DField::Friction),
+        "restitution" => Some(CharacterBody2DField::Restitution),
+        "density" => Some(CharacterBody2DField::Density),
+        "floor_max_angle" => Some(CharacterBody2DField::FloorMaxAngle),
+        "floor_snap_distance" => Some(CharacterBody2DField::FloorSnapDistance),
+        "inherit_platform_velocity" => Some(CharacterBody2DField::InheritPlatformVelocity),
         _ => None,
     }
 }
@@ -248,6 +294,66 @@ pub(in super::super) fn resolve_scene_area_3d(field: &SceneFieldName) -> Option<
         SceneFieldName::Enabled => Some(Area3DField::Enabled),
         SceneFieldName::CollisionLayers => Some(Area3DField::CollisionLayers),
         SceneFieldName::CollisionMask => Some(Area3DField::CollisionMask),
+        _ => resolve_area_3d(field.as_ref()),
+    }
+}
+
+pub(in super::super) fn resolve_rigid_body_3d(field: &str) -> Option<RigidBody3DField> {
+    match field {
+        "enabled" => Some(RigidBody3DField::Enabled),
+        "collision_layers" => Some(RigidBody3DField::CollisionLayers),
+        "collision_mask" => Some(RigidBody3DField::CollisionMask),
+        "continuous_collision_detection" | "ccd" => {
+            Some(RigidBody3DField::ContinuousCollisionDetection)
+        }
+        "mass" => Some(RigidBody3DField::Mass),
+        "linear_velocity" | "velocity" => Some(RigidBody3DField::LinearVelocity),
+        "angular_velocity" => Some(RigidBody3DField::AngularVelocity),
+        "gravity_scale" => Some(RigidBody3DField::GravityScale),
+        "linear_damping" => Some(RigidBody3DField::LinearDamping),
+        "angular_damping" => Some(RigidBody3DField::AngularDamping),
+        "can_sleep" => Some(RigidBody3DField::CanSleep),
+        "friction" => Some(RigidBody3DField::Friction),
+        "restitution" => Some(RigidBody3DField::Restitution),
+        "density" => Some(RigidBody3DField::Density),
+        _ => None,
+    }
+}
+
+pub(in super::super) fn resolve_vehicle_body_3d(field: &str) -> Option<VehicleBody3DField> {
+    match field {
+        "engine_force" => Some(VehicleBody3DField::EngineForce),
+        "brake" => Some(VehicleBody3DField::Brake),
+        "steering" => Some(VehicleBody3DField::Steering),
+        _ => resolve_rigid_body_3d(field).map(VehicleBody3DField::Body),
+    }
+}
+
+pub(in super::super) fn resolve_vehicle_wheel_3d(field: &str) -> Option<VehicleWheel3DField> {
+    match field {
+        "radius" => Some(VehicleWheel3DField::Radius),
+        "suspension_rest_length" | "rest_length" => Some(VehicleWheel3DField::SuspensionRestLength),
+        "suspension_travel" => Some(VehicleWheel3DField::SuspensionTravel),
+        "suspension_stiffness" | "stiffness" => Some(VehicleWheel3DField::SuspensionStiffness),
+        "suspension_compression" => Some(VehicleWheel3DField::SuspensionCompression),
+        "suspension_damping" | "damping" => Some(VehicleWheel3DField::SuspensionDamping),
+        "max_suspension_force" => Some(VehicleWheel3DField::MaxSuspensionForce),
+        "friction_slip" => Some(VehicleWheel3DField::FrictionSlip),
+        "side_friction" => Some(VehicleWheel3DField::SideFriction),
+        "steer" => Some(VehicleWheel3DField::Steer),
+        "drive" => Some(VehicleWheel3DField::Drive),
+        _ => None,
+    }
+}
+
+pub(in super::super) fn resolve_area_3d(field: &str) -> Option<Area3DField> {
+    match field {
+        "enabled" => Some(Area3DField::Enabled),
+        "collision_layers" => Some(Area3DField::CollisionLayers),
+        "collision_mask" => Some(Area3DField::CollisionMask),
+        "gravity_mode" => Some(Area3DField::GravityMode),
+        "gravity" => Some(Area3DField::Gravity),
+        "priority" => Some(Area3DField::Priority),
         _ => None,
     }
 }
@@ -392,6 +498,8 @@ pub(in super::super) fn resolve_joint2d_common(field: &str) -> Option<Joint2DFie
         "anchor_b" => Some(Joint2DField::AnchorB),
         "enabled" => Some(Joint2DField::Enabled),
         "collide_connected" | "collision" => Some(Joint2DField::CollideConnected),
+        "break_force" => Some(Joint2DField::BreakForce),
+        "break_torque" => Some(Joint2DField::BreakTorque),
         _ => None,
     }
 }
@@ -404,6 +512,29 @@ pub(in super::super) fn resolve_joint3d_common(field: &str) -> Option<Joint3DFie
         "anchor_b" => Some(Joint3DField::AnchorB),
         "enabled" => Some(Joint3DField::Enabled),
         "collide_connected" | "collision" => Some(Joint3DField::CollideConnected),
+        "break_force" => Some(Joint3DField::BreakForce),
+        "break_torque" => Some(Joint3DField::BreakTorque),
+        _ => None,
+    }
+}
+
+pub(in super::super) fn resolve_joint_limit_motor(field: &str) -> Option<JointLimitMotorField> {
+    match field {
+        "limit_enabled" => Some(JointLimitMotorField::LimitEnabled),
+        "lower_limit" => Some(JointLimitMotorField::LowerLimit),
+        "upper_limit" => Some(JointLimitMotorField::UpperLimit),
+        "motor_enabled" => Some(JointLimitMotorField::MotorEnabled),
+        "motor_target_velocity" => Some(JointLimitMotorField::MotorTargetVelocity),
+        "motor_max_force" => Some(JointLimitMotorField::MotorMaxForce),
+        _ => None,
+    }
+}
+
+pub(in super::super) fn resolve_joint_spring(field: &str) -> Option<JointSpringField> {
+    match field {
+        "rest_length" | "length" => Some(JointSpringField::RestLength),
+        "stiffness" => Some(JointSpringField::Stiffness),
+        "damping" => Some(JointSpringField::Damping),
         _ => None,
     }
 }
@@ -513,6 +644,7 @@ pub(in super::super) fn resolve_base_node_field(
             }
             "input_enabled" => Some(NodeField::UiNode(UiNodeField::InputEnabled)),
             "clip_children" => Some(NodeField::UiNode(UiNodeField::ClipChildren)),
+            "initial_focus" => Some(NodeField::UiNode(UiNodeField::InitialFocus)),
             "z_index" => Some(NodeField::UiNode(UiNodeField::ZIndex)),
             _ => None,
         };
@@ -192,6 +192,7 @@ pub(in super::super) fn resolve_node_field_for_type(
         NodeType::WaterBody2D => resolve_water_body(field).map(NodeField::WaterBody2D),
         NodeType::CollisionShape2D => match field {
             "shape" => Some(NodeField::CollisionShape2D(CollisionShape2DField::Shape)),
+            "one_way" => Some(NodeField::CollisionShape2D(CollisionShape2DField::OneWay)),
             _ => None,
         },
         NodeType::StaticBody2D => match field {
@@ -225,18 +226,22 @@ pub(in super::super) fn resolve_node_field_for_type(
             "density" => Some(NodeField::RigidBody2D(RigidBody2DField::Density)),
             _ => None,
         },
-        NodeType::CharacterBody2D => resolve_character_body(field).map(NodeField::CharacterBody2D),
+        NodeType::CharacterBody2D => {
+            resolve_character_body_2d(field).map(NodeField::CharacterBody2D)
+        }
         NodeType::PhysicsForceEmitter2D => {
             resolve_physics_force_emitter(field).map(NodeField::PhysicsForceEmitter2D)
         }
-        NodeType::Area2D => match field {
-            "enabled" => Some(NodeField::Area2D(Area2DField::Enabled)),
-            "collision_layers" => Some(NodeField::Area2D(Area2DField::CollisionLayers)),
-            "collision_mask" => Some(NodeField::Area2D(Area2DField::CollisionMask)),
-            _ => None,
-        },
-        NodeType::PinJoint2D => resolve_joint2d_common(field).map(NodeField::PinJoint2D),
+        NodeType::Area2D => resolve_area_2d(field).map(NodeField::Area2D),
+        NodeType::PinJoint2D => resolve_joint2d_common(field)
+            .map(PinJoint2DField::Common)
+            .or_else(|| resolve_joint_limit_motor(field).map(PinJoint2DField::LimitMotor))
+            .map(NodeField::PinJoint2D),
         NodeType::FixedJoint2D => resolve_joint2d_common(field).map(NodeField::FixedJoint2D),
+        NodeType::SpringJoint2D => resolve_joint2d_common(field)
+            .map(SpringJoint2DField::Common)
+            .or_else(|| resolve_joint_spring(field).map(SpringJoint2DField::Spring))
+            .map(NodeField::SpringJoint2D),
         NodeType::DistanceJoint2D => match field {
             "min_distance" | "min" => Some(NodeField::DistanceJoint2D(
                 DistanceJoint2DField::MinDistance,
@@ -560,45 +565,27 @@ pub(in super::super) fn resolve_node_field_for_type(
             "density" => Some(NodeField::StaticBody3D(StaticBody3DField::Density)),
             _ => None,
         },
-        NodeType::RigidBody3D => match field {
-            "enabled" => Some(NodeField::RigidBody3D(RigidBody3DField::Enabled)),
-            "collision_layers" => Some(NodeField::RigidBody3D(RigidBody3DField::CollisionLayers)),
-            "collision_mask" => Some(NodeField::RigidBody3D(RigidBody3DField::CollisionMask)),
-            "continuous_collision_detection" | "ccd" => Some(NodeField::RigidBody3D(
-                RigidBody3DField::ContinuousCollisionDetection,
-            )),
-            "mass" => Some(NodeField::RigidBody3D(RigidBody3DField::Mass)),
-            "linear_velocity" | "velocity" => {
-                Some(NodeField::RigidBody3D(RigidBody3DField::LinearVelocity))
-            }
-            "angular_velocity" => Some(NodeField::RigidBody3D(RigidBody3DField::AngularVelocity)),
-            "gravity_scale" => Some(NodeField::RigidBody3D(RigidBody3DField::GravityScale)),
-            "linear_damping" => Some(NodeField::RigidBody3D(RigidBody3DField::LinearDamping)),
-            "angular_damping" => Some(NodeField::RigidBody3D(RigidBody3DField::AngularDamping)),
-            "can_sleep" => Some(NodeField::RigidBody3D(RigidBody3DField::CanSleep)),
-            "friction" => Some(NodeField::RigidBody3D(RigidBody3DField::Friction)),
-            "restitution" => Some(NodeField::RigidBody3D(RigidBody3DField::Restitution)),
-            "density" => Some(NodeField::RigidBody3D(RigidBody3DField::Density)),
-            _ => None,
-        },
+        NodeType::RigidBody3D => resolve_rigid_body_3d(field).map(NodeField::RigidBody3D),
         NodeType::CharacterBody3D => resolve_character_body(field).map(NodeField::CharacterBody3D),
         NodeType::PhysicsForceEmitter3D => {
             resolve_physics_force_emitter(field).map(NodeField::PhysicsForceEmitter3D)
         }
-        NodeType::Area3D => match field {
-            "enabled" => Some(NodeField::Area3D(Area3DField::Enabled)),
-            "collision_layers" => Some(NodeField::Area3D(Area3DField::CollisionLayers)),
-            "collision_mask" => Some(NodeField::Area3D(Area3DField::CollisionMask)),
-            _ => None,
-        },
+        NodeType::Area3D => resolve_area_3d(field).map(NodeField::Area3D),
         NodeType::BallJoint3D => resolve_joint3d_common(field).map(NodeField::BallJoint3D),
         NodeType::FixedJoint3D => resolve_joint3d_common(field).map(NodeField::FixedJoint3D),
         NodeType::HingeJoint3D => match field {
             "axis" => Some(NodeField::HingeJoint3D(HingeJoint3DField::Axis)),
             _ => resolve_joint3d_common(field)
                 .map(HingeJoint3DField::Common)
+                .or_else(|| resolve_joint_limit_motor(field).map(HingeJoint3DField::LimitMotor))
                 .map(NodeField::HingeJoint3D),
         },
+        NodeType::SpringJoint3D => resolve_joint3d_common(field)
+            .map(SpringJoint3DField::Common)
+            .or_else(|| resolve_joint_spring(field).map(SpringJoint3DField::Spring))
+            .map(NodeField::SpringJoint3D),
+        NodeType::VehicleBody3D => resolve_vehicle_body_3d(field).map(NodeField::VehicleBody3D),
+        NodeType::VehicleWheel3D => resolve_vehicle_wheel_3d(field).map(NodeField::VehicleWheel3D),
         NodeType::UiImage
         | NodeType::UiImageButton
         | NodeType::UiNineSliceButton
@@ -27,12 +27,13 @@ pub enum NodeField {
     CollisionShape2D(CollisionShape2DField),
     StaticBody2D(StaticBody2DField),
     RigidBody2D(RigidBody2DField),
-    CharacterBody2D(CharacterBodyField),
+    CharacterBody2D(CharacterBody2DField),
     PhysicsForceEmitter2D(PhysicsForceEmitterField),
     Area2D(Area2DField),
-    PinJoint2D(Joint2DField),
+    PinJoint2D(PinJoint2DField),
     DistanceJoint2D(DistanceJoint2DField),
     FixedJoint2D(Joint2DField),
+    SpringJoint2D(SpringJoint2DField),
     MeshInstance3D(MeshInstance3DField),
     Skeleton3D(Skeleton3DField),
     BoneAttachment3D(BoneAttachment3DField),
@@ -58,6 +59,9 @@ pub enum NodeField {
     BallJoint3D(Joint3DField),
     HingeJoint3D(HingeJoint3DField),
     FixedJoint3D(Joint3DField),
+    SpringJoint3D(SpringJoint3DField),
+    VehicleBody3D(VehicleBody3DField),
+    VehicleWheel3D(VehicleWheel3DField),
     UiNode(UiNodeField),
     UiImage(UiImageField),
     UiImageButton(UiImageField),
@@ -128,6 +132,7 @@ pub enum UiNodeField {
     ChildrenModulate,
     InputEnabled,
     ClipChildren,
+    InitialFocus,
     ZIndex,
 }
 
@@ -284,6 +289,7 @@ pub enum Skeleton2DField {
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CollisionShape2DField {
     Shape,
+    OneWay,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -315,11 +321,27 @@ pub enum RigidBody2DField {
     Density,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CharacterBody2DField {
+    Enabled,
+    CollisionLayers,
+    CollisionMask,
+    Friction,
+    Restitution,
+    Density,
+    FloorMaxAngle,
+    FloorSnapDistance,
+    InheritPlatformVelocity,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Area2DField {
     Enabled,
     CollisionLayers,
     CollisionMask,
+    GravityMode,
+    Gravity,
+    Priority,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -346,6 +368,14 @@ pub enum Joint2DField {
     AnchorB,
     Enabled,
     CollideConnected,
+    BreakForce,
+    BreakTorque,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PinJoint2DField {
+    Common(Joint2DField),
+    LimitMotor(JointLimitMotorField),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -355,6 +385,30 @@ pub enum DistanceJoint2DField {
     MaxDistance,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpringJoint2DField {
+    Common(Joint2DField),
+    Spring(JointSpringField),
+}
+
+/// Angle limit + motor fields shared by PinJoint2D and HingeJoint3D.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JointLimitMotorField {
+    LimitEnabled,
+    LowerLimit,
+    UpperLimit,
+    MotorEnabled,
+    MotorTargetVelocity,
+    MotorMaxForce,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JointSpringField {
+    RestLength,
+    Stiffness,
+    Damping,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum MeshInstance3DField {
     Mesh,
@@ -615,6 +669,9 @@ pub enum Area3DField {
     Enabled,
     CollisionLayers,
     CollisionMask,
+    GravityMode,
+    Gravity,
+    Priority,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -625,12 +682,44 @@ pub enum Joint3DField {
     AnchorB,
     Enabled,
     CollideConnected,
+    BreakForce,
+    BreakTorque,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum HingeJoint3DField {
     Common(Joint3DField),
     Axis,
+    LimitMotor(JointLimitMotorField),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpringJoint3DField {
+    Common(Joint3DField),
+    Spring(JointSpringField),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VehicleBody3DField {
+    Body(RigidBody3DField),
+    EngineForce,
+    Brake,
+    Steering,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VehicleWheel3DField {
+    Radius,
+    SuspensionRestLength,
+    SuspensionTravel,
+    SuspensionStiffness,
+    SuspensionCompression,
+    SuspensionDamping,
+    MaxSuspensionForce,
+    FrictionSlip,
+    SideFriction,
+    Steer,
+    Drive,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
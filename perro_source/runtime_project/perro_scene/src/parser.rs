@@ -1,24 +1,107 @@
 // parser.rs - Parse into scene types
 use crate::{
-    Lexer, NodeFieldType, Scene, SceneFieldName, SceneKey, SceneNodeData, SceneNodeDataBase,
-    SceneNodeEntry, SceneObjectField, SceneValue, SceneValueKey, Token, scene_node_spec,
+    Lexer, NodeFieldType, Scene, SceneConnection, SceneFieldName, SceneKey, SceneNodeData,
+    SceneNodeDataBase, SceneNodeEntry, SceneObjectField, SceneTarget, SceneValue, SceneValueKey,
+    Span, Token, scene_node_spec,
 };
 use perro_nodes::NodeType;
 use perro_structs::Quaternion;
 use std::borrow::Cow;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
 use std::str::FromStr;
 
 type ParseResult<T> = Result<T, String>;
 
+/// Loads the source text of a scene named by `@extends` or `@include`.
+pub type SceneSourceLoader<'a> = &'a dyn Fn(&str) -> Result<String, String>;
+
 const MAX_SCENE_VALUE_DEPTH: usize = 128;
+const MAX_NODE_BASE_DEPTH: usize = 64;
+const MAX_SCENE_EXTENDS_DEPTH: usize = 32;
+const MAX_SCENE_INCLUDE_DEPTH: usize = 32;
+const UNCLOSED_GUARD: &str = "`@if` guard is missing its `@end`";
+// `$b = [$a, $a]` chains double per var; cap total values copied out of vars.
+const MAX_SCENE_VAR_EXPANSION: usize = 1 << 20;
+
+/// Scene parse error, at the token the parser stopped on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SceneParseError {
+    pub message: String,
+    pub span: Span,
+    /// 1-based.
+    pub line: usize,
+    /// 1-based, in chars.
+    pub column: usize,
+}
+
+impl SceneParseError {
+    pub fn new(src: &str, span: Span, message: impl Into<String>) -> Self {
+        let before = src.get(..span.start).unwrap_or(src);
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rsplit('\n')
+            .next()
+            .map_or(0, |line| line.chars().count())
+            + 1;
+        Self {
+            message: message.into(),
+            span,
+            line,
+            column,
+        }
+    }
+}
+
+impl fmt::Display for SceneParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for SceneParseError {}
+
+/// One located problem reported by [`Parser::parse_scene_checked`].
+pub type SceneDiagnostic = SceneParseError;
 
 pub struct Parser<'a> {
     src: &'a str,
     lexer: Lexer<'a>,
     current: Token<'a>,
     vars: HashMap<String, SceneValue>,
+    var_expansion: usize,
     lenient_separators: bool,
+    scene_loader: Option<SceneSourceLoader<'a>>,
+    extends_chain: Vec<String>,
+    include_chain: Vec<String>,
+    /// Platform / features `@if` guards test against.
+    target: SceneTarget,
+    /// `@if` guards entered and not yet closed by `@end`.
+    open_guards: usize,
+    /// End of the last consumed token.
+    last_end: usize,
+    /// Filled only for [`SceneWriter`](crate::SceneWriter) parses.
+    spans: Option<SceneSpans>,
+}
+
+/// Source spans of a scene file, so a writer can patch values in place.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SceneSpans {
+    /// Value of `$root = @Key`.
+    pub root: Option<Span>,
+    pub nodes: Vec<SceneNodeSpans>,
+}
+
+/// Spans of one top-level node block.
+#[derive(Debug, Clone)]
+pub(crate) struct SceneNodeSpans {
+    pub key: String,
+    /// `[Key]` through its closing `]`.
+    pub block: Span,
+    /// Header field name -> value span (`name`, `parent`, `script_vars`, ...).
+    pub header: Vec<(String, Span)>,
+    /// Outermost type block field name, as written -> value span.
+    pub fields: Vec<(String, Span)>,
 }
 
 impl<'a> Parser<'a> {
@@ -30,10 +113,38 @@ impl<'a> Parser<'a> {
             lexer,
             current,
             vars: HashMap::new(),
+            var_expansion: 0,
             lenient_separators: false,
+            scene_loader: None,
+            extends_chain: Vec::new(),
+            include_chain: Vec::new(),
+            target: SceneTarget::host(),
+            open_guards: 0,
+            last_end: 0,
+            spans: None,
         }
     }
 
+    /// Resolves `@extends = "res://base.scn"` and `@include "res://part.scn"`
+    /// through `loader`.
+    ///
+    /// Without a loader, scenes that use either fail to parse.
+    pub fn with_scene_loader(mut self, loader: SceneSourceLoader<'a>) -> Self {
+        self.scene_loader = Some(loader);
+        self
+    }
+
+    /// Platform and features for `@if` guards. Defaults to
+    /// [`SceneTarget::host`].
+    pub fn with_target(mut self, target: SceneTarget) -> Self {
+        self.target = target;
+        self
+    }
+
+    pub(crate) fn src(&self) -> &'a str {
+        self.src
+    }
+
     pub(crate) fn new_lenient(src: &'a str) -> Self {
         let mut parser = Self::new(src);
         parser.lenient_separators = true;
@@ -41,9 +152,14 @@ impl<'a> Parser<'a> {
     }
 
     fn advance(&mut self) {
+        self.last_end = self.lexer.token_span().end;
         self.current = self.lexer.next_token();
     }
 
+    fn located<T>(&self, result: ParseResult<T>) -> Result<T, SceneParseError> {
+        result.map_err(|message| SceneParseError::new(self.src, self.lexer.token_span(), message))
+    }
+
     fn expect(&mut self, t: Token<'a>) -> ParseResult<()> {
         if self.current != t {
             return Err(format!("Expected {:?}, got {:?}", t, self.current));
@@ -62,6 +178,40 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Rest of a `connect signal -> @key.method` or
+    /// `connect signal -> /root/a/b.method` line.
+    fn parse_connection(&mut self) -> ParseResult<SceneConnection> {
+        const USAGE: &str = "connect must look like `connect signal -> @node.method`";
+        let signal = self.expect_ident().map_err(|_| USAGE.to_string())?;
+        self.expect(Token::Arrow).map_err(|_| USAGE.to_string())?;
+        let mut target = String::new();
+        if self.current == Token::At {
+            self.advance();
+            target.push('@');
+            target.push_str(self.expect_ident()?);
+        } else if self.current == Token::Slash {
+            while self.current == Token::Slash {
+                self.advance();
+                target.push('/');
+                target.push_str(self.expect_ident()?);
+            }
+            if target != "/root" && !target.starts_with("/root/") {
+                return Err(format!(
+                    "connect path `{target}` must start at `/root`, the scene root"
+                ));
+            }
+        } else {
+            return Err(USAGE.to_string());
+        }
+        self.expect(Token::Dot).map_err(|_| USAGE.to_string())?;
+        let method = self.expect_ident().map_err(|_| USAGE.to_string())?;
+        Ok(SceneConnection {
+            signal: Cow::Owned(signal.to_string()),
+            target: Cow::Owned(target),
+            method: Cow::Owned(method.to_string()),
+        })
+    }
+
     fn expect_scene_key(&mut self) -> ParseResult<Cow<'a, str>> {
         let mut at_count = 0;
         while self.current == Token::At {
@@ -94,7 +244,7 @@ impl<'a> Parser<'a> {
         Ok(key)
     }
 
-    fn try_collect_vars(mut self) -> ParseResult<HashMap<String, SceneValue>> {
+    fn try_collect_vars(mut self) -> Result<HashMap<String, SceneValue>, SceneParseError> {
         Ok(self.try_collect_var_entries()?.into_iter().collect())
     }
 
@@ -103,12 +253,29 @@ impl<'a> Parser<'a> {
             .unwrap_or_else(|err| panic!("{err}"))
     }
 
-    pub(crate) fn try_collect_var_entries(&mut self) -> ParseResult<Vec<(String, SceneValue)>> {
+    pub(crate) fn try_collect_var_entries(
+        &mut self,
+    ) -> Result<Vec<(String, SceneValue)>, SceneParseError> {
+        let result = self.collect_var_entries_inner();
+        self.located(result)
+    }
+
+    fn collect_var_entries_inner(&mut self) -> ParseResult<Vec<(String, SceneValue)>> {
         let mut vars = Vec::new();
         while self.current != Token::Eof {
             if let Token::Error(err) = &self.current {
                 return Err(err.to_string());
             }
+            if self.current == Token::At {
+                self.advance();
+                if self.current == Token::Ident("if") {
+                    self.advance();
+                    if !self.parse_guard_condition()? {
+                        self.skip_guarded_items()?;
+                    }
+                }
+                continue;
+            }
             if self.current == Token::Dollar {
                 self.advance();
                 let name = self.expect_ident()?.to_string();
@@ -136,6 +303,81 @@ impl<'a> Parser<'a> {
             ));
         }
 
+        let value = self.parse_value_atom(depth)?;
+        if !self.at_arith_op() {
+            return Ok(value);
+        }
+        let Some(first) = arith_operand(&value) else {
+            return Ok(value);
+        };
+        Ok(SceneValue::F32(self.parse_sum_tail(first, depth)?))
+    }
+
+    /// `+`, `-`, `*`, `/`, or a negative literal read as `- n` (`$x -16`).
+    fn at_arith_op(&self) -> bool {
+        match self.current {
+            Token::Plus | Token::Minus | Token::Star | Token::Slash => true,
+            Token::Number(n) => n.is_sign_negative(),
+            _ => false,
+        }
+    }
+
+    /// Folds `first (+|-) term ...`; terms bind `*` and `/` tighter.
+    fn parse_sum_tail(&mut self, first: f32, depth: usize) -> ParseResult<f32> {
+        let mut acc = self.parse_product_tail(first, depth)?;
+        loop {
+            let sign = match self.current {
+                Token::Plus => 1.0,
+                Token::Minus => -1.0,
+                // Lexed w/ its sign, so it adds as-is.
+                Token::Number(n) if n.is_sign_negative() => {
+                    let term = self.parse_arith_factor(depth)?;
+                    acc += self.parse_product_tail(term, depth)?;
+                    continue;
+                }
+                _ => break,
+            };
+            self.advance();
+            let term = self.parse_arith_factor(depth)?;
+            acc += sign * self.parse_product_tail(term, depth)?;
+        }
+        finite_arith_result(acc)
+    }
+
+    fn parse_product_tail(&mut self, first: f32, depth: usize) -> ParseResult<f32> {
+        let mut acc = first;
+        loop {
+            match self.current {
+                Token::Star => {
+                    self.advance();
+                    acc *= self.parse_arith_factor(depth)?;
+                }
+                Token::Slash => {
+                    self.advance();
+                    let rhs = self.parse_arith_factor(depth)?;
+                    if rhs == 0.0 {
+                        return Err("division by zero in scene expression".to_string());
+                    }
+                    acc /= rhs;
+                }
+                _ => break,
+            }
+        }
+        finite_arith_result(acc)
+    }
+
+    fn parse_arith_factor(&mut self, depth: usize) -> ParseResult<f32> {
+        if depth > MAX_SCENE_VALUE_DEPTH {
+            return Err(format!(
+                "Scene value nesting exceeds limit of {MAX_SCENE_VALUE_DEPTH}"
+            ));
+        }
+        let value = self.parse_value_atom(depth)?;
+        arith_operand(&value)
+            .ok_or_else(|| format!("scene expression needs a number, got {value:?}"))
+    }
+
+    fn parse_value_atom(&mut self, depth: usize) -> ParseResult<SceneValue> {
         match &self.current {
             Token::Number(n) => {
                 let v = *n;
@@ -149,6 +391,12 @@ impl<'a> Parser<'a> {
                 Ok(SceneValue::Str(Cow::Owned(v)))
             }
 
+            Token::Color([r, g, b, a]) => {
+                let (r, g, b, a) = (*r, *g, *b, *a);
+                self.advance();
+                Ok(SceneValue::Color { r, g, b, a })
+            }
+
             Token::Dollar => {
                 self.advance();
                 let name = self.expect_ident()?;
@@ -156,8 +404,15 @@ impl<'a> Parser<'a> {
                     .vars
                     .get(name)
                     .ok_or_else(|| format!("Unknown variable ${name}"))?;
-                ensure_value_fits_depth(value, depth)?;
-                Ok(value.clone())
+                let count = ensure_value_fits_depth(value, depth)?;
+                let value = value.clone();
+                self.var_expansion = self.var_expansion.saturating_add(count);
+                if self.var_expansion > MAX_SCENE_VAR_EXPANSION {
+                    return Err(format!(
+                        "Scene variable expansion exceeds limit of {MAX_SCENE_VAR_EXPANSION} values"
+                    ));
+                }
+                Ok(value)
             }
 
             Token::Percent => {
@@ -167,19 +422,7 @@ impl<'a> Parser<'a> {
                 if marker != "loc" {
                     return Err(format!("Unknown percent marker %{marker}:"));
                 }
-                let key = match std::mem::replace(&mut self.current, Token::Eof) {
-                    Token::String(s) => {
-                        self.advance();
-                        s
-                    }
-                    Token::Ident(s) => {
-                        self.advance();
-                        s.to_string()
-                    }
-                    other => {
-                        return Err(format!("Expected locale key after %loc:, got {:?}", other));
-                    }
-                };
+                let key = self.parse_locale_key("%loc:")?;
                 Ok(SceneValue::Str(Cow::Owned(format!("%loc:{key}"))))
             }
 
@@ -195,31 +438,49 @@ impl<'a> Parser<'a> {
                 if matches!(key.as_str(), "only" | "without") && self.current == Token::LParen {
                     return self.parse_bitmask_call_key(key);
                 }
+                if key == "rect" && self.current == Token::LParen {
+                    return self.parse_rect_call(depth);
+                }
+                // `tr("KEY")` == `%loc: "KEY"`
+                if key == "tr" && self.current == Token::LParen {
+                    self.advance();
+                    let loc = self.parse_locale_key("tr(")?;
+                    self.expect(Token::RParen)?;
+                    return Ok(SceneValue::Str(Cow::Owned(format!("%loc:{loc}"))));
+                }
                 Ok(SceneValue::Key(SceneValueKey::from(key)))
             }
 
+            Token::Minus => {
+                self.advance();
+                let value = self.parse_arith_factor(depth + 1)?;
+                Ok(SceneValue::F32(-value))
+            }
+
             Token::LParen => {
                 self.advance();
                 let mut nums = [0.0; 4];
                 let mut len = 0;
-                loop {
-                    if let Token::Number(n) = self.current {
-                        if len >= nums.len() {
-                            return Err("Invalid vector length".to_string());
-                        }
-                        nums[len] = n;
-                        len += 1;
-                        self.advance();
+                while self.current != Token::RParen {
+                    if len >= nums.len() {
+                        return Err("Invalid vector length".to_string());
                     }
-                    if self.current == Token::Comma {
-                        self.advance();
-                        continue;
+                    nums[len] = match self.parse_value_at_depth(depth + 1)? {
+                        SceneValue::F32(v) => v,
+                        SceneValue::I32(v) => v as f32,
+                        other => return Err(format!("Expected vector component, got {other:?}")),
+                    };
+                    len += 1;
+                    if self.current != Token::Comma {
+                        break;
                     }
-                    break;
+                    self.advance();
                 }
                 self.expect(Token::RParen)?;
 
                 Ok(match len {
+                    // `(expr)` groups; one-element vectors do not exist.
+                    1 => SceneValue::F32(nums[0]),
                     2 => SceneValue::Vec2 {
                         x: nums[0],
                         y: nums[1],
@@ -359,6 +620,39 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// `rect(x, y, w, h)`; `rect` already consumed.
+    fn parse_locale_key(&mut self, after: &str) -> ParseResult<String> {
+        match std::mem::replace(&mut self.current, Token::Eof) {
+            Token::String(s) => {
+                self.advance();
+                Ok(s)
+            }
+            Token::Ident(s) => {
+                self.advance();
+                Ok(s.to_string())
+            }
+            other => Err(format!("Expected locale key after {after}, got {other:?}")),
+        }
+    }
+
+    fn parse_rect_call(&mut self, depth: usize) -> ParseResult<SceneValue> {
+        self.expect(Token::LParen)?;
+        let mut nums = [0.0; 4];
+        for (idx, num) in nums.iter_mut().enumerate() {
+            if idx > 0 {
+                self.expect(Token::Comma)?;
+            }
+            *num = match self.parse_value_at_depth(depth + 1)? {
+                SceneValue::F32(v) => v,
+                SceneValue::I32(v) => v as f32,
+                other => return Err(format!("Expected rect component, got {other:?}")),
+            };
+        }
+        self.expect(Token::RParen)?;
+        let [x, y, w, h] = nums;
+        Ok(SceneValue::Rect { x, y, w, h })
+    }
+
     fn parse_bitmask_call_key(&mut self, name: String) -> ParseResult<SceneValue> {
         self.expect(Token::LParen)?;
         let mut layers = Vec::new();
@@ -419,7 +713,12 @@ impl<'a> Parser<'a> {
         Ok(SceneValue::Key(SceneValueKey::from(out)))
     }
 
-    fn parse_type_block_after_lbracket(&mut self) -> ParseResult<SceneNodeData> {
+    fn parse_type_block_after_lbracket(&mut self, depth: usize) -> ParseResult<SceneNodeData> {
+        if depth > MAX_NODE_BASE_DEPTH {
+            return Err(format!(
+                "Node type base nesting exceeds limit of {MAX_NODE_BASE_DEPTH}"
+            ));
+        }
         let ty = self.expect_ident()?.to_string();
         if self.current == Token::Slash {
             self.advance();
@@ -444,7 +743,7 @@ impl<'a> Parser<'a> {
                         }
                         break;
                     } else {
-                        let nested = self.parse_type_block_after_lbracket()?;
+                        let nested = self.parse_type_block_after_lbracket(depth + 1)?;
                         base = Some(SceneNodeDataBase::Owned(Box::new(nested)));
                     }
                 }
@@ -452,7 +751,13 @@ impl<'a> Parser<'a> {
                 Token::Ident(_) => {
                     let key = self.expect_ident()?;
                     self.expect(Token::Equals)?;
+                    let start = self.lexer.token_span().start;
                     let val = self.parse_value()?;
+                    if depth == 0 {
+                        self.record_node_span(|node, end| {
+                            node.fields.push((key.to_string(), Span { start, end }));
+                        });
+                    }
                     fields.push((canonical_scene_field_name(key), val));
                 }
 
@@ -555,250 +860,756 @@ impl<'a> Parser<'a> {
         Ok(())
     }
 
-    pub(crate) fn parse_scene_inner(mut self) -> ParseResult<Scene> {
-        let mut nodes = Vec::new();
-        let mut root_name = None::<String>;
-        let mut key_names = Vec::<Cow<'static, str>>::new();
-        let mut key_ids = HashMap::<Cow<'a, str>, SceneKey>::new();
-        let mut defined_keys = HashSet::<SceneKey>::new();
-        let mut pending_parents = Vec::<(usize, String)>::new();
+    fn parse_scene_inner(&mut self) -> ParseResult<Scene> {
+        let mut build = SceneBuild::default();
+        self.parse_scene_items(&mut build)?;
+        build.finish().map_err(|mut errors| errors.swap_remove(0).1)
+    }
 
+    fn parse_scene_items(&mut self, build: &mut SceneBuild<'a>) -> ParseResult<()> {
         while self.current != Token::Eof {
+            self.parse_scene_item(build, &mut None)?;
+        }
+        if self.open_guards > 0 {
+            return Err(UNCLOSED_GUARD.to_string());
+        }
+        Ok(())
+    }
+
+    /// Parses the condition after `@if`:
+    /// `subject == "value"` / `subject != "value"`, joined by `or`.
+    fn parse_guard_condition(&mut self) -> ParseResult<bool> {
+        let mut result = false;
+        loop {
+            let subject = self.expect_ident()?;
+            let negate = match self.current {
+                Token::Equals => false,
+                Token::Bang => true,
+                _ => return Err("@if expects `==` or `!=`".to_string()),
+            };
+            self.advance();
+            // `=` must directly follow, so `= =` does not read as `==`.
+            if self.current != Token::Equals || self.lexer.token_span().start != self.last_end {
+                return Err("@if expects `==` or `!=`".to_string());
+            }
+            self.advance();
+            let value = match &self.current {
+                Token::String(value) => value.clone(),
+                _ => return Err("@if compares against a string like \"wasm\"".to_string()),
+            };
+            self.advance();
+            result |= self.target.matches(subject, &value)? != negate;
+            if self.current != Token::Ident("or") {
+                return Ok(result);
+            }
+            self.advance();
+        }
+    }
+
+    /// Skips past the `@end` closing a false `@if`, including nested guards.
+    fn skip_guarded_items(&mut self) -> ParseResult<()> {
+        let mut depth = 1usize;
+        loop {
             match self.current {
-                Token::Error(ref err) => return Err(err.to_string()),
-                Token::Dollar => {
+                Token::Eof => return Err(UNCLOSED_GUARD.to_string()),
+                Token::At => {
                     self.advance();
-                    let name = self.expect_ident()?;
-                    self.expect(Token::Equals)?;
+                    match self.current {
+                        Token::Ident("if") => depth += 1,
+                        Token::Ident("end") => depth -= 1,
+                        _ => continue,
+                    }
+                    self.advance();
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                _ => self.advance(),
+            }
+        }
+    }
 
-                    if name == "root" {
-                        match self.parse_value()? {
-                            SceneValue::Key(k) => {
-                                let key = k.to_string();
-                                root_name = Some(key.clone());
-                                self.vars.insert("root".to_string(), SceneValue::Key(k));
+    /// Parses one top-level item: `@extends`, `@include`, an `@if` guard or
+    /// its `@end`, a `$var`, or a node block.
+    ///
+    /// `open_key` holds the node key while its block is open, so checked
+    /// parsing can skip to the block's closing tag after an error.
+    fn parse_scene_item(
+        &mut self,
+        build: &mut SceneBuild<'a>,
+        open_key: &mut Option<String>,
+    ) -> ParseResult<()> {
+        match self.current {
+            Token::Error(ref err) => return Err(err.to_string()),
+            Token::At => {
+                self.advance();
+                match self.current {
+                    Token::Ident("if") => {
+                        self.advance();
+                        if self.parse_guard_condition()? {
+                            self.open_guards += 1;
+                        } else {
+                            self.skip_guarded_items()?;
+                        }
+                        return Ok(());
+                    }
+                    Token::Ident("end") => {
+                        self.advance();
+                        if self.open_guards == 0 {
+                            return Err("`@end` without a matching `@if`".to_string());
+                        }
+                        self.open_guards -= 1;
+                        return Ok(());
+                    }
+                    Token::Ident("include") => {
+                        self.advance();
+                        let span = self.lexer.token_span();
+                        let path = match self.parse_value()? {
+                            SceneValue::Str(path) => path.into_owned(),
+                            _ => return Err("@include must be a scene path string".to_string()),
+                        };
+                        return self.include_scene(build, &path, span);
+                    }
+                    _ => {}
+                }
+                if self.current != Token::Ident("extends") {
+                    return Err("use `$root = @NodeKey`; @ only marks node refs".to_string());
+                }
+                self.advance();
+                self.expect(Token::Equals)?;
+                let path = match self.parse_value()? {
+                    SceneValue::Str(path) => path.into_owned(),
+                    _ => return Err("@extends must be a scene path string".to_string()),
+                };
+                if build.extended || !build.nodes.is_empty() {
+                    return Err("@extends must appear once, before any node block".to_string());
+                }
+                let base = self.parse_extended_scene(&path)?;
+                build.extended = true;
+                build.base_root = base.root;
+                for (index, node) in base.nodes.iter().enumerate() {
+                    build.inherited.insert(node.key, index);
+                }
+                for (index, name) in base.key_names.iter().enumerate() {
+                    build
+                        .key_ids
+                        .insert(Cow::Owned(name.to_string()), SceneKey::new(index as u32));
+                }
+                build.key_names = base.key_names.into_owned();
+                build.nodes = base.nodes.into_owned();
+            }
+            Token::Dollar => {
+                self.advance();
+                let name = self.expect_ident()?;
+                self.expect(Token::Equals)?;
+
+                if name == "root" {
+                    let value_span = self.lexer.token_span();
+                    match self.parse_value()? {
+                        SceneValue::Key(k) => {
+                            let key = k.to_string();
+                            build.root_name = Some((key.clone(), value_span));
+                            if let Some(spans) = &mut self.spans {
+                                spans.root = Some(Span {
+                                    start: value_span.start,
+                                    end: self.last_end,
+                                });
                             }
-                            _ => return Err("root must be a node ref like @Main".to_string()),
+                            self.vars.insert("root".to_string(), SceneValue::Key(k));
                         }
-                    } else {
-                        let value = self.parse_value()?;
-                        self.vars.insert(name.to_string(), value);
+                        _ => return Err("root must be a node ref like @Main".to_string()),
                     }
+                } else {
+                    let value = self.parse_value()?;
+                    self.vars.insert(name.to_string(), value);
                 }
+            }
 
-                Token::At => {
-                    return Err("use `$root = @NodeKey`; @ only marks node refs".to_string());
+            Token::LBracket => {
+                let block_start = self.lexer.token_span().start;
+                self.advance();
+                if self.current == Token::Slash {
+                    let end = self.skip_closing_tag_after_lbracket()?;
+                    if self.lenient_separators {
+                        return Ok(());
+                    }
+                    return Err(format!(
+                        "unexpected closing tag `/{end}` outside node block"
+                    ));
+                }
+                let key = self.expect_scene_key()?;
+                self.expect(Token::RBracket)?;
+                *open_key = Some(key.to_string());
+                let key_ref = key.as_ref();
+                let key_id = if let Some(key_id) = build.key_ids.get(key_ref) {
+                    *key_id
+                } else {
+                    let key_id = SceneKey::new(build.key_names.len() as u32);
+                    build.key_ids.insert(key.clone(), key_id);
+                    build.key_names.push(Cow::Owned(key_ref.to_string()));
+                    key_id
+                };
+                if !build.defined_keys.insert(key_id) {
+                    if self.lenient_separators {
+                        self.skip_node_body(key_ref)?;
+                        return Ok(());
+                    }
+                    return Err(format!("duplicate scene key `{key_ref}`"));
+                }
+                if let Some(spans) = &mut self.spans {
+                    spans.nodes.push(SceneNodeSpans {
+                        key: key_ref.to_string(),
+                        block: Span {
+                            start: block_start,
+                            end: block_start,
+                        },
+                        header: Vec::new(),
+                        fields: Vec::new(),
+                    });
                 }
 
-                Token::LBracket => {
-                    self.advance();
-                    if self.current == Token::Slash {
-                        let end = self.skip_closing_tag_after_lbracket()?;
-                        if self.lenient_separators {
-                            continue;
+                let mut name = None;
+                let mut tags = None;
+                let mut parent = None;
+                let mut script = None;
+                let mut clear_script = false;
+                let mut set_script = false;
+                let mut enabled = None;
+                let mut visible = None;
+                let mut root_of = None;
+                let mut script_vars: Option<Vec<SceneObjectField>> = None;
+                let mut meta: Option<Vec<SceneObjectField>> = None;
+                let mut connections: Vec<SceneConnection> = Vec::new();
+
+                while matches!(self.current, Token::Ident(_)) {
+                    let k = self.expect_ident()?;
+                    if k == "connect" && self.current != Token::Equals {
+                        let connection = self.parse_connection()?;
+                        if !connections.contains(&connection) {
+                            connections.push(connection);
                         }
-                        return Err(format!(
-                            "unexpected closing tag `/{end}` outside node block"
-                        ));
+                        continue;
                     }
-                    let key = self.expect_scene_key()?;
-                    self.expect(Token::RBracket)?;
-                    let key_ref = key.as_ref();
-                    let key_id = if let Some(key_id) = key_ids.get(key_ref) {
-                        *key_id
-                    } else {
-                        let key_id = SceneKey::new(key_names.len() as u32);
-                        key_ids.insert(key.clone(), key_id);
-                        key_names.push(Cow::Owned(key_ref.to_string()));
-                        key_id
-                    };
-                    if !defined_keys.insert(key_id) {
-                        if self.lenient_separators {
-                            self.skip_node_body(key_ref)?;
-                            continue;
+                    if k == "vars" && self.current == Token::LBrace {
+                        // `vars { speed = 240.0 }` is `script_vars = { ... }`.
+                        let value_span = self.lexer.token_span();
+                        let SceneValue::Object(entries) = self.parse_value()? else {
+                            return Err("vars must be an object".to_string());
+                        };
+                        let vars = custom_script_var_fields(entries.into_owned());
+                        merge_scene_fields(script_vars.get_or_insert_with(Vec::new), vars);
+                        self.record_header_span(k, value_span.start);
+                        continue;
+                    }
+                    self.expect(Token::Equals)?;
+                    let value_span = self.lexer.token_span();
+                    if matches!(k, "tags" | "groups") {
+                        // Groups are tags; both keys merge into one list.
+                        let mut merged: Vec<String> = tags.take().unwrap_or_default();
+                        for tag in self.parse_tags()? {
+                            if !merged.contains(&tag) {
+                                merged.push(tag);
+                            }
                         }
-                        return Err(format!("duplicate scene key `{key_ref}`"));
+                        tags = Some(merged);
+                        self.record_header_span(k, value_span.start);
+                        continue;
                     }
-
-                    let mut name = None;
-                    let mut tags = Vec::new();
-                    let mut parent = None;
-                    let mut script = None;
-                    let mut clear_script = false;
-                    let mut root_of = None;
-                    let mut script_vars: Vec<SceneObjectField> = Vec::new();
-
-                    while matches!(self.current, Token::Ident(_)) {
-                        let k = self.expect_ident()?;
-                        self.expect(Token::Equals)?;
-                        if k == "tags" {
-                            tags = self.parse_tags()?;
-                            continue;
+                    let v = self.parse_value()?;
+                    self.record_header_span(k, value_span.start);
+                    set_script |= matches!(k, "script" | "clear_script");
+                    match k {
+                        "name" => {
+                            name = Some(match v {
+                                SceneValue::Str(s) => s.into_owned(),
+                                _ => return Err("name must be a string".to_string()),
+                            })
                         }
-                        let v = self.parse_value()?;
-                        match k {
-                            "name" => {
-                                name = Some(match v {
-                                    SceneValue::Str(s) => s.into_owned(),
-                                    _ => return Err("name must be a string".to_string()),
-                                })
-                            }
-                            "parent" => {
-                                parent = Some(match v {
-                                    SceneValue::Key(k) => k.0.into_owned(),
-                                    _ => {
-                                        return Err(
-                                            "parent must be a node ref like @Parent".to_string()
-                                        );
-                                    }
-                                })
-                            }
-                            "script" => match v {
-                                SceneValue::Str(s) => {
-                                    script = Some(s.into_owned());
-                                    clear_script = false;
+                        "parent" => {
+                            parent = Some(match v {
+                                SceneValue::Key(k) => (k.0.into_owned(), value_span),
+                                _ => {
+                                    return Err(
+                                        "parent must be a node ref like @Parent".to_string()
+                                    );
                                 }
-                                SceneValue::Key(k) if k.as_ref() == "null" => {
-                                    script = None;
-                                    clear_script = true;
-                                }
-                                _ => return Err("script must be a string or null".to_string()),
-                            },
-                            "clear_script" => {
-                                clear_script = match v {
-                                    SceneValue::Bool(v) => v,
-                                    _ => return Err("clear_script must be a bool".to_string()),
-                                };
+                            })
+                        }
+                        "script" => match v {
+                            SceneValue::Str(s) => {
+                                script = Some(s.into_owned());
+                                clear_script = false;
                             }
-                            "root_of" => {
-                                root_of = Some(match v {
-                                    SceneValue::Str(s) => s.into_owned(),
-                                    _ => return Err("root_of must be a string".to_string()),
-                                })
+                            SceneValue::Key(k) if k.as_ref() == "null" => {
+                                script = None;
+                                clear_script = true;
                             }
-                            "script_vars" => match v {
-                                SceneValue::Object(entries) => {
-                                    script_vars = custom_script_var_fields(entries.into_owned());
-                                }
-                                _ => return Err("script_vars must be an object".to_string()),
-                            },
-                            _ => {}
+                            _ => return Err("script must be a string or null".to_string()),
+                        },
+                        "clear_script" => {
+                            clear_script = match v {
+                                SceneValue::Bool(v) => v,
+                                _ => return Err("clear_script must be a bool".to_string()),
+                            };
+                        }
+                        "enabled" | "visible" => {
+                            let SceneValue::Bool(v) = v else {
+                                return Err(format!("{k} must be a bool"));
+                            };
+                            if k == "enabled" {
+                                enabled = Some(v);
+                            } else {
+                                visible = Some(v);
+                            }
+                        }
+                        "root_of" => {
+                            root_of = Some(match v {
+                                SceneValue::Str(s) => s.into_owned(),
+                                _ => return Err("root_of must be a string".to_string()),
+                            })
                         }
+                        "script_vars" | "vars" => match v {
+                            SceneValue::Object(entries) => {
+                                let vars = custom_script_var_fields(entries.into_owned());
+                                merge_scene_fields(script_vars.get_or_insert_with(Vec::new), vars);
+                            }
+                            _ => return Err(format!("{k} must be an object")),
+                        },
+                        "meta" => match v {
+                            SceneValue::Object(entries) => {
+                                let entries = custom_script_var_fields(entries.into_owned());
+                                merge_scene_fields(meta.get_or_insert_with(Vec::new), entries);
+                            }
+                            _ => return Err("meta must be an object".to_string()),
+                        },
+                        _ => {}
                     }
+                }
 
-                    if self.current != Token::LBracket {
-                        return Err(format!(
-                            "Expected node type block or closing tag for node `{key}`, got {:?}",
-                            self.current
-                        ));
+                if self.current != Token::LBracket {
+                    return Err(format!(
+                        "Expected node type block or closing tag for node `{key}`, got {:?}",
+                        self.current
+                    ));
+                }
+                self.advance();
+
+                let (mut data, has_data_override) = if self.current == Token::Slash {
+                    (
+                        SceneNodeData::new(NodeType::Node, Cow::Owned(Vec::new()), None),
+                        false,
+                    )
+                } else {
+                    (self.parse_type_block_after_lbracket(0)?, true)
+                };
+
+                if has_data_override {
+                    self.expect(Token::LBracket)?;
+                    self.expect(Token::Slash)?;
+                    let end = self.expect_scene_key()?;
+                    self.expect(Token::RBracket)?;
+                    *open_key = None;
+                    self.record_node_span(|node, end| node.block.end = end);
+                    if end != key {
+                        return Err(format!("Expected closing tag `/{}`, got `/{}`", key, end));
                     }
-                    self.advance();
+                } else {
+                    self.expect(Token::Slash)?;
+                    let end = self.expect_scene_key()?;
+                    self.expect(Token::RBracket)?;
+                    *open_key = None;
+                    self.record_node_span(|node, end| node.block.end = end);
+                    if end != key {
+                        return Err(format!("Expected closing tag `/{}`, got `/{}`", key, end));
+                    }
+                }
 
-                    let (data, has_data_override) = if self.current == Token::Slash {
-                        (
-                            SceneNodeData::new(NodeType::Node, Cow::Owned(Vec::new()), None),
-                            false,
-                        )
-                    } else {
-                        (self.parse_type_block_after_lbracket()?, true)
-                    };
+                let parent_name = parent;
+                let tags: Option<Cow<'static, [Cow<'static, str>]>> =
+                    tags.map(|tags| Cow::Owned(tags.into_iter().map(Cow::Owned).collect()));
 
-                    if has_data_override {
-                        self.expect(Token::LBracket)?;
-                        self.expect(Token::Slash)?;
-                        let end = self.expect_scene_key()?;
-                        self.expect(Token::RBracket)?;
-                        if end != key {
-                            return Err(format!("Expected closing tag `/{}`, got `/{}`", key, end));
-                        }
-                    } else {
-                        self.expect(Token::Slash)?;
-                        let end = self.expect_scene_key()?;
-                        self.expect(Token::RBracket)?;
-                        if end != key {
-                            return Err(format!("Expected closing tag `/{}`, got `/{}`", key, end));
+                let index = if let Some(&index) = build.inherited.get(&key_id) {
+                    // Inherited node: only what this scene spells out replaces the base.
+                    let node = &mut build.nodes[index];
+                    if let Some(name) = name {
+                        node.name = Some(Cow::Owned(name));
+                    }
+                    if let Some(tags) = tags {
+                        node.tags = tags;
+                    }
+                    if set_script {
+                        node.script = script.map(Cow::Owned);
+                        node.clear_script = clear_script;
+                    }
+                    if let Some(enabled) = enabled {
+                        node.enabled = enabled;
+                    }
+                    if let Some(root_of) = root_of {
+                        node.root_of = Some(Cow::Owned(root_of));
+                    }
+                    if let Some(script_vars) = script_vars {
+                        merge_scene_fields(node.script_vars.to_mut(), script_vars);
+                    }
+                    if let Some(meta) = meta {
+                        merge_scene_fields(node.meta.to_mut(), meta);
+                    }
+                    for connection in connections {
+                        if !node.connections.contains(&connection) {
+                            node.connections.to_mut().push(connection);
                         }
                     }
-
+                    if has_data_override {
+                        let base = std::mem::replace(
+                            &mut node.data,
+                            SceneNodeData::new(NodeType::Node, Cow::Owned(Vec::new()), None),
+                        );
+                        node.data = merge_inherited_node_data(key_ref, base, data)?;
+                        node.has_data_override = true;
+                    }
+                    if let Some(visible) = visible {
+                        set_visible_header(key_ref, &mut node.data, visible)?;
+                        node.has_data_override = true;
+                    }
+                    index
+                } else {
                     let name = name.or_else(|| Some(key_ref.to_string()));
-                    let parent_name = parent;
-
-                    nodes.push(SceneNodeEntry {
+                    if let Some(visible) = visible {
+                        set_visible_header(key_ref, &mut data, visible)?;
+                    }
+                    build.nodes.push(SceneNodeEntry {
                         has_data_override,
                         key: key_id,
                         name: name.map(Cow::Owned),
-                        tags: Cow::Owned(tags.into_iter().map(Cow::Owned).collect()),
+                        tags: tags.unwrap_or(Cow::Owned(Vec::new())),
                         children: Cow::Owned(Vec::new()),
                         parent: None,
                         script: script.map(Cow::Owned),
                         clear_script,
+                        enabled: enabled.unwrap_or(true),
                         root_of: root_of.map(Cow::Owned),
-                        script_vars: Cow::Owned(script_vars),
+                        script_vars: Cow::Owned(script_vars.unwrap_or_default()),
+                        meta: Cow::Owned(meta.unwrap_or_default()),
+                        connections: Cow::Owned(connections),
                         data,
                     });
-                    if let Some(parent_name) = parent_name {
-                        pending_parents.push((nodes.len() - 1, parent_name));
-                    }
+                    build.nodes.len() - 1
+                };
+                if let Some((parent_name, parent_span)) = parent_name {
+                    build
+                        .pending_parents
+                        .push((index, parent_name, parent_span));
                 }
-
-                _ => self.advance(),
             }
+
+            _ => self.advance(),
         }
+        Ok(())
+    }
 
-        for (idx, parent_name) in pending_parents {
-            let parent = if let Some(parent) = key_ids.get(parent_name.as_str()) {
-                *parent
-            } else {
-                return Err(format!(
-                    "parent node key `{}` not found for child `{}`",
-                    parent_name,
-                    key_names
-                        .get(nodes[idx].key.as_usize())
-                        .map(|name| name.as_ref())
-                        .unwrap_or("<unknown>")
-                ));
-            };
-            nodes[idx].parent = Some(parent);
+    /// Runs `f` on the open node's spans w/ the end of the last token.
+    fn record_node_span(&mut self, f: impl FnOnce(&mut SceneNodeSpans, usize)) {
+        let end = self.last_end;
+        if let Some(node) = self.spans.as_mut().and_then(|spans| spans.nodes.last_mut()) {
+            f(node, end);
         }
-        let root = if let Some(name) = root_name {
-            Some(
-                *key_ids
-                    .get(name.as_str())
-                    .ok_or_else(|| format!("scene root `{name}` not found in node list"))?,
-            )
-        } else {
-            None
+    }
+
+    fn record_header_span(&mut self, name: &str, start: usize) {
+        self.record_node_span(|node, end| {
+            node.header.push((name.to_string(), Span { start, end }));
+        });
+    }
+
+    /// Skips past a broken top-level item so checked parsing can go on.
+    fn recover_scene_item(&mut self, open_key: Option<&str>) {
+        if let Some(key) = open_key
+            && self.skip_node_body(key).is_ok()
+        {
+            return;
+        }
+        if self.current != Token::Eof {
+            self.advance();
+        }
+        while !matches!(
+            self.current,
+            Token::Eof | Token::Dollar | Token::At | Token::LBracket
+        ) {
+            self.advance();
+        }
+    }
+
+    /// Parses the base scene named by `@extends`, following its own chain.
+    fn parse_extended_scene(&mut self, path: &str) -> ParseResult<Scene> {
+        let Some(loader) = self.scene_loader else {
+            return Err(format!(
+                "@extends \"{path}\" needs a scene loader; parse with `Parser::with_scene_loader`"
+            ));
         };
+        if self.extends_chain.iter().any(|seen| seen == path) {
+            return Err(format!(
+                "@extends cycle: {} -> {path}",
+                self.extends_chain.join(" -> ")
+            ));
+        }
+        if self.extends_chain.len() >= MAX_SCENE_EXTENDS_DEPTH {
+            return Err(format!(
+                "@extends chain exceeds limit of {MAX_SCENE_EXTENDS_DEPTH}"
+            ));
+        }
+        let src = loader(path).map_err(|err| format!("@extends \"{path}\": {err}"))?;
+        let mut chain = self.extends_chain.clone();
+        chain.push(path.to_string());
+        let mut base = Parser::new(&src)
+            .with_scene_loader(loader)
+            .with_target(self.target.clone());
+        base.extends_chain = chain;
+        base.try_parse_scene()
+            .map_err(|err| format!("@extends \"{path}\": {err}"))
+    }
 
-        Ok(Scene {
-            nodes: Cow::Owned(nodes),
-            root,
-            key_names: Cow::Owned(key_names),
-        })
+    /// Splices the node blocks of the fragment at `path` into `build`, as if
+    /// they were written here. The fragment sees this scene's `$vars` and may
+    /// parent its nodes to nodes declared here; it cannot set `$root` or use
+    /// `@extends`. Bad parent refs in the fragment point at `span`.
+    fn include_scene(
+        &mut self,
+        build: &mut SceneBuild<'a>,
+        path: &str,
+        span: Span,
+    ) -> ParseResult<()> {
+        let Some(loader) = self.scene_loader else {
+            return Err(format!(
+                "@include \"{path}\" needs a scene loader; parse with `Parser::with_scene_loader`"
+            ));
+        };
+        if self.include_chain.iter().any(|seen| seen == path) {
+            return Err(format!(
+                "@include cycle: {} -> {path}",
+                self.include_chain.join(" -> ")
+            ));
+        }
+        if self.include_chain.len() >= MAX_SCENE_INCLUDE_DEPTH {
+            return Err(format!(
+                "@include nesting exceeds limit of {MAX_SCENE_INCLUDE_DEPTH}"
+            ));
+        }
+        let src = loader(path).map_err(|err| format!("@include \"{path}\": {err}"))?;
+        let fragment = self
+            .parse_fragment(&src, path)
+            .map_err(|err| format!("@include \"{path}\": {err}"))?;
+        if fragment.extended {
+            return Err(format!(
+                "@include \"{path}\": fragments cannot use @extends"
+            ));
+        }
+        if fragment.root_name.is_some() {
+            return Err(format!("@include \"{path}\": fragments cannot set `$root`"));
+        }
+
+        let offset = build.nodes.len();
+        for mut node in fragment.nodes {
+            let name = fragment.key_names[node.key.as_usize()].clone();
+            // Keys seen only as a streamed parent ref are reused, not duplicates.
+            let key_id = match build.key_ids.get(name.as_ref()) {
+                Some(key_id)
+                    if !build.defined_keys.contains(key_id)
+                        && !build.inherited.contains_key(key_id) =>
+                {
+                    *key_id
+                }
+                Some(_) => {
+                    return Err(format!("@include \"{path}\": duplicate scene key `{name}`"));
+                }
+                None => {
+                    let key_id = SceneKey::new(build.key_names.len() as u32);
+                    build.key_ids.insert(Cow::Owned(name.to_string()), key_id);
+                    build.key_names.push(name);
+                    key_id
+                }
+            };
+            build.defined_keys.insert(key_id);
+            node.key = key_id;
+            build.nodes.push(node);
+        }
+        for (index, parent, _) in fragment.pending_parents {
+            build.pending_parents.push((offset + index, parent, span));
+        }
+        Ok(())
+    }
+
+    /// Node blocks of an `@include` fragment, parents still unlinked.
+    fn parse_fragment<'b>(&self, src: &'b str, path: &str) -> Result<SceneBuild<'b>, String>
+    where
+        'a: 'b,
+    {
+        let mut include_chain = self.include_chain.clone();
+        include_chain.push(path.to_string());
+        let mut parser = Parser::new(src).with_target(self.target.clone());
+        parser.scene_loader = self.scene_loader;
+        parser.include_chain = include_chain;
+        parser.vars = self.vars.clone();
+        if needs_var_prefetch(src) {
+            let mut prefetch = Parser::new(src).with_target(self.target.clone());
+            prefetch.vars = self.vars.clone();
+            let own = prefetch.try_collect_vars().map_err(|err| err.to_string())?;
+            parser.vars.extend(own);
+        }
+        let mut build = SceneBuild::default();
+        let result = parser.parse_scene_items(&mut build);
+        parser.located(result).map_err(|err| err.to_string())?;
+        Ok(build)
     }
 
+    /// Parses a scene, panicking if the input is invalid.
+    ///
+    /// For tests, benches, and generated text. Use [`Parser::try_parse_scene`]
+    /// for anything read from disk.
     pub fn parse_scene(self) -> Scene {
         self.try_parse_scene().unwrap_or_else(|err| panic!("{err}"))
     }
 
-    pub fn try_parse_scene(self) -> Result<Scene, String> {
+    /// Parses a scene, returning the first error with its source position.
+    pub fn try_parse_scene(self) -> Result<Scene, SceneParseError> {
         let mut parser = Parser::new(self.src);
+        parser.scene_loader = self.scene_loader;
+        parser.extends_chain = self.extends_chain;
+        parser.target = self.target.clone();
         if needs_var_prefetch(self.src) {
-            parser.vars = Parser::new(self.src).try_collect_vars()?;
+            parser.vars = Parser::new(self.src)
+                .with_target(self.target.clone())
+                .try_collect_vars()?;
         }
-        parser.parse_scene_inner()
+        let result = parser.parse_scene_inner();
+        parser.located(result)
     }
 
-    pub(crate) fn try_parse_scene_lenient(self) -> ParseResult<Scene> {
+    /// Parses node blocks one at a time, in source order.
+    ///
+    /// Unlike [`Parser::try_parse_scene`], the whole tree is never held at
+    /// once, so a runtime can instance a huge scene a chunk per frame. Each
+    /// node's `parent` is already linked; a parent ref to a key defined
+    /// further down gets its key id early. Refs that never resolve, a missing
+    /// `$root`, or an unclosed `@if` are reported as the last item.
+    ///
+    /// `@include` works. `@extends` does not, since overrides patch base
+    /// nodes after they would have been yielded.
+    pub fn parse_nodes(self) -> SceneNodeStream<'a> {
+        let mut parser = Parser::new(self.src);
+        parser.scene_loader = self.scene_loader;
+        parser.extends_chain = self.extends_chain;
+        parser.target = self.target.clone();
+        let mut pending_error = None;
+        if needs_var_prefetch(self.src) {
+            match Parser::new(self.src)
+                .with_target(self.target.clone())
+                .try_collect_vars()
+            {
+                Ok(vars) => parser.vars = vars,
+                Err(err) => pending_error = Some(err),
+            }
+        }
+        SceneNodeStream {
+            parser,
+            build: SceneBuild::default(),
+            ready: VecDeque::new(),
+            forward_parents: Vec::new(),
+            pending_error,
+            done: false,
+        }
+    }
+
+    /// [`Parser::try_parse_scene`] that also records where nodes and values sit.
+    pub(crate) fn try_parse_scene_spans(self) -> Result<(Scene, SceneSpans), SceneParseError> {
+        let mut parser = Parser::new(self.src);
+        parser.scene_loader = self.scene_loader;
+        parser.extends_chain = self.extends_chain;
+        parser.target = self.target.clone();
+        parser.spans = Some(SceneSpans::default());
+        if needs_var_prefetch(self.src) {
+            parser.vars = Parser::new(self.src)
+                .with_target(self.target.clone())
+                .try_collect_vars()?;
+        }
+        let result = parser.parse_scene_inner();
+        let scene = parser.located(result)?;
+        Ok((scene, parser.spans.take().unwrap_or_default()))
+    }
+
+    /// Parses a scene, collecting every error instead of stopping at the first.
+    ///
+    /// After an error the parser skips to the end of the broken node block (or
+    /// the next top-level item) and keeps going, so one typo does not hide the
+    /// rest. Diagnostics come back sorted by source position.
+    pub fn parse_scene_checked(self) -> Result<Scene, Vec<SceneDiagnostic>> {
+        let mut parser = Parser::new(self.src);
+        parser.scene_loader = self.scene_loader;
+        parser.extends_chain = self.extends_chain;
+        parser.target = self.target.clone();
+        let mut diagnostics = Vec::new();
+        if needs_var_prefetch(self.src) {
+            match Parser::new(self.src)
+                .with_target(self.target.clone())
+                .try_collect_vars()
+            {
+                Ok(vars) => parser.vars = vars,
+                Err(err) => diagnostics.push(err),
+            }
+        }
+
+        let mut build = SceneBuild::default();
+        while parser.current != Token::Eof {
+            let mut open_key = None;
+            if let Err(message) = parser.parse_scene_item(&mut build, &mut open_key) {
+                let diagnostic = SceneParseError::new(self.src, parser.lexer.token_span(), message);
+                // Var prefetch + main pass can trip on the same token.
+                if !diagnostics.contains(&diagnostic) {
+                    diagnostics.push(diagnostic);
+                }
+                parser.recover_scene_item(open_key.as_deref());
+            }
+        }
+        if parser.open_guards > 0 {
+            diagnostics.push(SceneParseError::new(
+                self.src,
+                parser.lexer.token_span(),
+                UNCLOSED_GUARD,
+            ));
+        }
+
+        let scene = match build.finish() {
+            Ok(scene) => Some(scene),
+            Err(errors) => {
+                diagnostics.extend(
+                    errors
+                        .into_iter()
+                        .map(|(span, message)| SceneParseError::new(self.src, span, message)),
+                );
+                None
+            }
+        };
+        match scene {
+            Some(scene) if diagnostics.is_empty() => Ok(scene),
+            _ => {
+                diagnostics.sort_by_key(|diagnostic| diagnostic.span.start);
+                Err(diagnostics)
+            }
+        }
+    }
+
+    pub(crate) fn try_parse_scene_lenient(self) -> Result<Scene, SceneParseError> {
         let mut parser = Parser::new_lenient(self.src);
+        parser.target = self.target.clone();
         if needs_var_prefetch(self.src) {
-            parser.vars = Parser::new_lenient(self.src).try_collect_vars()?;
+            let mut prefetch = Parser::new_lenient(self.src);
+            prefetch.target = self.target.clone();
+            parser.vars = prefetch.try_collect_vars()?;
         }
-        parser.parse_scene_inner()
+        let result = parser.parse_scene_inner();
+        parser.located(result)
     }
 
     /// Parses a scene document, returning an error for invalid input.
-    pub fn try_parse_scene_doc(self) -> Result<crate::SceneDoc, String> {
+    pub fn try_parse_scene_doc(self) -> Result<crate::SceneDoc, SceneParseError> {
         crate::SceneDoc::try_parse_lenient(self.src)
     }
 
@@ -815,7 +1626,12 @@ impl<'a> Parser<'a> {
     }
 
     /// Parses one value literal, returning an error for invalid input.
-    pub fn try_parse_value_literal(mut self) -> Result<SceneValue, String> {
+    pub fn try_parse_value_literal(mut self) -> Result<SceneValue, SceneParseError> {
+        let result = self.parse_value_literal_inner();
+        self.located(result)
+    }
+
+    fn parse_value_literal_inner(&mut self) -> ParseResult<SceneValue> {
         let value = self.parse_value()?;
         if self.current != Token::Eof {
             return Err(format!("Expected end of value, got {:?}", self.current));
@@ -837,9 +1653,12 @@ impl<'a> Parser<'a> {
     }
 }
 
-fn ensure_value_fits_depth(value: &SceneValue, start_depth: usize) -> ParseResult<()> {
+/// Checks nesting from `start_depth` and returns how many values `value` holds.
+fn ensure_value_fits_depth(value: &SceneValue, start_depth: usize) -> ParseResult<usize> {
     let mut pending = vec![(value, start_depth)];
+    let mut count = 0usize;
     while let Some((value, depth)) = pending.pop() {
+        count += 1;
         if depth > MAX_SCENE_VALUE_DEPTH {
             return Err(format!(
                 "Scene value nesting exceeds limit of {MAX_SCENE_VALUE_DEPTH}"
@@ -855,10 +1674,10 @@ fn ensure_value_fits_depth(value: &SceneValue, start_depth: usize) -> ParseResul
             _ => {}
         }
     }
-    Ok(())
+    Ok(count)
 }
 
-fn custom_script_var_fields(fields: Vec<SceneObjectField>) -> Vec<SceneObjectField> {
+pub(crate) fn custom_script_var_fields(fields: Vec<SceneObjectField>) -> Vec<SceneObjectField> {
     fields
         .into_iter()
         .map(|(name, value)| {
@@ -886,6 +1705,206 @@ fn custom_script_var_value(value: SceneValue) -> SceneValue {
     }
 }
 
+fn arith_operand(value: &SceneValue) -> Option<f32> {
+    match value {
+        SceneValue::F32(v) => Some(*v),
+        SceneValue::I32(v) => Some(*v as f32),
+        _ => None,
+    }
+}
+
+fn finite_arith_result(value: f32) -> ParseResult<f32> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err("scene expression result is not finite".to_string())
+    }
+}
+
+/// Scene state built up across top-level items.
+#[derive(Default)]
+struct SceneBuild<'a> {
+    nodes: Vec<SceneNodeEntry>,
+    root_name: Option<(String, Span)>,
+    key_names: Vec<Cow<'static, str>>,
+    key_ids: HashMap<Cow<'a, str>, SceneKey>,
+    defined_keys: HashSet<SceneKey>,
+    pending_parents: Vec<(usize, String, Span)>,
+    inherited: HashMap<SceneKey, usize>,
+    base_root: Option<SceneKey>,
+    extended: bool,
+}
+
+impl SceneBuild<'_> {
+    /// Links parents and the root; errors carry the span of the bad ref.
+    fn finish(mut self) -> Result<Scene, Vec<(Span, String)>> {
+        let mut errors = Vec::new();
+        for (idx, parent_name, span) in self.pending_parents {
+            if let Some(parent) = self.key_ids.get(parent_name.as_str()) {
+                self.nodes[idx].parent = Some(*parent);
+            } else {
+                errors.push((
+                    span,
+                    format!(
+                        "parent node key `{}` not found for child `{}`",
+                        parent_name,
+                        self.key_names
+                            .get(self.nodes[idx].key.as_usize())
+                            .map(|name| name.as_ref())
+                            .unwrap_or("<unknown>")
+                    ),
+                ));
+            }
+        }
+        let root = match self.root_name {
+            Some((name, span)) => match self.key_ids.get(name.as_str()) {
+                Some(root) => Some(*root),
+                None => {
+                    errors.push((span, format!("scene root `{name}` not found in node list")));
+                    None
+                }
+            },
+            None => self.base_root,
+        };
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(Scene {
+            nodes: Cow::Owned(self.nodes),
+            root,
+            key_names: Cow::Owned(self.key_names),
+        })
+    }
+}
+
+/// Node blocks of one scene, yielded as they are parsed. Made by
+/// [`Parser::parse_nodes`].
+///
+/// Stops after the first error.
+pub struct SceneNodeStream<'a> {
+    parser: Parser<'a>,
+    build: SceneBuild<'a>,
+    ready: VecDeque<SceneNodeEntry>,
+    /// Parent key, child key, span of the ref; checked once the source ends.
+    forward_parents: Vec<(SceneKey, SceneKey, Span)>,
+    pending_error: Option<SceneParseError>,
+    done: bool,
+}
+
+impl SceneNodeStream<'_> {
+    pub fn key_name(&self, key: SceneKey) -> Option<&str> {
+        self.build
+            .key_names
+            .get(key.as_usize())
+            .map(|name| name.as_ref())
+    }
+
+    /// `$root` key, once its node block has been yielded.
+    pub fn root(&self) -> Option<SceneKey> {
+        let (name, _) = self.build.root_name.as_ref()?;
+        let key = self.build.key_ids.get(name.as_str())?;
+        self.build.defined_keys.contains(key).then_some(*key)
+    }
+
+    fn fail(&mut self, err: SceneParseError) -> Option<Result<SceneNodeEntry, SceneParseError>> {
+        self.done = true;
+        Some(Err(err))
+    }
+
+    /// Links parents of nodes parsed by the last item and queues them.
+    fn queue_parsed(&mut self) {
+        let build = &mut self.build;
+        for (index, parent_name, span) in std::mem::take(&mut build.pending_parents) {
+            let parent = match build.key_ids.get(parent_name.as_str()) {
+                Some(key) => *key,
+                None => {
+                    let key = SceneKey::new(build.key_names.len() as u32);
+                    build.key_names.push(Cow::Owned(parent_name.clone()));
+                    build.key_ids.insert(Cow::Owned(parent_name), key);
+                    key
+                }
+            };
+            if !build.defined_keys.contains(&parent) {
+                self.forward_parents
+                    .push((parent, build.nodes[index].key, span));
+            }
+            build.nodes[index].parent = Some(parent);
+        }
+        self.ready.extend(build.nodes.drain(..));
+    }
+
+    /// Errors left once the source is fully read.
+    fn finish(&self) -> Option<SceneParseError> {
+        let src = self.parser.src;
+        if self.parser.open_guards > 0 {
+            return Some(SceneParseError::new(
+                src,
+                self.parser.lexer.token_span(),
+                UNCLOSED_GUARD,
+            ));
+        }
+        for (parent, child, span) in &self.forward_parents {
+            if !self.build.defined_keys.contains(parent) {
+                return Some(SceneParseError::new(
+                    src,
+                    *span,
+                    format!(
+                        "parent node key `{}` not found for child `{}`",
+                        self.key_name(*parent).unwrap_or("<unknown>"),
+                        self.key_name(*child).unwrap_or("<unknown>")
+                    ),
+                ));
+            }
+        }
+        if let Some((name, span)) = &self.build.root_name
+            && self.root().is_none()
+        {
+            return Some(SceneParseError::new(
+                src,
+                *span,
+                format!("scene root `{name}` not found in node list"),
+            ));
+        }
+        None
+    }
+}
+
+impl Iterator for SceneNodeStream<'_> {
+    type Item = Result<SceneNodeEntry, SceneParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(node) = self.ready.pop_front() {
+                return Some(Ok(node));
+            }
+            if self.done {
+                return None;
+            }
+            if let Some(err) = self.pending_error.take() {
+                return self.fail(err);
+            }
+            if self.parser.current == Token::Eof {
+                self.done = true;
+                return self.finish().map(Err);
+            }
+            let result = self.parser.parse_scene_item(&mut self.build, &mut None);
+            if let Err(err) = self.parser.located(result) {
+                return self.fail(err);
+            }
+            if self.build.extended {
+                let err = SceneParseError::new(
+                    self.parser.src,
+                    self.parser.lexer.token_span(),
+                    "`parse_nodes` cannot stream `@extends` scenes; use `try_parse_scene`",
+                );
+                return self.fail(err);
+            }
+            self.queue_parsed();
+        }
+    }
+}
+
 fn needs_var_prefetch(src: &str) -> bool {
     let bytes = src.as_bytes();
     let mut i = 0;
@@ -906,6 +1925,56 @@ fn needs_var_prefetch(src: &str) -> bool {
     false
 }
 
+/// Replaces same-named fields in `base` and appends the rest.
+fn merge_scene_fields(base: &mut Vec<SceneObjectField>, overrides: Vec<SceneObjectField>) {
+    for (name, value) in overrides {
+        if let Some(slot) = base.iter_mut().find(|(existing, _)| *existing == name) {
+            slot.1 = value;
+        } else {
+            base.push((name, value));
+        }
+    }
+}
+
+/// `visible = ...` in a node header is the type's own `visible` field.
+fn set_visible_header(key: &str, data: &mut SceneNodeData, visible: bool) -> ParseResult<()> {
+    if scene_node_spec(data.node_type)
+        .resolve(&SceneFieldName::Visible)
+        .is_none()
+    {
+        return Err(format!(
+            "node `{key}` is `{}`, which has no `visible` field; use `enabled = false` instead",
+            data.type_name()
+        ));
+    }
+    merge_scene_fields(
+        data.fields.to_mut(),
+        vec![(SceneFieldName::Visible, SceneValue::Bool(visible))],
+    );
+    Ok(())
+}
+
+fn merge_inherited_node_data(
+    key: &str,
+    base: SceneNodeData,
+    derived: SceneNodeData,
+) -> ParseResult<SceneNodeData> {
+    if base.node_type != derived.node_type {
+        return Err(format!(
+            "node `{key}` is `{}` in the base scene; @extends overrides cannot change it to `{}`",
+            base.type_name(),
+            derived.type_name()
+        ));
+    }
+    let mut fields = base.fields.into_owned();
+    merge_scene_fields(&mut fields, derived.fields.into_owned());
+    Ok(SceneNodeData::new(
+        derived.node_type,
+        Cow::Owned(fields),
+        derived.base.or(base.base),
+    ))
+}
+
 fn canonical_node_type(name: &str) -> Option<NodeType> {
     match name {
         "UiScroll" => Some(NodeType::UiScrollContainer),
@@ -963,7 +2032,7 @@ fn euler_xyz_radians_to_quat_value(x: f32, y: f32, z: f32) -> SceneValue {
 
 #[cfg(test)]
 mod tests {
-    use super::{MAX_SCENE_VALUE_DEPTH, Parser};
+    use super::{MAX_NODE_BASE_DEPTH, MAX_SCENE_VALUE_DEPTH, Parser};
     use crate::SceneValue;
 
     #[test]
@@ -988,7 +2057,16 @@ mod tests {
             Ok(_) => panic!("expected parse error"),
             Err(err) => err,
         };
-        assert!(err.contains("Expected"));
+        assert!(err.message.contains("Expected"));
+    }
+
+    #[test]
+    fn try_parse_scene_error_reports_line_and_column() {
+        let err = Parser::new("$root = @main\n[main]\n    speed = )\n[/main]\n")
+            .try_parse_scene()
+            .expect_err("invalid test input must fail");
+        assert_eq!((err.line, err.column), (3, 13), "{err}");
+        assert!(err.to_string().starts_with("3:13: "), "{err}");
     }
 
     #[test]
@@ -998,17 +2076,43 @@ mod tests {
             .expect_err("invalid test input must fail");
 
         assert!(
-            err.contains("Unterminated node type block `[Node]`"),
+            err.message
+                .contains("Unterminated node type block `[Node]`"),
             "{err}"
         );
     }
 
+    #[test]
+    fn parse_scene_checked_collects_every_error_in_order() {
+        let src = "$root = @main\n[main]\n    speed = )\n[/main]\n[child]\nparent = @main\n[Node2D]\n    visible = ]\n[/Node2D]\n[/child]\n[orphan]\nparent = @ghost\n[/orphan]\n";
+        let diagnostics = Parser::new(src)
+            .parse_scene_checked()
+            .expect_err("invalid test input must fail");
+        let lines: Vec<usize> = diagnostics.iter().map(|d| d.line).collect();
+        assert_eq!(lines, vec![3, 8, 12], "{diagnostics:?}");
+        assert_eq!(diagnostics[0].column, 13, "{}", diagnostics[0]);
+        assert!(
+            diagnostics[2].message.contains("`ghost` not found"),
+            "{}",
+            diagnostics[2]
+        );
+    }
+
+    #[test]
+    fn parse_scene_checked_accepts_valid_scene() {
+        let src = "$root = @main\n[main]\n[Node2D]\n    visible = true\n[/Node2D]\n[/main]\n";
+        let scene = Parser::new(src)
+            .parse_scene_checked()
+            .expect("valid test input must parse");
+        assert_eq!(scene.nodes.len(), 1);
+    }
+
     #[test]
     fn try_parse_scene_doc_returns_parse_error() {
         let err = Parser::new("$speed =")
             .try_parse_scene_doc()
             .expect_err("invalid test input must fail");
-        assert!(err.contains("Invalid value token"), "{err}");
+        assert!(err.message.contains("Invalid value token"), "{err}");
     }
 
     #[test]
@@ -1016,7 +2120,7 @@ mod tests {
         let err = Parser::new("[1, 2")
             .try_parse_value_literal()
             .expect_err("invalid test input must fail");
-        assert!(err.contains("Expected"), "{err}");
+        assert!(err.message.contains("Expected"), "{err}");
     }
 
     #[test]
@@ -1024,7 +2128,7 @@ mod tests {
         let err = Parser::new("1 2")
             .try_parse_value_literal()
             .expect_err("invalid test input must fail");
-        assert!(err.contains("Expected end of value"), "{err}");
+        assert!(err.message.contains("Expected end of value"), "{err}");
     }
 
     #[test]
@@ -1042,6 +2146,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parser_accepts_color_and_rect_literals() {
+        let value =
+            Parser::new("{ tint = #ff800080, area = rect(1, 2, 16 * 2, 8) }").parse_value_literal();
+        let SceneValue::Object(fields) = value else {
+            panic!("expected object");
+        };
+        assert_eq!(
+            fields[0].1,
+            SceneValue::Color {
+                r: 255,
+                g: 128,
+                b: 0,
+                a: 128
+            }
+        );
+        assert_eq!(fields[1].1.as_rect(), Some((1.0, 2.0, 32.0, 8.0)));
+        assert_eq!(
+            Parser::new("#FFFFFF").parse_value_literal().as_color(),
+            Some([1.0; 4])
+        );
+    }
+
+    #[test]
+    fn parser_rejects_short_rect() {
+        let err = Parser::new("rect(1, 2, 3)")
+            .try_parse_value_literal()
+            .expect_err("invalid test input must fail");
+        assert!(err.message.contains("Expected Comma"), "{err}");
+    }
+
     #[test]
     fn parser_rejects_value_over_depth_limit() {
         let src = format!(
@@ -1052,7 +2187,7 @@ mod tests {
         let err = Parser::new(&src)
             .try_parse_value_literal()
             .expect_err("invalid test input must fail");
-        assert!(err.contains("nesting exceeds limit"), "{err}");
+        assert!(err.message.contains("nesting exceeds limit"), "{err}");
     }
 
     #[test]
@@ -1064,6 +2199,30 @@ mod tests {
         let err = Parser::new(&src)
             .try_parse_scene()
             .expect_err("invalid test input must fail");
-        assert!(err.contains("nesting exceeds limit"), "{err}");
+        assert!(err.message.contains("nesting exceeds limit"), "{err}");
+    }
+
+    #[test]
+    fn parser_rejects_exponential_variable_expansion() {
+        let mut src = String::from("$v0 = [0, 0]\n");
+        for idx in 1..40 {
+            src.push_str(&format!("$v{idx} = [$v{}, $v{}]\n", idx - 1, idx - 1));
+        }
+        let err = Parser::new(&src)
+            .try_parse_scene()
+            .expect_err("invalid test input must fail");
+        assert!(err.message.contains("expansion exceeds limit"), "{err}");
+    }
+
+    #[test]
+    fn parser_rejects_node_base_over_depth_limit() {
+        let src = format!(
+            "$root = @main\n[main]\n{}",
+            "[Node]\n".repeat(MAX_NODE_BASE_DEPTH + 2)
+        );
+        let err = Parser::new(&src)
+            .try_parse_scene()
+            .expect_err("invalid test input must fail");
+        assert!(err.message.contains("base nesting exceeds limit"), "{err}");
     }
 }
@@ -0,0 +1,646 @@
+//! Compiled `.scnb` scene encoding for shipped builds.
+//!
+//! The static pipeline parses `.scn` text once (resolving `@extends`) and
+//! writes this form; the runtime decodes it w/o lexing or parsing. Layout:
+//! magic, version, a deduped string table, then the scene body. Counts and
+//! string ids are LEB128 varints; scalars are fixed little-endian.
+
+use crate::parser::custom_script_var_fields;
+use crate::{
+    NodeType, Scene, SceneConnection, SceneFieldName, SceneKey, SceneNodeData, SceneNodeDataBase,
+    SceneNodeEntry, SceneObjectField, SceneValue, SceneValueKey,
+};
+use perro_asset_formats::{pscn, source_ext};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Max nesting of object/array values and node data bases accepted on decode.
+const MAX_DECODE_DEPTH: u32 = 64;
+
+const NODE_HAS_DATA_OVERRIDE: u8 = 1 << 0;
+const NODE_CLEAR_SCRIPT: u8 = 1 << 1;
+const NODE_HAS_NAME: u8 = 1 << 2;
+const NODE_HAS_PARENT: u8 = 1 << 3;
+const NODE_HAS_SCRIPT: u8 = 1 << 4;
+const NODE_HAS_ROOT_OF: u8 = 1 << 5;
+const NODE_HAS_CONNECTIONS: u8 = 1 << 6;
+const NODE_DISABLED: u8 = 1 << 7;
+
+const VALUE_BOOL: u8 = 0;
+const VALUE_I32: u8 = 1;
+const VALUE_F32: u8 = 2;
+const VALUE_VEC2: u8 = 3;
+const VALUE_VEC3: u8 = 4;
+const VALUE_VEC4: u8 = 5;
+const VALUE_IVEC2: u8 = 6;
+const VALUE_IVEC3: u8 = 7;
+const VALUE_IVEC4: u8 = 8;
+const VALUE_UVEC2: u8 = 9;
+const VALUE_UVEC3: u8 = 10;
+const VALUE_UVEC4: u8 = 11;
+const VALUE_STR: u8 = 12;
+const VALUE_HASHED: u8 = 13;
+const VALUE_KEY: u8 = 14;
+const VALUE_OBJECT: u8 = 15;
+const VALUE_ARRAY: u8 = 16;
+const VALUE_COLOR: u8 = 17;
+const VALUE_RECT: u8 = 18;
+
+/// `res://a/b.scn` -> `res://a/b.scnb`. Other paths pass through unchanged.
+pub fn compiled_scene_path(path: &str) -> Cow<'_, str> {
+    match path.rsplit_once('.') {
+        Some((stem, ext)) if ext.eq_ignore_ascii_case(source_ext::SCENE) => {
+            Cow::Owned(format!("{stem}.{}", pscn::EXTENSION))
+        }
+        _ => Cow::Borrowed(path),
+    }
+}
+
+/// Returns true when `bytes` starts w/ the `.scnb` magic.
+pub fn is_compiled_scene(bytes: &[u8]) -> bool {
+    bytes.starts_with(pscn::MAGIC)
+}
+
+/// Encodes a parsed scene into `.scnb` bytes.
+pub fn encode_scene(scene: &Scene) -> Vec<u8> {
+    let mut encoder = Encoder::default();
+    encoder.scene(scene);
+
+    let mut out = Vec::with_capacity(encoder.body.len() + encoder.strings_len + 16);
+    out.extend_from_slice(pscn::MAGIC);
+    out.extend_from_slice(&pscn::VERSION.to_le_bytes());
+    write_varint(&mut out, encoder.strings.len() as u64);
+    for s in &encoder.strings {
+        write_varint(&mut out, s.len() as u64);
+        out.extend_from_slice(s.as_bytes());
+    }
+    out.extend_from_slice(&encoder.body);
+    out
+}
+
+/// Decodes `.scnb` bytes produced by [`encode_scene`].
+pub fn decode_scene(bytes: &[u8]) -> Result<Scene, String> {
+    let mut reader = Reader { bytes, pos: 0 };
+    let magic = reader.take(pscn::MAGIC.len())?;
+    if magic != pscn::MAGIC {
+        return Err("not a compiled scene (bad magic)".to_string());
+    }
+    let version = u32::from_le_bytes(reader.array()?);
+    if version != pscn::VERSION {
+        return Err(format!(
+            "compiled scene version {version} unsupported (expected {})",
+            pscn::VERSION
+        ));
+    }
+
+    let string_count = reader.count()?;
+    let mut strings = Vec::with_capacity(string_count);
+    for _ in 0..string_count {
+        let len = reader.count()?;
+        let raw = reader.take(len)?;
+        let s = std::str::from_utf8(raw)
+            .map_err(|err| format!("compiled scene string is not valid UTF-8: {err}"))?;
+        strings.push(s.to_string());
+    }
+
+    let mut decoder = Decoder { reader, strings };
+    let scene = decoder.scene()?;
+    if decoder.reader.pos != decoder.reader.bytes.len() {
+        return Err(format!(
+            "compiled scene has {} trailing bytes",
+            decoder.reader.bytes.len() - decoder.reader.pos
+        ));
+    }
+    Ok(scene)
+}
+
+#[derive(Default)]
+struct Encoder<'a> {
+    body: Vec<u8>,
+    strings: Vec<&'a str>,
+    string_ids: HashMap<&'a str, u64>,
+    strings_len: usize,
+}
+
+impl<'a> Encoder<'a> {
+    fn scene(&mut self, scene: &'a Scene) {
+        self.opt_key(scene.root);
+        write_varint(&mut self.body, scene.key_names.len() as u64);
+        for name in scene.key_names.iter() {
+            self.string(name.as_ref());
+        }
+        write_varint(&mut self.body, scene.nodes.len() as u64);
+        for node in scene.nodes.iter() {
+            self.node(node);
+        }
+    }
+
+    fn node(&mut self, node: &'a SceneNodeEntry) {
+        let mut flags = 0;
+        if node.has_data_override {
+            flags |= NODE_HAS_DATA_OVERRIDE;
+        }
+        if node.clear_script {
+            flags |= NODE_CLEAR_SCRIPT;
+        }
+        if node.name.is_some() {
+            flags |= NODE_HAS_NAME;
+        }
+        if node.parent.is_some() {
+            flags |= NODE_HAS_PARENT;
+        }
+        if node.script.is_some() {
+            flags |= NODE_HAS_SCRIPT;
+        }
+        if node.root_of.is_some() {
+            flags |= NODE_HAS_ROOT_OF;
+        }
+        if !node.connections.is_empty() {
+            flags |= NODE_HAS_CONNECTIONS;
+        }
+        if !node.enabled {
+            flags |= NODE_DISABLED;
+        }
+        self.body.push(flags);
+        write_varint(&mut self.body, node.key.as_u32() as u64);
+        if let Some(name) = node.name.as_deref() {
+            self.string(name);
+        }
+        if let Some(parent) = node.parent {
+            write_varint(&mut self.body, parent.as_u32() as u64);
+        }
+        if let Some(script) = node.script.as_deref() {
+            self.string(script);
+        }
+        if let Some(root_of) = node.root_of.as_deref() {
+            self.string(root_of);
+        }
+        write_varint(&mut self.body, node.tags.len() as u64);
+        for tag in node.tags.iter() {
+            self.string(tag.as_ref());
+        }
+        write_varint(&mut self.body, node.children.len() as u64);
+        for child in node.children.iter() {
+            write_varint(&mut self.body, child.as_u32() as u64);
+        }
+        self.fields(&node.script_vars);
+        self.fields(&node.meta);
+        if !node.connections.is_empty() {
+            write_varint(&mut self.body, node.connections.len() as u64);
+            for connection in node.connections.iter() {
+                self.string(connection.signal.as_ref());
+                self.string(connection.target.as_ref());
+                self.string(connection.method.as_ref());
+            }
+        }
+        self.data(&node.data);
+    }
+
+    fn data(&mut self, data: &'a SceneNodeData) {
+        self.string(data.node_type.as_str());
+        self.fields(&data.fields);
+        match data.base_ref() {
+            Some(base) => {
+                self.body.push(1);
+                self.data(base);
+            }
+            None => self.body.push(0),
+        }
+    }
+
+    fn fields(&mut self, fields: &'a [SceneObjectField]) {
+        write_varint(&mut self.body, fields.len() as u64);
+        for (name, value) in fields {
+            self.string(name.as_str());
+            self.value(value);
+        }
+    }
+
+    fn value(&mut self, value: &'a SceneValue) {
+        match value {
+            SceneValue::Bool(v) => {
+                self.body.push(VALUE_BOOL);
+                self.body.push(u8::from(*v));
+            }
+            SceneValue::I32(v) => {
+                self.body.push(VALUE_I32);
+                self.words(&[v.to_le_bytes()]);
+            }
+            SceneValue::F32(v) => {
+                self.body.push(VALUE_F32);
+                self.words(&[v.to_le_bytes()]);
+            }
+            SceneValue::Vec2 { x, y } => {
+                self.body.push(VALUE_VEC2);
+                self.words(&[x.to_le_bytes(), y.to_le_bytes()]);
+            }
+            SceneValue::Vec3 { x, y, z } => {
+                self.body.push(VALUE_VEC3);
+                self.words(&[x.to_le_bytes(), y.to_le_bytes(), z.to_le_bytes()]);
+            }
+            SceneValue::Vec4 { x, y, z, w } => {
+                self.body.push(VALUE_VEC4);
+                self.words(&[
+                    x.to_le_bytes(),
+                    y.to_le_bytes(),
+                    z.to_le_bytes(),
+                    w.to_le_bytes(),
+                ]);
+            }
+            SceneValue::IVec2 { x, y } => {
+                self.body.push(VALUE_IVEC2);
+                self.words(&[x.to_le_bytes(), y.to_le_bytes()]);
+            }
+            SceneValue::IVec3 { x, y, z } => {
+                self.body.push(VALUE_IVEC3);
+                self.words(&[x.to_le_bytes(), y.to_le_bytes(), z.to_le_bytes()]);
+            }
+            SceneValue::IVec4 { x, y, z, w } => {
+                self.body.push(VALUE_IVEC4);
+                self.words(&[
+                    x.to_le_bytes(),
+                    y.to_le_bytes(),
+                    z.to_le_bytes(),
+                    w.to_le_bytes(),
+                ]);
+            }
+            SceneValue::UVec2 { x, y } => {
+                self.body.push(VALUE_UVEC2);
+                self.words(&[x.to_le_bytes(), y.to_le_bytes()]);
+            }
+            SceneValue::UVec3 { x, y, z } => {
+                self.body.push(VALUE_UVEC3);
+                self.words(&[x.to_le_bytes(), y.to_le_bytes(), z.to_le_bytes()]);
+            }
+            SceneValue::UVec4 { x, y, z, w } => {
+                self.body.push(VALUE_UVEC4);
+                self.words(&[
+                    x.to_le_bytes(),
+                    y.to_le_bytes(),
+                    z.to_le_bytes(),
+                    w.to_le_bytes(),
+                ]);
+            }
+            SceneValue::Color { r, g, b, a } => {
+                self.body.push(VALUE_COLOR);
+                self.body.extend_from_slice(&[*r, *g, *b, *a]);
+            }
+            SceneValue::Rect { x, y, w, h } => {
+                self.body.push(VALUE_RECT);
+                self.words(&[
+                    x.to_le_bytes(),
+                    y.to_le_bytes(),
+                    w.to_le_bytes(),
+                    h.to_le_bytes(),
+                ]);
+            }
+            SceneValue::Str(v) => {
+                self.body.push(VALUE_STR);
+                self.string(v.as_ref());
+            }
+            SceneValue::Hashed(v) => {
+                self.body.push(VALUE_HASHED);
+                self.body.extend_from_slice(&v.to_le_bytes());
+            }
+            SceneValue::Key(v) => {
+                self.body.push(VALUE_KEY);
+                self.string(v.as_ref());
+            }
+            SceneValue::Object(fields) => {
+                self.body.push(VALUE_OBJECT);
+                self.fields(fields);
+            }
+            SceneValue::Array(items) => {
+                self.body.push(VALUE_ARRAY);
+                write_varint(&mut self.body, items.len() as u64);
+                for item in items.iter() {
+                    self.value(item);
+                }
+            }
+        }
+    }
+
+    fn words(&mut self, words: &[[u8; 4]]) {
+        for word in words {
+            self.body.extend_from_slice(word);
+        }
+    }
+
+    fn opt_key(&mut self, key: Option<SceneKey>) {
+        write_varint(&mut self.body, key.map_or(0, |key| key.as_u32() as u64 + 1));
+    }
+
+    fn string(&mut self, s: &'a str) {
+        let id = match self.string_ids.get(s) {
+            Some(id) => *id,
+            None => {
+                let id = self.strings.len() as u64;
+                self.strings.push(s);
+                self.string_ids.insert(s, id);
+                self.strings_len += s.len() + 2;
+                id
+            }
+        };
+        write_varint(&mut self.body, id);
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| format!("compiled scene truncated at byte {}", self.pos))?;
+        let out = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(out)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let mut out = [0; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn varint(&mut self) -> Result<u64, String> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(format!(
+            "compiled scene varint overflow at byte {}",
+            self.pos
+        ))
+    }
+
+    /// Element count, capped by remaining bytes so corrupt input can't force
+    /// a huge allocation (every element takes at least one byte).
+    fn count(&mut self) -> Result<usize, String> {
+        let count = self.varint()?;
+        let remaining = (self.bytes.len() - self.pos) as u64;
+        if count > remaining {
+            return Err(format!(
+                "compiled scene count {count} exceeds remaining {remaining} bytes"
+            ));
+        }
+        Ok(count as usize)
+    }
+
+    fn key(&mut self) -> Result<SceneKey, String> {
+        let raw = self.varint()?;
+        u32::try_from(raw)
+            .map(SceneKey::new)
+            .map_err(|_| format!("compiled scene key {raw} out of range"))
+    }
+}
+
+struct Decoder<'a> {
+    reader: Reader<'a>,
+    strings: Vec<String>,
+}
+
+impl Decoder<'_> {
+    fn scene(&mut self) -> Result<Scene, String> {
+        let root = match self.reader.varint()? {
+            0 => None,
+            raw => Some(SceneKey::new(u32::try_from(raw - 1).map_err(|_| {
+                format!("compiled scene root key {raw} out of range")
+            })?)),
+        };
+        let key_count = self.reader.count()?;
+        let mut key_names = Vec::with_capacity(key_count);
+        for _ in 0..key_count {
+            key_names.push(Cow::Owned(self.string()?));
+        }
+        let node_count = self.reader.count()?;
+        let mut nodes = Vec::with_capacity(node_count);
+        for _ in 0..node_count {
+            nodes.push(self.node()?);
+        }
+        Ok(Scene {
+            nodes: Cow::Owned(nodes),
+            root,
+            key_names: Cow::Owned(key_names),
+        })
+    }
+
+    fn node(&mut self) -> Result<SceneNodeEntry, String> {
+        let flags = self.reader.u8()?;
+        let key = self.reader.key()?;
+        let name = self.opt_string(flags & NODE_HAS_NAME != 0)?;
+        let parent = if flags & NODE_HAS_PARENT != 0 {
+            Some(self.reader.key()?)
+        } else {
+            None
+        };
+        let script = self.opt_string(flags & NODE_HAS_SCRIPT != 0)?;
+        let root_of = self.opt_string(flags & NODE_HAS_ROOT_OF != 0)?;
+        let tag_count = self.reader.count()?;
+        let mut tags = Vec::with_capacity(tag_count);
+        for _ in 0..tag_count {
+            tags.push(Cow::Owned(self.string()?));
+        }
+        let child_count = self.reader.count()?;
+        let mut children = Vec::with_capacity(child_count);
+        for _ in 0..child_count {
+            children.push(self.reader.key()?);
+        }
+        // script var + meta keys are user names, never built-in fields
+        let script_vars = custom_script_var_fields(self.fields(0)?);
+        let meta = custom_script_var_fields(self.fields(0)?);
+        let mut connections = Vec::new();
+        if flags & NODE_HAS_CONNECTIONS != 0 {
+            let count = self.reader.count()?;
+            connections.reserve(count);
+            for _ in 0..count {
+                connections.push(SceneConnection {
+                    signal: Cow::Owned(self.string()?),
+                    target: Cow::Owned(self.string()?),
+                    method: Cow::Owned(self.string()?),
+                });
+            }
+        }
+        let data = self.data(0)?;
+        Ok(SceneNodeEntry {
+            data,
+            has_data_override: flags & NODE_HAS_DATA_OVERRIDE != 0,
+            key,
+            name,
+            tags: Cow::Owned(tags),
+            children: Cow::Owned(children),
+            parent,
+            script,
+            clear_script: flags & NODE_CLEAR_SCRIPT != 0,
+            enabled: flags & NODE_DISABLED == 0,
+            root_of,
+            script_vars: Cow::Owned(script_vars),
+            meta: Cow::Owned(meta),
+            connections: Cow::Owned(connections),
+        })
+    }
+
+    fn data(&mut self, depth: u32) -> Result<SceneNodeData, String> {
+        if depth > MAX_DECODE_DEPTH {
+            return Err("compiled scene node data nests too deep".to_string());
+        }
+        let type_name = self.string_ref()?;
+        let node_type = NodeType::from_str(type_name)?;
+        let fields = self.fields(depth)?;
+        let base = match self.reader.u8()? {
+            0 => None,
+            1 => Some(SceneNodeDataBase::Owned(Box::new(self.data(depth + 1)?))),
+            other => return Err(format!("compiled scene base flag {other} is invalid")),
+        };
+        Ok(SceneNodeData::new(node_type, Cow::Owned(fields), base))
+    }
+
+    fn fields(&mut self, depth: u32) -> Result<Vec<SceneObjectField>, String> {
+        let count = self.reader.count()?;
+        let mut fields = Vec::with_capacity(count);
+        for _ in 0..count {
+            let name = SceneFieldName::from_name(self.string()?);
+            let value = self.value(depth)?;
+            fields.push((name, value));
+        }
+        Ok(fields)
+    }
+
+    fn value(&mut self, depth: u32) -> Result<SceneValue, String> {
+        if depth > MAX_DECODE_DEPTH {
+            return Err("compiled scene value nests too deep".to_string());
+        }
+        let tag = self.reader.u8()?;
+        Ok(match tag {
+            VALUE_BOOL => SceneValue::Bool(self.reader.u8()? != 0),
+            VALUE_I32 => SceneValue::I32(i32::from_le_bytes(self.reader.array()?)),
+            VALUE_F32 => SceneValue::F32(self.f32()?),
+            VALUE_VEC2 => SceneValue::Vec2 {
+                x: self.f32()?,
+                y: self.f32()?,
+            },
+            VALUE_VEC3 => SceneValue::Vec3 {
+                x: self.f32()?,
+                y: self.f32()?,
+                z: self.f32()?,
+            },
+            VALUE_VEC4 => SceneValue::Vec4 {
+                x: self.f32()?,
+                y: self.f32()?,
+                z: self.f32()?,
+                w: self.f32()?,
+            },
+            VALUE_IVEC2 => SceneValue::IVec2 {
+                x: self.i32()?,
+                y: self.i32()?,
+            },
+            VALUE_IVEC3 => SceneValue::IVec3 {
+                x: self.i32()?,
+                y: self.i32()?,
+                z: self.i32()?,
+            },
+            VALUE_IVEC4 => SceneValue::IVec4 {
+                x: self.i32()?,
+                y: self.i32()?,
+                z: self.i32()?,
+                w: self.i32()?,
+            },
+            VALUE_UVEC2 => SceneValue::UVec2 {
+                x: self.u32()?,
+                y: self.u32()?,
+            },
+            VALUE_UVEC3 => SceneValue::UVec3 {
+                x: self.u32()?,
+                y: self.u32()?,
+                z: self.u32()?,
+            },
+            VALUE_UVEC4 => SceneValue::UVec4 {
+                x: self.u32()?,
+                y: self.u32()?,
+                z: self.u32()?,
+                w: self.u32()?,
+            },
+            VALUE_COLOR => {
+                let [r, g, b, a] = self.reader.array()?;
+                SceneValue::Color { r, g, b, a }
+            }
+            VALUE_RECT => SceneValue::Rect {
+                x: self.f32()?,
+                y: self.f32()?,
+                w: self.f32()?,
+                h: self.f32()?,
+            },
+            VALUE_STR => SceneValue::Str(Cow::Owned(self.string()?)),
+            VALUE_HASHED => SceneValue::Hashed(u64::from_le_bytes(self.reader.array()?)),
+            VALUE_KEY => SceneValue::Key(SceneValueKey::from(self.string()?)),
+            VALUE_OBJECT => SceneValue::Object(Cow::Owned(self.fields(depth + 1)?)),
+            VALUE_ARRAY => {
+                let count = self.reader.count()?;
+                let mut items = Vec::with_capacity(count);
+                for _ in 0..count {
+                    items.push(self.value(depth + 1)?);
+                }
+                SceneValue::Array(Cow::Owned(items))
+            }
+            other => return Err(format!("compiled scene value tag {other} is invalid")),
+        })
+    }
+
+    fn f32(&mut self) -> Result<f32, String> {
+        Ok(f32::from_le_bytes(self.reader.array()?))
+    }
+
+    fn i32(&mut self) -> Result<i32, String> {
+        Ok(i32::from_le_bytes(self.reader.array()?))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.reader.array()?))
+    }
+
+    fn string_ref(&mut self) -> Result<&str, String> {
+        let id = self.reader.varint()?;
+        usize::try_from(id)
+            .ok()
+            .and_then(|id| self.strings.get(id))
+            .map(String::as_str)
+            .ok_or_else(|| format!("compiled scene string id {id} out of range"))
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.string_ref().map(str::to_string)
+    }
+
+    fn opt_string(&mut self, present: bool) -> Result<Option<Cow<'static, str>>, String> {
+        if !present {
+            return Ok(None);
+        }
+        self.string().map(|s| Some(Cow::Owned(s)))
+    }
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
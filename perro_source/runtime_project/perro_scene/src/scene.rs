@@ -93,6 +93,10 @@ pub enum SceneFieldName {
     Friction,
     Restitution,
     Density,
+    OneWay,
+    FloorMaxAngle,
+    FloorSnapDistance,
+    InheritPlatformVelocity,
     Mass,
     ContinuousCollisionDetection,
     LinearVelocity,
@@ -256,6 +260,10 @@ impl SceneFieldName {
             Self::Friction => "friction",
             Self::Restitution => "restitution",
             Self::Density => "density",
+            Self::OneWay => "one_way",
+            Self::FloorMaxAngle => "floor_max_angle",
+            Self::FloorSnapDistance => "floor_snap_distance",
+            Self::InheritPlatformVelocity => "inherit_platform_velocity",
             Self::Mass => "mass",
             Self::ContinuousCollisionDetection => "continuous_collision_detection",
             Self::LinearVelocity => "linear_velocity",
@@ -429,6 +437,10 @@ impl SceneFieldName {
             "friction" => Self::Friction,
             "restitution" => Self::Restitution,
             "density" => Self::Density,
+            "one_way" => Self::OneWay,
+            "floor_max_angle" => Self::FloorMaxAngle,
+            "floor_snap_distance" => Self::FloorSnapDistance,
+            "inherit_platform_velocity" => Self::InheritPlatformVelocity,
             "mass" => Self::Mass,
             "continuous_collision_detection" => Self::ContinuousCollisionDetection,
             "linear_velocity" => Self::LinearVelocity,
@@ -580,15 +592,65 @@ pub enum SceneValue {
     Bool(bool),
     I32(i32),
     F32(f32),
-    Vec2 { x: f32, y: f32 },
-    Vec3 { x: f32, y: f32, z: f32 },
-    Vec4 { x: f32, y: f32, z: f32, w: f32 },
-    IVec2 { x: i32, y: i32 },
-    IVec3 { x: i32, y: i32, z: i32 },
-    IVec4 { x: i32, y: i32, z: i32, w: i32 },
-    UVec2 { x: u32, y: u32 },
-    UVec3 { x: u32, y: u32, z: u32 },
-    UVec4 { x: u32, y: u32, z: u32, w: u32 },
+    Vec2 {
+        x: f32,
+        y: f32,
+    },
+    Vec3 {
+        x: f32,
+        y: f32,
+        z: f32,
+    },
+    Vec4 {
+        x: f32,
+        y: f32,
+        z: f32,
+        w: f32,
+    },
+    IVec2 {
+        x: i32,
+        y: i32,
+    },
+    IVec3 {
+        x: i32,
+        y: i32,
+        z: i32,
+    },
+    IVec4 {
+        x: i32,
+        y: i32,
+        z: i32,
+        w: i32,
+    },
+    UVec2 {
+        x: u32,
+        y: u32,
+    },
+    UVec3 {
+        x: u32,
+        y: u32,
+        z: u32,
+    },
+    UVec4 {
+        x: u32,
+        y: u32,
+        z: u32,
+        w: u32,
+    },
+    /// `#RRGGBB` / `#RRGGBBAA` literal; channels kept as written.
+    Color {
+        r: u8,
+        g: u8,
+        b: u8,
+        a: u8,
+    },
+    /// `rect(x, y, w, h)` literal.
+    Rect {
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+    },
     Str(Cow<'static, str>),
     Hashed(u64),
     Key(SceneValueKey),
@@ -640,6 +702,25 @@ impl SceneValue {
         }
     }
 
+    /// Normalized RGBA from a color literal, `Vec4`, or `Vec3` (opaque).
+    pub fn as_color(&self) -> Option<[f32; 4]> {
+        match self {
+            Self::Color { r, g, b, a } => Some(color_channels([*r, *g, *b, *a])),
+            Self::Vec4 { x, y, z, w } => Some([*x, *y, *z, *w]),
+            Self::Vec3 { x, y, z } => Some([*x, *y, *z, 1.0]),
+            _ => None,
+        }
+    }
+
+    /// `(x, y, w, h)` from a rect literal or `Vec4`.
+    pub fn as_rect(&self) -> Option<(f32, f32, f32, f32)> {
+        match self {
+            Self::Rect { x, y, w, h } => Some((*x, *y, *w, *h)),
+            Self::Vec4 { x, y, z, w } => Some((*x, *y, *z, *w)),
+            _ => None,
+        }
+    }
+
     pub fn as_ivec2(&self) -> Option<(i32, i32)> {
         match self {
             Self::IVec2 { x, y } => Some((*x, *y)),
@@ -720,6 +801,8 @@ impl TryFrom<&SceneValue> for ConstParamValue {
             SceneValue::Vec2 { x, y } => Ok(Self::Vec2([*x, *y])),
             SceneValue::Vec3 { x, y, z } => Ok(Self::Vec3([*x, *y, *z])),
             SceneValue::Vec4 { x, y, z, w } => Ok(Self::Vec4([*x, *y, *z, *w])),
+            SceneValue::Color { r, g, b, a } => Ok(Self::Vec4(color_channels([*r, *g, *b, *a]))),
+            SceneValue::Rect { x, y, w, h } => Ok(Self::Vec4([*x, *y, *w, *h])),
             _ => Err(()),
         }
     }
@@ -747,6 +830,10 @@ impl From<ConstParamValue> for SceneValue {
     }
 }
 
+fn color_channels(rgba: [u8; 4]) -> [f32; 4] {
+    rgba.map(|c| f32::from(c) / 255.0)
+}
+
 #[derive(Clone, Copy)]
 pub struct SceneFieldIterRef<'a> {
     fields: &'a [SceneObjectField],
@@ -800,8 +887,27 @@ pub struct SceneNodeEntry {
     pub parent: Option<SceneKey>,
     pub script: Option<Cow<'static, str>>,
     pub clear_script: bool,
+    /// `enabled = false` header: the node and its subtree skip scripts,
+    /// internal updates, physics, and rendering until enabled at runtime.
+    pub enabled: bool,
     pub root_of: Option<Cow<'static, str>>,
     pub script_vars: Cow<'static, [SceneObjectField]>,
+    /// `meta = { .. }` header: untyped per-node values scripts read w/
+    /// `get_meta!`, independent of any script state.
+    pub meta: Cow<'static, [SceneObjectField]>,
+    pub connections: Cow<'static, [SceneConnection]>,
+}
+
+/// A `connect signal -> target.method` line on a scene node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SceneConnection {
+    /// Event on the declaring node. Binds the `{node name}_{signal}` signal,
+    /// e.g. `pressed` on `play_button` binds `play_button_pressed`.
+    pub signal: Cow<'static, str>,
+    /// `@key` of a node in the same scene, or a `/root/a/b` name path where
+    /// `/root` is the scene root.
+    pub target: Cow<'static, str>,
+    pub method: Cow<'static, str>,
 }
 
 #[derive(Debug, Clone)]
@@ -1,6 +1,6 @@
 use crate::{
-    Parser, Scene, SceneKey, SceneNodeData, SceneNodeEntry, SceneObjectField, SceneValue,
-    default_scene_field_value,
+    Parser, Scene, SceneKey, SceneNodeData, SceneNodeEntry, SceneObjectField, SceneParseError,
+    SceneValue, default_scene_field_value,
 };
 use perro_structs::BitMask;
 use std::borrow::Cow;
@@ -25,7 +25,14 @@ impl SceneDoc {
         Self::try_parse_lenient(src).unwrap_or_else(|err| panic!("{err}"))
     }
 
-    pub(crate) fn try_parse_lenient(src: &str) -> Result<Self, String> {
+    /// Parses a scene document, returning an error for invalid input.
+    pub fn try_parse(src: &str) -> Result<Self, SceneParseError> {
+        let vars = Parser::new(src).try_collect_var_entries()?;
+        let scene = Parser::new(src).try_parse_scene()?;
+        Ok(Self::from_parts(vars, scene))
+    }
+
+    pub(crate) fn try_parse_lenient(src: &str) -> Result<Self, SceneParseError> {
         let vars = Parser::new_lenient(src).try_collect_var_entries()?;
         let scene = Parser::new(src).try_parse_scene_lenient()?;
         Ok(Self::from_parts(vars, scene))
@@ -110,13 +117,13 @@ impl<'a> SceneWrite<'a> {
     }
 }
 
-struct SceneDocWriter<'a> {
+pub(crate) struct SceneDocWriter<'a> {
     doc: &'a SceneDoc,
     value_vars: HashMap<String, String>,
 }
 
 impl<'a> SceneDocWriter<'a> {
-    fn new(doc: &'a SceneDoc, dedup: bool) -> Self {
+    pub(crate) fn new(doc: &'a SceneDoc, dedup: bool) -> Self {
         let value_vars = if dedup {
             collect_dedupe_vars(doc)
         } else {
@@ -160,7 +167,7 @@ impl<'a> SceneDocWriter<'a> {
         out
     }
 
-    fn write_node(&self, node: &SceneNodeEntry, out: &mut String) {
+    pub(crate) fn write_node(&self, node: &SceneNodeEntry, out: &mut String) {
         out.push('[');
         let node_key = self.doc.scene.key_name_or_id(node.key);
         out.push_str(node_key.as_ref());
@@ -197,6 +204,9 @@ impl<'a> SceneDocWriter<'a> {
         } else if node.clear_script {
             out.push_str("script = null\n");
         }
+        if !node.enabled {
+            out.push_str("enabled = false\n");
+        }
         if let Some(root_of) = &node.root_of {
             out.push_str("root_of = ");
             write_str(root_of, out);
@@ -212,6 +222,20 @@ impl<'a> SceneDocWriter<'a> {
             );
             out.push('\n');
         }
+        if !node.meta.is_empty() {
+            out.push_str("meta = ");
+            self.write_object(node.meta.as_ref(), out, 0, node.meta.len() > 1);
+            out.push('\n');
+        }
+        for connection in node.connections.iter() {
+            out.push_str("connect ");
+            out.push_str(&connection.signal);
+            out.push_str(" -> ");
+            out.push_str(&connection.target);
+            out.push('.');
+            out.push_str(&connection.method);
+            out.push('\n');
+        }
 
         if node.has_data_override {
             self.write_data(&node.data, out, 1);
@@ -302,6 +326,8 @@ impl<'a> SceneDocWriter<'a> {
             SceneValue::UVec2 { x, y } => write_uint_vec(out, &[*x, *y]),
             SceneValue::UVec3 { x, y, z } => write_uint_vec(out, &[*x, *y, *z]),
             SceneValue::UVec4 { x, y, z, w } => write_uint_vec(out, &[*x, *y, *z, *w]),
+            SceneValue::Color { r, g, b, a } => write_color(out, [*r, *g, *b, *a]),
+            SceneValue::Rect { x, y, w, h } => write_rect(out, [*x, *y, *w, *h]),
             SceneValue::Str(v) => write_str(v, out),
             SceneValue::Hashed(v) => out.push_str(&v.to_string()),
             SceneValue::Key(v) => self.write_key_value(v.as_ref(), out),
@@ -322,6 +348,22 @@ impl<'a> SceneDocWriter<'a> {
         self.write_value(value, out, depth, true);
     }
 
+    /// One-line field value, for in-place patches.
+    pub(crate) fn write_field_value_inline(
+        &self,
+        name: &str,
+        value: &SceneValue,
+        out: &mut String,
+    ) {
+        if is_node_ref_field(name)
+            && let SceneValue::Key(key) = value
+        {
+            self.write_node_ref_value(key.as_ref(), out);
+            return;
+        }
+        self.write_value_inline(value, out);
+    }
+
     fn write_key_value(&self, value: &str, out: &mut String) {
         if self
             .doc
@@ -404,7 +446,7 @@ impl<'a> SceneDocWriter<'a> {
         out.push(']');
     }
 
-    fn write_value_inline(&self, value: &SceneValue, out: &mut String) {
+    pub(crate) fn write_value_inline(&self, value: &SceneValue, out: &mut String) {
         let key = value_key(value);
         if let Some(var) = self.value_vars.get(&key) {
             out.push('$');
@@ -449,6 +491,8 @@ impl<'a> SceneDocWriter<'a> {
             SceneValue::UVec2 { x, y } => write_uint_vec(out, &[*x, *y]),
             SceneValue::UVec3 { x, y, z } => write_uint_vec(out, &[*x, *y, *z]),
             SceneValue::UVec4 { x, y, z, w } => write_uint_vec(out, &[*x, *y, *z, *w]),
+            SceneValue::Color { r, g, b, a } => write_color(out, [*r, *g, *b, *a]),
+            SceneValue::Rect { x, y, w, h } => write_rect(out, [*x, *y, *w, *h]),
             SceneValue::Str(v) => write_str(v, out),
             SceneValue::Hashed(v) => out.push_str(&v.to_string()),
             SceneValue::Key(v) => self.write_key_value(v.as_ref(), out),
@@ -559,7 +603,7 @@ fn parse_bitmask_call(raw: &str) -> Option<BitMask> {
     }
 }
 
-fn sync_children_from_parents(scene: &mut Scene) {
+pub(crate) fn sync_children_from_parents(scene: &mut Scene) {
     let mut children: BTreeMap<String, Vec<SceneKey>> = BTreeMap::new();
     for node in scene.nodes.iter() {
         if let Some(parent) = &node.parent {
@@ -594,6 +638,7 @@ fn collect_dedupe_vars(doc: &SceneDoc) -> HashMap<String, String> {
     let mut counts = BTreeMap::<String, usize>::new();
     for node in doc.scene.nodes.iter() {
         collect_fields(&node.script_vars, &mut counts);
+        collect_fields(&node.meta, &mut counts);
         collect_data(&node.data, &mut counts);
     }
     let mut out = HashMap::new();
@@ -687,6 +732,8 @@ fn write_value_plain(value: &SceneValue, out: &mut String) {
         SceneValue::UVec2 { x, y } => write_uint_vec(out, &[*x, *y]),
         SceneValue::UVec3 { x, y, z } => write_uint_vec(out, &[*x, *y, *z]),
         SceneValue::UVec4 { x, y, z, w } => write_uint_vec(out, &[*x, *y, *z, *w]),
+        SceneValue::Color { r, g, b, a } => write_color(out, [*r, *g, *b, *a]),
+        SceneValue::Rect { x, y, w, h } => write_rect(out, [*x, *y, *w, *h]),
         SceneValue::Str(v) => write_str(v, out),
         SceneValue::Hashed(v) => out.push_str(&v.to_string()),
         SceneValue::Key(v) => write_key_value(v.as_ref(), out),
@@ -715,6 +762,26 @@ fn write_value_plain(value: &SceneValue, out: &mut String) {
     }
 }
 
+/// `#rrggbb`, or `#rrggbbaa` when not opaque.
+fn write_color(out: &mut String, rgba: [u8; 4]) {
+    let len = if rgba[3] == u8::MAX { 3 } else { 4 };
+    out.push('#');
+    for channel in &rgba[..len] {
+        out.push_str(&format!("{channel:02x}"));
+    }
+}
+
+fn write_rect(out: &mut String, values: [f32; 4]) {
+    out.push_str("rect(");
+    for (idx, value) in values.iter().enumerate() {
+        if idx > 0 {
+            out.push_str(", ");
+        }
+        out.push_str(&fmt_f32(*value));
+    }
+    out.push(')');
+}
+
 fn write_int_vec(out: &mut String, values: &[i32]) {
     out.push('(');
     for (idx, value) in values.iter().enumerate() {
@@ -737,7 +804,7 @@ fn write_uint_vec(out: &mut String, values: &[u32]) {
     out.push(')');
 }
 
-fn write_str(value: &str, out: &mut String) {
+pub(crate) fn write_str(value: &str, out: &mut String) {
     out.push('"');
     for ch in value.chars() {
         match ch {
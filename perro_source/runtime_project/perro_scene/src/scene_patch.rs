@@ -0,0 +1,382 @@
+//! Minimal edits between two versions of a [`Scene`].
+//!
+//! [`diff`] lists what changed node by node and field by field; [`apply_patch`]
+//! replays that list onto the old scene. The editor uses it to ship small
+//! changes; hot reload and collaborative editing can apply it without
+//! rebuilding the whole tree. Nodes are matched by scene key (`[key]`), so key
+//! ids may differ between the two scenes.
+
+use crate::{
+    Scene, SceneConnection, SceneFieldName, SceneKey, SceneNodeData, SceneNodeEntry,
+    SceneObjectField, SceneValue, scene_doc::sync_children_from_parents,
+};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+/// Ordered edits that turn one scene into another. Built by [`diff`].
+#[derive(Debug, Clone, Default)]
+pub struct ScenePatch {
+    pub ops: Vec<ScenePatchOp>,
+}
+
+impl ScenePatch {
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+}
+
+/// One scene edit. `key` is the scene key name of the edited node.
+#[derive(Debug, Clone)]
+pub enum ScenePatchOp {
+    /// `$root` now points at `key`, or nothing.
+    SetRoot(Option<Cow<'static, str>>),
+    /// Node removed. Its children get their own ops.
+    RemoveNode { key: Cow<'static, str> },
+    /// New node. `entry.key`, `entry.parent`, and `entry.children` are
+    /// rebuilt from `key` and `parent` on apply.
+    AddNode {
+        key: Cow<'static, str>,
+        parent: Option<Cow<'static, str>>,
+        entry: Box<SceneNodeEntry>,
+    },
+    SetParent {
+        key: Cow<'static, str>,
+        parent: Option<Cow<'static, str>>,
+    },
+    /// Node type or `@extends` base changed; replaces the whole data block.
+    SetData {
+        key: Cow<'static, str>,
+        data: SceneNodeData,
+        has_data_override: bool,
+    },
+    /// Data field added or changed.
+    SetField {
+        key: Cow<'static, str>,
+        field: SceneFieldName,
+        value: SceneValue,
+    },
+    RemoveField {
+        key: Cow<'static, str>,
+        field: SceneFieldName,
+    },
+    SetName {
+        key: Cow<'static, str>,
+        name: Option<Cow<'static, str>>,
+    },
+    SetTags {
+        key: Cow<'static, str>,
+        tags: Cow<'static, [Cow<'static, str>]>,
+    },
+    SetScript {
+        key: Cow<'static, str>,
+        script: Option<Cow<'static, str>>,
+        clear_script: bool,
+    },
+    SetEnabled {
+        key: Cow<'static, str>,
+        enabled: bool,
+    },
+    SetRootOf {
+        key: Cow<'static, str>,
+        root_of: Option<Cow<'static, str>>,
+    },
+    SetScriptVars {
+        key: Cow<'static, str>,
+        script_vars: Cow<'static, [SceneObjectField]>,
+    },
+    SetMeta {
+        key: Cow<'static, str>,
+        meta: Cow<'static, [SceneObjectField]>,
+    },
+    SetConnections {
+        key: Cow<'static, str>,
+        connections: Cow<'static, [SceneConnection]>,
+    },
+}
+
+/// Edits that turn `old` into `new`. Applying the result to `old` with
+/// [`apply_patch`] yields the same nodes as `new`; added nodes go last.
+pub fn diff(old: &Scene, new: &Scene) -> ScenePatch {
+    let mut ops = Vec::new();
+    let old_nodes: HashMap<Cow<'_, str>, &SceneNodeEntry> = old
+        .nodes
+        .iter()
+        .map(|node| (old.key_name_or_id(node.key), node))
+        .collect();
+    let new_keys: HashSet<Cow<'_, str>> = new
+        .nodes
+        .iter()
+        .map(|node| new.key_name_or_id(node.key))
+        .collect();
+
+    for node in old.nodes.iter() {
+        if !new_keys.contains(&old.key_name_or_id(node.key)) {
+            ops.push(ScenePatchOp::RemoveNode {
+                key: owned_key(old, node.key),
+            });
+        }
+    }
+
+    for node in new.nodes.iter() {
+        let key = owned_key(new, node.key);
+        let parent = node.parent.map(|parent| owned_key(new, parent));
+        let Some(prev) = old_nodes.get(key.as_ref()) else {
+            ops.push(ScenePatchOp::AddNode {
+                key,
+                parent,
+                entry: Box::new(node.clone()),
+            });
+            continue;
+        };
+        diff_node(old, prev, node, key, parent, &mut ops);
+    }
+
+    // last, so a new root node already exists
+    let old_root = old.root.map(|root| owned_key(old, root));
+    let new_root = new.root.map(|root| owned_key(new, root));
+    if old_root != new_root {
+        ops.push(ScenePatchOp::SetRoot(new_root));
+    }
+    ScenePatch { ops }
+}
+
+fn diff_node(
+    old: &Scene,
+    prev: &SceneNodeEntry,
+    node: &SceneNodeEntry,
+    key: Cow<'static, str>,
+    parent: Option<Cow<'static, str>>,
+    ops: &mut Vec<ScenePatchOp>,
+) {
+    if prev.parent.map(|parent| owned_key(old, parent)) != parent {
+        ops.push(ScenePatchOp::SetParent {
+            key: key.clone(),
+            parent,
+        });
+    }
+
+    let same_shape = prev.data.node_type == node.data.node_type
+        && prev.has_data_override == node.has_data_override
+        && same_base(&prev.data, &node.data);
+    if same_shape {
+        diff_fields(&prev.data.fields, &node.data.fields, key.clone(), ops);
+    } else {
+        ops.push(ScenePatchOp::SetData {
+            key: key.clone(),
+            data: node.data.clone(),
+            has_data_override: node.has_data_override,
+        });
+    }
+
+    if prev.name != node.name {
+        ops.push(ScenePatchOp::SetName {
+            key: key.clone(),
+            name: node.name.clone(),
+        });
+    }
+    if prev.tags != node.tags {
+        ops.push(ScenePatchOp::SetTags {
+            key: key.clone(),
+            tags: node.tags.clone(),
+        });
+    }
+    if prev.script != node.script || prev.clear_script != node.clear_script {
+        ops.push(ScenePatchOp::SetScript {
+            key: key.clone(),
+            script: node.script.clone(),
+            clear_script: node.clear_script,
+        });
+    }
+    if prev.enabled != node.enabled {
+        ops.push(ScenePatchOp::SetEnabled {
+            key: key.clone(),
+            enabled: node.enabled,
+        });
+    }
+    if prev.root_of != node.root_of {
+        ops.push(ScenePatchOp::SetRootOf {
+            key: key.clone(),
+            root_of: node.root_of.clone(),
+        });
+    }
+    if prev.script_vars != node.script_vars {
+        ops.push(ScenePatchOp::SetScriptVars {
+            key: key.clone(),
+            script_vars: node.script_vars.clone(),
+        });
+    }
+    if prev.meta != node.meta {
+        ops.push(ScenePatchOp::SetMeta {
+            key: key.clone(),
+            meta: node.meta.clone(),
+        });
+    }
+    if prev.connections != node.connections {
+        ops.push(ScenePatchOp::SetConnections {
+            key,
+            connections: node.connections.clone(),
+        });
+    }
+}
+
+fn diff_fields(
+    old: &[SceneObjectField],
+    new: &[SceneObjectField],
+    key: Cow<'static, str>,
+    ops: &mut Vec<ScenePatchOp>,
+) {
+    for (field, value) in new {
+        if field_value(old, field) != Some(value) {
+            ops.push(ScenePatchOp::SetField {
+                key: key.clone(),
+                field: field.clone(),
+                value: value.clone(),
+            });
+        }
+    }
+    for (field, _) in old {
+        if field_value(new, field).is_none() {
+            ops.push(ScenePatchOp::RemoveField {
+                key: key.clone(),
+                field: field.clone(),
+            });
+        }
+    }
+}
+
+/// Last value wins, matching how node builders read repeated fields.
+fn field_value<'a>(
+    fields: &'a [SceneObjectField],
+    field: &SceneFieldName,
+) -> Option<&'a SceneValue> {
+    fields
+        .iter()
+        .rev()
+        .find(|(name, _)| name == field)
+        .map(|(_, value)| value)
+}
+
+fn same_base(a: &SceneNodeData, b: &SceneNodeData) -> bool {
+    match (a.base_ref(), b.base_ref()) {
+        (None, None) => true,
+        (Some(a), Some(b)) => a.node_type == b.node_type && a.fields == b.fields && same_base(a, b),
+        _ => false,
+    }
+}
+
+fn owned_key(scene: &Scene, key: SceneKey) -> Cow<'static, str> {
+    Cow::Owned(scene.key_name_or_id(key).into_owned())
+}
+
+/// Applies `patch` to `scene` in order. Fails on the first op that names a
+/// missing node or adds one that already exists; `scene` is left unchanged
+/// then.
+pub fn apply_patch(scene: &mut Scene, patch: &ScenePatch) -> Result<(), String> {
+    let mut out = scene.clone();
+    for op in &patch.ops {
+        apply_op(&mut out, op)?;
+    }
+    sync_children_from_parents(&mut out);
+    *scene = out;
+    Ok(())
+}
+
+fn apply_op(scene: &mut Scene, op: &ScenePatchOp) -> Result<(), String> {
+    match op {
+        ScenePatchOp::SetRoot(root) => {
+            scene.root = match root {
+                Some(key) => Some(existing_key(scene, key)?),
+                None => None,
+            };
+        }
+        ScenePatchOp::RemoveNode { key } => {
+            let index = node_index(scene, key)?;
+            scene.nodes.to_mut().remove(index);
+        }
+        ScenePatchOp::AddNode { key, parent, entry } => {
+            if node_index(scene, key).is_ok() {
+                return Err(format!("scene patch adds `{key}`, which already exists"));
+            }
+            let mut entry = entry.as_ref().clone();
+            entry.key = intern_key(scene, key);
+            entry.parent = parent.as_deref().map(|parent| intern_key(scene, parent));
+            entry.children = Cow::Owned(Vec::new());
+            scene.nodes.to_mut().push(entry);
+        }
+        ScenePatchOp::SetParent { key, parent } => {
+            let parent = parent.as_deref().map(|parent| intern_key(scene, parent));
+            node_mut(scene, key)?.parent = parent;
+        }
+        ScenePatchOp::SetData {
+            key,
+            data,
+            has_data_override,
+        } => {
+            let node = node_mut(scene, key)?;
+            node.data = data.clone();
+            node.has_data_override = *has_data_override;
+        }
+        ScenePatchOp::SetField { key, field, value } => {
+            let fields = node_mut(scene, key)?.data.fields.to_mut();
+            fields.retain(|(name, _)| name != field);
+            fields.push((field.clone(), value.clone()));
+        }
+        ScenePatchOp::RemoveField { key, field } => {
+            node_mut(scene, key)?
+                .data
+                .fields
+                .to_mut()
+                .retain(|(name, _)| name != field);
+        }
+        ScenePatchOp::SetName { key, name } => node_mut(scene, key)?.name = name.clone(),
+        ScenePatchOp::SetTags { key, tags } => node_mut(scene, key)?.tags = tags.clone(),
+        ScenePatchOp::SetScript {
+            key,
+            script,
+            clear_script,
+        } => {
+            let node = node_mut(scene, key)?;
+            node.script = script.clone();
+            node.clear_script = *clear_script;
+        }
+        ScenePatchOp::SetEnabled { key, enabled } => node_mut(scene, key)?.enabled = *enabled,
+        ScenePatchOp::SetRootOf { key, root_of } => {
+            node_mut(scene, key)?.root_of = root_of.clone();
+        }
+        ScenePatchOp::SetScriptVars { key, script_vars } => {
+            node_mut(scene, key)?.script_vars = script_vars.clone();
+        }
+        ScenePatchOp::SetMeta { key, meta } => node_mut(scene, key)?.meta = meta.clone(),
+        ScenePatchOp::SetConnections { key, connections } => {
+            node_mut(scene, key)?.connections = connections.clone();
+        }
+    }
+    Ok(())
+}
+
+fn node_index(scene: &Scene, key: &str) -> Result<usize, String> {
+    scene
+        .nodes
+        .iter()
+        .position(|node| scene.key_name_or_id(node.key) == key)
+        .ok_or_else(|| format!("scene patch names missing node `{key}`"))
+}
+
+fn node_mut<'a>(scene: &'a mut Scene, key: &str) -> Result<&'a mut SceneNodeEntry, String> {
+    let index = node_index(scene, key)?;
+    Ok(&mut scene.nodes.to_mut()[index])
+}
+
+fn existing_key(scene: &Scene, key: &str) -> Result<SceneKey, String> {
+    let index = node_index(scene, key)?;
+    Ok(scene.nodes[index].key)
+}
+
+/// Id for `key` in `scene`, adding it to the key table when new.
+fn intern_key(scene: &mut Scene, key: &str) -> SceneKey {
+    if let Some(index) = scene.key_names.iter().position(|name| name == key) {
+        return SceneKey::new(index as u32);
+    }
+    scene.key_names.to_mut().push(Cow::Owned(key.to_string()));
+    SceneKey::new((scene.key_names.len() - 1) as u32)
+}
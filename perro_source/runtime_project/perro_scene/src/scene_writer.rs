@@ -0,0 +1,339 @@
+use crate::parser::{SceneNodeSpans, SceneSpans};
+use crate::scene_doc::{SceneDocWriter, write_str};
+use crate::{
+    Parser, Scene, SceneDoc, SceneNodeData, SceneNodeEntry, SceneParseError, SceneValue, Span,
+    scene_node_field,
+};
+use std::collections::HashMap;
+
+/// Writes an edited [`Scene`] back over the text it was parsed from.
+///
+/// Comments, `$vars`, blank lines, and node order come from the source.
+/// A changed value is rewritten in place; a node that gains or loses fields
+/// is re-rendered as one block. Nodes new to the scene land after the node
+/// before them, so one edited field is a one-line diff.
+pub struct SceneWriter<'a> {
+    src: &'a str,
+    original: Scene,
+    spans: SceneSpans,
+}
+
+/// One header or type block value that differs from the source.
+enum NodeChange {
+    Header(&'static str, String),
+    Field(String, String),
+}
+
+impl<'a> SceneWriter<'a> {
+    pub fn try_new(src: &'a str) -> Result<Self, SceneParseError> {
+        Self::try_from_parser(Parser::new(src))
+    }
+
+    /// Use w/ [`Parser::with_scene_loader`] for sources that `@extends`.
+    pub fn try_from_parser(parser: Parser<'a>) -> Result<Self, SceneParseError> {
+        let src = parser.src();
+        let (original, spans) = parser.try_parse_scene_spans()?;
+        Ok(Self {
+            src,
+            original,
+            spans,
+        })
+    }
+
+    /// Scene as parsed from the source; edit a clone and pass it to `write`.
+    pub fn scene(&self) -> &Scene {
+        &self.original
+    }
+
+    pub fn write(&self, scene: &Scene) -> String {
+        let doc = SceneDoc::from_scene(scene.clone());
+        let writer = SceneDocWriter::new(&doc, false);
+        let block_index = self
+            .spans
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.key.as_str(), index))
+            .collect::<HashMap<_, _>>();
+
+        // Nodes w/o a source block, grouped after the last node w/ one.
+        // Slot 0 is right after the preamble.
+        let mut present = vec![false; self.spans.nodes.len()];
+        let mut appended = vec![Vec::new(); self.spans.nodes.len() + 1];
+        let mut slot = 0;
+        for node in scene.nodes.iter() {
+            let key = scene.key_name_or_id(node.key);
+            if let Some(&index) = block_index.get(key.as_ref()) {
+                present[index] = true;
+                slot = index + 1;
+                continue;
+            }
+            // Inherited from `@extends` and untouched: the base still has it.
+            if self
+                .original_node(key.as_ref())
+                .and_then(|old| node_changes(&self.original, old, scene, node, &writer))
+                .is_some_and(|changes| changes.is_empty())
+            {
+                continue;
+            }
+            appended[slot].push(node);
+        }
+
+        let mut out = String::new();
+        let mut root_patch = Vec::new();
+        let old_root = self.original.root.map(|k| self.original.key_name_or_id(k));
+        let new_root = scene.root.map(|k| scene.key_name_or_id(k));
+        if old_root != new_root
+            && let Some(new_root) = &new_root
+        {
+            match self.spans.root {
+                Some(span) => root_patch.push((span, format!("@{new_root}"))),
+                None => {
+                    out.push_str("$root = @");
+                    out.push_str(new_root);
+                    out.push('\n');
+                }
+            }
+        }
+
+        let preamble_end = self
+            .spans
+            .nodes
+            .first()
+            .map_or(self.src.len(), |node| node.block.start);
+        self.push_patched(&mut out, 0, preamble_end, &root_patch);
+        push_rendered(&mut out, &appended[0], &writer);
+
+        for (index, spans) in self.spans.nodes.iter().enumerate() {
+            let gap_start = index
+                .checked_sub(1)
+                .map_or(preamble_end, |prev| self.spans.nodes[prev].block.end);
+            // A dropped node's blank-line gap would double the spacing.
+            if present[index] || !self.src[gap_start..spans.block.start].trim().is_empty() {
+                self.push_patched(&mut out, gap_start, spans.block.start, &root_patch);
+            }
+            if present[index] {
+                if !out.is_empty() && !out.ends_with(char::is_whitespace) {
+                    out.push_str("\n\n");
+                }
+                self.push_node_block(&mut out, spans, scene, &writer);
+            }
+            push_rendered(&mut out, &appended[index + 1], &writer);
+        }
+
+        let tail_start = self
+            .spans
+            .nodes
+            .last()
+            .map_or(self.src.len(), |node| node.block.end);
+        self.push_patched(&mut out, tail_start, self.src.len(), &root_patch);
+        out
+    }
+
+    fn original_node(&self, key: &str) -> Option<&SceneNodeEntry> {
+        find_node(&self.original, key)
+    }
+
+    fn push_node_block(
+        &self,
+        out: &mut String,
+        spans: &SceneNodeSpans,
+        scene: &Scene,
+        writer: &SceneDocWriter<'_>,
+    ) {
+        let Some(node) = find_node(scene, &spans.key) else {
+            return;
+        };
+        let patches = self
+            .original_node(&spans.key)
+            .and_then(|old| node_changes(&self.original, old, scene, node, writer))
+            .and_then(|changes| change_patches(spans, node, changes));
+        match patches {
+            Some(patches) => self.push_patched(out, spans.block.start, spans.block.end, &patches),
+            None => {
+                let mut text = String::new();
+                writer.write_node(node, &mut text);
+                out.push_str(text.trim_end());
+            }
+        }
+    }
+
+    /// Copies `src[start..end]`, swapping in every patch inside that range.
+    fn push_patched(&self, out: &mut String, start: usize, end: usize, patches: &[(Span, String)]) {
+        let mut patches = patches
+            .iter()
+            .filter(|(span, _)| span.start >= start && span.end <= end)
+            .collect::<Vec<_>>();
+        patches.sort_by_key(|(span, _)| span.start);
+        let mut at = start;
+        for (span, text) in patches {
+            out.push_str(&self.src[at..span.start]);
+            out.push_str(text);
+            at = span.end;
+        }
+        out.push_str(&self.src[at..end]);
+    }
+}
+
+fn find_node<'s>(scene: &'s Scene, key: &str) -> Option<&'s SceneNodeEntry> {
+    scene
+        .nodes
+        .iter()
+        .find(|node| scene.key_name_or_id(node.key) == key)
+}
+
+fn push_rendered(out: &mut String, nodes: &[&SceneNodeEntry], writer: &SceneDocWriter<'_>) {
+    for node in nodes {
+        if !out.is_empty() && !out.ends_with("\n\n") {
+            out.push_str(if out.ends_with('\n') { "\n" } else { "\n\n" });
+        }
+        let mut text = String::new();
+        writer.write_node(node, &mut text);
+        out.push_str(text.trim_end());
+    }
+}
+
+/// Values in `new` that differ from `old`, rendered one-line.
+///
+/// `None` when the node cannot be patched value by value: its type,
+/// base block, or field set changed, or a header value was removed.
+fn node_changes(
+    old_scene: &Scene,
+    old: &SceneNodeEntry,
+    new_scene: &Scene,
+    new: &SceneNodeEntry,
+    writer: &SceneDocWriter<'_>,
+) -> Option<Vec<NodeChange>> {
+    if old.has_data_override != new.has_data_override
+        || old.data.node_type != new.data.node_type
+        || !same_data(old.data.base_ref(), new.data.base_ref())
+        || old.data.fields.len() != new.data.fields.len()
+    {
+        return None;
+    }
+
+    // `connect` lines have no single value to patch.
+    if old.connections != new.connections {
+        return None;
+    }
+
+    let mut changes = Vec::new();
+    if old.name != new.name {
+        let mut text = String::new();
+        write_str(new.name.as_deref()?, &mut text);
+        changes.push(NodeChange::Header("name", text));
+    }
+    if old.tags != new.tags {
+        let tags = new
+            .tags
+            .iter()
+            .map(|tag| SceneValue::Str(tag.clone()))
+            .collect::<Vec<_>>();
+        let mut text = String::new();
+        writer.write_value_inline(&SceneValue::Array(tags.into()), &mut text);
+        changes.push(NodeChange::Header("tags", text));
+    }
+    let old_parent = old.parent.map(|k| old_scene.key_name_or_id(k));
+    let new_parent = new.parent.map(|k| new_scene.key_name_or_id(k));
+    if old_parent != new_parent {
+        changes.push(NodeChange::Header("parent", format!("@{}", new_parent?)));
+    }
+    if old.script != new.script || old.clear_script != new.clear_script {
+        let text = match (&new.script, new.clear_script) {
+            (Some(script), _) => {
+                let mut text = String::new();
+                write_str(script, &mut text);
+                text
+            }
+            (None, true) => "null".to_string(),
+            (None, false) => return None,
+        };
+        changes.push(NodeChange::Header("script", text));
+    }
+    if old.enabled != new.enabled {
+        changes.push(NodeChange::Header("enabled", new.enabled.to_string()));
+    }
+    if old.root_of != new.root_of {
+        let mut text = String::new();
+        write_str(new.root_of.as_deref()?, &mut text);
+        changes.push(NodeChange::Header("root_of", text));
+    }
+    if old.script_vars != new.script_vars {
+        let mut text = String::new();
+        writer.write_value_inline(&SceneValue::Object(new.script_vars.clone()), &mut text);
+        changes.push(NodeChange::Header("script_vars", text));
+    }
+    if old.meta != new.meta {
+        let mut text = String::new();
+        writer.write_value_inline(&SceneValue::Object(new.meta.clone()), &mut text);
+        changes.push(NodeChange::Header("meta", text));
+    }
+
+    for (name, value) in new.data.fields.iter() {
+        let (_, old_value) = old
+            .data
+            .fields
+            .iter()
+            .find(|(old_name, _)| old_name == name)?;
+        if old_value != value {
+            let mut text = String::new();
+            writer.write_field_value_inline(name.as_ref(), value, &mut text);
+            changes.push(NodeChange::Field(name.to_string(), text));
+        }
+    }
+    Some(changes)
+}
+
+/// Maps changes onto source spans; `None` if one has no value to replace.
+fn change_patches(
+    spans: &SceneNodeSpans,
+    node: &SceneNodeEntry,
+    changes: Vec<NodeChange>,
+) -> Option<Vec<(Span, String)>> {
+    changes
+        .into_iter()
+        .map(|change| match change {
+            // `groups` lines feed the same tag list; rewrite the whole node.
+            NodeChange::Header("tags", _)
+                if spans.header.iter().any(|(written, _)| written == "groups") =>
+            {
+                None
+            }
+            // `vars` blocks merge into script_vars too.
+            NodeChange::Header("script_vars", _)
+                if spans.header.iter().any(|(written, _)| written == "vars") =>
+            {
+                None
+            }
+            NodeChange::Header(name, text) => spans
+                .header
+                .iter()
+                .rev()
+                .find(|(written, _)| written == name)
+                .map(|(_, span)| (*span, text)),
+            NodeChange::Field(name, text) => spans
+                .fields
+                .iter()
+                .rev()
+                .find(|(written, _)| {
+                    // Source may use an alias; compare by canonical spec name.
+                    scene_node_field(node.data.node_type, written)
+                        .map_or(written.as_str(), |field| field.name)
+                        == name
+                })
+                .map(|(_, span)| (*span, text)),
+        })
+        .collect()
+}
+
+fn same_data(a: Option<&SceneNodeData>, b: Option<&SceneNodeData>) -> bool {
+    match (a, b) {
+        (None, None) => true,
+        (Some(a), Some(b)) => {
+            a.node_type == b.node_type
+                && a.fields == b.fields
+                && same_data(a.base_ref(), b.base_ref())
+        }
+        _ => false,
+    }
+}
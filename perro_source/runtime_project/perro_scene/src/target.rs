@@ -0,0 +1,86 @@
+/// Platform and feature set that `@if` guards in scene files test against.
+///
+/// `@if platform == "wasm"` matches [`SceneTarget::platform`];
+/// `@if feature == "touch"` matches any entry in [`SceneTarget::features`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SceneTarget {
+    /// `wasm`, `android`, `ios`, `windows`, `macos`, or `linux`.
+    pub platform: String,
+    pub features: Vec<String>,
+}
+
+impl SceneTarget {
+    pub fn new(platform: impl Into<String>) -> Self {
+        Self {
+            platform: platform.into(),
+            features: Vec::new(),
+        }
+    }
+
+    /// Target this crate was compiled for. Android and iOS also get the
+    /// `mobile` feature.
+    pub fn host() -> Self {
+        let platform = if cfg!(target_arch = "wasm32") {
+            "wasm"
+        } else {
+            std::env::consts::OS
+        };
+        Self::new(platform).with_feature_if(matches!(platform, "android" | "ios"), "mobile")
+    }
+
+    /// Target for a Rust target triple, e.g. `wasm32-unknown-unknown` or
+    /// `aarch64-linux-android`.
+    pub fn from_triple(triple: &str) -> Self {
+        let platform = if triple.starts_with("wasm32") {
+            "wasm"
+        } else if triple.contains("android") {
+            "android"
+        } else if triple.contains("apple-ios") {
+            "ios"
+        } else if triple.contains("windows") {
+            "windows"
+        } else if triple.contains("apple-darwin") {
+            "macos"
+        } else {
+            "linux"
+        };
+        Self::new(platform).with_feature_if(matches!(platform, "android" | "ios"), "mobile")
+    }
+
+    pub fn with_feature(mut self, feature: impl Into<String>) -> Self {
+        let feature = feature.into();
+        if !self.features.contains(&feature) {
+            self.features.push(feature);
+        }
+        self
+    }
+
+    pub fn with_feature_if(self, enabled: bool, feature: impl Into<String>) -> Self {
+        if enabled {
+            self.with_feature(feature)
+        } else {
+            self
+        }
+    }
+
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.features.iter().any(|f| f == feature)
+    }
+
+    /// Tests one `subject == "value"` term of an `@if` guard.
+    pub(crate) fn matches(&self, subject: &str, value: &str) -> Result<bool, String> {
+        match subject {
+            "platform" => Ok(self.platform == value),
+            "feature" => Ok(self.has_feature(value)),
+            other => Err(format!(
+                "unknown @if subject `{other}`; expected `platform` or `feature`"
+            )),
+        }
+    }
+}
+
+impl Default for SceneTarget {
+    fn default() -> Self {
+        Self::host()
+    }
+}
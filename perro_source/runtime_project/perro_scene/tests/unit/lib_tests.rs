@@ -40,6 +40,15 @@ fn full_filter_keeps_node_and_strips_reserved_tag() {
     assert_eq!(scene.nodes[0].tags.as_ref(), &["gameplay"]);
 }
 
+#[test]
+fn parse_groups_merge_into_node_tags() {
+    let scene = Parser::new(
+        "[node]\ntags = [\"saveable\"]\ngroups = [\"enemies\", saveable]\n[Node]\n[/Node]\n[/node]\n",
+    )
+    .parse_scene();
+    assert_eq!(scene.nodes[0].tags.as_ref(), &["saveable", "enemies"]);
+}
+
 fn find_node<'a>(scene: &'a Scene, key: &str) -> &'a SceneNodeEntry {
     scene
         .nodes
@@ -111,6 +120,40 @@ fn parse_ui_node_type_block() {
     assert_eq!(ui.data.type_name(), "UiNode");
 }
 
+#[test]
+fn parse_tr_call_matches_loc_marker() {
+    let src = r#"
+    [title]
+    [UiLabel]
+        text = tr("MENU_START")
+        placeholder = %loc: "MENU_START"
+        tooltip = tr(MENU_QUIT)
+    [/UiLabel]
+    [/title]
+    "#;
+
+    let scene = Parser::new(src).parse_scene();
+    let title = find_node(&scene, "title");
+    let field = |name: &str| {
+        title
+            .data
+            .fields
+            .iter()
+            .find(|(field, _)| field.as_ref() == name)
+            .map(|(_, value)| value.clone())
+            .expect("field")
+    };
+
+    assert_eq!(field("text"), field("placeholder"));
+    assert_eq!(field("text"), SceneValue::Str("%loc:MENU_START".into()));
+    assert_eq!(field("tooltip"), SceneValue::Str("%loc:MENU_QUIT".into()));
+    assert!(
+        Parser::new("[a]\n[UiLabel]\ntext = tr(1)\n[/UiLabel]\n[/a]\n")
+            .try_parse_scene()
+            .is_err()
+    );
+}
+
 #[test]
 fn parse_object_literal() {
     let src = r#"
@@ -143,6 +186,159 @@ fn parse_object_literal() {
     }
 }
 
+#[test]
+fn parse_nested_list_and_map_literals() {
+    let src = r#"
+    $root = @main
+
+    [main]
+    script_vars = {
+        waypoints = [(0, 0), (64, 0)],
+        tiles = { width = 2, cells = [1, 0] }
+    }
+    [Node2D]
+    [/Node2D]
+    [/main]
+    "#;
+
+    let scene = Parser::new(src).parse_scene();
+    let main = find_node(&scene, "main");
+    let field = |name: &str| {
+        main.script_vars
+            .iter()
+            .find(|(key, _)| key.as_ref() == name)
+            .map(|(_, value)| value)
+            .expect("script var")
+    };
+
+    let SceneValue::Array(waypoints) = field("waypoints") else {
+        panic!("expected waypoint list");
+    };
+    assert_eq!(waypoints.len(), 2);
+    assert!(matches!(waypoints[1], SceneValue::Vec2 { x, y } if x == 64.0 && y == 0.0));
+
+    let SceneValue::Object(tiles) = field("tiles") else {
+        panic!("expected tile map");
+    };
+    assert!(matches!(
+        tiles.iter().find(|(key, _)| key.as_ref() == "cells"),
+        Some((_, SceneValue::Array(cells))) if cells.len() == 2
+    ));
+}
+
+#[test]
+fn parse_folds_constant_expressions() {
+    let src = r#"
+    $root = @main
+    $spawn_x = 100
+    $step = $spawn_x / 4
+
+    [main]
+    script_vars = {
+        grouped = (2 + 3) * 4,
+        spaced = $step -5 * 2,
+        negated = -$spawn_x + 1
+    }
+    [Node2D]
+        position = ($spawn_x + 16, 32 * 2)
+    [/Node2D]
+    [/main]
+    "#;
+
+    let scene = Parser::new(src).parse_scene();
+    let main = find_node(&scene, "main");
+    let position = main
+        .data
+        .fields
+        .iter()
+        .find(|(name, _)| name.as_ref() == "position")
+        .map(|(_, value)| value)
+        .expect("position field");
+    assert!(matches!(position, SceneValue::Vec2 { x, y } if *x == 116.0 && *y == 64.0));
+
+    let var = |name: &str| {
+        main.script_vars
+            .iter()
+            .find(|(key, _)| key.as_ref() == name)
+            .and_then(|(_, value)| value.as_f32())
+            .expect("script var")
+    };
+    assert_eq!(var("grouped"), 20.0);
+    assert_eq!(var("spaced"), 15.0);
+    assert_eq!(var("negated"), -99.0);
+}
+
+#[test]
+fn parse_rejects_bad_constant_expressions() {
+    for (expr, message) in [
+        ("1 / 0", "division by zero"),
+        ("1 + \"a\"", "needs a number"),
+    ] {
+        let src = format!("$root = @main\n[main]\nscript_vars = {{ v = {expr} }}\n[/main]\n");
+        let err = Parser::new(&src)
+            .try_parse_scene()
+            .expect_err("invalid test input must fail");
+        assert!(err.message.contains(message), "{err}");
+    }
+}
+
+#[test]
+fn parse_connect_lines_into_node_connections() {
+    let src = r#"$root = @root
+[root]
+[Node]
+[/Node]
+[/root]
+[play_button]
+parent = @root
+connect pressed -> /root/ui_controller.on_button_pressed
+connect hovered -> @ui_controller.on_hover
+connect pressed -> /root/ui_controller.on_button_pressed
+[UiButton]
+[/UiButton]
+[/play_button]
+[ui_controller]
+parent = @root
+[Node]
+[/Node]
+[/ui_controller]
+"#;
+    let scene = Parser::new(src).parse_scene();
+    let button = find_node(&scene, "play_button");
+    let expected = [
+        SceneConnection {
+            signal: "pressed".into(),
+            target: "/root/ui_controller".into(),
+            method: "on_button_pressed".into(),
+        },
+        SceneConnection {
+            signal: "hovered".into(),
+            target: "@ui_controller".into(),
+            method: "on_hover".into(),
+        },
+    ];
+    assert_eq!(button.connections.as_ref(), &expected);
+
+    let text = Parser::new(src).parse_scene_doc().to_text();
+    assert!(text.contains("connect pressed -> /root/ui_controller.on_button_pressed\n"));
+    let reparsed = Parser::new(&text).parse_scene();
+    assert_eq!(
+        find_node(&reparsed, "play_button").connections.as_ref(),
+        &expected
+    );
+
+    for (line, message) in [
+        ("connect pressed -> /hud.on_press", "must start at `/root`"),
+        ("connect pressed -> @hud", "connect must look like"),
+    ] {
+        let src = format!("[a]\n{line}\n[Node]\n[/Node]\n[/a]\n");
+        let err = Parser::new(&src)
+            .try_parse_scene()
+            .expect_err("invalid test input must fail");
+        assert!(err.message.contains(message), "{err}");
+    }
+}
+
 #[test]
 fn scene_key_and_value_key_as_ref() {
     let key = SceneKey::new(7);
@@ -186,6 +382,32 @@ fn parse_script_vars_object() {
     );
 }
 
+#[test]
+fn parse_vars_block_merges_into_script_vars() {
+    let src = r#"
+    [enemy]
+    script = "res://scripts/enemy.rs"
+    script_vars = { speed = 100.0, hp = 1 }
+    vars { speed = 240.0, hp = 3 }
+    [Node/]
+    [/enemy]
+    "#;
+
+    let scene = Parser::new(src).parse_scene();
+    let enemy = find_node(&scene, "enemy");
+
+    assert_eq!(enemy.script_vars.len(), 2);
+    let value = |name: &str| {
+        enemy
+            .script_vars
+            .iter()
+            .find(|(field, _)| field.as_ref() == name)
+            .map(|(_, value)| value.clone())
+    };
+    assert_eq!(value("speed"), Some(SceneValue::F32(240.0)));
+    assert_eq!(value("hp"), Some(SceneValue::F32(3.0)));
+}
+
 #[test]
 fn parse_script_vars_keep_custom_field_names() {
     let src = r#"
@@ -335,6 +557,89 @@ fn parse_script_clear_options() {
     assert!(child.clear_script);
 }
 
+#[test]
+fn parse_enabled_and_visible_node_headers() {
+    let src = r#"
+    $root = @main
+    [main]
+    [Node/]
+    [/main]
+
+    [hud]
+    parent = @main
+    enabled = false
+    visible = false
+    [Sprite2D]
+        texture = "res://icon.png"
+    [/Sprite2D]
+    [/hud]
+    "#;
+
+    let doc = Parser::new(src).parse_scene_doc();
+    let main = find_node(&doc.scene, "main");
+    assert!(main.enabled);
+    let hud = find_node(&doc.scene, "hud");
+    assert!(!hud.enabled);
+    assert!(
+        hud.data
+            .fields
+            .iter()
+            .any(|(name, value)| *name == SceneFieldName::Visible
+                && *value == SceneValue::Bool(false))
+    );
+
+    let text = doc.to_text();
+    assert!(text.contains("enabled = false"), "{text}");
+    let reparsed = Parser::new(&text).parse_scene();
+    assert!(!find_node(&reparsed, "hud").enabled);
+
+    let err = Parser::new("$root = @main\n[main]\nvisible = false\n[Node/]\n[/main]\n")
+        .try_parse_scene()
+        .expect_err("Node has no visible field");
+    assert!(err.message.contains("no `visible` field"), "{err}");
+}
+
+#[test]
+fn parse_meta_node_header() {
+    let src = r#"
+    $root = @chest
+    [chest]
+    meta = { loot_table = "res://loot/chest.ron", guard = @guard }
+    meta = { weight = 3 }
+    [Node/]
+    [/chest]
+
+    [guard]
+    parent = @chest
+    [Node/]
+    [/guard]
+    "#;
+
+    let doc = Parser::new(src).parse_scene_doc();
+    let chest = find_node(&doc.scene, "chest");
+    let names = chest
+        .meta
+        .iter()
+        .map(|(name, _)| name.as_ref())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["loot_table", "guard", "weight"]);
+    assert!(chest.script_vars.is_empty());
+    assert!(find_node(&doc.scene, "guard").meta.is_empty());
+
+    let text = doc.to_text();
+    assert!(text.contains("meta = "), "{text}");
+    let reparsed = Parser::new(&text).parse_scene();
+    assert_eq!(
+        find_node(&reparsed, "chest").meta.as_ref(),
+        chest.meta.as_ref()
+    );
+
+    let err = Parser::new("$root = @main\n[main]\nmeta = 3\n[Node/]\n[/main]\n")
+        .try_parse_scene()
+        .expect_err("meta must be an object");
+    assert!(err.message.contains("meta must be an object"), "{err}");
+}
+
 #[test]
 fn parse_root_of_without_type_block() {
     let src = r#"
@@ -537,6 +842,31 @@ fn scene_doc_writes_arrays_and_objects_multiline() {
     assert_eq!(Parser::new(&text).parse_scene().nodes.len(), 2);
 }
 
+#[test]
+fn scene_doc_writes_color_and_rect_literals() {
+    let src = r#"
+    [main]
+    script_vars = { tint = #FF8000 } # warm
+    [Sprite2D]
+        texture_region = rect(0, 16, 32, 32.5)
+    [/Sprite2D]
+    [/main]
+    "#;
+
+    let text = Parser::new(src).parse_scene_doc().to_text();
+
+    assert!(text.contains("tint = #ff8000"), "{text}");
+    assert!(
+        text.contains("texture_region = rect(0.0, 16.0, 32.0, 32.5)"),
+        "{text}"
+    );
+    let reparsed = Parser::new(&text).parse_scene();
+    let original = Parser::new(src).parse_scene();
+    let (main, expected) = (find_node(&reparsed, "main"), find_node(&original, "main"));
+    assert_eq!(main.script_vars.as_ref(), expected.script_vars.as_ref());
+    assert_eq!(main.data.fields.as_ref(), expected.data.fields.as_ref());
+}
+
 #[test]
 fn parse_object_allows_line_separated_entries() {
     let scene = Parser::new(
@@ -857,8 +1187,11 @@ script_vars = { target = @root, speed = 2.5 }
         parent: doc.scene.root,
         script: None,
         clear_script: false,
+        enabled: true,
         root_of: None,
         script_vars: Cow::Owned(Vec::new()),
+        meta: Cow::Owned(Vec::new()),
+        connections: Cow::Owned(Vec::new()),
     });
     doc.normalize_links();
 
@@ -878,3 +1211,778 @@ script_vars = { target = @root, speed = 2.5 }
         Some("root")
     );
 }
+
+#[test]
+fn scene_asset_refs_cover_script_root_of_and_fields() {
+    let scene = Parser::new(
+        r#"
+    [main]
+    script = "res://main.rs"
+    root_of = "res://base.scn"
+    [Node2D/]
+    [/main]
+
+    [player]
+    parent = @main
+    [Sprite2D]
+        texture = "res://player.png"
+    [/Sprite2D]
+    [/player]
+    "#,
+    )
+    .parse_scene();
+    let mut refs = deps::scene_asset_refs(&scene);
+    refs.sort();
+    assert_eq!(
+        refs,
+        vec!["res://base.scn", "res://main.rs", "res://player.png"]
+    );
+}
+
+#[test]
+fn scene_resource_refs_type_standalone_resource_files() {
+    let scene = Parser::new(
+        r#"
+    [main]
+    script = "res://main.rs"
+    script_vars = { walk = "res://anim/walk.panim" }
+    [MeshInstance3D]
+        mesh = "res://rock.glb:mesh[0]"
+        materials = ["res://rock.pmat", "res://ROCK_WET.PMAT", "res://rock.pmat"]
+    [/MeshInstance3D]
+    [/main]
+    "#,
+    )
+    .parse_scene();
+    let refs = deps::scene_resource_refs(&scene);
+    let kinds = refs
+        .iter()
+        .map(|r| (r.kind, r.path.as_str(), r.node.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec![
+            (
+                deps::SceneResourceKind::Animation,
+                "res://anim/walk.panim",
+                "main"
+            ),
+            (deps::SceneResourceKind::Material, "res://rock.pmat", "main"),
+            (
+                deps::SceneResourceKind::Material,
+                "res://ROCK_WET.PMAT",
+                "main"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn dep_graph_walks_forward_and_reverse() {
+    let mut graph = deps::AssetDepGraph::new();
+    graph.set_file_refs(
+        "res://main.scn",
+        ["res://hero.scn".to_string(), "res://ui.uistyle".to_string()],
+    );
+    graph.set_file_refs(
+        "res://hero.scn",
+        [
+            "res://hero.glb:mesh0".to_string(),
+            "res://hero.pmat".to_string(),
+        ],
+    );
+    graph.set_file_refs("res://hero.pmat", ["res://player.png".to_string()]);
+
+    let deps = graph.transitive_deps("res://main.scn");
+    assert!(deps.contains("res://hero.glb"));
+    assert!(deps.contains("res://player.png"));
+    assert!(!deps.contains("res://main.scn"));
+
+    assert_eq!(
+        graph.users_of("res://player.png").collect::<Vec<_>>(),
+        vec!["res://hero.pmat"]
+    );
+    let users = graph.transitive_users("res://player.png");
+    assert!(users.contains("res://main.scn"));
+
+    graph.remove_file("res://hero.pmat");
+    assert!(!graph.is_used("res://player.png"));
+}
+
+#[test]
+fn dep_tree_text_marks_repeats_and_stops_cycles() {
+    let mut graph = deps::AssetDepGraph::new();
+    graph.set_file_refs(
+        "res://a.scn",
+        ["res://b.scn".to_string(), "res://c.png".to_string()],
+    );
+    graph.set_file_refs(
+        "res://b.scn",
+        ["res://a.scn".to_string(), "res://c.png".to_string()],
+    );
+    assert_eq!(
+        graph.deps_tree_text("res://a.scn"),
+        "res://a.scn\n  res://b.scn\n    res://a.scn (see above)\n    res://c.png\n  res://c.png (see above)\n"
+    );
+}
+
+#[test]
+fn dlc_self_refs_resolve_to_owning_dlc() {
+    assert_eq!(
+        deps::normalize_asset_ref("dlc://self/boss.png", "dlc://expansion/boss.scn"),
+        "dlc://expansion/boss.png"
+    );
+    assert_eq!(
+        deps::normalize_asset_ref("res://hero.glb:mesh0", "res://main.scn"),
+        "res://hero.glb"
+    );
+}
+
+fn extends_loader(path: &str) -> Result<String, String> {
+    match path {
+        "res://base_level.scn" => Ok(r#"
+$root = @level
+[level]
+[Node2D]
+    position = (1, 2)
+    scale = (3, 3)
+[/Node2D]
+[/level]
+[door]
+parent = @level
+tags = ["exit"]
+script = "res://scripts/door.rs"
+script_vars = { locked = true, cost = 5 }
+[Sprite2D]
+    texture = "res://door.png"
+[/Sprite2D]
+[/door]
+"#
+        .to_string()),
+        "res://mid_level.scn" => Ok(r#"
+@extends = "res://base_level.scn"
+[door]
+[Sprite2D]
+    flip_x = true
+[/Sprite2D]
+[/door]
+"#
+        .to_string()),
+        "res://loop_a.scn" => Ok("@extends = \"res://loop_b.scn\"\n".to_string()),
+        "res://loop_b.scn" => Ok("@extends = \"res://loop_a.scn\"\n".to_string()),
+        _ => Err("not found".to_string()),
+    }
+}
+
+#[test]
+fn extends_overrides_inherited_fields_and_adds_children() {
+    let src = r#"
+@extends = "res://base_level.scn"
+
+[door]
+script_vars = { locked = false }
+[Sprite2D]
+    texture = "res://door_gold.png"
+[/Sprite2D]
+[/door]
+
+[key]
+parent = @level
+[Node2D]
+[/Node2D]
+[/key]
+"#;
+    let scene = Parser::new(src)
+        .with_scene_loader(&extends_loader)
+        .parse_scene();
+
+    assert_eq!(scene.root.and_then(|k| scene.key_name(k)), Some("level"));
+    assert_eq!(scene.nodes.len(), 3);
+
+    let level = find_node(&scene, "level");
+    assert_eq!(level.data.fields.len(), 2);
+
+    let door = find_node(&scene, "door");
+    assert_eq!(door.parent.and_then(|k| scene.key_name(k)), Some("level"));
+    assert_eq!(door.tags.as_ref(), &["exit"]);
+    assert_eq!(door.script.as_deref(), Some("res://scripts/door.rs"));
+    assert_eq!(door.data.type_name(), "Sprite2D");
+    assert_eq!(
+        door.data.fields[0].1,
+        SceneValue::Str("res://door_gold.png".into())
+    );
+    assert_eq!(door.script_vars.len(), 2);
+    assert_eq!(door.script_vars[0].1, SceneValue::Bool(false));
+
+    let key = find_node(&scene, "key");
+    assert_eq!(key.parent.and_then(|k| scene.key_name(k)), Some("level"));
+}
+
+#[test]
+fn extends_resolves_multi_level_chains() {
+    let scene = Parser::new("@extends = \"res://mid_level.scn\"\n$root = @door\n")
+        .with_scene_loader(&extends_loader)
+        .parse_scene();
+
+    assert_eq!(scene.root.and_then(|k| scene.key_name(k)), Some("door"));
+    let door = find_node(&scene, "door");
+    assert_eq!(door.data.fields.len(), 2);
+}
+
+#[test]
+fn extends_rejects_cycles_type_changes_and_missing_loader() {
+    let err = Parser::new("@extends = \"res://loop_a.scn\"\n")
+        .with_scene_loader(&extends_loader)
+        .try_parse_scene()
+        .expect_err("cycle must fail");
+    assert!(err.message.contains("@extends cycle"), "{err}");
+
+    let err =
+        Parser::new("@extends = \"res://base_level.scn\"\n[door]\n[Node3D]\n[/Node3D]\n[/door]\n")
+            .with_scene_loader(&extends_loader)
+            .try_parse_scene()
+            .expect_err("type change must fail");
+    assert!(err.message.contains("cannot change it"), "{err}");
+
+    let err = Parser::new("@extends = \"res://base_level.scn\"\n")
+        .try_parse_scene()
+        .expect_err("missing loader must fail");
+    assert!(err.message.contains("needs a scene loader"), "{err}");
+}
+
+fn include_loader(path: &str) -> Result<String, String> {
+    match path {
+        "res://fragments/hud.scn" => Ok(r#"
+[hud]
+parent = @main
+[Node2D]
+    scale = ($ui_scale, $ui_scale)
+[/Node2D]
+[/hud]
+@include "res://fragments/health.scn"
+"#
+        .to_string()),
+        "res://fragments/health.scn" => Ok(r#"
+[health]
+parent = @hud
+[Node2D/]
+[/health]
+"#
+        .to_string()),
+        "res://fragments/loop.scn" => Ok("@include \"res://fragments/loop.scn\"\n".to_string()),
+        "res://fragments/rooted.scn" => Ok("$root = @main\n".to_string()),
+        _ => Err("not found".to_string()),
+    }
+}
+
+#[test]
+fn include_splices_fragment_nodes_with_host_vars_and_parents() {
+    let src = r#"
+$root = @main
+$ui_scale = 2
+[main]
+[Node2D/]
+[/main]
+@include "res://fragments/hud.scn"
+[after]
+parent = @health
+[Node2D/]
+[/after]
+"#;
+    let scene = Parser::new(src)
+        .with_scene_loader(&include_loader)
+        .parse_scene();
+
+    assert_eq!(scene.nodes.len(), 4);
+    let hud = find_node(&scene, "hud");
+    assert_eq!(hud.parent.and_then(|k| scene.key_name(k)), Some("main"));
+    assert_eq!(hud.data.fields[0].1, SceneValue::Vec2 { x: 2.0, y: 2.0 });
+    let health = find_node(&scene, "health");
+    assert_eq!(health.parent.and_then(|k| scene.key_name(k)), Some("hud"));
+    let after = find_node(&scene, "after");
+    assert_eq!(after.parent.and_then(|k| scene.key_name(k)), Some("health"));
+}
+
+#[test]
+fn include_rejects_cycles_duplicate_keys_and_root() {
+    let err = Parser::new("@include \"res://fragments/loop.scn\"\n")
+        .with_scene_loader(&include_loader)
+        .try_parse_scene()
+        .expect_err("cycle must fail");
+    assert!(err.message.contains("@include cycle"), "{err}");
+
+    let err =
+        Parser::new("[health]\n[Node/]\n[/health]\n@include \"res://fragments/health.scn\"\n")
+            .with_scene_loader(&include_loader)
+            .try_parse_scene()
+            .expect_err("duplicate key must fail");
+    assert!(
+        err.message.contains("duplicate scene key `health`"),
+        "{err}"
+    );
+
+    let err = Parser::new("@include \"res://fragments/rooted.scn\"\n")
+        .with_scene_loader(&include_loader)
+        .try_parse_scene()
+        .expect_err("fragment root must fail");
+    assert!(err.message.contains("cannot set `$root`"), "{err}");
+}
+
+const GUARDED_SCENE: &str = r#"
+$root = @main
+[main]
+[Node2D]
+[/Node2D]
+[/main]
+@if platform == "wasm" or feature == "mobile"
+$touch_scale = 2
+[touch_ui]
+parent = @main
+[Node2D]
+    scale = ($touch_scale, $touch_scale)
+[/Node2D]
+[/touch_ui]
+@if feature != "gamepad"
+[touch_hint]
+parent = @touch_ui
+[Node2D]
+[/Node2D]
+[/touch_hint]
+@end
+@end
+@if platform != "wasm"
+@if feature != "mobile"
+[touch_ui]
+parent = @main
+[Node]
+[/Node]
+[/touch_ui]
+@end
+@end
+"#;
+
+#[test]
+fn platform_guards_keep_only_matching_blocks() {
+    let web = Parser::new(GUARDED_SCENE)
+        .with_target(SceneTarget::new("wasm"))
+        .parse_scene();
+    assert_eq!(find_node(&web, "touch_ui").data.node_type, NodeType::Node2D);
+    assert!(
+        web.nodes
+            .iter()
+            .any(|n| web.key_name(n.key) == Some("touch_hint"))
+    );
+
+    let gamepad = SceneTarget::from_triple("aarch64-linux-android").with_feature("gamepad");
+    let android = Parser::new(GUARDED_SCENE)
+        .with_target(gamepad)
+        .parse_scene();
+    assert_eq!(
+        find_node(&android, "touch_ui").data.node_type,
+        NodeType::Node2D
+    );
+    assert!(
+        !android
+            .nodes
+            .iter()
+            .any(|n| android.key_name(n.key) == Some("touch_hint"))
+    );
+
+    let desktop = Parser::new(GUARDED_SCENE)
+        .with_target(SceneTarget::new("linux"))
+        .parse_scene();
+    assert_eq!(
+        find_node(&desktop, "touch_ui").data.node_type,
+        NodeType::Node
+    );
+    assert_eq!(desktop.nodes.len(), 2);
+}
+
+#[test]
+fn platform_guards_reject_unbalanced_and_unknown_subjects() {
+    let err = Parser::new("@if platform == \"wasm\"\n[a]\n[Node]\n[/Node]\n[/a]\n")
+        .with_target(SceneTarget::new("wasm"))
+        .try_parse_scene()
+        .expect_err("missing @end must fail");
+    assert!(err.message.contains("missing its `@end`"), "{err}");
+
+    let err = Parser::new("@end\n")
+        .try_parse_scene()
+        .expect_err("stray @end must fail");
+    assert!(err.message.contains("without a matching `@if`"), "{err}");
+
+    let err = Parser::new("@if arch == \"x86\"\n@end\n")
+        .try_parse_scene()
+        .expect_err("unknown subject must fail");
+    assert!(err.message.contains("unknown @if subject"), "{err}");
+}
+
+#[test]
+fn compiled_scene_roundtrips_nodes_values_and_bases() {
+    let scene = Parser::new(
+        r#"
+$root = @main
+[main]
+name = "Root"
+tags = ["level", "spawn"]
+script = "res://scripts/main.rs"
+script_vars = { speed = 2.5, tint = #11223344, hitbox = rect(0, 0, 8, 8), route = [@player, "res://next.scn"] }
+[Node2D]
+    position = (1, -2)
+    z_index = 3
+[/Node2D]
+[/main]
+[player]
+parent = @main
+enabled = false
+meta = { loot_table = "res://loot/goblin.ron", weight = 3 }
+connect hit -> @main.on_player_hit
+[Sprite2D]
+    texture = "res://player.png"
+    [Node2D]
+        position = (4, 5)
+    [/Node2D]
+[/Sprite2D]
+[/player]
+"#,
+    )
+    .parse_scene();
+
+    let bytes = encode_scene(&scene);
+    assert!(is_compiled_scene(&bytes));
+    let decoded = decode_scene(&bytes).expect("decode compiled scene");
+    assert_eq!(encode_scene(&decoded), bytes);
+
+    assert_eq!(decoded.root.and_then(|k| decoded.key_name(k)), Some("main"));
+    let main = find_node(&decoded, "main");
+    assert_eq!(main.name.as_deref(), Some("Root"));
+    assert_eq!(
+        find_node(&decoded, "player").connections.as_ref(),
+        find_node(&scene, "player").connections.as_ref()
+    );
+    assert!(main.connections.is_empty());
+    assert!(main.enabled && !find_node(&decoded, "player").enabled);
+    assert_eq!(
+        find_node(&decoded, "player").meta.as_ref(),
+        find_node(&scene, "player").meta.as_ref()
+    );
+    assert_eq!(main.tags.as_ref(), &["level", "spawn"]);
+    assert_eq!(main.script.as_deref(), Some("res://scripts/main.rs"));
+    assert_eq!(
+        main.script_vars.as_ref(),
+        find_node(&scene, "main").script_vars.as_ref()
+    );
+    assert_eq!(
+        main.data.fields.as_ref(),
+        find_node(&scene, "main").data.fields.as_ref()
+    );
+
+    let player = find_node(&decoded, "player");
+    assert_eq!(
+        player.parent.and_then(|k| decoded.key_name(k)),
+        Some("main")
+    );
+    assert_eq!(player.data.type_name(), "Sprite2D");
+    assert_eq!(
+        player.data.fields.as_ref(),
+        find_node(&scene, "player").data.fields.as_ref()
+    );
+    let base = player.data.base_ref().expect("sprite base chain");
+    assert_eq!(base.type_name(), "Node2D");
+    assert_eq!(base.fields[0].1, SceneValue::Vec2 { x: 4.0, y: 5.0 });
+}
+
+#[test]
+fn compiled_scene_paths_and_corrupt_input() {
+    assert_eq!(
+        compiled_scene_path("res://levels/a.scn"),
+        "res://levels/a.scnb"
+    );
+    assert_eq!(compiled_scene_path("dlc://pack/b.SCN"), "dlc://pack/b.scnb");
+    assert_eq!(
+        compiled_scene_path("res://levels/a.scnb"),
+        "res://levels/a.scnb"
+    );
+    assert_eq!(compiled_scene_path("res://tex.png"), "res://tex.png");
+
+    let bytes = encode_scene(&Parser::new("[a]\n[Node]\n[/Node]\n[/a]\n").parse_scene());
+    assert!(decode_scene(b"[a]").is_err());
+    assert!(decode_scene(&bytes[..bytes.len() - 1]).is_err());
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert!(decode_scene(&trailing).is_err());
+    let mut future = bytes;
+    future[5] = 99;
+    assert!(decode_scene(&future).is_err());
+}
+
+const WRITER_SRC: &str = "# Level layout
+$root = @main
+$speed = 4
+
+[main]
+    [Node2D]
+        position = (0, 0) # spawn
+    [/Node2D]
+[/main]
+
+// enemy
+[enemy]
+parent = @main
+script_vars = { speed = $speed }
+    [Node2D]
+        position = (10, 20)
+    [/Node2D]
+[/enemy]
+";
+
+fn writer_node_mut<'s>(scene: &'s mut Scene, key: &str) -> &'s mut SceneNodeEntry {
+    let key = SceneKey::new(
+        scene
+            .key_names
+            .iter()
+            .position(|name| name.as_ref() == key)
+            .expect("node key") as u32,
+    );
+    scene
+        .nodes
+        .to_mut()
+        .iter_mut()
+        .find(|node| node.key == key)
+        .expect("node")
+}
+
+#[test]
+fn scene_writer_keeps_unchanged_source_verbatim() {
+    let writer = SceneWriter::try_new(WRITER_SRC).expect("valid test input must parse");
+    assert_eq!(writer.write(writer.scene()), WRITER_SRC);
+}
+
+#[test]
+fn scene_writer_patches_one_field_in_place() {
+    let writer = SceneWriter::try_new(WRITER_SRC).expect("valid test input must parse");
+    let mut scene = writer.scene().clone();
+    let enemy = writer_node_mut(&mut scene, "enemy");
+    let position = enemy
+        .data
+        .fields
+        .to_mut()
+        .iter_mut()
+        .find(|(name, _)| name.as_ref() == "position")
+        .expect("position field");
+    position.1 = SceneValue::Vec2 { x: 32.0, y: 20.0 };
+
+    assert_eq!(
+        writer.write(&scene),
+        WRITER_SRC.replace("position = (10, 20)", "position = (32.0, 20.0)")
+    );
+}
+
+#[test]
+fn scene_writer_rerenders_reshaped_nodes_and_appends_new_ones() {
+    let writer = SceneWriter::try_new(WRITER_SRC).expect("valid test input must parse");
+    let mut scene = writer.scene().clone();
+    writer_node_mut(&mut scene, "enemy")
+        .data
+        .fields
+        .to_mut()
+        .push(("visible".into(), SceneValue::Bool(false)));
+    let main = scene.root.expect("root");
+    let coin = SceneKey::new(scene.key_names.len() as u32);
+    scene.key_names.to_mut().push("coin".into());
+    scene.nodes.to_mut().push(SceneNodeEntry {
+        data: SceneNodeData::new(NodeType::Node2D, Vec::new().into(), None),
+        has_data_override: true,
+        key: coin,
+        name: None,
+        tags: Vec::new().into(),
+        children: Vec::new().into(),
+        parent: Some(main),
+        script: None,
+        clear_script: false,
+        enabled: true,
+        root_of: None,
+        script_vars: Vec::new().into(),
+        meta: Vec::new().into(),
+        connections: Vec::new().into(),
+    });
+
+    let text = writer.write(&scene);
+    assert!(
+        text.starts_with("# Level layout\n$root = @main\n$speed = 4\n"),
+        "{text}"
+    );
+    assert!(text.contains("position = (0, 0) # spawn"), "{text}");
+    assert!(text.contains("// enemy\n[enemy]"), "{text}");
+    assert!(text.contains("visible = false"), "{text}");
+    assert!(
+        text.ends_with("[coin]\nparent = @main\n    [Node2D/]\n[/coin]\n"),
+        "{text}"
+    );
+
+    let reparsed = Parser::new(&text).parse_scene();
+    assert_eq!(reparsed.nodes.len(), 3);
+}
+
+#[test]
+fn scene_writer_rewrites_node_with_groups_when_tags_change() {
+    let src = "[main]\ngroups = [\"enemies\"]\n[Node]\n[/Node]\n[/main]\n";
+    let writer = SceneWriter::try_new(src).expect("valid test input must parse");
+    let mut scene = writer.scene().clone();
+    writer_node_mut(&mut scene, "main")
+        .tags
+        .to_mut()
+        .push("saveable".into());
+
+    let text = writer.write(&scene);
+    assert!(
+        text.contains("tags = [\"enemies\", \"saveable\"]"),
+        "{text}"
+    );
+    assert!(!text.contains("groups"), "{text}");
+}
+
+#[test]
+fn scene_diff_patch_turns_old_scene_into_new() {
+    let old = Parser::new(
+        r#"
+    $root = @main
+    [main]
+    [Node2D]
+        position = (0, 0)
+    [/Node2D]
+    [/main]
+    [player]
+    parent = @main
+    [Sprite2D]
+        visible = true
+        texture = "res://player.png"
+    [/Sprite2D]
+    [/player]
+    [enemy]
+    parent = @main
+    [Node2D]
+    [/Node2D]
+    [/enemy]
+    "#,
+    )
+    .parse_scene();
+    let new = Parser::new(
+        r#"
+    $root = @main
+    [main]
+    [Node2D]
+        position = (0, 0)
+    [/Node2D]
+    [/main]
+    [player]
+    parent = @main
+    script = "res://player.rs"
+    [Sprite2D]
+        texture = "res://hero.png"
+    [/Sprite2D]
+    [/player]
+    [coin]
+    parent = @player
+    [Node2D]
+    [/Node2D]
+    [/coin]
+    "#,
+    )
+    .parse_scene();
+
+    let patch = diff(&old, &new);
+    assert!(!patch.ops.iter().any(|op| matches!(
+        op,
+        ScenePatchOp::SetField { key, .. } | ScenePatchOp::RemoveField { key, .. } if key == "main"
+    )));
+    assert!(diff(&new, &new).is_empty());
+
+    let mut patched = old.clone();
+    apply_patch(&mut patched, &patch).expect("patch must apply to its base");
+    assert!(diff(&patched, &new).is_empty());
+    assert_eq!(patched.nodes.len(), 3);
+    let player = find_node(&patched, "player");
+    assert_eq!(player.script.as_deref(), Some("res://player.rs"));
+    assert_eq!(player.data.fields.len(), 1);
+    let coin = find_node(&patched, "coin");
+    assert_eq!(coin.parent, Some(player.key));
+    assert_eq!(player.children.as_ref(), [coin.key]);
+
+    // A patch built against another base fails and leaves the scene alone.
+    let mut unrelated = Parser::new("[other]\n[Node]\n[/Node]\n[/other]\n").parse_scene();
+    assert!(apply_patch(&mut unrelated, &patch).is_err());
+    assert_eq!(unrelated.nodes.len(), 1);
+}
+
+#[test]
+fn parse_nodes_streams_blocks_with_linked_parents() {
+    let src = r#"
+$root = @main
+$ui_scale = 2
+[main]
+[Node2D/]
+[/main]
+[child]
+parent = @later
+[Node2D/]
+[/child]
+@include "res://fragments/hud.scn"
+[later]
+parent = @main
+[Node2D/]
+[/later]
+"#;
+    let mut stream = Parser::new(src)
+        .with_scene_loader(&include_loader)
+        .parse_nodes();
+
+    let main = stream.next().expect("main").expect("main parses");
+    assert_eq!(stream.root(), Some(main.key));
+    let child = stream.next().expect("child").expect("child parses");
+    let hud = stream.next().expect("hud").expect("hud parses");
+    let health = stream.next().expect("health").expect("health parses");
+    let later = stream.next().expect("later").expect("later parses");
+    assert!(stream.next().is_none());
+
+    assert_eq!(child.parent, Some(later.key));
+    assert_eq!(hud.parent, Some(main.key));
+    assert_eq!(hud.data.fields[0].1, SceneValue::Vec2 { x: 2.0, y: 2.0 });
+    assert_eq!(health.parent, Some(hud.key));
+    assert_eq!(stream.key_name(later.key), Some("later"));
+
+    let scene = Parser::new(src)
+        .with_scene_loader(&include_loader)
+        .parse_scene();
+    assert_eq!(scene.nodes.len(), 5);
+}
+
+#[test]
+fn parse_nodes_reports_unresolved_refs_after_last_node() {
+    let src =
+        "$root = @main\n[main]\n[Node/]\n[/main]\n[orphan]\nparent = @ghost\n[Node/]\n[/orphan]\n";
+    let items: Vec<_> = Parser::new(src).parse_nodes().collect();
+    assert_eq!(items.len(), 3);
+    assert!(items[0].is_ok() && items[1].is_ok());
+    let err = items[2].as_ref().expect_err("ghost parent must fail");
+    assert!(
+        err.message.contains("`ghost` not found for child `orphan`"),
+        "{err}"
+    );
+
+    let items: Vec<_> = Parser::new("$root = @gone\n[main]\n[Node/]\n[/main]\n")
+        .parse_nodes()
+        .collect();
+    let err = items[1].as_ref().expect_err("missing root must fail");
+    assert!(err.message.contains("scene root `gone`"), "{err}");
+
+    let mut stream = Parser::new("@extends = \"res://base_level.scn\"\n")
+        .with_scene_loader(&extends_loader)
+        .parse_nodes();
+    let err = stream
+        .next()
+        .expect("error item")
+        .expect_err("extends must fail");
+    assert!(err.message.contains("@extends"), "{err}");
+    assert!(stream.next().is_none());
+}
@@ -4,6 +4,7 @@ use perro_resource_api::{ResourceWindow, api::ResourceAPI};
 use perro_runtime_api::{RuntimeWindow, api::RuntimeAPI};
 use perro_variant::{SceneVariantResolver, Variant};
 use std::any::Any;
+use std::sync::Arc;
 
 /// Magic bytes at the start of every v2 dynamic-script ABI descriptor.
 pub const SCRIPT_ABI_V2_MAGIC: [u8; 8] = *b"PERROSC\0";
@@ -101,8 +102,18 @@ pub trait ScriptLifecycle<API: ScriptAPI + ?Sized> {
     fn on_update(&self, _ctx: &mut ScriptContext<'_, API>) {}
     /// Called during fixed-step update when scheduled.
     fn on_fixed_update(&self, _ctx: &mut ScriptContext<'_, API>) {}
+    /// Called once per buffered device event, in arrival order, before
+    /// `on_update`. `event` is a `Variant::Object` w/ `type` and `time`.
+    fn on_input(&self, _ctx: &mut ScriptContext<'_, API>, _event: &Variant) {}
     /// Called before this script instance is detached or its node is removed.
     fn on_removal(&self, _ctx: &mut ScriptContext<'_, API>) {}
+    /// Called when restoring state saved under an older `STATE_VERSION`.
+    ///
+    /// `old` is the saved `Variant::Object` keyed by field name. Return the
+    /// object rewritten for the current layout; unknown keys are ignored.
+    fn migrate_state(&self, old: Variant, _old_version: u32) -> Variant {
+        old
+    }
 }
 
 /// Behavior object shared by all instances of one script definition.
@@ -113,7 +124,7 @@ pub trait ScriptLifecycle<API: ScriptAPI + ?Sized> {
 /// attached node receives its own [`Any`] state object from
 /// [`ScriptBehavior::create_state`], so mutable game state stays per instance.
 pub trait ScriptBehavior<API: ScriptAPI + ?Sized>: ScriptLifecycle<API> {
-    /// Return lifecycle flags used to build update/fixed/removal schedules.
+    /// Return lifecycle flags used to build update/fixed/input/removal schedules.
     fn script_flags(&self) -> ScriptFlags;
 
     /// Create per-instance script state.
@@ -154,6 +165,67 @@ pub trait ScriptBehavior<API: ScriptAPI + ?Sized>: ScriptLifecycle<API> {
         ctx: &mut ScriptContext<'_, API>,
         params: &[Variant],
     ) -> Variant;
+
+    /// Return the `#[attr("...")]` tags declared on one state member.
+    ///
+    /// Generated scripts override this; hand-written behaviors report none.
+    fn attributes_of(&self, member: ScriptMemberID) -> &'static [&'static str] {
+        let _ = member;
+        &[]
+    }
+
+    /// Return every state member tagged with `#[attr(attribute)]`, in
+    /// declaration order.
+    fn members_with(&self, attribute: &str) -> &'static [ScriptMemberID] {
+        let _ = attribute;
+        &[]
+    }
+
+    /// Return the state layout version declared by `const STATE_VERSION: u32`.
+    ///
+    /// Scripts without the constant report version `0`.
+    fn state_version(&self) -> u32 {
+        0
+    }
+
+    /// Return state field names in declaration order.
+    ///
+    /// Generated scripts override this; hand-written behaviors report none.
+    fn state_fields(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Capture every state field as a `Variant::Object` keyed by field name.
+    fn save_state(&self, state: &dyn Any) -> Variant {
+        let fields = self
+            .state_fields()
+            .iter()
+            .map(|&name| {
+                let value = self.get_var(state, ScriptMemberID::from_string(name));
+                (Arc::<str>::from(name), value)
+            })
+            .collect();
+        Variant::Object(fields)
+    }
+
+    /// Write a saved field object back into state.
+    ///
+    /// Runs [`ScriptLifecycle::migrate_state`] first when `saved_version`
+    /// differs from [`ScriptBehavior::state_version`]. Non-object payloads are
+    /// ignored so state keeps its defaults.
+    fn restore_state(&self, state: &mut dyn Any, saved: Variant, saved_version: u32) {
+        let saved = if saved_version == self.state_version() {
+            saved
+        } else {
+            self.migrate_state(saved, saved_version)
+        };
+        let Variant::Object(fields) = saved else {
+            return;
+        };
+        for (name, value) in fields {
+            self.set_var(state, ScriptMemberID::from_string(&name), value);
+        }
+    }
 }
 
 /// Cast script state to a concrete type without a runtime type check.
@@ -249,6 +321,8 @@ impl ScriptFlags {
     pub const HAS_ALL_INIT: u8 = 1 << 3;
     /// `on_removal` exists.
     pub const HAS_REMOVAL: u8 = 1 << 4;
+    /// `on_input` exists.
+    pub const HAS_INPUT: u8 = 1 << 5;
 
     /// Create flags from a bitmask built by generated script glue.
     #[inline(always)]
@@ -285,4 +359,10 @@ impl ScriptFlags {
     pub const fn has_removal(self) -> bool {
         self.0 & Self::HAS_REMOVAL != 0
     }
+
+    /// Return whether `on_input` exists.
+    #[inline(always)]
+    pub const fn has_input(self) -> bool {
+        self.0 & Self::HAS_INPUT != 0
+    }
 }
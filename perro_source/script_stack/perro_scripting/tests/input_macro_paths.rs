@@ -48,6 +48,8 @@ fn exercise_gamepad_macros<IP: InputAPI + ?Sized>(ipt: &InputWindow<'_, IP>) {
     let _ = gamepad_gyro!(ipt, 0);
     let _ = gamepad_accel!(ipt, 0);
     gamepad_set_rumble!(ipt, 0, 0.0, 0.0);
+    rumble!(ipt, RumbleDevice::Player(0), 0.4, 0.8, 0.25);
+    let _ = rumble_supported!(ipt, GamepadIndex(0));
 }
 
 fn exercise_key_action_mouse_macros<IP: InputAPI + ?Sized>(ipt: &InputWindow<'_, IP>) {
@@ -1223,6 +1223,11 @@ fn take_default_expr(field: &mut Field) -> Result<Option<Expr>> {
         if attr.path().is_ident("expose") || attr.path().is_ident("node_ref") {
             continue;
         }
+        if attr.path().is_ident("attr") {
+            // Tags are read by the script compiler; only validate the shape here.
+            parse_field_tags(&attr.meta)?;
+            continue;
+        }
         if !attr.path().is_ident("default") {
             retained.push(attr);
             continue;
@@ -1242,6 +1247,24 @@ fn take_default_expr(field: &mut Field) -> Result<Option<Expr>> {
     Ok(default_expr)
 }
 
+fn parse_field_tags(meta: &Meta) -> Result<()> {
+    let Meta::List(list) = meta else {
+        return Err(syn::Error::new_spanned(
+            meta,
+            "`attr` requires string tags, for example `#[attr(\"save\")]`",
+        ));
+    };
+    let tags = list
+        .parse_args_with(syn::punctuated::Punctuated::<LitStr, syn::Token![,]>::parse_terminated)?;
+    if tags.is_empty() {
+        return Err(syn::Error::new_spanned(
+            list,
+            "`attr` requires at least one string tag",
+        ));
+    }
+    Ok(())
+}
+
 fn parse_default_expr(meta: &Meta) -> Result<Expr> {
     match meta {
         Meta::NameValue(named) => syn::parse2(named.value.to_token_stream()),
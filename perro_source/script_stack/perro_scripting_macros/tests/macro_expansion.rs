@@ -13,9 +13,11 @@ use perro_variant::{DeriveVariant, Variant as VariantValue, VariantSchema};
 #[State]
 #[derive(Debug, PartialEq)]
 struct Defaults {
+    #[attr("save")]
     #[default = 7]
     count: i32,
     #[default("ready".to_owned())]
+    #[attr("save", "editor_readonly")]
     label: String,
     flag: bool,
 }
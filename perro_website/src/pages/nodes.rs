@@ -391,6 +391,7 @@ const fn node_role(node_type: NodeType) -> NodeRole {
         | NodeType::PinJoint2D
         | NodeType::DistanceJoint2D
         | NodeType::FixedJoint2D
+        | NodeType::SpringJoint2D
         | NodeType::CollisionShape3D
         | NodeType::StaticBody3D
         | NodeType::Area3D
@@ -399,7 +400,10 @@ const fn node_role(node_type: NodeType) -> NodeRole {
         | NodeType::PhysicsForceEmitter3D
         | NodeType::BallJoint3D
         | NodeType::HingeJoint3D
-        | NodeType::FixedJoint3D => NodeRole::Physics,
+        | NodeType::FixedJoint3D
+        | NodeType::SpringJoint3D
+        | NodeType::VehicleBody3D
+        | NodeType::VehicleWheel3D => NodeRole::Physics,
         NodeType::AudioMask2D
         | NodeType::AudioEffectZone2D
         | NodeType::AudioPortal2D